@@ -5,14 +5,28 @@ import { writeText } from "@tauri-apps/plugin-clipboard-manager";
 import { openUrl } from "@tauri-apps/plugin-opener";
 import { Copy, ExternalLink, Trash2, X, Download } from "lucide-solid";
 import { Titlebar } from "./components/Titlebar";
-import { api, HotkeyDiagnostics, UpdateInfo } from "./api";
+import {
+  api,
+  CrashReport,
+  DndStatus,
+  EncodingProgress,
+  GameModeStatus,
+  HotkeyDiagnostics,
+  RecordingReviewInfo,
+  RecordingStats,
+  RecoveredRecording,
+  UpdateInfo,
+} from "./api";
 import { configDirty, setConfigDirty } from "./dirty";
+import { arrowNav, trapFocus } from "./a11y";
+import { MessageKey, t, tm } from "./i18n";
 import { Settings } from "./views/Settings";
 import { History } from "./views/History";
 import { Destinations } from "./views/Destinations";
 import { Tasks } from "./views/Tasks";
 import { config, refetchConfig, mutateConfig } from "./store";
 import { HotkeyInput } from "./components/HotkeyInput";
+import { RecordingReview } from "./components/RecordingReview";
 import { PinView } from "./views/PinView";
 import { Selector } from "./views/Selector";
 import { RecBar } from "./views/RecBar";
@@ -32,6 +46,17 @@ interface Toast {
   msg: string;
 }
 
+// one line in the statusbar message strip. the strip shows the newest for a
+// few seconds; clicking it opens the session's log with per-entry actions
+interface StatusEntry {
+  id: number;
+  at: number;
+  tone: "ok" | "err";
+  text: string;
+  path?: string;
+  url?: string;
+}
+
 interface UploadCard {
   id: number;
   url: string;
@@ -41,7 +66,7 @@ interface UploadCard {
 type Tab = {
   id: "settings" | "tasks" | "history" | "destinations" | "marketplace";
   key: string; // single-char keyboard mnemonic shown in brackets, also Alt-N shortcut
-  label: string;
+  label: MessageKey;
   context: string;
 };
 
@@ -49,18 +74,18 @@ type Tab = {
 const RELEASES_URL = "https://github.com/zeo/capscr/releases/latest";
 
 const TABS: Tab[] = [
-  { id: "settings", key: "s", label: "settings", context: "settings" },
-  { id: "tasks", key: "t", label: "tasks", context: "tasks" },
-  { id: "history", key: "h", label: "history", context: "history" },
-  { id: "destinations", key: "d", label: "destinations", context: "upload" },
-  { id: "marketplace", key: "m", label: "plugins", context: "plugins" },
+  { id: "settings", key: "s", label: "tab.settings", context: "settings" },
+  { id: "tasks", key: "t", label: "tab.tasks", context: "tasks" },
+  { id: "history", key: "h", label: "tab.history", context: "history" },
+  { id: "destinations", key: "d", label: "tab.destinations", context: "upload" },
+  { id: "marketplace", key: "m", label: "tab.plugins", context: "plugins" },
 ];
 
 export function App() {
   const label = getCurrentWindow().label;
   if (label === "editor") {
     return (
-      <Suspense fallback={<div class="editor-loading">loading editor…</div>}>
+      <Suspense fallback={<div class="editor-loading">{t("app.loading-editor")}</div>}>
         <Editor />
       </Suspense>
     );
@@ -81,13 +106,13 @@ function Hub() {
   const needsOnboarding = () => {
     const c = config();
     if (!c) return false;
-    const task = c.capture_tasks.find((t) => t.id === "screenshot-save-clipboard");
+    const task = c.capture_tasks.find((x) => x.id === "screenshot-save-clipboard");
     return task && !task.hotkey;
   };
 
   // open to history by default — "what just happened" is the expected view;
   // settings is buried behind a tab click.
-  const historyTab = TABS.find((t) => t.id === "history") ?? TABS[0];
+  const historyTab = TABS.find((x) => x.id === "history") ?? TABS[0];
   const [tab, setTab] = createSignal<Tab>(historyTab);
   const [captures, { refetch: refetchCaptures }] = createResource(api.listCaptures);
   const [toasts, setToasts] = createSignal<Toast[]>([]);
@@ -95,12 +120,27 @@ function Hub() {
   const [recording, setRecording] = createSignal(false);
   const [recordingSince, setRecordingSince] = createSignal<number | null>(null);
   const [recordingElapsed, setRecordingElapsed] = createSignal("00:00");
+  // captures still being written in the background
+  const [encoding, setEncoding] = createSignal<EncodingProgress>({
+    queued: 0,
+    running: 0,
+    pending_bytes: 0,
+  });
+  const [dnd, setDnd] = createSignal<DndStatus>({ active: false, until_ms: null });
+  const [gameMode, setGameMode] = createSignal<GameModeStatus>({ app: null, action: "none" });
   const [dragOver, setDragOver] = createSignal(false);
   const [updateInfo, setUpdateInfo] = createSignal<UpdateInfo | null>(null);
   const [updateDismissed, setUpdateDismissed] = createSignal(false);
   const [trayMissing, setTrayMissing] = createSignal(false);
+  // gif recordings a crash (or a failed save on quit) left behind
+  const [recovered, setRecovered] = createSignal<RecoveredRecording[]>([]);
+  const [crashes, setCrashes] = createSignal<CrashReport[]>([]);
+  const [review, setReview] = createSignal<RecordingReviewInfo | null>(null);
   const [updating, setUpdating] = createSignal(false);
   const [showShortcuts, setShowShortcuts] = createSignal(false);
+  const [statusMsg, setStatusMsg] = createSignal<StatusEntry | null>(null);
+  const [statusLog, setStatusLog] = createSignal<StatusEntry[]>([]);
+  const [showStatusLog, setShowStatusLog] = createSignal(false);
   const [hotkeyDiag, { refetch: refetchHotkeyDiag }] = createResource<HotkeyDiagnostics>(
     api.hotkeyDiagnostics,
   );
@@ -139,6 +179,7 @@ function Hub() {
   // up unbounded DOM nodes — anything older than the cap is silently dropped.
   const MAX_TOASTS = 8;
   const MAX_UPLOADS = 6;
+  const MAX_STATUS_LOG = 30;
 
   onCleanup(() => toastTimers.forEach(clearTimeout));
 
@@ -149,10 +190,19 @@ function Hub() {
       return next.length > MAX_TOASTS ? next.slice(-MAX_TOASTS) : next;
     });
     toastTimers.push(setTimeout(() => {
-      setToasts((cur) => cur.filter((t) => t.id !== id));
+      setToasts((cur) => cur.filter((x) => x.id !== id));
     }, 6000));
   };
 
+  const pushStatus = (entry: Omit<StatusEntry, "id" | "at">) => {
+    const next = { ...entry, id: nextId++, at: Date.now() };
+    setStatusMsg(next);
+    setStatusLog((cur) => [...cur, next].slice(-MAX_STATUS_LOG));
+    toastTimers.push(setTimeout(() => {
+      setStatusMsg((cur) => (cur?.id === next.id ? null : cur));
+    }, 5000));
+  };
+
   const pushUpload = (url: string, deleteUrl: string | null) => {
     const id = nextId++;
     setUploads((cur) => {
@@ -161,15 +211,91 @@ function Hub() {
     });
   };
 
+  onMount(() => {
+    api
+      .listRecoveredRecordings()
+      .then(setRecovered)
+      .catch(() => {
+        // nothing to offer
+      });
+  });
+
+  // the backend releases the hotkeys, saves any running recording and writes
+  // config before it exits
+  const quit = async () => {
+    if (configDirty() && !window.confirm(t("app.quit.dirty"))) return;
+    if (recording() && !window.confirm(t("app.quit.recording"))) return;
+    await api.exitApp();
+  };
+
+  onMount(() => {
+    api
+      .listCrashReports()
+      .then(setCrashes)
+      .catch(() => {
+        // nothing to offer
+      });
+  });
+
+  const copyCrashReport = async (item: CrashReport) => {
+    try {
+      await writeText(await api.readCrashReport(item.id));
+      pushStatus({ tone: "ok", text: t("app.status.crash-copied") });
+    } catch (e) {
+      pushStatus({ tone: "err", text: t("app.status.crash-error", { error: String(e) }) });
+    }
+  };
+
+  const dismissCrashReport = async (item: CrashReport) => {
+    try {
+      await api.dismissCrashReport(item.id);
+      setCrashes((cur) => cur.filter((r) => r.id !== item.id));
+    } catch (e) {
+      pushStatus({ tone: "err", text: t("app.status.crash-error", { error: String(e) }) });
+    }
+  };
+
+  const settleRecovered = async (item: RecoveredRecording, restore: boolean) => {
+    try {
+      if (restore) {
+        const path = await api.restoreRecoveredRecording(item.id);
+        pushStatus({ tone: "ok", text: t("app.status.restored", { path }) });
+      } else {
+        await api.discardRecoveredRecording(item.id);
+      }
+      setRecovered((cur) => cur.filter((r) => r.id !== item.id));
+    } catch (e) {
+      pushStatus({ tone: "err", text: t("app.status.recovered-error", { error: String(e) }) });
+    }
+  };
+
   onMount(async () => {
     unlisteners.push(
       await listen<{ kind: string; msg: string }>(
         "capscr://error",
-        (e) => pushToast(e.payload.kind, e.payload.msg),
+        (e) => {
+          pushToast(e.payload.kind, e.payload.msg);
+          pushStatus({
+            tone: "err",
+            text: t("app.status.failed", { kind: e.payload.kind, msg: e.payload.msg }),
+          });
+        },
       ),
       await listen<{ url: string; delete_url: string | null }>(
         "capscr://upload-success",
-        (e) => pushUpload(e.payload.url, e.payload.delete_url),
+        (e) => {
+          // the email destination hands off to the mail client without a link
+          if (!e.payload.url) {
+            pushStatus({ tone: "ok", text: t("app.status.mail") });
+            return;
+          }
+          pushUpload(e.payload.url, e.payload.delete_url);
+          pushStatus({
+            tone: "ok",
+            text: t("app.status.uploaded", { url: e.payload.url }),
+            url: e.payload.url,
+          });
+        },
       ),
       await listen<string>("capscr://recording-started", (e) => {
         setRecording(true);
@@ -180,10 +306,10 @@ function Hub() {
         // the recording off, but that's not obvious. Look up the hotkey from
         // config so the toast is concrete.
         const taskId = e.payload;
-        const task = config()?.capture_tasks.find((t) => t.id === taskId);
+        const task = config()?.capture_tasks.find((x) => x.id === taskId);
         const hint = task?.hotkey
-          ? `recording — press ${task.hotkey} again to stop`
-          : `recording — press the same hotkey again to stop`;
+          ? t("app.toast.recording", { hotkey: task.hotkey })
+          : t("app.toast.recording.same");
         pushToast("recording", hint);
       }),
       await listen("capscr://recording-stopped", () => {
@@ -195,15 +321,49 @@ function Hub() {
       // gnome); the hub is already open, this just explains why there's no
       // tray icon and how to keep reaching capscr
       await listen("capscr://tray-missing", () => setTrayMissing(true)),
+      await listen<EncodingProgress>("capscr://encoding-progress", (e) => setEncoding(e.payload)),
+      await listen<DndStatus>("capscr://dnd-changed", (e) => setDnd(e.payload)),
+      await listen<GameModeStatus>("capscr://game-mode", (e) => setGameMode(e.payload)),
+      // how the capture loop kept up, for telling a choppy gif apart from a
+      // slow screen. more than a tenth of the slots lost is worth flagging
+      await listen<RecordingStats>("capscr://recording-stats", (e) => {
+        const s = e.payload;
+        const expected = (s.target_fps * s.duration_ms) / 1000;
+        const choppy = s.dropped > 0 && s.dropped * 10 > expected;
+        pushStatus({
+          tone: choppy ? "err" : "ok",
+          text:
+            t("app.status.stats", {
+              achieved: s.achieved_fps.toFixed(1),
+              target: s.target_fps,
+              dropped: s.dropped,
+            }) +
+            (s.failed > 0 ? t("app.status.stats.failed", { failed: s.failed }) : "") +
+            (choppy ? t("app.status.stats.choppy") : ""),
+        });
+      }),
+      // the running recording is about to hit its max duration
+      await listen<number>("capscr://recording-ending", (e) => {
+        pushStatus({
+          tone: "err",
+          text: t("app.status.ending", { seconds: e.payload }),
+        });
+      }),
+      // a stopped recording is waiting on the trim preview
+      await listen("capscr://recording-review", () => {
+        void api.recordingReview().then(setReview).catch(() => {});
+      }),
       // the hub window is reused for the whole process, so this resource loads
       // once at first mount; refetch it when a capture lands so the statusbar
       // count actually tracks new screenshots and recordings
-      await listen("capscr://capture-saved", () => {
+      await listen<string>("capscr://capture-saved", (e) => {
         refetchCaptures();
+        const name = e.payload.split(/[\\/]/).pop() ?? e.payload;
+        pushStatus({ tone: "ok", text: t("app.status.saved", { name }), path: e.payload });
       }),
       // tray "Open hub → <Tab>" fires this so the hub lands on the chosen tab
       await listen<string>("capscr://goto-tab", (e) => {
-        const target = TABS.find((t) => t.id === e.payload);
+        const target = TABS.find((x) => x.id === e.payload);
         if (target) tryChangeTab(target);
       }),
       // tray destination switcher mutates config — refetch so any visible
@@ -212,16 +372,24 @@ function Hub() {
       // the user's pending changes. their next save wins instead.
       await listen("capscr://config-updated", async () => {
         if (configDirty()) {
-          // don't clobber unsaved edits, but still pick up the one field the
-          // tray can change out from under the user — the upload destination —
-          // so their next save doesn't silently revert the tray switch
+          // don't clobber unsaved edits, but still pick up the fields the
+          // backend can change out from under the user — the tray's upload
+          // destination and the imgur sign-in — so the UI doesn't go stale
           try {
             const fresh = await api.getConfig();
             const cur = config();
             if (cur) {
               mutateConfig({
                 ...cur,
-                upload: { ...cur.upload, destination: fresh.upload.destination },
+                upload: {
+                  ...cur.upload,
+                  destination: fresh.upload.destination,
+                  imgur: {
+                    ...cur.upload.imgur,
+                    refresh_token_encrypted: fresh.upload.imgur.refresh_token_encrypted,
+                    username: fresh.upload.imgur.username,
+                  },
+                },
               });
             }
           } catch {
@@ -242,6 +410,13 @@ function Hub() {
       }),
     );
 
+    void api.encodingProgress().then(setEncoding).catch(() => {});
+    void api.doNotDisturbStatus().then(setDnd).catch(() => {});
+    void api.gameModeStatus().then(setGameMode).catch(() => {});
+
+    // the hub may have been opened by the review itself, after the event fired
+    void api.recordingReview().then((info) => info && setReview(info)).catch(() => {});
+
     // background update check — delayed 4s so it doesn't compete with hub
     // first-paint or block the network during the user's first capture.
     // skip entirely if the user has opted out in Settings.
@@ -275,12 +450,23 @@ function Hub() {
         setShowShortcuts(false);
         return;
       }
+      if (ev.key === "Escape" && showStatusLog()) {
+        ev.preventDefault();
+        setShowStatusLog(false);
+        return;
+      }
+      // ctrl+Q — the one way out on a desktop without a tray icon
+      if (ev.key.toLowerCase() === "q" && (ev.ctrlKey || ev.metaKey) && !ev.altKey && !ev.shiftKey) {
+        ev.preventDefault();
+        void quit();
+        return;
+      }
       // alt+S/T/H/D/M for tab switching — sidebar titles advertise these so
       // the keybind has to actually work. We respect the dirty-state guard so
       // alt-jumping out of unsaved edits still prompts.
       if (!ev.altKey || ev.ctrlKey || ev.metaKey || ev.shiftKey) return;
       const k = ev.key.toLowerCase();
-      const target = TABS.find((t) => t.key === k);
+      const target = TABS.find((x) => x.key === k);
       if (!target) return;
       ev.preventDefault();
       tryChangeTab(target);
@@ -304,10 +490,7 @@ function Hub() {
         const accepted = paths.slice(0, MAX_BATCH);
         const overflow = paths.length - accepted.length;
         if (overflow > 0) {
-          pushToast(
-            "upload",
-            `dropped ${paths.length} files — uploading first ${MAX_BATCH}, drop the rest after these finish`,
-          );
+          pushToast("upload", t("app.toast.dropped", { count: paths.length, max: MAX_BATCH }));
         }
         for (const path of accepted) {
           try {
@@ -324,7 +507,7 @@ function Hub() {
   onCleanup(() => unlisteners.forEach((u) => u()));
 
   const confirmDiscardEdits = (): boolean =>
-    !configDirty() || window.confirm("Discard unsaved settings changes?");
+    !configDirty() || window.confirm(t("app.discard-edits"));
 
   const tryChangeTab = (next: Tab) => {
     if (tab().id === next.id) return;
@@ -354,16 +537,21 @@ function Hub() {
 
   return (
     <div class="app">
+      <a class="skip-link" href="#main-content">{t("app.skip")}</a>
       <Titlebar context={tab().context} onClose={onClose} />
 
       <Show when={needsOnboarding()}>
         <div class="onboarding-overlay">
-          <div class="onboarding-card">
-            <h2>welcome to capscr</h2>
-            <p class="lede">let's set up your screenshot shortcut key first</p>
-            <p class="desc">
-              not every keyboard has a PrintScreen key. click the box below and press any key combination (e.g. PrintScreen, Ctrl+Alt+S, Alt+Shift+A) to bind it.
-            </p>
+          <div
+            class="onboarding-card"
+            role="dialog"
+            aria-modal="true"
+            aria-label={t("app.onboarding.title")}
+            ref={trapFocus}
+          >
+            <h2>{t("app.onboarding.title")}</h2>
+            <p class="lede">{t("app.onboarding.lede")}</p>
+            <p class="desc">{t("app.onboarding.desc")}</p>
             <div style="margin: 24px 0 12px 0; width: 280px; align-self: center;">
               <HotkeyInput
                 value=""
@@ -371,7 +559,7 @@ function Hub() {
                   if (!nextHotkey) return;
                   const c = config();
                   if (!c) return;
-                  const index = c.capture_tasks.findIndex((t) => t.id === "screenshot-save-clipboard");
+                  const index = c.capture_tasks.findIndex((x) => x.id === "screenshot-save-clipboard");
                   if (index !== -1) {
                     const next = [...c.capture_tasks];
                     next[index] = { ...next[index], hotkey: nextHotkey };
@@ -380,9 +568,9 @@ function Hub() {
                     try {
                       await api.setConfig(nextConfig);
                       setConfigDirty(false);
-                      pushToast("config", "screenshot hotkey bound successfully");
+                      pushToast("config", t("app.toast.hotkey-bound"));
                     } catch (e) {
-                      pushToast("config", `failed to save: ${e}`);
+                      pushToast("config", t("app.toast.save-failed", { error: String(e) }));
                     }
                   }
                 }}
@@ -396,22 +584,27 @@ function Hub() {
         <div class="sidebar-label">
           <span class="sidebar-label-mark">▮</span> capscr/console
         </div>
-        <nav class="sidebar-nav">
+        <nav
+          class="sidebar-nav"
+          aria-label={t("app.nav")}
+          onKeyDown={(ev) => arrowNav(ev, "vertical")}
+        >
           <For each={TABS}>
-            {(t) => (
+            {(item) => (
               <button
                 type="button"
                 class="nav-item"
-                classList={{ "is-active": active() === t.id }}
-                onClick={() => tryChangeTab(t)}
-                title={`Alt+${t.key.toUpperCase()}`}
+                classList={{ "is-active": active() === item.id }}
+                aria-current={active() === item.id ? "page" : undefined}
+                onClick={() => tryChangeTab(item)}
+                title={`Alt+${item.key.toUpperCase()}`}
               >
                 <span class="nav-item-key">
                   <span class="nav-item-bracket">[</span>
-                  {t.key}
+                  {item.key}
                   <span class="nav-item-bracket">]</span>
                 </span>
-                <span class="nav-item-label">{t.label}</span>
+                <span class="nav-item-label">{t(item.label)}</span>
               </button>
             )}
           </For>
@@ -427,71 +620,153 @@ function Hub() {
           class="shortcuts-overlay"
           onClick={() => setShowShortcuts(false)}
           role="dialog"
-          aria-label="keyboard shortcuts"
+          aria-modal="true"
+          aria-label={t("app.shortcuts.title")}
         >
-          <div class="shortcuts-panel" onClick={(e) => e.stopPropagation()}>
+          <div class="shortcuts-panel" ref={trapFocus} onClick={(e) => e.stopPropagation()}>
             <div class="shortcuts-head">
-              <span class="shortcuts-title">keyboard shortcuts</span>
+              <span class="shortcuts-title">{t("app.shortcuts.title")}</span>
               <button
                 type="button"
                 class="icon-btn"
                 onClick={() => setShowShortcuts(false)}
-                aria-label="close"
+                aria-label={t("common.close")}
               >
                 <X size={12} stroke-width={1.5} />
               </button>
             </div>
             <div class="shortcuts-body">
               <div class="shortcuts-group">
-                <span class="shortcuts-group-label">hub</span>
-                <div class="shortcuts-row"><kbd>F1</kbd><span>toggle this overlay</span></div>
-                <div class="shortcuts-row"><kbd>Alt</kbd>+<kbd>S</kbd><span>settings tab</span></div>
-                <div class="shortcuts-row"><kbd>Alt</kbd>+<kbd>T</kbd><span>tasks tab</span></div>
-                <div class="shortcuts-row"><kbd>Alt</kbd>+<kbd>H</kbd><span>history tab</span></div>
-                <div class="shortcuts-row"><kbd>Alt</kbd>+<kbd>D</kbd><span>destinations tab</span></div>
-                <div class="shortcuts-row"><kbd>Alt</kbd>+<kbd>M</kbd><span>plugins tab</span></div>
-                <div class="shortcuts-row"><kbd>Esc</kbd><span>close overlay / hide hub</span></div>
+                <span class="shortcuts-group-label">{t("app.shortcuts.hub")}</span>
+                <div class="shortcuts-row"><kbd>F1</kbd><span>{t("app.shortcuts.toggle")}</span></div>
+                <div class="shortcuts-row"><kbd>Alt</kbd>+<kbd>S</kbd><span>{t("app.shortcuts.settings")}</span></div>
+                <div class="shortcuts-row"><kbd>Alt</kbd>+<kbd>T</kbd><span>{t("app.shortcuts.tasks")}</span></div>
+                <div class="shortcuts-row"><kbd>Alt</kbd>+<kbd>H</kbd><span>{t("app.shortcuts.history")}</span></div>
+                <div class="shortcuts-row"><kbd>Alt</kbd>+<kbd>D</kbd><span>{t("app.shortcuts.destinations")}</span></div>
+                <div class="shortcuts-row"><kbd>Alt</kbd>+<kbd>M</kbd><span>{t("app.shortcuts.plugins")}</span></div>
+                <div class="shortcuts-row"><kbd>Ctrl</kbd>+<kbd>Q</kbd><span>{t("app.shortcuts.quit")}</span></div>
+                <div class="shortcuts-row"><kbd>Esc</kbd><span>{t("app.shortcuts.esc")}</span></div>
+                <div class="shortcuts-row"><kbd>Tab</kbd><span>{t("app.shortcuts.tab")}</span></div>
+                <div class="shortcuts-row"><kbd>↑</kbd>/<kbd>↓</kbd><span>{t("app.shortcuts.sidebar")}</span></div>
+                <div class="shortcuts-row"><kbd>←</kbd>/<kbd>→</kbd><span>{t("app.shortcuts.panes")}</span></div>
+                <div class="shortcuts-row"><kbd>Enter</kbd><span>{t("app.shortcuts.open-item")}</span></div>
               </div>
               <div class="shortcuts-group">
-                <span class="shortcuts-group-label">editor</span>
-                <div class="shortcuts-row"><kbd>1</kbd><span>arrow tool</span></div>
-                <div class="shortcuts-row"><kbd>2</kbd><span>rect tool</span></div>
-                <div class="shortcuts-row"><kbd>3</kbd><span>text tool</span></div>
-                <div class="shortcuts-row"><kbd>4</kbd><span>blur tool</span></div>
-                <div class="shortcuts-row"><kbd>Ctrl</kbd>+<kbd>Z</kbd><span>undo</span></div>
-                <div class="shortcuts-row"><kbd>Ctrl</kbd>+<kbd>Y</kbd><span>redo</span></div>
-                <div class="shortcuts-row"><kbd>Ctrl</kbd>+<kbd>V</kbd><span>paste image from clipboard</span></div>
-                <div class="shortcuts-row"><kbd>Ctrl</kbd>+<kbd>=</kbd>/<kbd>-</kbd><span>zoom in / out</span></div>
-                <div class="shortcuts-row"><kbd>Ctrl</kbd>+<kbd>0</kbd><span>zoom 100%</span></div>
-                <div class="shortcuts-row"><kbd>Ctrl</kbd>+wheel<span>zoom</span></div>
+                <span class="shortcuts-group-label">{t("app.shortcuts.editor")}</span>
+                <div class="shortcuts-row"><kbd>1</kbd><span>{t("app.shortcuts.arrow")}</span></div>
+                <div class="shortcuts-row"><kbd>2</kbd><span>{t("app.shortcuts.rect")}</span></div>
+                <div class="shortcuts-row"><kbd>3</kbd><span>{t("app.shortcuts.text")}</span></div>
+                <div class="shortcuts-row"><kbd>4</kbd><span>{t("app.shortcuts.blur")}</span></div>
+                <div class="shortcuts-row"><kbd>5</kbd>–<kbd>9</kbd><span>{t("app.shortcuts.more-tools")}</span></div>
+                <div class="shortcuts-row"><kbd>0</kbd><span>{t("app.shortcuts.stamp")}</span></div>
+                <div class="shortcuts-row"><kbd>M</kbd><span>{t("app.shortcuts.measure")}</span></div>
+                <div class="shortcuts-row"><kbd>Enter</kbd><span>{t("app.shortcuts.save")}</span></div>
+                <div class="shortcuts-row"><kbd>Ctrl</kbd>+<kbd>C</kbd>/<kbd>U</kbd><span>{t("app.shortcuts.copy-upload")}</span></div>
+                <div class="shortcuts-row"><kbd>Esc</kbd><span>{t("app.shortcuts.close-editor")}</span></div>
+                <div class="shortcuts-row"><kbd>Ctrl</kbd>+<kbd>Z</kbd><span>{t("app.shortcuts.undo")}</span></div>
+                <div class="shortcuts-row"><kbd>Ctrl</kbd>+<kbd>Y</kbd><span>{t("app.shortcuts.redo")}</span></div>
+                <div class="shortcuts-row"><kbd>Ctrl</kbd>+<kbd>V</kbd><span>{t("app.shortcuts.paste")}</span></div>
+                <div class="shortcuts-row"><kbd>Ctrl</kbd>+<kbd>=</kbd>/<kbd>-</kbd><span>{t("app.shortcuts.zoom-in-out")}</span></div>
+                <div class="shortcuts-row"><kbd>Ctrl</kbd>+<kbd>0</kbd><span>{t("app.shortcuts.zoom-100")}</span></div>
+                <div class="shortcuts-row"><kbd>F</kbd><span>{t("app.shortcuts.fit")}</span></div>
+                <div class="shortcuts-row"><kbd>Space</kbd>+drag<span>{t("app.shortcuts.pan")}</span></div>
+                <div class="shortcuts-row"><kbd>Ctrl</kbd>+wheel<span>{t("app.shortcuts.zoom")}</span></div>
               </div>
               <div class="shortcuts-group">
-                <span class="shortcuts-group-label">global (default — rebindable in tasks)</span>
-                <div class="shortcuts-row"><kbd>PrintScreen</kbd><span>region screenshot → save + clipboard</span></div>
-                <div class="shortcuts-row"><kbd>Ctrl+Shift+G</kbd><span>region GIF → save</span></div>
+                <span class="shortcuts-group-label">{t("app.shortcuts.global")}</span>
+                <div class="shortcuts-row"><kbd>PrintScreen</kbd><span>{t("app.shortcuts.region-shot")}</span></div>
+                <div class="shortcuts-row"><kbd>Ctrl+Shift+G</kbd><span>{t("app.shortcuts.region-gif")}</span></div>
               </div>
             </div>
             <div class="shortcuts-foot">
-              <span class="muted">press <kbd>F1</kbd> or <kbd>Esc</kbd> to close</span>
+              <span class="muted">
+                {tm("app.shortcuts.foot", { f1: <kbd>F1</kbd>, esc: <kbd>Esc</kbd> })}
+              </span>
             </div>
           </div>
         </div>
       </Show>
 
-      <main class="content">
+      <Show when={showStatusLog()}>
+        <div
+          class="shortcuts-overlay"
+          onClick={() => setShowStatusLog(false)}
+          role="dialog"
+          aria-modal="true"
+          aria-label={t("app.log.title")}
+        >
+          <div class="shortcuts-panel" ref={trapFocus} onClick={(e) => e.stopPropagation()}>
+            <div class="shortcuts-head">
+              <span class="shortcuts-title">{t("app.log.title")}</span>
+              <button
+                type="button"
+                class="icon-btn"
+                onClick={() => setShowStatusLog(false)}
+                aria-label={t("common.close")}
+              >
+                <X size={12} stroke-width={1.5} />
+              </button>
+            </div>
+            <div class="status-log">
+              <For
+                each={[...statusLog()].reverse()}
+                fallback={<span class="muted">{t("app.log.empty")}</span>}
+              >
+                {(entry) => (
+                  <div class="status-log-row" data-tone={entry.tone}>
+                    <span class="status-log-time">
+                      {new Date(entry.at).toLocaleTimeString()}
+                    </span>
+                    <span class="status-log-text">{entry.text}</span>
+                    <Show when={entry.path}>
+                      <button
+                        class="btn"
+                        data-variant="ghost"
+                        data-size="xs"
+                        onClick={() =>
+                          api
+                            .openInExplorer(entry.path!)
+                            .catch((e) => pushToast("err", String(e)))
+                        }
+                      >
+                        {t("common.show-in-folder")}
+                      </button>
+                    </Show>
+                    <Show when={entry.url}>
+                      <button
+                        class="btn"
+                        data-variant="ghost"
+                        data-size="xs"
+                        onClick={() => void openUrl(entry.url!)}
+                      >
+                        <ExternalLink size={11} stroke-width={1.5} />
+                        {t("common.open")}
+                      </button>
+                    </Show>
+                  </div>
+                )}
+              </For>
+            </div>
+          </div>
+        </div>
+      </Show>
+
+      <main class="content" id="main-content" tabIndex={-1}>
         {/* in normal flow at the top of the content area so it pushes the view
             down instead of overlaying the view title */}
         <Show when={trayMissing()}>
           <div class="update-banner">
             <span class="update-banner-glyph">▮</span>
             <div class="update-banner-text">
-              <span class="update-banner-title">no system tray detected</span>
+              <span class="update-banner-title">{t("app.tray-missing.title")}</span>
               <span class="update-banner-meta">
-                common on vanilla GNOME. capscr keeps running in the
-                background: global hotkeys still work, right-click the capscr
-                entry in Activities for capture actions, and launching capscr
-                again reopens this window. for a tray icon, install the
-                AppIndicator extension.
+                {tm("app.tray-missing.meta", {
+                  quit: (
+                    <>
+                      <kbd>Ctrl</kbd>+<kbd>Q</kbd>
+                    </>
+                  ),
+                })}
               </span>
             </div>
             <button
@@ -502,7 +777,7 @@ function Hub() {
                 void openUrl("https://extensions.gnome.org/extension/615/appindicator-support/")
               }
             >
-              get extension
+              {t("app.tray-missing.get")}
             </button>
             <button
               type="button"
@@ -514,24 +789,106 @@ function Hub() {
                 void api.dismissTrayHint();
               }}
             >
-              don't show again
+              {t("app.tray-missing.never")}
             </button>
           </div>
         </Show>
 
+        <For each={crashes()}>
+          {(item) => (
+            <div class="update-banner">
+              <span class="update-banner-glyph">▮</span>
+              <div class="update-banner-text">
+                <span class="update-banner-title">{t("app.crash.title")}</span>
+                <span class="update-banner-meta">
+                  {t("app.crash.meta", {
+                    when: new Date(item.crashed_at * 1000).toLocaleString(),
+                    summary: item.summary,
+                  })}
+                </span>
+              </div>
+              <button
+                type="button"
+                class="btn"
+                data-size="xs"
+                onClick={() => void copyCrashReport(item)}
+              >
+                {t("common.copy")}
+              </button>
+              <button
+                type="button"
+                class="btn"
+                data-variant="ghost"
+                data-size="xs"
+                onClick={() =>
+                  api.revealCrashReport(item.id).catch((e) =>
+                    pushStatus({ tone: "err", text: t("app.status.crash-error", { error: String(e) }) }),
+                  )
+                }
+              >
+                {t("common.open")}
+              </button>
+              <button
+                type="button"
+                class="btn"
+                data-variant="ghost"
+                data-size="xs"
+                onClick={() => void dismissCrashReport(item)}
+              >
+                {t("common.dismiss")}
+              </button>
+            </div>
+          )}
+        </For>
+
+        <For each={recovered()}>
+          {(item) => (
+            <div class="update-banner">
+              <span class="update-banner-glyph">▮</span>
+              <div class="update-banner-text">
+                <span class="update-banner-title">{t("app.recovered.title")}</span>
+                <span class="update-banner-meta">
+                  {t("app.recovered.meta", {
+                    frames: item.frames,
+                    seconds: (item.duration_ms / 1000).toFixed(1),
+                    when: new Date(item.kept_at * 1000).toLocaleString(),
+                  })}
+                </span>
+              </div>
+              <button
+                type="button"
+                class="btn"
+                data-size="xs"
+                onClick={() => void settleRecovered(item, true)}
+              >
+                {t("app.recovered.restore")}
+              </button>
+              <button
+                type="button"
+                class="btn"
+                data-variant="ghost"
+                data-size="xs"
+                onClick={() => void settleRecovered(item, false)}
+              >
+                {t("app.recovered.discard")}
+              </button>
+            </div>
+          )}
+        </For>
+
         <Show when={updateInfo() && !updateDismissed()}>
           <div class="update-banner">
             <span class="update-banner-glyph">▮</span>
             <div class="update-banner-text">
               <span class="update-banner-title">
-                update available · v{updateInfo()!.version}
+                {t("app.update.title", { version: updateInfo()!.version })}
               </span>
               <span class="update-banner-meta">
-                you're on v{updateInfo()!.current_version}
+                {t("app.update.current", { version: updateInfo()!.current_version })}
               </span>
               <Show when={updateInfo()!.notes}>
                 <details class="update-notes">
-                  <summary>what's new</summary>
+                  <summary>{t("app.update.notes")}</summary>
                   <pre class="update-notes-body">{updateInfo()!.notes}</pre>
                 </details>
               </Show>
@@ -548,18 +905,18 @@ function Hub() {
                   }
                   title={
                     updateInfo()!.install_kind === "deb"
-                      ? "then: sudo apt install ./the-downloaded.deb"
+                      ? t("app.update.deb-hint")
                       : updateInfo()!.install_kind === "rpm"
-                        ? "then: sudo dnf install ./the-downloaded.rpm"
+                        ? t("app.update.rpm-hint")
                         : undefined
                   }
                 >
                   <Download size={11} stroke-width={1.5} />
                   {updateInfo()!.install_kind === "deb"
-                    ? "download .deb"
+                    ? t("app.update.deb")
                     : updateInfo()!.install_kind === "rpm"
-                      ? "download .rpm"
-                      : "get release"}
+                      ? t("app.update.rpm")
+                      : t("app.update.release")}
                 </button>
               }
             >
@@ -571,7 +928,7 @@ function Hub() {
                 disabled={updating()}
               >
                 <Download size={11} stroke-width={1.5} />
-                {updating() ? "installing..." : "install + restart"}
+                {updating() ? t("app.update.installing") : t("app.update.install")}
               </button>
             </Show>
             <button
@@ -581,7 +938,7 @@ function Hub() {
               data-size="xs"
               onClick={() => setUpdateDismissed(true)}
             >
-              later
+              {t("app.update.later")}
             </button>
           </div>
         </Show>
@@ -642,9 +999,41 @@ function Hub() {
           <span class="seg-sep">│</span>
           <span class="seg is-dirty">
             <span class="seg-k">edit</span>
-            <span class="seg-v">unsaved</span>
+            <span class="seg-v">{t("app.bar.unsaved")}</span>
           </span>
         </Show>
+        <Show when={encoding().queued + encoding().running > 0}>
+          <span class="seg-sep">│</span>
+          <button
+            type="button"
+            class="seg seg-btn is-busy"
+            disabled={encoding().queued === 0}
+            onClick={async () => {
+              try {
+                const dropped = await api.cancelPendingEncodes();
+                pushStatus({
+                  tone: "ok",
+                  text: t(dropped === 1 ? "app.status.cancelled.one" : "app.status.cancelled.other", {
+                    count: dropped,
+                  }),
+                });
+              } catch (e) {
+                pushToast("err", t("app.toast.cancel-failed", { error: String(e) }));
+              }
+            }}
+            title={
+              encoding().queued > 0
+                ? t("app.bar.enc.cancel")
+                : t("app.bar.enc")
+            }
+          >
+            <span class="seg-k">enc</span>
+            <span class="seg-v">
+              {encoding().queued + encoding().running} · ~
+              {(encoding().pending_bytes / 1024 / 1024).toFixed(1)}M
+            </span>
+          </button>
+        </Show>
         <Show when={hotkeyDiag()?.disabled_globally}>
           <span class="seg-sep">│</span>
           <button
@@ -655,21 +1044,80 @@ function Hub() {
                 await api.setHotkeysDisabled(false);
                 refetchHotkeyDiag();
               } catch (e) {
-                pushToast("err", `couldn't re-enable hotkeys: ${e}`);
+                pushToast("err", t("app.toast.keys-failed", { error: String(e) }));
               }
             }}
-            title="hotkeys are off — click to re-enable"
+            title={t("app.bar.keys")}
           >
             <span class="seg-k">keys</span>
-            <span class="seg-v">off</span>
+            <span class="seg-v">{t("common.off")}</span>
+          </button>
+        </Show>
+        <Show when={dnd().active}>
+          <span class="seg-sep">│</span>
+          <button
+            type="button"
+            class="seg seg-btn is-err"
+            onClick={async () => {
+              try {
+                await api.setDoNotDisturb(false);
+              } catch (e) {
+                pushToast("err", t("app.toast.dnd-failed", { error: String(e) }));
+              }
+            }}
+            title={t("app.bar.dnd")}
+          >
+            <span class="seg-k">dnd</span>
+            <span class="seg-v">
+              {dnd().until_ms
+                ? new Date(dnd().until_ms!).toLocaleTimeString([], { hour: "2-digit", minute: "2-digit" })
+                : t("common.on")}
+            </span>
+          </button>
+        </Show>
+        <Show when={gameMode().app}>
+          <span class="seg-sep">│</span>
+          <span
+            class="seg"
+            title={
+              gameMode().action === "suspend-hotkeys"
+                ? t("app.bar.game.suspend")
+                : t("app.bar.game.wgc")
+            }
+          >
+            <span class="seg-k">game</span>
+            <span class="seg-v">{gameMode().app}</span>
+          </span>
+        </Show>
+        <Show when={statusMsg() || recording()}>
+          <span class="seg-sep">│</span>
+          <button
+            type="button"
+            class="seg seg-btn seg-msg"
+            data-tone={statusMsg()?.tone ?? "rec"}
+            onClick={() => setShowStatusLog(true)}
+            title={t("app.log.title")}
+          >
+            <span class="seg-k">msg</span>
+            <span class="seg-v">{statusMsg()?.text ?? t("app.bar.recording", { elapsed: recordingElapsed() })}</span>
           </button>
         </Show>
         <span class="grow" />
+        <button
+          type="button"
+          class="seg seg-btn"
+          onClick={() => setShowStatusLog(true)}
+          title={t("app.log.title")}
+        >
+          <span class="seg-k">log</span>
+          <span class="seg-v">{statusLog().length.toString().padStart(2, "0")}</span>
+        </button>
+        <span class="seg-sep">│</span>
         <button
           type="button"
           class="seg seg-btn"
           onClick={() => setShowShortcuts(true)}
-          title="keyboard shortcuts"
+          title={t("app.shortcuts.title")}
         >
           <span class="seg-k">help</span>
           <span class="seg-v">F1</span>
@@ -681,29 +1129,33 @@ function Hub() {
         </span>
       </footer>
 
+      <Show when={review()} keyed>
+        {(info) => <RecordingReview info={info} onClose={() => setReview(null)} />}
+      </Show>
+
       <Show when={dragOver()}>
         <div class="drop-overlay">
           <div class="drop-overlay-inner">
             <div class="drop-overlay-glyph">+</div>
-            <div class="drop-overlay-title">drop to upload</div>
+            <div class="drop-overlay-title">{t("app.drop.title")}</div>
             <div class="drop-overlay-lede">
-              destination: {config()?.upload.destination ?? "..."}
+              {t("app.drop.destination", { destination: config()?.upload.destination ?? "..." })}
             </div>
           </div>
         </div>
       </Show>
 
       <Show when={toasts().length > 0 || uploads().length > 0}>
-        <div class="toasts" role="region" aria-label="notifications">
+        <div class="toasts" role="region" aria-label={t("app.toasts")} aria-live="polite">
           <For each={uploads()}>
             {(u) => (
               <div class="toast upload-card">
                 <div class="upload-card-head">
-                  <span class="toast-kind">uploaded</span>
+                  <span class="toast-kind">{t("app.upload.kind")}</span>
                   <button
                     type="button"
                     class="toast-close"
-                    aria-label="dismiss"
+                    aria-label={t("common.dismiss")}
                     onClick={() =>
                       setUploads((cur) => cur.filter((x) => x.id !== u.id))
                     }
@@ -716,10 +1168,10 @@ function Hub() {
                   <button
                     class="btn"
                     data-size="xs"
-                    onClick={() => writeText(u.url).catch(() => pushToast("err", "clipboard busy — try again"))}
+                    onClick={() => writeText(u.url).catch(() => pushToast("err", t("common.clipboard-busy")))}
                   >
                     <Copy size={11} stroke-width={1.5} />
-                    copy
+                    {t("common.copy")}
                   </button>
                   <button
                     class="btn"
@@ -728,7 +1180,7 @@ function Hub() {
                     onClick={() => void openUrl(u.url)}
                   >
                     <ExternalLink size={11} stroke-width={1.5} />
-                    open
+                    {t("common.open")}
                   </button>
                   <Show when={u.deleteUrl}>
                     <button
@@ -736,10 +1188,10 @@ function Hub() {
                       data-variant="ghost"
                       data-size="xs"
                       title={u.deleteUrl!}
-                      onClick={() => writeText(u.deleteUrl!).catch(() => pushToast("err", "clipboard busy — try again"))}
+                      onClick={() => writeText(u.deleteUrl!).catch(() => pushToast("err", t("common.clipboard-busy")))}
                     >
                       <Trash2 size={11} stroke-width={1.5} />
-                      copy del url
+                      {t("app.upload.copy-delete")}
                     </button>
                   </Show>
                 </div>
@@ -747,22 +1199,22 @@ function Hub() {
             )}
           </For>
           <For each={toasts()}>
-            {(t) => (
+            {(toast) => (
               <output
                 class="toast"
-                data-kind={t.kind}
+                data-kind={toast.kind}
                 role="status"
                 aria-live="polite"
                 aria-atomic="true"
               >
-                <span class="toast-kind">{t.kind}</span>
-                <span class="toast-msg">{t.msg}</span>
+                <span class="toast-kind">{toast.kind}</span>
+                <span class="toast-msg">{toast.msg}</span>
                 <button
                   type="button"
                   class="toast-close"
-                  aria-label="dismiss"
+                  aria-label={t("common.dismiss")}
                   onClick={() =>
-                    setToasts((cur) => cur.filter((x) => x.id !== t.id))
+                    setToasts((cur) => cur.filter((x) => x.id !== toast.id))
                   }
                 >
                   ×
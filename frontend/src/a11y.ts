@@ -0,0 +1,74 @@
+import { onCleanup } from "solid-js";
+
+const FOCUSABLE =
+  'button:not([disabled]), [href], input:not([disabled]), select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex="-1"])';
+
+// ref callback for a modal panel: moves focus into it on open, keeps Tab
+// cycling inside it, and hands focus back to whatever opened it on close.
+// the opener is usually a button that is still mounted, so focus lands where
+// the keyboard user left off instead of on <body>
+export function trapFocus(panel: HTMLElement) {
+  const opener = document.activeElement as HTMLElement | null;
+  const focusables = () =>
+    [...panel.querySelectorAll<HTMLElement>(FOCUSABLE)].filter((el) => el.offsetParent !== null);
+
+  // the panel isn't attached yet when the ref runs
+  queueMicrotask(() => (focusables()[0] ?? panel).focus());
+
+  const onKey = (ev: KeyboardEvent) => {
+    if (ev.key !== "Tab") return;
+    const list = focusables();
+    if (list.length === 0) return;
+    const first = list[0];
+    const last = list[list.length - 1];
+    if (ev.shiftKey && document.activeElement === first) {
+      ev.preventDefault();
+      last.focus();
+    } else if (!ev.shiftKey && document.activeElement === last) {
+      ev.preventDefault();
+      first.focus();
+    }
+  };
+  panel.addEventListener("keydown", onKey);
+  onCleanup(() => {
+    panel.removeEventListener("keydown", onKey);
+    if (opener?.isConnected) opener.focus();
+  });
+}
+
+// arrow / Home / End movement between the buttons of a nav or tablist.
+// `activate` clicks the newly focused button too, which suits tabs whose
+// switch is cheap; the hub sidebar leaves it off since changing tabs can
+// raise the discard-edits prompt
+export function arrowNav(
+  ev: KeyboardEvent,
+  orientation: "horizontal" | "vertical",
+  activate = false,
+) {
+  const keys =
+    orientation === "vertical" ? ["ArrowUp", "ArrowDown"] : ["ArrowLeft", "ArrowRight"];
+  if (![...keys, "Home", "End"].includes(ev.key)) return;
+  const items = [
+    ...(ev.currentTarget as HTMLElement).querySelectorAll<HTMLElement>("button:not([disabled])"),
+  ];
+  const at = items.indexOf(document.activeElement as HTMLElement);
+  if (at < 0 || items.length === 0) return;
+  ev.preventDefault();
+  const next =
+    ev.key === "Home"
+      ? 0
+      : ev.key === "End"
+        ? items.length - 1
+        : (at + (ev.key === keys[1] ? 1 : -1) + items.length) % items.length;
+  items[next].focus();
+  if (activate) items[next].click();
+}
+
+// Enter / Space on a non-button element that acts like one
+export function activateOnKey(ev: KeyboardEvent, run: () => void) {
+  if (ev.target !== ev.currentTarget) return;
+  if (ev.key === "Enter" || ev.key === " ") {
+    ev.preventDefault();
+    run();
+  }
+}
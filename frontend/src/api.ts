@@ -1,6 +1,7 @@
 import { invoke } from "@tauri-apps/api/core";
 
 export type CaptureMode = "region" | "window" | "fullscreen" | "active-monitor";
+export type CaptureKind = "region" | "window" | "full-screen" | "gif";
 export type PostAction =
   | "clipboard"
   | "save-file"
@@ -14,7 +15,9 @@ export interface HdrConfig {
   brightness_nits: number;
   user_brightness_scale: number;
   use_p99_max_cll: boolean;
+  tonemap_operator: "bt2390" | "reinhard-extended" | "aces-filmic" | "hable" | "exposure";
   output_format: "pq" | "hlg";
+  container: "png" | "avif" | "jxr";
 }
 
 export interface OutputConfig {
@@ -23,6 +26,70 @@ export interface OutputConfig {
   quality: number;
   filename_template: string;
   preserve_hdr: boolean;
+  editor_projects: boolean;
+  decoration: DecorationConfig;
+  resize: ResizeConfig;
+  metadata: "keep" | "strip" | "embed";
+  // tags and notes also go into png text chunks
+  tags_in_metadata?: boolean;
+  // null: capscr/ inside the system temp folder
+  scratch_directory?: string | null;
+  scratch_cap_mb: number;
+  // window captures of a listed process follow its profile
+  app_profiles?: AppProfile[];
+  archive_originals?: boolean;
+  // null: originals/ inside the output directory
+  archive_directory?: string | null;
+  retention?: RetentionPolicy;
+}
+
+// 0 is no limit; oldest captures go first
+export interface RetentionPolicy {
+  max_files: number;
+  max_size_mb: number;
+  // null deletes; a relative path is a folder inside the one kept in check
+  move_to?: string | null;
+}
+
+export interface AppProfile {
+  app: string;
+  // null keeps the global format / the task's action
+  format: OutputConfig["format"] | null;
+  post_action: CaptureTask["post_action"] | null;
+  // under the output directory; "" saves straight into it
+  subfolder: string;
+  // null follows the output folder's policy
+  retention?: RetentionPolicy | null;
+}
+
+export interface ResizeConfig {
+  mode: "off" | "max-dimension" | "percent";
+  max_dimension: number;
+  percent: number;
+}
+
+// ocr pass before uploading that asks first when it spots likely secrets
+export interface SecretScanConfig {
+  enabled: boolean;
+  emails: boolean;
+  api_keys: boolean;
+  card_numbers: boolean;
+}
+
+export interface RecompressConfig {
+  // re-encode an upload that is over the destination's limit instead of failing
+  enabled: boolean;
+  // webp tries lossless first, then falls through to the jpeg steps
+  format: "jpeg" | "webp";
+  min_quality: number;
+}
+
+export interface DecorationConfig {
+  border_width: number;
+  border_color: string;
+  corner_radius: number;
+  corner_background: string;
+  caption: string;
 }
 
 export interface CaptureConfig {
@@ -34,6 +101,44 @@ export interface CaptureConfig {
   video_quality: "High" | "Balanced" | "Compact";
   hdr: HdrConfig;
   record_audio: boolean;
+  window_frame: "visible" | "with-shadow" | "client-only";
+  clean_window_corners: boolean;
+  // windows: PrintWindow render, so overlapping windows don't bleed in
+  capture_covered_windows: boolean;
+  // windows: "wgc" routes every monitor and window through Windows.Graphics.Capture
+  backend: "auto" | "wgc";
+  wgc_border: boolean;
+  // process names kept out of captures and recordings
+  exclusion: { apps: string[]; mode: "blackout" | "skip" };
+  // what changes while a full-screen app has focus; apps override action
+  game_mode?: {
+    enabled: boolean;
+    action: GameModeAction;
+    apps: { app: string; action: GameModeAction }[];
+  };
+  // region recordings ease in on the cursor while it moves
+  zoom: { enabled: boolean; zoom_percent: number; smoothing_ms: number };
+  // stop → trim preview instead of saving the whole recording
+  review_recordings: boolean;
+  // region-stream tasks push here; url_encrypted is the vault-wrapped url
+  stream: { url: string; url_encrypted: string; bitrate_kbps: number };
+  detect_ui_elements: boolean;
+  // the selection overlay spans only the monitor under the pointer
+  selector_cursor_monitor?: boolean;
+  hide_hub_during_capture: boolean;
+  // a capture identical to the previous one within a minute
+  duplicates?: "off" | "warn" | "skip";
+}
+
+export interface ProxyConfig {
+  // "system" follows the os / HTTP(S)_PROXY; ftp and sftp never use a proxy
+  mode: "system" | "direct" | "manual";
+  // http://, https://, socks5:// or socks5h:// with host and port
+  url: string;
+  username: string;
+  /** plaintext until the next save moves it into password_encrypted */
+  password: string;
+  password_encrypted: string;
 }
 
 export interface FtpConfig {
@@ -82,33 +187,160 @@ export interface S3Config {
   public_url_template: string;
 }
 
+export interface WebdavConfig {
+  server_url: string;
+  username: string;
+  password: string;
+  password_encrypted: string;
+  path_template: string;
+  share_link: boolean;
+  public_url_template: string;
+}
+
+export interface DropboxConfig {
+  app_key: string;
+  refresh_token_encrypted: string;
+  account_name: string;
+  folder: string;
+}
+
+export interface EmailConfig {
+  to: string;
+  subject: string;
+}
+
+export type WebhookKind = "discord" | "slack" | "teams";
+
+export interface WebhookConfig {
+  kind: WebhookKind;
+  url: string;
+  url_encrypted: string;
+  message: string;
+  // slack and teams post a link, so the capture is uploaded here first
+  link_host: "Imgur" | "Custom" | "Ftp" | "Sftp" | "S3" | "Webdav" | "Dropbox";
+}
+
+export interface ImgurAccountConfig {
+  client_secret: string;
+  client_secret_encrypted: string;
+  refresh_token_encrypted: string;
+  username: string;
+  album_id: string;
+  title: string;
+  description: string;
+  album_privacy: "hidden" | "public" | "secret";
+}
+
+export interface ImgurAlbum {
+  id: string;
+  title: string;
+  privacy: string;
+  images_count: number;
+}
+
+export interface CustomHeader {
+  name: string;
+  value: string;
+  secret: boolean;
+  value_encrypted: string;
+}
+
+export interface CustomFormField {
+  name: string;
+  value: string;
+  secret: boolean;
+  value_encrypted: string;
+}
+
+export interface CustomUploaderPreset {
+  id: string;
+  name: string;
+  url: string;
+  method: "post" | "put";
+  form_name: string;
+  response_path: string;
+  response_regex: string;
+  headers: CustomHeader[];
+  form_fields: CustomFormField[];
+  // the host's upload limit; 0 = capscr's own 32 MB cap
+  max_file_mb: number;
+}
+
+// a parsed ShareX .sxcu; the preset has no id until it's added
+export interface SxcuImport {
+  preset: CustomUploaderPreset;
+  warnings: string[];
+}
+
 export interface UploadConfig {
-  destination: "Imgur" | "Custom" | "Ftp" | "Sftp" | "S3";
+  destination:
+    | "Imgur"
+    | "Custom"
+    | "Ftp"
+    | "Sftp"
+    | "S3"
+    | "Webdav"
+    | "Dropbox"
+    | "Email"
+    | "Webhook";
   copy_url_to_clipboard: boolean;
-  custom_url: string;
-  custom_form_name: string;
-  custom_response_path: string;
+  link_format: "url" | "markdown" | "html" | "bbcode";
+  custom_presets: CustomUploaderPreset[];
+  // preset used when a task or upload doesn't name one
+  active_custom_preset: string;
   imgur_client_id: string;
   ftp: FtpConfig;
   sftp: SftpConfig;
   s3: S3Config;
+  webdav: WebdavConfig;
+  dropbox: DropboxConfig;
+  imgur: ImgurAccountConfig;
+  email: EmailConfig;
+  webhook: WebhookConfig;
+  resize: ResizeConfig;
+  recompress: RecompressConfig;
+  secret_scan: SecretScanConfig;
+  // seal uploads with aes-gcm; the key goes in the link's #fragment
+  encrypt: boolean;
+  // what to do when the same bytes already went to the same destination
+  dedupe: "off" | "ask" | "reuse";
+  dedupe_days: number;
+  proxy: ProxyConfig;
 }
 
 export interface UiConfig {
-  theme: "Light" | "Dark";
+  theme: "Light" | "Dark" | "System";
+  accent: "mono" | "amber" | "phosphor" | "ice" | "rose" | "custom";
+  accent_color: string;
+  scale_percent: number;
+  compact: boolean;
+  language: "system" | "en" | "de" | "fr" | "ja";
   show_notifications: boolean;
   copy_to_clipboard: boolean;
   close_behavior: "minimize-to-tray" | "minimize-to-taskbar" | "exit";
   auto_start: boolean;
+  context_menu: boolean;
+  start_minimized: boolean;
   check_updates_on_launch: boolean;
   save_clipboard_to_history: boolean;
+  editor_recent_colors: string[];
 }
 
 export interface CaptureTask {
   id: string;
   name: string;
   hotkey: string;
-  capture_mode: "region" | "region-last" | "window" | "fullscreen" | "active-monitor" | "region-gif" | "region-mp4";
+  capture_mode:
+    | "region"
+    | "region-last"
+    | "window"
+    | "active-window"
+    | "target-window"
+    | "fullscreen"
+    | "active-monitor"
+    | "region-gif"
+    | "region-mp4"
+    | "region-stream";
   post_action:
     | "clipboard"
     | "save-file"
@@ -118,28 +350,71 @@ export interface CaptureTask {
     | "prompt"
     | "do-nothing"
     | "copy-text";
-  target_destination?: "imgur" | "custom" | "ftp" | "sftp" | "s3" | null;
+  target_destination?:
+    | "imgur"
+    | "custom"
+    | "ftp"
+    | "sftp"
+    | "s3"
+    | "webdav"
+    | "dropbox"
+    | "email"
+    | "webhook"
+    | null;
+  // custom uploader preset id; null uses the active preset
+  custom_preset?: string | null;
   // per-task pre-capture delay in ms; null falls back to the global delay
   delay_ms?: number | null;
+  // regex patterns for "target-window" tasks; empty matches anything
+  target_window?: { title: string; process: string } | null;
+  // recording modes: frame the focused window instead of a fixed region
+  follow_active_window?: boolean;
+  // recording modes: fixed frame size in physical px; null drags a region
+  recording_size?: { width: number; height: number } | null;
+  // gif/mp4: record only while the hotkey is held; the release saves
+  hold_to_record?: boolean;
+  // tasks can share a hotkey when each wants a different gesture
+  gesture?: "press" | "double-press" | "long-press";
 }
 
 export interface AppConfig {
+  // schema version; the backend migrates older files on load
+  version: number;
   output: OutputConfig;
   capture: CaptureConfig;
   hotkeys: {
     screenshot: string;
     record_gif: string;
+    // ends whichever recording is running; empty leaves it unbound
+    stop_recording: string;
+    // toggles do not disturb; empty leaves it to the tray
+    do_not_disturb?: string;
+    // how long the hotkey turns it on for; 0 until toggled off
+    do_not_disturb_minutes?: number;
     disabled_globally: boolean;
     // linux evdev opt-in; absent means unresolved (backend migrates it)
     advanced_input?: boolean | null;
   };
   ui: UiConfig;
   post_capture: {
-    action: PostAction;
+    // captures started without a task: the tray menu and the jump list
+    actions: Record<CaptureKind, CaptureTask["post_action"]>;
     open_file_after_save: boolean;
     play_sound: boolean;
   };
   upload: UploadConfig;
+  auto_capture: {
+    interval_secs: number;
+    target: "active-monitor" | "all-monitors";
+    directory: string;
+    max_count: number;
+    max_disk_mb: number;
+  };
+  watch_folder: {
+    enabled: boolean;
+    directory: string;
+    action: "upload" | "clipboard" | "save-file" | "save-and-clipboard";
+  };
   capture_tasks: CaptureTask[];
 }
 
@@ -151,6 +426,127 @@ export interface HistoryEntry {
   is_gif: boolean;
   is_mp4: boolean;
   has_hdr: boolean;
+  tags: string[];
+  note: string;
+}
+
+export interface CaptureNotes {
+  tags: string[];
+  note: string;
+}
+
+// one row of a batch upload, streamed as capscr://batch-upload-progress
+export interface BatchUploadRow {
+  index: number;
+  path: string;
+  state: "uploading" | "done" | "failed";
+  url: string | null;
+  error: string | null;
+}
+
+// the compare tool's pixel diff; heatmap is a png in the app cache dir
+export interface CaptureComparison {
+  heatmap: string;
+  width: number;
+  height: number;
+  changed: number;
+  total: number;
+}
+
+// one finished file of a folder conversion, streamed as
+// capscr://batch-convert-progress
+export interface BatchConvertProgress {
+  done: number;
+  total: number;
+  source: string;
+  output: string | null;
+  error: string | null;
+}
+
+// captures waiting on or being written by the encode pool
+// (capscr://encoding-progress)
+export interface EncodingProgress {
+  queued: number;
+  running: number;
+  pending_bytes: number;
+}
+
+export type GameModeAction = "none" | "suspend-hotkeys" | "low-overhead";
+
+// the full-screen app game mode is acting on (capscr://game-mode)
+export interface GameModeStatus {
+  app: string | null;
+  action: GameModeAction;
+}
+
+// no task hotkeys, notifications or sounds (capscr://dnd-changed)
+export interface DndStatus {
+  active: boolean;
+  // unix ms it turns itself off at; null while on until turned off
+  until_ms: number | null;
+}
+
+// a stopped recording waiting on the trim preview (capture.review_recordings)
+export interface RecordingReviewInfo {
+  format: "gif" | "mp4";
+  // mp4: the staged file, playable through the asset protocol
+  video_path: string | null;
+  // gif: capture time of each frame, ms from the start
+  frame_times_ms: number[];
+}
+
+// a gif recording kept from a session that ended before it was saved
+export interface RecoveredRecording {
+  id: string;
+  frames: number;
+  duration_ms: number;
+  // unix seconds
+  kept_at: number;
+}
+
+// a panic report left by an earlier session
+export interface CrashReport {
+  id: string;
+  summary: string;
+  // unix seconds
+  crashed_at: number;
+}
+
+// how a finished recording's capture loop kept up (capscr://recording-stats)
+export interface RecordingStats {
+  target_fps: number;
+  achieved_fps: number;
+  // frames that made it into the file; duplicates are folded away
+  frames: number;
+  // slots at the target rate that went by without a grab
+  dropped: number;
+  failed: number;
+  duration_ms: number;
+}
+
+export interface OpenWindow {
+  id: number;
+  title: string;
+  app_name: string;
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+}
+
+/** where a picker row wants a live window preview, in window pixels */
+export interface ThumbSlot {
+  id: number;
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+}
+
+export interface FormatSizeEstimate {
+  format: OutputConfig["format"];
+  extension: string;
+  bytes: number;
 }
 
 export interface InstalledPlugin {
@@ -254,14 +650,49 @@ export const api = {
     invoke<void>("take_screenshot", { mode, post }),
   listCaptures: () => invoke<HistoryEntry[]>("list_captures"),
   historyThumbnail: (path: string) => invoke<string>("history_thumbnail", { path }),
+  compareCaptures: (a: string, b: string, tolerance: number) =>
+    invoke<CaptureComparison>("compare_captures", { a, b, tolerance }),
+  // joins them in the order given; resolves to the saved image
+  stitchCaptures: (paths: string[]) => invoke<string>("stitch_captures", { paths }),
+  estimateCaptureSizes: (path: string) =>
+    invoke<FormatSizeEstimate[]>("estimate_capture_sizes", { path }),
+  listOpenWindows: () => invoke<OpenWindow[]>("list_open_windows"),
+  windowThumbnail: (id: number) => invoke<string>("window_thumbnail", { id }),
+  setLiveWindowThumbnails: (slots: ThumbSlot[]) =>
+    invoke<boolean>("set_live_window_thumbnails", { slots }),
   deleteCapture: (path: string) => invoke<void>("delete_capture", { path }),
+  getCaptureTags: (path: string) => invoke<CaptureNotes>("get_capture_tags", { path }),
+  setCaptureTags: (path: string, tags: string[], note: string) =>
+    invoke<CaptureNotes>("set_capture_tags", { path, tags, note }),
   copyCaptureToClipboard: (path: string) =>
     invoke<void>("copy_capture_to_clipboard", { path }),
-  reuploadCapture: (path: string) =>
-    invoke<{ url: string; delete_url: string | null }>("reupload_capture", { path }),
+  reuploadCapture: (path: string, preset?: string) =>
+    invoke<{ url: string; delete_url: string | null }>("reupload_capture", {
+      path,
+      preset: preset ?? null,
+    }),
+  batchUpload: (paths: string[], preset?: string) =>
+    invoke<BatchUploadRow[]>("batch_upload", { paths, preset: preset ?? null }),
+  copyBatchLinks: (urls: string[], format: "url" | "markdown") =>
+    invoke<void>("copy_batch_links", { urls, format }),
   openInExplorer: (path: string) => invoke<void>("open_in_explorer", { path }),
   trimMp4: (path: string, startSecs: number, endSecs: number, fast: boolean) =>
     invoke<string>("trim_mp4", { path, startSecs, endSecs, fast }),
+  recordingReview: () => invoke<RecordingReviewInfo | null>("recording_review"),
+  recordingReviewFrame: (index: number) => invoke<string>("recording_review_frame", { index }),
+  // null in/out points keep the whole recording
+  finishRecordingReview: (startMs: number | null, endMs: number | null) =>
+    invoke<string>("finish_recording_review", { startMs, endMs }),
+  discardRecordingReview: () => invoke<void>("discard_recording_review"),
+  listRecoveredRecordings: () => invoke<RecoveredRecording[]>("list_recovered_recordings"),
+  restoreRecoveredRecording: (id: string) =>
+    invoke<string>("restore_recovered_recording", { id }),
+  discardRecoveredRecording: (id: string) =>
+    invoke<void>("discard_recovered_recording", { id }),
+  listCrashReports: () => invoke<CrashReport[]>("list_crash_reports"),
+  readCrashReport: (id: string) => invoke<string>("read_crash_report", { id }),
+  revealCrashReport: (id: string) => invoke<void>("reveal_crash_report", { id }),
+  dismissCrashReport: (id: string) => invoke<void>("dismiss_crash_report", { id }),
   exitApp: () => invoke<void>("exit_app"),
 
   listInstalledPlugins: () => invoke<InstalledPlugin[]>("list_installed_plugins"),
@@ -269,9 +700,26 @@ export const api = {
   openPluginsFolder: () => invoke<void>("open_plugins_folder"),
   setAutostart: (enabled: boolean) => invoke<void>("set_autostart", { enabled }),
   getAutostart: () => invoke<boolean>("get_autostart"),
+  clearUploadIndex: () => invoke<void>("clear_upload_index"),
   uploadFile: (path: string) =>
     invoke<{ url: string; delete_url: string | null }>("upload_file", { path }),
   openEditor: (path: string) => invoke<void>("open_editor", { path }),
+  // null when the file dialog was cancelled
+  openImageInEditor: () => invoke<string | null>("open_image_in_editor"),
+  // picks a folder; null when the picker was cancelled, else how many were written
+  batchConvertFolder: () => invoke<number | null>("batch_convert_folder"),
+  encodingProgress: () => invoke<EncodingProgress>("encoding_progress"),
+  // drops the saves that haven't started; resolves to how many that was
+  cancelPendingEncodes: () => invoke<number>("cancel_pending_encodes"),
+  // decrypts into history and opens it; resolves to the saved path
+  openEncryptedLink: (link: string) => invoke<string>("open_encrypted_link", { link }),
+  loadEditorProject: (path: string) => invoke<string | null>("load_editor_project", { path }),
+  // null removes the project
+  saveEditorProject: (targetPath: string, project: string | null) =>
+    invoke<void>("save_editor_project", { targetPath, project }),
+  // opens the native save dialog; null when it was cancelled
+  saveEditedImageAs: (bytes: Uint8Array) =>
+    invoke<string | null>("save_edited_image_as", { bytes: Array.from(bytes) }),
   checkForUpdates: () => invoke<UpdateInfo | null>("check_for_updates"),
   installUpdate: () => invoke<void>("install_update"),
   isHdrCapture: (path: string) => invoke<boolean>("is_hdr_capture", { path }),
@@ -292,15 +740,45 @@ export const api = {
   pinMoveBy: (label: string, dx: number, dy: number) =>
     invoke<void>("pin_move_by", { label, dx, dy }),
   dismissTrayHint: () => invoke<void>("dismiss_tray_hint"),
+  rememberEditorColor: (color: string) =>
+    invoke<string[]>("remember_editor_color", { color }),
   setHotkeysDisabled: (disabled: boolean) =>
     invoke<void>("set_hotkeys_disabled", { disabled }),
+  doNotDisturbStatus: () => invoke<DndStatus>("do_not_disturb_status"),
+  setDoNotDisturb: (enabled: boolean, minutes?: number) =>
+    invoke<void>("set_do_not_disturb", { enabled, minutes: minutes ?? null }),
+  gameModeStatus: () => invoke<GameModeStatus>("game_mode_status"),
+  autoCaptureRunning: () => invoke<boolean>("auto_capture_running"),
+  setAutoCaptureRunning: (running: boolean) =>
+    invoke<void>("set_auto_capture_running", { running }),
+  assembleTimelapse: (format: "gif" | "mp4", fps: number, maxWidth: number, dir?: string) =>
+    invoke<string>("assemble_timelapse", { dir: dir ?? null, format, fps, maxWidth }),
   startHotkeyCapture: () => invoke<void>("start_hotkey_capture"),
   cancelHotkeyCapture: () => invoke<void>("cancel_hotkey_capture"),
   sftpKnownHosts: () => invoke<SftpKnownHost[]>("sftp_known_hosts"),
+  readLogTail: () => invoke<string>("read_log_tail"),
+  revealLogFile: () => invoke<void>("reveal_log_file"),
   sftpForgetHost: (hostPort: string) =>
     invoke<boolean>("sftp_forget_host", { hostPort }),
-  testUploadConnection: (destination: "Ftp" | "Sftp" | "Imgur" | "Custom" | "S3") =>
-    invoke<ConnectionTestReport>("test_upload_connection", { destination }),
+  testUploadConnection: (
+    destination: "Ftp" | "Sftp" | "Imgur" | "Custom" | "S3" | "Webdav",
+    preset?: string,
+  ) =>
+    invoke<ConnectionTestReport>("test_upload_connection", {
+      destination,
+      preset: preset ?? null,
+    }),
+  importSxcu: (path: string) => invoke<SxcuImport>("import_sxcu", { path }),
+  imgurAuthorizeUrl: () => invoke<string>("imgur_authorize_url"),
+  // resolves to the imgur account name
+  imgurSignIn: (pin: string) => invoke<string>("imgur_sign_in", { pin }),
+  imgurSignOut: () => invoke<void>("imgur_sign_out"),
+  imgurAlbums: () => invoke<ImgurAlbum[]>("imgur_albums"),
+  imgurCreateAlbum: (title: string) => invoke<ImgurAlbum>("imgur_create_album", { title }),
+  dropboxAuthorizeUrl: () => invoke<string>("dropbox_authorize_url"),
+  // resolves to the dropbox account name
+  dropboxSignIn: (code: string) => invoke<string>("dropbox_sign_in", { code }),
+  dropboxSignOut: () => invoke<void>("dropbox_sign_out"),
   fireTask: (taskId: string) => invoke<void>("fire_task", { taskId }),
   runOcr: (path: string) => invoke<string>("run_ocr", { path }),
   pinImage: (path: string) => invoke<void>("pin_image", { path }),
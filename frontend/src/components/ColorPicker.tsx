@@ -0,0 +1,194 @@
+import { createSignal, For, onCleanup, onMount, Show } from "solid-js";
+import { trapFocus } from "../a11y";
+import { t } from "../i18n";
+
+interface Props {
+  value: string;
+  // every change while dragging, so the caller can preview it
+  onInput: (color: string) => void;
+  // the color the user settled on: a swatch click, a released drag, a hex
+  // entered
+  onPick: (color: string) => void;
+  presets: string[];
+  recent: string[];
+}
+
+interface Hsv {
+  h: number;
+  s: number;
+  v: number;
+}
+
+const HEX = /^#[0-9a-f]{6}$/i;
+
+function hexToHsv(hex: string): Hsv {
+  const n = parseInt(hex.slice(1), 16);
+  const r = ((n >> 16) & 255) / 255;
+  const g = ((n >> 8) & 255) / 255;
+  const b = (n & 255) / 255;
+  const max = Math.max(r, g, b);
+  const d = max - Math.min(r, g, b);
+  let h = 0;
+  if (d > 0) {
+    if (max === r) h = ((g - b) / d) % 6;
+    else if (max === g) h = (b - r) / d + 2;
+    else h = (r - g) / d + 4;
+  }
+  return { h: (h * 60 + 360) % 360, s: max === 0 ? 0 : d / max, v: max };
+}
+
+function hsvToHex({ h, s, v }: Hsv): string {
+  const f = (k: number) => {
+    const x = (k + h / 60) % 6;
+    return v - v * s * Math.max(0, Math.min(x, 4 - x, 1));
+  };
+  return (
+    "#" +
+    [f(5), f(3), f(1)]
+      .map((c) => Math.round(c * 255).toString(16).padStart(2, "0"))
+      .join("")
+  );
+}
+
+// the current color as a swatch button; it opens a popover with a
+// saturation/value square, a hue strip, a hex field, the presets and the
+// recently picked colors
+export function ColorPicker(props: Props) {
+  const [open, setOpen] = createSignal(false);
+  // hue is kept apart from the color: dragging to grey or black would
+  // otherwise lose it and snap the strip back to red
+  const [hsv, setHsv] = createSignal<Hsv>(hexToHsv(props.value));
+  const [hex, setHex] = createSignal(props.value);
+  let root!: HTMLDivElement;
+  let square!: HTMLDivElement;
+
+  const show = () => {
+    setHsv(hexToHsv(props.value));
+    setHex(props.value);
+    setOpen(true);
+  };
+
+  const apply = (next: Hsv) => {
+    setHsv(next);
+    const c = hsvToHex(next);
+    setHex(c);
+    props.onInput(c);
+  };
+
+  const pick = (c: string) => {
+    setOpen(false);
+    props.onPick(c.toLowerCase());
+  };
+
+  const onSquareDown = (e: PointerEvent) => {
+    square.setPointerCapture(e.pointerId);
+    const at = (ev: PointerEvent) => {
+      const r = square.getBoundingClientRect();
+      const s = Math.max(0, Math.min(1, (ev.clientX - r.left) / r.width));
+      const v = 1 - Math.max(0, Math.min(1, (ev.clientY - r.top) / r.height));
+      apply({ ...hsv(), s, v });
+    };
+    at(e);
+    const up = () => {
+      square.removeEventListener("pointermove", at);
+      square.removeEventListener("pointerup", up);
+      props.onPick(hex());
+    };
+    square.addEventListener("pointermove", at);
+    square.addEventListener("pointerup", up);
+  };
+
+  const onOutside = (e: PointerEvent) => {
+    if (open() && !root.contains(e.target as Node)) setOpen(false);
+  };
+  onMount(() => document.addEventListener("pointerdown", onOutside));
+  onCleanup(() => document.removeEventListener("pointerdown", onOutside));
+
+  const row = (label: string, colors: string[]) => (
+    <Show when={colors.length > 0}>
+      <div class="color-pop-row" role="group" aria-label={label}>
+        <For each={colors}>
+          {(c) => (
+            <button
+              type="button"
+              class="swatch"
+              classList={{ "is-active": props.value === c }}
+              style={{ background: c }}
+              aria-label={c}
+              title={c}
+              onClick={() => pick(c)}
+            />
+          )}
+        </For>
+      </div>
+    </Show>
+  );
+
+  return (
+    <div class="color-picker" ref={root}>
+      <button
+        type="button"
+        class="swatch color-current"
+        style={{ background: props.value }}
+        aria-label={t("color.current", { color: props.value })}
+        aria-expanded={open()}
+        title={t("color.pick")}
+        onClick={() => (open() ? setOpen(false) : show())}
+      />
+      <Show when={open()}>
+        <div
+          class="color-pop"
+          role="dialog"
+          aria-label={t("color.dialog")}
+          ref={trapFocus}
+          onKeyDown={(e) => {
+            if (e.key === "Escape") {
+              // the editor closes on escape too
+              e.stopPropagation();
+              setOpen(false);
+            }
+          }}
+        >
+          <div
+            ref={square}
+            class="color-pop-sv"
+            style={{ background: `hsl(${hsv().h}, 100%, 50%)` }}
+            onPointerDown={onSquareDown}
+          >
+            <span
+              class="color-pop-thumb"
+              style={{ left: `${hsv().s * 100}%`, top: `${(1 - hsv().v) * 100}%` }}
+            />
+          </div>
+          <input
+            type="range"
+            class="color-pop-hue"
+            min={0}
+            max={359}
+            aria-label={t("color.hue")}
+            value={Math.round(hsv().h)}
+            onInput={(e) => apply({ ...hsv(), h: parseInt(e.currentTarget.value) })}
+            onChange={() => props.onPick(hex())}
+          />
+          <input
+            type="text"
+            class="color-pop-hex"
+            aria-label={t("color.hex")}
+            spellcheck={false}
+            value={hex()}
+            onInput={(e) => setHex(e.currentTarget.value)}
+            onKeyDown={(e) => {
+              if (e.key !== "Enter") return;
+              e.preventDefault();
+              e.stopPropagation();
+              const c = hex().startsWith("#") ? hex() : `#${hex()}`;
+              if (HEX.test(c)) pick(c);
+            }}
+          />
+          {row(t("color.presets"), props.presets)}
+          {row(t("color.recent"), props.recent)}
+        </div>
+      </Show>
+    </div>
+  );
+}
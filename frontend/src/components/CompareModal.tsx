@@ -0,0 +1,170 @@
+import { createResource, createSignal, onCleanup, onMount, Show } from "solid-js";
+import { convertFileSrc } from "@tauri-apps/api/core";
+import { Columns, X } from "lucide-solid";
+import { api } from "../api";
+import { t, tm } from "../i18n";
+import { trapFocus } from "../a11y";
+
+function basename(p: string): string {
+  return p.split(/[\\/]/).pop() ?? p;
+}
+
+type CompareMode = "slider" | "diff";
+
+// before/after check of two captures: a wipe slider between them, or a pixel
+// diff heatmap from the backend with the share of pixels that changed
+export function CompareModal(props: { a: string; b: string; onClose: () => void }) {
+  const [mode, setMode] = createSignal<CompareMode>("slider");
+  const [split, setSplit] = createSignal(50);
+  const [tolerance, setTolerance] = createSignal(8);
+  // the heatmap file is overwritten per run, so each result gets its own url
+  const [diff] = createResource(
+    () => (mode() === "diff" ? tolerance() : null),
+    async (tol) => {
+      const result = await api.compareCaptures(props.a, props.b, tol);
+      return { ...result, src: `${convertFileSrc(result.heatmap)}?v=${Date.now()}` };
+    },
+  );
+
+  onMount(() => {
+    const onKey = (ev: KeyboardEvent) => {
+      if (ev.key === "Escape") {
+        ev.preventDefault();
+        props.onClose();
+      }
+    };
+    window.addEventListener("keydown", onKey);
+    onCleanup(() => window.removeEventListener("keydown", onKey));
+  });
+
+  const share = () => {
+    const d = diff();
+    if (!d || d.total === 0) return "0";
+    const pct = (d.changed / d.total) * 100;
+    return pct > 0 && pct < 0.01 ? "<0.01" : pct.toFixed(2);
+  };
+  const summary = () => {
+    const d = diff();
+    if (diff.loading || !d) return t("compare.comparing");
+    return t("compare.summary", {
+      count: d.changed.toLocaleString(),
+      share: share(),
+      width: d.width,
+      height: d.height,
+    });
+  };
+
+  return (
+    <div
+      class="modal-backdrop"
+      onClick={(e) => {
+        if (e.target === e.currentTarget) props.onClose();
+      }}
+    >
+      <div
+        class="modal compare-modal"
+        role="dialog"
+        aria-modal="true"
+        aria-label={t("compare.dialog")}
+        ref={trapFocus}
+      >
+        <div class="modal-head">
+          <h2>
+            <Columns size={13} stroke-width={1.5} /> {t("compare.title")}
+          </h2>
+          <div class="history-filters">
+            <button
+              type="button"
+              class="filter-pill"
+              classList={{ "is-active": mode() === "slider" }}
+              onClick={() => setMode("slider")}
+            >
+              {t("compare.slider")}
+            </button>
+            <button
+              type="button"
+              class="filter-pill"
+              classList={{ "is-active": mode() === "diff" }}
+              onClick={() => setMode("diff")}
+            >
+              {t("compare.diff")}
+            </button>
+          </div>
+          <button
+            class="icon-btn"
+            title={t("common.close")}
+            aria-label={t("common.close")}
+            onClick={() => props.onClose()}
+          >
+            <X size={12} stroke-width={1.5} />
+          </button>
+        </div>
+
+        <Show
+          when={mode() === "diff"}
+          fallback={
+            <>
+              <div class="compare-stage">
+                <img src={convertFileSrc(props.b)} alt={basename(props.b)} />
+                <img
+                  class="compare-top"
+                  src={convertFileSrc(props.a)}
+                  alt={basename(props.a)}
+                  style={{ "clip-path": `inset(0 ${100 - split()}% 0 0)` }}
+                />
+                <div class="compare-divider" style={{ left: `${split()}%` }} />
+              </div>
+              <div class="trim-row">
+                <span class="trim-label" title={props.a}>
+                  <b>{basename(props.a)}</b>
+                </span>
+                <input
+                  type="range"
+                  min={0}
+                  max={100}
+                  step={0.5}
+                  value={split()}
+                  aria-label={t("compare.wipe")}
+                  onInput={(e) => setSplit(parseFloat(e.currentTarget.value))}
+                />
+                <span class="trim-label" title={props.b}>
+                  <b>{basename(props.b)}</b>
+                </span>
+              </div>
+            </>
+          }
+        >
+          <div class="compare-stage">
+            <Show when={!diff.error && diff()}>
+              <img src={diff()!.src} alt={t("compare.diff-image")} />
+            </Show>
+          </div>
+          <div class="trim-row">
+            <span class="trim-label">
+              {tm("compare.tolerance.label", { value: <b>{tolerance()}</b> })}
+            </span>
+            <input
+              type="range"
+              min={0}
+              max={64}
+              step={1}
+              value={tolerance()}
+              aria-label={t("compare.tolerance")}
+              onChange={(e) => setTolerance(parseInt(e.currentTarget.value, 10))}
+            />
+          </div>
+          <Show
+            when={!diff.error}
+            fallback={
+              <div class="flash" data-tone="err">
+                {String(diff.error)}
+              </div>
+            }
+          >
+            <span class="trim-len">{summary()}</span>
+          </Show>
+        </Show>
+      </div>
+    </div>
+  );
+}
@@ -7,6 +7,7 @@ import {
   META_LABEL,
 } from "../keys";
 import { api } from "../api";
+import { t } from "../i18n";
 
 interface Props {
   value: string;
@@ -62,9 +63,7 @@ export function HotkeyInput(props: Props) {
     const stripped = payload.hotkey.split("+").pop() ?? payload.hotkey;
     const hasModifier = payload.mods !== 0;
     if (!hasModifier && !RISKY_BARE_OK.has(stripped)) {
-      setWarning(
-        `${payload.hotkey} would steal that key from every app — add a modifier (Ctrl / Alt / Shift / ${META_LABEL}).`,
-      );
+      setWarning(t("hotkey.bare", { hotkey: payload.hotkey, meta: META_LABEL }));
       // re-arm for another attempt
       void api.startHotkeyCapture().catch((e) => {
         setWarning(String(e));
@@ -177,7 +176,7 @@ export function HotkeyInput(props: Props) {
             fallback={
               <Show
                 when={props.value}
-                fallback={<span class="ph">click to bind…</span>}
+                fallback={<span class="ph">{t("hotkey.bind")}</span>}
               >
                 <For each={splitHotkey(props.value)}>
                   {(p, i) => (
@@ -190,14 +189,14 @@ export function HotkeyInput(props: Props) {
               </Show>
             }
           >
-            <span>press a key… (esc to cancel)</span>
+            <span>{t("hotkey.press")}</span>
           </Show>
         </div>
         <Show when={props.value && !capturing()}>
           <button
             type="button"
             class="hk-clear"
-            aria-label="Clear hotkey"
+            aria-label={t("hotkey.clear")}
             onClick={clear}
           >
             <X size={12} stroke-width={1.5} />
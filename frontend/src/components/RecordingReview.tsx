@@ -0,0 +1,217 @@
+import { createMemo, createResource, createSignal, Show } from "solid-js";
+import { convertFileSrc } from "@tauri-apps/api/core";
+import { Film } from "lucide-solid";
+import { api, RecordingReviewInfo } from "../api";
+import { trapFocus } from "../a11y";
+import { t, tm } from "../i18n";
+
+function fmt(s: number): string {
+  if (!isFinite(s) || s < 0) s = 0;
+  const m = Math.floor(s / 60);
+  const sec = (s % 60).toFixed(1).padStart(4, "0");
+  return `${m}:${sec}`;
+}
+
+// shown when a recording stops with capture.review_recordings on. nothing is
+// in the output folder yet: the backend holds the frames (gif) or a staged
+// file (mp4) until the user saves a range or discards it. there's no close
+// button on purpose — walking away would strand the recording, so the choices
+// are all explicit. a save reports itself through capscr://capture-saved like
+// any other recording
+export function RecordingReview(props: { info: RecordingReviewInfo; onClose: () => void }) {
+  let video: HTMLVideoElement | undefined;
+  const times = () => props.info.frame_times_ms;
+  const isGif = () => props.info.format === "gif";
+
+  // a gif's last frame holds for about one average gap
+  const gifLength = () => {
+    const ts = times();
+    if (ts.length === 0) return 0;
+    const gap = ts.length > 1 ? (ts[ts.length - 1] - ts[0]) / (ts.length - 1) : 100;
+    return (ts[ts.length - 1] + gap) / 1000;
+  };
+
+  const [dur, setDur] = createSignal(isGif() ? gifLength() : 0);
+  const [start, setStart] = createSignal(0);
+  const [end, setEnd] = createSignal(dur());
+  const [playhead, setPlayhead] = createSignal(0);
+  const [busy, setBusy] = createSignal(false);
+  const [err, setErr] = createSignal<string | null>(null);
+
+  // the frame on screen at the playhead
+  const frameIndex = createMemo(() => {
+    const ms = playhead() * 1000;
+    const ts = times();
+    let lo = 0;
+    let hi = ts.length;
+    while (lo < hi) {
+      const mid = (lo + hi) >> 1;
+      if (ts[mid] <= ms) lo = mid + 1;
+      else hi = mid;
+    }
+    return Math.max(0, lo - 1);
+  });
+  const [frame] = createResource(
+    () => (isGif() && times().length > 0 ? frameIndex() : false),
+    (index) => api.recordingReviewFrame(index as number),
+  );
+
+  const onMeta = () => {
+    const d = video?.duration ?? 0;
+    if (isFinite(d) && d > 0) {
+      setDur(d);
+      setEnd(d);
+    }
+  };
+
+  const seek = (s: number) => {
+    setPlayhead(s);
+    if (video) video.currentTime = s;
+  };
+  const setStartClamped = (v: number) => {
+    setStart(Math.max(0, Math.min(v, end() - 0.05)));
+    seek(start());
+  };
+  const setEndClamped = (v: number) => {
+    setEnd(Math.min(dur(), Math.max(v, start() + 0.05)));
+    seek(end());
+  };
+
+  const len = () => Math.max(0, end() - start());
+  const trimmed = () => start() > 0.001 || end() < dur() - 0.001;
+
+  const save = async () => {
+    if (busy() || len() < 0.05) return;
+    setBusy(true);
+    setErr(null);
+    try {
+      if (trimmed()) {
+        await api.finishRecordingReview(Math.round(start() * 1000), Math.round(end() * 1000));
+      } else {
+        await api.finishRecordingReview(null, null);
+      }
+      props.onClose();
+    } catch (e) {
+      setErr(String(e));
+      setBusy(false);
+    }
+  };
+
+  const discard = async () => {
+    if (busy() || !window.confirm(t("review.discard.confirm"))) return;
+    await api.discardRecordingReview().catch(() => {});
+    props.onClose();
+  };
+
+  return (
+    <div class="modal-backdrop">
+      <div
+        class="modal trim-modal"
+        role="dialog"
+        aria-modal="true"
+        aria-label={t("review.title")}
+        ref={trapFocus}
+      >
+        <div class="modal-head">
+          <h2>
+            <Film size={13} stroke-width={1.5} /> {t("review.title")}
+          </h2>
+        </div>
+
+        <Show
+          when={isGif()}
+          fallback={
+            <video
+              ref={video}
+              class="trim-video"
+              src={convertFileSrc(props.info.video_path ?? "")}
+              controls
+              onLoadedMetadata={onMeta}
+              onTimeUpdate={() => setPlayhead(video?.currentTime ?? 0)}
+            />
+          }
+        >
+          <img class="trim-video" src={frame.latest ?? ""} alt={t("review.frame")} />
+          <div class="trim-row">
+            <span class="trim-label">{tm("review.at", { time: <b>{fmt(playhead())}</b> })}</span>
+            <input
+              type="range"
+              min={0}
+              max={dur()}
+              step={0.05}
+              value={playhead()}
+              disabled={busy()}
+              aria-label={t("review.scrub")}
+              onInput={(e) => setPlayhead(parseFloat(e.currentTarget.value))}
+            />
+          </div>
+        </Show>
+
+        <div class="trim-row">
+          <span class="trim-label">{tm("review.in", { time: <b>{fmt(start())}</b> })}</span>
+          <input
+            type="range"
+            min={0}
+            max={dur()}
+            step={0.05}
+            value={start()}
+            disabled={busy()}
+            onInput={(e) => setStartClamped(parseFloat(e.currentTarget.value))}
+          />
+          <button
+            class="btn"
+            data-variant="ghost"
+            disabled={busy()}
+            onClick={() => setStartClamped(playhead())}
+          >
+            {t("trim.playhead")}
+          </button>
+        </div>
+
+        <div class="trim-row">
+          <span class="trim-label">{tm("review.out", { time: <b>{fmt(end())}</b> })}</span>
+          <input
+            type="range"
+            min={0}
+            max={dur()}
+            step={0.05}
+            value={end()}
+            disabled={busy()}
+            onInput={(e) => setEndClamped(parseFloat(e.currentTarget.value))}
+          />
+          <button
+            class="btn"
+            data-variant="ghost"
+            disabled={busy()}
+            onClick={() => setEndClamped(playhead())}
+          >
+            {t("trim.playhead")}
+          </button>
+        </div>
+
+        <div class="trim-foot">
+          <span class="trim-len">
+            {trimmed()
+              ? t("review.keeping", { length: fmt(len()), total: fmt(dur()) })
+              : t("review.whole", { total: fmt(dur()) })}
+          </span>
+        </div>
+
+        <Show when={err()}>
+          <div class="flash" data-tone="err">
+            {err()}
+          </div>
+        </Show>
+
+        <div class="modal-actions">
+          <button class="btn" data-variant="ghost" disabled={busy()} onClick={discard}>
+            {t("review.discard")}
+          </button>
+          <button class="btn" disabled={busy() || len() < 0.05} onClick={save}>
+            {busy() ? t("common.saving") : trimmed() ? t("review.save-trimmed") : t("common.save")}
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,64 @@
+import { Show } from "solid-js";
+import { ResizeConfig } from "../api";
+import { t } from "../i18n";
+import { commitNumber } from "../num";
+
+interface Props {
+  value: ResizeConfig;
+  onChange: (next: ResizeConfig) => void;
+  hint: string;
+}
+
+// one "downscale" row; output settings and the upload destination each keep
+// their own copy of the config
+export function ResizeField(props: Props) {
+  const set = (next: Partial<ResizeConfig>) => props.onChange({ ...props.value, ...next });
+  return (
+    <div class="field">
+      <label class="field-label">{t("resize.label")}</label>
+      <div class="field-control">
+        <div class="input-row">
+          <select
+            value={props.value.mode}
+            onChange={(e) => set({ mode: e.currentTarget.value as ResizeConfig["mode"] })}
+          >
+            <option value="off">{t("common.off")}</option>
+            <option value="max-dimension">{t("resize.max-dimension")}</option>
+            <option value="percent">{t("resize.percent")}</option>
+          </select>
+          <Show when={props.value.mode === "max-dimension"}>
+            <input
+              type="number"
+              min={16}
+              max={16384}
+              aria-label={t("resize.max-dimension.aria")}
+              value={props.value.max_dimension}
+              onChange={(e) =>
+                set({
+                  max_dimension: commitNumber(e.currentTarget, { min: 16, max: 16384, fallback: props.value.max_dimension, int: true }),
+                })
+              }
+            />
+            <span class="field-hint">px</span>
+          </Show>
+          <Show when={props.value.mode === "percent"}>
+            <input
+              type="number"
+              min={5}
+              max={100}
+              aria-label={t("resize.percent.aria")}
+              value={props.value.percent}
+              onChange={(e) =>
+                set({
+                  percent: commitNumber(e.currentTarget, { min: 5, max: 100, fallback: props.value.percent, int: true }),
+                })
+              }
+            />
+            <span class="field-hint">%</span>
+          </Show>
+        </div>
+        <span class="field-hint">{props.hint}</span>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,134 @@
+import { createSignal, onCleanup, onMount, Show } from "solid-js";
+import { Tag, X } from "lucide-solid";
+import { api, CaptureNotes } from "../api";
+import { trapFocus } from "../a11y";
+import { t } from "../i18n";
+
+function basename(p: string): string {
+  return p.split(/[\\/]/).pop() ?? p;
+}
+
+// commas or spaces separate tags; the backend normalizes the rest
+function parseTags(text: string): string[] {
+  return text.split(/[,\s]+/).filter((tag) => tag.length > 0);
+}
+
+export function TagsModal(props: {
+  path: string;
+  onClose: () => void;
+  onSaved: (notes: CaptureNotes) => void;
+}) {
+  const [tags, setTags] = createSignal("");
+  const [note, setNote] = createSignal("");
+  const [loaded, setLoaded] = createSignal(false);
+  const [busy, setBusy] = createSignal(false);
+  const [err, setErr] = createSignal<string | null>(null);
+
+  onMount(() => {
+    api
+      .getCaptureTags(props.path)
+      .then((n) => {
+        setTags(n.tags.join(", "));
+        setNote(n.note);
+      })
+      .catch((e) => setErr(String(e)))
+      .finally(() => setLoaded(true));
+    const onKey = (ev: KeyboardEvent) => {
+      if (ev.key === "Escape" && !busy()) {
+        ev.preventDefault();
+        props.onClose();
+      }
+    };
+    window.addEventListener("keydown", onKey);
+    onCleanup(() => window.removeEventListener("keydown", onKey));
+  });
+
+  const save = async () => {
+    if (busy()) return;
+    setBusy(true);
+    setErr(null);
+    try {
+      props.onSaved(await api.setCaptureTags(props.path, parseTags(tags()), note()));
+    } catch (e) {
+      setErr(String(e));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  return (
+    <div
+      class="modal-backdrop"
+      onClick={(e) => {
+        if (e.target === e.currentTarget && !busy()) props.onClose();
+      }}
+    >
+      <div
+        class="modal tags-modal"
+        role="dialog"
+        aria-modal="true"
+        aria-label={t("tags.title")}
+        ref={trapFocus}
+      >
+        <div class="modal-head">
+          <h2>
+            <Tag size={13} stroke-width={1.5} /> {basename(props.path)}
+          </h2>
+          <button
+            class="icon-btn"
+            title={t("common.close")}
+            aria-label={t("common.close")}
+            disabled={busy()}
+            onClick={() => props.onClose()}
+          >
+            <X size={12} stroke-width={1.5} />
+          </button>
+        </div>
+
+        <label class="tags-field">
+          <span>{t("tags.tags")}</span>
+          <input
+            type="text"
+            placeholder={t("tags.placeholder")}
+            value={tags()}
+            disabled={busy() || !loaded()}
+            onInput={(e) => setTags(e.currentTarget.value)}
+            onKeyDown={(e) => {
+              if (e.key === "Enter") void save();
+            }}
+          />
+        </label>
+
+        <label class="tags-field">
+          <span>{t("tags.note")}</span>
+          <textarea
+            rows={4}
+            value={note()}
+            disabled={busy() || !loaded()}
+            onInput={(e) => setNote(e.currentTarget.value)}
+          />
+        </label>
+
+        <Show when={err()}>
+          <div class="flash" data-tone="err">
+            {err()}
+          </div>
+        </Show>
+
+        <div class="modal-actions">
+          <button
+            class="btn"
+            data-variant="ghost"
+            disabled={busy()}
+            onClick={() => props.onClose()}
+          >
+            {t("common.cancel")}
+          </button>
+          <button class="btn" disabled={busy() || !loaded()} onClick={save}>
+            {busy() ? t("common.saving") : t("common.save")}
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -1,6 +1,7 @@
 import { onCleanup, onMount, createSignal } from "solid-js";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { Minus, Square, Copy as Restore, X } from "lucide-solid";
+import { t } from "../i18n";
 
 interface Props {
   context?: string;
@@ -51,7 +52,7 @@ export function Titlebar(props: Props) {
           type="button"
           class="titlebar-btn"
           data-action="minimize"
-          aria-label="minimize"
+          aria-label={t("titlebar.minimize")}
           onClick={() => win.minimize()}
         >
           <Minus size={14} stroke-width={1.5} />
@@ -60,7 +61,7 @@ export function Titlebar(props: Props) {
           type="button"
           class="titlebar-btn"
           data-action="maximize"
-          aria-label={maximized() ? "restore" : "maximize"}
+          aria-label={maximized() ? t("titlebar.restore") : t("titlebar.maximize")}
           onClick={async () => {
             await win.toggleMaximize();
             setMaximized(await win.isMaximized());
@@ -76,7 +77,7 @@ export function Titlebar(props: Props) {
           type="button"
           class="titlebar-btn"
           data-action="close"
-          aria-label="close"
+          aria-label={t("common.close")}
           onClick={onClose}
         >
           <X size={14} stroke-width={1.5} />
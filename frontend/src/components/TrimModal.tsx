@@ -2,6 +2,8 @@ import { createSignal, onCleanup, onMount, Show } from "solid-js";
 import { convertFileSrc } from "@tauri-apps/api/core";
 import { Scissors, X } from "lucide-solid";
 import { api } from "../api";
+import { trapFocus } from "../a11y";
+import { t, tm } from "../i18n";
 
 function fmt(s: number): string {
   if (!isFinite(s) || s < 0) s = 0;
@@ -61,7 +63,7 @@ export function TrimModal(props: {
     setErr(null);
     try {
       const out = await api.trimMp4(props.path, start(), end(), fast());
-      props.onDone(`trimmed → ${basename(out)}`);
+      props.onDone(t("trim.done", { name: basename(out) }));
     } catch (e) {
       setErr(String(e));
     } finally {
@@ -76,14 +78,21 @@ export function TrimModal(props: {
         if (e.target === e.currentTarget && !busy()) props.onClose();
       }}
     >
-      <div class="modal trim-modal">
+      <div
+        class="modal trim-modal"
+        role="dialog"
+        aria-modal="true"
+        aria-label={t("trim.title")}
+        ref={trapFocus}
+      >
         <div class="modal-head">
           <h2>
-            <Scissors size={13} stroke-width={1.5} /> trim recording
+            <Scissors size={13} stroke-width={1.5} /> {t("trim.title")}
           </h2>
           <button
             class="icon-btn"
-            title="close"
+            title={t("common.close")}
+            aria-label={t("common.close")}
             disabled={busy()}
             onClick={() => props.onClose()}
           >
@@ -100,7 +109,7 @@ export function TrimModal(props: {
         />
 
         <div class="trim-row">
-          <span class="trim-label">start <b>{fmt(start())}</b></span>
+          <span class="trim-label">{tm("trim.start", { time: <b>{fmt(start())}</b> })}</span>
           <input
             type="range"
             min={0}
@@ -116,12 +125,12 @@ export function TrimModal(props: {
             disabled={busy()}
             onClick={() => setStartClamped(video?.currentTime ?? 0)}
           >
-            playhead
+            {t("trim.playhead")}
           </button>
         </div>
 
         <div class="trim-row">
-          <span class="trim-label">end <b>{fmt(end())}</b></span>
+          <span class="trim-label">{tm("trim.end", { time: <b>{fmt(end())}</b> })}</span>
           <input
             type="range"
             min={0}
@@ -137,7 +146,7 @@ export function TrimModal(props: {
             disabled={busy()}
             onClick={() => setEndClamped(video?.currentTime ?? 0)}
           >
-            playhead
+            {t("trim.playhead")}
           </button>
         </div>
 
@@ -150,10 +159,10 @@ export function TrimModal(props: {
               onChange={(e) => setFast(e.currentTarget.checked)}
             />
             <span class="check-label">
-              fast (lossless, start snaps to keyframe)
+              {t("trim.fast")}
             </span>
           </label>
-          <span class="trim-len">length {fmt(len())}</span>
+          <span class="trim-len">{t("trim.length", { time: fmt(len()) })}</span>
         </div>
 
         <Show when={err()}>
@@ -169,10 +178,10 @@ export function TrimModal(props: {
             disabled={busy()}
             onClick={() => props.onClose()}
           >
-            cancel
+            {t("common.cancel")}
           </button>
           <button class="btn" disabled={busy() || len() < 0.05} onClick={exportTrim}>
-            {busy() ? "exporting…" : "export trim"}
+            {busy() ? t("trim.exporting") : t("trim.export")}
           </button>
         </div>
       </div>
@@ -0,0 +1,192 @@
+import {
+  createEffect,
+  createMemo,
+  createResource,
+  createSignal,
+  For,
+  onCleanup,
+  onMount,
+  Show,
+} from "solid-js";
+import { convertFileSrc } from "@tauri-apps/api/core";
+import { RefreshCw, Search, X } from "lucide-solid";
+import { api, OpenWindow, ThumbSlot } from "../api";
+import { t } from "../i18n";
+
+// escape a literal window title for the task's title regex
+const escapeRegex = (s: string) => s.replace(/[.*+?^${}()|[\]\\]/g, "\\$&");
+
+export const targetFor = (w: OpenWindow) => ({
+  title: `^${escapeRegex(w.title)}$`,
+  process: w.app_name ? `^${escapeRegex(w.app_name)}$` : "",
+});
+
+// live is null until the backend says whether dwm draws the previews; only a
+// definite no grabs one-shot ones, so windows never pays for both
+function Thumb(props: { id: number; stamp: number; live: boolean | null }) {
+  // keyed on the refresh stamp too, so a refresh re-grabs every preview even
+  // though the backend writes to the same per-window file
+  const [src] = createResource(
+    () => props.live === false && ([props.id, props.stamp] as const),
+    async ([id, stamp]) =>
+      `${convertFileSrc(await api.windowThumbnail(id))}?t=${stamp}`,
+  );
+  return (
+    <div class="window-thumb" data-window-id={props.id}>
+      <Show when={src()}>
+        <img src={src()} alt="" loading="lazy" />
+      </Show>
+    </div>
+  );
+}
+
+export function WindowPicker(props: {
+  onPick: (w: OpenWindow) => void;
+  onClose: () => void;
+}) {
+  const [query, setQuery] = createSignal("");
+  const [stamp, setStamp] = createSignal(Date.now());
+  // the stamp is the resource source, so bumping it relists the windows too
+  const [windows] = createResource(stamp, () => api.listOpenWindows());
+  const [live, setLive] = createSignal<boolean | null>(null);
+  let root!: HTMLDivElement;
+
+  // dwm draws over the webview rather than inside it, so only boxes wholly
+  // on screen get a preview; a half-scrolled row would spill over the header
+  const syncLive = () => {
+    if (!live()) return;
+    const bounds = root.getBoundingClientRect();
+    const top = Math.max(bounds.top, 0);
+    const bottom = Math.min(bounds.bottom, window.innerHeight);
+    const dpr = window.devicePixelRatio;
+    const slots: ThumbSlot[] = [];
+    const boxes = root.querySelectorAll<HTMLElement>(".window-thumb[data-window-id]");
+    for (const el of boxes) {
+      const r = el.getBoundingClientRect();
+      if (r.top < top || r.bottom > bottom || r.width === 0) continue;
+      slots.push({
+        id: Number(el.dataset.windowId),
+        x: Math.round(r.left * dpr),
+        y: Math.round(r.top * dpr),
+        width: Math.round(r.width * dpr),
+        height: Math.round(r.height * dpr),
+      });
+    }
+    api.setLiveWindowThumbnails(slots).catch(() => {});
+  };
+  let frame = 0;
+  const queueSync = () => {
+    cancelAnimationFrame(frame);
+    frame = requestAnimationFrame(syncLive);
+  };
+
+  onMount(() => {
+    api
+      .setLiveWindowThumbnails([])
+      .then(setLive)
+      .catch(() => setLive(false));
+    // capture, so scrolling whatever container the picker sits in counts
+    window.addEventListener("scroll", queueSync, true);
+    window.addEventListener("resize", queueSync);
+    onCleanup(() => {
+      window.removeEventListener("scroll", queueSync, true);
+      window.removeEventListener("resize", queueSync);
+      cancelAnimationFrame(frame);
+      if (live()) api.setLiveWindowThumbnails([]).catch(() => {});
+    });
+  });
+
+  const groups = createMemo(() => {
+    const needle = query().trim().toLowerCase();
+    const byApp = new Map<string, OpenWindow[]>();
+    for (const w of windows() ?? []) {
+      if (
+        needle &&
+        !w.title.toLowerCase().includes(needle) &&
+        !w.app_name.toLowerCase().includes(needle)
+      ) {
+        continue;
+      }
+      const app = w.app_name || t("windows.unknown-app");
+      byApp.set(app, [...(byApp.get(app) ?? []), w]);
+    }
+    return [...byApp.entries()];
+  });
+
+  // rows come and go with the list and the filter
+  createEffect(() => {
+    groups();
+    if (live()) queueSync();
+  });
+
+  return (
+    <div class="window-picker" ref={root}>
+      <div class="row between" style="gap: 8px;">
+        <label class="history-search">
+          <Search size={11} stroke-width={1.5} />
+          <input
+            type="text"
+            placeholder={t("windows.filter")}
+            value={query()}
+            onInput={(e) => setQuery(e.currentTarget.value)}
+            autofocus
+          />
+          <Show when={query()}>
+            <button
+              type="button"
+              class="search-clear"
+              title={t("common.clear")}
+              onClick={() => setQuery("")}
+            >
+              <X size={10} stroke-width={1.5} />
+            </button>
+          </Show>
+        </label>
+        <div class="row" style="gap: 6px;">
+          <button class="btn" data-variant="ghost" onClick={() => setStamp(Date.now())}>
+            <RefreshCw size={12} stroke-width={1.5} />
+            {t("common.refresh")}
+          </button>
+          <button class="btn" data-variant="ghost" onClick={() => props.onClose()}>
+            {t("common.close")}
+          </button>
+        </div>
+      </div>
+      <Show
+        when={groups().length > 0}
+        fallback={
+          <p class="field-hint">
+            {windows.loading
+              ? t("windows.listing")
+              : query()
+                ? t("windows.no-match")
+                : t("windows.none")}
+          </p>
+        }
+      >
+        <For each={groups()}>
+          {([app, list]) => (
+            <div class="window-group">
+              <div class="window-group-head">
+                {app} <span class="window-group-count">{list.length}</span>
+              </div>
+              <For each={list}>
+                {(w) => (
+                  <button
+                    type="button"
+                    class="window-row"
+                    title={`${w.width}×${w.height}`}
+                    onClick={() => props.onPick(w)}
+                  >
+                    <Thumb id={w.id} stamp={stamp()} live={live()} />
+                    <span class="window-title">{w.title}</span>
+                  </button>
+                )}
+              </For>
+            </div>
+          )}
+        </For>
+      </Show>
+    </div>
+  );
+}
@@ -0,0 +1,65 @@
+import { createEffect, createRoot, createSignal, JSX } from "solid-js";
+import { UiConfig } from "./api";
+import { config } from "./store";
+import { de } from "./locales/de";
+import { en } from "./locales/en";
+import { fr } from "./locales/fr";
+import { ja } from "./locales/ja";
+
+// message bundles live in ./locales, keyed by a dotted id. english is the
+// source of truth and the fallback: a key missing from another bundle renders
+// in english rather than as its id
+
+export type MessageKey = keyof typeof en;
+export type Bundle = Partial<Record<MessageKey, string>>;
+
+export type Locale = "en" | "de" | "fr" | "ja";
+
+const BUNDLES: Record<Locale, Bundle> = { en, de, fr, ja };
+
+export const LANGUAGES: { id: UiConfig["language"]; label: string }[] = [
+  { id: "system", label: "" },
+  { id: "en", label: "English" },
+  { id: "de", label: "Deutsch" },
+  { id: "fr", label: "Français" },
+  { id: "ja", label: "日本語" },
+];
+
+function systemLocale(): Locale {
+  for (const tag of navigator.languages ?? [navigator.language]) {
+    const base = tag.toLowerCase().split("-")[0];
+    if (base in BUNDLES) return base as Locale;
+  }
+  return "en";
+}
+
+const [locale, setLocale] = createSignal<Locale>(systemLocale());
+export { locale };
+
+// follow the config as it loads and changes, in every window that imports
+// this. Settings edits the store before saving, so a picked language applies
+// on the spot
+createRoot(() => {
+  createEffect(() => {
+    const lang = config()?.ui.language ?? "system";
+    const next = lang === "system" ? systemLocale() : lang;
+    setLocale(next);
+    document.documentElement.lang = next;
+  });
+});
+
+export function t(key: MessageKey, vars?: Record<string, string | number>): string {
+  const message = BUNDLES[locale()][key] ?? en[key];
+  if (!vars) return message;
+  return message.replace(/\{(\w+)\}/g, (whole, name) =>
+    name in vars ? String(vars[name]) : whole,
+  );
+}
+
+// t() for a message with markup in it: each `{name}` becomes the given node
+// instead of text, so a translation can move a <kbd> around the sentence
+export function tm(key: MessageKey, nodes: Record<string, JSX.Element>): JSX.Element[] {
+  return t(key)
+    .split(/\{(\w+)\}/)
+    .map((part, i) => (i % 2 === 1 ? (nodes[part] ?? `{${part}}`) : part));
+}
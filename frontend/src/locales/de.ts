@@ -0,0 +1,1217 @@
+import type { Bundle } from "../i18n";
+
+export const de: Bundle = {
+  // sidebar tabs
+  "tab.settings": "einstellungen",
+  "tab.tasks": "aufgaben",
+  "tab.history": "verlauf",
+  "tab.destinations": "ziele",
+  "tab.plugins": "plugins",
+
+  // shared across views
+  "common.copy": "kopieren",
+  "common.open": "öffnen",
+  "common.close": "schließen",
+  "common.dismiss": "ausblenden",
+  "common.cancel": "abbrechen",
+  "common.save": "speichern",
+  "common.delete": "löschen",
+  "common.remove": "entfernen",
+  "common.add": "hinzufügen",
+  "common.edit": "bearbeiten",
+  "common.on": "an",
+  "common.off": "aus",
+  "common.none": "keine",
+  "common.show-in-folder": "im ordner zeigen",
+  "common.clipboard-busy": "zwischenablage belegt — erneut versuchen",
+  "common.error": "fehler: {error}",
+  "common.save.title": "ausstehende änderungen übernehmen",
+  "common.save.clean": "keine änderungen zu speichern",
+  "common.writing": "schreibt...",
+  "common.saved": "gespeichert.",
+  "common.browse": "durchsuchen",
+
+  // hub shell: statusbar, banners, overlays
+  "app.loading-editor": "editor wird geladen…",
+  "app.skip": "zum inhalt springen",
+  "app.quit.dirty": "capscr beenden und ungespeicherte Einstellungen verwerfen?",
+  "app.quit.recording": "capscr beenden? Die laufende Aufnahme wird vorher gespeichert.",
+  "app.discard-edits": "Ungespeicherte Änderungen an den Einstellungen verwerfen?",
+
+  "app.status.failed": "{kind} fehlgeschlagen: {msg}",
+  "app.status.mail": "im mailprogramm geöffnet",
+  "app.status.uploaded": "hochgeladen: {url}",
+  "app.status.saved": "gespeichert unter {name}",
+  "app.status.crash-copied": "absturzbericht kopiert",
+  "app.status.crash-error": "absturzbericht: {error}",
+  "app.status.restored": "aufnahme wiederhergestellt → {path}",
+  "app.status.recovered-error": "wiederhergestellte aufnahme: {error}",
+  "app.status.stats": "aufgenommen mit {achieved} von {target} fps · {dropped} verworfen",
+  "app.status.stats.failed": ", {failed} fehlgeschlagene abgriffe",
+  "app.status.stats.choppy": " — eine niedrigere fps oder einen kleineren bereich versuchen",
+  "app.status.ending": "aufnahme endet in {seconds} s — die maximale dauer ist bald erreicht",
+  "app.status.cancelled.one": "{count} wartende speicherung abgebrochen",
+  "app.status.cancelled.other": "{count} wartende speicherungen abgebrochen",
+
+  "app.toast.recording": "aufnahme läuft — zum beenden erneut {hotkey} drücken",
+  "app.toast.recording.same": "aufnahme läuft — zum beenden dasselbe tastenkürzel erneut drücken",
+  "app.toast.dropped":
+    "{count} dateien abgelegt — die ersten {max} werden hochgeladen, den rest danach erneut ablegen",
+  "app.toast.hotkey-bound": "screenshot-tastenkürzel gesetzt",
+  "app.toast.save-failed": "speichern fehlgeschlagen: {error}",
+  "app.toast.cancel-failed": "abbrechen nicht möglich: {error}",
+  "app.toast.keys-failed": "tastenkürzel konnten nicht wieder aktiviert werden: {error}",
+  "app.toast.dnd-failed": "nicht stören konnte nicht ausgeschaltet werden: {error}",
+
+  "app.onboarding.title": "willkommen bei capscr",
+  "app.onboarding.lede": "richten wir zuerst dein screenshot-tastenkürzel ein",
+  "app.onboarding.desc":
+    "nicht jede tastatur hat eine druck-taste. klicke in das feld unten und drücke eine beliebige tastenkombination (z. b. PrintScreen, Ctrl+Alt+S, Alt+Shift+A), um sie zuzuweisen.",
+
+  "app.nav": "ansichten",
+
+  "app.shortcuts.title": "tastenkürzel",
+  "app.shortcuts.hub": "hub",
+  "app.shortcuts.toggle": "diese übersicht ein-/ausblenden",
+  "app.shortcuts.settings": "tab einstellungen",
+  "app.shortcuts.tasks": "tab aufgaben",
+  "app.shortcuts.history": "tab verlauf",
+  "app.shortcuts.destinations": "tab ziele",
+  "app.shortcuts.plugins": "tab plugins",
+  "app.shortcuts.quit": "capscr beenden",
+  "app.shortcuts.esc": "übersicht schließen / hub ausblenden",
+  "app.shortcuts.tab": "zwischen bedienelementen wechseln",
+  "app.shortcuts.sidebar": "in der seitenleiste bewegen",
+  "app.shortcuts.panes": "einstellungsbereiche wechseln",
+  "app.shortcuts.open-item": "fokussierten verlaufseintrag öffnen",
+  "app.shortcuts.editor": "editor",
+  "app.shortcuts.arrow": "pfeil",
+  "app.shortcuts.rect": "rechteck",
+  "app.shortcuts.text": "text",
+  "app.shortcuts.blur": "weichzeichnen",
+  "app.shortcuts.more-tools": "schritt, linie, ellipse, marker, stift",
+  "app.shortcuts.stamp": "stempel",
+  "app.shortcuts.measure": "messen",
+  "app.shortcuts.save": "speichern",
+  "app.shortcuts.copy-upload": "beschriftete aufnahme kopieren / hochladen",
+  "app.shortcuts.close-editor": "editor schließen",
+  "app.shortcuts.undo": "rückgängig",
+  "app.shortcuts.redo": "wiederholen",
+  "app.shortcuts.paste": "bild aus der zwischenablage einfügen",
+  "app.shortcuts.zoom-in-out": "vergrößern / verkleinern",
+  "app.shortcuts.zoom-100": "zoom 100 %",
+  "app.shortcuts.fit": "an fenster anpassen",
+  "app.shortcuts.pan": "verschieben (oder mit mittlerer taste ziehen)",
+  "app.shortcuts.zoom": "zoomen",
+  "app.shortcuts.global": "global (standard — unter aufgaben änderbar)",
+  "app.shortcuts.region-shot": "bereichs-screenshot → speichern + zwischenablage",
+  "app.shortcuts.region-gif": "bereichs-GIF → speichern",
+  "app.shortcuts.foot": "zum schließen {f1} oder {esc} drücken",
+
+  "app.log.title": "letzte aktivität",
+  "app.log.empty": "in dieser sitzung noch nichts",
+
+  "app.tray-missing.title": "kein infobereich gefunden",
+  "app.tray-missing.meta":
+    "üblich unter reinem GNOME. capscr läuft im hintergrund weiter: globale tastenkürzel funktionieren, ein rechtsklick auf capscr in den Aktivitäten zeigt die aufnahmeaktionen, und ein erneuter start von capscr öffnet dieses fenster wieder; {quit} hier beendet es. für ein tray-symbol die AppIndicator-erweiterung installieren.",
+  "app.tray-missing.get": "erweiterung holen",
+  "app.tray-missing.never": "nicht mehr anzeigen",
+  "app.crash.title": "capscr ist abgestürzt",
+  "app.crash.meta":
+    "{when}: {summary}. der bericht enthält einen backtrace und das log davor, für eine fehlermeldung.",
+  "app.recovered.title": "ungespeicherte aufnahme aufbewahrt",
+  "app.recovered.meta":
+    "eine gif-aufnahme ({frames} bilder, {seconds} s) war noch nicht gespeichert, als capscr zuletzt am {when} beendet wurde.",
+  "app.recovered.restore": "wiederherstellen",
+  "app.recovered.discard": "verwerfen",
+  "app.update.title": "update verfügbar · v{version}",
+  "app.update.current": "du nutzt v{version}",
+  "app.update.notes": "neuigkeiten",
+  "app.update.deb-hint": "danach: sudo apt install ./the-downloaded.deb",
+  "app.update.rpm-hint": "danach: sudo dnf install ./the-downloaded.rpm",
+  "app.update.deb": ".deb herunterladen",
+  "app.update.rpm": ".rpm herunterladen",
+  "app.update.release": "release holen",
+  "app.update.installing": "installiert...",
+  "app.update.install": "installieren + neu starten",
+  "app.update.later": "später",
+
+  "app.bar.unsaved": "ungespeichert",
+  "app.bar.enc.cancel": "speichert im hintergrund — klicken, um wartende speicherungen abzubrechen",
+  "app.bar.enc": "speichert im hintergrund",
+  "app.bar.keys": "tastenkürzel sind aus — klicken zum wiedereinschalten",
+  "app.bar.dnd": "nicht stören — tastenkürzel, hinweise und töne sind aus. klicken zum ausschalten",
+  "app.bar.game.suspend":
+    "vollbild-app im fokus — tastenkürzel ohne ctrl, alt oder win gehen an sie",
+  "app.bar.game.wgc": "vollbild-app im fokus — aufnahmen laufen über Windows.Graphics.Capture",
+  "app.bar.recording": "aufnahme {elapsed}",
+
+  "app.drop.title": "zum hochladen ablegen",
+  "app.drop.destination": "ziel: {destination}",
+  "app.toasts": "hinweise",
+  "app.upload.kind": "hochgeladen",
+  "app.upload.copy-delete": "lösch-url kopieren",
+
+  // settings view
+  "settings.title": "einstellungen",
+  "settings.search": "einstellungen durchsuchen...",
+  "settings.no-match": 'keine einstellung passt zu "{query}".',
+  "settings.pane.general": "allgemein",
+  "settings.pane.capture": "aufnahme",
+  "settings.pane.hdr": "hdr",
+  "settings.pane.hotkeys": "tastenkürzel",
+  "settings.pane.ssh": "ssh",
+  "settings.pane.notify": "hinweise",
+  "settings.reset": "zurücksetzen",
+  "settings.reset.title": "alle einstellungen auf standard zurücksetzen",
+  "settings.reset.confirm":
+    "Alle Einstellungen durch die Standardwerte ersetzen? Aufgaben, Tastenkürzel und Ziele werden ebenfalls zurückgesetzt.",
+  "settings.save": "speichern",
+  "settings.saving": "speichert...",
+  "settings.status.loading-defaults": "lädt standardwerte...",
+  "settings.status.defaults-loaded": "geladen — zum übernehmen speichern.",
+  "settings.language": "sprache",
+  "settings.language.hint": "wirkt sofort, auch im tray-menü und in benachrichtigungen",
+  "settings.language.system": "systemsprache",
+
+  "settings.section.output": "ausgabe",
+  "settings.section.per-app": "pro app",
+  "settings.section.decorations": "export-verzierungen",
+  "settings.section.appearance": "darstellung",
+  "settings.section.gnome": "gnome-integration",
+  "settings.section.cursor": "mauszeiger",
+  "settings.section.overlay": "auswahl-overlay",
+  "settings.section.after": "nach der aufnahme",
+  "settings.section.window": "fensteraufnahme",
+  "settings.section.timing": "zeitpunkt",
+  "settings.section.game": "spielmodus",
+  "settings.section.recording": "aufnahme (gif + mp4)",
+  "settings.section.auto": "automatische aufnahme",
+  "settings.section.watch": "überwachter ordner",
+  "settings.section.hdr-sidecar": "hdr-begleitdatei",
+  "settings.section.hdr-tonemap": "hdr-tonemapping",
+  "settings.section.global": "global",
+  "settings.section.advanced-input": "erweiterte eingabe",
+  "settings.section.bindings": "status je belegung",
+  "settings.section.hook": "ll-hook-telemetrie (debug)",
+  "settings.section.sftp": "bekannte sftp-hosts",
+  "settings.section.feedback": "rückmeldung",
+  "settings.section.system": "system",
+  "settings.section.logs": "logs",
+
+  "settings.field.directory": "verzeichnis",
+  "settings.field.filename": "dateinamenvorlage",
+  "settings.field.format": "format",
+  "settings.field.quality": "qualität",
+  "settings.field.editor-projects": "editor-projekte",
+  "settings.field.archive": "originale archivieren",
+  "settings.field.scratch": "arbeitsordner",
+  "settings.field.scratch-cap": "arbeitsordner-limit",
+  "settings.field.retention": "aufbewahrung",
+  "settings.field.metadata": "metadaten",
+  "settings.field.tags": "tags in dateien",
+  "settings.field.profiles": "app-profile",
+  "settings.field.border": "rahmen",
+  "settings.field.corners": "abgerundete ecken",
+  "settings.field.caption": "beschriftung",
+  "settings.field.theme": "design",
+  "settings.field.accent": "akzent",
+  "settings.field.scale": "ui-skalierung",
+  "settings.field.compact": "kompaktes layout",
+  "settings.field.companion": "begleiterweiterung",
+  "settings.field.cursor": "mauszeiger zeigen",
+  "settings.field.monitors": "monitore",
+  "settings.field.actions": "standardaktionen",
+  "settings.field.duplicates": "duplikate",
+  "settings.field.frame": "rahmen",
+  "settings.field.covered": "verdeckte fenster",
+  "settings.field.backend": "aufnahme-backend",
+  "settings.field.wgc-border": "aufnahmerahmen",
+  "settings.field.ui-elements": "ui-elemente",
+  "settings.field.delay": "verzögerung vor der aufnahme",
+  "settings.field.hide-hub": "hub ausblenden",
+  "settings.field.excluded": "ausgeschlossene apps",
+  "settings.field.exclusion-mode": "wenn eine im bild ist",
+  "settings.field.fullscreen": "vollbild-apps",
+  "settings.field.gif-fps": "gif-bildrate",
+  "settings.field.video-fps": "video-bildrate",
+  "settings.field.video-quality": "videoqualität",
+  "settings.field.max-duration": "maximale dauer",
+  "settings.field.audio": "ton aufnehmen",
+  "settings.field.review": "prüfen",
+  "settings.field.zoom-cursor": "zum mauszeiger zoomen",
+  "settings.field.zoom": "zoom",
+  "settings.field.smoothing": "glättung",
+  "settings.field.stream": "livestream",
+  "settings.field.bitrate": "stream-bitrate",
+  "settings.field.status": "status",
+  "settings.field.interval": "intervall",
+  "settings.field.capture": "aufnehmen",
+  "settings.field.folder": "ordner",
+  "settings.field.keep": "höchstens behalten",
+  "settings.field.quota": "speicherkontingent",
+  "settings.field.lapse": "zeitraffer",
+  "settings.field.watch": "überwachen",
+  "settings.field.then": "danach",
+  "settings.field.container": "container",
+  "settings.field.output-format": "ausgabeformat",
+  "settings.field.operator": "operator",
+  "settings.field.sdr-white": "sdr-weiß überschreiben",
+  "settings.field.pre-scale": "skalierung vor dem tonemapping",
+  "settings.field.p99": "p99 maxcll",
+  "settings.field.preserve-hdr": "hdr erhalten",
+  "settings.field.all-hotkeys": "alle tastenkürzel",
+  "settings.field.stop-recording": "aufnahme beenden",
+  "settings.field.dnd": "nicht stören",
+  "settings.field.hotkey-backend": "backend",
+  "settings.field.evdev": "rohe eingabe (evdev)",
+  "settings.field.notifications": "systembenachrichtigungen",
+  "settings.field.sound": "tonsignal",
+  "settings.field.open-after": "nach dem speichern öffnen",
+  "settings.field.autostart": "bei anmeldung starten",
+  "settings.field.context-menu": "kontextmenü",
+  "settings.field.minimized": "minimiert starten",
+  "settings.field.close": "verhalten der schließen-taste",
+  "settings.field.clipboard-history": "zwischenablage-verlauf",
+  "settings.field.updates": "nach updates suchen",
+  "settings.field.log": "logdatei",
+
+  "settings.browse.title": "ordner auswählen",
+  "settings.pick.output": "Ausgabeverzeichnis wählen",
+  "settings.pick.scratch": "Arbeitsordner wählen",
+  "settings.pick.archive": "Archivordner wählen",
+  "settings.pick.auto": "Ordner für automatische Aufnahmen wählen",
+  "settings.pick.watch": "Zu überwachenden Ordner wählen",
+
+  "settings.hint.directory": "absoluter pfad oder %env%-vorlage",
+  "settings.hint.filename":
+    "chrono-platzhalter: %Y jahr · %m monat · %d tag · %H stunde · %M minute · %S sekunde · endung wird automatisch ergänzt",
+  "settings.hint.quality": "1-100, bei png/bmp ignoriert",
+  "settings.check.editor-projects": "anmerkungen nach dem speichern bearbeitbar halten",
+  "settings.hint.editor-projects":
+    "schreibt neben jede aus dem editor gespeicherte aufnahme eine .capscr.json mit einer vollständigen kopie; beim erneuten öffnen kommt das unbearbeitete bild mit allen verschiebbaren anmerkungen zurück",
+  "settings.check.archive": "jede aufnahme zusätzlich unverändert als verlustfreies png behalten",
+  "settings.placeholder.archive": "originals/ im ausgabeverzeichnis",
+  "settings.hint.archive":
+    "gespeichert, bevor plugins, verzierungen, verkleinerung oder ein verlustbehaftetes format es ändern, zum späteren nachbearbeiten; ein absoluter pfad",
+  "settings.placeholder.scratch": "capscr/ im temp-ordner des systems",
+  "settings.hint.scratch":
+    "aufnahme-frames und ton, mail-anhänge; ein absoluter pfad, am besten auf einem laufwerk mit platz für lange aufnahmen",
+  "settings.hint.scratch-cap":
+    "MiB an resten früherer läufe, die beim start bleiben, älteste zuerst gelöscht; 0 löscht alles",
+  "settings.retention.files-kept": "behaltene dateien",
+  "settings.retention.mib-kept": "behaltene MiB",
+  "settings.retention.files": "dateien",
+  "settings.retention.move-to": "alte aufnahmen verschieben nach",
+  "settings.hint.retention":
+    "enthält der ausgabeordner mehr, werden die ältesten aufnahmen gelöscht oder in den angegebenen ordner verschoben (relativ zum ausgabeordner); 0 heißt kein limit · alle 10 minuten geprüft",
+  "settings.metadata.keep": "unverändert lassen",
+  "settings.metadata.strip": "alles entfernen",
+  "settings.metadata.embed": "aufnahme-infos einbetten",
+  "settings.hint.metadata":
+    "nur png und jpeg · entfernen gilt auch für editor-speicherungen · einbetten schreibt aufnahmezeit, monitor, fenstertitel und capscr-version als png-text / exif",
+  "settings.check.tags": "tags und notizen auch ins png schreiben",
+  "settings.hint.tags":
+    "tags und notizen liegen immer im verlaufsindex von capscr; das fügt sie zusätzlich als png-text Keywords / Comment hinzu, damit andere programme sie lesen können",
+  "settings.hint.resize":
+    "lanczos, vergrößert nie · gilt für per tastenkürzel gespeicherte aufnahmen, nicht für editor-speicherungen; uploads haben eine eigene einstellung unter ziele",
+
+  "settings.profiles.format": "format von oben",
+  "settings.profiles.action": "aktion der aufgabe",
+  "settings.profiles.subfolder": "unterordner",
+  "settings.profiles.files": "im unterordner behaltene dateien, 0 für kein limit",
+  "settings.profiles.mib": "im unterordner behaltene MiB, 0 für kein limit",
+  "settings.profiles.add": "app hinzufügen",
+  "settings.hint.profiles":
+    "fensteraufnahmen eines gelisteten prozesses werden in dessen format in einem ordner unter dem ausgabeverzeichnis gespeichert und gehen an dessen aktion statt an die der aufgabe · ein unterordner kann eigene datei- und MiB-limits haben",
+
+  "settings.deco.border-color": "rahmenfarbe",
+  "settings.hint.border": "pixel, 0 für keinen",
+  "settings.deco.transparent": "transparent",
+  "settings.deco.corner-background": "eckenhintergrund",
+  "settings.hint.corners":
+    "radius in pixeln · transparente ecken brauchen png, webp oder avif, andere formate zeigen sie schwarz",
+  "settings.hint.caption":
+    "text in einer leiste über der aufnahme, dieselben %-platzhalter wie die dateinamenvorlage · gilt, wenn eine aufnahme direkt gespeichert, kopiert oder hochgeladen wird, nicht bei übergabe an den editor",
+
+  "settings.hint.theme": "systemeinstellung folgt dem dunklen / hellen modus des betriebssystems",
+  "settings.accent.custom": "eigene",
+  "settings.accent.custom-label": "eigene akzentfarbe",
+  "settings.hint.accent": "färbt aktive tabs, häkchen, fokusrahmen und hauptschaltflächen",
+  "settings.hint.scale":
+    "vergrößert den hub zusätzlich zur anzeigeskalierung des systems; gilt nach dem speichern",
+  "settings.check.compact": "seitenleiste nur mit tasten und engere zeilen",
+  "settings.hint.compact": "lässt den hub in ein kleineres fenster schrumpfen",
+
+  "settings.gnome.active":
+    "aktiv — fensterauswahl, angeheftete bilder über vollbild und das aufnahmemenü in der oberen leiste übernimmt die shell-erweiterung",
+  "settings.gnome.installed": "installiert — zum aktivieren ab- und wieder anmelden",
+  "settings.gnome.installing": "installiert…",
+  "settings.gnome.install": "installieren",
+  "settings.gnome.missing":
+    "ohne sie nutzt die fensteraufnahme den auswahldialog von gnome, und angeheftete screenshots bleiben nicht über vollbildfenstern",
+
+  "settings.kind.region": "bereich",
+  "settings.kind.window": "fenster",
+  "settings.kind.full-screen": "vollbild",
+  "settings.kind.gif": "aufnahme",
+  "settings.cursor.captured": "mit aufgenommen",
+  "settings.cursor.hidden": "ausgeblendet",
+  "settings.hint.cursor": "gilt für screenshots und aufnahmen",
+  "settings.monitors.pointer": "der unter dem mauszeiger",
+  "settings.monitors.all": "alle",
+  "settings.hint.monitors":
+    "bereichs- und fensterauswahl öffnen auf dem monitor mit dem mauszeiger, statt den ganzen desktop abzudunkeln; ab drei bildschirmen schneller",
+  "settings.hint.actions":
+    "für aufnahmen aus dem tray-menü und der sprungliste; aufgaben mit tastenkürzel behalten ihre eigene aktion",
+  "settings.duplicates.off": "behalten, ohne hinweis",
+  "settings.duplicates.warn": "behalten, mit hinweis",
+  "settings.duplicates.skip": "überspringen",
+  "settings.hint.duplicates":
+    "eine aufnahme, die pixelgleich mit der vorherigen ist und innerhalb einer minute entstand — meist ein doppelt gedrücktes tastenkürzel",
+  "settings.frame.visible": "fensterrahmen — ohne schatten",
+  "settings.frame.shadow": "rahmen + schlagschatten",
+  "settings.frame.client": "nur clientbereich",
+  "settings.hint.frame": "was eine fensterauswahl um den fensterinhalt herum behält",
+  "settings.corners.left": "wie aufgenommen",
+  "settings.hint.window-corners":
+    "windows 11 — entfernt die schwarzen keile außerhalb der abgerundeten fensterecken (transparenz braucht png/webp/avif)",
+  "settings.covered.own": "eigener inhalt des fensters",
+  "settings.covered.screen": "was auf dem bildschirm ist",
+  "settings.hint.covered":
+    "windows — rendert das gewählte fenster selbst, sodass darüberliegende fenster oder der bildschirmrand nichts abschneiden. hdr-fenster und der schlagschatten kommen weiter vom bildschirm",
+  "settings.backend.auto": "automatisch — gdi, cpu-tonemapping für hdr",
+  "settings.hint.backend":
+    'windows — wgc sieht auch hardwarebeschleunigte und manche geschützte inhalte, die gdi schwarz liefert. fällt bei fehlern auf gdi zurück. der mauszeiger folgt weiter "mauszeiger zeigen"',
+  "settings.wgc-border.shown": "sichtbar",
+  "settings.wgc-border.suppressed": "unterdrückt",
+  "settings.hint.wgc-border":
+    "der gelbe rahmen, den windows während einer aufnahme zeichnet. ihn zu unterdrücken braucht windows 11",
+  "settings.ui-elements.on": "bedienelemente erkennen",
+  "settings.ui-elements.off": "nur fenster",
+  "settings.hint.ui-elements":
+    "ctrl beim überfahren in der auswahl halten, um einzelne schaltflächen, bereiche und symbolleisten hervorzuheben; ein klick nimmt nur dieses element auf",
+  "settings.hint.delay":
+    "ms vor dem abgreifen der pixel — nützlich für tooltips / menüs (0 = sofort)",
+  "settings.hide-hub.on": "während der aufnahme",
+  "settings.hide-hub.off": "bleibt offen",
+  "settings.hint.hide-hub":
+    "blendet dieses fenster während screenshots und aufnahmen aus und zeigt es danach wieder",
+  "settings.hint.excluded":
+    "prozessnamen, durch kommas getrennt. ihre fenster bleiben aus screenshots und aufnahmen heraus — das ganze fenster, auch wo etwas darüberliegt",
+  "settings.exclusion.blackout": "ihre fenster schwärzen",
+  "settings.exclusion.skip": "aufnahme überspringen",
+  "settings.hint.exclusion":
+    "aufnahmen schwärzen die fenster pro frame oder verwerfen frames, solange eines sichtbar ist. ein ausgeschlossenes fenster direkt zu wählen schlägt immer fehl",
+
+  "settings.game.off": "nicht darauf achten",
+  "settings.game.none": "alles so lassen",
+  "settings.game.suspend": "ihnen die einfachen tastenkürzel überlassen",
+  "settings.game.wgc": "über WGC aufnehmen",
+  "settings.hint.game":
+    "was sich ändert, solange ein fenster über den ganzen monitor (meist ein spiel) den fokus hat. einfache tastenkürzel sind solche ohne ctrl, alt oder win — F9, PrintScreen, maustasten —, die das spiel wahrscheinlich auch belegt; kombinationen bleiben aktiv. wird alle paar sekunden geprüft",
+  "settings.game.rule.none": "so lassen",
+  "settings.game.rule.suspend": "einfache tastenkürzel aus",
+  "settings.hint.game-apps":
+    "prozessnamen, die die auswahl oben überschreiben, z. b. ein videoplayer im vollbild",
+
+  "settings.hint.gif-fps": "fps, 1-60",
+  "settings.hint.video-fps": "fps für mp4-aufnahmen, 1-60",
+  "settings.video.high": "hoch — nahezu verlustfrei, größte dateien",
+  "settings.video.balanced": "ausgewogen",
+  "settings.video.compact": "kompakt — kleinste dateien",
+  "settings.hint.video-quality": "mp4-kodierqualität; gifs sind nicht betroffen",
+  "settings.hint.max-duration":
+    "sekunden, 1-300 — die aufnahme stoppt und speichert dann automatisch, mit einer warnung 10 s vorher",
+  "settings.toggle.enabled": "aktiviert",
+  "settings.toggle.disabled": "deaktiviert",
+  "settings.hint.audio": "nimmt den systemton (loopback) für MP4-aufnahmen auf",
+  "settings.check.review": "vor dem speichern zuschneiden",
+  "settings.hint.review":
+    "das beenden einer aufnahme öffnet eine vorschau mit start- und endpunkt; nur der behaltene bereich wird kodiert und gespeichert",
+  "settings.check.zoom": "dem mauszeiger folgen, während er sich bewegt",
+  "settings.hint.zoom-cursor":
+    "bereichsaufnahmen zoomen sanft auf den mauszeiger und wieder heraus, wenn er kurz ruht",
+  "settings.hint.zoom": "%, 125-400 — 200 zeigt ein viertel des bereichs",
+  "settings.hint.smoothing": "ms, 0-2000 — wie langsam die kamera nachzieht; 0 springt sofort",
+  "settings.stream.saved": "•••••••••••••••• (gespeichert)",
+  "settings.hint.stream":
+    "experimentell — wohin bereichs-stream-aufgaben senden, z. b. rtmp://127.0.0.1/live/key für einen lokalen empfang. enthält meist deinen stream-schlüssel und wird daher verschlüsselt gespeichert. nur video",
+  "settings.hint.bitrate": "kbps, 500-50000 — die bildrate folgt der video-bildrate oben",
+
+  "settings.auto.saved": "gespeichert: {path}",
+  "settings.auto.stop": "stoppen",
+  "settings.auto.start": "starten",
+  "settings.auto.capturing": "nimmt auf",
+  "settings.auto.stopped": "gestoppt",
+  "settings.hint.auto-status":
+    "screenshots im takt, für zeiterfassung und fortschritts-zeitraffer; auch im aufnahmemenü des trays. ausstehende änderungen vor dem start speichern",
+  "settings.hint.interval": "sekunden zwischen aufnahmen, 5-86400",
+  "settings.auto.active": "aktiver monitor",
+  "settings.auto.all": "alle monitore",
+  "settings.placeholder.auto": "auto/ im ausgabeverzeichnis",
+  "settings.hint.keep": "aufnahmen; darüber werden die ältesten gelöscht (0 = unbegrenzt)",
+  "settings.hint.quota": "MiB; darüber werden die ältesten aufnahmen gelöscht (0 = unbegrenzt)",
+  "settings.lapse.fps": "wiedergabe-fps",
+  "settings.lapse.width": "maximale breite in px (0 = original)",
+  "settings.lapse.assembling": "erstellt...",
+  "settings.lapse.assemble": "erstellen",
+  "settings.hint.lapse":
+    "spielt den ordner der automatischen aufnahmen als video ab — format, wiedergabe-fps (gif maximal 50), maximale breite px (0 = original); im ausgabeverzeichnis gespeichert",
+
+  "settings.check.watch": "neue bilder in einem ordner durch capscr schicken",
+  "settings.hint.watch":
+    "für screenshots, die ein anderes programm macht, etwa die screenshot-taste eines spiels; bilder, die schon im ordner liegen, bleiben unberührt",
+  "settings.placeholder.watch": "zu überwachender ordner",
+  "settings.watch.upload": "hochladen",
+  "settings.watch.clipboard": "in die zwischenablage kopieren",
+  "settings.watch.save": "im ausgabeordner speichern",
+  "settings.watch.save-copy": "speichern und kopieren",
+  "settings.hint.watch-action":
+    "export-verzierungen gelten wie bei einer aufnahme; uploads gehen an das standardziel und kopieren den link, wenn das an ist",
+
+  "settings.hdr.png": "png (16 bit, verlustfrei — standard)",
+  "settings.hdr.avif": "avif (10 bit av1)",
+  "settings.hdr.jxr": "jpeg xr (scrgb, windows)",
+  "settings.hint.container":
+    "avif wird mit dem ffmpeg der aufnahme kodiert und nutzt die ausgabequalität. jpeg xr ist lineares scrgb, die transferfunktion unten gilt daher nicht.",
+  "settings.hdr.pq": "pq (bt.2020, wie hdr10 — standard)",
+  "settings.hdr.hlg": "hlg (bt.2020, wie im rundfunk)",
+  "settings.hint.output-format":
+    "pq ist am sichersten für windows fotos / edge. hlg wird aus der pq-quelle umgerechnet, indem in nits dekodiert und dann die hlg-oetf angewendet wird.",
+  "settings.tonemap.bt2390": "weicher lichterabfall (standard)",
+  "settings.tonemap.reinhard": "reinhard erweitert",
+  "settings.tonemap.aces": "aces filmisch",
+  "settings.tonemap.hable": "hable filmisch",
+  "settings.tonemap.exposure": "belichtung (abschneiden)",
+  "settings.hint.operator":
+    "der standard hält sdr-inhalte pixelgenau. die filmischen kurven erhöhen den kontrast und dunkeln sdr leicht ab; belichtung schneidet nur die lichter ab.",
+  "settings.hint.sdr-white":
+    "manuelles sdr-weiß in nits. 0 = automatisch vom sdr-helligkeitsregler des systems (empfohlen)",
+  "settings.hint.pre-scale":
+    "belichtung: luminanz vor dem operator multiplizieren (1.0 = unverändert)",
+  "settings.p99.on": "p99 (himmel / lichter ignoriert)",
+  "settings.p99.off": "reines maximum, jede spitze zählt",
+  "settings.preserve.on": "schreibt neben jede hdr-aufnahme eine .hdr.{container}-begleitdatei",
+  "settings.preserve.off": "nur tonemapping auf sdr — keine hdr-begleitdatei",
+  "settings.hint.preserve":
+    "quellformat ist HDR10 (der übliche fall). transfer = das oben gewählte ausgabeformat. nur vollbild- / aktiver-monitor-aufnahmen.",
+
+  "settings.keys.enable": "wieder aktivieren",
+  "settings.keys.disable": "alle deaktivieren",
+  "settings.hint.all-hotkeys":
+    "notschalter, auch im tray-menü umschaltbar. bleibt nach einem neustart erhalten.",
+  "settings.hint.stop-recording":
+    "beendet die laufende aufnahme. das eigene tastenkürzel einer aufnahmeaufgabe stoppt sie weiterhin; ein tastenkürzel, das eine aufgabe schon nutzt, ist hier nicht möglich.",
+  "settings.dnd.minutes": "minuten nicht stören",
+  "settings.hint.dnd":
+    "schaltet alle anderen tastenkürzel, hinweise und töne ab, z. b. bei präsentationen oder beim spielen. minuten, bis es sich selbst wieder ausschaltet; 0 wartet auf das tastenkürzel oder das tray.",
+  "settings.rebind.title":
+    "die kürzel-freigabe des desktops erneut öffnen, falls sie geschlossen wurde",
+  "settings.rebind": "mit dem desktop neu binden",
+  "settings.hint.rebind":
+    "der desktop registriert diese belegungen und kann sie ändern; die spalte „wirksam“ unten zeigt, was jede aufgabe tatsächlich auslöst.",
+  "settings.hint.evdev":
+    "liest /dev/input direkt: nötig für maustasten-kürzel und für tastaturen auf desktops ohne das GlobalShortcuts-portal. sieht alle eingabegeräte, solange capscr läuft.",
+  "settings.evdev.none":
+    "keine lesbaren eingabegeräte — dich zur gruppe input hinzufügen ({command}), neu anmelden, dann",
+  "settings.evdev.recheck": "erneut prüfen",
+  "settings.bindings.none": "keine aufgaben angelegt.",
+  "settings.bindings.task": "aufgabe",
+  "settings.bindings.hotkey": "tastenkürzel",
+  "settings.bindings.effective": "wirksam",
+  "settings.bindings.reason": "grund",
+  "settings.bindings.unbound": "nicht belegt",
+  "settings.bindings.unknown": "unbekannt",
+  "settings.hook.lede":
+    "live-zähler des low-level-tastatur-hooks. eine taste drücken und auf aktualisieren klicken. steigt {counter} nicht, ist der hook nicht installiert (oder ein anderes programm blockiert ihn).",
+  "settings.hook.missing":
+    "hook-telemetrie fehlt in der antwort (backend ohne das feld — alter build läuft noch oder kein windows). diag-daten: {payload}",
+  "settings.hook.installed": "hook installiert",
+  "settings.hook.bindings": "belegungen in der hook-tabelle",
+  "settings.hook.calls": "callback-aufrufe gesamt",
+  "settings.hook.keydown": "keydown-aufrufe",
+  "settings.hook.matched": "belegung getroffen",
+  "settings.hook.dispatched": "an worker übergeben",
+  "settings.hook.dropped": "übergabe verworfen (warteschlange voll)",
+  "settings.hook.last": "zuletzt gesehen (vk + mods)",
+  "settings.hook.mods": "(mods: bit0=Ctrl bit1=Alt bit2=Shift bit3=Win)",
+  "settings.hook.registered": "in die hook-tabelle eingetragene kombinationen:",
+  "settings.hook.task": "aufgaben-id",
+
+  "settings.sftp.known-hosts": "bekannte hosts",
+  "settings.sftp.lede":
+    "capscr merkt sich beim ersten verbinden den fingerabdruck des öffentlichen schlüssels jedes sftp-servers und verweigert später bei abweichung den upload. einen host vergessen, um einem neuen schlüssel wieder zu vertrauen.",
+  "settings.sftp.empty":
+    "noch keine vertrauenswürdigen hosts. per sftp hochladen, um diese liste zu füllen.",
+  "settings.sftp.host": "host:port",
+  "settings.sftp.fingerprint": "fingerabdruck",
+  "settings.sftp.first-seen": "zuerst gesehen",
+  "settings.sftp.forget": "vergessen",
+
+  "settings.notify.silent": "stumm",
+  "settings.sound.on": "tonsignal bei aufnahme / upload",
+  "settings.open-after.on": "gespeicherte aufnahmen im standardbetrachter öffnen",
+  "settings.open-after.off": "nur speichern",
+  "settings.autostart.on": "startet automatisch bei der anmeldung",
+  "settings.autostart.off": "nur manueller start",
+  "settings.hint.autostart":
+    "capscr bei der anmeldung automatisch starten (gilt beim nächsten speichern)",
+  "settings.check.context-menu": "„Mit capscr beschriften“ bei bilddateien",
+  "settings.hint.context-menu":
+    "im rechtsklick-menü des dateimanagers (Öffnen mit unter Linux); öffnet eine kopie des bildes im editor, gespeichert neben deinen aufnahmen (gilt beim nächsten speichern)",
+  "settings.minimized.on": "nur im tray",
+  "settings.minimized.off": "dieses fenster beim start öffnen",
+  "settings.hint.minimized":
+    "capscr wartet im tray auf tastenkürzel; auf das tray-symbol klicken, um dieses fenster zu öffnen",
+  "settings.close.tray": "in den infobereich minimieren",
+  "settings.close.taskbar": "in die taskleiste minimieren",
+  "settings.close.exit": "anwendung beenden",
+  "settings.clipboard-history.on":
+    "speichert reine zwischenablage-aufnahmen im lokalen verlaufs-cache",
+  "settings.clipboard-history.off": "reine zwischenablage-aufnahmen landen nicht im verlauf",
+  "settings.updates.on": "fragt GitHub-releases 4 s nach dem öffnen des hubs ab",
+  "settings.updates.off": "kein netzwerkzugriff, updates musst du selbst holen",
+  "settings.logs.hide": "ausblenden",
+  "settings.logs.view": "logs ansehen",
+  "settings.logs.reveal": "capscr.log im dateimanager zeigen",
+  "settings.logs.open": "log-ordner öffnen",
+  "settings.hint.log":
+    "capscr.log im konfigurationsordner, ab 4 MB neu begonnen, die drei vorherigen bleiben erhalten; beim melden einer fehlgeschlagenen aufnahme oder eines uploads anhängen",
+  "settings.logs.recent": "letzte logzeilen",
+  "settings.logs.empty": "noch nichts protokolliert",
+
+  "settings.theme.dark": "dunkel",
+  "settings.theme.light": "hell",
+  "settings.theme.system": "systemeinstellung",
+  "settings.accent.mono": "mono",
+  "settings.accent.amber": "bernstein",
+  "settings.accent.phosphor": "phosphor",
+  "settings.accent.ice": "eis",
+  "settings.accent.rose": "rosé",
+
+  // shared components
+  "common.saving": "speichert…",
+  "common.clear": "leeren",
+  "common.refresh": "aktualisieren",
+
+  "resize.label": "verkleinern",
+  "resize.max-dimension": "längste seite auf",
+  "resize.percent": "skalieren auf",
+  "resize.max-dimension.aria": "maximale kantenlänge in pixeln",
+  "resize.percent.aria": "skalierung in prozent",
+
+  "hotkey.bare":
+    "{hotkey} würde diese taste allen apps wegnehmen — nimm eine zusatztaste dazu (Ctrl / Alt / Shift / {meta}).",
+  "hotkey.bind": "zum belegen klicken…",
+  "hotkey.press": "taste drücken… (esc bricht ab)",
+  "hotkey.clear": "tastenkürzel leeren",
+
+  "color.current": "farbe {color}",
+  "color.pick": "farbe wählen",
+  "color.dialog": "farbe",
+  "color.hue": "farbton",
+  "color.hex": "hex-farbe",
+  "color.presets": "vorgaben",
+  "color.recent": "zuletzt verwendet",
+
+  "compare.dialog": "aufnahmen vergleichen",
+  "compare.title": "vergleichen",
+  "compare.slider": "schieber",
+  "compare.diff": "differenz",
+  "compare.wipe": "trennlinie",
+  "compare.diff-image": "pixeldifferenz",
+  "compare.tolerance": "toleranz",
+  "compare.tolerance.label": "toleranz {value}",
+  "compare.comparing": "vergleicht…",
+  "compare.summary": "{count} px geändert ({share} %) · {width}×{height}",
+
+  "review.title": "aufnahme prüfen",
+  "review.frame": "aufnahmebild",
+  "review.scrub": "spulen",
+  "review.discard.confirm": "Diese Aufnahme verwerfen? Sie wurde noch nicht gespeichert.",
+  "review.at": "bei {time}",
+  "review.in": "ein {time}",
+  "review.out": "aus {time}",
+  "review.keeping": "behält {length} von {total}",
+  "review.whole": "ganze aufnahme, {total}",
+  "review.discard": "verwerfen",
+  "review.save-trimmed": "gekürzt speichern",
+  "trim.playhead": "abspielposition",
+
+  "tags.title": "tags und notiz",
+  "tags.tags": "tags",
+  "tags.placeholder": "bug, login-seite",
+  "tags.note": "notiz",
+
+  "trim.title": "aufnahme kürzen",
+  "trim.start": "anfang {time}",
+  "trim.end": "ende {time}",
+  "trim.fast": "schnell (verlustfrei, anfang rastet am keyframe ein)",
+  "trim.length": "länge {time}",
+  "trim.exporting": "exportiert…",
+  "trim.export": "kürzung exportieren",
+  "trim.done": "gekürzt → {name}",
+
+  "windows.filter": "nach titel oder app filtern...",
+  "windows.unknown-app": "(unbekannte app)",
+  "windows.listing": "listet fenster...",
+  "windows.no-match": "kein fenster passt zum filter",
+  "windows.none": "keine fenster zum auflisten — unter wayland den titel eintippen",
+
+  "titlebar.minimize": "minimieren",
+  "titlebar.restore": "wiederherstellen",
+  "titlebar.maximize": "maximieren",
+  "common.reload": "neu laden",
+
+  // tasks view
+  "tasks.mode.region": "bereich (rechteck ziehen)",
+  "tasks.mode.region-last": "bereich (letzter — ohne ziehen)",
+  "tasks.mode.active-window": "fenster (fokussiert — ohne auswahl)",
+  "tasks.mode.window": "fenster (auswählen)",
+  "tasks.mode.target-window": "fenster (nach titel / prozess)",
+  "tasks.mode.fullscreen": "vollbild (hauptbildschirm)",
+  "tasks.mode.active-monitor": "aktiver bildschirm",
+  "tasks.mode.region-gif": "bereich gif",
+  "tasks.mode.region-mp4": "bereich mp4 (video)",
+  "tasks.mode.region-stream": "bereich streamen (rtmp, experimentell)",
+
+  "tasks.action.clipboard": "nur zwischenablage",
+  "tasks.action.save-file": "im ausgabeordner speichern",
+  "tasks.action.save-and-clipboard": "speichern + zwischenablage",
+  "tasks.action.upload": "hochladen",
+  "tasks.action.open-editor": "im editor öffnen",
+  "tasks.action.copy-text": "erkannten text kopieren (ocr)",
+  "tasks.action.prompt": "nachfragen",
+  "tasks.action.do-nothing": "nichts tun",
+
+  "tasks.gesture.press": "drücken",
+  "tasks.gesture.double-press": "doppelt drücken",
+  "tasks.gesture.long-press": "lang drücken (½ s halten)",
+
+  "tasks.size.free": "frei (bereich ziehen)",
+  "tasks.size.720p": "1280×720",
+  "tasks.size.1080p": "1920×1080",
+  "tasks.size.custom": "eigene",
+
+  "tasks.status.duplicate":
+    "doppeltes tastenkürzel: {hotkeys} — aufgaben mit derselben kombination brauchen verschiedene gesten",
+  "tasks.status.saving": "speichert...",
+  "tasks.status.live.one": "{count} aufgabe aktiv.",
+  "tasks.status.live.other": "{count} aufgaben aktiv.",
+  "tasks.status.fire-error": "auslösen: {error}",
+
+  "tasks.new-name": "neue aufgabe",
+  "tasks.lede": "ein tastenkürzel, eine aufnahme, eine folgeaktion.",
+  "tasks.new": "neu",
+  "tasks.empty": "keine aufgaben",
+  "tasks.empty.hint": "{new} drücken, ein tastenkürzel vergeben, speichern.",
+  "tasks.unbound": "nicht belegt",
+  "tasks.registered": "beim tastatur-hook registriert",
+  "tasks.live": "aktiv",
+  "tasks.rejected": "abgelehnt",
+  "tasks.required": "erforderlich",
+
+  "tasks.field.name": "name",
+  "tasks.field.hotkey": "tastenkürzel",
+  "tasks.hint.hotkey": "klicken, kombination drücken",
+  "tasks.field.gesture": "geste",
+  "tasks.hint.gesture":
+    "ein einfacher druck auf eine taste mit weiteren gesten löst aus, sobald er keine davon mehr werden kann",
+  "tasks.field.mode": "aufnahmemodus",
+  "tasks.pick-window": "aus offenen fenstern wählen",
+  "tasks.field.window-title": "fenstertitel",
+  "tasks.hint.window-title":
+    "regex, ohne groß-/kleinschreibung — der oberste treffer wird aufgenommen",
+  "tasks.field.process": "prozess",
+  "tasks.hint.process": "optionaler regex auf den app- / prozessnamen",
+  "tasks.field.post-action": "folgeaktion",
+  "tasks.field.target": "ziel",
+  "tasks.field.preset": "vorlage",
+  "tasks.preset.default": "standard",
+  "tasks.field.follow": "fenster folgen",
+  "tasks.check.follow": "dem fokussierten fenster folgen",
+  "tasks.hint.follow":
+    "überspringt die auswahl; die aufnahme folgt dem jeweils fokussierten fenster, skaliert auf die größe des ersten",
+  "tasks.field.hold": "halten zum aufnehmen",
+  "tasks.check.hold": "aufnehmen, solange das tastenkürzel gehalten wird",
+  "tasks.hint.hold":
+    "loslassen stoppt und speichert; nimmt den zuletzt gewählten bereich auf oder öffnet beim ersten mal die auswahl",
+  "tasks.field.size": "rahmengröße",
+  "tasks.hint.size":
+    "ein fester rahmen folgt dem zeiger; klicken oder Enter drücken, um dort die aufnahme zu starten",
+  "tasks.field.delay": "verzögerung",
+  "tasks.delay.global": "global",
+  "tasks.hint.delay": "ms vor der aufnahme — leer nimmt die globale verzögerung",
+
+  "tasks.fire.title": "diese aufgabe jetzt ausführen (wie beim drücken ihres tastenkürzels)",
+  "tasks.fire": "auslösen",
+  "tasks.delete.title": "diese aufgabe löschen",
+  "common.confirm.title": "zum bestätigen erneut klicken",
+  "common.confirm": "sicher?",
+
+  // history view
+  "history.filter.all": "alle",
+  "history.filter.images": "bilder",
+  "history.filter.gifs": "gifs",
+  "history.filter.videos": "videos",
+  "history.filter.hdr": "hdr",
+  "history.your-key": "deine screenshot-taste",
+
+  "history.flash.uploading": "lädt hoch...",
+  "history.flash.reuploaded": "erneut hochgeladen",
+  "history.flash.upload-failed": "upload fehlgeschlagen: {error}",
+  "history.flash.open-image-failed": "bild konnte nicht geöffnet werden: {error}",
+  "history.flash.converting": "konvertiert {done}/{total}...",
+  "history.flash.converted.one": "{count} bild konvertiert",
+  "history.flash.converted.other": "{count} bilder konvertiert",
+  "history.flash.convert-failed": "stapelkonvertierung fehlgeschlagen: {error}",
+  "history.flash.uploads-failed": "{failed} von {total} uploads fehlgeschlagen",
+  "history.flash.uploaded": "{count} hochgeladen",
+  "history.flash.batch-failed": "stapel-upload fehlgeschlagen: {error}",
+  "history.flash.stitched": "{count} aufnahmen zusammengefügt",
+  "history.flash.stitch-failed": "zusammenfügen fehlgeschlagen: {error}",
+  "history.flash.links-copied": "{count} links kopiert",
+  "history.flash.copy-failed": "kopieren fehlgeschlagen: {error}",
+  "history.flash.copied": "in die zwischenablage kopiert",
+  "history.flash.revealed": "im dateimanager gezeigt",
+  "history.flash.open-failed": "öffnen fehlgeschlagen: {error}",
+  "history.flash.editor-failed": "editor fehlgeschlagen: {error}",
+  "history.flash.extracting": "text wird erkannt...",
+  "history.flash.no-text": "kein text im bild gefunden",
+  "history.flash.ocr-copied": "OCR: text kopiert ({count} zeichen)",
+  "history.flash.clipboard-failed": "kopieren in die zwischenablage fehlgeschlagen",
+  "history.flash.ocr-failed": "OCR fehlgeschlagen: {error}",
+  "history.flash.pin-failed": "anheften fehlgeschlagen: {error}",
+  "history.flash.deleted": "gelöscht",
+  "history.flash.delete-failed": "löschen fehlgeschlagen: {error}",
+
+  "history.reading-dir": "liest ordner...",
+  "history.count": "{total} dateien im ausgabeordner",
+  "history.count.filtered": "{shown} von {total} dateien passen",
+  "history.search": "nach name, #tag oder notiz filtern...",
+  "history.select": "auswählen",
+  "history.upload-selected": "{count} hochladen",
+  "history.compare.title": "zwei aufnahmen zum vergleichen auswählen",
+  "history.stitch.title":
+    "die ausgewählten aufnahmen in auswahlreihenfolge an ihren überlappungen zusammenfügen",
+  "history.stitching": "fügt zusammen…",
+  "history.stitch": "zusammenfügen",
+  "history.open-image.title": "eine bilddatei im editor öffnen",
+  "history.open-image": "bild öffnen",
+  "history.convert.title":
+    "alle bilder eines ordners mit den ausgabeeinstellungen in den aufnahmeordner übernehmen",
+  "history.convert": "ordner konvertieren",
+  "history.batch.failed": "fehlgeschlagen: {error}",
+  "history.batch.lines": "als zeilen kopieren",
+  "history.batch.markdown": "als markdown kopieren",
+  "history.reading": "liest...",
+  "history.empty": "noch keine aufnahmen",
+  "history.empty.hint":
+    "{shot} drücken, um einen bereich in die zwischenablage aufzunehmen, oder {gif}, um ein GIF aufzunehmen.",
+  "history.empty.rebind": "neu belegen unter {tasks} · ziele stehen unter {destinations}.",
+  "history.empty.reading-from": "liest aus {dir}",
+  "history.no-matches": "keine treffer",
+  "history.no-matches.hint": "nichts im ausgabeordner passt zu deinem filter.",
+  "history.tile.select": "{name}, zum hochladen auswählen",
+  "history.tile.reveal": "{name}, datei zeigen",
+  "history.tile.edit": "{name}, im editor öffnen",
+  "history.trim": "kürzen",
+  "history.ocr": "text erkennen (OCR)",
+  "history.pin": "an den bildschirm heften",
+  "history.reupload": "erneut hochladen",
+  "history.open-viewer": "im systembetrachter öffnen",
+  "history.copy": "in die zwischenablage kopieren",
+  "history.hdr": "HDR-begleitdatei vorhanden (.hdr.png / .avif / .jxr)",
+  "history.tag.title": "aufnahmen mit tag {tag} zeigen",
+
+  // plugins view
+  "plugins.status.rescanning": "sucht erneut...",
+  "plugins.status.done": "fertig.",
+  "plugins.status.folder-failed": "plugin-ordner konnte nicht geöffnet werden: {error}",
+  "plugins.status.installing": "installiert {name}...",
+  "plugins.status.installed.review":
+    "{name} v{version} installiert — deaktiviert, bis du unten seine berechtigungen prüfst und es aktivierst.",
+  "plugins.status.installed": "{name} v{version} installiert.",
+  "plugins.status.install-failed": "installation fehlgeschlagen: {error}",
+  "plugins.status.enabling": "aktiviert {name}...",
+  "plugins.status.disabling": "deaktiviert {name}...",
+  "plugins.status.enabled": "{name} aktiviert.",
+  "plugins.status.disabled": "{name} deaktiviert.",
+  "plugins.status.toggle-failed": "umschalten fehlgeschlagen: {error}",
+  "plugins.uninstall.confirm": "{name} deinstallieren? Die Plugin-Dateien werden gelöscht.",
+  "plugins.status.uninstalling": "deinstalliert {name}...",
+  "plugins.status.removed": "{name} entfernt.",
+  "plugins.status.uninstall-failed": "deinstallation fehlgeschlagen: {error}",
+
+  "plugins.lede": "rot.lt-verzeichnis durchsuchen · plugin-ordner in den ordner legen.",
+  "plugins.installed": "installiert",
+  "plugins.open-folder": "ordner öffnen",
+  "plugins.load-failed.one": "{count} plugin konnte nicht geladen werden",
+  "plugins.load-failed.other": "{count} plugins konnten nicht geladen werden",
+  "plugins.load-failed.hint":
+    "geladen wird beim start — capscr nach dem reparieren eines plugins neu starten.",
+  "plugins.none": "keine installiert",
+  "plugins.none.hint":
+    "unten stöbern oder einen plugin-ordner in den plugin-ordner legen und neu laden.",
+  "plugins.enabled": "aktiv",
+  "plugins.disabled": "inaktiv",
+  "plugins.grants": "erlaubt",
+  "plugins.disable": "deaktivieren",
+  "plugins.enable": "aktivieren",
+  "plugins.uninstall": "deinstallieren",
+  "plugins.browse": "stöbern",
+  "plugins.empty-registry": "leeres verzeichnis",
+  "plugins.empty-registry.hint":
+    "es gibt noch keine plugins zum installieren — einen plugin-ordner in den plugin-ordner legen oder später wieder vorbeischauen.",
+  "plugins.site": "website",
+  "plugins.update": "auf v{version} aktualisieren",
+  "plugins.install": "installieren",
+  "plugins.unreachable": "verzeichnis nicht erreichbar",
+  "plugins.unreachable.hint":
+    "unter Einstellungen → Ziele einen spiegel eintragen oder warten, bis rot.lt wieder da ist.",
+
+  "pin.gone": "das bild dieses pins ist nicht mehr vorhanden",
+  "pin.image-failed": "das bild dieses pins konnte nicht geladen werden",
+  "pin.failed": "dieser pin konnte nicht geladen werden",
+  "pin.image": "angeheftet",
+  "pin.empty": "nichts angeheftet",
+  "pin.opacity": "deckkraft anpassen",
+
+  "recbar.stop": "stopp",
+
+  // editor
+  "editor.font.mono": "mono",
+  "editor.font.sans": "sans",
+  "editor.font.serif": "serif",
+  "editor.font.hand": "handschrift",
+  "editor.layer.name": "ebene {n}",
+
+  "editor.stamp.check": "haken",
+  "editor.stamp.cross": "kreuz",
+  "editor.stamp.arrow": "pfeil",
+  "editor.stamp.star": "stern",
+  "editor.stamp.question": "frage",
+  "editor.stamp.warning": "warnung",
+  "editor.stamp.thumbs-up": "daumen hoch",
+  "editor.stamp.heart": "herz",
+
+  "editor.status.gif":
+    "GIFs lassen sich nicht bearbeiten — die frames würden zusammengefügt. Schließen, um das original zu behalten.",
+  "editor.status.load-failed": "bild konnte nicht geladen werden: {error}",
+  "editor.status.reopened.one": "mit {count} bearbeitbaren anmerkung wieder geöffnet.",
+  "editor.status.reopened.other": "mit {count} bearbeitbaren anmerkungen wieder geöffnet.",
+  "editor.status.no-path": "kein bildpfad vom backend erhalten",
+  "editor.status.pasted":
+    "aus der zwischenablage eingefügt — speichern schreibt es auf die festplatte.",
+  "editor.status.paste-failed": "einfügen fehlgeschlagen: {error}",
+  "editor.status.paste-text":
+    "einfügen: hier gehen nur bilder — text mit dem textwerkzeug hinzufügen",
+  "editor.discard.pasted":
+    "Eingefügtes Bild und ungespeicherte Anmerkungen verwerfen? Änderungen landen erst mit Speichern auf der Festplatte.",
+  "editor.discard.load": "Ungespeicherte Anmerkungen verwerfen und ein neues Bild öffnen?",
+  "editor.discard":
+    "Ungespeicherte Anmerkungen verwerfen? Sie landen erst mit Speichern auf der Festplatte.",
+  "editor.layer.delete.confirm.one": "{name} und seine {count} anmerkung löschen?",
+  "editor.layer.delete.confirm.other": "{name} und seine {count} anmerkungen löschen?",
+  "editor.status.project-failed": "gespeichert, aber das editor-projekt schlug fehl: {error}",
+  "editor.status.save-failed": "speichern fehlgeschlagen: {error}",
+  "editor.status.saved-copy": "kopie gespeichert unter {path}",
+  "editor.status.save-as-failed": "speichern unter fehlgeschlagen: {error}",
+  "editor.status.copying": "kopiert...",
+  "editor.status.copied": "in die zwischenablage kopiert.",
+  "editor.status.copy-failed": "kopieren fehlgeschlagen: {error}",
+  "editor.status.uploading": "lädt hoch...",
+  "editor.status.upload-failed": "upload fehlgeschlagen: {error}",
+  "editor.status.tags-saved": "tags gespeichert.",
+
+  "editor.tools": "werkzeuge",
+  "editor.tool.arrow": "pfeil (1)",
+  "editor.tool.rect": "rechteck (2)",
+  "editor.tool.text": "text (3)",
+  "editor.tool.blur": "verpixeln (4)",
+  "editor.tool.step": "nummerierter schritt (5)",
+  "editor.tool.line": "linie (6)",
+  "editor.tool.ellipse": "ellipse (7)",
+  "editor.tool.highlight": "textmarker (8)",
+  "editor.tool.pen": "stift (9)",
+  "editor.tool.stamp": "stempel (0)",
+  "editor.tool.measure": "messen (m)",
+  "editor.colors": "farben",
+  "editor.stroke": "strich",
+  "editor.size": "größe",
+  "editor.font": "schrift",
+  "editor.stamps": "stempel",
+  "editor.layers": "ebenen",
+  "editor.image.title": "die aufnahme selbst ein- / ausblenden",
+  "editor.image": "bild",
+  "editor.layer.hide": "{name} ausblenden",
+  "editor.layer.show": "{name} einblenden",
+  "editor.hide": "ausblenden",
+  "editor.show": "einblenden",
+  "editor.layer.draw": "auf dieser ebene zeichnen",
+  "editor.layer.delete": "{name} löschen",
+  "editor.layer.delete.title": "ebene löschen",
+  "editor.layer.new.title": "neue ebene obenauf",
+  "editor.layer.new": "ebene",
+  "editor.undo": "rückgängig",
+  "editor.redo": "wiederholen",
+  "editor.zoom.actual": "originalgröße (ctrl+0)",
+  "editor.zoom.fit.title": "ans fenster anpassen (f)",
+  "editor.zoom.fit": "ans fenster anpassen",
+  "editor.save-as": "speichern unter…",
+  "editor.tags.title": "tags und notiz, im verlauf durchsuchbar",
+  "editor.destination": "upload-ziel",
+  "editor.destination.default": "standardziel",
+  "editor.upload": "hochladen",
+  "editor.hdr": "HDR-aufnahme erkannt.",
+  "editor.hdr.flatten":
+    "die leinwand arbeitet in SDR; speichern macht aus der HDR-datei SDR. den editor ohne speichern schließen, um das HDR-original zu behalten.",
+  "editor.hdr.sidecar":
+    "capscr hat das HDR-original als {file} behalten — anmerkungen landen in der SDR-kopie; die HDR-begleitdatei bleibt unverändert.",
+  "editor.loading": "lädt aufnahme...",
+  "editor.text.placeholder": "tippen, enter übernimmt",
+  "editor.sizes": "geschätzte dateigröße je format bei der eingestellten qualität",
+
+  // destinations
+  "destinations.lede": "wohin uploads gehen.",
+  "destinations.vault.keyring": "dem system-schlüsselbund",
+  "destinations.encrypted.stored": "verschlüsselt abgelegt mit {vault} (pro benutzer)",
+  "destinations.encrypted.on-save": "beim speichern mit {vault} verschlüsselt abgelegt",
+  "destinations.stored": "(gespeichert — leer lassen, um es zu behalten)",
+  "destinations.saved-secret": "•••••••••••••••• (gespeichert)",
+  "destinations.test": "test",
+  "destinations.test.run": "verbindung testen",
+  "destinations.test.running": "prüft...",
+  "destinations.username": "benutzername",
+  "destinations.password": "passwort",
+  "destinations.host": "host",
+  "destinations.port": "port",
+  "destinations.remote-dir": "entferntes verzeichnis",
+  "destinations.public-url": "vorlage für öffentliche url",
+  "destinations.public-url.hint": "{filename} → dateiname, leer = keine url zurück",
+  "destinations.folder": "ordner",
+  "destinations.account": "konto",
+  "destinations.signed-in": "(angemeldet)",
+  "destinations.sign-in": "anmelden",
+  "destinations.sign-out": "abmelden",
+  "destinations.confirm": "bestätigen",
+  "destinations.secret": "geheim",
+  "destinations.optional": "optional",
+
+  "destinations.status.opening-browser": "öffnet browser...",
+  "destinations.status.signing-in": "meldet an...",
+  "destinations.status.signing-out": "meldet ab...",
+  "destinations.status.loading-albums": "lädt alben...",
+  "destinations.status.creating-album": "erstellt album...",
+  "destinations.status.decrypting": "entschlüsselt...",
+  "destinations.status.signed-in": "angemeldet als {name}.",
+  "destinations.status.unnamed": "(unbenanntes konto)",
+  "destinations.status.imgur-pin": "capscr im browser freigeben, dann die PIN hier einfügen.",
+  "destinations.status.dropbox-code":
+    "capscr im browser freigeben, dann den zugangscode hier einfügen.",
+  "destinations.status.imgur-out": "abgemeldet — uploads sind wieder anonym.",
+  "destinations.status.dropbox-out": "von dropbox abgemeldet.",
+  "destinations.status.saved-to": "gespeichert unter {path}",
+  "destinations.status.albums.one": "{count} album.",
+  "destinations.status.albums.other": "{count} alben.",
+  "destinations.status.album-created": "„{title}“ erstellt — speichern, um dorthin hochzuladen.",
+  "destinations.status.forgot": "frühere uploads vergessen.",
+  "destinations.status.imported": "„{name}“ als neue vorlage importiert.",
+  "destinations.status.preset-limit": "vorlagen-limit erreicht; zuerst eine löschen",
+
+  "destinations.active": "aktives ziel",
+  "destinations.target": "ziel",
+  "destinations.target.custom": "eigenes http",
+  "destinations.target.s3": "S3-kompatibel",
+  "destinations.target.email": "e-mail",
+  "destinations.target.webhook": "chat-webhook",
+  "destinations.imgur.client-id": "imgur client-id",
+  "destinations.imgur.client-id.hint":
+    "leer lassen für den gemeinsamen schlüssel von capscr; einen eigenen von api.imgur.com einfügen, um staus durch rate-limits zu vermeiden.",
+  "destinations.imgur.test.hint": "ruft api.imgur.com/3/credits mit dieser client-id auf.",
+  "destinations.copy-url": "url in die zwischenablage kopieren",
+  "destinations.copy-url.on": "bei erfolg automatisch kopieren",
+  "destinations.copy-url.off": "zwischenablage nicht anfassen",
+  "destinations.resize.hint": "lanczos, vergrößert nie · die gespeicherte datei behält ihre größe",
+  "destinations.recompress": "über dem größenlimit",
+  "destinations.recompress.jpeg": "als jpeg neu komprimieren",
+  "destinations.recompress.webp": "erst verlustfreies webp, dann jpeg",
+  "destinations.recompress.off": "upload abbrechen",
+  "destinations.recompress.min": "niedrigste qualität",
+  "destinations.recompress.hint":
+    "geht von qualität 90 abwärts, bis es passt · imgur nimmt 20 MB, eigene hosts ihr eigenes limit",
+  "destinations.link-format": "linkformat",
+  "destinations.link-format.url": "reine url",
+  "destinations.link-format.hint":
+    "gilt auch für „letzte url kopieren“ und die letzten uploads im tray",
+  "destinations.dedupe": "wiederholte uploads",
+  "destinations.dedupe.ask": "fragen, ob der frühere link genutzt wird",
+  "destinations.dedupe.reuse": "früheren link wiederverwenden",
+  "destinations.dedupe.off": "immer neu hochladen",
+  "destinations.dedupe.days": "tage",
+  "destinations.dedupe.forget": "vergessen",
+  "destinations.dedupe.hint":
+    "gleiche datei, gleiches ziel · abgeglichen per sha-256, nur hashes und links werden behalten",
+  "destinations.secret-scan": "geheimnis-scan",
+  "destinations.secret-scan.on": "vor dem hochladen möglicher geheimnisse fragen",
+  "destinations.secret-scan.api-keys": "api-schlüssel + tokens",
+  "destinations.secret-scan.emails": "e-mail-adressen",
+  "destinations.secret-scan.cards": "kartennummern",
+  "destinations.secret-scan.hint":
+    "liest den text im bild per ocr, bevor es den rechner verlässt · ein stolperdraht, keine garantie",
+
+  "destinations.proxy": "proxy",
+  "destinations.proxy.system": "system-proxy",
+  "destinations.proxy.direct": "kein proxy",
+  "destinations.proxy.manual": "manuell",
+  "destinations.proxy.hint": "für http-ziele · ftp und sftp verbinden direkt",
+  "destinations.proxy.url": "proxy-url",
+  "destinations.proxy.url.hint": "socks5h löst namen auf dem proxy auf",
+
+  "destinations.encryption": "verschlüsselung",
+  "destinations.encrypt": "vor dem upload verschlüsseln",
+  "destinations.encrypt.on": "nur geheimtext hochladen",
+  "destinations.encrypt.off": "bild unverändert hochladen",
+  "destinations.encrypt.imgur":
+    "imgur nimmt nur bilder — für verschlüsselung ein anderes ziel wählen",
+  "destinations.encrypt.hint":
+    "aes-256-gcm, neuer schlüssel pro upload · der schlüssel bleibt im #fragment des links",
+  "destinations.encrypted-link": "verschlüsselten link öffnen",
+
+  "destinations.imgur.account": "imgur-konto",
+  "destinations.imgur.secret": "client-secret",
+  "destinations.imgur.secret.hint":
+    "imgur meldet nur registrierte apps an: eine auf api.imgur.com registrieren, ihre client-id oben und ihr secret hier eintragen. verschlüsselt abgelegt mit {vault}.",
+  "destinations.imgur.sign-in": "mit imgur anmelden",
+  "destinations.imgur.pin": "PIN von imgur",
+  "destinations.imgur.anonymous": "uploads bleiben anonym, bis du dich anmeldest.",
+  "destinations.album": "album",
+  "destinations.album.none": "(kein album)",
+  "destinations.album.default": "standard",
+  "destinations.album.new": "neues album",
+  "destinations.album.title": "albumtitel",
+  "destinations.album.hidden": "versteckt (nur per link)",
+  "destinations.album.public": "öffentlich",
+  "destinations.album.secret": "geheim",
+  "destinations.album.create": "erstellen",
+  "destinations.imgur.title": "titel",
+  "destinations.imgur.description": "beschreibung",
+  "destinations.imgur.meta.hint": "gilt für jeden upload; leer lässt es ungesetzt.",
+
+  "destinations.ftp.port.hint": "21 unverschlüsselt, 990 implizites tls",
+  "destinations.ftp.tls": "tls",
+  "destinations.ftp.tls.hint":
+    "nur unverschlüsseltes ftp — für verschlüsselte übertragung sftp nutzen",
+  "destinations.ftp.test.hint":
+    "verbindet, meldet an, wechselt ins entfernte verzeichnis. lädt nichts hoch.",
+  "destinations.sftp.port.hint": "22 standard-ssh",
+  "destinations.sftp.key": "privater schlüssel",
+  "destinations.sftp.key.placeholder":
+    "C:\\Users\\you\\.ssh\\id_ed25519 (leer = passwort-anmeldung)",
+  "destinations.sftp.key.hint": "openssh-format. ed25519 / rsa / ecdsa werden unterstützt.",
+  "destinations.sftp.passphrase": "schlüssel-passphrase",
+  "destinations.sftp.passphrase.placeholder": "leer lassen, wenn der schlüssel unverschlüsselt ist",
+  "destinations.sftp.password.placeholder":
+    "ersatz, falls die schlüssel-anmeldung scheitert oder kein schlüssel gesetzt ist",
+  "destinations.sftp.test.hint":
+    "verbindet, authentifiziert, listet das entfernte verzeichnis. lädt nichts hoch.",
+
+  "destinations.s3.bucket": "Bucket",
+  "destinations.s3.region": "Region",
+  "destinations.s3.endpoint": "Endpunkt",
+  "destinations.s3.endpoint.placeholder": "https://s3.amazonaws.com (leer für standard-AWS)",
+  "destinations.s3.access-key": "Zugriffsschlüssel-ID",
+  "destinations.s3.secret-key": "Geheimer Zugriffsschlüssel",
+  "destinations.s3.vault": "verschlüsselter tresor befüllt",
+  "destinations.s3.public-url": "Vorlage für öffentliche URL",
+  "destinations.s3.public-url.hint": "{filename} → dateiname, leer = standard-s3-url zurück",
+  "destinations.s3.test.hint": "lädt zur prüfung der berechtigungen eine kleine testdatei hoch.",
+
+  "destinations.webdav.server": "server-url",
+  "destinations.webdav.server.hint":
+    "nextcloud und owncloud zeigen sie unter dateien → einstellungen → webdav",
+  "destinations.webdav.password.placeholder": "ein app-passwort, nicht dein login",
+  "destinations.webdav.folder.hint":
+    "unterhalb der server-url, wird bei bedarf angelegt; %Y / %m / %d werden ersetzt",
+  "destinations.webdav.link": "link",
+  "destinations.webdav.share": "öffentlichen freigabelink erstellen (nextcloud / owncloud)",
+  "destinations.webdav.public-url.hint":
+    "{path} → ordner, {filename} → dateiname; leer = die webdav-url (zum öffnen ist dein login nötig)",
+  "destinations.webdav.test.hint": "prüft url und login, ohne etwas hochzuladen.",
+
+  "destinations.dropbox.app-key": "app-schlüssel",
+  "destinations.dropbox.app-key.hint":
+    "eine app auf dropbox.com/developers mit den berechtigungen files.content.write und sharing.write anlegen und ihren app-schlüssel hier einfügen. ein secret ist nicht nötig.",
+  "destinations.dropbox.sign-in": "mit dropbox anmelden",
+  "destinations.dropbox.code": "zugangscode von dropbox",
+  "destinations.dropbox.kept": "die anmeldung wird in {vault} aufbewahrt.",
+  "destinations.dropbox.folder.hint":
+    "jeder upload bekommt einen freigabelink, und der wird kopiert",
+
+  "destinations.email.to": "an",
+  "destinations.email.to.placeholder": "in der neuen nachricht leer gelassen",
+  "destinations.email.subject": "betreff",
+  "destinations.email.hint":
+    "die aufnahme öffnet sich als anhang in deinem standard-mailprogramm; von dort schickst du sie ab",
+
+  "destinations.webhook.service": "dienst",
+  "destinations.webhook.url": "webhook-url",
+  "destinations.webhook.url.hint":
+    "wer die url hat, kann in den kanal posten, daher liegt sie in {vault}",
+  "destinations.webhook.message": "nachricht",
+  "destinations.webhook.host": "bild-host",
+  "destinations.webhook.host.hint":
+    "slack- und teams-webhooks nehmen nur text, daher wird die aufnahme hierhin hochgeladen und die nachricht verlinkt sie",
+
+  "destinations.preset": "vorlage",
+  "destinations.preset.default-suffix": " (standard)",
+  "destinations.preset.new-name": "eigene {n}",
+  "destinations.preset.hint":
+    "aufgaben und der editor können jede vorlage wählen; sonst gilt die standardvorlage",
+  "destinations.import": "import",
+  "destinations.import.sxcu": ".sxcu importieren",
+  "destinations.import.hint":
+    "fügt eine vorlage aus einer ShareX-uploader-datei hinzu; prüfen, dann speichern.",
+  "destinations.preset.none":
+    "noch keine vorlagen — eine hinzufügen oder eine .sxcu-datei importieren.",
+  "destinations.preset.name": "name",
+  "destinations.preset.default": "standardvorlage",
+  "destinations.custom.url": "upload-url",
+  "destinations.custom.url.hint": "nur https, unverschlüsseltes http wird abgelehnt",
+  "destinations.custom.method": "methode",
+  "destinations.custom.post": "POST (multipart)",
+  "destinations.custom.put": "PUT (roher body)",
+  "destinations.custom.method.hint":
+    "PUT schickt die datei als body; zusätzliche felder werden zu query-parametern",
+  "destinations.custom.form": "formularfeld",
+  "destinations.custom.form.hint": "multipart-schlüssel (oft „file“)",
+  "destinations.custom.path": "antwortpfad",
+  "destinations.custom.path.hint": "json-pfad mit punkten zur url, leer = roher body",
+  "destinations.custom.regex": "antwort-regex",
+  "destinations.custom.regex.hint":
+    "ersetzt den json-pfad, wenn gesetzt; die erste gruppe ist die url",
+  "destinations.custom.limit": "größenlimit",
+  "destinations.custom.limit.hint":
+    "MB · 0 = kein host-limit, größere aufnahmen werden neu komprimiert",
+  "destinations.custom.headers": "header",
+  "destinations.custom.value": "wert",
+  "destinations.custom.add-header": "header hinzufügen",
+  "destinations.custom.headers.hint":
+    "geheime werte (api-schlüssel, bearer-tokens) werden mit {vault} verschlüsselt abgelegt",
+  "destinations.custom.fields": "zusätzliche felder",
+  "destinations.custom.add-field": "feld hinzufügen",
+  "destinations.custom.fields.hint":
+    "api-schlüssel und tokens als geheim markieren, um sie in {vault} abzulegen",
+  "destinations.custom.test.hint":
+    "prüft header/felder und schickt dann OPTIONS an die url. 2xx/3xx/405 = erreichbar.",
+
+  "destinations.probe": "prüfung — {destination}",
+  "destinations.probe.ok": "alle schritte bestanden — zugangsdaten und verbindung sehen gut aus.",
+  "destinations.probe.failed": "ein oder mehrere schritte sind gescheitert — details unten.",
+  "destinations.probe.step": "schritt",
+  "destinations.probe.status": "status",
+  "destinations.probe.detail": "detail",
+  "destinations.probe.pass": "ok",
+  "destinations.probe.fail": "fehler",
+};
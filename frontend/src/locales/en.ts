@@ -0,0 +1,1192 @@
+// the source of truth: every key lives here, and a key another bundle
+// lacks renders this text instead. `{name}` is filled from t()'s vars
+export const en = {
+  // sidebar tabs
+  "tab.settings": "settings",
+  "tab.tasks": "tasks",
+  "tab.history": "history",
+  "tab.destinations": "destinations",
+  "tab.plugins": "plugins",
+
+  // shared across views
+  "common.copy": "copy",
+  "common.open": "open",
+  "common.close": "close",
+  "common.dismiss": "dismiss",
+  "common.cancel": "cancel",
+  "common.save": "save",
+  "common.delete": "delete",
+  "common.remove": "remove",
+  "common.add": "add",
+  "common.edit": "edit",
+  "common.on": "on",
+  "common.off": "off",
+  "common.none": "none",
+  "common.show-in-folder": "show in folder",
+  "common.clipboard-busy": "clipboard busy — try again",
+  "common.error": "err: {error}",
+  "common.save.title": "commit pending changes",
+  "common.save.clean": "no changes to save",
+  "common.writing": "writing...",
+  "common.saved": "saved.",
+  "common.browse": "browse",
+
+  // hub shell: statusbar, banners, overlays
+  "app.loading-editor": "loading editor…",
+  "app.skip": "skip to content",
+  "app.quit.dirty": "Quit capscr and discard unsaved settings?",
+  "app.quit.recording": "Quit capscr? The running recording is saved first.",
+  "app.discard-edits": "Discard unsaved settings changes?",
+
+  "app.status.failed": "{kind} failed: {msg}",
+  "app.status.mail": "opened in your mail client",
+  "app.status.uploaded": "uploaded {url}",
+  "app.status.saved": "saved to {name}",
+  "app.status.crash-copied": "crash report copied",
+  "app.status.crash-error": "crash report: {error}",
+  "app.status.restored": "restored recording → {path}",
+  "app.status.recovered-error": "recovered recording: {error}",
+  "app.status.stats": "recorded at {achieved} of {target} fps · {dropped} dropped",
+  "app.status.stats.failed": ", {failed} failed grabs",
+  "app.status.stats.choppy": " — try a lower fps or a smaller region",
+  "app.status.ending": "recording stops in {seconds}s — it's reaching the max duration",
+  "app.status.cancelled.one": "cancelled {count} queued save",
+  "app.status.cancelled.other": "cancelled {count} queued saves",
+
+  "app.toast.recording": "recording — press {hotkey} again to stop",
+  "app.toast.recording.same": "recording — press the same hotkey again to stop",
+  "app.toast.dropped":
+    "dropped {count} files — uploading first {max}, drop the rest after these finish",
+  "app.toast.hotkey-bound": "screenshot hotkey bound successfully",
+  "app.toast.save-failed": "failed to save: {error}",
+  "app.toast.cancel-failed": "couldn't cancel: {error}",
+  "app.toast.keys-failed": "couldn't re-enable hotkeys: {error}",
+  "app.toast.dnd-failed": "couldn't turn off do not disturb: {error}",
+
+  "app.onboarding.title": "welcome to capscr",
+  "app.onboarding.lede": "let's set up your screenshot shortcut key first",
+  "app.onboarding.desc":
+    "not every keyboard has a PrintScreen key. click the box below and press any key combination (e.g. PrintScreen, Ctrl+Alt+S, Alt+Shift+A) to bind it.",
+
+  "app.nav": "views",
+
+  "app.shortcuts.title": "keyboard shortcuts",
+  "app.shortcuts.hub": "hub",
+  "app.shortcuts.toggle": "toggle this overlay",
+  "app.shortcuts.settings": "settings tab",
+  "app.shortcuts.tasks": "tasks tab",
+  "app.shortcuts.history": "history tab",
+  "app.shortcuts.destinations": "destinations tab",
+  "app.shortcuts.plugins": "plugins tab",
+  "app.shortcuts.quit": "quit capscr",
+  "app.shortcuts.esc": "close overlay / hide hub",
+  "app.shortcuts.tab": "move between controls",
+  "app.shortcuts.sidebar": "move within the sidebar",
+  "app.shortcuts.panes": "switch settings panes",
+  "app.shortcuts.open-item": "open the focused history item",
+  "app.shortcuts.editor": "editor",
+  "app.shortcuts.arrow": "arrow tool",
+  "app.shortcuts.rect": "rect tool",
+  "app.shortcuts.text": "text tool",
+  "app.shortcuts.blur": "blur tool",
+  "app.shortcuts.more-tools": "step, line, ellipse, highlighter, pen",
+  "app.shortcuts.stamp": "stamp tool",
+  "app.shortcuts.measure": "measure tool",
+  "app.shortcuts.save": "save",
+  "app.shortcuts.copy-upload": "copy / upload the annotated capture",
+  "app.shortcuts.close-editor": "close editor",
+  "app.shortcuts.undo": "undo",
+  "app.shortcuts.redo": "redo",
+  "app.shortcuts.paste": "paste image from clipboard",
+  "app.shortcuts.zoom-in-out": "zoom in / out",
+  "app.shortcuts.zoom-100": "zoom 100%",
+  "app.shortcuts.fit": "fit to window",
+  "app.shortcuts.pan": "pan (or middle-drag)",
+  "app.shortcuts.zoom": "zoom",
+  "app.shortcuts.global": "global (default — rebindable in tasks)",
+  "app.shortcuts.region-shot": "region screenshot → save + clipboard",
+  "app.shortcuts.region-gif": "region GIF → save",
+  "app.shortcuts.foot": "press {f1} or {esc} to close",
+
+  "app.log.title": "recent activity",
+  "app.log.empty": "nothing yet this session",
+
+  "app.tray-missing.title": "no system tray detected",
+  "app.tray-missing.meta":
+    "common on vanilla GNOME. capscr keeps running in the background: global hotkeys still work, right-click the capscr entry in Activities for capture actions, and launching capscr again reopens this window; {quit} here quits it. for a tray icon, install the AppIndicator extension.",
+  "app.tray-missing.get": "get extension",
+  "app.tray-missing.never": "don't show again",
+  "app.crash.title": "capscr crashed",
+  "app.crash.meta":
+    "{when}: {summary}. the report has a backtrace and the log leading up to it, for a bug report.",
+  "app.recovered.title": "unsaved recording kept",
+  "app.recovered.meta":
+    "a gif recording ({frames} frames, {seconds}s) was still unsaved when capscr last stopped on {when}.",
+  "app.recovered.restore": "restore",
+  "app.recovered.discard": "discard",
+  "app.update.title": "update available · v{version}",
+  "app.update.current": "you're on v{version}",
+  "app.update.notes": "what's new",
+  "app.update.deb-hint": "then: sudo apt install ./the-downloaded.deb",
+  "app.update.rpm-hint": "then: sudo dnf install ./the-downloaded.rpm",
+  "app.update.deb": "download .deb",
+  "app.update.rpm": "download .rpm",
+  "app.update.release": "get release",
+  "app.update.installing": "installing...",
+  "app.update.install": "install + restart",
+  "app.update.later": "later",
+
+  "app.bar.unsaved": "unsaved",
+  "app.bar.enc.cancel": "saving in the background — click to cancel the queued saves",
+  "app.bar.enc": "saving in the background",
+  "app.bar.keys": "hotkeys are off — click to re-enable",
+  "app.bar.dnd": "do not disturb — hotkeys, notifications and sounds are off. click to turn it off",
+  "app.bar.game.suspend":
+    "full-screen app focused — hotkeys without ctrl, alt or win are handed to it",
+  "app.bar.game.wgc": "full-screen app focused — captures go through Windows.Graphics.Capture",
+  "app.bar.recording": "recording {elapsed}",
+
+  "app.drop.title": "drop to upload",
+  "app.drop.destination": "destination: {destination}",
+  "app.toasts": "notifications",
+  "app.upload.kind": "uploaded",
+  "app.upload.copy-delete": "copy del url",
+
+  // settings view
+  "settings.title": "settings",
+  "settings.search": "search settings...",
+  "settings.no-match": 'no setting matches "{query}".',
+  "settings.pane.general": "general",
+  "settings.pane.capture": "capture",
+  "settings.pane.hdr": "hdr",
+  "settings.pane.hotkeys": "hotkeys",
+  "settings.pane.ssh": "ssh",
+  "settings.pane.notify": "notify",
+  "settings.reset": "reset",
+  "settings.reset.title": "restore every setting to its default",
+  "settings.reset.confirm":
+    "Replace every setting with the defaults? Tasks, hotkeys, and destinations will all reset.",
+  "settings.save": "save",
+  "settings.saving": "saving...",
+  "settings.status.loading-defaults": "loading defaults...",
+  "settings.status.defaults-loaded": "loaded — click save to commit.",
+  "settings.language": "language",
+  "settings.language.hint": "switches immediately, tray menu and notifications included",
+  "settings.language.system": "system default",
+
+  "settings.section.output": "output",
+  "settings.section.per-app": "per app",
+  "settings.section.decorations": "export decorations",
+  "settings.section.appearance": "appearance",
+  "settings.section.gnome": "gnome integration",
+  "settings.section.cursor": "cursor",
+  "settings.section.overlay": "selection overlay",
+  "settings.section.after": "after capture",
+  "settings.section.window": "window capture",
+  "settings.section.timing": "timing",
+  "settings.section.game": "game mode",
+  "settings.section.recording": "recording (gif + mp4)",
+  "settings.section.auto": "auto capture",
+  "settings.section.watch": "watch folder",
+  "settings.section.hdr-sidecar": "hdr sidecar",
+  "settings.section.hdr-tonemap": "hdr tonemap",
+  "settings.section.global": "global",
+  "settings.section.advanced-input": "advanced input",
+  "settings.section.bindings": "per-binding status",
+  "settings.section.hook": "ll-hook telemetry (debug)",
+  "settings.section.sftp": "known sftp hosts",
+  "settings.section.feedback": "feedback",
+  "settings.section.system": "system",
+  "settings.section.logs": "logs",
+
+  "settings.field.directory": "directory",
+  "settings.field.filename": "filename template",
+  "settings.field.format": "format",
+  "settings.field.quality": "quality",
+  "settings.field.editor-projects": "editor projects",
+  "settings.field.archive": "archive originals",
+  "settings.field.scratch": "scratch folder",
+  "settings.field.scratch-cap": "scratch cap",
+  "settings.field.retention": "retention",
+  "settings.field.metadata": "metadata",
+  "settings.field.tags": "tags in files",
+  "settings.field.profiles": "app profiles",
+  "settings.field.border": "border",
+  "settings.field.corners": "rounded corners",
+  "settings.field.caption": "caption",
+  "settings.field.theme": "theme",
+  "settings.field.accent": "accent",
+  "settings.field.scale": "ui scale",
+  "settings.field.compact": "compact layout",
+  "settings.field.companion": "companion extension",
+  "settings.field.cursor": "show cursor",
+  "settings.field.monitors": "monitors",
+  "settings.field.actions": "default actions",
+  "settings.field.duplicates": "duplicates",
+  "settings.field.frame": "frame",
+  "settings.field.covered": "covered windows",
+  "settings.field.backend": "capture backend",
+  "settings.field.wgc-border": "capture border",
+  "settings.field.ui-elements": "ui elements",
+  "settings.field.delay": "pre-capture delay",
+  "settings.field.hide-hub": "hide hub",
+  "settings.field.excluded": "excluded apps",
+  "settings.field.exclusion-mode": "when one is in the shot",
+  "settings.field.fullscreen": "full-screen apps",
+  "settings.field.gif-fps": "gif frame rate",
+  "settings.field.video-fps": "video frame rate",
+  "settings.field.video-quality": "video quality",
+  "settings.field.max-duration": "max duration",
+  "settings.field.audio": "record audio",
+  "settings.field.review": "review",
+  "settings.field.zoom-cursor": "zoom to cursor",
+  "settings.field.zoom": "zoom",
+  "settings.field.smoothing": "smoothing",
+  "settings.field.stream": "live stream",
+  "settings.field.bitrate": "stream bitrate",
+  "settings.field.status": "status",
+  "settings.field.interval": "interval",
+  "settings.field.capture": "capture",
+  "settings.field.folder": "folder",
+  "settings.field.keep": "keep at most",
+  "settings.field.quota": "disk quota",
+  "settings.field.lapse": "time-lapse",
+  "settings.field.watch": "watch",
+  "settings.field.then": "then",
+  "settings.field.container": "container",
+  "settings.field.output-format": "output format",
+  "settings.field.operator": "operator",
+  "settings.field.sdr-white": "sdr white override",
+  "settings.field.pre-scale": "pre-tonemap scale",
+  "settings.field.p99": "p99 maxcll",
+  "settings.field.preserve-hdr": "preserve hdr",
+  "settings.field.all-hotkeys": "all hotkeys",
+  "settings.field.stop-recording": "stop recording",
+  "settings.field.dnd": "do not disturb",
+  "settings.field.hotkey-backend": "backend",
+  "settings.field.evdev": "raw input (evdev)",
+  "settings.field.notifications": "os notifications",
+  "settings.field.sound": "sound cue",
+  "settings.field.open-after": "open after save",
+  "settings.field.autostart": "start at login",
+  "settings.field.context-menu": "context menu",
+  "settings.field.minimized": "start minimized",
+  "settings.field.close": "close button behavior",
+  "settings.field.clipboard-history": "clipboard history",
+  "settings.field.updates": "check for updates",
+  "settings.field.log": "log file",
+
+  "settings.browse.title": "browse for folder",
+  "settings.pick.output": "Pick output directory",
+  "settings.pick.scratch": "Pick scratch folder",
+  "settings.pick.archive": "Pick archive folder",
+  "settings.pick.auto": "Pick auto capture folder",
+  "settings.pick.watch": "Pick folder to watch",
+
+  "settings.hint.directory": "absolute path or %env% template",
+  "settings.hint.filename":
+    "chrono tokens: %Y year · %m month · %d day · %H hour · %M min · %S sec · extension added automatically",
+  "settings.hint.quality": "1-100, ignored for png/bmp",
+  "settings.check.editor-projects": "keep annotations editable after saving",
+  "settings.hint.editor-projects":
+    "writes a .capscr.json beside each capture saved from the editor, holding a full copy of it; reopening it restores the unedited image with every annotation still movable",
+  "settings.check.archive": "also keep every capture untouched as a lossless png",
+  "settings.placeholder.archive": "originals/ inside the output directory",
+  "settings.hint.archive":
+    "saved before plugins, decorations, downscaling or a lossy format touch it, for re-editing later; an absolute path",
+  "settings.placeholder.scratch": "capscr/ inside the system temp folder",
+  "settings.hint.scratch":
+    "recording frames and audio, mail attachments; an absolute path, ideally on a disk with room for long recordings",
+  "settings.hint.scratch-cap":
+    "MiB of leftovers from earlier runs kept at launch, oldest cleared first; 0 clears them all",
+  "settings.retention.files-kept": "files kept",
+  "settings.retention.mib-kept": "MiB kept",
+  "settings.retention.files": "files",
+  "settings.retention.move-to": "move old captures to",
+  "settings.hint.retention":
+    "once the output folder holds more, the oldest captures are deleted, or moved to the folder given (relative to the output folder); 0 is no limit · checked every 10 minutes",
+  "settings.metadata.keep": "leave as written",
+  "settings.metadata.strip": "strip all",
+  "settings.metadata.embed": "embed capture info",
+  "settings.hint.metadata":
+    "png and jpeg only · strip also scrubs editor saves · embed writes the capture time, monitor, window title and capscr version as png text / exif",
+  "settings.check.tags": "also write tags and notes into the png",
+  "settings.hint.tags":
+    "tags and notes always live in capscr's history index; this adds them as png Keywords / Comment text so other tools can read them",
+  "settings.hint.resize":
+    "lanczos, never enlarges · applies to captures saved from a hotkey, not editor saves; uploads have their own setting under destinations",
+
+  "settings.profiles.format": "format above",
+  "settings.profiles.action": "task's action",
+  "settings.profiles.subfolder": "subfolder",
+  "settings.profiles.files": "files kept in the subfolder, 0 for no limit",
+  "settings.profiles.mib": "MiB kept in the subfolder, 0 for no limit",
+  "settings.profiles.add": "add app",
+  "settings.hint.profiles":
+    "window captures of a listed process save in its format, into a folder under the output directory, and go to its action instead of the task's · a subfolder can keep its own file and MiB limits",
+
+  "settings.deco.border-color": "border color",
+  "settings.hint.border": "pixels, 0 for none",
+  "settings.deco.transparent": "transparent",
+  "settings.deco.corner-background": "corner background",
+  "settings.hint.corners":
+    "radius in pixels · transparent corners need png, webp or avif, other formats show them black",
+  "settings.hint.caption":
+    "text in a bar above the capture, same % tokens as the filename template · applied when a capture is saved, copied or uploaded directly, not to editor hand-offs",
+
+  "settings.hint.theme": "follow system switches with the os dark / light setting",
+  "settings.accent.custom": "custom",
+  "settings.accent.custom-label": "custom accent",
+  "settings.hint.accent": "tints active tabs, checks, focus rings and primary buttons",
+  "settings.hint.scale": "zooms the hub on top of the system display scale; applies on save",
+  "settings.check.compact": "key-only sidebar and tighter rows",
+  "settings.hint.compact": "lets the hub shrink to a smaller window",
+
+  "settings.gnome.active":
+    "active — window picking, pins above fullscreen, and the top-bar capture menu are handled by the shell extension",
+  "settings.gnome.installed": "installed — log out and back in to activate it",
+  "settings.gnome.installing": "installing…",
+  "settings.gnome.install": "install",
+  "settings.gnome.missing":
+    "without it, window capture uses gnome's own picker dialog, and pinned screenshots can't stay above fullscreen windows",
+
+  "settings.kind.region": "region",
+  "settings.kind.window": "window",
+  "settings.kind.full-screen": "full screen",
+  "settings.kind.gif": "recording",
+  "settings.cursor.captured": "captured",
+  "settings.cursor.hidden": "hidden",
+  "settings.hint.cursor": "applies to screenshots and recordings",
+  "settings.monitors.pointer": "the one under the pointer",
+  "settings.monitors.all": "all of them",
+  "settings.hint.monitors":
+    "region and window selection open on the monitor the pointer is on instead of darkening the whole desktop; quicker to open on three or more screens",
+  "settings.hint.actions":
+    "for captures from the tray menu and jump list; hotkey tasks keep their own action",
+  "settings.duplicates.off": "keep, say nothing",
+  "settings.duplicates.warn": "keep, with a notification",
+  "settings.duplicates.skip": "skip",
+  "settings.hint.duplicates":
+    "a capture pixel-identical to the one before it, taken within a minute — usually a hotkey pressed twice",
+  "settings.frame.visible": "window frame — no shadow",
+  "settings.frame.shadow": "frame + drop shadow",
+  "settings.frame.client": "client area only",
+  "settings.hint.frame": "what a window pick keeps around the window's content",
+  "settings.corners.left": "left as captured",
+  "settings.hint.window-corners":
+    "windows 11 — clears the black wedges outside a window's rounded corners (needs png/webp/avif for transparency)",
+  "settings.covered.own": "window's own contents",
+  "settings.covered.screen": "what's on screen",
+  "settings.hint.covered":
+    "windows — renders the picked window itself, so windows on top of it or the screen edge don't cut in. hdr windows and the drop shadow still come from the screen",
+  "settings.backend.auto": "automatic — gdi, cpu tonemap for hdr",
+  "settings.hint.backend":
+    'windows — wgc also sees hardware-accelerated and some protected content that gdi returns black for. falls back to gdi when it fails. the cursor still follows "show cursor"',
+  "settings.wgc-border.shown": "shown",
+  "settings.wgc-border.suppressed": "suppressed",
+  "settings.hint.wgc-border":
+    "the yellow outline windows draws while a capture runs. suppressing it needs windows 11",
+  "settings.ui-elements.on": "detect controls",
+  "settings.ui-elements.off": "windows only",
+  "settings.hint.ui-elements":
+    "hold ctrl while hovering in the selector to highlight individual buttons, panes and toolbars; click captures just that control",
+  "settings.hint.delay": "ms before grabbing pixels — useful for tooltips / menus (0 = instant)",
+  "settings.hide-hub.on": "while capturing",
+  "settings.hide-hub.off": "stays open",
+  "settings.hint.hide-hub":
+    "hides this window during screenshots and recordings, and restores it afterwards",
+  "settings.hint.excluded":
+    "process names, comma separated. their windows are kept out of screenshots and recordings — the whole window, even where something overlaps it",
+  "settings.exclusion.blackout": "black out its windows",
+  "settings.exclusion.skip": "skip the capture",
+  "settings.hint.exclusion":
+    "recordings black the windows out per frame, or drop frames while one is visible. picking an excluded window directly always fails",
+
+  "settings.game.off": "don't watch for them",
+  "settings.game.none": "leave everything as is",
+  "settings.game.suspend": "hand them the bare hotkeys",
+  "settings.game.wgc": "capture through WGC",
+  "settings.hint.game":
+    "what changes while a window covering its whole monitor (usually a game) has focus. bare hotkeys are ones without ctrl, alt or win — F9, PrintScreen, mouse buttons — which the game likely binds too; chords stay live. checked every couple of seconds",
+  "settings.game.rule.none": "leave as is",
+  "settings.game.rule.suspend": "bare hotkeys off",
+  "settings.hint.game-apps":
+    "process names overriding the choice above, e.g. a video player that goes full screen",
+
+  "settings.hint.gif-fps": "fps, 1-60",
+  "settings.hint.video-fps": "fps for mp4 recordings, 1-60",
+  "settings.video.high": "high — near-lossless, biggest files",
+  "settings.video.balanced": "balanced",
+  "settings.video.compact": "compact — smallest files",
+  "settings.hint.video-quality": "mp4 encode quality; gifs are unaffected",
+  "settings.hint.max-duration":
+    "seconds, 1-300 — the recording auto-stops and saves when reached, with a warning 10s before",
+  "settings.toggle.enabled": "enabled",
+  "settings.toggle.disabled": "disabled",
+  "settings.hint.audio": "captures system loopback audio for MP4 recordings",
+  "settings.check.review": "trim before saving",
+  "settings.hint.review":
+    "stopping a recording opens a preview with in/out points; only the kept range is encoded and saved",
+  "settings.check.zoom": "follow the pointer while it moves",
+  "settings.hint.zoom-cursor":
+    "region recordings ease in on the cursor and back out after it rests for a moment",
+  "settings.hint.zoom": "%, 125-400 — 200 shows a quarter of the region",
+  "settings.hint.smoothing": "ms, 0-2000 — how slowly the camera catches up; 0 snaps",
+  "settings.stream.saved": "•••••••••••••••• (saved)",
+  "settings.hint.stream":
+    "experimental — where region-stream tasks push, e.g. rtmp://127.0.0.1/live/key for a local ingest. it usually holds your stream key, so it's stored encrypted. video only",
+  "settings.hint.bitrate": "kbps, 500-50000 — frame rate follows video frame rate above",
+
+  "settings.auto.saved": "saved {path}",
+  "settings.auto.stop": "stop",
+  "settings.auto.start": "start",
+  "settings.auto.capturing": "capturing",
+  "settings.auto.stopped": "stopped",
+  "settings.hint.auto-status":
+    "screenshots on a timer, for time-tracking and progress lapses; also in the tray's capture menu. save pending changes before starting",
+  "settings.hint.interval": "seconds between captures, 5-86400",
+  "settings.auto.active": "active monitor",
+  "settings.auto.all": "all monitors",
+  "settings.placeholder.auto": "auto/ inside the output directory",
+  "settings.hint.keep": "captures; the oldest are deleted past this (0 = unlimited)",
+  "settings.hint.quota": "MiB; the oldest captures are deleted past this (0 = unlimited)",
+  "settings.lapse.fps": "playback fps",
+  "settings.lapse.width": "max width in px (0 = original)",
+  "settings.lapse.assembling": "assembling...",
+  "settings.lapse.assemble": "assemble",
+  "settings.hint.lapse":
+    "plays the auto capture folder back as a video — format, playback fps (gif tops out at 50), max width px (0 = original); saved to the output directory",
+
+  "settings.check.watch": "run new images in a folder through capscr",
+  "settings.hint.watch":
+    "for screenshots another program takes, like a game's screenshot key; images already in the folder are left alone",
+  "settings.placeholder.watch": "folder to watch",
+  "settings.watch.upload": "upload",
+  "settings.watch.clipboard": "copy to clipboard",
+  "settings.watch.save": "save to output folder",
+  "settings.watch.save-copy": "save and copy",
+  "settings.hint.watch-action":
+    "export decorations apply as for a capture; uploads go to the default destination and copy the link if that's on",
+
+  "settings.hdr.png": "png (16-bit, lossless — default)",
+  "settings.hdr.avif": "avif (10-bit av1)",
+  "settings.hdr.jxr": "jpeg xr (scrgb, windows)",
+  "settings.hint.container":
+    "avif is encoded with the recording ffmpeg and uses the output quality. jpeg xr is linear scrgb, so the transfer below doesn't apply.",
+  "settings.hdr.pq": "pq (bt.2020, hdr10-style — default)",
+  "settings.hdr.hlg": "hlg (bt.2020, broadcast-style)",
+  "settings.hint.output-format":
+    "pq is the safest for windows photos / edge. hlg is transcoded from the pq source by decoding to nits then applying hlg oetf.",
+  "settings.tonemap.bt2390": "highlight rolloff (default)",
+  "settings.tonemap.reinhard": "reinhard extended",
+  "settings.tonemap.aces": "aces filmic",
+  "settings.tonemap.hable": "hable filmic",
+  "settings.tonemap.exposure": "exposure (clip)",
+  "settings.hint.operator":
+    "the default keeps sdr content pixel-exact. the filmic curves add contrast and dim sdr slightly; exposure just clips highlights.",
+  "settings.hint.sdr-white":
+    "manual sdr-white target in nits. 0 = auto-detect from the os sdr-content brightness slider (recommended)",
+  "settings.hint.pre-scale": "exposure: multiply luminance before the operator (1.0 = identity)",
+  "settings.p99.on": "p99 (sky / lights ignored)",
+  "settings.p99.off": "pure max, every spike counted",
+  "settings.preserve.on": "writes a .hdr.{container} sidecar next to each hdr capture",
+  "settings.preserve.off": "tonemaps to sdr only — no hdr sidecar saved",
+  "settings.hint.preserve":
+    "source format is HDR10 (the common case). transfer = whichever output format you pick above. fullscreen / active-monitor captures only.",
+
+  "settings.keys.enable": "re-enable",
+  "settings.keys.disable": "disable all",
+  "settings.hint.all-hotkeys": "kill switch toggled from the tray menu too. survives restart.",
+  "settings.hint.stop-recording":
+    "ends whichever recording is running. a recording task's own hotkey still stops it too; a hotkey a task already uses can't be reused here.",
+  "settings.dnd.minutes": "do not disturb minutes",
+  "settings.hint.dnd":
+    "toggles off every other hotkey, notification and sound, e.g. while presenting or gaming. minutes until it turns itself back off; 0 waits for the hotkey or the tray.",
+  "settings.rebind.title": "re-open the desktop's shortcut approval if it was dismissed",
+  "settings.rebind": "re-bind with desktop",
+  "settings.hint.rebind":
+    "the desktop registers and can remap these bindings; the effective column below shows what actually triggers each task.",
+  "settings.hint.evdev":
+    "reads /dev/input directly: needed for mouse-button hotkeys and for keyboards on desktops without the GlobalShortcuts portal. sees all input devices while capscr runs.",
+  "settings.evdev.none":
+    "no readable input devices — add yourself to the input group ({command}), re-login, then",
+  "settings.evdev.recheck": "re-check",
+  "settings.bindings.none": "no tasks defined.",
+  "settings.bindings.task": "task",
+  "settings.bindings.hotkey": "hotkey",
+  "settings.bindings.effective": "effective",
+  "settings.bindings.reason": "reason",
+  "settings.bindings.unbound": "unbound",
+  "settings.bindings.unknown": "unknown",
+  "settings.hook.lede":
+    "live counters from the low-level keyboard hook. press any key and click refresh. if {counter} doesn't climb, the hook isn't installed (or another tool is blocking it).",
+  "settings.hook.missing":
+    "hook telemetry not in response (backend missing the field — old build still running, or non-windows). diag payload: {payload}",
+  "settings.hook.installed": "hook installed",
+  "settings.hook.bindings": "bindings in hook table",
+  "settings.hook.calls": "callback calls total",
+  "settings.hook.keydown": "keydown calls",
+  "settings.hook.matched": "matched a binding",
+  "settings.hook.dispatched": "dispatched to worker",
+  "settings.hook.dropped": "dispatch dropped (queue full)",
+  "settings.hook.last": "last seen (vk + mods)",
+  "settings.hook.mods": "(mods: bit0=Ctrl bit1=Alt bit2=Shift bit3=Win)",
+  "settings.hook.registered": "registered chords pushed to the hook table:",
+  "settings.hook.task": "task id",
+
+  "settings.sftp.known-hosts": "known hosts",
+  "settings.sftp.lede":
+    "capscr remembers each sftp server's public-key fingerprint on first connect and refuses to upload on a later mismatch. forget a host to re-trust a rotated key.",
+  "settings.sftp.empty": "no hosts trusted yet. upload via sftp to populate this list.",
+  "settings.sftp.host": "host:port",
+  "settings.sftp.fingerprint": "fingerprint",
+  "settings.sftp.first-seen": "first seen",
+  "settings.sftp.forget": "forget",
+
+  "settings.notify.silent": "silent",
+  "settings.sound.on": "sound cue on capture / upload",
+  "settings.open-after.on": "open saved captures in the default viewer",
+  "settings.open-after.off": "just save",
+  "settings.autostart.on": "launches automatically at login",
+  "settings.autostart.off": "manual launch only",
+  "settings.hint.autostart": "launch capscr automatically at login (applied on next save)",
+  "settings.check.context-menu": '"Annotate with capscr" on image files',
+  "settings.hint.context-menu":
+    "in the file manager's right-click menu (Open With on Linux); opens a copy of the image in the editor, saved beside your captures (applied on next save)",
+  "settings.minimized.on": "tray only",
+  "settings.minimized.off": "open this window on launch",
+  "settings.hint.minimized":
+    "capscr waits in the tray for hotkeys; click the tray icon to open this window",
+  "settings.close.tray": "minimize to system tray",
+  "settings.close.taskbar": "minimize to taskbar",
+  "settings.close.exit": "exit application",
+  "settings.clipboard-history.on": "saves clipboard-only captures to the local history cache",
+  "settings.clipboard-history.off": "clipboard-only captures are not saved to history",
+  "settings.updates.on": "queries GitHub releases 4s after hub opens",
+  "settings.updates.off": "no network call, you'll need to grab updates manually",
+  "settings.logs.hide": "hide",
+  "settings.logs.view": "view logs",
+  "settings.logs.reveal": "show capscr.log in the file manager",
+  "settings.logs.open": "open log folder",
+  "settings.hint.log":
+    "capscr.log in the config folder, started afresh at 4 MB with the three before it kept; attach it when reporting a capture or upload that failed",
+  "settings.logs.recent": "recent log lines",
+  "settings.logs.empty": "nothing logged yet",
+
+  "settings.theme.dark": "dark",
+  "settings.theme.light": "light",
+  "settings.theme.system": "follow system",
+  "settings.accent.mono": "mono",
+  "settings.accent.amber": "amber",
+  "settings.accent.phosphor": "phosphor",
+  "settings.accent.ice": "ice",
+  "settings.accent.rose": "rose",
+
+  // shared components
+  "common.saving": "saving…",
+  "common.clear": "clear",
+  "common.refresh": "refresh",
+
+  "resize.label": "downscale",
+  "resize.max-dimension": "longest side to",
+  "resize.percent": "scale to",
+  "resize.max-dimension.aria": "max dimension in pixels",
+  "resize.percent.aria": "scale percentage",
+
+  "hotkey.bare":
+    "{hotkey} would steal that key from every app — add a modifier (Ctrl / Alt / Shift / {meta}).",
+  "hotkey.bind": "click to bind…",
+  "hotkey.press": "press a key… (esc to cancel)",
+  "hotkey.clear": "Clear hotkey",
+
+  "color.current": "color {color}",
+  "color.pick": "pick a color",
+  "color.dialog": "color",
+  "color.hue": "hue",
+  "color.hex": "hex color",
+  "color.presets": "presets",
+  "color.recent": "recent colors",
+
+  "compare.dialog": "compare captures",
+  "compare.title": "compare",
+  "compare.slider": "slider",
+  "compare.diff": "diff",
+  "compare.wipe": "wipe position",
+  "compare.diff-image": "pixel diff",
+  "compare.tolerance": "tolerance",
+  "compare.tolerance.label": "tolerance {value}",
+  "compare.comparing": "comparing…",
+  "compare.summary": "{count} px changed ({share}%) · {width}×{height}",
+
+  "review.title": "review recording",
+  "review.frame": "recording frame",
+  "review.scrub": "scrub",
+  "review.discard.confirm": "Discard this recording? It hasn't been saved.",
+  "review.at": "at {time}",
+  "review.in": "in {time}",
+  "review.out": "out {time}",
+  "review.keeping": "keeping {length} of {total}",
+  "review.whole": "whole recording, {total}",
+  "review.discard": "discard",
+  "review.save-trimmed": "save trimmed",
+  "trim.playhead": "playhead",
+
+  "tags.title": "tags and note",
+  "tags.tags": "tags",
+  "tags.placeholder": "bug, login-page",
+  "tags.note": "note",
+
+  "trim.title": "trim recording",
+  "trim.start": "start {time}",
+  "trim.end": "end {time}",
+  "trim.fast": "fast (lossless, start snaps to keyframe)",
+  "trim.length": "length {time}",
+  "trim.exporting": "exporting…",
+  "trim.export": "export trim",
+  "trim.done": "trimmed → {name}",
+
+  "windows.filter": "filter by title or app...",
+  "windows.unknown-app": "(unknown app)",
+  "windows.listing": "listing windows...",
+  "windows.no-match": "no window matches that filter",
+  "windows.none": "no windows to list — on wayland, type the title instead",
+
+  "titlebar.minimize": "minimize",
+  "titlebar.restore": "restore",
+  "titlebar.maximize": "maximize",
+  "common.reload": "reload",
+
+  // tasks view
+  "tasks.mode.region": "region (drag a rect)",
+  "tasks.mode.region-last": "region (last — no drag)",
+  "tasks.mode.active-window": "window (focused — no picker)",
+  "tasks.mode.window": "window (pick one)",
+  "tasks.mode.target-window": "window (by title / process)",
+  "tasks.mode.fullscreen": "fullscreen (primary)",
+  "tasks.mode.active-monitor": "active monitor",
+  "tasks.mode.region-gif": "region gif",
+  "tasks.mode.region-mp4": "region mp4 (video)",
+  "tasks.mode.region-stream": "region stream (rtmp, experimental)",
+
+  "tasks.action.clipboard": "clipboard only",
+  "tasks.action.save-file": "save to output dir",
+  "tasks.action.save-and-clipboard": "save + clipboard",
+  "tasks.action.upload": "upload",
+  "tasks.action.open-editor": "open in editor",
+  "tasks.action.copy-text": "copy detected text (ocr)",
+  "tasks.action.prompt": "prompt",
+  "tasks.action.do-nothing": "do nothing",
+
+  "tasks.gesture.press": "press",
+  "tasks.gesture.double-press": "double press",
+  "tasks.gesture.long-press": "long press (hold ½s)",
+
+  "tasks.size.free": "free (drag a region)",
+  "tasks.size.720p": "1280×720",
+  "tasks.size.1080p": "1920×1080",
+  "tasks.size.custom": "custom",
+
+  "tasks.status.duplicate":
+    "duplicate hotkey: {hotkeys} — tasks sharing a key combo need different gestures",
+  "tasks.status.saving": "saving...",
+  "tasks.status.live.one": "{count} task live.",
+  "tasks.status.live.other": "{count} tasks live.",
+  "tasks.status.fire-error": "fire: {error}",
+
+  "tasks.new-name": "new task",
+  "tasks.lede": "one hotkey, one capture, one post-action.",
+  "tasks.new": "new",
+  "tasks.empty": "no tasks",
+  "tasks.empty.hint": "press {new}, give it a hotkey, save.",
+  "tasks.unbound": "unbound",
+  "tasks.registered": "registered with the keyboard hook",
+  "tasks.live": "live",
+  "tasks.rejected": "rejected",
+  "tasks.required": "required",
+
+  "tasks.field.name": "name",
+  "tasks.field.hotkey": "hotkey",
+  "tasks.hint.hotkey": "click, press combo",
+  "tasks.field.gesture": "gesture",
+  "tasks.hint.gesture":
+    "a plain press on a key with other gestures fires once it can't become one of them",
+  "tasks.field.mode": "capture mode",
+  "tasks.pick-window": "pick from open windows",
+  "tasks.field.window-title": "window title",
+  "tasks.hint.window-title": "regex, case-insensitive — the topmost match is captured",
+  "tasks.field.process": "process",
+  "tasks.hint.process": "optional regex on the app / process name",
+  "tasks.field.post-action": "post-action",
+  "tasks.field.target": "target",
+  "tasks.field.preset": "preset",
+  "tasks.preset.default": "default",
+  "tasks.field.follow": "follow window",
+  "tasks.check.follow": "track the focused window",
+  "tasks.hint.follow":
+    "skips the selector; the recording re-frames on whichever window has focus, scaled to fit the first one's size",
+  "tasks.field.hold": "hold to record",
+  "tasks.check.hold": "record while the hotkey is held",
+  "tasks.hint.hold":
+    "letting go stops and saves; records the last region selected, or opens the selector the first time",
+  "tasks.field.size": "frame size",
+  "tasks.hint.size":
+    "a fixed frame follows the pointer; click or press Enter to start recording there",
+  "tasks.field.delay": "delay",
+  "tasks.delay.global": "global",
+  "tasks.hint.delay": "ms before capture — blank uses the global delay",
+
+  "tasks.fire.title": "run this task now (same path as pressing its hotkey)",
+  "tasks.fire": "fire",
+  "tasks.delete.title": "delete this task",
+  "common.confirm.title": "click again to confirm",
+  "common.confirm": "confirm?",
+
+  // history view
+  "history.filter.all": "all",
+  "history.filter.images": "images",
+  "history.filter.gifs": "gifs",
+  "history.filter.videos": "videos",
+  "history.filter.hdr": "hdr",
+  "history.your-key": "your screenshot key",
+
+  "history.flash.uploading": "uploading...",
+  "history.flash.reuploaded": "re-uploaded",
+  "history.flash.upload-failed": "upload failed: {error}",
+  "history.flash.open-image-failed": "couldn't open the image: {error}",
+  "history.flash.converting": "converting {done}/{total}...",
+  "history.flash.converted.one": "converted {count} image",
+  "history.flash.converted.other": "converted {count} images",
+  "history.flash.convert-failed": "batch convert failed: {error}",
+  "history.flash.uploads-failed": "{failed} of {total} uploads failed",
+  "history.flash.uploaded": "uploaded {count}",
+  "history.flash.batch-failed": "batch upload failed: {error}",
+  "history.flash.stitched": "stitched {count} captures",
+  "history.flash.stitch-failed": "stitch failed: {error}",
+  "history.flash.links-copied": "copied {count} links",
+  "history.flash.copy-failed": "copy failed: {error}",
+  "history.flash.copied": "copied to clipboard",
+  "history.flash.revealed": "revealed in file explorer",
+  "history.flash.open-failed": "open failed: {error}",
+  "history.flash.editor-failed": "editor failed: {error}",
+  "history.flash.extracting": "extracting text...",
+  "history.flash.no-text": "no text found in image",
+  "history.flash.ocr-copied": "OCR: text copied ({count} chars)",
+  "history.flash.clipboard-failed": "failed to copy to clipboard",
+  "history.flash.ocr-failed": "OCR failed: {error}",
+  "history.flash.pin-failed": "pin failed: {error}",
+  "history.flash.deleted": "deleted",
+  "history.flash.delete-failed": "delete failed: {error}",
+
+  "history.reading-dir": "reading dir...",
+  "history.count": "{total} files in output dir",
+  "history.count.filtered": "{shown} of {total} files match",
+  "history.search": "filter by name, #tag or note...",
+  "history.select": "select",
+  "history.upload-selected": "upload {count}",
+  "history.compare.title": "select two captures to compare",
+  "history.stitch.title": "join the selected captures, in the order picked, where they overlap",
+  "history.stitching": "stitching…",
+  "history.stitch": "stitch",
+  "history.open-image.title": "open an image file in the editor",
+  "history.open-image": "open image",
+  "history.convert.title":
+    "run every image in a folder through the output settings into the captures folder",
+  "history.convert": "convert folder",
+  "history.batch.failed": "failed: {error}",
+  "history.batch.lines": "copy as lines",
+  "history.batch.markdown": "copy as markdown",
+  "history.reading": "reading...",
+  "history.empty": "no captures yet",
+  "history.empty.hint":
+    "press {shot} to drag a region capture to clipboard, or {gif} to record a GIF.",
+  "history.empty.rebind": "rebind these in {tasks} · destinations live in {destinations}.",
+  "history.empty.reading-from": "reading from {dir}",
+  "history.no-matches": "no matches",
+  "history.no-matches.hint": "nothing in the output dir matches your filter.",
+  "history.tile.select": "{name}, select for upload",
+  "history.tile.reveal": "{name}, reveal file",
+  "history.tile.edit": "{name}, open in editor",
+  "history.trim": "trim",
+  "history.ocr": "extract text (OCR)",
+  "history.pin": "pin to screen",
+  "history.reupload": "re-upload",
+  "history.open-viewer": "open in os viewer",
+  "history.copy": "copy to clipboard",
+  "history.hdr": "HDR sidecar present (.hdr.png / .avif / .jxr)",
+  "history.tag.title": "show captures tagged {tag}",
+
+  // plugins view
+  "plugins.status.rescanning": "re-scanning...",
+  "plugins.status.done": "done.",
+  "plugins.status.folder-failed": "couldn't open plugins folder: {error}",
+  "plugins.status.installing": "installing {name}...",
+  "plugins.status.installed.review":
+    "installed {name} v{version} — disabled until you review its permissions below and enable it.",
+  "plugins.status.installed": "installed {name} v{version}.",
+  "plugins.status.install-failed": "install failed: {error}",
+  "plugins.status.enabling": "enabling {name}...",
+  "plugins.status.disabling": "disabling {name}...",
+  "plugins.status.enabled": "{name} enabled.",
+  "plugins.status.disabled": "{name} disabled.",
+  "plugins.status.toggle-failed": "toggle failed: {error}",
+  "plugins.uninstall.confirm": "Uninstall {name}? Plugin files will be deleted.",
+  "plugins.status.uninstalling": "uninstalling {name}...",
+  "plugins.status.removed": "removed {name}.",
+  "plugins.status.uninstall-failed": "uninstall failed: {error}",
+
+  "plugins.lede": "browse rot.lt registry · drop plugin dirs into the folder.",
+  "plugins.installed": "installed",
+  "plugins.open-folder": "open folder",
+  "plugins.load-failed.one": "{count} plugin failed to load",
+  "plugins.load-failed.other": "{count} plugins failed to load",
+  "plugins.load-failed.hint": "load runs at launch — restart capscr after fixing a plugin.",
+  "plugins.none": "none installed",
+  "plugins.none.hint": "browse below, or drop a plugin dir into the plugins folder and reload.",
+  "plugins.enabled": "enabled",
+  "plugins.disabled": "disabled",
+  "plugins.grants": "grants",
+  "plugins.disable": "disable",
+  "plugins.enable": "enable",
+  "plugins.uninstall": "uninstall",
+  "plugins.browse": "browse",
+  "plugins.empty-registry": "empty registry",
+  "plugins.empty-registry.hint":
+    "there are no plugins to install yet — drop a plugin dir into the plugins folder, or check back once the registry has entries.",
+  "plugins.site": "site",
+  "plugins.update": "update to v{version}",
+  "plugins.install": "install",
+  "plugins.unreachable": "registry unreachable",
+  "plugins.unreachable.hint":
+    "check Settings → destinations to point at a mirror, or wait for rot.lt to come back.",
+
+  "pin.gone": "this pin's image is no longer available",
+  "pin.image-failed": "couldn't load this pin's image",
+  "pin.failed": "couldn't load this pin",
+  "pin.image": "pinned",
+  "pin.empty": "nothing pinned",
+  "pin.opacity": "adjust opacity",
+
+  "recbar.stop": "stop",
+
+  // editor
+  "editor.font.mono": "mono",
+  "editor.font.sans": "sans",
+  "editor.font.serif": "serif",
+  "editor.font.hand": "handwritten",
+  "editor.layer.name": "layer {n}",
+
+  "editor.stamp.check": "check",
+  "editor.stamp.cross": "cross",
+  "editor.stamp.arrow": "arrow",
+  "editor.stamp.star": "star",
+  "editor.stamp.question": "question",
+  "editor.stamp.warning": "warning",
+  "editor.stamp.thumbs-up": "thumbs up",
+  "editor.stamp.heart": "heart",
+
+  "editor.status.gif":
+    "GIF editing isn't supported — would flatten frames. Close to keep the original.",
+  "editor.status.load-failed": "image load failed: {error}",
+  "editor.status.reopened.one": "reopened with {count} editable annotation.",
+  "editor.status.reopened.other": "reopened with {count} editable annotations.",
+  "editor.status.no-path": "no image path received from backend",
+  "editor.status.pasted": "pasted from clipboard — save to write back to disk.",
+  "editor.status.paste-failed": "paste failed: {error}",
+  "editor.status.paste-text":
+    "paste: only images are accepted here — use the text tool to add text",
+  "editor.discard.pasted":
+    "Discard pasted image and unsaved annotations? Changes aren't written to disk until you press Save.",
+  "editor.discard.load": "Discard unsaved annotations and open a new image?",
+  "editor.discard":
+    "Discard unsaved annotations? They aren't written back to disk until you press Save.",
+  "editor.layer.delete.confirm.one": "Delete {name} and its {count} annotation?",
+  "editor.layer.delete.confirm.other": "Delete {name} and its {count} annotations?",
+  "editor.status.project-failed": "saved, but the editor project failed: {error}",
+  "editor.status.save-failed": "save failed: {error}",
+  "editor.status.saved-copy": "saved a copy to {path}",
+  "editor.status.save-as-failed": "save as failed: {error}",
+  "editor.status.copying": "copying...",
+  "editor.status.copied": "copied to clipboard.",
+  "editor.status.copy-failed": "copy failed: {error}",
+  "editor.status.uploading": "uploading...",
+  "editor.status.upload-failed": "upload failed: {error}",
+  "editor.status.tags-saved": "tags saved.",
+
+  "editor.tools": "tools",
+  "editor.tool.arrow": "arrow (1)",
+  "editor.tool.rect": "rectangle (2)",
+  "editor.tool.text": "text (3)",
+  "editor.tool.blur": "pixelate (4)",
+  "editor.tool.step": "numbered step (5)",
+  "editor.tool.line": "line (6)",
+  "editor.tool.ellipse": "ellipse (7)",
+  "editor.tool.highlight": "highlighter (8)",
+  "editor.tool.pen": "pen (9)",
+  "editor.tool.stamp": "stamp (0)",
+  "editor.tool.measure": "measure (m)",
+  "editor.colors": "colors",
+  "editor.stroke": "stroke",
+  "editor.size": "size",
+  "editor.font": "font",
+  "editor.stamps": "stamps",
+  "editor.layers": "layers",
+  "editor.image.title": "show / hide the capture itself",
+  "editor.image": "image",
+  "editor.layer.hide": "hide {name}",
+  "editor.layer.show": "show {name}",
+  "editor.hide": "hide",
+  "editor.show": "show",
+  "editor.layer.draw": "draw on this layer",
+  "editor.layer.delete": "delete {name}",
+  "editor.layer.delete.title": "delete layer",
+  "editor.layer.new.title": "new layer on top",
+  "editor.layer.new": "layer",
+  "editor.undo": "undo",
+  "editor.redo": "redo",
+  "editor.zoom.actual": "actual size (ctrl+0)",
+  "editor.zoom.fit.title": "fit to window (f)",
+  "editor.zoom.fit": "fit to window",
+  "editor.save-as": "save as…",
+  "editor.tags.title": "tags and note, searchable from history",
+  "editor.destination": "upload destination",
+  "editor.destination.default": "default destination",
+  "editor.upload": "upload",
+  "editor.hdr": "HDR capture detected.",
+  "editor.hdr.flatten":
+    "canvas annotates in SDR; saving from here will flatten the HDR file to SDR. close the editor without saving to keep the original HDR.",
+  "editor.hdr.sidecar":
+    "capscr kept the HDR original as {file} — annotations save into the SDR copy; the HDR sidecar is untouched.",
+  "editor.loading": "loading capture...",
+  "editor.text.placeholder": "type, enter to commit",
+  "editor.sizes": "estimated file size per format at the configured quality",
+
+  // destinations
+  "destinations.lede": "where uploads go.",
+  "destinations.vault.keyring": "the system keyring",
+  "destinations.encrypted.stored": "encrypted at rest with {vault} (per-user)",
+  "destinations.encrypted.on-save": "encrypted at rest with {vault} on save",
+  "destinations.stored": "(stored — leave blank to keep current)",
+  "destinations.saved-secret": "•••••••••••••••• (saved)",
+  "destinations.test": "test",
+  "destinations.test.run": "test connection",
+  "destinations.test.running": "probing...",
+  "destinations.username": "username",
+  "destinations.password": "password",
+  "destinations.host": "host",
+  "destinations.port": "port",
+  "destinations.remote-dir": "remote dir",
+  "destinations.public-url": "public url template",
+  "destinations.public-url.hint": "{filename} → basename, empty = no url returned",
+  "destinations.folder": "folder",
+  "destinations.account": "account",
+  "destinations.signed-in": "(signed in)",
+  "destinations.sign-in": "sign in",
+  "destinations.sign-out": "sign out",
+  "destinations.confirm": "confirm",
+  "destinations.secret": "secret",
+  "destinations.optional": "optional",
+
+  "destinations.status.opening-browser": "opening browser...",
+  "destinations.status.signing-in": "signing in...",
+  "destinations.status.signing-out": "signing out...",
+  "destinations.status.loading-albums": "loading albums...",
+  "destinations.status.creating-album": "creating album...",
+  "destinations.status.decrypting": "decrypting...",
+  "destinations.status.signed-in": "signed in as {name}.",
+  "destinations.status.unnamed": "(unnamed account)",
+  "destinations.status.imgur-pin": "approve capscr in the browser, then paste the PIN here.",
+  "destinations.status.dropbox-code":
+    "approve capscr in the browser, then paste the access code here.",
+  "destinations.status.imgur-out": "signed out — uploads are anonymous again.",
+  "destinations.status.dropbox-out": "signed out of dropbox.",
+  "destinations.status.saved-to": "saved to {path}",
+  "destinations.status.albums.one": "{count} album.",
+  "destinations.status.albums.other": "{count} albums.",
+  "destinations.status.album-created": 'created "{title}" — save to upload into it.',
+  "destinations.status.forgot": "forgot earlier uploads.",
+  "destinations.status.imported": 'imported "{name}" as a new preset.',
+  "destinations.status.preset-limit": "preset limit reached; delete one first",
+
+  "destinations.active": "active target",
+  "destinations.target": "target",
+  "destinations.target.custom": "custom http",
+  "destinations.target.s3": "S3 Compatible",
+  "destinations.target.email": "email",
+  "destinations.target.webhook": "chat webhook",
+  "destinations.imgur.client-id": "imgur client-id",
+  "destinations.imgur.client-id.hint":
+    "leave blank for capscr's shared key; paste your own from api.imgur.com to avoid rate-limit pile-ups.",
+  "destinations.imgur.test.hint": "hits api.imgur.com/3/credits with this client-id.",
+  "destinations.copy-url": "copy url to clipboard",
+  "destinations.copy-url.on": "auto-copy on success",
+  "destinations.copy-url.off": "leave clipboard alone",
+  "destinations.resize.hint": "lanczos, never enlarges · the saved file keeps its own size",
+  "destinations.recompress": "over the size limit",
+  "destinations.recompress.jpeg": "recompress as jpeg",
+  "destinations.recompress.webp": "try lossless webp, then jpeg",
+  "destinations.recompress.off": "fail the upload",
+  "destinations.recompress.min": "lowest quality",
+  "destinations.recompress.hint":
+    "steps down from quality 90 until it fits · imgur takes 20 MB, custom hosts their own limit",
+  "destinations.link-format": "link format",
+  "destinations.link-format.url": "raw url",
+  "destinations.link-format.hint": 'also applies to "copy last url" and recent uploads in the tray',
+  "destinations.dedupe": "repeat uploads",
+  "destinations.dedupe.ask": "ask to reuse the earlier link",
+  "destinations.dedupe.reuse": "reuse the earlier link",
+  "destinations.dedupe.off": "always upload again",
+  "destinations.dedupe.days": "days",
+  "destinations.dedupe.forget": "forget",
+  "destinations.dedupe.hint":
+    "same file, same destination · matched by sha-256, only hashes and links are kept",
+  "destinations.secret-scan": "secret scan",
+  "destinations.secret-scan.on": "ask before uploading likely secrets",
+  "destinations.secret-scan.api-keys": "api keys + tokens",
+  "destinations.secret-scan.emails": "email addresses",
+  "destinations.secret-scan.cards": "card numbers",
+  "destinations.secret-scan.hint":
+    "reads the image's text with ocr before it leaves the machine · a tripwire, not a guarantee",
+
+  "destinations.proxy": "proxy",
+  "destinations.proxy.system": "system proxy",
+  "destinations.proxy.direct": "no proxy",
+  "destinations.proxy.manual": "manual",
+  "destinations.proxy.hint": "for http destinations · ftp and sftp connect directly",
+  "destinations.proxy.url": "proxy url",
+  "destinations.proxy.url.hint": "socks5h resolves names on the proxy",
+
+  "destinations.encryption": "encryption",
+  "destinations.encrypt": "encrypt before upload",
+  "destinations.encrypt.on": "upload ciphertext only",
+  "destinations.encrypt.off": "upload the image as is",
+  "destinations.encrypt.imgur": "imgur only takes images — pick another destination to encrypt",
+  "destinations.encrypt.hint":
+    "aes-256-gcm, fresh key per upload · the key stays in the link's #fragment",
+  "destinations.encrypted-link": "open encrypted link",
+
+  "destinations.imgur.account": "imgur account",
+  "destinations.imgur.secret": "client secret",
+  "destinations.imgur.secret.hint":
+    "imgur only signs in registered apps: register one at api.imgur.com, put its client-id above and its secret here. encrypted at rest with {vault}.",
+  "destinations.imgur.sign-in": "sign in with imgur",
+  "destinations.imgur.pin": "PIN from imgur",
+  "destinations.imgur.anonymous": "uploads stay anonymous until you sign in.",
+  "destinations.album": "album",
+  "destinations.album.none": "(no album)",
+  "destinations.album.default": "default",
+  "destinations.album.new": "new album",
+  "destinations.album.title": "album title",
+  "destinations.album.hidden": "hidden (link only)",
+  "destinations.album.public": "public",
+  "destinations.album.secret": "secret",
+  "destinations.album.create": "create",
+  "destinations.imgur.title": "title",
+  "destinations.imgur.description": "description",
+  "destinations.imgur.meta.hint": "applied to every upload; blank leaves it unset.",
+
+  "destinations.ftp.port.hint": "21 plain, 990 implicit tls",
+  "destinations.ftp.tls": "tls",
+  "destinations.ftp.tls.hint": "plain ftp only — use sftp for an encrypted transfer",
+  "destinations.ftp.test.hint": "connects, logs in, cwd's to remote dir. doesn't upload.",
+  "destinations.sftp.port.hint": "22 standard ssh",
+  "destinations.sftp.key": "private key",
+  "destinations.sftp.key.placeholder": "C:\\Users\\you\\.ssh\\id_ed25519 (blank = password auth)",
+  "destinations.sftp.key.hint": "openssh format. ed25519 / rsa / ecdsa supported.",
+  "destinations.sftp.passphrase": "key passphrase",
+  "destinations.sftp.passphrase.placeholder": "leave blank if the key is unencrypted",
+  "destinations.sftp.password.placeholder": "fallback if key auth fails or no key set",
+  "destinations.sftp.test.hint": "connects, authenticates, lists remote dir. doesn't upload.",
+
+  "destinations.s3.bucket": "Bucket",
+  "destinations.s3.region": "Region",
+  "destinations.s3.endpoint": "Endpoint",
+  "destinations.s3.endpoint.placeholder": "https://s3.amazonaws.com (leave empty for default AWS)",
+  "destinations.s3.access-key": "Access Key ID",
+  "destinations.s3.secret-key": "Secret Access Key",
+  "destinations.s3.vault": "encrypted vault populated",
+  "destinations.s3.public-url": "Public URL Template",
+  "destinations.s3.public-url.hint": "{filename} → basename, empty = default s3 url returned",
+  "destinations.s3.test.hint": "performs a test upload of a small file to verify permissions.",
+
+  "destinations.webdav.server": "server url",
+  "destinations.webdav.server.hint":
+    "nextcloud and owncloud show it under files → settings → webdav",
+  "destinations.webdav.password.placeholder": "an app password, not your login",
+  "destinations.webdav.folder.hint":
+    "below the server url, created as needed; %Y / %m / %d are expanded",
+  "destinations.webdav.link": "link",
+  "destinations.webdav.share": "create a public share link (nextcloud / owncloud)",
+  "destinations.webdav.public-url.hint":
+    "{path} → folder, {filename} → basename; empty = the webdav url (needs your login to open)",
+  "destinations.webdav.test.hint": "checks the url and login without uploading anything.",
+
+  "destinations.dropbox.app-key": "app key",
+  "destinations.dropbox.app-key.hint":
+    "create an app at dropbox.com/developers with the files.content.write and sharing.write permissions and paste its app key here. no secret is needed.",
+  "destinations.dropbox.sign-in": "sign in with dropbox",
+  "destinations.dropbox.code": "access code from dropbox",
+  "destinations.dropbox.kept": "the sign-in is kept in {vault}.",
+  "destinations.dropbox.folder.hint": "each upload gets a shared link, which is what's copied",
+
+  "destinations.email.to": "to",
+  "destinations.email.to.placeholder": "left blank in the new message",
+  "destinations.email.subject": "subject",
+  "destinations.email.hint":
+    "the capture opens as an attachment in your default mail client; you send it from there",
+
+  "destinations.webhook.service": "service",
+  "destinations.webhook.url": "webhook url",
+  "destinations.webhook.url.hint":
+    "anyone with the url can post to the channel, so it's kept in {vault}",
+  "destinations.webhook.message": "message",
+  "destinations.webhook.host": "image host",
+  "destinations.webhook.host.hint":
+    "slack and teams webhooks only take text, so the capture is uploaded here and the message links to it",
+
+  "destinations.preset": "preset",
+  "destinations.preset.default-suffix": " (default)",
+  "destinations.preset.new-name": "custom {n}",
+  "destinations.preset.hint":
+    "tasks and the editor can pick any preset; the default is used otherwise",
+  "destinations.import": "import",
+  "destinations.import.sxcu": "import .sxcu",
+  "destinations.import.hint": "adds a preset from a ShareX uploader file; review, then save.",
+  "destinations.preset.none": "no presets yet — add one or import a .sxcu file.",
+  "destinations.preset.name": "name",
+  "destinations.preset.default": "default preset",
+  "destinations.custom.url": "upload url",
+  "destinations.custom.url.hint": "https only, plain http rejected",
+  "destinations.custom.method": "method",
+  "destinations.custom.post": "POST (multipart)",
+  "destinations.custom.put": "PUT (raw body)",
+  "destinations.custom.method.hint":
+    "PUT sends the file as the body; extra fields become query parameters",
+  "destinations.custom.form": "form field",
+  "destinations.custom.form.hint": 'multipart key (often "file")',
+  "destinations.custom.path": "response path",
+  "destinations.custom.path.hint": "dotted json path to the url, empty = raw body",
+  "destinations.custom.regex": "response regex",
+  "destinations.custom.regex.hint":
+    "overrides the json path when set; first capture group is the url",
+  "destinations.custom.limit": "size limit",
+  "destinations.custom.limit.hint": "MB · 0 = no host limit, larger captures get recompressed",
+  "destinations.custom.headers": "headers",
+  "destinations.custom.value": "value",
+  "destinations.custom.add-header": "add header",
+  "destinations.custom.headers.hint":
+    "secret values (api keys, bearer tokens) are encrypted at rest with {vault}",
+  "destinations.custom.fields": "extra fields",
+  "destinations.custom.add-field": "add field",
+  "destinations.custom.fields.hint": "mark api keys and tokens secret to keep them in {vault}",
+  "destinations.custom.test.hint":
+    "checks headers/fields, then sends OPTIONS to the url. 2xx/3xx/405 = reachable.",
+
+  "destinations.probe": "probe — {destination}",
+  "destinations.probe.ok": "all steps passed — credentials and connectivity look good.",
+  "destinations.probe.failed": "one or more steps failed — see details below.",
+  "destinations.probe.step": "step",
+  "destinations.probe.status": "status",
+  "destinations.probe.detail": "detail",
+  "destinations.probe.pass": "ok",
+  "destinations.probe.fail": "fail",
+};
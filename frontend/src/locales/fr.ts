@@ -0,0 +1,1229 @@
+import type { Bundle } from "../i18n";
+
+export const fr: Bundle = {
+  // sidebar tabs
+  "tab.settings": "réglages",
+  "tab.tasks": "tâches",
+  "tab.history": "historique",
+  "tab.destinations": "destinations",
+  "tab.plugins": "extensions",
+
+  // shared across views
+  "common.copy": "copier",
+  "common.open": "ouvrir",
+  "common.close": "fermer",
+  "common.dismiss": "ignorer",
+  "common.cancel": "annuler",
+  "common.save": "enregistrer",
+  "common.delete": "supprimer",
+  "common.remove": "retirer",
+  "common.add": "ajouter",
+  "common.edit": "modifier",
+  "common.on": "activé",
+  "common.off": "désactivé",
+  "common.none": "aucun",
+  "common.show-in-folder": "afficher dans le dossier",
+  "common.clipboard-busy": "presse-papiers occupé — réessayez",
+  "common.error": "erreur : {error}",
+  "common.save.title": "appliquer les modifications en attente",
+  "common.save.clean": "aucune modification à enregistrer",
+  "common.writing": "écriture...",
+  "common.saved": "enregistré.",
+  "common.browse": "parcourir",
+
+  // hub shell: statusbar, banners, overlays
+  "app.loading-editor": "chargement de l'éditeur…",
+  "app.skip": "aller au contenu",
+  "app.quit.dirty": "Quitter capscr et abandonner les réglages non enregistrés ?",
+  "app.quit.recording": "Quitter capscr ? L'enregistrement en cours est d'abord sauvegardé.",
+  "app.discard-edits": "Abandonner les modifications de réglages non enregistrées ?",
+
+  "app.status.failed": "échec de {kind} : {msg}",
+  "app.status.mail": "ouvert dans votre client mail",
+  "app.status.uploaded": "envoyé : {url}",
+  "app.status.saved": "enregistré dans {name}",
+  "app.status.crash-copied": "rapport de plantage copié",
+  "app.status.crash-error": "rapport de plantage : {error}",
+  "app.status.restored": "enregistrement restauré → {path}",
+  "app.status.recovered-error": "enregistrement récupéré : {error}",
+  "app.status.stats": "enregistré à {achieved} sur {target} ips · {dropped} perdues",
+  "app.status.stats.failed": ", {failed} captures échouées",
+  "app.status.stats.choppy": " — essayez moins d'ips ou une zone plus petite",
+  "app.status.ending": "l'enregistrement s'arrête dans {seconds} s — la durée maximale approche",
+  "app.status.cancelled.one": "{count} enregistrement en attente annulé",
+  "app.status.cancelled.other": "{count} enregistrements en attente annulés",
+
+  "app.toast.recording": "enregistrement — appuyez de nouveau sur {hotkey} pour arrêter",
+  "app.toast.recording.same":
+    "enregistrement — appuyez de nouveau sur le même raccourci pour arrêter",
+  "app.toast.dropped":
+    "{count} fichiers déposés — envoi des {max} premiers, déposez le reste ensuite",
+  "app.toast.hotkey-bound": "raccourci de capture défini",
+  "app.toast.save-failed": "échec de l'enregistrement : {error}",
+  "app.toast.cancel-failed": "annulation impossible : {error}",
+  "app.toast.keys-failed": "impossible de réactiver les raccourcis : {error}",
+  "app.toast.dnd-failed": "impossible de désactiver ne pas déranger : {error}",
+
+  "app.onboarding.title": "bienvenue dans capscr",
+  "app.onboarding.lede": "commençons par définir votre raccourci de capture",
+  "app.onboarding.desc":
+    "tous les claviers n'ont pas de touche Impr. écran. cliquez dans le champ ci-dessous et appuyez sur une combinaison (p. ex. PrintScreen, Ctrl+Alt+S, Alt+Shift+A) pour l'associer.",
+
+  "app.nav": "vues",
+
+  "app.shortcuts.title": "raccourcis clavier",
+  "app.shortcuts.hub": "hub",
+  "app.shortcuts.toggle": "afficher/masquer cet aperçu",
+  "app.shortcuts.settings": "onglet réglages",
+  "app.shortcuts.tasks": "onglet tâches",
+  "app.shortcuts.history": "onglet historique",
+  "app.shortcuts.destinations": "onglet destinations",
+  "app.shortcuts.plugins": "onglet extensions",
+  "app.shortcuts.quit": "quitter capscr",
+  "app.shortcuts.esc": "fermer l'aperçu / masquer le hub",
+  "app.shortcuts.tab": "passer d'un contrôle à l'autre",
+  "app.shortcuts.sidebar": "se déplacer dans la barre latérale",
+  "app.shortcuts.panes": "changer de volet de réglages",
+  "app.shortcuts.open-item": "ouvrir l'élément d'historique sélectionné",
+  "app.shortcuts.editor": "éditeur",
+  "app.shortcuts.arrow": "outil flèche",
+  "app.shortcuts.rect": "outil rectangle",
+  "app.shortcuts.text": "outil texte",
+  "app.shortcuts.blur": "outil flou",
+  "app.shortcuts.more-tools": "étape, ligne, ellipse, surligneur, stylo",
+  "app.shortcuts.stamp": "outil tampon",
+  "app.shortcuts.measure": "outil mesure",
+  "app.shortcuts.save": "enregistrer",
+  "app.shortcuts.copy-upload": "copier / envoyer la capture annotée",
+  "app.shortcuts.close-editor": "fermer l'éditeur",
+  "app.shortcuts.undo": "annuler",
+  "app.shortcuts.redo": "rétablir",
+  "app.shortcuts.paste": "coller une image du presse-papiers",
+  "app.shortcuts.zoom-in-out": "zoom avant / arrière",
+  "app.shortcuts.zoom-100": "zoom 100 %",
+  "app.shortcuts.fit": "ajuster à la fenêtre",
+  "app.shortcuts.pan": "déplacer (ou glisser avec le bouton du milieu)",
+  "app.shortcuts.zoom": "zoom",
+  "app.shortcuts.global": "global (par défaut — modifiable dans les tâches)",
+  "app.shortcuts.region-shot": "capture de zone → enregistrer + presse-papiers",
+  "app.shortcuts.region-gif": "GIF de zone → enregistrer",
+  "app.shortcuts.foot": "appuyez sur {f1} ou {esc} pour fermer",
+
+  "app.log.title": "activité récente",
+  "app.log.empty": "rien pour l'instant dans cette session",
+
+  "app.tray-missing.title": "aucune zone de notification détectée",
+  "app.tray-missing.meta":
+    "fréquent sous GNOME standard. capscr continue de tourner en arrière-plan : les raccourcis globaux fonctionnent, un clic droit sur capscr dans Activités propose les captures, et relancer capscr rouvre cette fenêtre ; {quit} ici le quitte. pour une icône, installez l'extension AppIndicator.",
+  "app.tray-missing.get": "obtenir l'extension",
+  "app.tray-missing.never": "ne plus afficher",
+  "app.crash.title": "capscr a planté",
+  "app.crash.meta":
+    "{when} : {summary}. le rapport contient une trace et le journal qui précède, pour un signalement de bug.",
+  "app.recovered.title": "enregistrement non sauvegardé conservé",
+  "app.recovered.meta":
+    "un enregistrement gif ({frames} images, {seconds} s) n'était pas encore sauvegardé quand capscr s'est arrêté le {when}.",
+  "app.recovered.restore": "restaurer",
+  "app.recovered.discard": "abandonner",
+  "app.update.title": "mise à jour disponible · v{version}",
+  "app.update.current": "vous utilisez la v{version}",
+  "app.update.notes": "nouveautés",
+  "app.update.deb-hint": "ensuite : sudo apt install ./the-downloaded.deb",
+  "app.update.rpm-hint": "ensuite : sudo dnf install ./the-downloaded.rpm",
+  "app.update.deb": "télécharger le .deb",
+  "app.update.rpm": "télécharger le .rpm",
+  "app.update.release": "obtenir la version",
+  "app.update.installing": "installation...",
+  "app.update.install": "installer + redémarrer",
+  "app.update.later": "plus tard",
+
+  "app.bar.unsaved": "non enregistré",
+  "app.bar.enc.cancel": "enregistrement en arrière-plan — cliquez pour annuler ceux en attente",
+  "app.bar.enc": "enregistrement en arrière-plan",
+  "app.bar.keys": "raccourcis désactivés — cliquez pour les réactiver",
+  "app.bar.dnd":
+    "ne pas déranger — raccourcis, notifications et sons coupés. cliquez pour désactiver",
+  "app.bar.game.suspend":
+    "application plein écran active — les raccourcis sans ctrl, alt ou win lui sont transmis",
+  "app.bar.game.wgc":
+    "application plein écran active — les captures passent par Windows.Graphics.Capture",
+  "app.bar.recording": "enregistrement {elapsed}",
+
+  "app.drop.title": "déposer pour envoyer",
+  "app.drop.destination": "destination : {destination}",
+  "app.toasts": "notifications",
+  "app.upload.kind": "envoyé",
+  "app.upload.copy-delete": "copier l'url de suppression",
+
+  // settings view
+  "settings.title": "réglages",
+  "settings.search": "rechercher un réglage...",
+  "settings.no-match": "aucun réglage ne correspond à « {query} ».",
+  "settings.pane.general": "général",
+  "settings.pane.capture": "capture",
+  "settings.pane.hdr": "hdr",
+  "settings.pane.hotkeys": "raccourcis",
+  "settings.pane.ssh": "ssh",
+  "settings.pane.notify": "notifications",
+  "settings.reset": "réinitialiser",
+  "settings.reset.title": "rétablir tous les réglages par défaut",
+  "settings.reset.confirm":
+    "Remplacer tous les réglages par les valeurs par défaut ? Les tâches, raccourcis et destinations seront aussi réinitialisés.",
+  "settings.save": "enregistrer",
+  "settings.saving": "enregistrement...",
+  "settings.status.loading-defaults": "chargement des valeurs par défaut...",
+  "settings.status.defaults-loaded": "chargé — enregistrez pour appliquer.",
+  "settings.language": "langue",
+  "settings.language.hint":
+    "s'applique tout de suite, menu de la zone de notification et notifications compris",
+  "settings.language.system": "langue du système",
+
+  "settings.section.output": "sortie",
+  "settings.section.per-app": "par application",
+  "settings.section.decorations": "décorations à l'export",
+  "settings.section.appearance": "apparence",
+  "settings.section.gnome": "intégration gnome",
+  "settings.section.cursor": "curseur",
+  "settings.section.overlay": "surimpression de sélection",
+  "settings.section.after": "après la capture",
+  "settings.section.window": "capture de fenêtre",
+  "settings.section.timing": "minutage",
+  "settings.section.game": "mode jeu",
+  "settings.section.recording": "enregistrement (gif + mp4)",
+  "settings.section.auto": "capture automatique",
+  "settings.section.watch": "dossier surveillé",
+  "settings.section.hdr-sidecar": "fichier hdr associé",
+  "settings.section.hdr-tonemap": "mappage tonal hdr",
+  "settings.section.global": "global",
+  "settings.section.advanced-input": "saisie avancée",
+  "settings.section.bindings": "état par raccourci",
+  "settings.section.hook": "télémétrie du hook ll (débogage)",
+  "settings.section.sftp": "hôtes sftp connus",
+  "settings.section.feedback": "retour",
+  "settings.section.system": "système",
+  "settings.section.logs": "journaux",
+
+  "settings.field.directory": "dossier",
+  "settings.field.filename": "modèle de nom de fichier",
+  "settings.field.format": "format",
+  "settings.field.quality": "qualité",
+  "settings.field.editor-projects": "projets d'éditeur",
+  "settings.field.archive": "archiver les originaux",
+  "settings.field.scratch": "dossier de travail",
+  "settings.field.scratch-cap": "limite du dossier de travail",
+  "settings.field.retention": "conservation",
+  "settings.field.metadata": "métadonnées",
+  "settings.field.tags": "tags dans les fichiers",
+  "settings.field.profiles": "profils d'application",
+  "settings.field.border": "bordure",
+  "settings.field.corners": "coins arrondis",
+  "settings.field.caption": "légende",
+  "settings.field.theme": "thème",
+  "settings.field.accent": "accent",
+  "settings.field.scale": "échelle de l'interface",
+  "settings.field.compact": "disposition compacte",
+  "settings.field.companion": "extension compagnon",
+  "settings.field.cursor": "afficher le curseur",
+  "settings.field.monitors": "écrans",
+  "settings.field.actions": "actions par défaut",
+  "settings.field.duplicates": "doublons",
+  "settings.field.frame": "cadre",
+  "settings.field.covered": "fenêtres masquées",
+  "settings.field.backend": "moteur de capture",
+  "settings.field.wgc-border": "bordure de capture",
+  "settings.field.ui-elements": "éléments d'interface",
+  "settings.field.delay": "délai avant capture",
+  "settings.field.hide-hub": "masquer le hub",
+  "settings.field.excluded": "applications exclues",
+  "settings.field.exclusion-mode": "quand l'une apparaît",
+  "settings.field.fullscreen": "applications plein écran",
+  "settings.field.gif-fps": "fréquence d'images gif",
+  "settings.field.video-fps": "fréquence d'images vidéo",
+  "settings.field.video-quality": "qualité vidéo",
+  "settings.field.max-duration": "durée maximale",
+  "settings.field.audio": "enregistrer le son",
+  "settings.field.review": "relecture",
+  "settings.field.zoom-cursor": "zoomer sur le curseur",
+  "settings.field.zoom": "zoom",
+  "settings.field.smoothing": "lissage",
+  "settings.field.stream": "diffusion en direct",
+  "settings.field.bitrate": "débit du flux",
+  "settings.field.status": "état",
+  "settings.field.interval": "intervalle",
+  "settings.field.capture": "capturer",
+  "settings.field.folder": "dossier",
+  "settings.field.keep": "en garder au plus",
+  "settings.field.quota": "quota disque",
+  "settings.field.lapse": "accéléré",
+  "settings.field.watch": "surveiller",
+  "settings.field.then": "ensuite",
+  "settings.field.container": "conteneur",
+  "settings.field.output-format": "format de sortie",
+  "settings.field.operator": "opérateur",
+  "settings.field.sdr-white": "blanc sdr forcé",
+  "settings.field.pre-scale": "échelle avant mappage tonal",
+  "settings.field.p99": "p99 maxcll",
+  "settings.field.preserve-hdr": "conserver le hdr",
+  "settings.field.all-hotkeys": "tous les raccourcis",
+  "settings.field.stop-recording": "arrêter l'enregistrement",
+  "settings.field.dnd": "ne pas déranger",
+  "settings.field.hotkey-backend": "moteur",
+  "settings.field.evdev": "saisie brute (evdev)",
+  "settings.field.notifications": "notifications système",
+  "settings.field.sound": "signal sonore",
+  "settings.field.open-after": "ouvrir après l'enregistrement",
+  "settings.field.autostart": "lancer à l'ouverture de session",
+  "settings.field.context-menu": "menu contextuel",
+  "settings.field.minimized": "démarrer réduit",
+  "settings.field.close": "comportement du bouton fermer",
+  "settings.field.clipboard-history": "historique du presse-papiers",
+  "settings.field.updates": "rechercher des mises à jour",
+  "settings.field.log": "fichier journal",
+
+  "settings.browse.title": "choisir un dossier",
+  "settings.pick.output": "Choisir le dossier de sortie",
+  "settings.pick.scratch": "Choisir le dossier de travail",
+  "settings.pick.archive": "Choisir le dossier d'archive",
+  "settings.pick.auto": "Choisir le dossier des captures automatiques",
+  "settings.pick.watch": "Choisir le dossier à surveiller",
+
+  "settings.hint.directory": "chemin absolu ou modèle %env%",
+  "settings.hint.filename":
+    "jetons chrono : %Y année · %m mois · %d jour · %H heure · %M min · %S s · extension ajoutée automatiquement",
+  "settings.hint.quality": "1-100, ignoré pour png/bmp",
+  "settings.check.editor-projects": "garder les annotations modifiables après l'enregistrement",
+  "settings.hint.editor-projects":
+    "écrit un .capscr.json à côté de chaque capture enregistrée depuis l'éditeur, avec une copie complète ; le rouvrir restaure l'image non retouchée avec toutes les annotations encore déplaçables",
+  "settings.check.archive": "garder aussi chaque capture intacte en png sans perte",
+  "settings.placeholder.archive": "originals/ dans le dossier de sortie",
+  "settings.hint.archive":
+    "enregistré avant que les extensions, décorations, réductions ou un format avec pertes ne le modifient, pour retoucher plus tard ; un chemin absolu",
+  "settings.placeholder.scratch": "capscr/ dans le dossier temporaire du système",
+  "settings.hint.scratch":
+    "images et son des enregistrements, pièces jointes ; un chemin absolu, idéalement sur un disque assez grand pour de longs enregistrements",
+  "settings.hint.scratch-cap":
+    "Mio de restes des lancements précédents gardés au démarrage, les plus anciens effacés d'abord ; 0 efface tout",
+  "settings.retention.files-kept": "fichiers conservés",
+  "settings.retention.mib-kept": "Mio conservés",
+  "settings.retention.files": "fichiers",
+  "settings.retention.move-to": "déplacer les anciennes captures vers",
+  "settings.hint.retention":
+    "quand le dossier de sortie en contient plus, les plus anciennes captures sont supprimées ou déplacées dans le dossier indiqué (relatif au dossier de sortie) ; 0 = sans limite · vérifié toutes les 10 minutes",
+  "settings.metadata.keep": "laisser tel quel",
+  "settings.metadata.strip": "tout supprimer",
+  "settings.metadata.embed": "intégrer les infos de capture",
+  "settings.hint.metadata":
+    "png et jpeg uniquement · supprimer nettoie aussi les enregistrements de l'éditeur · intégrer écrit l'heure, l'écran, le titre de la fenêtre et la version de capscr en texte png / exif",
+  "settings.check.tags": "écrire aussi les tags et notes dans le png",
+  "settings.hint.tags":
+    "les tags et notes restent toujours dans l'index d'historique de capscr ; ceci les ajoute en texte png Keywords / Comment pour que d'autres outils les lisent",
+  "settings.hint.resize":
+    "lanczos, n'agrandit jamais · s'applique aux captures enregistrées par raccourci, pas à l'éditeur ; les envois ont leur propre réglage dans destinations",
+
+  "settings.profiles.format": "format ci-dessus",
+  "settings.profiles.action": "action de la tâche",
+  "settings.profiles.subfolder": "sous-dossier",
+  "settings.profiles.files": "fichiers conservés dans le sous-dossier, 0 = sans limite",
+  "settings.profiles.mib": "Mio conservés dans le sous-dossier, 0 = sans limite",
+  "settings.profiles.add": "ajouter une application",
+  "settings.hint.profiles":
+    "les captures de fenêtre d'un processus listé sont enregistrées dans son format, dans un dossier sous le dossier de sortie, et suivent son action plutôt que celle de la tâche · un sous-dossier peut avoir ses propres limites de fichiers et de Mio",
+
+  "settings.deco.border-color": "couleur de bordure",
+  "settings.hint.border": "pixels, 0 pour aucune",
+  "settings.deco.transparent": "transparent",
+  "settings.deco.corner-background": "fond des coins",
+  "settings.hint.corners":
+    "rayon en pixels · les coins transparents exigent png, webp ou avif, les autres formats les affichent en noir",
+  "settings.hint.caption":
+    "texte dans une barre au-dessus de la capture, mêmes jetons % que le modèle de nom · appliqué quand une capture est enregistrée, copiée ou envoyée directement, pas quand elle passe par l'éditeur",
+
+  "settings.hint.theme": "suivre le système bascule avec le mode sombre / clair du système",
+  "settings.accent.custom": "personnalisé",
+  "settings.accent.custom-label": "accent personnalisé",
+  "settings.hint.accent":
+    "teinte les onglets actifs, cases, contours de focus et boutons principaux",
+  "settings.hint.scale":
+    "agrandit le hub en plus de l'échelle d'affichage du système ; appliqué à l'enregistrement",
+  "settings.check.compact": "barre latérale réduite aux touches et lignes resserrées",
+  "settings.hint.compact": "permet de réduire le hub à une fenêtre plus petite",
+
+  "settings.gnome.active":
+    "active — la sélection de fenêtre, les épingles au-dessus du plein écran et le menu de capture de la barre supérieure passent par l'extension",
+  "settings.gnome.installed": "installée — déconnectez-vous puis reconnectez-vous pour l'activer",
+  "settings.gnome.installing": "installation…",
+  "settings.gnome.install": "installer",
+  "settings.gnome.missing":
+    "sans elle, la capture de fenêtre utilise le sélecteur de gnome et les captures épinglées ne restent pas au-dessus des fenêtres plein écran",
+
+  "settings.kind.region": "zone",
+  "settings.kind.window": "fenêtre",
+  "settings.kind.full-screen": "plein écran",
+  "settings.kind.gif": "enregistrement",
+  "settings.cursor.captured": "capturé",
+  "settings.cursor.hidden": "masqué",
+  "settings.hint.cursor": "s'applique aux captures et aux enregistrements",
+  "settings.monitors.pointer": "celui sous le pointeur",
+  "settings.monitors.all": "tous",
+  "settings.hint.monitors":
+    "la sélection de zone et de fenêtre s'ouvre sur l'écran du pointeur au lieu d'assombrir tout le bureau ; plus rapide à partir de trois écrans",
+  "settings.hint.actions":
+    "pour les captures lancées depuis la zone de notification et la jump list ; les tâches à raccourci gardent leur propre action",
+  "settings.duplicates.off": "garder, sans rien dire",
+  "settings.duplicates.warn": "garder, avec une notification",
+  "settings.duplicates.skip": "ignorer",
+  "settings.hint.duplicates":
+    "une capture identique au pixel près à la précédente, prise dans la minute — en général un raccourci pressé deux fois",
+  "settings.frame.visible": "cadre de fenêtre — sans ombre",
+  "settings.frame.shadow": "cadre + ombre portée",
+  "settings.frame.client": "zone cliente seule",
+  "settings.hint.frame": "ce qu'une capture de fenêtre garde autour de son contenu",
+  "settings.corners.left": "laissés tels quels",
+  "settings.hint.window-corners":
+    "windows 11 — efface les coins noirs hors des angles arrondis d'une fenêtre (la transparence exige png/webp/avif)",
+  "settings.covered.own": "contenu propre de la fenêtre",
+  "settings.covered.screen": "ce qui est à l'écran",
+  "settings.hint.covered":
+    "windows — rend la fenêtre choisie elle-même, sans que les fenêtres par-dessus ou le bord de l'écran ne la coupent. les fenêtres hdr et l'ombre portée viennent toujours de l'écran",
+  "settings.backend.auto": "automatique — gdi, mappage tonal cpu pour le hdr",
+  "settings.hint.backend":
+    "windows — wgc voit aussi le contenu accéléré matériellement et certains contenus protégés que gdi rend noirs. repasse sur gdi en cas d'échec. le curseur suit toujours « afficher le curseur »",
+  "settings.wgc-border.shown": "affichée",
+  "settings.wgc-border.suppressed": "masquée",
+  "settings.hint.wgc-border":
+    "le contour jaune que windows dessine pendant une capture. le masquer exige windows 11",
+  "settings.ui-elements.on": "détecter les contrôles",
+  "settings.ui-elements.off": "fenêtres uniquement",
+  "settings.hint.ui-elements":
+    "maintenez ctrl en survolant dans le sélecteur pour surligner boutons, volets et barres d'outils ; un clic capture seulement ce contrôle",
+  "settings.hint.delay":
+    "ms avant de saisir les pixels — utile pour les infobulles / menus (0 = immédiat)",
+  "settings.hide-hub.on": "pendant la capture",
+  "settings.hide-hub.off": "reste ouvert",
+  "settings.hint.hide-hub":
+    "masque cette fenêtre pendant les captures et enregistrements, puis la rétablit",
+  "settings.hint.excluded":
+    "noms de processus séparés par des virgules. leurs fenêtres sont exclues des captures et enregistrements — la fenêtre entière, même là où quelque chose la recouvre",
+  "settings.exclusion.blackout": "noircir ses fenêtres",
+  "settings.exclusion.skip": "ignorer la capture",
+  "settings.hint.exclusion":
+    "les enregistrements noircissent les fenêtres image par image, ou sautent les images tant que l'une est visible. choisir directement une fenêtre exclue échoue toujours",
+
+  "settings.game.off": "ne pas les surveiller",
+  "settings.game.none": "tout laisser tel quel",
+  "settings.game.suspend": "leur laisser les raccourcis simples",
+  "settings.game.wgc": "capturer via WGC",
+  "settings.hint.game":
+    "ce qui change quand une fenêtre couvrant tout son écran (souvent un jeu) a le focus. les raccourcis simples sont ceux sans ctrl, alt ou win — F9, PrintScreen, boutons de souris — que le jeu utilise sans doute aussi ; les combinaisons restent actives. vérifié toutes les quelques secondes",
+  "settings.game.rule.none": "laisser tel quel",
+  "settings.game.rule.suspend": "raccourcis simples désactivés",
+  "settings.hint.game-apps":
+    "noms de processus qui remplacent le choix ci-dessus, p. ex. un lecteur vidéo en plein écran",
+
+  "settings.hint.gif-fps": "ips, 1-60",
+  "settings.hint.video-fps": "ips des enregistrements mp4, 1-60",
+  "settings.video.high": "haute — quasi sans perte, fichiers les plus gros",
+  "settings.video.balanced": "équilibrée",
+  "settings.video.compact": "compacte — fichiers les plus petits",
+  "settings.hint.video-quality": "qualité d'encodage mp4 ; les gif ne sont pas concernés",
+  "settings.hint.max-duration":
+    "secondes, 1-300 — l'enregistrement s'arrête et s'enregistre une fois atteint, avec un avertissement 10 s avant",
+  "settings.toggle.enabled": "activé",
+  "settings.toggle.disabled": "désactivé",
+  "settings.hint.audio": "capture le son du système (loopback) pour les enregistrements MP4",
+  "settings.check.review": "découper avant d'enregistrer",
+  "settings.hint.review":
+    "arrêter un enregistrement ouvre un aperçu avec points d'entrée/sortie ; seule la plage conservée est encodée et enregistrée",
+  "settings.check.zoom": "suivre le pointeur quand il bouge",
+  "settings.hint.zoom-cursor":
+    "les enregistrements de zone zooment en douceur sur le curseur puis reculent quand il s'arrête un instant",
+  "settings.hint.zoom": "%, 125-400 — 200 montre un quart de la zone",
+  "settings.hint.smoothing":
+    "ms, 0-2000 — lenteur avec laquelle la caméra rattrape ; 0 saute directement",
+  "settings.stream.saved": "•••••••••••••••• (enregistré)",
+  "settings.hint.stream":
+    "expérimental — là où les tâches de diffusion de zone envoient, p. ex. rtmp://127.0.0.1/live/key pour une réception locale. contient souvent votre clé de diffusion, donc stocké chiffré. vidéo seule",
+  "settings.hint.bitrate":
+    "kbps, 500-50000 — la fréquence d'images suit la fréquence vidéo ci-dessus",
+
+  "settings.auto.saved": "enregistré : {path}",
+  "settings.auto.stop": "arrêter",
+  "settings.auto.start": "démarrer",
+  "settings.auto.capturing": "capture en cours",
+  "settings.auto.stopped": "arrêté",
+  "settings.hint.auto-status":
+    "captures à intervalle, pour le suivi du temps et les accélérés de progression ; aussi dans le menu de capture de la zone de notification. enregistrez les modifications en attente avant de démarrer",
+  "settings.hint.interval": "secondes entre les captures, 5-86400",
+  "settings.auto.active": "écran actif",
+  "settings.auto.all": "tous les écrans",
+  "settings.placeholder.auto": "auto/ dans le dossier de sortie",
+  "settings.hint.keep": "captures ; au-delà, les plus anciennes sont supprimées (0 = illimité)",
+  "settings.hint.quota":
+    "Mio ; au-delà, les plus anciennes captures sont supprimées (0 = illimité)",
+  "settings.lapse.fps": "ips de lecture",
+  "settings.lapse.width": "largeur max en px (0 = originale)",
+  "settings.lapse.assembling": "assemblage...",
+  "settings.lapse.assemble": "assembler",
+  "settings.hint.lapse":
+    "rejoue le dossier des captures automatiques en vidéo — format, ips de lecture (50 max en gif), largeur max px (0 = originale) ; enregistré dans le dossier de sortie",
+
+  "settings.check.watch": "passer les nouvelles images d'un dossier dans capscr",
+  "settings.hint.watch":
+    "pour les captures faites par un autre programme, comme la touche de capture d'un jeu ; les images déjà présentes sont ignorées",
+  "settings.placeholder.watch": "dossier à surveiller",
+  "settings.watch.upload": "envoyer",
+  "settings.watch.clipboard": "copier dans le presse-papiers",
+  "settings.watch.save": "enregistrer dans le dossier de sortie",
+  "settings.watch.save-copy": "enregistrer et copier",
+  "settings.hint.watch-action":
+    "les décorations s'appliquent comme pour une capture ; les envois vont à la destination par défaut et copient le lien si c'est activé",
+
+  "settings.hdr.png": "png (16 bits, sans perte — par défaut)",
+  "settings.hdr.avif": "avif (av1 10 bits)",
+  "settings.hdr.jxr": "jpeg xr (scrgb, windows)",
+  "settings.hint.container":
+    "l'avif est encodé avec le ffmpeg des enregistrements et utilise la qualité de sortie. le jpeg xr est en scrgb linéaire, la fonction de transfert ci-dessous ne s'applique donc pas.",
+  "settings.hdr.pq": "pq (bt.2020, type hdr10 — par défaut)",
+  "settings.hdr.hlg": "hlg (bt.2020, type diffusion)",
+  "settings.hint.output-format":
+    "pq est le plus sûr pour photos windows / edge. hlg est transcodé depuis la source pq en décodant en nits puis en appliquant l'oetf hlg.",
+  "settings.tonemap.bt2390": "atténuation des hautes lumières (par défaut)",
+  "settings.tonemap.reinhard": "reinhard étendu",
+  "settings.tonemap.aces": "aces filmique",
+  "settings.tonemap.hable": "hable filmique",
+  "settings.tonemap.exposure": "exposition (écrêtage)",
+  "settings.hint.operator":
+    "le réglage par défaut garde le contenu sdr exact au pixel près. les courbes filmiques ajoutent du contraste et assombrissent un peu le sdr ; l'exposition écrête seulement les hautes lumières.",
+  "settings.hint.sdr-white":
+    "cible de blanc sdr manuelle en nits. 0 = détection automatique depuis le curseur de luminosité sdr du système (recommandé)",
+  "settings.hint.pre-scale":
+    "exposition : multiplier la luminance avant l'opérateur (1.0 = identité)",
+  "settings.p99.on": "p99 (ciel / lumières ignorés)",
+  "settings.p99.off": "max pur, chaque pic compté",
+  "settings.preserve.on": "écrit un fichier .hdr.{container} à côté de chaque capture hdr",
+  "settings.preserve.off": "mappage vers sdr seulement — aucun fichier hdr associé",
+  "settings.hint.preserve":
+    "le format source est HDR10 (le cas courant). transfert = le format de sortie choisi ci-dessus. captures plein écran / écran actif uniquement.",
+
+  "settings.keys.enable": "réactiver",
+  "settings.keys.disable": "tout désactiver",
+  "settings.hint.all-hotkeys":
+    "interrupteur général, aussi dans le menu de la zone de notification. survit au redémarrage.",
+  "settings.hint.stop-recording":
+    "termine l'enregistrement en cours. le raccourci propre à une tâche d'enregistrement l'arrête aussi ; un raccourci déjà utilisé par une tâche ne peut pas servir ici.",
+  "settings.dnd.minutes": "minutes de ne pas déranger",
+  "settings.hint.dnd":
+    "coupe tous les autres raccourcis, notifications et sons, p. ex. en présentation ou en jeu. minutes avant la désactivation automatique ; 0 attend le raccourci ou la zone de notification.",
+  "settings.rebind.title": "rouvrir l'approbation des raccourcis du bureau si elle a été fermée",
+  "settings.rebind": "réassocier avec le bureau",
+  "settings.hint.rebind":
+    "le bureau enregistre ces raccourcis et peut les modifier ; la colonne effectif ci-dessous montre ce qui déclenche réellement chaque tâche.",
+  "settings.hint.evdev":
+    "lit /dev/input directement : nécessaire pour les raccourcis de boutons de souris et pour les claviers sur les bureaux sans le portail GlobalShortcuts. voit tous les périphériques d'entrée tant que capscr tourne.",
+  "settings.evdev.none":
+    "aucun périphérique d'entrée lisible — ajoutez-vous au groupe input ({command}), reconnectez-vous, puis",
+  "settings.evdev.recheck": "revérifier",
+  "settings.bindings.none": "aucune tâche définie.",
+  "settings.bindings.task": "tâche",
+  "settings.bindings.hotkey": "raccourci",
+  "settings.bindings.effective": "effectif",
+  "settings.bindings.reason": "raison",
+  "settings.bindings.unbound": "non défini",
+  "settings.bindings.unknown": "inconnu",
+  "settings.hook.lede":
+    "compteurs en direct du hook clavier bas niveau. appuyez sur une touche puis cliquez sur actualiser. si {counter} n'augmente pas, le hook n'est pas installé (ou un autre outil le bloque).",
+  "settings.hook.missing":
+    "télémétrie du hook absente de la réponse (champ manquant côté backend — ancienne version encore lancée, ou hors windows). données diag : {payload}",
+  "settings.hook.installed": "hook installé",
+  "settings.hook.bindings": "raccourcis dans la table du hook",
+  "settings.hook.calls": "appels de rappel au total",
+  "settings.hook.keydown": "appels keydown",
+  "settings.hook.matched": "raccourci reconnu",
+  "settings.hook.dispatched": "transmis au worker",
+  "settings.hook.dropped": "transmission abandonnée (file pleine)",
+  "settings.hook.last": "dernier vu (vk + mods)",
+  "settings.hook.mods": "(mods : bit0=Ctrl bit1=Alt bit2=Shift bit3=Win)",
+  "settings.hook.registered": "combinaisons enregistrées dans la table du hook :",
+  "settings.hook.task": "id de tâche",
+
+  "settings.sftp.known-hosts": "hôtes connus",
+  "settings.sftp.lede":
+    "capscr retient l'empreinte de la clé publique de chaque serveur sftp à la première connexion et refuse d'envoyer en cas de différence ultérieure. oubliez un hôte pour faire confiance à une nouvelle clé.",
+  "settings.sftp.empty":
+    "aucun hôte de confiance pour l'instant. envoyez via sftp pour remplir cette liste.",
+  "settings.sftp.host": "hôte:port",
+  "settings.sftp.fingerprint": "empreinte",
+  "settings.sftp.first-seen": "vu la première fois",
+  "settings.sftp.forget": "oublier",
+
+  "settings.notify.silent": "silencieux",
+  "settings.sound.on": "signal sonore à la capture / l'envoi",
+  "settings.open-after.on": "ouvrir les captures enregistrées dans la visionneuse par défaut",
+  "settings.open-after.off": "enregistrer seulement",
+  "settings.autostart.on": "se lance automatiquement à l'ouverture de session",
+  "settings.autostart.off": "lancement manuel uniquement",
+  "settings.hint.autostart":
+    "lancer capscr automatiquement à l'ouverture de session (appliqué au prochain enregistrement)",
+  "settings.check.context-menu": "« Annoter avec capscr » sur les fichiers image",
+  "settings.hint.context-menu":
+    "dans le menu clic droit du gestionnaire de fichiers (Ouvrir avec sous Linux) ; ouvre une copie de l'image dans l'éditeur, enregistrée à côté de vos captures (appliqué au prochain enregistrement)",
+  "settings.minimized.on": "zone de notification seulement",
+  "settings.minimized.off": "ouvrir cette fenêtre au lancement",
+  "settings.hint.minimized":
+    "capscr attend les raccourcis dans la zone de notification ; cliquez sur l'icône pour ouvrir cette fenêtre",
+  "settings.close.tray": "réduire dans la zone de notification",
+  "settings.close.taskbar": "réduire dans la barre des tâches",
+  "settings.close.exit": "quitter l'application",
+  "settings.clipboard-history.on":
+    "enregistre les captures presse-papiers seules dans le cache d'historique local",
+  "settings.clipboard-history.off":
+    "les captures presse-papiers seules ne sont pas gardées dans l'historique",
+  "settings.updates.on": "interroge les versions GitHub 4 s après l'ouverture du hub",
+  "settings.updates.off": "aucun appel réseau, il faudra récupérer les mises à jour vous-même",
+  "settings.logs.hide": "masquer",
+  "settings.logs.view": "voir les journaux",
+  "settings.logs.reveal": "afficher capscr.log dans le gestionnaire de fichiers",
+  "settings.logs.open": "ouvrir le dossier des journaux",
+  "settings.hint.log":
+    "capscr.log dans le dossier de configuration, recommencé à 4 Mo en gardant les trois précédents ; joignez-le pour signaler une capture ou un envoi échoué",
+  "settings.logs.recent": "dernières lignes du journal",
+  "settings.logs.empty": "rien de journalisé pour l'instant",
+
+  "settings.theme.dark": "sombre",
+  "settings.theme.light": "clair",
+  "settings.theme.system": "suivre le système",
+  "settings.accent.mono": "mono",
+  "settings.accent.amber": "ambre",
+  "settings.accent.phosphor": "phosphore",
+  "settings.accent.ice": "glace",
+  "settings.accent.rose": "rose",
+
+  // shared components
+  "common.saving": "enregistrement…",
+  "common.clear": "effacer",
+  "common.refresh": "actualiser",
+
+  "resize.label": "réduire",
+  "resize.max-dimension": "plus grand côté à",
+  "resize.percent": "mettre à l'échelle",
+  "resize.max-dimension.aria": "dimension maximale en pixels",
+  "resize.percent.aria": "pourcentage d'échelle",
+
+  "hotkey.bare":
+    "{hotkey} priverait toutes les applications de cette touche — ajoutez un modificateur (Ctrl / Alt / Shift / {meta}).",
+  "hotkey.bind": "cliquez pour assigner…",
+  "hotkey.press": "appuyez sur une touche… (échap pour annuler)",
+  "hotkey.clear": "effacer le raccourci",
+
+  "color.current": "couleur {color}",
+  "color.pick": "choisir une couleur",
+  "color.dialog": "couleur",
+  "color.hue": "teinte",
+  "color.hex": "couleur hex",
+  "color.presets": "préréglages",
+  "color.recent": "couleurs récentes",
+
+  "compare.dialog": "comparer les captures",
+  "compare.title": "comparer",
+  "compare.slider": "curseur",
+  "compare.diff": "différence",
+  "compare.wipe": "position du volet",
+  "compare.diff-image": "différence de pixels",
+  "compare.tolerance": "tolérance",
+  "compare.tolerance.label": "tolérance {value}",
+  "compare.comparing": "comparaison…",
+  "compare.summary": "{count} px modifiés ({share} %) · {width}×{height}",
+
+  "review.title": "vérifier l'enregistrement",
+  "review.frame": "image de l'enregistrement",
+  "review.scrub": "parcourir",
+  "review.discard.confirm": "Abandonner cet enregistrement ? Il n'a pas été enregistré.",
+  "review.at": "à {time}",
+  "review.in": "début {time}",
+  "review.out": "fin {time}",
+  "review.keeping": "conserve {length} sur {total}",
+  "review.whole": "enregistrement entier, {total}",
+  "review.discard": "abandonner",
+  "review.save-trimmed": "enregistrer la coupe",
+  "trim.playhead": "tête de lecture",
+
+  "tags.title": "tags et note",
+  "tags.tags": "tags",
+  "tags.placeholder": "bug, page-connexion",
+  "tags.note": "note",
+
+  "trim.title": "couper l'enregistrement",
+  "trim.start": "début {time}",
+  "trim.end": "fin {time}",
+  "trim.fast": "rapide (sans perte, le début s'aligne sur une image clé)",
+  "trim.length": "durée {time}",
+  "trim.exporting": "export…",
+  "trim.export": "exporter la coupe",
+  "trim.done": "coupé → {name}",
+
+  "windows.filter": "filtrer par titre ou application...",
+  "windows.unknown-app": "(application inconnue)",
+  "windows.listing": "liste des fenêtres...",
+  "windows.no-match": "aucune fenêtre ne correspond à ce filtre",
+  "windows.none": "aucune fenêtre à lister — sous wayland, tapez plutôt le titre",
+
+  "titlebar.minimize": "réduire",
+  "titlebar.restore": "restaurer",
+  "titlebar.maximize": "agrandir",
+  "common.reload": "recharger",
+
+  // tasks view
+  "tasks.mode.region": "zone (tracer un rectangle)",
+  "tasks.mode.region-last": "zone (dernière — sans tracer)",
+  "tasks.mode.active-window": "fenêtre (active — sans sélecteur)",
+  "tasks.mode.window": "fenêtre (à choisir)",
+  "tasks.mode.target-window": "fenêtre (par titre / processus)",
+  "tasks.mode.fullscreen": "plein écran (principal)",
+  "tasks.mode.active-monitor": "écran actif",
+  "tasks.mode.region-gif": "zone gif",
+  "tasks.mode.region-mp4": "zone mp4 (vidéo)",
+  "tasks.mode.region-stream": "diffuser une zone (rtmp, expérimental)",
+
+  "tasks.action.clipboard": "presse-papiers seulement",
+  "tasks.action.save-file": "enregistrer dans le dossier de sortie",
+  "tasks.action.save-and-clipboard": "enregistrer + presse-papiers",
+  "tasks.action.upload": "envoyer",
+  "tasks.action.open-editor": "ouvrir dans l'éditeur",
+  "tasks.action.copy-text": "copier le texte détecté (ocr)",
+  "tasks.action.prompt": "demander",
+  "tasks.action.do-nothing": "ne rien faire",
+
+  "tasks.gesture.press": "appui",
+  "tasks.gesture.double-press": "double appui",
+  "tasks.gesture.long-press": "appui long (maintenir ½ s)",
+
+  "tasks.size.free": "libre (tracer une zone)",
+  "tasks.size.720p": "1280×720",
+  "tasks.size.1080p": "1920×1080",
+  "tasks.size.custom": "personnalisé",
+
+  "tasks.status.duplicate":
+    "raccourci en double : {hotkeys} — des tâches qui partagent une combinaison ont besoin de gestes différents",
+  "tasks.status.saving": "enregistrement...",
+  "tasks.status.live.one": "{count} tâche active.",
+  "tasks.status.live.other": "{count} tâches actives.",
+  "tasks.status.fire-error": "lancement : {error}",
+
+  "tasks.new-name": "nouvelle tâche",
+  "tasks.lede": "un raccourci, une capture, une action ensuite.",
+  "tasks.new": "nouvelle",
+  "tasks.empty": "aucune tâche",
+  "tasks.empty.hint": "appuyez sur {new}, donnez-lui un raccourci, enregistrez.",
+  "tasks.unbound": "non assigné",
+  "tasks.registered": "enregistré auprès du hook clavier",
+  "tasks.live": "actif",
+  "tasks.rejected": "refusé",
+  "tasks.required": "requis",
+
+  "tasks.field.name": "nom",
+  "tasks.field.hotkey": "raccourci",
+  "tasks.hint.hotkey": "cliquez, appuyez sur la combinaison",
+  "tasks.field.gesture": "geste",
+  "tasks.hint.gesture":
+    "un simple appui sur une touche qui a d'autres gestes se déclenche dès qu'il ne peut plus devenir l'un d'eux",
+  "tasks.field.mode": "mode de capture",
+  "tasks.pick-window": "choisir parmi les fenêtres ouvertes",
+  "tasks.field.window-title": "titre de la fenêtre",
+  "tasks.hint.window-title":
+    "regex, insensible à la casse — la correspondance la plus haute est capturée",
+  "tasks.field.process": "processus",
+  "tasks.hint.process": "regex facultative sur le nom de l'application / du processus",
+  "tasks.field.post-action": "action ensuite",
+  "tasks.field.target": "cible",
+  "tasks.field.preset": "préréglage",
+  "tasks.preset.default": "par défaut",
+  "tasks.field.follow": "suivre la fenêtre",
+  "tasks.check.follow": "suivre la fenêtre active",
+  "tasks.hint.follow":
+    "ignore le sélecteur ; l'enregistrement recadre sur la fenêtre active, mise à l'échelle de la taille de la première",
+  "tasks.field.hold": "maintenir pour enregistrer",
+  "tasks.check.hold": "enregistrer tant que le raccourci est maintenu",
+  "tasks.hint.hold":
+    "relâcher arrête et enregistre ; filme la dernière zone choisie, ou ouvre le sélecteur la première fois",
+  "tasks.field.size": "taille du cadre",
+  "tasks.hint.size":
+    "un cadre fixe suit le pointeur ; cliquez ou appuyez sur Entrée pour y lancer l'enregistrement",
+  "tasks.field.delay": "délai",
+  "tasks.delay.global": "global",
+  "tasks.hint.delay": "ms avant la capture — vide utilise le délai global",
+
+  "tasks.fire.title": "lancer cette tâche maintenant (comme en appuyant sur son raccourci)",
+  "tasks.fire": "lancer",
+  "tasks.delete.title": "supprimer cette tâche",
+  "common.confirm.title": "cliquez de nouveau pour confirmer",
+  "common.confirm": "confirmer ?",
+
+  // history view
+  "history.filter.all": "tout",
+  "history.filter.images": "images",
+  "history.filter.gifs": "gif",
+  "history.filter.videos": "vidéos",
+  "history.filter.hdr": "hdr",
+  "history.your-key": "votre touche de capture",
+
+  "history.flash.uploading": "envoi...",
+  "history.flash.reuploaded": "renvoyé",
+  "history.flash.upload-failed": "échec de l'envoi : {error}",
+  "history.flash.open-image-failed": "impossible d'ouvrir l'image : {error}",
+  "history.flash.converting": "conversion {done}/{total}...",
+  "history.flash.converted.one": "{count} image convertie",
+  "history.flash.converted.other": "{count} images converties",
+  "history.flash.convert-failed": "échec de la conversion par lot : {error}",
+  "history.flash.uploads-failed": "{failed} envois sur {total} ont échoué",
+  "history.flash.uploaded": "{count} envoyé(s)",
+  "history.flash.batch-failed": "échec de l'envoi par lot : {error}",
+  "history.flash.stitched": "{count} captures assemblées",
+  "history.flash.stitch-failed": "échec de l'assemblage : {error}",
+  "history.flash.links-copied": "{count} liens copiés",
+  "history.flash.copy-failed": "échec de la copie : {error}",
+  "history.flash.copied": "copié dans le presse-papiers",
+  "history.flash.revealed": "affiché dans l'explorateur de fichiers",
+  "history.flash.open-failed": "échec de l'ouverture : {error}",
+  "history.flash.editor-failed": "échec de l'éditeur : {error}",
+  "history.flash.extracting": "extraction du texte...",
+  "history.flash.no-text": "aucun texte trouvé dans l'image",
+  "history.flash.ocr-copied": "OCR : texte copié ({count} caractères)",
+  "history.flash.clipboard-failed": "échec de la copie dans le presse-papiers",
+  "history.flash.ocr-failed": "échec de l'OCR : {error}",
+  "history.flash.pin-failed": "échec de l'épinglage : {error}",
+  "history.flash.deleted": "supprimé",
+  "history.flash.delete-failed": "échec de la suppression : {error}",
+
+  "history.reading-dir": "lecture du dossier...",
+  "history.count": "{total} fichiers dans le dossier de sortie",
+  "history.count.filtered": "{shown} fichiers sur {total} correspondent",
+  "history.search": "filtrer par nom, #tag ou note...",
+  "history.select": "sélectionner",
+  "history.upload-selected": "envoyer {count}",
+  "history.compare.title": "sélectionnez deux captures à comparer",
+  "history.stitch.title":
+    "assembler les captures choisies, dans l'ordre de sélection, là où elles se chevauchent",
+  "history.stitching": "assemblage…",
+  "history.stitch": "assembler",
+  "history.open-image.title": "ouvrir un fichier image dans l'éditeur",
+  "history.open-image": "ouvrir une image",
+  "history.convert.title":
+    "passer chaque image d'un dossier par les réglages de sortie vers le dossier des captures",
+  "history.convert": "convertir un dossier",
+  "history.batch.failed": "échec : {error}",
+  "history.batch.lines": "copier en lignes",
+  "history.batch.markdown": "copier en markdown",
+  "history.reading": "lecture...",
+  "history.empty": "aucune capture pour l'instant",
+  "history.empty.hint":
+    "appuyez sur {shot} pour capturer une zone dans le presse-papiers, ou sur {gif} pour enregistrer un GIF.",
+  "history.empty.rebind": "modifiez-les dans {tasks} · les destinations sont dans {destinations}.",
+  "history.empty.reading-from": "lecture depuis {dir}",
+  "history.no-matches": "aucun résultat",
+  "history.no-matches.hint": "rien dans le dossier de sortie ne correspond à votre filtre.",
+  "history.tile.select": "{name}, sélectionner pour l'envoi",
+  "history.tile.reveal": "{name}, afficher le fichier",
+  "history.tile.edit": "{name}, ouvrir dans l'éditeur",
+  "history.trim": "couper",
+  "history.ocr": "extraire le texte (OCR)",
+  "history.pin": "épingler à l'écran",
+  "history.reupload": "renvoyer",
+  "history.open-viewer": "ouvrir dans la visionneuse du système",
+  "history.copy": "copier dans le presse-papiers",
+  "history.hdr": "fichier HDR associé présent (.hdr.png / .avif / .jxr)",
+  "history.tag.title": "afficher les captures marquées {tag}",
+
+  // plugins view
+  "plugins.status.rescanning": "nouvelle analyse...",
+  "plugins.status.done": "terminé.",
+  "plugins.status.folder-failed": "impossible d'ouvrir le dossier des extensions : {error}",
+  "plugins.status.installing": "installation de {name}...",
+  "plugins.status.installed.review":
+    "{name} v{version} installé — désactivé tant que vous n'avez pas vérifié ses permissions ci-dessous et ne l'avez pas activé.",
+  "plugins.status.installed": "{name} v{version} installé.",
+  "plugins.status.install-failed": "échec de l'installation : {error}",
+  "plugins.status.enabling": "activation de {name}...",
+  "plugins.status.disabling": "désactivation de {name}...",
+  "plugins.status.enabled": "{name} activé.",
+  "plugins.status.disabled": "{name} désactivé.",
+  "plugins.status.toggle-failed": "échec du basculement : {error}",
+  "plugins.uninstall.confirm":
+    "Désinstaller {name} ? Les fichiers de l'extension seront supprimés.",
+  "plugins.status.uninstalling": "désinstallation de {name}...",
+  "plugins.status.removed": "{name} supprimé.",
+  "plugins.status.uninstall-failed": "échec de la désinstallation : {error}",
+
+  "plugins.lede":
+    "parcourir le registre rot.lt · déposer des dossiers d'extension dans le dossier.",
+  "plugins.installed": "installées",
+  "plugins.open-folder": "ouvrir le dossier",
+  "plugins.load-failed.one": "{count} extension n'a pas pu être chargée",
+  "plugins.load-failed.other": "{count} extensions n'ont pas pu être chargées",
+  "plugins.load-failed.hint":
+    "le chargement a lieu au lancement — redémarrez capscr après avoir corrigé une extension.",
+  "plugins.none": "aucune installée",
+  "plugins.none.hint":
+    "parcourez ci-dessous, ou déposez un dossier d'extension dans le dossier des extensions et rechargez.",
+  "plugins.enabled": "activée",
+  "plugins.disabled": "désactivée",
+  "plugins.grants": "autorise",
+  "plugins.disable": "désactiver",
+  "plugins.enable": "activer",
+  "plugins.uninstall": "désinstaller",
+  "plugins.browse": "parcourir",
+  "plugins.empty-registry": "registre vide",
+  "plugins.empty-registry.hint":
+    "il n'y a pas encore d'extension à installer — déposez un dossier d'extension dans le dossier des extensions, ou revenez quand le registre aura des entrées.",
+  "plugins.site": "site",
+  "plugins.update": "mettre à jour vers v{version}",
+  "plugins.install": "installer",
+  "plugins.unreachable": "registre injoignable",
+  "plugins.unreachable.hint":
+    "vérifiez Réglages → destinations pour pointer vers un miroir, ou attendez le retour de rot.lt.",
+
+  "pin.gone": "l'image de cet épinglage n'est plus disponible",
+  "pin.image-failed": "impossible de charger l'image de cet épinglage",
+  "pin.failed": "impossible de charger cet épinglage",
+  "pin.image": "épinglé",
+  "pin.empty": "rien d'épinglé",
+  "pin.opacity": "régler l'opacité",
+
+  "recbar.stop": "arrêter",
+
+  // editor
+  "editor.font.mono": "mono",
+  "editor.font.sans": "sans",
+  "editor.font.serif": "serif",
+  "editor.font.hand": "manuscrite",
+  "editor.layer.name": "calque {n}",
+
+  "editor.stamp.check": "coche",
+  "editor.stamp.cross": "croix",
+  "editor.stamp.arrow": "flèche",
+  "editor.stamp.star": "étoile",
+  "editor.stamp.question": "question",
+  "editor.stamp.warning": "avertissement",
+  "editor.stamp.thumbs-up": "pouce levé",
+  "editor.stamp.heart": "cœur",
+
+  "editor.status.gif":
+    "l'édition de GIF n'est pas prise en charge — les images seraient aplaties. Fermez pour garder l'original.",
+  "editor.status.load-failed": "échec du chargement de l'image : {error}",
+  "editor.status.reopened.one": "rouvert avec {count} annotation modifiable.",
+  "editor.status.reopened.other": "rouvert avec {count} annotations modifiables.",
+  "editor.status.no-path": "aucun chemin d'image reçu du backend",
+  "editor.status.pasted":
+    "collé depuis le presse-papiers — enregistrez pour l'écrire sur le disque.",
+  "editor.status.paste-failed": "échec du collage : {error}",
+  "editor.status.paste-text":
+    "coller : seules les images sont acceptées ici — utilisez l'outil texte pour ajouter du texte",
+  "editor.discard.pasted":
+    "Abandonner l'image collée et les annotations non enregistrées ? Rien n'est écrit sur le disque avant d'appuyer sur Enregistrer.",
+  "editor.discard.load":
+    "Abandonner les annotations non enregistrées et ouvrir une nouvelle image ?",
+  "editor.discard":
+    "Abandonner les annotations non enregistrées ? Elles ne sont écrites sur le disque qu'en appuyant sur Enregistrer.",
+  "editor.layer.delete.confirm.one": "Supprimer {name} et son annotation ({count}) ?",
+  "editor.layer.delete.confirm.other": "Supprimer {name} et ses {count} annotations ?",
+  "editor.status.project-failed": "enregistré, mais le projet de l'éditeur a échoué : {error}",
+  "editor.status.save-failed": "échec de l'enregistrement : {error}",
+  "editor.status.saved-copy": "copie enregistrée dans {path}",
+  "editor.status.save-as-failed": "échec de l'enregistrement sous : {error}",
+  "editor.status.copying": "copie...",
+  "editor.status.copied": "copié dans le presse-papiers.",
+  "editor.status.copy-failed": "échec de la copie : {error}",
+  "editor.status.uploading": "envoi...",
+  "editor.status.upload-failed": "échec de l'envoi : {error}",
+  "editor.status.tags-saved": "tags enregistrés.",
+
+  "editor.tools": "outils",
+  "editor.tool.arrow": "flèche (1)",
+  "editor.tool.rect": "rectangle (2)",
+  "editor.tool.text": "texte (3)",
+  "editor.tool.blur": "pixeliser (4)",
+  "editor.tool.step": "étape numérotée (5)",
+  "editor.tool.line": "ligne (6)",
+  "editor.tool.ellipse": "ellipse (7)",
+  "editor.tool.highlight": "surligneur (8)",
+  "editor.tool.pen": "stylo (9)",
+  "editor.tool.stamp": "tampon (0)",
+  "editor.tool.measure": "mesurer (m)",
+  "editor.colors": "couleurs",
+  "editor.stroke": "trait",
+  "editor.size": "taille",
+  "editor.font": "police",
+  "editor.stamps": "tampons",
+  "editor.layers": "calques",
+  "editor.image.title": "afficher / masquer la capture elle-même",
+  "editor.image": "image",
+  "editor.layer.hide": "masquer {name}",
+  "editor.layer.show": "afficher {name}",
+  "editor.hide": "masquer",
+  "editor.show": "afficher",
+  "editor.layer.draw": "dessiner sur ce calque",
+  "editor.layer.delete": "supprimer {name}",
+  "editor.layer.delete.title": "supprimer le calque",
+  "editor.layer.new.title": "nouveau calque au-dessus",
+  "editor.layer.new": "calque",
+  "editor.undo": "annuler",
+  "editor.redo": "rétablir",
+  "editor.zoom.actual": "taille réelle (ctrl+0)",
+  "editor.zoom.fit.title": "ajuster à la fenêtre (f)",
+  "editor.zoom.fit": "ajuster à la fenêtre",
+  "editor.save-as": "enregistrer sous…",
+  "editor.tags.title": "tags et note, recherchables depuis l'historique",
+  "editor.destination": "destination d'envoi",
+  "editor.destination.default": "destination par défaut",
+  "editor.upload": "envoyer",
+  "editor.hdr": "capture HDR détectée.",
+  "editor.hdr.flatten":
+    "le canevas annote en SDR ; enregistrer ici aplatira le fichier HDR en SDR. fermez l'éditeur sans enregistrer pour garder l'original HDR.",
+  "editor.hdr.sidecar":
+    "capscr a gardé l'original HDR sous {file} — les annotations sont enregistrées dans la copie SDR ; le fichier HDR associé reste intact.",
+  "editor.loading": "chargement de la capture...",
+  "editor.text.placeholder": "tapez, entrée pour valider",
+  "editor.sizes": "taille de fichier estimée par format à la qualité configurée",
+
+  // destinations
+  "destinations.lede": "où vont les envois.",
+  "destinations.vault.keyring": "le trousseau du système",
+  "destinations.encrypted.stored": "chiffré au repos avec {vault} (par utilisateur)",
+  "destinations.encrypted.on-save": "chiffré au repos avec {vault} à l'enregistrement",
+  "destinations.stored": "(enregistré — laisser vide pour le conserver)",
+  "destinations.saved-secret": "•••••••••••••••• (enregistré)",
+  "destinations.test": "test",
+  "destinations.test.run": "tester la connexion",
+  "destinations.test.running": "vérification...",
+  "destinations.username": "nom d'utilisateur",
+  "destinations.password": "mot de passe",
+  "destinations.host": "hôte",
+  "destinations.port": "port",
+  "destinations.remote-dir": "dossier distant",
+  "destinations.public-url": "modèle d'url publique",
+  "destinations.public-url.hint": "{filename} → nom du fichier, vide = aucune url renvoyée",
+  "destinations.folder": "dossier",
+  "destinations.account": "compte",
+  "destinations.signed-in": "(connecté)",
+  "destinations.sign-in": "se connecter",
+  "destinations.sign-out": "se déconnecter",
+  "destinations.confirm": "confirmer",
+  "destinations.secret": "secret",
+  "destinations.optional": "facultatif",
+
+  "destinations.status.opening-browser": "ouverture du navigateur...",
+  "destinations.status.signing-in": "connexion...",
+  "destinations.status.signing-out": "déconnexion...",
+  "destinations.status.loading-albums": "chargement des albums...",
+  "destinations.status.creating-album": "création de l'album...",
+  "destinations.status.decrypting": "déchiffrement...",
+  "destinations.status.signed-in": "connecté en tant que {name}.",
+  "destinations.status.unnamed": "(compte sans nom)",
+  "destinations.status.imgur-pin": "autorisez capscr dans le navigateur, puis collez le PIN ici.",
+  "destinations.status.dropbox-code":
+    "autorisez capscr dans le navigateur, puis collez le code d'accès ici.",
+  "destinations.status.imgur-out": "déconnecté — les envois sont de nouveau anonymes.",
+  "destinations.status.dropbox-out": "déconnecté de dropbox.",
+  "destinations.status.saved-to": "enregistré dans {path}",
+  "destinations.status.albums.one": "{count} album.",
+  "destinations.status.albums.other": "{count} albums.",
+  "destinations.status.album-created": "« {title} » créé — enregistrez pour y envoyer.",
+  "destinations.status.forgot": "envois précédents oubliés.",
+  "destinations.status.imported": "« {name} » importé comme nouveau préréglage.",
+  "destinations.status.preset-limit": "limite de préréglages atteinte ; supprimez-en un d'abord",
+
+  "destinations.active": "cible active",
+  "destinations.target": "cible",
+  "destinations.target.custom": "http personnalisé",
+  "destinations.target.s3": "compatible S3",
+  "destinations.target.email": "e-mail",
+  "destinations.target.webhook": "webhook de discussion",
+  "destinations.imgur.client-id": "client-id imgur",
+  "destinations.imgur.client-id.hint":
+    "laissez vide pour la clé partagée de capscr ; collez la vôtre depuis api.imgur.com pour éviter les limites de débit.",
+  "destinations.imgur.test.hint": "interroge api.imgur.com/3/credits avec ce client-id.",
+  "destinations.copy-url": "copier l'url dans le presse-papiers",
+  "destinations.copy-url.on": "copie automatique en cas de succès",
+  "destinations.copy-url.off": "ne pas toucher au presse-papiers",
+  "destinations.resize.hint": "lanczos, n'agrandit jamais · le fichier enregistré garde sa taille",
+  "destinations.recompress": "au-delà de la taille limite",
+  "destinations.recompress.jpeg": "recompresser en jpeg",
+  "destinations.recompress.webp": "essayer webp sans perte, puis jpeg",
+  "destinations.recompress.off": "faire échouer l'envoi",
+  "destinations.recompress.min": "qualité minimale",
+  "destinations.recompress.hint":
+    "descend depuis la qualité 90 jusqu'à ce que ça passe · imgur accepte 20 Mo, les hôtes personnalisés leur propre limite",
+  "destinations.link-format": "format du lien",
+  "destinations.link-format.url": "url brute",
+  "destinations.link-format.hint":
+    "s'applique aussi à « copier la dernière url » et aux envois récents dans la zone de notification",
+  "destinations.dedupe": "envois répétés",
+  "destinations.dedupe.ask": "proposer de réutiliser le lien précédent",
+  "destinations.dedupe.reuse": "réutiliser le lien précédent",
+  "destinations.dedupe.off": "toujours renvoyer",
+  "destinations.dedupe.days": "jours",
+  "destinations.dedupe.forget": "oublier",
+  "destinations.dedupe.hint":
+    "même fichier, même destination · comparé par sha-256, seuls les hachages et les liens sont conservés",
+  "destinations.secret-scan": "détection de secrets",
+  "destinations.secret-scan.on": "demander avant d'envoyer des secrets probables",
+  "destinations.secret-scan.api-keys": "clés d'api + jetons",
+  "destinations.secret-scan.emails": "adresses e-mail",
+  "destinations.secret-scan.cards": "numéros de carte",
+  "destinations.secret-scan.hint":
+    "lit le texte de l'image par ocr avant qu'elle quitte la machine · un garde-fou, pas une garantie",
+
+  "destinations.proxy": "proxy",
+  "destinations.proxy.system": "proxy du système",
+  "destinations.proxy.direct": "aucun proxy",
+  "destinations.proxy.manual": "manuel",
+  "destinations.proxy.hint": "pour les destinations http · ftp et sftp se connectent directement",
+  "destinations.proxy.url": "url du proxy",
+  "destinations.proxy.url.hint": "socks5h résout les noms sur le proxy",
+
+  "destinations.encryption": "chiffrement",
+  "destinations.encrypt": "chiffrer avant l'envoi",
+  "destinations.encrypt.on": "n'envoyer que le texte chiffré",
+  "destinations.encrypt.off": "envoyer l'image telle quelle",
+  "destinations.encrypt.imgur":
+    "imgur n'accepte que des images — choisissez une autre destination pour chiffrer",
+  "destinations.encrypt.hint":
+    "aes-256-gcm, nouvelle clé à chaque envoi · la clé reste dans le #fragment du lien",
+  "destinations.encrypted-link": "ouvrir un lien chiffré",
+
+  "destinations.imgur.account": "compte imgur",
+  "destinations.imgur.secret": "secret client",
+  "destinations.imgur.secret.hint":
+    "imgur ne connecte que les applications enregistrées : enregistrez-en une sur api.imgur.com, mettez son client-id ci-dessus et son secret ici. chiffré au repos avec {vault}.",
+  "destinations.imgur.sign-in": "se connecter avec imgur",
+  "destinations.imgur.pin": "PIN d'imgur",
+  "destinations.imgur.anonymous": "les envois restent anonymes tant que vous n'êtes pas connecté.",
+  "destinations.album": "album",
+  "destinations.album.none": "(aucun album)",
+  "destinations.album.default": "par défaut",
+  "destinations.album.new": "nouvel album",
+  "destinations.album.title": "titre de l'album",
+  "destinations.album.hidden": "masqué (lien uniquement)",
+  "destinations.album.public": "public",
+  "destinations.album.secret": "secret",
+  "destinations.album.create": "créer",
+  "destinations.imgur.title": "titre",
+  "destinations.imgur.description": "description",
+  "destinations.imgur.meta.hint": "appliqué à chaque envoi ; vide le laisse non défini.",
+
+  "destinations.ftp.port.hint": "21 en clair, 990 tls implicite",
+  "destinations.ftp.tls": "tls",
+  "destinations.ftp.tls.hint": "ftp en clair uniquement — utilisez sftp pour un transfert chiffré",
+  "destinations.ftp.test.hint":
+    "se connecte, s'authentifie, va dans le dossier distant. n'envoie rien.",
+  "destinations.sftp.port.hint": "22 ssh standard",
+  "destinations.sftp.key": "clé privée",
+  "destinations.sftp.key.placeholder":
+    "C:\\Users\\you\\.ssh\\id_ed25519 (vide = authentification par mot de passe)",
+  "destinations.sftp.key.hint": "format openssh. ed25519 / rsa / ecdsa pris en charge.",
+  "destinations.sftp.passphrase": "phrase de passe de la clé",
+  "destinations.sftp.passphrase.placeholder": "laisser vide si la clé n'est pas chiffrée",
+  "destinations.sftp.password.placeholder":
+    "solution de repli si l'authentification par clé échoue ou sans clé",
+  "destinations.sftp.test.hint":
+    "se connecte, s'authentifie, liste le dossier distant. n'envoie rien.",
+
+  "destinations.s3.bucket": "Bucket",
+  "destinations.s3.region": "Région",
+  "destinations.s3.endpoint": "Point de terminaison",
+  "destinations.s3.endpoint.placeholder": "https://s3.amazonaws.com (vide pour AWS par défaut)",
+  "destinations.s3.access-key": "ID de clé d'accès",
+  "destinations.s3.secret-key": "Clé d'accès secrète",
+  "destinations.s3.vault": "coffre chiffré rempli",
+  "destinations.s3.public-url": "Modèle d'URL publique",
+  "destinations.s3.public-url.hint":
+    "{filename} → nom du fichier, vide = url s3 par défaut renvoyée",
+  "destinations.s3.test.hint": "envoie un petit fichier de test pour vérifier les autorisations.",
+
+  "destinations.webdav.server": "url du serveur",
+  "destinations.webdav.server.hint":
+    "nextcloud et owncloud l'affichent sous fichiers → paramètres → webdav",
+  "destinations.webdav.password.placeholder":
+    "un mot de passe d'application, pas celui de connexion",
+  "destinations.webdav.folder.hint":
+    "sous l'url du serveur, créé au besoin ; %Y / %m / %d sont remplacés",
+  "destinations.webdav.link": "lien",
+  "destinations.webdav.share": "créer un lien de partage public (nextcloud / owncloud)",
+  "destinations.webdav.public-url.hint":
+    "{path} → dossier, {filename} → nom du fichier ; vide = l'url webdav (demande votre connexion pour l'ouvrir)",
+  "destinations.webdav.test.hint": "vérifie l'url et la connexion sans rien envoyer.",
+
+  "destinations.dropbox.app-key": "clé d'application",
+  "destinations.dropbox.app-key.hint":
+    "créez une application sur dropbox.com/developers avec les autorisations files.content.write et sharing.write et collez sa clé ici. aucun secret n'est nécessaire.",
+  "destinations.dropbox.sign-in": "se connecter avec dropbox",
+  "destinations.dropbox.code": "code d'accès de dropbox",
+  "destinations.dropbox.kept": "la connexion est conservée dans {vault}.",
+  "destinations.dropbox.folder.hint":
+    "chaque envoi reçoit un lien partagé, c'est lui qui est copié",
+
+  "destinations.email.to": "à",
+  "destinations.email.to.placeholder": "laissé vide dans le nouveau message",
+  "destinations.email.subject": "objet",
+  "destinations.email.hint":
+    "la capture s'ouvre en pièce jointe dans votre client de messagerie par défaut ; vous l'envoyez de là",
+
+  "destinations.webhook.service": "service",
+  "destinations.webhook.url": "url du webhook",
+  "destinations.webhook.url.hint":
+    "toute personne ayant l'url peut publier dans le canal, elle est donc conservée dans {vault}",
+  "destinations.webhook.message": "message",
+  "destinations.webhook.host": "hébergeur d'images",
+  "destinations.webhook.host.hint":
+    "les webhooks slack et teams n'acceptent que du texte, la capture est donc envoyée ici et le message y renvoie",
+
+  "destinations.preset": "préréglage",
+  "destinations.preset.default-suffix": " (par défaut)",
+  "destinations.preset.new-name": "personnalisé {n}",
+  "destinations.preset.hint":
+    "les tâches et l'éditeur peuvent choisir n'importe quel préréglage ; sinon celui par défaut est utilisé",
+  "destinations.import": "importer",
+  "destinations.import.sxcu": "importer un .sxcu",
+  "destinations.import.hint":
+    "ajoute un préréglage depuis un fichier d'envoi ShareX ; vérifiez, puis enregistrez.",
+  "destinations.preset.none": "aucun préréglage — ajoutez-en un ou importez un fichier .sxcu.",
+  "destinations.preset.name": "nom",
+  "destinations.preset.default": "préréglage par défaut",
+  "destinations.custom.url": "url d'envoi",
+  "destinations.custom.url.hint": "https uniquement, http en clair refusé",
+  "destinations.custom.method": "méthode",
+  "destinations.custom.post": "POST (multipart)",
+  "destinations.custom.put": "PUT (corps brut)",
+  "destinations.custom.method.hint":
+    "PUT envoie le fichier comme corps ; les champs supplémentaires deviennent des paramètres de requête",
+  "destinations.custom.form": "champ de formulaire",
+  "destinations.custom.form.hint": "clé multipart (souvent « file »)",
+  "destinations.custom.path": "chemin de la réponse",
+  "destinations.custom.path.hint": "chemin json pointé vers l'url, vide = corps brut",
+  "destinations.custom.regex": "regex de la réponse",
+  "destinations.custom.regex.hint":
+    "remplace le chemin json s'il est défini ; le premier groupe capturé est l'url",
+  "destinations.custom.limit": "taille limite",
+  "destinations.custom.limit.hint":
+    "Mo · 0 = aucune limite d'hôte, les captures plus grandes sont recompressées",
+  "destinations.custom.headers": "en-têtes",
+  "destinations.custom.value": "valeur",
+  "destinations.custom.add-header": "ajouter un en-tête",
+  "destinations.custom.headers.hint":
+    "les valeurs secrètes (clés d'api, jetons bearer) sont chiffrées au repos avec {vault}",
+  "destinations.custom.fields": "champs supplémentaires",
+  "destinations.custom.add-field": "ajouter un champ",
+  "destinations.custom.fields.hint":
+    "marquez les clés d'api et jetons comme secrets pour les garder dans {vault}",
+  "destinations.custom.test.hint":
+    "vérifie les en-têtes/champs, puis envoie OPTIONS à l'url. 2xx/3xx/405 = joignable.",
+
+  "destinations.probe": "vérification — {destination}",
+  "destinations.probe.ok":
+    "toutes les étapes ont réussi — identifiants et connectivité semblent corrects.",
+  "destinations.probe.failed": "une ou plusieurs étapes ont échoué — voir les détails ci-dessous.",
+  "destinations.probe.step": "étape",
+  "destinations.probe.status": "état",
+  "destinations.probe.detail": "détail",
+  "destinations.probe.pass": "ok",
+  "destinations.probe.fail": "échec",
+};
@@ -0,0 +1,1135 @@
+import type { Bundle } from "../i18n";
+
+export const ja: Bundle = {
+  // sidebar tabs
+  "tab.settings": "設定",
+  "tab.tasks": "タスク",
+  "tab.history": "履歴",
+  "tab.destinations": "アップロード先",
+  "tab.plugins": "プラグイン",
+
+  // shared across views
+  "common.copy": "コピー",
+  "common.open": "開く",
+  "common.close": "閉じる",
+  "common.dismiss": "閉じる",
+  "common.cancel": "キャンセル",
+  "common.save": "保存",
+  "common.delete": "削除",
+  "common.remove": "削除",
+  "common.add": "追加",
+  "common.edit": "編集",
+  "common.on": "オン",
+  "common.off": "オフ",
+  "common.none": "なし",
+  "common.show-in-folder": "フォルダーで表示",
+  "common.clipboard-busy": "クリップボードが使用中です — もう一度お試しください",
+  "common.error": "エラー: {error}",
+  "common.save.title": "変更を保存する",
+  "common.save.clean": "保存する変更はありません",
+  "common.writing": "書き込み中...",
+  "common.saved": "保存しました。",
+  "common.browse": "参照",
+
+  // hub shell: statusbar, banners, overlays
+  "app.loading-editor": "エディターを読み込み中…",
+  "app.skip": "本文へスキップ",
+  "app.quit.dirty": "capscr を終了して、保存されていない設定を破棄しますか？",
+  "app.quit.recording": "capscr を終了しますか？実行中の録画は先に保存されます。",
+  "app.discard-edits": "保存されていない設定の変更を破棄しますか？",
+
+  "app.status.failed": "{kind} に失敗しました: {msg}",
+  "app.status.mail": "メールクライアントで開きました",
+  "app.status.uploaded": "アップロードしました: {url}",
+  "app.status.saved": "{name} に保存しました",
+  "app.status.crash-copied": "クラッシュレポートをコピーしました",
+  "app.status.crash-error": "クラッシュレポート: {error}",
+  "app.status.restored": "録画を復元しました → {path}",
+  "app.status.recovered-error": "復元した録画: {error}",
+  "app.status.stats": "{target} fps 中 {achieved} fps で録画 · {dropped} 件を破棄",
+  "app.status.stats.failed": "、{failed} 件の取得失敗",
+  "app.status.stats.choppy": " — fps を下げるか範囲を小さくしてみてください",
+  "app.status.ending": "録画はあと {seconds} 秒で停止します — 最大時間に達します",
+  "app.status.cancelled.one": "待機中の保存を {count} 件キャンセルしました",
+  "app.status.cancelled.other": "待機中の保存を {count} 件キャンセルしました",
+
+  "app.toast.recording": "録画中 — もう一度 {hotkey} を押すと停止します",
+  "app.toast.recording.same": "録画中 — 同じホットキーをもう一度押すと停止します",
+  "app.toast.dropped": "{count} 個のファイルをドロップしました — 最初の {max} 個をアップロードします。残りは完了後にドロップしてください",
+  "app.toast.hotkey-bound": "スクリーンショットのホットキーを設定しました",
+  "app.toast.save-failed": "保存に失敗しました: {error}",
+  "app.toast.cancel-failed": "キャンセルできませんでした: {error}",
+  "app.toast.keys-failed": "ホットキーを再び有効にできませんでした: {error}",
+  "app.toast.dnd-failed": "おやすみモードをオフにできませんでした: {error}",
+
+  "app.onboarding.title": "capscr へようこそ",
+  "app.onboarding.lede": "まずスクリーンショットのショートカットキーを設定しましょう",
+  "app.onboarding.desc":
+    "PrintScreen キーがないキーボードもあります。下の欄をクリックして、割り当てたいキーの組み合わせ（例: PrintScreen、Ctrl+Alt+S、Alt+Shift+A）を押してください。",
+
+  "app.nav": "ビュー",
+
+  "app.shortcuts.title": "キーボードショートカット",
+  "app.shortcuts.hub": "ハブ",
+  "app.shortcuts.toggle": "この一覧を表示/非表示",
+  "app.shortcuts.settings": "設定タブ",
+  "app.shortcuts.tasks": "タスクタブ",
+  "app.shortcuts.history": "履歴タブ",
+  "app.shortcuts.destinations": "アップロード先タブ",
+  "app.shortcuts.plugins": "プラグインタブ",
+  "app.shortcuts.quit": "capscr を終了",
+  "app.shortcuts.esc": "一覧を閉じる / ハブを隠す",
+  "app.shortcuts.tab": "コントロール間を移動",
+  "app.shortcuts.sidebar": "サイドバー内を移動",
+  "app.shortcuts.panes": "設定のペインを切り替え",
+  "app.shortcuts.open-item": "フォーカス中の履歴項目を開く",
+  "app.shortcuts.editor": "エディター",
+  "app.shortcuts.arrow": "矢印ツール",
+  "app.shortcuts.rect": "矩形ツール",
+  "app.shortcuts.text": "テキストツール",
+  "app.shortcuts.blur": "ぼかしツール",
+  "app.shortcuts.more-tools": "ステップ、直線、楕円、蛍光ペン、ペン",
+  "app.shortcuts.stamp": "スタンプツール",
+  "app.shortcuts.measure": "計測ツール",
+  "app.shortcuts.save": "保存",
+  "app.shortcuts.copy-upload": "注釈付きキャプチャをコピー / アップロード",
+  "app.shortcuts.close-editor": "エディターを閉じる",
+  "app.shortcuts.undo": "元に戻す",
+  "app.shortcuts.redo": "やり直す",
+  "app.shortcuts.paste": "クリップボードから画像を貼り付け",
+  "app.shortcuts.zoom-in-out": "拡大 / 縮小",
+  "app.shortcuts.zoom-100": "100% 表示",
+  "app.shortcuts.fit": "ウィンドウに合わせる",
+  "app.shortcuts.pan": "パン（または中ボタンでドラッグ）",
+  "app.shortcuts.zoom": "ズーム",
+  "app.shortcuts.global": "グローバル（既定 — タスクで変更可能）",
+  "app.shortcuts.region-shot": "範囲スクリーンショット → 保存 + クリップボード",
+  "app.shortcuts.region-gif": "範囲 GIF → 保存",
+  "app.shortcuts.foot": "{f1} または {esc} で閉じます",
+
+  "app.log.title": "最近のアクティビティ",
+  "app.log.empty": "このセッションではまだ何もありません",
+
+  "app.tray-missing.title": "システムトレイが見つかりません",
+  "app.tray-missing.meta":
+    "素の GNOME ではよくあります。capscr はバックグラウンドで動き続けます: グローバルホットキーは使え、アクティビティの capscr を右クリックするとキャプチャ操作が出ます。もう一度 capscr を起動するとこのウィンドウが開き、ここで {quit} を押すと終了します。トレイアイコンを使うには AppIndicator 拡張機能を入れてください。",
+  "app.tray-missing.get": "拡張機能を入手",
+  "app.tray-missing.never": "今後表示しない",
+  "app.crash.title": "capscr がクラッシュしました",
+  "app.crash.meta": "{when}: {summary}。レポートにはバックトレースと直前のログが含まれます。不具合報告に使えます。",
+  "app.recovered.title": "未保存の録画を保持しています",
+  "app.recovered.meta": "capscr が最後に停止した {when} の時点で、GIF 録画（{frames} フレーム、{seconds} 秒）が未保存でした。",
+  "app.recovered.restore": "復元",
+  "app.recovered.discard": "破棄",
+  "app.update.title": "アップデートがあります · v{version}",
+  "app.update.current": "現在のバージョンは v{version} です",
+  "app.update.notes": "新機能",
+  "app.update.deb-hint": "その後: sudo apt install ./the-downloaded.deb",
+  "app.update.rpm-hint": "その後: sudo dnf install ./the-downloaded.rpm",
+  "app.update.deb": ".deb をダウンロード",
+  "app.update.rpm": ".rpm をダウンロード",
+  "app.update.release": "リリースを入手",
+  "app.update.installing": "インストール中...",
+  "app.update.install": "インストールして再起動",
+  "app.update.later": "後で",
+
+  "app.bar.unsaved": "未保存",
+  "app.bar.enc.cancel": "バックグラウンドで保存中 — クリックで待機中の保存をキャンセル",
+  "app.bar.enc": "バックグラウンドで保存中",
+  "app.bar.keys": "ホットキーはオフです — クリックで再び有効化",
+  "app.bar.dnd": "おやすみモード — ホットキー、通知、サウンドはオフです。クリックで解除",
+  "app.bar.game.suspend": "全画面アプリにフォーカス中 — ctrl・alt・win なしのホットキーはそのアプリに渡されます",
+  "app.bar.game.wgc": "全画面アプリにフォーカス中 — キャプチャは Windows.Graphics.Capture を使います",
+  "app.bar.recording": "録画中 {elapsed}",
+
+  "app.drop.title": "ドロップしてアップロード",
+  "app.drop.destination": "アップロード先: {destination}",
+  "app.toasts": "通知",
+  "app.upload.kind": "アップロード済み",
+  "app.upload.copy-delete": "削除 URL をコピー",
+
+  // settings view
+  "settings.title": "設定",
+  "settings.search": "設定を検索...",
+  "settings.no-match": "「{query}」に一致する設定はありません。",
+  "settings.pane.general": "一般",
+  "settings.pane.capture": "キャプチャ",
+  "settings.pane.hdr": "HDR",
+  "settings.pane.hotkeys": "ホットキー",
+  "settings.pane.ssh": "SSH",
+  "settings.pane.notify": "通知",
+  "settings.reset": "リセット",
+  "settings.reset.title": "すべての設定を既定値に戻す",
+  "settings.reset.confirm": "すべての設定を既定値に置き換えますか？タスク、ホットキー、アップロード先もリセットされます。",
+  "settings.save": "保存",
+  "settings.saving": "保存中...",
+  "settings.status.loading-defaults": "既定値を読み込み中...",
+  "settings.status.defaults-loaded": "読み込みました — 保存して適用します。",
+  "settings.language": "言語",
+  "settings.language.hint": "トレイメニューや通知も含めてすぐに切り替わります",
+  "settings.language.system": "システムの既定",
+
+  "settings.section.output": "出力",
+  "settings.section.per-app": "アプリごと",
+  "settings.section.decorations": "書き出し時の装飾",
+  "settings.section.appearance": "外観",
+  "settings.section.gnome": "GNOME 連携",
+  "settings.section.cursor": "カーソル",
+  "settings.section.overlay": "選択オーバーレイ",
+  "settings.section.after": "キャプチャ後",
+  "settings.section.window": "ウィンドウキャプチャ",
+  "settings.section.timing": "タイミング",
+  "settings.section.game": "ゲームモード",
+  "settings.section.recording": "録画（GIF + MP4）",
+  "settings.section.auto": "自動キャプチャ",
+  "settings.section.watch": "監視フォルダー",
+  "settings.section.hdr-sidecar": "HDR サイドカー",
+  "settings.section.hdr-tonemap": "HDR トーンマップ",
+  "settings.section.global": "グローバル",
+  "settings.section.advanced-input": "高度な入力",
+  "settings.section.bindings": "割り当てごとの状態",
+  "settings.section.hook": "LL フックのテレメトリ（デバッグ）",
+  "settings.section.sftp": "既知の SFTP ホスト",
+  "settings.section.feedback": "フィードバック",
+  "settings.section.system": "システム",
+  "settings.section.logs": "ログ",
+
+  "settings.field.directory": "フォルダー",
+  "settings.field.filename": "ファイル名テンプレート",
+  "settings.field.format": "形式",
+  "settings.field.quality": "品質",
+  "settings.field.editor-projects": "エディタープロジェクト",
+  "settings.field.archive": "元画像を保管",
+  "settings.field.scratch": "作業フォルダー",
+  "settings.field.scratch-cap": "作業フォルダーの上限",
+  "settings.field.retention": "保持",
+  "settings.field.metadata": "メタデータ",
+  "settings.field.tags": "ファイル内のタグ",
+  "settings.field.profiles": "アプリのプロファイル",
+  "settings.field.border": "枠線",
+  "settings.field.corners": "角の丸み",
+  "settings.field.caption": "キャプション",
+  "settings.field.theme": "テーマ",
+  "settings.field.accent": "アクセント",
+  "settings.field.scale": "UI の拡大率",
+  "settings.field.compact": "コンパクト表示",
+  "settings.field.companion": "連携拡張機能",
+  "settings.field.cursor": "カーソルを表示",
+  "settings.field.monitors": "モニター",
+  "settings.field.actions": "既定の操作",
+  "settings.field.duplicates": "重複",
+  "settings.field.frame": "フレーム",
+  "settings.field.covered": "隠れたウィンドウ",
+  "settings.field.backend": "キャプチャ方式",
+  "settings.field.wgc-border": "キャプチャ枠",
+  "settings.field.ui-elements": "UI 要素",
+  "settings.field.delay": "キャプチャ前の待機",
+  "settings.field.hide-hub": "ハブを隠す",
+  "settings.field.excluded": "除外するアプリ",
+  "settings.field.exclusion-mode": "写り込んだとき",
+  "settings.field.fullscreen": "全画面アプリ",
+  "settings.field.gif-fps": "GIF のフレームレート",
+  "settings.field.video-fps": "動画のフレームレート",
+  "settings.field.video-quality": "動画の品質",
+  "settings.field.max-duration": "最大時間",
+  "settings.field.audio": "音声を録音",
+  "settings.field.review": "確認",
+  "settings.field.zoom-cursor": "カーソルへズーム",
+  "settings.field.zoom": "ズーム",
+  "settings.field.smoothing": "スムージング",
+  "settings.field.stream": "ライブ配信",
+  "settings.field.bitrate": "配信ビットレート",
+  "settings.field.status": "状態",
+  "settings.field.interval": "間隔",
+  "settings.field.capture": "キャプチャ対象",
+  "settings.field.folder": "フォルダー",
+  "settings.field.keep": "保持する最大数",
+  "settings.field.quota": "ディスク容量の上限",
+  "settings.field.lapse": "タイムラプス",
+  "settings.field.watch": "監視",
+  "settings.field.then": "その後",
+  "settings.field.container": "コンテナ",
+  "settings.field.output-format": "出力形式",
+  "settings.field.operator": "オペレーター",
+  "settings.field.sdr-white": "SDR 白レベルの上書き",
+  "settings.field.pre-scale": "トーンマップ前のスケール",
+  "settings.field.p99": "p99 maxcll",
+  "settings.field.preserve-hdr": "HDR を保持",
+  "settings.field.all-hotkeys": "すべてのホットキー",
+  "settings.field.stop-recording": "録画を停止",
+  "settings.field.dnd": "おやすみモード",
+  "settings.field.hotkey-backend": "バックエンド",
+  "settings.field.evdev": "生入力（evdev）",
+  "settings.field.notifications": "OS の通知",
+  "settings.field.sound": "効果音",
+  "settings.field.open-after": "保存後に開く",
+  "settings.field.autostart": "ログイン時に起動",
+  "settings.field.context-menu": "コンテキストメニュー",
+  "settings.field.minimized": "最小化して起動",
+  "settings.field.close": "閉じるボタンの動作",
+  "settings.field.clipboard-history": "クリップボードの履歴",
+  "settings.field.updates": "アップデートを確認",
+  "settings.field.log": "ログファイル",
+
+  "settings.browse.title": "フォルダーを選択",
+  "settings.pick.output": "出力フォルダーを選択",
+  "settings.pick.scratch": "作業フォルダーを選択",
+  "settings.pick.archive": "保管フォルダーを選択",
+  "settings.pick.auto": "自動キャプチャのフォルダーを選択",
+  "settings.pick.watch": "監視するフォルダーを選択",
+
+  "settings.hint.directory": "絶対パスまたは %env% テンプレート",
+  "settings.hint.filename": "chrono トークン: %Y 年 · %m 月 · %d 日 · %H 時 · %M 分 · %S 秒 · 拡張子は自動で付きます",
+  "settings.hint.quality": "1-100、png/bmp では無視されます",
+  "settings.check.editor-projects": "保存後も注釈を編集できるようにする",
+  "settings.hint.editor-projects":
+    "エディターから保存した各キャプチャの横に完全なコピーを含む .capscr.json を書き出します。開き直すと、注釈を動かせる状態で未編集の画像に戻ります",
+  "settings.check.archive": "各キャプチャを無劣化 PNG のまま別に保管する",
+  "settings.placeholder.archive": "出力フォルダー内の originals/",
+  "settings.hint.archive": "プラグイン・装飾・縮小・非可逆形式の処理前に保存され、後で編集し直せます。絶対パスで指定します",
+  "settings.placeholder.scratch": "システムの一時フォルダー内の capscr/",
+  "settings.hint.scratch": "録画のフレームと音声、メール添付に使います。絶対パスで、長い録画にも足りる容量のディスクが理想です",
+  "settings.hint.scratch-cap": "起動時に残す以前の実行の残り（MiB）。古いものから消去し、0 ですべて消去します",
+  "settings.retention.files-kept": "保持するファイル数",
+  "settings.retention.mib-kept": "保持する MiB",
+  "settings.retention.files": "ファイル",
+  "settings.retention.move-to": "古いキャプチャの移動先",
+  "settings.hint.retention":
+    "出力フォルダーがこれを超えると、古いキャプチャから削除するか、指定のフォルダー（出力フォルダーからの相対パス）へ移動します。0 は無制限 · 10 分ごとに確認",
+  "settings.metadata.keep": "そのまま",
+  "settings.metadata.strip": "すべて削除",
+  "settings.metadata.embed": "キャプチャ情報を埋め込む",
+  "settings.hint.metadata":
+    "PNG と JPEG のみ · 削除はエディターでの保存にも適用 · 埋め込みではキャプチャ時刻、モニター、ウィンドウタイトル、capscr のバージョンを PNG テキスト / EXIF として書き込みます",
+  "settings.check.tags": "タグとメモを PNG にも書き込む",
+  "settings.hint.tags":
+    "タグとメモは常に capscr の履歴インデックスに保存されます。これを有効にすると PNG の Keywords / Comment テキストとしても追加され、ほかのツールから読めます",
+  "settings.hint.resize":
+    "Lanczos、拡大はしません · ホットキーで保存したキャプチャに適用され、エディターでの保存には適用されません。アップロードはアップロード先に別の設定があります",
+
+  "settings.profiles.format": "上の形式",
+  "settings.profiles.action": "タスクの操作",
+  "settings.profiles.subfolder": "サブフォルダー",
+  "settings.profiles.files": "サブフォルダーに保持するファイル数（0 で無制限）",
+  "settings.profiles.mib": "サブフォルダーに保持する MiB（0 で無制限）",
+  "settings.profiles.add": "アプリを追加",
+  "settings.hint.profiles":
+    "一覧のプロセスのウィンドウキャプチャは、その形式で出力フォルダー下のフォルダーに保存され、タスクではなくその操作に回されます · サブフォルダーごとにファイル数と MiB の上限を設定できます",
+
+  "settings.deco.border-color": "枠線の色",
+  "settings.hint.border": "ピクセル、0 でなし",
+  "settings.deco.transparent": "透明",
+  "settings.deco.corner-background": "角の背景",
+  "settings.hint.corners": "半径（ピクセル） · 透明な角には PNG、WebP、AVIF が必要で、ほかの形式では黒になります",
+  "settings.hint.caption":
+    "キャプチャ上部のバーに入るテキスト。ファイル名テンプレートと同じ % トークンが使えます · 直接保存・コピー・アップロードしたときに適用され、エディターへ渡したときは適用されません",
+
+  "settings.hint.theme": "システムに合わせると OS のダーク / ライト設定に連動します",
+  "settings.accent.custom": "カスタム",
+  "settings.accent.custom-label": "カスタムアクセント",
+  "settings.hint.accent": "アクティブなタブ、チェック、フォーカス枠、主要ボタンの色になります",
+  "settings.hint.scale": "システムの表示倍率に加えてハブを拡大します。保存時に適用",
+  "settings.check.compact": "キーだけのサイドバーと詰めた行間",
+  "settings.hint.compact": "ハブをより小さなウィンドウに縮められます",
+
+  "settings.gnome.active": "有効 — ウィンドウの選択、全画面より手前のピン、トップバーのキャプチャメニューはシェル拡張機能が担当します",
+  "settings.gnome.installed": "インストール済み — 有効にするには一度ログアウトして再ログインしてください",
+  "settings.gnome.installing": "インストール中…",
+  "settings.gnome.install": "インストール",
+  "settings.gnome.missing":
+    "これがないと、ウィンドウキャプチャは GNOME 標準の選択ダイアログを使い、ピン留めしたスクリーンショットを全画面ウィンドウより手前に保てません",
+
+  "settings.kind.region": "範囲",
+  "settings.kind.window": "ウィンドウ",
+  "settings.kind.full-screen": "全画面",
+  "settings.kind.gif": "録画",
+  "settings.cursor.captured": "キャプチャする",
+  "settings.cursor.hidden": "隠す",
+  "settings.hint.cursor": "スクリーンショットと録画に適用",
+  "settings.monitors.pointer": "ポインターのあるモニター",
+  "settings.monitors.all": "すべて",
+  "settings.hint.monitors": "範囲とウィンドウの選択を、デスクトップ全体を暗くする代わりにポインターのあるモニターで開きます。3 画面以上では速く開きます",
+  "settings.hint.actions": "トレイメニューとジャンプリストからのキャプチャ用です。ホットキーのタスクは独自の操作を使います",
+  "settings.duplicates.off": "保存し、何も通知しない",
+  "settings.duplicates.warn": "保存して通知する",
+  "settings.duplicates.skip": "スキップ",
+  "settings.hint.duplicates": "1 分以内に撮った直前のものとピクセル単位で同じキャプチャ — たいていはホットキーの二度押しです",
+  "settings.frame.visible": "ウィンドウ枠 — 影なし",
+  "settings.frame.shadow": "枠 + ドロップシャドウ",
+  "settings.frame.client": "クライアント領域のみ",
+  "settings.hint.frame": "ウィンドウを選んだとき、内容の周りに何を残すか",
+  "settings.corners.left": "キャプチャしたまま",
+  "settings.hint.window-corners":
+    "Windows 11 — ウィンドウの丸い角の外側にできる黒い部分を消します（透明にするには PNG/WebP/AVIF が必要）",
+  "settings.covered.own": "ウィンドウ自身の内容",
+  "settings.covered.screen": "画面に見えているもの",
+  "settings.hint.covered":
+    "Windows — 選んだウィンドウ自体を描画するので、手前のウィンドウや画面端で欠けません。HDR ウィンドウとドロップシャドウは引き続き画面から取得します",
+  "settings.backend.auto": "自動 — GDI、HDR は CPU でトーンマップ",
+  "settings.hint.backend":
+    "Windows — WGC は GDI では黒くなるハードウェアアクセラレーションや一部の保護されたコンテンツも取得できます。失敗したときは GDI に戻ります。カーソルは引き続き「カーソルを表示」に従います",
+  "settings.wgc-border.shown": "表示",
+  "settings.wgc-border.suppressed": "非表示",
+  "settings.hint.wgc-border": "キャプチャ中に Windows が描く黄色い枠です。消すには Windows 11 が必要です",
+  "settings.ui-elements.on": "コントロールを検出",
+  "settings.ui-elements.off": "ウィンドウのみ",
+  "settings.hint.ui-elements":
+    "選択中に Ctrl を押しながらホバーすると、ボタン・ペイン・ツールバーを個別に強調します。クリックでそのコントロールだけをキャプチャします",
+  "settings.hint.delay": "ピクセル取得までの ms — ツールチップやメニューに便利（0 = 即時）",
+  "settings.hide-hub.on": "キャプチャ中",
+  "settings.hide-hub.off": "開いたまま",
+  "settings.hint.hide-hub": "スクリーンショットと録画の間はこのウィンドウを隠し、終わったら元に戻します",
+  "settings.hint.excluded":
+    "プロセス名をカンマ区切りで指定します。それらのウィンドウはスクリーンショットと録画から除外されます — 何かが重なっている部分も含めウィンドウ全体です",
+  "settings.exclusion.blackout": "ウィンドウを黒く塗る",
+  "settings.exclusion.skip": "キャプチャをスキップ",
+  "settings.hint.exclusion": "録画ではフレームごとにウィンドウを黒く塗るか、表示中はフレームを捨てます。除外したウィンドウを直接選ぶと常に失敗します",
+
+  "settings.game.off": "監視しない",
+  "settings.game.none": "何も変えない",
+  "settings.game.suspend": "修飾キーなしのホットキーを渡す",
+  "settings.game.wgc": "WGC でキャプチャ",
+  "settings.hint.game":
+    "モニター全体を覆うウィンドウ（たいていはゲーム）にフォーカスがある間に何を変えるか。修飾キーなしのホットキーとは ctrl・alt・win を含まないもの（F9、PrintScreen、マウスボタンなど）で、ゲームも使っていそうなものです。組み合わせは有効なままです。数秒ごとに確認します",
+  "settings.game.rule.none": "そのまま",
+  "settings.game.rule.suspend": "修飾キーなしのホットキーをオフ",
+  "settings.hint.game-apps": "上の選択を上書きするプロセス名。例: 全画面になる動画プレーヤー",
+
+  "settings.hint.gif-fps": "fps、1-60",
+  "settings.hint.video-fps": "MP4 録画の fps、1-60",
+  "settings.video.high": "高 — ほぼ無劣化、ファイルは最大",
+  "settings.video.balanced": "バランス",
+  "settings.video.compact": "コンパクト — ファイルは最小",
+  "settings.hint.video-quality": "MP4 のエンコード品質。GIF には影響しません",
+  "settings.hint.max-duration": "秒、1-300 — 達すると録画は自動で停止して保存されます。10 秒前に警告します",
+  "settings.toggle.enabled": "有効",
+  "settings.toggle.disabled": "無効",
+  "settings.hint.audio": "MP4 録画でシステム音声（ループバック）を録音します",
+  "settings.check.review": "保存前にトリミング",
+  "settings.hint.review": "録画を停止すると開始点 / 終了点付きのプレビューが開き、残した範囲だけがエンコードされて保存されます",
+  "settings.check.zoom": "ポインターの動きに追従する",
+  "settings.hint.zoom-cursor": "範囲録画はカーソルへなめらかにズームし、しばらく止まると元に戻ります",
+  "settings.hint.zoom": "%、125-400 — 200 で範囲の 4 分の 1 を表示",
+  "settings.hint.smoothing": "ms、0-2000 — カメラが追いつく遅さ。0 で即座に移動",
+  "settings.stream.saved": "••••••••••••••••（保存済み）",
+  "settings.hint.stream":
+    "実験的 — 範囲配信タスクの送信先。例: ローカル受信なら rtmp://127.0.0.1/live/key。通常は配信キーを含むため暗号化して保存します。映像のみ",
+  "settings.hint.bitrate": "kbps、500-50000 — フレームレートは上の動画のフレームレートに従います",
+
+  "settings.auto.saved": "保存しました: {path}",
+  "settings.auto.stop": "停止",
+  "settings.auto.start": "開始",
+  "settings.auto.capturing": "キャプチャ中",
+  "settings.auto.stopped": "停止中",
+  "settings.hint.auto-status":
+    "タイマーでのスクリーンショット。作業記録や進捗のタイムラプスに。トレイのキャプチャメニューにもあります。開始前に保留中の変更を保存してください",
+  "settings.hint.interval": "キャプチャの間隔（秒）、5-86400",
+  "settings.auto.active": "アクティブなモニター",
+  "settings.auto.all": "すべてのモニター",
+  "settings.placeholder.auto": "出力フォルダー内の auto/",
+  "settings.hint.keep": "枚。超えると古いものから削除（0 = 無制限）",
+  "settings.hint.quota": "MiB。超えると古いキャプチャから削除（0 = 無制限）",
+  "settings.lapse.fps": "再生 fps",
+  "settings.lapse.width": "最大幅 px（0 = 元のまま）",
+  "settings.lapse.assembling": "作成中...",
+  "settings.lapse.assemble": "作成",
+  "settings.hint.lapse":
+    "自動キャプチャのフォルダーを動画にします — 形式、再生 fps（GIF は最大 50）、最大幅 px（0 = 元のまま）。出力フォルダーに保存します",
+
+  "settings.check.watch": "フォルダー内の新しい画像を capscr で処理する",
+  "settings.hint.watch": "ゲームのスクリーンショットキーなど、ほかのプログラムが撮った画像用です。すでにフォルダーにある画像はそのままです",
+  "settings.placeholder.watch": "監視するフォルダー",
+  "settings.watch.upload": "アップロード",
+  "settings.watch.clipboard": "クリップボードにコピー",
+  "settings.watch.save": "出力フォルダーに保存",
+  "settings.watch.save-copy": "保存してコピー",
+  "settings.hint.watch-action": "書き出し時の装飾はキャプチャと同じく適用されます。アップロードは既定のアップロード先に送られ、有効ならリンクをコピーします",
+
+  "settings.hdr.png": "PNG（16 ビット、無劣化 — 既定）",
+  "settings.hdr.avif": "AVIF（10 ビット AV1）",
+  "settings.hdr.jxr": "JPEG XR（scRGB、Windows）",
+  "settings.hint.container":
+    "AVIF は録画用の ffmpeg でエンコードされ、出力の品質を使います。JPEG XR はリニア scRGB なので、下の伝達関数は適用されません。",
+  "settings.hdr.pq": "PQ（BT.2020、HDR10 方式 — 既定）",
+  "settings.hdr.hlg": "HLG（BT.2020、放送方式）",
+  "settings.hint.output-format":
+    "Windows フォト / Edge では PQ が最も確実です。HLG は PQ のソースを nit にデコードしてから HLG OETF を適用して変換します。",
+  "settings.tonemap.bt2390": "ハイライトロールオフ（既定）",
+  "settings.tonemap.reinhard": "Reinhard 拡張",
+  "settings.tonemap.aces": "ACES フィルミック",
+  "settings.tonemap.hable": "Hable フィルミック",
+  "settings.tonemap.exposure": "露出（クリップ）",
+  "settings.hint.operator":
+    "既定では SDR コンテンツをピクセル単位で正確に保ちます。フィルミック系はコントラストを上げ SDR を少し暗くし、露出はハイライトを切り捨てるだけです。",
+  "settings.hint.sdr-white": "SDR の白レベルを nit で手動指定。0 = OS の SDR コンテンツの明るさスライダーから自動検出（推奨）",
+  "settings.hint.pre-scale": "露出: オペレーターの前に輝度を掛けます（1.0 = そのまま）",
+  "settings.p99.on": "p99（空や照明は無視）",
+  "settings.p99.off": "純粋な最大値、すべてのピークを計算",
+  "settings.preserve.on": "HDR キャプチャごとに .hdr.{container} サイドカーを書き出します",
+  "settings.preserve.off": "SDR へのトーンマップのみ — HDR サイドカーは保存しません",
+  "settings.hint.preserve": "ソース形式は HDR10（一般的なケース）。伝達関数は上で選んだ出力形式になります。全画面 / アクティブモニターのキャプチャのみ。",
+
+  "settings.keys.enable": "再び有効化",
+  "settings.keys.disable": "すべて無効化",
+  "settings.hint.all-hotkeys": "トレイメニューからも切り替えられる一括スイッチ。再起動後も保持されます。",
+  "settings.hint.stop-recording": "実行中の録画を終了します。録画タスク自身のホットキーでも停止できます。タスクが使っているホットキーはここでは使えません。",
+  "settings.dnd.minutes": "おやすみモードの分数",
+  "settings.hint.dnd":
+    "ほかのホットキー、通知、サウンドをすべてオフにします（発表中やゲーム中など）。自動で解除されるまでの分数で、0 はホットキーかトレイで解除するまで続きます。",
+  "settings.rebind.title": "閉じてしまった場合にデスクトップのショートカット承認を開き直します",
+  "settings.rebind": "デスクトップで再割り当て",
+  "settings.hint.rebind": "これらの割り当てはデスクトップが登録し、変更することもあります。下の「実効」列に各タスクを実際に起動するものを表示します。",
+  "settings.hint.evdev":
+    "/dev/input を直接読みます。マウスボタンのホットキーや、GlobalShortcuts ポータルのないデスクトップでのキーボードに必要です。capscr の実行中はすべての入力デバイスが見えます。",
+  "settings.evdev.none": "読み取れる入力デバイスがありません — input グループに自分を追加し（{command}）、再ログインしてから",
+  "settings.evdev.recheck": "再確認",
+  "settings.bindings.none": "タスクがありません。",
+  "settings.bindings.task": "タスク",
+  "settings.bindings.hotkey": "ホットキー",
+  "settings.bindings.effective": "実効",
+  "settings.bindings.reason": "理由",
+  "settings.bindings.unbound": "未割り当て",
+  "settings.bindings.unknown": "不明",
+  "settings.hook.lede":
+    "低レベルキーボードフックのライブカウンター。何かキーを押して更新をクリックしてください。{counter} が増えなければ、フックが入っていない（またはほかのツールが妨げている）状態です。",
+  "settings.hook.missing":
+    "応答にフックのテレメトリがありません（バックエンドにフィールドがない — 古いビルドが動いているか Windows 以外）。診断データ: {payload}",
+  "settings.hook.installed": "フックのインストール",
+  "settings.hook.bindings": "フックテーブルの割り当て数",
+  "settings.hook.calls": "コールバック呼び出しの合計",
+  "settings.hook.keydown": "keydown の呼び出し",
+  "settings.hook.matched": "割り当てに一致",
+  "settings.hook.dispatched": "ワーカーへ送信",
+  "settings.hook.dropped": "送信を破棄（キューが満杯）",
+  "settings.hook.last": "最後の入力（vk + mods）",
+  "settings.hook.mods": "（mods: bit0=Ctrl bit1=Alt bit2=Shift bit3=Win）",
+  "settings.hook.registered": "フックテーブルに登録された組み合わせ:",
+  "settings.hook.task": "タスク ID",
+
+  "settings.sftp.known-hosts": "既知のホスト",
+  "settings.sftp.lede":
+    "capscr は初回接続時に各 SFTP サーバーの公開鍵フィンガープリントを記憶し、後で一致しなければアップロードを拒否します。鍵が更新されたときはホストを削除して信頼し直してください。",
+  "settings.sftp.empty": "信頼済みのホストはまだありません。SFTP でアップロードするとここに追加されます。",
+  "settings.sftp.host": "ホスト:ポート",
+  "settings.sftp.fingerprint": "フィンガープリント",
+  "settings.sftp.first-seen": "初回確認",
+  "settings.sftp.forget": "削除",
+
+  "settings.notify.silent": "サイレント",
+  "settings.sound.on": "キャプチャ / アップロード時に効果音",
+  "settings.open-after.on": "保存したキャプチャを既定のビューアーで開く",
+  "settings.open-after.off": "保存のみ",
+  "settings.autostart.on": "ログイン時に自動で起動します",
+  "settings.autostart.off": "手動でのみ起動",
+  "settings.hint.autostart": "ログイン時に capscr を自動で起動します（次回の保存時に適用）",
+  "settings.check.context-menu": "画像ファイルに「capscr で注釈」を追加",
+  "settings.hint.context-menu":
+    "ファイルマネージャーの右クリックメニュー（Linux では「アプリケーションで開く」）に表示されます。画像のコピーをエディターで開き、キャプチャと同じ場所に保存します（次回の保存時に適用）",
+  "settings.minimized.on": "トレイのみ",
+  "settings.minimized.off": "起動時にこのウィンドウを開く",
+  "settings.hint.minimized": "capscr はトレイでホットキーを待ちます。トレイアイコンをクリックするとこのウィンドウが開きます",
+  "settings.close.tray": "システムトレイに最小化",
+  "settings.close.taskbar": "タスクバーに最小化",
+  "settings.close.exit": "アプリを終了",
+  "settings.clipboard-history.on": "クリップボードのみのキャプチャもローカルの履歴キャッシュに保存します",
+  "settings.clipboard-history.off": "クリップボードのみのキャプチャは履歴に保存しません",
+  "settings.updates.on": "ハブを開いて 4 秒後に GitHub のリリースを確認します",
+  "settings.updates.off": "ネットワークには接続しません。アップデートは手動で入手してください",
+  "settings.logs.hide": "隠す",
+  "settings.logs.view": "ログを表示",
+  "settings.logs.reveal": "ファイルマネージャーで capscr.log を表示",
+  "settings.logs.open": "ログフォルダーを開く",
+  "settings.hint.log":
+    "設定フォルダーの capscr.log。4 MB で新しく始まり、直前の 3 つは残ります。キャプチャやアップロードの失敗を報告するときに添付してください",
+  "settings.logs.recent": "最近のログ",
+  "settings.logs.empty": "まだ何も記録されていません",
+
+  "settings.theme.dark": "ダーク",
+  "settings.theme.light": "ライト",
+  "settings.theme.system": "システムに合わせる",
+  "settings.accent.mono": "モノ",
+  "settings.accent.amber": "アンバー",
+  "settings.accent.phosphor": "蛍光グリーン",
+  "settings.accent.ice": "アイス",
+  "settings.accent.rose": "ローズ",
+
+  // shared components
+  "common.saving": "保存中…",
+  "common.clear": "クリア",
+  "common.refresh": "更新",
+
+  "resize.label": "縮小",
+  "resize.max-dimension": "長辺を",
+  "resize.percent": "拡大率",
+  "resize.max-dimension.aria": "最大寸法（ピクセル）",
+  "resize.percent.aria": "拡大率（パーセント）",
+
+  "hotkey.bare": "{hotkey} はすべてのアプリからそのキーを奪います — 修飾キー（Ctrl / Alt / Shift / {meta}）を加えてください。",
+  "hotkey.bind": "クリックして割り当て…",
+  "hotkey.press": "キーを押してください…（Esc でキャンセル）",
+  "hotkey.clear": "ホットキーをクリア",
+
+  "color.current": "色 {color}",
+  "color.pick": "色を選ぶ",
+  "color.dialog": "色",
+  "color.hue": "色相",
+  "color.hex": "16 進カラー",
+  "color.presets": "プリセット",
+  "color.recent": "最近の色",
+
+  "compare.dialog": "キャプチャを比較",
+  "compare.title": "比較",
+  "compare.slider": "スライダー",
+  "compare.diff": "差分",
+  "compare.wipe": "境界の位置",
+  "compare.diff-image": "ピクセル差分",
+  "compare.tolerance": "許容値",
+  "compare.tolerance.label": "許容値 {value}",
+  "compare.comparing": "比較中…",
+  "compare.summary": "{count} px 変化（{share}%）· {width}×{height}",
+
+  "review.title": "録画を確認",
+  "review.frame": "録画フレーム",
+  "review.scrub": "シーク",
+  "review.discard.confirm": "この録画を破棄しますか？まだ保存されていません。",
+  "review.at": "位置 {time}",
+  "review.in": "イン {time}",
+  "review.out": "アウト {time}",
+  "review.keeping": "{total} のうち {length} を残す",
+  "review.whole": "録画全体、{total}",
+  "review.discard": "破棄",
+  "review.save-trimmed": "トリムして保存",
+  "trim.playhead": "再生位置",
+
+  "tags.title": "タグとメモ",
+  "tags.tags": "タグ",
+  "tags.placeholder": "bug, login-page",
+  "tags.note": "メモ",
+
+  "trim.title": "録画をトリム",
+  "trim.start": "開始 {time}",
+  "trim.end": "終了 {time}",
+  "trim.fast": "高速（無劣化、開始位置はキーフレームに揃う）",
+  "trim.length": "長さ {time}",
+  "trim.exporting": "書き出し中…",
+  "trim.export": "トリムを書き出す",
+  "trim.done": "トリム済み → {name}",
+
+  "windows.filter": "タイトルやアプリで絞り込み...",
+  "windows.unknown-app": "（不明なアプリ）",
+  "windows.listing": "ウィンドウを一覧中...",
+  "windows.no-match": "条件に一致するウィンドウはありません",
+  "windows.none": "一覧できるウィンドウがありません — wayland ではタイトルを入力してください",
+
+  "titlebar.minimize": "最小化",
+  "titlebar.restore": "元に戻す",
+  "titlebar.maximize": "最大化",
+  "common.reload": "再読み込み",
+
+  // tasks view
+  "tasks.mode.region": "範囲（矩形をドラッグ）",
+  "tasks.mode.region-last": "範囲（前回 — ドラッグなし）",
+  "tasks.mode.active-window": "ウィンドウ（フォーカス中 — 選択なし）",
+  "tasks.mode.window": "ウィンドウ（選択）",
+  "tasks.mode.target-window": "ウィンドウ（タイトル / プロセス指定）",
+  "tasks.mode.fullscreen": "全画面（メイン）",
+  "tasks.mode.active-monitor": "アクティブなモニター",
+  "tasks.mode.region-gif": "範囲 GIF",
+  "tasks.mode.region-mp4": "範囲 MP4（動画）",
+  "tasks.mode.region-stream": "範囲を配信（RTMP、実験的）",
+
+  "tasks.action.clipboard": "クリップボードのみ",
+  "tasks.action.save-file": "出力フォルダーに保存",
+  "tasks.action.save-and-clipboard": "保存 + クリップボード",
+  "tasks.action.upload": "アップロード",
+  "tasks.action.open-editor": "エディターで開く",
+  "tasks.action.copy-text": "検出したテキストをコピー（OCR）",
+  "tasks.action.prompt": "確認する",
+  "tasks.action.do-nothing": "何もしない",
+
+  "tasks.gesture.press": "押す",
+  "tasks.gesture.double-press": "2 回押す",
+  "tasks.gesture.long-press": "長押し（0.5 秒）",
+
+  "tasks.size.free": "自由（範囲をドラッグ）",
+  "tasks.size.720p": "1280×720",
+  "tasks.size.1080p": "1920×1080",
+  "tasks.size.custom": "カスタム",
+
+  "tasks.status.duplicate": "ホットキーが重複しています: {hotkeys} — 同じキーを使うタスクには別のジェスチャーが必要です",
+  "tasks.status.saving": "保存中...",
+  "tasks.status.live.one": "{count} 件のタスクが有効です。",
+  "tasks.status.live.other": "{count} 件のタスクが有効です。",
+  "tasks.status.fire-error": "実行: {error}",
+
+  "tasks.new-name": "新しいタスク",
+  "tasks.lede": "1 つのホットキー、1 つのキャプチャ、1 つの後処理。",
+  "tasks.new": "新規",
+  "tasks.empty": "タスクはありません",
+  "tasks.empty.hint": "{new} を押してホットキーを設定し、保存します。",
+  "tasks.unbound": "未割り当て",
+  "tasks.registered": "キーボードフックに登録済み",
+  "tasks.live": "有効",
+  "tasks.rejected": "拒否",
+  "tasks.required": "必須",
+
+  "tasks.field.name": "名前",
+  "tasks.field.hotkey": "ホットキー",
+  "tasks.hint.hotkey": "クリックして組み合わせを押す",
+  "tasks.field.gesture": "ジェスチャー",
+  "tasks.hint.gesture": "他のジェスチャーもあるキーの単押しは、それらになり得ないと確定した時点で発動します",
+  "tasks.field.mode": "キャプチャモード",
+  "tasks.pick-window": "開いているウィンドウから選ぶ",
+  "tasks.field.window-title": "ウィンドウタイトル",
+  "tasks.hint.window-title": "正規表現、大文字小文字を区別しません — 最前面の一致をキャプチャします",
+  "tasks.field.process": "プロセス",
+  "tasks.hint.process": "アプリ / プロセス名に対する任意の正規表現",
+  "tasks.field.post-action": "後処理",
+  "tasks.field.target": "送信先",
+  "tasks.field.preset": "プリセット",
+  "tasks.preset.default": "既定",
+  "tasks.field.follow": "ウィンドウを追従",
+  "tasks.check.follow": "フォーカス中のウィンドウを追う",
+  "tasks.hint.follow": "選択をスキップし、フォーカス中のウィンドウに合わせて最初のウィンドウのサイズに収めて録画します",
+  "tasks.field.hold": "押している間録画",
+  "tasks.check.hold": "ホットキーを押している間だけ録画",
+  "tasks.hint.hold": "離すと停止して保存します。前回選んだ範囲を録画し、初回は選択画面を開きます",
+  "tasks.field.size": "フレームサイズ",
+  "tasks.hint.size": "固定フレームがポインターに追従します。クリックか Enter でその位置から録画を開始します",
+  "tasks.field.delay": "遅延",
+  "tasks.delay.global": "全体設定",
+  "tasks.hint.delay": "キャプチャ前の ms — 空欄なら全体の遅延を使います",
+
+  "tasks.fire.title": "このタスクを今すぐ実行（ホットキーを押したのと同じ処理）",
+  "tasks.fire": "実行",
+  "tasks.delete.title": "このタスクを削除",
+  "common.confirm.title": "もう一度クリックして確定",
+  "common.confirm": "確定？",
+
+  // history view
+  "history.filter.all": "すべて",
+  "history.filter.images": "画像",
+  "history.filter.gifs": "GIF",
+  "history.filter.videos": "動画",
+  "history.filter.hdr": "HDR",
+  "history.your-key": "スクリーンショットキー",
+
+  "history.flash.uploading": "アップロード中...",
+  "history.flash.reuploaded": "再アップロードしました",
+  "history.flash.upload-failed": "アップロードに失敗しました: {error}",
+  "history.flash.open-image-failed": "画像を開けませんでした: {error}",
+  "history.flash.converting": "変換中 {done}/{total}...",
+  "history.flash.converted.one": "{count} 枚の画像を変換しました",
+  "history.flash.converted.other": "{count} 枚の画像を変換しました",
+  "history.flash.convert-failed": "一括変換に失敗しました: {error}",
+  "history.flash.uploads-failed": "{total} 件中 {failed} 件のアップロードに失敗しました",
+  "history.flash.uploaded": "{count} 件アップロードしました",
+  "history.flash.batch-failed": "一括アップロードに失敗しました: {error}",
+  "history.flash.stitched": "{count} 枚のキャプチャをつなげました",
+  "history.flash.stitch-failed": "つなぎ合わせに失敗しました: {error}",
+  "history.flash.links-copied": "{count} 件のリンクをコピーしました",
+  "history.flash.copy-failed": "コピーに失敗しました: {error}",
+  "history.flash.copied": "クリップボードにコピーしました",
+  "history.flash.revealed": "ファイルマネージャーで表示しました",
+  "history.flash.open-failed": "開けませんでした: {error}",
+  "history.flash.editor-failed": "エディターを開けませんでした: {error}",
+  "history.flash.extracting": "テキストを抽出中...",
+  "history.flash.no-text": "画像にテキストが見つかりません",
+  "history.flash.ocr-copied": "OCR: テキストをコピーしました（{count} 文字）",
+  "history.flash.clipboard-failed": "クリップボードへのコピーに失敗しました",
+  "history.flash.ocr-failed": "OCR に失敗しました: {error}",
+  "history.flash.pin-failed": "ピン留めに失敗しました: {error}",
+  "history.flash.deleted": "削除しました",
+  "history.flash.delete-failed": "削除に失敗しました: {error}",
+
+  "history.reading-dir": "フォルダーを読み込み中...",
+  "history.count": "出力フォルダーに {total} 個のファイル",
+  "history.count.filtered": "{total} 個中 {shown} 個が一致",
+  "history.search": "名前、#タグ、メモで絞り込み...",
+  "history.select": "選択",
+  "history.upload-selected": "{count} 件をアップロード",
+  "history.compare.title": "比較するキャプチャを 2 つ選択",
+  "history.stitch.title": "選んだ順に、重なる部分でキャプチャをつなげる",
+  "history.stitching": "つなぎ合わせ中…",
+  "history.stitch": "つなげる",
+  "history.open-image.title": "画像ファイルをエディターで開く",
+  "history.open-image": "画像を開く",
+  "history.convert.title": "フォルダー内のすべての画像を出力設定で変換してキャプチャフォルダーに入れる",
+  "history.convert": "フォルダーを変換",
+  "history.batch.failed": "失敗: {error}",
+  "history.batch.lines": "行としてコピー",
+  "history.batch.markdown": "Markdown としてコピー",
+  "history.reading": "読み込み中...",
+  "history.empty": "キャプチャはまだありません",
+  "history.empty.hint": "{shot} で範囲をドラッグしてクリップボードにキャプチャ、{gif} で GIF を録画します。",
+  "history.empty.rebind": "{tasks} で割り当てを変更 · アップロード先は {destinations} にあります。",
+  "history.empty.reading-from": "{dir} から読み込み中",
+  "history.no-matches": "一致なし",
+  "history.no-matches.hint": "出力フォルダーにフィルターと一致するものはありません。",
+  "history.tile.select": "{name}、アップロード用に選択",
+  "history.tile.reveal": "{name}、ファイルを表示",
+  "history.tile.edit": "{name}、エディターで開く",
+  "history.trim": "トリム",
+  "history.ocr": "テキストを抽出（OCR）",
+  "history.pin": "画面にピン留め",
+  "history.reupload": "再アップロード",
+  "history.open-viewer": "OS のビューアーで開く",
+  "history.copy": "クリップボードにコピー",
+  "history.hdr": "HDR サイドカーあり（.hdr.png / .avif / .jxr）",
+  "history.tag.title": "タグ {tag} のキャプチャを表示",
+
+  // plugins view
+  "plugins.status.rescanning": "再スキャン中...",
+  "plugins.status.done": "完了。",
+  "plugins.status.folder-failed": "プラグインフォルダーを開けませんでした: {error}",
+  "plugins.status.installing": "{name} をインストール中...",
+  "plugins.status.installed.review": "{name} v{version} をインストールしました — 下の権限を確認して有効にするまで無効のままです。",
+  "plugins.status.installed": "{name} v{version} をインストールしました。",
+  "plugins.status.install-failed": "インストールに失敗しました: {error}",
+  "plugins.status.enabling": "{name} を有効化中...",
+  "plugins.status.disabling": "{name} を無効化中...",
+  "plugins.status.enabled": "{name} を有効にしました。",
+  "plugins.status.disabled": "{name} を無効にしました。",
+  "plugins.status.toggle-failed": "切り替えに失敗しました: {error}",
+  "plugins.uninstall.confirm": "{name} をアンインストールしますか？プラグインのファイルは削除されます。",
+  "plugins.status.uninstalling": "{name} をアンインストール中...",
+  "plugins.status.removed": "{name} を削除しました。",
+  "plugins.status.uninstall-failed": "アンインストールに失敗しました: {error}",
+
+  "plugins.lede": "rot.lt のレジストリを見る · プラグインのフォルダーをフォルダーに置く。",
+  "plugins.installed": "インストール済み",
+  "plugins.open-folder": "フォルダーを開く",
+  "plugins.load-failed.one": "{count} 個のプラグインを読み込めませんでした",
+  "plugins.load-failed.other": "{count} 個のプラグインを読み込めませんでした",
+  "plugins.load-failed.hint": "読み込みは起動時に行われます — プラグインを直したら capscr を再起動してください。",
+  "plugins.none": "インストールされていません",
+  "plugins.none.hint": "下から探すか、プラグインのフォルダーをプラグインフォルダーに置いて再読み込みしてください。",
+  "plugins.enabled": "有効",
+  "plugins.disabled": "無効",
+  "plugins.grants": "権限",
+  "plugins.disable": "無効にする",
+  "plugins.enable": "有効にする",
+  "plugins.uninstall": "アンインストール",
+  "plugins.browse": "探す",
+  "plugins.empty-registry": "レジストリが空です",
+  "plugins.empty-registry.hint":
+    "インストールできるプラグインはまだありません — プラグインのフォルダーをプラグインフォルダーに置くか、レジストリに登録されるのを待ってください。",
+  "plugins.site": "サイト",
+  "plugins.update": "v{version} に更新",
+  "plugins.install": "インストール",
+  "plugins.unreachable": "レジストリに接続できません",
+  "plugins.unreachable.hint": "設定 → アップロード先でミラーを指定するか、rot.lt の復旧を待ってください。",
+
+  "pin.gone": "このピンの画像はもうありません",
+  "pin.image-failed": "このピンの画像を読み込めませんでした",
+  "pin.failed": "このピンを読み込めませんでした",
+  "pin.image": "ピン留め",
+  "pin.empty": "ピン留めはありません",
+  "pin.opacity": "不透明度を調整",
+
+  "recbar.stop": "停止",
+
+  // editor
+  "editor.font.mono": "等幅",
+  "editor.font.sans": "ゴシック",
+  "editor.font.serif": "明朝",
+  "editor.font.hand": "手書き",
+  "editor.layer.name": "レイヤー {n}",
+
+  "editor.stamp.check": "チェック",
+  "editor.stamp.cross": "バツ",
+  "editor.stamp.arrow": "矢印",
+  "editor.stamp.star": "星",
+  "editor.stamp.question": "疑問",
+  "editor.stamp.warning": "警告",
+  "editor.stamp.thumbs-up": "いいね",
+  "editor.stamp.heart": "ハート",
+
+  "editor.status.gif": "GIF は編集できません — フレームが 1 枚に統合されてしまいます。閉じれば元のまま残ります。",
+  "editor.status.load-failed": "画像を読み込めませんでした: {error}",
+  "editor.status.reopened.one": "編集可能な注釈 {count} 件とともに開き直しました。",
+  "editor.status.reopened.other": "編集可能な注釈 {count} 件とともに開き直しました。",
+  "editor.status.no-path": "バックエンドから画像のパスを受け取れませんでした",
+  "editor.status.pasted": "クリップボードから貼り付けました — 保存するとディスクに書き込まれます。",
+  "editor.status.paste-failed": "貼り付けに失敗しました: {error}",
+  "editor.status.paste-text": "貼り付け: ここには画像のみ貼り付けられます — テキストはテキストツールで追加してください",
+  "editor.discard.pasted": "貼り付けた画像と未保存の注釈を破棄しますか？保存を押すまでディスクには書き込まれません。",
+  "editor.discard.load": "未保存の注釈を破棄して新しい画像を開きますか？",
+  "editor.discard": "未保存の注釈を破棄しますか？保存を押すまでディスクには書き込まれません。",
+  "editor.layer.delete.confirm.one": "{name} とその注釈 {count} 件を削除しますか？",
+  "editor.layer.delete.confirm.other": "{name} とその注釈 {count} 件を削除しますか？",
+  "editor.status.project-failed": "保存しましたが、エディターのプロジェクトに失敗しました: {error}",
+  "editor.status.save-failed": "保存に失敗しました: {error}",
+  "editor.status.saved-copy": "{path} にコピーを保存しました",
+  "editor.status.save-as-failed": "名前を付けて保存に失敗しました: {error}",
+  "editor.status.copying": "コピー中...",
+  "editor.status.copied": "クリップボードにコピーしました。",
+  "editor.status.copy-failed": "コピーに失敗しました: {error}",
+  "editor.status.uploading": "アップロード中...",
+  "editor.status.upload-failed": "アップロードに失敗しました: {error}",
+  "editor.status.tags-saved": "タグを保存しました。",
+
+  "editor.tools": "ツール",
+  "editor.tool.arrow": "矢印 (1)",
+  "editor.tool.rect": "矩形 (2)",
+  "editor.tool.text": "テキスト (3)",
+  "editor.tool.blur": "モザイク (4)",
+  "editor.tool.step": "番号付きステップ (5)",
+  "editor.tool.line": "直線 (6)",
+  "editor.tool.ellipse": "楕円 (7)",
+  "editor.tool.highlight": "蛍光ペン (8)",
+  "editor.tool.pen": "ペン (9)",
+  "editor.tool.stamp": "スタンプ (0)",
+  "editor.tool.measure": "計測 (m)",
+  "editor.colors": "色",
+  "editor.stroke": "線幅",
+  "editor.size": "サイズ",
+  "editor.font": "フォント",
+  "editor.stamps": "スタンプ",
+  "editor.layers": "レイヤー",
+  "editor.image.title": "キャプチャ本体の表示 / 非表示",
+  "editor.image": "画像",
+  "editor.layer.hide": "{name} を非表示",
+  "editor.layer.show": "{name} を表示",
+  "editor.hide": "非表示",
+  "editor.show": "表示",
+  "editor.layer.draw": "このレイヤーに描く",
+  "editor.layer.delete": "{name} を削除",
+  "editor.layer.delete.title": "レイヤーを削除",
+  "editor.layer.new.title": "一番上に新しいレイヤー",
+  "editor.layer.new": "レイヤー",
+  "editor.undo": "元に戻す",
+  "editor.redo": "やり直す",
+  "editor.zoom.actual": "実寸 (ctrl+0)",
+  "editor.zoom.fit.title": "ウィンドウに合わせる (f)",
+  "editor.zoom.fit": "ウィンドウに合わせる",
+  "editor.save-as": "名前を付けて保存…",
+  "editor.tags.title": "タグとメモ（履歴から検索できます）",
+  "editor.destination": "アップロード先",
+  "editor.destination.default": "既定のアップロード先",
+  "editor.upload": "アップロード",
+  "editor.hdr": "HDR キャプチャを検出しました。",
+  "editor.hdr.flatten":
+    "キャンバスは SDR で注釈します。ここで保存すると HDR ファイルが SDR になります。元の HDR を残すには保存せずにエディターを閉じてください。",
+  "editor.hdr.sidecar":
+    "capscr は HDR の元画像を {file} として保存しています — 注釈は SDR のコピーに保存され、HDR サイドカーはそのままです。",
+  "editor.loading": "キャプチャを読み込み中...",
+  "editor.text.placeholder": "入力して Enter で確定",
+  "editor.sizes": "設定した品質での形式ごとの推定ファイルサイズ",
+
+  // destinations
+  "destinations.lede": "アップロードの送り先。",
+  "destinations.vault.keyring": "システムのキーリング",
+  "destinations.encrypted.stored": "{vault} で暗号化して保存済み（ユーザーごと）",
+  "destinations.encrypted.on-save": "保存時に {vault} で暗号化されます",
+  "destinations.stored": "（保存済み — 空欄のままで現在の値を維持）",
+  "destinations.saved-secret": "••••••••••••••••（保存済み）",
+  "destinations.test": "テスト",
+  "destinations.test.run": "接続をテスト",
+  "destinations.test.running": "確認中...",
+  "destinations.username": "ユーザー名",
+  "destinations.password": "パスワード",
+  "destinations.host": "ホスト",
+  "destinations.port": "ポート",
+  "destinations.remote-dir": "リモートのフォルダー",
+  "destinations.public-url": "公開 URL のテンプレート",
+  "destinations.public-url.hint": "{filename} → ファイル名、空欄 = URL を返さない",
+  "destinations.folder": "フォルダー",
+  "destinations.account": "アカウント",
+  "destinations.signed-in": "（サインイン済み）",
+  "destinations.sign-in": "サインイン",
+  "destinations.sign-out": "サインアウト",
+  "destinations.confirm": "確定",
+  "destinations.secret": "秘密",
+  "destinations.optional": "任意",
+
+  "destinations.status.opening-browser": "ブラウザーを開いています...",
+  "destinations.status.signing-in": "サインイン中...",
+  "destinations.status.signing-out": "サインアウト中...",
+  "destinations.status.loading-albums": "アルバムを読み込み中...",
+  "destinations.status.creating-album": "アルバムを作成中...",
+  "destinations.status.decrypting": "復号中...",
+  "destinations.status.signed-in": "{name} としてサインインしました。",
+  "destinations.status.unnamed": "（名前のないアカウント）",
+  "destinations.status.imgur-pin": "ブラウザーで capscr を許可してから、ここに PIN を貼り付けてください。",
+  "destinations.status.dropbox-code": "ブラウザーで capscr を許可してから、ここにアクセスコードを貼り付けてください。",
+  "destinations.status.imgur-out": "サインアウトしました — アップロードは再び匿名になります。",
+  "destinations.status.dropbox-out": "dropbox からサインアウトしました。",
+  "destinations.status.saved-to": "{path} に保存しました",
+  "destinations.status.albums.one": "アルバム {count} 件。",
+  "destinations.status.albums.other": "アルバム {count} 件。",
+  "destinations.status.album-created": "「{title}」を作成しました — 保存するとここにアップロードされます。",
+  "destinations.status.forgot": "以前のアップロードを忘れました。",
+  "destinations.status.imported": "「{name}」を新しいプリセットとして読み込みました。",
+  "destinations.status.preset-limit": "プリセットの上限に達しました。先に 1 つ削除してください",
+
+  "destinations.active": "使用中の送り先",
+  "destinations.target": "送り先",
+  "destinations.target.custom": "カスタム HTTP",
+  "destinations.target.s3": "S3 互換",
+  "destinations.target.email": "メール",
+  "destinations.target.webhook": "チャット Webhook",
+  "destinations.imgur.client-id": "imgur クライアント ID",
+  "destinations.imgur.client-id.hint":
+    "空欄なら capscr の共有キーを使います。レート制限を避けるには api.imgur.com で取得した自分のキーを貼り付けてください。",
+  "destinations.imgur.test.hint": "このクライアント ID で api.imgur.com/3/credits を呼び出します。",
+  "destinations.copy-url": "URL をクリップボードにコピー",
+  "destinations.copy-url.on": "成功したら自動でコピー",
+  "destinations.copy-url.off": "クリップボードはそのまま",
+  "destinations.resize.hint": "lanczos・拡大はしません · 保存したファイルは元のサイズのまま",
+  "destinations.recompress": "サイズ上限を超えたとき",
+  "destinations.recompress.jpeg": "jpeg で再圧縮",
+  "destinations.recompress.webp": "まず可逆 webp、次に jpeg",
+  "destinations.recompress.off": "アップロードを失敗させる",
+  "destinations.recompress.min": "最低品質",
+  "destinations.recompress.hint": "収まるまで品質 90 から下げます · imgur は 20 MB、カスタムホストはそれぞれの上限",
+  "destinations.link-format": "リンクの形式",
+  "destinations.link-format.url": "URL のみ",
+  "destinations.link-format.hint": "トレイの「最後の URL をコピー」と最近のアップロードにも適用されます",
+  "destinations.dedupe": "同じファイルの再アップロード",
+  "destinations.dedupe.ask": "以前のリンクを使うか確認する",
+  "destinations.dedupe.reuse": "以前のリンクを使う",
+  "destinations.dedupe.off": "毎回アップロードする",
+  "destinations.dedupe.days": "日",
+  "destinations.dedupe.forget": "忘れる",
+  "destinations.dedupe.hint": "同じファイル・同じ送り先 · sha-256 で照合し、ハッシュとリンクだけを保持します",
+  "destinations.secret-scan": "機密情報のスキャン",
+  "destinations.secret-scan.on": "機密情報らしきものはアップロード前に確認する",
+  "destinations.secret-scan.api-keys": "API キー + トークン",
+  "destinations.secret-scan.emails": "メールアドレス",
+  "destinations.secret-scan.cards": "カード番号",
+  "destinations.secret-scan.hint": "画像が手元を離れる前に OCR で文字を読み取ります · 保険であって保証ではありません",
+
+  "destinations.proxy": "プロキシ",
+  "destinations.proxy.system": "システムのプロキシ",
+  "destinations.proxy.direct": "プロキシなし",
+  "destinations.proxy.manual": "手動",
+  "destinations.proxy.hint": "HTTP の送り先向け · ftp と sftp は直接接続します",
+  "destinations.proxy.url": "プロキシの URL",
+  "destinations.proxy.url.hint": "socks5h はプロキシ側で名前解決します",
+
+  "destinations.encryption": "暗号化",
+  "destinations.encrypt": "アップロード前に暗号化",
+  "destinations.encrypt.on": "暗号文だけをアップロード",
+  "destinations.encrypt.off": "画像をそのままアップロード",
+  "destinations.encrypt.imgur": "imgur は画像しか受け付けません — 暗号化するには別の送り先を選んでください",
+  "destinations.encrypt.hint": "aes-256-gcm、アップロードごとに新しい鍵 · 鍵はリンクの #フラグメントに残ります",
+  "destinations.encrypted-link": "暗号化されたリンクを開く",
+
+  "destinations.imgur.account": "imgur アカウント",
+  "destinations.imgur.secret": "クライアントシークレット",
+  "destinations.imgur.secret.hint":
+    "imgur は登録済みのアプリしかサインインできません。api.imgur.com で登録し、クライアント ID を上に、シークレットをここに入力してください。{vault} で暗号化して保存します。",
+  "destinations.imgur.sign-in": "imgur でサインイン",
+  "destinations.imgur.pin": "imgur の PIN",
+  "destinations.imgur.anonymous": "サインインするまでアップロードは匿名です。",
+  "destinations.album": "アルバム",
+  "destinations.album.none": "（アルバムなし）",
+  "destinations.album.default": "既定",
+  "destinations.album.new": "新しいアルバム",
+  "destinations.album.title": "アルバムのタイトル",
+  "destinations.album.hidden": "非公開（リンクのみ）",
+  "destinations.album.public": "公開",
+  "destinations.album.secret": "シークレット",
+  "destinations.album.create": "作成",
+  "destinations.imgur.title": "タイトル",
+  "destinations.imgur.description": "説明",
+  "destinations.imgur.meta.hint": "すべてのアップロードに適用されます。空欄なら設定しません。",
+
+  "destinations.ftp.port.hint": "21 は平文、990 は暗黙の TLS",
+  "destinations.ftp.tls": "TLS",
+  "destinations.ftp.tls.hint": "平文の FTP のみです — 暗号化して転送するには sftp を使ってください",
+  "destinations.ftp.test.hint": "接続してログインし、リモートのフォルダーに移動します。アップロードはしません。",
+  "destinations.sftp.port.hint": "22 は標準の SSH",
+  "destinations.sftp.key": "秘密鍵",
+  "destinations.sftp.key.placeholder": "C:\\Users\\you\\.ssh\\id_ed25519（空欄 = パスワード認証）",
+  "destinations.sftp.key.hint": "OpenSSH 形式。ed25519 / rsa / ecdsa に対応しています。",
+  "destinations.sftp.passphrase": "鍵のパスフレーズ",
+  "destinations.sftp.passphrase.placeholder": "鍵が暗号化されていなければ空欄",
+  "destinations.sftp.password.placeholder": "鍵認証に失敗したとき、または鍵がないときの代わり",
+  "destinations.sftp.test.hint": "接続して認証し、リモートのフォルダーを一覧します。アップロードはしません。",
+
+  "destinations.s3.bucket": "バケット",
+  "destinations.s3.region": "リージョン",
+  "destinations.s3.endpoint": "エンドポイント",
+  "destinations.s3.endpoint.placeholder": "https://s3.amazonaws.com（空欄で既定の AWS）",
+  "destinations.s3.access-key": "アクセスキー ID",
+  "destinations.s3.secret-key": "シークレットアクセスキー",
+  "destinations.s3.vault": "暗号化された保管庫に保存済み",
+  "destinations.s3.public-url": "公開 URL のテンプレート",
+  "destinations.s3.public-url.hint": "{filename} → ファイル名、空欄 = 既定の S3 URL を返す",
+  "destinations.s3.test.hint": "権限を確認するため小さなファイルをテストアップロードします。",
+
+  "destinations.webdav.server": "サーバーの URL",
+  "destinations.webdav.server.hint": "nextcloud と owncloud では ファイル → 設定 → WebDAV に表示されます",
+  "destinations.webdav.password.placeholder": "ログインではなくアプリ用パスワード",
+  "destinations.webdav.folder.hint": "サーバー URL の下に必要に応じて作成します。%Y / %m / %d は展開されます",
+  "destinations.webdav.link": "リンク",
+  "destinations.webdav.share": "公開共有リンクを作成（nextcloud / owncloud）",
+  "destinations.webdav.public-url.hint":
+    "{path} → フォルダー、{filename} → ファイル名。空欄 = WebDAV の URL（開くにはログインが必要）",
+  "destinations.webdav.test.hint": "何もアップロードせずに URL とログインを確認します。",
+
+  "destinations.dropbox.app-key": "アプリキー",
+  "destinations.dropbox.app-key.hint":
+    "dropbox.com/developers で files.content.write と sharing.write の権限を持つアプリを作成し、そのアプリキーをここに貼り付けてください。シークレットは不要です。",
+  "destinations.dropbox.sign-in": "dropbox でサインイン",
+  "destinations.dropbox.code": "dropbox のアクセスコード",
+  "destinations.dropbox.kept": "サインイン情報は {vault} に保存されます。",
+  "destinations.dropbox.folder.hint": "アップロードごとに共有リンクが作られ、それがコピーされます",
+
+  "destinations.email.to": "宛先",
+  "destinations.email.to.placeholder": "新しいメッセージでは空欄のまま",
+  "destinations.email.subject": "件名",
+  "destinations.email.hint": "キャプチャは既定のメールソフトで添付ファイルとして開きます。送信はそこから行ってください",
+
+  "destinations.webhook.service": "サービス",
+  "destinations.webhook.url": "Webhook の URL",
+  "destinations.webhook.url.hint": "URL を知っていれば誰でもチャンネルに投稿できるため、{vault} に保存されます",
+  "destinations.webhook.message": "メッセージ",
+  "destinations.webhook.host": "画像の置き場所",
+  "destinations.webhook.host.hint":
+    "slack と teams の Webhook はテキストしか受け付けないため、キャプチャはここにアップロードされ、メッセージからリンクされます",
+
+  "destinations.preset": "プリセット",
+  "destinations.preset.default-suffix": "（既定）",
+  "destinations.preset.new-name": "カスタム {n}",
+  "destinations.preset.hint": "タスクとエディターは任意のプリセットを選べます。それ以外では既定のものが使われます",
+  "destinations.import": "読み込み",
+  "destinations.import.sxcu": ".sxcu を読み込む",
+  "destinations.import.hint": "ShareX のアップローダーファイルからプリセットを追加します。確認してから保存してください。",
+  "destinations.preset.none": "プリセットはまだありません — 追加するか .sxcu ファイルを読み込んでください。",
+  "destinations.preset.name": "名前",
+  "destinations.preset.default": "既定のプリセット",
+  "destinations.custom.url": "アップロード先の URL",
+  "destinations.custom.url.hint": "https のみ。平文の http は拒否されます",
+  "destinations.custom.method": "メソッド",
+  "destinations.custom.post": "POST（multipart）",
+  "destinations.custom.put": "PUT（本文そのまま）",
+  "destinations.custom.method.hint": "PUT はファイルを本文として送ります。追加フィールドはクエリパラメーターになります",
+  "destinations.custom.form": "フォームのフィールド",
+  "destinations.custom.form.hint": "multipart のキー（多くは「file」）",
+  "destinations.custom.path": "レスポンスのパス",
+  "destinations.custom.path.hint": "URL までのドット区切り JSON パス。空欄 = 本文そのまま",
+  "destinations.custom.regex": "レスポンスの正規表現",
+  "destinations.custom.regex.hint": "設定すると JSON パスより優先されます。最初のキャプチャグループが URL です",
+  "destinations.custom.limit": "サイズ上限",
+  "destinations.custom.limit.hint": "MB · 0 = ホスト側の上限なし。超えるキャプチャは再圧縮されます",
+  "destinations.custom.headers": "ヘッダー",
+  "destinations.custom.value": "値",
+  "destinations.custom.add-header": "ヘッダーを追加",
+  "destinations.custom.headers.hint": "秘密の値（API キー、Bearer トークン）は {vault} で暗号化して保存されます",
+  "destinations.custom.fields": "追加フィールド",
+  "destinations.custom.add-field": "フィールドを追加",
+  "destinations.custom.fields.hint": "API キーとトークンは秘密にすると {vault} に保存されます",
+  "destinations.custom.test.hint": "ヘッダーとフィールドを確認してから URL に OPTIONS を送ります。2xx/3xx/405 = 到達可能。",
+
+  "destinations.probe": "接続確認 — {destination}",
+  "destinations.probe.ok": "すべての手順に成功しました — 認証情報と接続に問題はなさそうです。",
+  "destinations.probe.failed": "1 つ以上の手順に失敗しました — 詳細は下を見てください。",
+  "destinations.probe.step": "手順",
+  "destinations.probe.status": "状態",
+  "destinations.probe.detail": "詳細",
+  "destinations.probe.pass": "成功",
+  "destinations.probe.fail": "失敗",
+};
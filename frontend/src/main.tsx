@@ -1,6 +1,7 @@
 import { render } from "solid-js/web";
 import { App } from "./App";
 import "./styles.css";
+import "./theme";
 
 const root = document.getElementById("root");
 if (!root) throw new Error("root element missing");
@@ -0,0 +1,137 @@
+// searchable registry of every row in the settings view. the panes still lay
+// their rows out by hand; this is what the search box filters, and each entry
+// names the pane, section and field label a result jumps to. add a row here
+// when adding one to a pane, or it can't be found by search.
+
+import { MessageKey, t } from "./i18n";
+import { en } from "./locales/en";
+
+export type SettingsPane = "general" | "capture" | "hdr" | "hotkeys" | "ssh" | "notify";
+
+export interface SettingDescriptor {
+  pane: SettingsPane;
+  section: MessageKey;
+  // renders as the row's .field-label text, which is how a result finds its row
+  label: MessageKey;
+  keywords: string;
+  // rows that only render on one side of the linux / non-linux split
+  only?: "linux" | "not-linux";
+}
+
+const row = (
+  pane: SettingsPane,
+  section: MessageKey,
+  label: MessageKey,
+  keywords = "",
+  only?: SettingDescriptor["only"],
+): SettingDescriptor => ({ pane, section, label, keywords, only });
+
+export const SETTINGS_INDEX: SettingDescriptor[] = [
+  row("general", "settings.section.output", "settings.field.directory", "folder save location path"),
+  row("general", "settings.section.output", "settings.field.filename", "name pattern date time"),
+  row("general", "settings.section.output", "settings.field.format", "png jpg jpeg webp avif jxl file type"),
+  row("general", "settings.section.output", "settings.field.quality", "compression lossy"),
+  row("general", "settings.section.output", "settings.field.editor-projects", "annotations re-edit sidecar capscr.json layers"),
+  row("general", "settings.section.output", "settings.field.archive", "raw lossless png untouched backup copy re-edit folder"),
+  row("general", "settings.section.output", "settings.field.scratch", "temp temporary intermediate spool frames cache location"),
+  row("general", "settings.section.output", "settings.field.scratch-cap", "temp cleanup leftovers size limit disk space"),
+  row("general", "settings.section.output", "settings.field.retention", "quota limit cleanup delete old oldest files size disk space max count move archive"),
+  row("general", "settings.section.output", "settings.field.metadata", "exif png text strip privacy embed timestamp window title archive"),
+  row("general", "settings.section.output", "settings.field.tags", "tags notes keywords comment png text metadata label search"),
+  row("general", "settings.section.output", "resize.label", "resize shrink scale max width height percent lanczos"),
+  row("general", "settings.section.per-app", "settings.field.profiles", "application process program window format folder subfolder action override remember"),
+  row("general", "settings.section.decorations", "settings.field.border", "outline frame padding edge color"),
+  row("general", "settings.section.decorations", "settings.field.corners", "radius round transparent background"),
+  row("general", "settings.section.decorations", "settings.field.caption", "title label text date timestamp bar"),
+  row("general", "settings.section.appearance", "settings.field.theme", "dark light mode system color scheme"),
+  row("general", "settings.section.appearance", "settings.field.accent", "color colour highlight palette tint"),
+  row("general", "settings.section.appearance", "settings.field.scale", "zoom size dpi 4k larger smaller"),
+  row("general", "settings.section.appearance", "settings.field.compact", "small narrow sidebar dense"),
+  row("general", "settings.section.gnome", "settings.field.companion", "shell gnome window picking pins", "linux"),
+
+  row("capture", "settings.section.cursor", "settings.field.cursor", "mouse pointer"),
+  row("capture", "settings.section.overlay", "settings.field.monitors", "multi monitor screen display single pointer region selector dark canvas"),
+  row("capture", "settings.section.after", "settings.field.actions", "post capture action tray clipboard save upload region window full screen recording matrix"),
+  row("capture", "settings.section.after", "settings.field.duplicates", "identical same repeat double press hotkey twice skip warn"),
+  row("capture", "settings.section.window", "settings.field.frame", "shadow border client area", "not-linux"),
+  row("capture", "settings.section.window", "settings.field.corners", "windows 11 transparent", "not-linux"),
+  row("capture", "settings.section.window", "settings.field.ui-elements", "automation controls buttons ctrl", "not-linux"),
+  row("capture", "settings.section.timing", "settings.field.delay", "timer wait tooltip menu"),
+  row("capture", "settings.section.timing", "settings.field.hide-hub", "toolbar window hide during capture recording"),
+  row("capture", "settings.section.game", "settings.field.fullscreen", "game gaming fullscreen exclusive suspend hotkeys conflict wgc"),
+  row("capture", "settings.section.game", "settings.section.per-app", "game process exe override rules"),
+  row("capture", "settings.section.recording", "settings.field.gif-fps", "fps animation"),
+  row("capture", "settings.section.recording", "settings.field.video-fps", "fps mp4"),
+  row("capture", "settings.section.recording", "settings.field.video-quality", "crf bitrate mp4"),
+  row("capture", "settings.section.recording", "settings.field.max-duration", "length limit seconds"),
+  row("capture", "settings.section.recording", "settings.field.audio", "sound microphone"),
+  row("capture", "settings.section.recording", "settings.field.review", "trim preview cut discard before saving"),
+  row("capture", "settings.section.recording", "settings.field.zoom-cursor", "follow pointer magnify pan"),
+  row("capture", "settings.section.recording", "settings.field.stream", "rtmp url ingest twitch obs broadcast"),
+  row("capture", "settings.section.recording", "settings.field.bitrate", "rtmp kbps"),
+  row("capture", "settings.section.auto", "settings.field.status", "interval timer periodic start stop"),
+  row("capture", "settings.section.auto", "settings.field.interval", "seconds periodic"),
+  row("capture", "settings.section.auto", "settings.field.capture", "monitor all screens"),
+  row("capture", "settings.section.auto", "settings.field.folder", "directory"),
+  row("capture", "settings.section.auto", "settings.field.keep", "prune count limit"),
+  row("capture", "settings.section.auto", "settings.field.quota", "space size limit prune"),
+  row("capture", "settings.section.auto", "settings.field.lapse", "timelapse video gif assemble"),
+  row("capture", "settings.section.watch", "settings.field.watch", "auto upload game screenshots steam new images monitor"),
+  row("capture", "settings.section.watch", "settings.field.folder", "directory"),
+  row("capture", "settings.section.watch", "settings.field.then", "action upload clipboard save"),
+
+  row("hdr", "settings.section.hdr-sidecar", "settings.field.container", "png avif jxr file"),
+  row("hdr", "settings.section.hdr-sidecar", "settings.field.output-format", "pq hlg transfer"),
+  row("hdr", "settings.section.hdr-tonemap", "settings.field.operator", "tonemap bt2390 reinhard aces hable"),
+  row("hdr", "settings.section.hdr-tonemap", "settings.field.sdr-white", "brightness nits"),
+  row("hdr", "settings.section.hdr-tonemap", "settings.field.pre-scale", "exposure brightness"),
+  row("hdr", "settings.section.hdr-tonemap", "settings.field.p99", "peak outliers"),
+  row("hdr", "settings.section.hdr-tonemap", "settings.field.preserve-hdr", "sidecar keep"),
+
+  row("hotkeys", "settings.section.global", "settings.field.all-hotkeys", "disable shortcuts kill switch"),
+  row("hotkeys", "settings.section.global", "settings.field.stop-recording", "end gif mp4 stream shortcut"),
+  row("hotkeys", "settings.section.global", "settings.field.dnd", "dnd mute silence presenting gaming snooze notifications sounds timer"),
+  row("hotkeys", "settings.section.global", "settings.field.hotkey-backend", "portal x11 registration"),
+  row("hotkeys", "settings.section.advanced-input", "settings.field.evdev", "keyboard wayland", "linux"),
+
+  row("ssh", "settings.section.sftp", "settings.sftp.known-hosts", "sftp fingerprint trust"),
+
+  row("notify", "settings.section.feedback", "settings.field.notifications", "toast alerts"),
+  row("notify", "settings.section.feedback", "settings.field.sound", "audio shutter"),
+  row("notify", "settings.section.feedback", "settings.field.open-after", "viewer launch"),
+  row("notify", "settings.section.system", "settings.language", "locale translation english deutsch français japanese"),
+  row("notify", "settings.section.system", "settings.field.autostart", "autostart boot startup windows"),
+  row("notify", "settings.section.system", "settings.field.context-menu", "explorer files right-click annotate edit with open with shell"),
+  row("notify", "settings.section.system", "settings.field.minimized", "tray background launch silent"),
+  row("notify", "settings.section.system", "settings.field.close", "exit tray taskbar"),
+  row("notify", "settings.section.system", "settings.field.clipboard-history", "copy"),
+  row("notify", "settings.section.system", "settings.field.updates", "updater version"),
+  row("notify", "settings.section.logs", "settings.field.log", "log viewer debug troubleshoot bug report error failure diagnostics"),
+];
+
+// every whitespace-separated term has to appear in the row's label, section,
+// pane or keywords. labels and sections match in english as well as the
+// current language, since the keywords are english
+export function searchSettings(
+  query: string,
+  opts: { isLinux: boolean; panes: SettingsPane[] },
+): SettingDescriptor[] {
+  const terms = query.trim().toLowerCase().split(/\s+/).filter(Boolean);
+  if (terms.length === 0) return [];
+  return SETTINGS_INDEX.filter((s) => {
+    if (!opts.panes.includes(s.pane)) return false;
+    if (s.only === "linux" && !opts.isLinux) return false;
+    if (s.only === "not-linux" && opts.isLinux) return false;
+    const haystack = [
+      t(s.label),
+      t(s.section),
+      en[s.label],
+      en[s.section],
+      s.pane,
+      s.keywords,
+    ]
+      .join(" ")
+      .toLowerCase();
+    return terms.every((term) => haystack.includes(term));
+  });
+}
@@ -0,0 +1,70 @@
+import { MessageKey, t } from "./i18n";
+
+// stamps for the editor's review marks. they ship as svg source in the bundle
+// rather than as emoji text: emoji fonts differ per desktop (and are missing
+// on some), and a stamp should look the same on whichever machine reopens
+// the project
+
+export type StampId =
+  | "check"
+  | "cross"
+  | "arrow"
+  | "star"
+  | "question"
+  | "warning"
+  | "thumbs-up"
+  | "heart";
+
+// every stamp is drawn in a 64x64 box; the editor scales it to the op's size
+const SVG: Record<StampId, string> = {
+  check: `<circle cx="32" cy="32" r="30" fill="#10b981"/><path d="M18 33l9 9 19-20" fill="none" stroke="#fff" stroke-width="7" stroke-linecap="round" stroke-linejoin="round"/>`,
+  cross: `<circle cx="32" cy="32" r="30" fill="#ef4444"/><path d="M21 21l22 22M43 21L21 43" stroke="#fff" stroke-width="7" stroke-linecap="round"/>`,
+  arrow: `<circle cx="32" cy="32" r="30" fill="#3b82f6"/><path d="M16 32h28M34 20l12 12-12 12" fill="none" stroke="#fff" stroke-width="7" stroke-linecap="round" stroke-linejoin="round"/>`,
+  star: `<path d="M32 3l8.6 18.4 20.1 2.4-14.9 13.8 4 19.9L32 47.6 14.2 57.5l4-19.9L3.3 23.8l20.1-2.4z" fill="#f59e0b" stroke="#b45309" stroke-width="2" stroke-linejoin="round"/>`,
+  question: `<circle cx="32" cy="32" r="30" fill="#a855f7"/><path d="M23 24a9 9 0 1 1 13 8c-3 1.6-4 3.5-4 7" fill="none" stroke="#fff" stroke-width="6" stroke-linecap="round"/><circle cx="32" cy="48" r="4" fill="#fff"/>`,
+  warning: `<path d="M32 4L62 58H2z" fill="#f59e0b" stroke="#b45309" stroke-width="2" stroke-linejoin="round"/><path d="M32 22v18" stroke="#1f2937" stroke-width="6" stroke-linecap="round"/><circle cx="32" cy="49" r="3.5" fill="#1f2937"/>`,
+  "thumbs-up": `<circle cx="32" cy="32" r="30" fill="#3b82f6"/><path d="M16 30h7v18h-7zM26 48V30l8-13c3 0 5 2 4 6l-2 6h10c3 0 5 3 4 6l-3 10c-1 2-3 3-5 3z" fill="#fff"/>`,
+  heart: `<path d="M32 57S5 41 5 22C5 12 13 6 21 6c5 0 9 3 11 7 2-4 6-7 11-7 8 0 16 6 16 16 0 19-27 35-27 35z" fill="#ef4444" stroke="#991b1b" stroke-width="2" stroke-linejoin="round"/>`,
+};
+
+export const STAMP_IDS = Object.keys(SVG) as StampId[];
+
+const LABELS: Record<StampId, MessageKey> = {
+  check: "editor.stamp.check",
+  cross: "editor.stamp.cross",
+  arrow: "editor.stamp.arrow",
+  star: "editor.stamp.star",
+  question: "editor.stamp.question",
+  warning: "editor.stamp.warning",
+  "thumbs-up": "editor.stamp.thumbs-up",
+  heart: "editor.stamp.heart",
+};
+
+export function stampLabel(id: StampId): string {
+  return t(LABELS[id]);
+}
+
+export function stampUrl(id: StampId): string {
+  const svg = `<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 64 64">${SVG[id]}</svg>`;
+  return `data:image/svg+xml;charset=utf-8,${encodeURIComponent(svg)}`;
+}
+
+const images = new Map<StampId, HTMLImageElement>();
+
+// decoded once and shared; svg data urls decode fast, but a redraw can't wait
+// for one, so the editor preloads them all when it mounts
+export function stampImage(id: StampId): HTMLImageElement {
+  let img = images.get(id);
+  if (!img) {
+    img = new Image();
+    img.src = stampUrl(id);
+    images.set(id, img);
+  }
+  return img;
+}
+
+export function preloadStamps(): Promise<void> {
+  return Promise.all(STAMP_IDS.map((id) => stampImage(id).decode().catch(() => {}))).then(
+    () => {},
+  );
+}
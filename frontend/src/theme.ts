@@ -0,0 +1,66 @@
+import { createEffect, createRoot, createSignal, onCleanup } from "solid-js";
+import { getCurrentWindow } from "@tauri-apps/api/window";
+import { UiConfig } from "./api";
+import type { MessageKey } from "./i18n";
+import { config } from "./store";
+
+// preset accents. mono is null: the css default, where the accent is just the
+// top of the greyscale ladder
+export const ACCENTS: { id: UiConfig["accent"]; label: MessageKey; color: string | null }[] = [
+  { id: "mono", label: "settings.accent.mono", color: null },
+  { id: "amber", label: "settings.accent.amber", color: "#e0a84a" },
+  { id: "phosphor", label: "settings.accent.phosphor", color: "#5fd38d" },
+  { id: "ice", label: "settings.accent.ice", color: "#6fb3e8" },
+  { id: "rose", label: "settings.accent.rose", color: "#e07a9a" },
+];
+
+export const THEMES: { id: UiConfig["theme"]; label: MessageKey }[] = [
+  { id: "Dark", label: "settings.theme.dark" },
+  { id: "Light", label: "settings.theme.light" },
+  { id: "System", label: "settings.theme.system" },
+];
+
+// text that sits on an accent fill (primary buttons, checked boxes) has to
+// flip with the accent's brightness, not the theme's
+function inkFor(hex: string): string {
+  const n = parseInt(hex.slice(1), 16);
+  const [r, g, b] = [(n >> 16) & 0xff, (n >> 8) & 0xff, n & 0xff];
+  return 0.2126 * r + 0.7152 * g + 0.0722 * b > 140 ? "#050505" : "#f5f5f5";
+}
+
+const darkQuery = window.matchMedia("(prefers-color-scheme: dark)");
+const [systemDark, setSystemDark] = createSignal(darkQuery.matches);
+
+// only the hub and editor are themed: selectors, pins and the recording bar
+// draw over the desktop and keep their fixed overlay colors
+const THEMED = (label: string) => label === "hub" || label === "editor";
+
+createRoot(() => {
+  if (!THEMED(getCurrentWindow().label)) return;
+  const onChange = (e: MediaQueryListEvent) => setSystemDark(e.matches);
+  darkQuery.addEventListener("change", onChange);
+  onCleanup(() => darkQuery.removeEventListener("change", onChange));
+
+  createEffect(() => {
+    const ui = config()?.ui;
+    const root = document.documentElement;
+    // the hub's zoom is applied natively (set_config → set_zoom); only the
+    // compact layout is css
+    root.toggleAttribute("data-compact", getCurrentWindow().label === "hub" && !!ui?.compact);
+    const theme = ui?.theme ?? "Dark";
+    const light = theme === "Light" || (theme === "System" && !systemDark());
+    root.dataset.theme = light ? "light" : "dark";
+
+    const accent =
+      ui?.accent === "custom"
+        ? ui.accent_color
+        : (ACCENTS.find((a) => a.id === ui?.accent)?.color ?? null);
+    if (accent) {
+      root.style.setProperty("--accent", accent);
+      root.style.setProperty("--accent-ink", inkFor(accent));
+    } else {
+      root.style.removeProperty("--accent");
+      root.style.removeProperty("--accent-ink");
+    }
+  });
+});
@@ -1,29 +1,43 @@
 import { createSignal, For, Show } from "solid-js";
 import { Save, FolderOpen, Zap } from "lucide-solid";
 import { open as openDialog } from "@tauri-apps/plugin-dialog";
+import { openUrl } from "@tauri-apps/plugin-opener";
 import { Section } from "../components/Section";
-import { api, AppConfig, ConnectionTestReport } from "../api";
+import { ResizeField } from "../components/ResizeField";
+import {
+  api,
+  AppConfig,
+  ConnectionTestReport,
+  CustomUploaderPreset,
+  ImgurAlbum,
+  WebhookConfig,
+  WebhookKind,
+} from "../api";
 import { configDirty, setConfigDirty } from "../dirty";
 import { config, mutateConfig } from "../store";
 import { commitNumber } from "../num";
 import { IS_LINUX } from "../keys";
+import { t } from "../i18n";
 
 // the secret-at-rest backend differs per platform: DPAPI blobs on windows,
 // the freedesktop secret service (login keyring) on linux
-const VAULT = IS_LINUX ? "the system keyring" : "Windows DPAPI";
+const vault = () => (IS_LINUX ? t("destinations.vault.keyring") : "Windows DPAPI");
+
+type TestTarget = Parameters<typeof api.testUploadConnection>[0];
 
 export function Destinations() {
   const [status, setStatus] = createSignal<{ tone: string; msg: string } | null>(
     null,
   );
-  const [testing, setTesting] = createSignal<"Ftp" | "Sftp" | "Imgur" | "Custom" | "S3" | null>(null);
+  const [testing, setTesting] = createSignal<TestTarget | null>(null);
   const [report, setReport] = createSignal<ConnectionTestReport | null>(null);
 
-  const test = async (destination: "Ftp" | "Sftp" | "Imgur" | "Custom" | "S3") => {
+  const test = async (destination: TestTarget) => {
     setTesting(destination);
     setReport(null);
     try {
-      const r = await api.testUploadConnection(destination);
+      const preset = destination === "Custom" ? selectedPreset()?.id : undefined;
+      const r = await api.testUploadConnection(destination, preset);
       setReport(r);
     } catch (e) {
       setReport({
@@ -39,13 +53,13 @@ export function Destinations() {
   const save = async () => {
     const c = config();
     if (!c) return;
-    setStatus({ tone: "", msg: "writing..." });
+    setStatus({ tone: "", msg: t("common.writing") });
     try {
       await api.setConfig(c);
-      setStatus({ tone: "ok", msg: "saved." });
+      setStatus({ tone: "ok", msg: t("common.saved") });
       setConfigDirty(false);
     } catch (e) {
-      setStatus({ tone: "err", msg: `err: ${e}` });
+      setStatus({ tone: "err", msg: t("common.error", { error: String(e) }) });
     }
   };
 
@@ -56,11 +70,226 @@ export function Destinations() {
     setConfigDirty(true);
   };
 
+  const patchImgur = (next: Partial<AppConfig["upload"]["imgur"]>) => {
+    const c = config();
+    if (!c) return;
+    patch({ ...c.upload, imgur: { ...c.upload.imgur, ...next } });
+  };
+
+  // custom uploader presets. the one being edited defaults to the active
+  // preset, the same fallback the backend uses for uploads
+  const [editingPreset, setEditingPreset] = createSignal("");
+  const selectedPreset = () => {
+    const u = config()?.upload;
+    if (!u) return undefined;
+    return (
+      u.custom_presets.find((x) => x.id === editingPreset()) ??
+      u.custom_presets.find((x) => x.id === u.active_custom_preset) ??
+      u.custom_presets[0]
+    );
+  };
+  const preset = () => selectedPreset()!;
+
+  const patchPreset = (next: Partial<CustomUploaderPreset>) => {
+    const c = config();
+    const current = selectedPreset();
+    if (!c || !current) return;
+    patch({
+      ...c.upload,
+      custom_presets: c.upload.custom_presets.map((x) =>
+        x.id === current.id ? { ...x, ...next } : x,
+      ),
+    });
+  };
+
+  const addPreset = (from?: CustomUploaderPreset) => {
+    const c = config();
+    if (!c) return;
+    const id = `preset-${Date.now().toString(36)}`;
+    const fresh: CustomUploaderPreset = from
+      ? { ...from, id }
+      : {
+          id,
+          name: t("destinations.preset.new-name", { n: c.upload.custom_presets.length + 1 }),
+          url: "",
+          method: "post",
+          form_name: "file",
+          response_path: "url",
+          response_regex: "",
+          headers: [],
+          form_fields: [],
+          max_file_mb: 0,
+        };
+    patch({
+      ...c.upload,
+      custom_presets: [...c.upload.custom_presets, fresh],
+      active_custom_preset: c.upload.active_custom_preset || id,
+    });
+    setEditingPreset(id);
+  };
+
+  const removePreset = () => {
+    const c = config();
+    const current = selectedPreset();
+    if (!c || !current) return;
+    const rest = c.upload.custom_presets.filter((x) => x.id !== current.id);
+    patch({
+      ...c.upload,
+      custom_presets: rest,
+      active_custom_preset:
+        c.upload.active_custom_preset === current.id
+          ? (rest[0]?.id ?? "")
+          : c.upload.active_custom_preset,
+    });
+    setEditingPreset(rest[0]?.id ?? "");
+  };
+
+  const [sxcuNotes, setSxcuNotes] = createSignal<{ tone: string; msg: string }[] | null>(null);
+
+  const importSxcu = async () => {
+    const picked = await openDialog({
+      multiple: false,
+      directory: false,
+      filters: [{ name: "ShareX uploader", extensions: ["sxcu", "json"] }],
+    });
+    if (typeof picked !== "string") return;
+    const c = config();
+    if (!c) return;
+    try {
+      const { preset: imported, warnings } = await api.importSxcu(picked);
+      if (c.upload.custom_presets.length >= 32) {
+        throw new Error(t("destinations.status.preset-limit"));
+      }
+      addPreset(imported);
+      setSxcuNotes([
+        { tone: "ok", msg: t("destinations.status.imported", { name: imported.name }) },
+        ...warnings.map((w) => ({ tone: "err", msg: w })),
+      ]);
+    } catch (e) {
+      setSxcuNotes([{ tone: "err", msg: t("common.error", { error: String(e) }) }]);
+    }
+  };
+
+  // imgur PIN sign-in: the client secret has to be on disk before the
+  // backend can trade the PIN, so step one saves first
+  const [pin, setPin] = createSignal("");
+  const [awaitingPin, setAwaitingPin] = createSignal(false);
+  const [albums, setAlbums] = createSignal<ImgurAlbum[]>([]);
+  const [newAlbum, setNewAlbum] = createSignal("");
+  const [imgurStatus, setImgurStatus] = createSignal<{ tone: string; msg: string } | null>(
+    null,
+  );
+
+  const imgurAction = async (pending: string, run: () => Promise<string>) => {
+    setImgurStatus({ tone: "", msg: pending });
+    try {
+      setImgurStatus({ tone: "ok", msg: await run() });
+    } catch (e) {
+      setImgurStatus({ tone: "err", msg: t("common.error", { error: String(e) }) });
+    }
+  };
+
+  const beginImgurSignIn = () =>
+    imgurAction(t("destinations.status.opening-browser"), async () => {
+      const c = config();
+      if (c && configDirty()) {
+        await api.setConfig(c);
+        setConfigDirty(false);
+      }
+      await openUrl(await api.imgurAuthorizeUrl());
+      setAwaitingPin(true);
+      return t("destinations.status.imgur-pin");
+    });
+
+  const finishImgurSignIn = () =>
+    imgurAction(t("destinations.status.signing-in"), async () => {
+      const name = await api.imgurSignIn(pin());
+      setPin("");
+      setAwaitingPin(false);
+      return t("destinations.status.signed-in", {
+        name: name || t("destinations.status.unnamed"),
+      });
+    });
+
+  // dropbox sign-in mirrors imgur's: the app key has to be saved before the
+  // backend builds the authorize url, and the code is pasted back here
+  const [dropboxCode, setDropboxCode] = createSignal("");
+  const [awaitingDropbox, setAwaitingDropbox] = createSignal(false);
+  const [dropboxStatus, setDropboxStatus] = createSignal<{ tone: string; msg: string } | null>(
+    null,
+  );
+
+  const dropboxAction = async (pending: string, run: () => Promise<string>) => {
+    setDropboxStatus({ tone: "", msg: pending });
+    try {
+      setDropboxStatus({ tone: "ok", msg: await run() });
+    } catch (e) {
+      setDropboxStatus({ tone: "err", msg: t("common.error", { error: String(e) }) });
+    }
+  };
+
+  const beginDropboxSignIn = () =>
+    dropboxAction(t("destinations.status.opening-browser"), async () => {
+      const c = config();
+      if (c && configDirty()) {
+        await api.setConfig(c);
+        setConfigDirty(false);
+      }
+      await openUrl(await api.dropboxAuthorizeUrl());
+      setAwaitingDropbox(true);
+      return t("destinations.status.dropbox-code");
+    });
+
+  const finishDropboxSignIn = () =>
+    dropboxAction(t("destinations.status.signing-in"), async () => {
+      const name = await api.dropboxSignIn(dropboxCode());
+      setDropboxCode("");
+      setAwaitingDropbox(false);
+      return t("destinations.status.signed-in", {
+        name: name || t("destinations.status.unnamed"),
+      });
+    });
+
+  // opening an encrypted link downloads and decrypts it in the backend; the
+  // result lands in history and opens in the editor
+  const [encryptedLink, setEncryptedLink] = createSignal("");
+  const [linkStatus, setLinkStatus] = createSignal<{ tone: string; msg: string } | null>(null);
+
+  const openEncryptedLink = async () => {
+    setLinkStatus({ tone: "", msg: t("destinations.status.decrypting") });
+    try {
+      const path = await api.openEncryptedLink(encryptedLink().trim());
+      setEncryptedLink("");
+      setLinkStatus({ tone: "ok", msg: t("destinations.status.saved-to", { path }) });
+    } catch (e) {
+      setLinkStatus({ tone: "err", msg: t("common.error", { error: String(e) }) });
+    }
+  };
+
+  const loadAlbums = () =>
+    imgurAction(t("destinations.status.loading-albums"), async () => {
+      const list = await api.imgurAlbums();
+      setAlbums(list);
+      return t(
+        list.length === 1 ? "destinations.status.albums.one" : "destinations.status.albums.other",
+        { count: list.length },
+      );
+    });
+
+  const createAlbum = () =>
+    imgurAction(t("destinations.status.creating-album"), async () => {
+      const album = await api.imgurCreateAlbum(newAlbum());
+      setAlbums([album, ...albums()]);
+      setNewAlbum("");
+      patchImgur({ album_id: album.id });
+      return t("destinations.status.album-created", { title: album.title });
+    });
+
   return (
     <>
       <div class="view-head">
-        <h1>destinations</h1>
-        <span class="lede">where uploads go.</span>
+        <h1>{t("tab.destinations")}</h1>
+        <span class="lede">{t("destinations.lede")}</span>
       </div>
 
       <Show
@@ -75,9 +304,9 @@ export function Destinations() {
       >
         {(c) => (
           <>
-            <Section title="active target">
+            <Section title={t("destinations.active")}>
               <div class="field">
-                <label class="field-label">target</label>
+                <label class="field-label">{t("destinations.target")}</label>
                 <div class="field-control">
                   <select
                     value={c().upload.destination}
@@ -88,17 +317,21 @@ export function Destinations() {
                       })
                     }
                   >
-                    <option value="Imgur">imgur (anonymous)</option>
-                    <option value="Custom">custom http</option>
+                    <option value="Imgur">imgur</option>
+                    <option value="Custom">{t("destinations.target.custom")}</option>
                     <option value="Ftp">ftp</option>
                     <option value="Sftp">sftp (ssh)</option>
-                    <option value="S3">S3 Compatible</option>
+                    <option value="S3">{t("destinations.target.s3")}</option>
+                    <option value="Webdav">webdav / nextcloud</option>
+                    <option value="Dropbox">dropbox</option>
+                    <option value="Email">{t("destinations.target.email")}</option>
+                    <option value="Webhook">{t("destinations.target.webhook")}</option>
                   </select>
                 </div>
               </div>
               <Show when={c().upload.destination === "Imgur"}>
                 <div class="field">
-                  <label class="field-label">imgur client-id</label>
+                  <label class="field-label">{t("destinations.imgur.client-id")}</label>
                   <div class="field-control">
                     <input
                       type="text"
@@ -112,13 +345,12 @@ export function Destinations() {
                       }
                     />
                     <span class="field-hint">
-                      leave blank for capscr's shared key; paste your own from
-                      api.imgur.com to avoid rate-limit pile-ups.
+                      {t("destinations.imgur.client-id.hint")}
                     </span>
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">test</label>
+                  <label class="field-label">{t("destinations.test")}</label>
                   <div class="field-control">
                     <button
                       class="btn"
@@ -127,16 +359,16 @@ export function Destinations() {
                       onClick={() => test("Imgur")}
                     >
                       <Zap size={12} stroke-width={1.5} />
-                      {testing() === "Imgur" ? "probing..." : "test connection"}
+                      {testing() === "Imgur" ? t("destinations.test.running") : t("destinations.test.run")}
                     </button>
                     <span class="field-hint">
-                      hits api.imgur.com/3/credits with this client-id.
+                      {t("destinations.imgur.test.hint")}
                     </span>
                   </div>
                 </div>
               </Show>
               <div class="field">
-                <label class="field-label">copy url to clipboard</label>
+                <label class="field-label">{t("destinations.copy-url")}</label>
                 <div class="field-control">
                   <label class="check">
                     <input
@@ -151,198 +383,681 @@ export function Destinations() {
                     />
                     <span class="check-label">
                       {c().upload.copy_url_to_clipboard
-                        ? "auto-copy on success"
-                        : "leave clipboard alone"}
+                        ? t("destinations.copy-url.on")
+                        : t("destinations.copy-url.off")}
                     </span>
                   </label>
                 </div>
               </div>
-            </Section>
-
-            <Show when={report()}>
-              <ConnectionTestPanel report={report()!} />
-            </Show>
-
-            <Show when={c().upload.destination === "Ftp"}>
-              <Section title="ftp">
-                <div class="field">
-                  <label class="field-label">host</label>
-                  <div class="field-control">
+              <ResizeField
+                value={c().upload.resize}
+                onChange={(resize) => patch({ ...c().upload, resize })}
+                hint={t("destinations.resize.hint")}
+              />
+              <div class="field">
+                <label class="field-label">{t("destinations.recompress")}</label>
+                <div class="field-control">
+                  <select
+                    value={c().upload.recompress.enabled ? c().upload.recompress.format : "off"}
+                    onChange={(e) => {
+                      const v = e.currentTarget.value;
+                      patch({
+                        ...c().upload,
+                        recompress:
+                          v === "off"
+                            ? { ...c().upload.recompress, enabled: false }
+                            : { ...c().upload.recompress, enabled: true, format: v as never },
+                      });
+                    }}
+                  >
+                    <option value="jpeg">{t("destinations.recompress.jpeg")}</option>
+                    <option value="webp">{t("destinations.recompress.webp")}</option>
+                    <option value="off">{t("destinations.recompress.off")}</option>
+                  </select>
+                  <Show when={c().upload.recompress.enabled}>
                     <input
-                      type="text"
-                      placeholder="ftp.example.com"
-                      value={c().upload.ftp.host}
-                      onInput={(e) =>
+                      type="number"
+                      min={10}
+                      max={90}
+                      value={c().upload.recompress.min_quality}
+                      onChange={(e) =>
                         patch({
                           ...c().upload,
-                          ftp: { ...c().upload.ftp, host: e.currentTarget.value },
+                          recompress: {
+                            ...c().upload.recompress,
+                            min_quality: commitNumber(e.currentTarget, { min: 10, max: 90, fallback: c().upload.recompress.min_quality, int: true }),
+                          },
                         })
                       }
                     />
-                  </div>
+                    <span class="field-hint">{t("destinations.recompress.min")}</span>
+                  </Show>
+                  <span class="field-hint">
+                    {t("destinations.recompress.hint")}
+                  </span>
                 </div>
+              </div>
+              <Show when={c().upload.copy_url_to_clipboard}>
                 <div class="field">
-                  <label class="field-label">port</label>
+                  <label class="field-label">{t("destinations.link-format")}</label>
                   <div class="field-control">
+                    <select
+                      value={c().upload.link_format}
+                      onChange={(e) =>
+                        patch({
+                          ...c().upload,
+                          link_format: e.currentTarget.value as never,
+                        })
+                      }
+                    >
+                      <option value="url">{t("destinations.link-format.url")}</option>
+                      <option value="markdown">markdown ![](url)</option>
+                      <option value="html">html &lt;img&gt;</option>
+                      <option value="bbcode">bbcode [img]</option>
+                    </select>
+                    <span class="field-hint">
+                      {t("destinations.link-format.hint")}
+                    </span>
+                  </div>
+                </div>
+              </Show>
+              <div class="field">
+                <label class="field-label">{t("destinations.dedupe")}</label>
+                <div class="field-control">
+                  <select
+                    value={c().upload.dedupe}
+                    onChange={(e) =>
+                      patch({ ...c().upload, dedupe: e.currentTarget.value as never })
+                    }
+                  >
+                    <option value="ask">{t("destinations.dedupe.ask")}</option>
+                    <option value="reuse">{t("destinations.dedupe.reuse")}</option>
+                    <option value="off">{t("destinations.dedupe.off")}</option>
+                  </select>
+                  <Show when={c().upload.dedupe !== "off"}>
                     <input
                       type="number"
                       min={1}
-                      max={65535}
-                      value={c().upload.ftp.port}
+                      max={365}
+                      value={c().upload.dedupe_days}
                       onInput={(e) => {
                         const v = parseInt(e.currentTarget.value);
-                        if (!isNaN(v) && v >= 1 && v <= 65535) {
-                          patch({
-                            ...c().upload,
-                            ftp: { ...c().upload.ftp, port: v },
-                          });
+                        if (!isNaN(v) && v >= 1 && v <= 365) {
+                          patch({ ...c().upload, dedupe_days: v });
                         }
                       }}
                       onChange={(e) =>
                         patch({
                           ...c().upload,
-                          ftp: {
-                            ...c().upload.ftp,
-                            port: commitNumber(e.currentTarget, { min: 1, max: 65535, fallback: c().upload.ftp.port, int: true }),
-                          },
+                          dedupe_days: commitNumber(e.currentTarget, { min: 1, max: 365, fallback: c().upload.dedupe_days, int: true }),
                         })
                       }
                     />
-                    <span class="field-hint">21 plain, 990 implicit tls</span>
-                  </div>
+                    <span class="field-hint">{t("destinations.dedupe.days")}</span>
+                  </Show>
+                  <button
+                    class="btn"
+                    data-variant="ghost"
+                    onClick={async () => {
+                      try {
+                        await api.clearUploadIndex();
+                        setStatus({ tone: "ok", msg: t("destinations.status.forgot") });
+                      } catch (e) {
+                        setStatus({ tone: "err", msg: t("common.error", { error: String(e) }) });
+                      }
+                    }}
+                  >
+                    {t("destinations.dedupe.forget")}
+                  </button>
+                  <span class="field-hint">
+                    {t("destinations.dedupe.hint")}
+                  </span>
                 </div>
-                <div class="field">
-                  <label class="field-label">username</label>
-                  <div class="field-control">
+              </div>
+              <div class="field">
+                <label class="field-label">{t("destinations.secret-scan")}</label>
+                <div class="field-control">
+                  <label class="check">
                     <input
-                      type="text"
-                      placeholder="anonymous"
-                      value={c().upload.ftp.username}
-                      onInput={(e) =>
+                      type="checkbox"
+                      checked={c().upload.secret_scan.enabled}
+                      onChange={(e) =>
                         patch({
                           ...c().upload,
-                          ftp: { ...c().upload.ftp, username: e.currentTarget.value },
+                          secret_scan: { ...c().upload.secret_scan, enabled: e.currentTarget.checked },
                         })
                       }
                     />
-                  </div>
+                    <span class="check-label">{t("destinations.secret-scan.on")}</span>
+                  </label>
+                  <Show when={c().upload.secret_scan.enabled}>
+                    <For
+                      each={[
+                        ["api_keys", "destinations.secret-scan.api-keys"],
+                        ["emails", "destinations.secret-scan.emails"],
+                        ["card_numbers", "destinations.secret-scan.cards"],
+                      ] as const}
+                    >
+                      {([key, label]) => (
+                        <label class="check">
+                          <input
+                            type="checkbox"
+                            checked={c().upload.secret_scan[key]}
+                            onChange={(e) =>
+                              patch({
+                                ...c().upload,
+                                secret_scan: { ...c().upload.secret_scan, [key]: e.currentTarget.checked },
+                              })
+                            }
+                          />
+                          <span class="check-label">{t(label)}</span>
+                        </label>
+                      )}
+                    </For>
+                  </Show>
+                  <span class="field-hint">
+                    {t("destinations.secret-scan.hint")}
+                  </span>
+                </div>
+              </div>
+            </Section>
+
+            <Show when={report()}>
+              <ConnectionTestPanel report={report()!} />
+            </Show>
+
+            <Section title={t("destinations.proxy")}>
+              <div class="field">
+                <label class="field-label">{t("destinations.proxy")}</label>
+                <div class="field-control">
+                  <select
+                    value={c().upload.proxy.mode}
+                    onChange={(e) =>
+                      patch({
+                        ...c().upload,
+                        proxy: { ...c().upload.proxy, mode: e.currentTarget.value as never },
+                      })
+                    }
+                  >
+                    <option value="system">{t("destinations.proxy.system")}</option>
+                    <option value="direct">{t("destinations.proxy.direct")}</option>
+                    <option value="manual">{t("destinations.proxy.manual")}</option>
+                  </select>
+                  <span class="field-hint">{t("destinations.proxy.hint")}</span>
                 </div>
+              </div>
+              <Show when={c().upload.proxy.mode === "manual"}>
                 <div class="field">
-                  <label class="field-label">password</label>
+                  <label class="field-label">{t("destinations.proxy.url")}</label>
                   <div class="field-control">
                     <input
-                      type="password"
-                      placeholder={
-                        c().upload.ftp.password_encrypted
-                          ? "(stored — leave blank to keep current)"
-                          : ""
-                      }
-                      value={c().upload.ftp.password}
+                      type="text"
+                      placeholder="http://proxy.corp:3128 or socks5://127.0.0.1:1080"
+                      value={c().upload.proxy.url}
                       onInput={(e) =>
                         patch({
                           ...c().upload,
-                          ftp: { ...c().upload.ftp, password: e.currentTarget.value },
+                          proxy: { ...c().upload.proxy, url: e.currentTarget.value },
                         })
                       }
                     />
-                    <span class="field-hint">
-                      {c().upload.ftp.password_encrypted
-                        ? `encrypted at rest with ${VAULT} (per-user)`
-                        : `encrypted at rest with ${VAULT} on save`}
-                    </span>
+                    <span class="field-hint">{t("destinations.proxy.url.hint")}</span>
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">remote dir</label>
+                  <label class="field-label">{t("destinations.username")}</label>
                   <div class="field-control">
                     <input
                       type="text"
-                      placeholder="/uploads"
-                      value={c().upload.ftp.remote_dir}
+                      placeholder={t("common.none")}
+                      value={c().upload.proxy.username}
                       onInput={(e) =>
                         patch({
                           ...c().upload,
-                          ftp: { ...c().upload.ftp, remote_dir: e.currentTarget.value },
+                          proxy: { ...c().upload.proxy, username: e.currentTarget.value },
                         })
                       }
                     />
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">tls</label>
-                  <div class="field-control">
-                    <span class="field-hint">plain ftp only — use sftp for an encrypted transfer</span>
-                  </div>
-                </div>
-                <div class="field">
-                  <label class="field-label">public url template</label>
+                  <label class="field-label">{t("destinations.password")}</label>
                   <div class="field-control">
                     <input
-                      type="text"
-                      placeholder="https://cdn.example.com/{filename}"
-                      value={c().upload.ftp.public_url_template}
+                      type="password"
+                      placeholder={
+                        c().upload.proxy.password_encrypted
+                          ? t("destinations.stored")
+                          : ""
+                      }
+                      value={c().upload.proxy.password}
                       onInput={(e) =>
                         patch({
                           ...c().upload,
-                          ftp: {
-                            ...c().upload.ftp,
-                            public_url_template: e.currentTarget.value,
+                          proxy: {
+                            ...c().upload.proxy,
+                            password: e.currentTarget.value,
+                            password_encrypted: "",
                           },
                         })
                       }
                     />
                     <span class="field-hint">
-                      {`{filename} → basename, empty = no url returned`}
+                      {c().upload.proxy.password_encrypted
+                        ? t("destinations.encrypted.stored", { vault: vault() })
+                        : t("destinations.encrypted.on-save", { vault: vault() })}
                     </span>
                   </div>
                 </div>
+              </Show>
+            </Section>
+
+            <Section title={t("destinations.encryption")}>
+              <div class="field">
+                <label class="field-label">{t("destinations.encrypt")}</label>
+                <div class="field-control">
+                  <label class="check">
+                    <input
+                      type="checkbox"
+                      checked={c().upload.encrypt}
+                      onChange={(e) => patch({ ...c().upload, encrypt: e.currentTarget.checked })}
+                    />
+                    <span class="check-label">
+                      {c().upload.encrypt ? t("destinations.encrypt.on") : t("destinations.encrypt.off")}
+                    </span>
+                  </label>
+                  <span class="field-hint">
+                    {c().upload.destination === "Imgur"
+                      ? t("destinations.encrypt.imgur")
+                      : t("destinations.encrypt.hint")}
+                  </span>
+                </div>
+              </div>
+              <div class="field">
+                <label class="field-label">{t("destinations.encrypted-link")}</label>
+                <div class="field-control">
+                  <input
+                    type="url"
+                    placeholder="https://…#key"
+                    value={encryptedLink()}
+                    onInput={(e) => setEncryptedLink(e.currentTarget.value)}
+                  />
+                  <button
+                    class="btn"
+                    data-variant="ghost"
+                    disabled={!encryptedLink().includes("#")}
+                    onClick={openEncryptedLink}
+                  >
+                    {t("common.open")}
+                  </button>
+                </div>
+              </div>
+              <Show when={linkStatus()}>
                 <div class="field">
-                  <label class="field-label">test</label>
+                  <label class="field-label" />
                   <div class="field-control">
-                    <button
-                      class="btn"
-                      data-variant="ghost"
-                      disabled={testing() === "Ftp"}
-                      onClick={() => test("Ftp")}
-                    >
-                      <Zap size={12} stroke-width={1.5} />
-                      {testing() === "Ftp" ? "probing..." : "test connection"}
-                    </button>
-                    <span class="field-hint">
-                      connects, logs in, cwd's to remote dir. doesn't upload.
+                    <span class="flash" data-tone={linkStatus()!.tone}>
+                      {linkStatus()!.msg}
                     </span>
                   </div>
                 </div>
-              </Section>
-            </Show>
+              </Show>
+            </Section>
 
-            <Show when={c().upload.destination === "Sftp"}>
-              <Section title="sftp (ssh)">
-                <div class="field">
-                  <label class="field-label">host</label>
-                  <div class="field-control">
-                    <input
-                      type="text"
-                      placeholder="sftp.example.com"
-                      value={c().upload.sftp.host}
-                      onInput={(e) =>
-                        patch({
-                          ...c().upload,
-                          sftp: { ...c().upload.sftp, host: e.currentTarget.value },
-                        })
-                      }
-                    />
+            <Show when={c().upload.destination === "Imgur"}>
+              <Section title={t("destinations.imgur.account")}>
+                <Show
+                  when={c().upload.imgur.refresh_token_encrypted}
+                  fallback={
+                    <>
+                      <div class="field">
+                        <label class="field-label">{t("destinations.imgur.secret")}</label>
+                        <div class="field-control">
+                          <input
+                            type="password"
+                            placeholder={
+                              c().upload.imgur.client_secret_encrypted
+                                ? t("destinations.stored")
+                                : ""
+                            }
+                            value={c().upload.imgur.client_secret}
+                            onInput={(e) => patchImgur({ client_secret: e.currentTarget.value })}
+                          />
+                          <span class="field-hint">
+                            {t("destinations.imgur.secret.hint", { vault: vault() })}
+                          </span>
+                        </div>
+                      </div>
+                      <div class="field">
+                        <label class="field-label">{t("destinations.sign-in")}</label>
+                        <div class="field-control">
+                          <Show
+                            when={awaitingPin()}
+                            fallback={
+                              <button class="btn" data-variant="ghost" onClick={beginImgurSignIn}>
+                                {t("destinations.imgur.sign-in")}
+                              </button>
+                            }
+                          >
+                            <input
+                              type="text"
+                              placeholder={t("destinations.imgur.pin")}
+                              value={pin()}
+                              onInput={(e) => setPin(e.currentTarget.value)}
+                            />
+                            <button
+                              class="btn"
+                              data-variant="ghost"
+                              disabled={!pin().trim()}
+                              onClick={finishImgurSignIn}
+                            >
+                              {t("destinations.confirm")}
+                            </button>
+                          </Show>
+                          <span class="field-hint">
+                            {t("destinations.imgur.anonymous")}
+                          </span>
+                        </div>
+                      </div>
+                    </>
+                  }
+                >
+                  <div class="field">
+                    <label class="field-label">{t("destinations.account")}</label>
+                    <div class="field-control">
+                      <span>{c().upload.imgur.username || t("destinations.signed-in")}</span>
+                      <button
+                        class="btn"
+                        data-variant="ghost"
+                        onClick={() =>
+                          imgurAction(t("destinations.status.signing-out"), async () => {
+                            await api.imgurSignOut();
+                            setAlbums([]);
+                            return t("destinations.status.imgur-out");
+                          })
+                        }
+                      >
+                        {t("destinations.sign-out")}
+                      </button>
+                    </div>
                   </div>
-                </div>
-                <div class="field">
-                  <label class="field-label">port</label>
-                  <div class="field-control">
-                    <input
-                      type="number"
-                      min={1}
-                      max={65535}
-                      value={c().upload.sftp.port}
-                      onInput={(e) => {
+                  <div class="field">
+                    <label class="field-label">{t("destinations.album")}</label>
+                    <div class="field-control">
+                      <select
+                        value={c().upload.imgur.album_id}
+                        onChange={(e) => patchImgur({ album_id: e.currentTarget.value })}
+                      >
+                        <option value="">{t("destinations.album.none")}</option>
+                        <Show
+                          when={
+                            c().upload.imgur.album_id &&
+                            !albums().some((a) => a.id === c().upload.imgur.album_id)
+                          }
+                        >
+                          <option value={c().upload.imgur.album_id}>
+                            {c().upload.imgur.album_id}
+                          </option>
+                        </Show>
+                        <For each={albums()}>
+                          {(a) => (
+                            <option value={a.id}>
+                              {a.title} ({a.images_count}, {a.privacy || t("destinations.album.default")})
+                            </option>
+                          )}
+                        </For>
+                      </select>
+                      <button class="btn" data-variant="ghost" onClick={loadAlbums}>
+                        {t("common.refresh")}
+                      </button>
+                    </div>
+                  </div>
+                  <div class="field">
+                    <label class="field-label">{t("destinations.album.new")}</label>
+                    <div class="field-control">
+                      <input
+                        type="text"
+                        placeholder={t("destinations.album.title")}
+                        value={newAlbum()}
+                        onInput={(e) => setNewAlbum(e.currentTarget.value)}
+                      />
+                      <select
+                        value={c().upload.imgur.album_privacy}
+                        onChange={(e) =>
+                          patchImgur({ album_privacy: e.currentTarget.value as never })
+                        }
+                      >
+                        <option value="hidden">{t("destinations.album.hidden")}</option>
+                        <option value="public">{t("destinations.album.public")}</option>
+                        <option value="secret">{t("destinations.album.secret")}</option>
+                      </select>
+                      <button
+                        class="btn"
+                        data-variant="ghost"
+                        disabled={!newAlbum().trim()}
+                        onClick={createAlbum}
+                      >
+                        {t("destinations.album.create")}
+                      </button>
+                    </div>
+                  </div>
+                  <div class="field">
+                    <label class="field-label">{t("destinations.imgur.title")}</label>
+                    <div class="field-control">
+                      <input
+                        type="text"
+                        maxLength={1024}
+                        value={c().upload.imgur.title}
+                        onInput={(e) => patchImgur({ title: e.currentTarget.value })}
+                      />
+                    </div>
+                  </div>
+                  <div class="field">
+                    <label class="field-label">{t("destinations.imgur.description")}</label>
+                    <div class="field-control">
+                      <input
+                        type="text"
+                        maxLength={1024}
+                        value={c().upload.imgur.description}
+                        onInput={(e) => patchImgur({ description: e.currentTarget.value })}
+                      />
+                      <span class="field-hint">{t("destinations.imgur.meta.hint")}</span>
+                    </div>
+                  </div>
+                </Show>
+                <Show when={imgurStatus()}>
+                  <div class="field">
+                    <label class="field-label" />
+                    <div class="field-control">
+                      <span class="flash" data-tone={imgurStatus()!.tone}>
+                        {imgurStatus()!.msg}
+                      </span>
+                    </div>
+                  </div>
+                </Show>
+              </Section>
+            </Show>
+
+            <Show when={c().upload.destination === "Ftp"}>
+              <Section title="ftp">
+                <div class="field">
+                  <label class="field-label">{t("destinations.host")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      placeholder="ftp.example.com"
+                      value={c().upload.ftp.host}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          ftp: { ...c().upload.ftp, host: e.currentTarget.value },
+                        })
+                      }
+                    />
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.port")}</label>
+                  <div class="field-control">
+                    <input
+                      type="number"
+                      min={1}
+                      max={65535}
+                      value={c().upload.ftp.port}
+                      onInput={(e) => {
+                        const v = parseInt(e.currentTarget.value);
+                        if (!isNaN(v) && v >= 1 && v <= 65535) {
+                          patch({
+                            ...c().upload,
+                            ftp: { ...c().upload.ftp, port: v },
+                          });
+                        }
+                      }}
+                      onChange={(e) =>
+                        patch({
+                          ...c().upload,
+                          ftp: {
+                            ...c().upload.ftp,
+                            port: commitNumber(e.currentTarget, { min: 1, max: 65535, fallback: c().upload.ftp.port, int: true }),
+                          },
+                        })
+                      }
+                    />
+                    <span class="field-hint">{t("destinations.ftp.port.hint")}</span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.username")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      placeholder="anonymous"
+                      value={c().upload.ftp.username}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          ftp: { ...c().upload.ftp, username: e.currentTarget.value },
+                        })
+                      }
+                    />
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.password")}</label>
+                  <div class="field-control">
+                    <input
+                      type="password"
+                      placeholder={
+                        c().upload.ftp.password_encrypted
+                          ? t("destinations.stored")
+                          : ""
+                      }
+                      value={c().upload.ftp.password}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          ftp: { ...c().upload.ftp, password: e.currentTarget.value },
+                        })
+                      }
+                    />
+                    <span class="field-hint">
+                      {c().upload.ftp.password_encrypted
+                        ? t("destinations.encrypted.stored", { vault: vault() })
+                        : t("destinations.encrypted.on-save", { vault: vault() })}
+                    </span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.remote-dir")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      placeholder="/uploads"
+                      value={c().upload.ftp.remote_dir}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          ftp: { ...c().upload.ftp, remote_dir: e.currentTarget.value },
+                        })
+                      }
+                    />
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.ftp.tls")}</label>
+                  <div class="field-control">
+                    <span class="field-hint">{t("destinations.ftp.tls.hint")}</span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.public-url")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      placeholder="https://cdn.example.com/{filename}"
+                      value={c().upload.ftp.public_url_template}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          ftp: {
+                            ...c().upload.ftp,
+                            public_url_template: e.currentTarget.value,
+                          },
+                        })
+                      }
+                    />
+                    <span class="field-hint">
+                      {t("destinations.public-url.hint")}
+                    </span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.test")}</label>
+                  <div class="field-control">
+                    <button
+                      class="btn"
+                      data-variant="ghost"
+                      disabled={testing() === "Ftp"}
+                      onClick={() => test("Ftp")}
+                    >
+                      <Zap size={12} stroke-width={1.5} />
+                      {testing() === "Ftp" ? t("destinations.test.running") : t("destinations.test.run")}
+                    </button>
+                    <span class="field-hint">
+                      {t("destinations.ftp.test.hint")}
+                    </span>
+                  </div>
+                </div>
+              </Section>
+            </Show>
+
+            <Show when={c().upload.destination === "Sftp"}>
+              <Section title="sftp (ssh)">
+                <div class="field">
+                  <label class="field-label">{t("destinations.host")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      placeholder="sftp.example.com"
+                      value={c().upload.sftp.host}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          sftp: { ...c().upload.sftp, host: e.currentTarget.value },
+                        })
+                      }
+                    />
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.port")}</label>
+                  <div class="field-control">
+                    <input
+                      type="number"
+                      min={1}
+                      max={65535}
+                      value={c().upload.sftp.port}
+                      onInput={(e) => {
                         const v = parseInt(e.currentTarget.value);
                         if (!isNaN(v) && v >= 1 && v <= 65535) {
                           patch({
@@ -361,11 +1076,11 @@ export function Destinations() {
                         })
                       }
                     />
-                    <span class="field-hint">22 standard ssh</span>
+                    <span class="field-hint">{t("destinations.sftp.port.hint")}</span>
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">username</label>
+                  <label class="field-label">{t("destinations.username")}</label>
                   <div class="field-control">
                     <input
                       type="text"
@@ -380,11 +1095,11 @@ export function Destinations() {
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">private key</label>
+                  <label class="field-label">{t("destinations.sftp.key")}</label>
                   <div class="field-control">
                     <input
                       type="text"
-                      placeholder="C:\Users\you\.ssh\id_ed25519 (blank = password auth)"
+                      placeholder={t("destinations.sftp.key.placeholder")}
                       value={c().upload.sftp.private_key_path}
                       onInput={(e) =>
                         patch({
@@ -414,23 +1129,23 @@ export function Destinations() {
                       }}
                     >
                       <FolderOpen size={11} stroke-width={1.5} />
-                      browse
+                      {t("common.browse")}
                     </button>
                     <span class="field-hint">
-                      openssh format. ed25519 / rsa / ecdsa supported.
+                      {t("destinations.sftp.key.hint")}
                     </span>
                   </div>
                 </div>
                 <Show when={c().upload.sftp.private_key_path}>
                   <div class="field">
-                    <label class="field-label">key passphrase</label>
+                    <label class="field-label">{t("destinations.sftp.passphrase")}</label>
                     <div class="field-control">
                       <input
                         type="password"
                         placeholder={
                           c().upload.sftp.private_key_passphrase_encrypted
-                            ? "(stored — leave blank to keep current)"
-                            : "leave blank if the key is unencrypted"
+                            ? t("destinations.stored")
+                            : t("destinations.sftp.passphrase.placeholder")
                         }
                         value={c().upload.sftp.private_key_passphrase}
                         onInput={(e) =>
@@ -441,20 +1156,20 @@ export function Destinations() {
                         }
                       />
                       <span class="field-hint">
-                        encrypted at rest with {VAULT} on save.
+                        {t("destinations.encrypted.on-save", { vault: vault() })}
                       </span>
                     </div>
                   </div>
                 </Show>
                 <div class="field">
-                  <label class="field-label">password</label>
+                  <label class="field-label">{t("destinations.password")}</label>
                   <div class="field-control">
                     <input
                       type="password"
                       placeholder={
                         c().upload.sftp.password_encrypted
-                          ? "(stored — leave blank to keep current)"
-                          : "fallback if key auth fails or no key set"
+                          ? t("destinations.stored")
+                          : t("destinations.sftp.password.placeholder")
                       }
                       value={c().upload.sftp.password}
                       onInput={(e) =>
@@ -466,13 +1181,13 @@ export function Destinations() {
                     />
                     <span class="field-hint">
                       {c().upload.sftp.password_encrypted
-                        ? `encrypted at rest with ${VAULT} (per-user)`
-                        : `encrypted at rest with ${VAULT} on save`}
+                        ? t("destinations.encrypted.stored", { vault: vault() })
+                        : t("destinations.encrypted.on-save", { vault: vault() })}
                     </span>
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">remote dir</label>
+                  <label class="field-label">{t("destinations.remote-dir")}</label>
                   <div class="field-control">
                     <input
                       type="text"
@@ -488,7 +1203,7 @@ export function Destinations() {
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">public url template</label>
+                  <label class="field-label">{t("destinations.public-url")}</label>
                   <div class="field-control">
                     <input
                       type="text"
@@ -505,12 +1220,12 @@ export function Destinations() {
                       }
                     />
                     <span class="field-hint">
-                      {`{filename} → basename, empty = no url returned`}
+                      {t("destinations.public-url.hint")}
                     </span>
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">test</label>
+                  <label class="field-label">{t("destinations.test")}</label>
                   <div class="field-control">
                     <button
                       class="btn"
@@ -519,10 +1234,10 @@ export function Destinations() {
                       onClick={() => test("Sftp")}
                     >
                       <Zap size={12} stroke-width={1.5} />
-                      {testing() === "Sftp" ? "probing..." : "test connection"}
+                      {testing() === "Sftp" ? t("destinations.test.running") : t("destinations.test.run")}
                     </button>
                     <span class="field-hint">
-                      connects, authenticates, lists remote dir. doesn't upload.
+                      {t("destinations.sftp.test.hint")}
                     </span>
                   </div>
                 </div>
@@ -530,9 +1245,9 @@ export function Destinations() {
             </Show>
 
             <Show when={c().upload.destination === "S3"}>
-              <Section title="S3 Compatible">
+              <Section title={t("destinations.target.s3")}>
                 <div class="field">
-                  <label class="field-label">Bucket</label>
+                  <label class="field-label">{t("destinations.s3.bucket")}</label>
                   <div class="field-control">
                     <input
                       type="text"
@@ -548,7 +1263,7 @@ export function Destinations() {
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">Region</label>
+                  <label class="field-label">{t("destinations.s3.region")}</label>
                   <div class="field-control">
                     <input
                       type="text"
@@ -564,11 +1279,11 @@ export function Destinations() {
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">Endpoint</label>
+                  <label class="field-label">{t("destinations.s3.endpoint")}</label>
                   <div class="field-control">
                     <input
                       type="text"
-                      placeholder="https://s3.amazonaws.com (leave empty for default AWS)"
+                      placeholder={t("destinations.s3.endpoint.placeholder")}
                       value={c().upload.s3?.endpoint || ""}
                       onInput={(e) =>
                         patch({
@@ -580,7 +1295,7 @@ export function Destinations() {
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">Access Key ID</label>
+                  <label class="field-label">{t("destinations.s3.access-key")}</label>
                   <div class="field-control">
                     <input
                       type="text"
@@ -596,13 +1311,13 @@ export function Destinations() {
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">Secret Access Key</label>
+                  <label class="field-label">{t("destinations.s3.secret-key")}</label>
                   <div class="field-control">
                     <input
                       type="password"
                       placeholder={
                         c().upload.s3?.secret_access_key_encrypted
-                          ? "•••••••••••••••• (saved)"
+                          ? t("destinations.saved-secret")
                           : "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY"
                       }
                       value={c().upload.s3?.secret_access_key || ""}
@@ -615,13 +1330,13 @@ export function Destinations() {
                     />
                     <Show when={c().upload.s3?.secret_access_key_encrypted}>
                       <span class="field-hint text-live">
-                        encrypted vault populated
+                        {t("destinations.s3.vault")}
                       </span>
                     </Show>
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">Public URL Template</label>
+                  <label class="field-label">{t("destinations.s3.public-url")}</label>
                   <div class="field-control">
                     <input
                       type="text"
@@ -635,12 +1350,12 @@ export function Destinations() {
                       }
                     />
                     <span class="field-hint">
-                      {`{filename} → basename, empty = default s3 url returned`}
+                      {t("destinations.s3.public-url.hint")}
                     </span>
                   </div>
                 </div>
                 <div class="field">
-                  <label class="field-label">test</label>
+                  <label class="field-label">{t("destinations.test")}</label>
                   <div class="field-control">
                     <button
                       class="btn"
@@ -649,88 +1364,777 @@ export function Destinations() {
                       onClick={() => test("S3")}
                     >
                       <Zap size={12} stroke-width={1.5} />
-                      {testing() === "S3" ? "probing..." : "test connection"}
+                      {testing() === "S3" ? t("destinations.test.running") : t("destinations.test.run")}
                     </button>
                     <span class="field-hint">
-                      performs a test upload of a small file to verify permissions.
+                      {t("destinations.s3.test.hint")}
                     </span>
                   </div>
                 </div>
               </Section>
             </Show>
 
-            <Show when={c().upload.destination === "Custom"}>
-            <Section title="custom http">
-              <div class="field">
-                <label class="field-label">post url</label>
-                <div class="field-control">
-                  <input
-                    type="text"
-                    placeholder="https://i.your-server.example/upload"
-                    value={c().upload.custom_url}
-                    onInput={(e) =>
-                      patch({
-                        ...c().upload,
-                        custom_url: e.currentTarget.value,
-                      })
-                    }
-                  />
-                  <span class="field-hint">https only, plain http rejected</span>
-                </div>
-              </div>
-              <div class="field">
-                <label class="field-label">form field</label>
-                <div class="field-control">
-                  <input
-                    type="text"
-                    placeholder="file"
-                    value={c().upload.custom_form_name}
-                    onInput={(e) =>
-                      patch({
-                        ...c().upload,
-                        custom_form_name: e.currentTarget.value,
-                      })
-                    }
-                  />
-                  <span class="field-hint">multipart key (often "file")</span>
+            <Show when={c().upload.destination === "Webdav"}>
+              <Section title="webdav / nextcloud">
+                <div class="field">
+                  <label class="field-label">{t("destinations.webdav.server")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      placeholder="https://cloud.example.com/remote.php/dav/files/me"
+                      value={c().upload.webdav.server_url}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          webdav: { ...c().upload.webdav, server_url: e.currentTarget.value },
+                        })
+                      }
+                    />
+                    <span class="field-hint">
+                      {t("destinations.webdav.server.hint")}
+                    </span>
+                  </div>
                 </div>
-              </div>
-              <div class="field">
-                <label class="field-label">response path</label>
-                <div class="field-control">
-                  <input
-                    type="text"
-                    placeholder="data.link"
-                    value={c().upload.custom_response_path}
-                    onInput={(e) =>
-                      patch({
-                        ...c().upload,
-                        custom_response_path: e.currentTarget.value,
-                      })
-                    }
-                  />
-                  <span class="field-hint">
-                    dotted json path to the url, empty = raw body
-                  </span>
+                <div class="field">
+                  <label class="field-label">{t("destinations.username")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      value={c().upload.webdav.username}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          webdav: { ...c().upload.webdav, username: e.currentTarget.value },
+                        })
+                      }
+                    />
+                  </div>
                 </div>
-              </div>
-              <div class="field">
-                <label class="field-label">test</label>
-                <div class="field-control">
-                  <button
-                    class="btn"
-                    data-variant="ghost"
-                    disabled={testing() === "Custom"}
-                    onClick={() => test("Custom")}
-                  >
-                    <Zap size={12} stroke-width={1.5} />
-                    {testing() === "Custom" ? "probing..." : "test connection"}
+                <div class="field">
+                  <label class="field-label">{t("destinations.password")}</label>
+                  <div class="field-control">
+                    <input
+                      type="password"
+                      placeholder={
+                        c().upload.webdav.password_encrypted
+                          ? t("destinations.stored")
+                          : t("destinations.webdav.password.placeholder")
+                      }
+                      value={c().upload.webdav.password}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          webdav: {
+                            ...c().upload.webdav,
+                            password: e.currentTarget.value,
+                            password_encrypted: "",
+                          },
+                        })
+                      }
+                    />
+                    <span class="field-hint">
+                      {c().upload.webdav.password_encrypted
+                        ? t("destinations.encrypted.stored", { vault: vault() })
+                        : t("destinations.encrypted.on-save", { vault: vault() })}
+                    </span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.folder")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      maxLength={256}
+                      placeholder="capscr/%Y-%m"
+                      value={c().upload.webdav.path_template}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          webdav: { ...c().upload.webdav, path_template: e.currentTarget.value },
+                        })
+                      }
+                    />
+                    <span class="field-hint">
+                      {t("destinations.webdav.folder.hint")}
+                    </span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.webdav.link")}</label>
+                  <div class="field-control">
+                    <label class="check">
+                      <input
+                        type="checkbox"
+                        checked={c().upload.webdav.share_link}
+                        onChange={(e) =>
+                          patch({
+                            ...c().upload,
+                            webdav: { ...c().upload.webdav, share_link: e.currentTarget.checked },
+                          })
+                        }
+                      />
+                      <span class="check-label">{t("destinations.webdav.share")}</span>
+                    </label>
+                  </div>
+                </div>
+                <Show when={!c().upload.webdav.share_link}>
+                  <div class="field">
+                    <label class="field-label">{t("destinations.public-url")}</label>
+                    <div class="field-control">
+                      <input
+                        type="text"
+                        placeholder="https://files.example.com/{path}/{filename}"
+                        value={c().upload.webdav.public_url_template}
+                        onInput={(e) =>
+                          patch({
+                            ...c().upload,
+                            webdav: {
+                              ...c().upload.webdav,
+                              public_url_template: e.currentTarget.value,
+                            },
+                          })
+                        }
+                      />
+                      <span class="field-hint">
+                        {t("destinations.webdav.public-url.hint")}
+                      </span>
+                    </div>
+                  </div>
+                </Show>
+                <div class="field">
+                  <label class="field-label">{t("destinations.test")}</label>
+                  <div class="field-control">
+                    <button
+                      class="btn"
+                      data-variant="ghost"
+                      disabled={testing() === "Webdav"}
+                      onClick={() => test("Webdav")}
+                    >
+                      <Zap size={12} stroke-width={1.5} />
+                      {testing() === "Webdav" ? t("destinations.test.running") : t("destinations.test.run")}
+                    </button>
+                    <span class="field-hint">
+                      {t("destinations.webdav.test.hint")}
+                    </span>
+                  </div>
+                </div>
+              </Section>
+            </Show>
+
+            <Show when={c().upload.destination === "Dropbox"}>
+              <Section title="dropbox">
+                <Show
+                  when={c().upload.dropbox.refresh_token_encrypted}
+                  fallback={
+                    <>
+                      <div class="field">
+                        <label class="field-label">{t("destinations.dropbox.app-key")}</label>
+                        <div class="field-control">
+                          <input
+                            type="text"
+                            value={c().upload.dropbox.app_key}
+                            onInput={(e) =>
+                              patch({
+                                ...c().upload,
+                                dropbox: { ...c().upload.dropbox, app_key: e.currentTarget.value },
+                              })
+                            }
+                          />
+                          <span class="field-hint">
+                            {t("destinations.dropbox.app-key.hint")}
+                          </span>
+                        </div>
+                      </div>
+                      <div class="field">
+                        <label class="field-label">{t("destinations.sign-in")}</label>
+                        <div class="field-control">
+                          <Show
+                            when={awaitingDropbox()}
+                            fallback={
+                              <button
+                                class="btn"
+                                data-variant="ghost"
+                                disabled={!c().upload.dropbox.app_key.trim()}
+                                onClick={beginDropboxSignIn}
+                              >
+                                {t("destinations.dropbox.sign-in")}
+                              </button>
+                            }
+                          >
+                            <input
+                              type="text"
+                              placeholder={t("destinations.dropbox.code")}
+                              value={dropboxCode()}
+                              onInput={(e) => setDropboxCode(e.currentTarget.value)}
+                            />
+                            <button
+                              class="btn"
+                              data-variant="ghost"
+                              disabled={!dropboxCode().trim()}
+                              onClick={finishDropboxSignIn}
+                            >
+                              {t("destinations.confirm")}
+                            </button>
+                          </Show>
+                          <span class="field-hint">
+                            {t("destinations.dropbox.kept", { vault: vault() })}
+                          </span>
+                        </div>
+                      </div>
+                    </>
+                  }
+                >
+                  <div class="field">
+                    <label class="field-label">{t("destinations.account")}</label>
+                    <div class="field-control">
+                      <span>{c().upload.dropbox.account_name || t("destinations.signed-in")}</span>
+                      <button
+                        class="btn"
+                        data-variant="ghost"
+                        onClick={() =>
+                          dropboxAction(t("destinations.status.signing-out"), async () => {
+                            await api.dropboxSignOut();
+                            return t("destinations.status.dropbox-out");
+                          })
+                        }
+                      >
+                        {t("destinations.sign-out")}
+                      </button>
+                    </div>
+                  </div>
+                </Show>
+                <div class="field">
+                  <label class="field-label">{t("destinations.folder")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      maxLength={256}
+                      placeholder="/capscr"
+                      value={c().upload.dropbox.folder}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          dropbox: { ...c().upload.dropbox, folder: e.currentTarget.value },
+                        })
+                      }
+                    />
+                    <span class="field-hint">
+                      {t("destinations.dropbox.folder.hint")}
+                    </span>
+                  </div>
+                </div>
+                <Show when={dropboxStatus()}>
+                  <div class="field">
+                    <label class="field-label" />
+                    <div class="field-control">
+                      <span class="flash" data-tone={dropboxStatus()!.tone}>
+                        {dropboxStatus()!.msg}
+                      </span>
+                    </div>
+                  </div>
+                </Show>
+              </Section>
+            </Show>
+
+            <Show when={c().upload.destination === "Email"}>
+              <Section title={t("destinations.target.email")}>
+                <div class="field">
+                  <label class="field-label">{t("destinations.email.to")}</label>
+                  <div class="field-control">
+                    <input
+                      type="email"
+                      placeholder={t("destinations.email.to.placeholder")}
+                      value={c().upload.email.to}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          email: { ...c().upload.email, to: e.currentTarget.value },
+                        })
+                      }
+                    />
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.email.subject")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      maxLength={200}
+                      value={c().upload.email.subject}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          email: { ...c().upload.email, subject: e.currentTarget.value },
+                        })
+                      }
+                    />
+                    <span class="field-hint">
+                      {t("destinations.email.hint")}
+                    </span>
+                  </div>
+                </div>
+              </Section>
+            </Show>
+
+            <Show when={c().upload.destination === "Webhook"}>
+              <Section title={t("destinations.target.webhook")}>
+                <div class="field">
+                  <label class="field-label">{t("destinations.webhook.service")}</label>
+                  <div class="field-control">
+                    <select
+                      value={c().upload.webhook.kind}
+                      onChange={(e) =>
+                        patch({
+                          ...c().upload,
+                          webhook: {
+                            ...c().upload.webhook,
+                            kind: e.currentTarget.value as WebhookKind,
+                          },
+                        })
+                      }
+                    >
+                      <option value="discord">discord</option>
+                      <option value="slack">slack</option>
+                      <option value="teams">microsoft teams</option>
+                    </select>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.webhook.url")}</label>
+                  <div class="field-control">
+                    <input
+                      type="password"
+                      placeholder={
+                        c().upload.webhook.url_encrypted
+                          ? t("destinations.saved-secret")
+                          : "https://…"
+                      }
+                      value={c().upload.webhook.url}
+                      onInput={(e) =>
+                        // a new url replaces the stored one rather than being
+                        // ignored in favour of it on save
+                        patch({
+                          ...c().upload,
+                          webhook: {
+                            ...c().upload.webhook,
+                            url: e.currentTarget.value,
+                            url_encrypted: "",
+                          },
+                        })
+                      }
+                    />
+                    <span class="field-hint">
+                      {t("destinations.webhook.url.hint", { vault: vault() })}
+                    </span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.webhook.message")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      maxLength={2000}
+                      placeholder={t("destinations.optional")}
+                      value={c().upload.webhook.message}
+                      onInput={(e) =>
+                        patch({
+                          ...c().upload,
+                          webhook: { ...c().upload.webhook, message: e.currentTarget.value },
+                        })
+                      }
+                    />
+                  </div>
+                </div>
+                <Show when={c().upload.webhook.kind !== "discord"}>
+                  <div class="field">
+                    <label class="field-label">{t("destinations.webhook.host")}</label>
+                    <div class="field-control">
+                      <select
+                        value={c().upload.webhook.link_host}
+                        onChange={(e) =>
+                          patch({
+                            ...c().upload,
+                            webhook: {
+                              ...c().upload.webhook,
+                              link_host: e.currentTarget.value as WebhookConfig["link_host"],
+                            },
+                          })
+                        }
+                      >
+                        <option value="Imgur">imgur</option>
+                        <option value="Custom">{t("destinations.target.custom")}</option>
+                        <option value="Ftp">ftp</option>
+                        <option value="Sftp">sftp (ssh)</option>
+                        <option value="S3">{t("destinations.target.s3")}</option>
+                        <option value="Webdav">webdav / nextcloud</option>
+                        <option value="Dropbox">dropbox</option>
+                      </select>
+                      <span class="field-hint">
+                        {t("destinations.webhook.host.hint")}
+                      </span>
+                    </div>
+                  </div>
+                </Show>
+              </Section>
+            </Show>
+
+            <Show when={c().upload.destination === "Custom"}>
+            <Section title={t("destinations.target.custom")}>
+              <div class="field">
+                <label class="field-label">{t("destinations.preset")}</label>
+                <div class="field-control">
+                  <div class="btn-row">
+                    <select
+                      value={selectedPreset()?.id ?? ""}
+                      disabled={c().upload.custom_presets.length === 0}
+                      onChange={(e) => setEditingPreset(e.currentTarget.value)}
+                    >
+                      <For each={c().upload.custom_presets}>
+                        {(x) => (
+                          <option value={x.id}>
+                            {x.name || x.id}
+                            {x.id === c().upload.active_custom_preset ? t("destinations.preset.default-suffix") : ""}
+                          </option>
+                        )}
+                      </For>
+                    </select>
+                    <button
+                      class="btn"
+                      data-variant="ghost"
+                      disabled={c().upload.custom_presets.length >= 32}
+                      onClick={() => addPreset()}
+                    >
+                      {t("common.add")}
+                    </button>
+                    <button
+                      class="btn"
+                      data-variant="ghost"
+                      disabled={!selectedPreset()}
+                      onClick={removePreset}
+                    >
+                      {t("common.delete")}
+                    </button>
+                  </div>
+                  <span class="field-hint">
+                    {t("destinations.preset.hint")}
+                  </span>
+                </div>
+              </div>
+              <div class="field">
+                <label class="field-label">{t("destinations.import")}</label>
+                <div class="field-control">
+                  <button class="btn" data-variant="ghost" onClick={importSxcu}>
+                    <FolderOpen size={12} stroke-width={1.5} />
+                    {t("destinations.import.sxcu")}
                   </button>
                   <span class="field-hint">
-                    sends OPTIONS to the post url. 2xx/3xx/405 = reachable.
+                    {t("destinations.import.hint")}
                   </span>
+                  <Show when={sxcuNotes()}>
+                    <For each={sxcuNotes()!}>
+                      {(note) => (
+                        <span class="flash" data-tone={note.tone}>
+                          {note.msg}
+                        </span>
+                      )}
+                    </For>
+                  </Show>
                 </div>
               </div>
+              <Show
+                when={selectedPreset()}
+                fallback={
+                  <span class="field-hint">{t("destinations.preset.none")}</span>
+                }
+              >
+                <div class="field">
+                  <label class="field-label">{t("destinations.preset.name")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      maxLength={64}
+                      value={preset().name}
+                      onInput={(e) => patchPreset({ name: e.currentTarget.value })}
+                    />
+                    <label class="check">
+                      <input
+                        type="checkbox"
+                        checked={preset().id === c().upload.active_custom_preset}
+                        disabled={preset().id === c().upload.active_custom_preset}
+                        onChange={() =>
+                          patch({ ...c().upload, active_custom_preset: preset().id })
+                        }
+                      />
+                      <span class="check-label">{t("destinations.preset.default")}</span>
+                    </label>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.custom.url")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      placeholder="https://i.your-server.example/upload"
+                      value={preset().url}
+                      onInput={(e) =>
+                        patchPreset({
+                          url: e.currentTarget.value,
+                        })
+                      }
+                    />
+                    <span class="field-hint">{t("destinations.custom.url.hint")}</span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.custom.method")}</label>
+                  <div class="field-control">
+                    <select
+                      value={preset().method}
+                      onChange={(e) =>
+                        patchPreset({
+                          method: e.currentTarget.value as never,
+                        })
+                      }
+                    >
+                      <option value="post">{t("destinations.custom.post")}</option>
+                      <option value="put">{t("destinations.custom.put")}</option>
+                    </select>
+                    <span class="field-hint">
+                      {t("destinations.custom.method.hint")}
+                    </span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.custom.form")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      placeholder="file"
+                      value={preset().form_name}
+                      onInput={(e) =>
+                        patchPreset({
+                          form_name: e.currentTarget.value,
+                        })
+                      }
+                    />
+                    <span class="field-hint">{t("destinations.custom.form.hint")}</span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.custom.path")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      placeholder="data.link"
+                      value={preset().response_path}
+                      onInput={(e) =>
+                        patchPreset({
+                          response_path: e.currentTarget.value,
+                        })
+                      }
+                    />
+                    <span class="field-hint">
+                      {t("destinations.custom.path.hint")}
+                    </span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.custom.regex")}</label>
+                  <div class="field-control">
+                    <input
+                      type="text"
+                      placeholder={'href="([^"]+)"'}
+                      value={preset().response_regex}
+                      onInput={(e) =>
+                        patchPreset({
+                          response_regex: e.currentTarget.value,
+                        })
+                      }
+                    />
+                    <span class="field-hint">
+                      {t("destinations.custom.regex.hint")}
+                    </span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.custom.limit")}</label>
+                  <div class="field-control">
+                    <input
+                      type="number"
+                      min={0}
+                      max={32}
+                      value={preset().max_file_mb}
+                      onChange={(e) =>
+                        patchPreset({
+                          max_file_mb: commitNumber(e.currentTarget, { min: 0, max: 32, fallback: preset().max_file_mb, int: true }),
+                        })
+                      }
+                    />
+                    <span class="field-hint">{t("destinations.custom.limit.hint")}</span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.custom.headers")}</label>
+                  <div class="field-control">
+                    <For each={preset().headers}>
+                      {(h, i) => {
+                        const set = (next: Partial<typeof h>) =>
+                          patchPreset({
+                            headers: preset().headers.map((x, j) =>
+                              j === i() ? { ...x, ...next } : x,
+                            ),
+                          });
+                        return (
+                          <div class="btn-row">
+                            <input
+                              type="text"
+                              placeholder="Authorization"
+                              value={h.name}
+                              onInput={(e) => set({ name: e.currentTarget.value })}
+                            />
+                            <input
+                              type={h.secret ? "password" : "text"}
+                              placeholder={
+                                h.value_encrypted ? t("destinations.stored") : t("destinations.custom.value")
+                              }
+                              value={h.value}
+                              onInput={(e) => set({ value: e.currentTarget.value, value_encrypted: "" })}
+                            />
+                            <label class="check">
+                              <input
+                                type="checkbox"
+                                checked={h.secret}
+                                onChange={(e) => set({ secret: e.currentTarget.checked })}
+                              />
+                              <span class="check-label">{t("destinations.secret")}</span>
+                            </label>
+                            <button
+                              class="btn"
+                              data-variant="ghost"
+                              onClick={() =>
+                                patchPreset({
+                                  headers: preset().headers.filter(
+                                    (_, j) => j !== i(),
+                                  ),
+                                })
+                              }
+                            >
+                              {t("common.remove")}
+                            </button>
+                          </div>
+                        );
+                      }}
+                    </For>
+                    <button
+                      class="btn"
+                      data-variant="ghost"
+                      disabled={preset().headers.length >= 16}
+                      onClick={() =>
+                        patchPreset({
+                          headers: [
+                            ...preset().headers,
+                            { name: "", value: "", secret: false, value_encrypted: "" },
+                          ],
+                        })
+                      }
+                    >
+                      {t("destinations.custom.add-header")}
+                    </button>
+                    <span class="field-hint">
+                      {t("destinations.custom.headers.hint", { vault: vault() })}
+                    </span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.custom.fields")}</label>
+                  <div class="field-control">
+                    <For each={preset().form_fields}>
+                      {(f, i) => {
+                        const set = (next: Partial<typeof f>) =>
+                          patchPreset({
+                            form_fields: preset().form_fields.map((x, j) =>
+                              j === i() ? { ...x, ...next } : x,
+                            ),
+                          });
+                        return (
+                          <div class="btn-row">
+                            <input
+                              type="text"
+                              placeholder={t("destinations.preset.name")}
+                              value={f.name}
+                              onInput={(e) => set({ name: e.currentTarget.value })}
+                            />
+                            <input
+                              type={f.secret ? "password" : "text"}
+                              placeholder={
+                                f.value_encrypted ? t("destinations.stored") : t("destinations.custom.value")
+                              }
+                              value={f.value}
+                              onInput={(e) => set({ value: e.currentTarget.value, value_encrypted: "" })}
+                            />
+                            <label class="check">
+                              <input
+                                type="checkbox"
+                                checked={f.secret}
+                                onChange={(e) => set({ secret: e.currentTarget.checked })}
+                              />
+                              <span class="check-label">{t("destinations.secret")}</span>
+                            </label>
+                            <button
+                              class="btn"
+                              data-variant="ghost"
+                              onClick={() =>
+                                patchPreset({
+                                  form_fields: preset().form_fields.filter(
+                                    (_, j) => j !== i(),
+                                  ),
+                                })
+                              }
+                            >
+                              {t("common.remove")}
+                            </button>
+                          </div>
+                        );
+                      }}
+                    </For>
+                    <button
+                      class="btn"
+                      data-variant="ghost"
+                      disabled={preset().form_fields.length >= 16}
+                      onClick={() =>
+                        patchPreset({
+                          form_fields: [
+                            ...preset().form_fields,
+                            { name: "", value: "", secret: false, value_encrypted: "" },
+                          ],
+                        })
+                      }
+                    >
+                      {t("destinations.custom.add-field")}
+                    </button>
+                    <span class="field-hint">
+                      {t("destinations.custom.fields.hint", { vault: vault() })}
+                    </span>
+                  </div>
+                </div>
+                <div class="field">
+                  <label class="field-label">{t("destinations.test")}</label>
+                  <div class="field-control">
+                    <button
+                      class="btn"
+                      data-variant="ghost"
+                      disabled={testing() === "Custom"}
+                      onClick={() => test("Custom")}
+                    >
+                      <Zap size={12} stroke-width={1.5} />
+                      {testing() === "Custom" ? t("destinations.test.running") : t("destinations.test.run")}
+                    </button>
+                    <span class="field-hint">
+                      {t("destinations.custom.test.hint")}
+                    </span>
+                  </div>
+                </div>
+              </Show>
             </Section>
             </Show>
 
@@ -746,10 +2150,10 @@ export function Destinations() {
                 data-variant={configDirty() ? "primary" : undefined}
                 onClick={save}
                 disabled={!configDirty()}
-                title={configDirty() ? "commit pending changes" : "no changes to save"}
+                title={configDirty() ? t("common.save.title") : t("common.save.clean")}
               >
                 <Save size={12} stroke-width={1.5} />
-                save
+                {t("common.save")}
               </button>
             </div>
           </>
@@ -761,22 +2165,22 @@ export function Destinations() {
 
 function ConnectionTestPanel(props: { report: ConnectionTestReport }) {
   return (
-    <Section title={`probe — ${props.report.destination.toLowerCase()}`}>
+    <Section title={t("destinations.probe", { destination: props.report.destination.toLowerCase() })}>
       <p
         class="flash"
         data-tone={props.report.overall_ok ? "ok" : "err"}
         style="margin-bottom: 8px;"
       >
         {props.report.overall_ok
-          ? "all steps passed — credentials and connectivity look good."
-          : "one or more steps failed — see details below."}
+          ? t("destinations.probe.ok")
+          : t("destinations.probe.failed")}
       </p>
       <table class="diag-table">
         <thead>
           <tr>
-            <th>step</th>
-            <th>status</th>
-            <th>detail</th>
+            <th>{t("destinations.probe.step")}</th>
+            <th>{t("destinations.probe.status")}</th>
+            <th>{t("destinations.probe.detail")}</th>
           </tr>
         </thead>
         <tbody>
@@ -786,7 +2190,7 @@ function ConnectionTestPanel(props: { report: ConnectionTestReport }) {
                 <td>{s.step}</td>
                 <td>
                   <span class={s.ok ? "chip-live" : "chip-fail"}>
-                    ● {s.ok ? "ok" : "fail"}
+                    ● {s.ok ? t("destinations.probe.pass") : t("destinations.probe.fail")}
                   </span>
                 </td>
                 <td style="word-break: break-word;">{s.detail}</td>
@@ -2,7 +2,9 @@ import { createSignal, For, onCleanup, onMount, Show } from "solid-js";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { convertFileSrc, invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
-import { api } from "../api";
+import { api, AppConfig, CustomUploaderPreset, FormatSizeEstimate } from "../api";
+import { arrowNav } from "../a11y";
+import { MessageKey, t, tm } from "../i18n";
 import {
   ArrowRight,
   Square,
@@ -21,8 +23,18 @@ import {
   Minus,
   CircleDashed,
   Highlighter,
+  Pencil,
+  Eye,
+  EyeOff,
+  Plus,
+  Sticker,
+  Ruler,
+  Tag,
 } from "lucide-solid";
 import { Titlebar } from "../components/Titlebar";
+import { ColorPicker } from "../components/ColorPicker";
+import { TagsModal } from "../components/TagsModal";
+import { preloadStamps, STAMP_IDS, StampId, stampImage, stampLabel, stampUrl } from "../stamps";
 
 type Tool =
   | "arrow"
@@ -32,7 +44,10 @@ type Tool =
   | "step"
   | "line"
   | "ellipse"
-  | "highlight";
+  | "highlight"
+  | "pen"
+  | "stamp"
+  | "measure";
 
 interface Point {
   x: number;
@@ -61,8 +76,25 @@ interface TextOp {
   text: string;
   color: string;
   fontSize: number;
+  // absent in projects saved before the font picker: mono
+  font?: TextFont;
 }
 
+// generic families rather than named faces: nothing is bundled, so each one
+// resolves to whatever the desktop has, and the canvas that draws it is also
+// what export flattens
+type TextFont = "mono" | "sans" | "serif" | "hand";
+
+const TEXT_FONTS: { id: TextFont; label: MessageKey; stack: string }[] = [
+  { id: "mono", label: "editor.font.mono", stack: `"Fira Code", "Hack", ui-monospace, monospace` },
+  { id: "sans", label: "editor.font.sans", stack: `system-ui, "Segoe UI", "Noto Sans", sans-serif` },
+  { id: "serif", label: "editor.font.serif", stack: `Georgia, "Noto Serif", serif` },
+  { id: "hand", label: "editor.font.hand", stack: `"Segoe Print", "Comic Neue", "Comic Sans MS", cursive` },
+];
+
+const fontStack = (font?: TextFont) =>
+  (TEXT_FONTS.find((f) => f.id === font) ?? TEXT_FONTS[0]).stack;
+
 interface BlurOp {
   kind: "blur";
   origin: Point;
@@ -102,7 +134,49 @@ interface HighlightOp {
   width: number;
 }
 
-type Op =
+// one sample of a freehand stroke; p is pen pressure 0-1, 1 for a mouse
+interface PenPoint extends Point {
+  p: number;
+}
+
+interface PenOp {
+  kind: "pen";
+  points: PenPoint[];
+  color: string;
+  width: number;
+}
+
+// a bundled stamp centred on `center`, `size` px square, turned `angle`
+// radians clockwise
+interface StampOp {
+  kind: "stamp";
+  center: Point;
+  stamp: StampId;
+  size: number;
+  angle: number;
+}
+
+// a ruler between two points, labelled with its length and the size of the
+// box it spans, all in image pixels
+interface MeasureOp {
+  kind: "measure";
+  from: Point;
+  to: Point;
+  color: string;
+}
+
+// annotation layers, bottom to top. every op carries the id of the layer it
+// was drawn on, and only export flattens them, so a layer can be hidden or
+// dropped without redoing what's on the others
+interface Layer {
+  id: number;
+  name: string;
+  visible: boolean;
+}
+
+const firstLayer = (): Layer => ({ id: 1, name: t("editor.layer.name", { n: 1 }), visible: true });
+
+type Op = (
   | ArrowOp
   | RectOp
   | TextOp
@@ -110,38 +184,87 @@ type Op =
   | StepOp
   | LineOp
   | EllipseOp
-  | HighlightOp;
+  | HighlightOp
+  | PenOp
+  | StampOp
+  | MeasureOp
+) & { layer?: number };
+
+// `<file name>.capscr.json` beside a capture saved from here. the flattened file is
+// what everything else reads; this keeps what it was flattened from, so a
+// reopen starts from the unedited pixels with every annotation still an op
+interface EditorProject {
+  version: 1;
+  // size of the flattened capture it belongs to; a file edited elsewhere
+  // since won't match and the project is ignored
+  width: number;
+  height: number;
+  // png data url of the unedited capture
+  original: string;
+  ops: Op[];
+  // absent in projects saved before layers: everything is on one layer
+  layers?: Layer[];
+  background?: boolean;
+}
 
 const COLORS = ["#ef4444", "#f59e0b", "#10b981", "#3b82f6", "#a855f7", "#ffffff", "#000000"];
 
 export function Editor() {
   let canvasRef!: HTMLCanvasElement;
+  let wrapRef!: HTMLDivElement;
   let baseImage: HTMLImageElement | null = null;
 
   const [imagePath, setImagePath] = createSignal<string | null>(null);
   const [loaded, setLoaded] = createSignal(false);
+  // the tags and note dialog is open over the canvas
+  const [tagging, setTagging] = createSignal(false);
   const [tool, setTool] = createSignal<Tool>("arrow");
   const [color, setColor] = createSignal<string>(COLORS[0]);
+  // persisted in config.ui so the row survives the editor closing
+  const [recentColors, setRecentColors] = createSignal<string[]>([]);
   const [strokeWidth, setStrokeWidth] = createSignal(3);
   const [textSize, setTextSize] = createSignal(24);
+  const [textFont, setTextFont] = createSignal<TextFont>("mono");
   const [stepRadius, setStepRadius] = createSignal(16);
+  const [stamp, setStamp] = createSignal<StampId>("check");
+  const [stampSize, setStampSize] = createSignal(48);
   const [ops, setOps] = createSignal<Op[]>([]);
   const [redoStack, setRedoStack] = createSignal<Op[]>([]);
-  // 1.0 = fit-to-wrap (handled by CSS max-width); >1.0 = explicit pixel scale.
+  // css pixels per image pixel. fit-to-window lands between the steps, so the
+  // steps are where ctrl+=/- go next rather than the only values allowed
   const [zoom, setZoom] = createSignal(1.0);
-  const ZOOM_LEVELS = [0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 4.0];
+  const ZOOM_LEVELS = [0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 4.0];
+  // natural size of the image on the canvas; drives the canvas's css size
+  const [imageSize, setImageSize] = createSignal({ w: 0, h: 0 });
+  // space held (or middle button down) turns a drag into a pan
+  const [spaceHeld, setSpaceHeld] = createSignal(false);
+  const [panning, setPanning] = createSignal(false);
   const [draft, setDraft] = createSignal<Op | null>(null);
   const [textInputAt, setTextInputAt] = createSignal<Point | null>(null);
   const [textBuffer, setTextBuffer] = createSignal("");
+  // text tool over an existing text box: a drag moves it instead of typing
+  const [overText, setOverText] = createSignal(false);
   const [busy, setBusy] = createSignal<"save" | "copy" | "upload" | null>(null);
   const [status, setStatus] = createSignal<{ tone: string; msg: string } | null>(null);
   // tracks whether a paste replaced the canvas — paste doesn't add to ops[]
   // so isDirty() would otherwise return false, silently discarding the paste
   const [hasPastedContent, setHasPastedContent] = createSignal(false);
+  // the ops as last loaded from or saved to a project; anything else is unsaved
+  const [savedOps, setSavedOps] = createSignal<Op[]>([]);
+  const [layers, setLayers] = createSignal<Layer[]>([firstLayer()]);
+  const [activeLayer, setActiveLayer] = createSignal(firstLayer().id);
+  const [backgroundVisible, setBackgroundVisible] = createSignal(true);
+  const [editorProjects, setEditorProjects] = createSignal(false);
   const [isHdrSource, setIsHdrSource] = createSignal(false);
   const [hdrSidecarPath, setHdrSidecarPath] = createSignal<string | null>(null);
 
   let dragStart: Point | null = null;
+  // a text box being dragged, and where in it the pointer grabbed it. it
+  // stays in ops() until the drop but isn't drawn; its draft is
+  let moving: { op: TextOp; grab: Point } | null = null;
+  // a moved text box is re-pushed on top; this remembers the op it replaced
+  // and where it sat, so undo puts it back rather than deleting the text
+  const movedFrom = new WeakMap<Op, { op: Op; index: number }>();
 
   const win = getCurrentWindow();
 
@@ -149,6 +272,8 @@ export function Editor() {
     setLoaded(false);
     setStatus(null);
     setOps([]);
+    setSavedOps([]);
+    resetLayers();
     setRedoStack([]);
     setDraft(null);
     setHasPastedContent(false);
@@ -161,7 +286,7 @@ export function Editor() {
       setImagePath(path);
       setStatus({
         tone: "err",
-        msg: "GIF editing isn't supported — would flatten frames. Close to keep the original.",
+        msg: t("editor.status.gif"),
       });
       return;
     }
@@ -191,20 +316,77 @@ export function Editor() {
     try {
       await img.decode();
     } catch (e) {
-      setStatus({ tone: "err", msg: `image load failed: ${e}` });
+      setStatus({ tone: "err", msg: t("editor.status.load-failed", { error: String(e) }) });
       return;
     }
     baseImage = img;
+    const project = await readProject(path, img);
+    if (project) {
+      baseImage = project.original;
+      resetLayers(project.layers, project.background);
+      const bottom = layers()[0].id;
+      const projectOps = project.ops.map((op) => ({ ...op, layer: op.layer ?? bottom }));
+      setOps(projectOps);
+      setSavedOps(projectOps);
+      setStatus({
+        tone: "ok",
+        msg: t(
+          project.ops.length === 1 ? "editor.status.reopened.one" : "editor.status.reopened.other",
+          { count: project.ops.length },
+        ),
+      });
+    }
+    setImageSize({ w: img.naturalWidth, h: img.naturalHeight });
+    // mount the canvas first; it lives under the loaded() <Show>
+    setLoaded(true);
     canvasRef.width = img.naturalWidth;
     canvasRef.height = img.naturalHeight;
-    setLoaded(true);
+    // open big captures (4k and up) whole rather than at 100% in a corner
+    setZoom(Math.min(1, fitZoom()));
     redraw();
+
+    // estimates describe the capture as it is on disk; annotations only nudge
+    // them, so they aren't recomputed per stroke
+    setSizeEstimates([]);
+    api
+      .estimateCaptureSizes(path)
+      .then((sizes) => {
+        if (imagePath() === path) setSizeEstimates(sizes);
+      })
+      .catch(() => {
+        // footer just omits the estimates
+      });
   };
 
+  const [sizeEstimates, setSizeEstimates] = createSignal<FormatSizeEstimate[]>([]);
+  const [outputFormat, setOutputFormat] = createSignal<AppConfig["output"]["format"] | null>(
+    null,
+  );
+
+  // custom uploader presets the upload button can target; "" follows the
+  // configured destination
+  const [presets, setPresets] = createSignal<CustomUploaderPreset[]>([]);
+  const [uploadPreset, setUploadPreset] = createSignal("");
+  onMount(async () => {
+    try {
+      const c = await api.getConfig();
+      setEditorProjects(c.output.editor_projects);
+      setPresets(c.upload.custom_presets);
+      setOutputFormat(c.output.format);
+      setRecentColors(c.ui.editor_recent_colors);
+      if (c.ui.editor_recent_colors.length > 0) setColor(c.ui.editor_recent_colors[0]);
+    } catch {
+      // no picker; upload still follows the configured destination
+    }
+  });
+
+  // a project reopened before they decode would draw its stamps as nothing
+  onMount(() => void preloadStamps().then(redraw));
+
   onMount(async () => {
     const path = await invoke<string | null>("get_editor_image_path");
     if (!path) {
-      setStatus({ tone: "err", msg: "no image path received from backend" });
+      setStatus({ tone: "err", msg: t("editor.status.no-path") });
       return;
     }
     await loadImage(path);
@@ -216,10 +398,8 @@ export function Editor() {
   // the new file — any save would overwrite the wrong file.
   onMount(async () => {
     const unlisten = await listen<string>("capscr://editor-load", async (e) => {
-      if (ops().length > 0 || draft() !== null) {
-        const ok = window.confirm(
-          "Discard unsaved annotations and open a new image?",
-        );
+      if (isDirty()) {
+        const ok = window.confirm(t("editor.discard.load"));
         if (!ok) return;
       }
       await loadImage(e.payload);
@@ -227,17 +407,50 @@ export function Editor() {
     onCleanup(unlisten);
   });
 
-  const stepZoom = (dir: 1 | -1) => {
+  // the zoom at which the whole image fits the visible canvas area
+  const fitZoom = () => {
+    const { w, h } = imageSize();
+    if (!wrapRef || w === 0 || h === 0) return 1;
+    const style = getComputedStyle(wrapRef);
+    const padX = parseFloat(style.paddingLeft) + parseFloat(style.paddingRight);
+    const padY = parseFloat(style.paddingTop) + parseFloat(style.paddingBottom);
+    return Math.max(
+      ZOOM_LEVELS[0],
+      Math.min((wrapRef.clientWidth - padX) / w, (wrapRef.clientHeight - padY) / h),
+    );
+  };
+
+  // change zoom keeping the image point under `anchor` (client coords, the
+  // view's center when omitted) where it is on screen
+  const zoomTo = (next: number, anchor?: { x: number; y: number }) => {
+    const prev = zoom();
+    if (!wrapRef || Math.abs(next - prev) < 0.001) return;
+    const rect = wrapRef.getBoundingClientRect();
+    const ax = anchor ? anchor.x - rect.left : wrapRef.clientWidth / 2;
+    const ay = anchor ? anchor.y - rect.top : wrapRef.clientHeight / 2;
+    const left = wrapRef.scrollLeft;
+    const top = wrapRef.scrollTop;
+    setZoom(next);
+    const ratio = next / prev;
+    wrapRef.scrollLeft = (left + ax) * ratio - ax;
+    wrapRef.scrollTop = (top + ay) * ratio - ay;
+  };
+
+  const stepZoom = (dir: 1 | -1, anchor?: { x: number; y: number }) => {
     const cur = zoom();
-    const idx = ZOOM_LEVELS.findIndex((z) => Math.abs(z - cur) < 0.01);
-    const fallback = ZOOM_LEVELS.findIndex((z) => z >= cur);
-    const start = idx >= 0 ? idx : Math.max(0, fallback);
-    const next = Math.max(0, Math.min(ZOOM_LEVELS.length - 1, start + dir));
-    setZoom(ZOOM_LEVELS[next]);
+    const next =
+      dir > 0
+        ? ZOOM_LEVELS.find((z) => z > cur + 0.001)
+        : [...ZOOM_LEVELS].reverse().find((z) => z < cur - 0.001);
+    if (next !== undefined) zoomTo(next, anchor);
   };
 
+  const fitToWindow = () => zoomTo(fitZoom());
+
   const onKeydown = (e: KeyboardEvent) => {
-    if (textInputAt()) return;
+    if (textInputAt() || tagging()) return;
+    // typing a hex color mustn't switch tools or save
+    if (e.target instanceof HTMLInputElement && e.target.type === "text") return;
     const mod = e.ctrlKey || e.metaKey;
     if (e.key === "z" && mod && !e.shiftKey) {
       e.preventDefault();
@@ -251,11 +464,29 @@ export function Editor() {
     } else if (mod && e.key === "-") {
       e.preventDefault();
       stepZoom(-1);
+    } else if (mod && e.shiftKey && e.key.toLowerCase() === "s" && busy() === null && loaded()) {
+      e.preventDefault();
+      void onSaveAs();
+    } else if (mod && !e.shiftKey && e.key === "c" && busy() === null && loaded()) {
+      // nothing else in the editor is selectable, so copy means the capture
+      e.preventDefault();
+      void onCopy();
+    } else if (mod && !e.shiftKey && e.key === "u" && busy() === null && loaded()) {
+      e.preventDefault();
+      void onUpload();
     } else if (mod && e.key === "0") {
       e.preventDefault();
-      setZoom(1.0);
+      zoomTo(1.0);
+    } else if (e.key === " " && !isControl(e.target)) {
+      // a focused button keeps space for itself
+      e.preventDefault();
+      setSpaceHeld(true);
     } else if (e.key === "Escape") {
       void confirmCloseEditor();
+    } else if (e.key === "Enter" && !mod && busy() === null && loaded()) {
+      // the prompt post-action lands here, so Enter takes its default: save
+      e.preventDefault();
+      void onSave();
     } else if (e.key === "1") setTool("arrow");
     else if (e.key === "2") setTool("rect");
     else if (e.key === "3") {
@@ -265,12 +496,53 @@ export function Editor() {
     else if (e.key === "6") setTool("line");
     else if (e.key === "7") setTool("ellipse");
     else if (e.key === "8") setTool("highlight");
+    else if (e.key === "9") setTool("pen");
+    else if (e.key === "0") setTool("stamp");
+    else if (e.key === "m" && !mod) setTool("measure");
+    else if (e.key === "f" && !mod) fitToWindow();
+  };
+
+  const pickColor = (c: string) => {
+    setColor(c);
+    api
+      .rememberEditorColor(c)
+      .then(setRecentColors)
+      .catch(() => {
+        // the row just doesn't update
+      });
+  };
+
+  const isControl = (target: EventTarget | null) =>
+    target instanceof HTMLElement && !!target.closest("button, input, select, textarea");
+
+  const onKeyup = (e: KeyboardEvent) => {
+    if (e.key === " ") setSpaceHeld(false);
+  };
+  // a space released while another window had focus never sends keyup
+  const onBlur = () => setSpaceHeld(false);
+
+  const onPanStart = (e: MouseEvent) => {
+    if (!(spaceHeld() || e.button === 1)) return;
+    e.preventDefault();
+    const from = { x: e.clientX, y: e.clientY, left: wrapRef.scrollLeft, top: wrapRef.scrollTop };
+    setPanning(true);
+    const move = (ev: MouseEvent) => {
+      wrapRef.scrollLeft = from.left - (ev.clientX - from.x);
+      wrapRef.scrollTop = from.top - (ev.clientY - from.y);
+    };
+    const up = () => {
+      setPanning(false);
+      window.removeEventListener("mousemove", move);
+      window.removeEventListener("mouseup", up);
+    };
+    window.addEventListener("mousemove", move);
+    window.addEventListener("mouseup", up);
   };
 
   const onWheelZoom = (e: WheelEvent) => {
     if (!(e.ctrlKey || e.metaKey)) return;
     e.preventDefault();
-    stepZoom(e.deltaY < 0 ? 1 : -1);
+    stepZoom(e.deltaY < 0 ? 1 : -1, { x: e.clientX, y: e.clientY });
   };
 
   // replace the current canvas with a pasted clipboard image; users expect
@@ -290,31 +562,36 @@ export function Editor() {
         img.src = url;
         await img.decode();
         baseImage = img;
+        setImageSize({ w: img.naturalWidth, h: img.naturalHeight });
+        setLoaded(true);
         canvasRef.width = img.naturalWidth;
         canvasRef.height = img.naturalHeight;
+        setZoom(Math.min(1, fitZoom()));
         setOps([]);
+        resetLayers();
         setRedoStack([]);
         setHasPastedContent(true);
-        setLoaded(true);
         setStatus({
           tone: "ok",
-          msg: "pasted from clipboard — save to write back to disk.",
+          msg: t("editor.status.pasted"),
         });
         redraw();
       } catch (err) {
-        setStatus({ tone: "err", msg: `paste failed: ${err}` });
+        setStatus({ tone: "err", msg: t("editor.status.paste-failed", { error: String(err) }) });
       } finally {
         URL.revokeObjectURL(url);
       }
       return;
     }
-    setStatus({ tone: "", msg: "paste: only images are accepted here — use the text tool to add text" });
+    setStatus({ tone: "", msg: t("editor.status.paste-text") });
   };
 
   // truthy when there's at least one committed edit (or a draft mid-drag).
   // used by the dirty-state guard so Escape / the close button warn before
   // throwing away the user's work.
-  const isDirty = () => ops().length > 0 || draft() !== null || hasPastedContent();
+  const opsChanged = () =>
+    ops() !== savedOps() && (ops().length > 0 || savedOps().length > 0);
+  const isDirty = () => opsChanged() || draft() !== null || hasPastedContent();
 
   const confirmCloseEditor = async () => {
     if (!isDirty()) {
@@ -322,8 +599,8 @@ export function Editor() {
       return;
     }
     const msg = hasPastedContent()
-      ? "Discard pasted image and unsaved annotations? Changes aren't written to disk until you press Save."
-      : "Discard unsaved annotations? They aren't written back to disk until you press Save.";
+      ? t("editor.discard.pasted")
+      : t("editor.discard");
     const ok = window.confirm(msg);
     if (ok) {
       void win.close();
@@ -332,10 +609,14 @@ export function Editor() {
 
   onMount(() => {
     window.addEventListener("keydown", onKeydown);
+    window.addEventListener("keyup", onKeyup);
+    window.addEventListener("blur", onBlur);
     window.addEventListener("paste", onPaste);
   });
   onCleanup(() => {
     window.removeEventListener("keydown", onKeydown);
+    window.removeEventListener("keyup", onKeyup);
+    window.removeEventListener("blur", onBlur);
     window.removeEventListener("paste", onPaste);
   });
 
@@ -347,8 +628,8 @@ export function Editor() {
     closeUnlisten = await win.onCloseRequested(async (ev) => {
       if (!isDirty()) return;
       const msg = hasPastedContent()
-        ? "Discard pasted image and unsaved annotations? Changes aren't written to disk until you press Save."
-        : "Discard unsaved annotations? They aren't written back to disk until you press Save.";
+        ? t("editor.discard.pasted")
+        : t("editor.discard");
       const ok = window.confirm(msg);
       if (!ok) ev.preventDefault();
     });
@@ -369,9 +650,15 @@ export function Editor() {
     if (!baseImage) return;
     const ctx = canvasRef.getContext("2d");
     if (!ctx) return;
-    ctx.drawImage(baseImage, 0, 0);
-    for (const op of ops()) {
-      renderOp(ctx, op);
+    // captures with transparent pixels (clean window corners) don't cover the
+    // previous frame, so a dragged draft would smear without the clear
+    ctx.clearRect(0, 0, canvasRef.width, canvasRef.height);
+    if (backgroundVisible()) ctx.drawImage(baseImage, 0, 0);
+    for (const layer of layers()) {
+      if (!layer.visible) continue;
+      for (const op of ops()) {
+        if (op.layer === layer.id && op !== moving?.op) renderOp(ctx, op);
+      }
     }
     const d = draft();
     if (d) renderOp(ctx, d);
@@ -390,7 +677,7 @@ export function Editor() {
         break;
       case "text":
         ctx.fillStyle = op.color;
-        ctx.font = `${op.fontSize}px "Fira Code", "Hack", ui-monospace, monospace`;
+        ctx.font = `${op.fontSize}px ${fontStack(op.font)}`;
         ctx.textBaseline = "top";
         // black shadow for legibility
         ctx.shadowColor = "rgba(0,0,0,0.85)";
@@ -405,6 +692,19 @@ export function Editor() {
       case "step":
         drawStep(ctx, op);
         break;
+      case "pen":
+        drawPen(ctx, op);
+        break;
+      case "measure":
+        drawMeasure(ctx, op);
+        break;
+      case "stamp":
+        ctx.save();
+        ctx.translate(op.center.x, op.center.y);
+        ctx.rotate(op.angle);
+        ctx.drawImage(stampImage(op.stamp), -op.size / 2, -op.size / 2, op.size, op.size);
+        ctx.restore();
+        break;
       case "line":
         ctx.strokeStyle = op.color;
         ctx.lineWidth = op.width;
@@ -443,6 +743,89 @@ export function Editor() {
     }
   }
 
+  // catmull-rom through the samples, drawn as one cubic per segment so each
+  // segment can take the width of its own pressure. a pen at full pressure
+  // (or a mouse) draws at the set stroke width, a light touch down to a fifth
+  function drawPen(ctx: CanvasRenderingContext2D, op: PenOp) {
+    const pts = op.points;
+    const widthAt = (p: number) => op.width * (0.2 + 0.8 * p);
+    ctx.save();
+    ctx.strokeStyle = op.color;
+    ctx.fillStyle = op.color;
+    ctx.lineCap = "round";
+    ctx.lineJoin = "round";
+    if (pts.length === 1) {
+      ctx.beginPath();
+      ctx.arc(pts[0].x, pts[0].y, widthAt(pts[0].p) / 2, 0, Math.PI * 2);
+      ctx.fill();
+    }
+    for (let i = 0; i < pts.length - 1; i++) {
+      const p0 = pts[Math.max(0, i - 1)];
+      const p1 = pts[i];
+      const p2 = pts[i + 1];
+      const p3 = pts[Math.min(pts.length - 1, i + 2)];
+      ctx.lineWidth = widthAt((p1.p + p2.p) / 2);
+      ctx.beginPath();
+      ctx.moveTo(p1.x, p1.y);
+      ctx.bezierCurveTo(
+        p1.x + (p2.x - p0.x) / 6,
+        p1.y + (p2.y - p0.y) / 6,
+        p2.x - (p3.x - p1.x) / 6,
+        p2.y - (p3.y - p1.y) / 6,
+        p2.x,
+        p2.y,
+      );
+      ctx.stroke();
+    }
+    ctx.restore();
+  }
+
+  function drawMeasure(ctx: CanvasRenderingContext2D, op: MeasureOp) {
+    const { from: a, to: b } = op;
+    const dx = b.x - a.x;
+    const dy = b.y - a.y;
+    const len = Math.hypot(dx, dy);
+    ctx.save();
+    // the spanned box, faint, so its sides can be read off against the ui
+    // being measured
+    if (Math.abs(dx) >= 1 && Math.abs(dy) >= 1) {
+      ctx.strokeStyle = op.color;
+      ctx.globalAlpha = 0.45;
+      ctx.lineWidth = 1;
+      ctx.setLineDash([4, 3]);
+      ctx.strokeRect(Math.min(a.x, b.x), Math.min(a.y, b.y), Math.abs(dx), Math.abs(dy));
+      ctx.setLineDash([]);
+      ctx.globalAlpha = 1;
+    }
+    ctx.strokeStyle = op.color;
+    ctx.lineWidth = 1.5;
+    ctx.beginPath();
+    ctx.moveTo(a.x, a.y);
+    ctx.lineTo(b.x, b.y);
+    // end ticks across the line
+    if (len >= 1) {
+      const nx = (-dy / len) * 6;
+      const ny = (dx / len) * 6;
+      for (const p of [a, b]) {
+        ctx.moveTo(p.x - nx, p.y - ny);
+        ctx.lineTo(p.x + nx, p.y + ny);
+      }
+    }
+    ctx.stroke();
+    const label = `${Math.round(len)} px · ${Math.round(Math.abs(dx))} × ${Math.round(Math.abs(dy))}`;
+    ctx.font = `12px "Fira Code", "Hack", ui-monospace, monospace`;
+    const w = ctx.measureText(label).width + 10;
+    const h = 18;
+    const mx = (a.x + b.x) / 2 - w / 2;
+    const my = (a.y + b.y) / 2 - h - 4;
+    ctx.fillStyle = "rgba(0,0,0,0.75)";
+    ctx.fillRect(mx, my, w, h);
+    ctx.fillStyle = "#ffffff";
+    ctx.textBaseline = "middle";
+    ctx.fillText(label, mx + 5, my + h / 2 + 1);
+    ctx.restore();
+  }
+
   function drawStep(ctx: CanvasRenderingContext2D, op: StepOp) {
     const r = op.radius;
     ctx.beginPath();
@@ -537,21 +920,30 @@ export function Editor() {
     }
   }
 
-  function onMouseDown(e: MouseEvent) {
+  // mice report a flat 0.5 while a button is down; only a pen's pressure means
+  // anything
+  const pressureOf = (e: PointerEvent) =>
+    e.pointerType === "pen" && e.pressure > 0 ? e.pressure : 1;
+
+  function onPointerDown(e: PointerEvent) {
     if (!loaded()) return;
     if (textInputAt()) return;
+    // left to the wrap, which pans
+    if (spaceHeld() || e.button !== 0) return;
     const p = pointFromEvent(e);
-    const t = tool();
+    const active = tool();
     dragStart = p;
-    if (t === "arrow") {
+    if (active === "arrow") {
       setDraft({ kind: "arrow", from: p, to: p, color: color(), width: strokeWidth() });
-    } else if (t === "rect") {
+    } else if (active === "rect") {
       setDraft({ kind: "rect", origin: p, size: { w: 0, h: 0 }, color: color(), width: strokeWidth() });
-    } else if (t === "blur") {
+    } else if (active === "blur") {
       setDraft({ kind: "blur", origin: p, size: { w: 0, h: 0 }, radius: 12 });
-    } else if (t === "line") {
+    } else if (active === "line") {
       setDraft({ kind: "line", from: p, to: p, color: color(), width: strokeWidth() });
-    } else if (t === "ellipse") {
+    } else if (active === "measure") {
+      setDraft({ kind: "measure", from: p, to: p, color: color() });
+    } else if (active === "ellipse") {
       setDraft({
         kind: "ellipse",
         origin: p,
@@ -559,7 +951,7 @@ export function Editor() {
         color: color(),
         width: strokeWidth(),
       });
-    } else if (t === "highlight") {
+    } else if (active === "highlight") {
       // 3x default stroke so the marker actually feels like a highlighter
       setDraft({
         kind: "highlight",
@@ -568,7 +960,30 @@ export function Editor() {
         color: color(),
         width: Math.max(8, strokeWidth() * 4),
       });
-    } else if (t === "text") {
+    } else if (active === "pen") {
+      // keep the stroke when the pen or mouse wanders off the canvas
+      canvasRef.setPointerCapture(e.pointerId);
+      setDraft({
+        kind: "pen",
+        points: [{ ...p, p: pressureOf(e) }],
+        color: color(),
+        width: strokeWidth(),
+      });
+    } else if (active === "stamp") {
+      // a click drops it at the set size; a drag from the click scales and
+      // turns it
+      setDraft({ kind: "stamp", center: p, stamp: stamp(), size: stampSize(), angle: 0 });
+      redraw();
+    } else if (active === "text") {
+      const index = textAt(p);
+      if (index !== null) {
+        const op = ops()[index] as TextOp;
+        canvasRef.setPointerCapture(e.pointerId);
+        moving = { op, grab: { x: p.x - op.origin.x, y: p.y - op.origin.y } };
+        setDraft(op);
+        redraw();
+        return;
+      }
       setTextInputAt(p);
       setTextBuffer("");
       // focus the input after solid renders it
@@ -576,7 +991,7 @@ export function Editor() {
         const el = document.getElementById("editor-text-input") as HTMLInputElement | null;
         el?.focus();
       }, 0);
-    } else if (t === "step") {
+    } else if (active === "step") {
       // click-to-drop, not drag. Auto-increment from the highest existing
       // step number so undo/redo stays consistent.
       const next = nextStepNumber();
@@ -587,13 +1002,33 @@ export function Editor() {
         color: color(),
         radius: stepRadius(),
       };
-      setOps([...ops(), op]);
+      pushOp(op);
       setRedoStack([]);
       dragStart = null;
       redraw();
     }
   }
 
+  // topmost visible text box under `p`, as an index into ops()
+  function textAt(p: Point): number | null {
+    const ctx = canvasRef.getContext("2d");
+    if (!ctx) return null;
+    const visible = new Set(layers().filter((l) => l.visible).map((l) => l.id));
+    const all = ops();
+    for (let i = all.length - 1; i >= 0; i--) {
+      const op = all[i];
+      if (op.kind !== "text" || !visible.has(op.layer!)) continue;
+      ctx.font = `${op.fontSize}px ${fontStack(op.font)}`;
+      const w = ctx.measureText(op.text).width;
+      // drawn with textBaseline top, so the box runs about a line down
+      const h = op.fontSize * 1.2;
+      if (p.x >= op.origin.x && p.x <= op.origin.x + w && p.y >= op.origin.y && p.y <= op.origin.y + h) {
+        return i;
+      }
+    }
+    return null;
+  }
+
   function nextStepNumber(): number {
     let max = 0;
     for (const op of ops()) {
@@ -602,12 +1037,50 @@ export function Editor() {
     return max + 1;
   }
 
-  function onMouseMove(e: MouseEvent) {
-    if (!dragStart) return;
+  function onPointerMove(e: PointerEvent) {
+    if (!dragStart) {
+      if (tool() === "text" && loaded() && !textInputAt()) {
+        setOverText(textAt(pointFromEvent(e)) !== null);
+      }
+      return;
+    }
     const p = pointFromEvent(e);
     const d = draft();
     if (!d) return;
-    if (d.kind === "arrow" || d.kind === "line" || d.kind === "highlight") {
+    if (d.kind === "stamp") {
+      const dx = p.x - d.center.x;
+      const dy = p.y - d.center.y;
+      if (Math.hypot(dx, dy) >= 4) {
+        // shift keeps it upright while it grows
+        const angle = e.shiftKey ? 0 : Math.atan2(dy, dx);
+        setDraft({ ...d, size: Math.max(16, Math.hypot(dx, dy) * 2), angle });
+      }
+    } else if (moving && d.kind === "text") {
+      setDraft({ ...d, origin: { x: p.x - moving.grab.x, y: p.y - moving.grab.y } });
+    } else if (d.kind === "pen") {
+      // a tablet reports far more samples than animation frames; the
+      // coalesced list keeps the in-between ones
+      const samples = e.getCoalescedEvents?.() ?? [];
+      const points = [...d.points];
+      for (const ev of samples.length > 0 ? samples : [e]) {
+        const q = pointFromEvent(ev);
+        const last = points[points.length - 1];
+        // sub-pixel jitter only adds kinks for the spline to follow
+        if (Math.hypot(q.x - last.x, q.y - last.y) < 1.5) continue;
+        points.push({ ...q, p: pressureOf(ev) });
+      }
+      setDraft({ ...d, points });
+    } else if (d.kind === "measure") {
+      // shift pins it to a horizontal or vertical run, the usual thing to
+      // measure in a ui
+      const to =
+        e.shiftKey && Math.abs(p.x - d.from.x) >= Math.abs(p.y - d.from.y)
+          ? { x: p.x, y: d.from.y }
+          : e.shiftKey
+            ? { x: d.from.x, y: p.y }
+            : p;
+      setDraft({ ...d, to });
+    } else if (d.kind === "arrow" || d.kind === "line" || d.kind === "highlight") {
       setDraft({ ...d, to: p });
     } else if (d.kind === "rect" || d.kind === "blur" || d.kind === "ellipse") {
       const ox = Math.min(dragStart.x, p.x);
@@ -619,12 +1092,18 @@ export function Editor() {
     redraw();
   }
 
-  function onMouseUp() {
+  function onPointerUp() {
     const d = draft();
     dragStart = null;
+    if (moving) {
+      const original = moving.op;
+      moving = null;
+      finishMove(original, d as TextOp);
+      return;
+    }
     if (!d) return;
     // discard zero-size drafts
-    if (d.kind === "arrow" || d.kind === "line" || d.kind === "highlight") {
+    if (d.kind === "arrow" || d.kind === "line" || d.kind === "highlight" || d.kind === "measure") {
       const dx = d.to.x - d.from.x;
       const dy = d.to.y - d.from.y;
       if (Math.hypot(dx, dy) < 3) {
@@ -640,20 +1119,41 @@ export function Editor() {
         return;
       }
     }
-    setOps([...ops(), d]);
+    pushOp(d);
     setRedoStack([]);
     setDraft(null);
     redraw();
   }
 
+  // the dragged text goes to the top of its layer; a click without a drag
+  // leaves everything where it was
+  function finishMove(original: TextOp, moved: TextOp) {
+    setDraft(null);
+    const cur = ops();
+    const index = cur.indexOf(original);
+    if (index < 0 || (original.origin.x === moved.origin.x && original.origin.y === moved.origin.y)) {
+      redraw();
+      return;
+    }
+    const next: Op = { ...moved };
+    movedFrom.set(next, { op: original, index });
+    setOps([...cur.slice(0, index), ...cur.slice(index + 1), next]);
+    setRedoStack([]);
+    redraw();
+  }
+
   function commitText() {
     const at = textInputAt();
-    const t = textBuffer().trim();
-    if (at && t.length > 0) {
-      setOps([
-        ...ops(),
-        { kind: "text", origin: at, text: t, color: color(), fontSize: textSize() },
-      ]);
+    const text = textBuffer().trim();
+    if (at && text.length > 0) {
+      pushOp({
+        kind: "text",
+        origin: at,
+        text,
+        color: color(),
+        fontSize: textSize(),
+        font: textFont(),
+      });
       setRedoStack([]);
     }
     setTextInputAt(null);
@@ -666,11 +1166,60 @@ export function Editor() {
     setTextBuffer("");
   }
 
+  function pushOp(op: Op) {
+    const id = activeLayer();
+    // drawing on a hidden layer would look like nothing happened
+    setLayers(layers().map((l) => (l.id === id ? { ...l, visible: true } : l)));
+    setOps([...ops(), { ...op, layer: id }]);
+  }
+
+  function resetLayers(next: Layer[] = [firstLayer()], background = true) {
+    setLayers(next);
+    setActiveLayer(next[next.length - 1].id);
+    setBackgroundVisible(background);
+  }
+
+  function addLayer() {
+    const id = Math.max(0, ...layers().map((l) => l.id)) + 1;
+    setLayers([...layers(), { id, name: t("editor.layer.name", { n: id }), visible: true }]);
+    setActiveLayer(id);
+  }
+
+  function toggleLayer(id: number) {
+    setLayers(layers().map((l) => (l.id === id ? { ...l, visible: !l.visible } : l)));
+    redraw();
+  }
+
+  function deleteLayer(layer: Layer) {
+    // always keep one to draw on
+    if (layers().length === 1) return;
+    const count = ops().filter((op) => op.layer === layer.id).length;
+    if (
+      count > 0 &&
+      !window.confirm(
+        t(count === 1 ? "editor.layer.delete.confirm.one" : "editor.layer.delete.confirm.other", {
+          name: layer.name,
+          count,
+        }),
+      )
+    ) {
+      return;
+    }
+    setOps(ops().filter((op) => op.layer !== layer.id));
+    setRedoStack(redoStack().filter((op) => op.layer !== layer.id));
+    const rest = layers().filter((l) => l.id !== layer.id);
+    setLayers(rest);
+    if (activeLayer() === layer.id) setActiveLayer(rest[rest.length - 1].id);
+    redraw();
+  }
+
   function undo() {
     const cur = ops();
     if (cur.length === 0) return;
     const last = cur[cur.length - 1];
-    setOps(cur.slice(0, -1));
+    const rest = cur.slice(0, -1);
+    const from = movedFrom.get(last);
+    setOps(from ? [...rest.slice(0, from.index), from.op, ...rest.slice(from.index)] : rest);
     setRedoStack([...redoStack(), last]);
     redraw();
   }
@@ -680,7 +1229,8 @@ export function Editor() {
     if (stack.length === 0) return;
     const next = stack[stack.length - 1];
     setRedoStack(stack.slice(0, -1));
-    setOps([...ops(), next]);
+    const from = movedFrom.get(next);
+    setOps([...(from ? ops().filter((op) => op !== from.op) : ops()), next]);
     redraw();
   }
 
@@ -711,11 +1261,74 @@ export function Editor() {
     return new Uint8Array(buf);
   }
 
+  // the project beside `path`, if there is one that still belongs to `img`
+  async function readProject(
+    path: string,
+    img: HTMLImageElement,
+  ): Promise<{
+    original: HTMLImageElement;
+    ops: Op[];
+    layers?: Layer[];
+    background?: boolean;
+  } | null> {
+    try {
+      const text = await api.loadEditorProject(path);
+      if (!text) return null;
+      const project = JSON.parse(text) as EditorProject;
+      if (
+        project.version !== 1 ||
+        project.width !== img.naturalWidth ||
+        project.height !== img.naturalHeight ||
+        !Array.isArray(project.ops)
+      ) {
+        return null;
+      }
+      const original = new Image();
+      original.src = project.original;
+      await original.decode();
+      return {
+        original,
+        ops: project.ops,
+        layers: Array.isArray(project.layers) && project.layers.length > 0 ? project.layers : undefined,
+        background: project.background,
+      };
+    } catch {
+      // unreadable or stale project: edit the flattened file as before
+      return null;
+    }
+  }
+
+  // the unedited pixels as a png data url, for the project file
+  function originalDataUrl(): string {
+    const c = document.createElement("canvas");
+    c.width = baseImage!.naturalWidth;
+    c.height = baseImage!.naturalHeight;
+    c.getContext("2d")!.drawImage(baseImage!, 0, 0);
+    return c.toDataURL("image/png");
+  }
+
+  async function writeProject(path: string) {
+    // no annotations left means the flattened file is the original again
+    const project: EditorProject | null =
+      editorProjects() && ops().length > 0
+        ? {
+            version: 1,
+            width: canvasRef.width,
+            height: canvasRef.height,
+            original: originalDataUrl(),
+            ops: ops(),
+            layers: layers(),
+            background: backgroundVisible(),
+          }
+        : null;
+    await api.saveEditorProject(path, project ? JSON.stringify(project) : null);
+  }
+
   async function onSave() {
     const path = imagePath();
     if (!path) return;
     setBusy("save");
-    setStatus({ tone: "", msg: "writing..." });
+    setStatus({ tone: "", msg: t("common.writing") });
     try {
       // encode in the source file's format so we don't write PNG bytes to a
       // .jpg path (which would silently corrupt the file for downstream
@@ -725,12 +1338,36 @@ export function Editor() {
         bytes: Array.from(bytes),
         targetPath: path,
       });
+      try {
+        await writeProject(path);
+      } catch (e) {
+        // the image itself is saved; only re-editing is lost
+        setStatus({ tone: "err", msg: t("editor.status.project-failed", { error: String(e) }) });
+        return;
+      }
       setOps([]);
+      setSavedOps([]);
       setHasPastedContent(false);
-      setStatus({ tone: "ok", msg: "saved." });
+      setStatus({ tone: "ok", msg: t("common.saved") });
       setTimeout(() => void win.close(), 400);
     } catch (e) {
-      setStatus({ tone: "err", msg: `save failed: ${e}` });
+      setStatus({ tone: "err", msg: t("editor.status.save-failed", { error: String(e) }) });
+    } finally {
+      setBusy(null);
+    }
+  }
+
+  // a copy anywhere, in any format; the capture being edited stays as it was
+  // and the editor stays open
+  async function onSaveAs() {
+    setBusy("save");
+    try {
+      // the backend re-encodes to whatever extension is picked
+      const bytes = await exportBytes("image/png");
+      const saved = await api.saveEditedImageAs(bytes);
+      if (saved) setStatus({ tone: "ok", msg: t("editor.status.saved-copy", { path: saved }) });
+    } catch (e) {
+      setStatus({ tone: "err", msg: t("editor.status.save-as-failed", { error: String(e) }) });
     } finally {
       setBusy(null);
     }
@@ -738,7 +1375,7 @@ export function Editor() {
 
   async function onCopy() {
     setBusy("copy");
-    setStatus({ tone: "", msg: "copying..." });
+    setStatus({ tone: "", msg: t("editor.status.copying") });
     try {
       // clipboard always wants PNG — that's what the Rust ClipboardManager
       // expects to decode.
@@ -746,9 +1383,9 @@ export function Editor() {
       await invoke<void>("copy_edited_image_to_clipboard", {
         bytes: Array.from(bytes),
       });
-      setStatus({ tone: "ok", msg: "copied to clipboard." });
+      setStatus({ tone: "ok", msg: t("editor.status.copied") });
     } catch (e) {
-      setStatus({ tone: "err", msg: `copy failed: ${e}` });
+      setStatus({ tone: "err", msg: t("editor.status.copy-failed", { error: String(e) }) });
     } finally {
       setBusy(null);
     }
@@ -756,18 +1393,18 @@ export function Editor() {
 
   async function onUpload() {
     setBusy("upload");
-    setStatus({ tone: "", msg: "uploading..." });
+    setStatus({ tone: "", msg: t("editor.status.uploading") });
     try {
       // upload path also expects PNG — the existing ImageUploader.upload
       // re-encodes, so we feed it canonical bytes.
       const bytes = await exportBytes("image/png");
       const result = await invoke<{ url: string; delete_url: string | null }>(
         "upload_edited_image",
-        { bytes: Array.from(bytes) },
+        { bytes: Array.from(bytes), preset: uploadPreset() || null },
       );
-      setStatus({ tone: "ok", msg: result.url });
+      setStatus({ tone: "ok", msg: result.url || t("app.status.mail") });
     } catch (e) {
-      setStatus({ tone: "err", msg: `upload failed: ${e}` });
+      setStatus({ tone: "err", msg: t("editor.status.upload-failed", { error: String(e) }) });
     } finally {
       setBusy(null);
     }
@@ -778,13 +1415,19 @@ export function Editor() {
       <Titlebar context="edit" />
 
       <div class="editor-toolbar">
-        <div class="editor-tools">
+        <div
+          class="editor-tools"
+          role="toolbar"
+          aria-label={t("editor.tools")}
+          onKeyDown={(ev) => arrowNav(ev, "horizontal", true)}
+        >
           <button
             type="button"
             class="tool"
             classList={{ "is-active": tool() === "arrow" }}
+            aria-pressed={tool() === "arrow"}
             onClick={() => setTool("arrow")}
-            title="arrow (1)"
+            title={t("editor.tool.arrow")}
           >
             <ArrowRight size={14} stroke-width={1.5} />
           </button>
@@ -792,8 +1435,9 @@ export function Editor() {
             type="button"
             class="tool"
             classList={{ "is-active": tool() === "rect" }}
+            aria-pressed={tool() === "rect"}
             onClick={() => setTool("rect")}
-            title="rectangle (2)"
+            title={t("editor.tool.rect")}
           >
             <Square size={14} stroke-width={1.5} />
           </button>
@@ -801,8 +1445,9 @@ export function Editor() {
             type="button"
             class="tool"
             classList={{ "is-active": tool() === "text" }}
+            aria-pressed={tool() === "text"}
             onClick={() => setTool("text")}
-            title="text (3)"
+            title={t("editor.tool.text")}
           >
             <Type size={14} stroke-width={1.5} />
           </button>
@@ -810,8 +1455,9 @@ export function Editor() {
             type="button"
             class="tool"
             classList={{ "is-active": tool() === "blur" }}
+            aria-pressed={tool() === "blur"}
             onClick={() => setTool("blur")}
-            title="pixelate (4)"
+            title={t("editor.tool.blur")}
           >
             <Droplet size={14} stroke-width={1.5} />
           </button>
@@ -819,8 +1465,9 @@ export function Editor() {
             type="button"
             class="tool"
             classList={{ "is-active": tool() === "step" }}
+            aria-pressed={tool() === "step"}
             onClick={() => setTool("step")}
-            title="numbered step (5)"
+            title={t("editor.tool.step")}
           >
             <Circle size={14} stroke-width={1.5} />
           </button>
@@ -828,8 +1475,9 @@ export function Editor() {
             type="button"
             class="tool"
             classList={{ "is-active": tool() === "line" }}
+            aria-pressed={tool() === "line"}
             onClick={() => setTool("line")}
-            title="line (6)"
+            title={t("editor.tool.line")}
           >
             <Minus size={14} stroke-width={1.5} />
           </button>
@@ -837,8 +1485,9 @@ export function Editor() {
             type="button"
             class="tool"
             classList={{ "is-active": tool() === "ellipse" }}
+            aria-pressed={tool() === "ellipse"}
             onClick={() => setTool("ellipse")}
-            title="ellipse (7)"
+            title={t("editor.tool.ellipse")}
           >
             <CircleDashed size={14} stroke-width={1.5} />
           </button>
@@ -846,22 +1495,66 @@ export function Editor() {
             type="button"
             class="tool"
             classList={{ "is-active": tool() === "highlight" }}
+            aria-pressed={tool() === "highlight"}
             onClick={() => setTool("highlight")}
-            title="highlighter (8)"
+            title={t("editor.tool.highlight")}
           >
             <Highlighter size={14} stroke-width={1.5} />
           </button>
+          <button
+            type="button"
+            class="tool"
+            classList={{ "is-active": tool() === "pen" }}
+            aria-pressed={tool() === "pen"}
+            onClick={() => setTool("pen")}
+            title={t("editor.tool.pen")}
+          >
+            <Pencil size={14} stroke-width={1.5} />
+          </button>
+          <button
+            type="button"
+            class="tool"
+            classList={{ "is-active": tool() === "stamp" }}
+            aria-pressed={tool() === "stamp"}
+            onClick={() => setTool("stamp")}
+            title={t("editor.tool.stamp")}
+          >
+            <Sticker size={14} stroke-width={1.5} />
+          </button>
+          <button
+            type="button"
+            class="tool"
+            classList={{ "is-active": tool() === "measure" }}
+            aria-pressed={tool() === "measure"}
+            onClick={() => setTool("measure")}
+            title={t("editor.tool.measure")}
+          >
+            <Ruler size={14} stroke-width={1.5} />
+          </button>
         </div>
 
-        <div class="editor-colors">
-          <For each={COLORS}>
+        <div
+          class="editor-colors"
+          role="toolbar"
+          aria-label={t("editor.colors")}
+          onKeyDown={(ev) => arrowNav(ev, "horizontal", true)}
+        >
+          <ColorPicker
+            value={color()}
+            onInput={setColor}
+            onPick={pickColor}
+            presets={COLORS}
+            recent={recentColors()}
+          />
+          <For each={recentColors().length > 0 ? recentColors().slice(0, 6) : COLORS}>
             {(c) => (
               <button
                 type="button"
                 class="swatch"
                 classList={{ "is-active": color() === c }}
+                aria-pressed={color() === c}
                 style={{ background: c }}
-                onClick={() => setColor(c)}
+                onClick={() => pickColor(c)}
                 aria-label={c}
               />
             )}
@@ -875,11 +1568,12 @@ export function Editor() {
               tool() === "rect" ||
               tool() === "line" ||
               tool() === "ellipse" ||
-              tool() === "highlight"
+              tool() === "highlight" ||
+              tool() === "pen"
             }
           >
             <label class="ctrl">
-              <span>stroke</span>
+              <span>{t("editor.stroke")}</span>
               <input
                 type="range"
                 min={1}
@@ -892,7 +1586,7 @@ export function Editor() {
           </Show>
           <Show when={tool() === "text"}>
             <label class="ctrl">
-              <span>size</span>
+              <span>{t("editor.size")}</span>
               <input
                 type="range"
                 min={10}
@@ -902,10 +1596,19 @@ export function Editor() {
               />
               <span class="ctrl-val">{textSize()}</span>
             </label>
+            <label class="ctrl">
+              <span>{t("editor.font")}</span>
+              <select
+                value={textFont()}
+                onChange={(e) => setTextFont(e.currentTarget.value as TextFont)}
+              >
+                <For each={TEXT_FONTS}>{(f) => <option value={f.id}>{t(f.label)}</option>}</For>
+              </select>
+            </label>
           </Show>
           <Show when={tool() === "step"}>
             <label class="ctrl">
-              <span>size</span>
+              <span>{t("editor.size")}</span>
               <input
                 type="range"
                 min={8}
@@ -916,6 +1619,107 @@ export function Editor() {
               <span class="ctrl-val">{stepRadius()}</span>
             </label>
           </Show>
+          <Show when={tool() === "stamp"}>
+            <div
+              class="editor-stamps"
+              role="toolbar"
+              aria-label={t("editor.stamps")}
+              onKeyDown={(ev) => arrowNav(ev, "horizontal", true)}
+            >
+              <For each={STAMP_IDS}>
+                {(id) => (
+                  <button
+                    type="button"
+                    class="stamp-pick"
+                    classList={{ "is-active": stamp() === id }}
+                    aria-pressed={stamp() === id}
+                    aria-label={stampLabel(id)}
+                    title={stampLabel(id)}
+                    onClick={() => setStamp(id)}
+                  >
+                    <img src={stampUrl(id)} alt="" />
+                  </button>
+                )}
+              </For>
+            </div>
+            <label class="ctrl">
+              <span>{t("editor.size")}</span>
+              <input
+                type="range"
+                min={16}
+                max={160}
+                value={stampSize()}
+                onInput={(e) => setStampSize(parseInt(e.currentTarget.value))}
+              />
+              <span class="ctrl-val">{stampSize()}</span>
+            </label>
+          </Show>
+        </div>
+
+        <div class="editor-layers" role="group" aria-label={t("editor.layers")}>
+          <button
+            type="button"
+            class="layer-chip"
+            classList={{ "is-hidden": !backgroundVisible() }}
+            aria-pressed={backgroundVisible()}
+            title={t("editor.image.title")}
+            onClick={() => {
+              setBackgroundVisible(!backgroundVisible());
+              redraw();
+            }}
+          >
+            {backgroundVisible() ? <Eye size={11} stroke-width={1.5} /> : <EyeOff size={11} stroke-width={1.5} />}
+            {t("editor.image")}
+          </button>
+          <For each={layers()}>
+            {(layer) => (
+              <span
+                class="layer-chip"
+                classList={{ "is-active": activeLayer() === layer.id, "is-hidden": !layer.visible }}
+              >
+                <button
+                  type="button"
+                  class="layer-btn"
+                  aria-label={t(layer.visible ? "editor.layer.hide" : "editor.layer.show", { name: layer.name })}
+                  title={t(layer.visible ? "editor.hide" : "editor.show")}
+                  onClick={() => toggleLayer(layer.id)}
+                >
+                  {layer.visible ? <Eye size={11} stroke-width={1.5} /> : <EyeOff size={11} stroke-width={1.5} />}
+                </button>
+                <button
+                  type="button"
+                  class="layer-btn layer-name"
+                  aria-pressed={activeLayer() === layer.id}
+                  title={t("editor.layer.draw")}
+                  onClick={() => setActiveLayer(layer.id)}
+                >
+                  {layer.name}
+                </button>
+                <Show when={layers().length > 1}>
+                  <button
+                    type="button"
+                    class="layer-btn"
+                    aria-label={t("editor.layer.delete", { name: layer.name })}
+                    title={t("editor.layer.delete.title")}
+                    onClick={() => deleteLayer(layer)}
+                  >
+                    <X size={10} stroke-width={1.5} />
+                  </button>
+                </Show>
+              </span>
+            )}
+          </For>
+          <button
+            type="button"
+            class="btn"
+            data-variant="ghost"
+            data-size="xs"
+            title={t("editor.layer.new.title")}
+            onClick={addLayer}
+          >
+            <Plus size={11} stroke-width={1.5} />
+            {t("editor.layer.new")}
+          </button>
         </div>
 
         <div class="editor-actions">
@@ -927,7 +1731,7 @@ export function Editor() {
             disabled={ops().length === 0}
           >
             <RotateCcw size={12} stroke-width={1.5} />
-            undo
+            {t("editor.undo")}
           </button>
           <button
             class="btn"
@@ -937,7 +1741,7 @@ export function Editor() {
             disabled={redoStack().length === 0}
           >
             <RotateCw size={12} stroke-width={1.5} />
-            redo
+            {t("editor.redo")}
           </button>
           <span class="editor-zoom-group">
             <button
@@ -954,12 +1758,21 @@ export function Editor() {
               class="btn"
               data-variant="ghost"
               data-size="xs"
-              onClick={() => setZoom(1.0)}
-              title="ctrl+0"
+              onClick={() => zoomTo(1.0)}
+              title={t("editor.zoom.actual")}
             >
-              <Maximize2 size={11} stroke-width={1.5} />
               {Math.round(zoom() * 100)}%
             </button>
+            <button
+              class="btn"
+              data-variant="ghost"
+              data-size="xs"
+              onClick={fitToWindow}
+              title={t("editor.zoom.fit.title")}
+              aria-label={t("editor.zoom.fit")}
+            >
+              <Maximize2 size={11} stroke-width={1.5} />
+            </button>
             <button
               class="btn"
               data-variant="ghost"
@@ -973,19 +1786,50 @@ export function Editor() {
           </span>
           <button class="btn" onClick={onSave} disabled={busy() !== null || !loaded()}>
             <Save size={12} stroke-width={1.5} />
-            save
+            {t("common.save")}
+          </button>
+          <button
+            class="btn"
+            data-variant="ghost"
+            onClick={onSaveAs}
+            disabled={busy() !== null || !loaded()}
+            title="ctrl+shift+s"
+          >
+            {t("editor.save-as")}
           </button>
-          <button class="btn" data-variant="ghost" onClick={onCopy} disabled={busy() !== null || !loaded()}>
+          <button class="btn" data-variant="ghost" onClick={onCopy} disabled={busy() !== null || !loaded()} title="ctrl+c">
             <Copy size={12} stroke-width={1.5} />
-            copy
+            {t("common.copy")}
           </button>
-          <button class="btn" data-variant="ghost" onClick={onUpload} disabled={busy() !== null || !loaded()}>
+          <button
+            class="btn"
+            data-variant="ghost"
+            onClick={() => setTagging(true)}
+            disabled={!imagePath()}
+            title={t("editor.tags.title")}
+          >
+            <Tag size={12} stroke-width={1.5} />
+            {t("tags.tags")}
+          </button>
+          <Show when={presets().length > 0}>
+            <select
+              title={t("editor.destination")}
+              value={uploadPreset()}
+              onChange={(e) => setUploadPreset(e.currentTarget.value)}
+            >
+              <option value="">{t("editor.destination.default")}</option>
+              <For each={presets()}>
+                {(p) => <option value={p.id}>{p.name || p.id}</option>}
+              </For>
+            </select>
+          </Show>
+          <button class="btn" data-variant="ghost" onClick={onUpload} disabled={busy() !== null || !loaded()} title="ctrl+u">
             <Upload size={12} stroke-width={1.5} />
-            upload
+            {t("editor.upload")}
           </button>
           <button class="btn" data-variant="ghost" onClick={() => void win.close()}>
             <X size={12} stroke-width={1.5} />
-            close
+            {t("common.close")}
           </button>
         </div>
       </div>
@@ -994,51 +1838,60 @@ export function Editor() {
         <div class="editor-hdr-banner" role="status">
           <span class="editor-hdr-glyph">▮</span>
           <span class="editor-hdr-text">
-            <strong>HDR capture detected.</strong>
+            <strong>{t("editor.hdr")}</strong>
             <Show
               when={hdrSidecarPath()}
               fallback={
                 <span>
-                  {" "}canvas annotates in SDR; saving from here will flatten the HDR file
-                  to SDR. close the editor without saving to keep the original HDR.
+                  {" "}
+                  {t("editor.hdr.flatten")}
                 </span>
               }
             >
               <span>
-                {" "}capscr kept the HDR original as <code>{hdrSidecarPath()!.split(/[\\/]/).pop()}</code>{" "}
-                — annotations save into the SDR copy; the HDR sidecar is untouched.
+                {" "}
+                {tm("editor.hdr.sidecar", {
+                  file: <code>{hdrSidecarPath()!.split(/[\\/]/).pop()}</code>,
+                })}
               </span>
             </Show>
           </span>
         </div>
       </Show>
 
-      <div class="editor-canvas-wrap" onWheel={onWheelZoom}>
+      <div
+        ref={wrapRef}
+        class="editor-canvas-wrap"
+        data-pan={panning() ? "grabbing" : spaceHeld() ? "grab" : undefined}
+        onWheel={onWheelZoom}
+        onMouseDown={onPanStart}
+      >
         <Show
           when={loaded()}
           fallback={
             <div class="editor-loading">
-              <span class="lede">loading capture...</span>
+              <span class="lede">{t("editor.loading")}</span>
             </div>
           }
         >
           <div
             class="editor-canvas-scroll"
-            style={{ width: `${canvasRef ? canvasRef.width * zoom() : 0}px` }}
+            style={{ width: `${imageSize().w * zoom()}px` }}
           >
             <canvas
               ref={canvasRef!}
               class="editor-canvas"
               data-tool={tool()}
+              data-over-text={tool() === "text" && overText() ? "" : undefined}
               style={{
-                width: `${canvasRef ? canvasRef.width * zoom() : 0}px`,
-                height: `${canvasRef ? canvasRef.height * zoom() : 0}px`,
+                width: `${imageSize().w * zoom()}px`,
+                height: `${imageSize().h * zoom()}px`,
                 "max-width": "none",
               }}
-              onMouseDown={onMouseDown}
-              onMouseMove={onMouseMove}
-              onMouseUp={onMouseUp}
-              onMouseLeave={onMouseUp}
+              onPointerDown={onPointerDown}
+              onPointerMove={onPointerMove}
+              onPointerUp={onPointerUp}
+              onPointerLeave={onPointerUp}
             />
             <Show when={textInputAt()}>
               <div
@@ -1058,6 +1911,7 @@ export function Editor() {
                   id="editor-text-input"
                   type="text"
                   class="editor-text-input"
+                  style={{ "font-family": fontStack(textFont()) }}
                   value={textBuffer()}
                   onInput={(e) => setTextBuffer(e.currentTarget.value)}
                   onKeyDown={(e) => {
@@ -1070,7 +1924,7 @@ export function Editor() {
                     }
                   }}
                   onBlur={commitText}
-                  placeholder="type, enter to commit"
+                  placeholder={t("editor.text.placeholder")}
                 />
               </div>
             </Show>
@@ -1085,12 +1939,40 @@ export function Editor() {
           </span>
         </Show>
         <span class="grow" />
+        <Show when={loaded() && sizeEstimates().length > 0}>
+          <span class="editor-sizes" title={t("editor.sizes")}>
+            <For each={sizeEstimates()}>
+              {(est) => (
+                <span classList={{ "is-current": est.format === outputFormat() }}>
+                  {est.extension} ~{formatBytes(est.bytes)}
+                </span>
+              )}
+            </For>
+          </span>
+        </Show>
         <Show when={loaded() && baseImage}>
           <span class="muted">
             {baseImage!.naturalWidth} × {baseImage!.naturalHeight}
           </span>
         </Show>
       </footer>
+
+      <Show when={tagging() && imagePath()}>
+        <TagsModal
+          path={imagePath()!}
+          onClose={() => setTagging(false)}
+          onSaved={() => {
+            setTagging(false);
+            setStatus({ tone: "ok", msg: t("editor.status.tags-saved") });
+          }}
+        />
+      </Show>
     </div>
   );
 }
+
+function formatBytes(b: number): string {
+  if (b < 1024) return `${b} B`;
+  if (b < 1024 * 1024) return `${(b / 1024).toFixed(1)} KB`;
+  return `${(b / 1024 / 1024).toFixed(2)} MB`;
+}
@@ -21,13 +21,31 @@ import {
   X,
   Type,
   Pin,
+  CheckSquare,
+  FolderOpen,
+  Images,
+  Columns,
+  Layers,
+  Tag,
 } from "lucide-solid";
-import { api } from "../api";
+import { api, BatchConvertProgress, BatchUploadRow, HistoryEntry } from "../api";
 import { hdrSupported } from "../hdrSupport";
 import { TrimModal } from "../components/TrimModal";
+import { CompareModal } from "../components/CompareModal";
+import { TagsModal } from "../components/TagsModal";
+import { activateOnKey } from "../a11y";
+import { MessageKey, t, tm } from "../i18n";
 
 type FilterKind = "all" | "images" | "gifs" | "videos" | "hdr";
 
+const FILTER_LABELS: Record<FilterKind, MessageKey> = {
+  all: "history.filter.all",
+  images: "history.filter.images",
+  gifs: "history.filter.gifs",
+  videos: "history.filter.videos",
+  hdr: "history.filter.hdr",
+};
+
 // the hdr pill only shows where captures can produce hdr files
 const FILTER_PILLS = () =>
   (["all", "images", "gifs", "videos", "hdr"] as FilterKind[]).filter(
@@ -77,7 +95,7 @@ function formatDate(unix: number): string {
 export function History() {
   const [entries, { refetch }] = createResource(api.listCaptures);
   const [outputDir, setOutputDir] = createSignal<string>("");
-  const [screenshotHotkey, setScreenshotHotkey] = createSignal<string>("your screenshot key");
+  const [screenshotHotkey, setScreenshotHotkey] = createSignal<string | null>(null);
   const [recordGifHotkey, setRecordGifHotkey] = createSignal<string>("Ctrl+Shift+G");
   onMount(() => {
     api.getConfig().then((c) => {
@@ -85,11 +103,11 @@ export function History() {
       // read the live bindings from the tasks, not the legacy hotkeys.* fields
       // that nothing registers, so the hint shows the key that actually fires
       const shot =
-        c.capture_tasks?.find((t) => t.id === "screenshot-save-clipboard") ??
-        c.capture_tasks?.find((t) => t.capture_mode === "region" && t.hotkey);
+        c.capture_tasks?.find((task) => task.id === "screenshot-save-clipboard") ??
+        c.capture_tasks?.find((task) => task.capture_mode === "region" && task.hotkey);
       const gif =
-        c.capture_tasks?.find((t) => t.id === "gif-save") ??
-        c.capture_tasks?.find((t) => t.capture_mode === "region-gif" && t.hotkey);
+        c.capture_tasks?.find((task) => task.id === "gif-save") ??
+        c.capture_tasks?.find((task) => task.capture_mode === "region-gif" && task.hotkey);
       if (shot?.hotkey) setScreenshotHotkey(shot.hotkey);
       if (gif?.hotkey) setRecordGifHotkey(gif.hotkey);
     }).catch(() => {});
@@ -101,36 +119,56 @@ export function History() {
   const [filter, setFilter] = createSignal<FilterKind>("all");
   // path of the mp4 currently open in the trim modal, or null
   const [trimPath, setTrimPath] = createSignal<string | null>(null);
+  // the two selected captures open in the compare modal, or null
+  const [comparing, setComparing] = createSignal<[string, string] | null>(null);
+  // path of the capture whose tags and note are being edited, or null
+  const [taggingPath, setTaggingPath] = createSignal<string | null>(null);
 
   // live-refresh the grid when a new capture lands so the user doesn't
   // have to click "reload" after every screenshot. Coalesce rapid bursts
   // (e.g. a GIF + sidecar landing back-to-back) into one refetch.
   let refreshTimer: ReturnType<typeof setTimeout> | null = null;
   let unlisten: UnlistenFn | null = null;
+  let unlistenTags: UnlistenFn | null = null;
   onMount(async () => {
-    unlisten = await listen("capscr://capture-saved", () => {
+    const scheduleRefresh = () => {
       if (refreshTimer) clearTimeout(refreshTimer);
       refreshTimer = setTimeout(() => {
         refetch();
         refreshTimer = null;
       }, 250);
-    });
+    };
+    unlisten = await listen("capscr://capture-saved", scheduleRefresh);
+    // tags set from the editor show up here too
+    unlistenTags = await listen("capscr://tags-changed", scheduleRefresh);
   });
   onCleanup(() => {
     if (refreshTimer) clearTimeout(refreshTimer);
     unlisten?.();
+    unlistenTags?.();
   });
 
+  // every word has to match: "#tag" a tag exactly, anything else part of the
+  // filename, a tag or the note
+  const matchesSearch = (e: HistoryEntry, words: string[]) =>
+    words.every((w) =>
+      w.startsWith("#") && w.length > 1
+        ? e.tags.includes(w.slice(1))
+        : e.filename.toLowerCase().includes(w) ||
+          e.tags.some((tag) => tag.includes(w)) ||
+          e.note.toLowerCase().includes(w),
+    );
+
   const filtered = createMemo(() => {
     const list = entries() ?? [];
-    const needle = search().trim().toLowerCase();
+    const words = search().trim().toLowerCase().split(/\s+/).filter(Boolean);
     const kind = filter();
     return list.filter((e) => {
       if (kind === "gifs" && !e.is_gif) return false;
       if (kind === "videos" && !e.is_mp4) return false;
       if (kind === "images" && (e.is_gif || e.is_mp4)) return false;
       if (kind === "hdr" && !e.has_hdr) return false;
-      if (needle && !e.filename.toLowerCase().includes(needle)) return false;
+      if (words.length > 0 && !matchesSearch(e, words)) return false;
       return true;
     });
   });
@@ -159,66 +197,192 @@ export function History() {
     flashTimer = setTimeout(() => setFlash(null), tone === "err" ? 6000 : 2500);
   };
   const doReupload = (path: string) => {
-    showFlash("ok", "uploading...");
+    showFlash("ok", t("history.flash.uploading"));
     api.reuploadCapture(path)
-      .then(() => showFlash("ok", "re-uploaded"))
-      .catch((e: unknown) => showFlash("err", `upload failed: ${e}`));
+      .then(() => showFlash("ok", t("history.flash.reuploaded")))
+      .catch((e: unknown) => showFlash("err", t("history.flash.upload-failed", { error: String(e) })));
+  };
+  // any image on disk, edited like a capture; an outside file arrives as a
+  // copy in the output folder, so the list picks it up
+  const openImage = () => {
+    api
+      .openImageInEditor()
+      .then((path) => {
+        if (path) refetch();
+      })
+      .catch((e: unknown) => showFlash("err", t("history.flash.open-image-failed", { error: String(e) })));
+  };
+  // a whole folder through the output settings into the output folder; the
+  // flash counts files as they finish
+  const [converting, setConverting] = createSignal(false);
+  let unlistenConvert: UnlistenFn | null = null;
+  onMount(async () => {
+    unlistenConvert = await listen<BatchConvertProgress>(
+      "capscr://batch-convert-progress",
+      (e) => {
+        if (converting()) {
+          showFlash("ok", t("history.flash.converting", { done: e.payload.done, total: e.payload.total }));
+        }
+      },
+    );
+  });
+  onCleanup(() => unlistenConvert?.());
+  const convertFolder = () => {
+    setConverting(true);
+    api
+      .batchConvertFolder()
+      .then((written) => {
+        if (written === null) return;
+        showFlash(
+          "ok",
+          t(written === 1 ? "history.flash.converted.one" : "history.flash.converted.other", {
+            count: written,
+          }),
+        );
+        refetch();
+      })
+      .catch((e: unknown) => showFlash("err", t("history.flash.convert-failed", { error: String(e) })))
+      .finally(() => setConverting(false));
+  };
+  // batch mode: tiles toggle selection instead of opening, and the selected
+  // stills go up together, are stitched, or (two of them) open in compare.
+  // gifs and videos can't be re-uploaded yet, so they aren't selectable
+  const [selecting, setSelecting] = createSignal(false);
+  const [selected, setSelected] = createSignal<string[]>([]);
+  const [batch, setBatch] = createSignal<BatchUploadRow[] | null>(null);
+  const [batchRunning, setBatchRunning] = createSignal(false);
+  const selectable = (e: HistoryEntry) => !e.is_gif && !e.is_mp4;
+  const toggleSelected = (path: string) =>
+    setSelected((list) =>
+      list.includes(path) ? list.filter((p) => p !== path) : [...list, path],
+    );
+  const stopSelecting = () => {
+    setSelecting(false);
+    setSelected([]);
+  };
+  let unlistenBatch: UnlistenFn | null = null;
+  onMount(async () => {
+    unlistenBatch = await listen<BatchUploadRow>("capscr://batch-upload-progress", (e) => {
+      if (!batchRunning()) return;
+      setBatch((rows) =>
+        rows ? rows.map((r) => (r.index === e.payload.index ? e.payload : r)) : rows,
+      );
+    });
+  });
+  onCleanup(() => unlistenBatch?.());
+  const runBatch = () => {
+    const paths = selected();
+    if (paths.length === 0) return;
+    setBatch(
+      paths.map((path, index) => ({ index, path, state: "uploading", url: null, error: null })),
+    );
+    setBatchRunning(true);
+    stopSelecting();
+    api.batchUpload(paths)
+      .then((rows) => {
+        setBatch(rows);
+        const failed = rows.filter((r) => r.state === "failed").length;
+        showFlash(
+          failed ? "err" : "ok",
+          failed
+            ? t("history.flash.uploads-failed", { failed, total: rows.length })
+            : t("history.flash.uploaded", { count: rows.length }),
+        );
+      })
+      .catch((e: unknown) => showFlash("err", t("history.flash.batch-failed", { error: String(e) })))
+      .finally(() => setBatchRunning(false));
+  };
+  // overlapping captures joined in the order they were picked; the result is
+  // a new capture, so the list picks it up
+  const [stitching, setStitching] = createSignal(false);
+  const runStitch = () => {
+    const paths = selected();
+    if (paths.length < 2) return;
+    setStitching(true);
+    api
+      .stitchCaptures(paths)
+      .then(() => {
+        showFlash("ok", t("history.flash.stitched", { count: paths.length }));
+        stopSelecting();
+        refetch();
+      })
+      .catch((e: unknown) => showFlash("err", t("history.flash.stitch-failed", { error: String(e) })))
+      .finally(() => setStitching(false));
+  };
+  const batchUrls = () =>
+    (batch() ?? []).filter((r) => r.state === "done" && r.url).map((r) => r.url!);
+  const copyBatch = (format: "url" | "markdown") => {
+    api.copyBatchLinks(batchUrls(), format)
+      .then(() => showFlash("ok", t("history.flash.links-copied", { count: batchUrls().length })))
+      .catch((e: unknown) => showFlash("err", t("history.flash.copy-failed", { error: String(e) })));
   };
+  const fileName = (path: string) => path.split(/[\\/]/).pop() ?? path;
+
   const doCopy = (path: string) => {
     api.copyCaptureToClipboard(path)
-      .then(() => showFlash("ok", "copied to clipboard"))
-      .catch((e: unknown) => showFlash("err", `copy failed: ${e}`));
+      .then(() => showFlash("ok", t("history.flash.copied")))
+      .catch((e: unknown) => showFlash("err", t("history.flash.copy-failed", { error: String(e) })));
   };
   const doReveal = (path: string) => {
     api.openInExplorer(path)
-      .then(() => showFlash("ok", "revealed in file explorer"))
-      .catch((e: unknown) => showFlash("err", `open failed: ${e}`));
+      .then(() => showFlash("ok", t("history.flash.revealed")))
+      .catch((e: unknown) => showFlash("err", t("history.flash.open-failed", { error: String(e) })));
   };
   const doEdit = (path: string) => {
-    api.openEditor(path).catch((e: unknown) => showFlash("err", `editor failed: ${e}`));
+    api
+      .openEditor(path)
+      .catch((e: unknown) => showFlash("err", t("history.flash.editor-failed", { error: String(e) })));
+  };
+  // recordings can't be edited — opening them reveals the file instead
+  const openTile = (e: HistoryEntry) => {
+    if (e.is_gif || e.is_mp4) {
+      void api.openInExplorer(e.path);
+      return;
+    }
+    void api.openEditor(e.path);
   };
   const doOcr = (path: string) => {
-    showFlash("ok", "extracting text...");
+    showFlash("ok", t("history.flash.extracting"));
     api.runOcr(path)
       .then((text: string) => {
         if (!text || text.trim() === "") {
-          showFlash("err", "no text found in image");
+          showFlash("err", t("history.flash.no-text"));
         } else {
           navigator.clipboard.writeText(text)
-            .then(() => showFlash("ok", `OCR: text copied (${text.length} chars)`))
-            .catch(() => showFlash("err", "failed to copy to clipboard"));
+            .then(() => showFlash("ok", t("history.flash.ocr-copied", { count: text.length })))
+            .catch(() => showFlash("err", t("history.flash.clipboard-failed")));
         }
       })
-      .catch((e: unknown) => showFlash("err", `OCR failed: ${e}`));
+      .catch((e: unknown) => showFlash("err", t("history.flash.ocr-failed", { error: String(e) })));
   };
   const doPin = (path: string) => {
     api.pinImage(path)
-      .catch((e: unknown) => showFlash("err", `pin failed: ${e}`));
+      .catch((e: unknown) => showFlash("err", t("history.flash.pin-failed", { error: String(e) })));
   };
   const doDelete = (path: string) => {
     api.deleteCapture(path).then(() => {
       setConfirmDelete(null);
       refetch();
-      showFlash("ok", "deleted");
+      showFlash("ok", t("history.flash.deleted"));
     }).catch((e: unknown) => {
       setConfirmDelete(null);
-      showFlash("err", `delete failed: ${e}`);
+      showFlash("err", t("history.flash.delete-failed", { error: String(e) }));
     });
   };
 
   return (
     <>
       <div class="view-head">
-        <h1>history</h1>
+        <h1>{t("tab.history")}</h1>
         <span class="lede">
           {entries.loading
-            ? "reading dir..."
+            ? t("history.reading-dir")
             : (() => {
                 const total = entries()?.length ?? 0;
                 const shown = filtered().length;
                 return shown === total
-                  ? `${total} files in output dir`
-                  : `${shown} of ${total} files match`;
+                  ? t("history.count", { total })
+                  : t("history.count.filtered", { shown, total });
               })()}
         </span>
       </div>
@@ -229,7 +393,7 @@ export function History() {
             <Search size={11} stroke-width={1.5} />
             <input
               type="text"
-              placeholder="filter by filename..."
+              placeholder={t("history.search")}
               value={search()}
               onInput={(e) => setSearch(e.currentTarget.value)}
             />
@@ -237,7 +401,7 @@ export function History() {
               <button
                 type="button"
                 class="search-clear"
-                title="clear"
+                title={t("common.clear")}
                 onClick={() => setSearch("")}
               >
                 <X size={10} stroke-width={1.5} />
@@ -253,18 +417,137 @@ export function History() {
                   classList={{ "is-active": filter() === k }}
                   onClick={() => setFilter(k)}
                 >
-                  {k}
+                  {t(FILTER_LABELS[k])}
                 </button>
               )}
             </For>
           </div>
         </div>
-        <button class="btn" data-variant="ghost" onClick={() => refetch()}>
-          <RefreshCw size={12} stroke-width={1.5} />
-          reload
-        </button>
+        <div class="btn-row">
+          <Show
+            when={selecting()}
+            fallback={
+              <button
+                class="btn"
+                data-variant="ghost"
+                disabled={batchRunning()}
+                onClick={() => setSelecting(true)}
+              >
+                <CheckSquare size={12} stroke-width={1.5} />
+                {t("history.select")}
+              </button>
+            }
+          >
+            <button
+              class="btn"
+              disabled={selected().length === 0}
+              onClick={runBatch}
+            >
+              <UploadCloud size={12} stroke-width={1.5} />
+              {t("history.upload-selected", { count: selected().length })}
+            </button>
+            <button
+              class="btn"
+              data-variant="ghost"
+              disabled={selected().length !== 2}
+              onClick={() => {
+                const [a, b] = selected();
+                setComparing([a, b]);
+              }}
+              title={t("history.compare.title")}
+            >
+              <Columns size={12} stroke-width={1.5} />
+              {t("compare.title")}
+            </button>
+            <button
+              class="btn"
+              data-variant="ghost"
+              disabled={selected().length < 2 || stitching()}
+              onClick={runStitch}
+              title={t("history.stitch.title")}
+            >
+              <Layers size={12} stroke-width={1.5} />
+              {stitching() ? t("history.stitching") : t("history.stitch")}
+            </button>
+            <button class="btn" data-variant="ghost" onClick={stopSelecting}>
+              {t("common.cancel")}
+            </button>
+          </Show>
+          <button
+            class="btn"
+            data-variant="ghost"
+            disabled={selecting()}
+            onClick={openImage}
+            title={t("history.open-image.title")}
+          >
+            <FolderOpen size={12} stroke-width={1.5} />
+            {t("history.open-image")}
+          </button>
+          <button
+            class="btn"
+            data-variant="ghost"
+            disabled={selecting() || converting()}
+            onClick={convertFolder}
+            title={t("history.convert.title")}
+          >
+            <Images size={12} stroke-width={1.5} />
+            {t("history.convert")}
+          </button>
+          <button class="btn" data-variant="ghost" onClick={() => refetch()}>
+            <RefreshCw size={12} stroke-width={1.5} />
+            {t("common.reload")}
+          </button>
+        </div>
       </div>
 
+      <Show when={batch()}>
+        <div class="batch-list">
+          <For each={batch()!}>
+            {(row) => (
+              <div class="batch-row" data-state={row.state}>
+                <span class="name" title={row.path}>{fileName(row.path)}</span>
+                <span class="status" title={row.error ?? row.url ?? ""}>
+                  {row.state === "uploading"
+                    ? t("history.flash.uploading")
+                    : row.state === "done"
+                      ? row.url
+                      : t("history.batch.failed", { error: row.error ?? "" })}
+                </span>
+              </div>
+            )}
+          </For>
+          <div class="btn-row">
+            <button
+              class="btn"
+              data-variant="ghost"
+              disabled={batchRunning() || batchUrls().length === 0}
+              onClick={() => copyBatch("url")}
+            >
+              <Copy size={12} stroke-width={1.5} />
+              {t("history.batch.lines")}
+            </button>
+            <button
+              class="btn"
+              data-variant="ghost"
+              disabled={batchRunning() || batchUrls().length === 0}
+              onClick={() => copyBatch("markdown")}
+            >
+              <Copy size={12} stroke-width={1.5} />
+              {t("history.batch.markdown")}
+            </button>
+            <button
+              class="btn"
+              data-variant="ghost"
+              disabled={batchRunning()}
+              onClick={() => setBatch(null)}
+            >
+              <X size={12} stroke-width={1.5} />
+              {t("common.close")}
+            </button>
+          </div>
+        </div>
+      </Show>
+
       <Show when={flash()}>
         <div class="flash" data-tone={flash()!.tone} style="margin-bottom: 12px;">
           {flash()!.msg}
@@ -279,23 +562,28 @@ export function History() {
             fallback={
               <div class="empty">
                 <span class="stick" />
-                reading...
+                {t("history.reading")}
               </div>
             }
           >
             <div class="empty">
               <span class="stick" />
-              no captures yet
+              {t("history.empty")}
               <p>
-                press <kbd>{screenshotHotkey()}</kbd> to drag a region capture to clipboard,
-                or <kbd>{recordGifHotkey()}</kbd> to record a GIF.
+                {tm("history.empty.hint", {
+                  shot: <kbd>{screenshotHotkey() ?? t("history.your-key")}</kbd>,
+                  gif: <kbd>{recordGifHotkey()}</kbd>,
+                })}
               </p>
               <p class="muted" style="margin-top: 8px; font-size: 11px;">
-                rebind these in <strong>tasks</strong> · destinations live in <strong>destinations</strong>.
+                {tm("history.empty.rebind", {
+                  tasks: <strong>{t("tab.tasks")}</strong>,
+                  destinations: <strong>{t("tab.destinations")}</strong>,
+                })}
               </p>
               <Show when={outputDir()}>
                 <p class="muted" style="margin-top: 12px; font-size: 10px;">
-                  reading from <code>{outputDir()}</code>
+                  {tm("history.empty.reading-from", { dir: <code>{outputDir()}</code> })}
                 </p>
               </Show>
             </div>
@@ -307,8 +595,8 @@ export function History() {
           fallback={
             <div class="empty">
               <span class="stick" />
-              no matches
-              <p>nothing in the output dir matches your filter.</p>
+              {t("history.no-matches")}
+              <p>{t("history.no-matches.hint")}</p>
             </div>
           }
         >
@@ -317,18 +605,35 @@ export function History() {
             {(e) => (
               <div
                 class="tile"
+                classList={{
+                  "is-selected": selecting() && selected().includes(e.path),
+                  "is-unselectable": selecting() && !selectable(e),
+                }}
+                role="button"
+                tabIndex={0}
+                aria-pressed={selecting() ? selected().includes(e.path) : undefined}
+                aria-label={
+                  selecting()
+                    ? t("history.tile.select", { name: e.filename })
+                    : e.is_gif || e.is_mp4
+                      ? t("history.tile.reveal", { name: e.filename })
+                      : t("history.tile.edit", { name: e.filename })
+                }
                 onClick={(ev) => {
                   // don't open the editor when the click landed on an
-                  // overlay button.
-                  if ((ev.target as HTMLElement).closest(".tile-actions")) return;
-                  // recordings can't be edited — clicking them reveals the
-                  // file instead of opening the editor
-                  if (e.is_gif || e.is_mp4) {
-                    void api.openInExplorer(e.path);
+                  // overlay button or a tag.
+                  if ((ev.target as HTMLElement).closest(".tile-actions, .tile-tags")) return;
+                  if (selecting()) {
+                    if (selectable(e)) toggleSelected(e.path);
                     return;
                   }
-                  void api.openEditor(e.path);
+                  openTile(e);
                 }}
+                onKeyDown={(ev) =>
+                  activateOnKey(ev, () =>
+                    selecting() ? selectable(e) && toggleSelected(e.path) : openTile(e),
+                  )
+                }
               >
                 <Show
                   when={e.is_mp4}
@@ -352,11 +657,12 @@ export function History() {
                     style={{ "object-fit": "cover" }}
                   />
                 </Show>
+                <Show when={!selecting()}>
                 <div class="tile-actions">
                   <Show when={e.is_mp4}>
                     <button
                       class="icon-btn"
-                      title="trim"
+                      title={t("history.trim")}
                       onClick={() => setTrimPath(e.path)}
                     >
                       <Scissors size={12} stroke-width={1.5} />
@@ -365,21 +671,21 @@ export function History() {
                    <Show when={!e.is_gif && !e.is_mp4}>
                     <button
                       class="icon-btn"
-                      title="edit"
+                      title={t("common.edit")}
                       onClick={() => doEdit(e.path)}
                     >
                       <Edit3 size={12} stroke-width={1.5} />
                     </button>
                     <button
                       class="icon-btn"
-                      title="extract text (OCR)"
+                      title={t("history.ocr")}
                       onClick={() => doOcr(e.path)}
                     >
                       <Type size={12} stroke-width={1.5} />
                     </button>
                     <button
                       class="icon-btn"
-                      title="pin to screen"
+                      title={t("history.pin")}
                       onClick={() => doPin(e.path)}
                     >
                       <Pin size={12} stroke-width={1.5} />
@@ -387,21 +693,28 @@ export function History() {
                   </Show>
                   <button
                     class="icon-btn"
-                    title="re-upload"
+                    title={t("tags.title")}
+                    onClick={() => setTaggingPath(e.path)}
+                  >
+                    <Tag size={12} stroke-width={1.5} />
+                  </button>
+                  <button
+                    class="icon-btn"
+                    title={t("history.reupload")}
                     onClick={() => doReupload(e.path)}
                   >
                     <UploadCloud size={12} stroke-width={1.5} />
                   </button>
                   <button
                     class="icon-btn"
-                    title="open in os viewer"
+                    title={t("history.open-viewer")}
                     onClick={() => doReveal(e.path)}
                   >
                     <ExternalLink size={12} stroke-width={1.5} />
                   </button>
                   <button
                     class="icon-btn"
-                    title="copy to clipboard"
+                    title={t("history.copy")}
                     onClick={() => doCopy(e.path)}
                   >
                     <Copy size={12} stroke-width={1.5} />
@@ -411,8 +724,8 @@ export function History() {
                     classList={{ "is-arm": confirmDelete() === e.path }}
                     title={
                       confirmDelete() === e.path
-                        ? "click again to confirm"
-                        : "delete"
+                        ? t("common.confirm.title")
+                        : t("common.delete")
                     }
                     onClick={() =>
                       confirmDelete() === e.path
@@ -423,6 +736,7 @@ export function History() {
                     <Trash2 size={12} stroke-width={1.5} />
                   </button>
                 </div>
+                </Show>
                 <div class="tile-meta">
                   <div class="name" title={e.path}>
                     {e.filename}
@@ -435,12 +749,31 @@ export function History() {
                       <span>·</span>
                       <span
                         class="tile-tag"
-                        title="HDR sidecar present (.hdr.png)"
+                        title={t("history.hdr")}
                       >
                         HDR
                       </span>
                     </Show>
                   </div>
+                  <Show when={e.tags.length > 0 || e.note}>
+                    <div class="tile-tags" title={e.note || undefined}>
+                      <For each={e.tags}>
+                        {(tag) => (
+                          <button
+                            type="button"
+                            class="tile-tag"
+                            title={t("history.tag.title", { tag })}
+                            onClick={() => setSearch(`#${tag}`)}
+                          >
+                            #{tag}
+                          </button>
+                        )}
+                      </For>
+                      <Show when={e.note}>
+                        <span class="tile-note">{e.note}</span>
+                      </Show>
+                    </div>
+                  </Show>
                 </div>
               </div>
             )}
@@ -449,6 +782,25 @@ export function History() {
         </Show>
       </Show>
 
+      <Show when={comparing()}>
+        <CompareModal
+          a={comparing()![0]}
+          b={comparing()![1]}
+          onClose={() => setComparing(null)}
+        />
+      </Show>
+
+      <Show when={taggingPath()}>
+        <TagsModal
+          path={taggingPath()!}
+          onClose={() => setTaggingPath(null)}
+          onSaved={() => {
+            setTaggingPath(null);
+            refetch();
+          }}
+        />
+      </Show>
+
       <Show when={trimPath()}>
         <TrimModal
           path={trimPath()!}
@@ -3,6 +3,7 @@ import { FolderOpen, RefreshCw, Power, Download, Trash2, ExternalLink } from "lu
 import { openUrl } from "@tauri-apps/plugin-opener";
 import { Section } from "../components/Section";
 import { api, InstalledPlugin, RegistryEntry } from "../api";
+import { t } from "../i18n";
 
 function formatSize(bytes: number): string {
   if (bytes < 1024) return `${bytes} B`;
@@ -38,12 +39,12 @@ export function Marketplace() {
   const [busyId, setBusyId] = createSignal<string | null>(null);
 
   const reload = async () => {
-    setStatus({ tone: "", msg: "re-scanning..." });
+    setStatus({ tone: "", msg: t("plugins.status.rescanning") });
     try {
       await Promise.all([refetchInstalled(), refetchRegistry(), refetchErrors()]);
-      setStatus({ tone: "ok", msg: "done." });
+      setStatus({ tone: "ok", msg: t("plugins.status.done") });
     } catch (e) {
-      setStatus({ tone: "err", msg: `err: ${e}` });
+      setStatus({ tone: "err", msg: t("common.error", { error: String(e) }) });
     }
   };
 
@@ -53,27 +54,27 @@ export function Marketplace() {
     } catch (e) {
       setStatus({
         tone: "err",
-        msg: `couldn't open plugins folder: ${e}`,
+        msg: t("plugins.status.folder-failed", { error: String(e) }),
       });
     }
   };
 
   const install = async (entry: RegistryEntry) => {
     setBusyId(entry.id);
-    setStatus({ tone: "", msg: `installing ${entry.name}...` });
+    setStatus({ tone: "", msg: t("plugins.status.installing", { name: entry.name }) });
     try {
       const needsReview = await api.marketplaceInstall(entry.id);
       setStatus(
         needsReview
           ? {
               tone: "ok",
-              msg: `installed ${entry.name} v${entry.version} — disabled until you review its permissions below and enable it.`,
+              msg: t("plugins.status.installed.review", { name: entry.name, version: entry.version }),
             }
-          : { tone: "ok", msg: `installed ${entry.name} v${entry.version}.` },
+          : { tone: "ok", msg: t("plugins.status.installed", { name: entry.name, version: entry.version }) },
       );
       await refetchInstalled();
     } catch (e) {
-      setStatus({ tone: "err", msg: `install failed: ${e}` });
+      setStatus({ tone: "err", msg: t("plugins.status.install-failed", { error: String(e) }) });
     } finally {
       setBusyId(null);
     }
@@ -82,30 +83,36 @@ export function Marketplace() {
   const toggleEnabled = async (entry: InstalledPlugin) => {
     setBusyId(entry.id);
     const next = !entry.enabled;
-    setStatus({ tone: "", msg: `${next ? "enabling" : "disabling"} ${entry.name}...` });
+    setStatus({
+      tone: "",
+      msg: t(next ? "plugins.status.enabling" : "plugins.status.disabling", { name: entry.name }),
+    });
     try {
       await api.togglePluginEnabled(entry.id, next);
-      setStatus({ tone: "ok", msg: `${entry.name} ${next ? "enabled" : "disabled"}.` });
+      setStatus({
+        tone: "ok",
+        msg: t(next ? "plugins.status.enabled" : "plugins.status.disabled", { name: entry.name }),
+      });
       await refetchInstalled();
     } catch (e) {
-      setStatus({ tone: "err", msg: `toggle failed: ${e}` });
+      setStatus({ tone: "err", msg: t("plugins.status.toggle-failed", { error: String(e) }) });
     } finally {
       setBusyId(null);
     }
   };
 
   const uninstall = async (entry: InstalledPlugin) => {
-    if (!window.confirm(`Uninstall ${entry.name}? Plugin files will be deleted.`)) {
+    if (!window.confirm(t("plugins.uninstall.confirm", { name: entry.name }))) {
       return;
     }
     setBusyId(entry.id);
-    setStatus({ tone: "", msg: `uninstalling ${entry.name}...` });
+    setStatus({ tone: "", msg: t("plugins.status.uninstalling", { name: entry.name }) });
     try {
       await api.marketplaceUninstall(entry.id);
-      setStatus({ tone: "ok", msg: `removed ${entry.name}.` });
+      setStatus({ tone: "ok", msg: t("plugins.status.removed", { name: entry.name }) });
       await refetchInstalled();
     } catch (e) {
-      setStatus({ tone: "err", msg: `uninstall failed: ${e}` });
+      setStatus({ tone: "err", msg: t("plugins.status.uninstall-failed", { error: String(e) }) });
     } finally {
       setBusyId(null);
     }
@@ -124,20 +131,20 @@ export function Marketplace() {
   return (
     <>
       <div class="view-head">
-        <h1>plugins</h1>
-        <span class="lede">browse rot.lt registry · drop plugin dirs into the folder.</span>
+        <h1>{t("tab.plugins")}</h1>
+        <span class="lede">{t("plugins.lede")}</span>
       </div>
 
-      <Section title="installed">
+      <Section title={t("plugins.installed")}>
         <div class="row between" style="margin: 4px 0 14px;">
           <div class="btn-row">
             <button class="btn" onClick={openFolder}>
               <FolderOpen size={12} stroke-width={1.5} />
-              open folder
+              {t("plugins.open-folder")}
             </button>
             <button class="btn" data-variant="ghost" onClick={reload}>
               <RefreshCw size={12} stroke-width={1.5} />
-              reload
+              {t("common.reload")}
             </button>
           </div>
           <Show when={status()}>
@@ -153,12 +160,19 @@ export function Marketplace() {
             data-tone="err"
             style="display: block; margin: 0 0 14px; white-space: normal;"
           >
-            <strong>{(loadErrors() ?? []).length} plugin(s) failed to load</strong>
+            <strong>
+              {t(
+                (loadErrors() ?? []).length === 1
+                  ? "plugins.load-failed.one"
+                  : "plugins.load-failed.other",
+                { count: (loadErrors() ?? []).length },
+              )}
+            </strong>
             <ul style="margin: 6px 0 0; padding-left: 18px;">
               <For each={loadErrors()!}>{(err) => <li>{err}</li>}</For>
             </ul>
             <div class="muted" style="margin-top: 6px;">
-              load runs at launch — restart capscr after fixing a plugin.
+              {t("plugins.load-failed.hint")}
             </div>
           </div>
         </Show>
@@ -168,9 +182,9 @@ export function Marketplace() {
           fallback={
             <div class="empty">
               <span class="stick" />
-              none installed
+              {t("plugins.none")}
               <p>
-                browse below, or drop a plugin dir into the plugins folder and reload.
+                {t("plugins.none.hint")}
               </p>
             </div>
           }
@@ -194,7 +208,7 @@ export function Marketplace() {
                               class="tag"
                               data-tone={p.enabled ? "on" : "off"}
                             >
-                              {p.enabled ? "enabled" : "disabled"}
+                              {p.enabled ? t("plugins.enabled") : t("plugins.disabled")}
                             </span>
                           </span>
                         </span>
@@ -209,7 +223,7 @@ export function Marketplace() {
                       </Show>
                       <Show when={p.capabilities.length > 0}>
                         <div class="perm-row">
-                          <span class="perm-label">grants</span>
+                          <span class="perm-label">{t("plugins.grants")}</span>
                           <For each={p.capabilities}>
                             {(cap) => <span class="perm-tag">{cap}</span>}
                           </For>
@@ -225,7 +239,7 @@ export function Marketplace() {
                         onClick={() => toggleEnabled(p)}
                       >
                         <Power size={11} stroke-width={1.5} />
-                        {p.enabled ? "disable" : "enable"}
+                        {p.enabled ? t("plugins.disable") : t("plugins.enable")}
                       </button>
                       <button
                         class="btn"
@@ -235,7 +249,7 @@ export function Marketplace() {
                         onClick={() => uninstall(p)}
                       >
                         <Trash2 size={11} stroke-width={1.5} />
-                        uninstall
+                        {t("plugins.uninstall")}
                       </button>
                     </div>
                   </div>
@@ -246,7 +260,7 @@ export function Marketplace() {
         </Show>
       </Section>
 
-      <Section title="browse">
+      <Section title={t("plugins.browse")}>
         <Show
           when={!registry.loading}
           fallback={
@@ -265,11 +279,9 @@ export function Marketplace() {
                 fallback={
                   <div class="empty">
                     <span class="stick" />
-                    empty registry
+                    {t("plugins.empty-registry")}
                     <p>
-                      there are no plugins to install yet — drop a plugin dir
-                      into the plugins folder, or check back once the registry
-                      has entries.
+                      {t("plugins.empty-registry.hint")}
                     </p>
                   </div>
                 }
@@ -340,14 +352,14 @@ export function Marketplace() {
                                 title={entry.homepage}
                               >
                                 <ExternalLink size={11} stroke-width={1.5} />
-                                site
+                                {t("plugins.site")}
                               </button>
                             </Show>
                             <Show
                               when={!installed() || !upToDate()}
                               fallback={
                                 <span class="tag" data-tone="on">
-                                  installed
+                                  {t("plugins.installed")}
                                 </span>
                               }
                             >
@@ -361,8 +373,8 @@ export function Marketplace() {
                                 {busyId() === entry.id
                                   ? "..."
                                   : installed()
-                                  ? `update to v${entry.version}`
-                                  : "install"}
+                                  ? t("plugins.update", { version: entry.version })
+                                  : t("plugins.install")}
                               </button>
                             </Show>
                           </div>
@@ -376,12 +388,12 @@ export function Marketplace() {
           >
             <div class="empty">
               <span class="stick" />
-              registry unreachable
+              {t("plugins.unreachable")}
               <p>
                 {String(registry.error)}
               </p>
               <p class="muted" style="margin-top: 8px; font-size: 11px;">
-                check Settings → destinations to point at a mirror, or wait for rot.lt to come back.
+                {t("plugins.unreachable.hint")}
               </p>
             </div>
           </Show>
@@ -1,9 +1,10 @@
 import { createSignal, onCleanup, onMount, Show } from "solid-js";
 import { getCurrentWindow } from "@tauri-apps/api/window";
-import { LogicalSize } from "@tauri-apps/api/dpi";
+import { LogicalSize, PhysicalSize } from "@tauri-apps/api/dpi";
 import { convertFileSrc } from "@tauri-apps/api/core";
 import { X } from "lucide-solid";
 import { api } from "../api";
+import { t } from "../i18n";
 
 export function PinView(props: { label: string }) {
   const [imagePath, setImagePath] = createSignal<string | null>(null);
@@ -14,10 +15,10 @@ export function PinView(props: { label: string }) {
   // the pin window is created hidden and revealed once we know its size. every
   // path has to reach a reveal, or a failed load leaves an invisible window the
   // user can't find to close.
-  const reveal = async (w?: number, h?: number) => {
+  const reveal = async (size?: LogicalSize | PhysicalSize) => {
     const win = getCurrentWindow();
-    if (w && h) {
-      await win.setSize(new LogicalSize(w, h)).catch(() => {});
+    if (size) {
+      await win.setSize(size).catch(() => {});
     }
     await win.show().catch(() => {});
   };
@@ -26,8 +27,8 @@ export function PinView(props: { label: string }) {
     api.getPinnedImagePath(props.label)
       .then((path) => {
         if (!path) {
-          setError("this pin's image is no longer available");
-          reveal(320, 120);
+          setError(t("pin.gone"));
+          reveal(new LogicalSize(320, 120));
           return;
         }
         setImagePath(path);
@@ -37,27 +38,30 @@ export function PinView(props: { label: string }) {
           let w = img.naturalWidth;
           let h = img.naturalHeight;
 
-          // Optional: cap size at 85% of screen dimensions to keep it reasonable
-          const maxW = window.screen.availWidth * 0.85;
-          const maxH = window.screen.availHeight * 0.85;
+          // the capture is in device pixels, so the pin is sized physically
+          // and shows 1:1 on any monitor scale; the screen is reported in css
+          // pixels, so scale the 85%-of-screen cap to match
+          const dpr = window.devicePixelRatio || 1;
+          const maxW = window.screen.availWidth * dpr * 0.85;
+          const maxH = window.screen.availHeight * dpr * 0.85;
           if (w > maxW || h > maxH) {
             const ratio = Math.min(maxW / w, maxH / h);
             w = Math.round(w * ratio);
             h = Math.round(h * ratio);
           }
-          reveal(w, h);
+          reveal(new PhysicalSize(w, h));
         };
         img.onerror = () => {
           setImagePath(null);
-          setError("couldn't load this pin's image");
-          reveal(320, 120);
+          setError(t("pin.image-failed"));
+          reveal(new LogicalSize(320, 120));
         };
         img.src = convertFileSrc(path);
       })
       .catch((e) => {
         console.error("Failed to load pinned image path:", e);
-        setError("couldn't load this pin");
-        reveal(320, 120);
+        setError(t("pin.failed"));
+        reveal(new LogicalSize(320, 120));
       });
   });
 
@@ -122,12 +126,12 @@ export function PinView(props: { label: string }) {
         {imagePath() ? (
           <img
             src={convertFileSrc(imagePath()!)}
-            alt="pinned"
+            alt={t("pin.image")}
             class="pin-image"
             draggable={false}
           />
         ) : (
-          <div class="pin-error">{error() ?? "nothing pinned"}</div>
+          <div class="pin-error">{error() ?? t("pin.empty")}</div>
         )}
       </div>
       <Show when={hovered()}>
@@ -140,9 +144,9 @@ export function PinView(props: { label: string }) {
             value={opacity()}
             onInput={(e) => setOpacity(parseFloat(e.currentTarget.value))}
             class="pin-opacity-slider"
-            title="adjust opacity"
+            title={t("pin.opacity")}
           />
-          <button class="pin-close-btn" onClick={closePin} title="close">
+          <button class="pin-close-btn" onClick={closePin} title={t("common.close")}>
             <X size={12} />
           </button>
         </div>
@@ -1,10 +1,14 @@
 import { createSignal, onCleanup, onMount } from "solid-js";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { t } from "../i18n";
 
 // linux recording control bar: elapsed clock + stop. the window is created
 // when the recording starts, so the clock counts from mount.
 export function RecBar() {
   const [elapsed, setElapsed] = createSignal("00:00");
+  // set once the recording is within seconds of its max duration
+  const [ending, setEnding] = createSignal(false);
   const started = Date.now();
 
   const tick = setInterval(() => {
@@ -15,6 +19,11 @@ export function RecBar() {
   }, 1000);
   onCleanup(() => clearInterval(tick));
 
+  const unlisten = listen("capscr://recording-ending", () => setEnding(true));
+  onCleanup(() => {
+    void unlisten.then((u) => u());
+  });
+
   onMount(() => {
     // clear every layer of the app background so only the pill shows; the
     // window itself can come up larger than the bar on gtk-wayland
@@ -33,9 +42,11 @@ export function RecBar() {
   return (
     <div class="recbar">
       <span class="recbar-dot" />
-      <span class="recbar-time">{elapsed()}</span>
+      <span class="recbar-time" data-ending={ending() ? "" : undefined}>
+        {elapsed()}
+      </span>
       <button type="button" class="recbar-stop" onClick={stop}>
-        stop
+        {t("recbar.stop")}
       </button>
     </div>
   );
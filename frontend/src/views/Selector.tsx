@@ -16,11 +16,14 @@ interface SelectorContext {
   frame_width: number;
   frame_height: number;
   windows: WindowRect[];
+  fixed_width: number | null;
+  fixed_height: number | null;
 }
 
 const CLICK_THRESHOLD = 5;
 const MAGNIFIER_SIZE = 120;
 const ASPECT_TARGETS = [1, 16 / 9, 16 / 10, 4 / 3, 21 / 9];
+const EDGE_SNAP_DISTANCE = 8;
 const DRAG_CHANNEL = "capscr-selector-drag";
 
 interface SharedDrag {
@@ -55,6 +58,9 @@ export function Selector() {
   let mouseDown = false;
   let dragStarted = false;
   let hovered: WindowRect | null = null;
+  // set when Tab picked `hovered`, so Enter takes that window; a mouse hover
+  // alone keeps Enter meaning full screen
+  let keyboardPicked = false;
   let zoom = 8;
   let shiftHeld = false;
   let altHeld = false;
@@ -85,6 +91,31 @@ export function Selector() {
     y: e.clientY + (ctxInfo?.origin_y ?? 0),
   });
 
+  // ctrl pulls a corner onto the nearest window edge on each axis; an edge only
+  // counts while the point is alongside it (mirrors snap_to_window_edges)
+  const snapToEdges = (point: { x: number; y: number }, enabled: boolean) => {
+    if (!enabled || !ctxInfo) return point;
+    let bestX = { distance: EDGE_SNAP_DISTANCE + 1, value: point.x };
+    let bestY = { distance: EDGE_SNAP_DISTANCE + 1, value: point.y };
+    for (const win of ctxInfo.windows) {
+      const right = win.x + win.width;
+      const bottom = win.y + win.height;
+      if (point.y >= win.y - EDGE_SNAP_DISTANCE && point.y <= bottom + EDGE_SNAP_DISTANCE) {
+        for (const edge of [win.x, right]) {
+          const distance = Math.abs(point.x - edge);
+          if (distance < bestX.distance) bestX = { distance, value: edge };
+        }
+      }
+      if (point.x >= win.x - EDGE_SNAP_DISTANCE && point.x <= right + EDGE_SNAP_DISTANCE) {
+        for (const edge of [win.y, bottom]) {
+          const distance = Math.abs(point.y - edge);
+          if (distance < bestY.distance) bestY = { distance, value: edge };
+        }
+      }
+    }
+    return { x: bestX.value, y: bestY.value };
+  };
+
   const shareDrag = () => {
     dragChannel.postMessage({
       startX,
@@ -98,8 +129,33 @@ export function Selector() {
     } satisfies SharedDrag);
   };
 
+  // recording size preset: a frame of this many physical px follows the pointer
+  const fixedSize = () =>
+    ctxInfo?.fixed_width && ctxInfo.fixed_height
+      ? { width: ctxInfo.fixed_width, height: ctxInfo.fixed_height }
+      : null;
+
+  // centre the fixed frame on a client point, kept inside this surface
+  const placeFixed = (clientX: number, clientY: number) => {
+    const size = fixedSize();
+    if (!size || !ctxInfo) return;
+    const { sx, sy } = scale();
+    const width = Math.min(size.width / sx, window.innerWidth);
+    const height = Math.min(size.height / sy, window.innerHeight);
+    const left = Math.max(0, Math.min(clientX - width / 2, window.innerWidth - width));
+    const top = Math.max(0, Math.min(clientY - height / 2, window.innerHeight - height));
+    startX = left + ctxInfo.origin_x;
+    startY = top + ctxInfo.origin_y;
+    endX = startX + width;
+    endY = startY + height;
+    selectionScaleX = sx;
+    selectionScaleY = sy;
+    dragStarted = true;
+    shareDrag();
+  };
+
   const snappedEnd = () => {
-    if (!shiftHeld) return { ex: endX, ey: endY };
+    if (!shiftHeld || fixedSize()) return { ex: endX, ey: endY };
     const dx = endX - startX;
     const dy = endY - startY;
     const width = Math.abs(dx);
@@ -310,8 +366,10 @@ export function Selector() {
     shiftHeld = e.shiftKey;
     altHeld = e.altKey;
     if (altHeld) requestFrame();
-    if (mouseDown) {
-      const desktop = toDesktop(e);
+    if (fixedSize()) {
+      placeFixed(e.clientX, e.clientY);
+    } else if (mouseDown) {
+      const desktop = snapToEdges(toDesktop(e), e.ctrlKey);
       endX = desktop.x;
       endY = desktop.y;
       shareDrag();
@@ -319,6 +377,7 @@ export function Selector() {
       const nextHovered = windowAt(e.clientX, e.clientY);
       if (nextHovered === hovered && !altHeld) return;
       hovered = nextHovered;
+      keyboardPicked = false;
     }
     schedule();
   };
@@ -344,7 +403,14 @@ export function Selector() {
       finish({ kind: "color", r, g, b });
       return;
     }
-    const desktop = toDesktop(e);
+    if (fixedSize()) {
+      // the frame already sits under the pointer; commit on release
+      placeFixed(e.clientX, e.clientY);
+      mouseDown = true;
+      schedule();
+      return;
+    }
+    const desktop = snapToEdges(toDesktop(e), e.ctrlKey);
     const { sx, sy } = scale();
     selectionScaleX = sx;
     selectionScaleY = sy;
@@ -360,7 +426,11 @@ export function Selector() {
 
   const onMouseUp = (e: MouseEvent) => {
     if (e.button !== 0 || !mouseDown) return;
-    const desktop = toDesktop(e);
+    if (fixedSize()) {
+      mouseDown = false;
+      return commitRegion();
+    }
+    const desktop = snapToEdges(toDesktop(e), e.ctrlKey);
     endX = desktop.x;
     endY = desktop.y;
     mouseDown = false;
@@ -370,17 +440,7 @@ export function Selector() {
     shareDrag();
     if (Math.abs(endX - startX) <= CLICK_THRESHOLD && Math.abs(endY - startY) <= CLICK_THRESHOLD) {
       const target = windowAt(e.clientX, e.clientY);
-      finish(
-        target
-          ? {
-              kind: "window",
-              id: target.id,
-              handle: target.handle ?? null,
-              x: target.x,
-              y: target.y,
-            }
-          : { kind: "full_screen" },
-      );
+      finish(target ? windowResult(target) : { kind: "full_screen" });
     } else if (!e.ctrlKey) {
       commitRegion();
     } else {
@@ -388,13 +448,33 @@ export function Selector() {
     }
   };
 
+  const windowResult = (target: WindowRect) => ({
+    kind: "window",
+    id: target.id,
+    handle: target.handle ?? null,
+    x: target.x,
+    y: target.y,
+  });
+
   const onKeyDown = (e: KeyboardEvent) => {
     shiftHeld = e.shiftKey;
     altHeld = e.altKey;
     if (altHeld) requestFrame();
     if (e.key === "Escape") return finish({ kind: "cancelled" });
     if (e.key === "Enter" || e.key === " ") {
-      return hasSelection() ? commitRegion() : finish({ kind: "full_screen" });
+      if (hasSelection()) return commitRegion();
+      return finish(keyboardPicked && hovered ? windowResult(hovered) : { kind: "full_screen" });
+    }
+    // Tab / Shift+Tab walk the windows top to bottom without the mouse
+    if (e.key === "Tab" && !hasSelection() && ctxInfo && ctxInfo.windows.length > 0) {
+      e.preventDefault();
+      const list = ctxInfo.windows;
+      const at = hovered ? list.indexOf(hovered) : -1;
+      const next = at < 0 ? (e.shiftKey ? list.length - 1 : 0) : at + (e.shiftKey ? -1 : 1);
+      hovered = list[(next + list.length) % list.length];
+      keyboardPicked = true;
+      schedule();
+      return;
     }
     const arrows: Record<string, [number, number]> = {
       ArrowLeft: [-1, 0],
@@ -406,7 +486,13 @@ export function Selector() {
     if (!delta) return;
     e.preventDefault();
     const [dx, dy] = delta;
-    if (e.ctrlKey && e.shiftKey && dragStarted) {
+    if (fixedSize() && dragStarted && ctxInfo) {
+      // arrows nudge the fixed frame
+      placeFixed(
+        (startX + endX) / 2 - ctxInfo.origin_x + dx,
+        (startY + endY) / 2 - ctxInfo.origin_y + dy,
+      );
+    } else if (e.ctrlKey && e.shiftKey && dragStarted) {
       startX += dx;
       startY += dy;
     } else if (e.ctrlKey && dragStarted) {
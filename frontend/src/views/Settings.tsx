@@ -1,30 +1,74 @@
-import { createResource, createSignal, For, Match, onCleanup, Show, Switch } from "solid-js";
+import { createMemo, createResource, createSignal, For, Match, onCleanup, Show, Switch } from "solid-js";
 import { listen } from "@tauri-apps/api/event";
 import { open as openDialog } from "@tauri-apps/plugin-dialog";
 import { Section } from "../components/Section";
-import { api, AppConfig, HotkeyDiagnostics, SftpKnownHost } from "../api";
+import { HotkeyInput } from "../components/HotkeyInput";
+import { ResizeField } from "../components/ResizeField";
+import { arrowNav } from "../a11y";
+import {
+  api,
+  AppConfig,
+  AppProfile,
+  CaptureKind,
+  GameModeAction,
+  HotkeyDiagnostics,
+  RetentionPolicy,
+  SftpKnownHost,
+} from "../api";
 import { configDirty, setConfigDirty } from "../dirty";
-import { FolderOpen, RotateCcw, Save } from "lucide-solid";
+import { FolderOpen, RotateCcw, Save, Search, X } from "lucide-solid";
 import { config, mutateConfig } from "../store";
 import { commitNumber } from "../num";
 import { IS_LINUX } from "../keys";
 import { hdrSupported } from "../hdrSupport";
+import { LANGUAGES, MessageKey, t, tm } from "../i18n";
+import { ACCENTS, THEMES } from "../theme";
+import { POST_ACTIONS, STILL_ONLY_ACTIONS } from "./Tasks";
+import { searchSettings, SettingDescriptor, SettingsPane as Pane } from "../settingsIndex";
 
-type Pane = "general" | "capture" | "hdr" | "hotkeys" | "ssh" | "notify";
-
-const ALL_PANES: { id: Pane; label: string }[] = [
-  { id: "general", label: "general" },
-  { id: "capture", label: "capture" },
-  { id: "hdr", label: "hdr" },
-  { id: "hotkeys", label: "hotkeys" },
-  { id: "ssh", label: "ssh" },
-  { id: "notify", label: "notify" },
+const ALL_PANES: { id: Pane }[] = [
+  { id: "general" },
+  { id: "capture" },
+  { id: "hdr" },
+  { id: "hotkeys" },
+  { id: "ssh" },
+  { id: "notify" },
 ];
 // the hdr pane only shows where a pixel source exists (windows, gnome 50)
 const PANES = () => ALL_PANES.filter((pane) => !(pane.id === "hdr" && !hdrSupported()));
 
+// bring a search result's row into view once its pane has rendered. rows are
+// found by section title, then field label, so the panes need no ids
+function revealSetting(s: SettingDescriptor) {
+  requestAnimationFrame(() => {
+    const section = [...document.querySelectorAll<HTMLElement>(".section")].find(
+      (el) => el.querySelector(".section-title")?.textContent?.trim() === t(s.section),
+    );
+    if (!section) return;
+    const row =
+      [...section.querySelectorAll<HTMLElement>(".field")].find(
+        (el) => el.querySelector(".field-label")?.textContent?.trim() === t(s.label),
+      ) ?? section;
+    row.scrollIntoView({ block: "center", behavior: "smooth" });
+    row.classList.add("is-found");
+    setTimeout(() => row.classList.remove("is-found"), 1600);
+  });
+}
+
 export function Settings() {
   const [pane, setPane] = createSignal<Pane>("general");
+  const [query, setQuery] = createSignal("");
+  const results = createMemo(() =>
+    searchSettings(query(), {
+      isLinux: IS_LINUX,
+      panes: PANES().map((p) => p.id),
+    }),
+  );
+  const jumpTo = (s: SettingDescriptor) => {
+    setQuery("");
+    setPane(s.pane);
+    revealSetting(s);
+  };
   const [saving, setSaving] = createSignal(false);
   const [status, setStatus] = createSignal<{ tone: string; msg: string } | null>(
     null,
@@ -41,43 +85,95 @@ export function Settings() {
     const c = config();
     if (!c) return;
     setSaving(true);
-    setStatus({ tone: "", msg: "writing..." });
+    setStatus({ tone: "", msg: t("common.writing") });
     try {
       await api.setConfig(c);
-      setStatus({ tone: "ok", msg: "saved." });
+      setStatus({ tone: "ok", msg: t("common.saved") });
       setConfigDirty(false);
     } catch (e) {
-      setStatus({ tone: "err", msg: `err: ${e}` });
+      setStatus({ tone: "err", msg: t("common.error", { error: String(e) }) });
     } finally {
       setSaving(false);
     }
   };
 
   const resetDefaults = async () => {
-    if (!window.confirm("Replace every setting with the defaults? Tasks, hotkeys, and destinations will all reset.")) {
+    if (!window.confirm(t("settings.reset.confirm"))) {
       return;
     }
-    setStatus({ tone: "", msg: "loading defaults..." });
+    setStatus({ tone: "", msg: t("settings.status.loading-defaults") });
     try {
       const defaults = await api.getDefaultConfig();
       mutateConfig(defaults);
       setConfigDirty(true);
-      setStatus({ tone: "ok", msg: "loaded — click save to commit." });
+      setStatus({ tone: "ok", msg: t("settings.status.defaults-loaded") });
     } catch (e) {
-      setStatus({ tone: "err", msg: `err: ${e}` });
+      setStatus({ tone: "err", msg: t("common.error", { error: String(e) }) });
     }
   };
 
   return (
     <>
       <div class="view-head">
-        <h1>settings</h1>
+        <h1>{t("settings.title")}</h1>
         <span class="lede">
           <code>%appdata%\capscr\config.toml</code>
         </span>
       </div>
 
-      <nav class="subnav" role="tablist">
+      <div class="row" style="margin-bottom: 12px;">
+        <label class="history-search">
+          <Search size={11} stroke-width={1.5} />
+          <input
+            type="text"
+            placeholder={t("settings.search")}
+            value={query()}
+            onInput={(e) => setQuery(e.currentTarget.value)}
+            onKeyDown={(e) => {
+              if (e.key === "Enter" && results().length > 0) jumpTo(results()[0]);
+              if (e.key === "Escape") setQuery("");
+            }}
+            disabled={!config()}
+          />
+          <Show when={query()}>
+            <button
+              type="button"
+              class="search-clear"
+              title={t("common.clear")}
+              onClick={() => setQuery("")}
+            >
+              <X size={10} stroke-width={1.5} />
+            </button>
+          </Show>
+        </label>
+      </div>
+
+      <Show when={query().trim()}>
+        <div class="settings-results">
+          <Show
+            when={results().length > 0}
+            fallback={<p class="lede">{t("settings.no-match", { query: query().trim() })}</p>}
+          >
+            <For each={results()}>
+              {(s) => (
+                <button type="button" class="settings-result" onClick={() => jumpTo(s)}>
+                  <span class="settings-result-label">{t(s.label)}</span>
+                  <span class="settings-result-path">
+                    {t(`settings.pane.${s.pane}`)} › {t(s.section)}
+                  </span>
+                </button>
+              )}
+            </For>
+          </Show>
+        </div>
+      </Show>
+
+      <nav
+        class="subnav"
+        role="tablist"
+        hidden={!!query().trim()}
+        onKeyDown={(ev) => arrowNav(ev, "horizontal", true)}
+      >
         <For each={PANES()}>
           {(p) => (
             <button
@@ -85,10 +181,12 @@ export function Settings() {
               role="tab"
               class="subnav-item"
               classList={{ "is-active": pane() === p.id }}
+              aria-selected={pane() === p.id}
+              tabIndex={pane() === p.id ? 0 : -1}
               onClick={() => setPane(p.id)}
               disabled={!config()}
             >
-              {p.label}
+              {t(`settings.pane.${p.id}`)}
             </button>
           )}
         </For>
@@ -107,7 +205,7 @@ export function Settings() {
         }
       >
         {(c) => (
-          <>
+          <Show when={!query().trim()}>
 
             <Switch>
               <Match when={pane() === "general"}>
@@ -142,23 +240,23 @@ export function Settings() {
                 data-variant="ghost"
                 onClick={resetDefaults}
                 disabled={saving()}
-                title="restore every setting to its default"
+                title={t("settings.reset.title")}
               >
                 <RotateCcw size={12} stroke-width={1.5} />
-                reset
+                {t("settings.reset")}
               </button>
               <button
                 class="btn"
                 data-variant={configDirty() ? "primary" : undefined}
                 onClick={save}
                 disabled={saving() || !configDirty()}
-                title={configDirty() ? "commit pending changes" : "no changes to save"}
+                title={configDirty() ? t("common.save.title") : t("common.save.clean")}
               >
                 <Save size={12} stroke-width={1.5} />
-                {saving() ? "saving..." : "save"}
+                {saving() ? t("settings.saving") : t("settings.save")}
               </button>
             </div>
-          </>
+          </Show>
         )}
       </Show>
     </>
@@ -167,6 +265,8 @@ export function Settings() {
 
 type Patch = <K extends keyof AppConfig>(key: K, value: AppConfig[K]) => void;
 
+const UI_SCALES = [80, 90, 100, 110, 125, 150];
+
 function GeneralPane(props: { c: AppConfig; patch: Patch }) {
   const c = () => props.c;
   const pickDirectory = async () => {
@@ -174,17 +274,59 @@ function GeneralPane(props: { c: AppConfig; patch: Patch }) {
       directory: true,
       multiple: false,
       defaultPath: c().output.directory,
-      title: "Pick output directory",
+      title: t("settings.pick.output"),
     });
     if (typeof picked === "string" && picked.length > 0) {
       props.patch("output", { ...c().output, directory: picked });
     }
   };
+  const pickScratch = async () => {
+    const picked = await openDialog({
+      directory: true,
+      multiple: false,
+      defaultPath: c().output.scratch_directory ?? undefined,
+      title: t("settings.pick.scratch"),
+    });
+    if (typeof picked === "string" && picked.length > 0) {
+      props.patch("output", { ...c().output, scratch_directory: picked });
+    }
+  };
+  const pickArchive = async () => {
+    const picked = await openDialog({
+      directory: true,
+      multiple: false,
+      defaultPath: c().output.archive_directory ?? c().output.directory,
+      title: t("settings.pick.archive"),
+    });
+    if (typeof picked === "string" && picked.length > 0) {
+      props.patch("output", { ...c().output, archive_directory: picked });
+    }
+  };
+  const deco = () => c().output.decoration;
+  const patchDeco = (next: Partial<AppConfig["output"]["decoration"]>) =>
+    props.patch("output", { ...c().output, decoration: { ...deco(), ...next } });
+  const retention = (): RetentionPolicy =>
+    c().output.retention ?? { max_files: 0, max_size_mb: 0, move_to: null };
+  const patchRetention = (next: Partial<RetentionPolicy>) =>
+    props.patch("output", { ...c().output, retention: { ...retention(), ...next } });
+  const profiles = () => c().output.app_profiles ?? [];
+  const patchProfiles = (app_profiles: AppProfile[]) =>
+    props.patch("output", { ...c().output, app_profiles });
+  const patchProfile = (index: number, next: Partial<AppProfile>) =>
+    patchProfiles(profiles().map((p, j) => (j === index ? { ...p, ...next } : p)));
+  // a profile's limits move or delete the way the output folder's do
+  const patchProfileRetention = (index: number, next: Partial<RetentionPolicy>) => {
+    const current = profiles()[index].retention ?? { max_files: 0, max_size_mb: 0 };
+    const policy = { ...current, ...next, move_to: retention().move_to ?? null };
+    patchProfile(index, {
+      retention: policy.max_files || policy.max_size_mb ? policy : null,
+    });
+  };
   return (
     <>
-    <Section title="output">
+    <Section title={t("settings.section.output")}>
       <div class="field">
-        <label class="field-label">directory</label>
+        <label class="field-label">{t("settings.field.directory")}</label>
         <div class="field-control">
           <div class="input-row">
             <input
@@ -200,17 +342,17 @@ function GeneralPane(props: { c: AppConfig; patch: Patch }) {
               data-variant="ghost"
               data-size="xs"
               onClick={pickDirectory}
-              title="browse for folder"
+              title={t("settings.browse.title")}
             >
               <FolderOpen size={11} stroke-width={1.5} />
-              browse
+              {t("common.browse")}
             </button>
           </div>
-          <span class="field-hint">absolute path or %env% template</span>
+          <span class="field-hint">{t("settings.hint.directory")}</span>
         </div>
       </div>
       <div class="field">
-        <label class="field-label">filename template</label>
+        <label class="field-label">{t("settings.field.filename")}</label>
         <div class="field-control">
           <input
             type="text"
@@ -222,11 +364,11 @@ function GeneralPane(props: { c: AppConfig; patch: Patch }) {
               })
             }
           />
-          <span class="field-hint">chrono tokens: %Y year · %m month · %d day · %H hour · %M min · %S sec · extension added automatically</span>
+          <span class="field-hint">{t("settings.hint.filename")}</span>
         </div>
       </div>
       <div class="field">
-        <label class="field-label">format</label>
+        <label class="field-label">{t("settings.field.format")}</label>
         <div class="field-control">
           <select
             value={c().output.format}
@@ -247,7 +389,7 @@ function GeneralPane(props: { c: AppConfig; patch: Patch }) {
         </div>
       </div>
       <div class="field">
-        <label class="field-label">quality</label>
+        <label class="field-label">{t("settings.field.quality")}</label>
         <div class="field-control">
           <input
             type="number"
@@ -266,250 +408,1521 @@ function GeneralPane(props: { c: AppConfig; patch: Patch }) {
               })
             }
           />
-          <span class="field-hint">1-100, ignored for png/bmp</span>
+          <span class="field-hint">{t("settings.hint.quality")}</span>
         </div>
       </div>
-    </Section>
-    <Show when={IS_LINUX}>
-      <GnomeCompanionSection />
-    </Show>
-    </>
-  );
-}
-
-// gnome withholds the window list, keep-above, and the tray from ordinary
-// apps; the bundled shell extension restores all three. only rendered on a
-// gnome session.
-function GnomeCompanionSection() {
-  const [status, { refetch }] = createResource(api.gnomeCompanionStatus);
-  const [installing, setInstalling] = createSignal(false);
-  const [err, setErr] = createSignal<string | null>(null);
-
-  const install = async () => {
-    setInstalling(true);
-    setErr(null);
-    try {
-      await api.installGnomeCompanion();
-      await refetch();
-    } catch (e) {
-      setErr(String(e));
-    } finally {
-      setInstalling(false);
-    }
-  };
-
-  return (
-    <Show when={status()?.on_gnome}>
-      <Section title="gnome integration">
-        <div class="field">
-          <label class="field-label">companion extension</label>
-          <div class="field-control">
-            <Switch>
-              <Match when={status()?.active}>
-                <span class="field-hint">
-                  active — window picking, pins above fullscreen, and the
-                  top-bar capture menu are handled by the shell extension
-                </span>
-              </Match>
-              <Match when={status()?.installed}>
-                <span class="field-hint">
-                  installed — log out and back in to activate it
-                </span>
-              </Match>
-              <Match when={true}>
-                <div class="input-row">
-                  <button
-                    class="btn"
-                    data-variant="ghost"
-                    disabled={installing()}
-                    onClick={install}
-                  >
-                    {installing() ? "installing…" : "install"}
-                  </button>
-                </div>
-                <span class="field-hint">
-                  without it, window capture uses gnome's own picker dialog,
-                  and pinned screenshots can't stay above fullscreen windows
-                </span>
-              </Match>
-            </Switch>
-            <Show when={err()}>
-              <p class="flash" data-tone="warn">{err()}</p>
-            </Show>
-          </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.editor-projects")}</label>
+        <div class="field-control">
+          <label class="check">
+            <input
+              type="checkbox"
+              checked={c().output.editor_projects}
+              onChange={(e) =>
+                props.patch("output", { ...c().output, editor_projects: e.currentTarget.checked })
+              }
+            />
+            <span class="check-label">{t("settings.check.editor-projects")}</span>
+          </label>
+          <span class="field-hint">
+            {t("settings.hint.editor-projects")}
+          </span>
         </div>
-      </Section>
-    </Show>
-  );
-}
-
-function CapturePane(props: { c: AppConfig; patch: Patch }) {
-  const c = () => props.c;
-  return (
-    <>
-      <Section title="cursor">
-        <div class="field">
-          <label class="field-label">show cursor</label>
-          <div class="field-control">
-            <label class="check">
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.archive")}</label>
+        <div class="field-control">
+          <label class="check">
+            <input
+              type="checkbox"
+              checked={c().output.archive_originals ?? false}
+              onChange={(e) =>
+                props.patch("output", { ...c().output, archive_originals: e.currentTarget.checked })
+              }
+            />
+            <span class="check-label">{t("settings.check.archive")}</span>
+          </label>
+          <Show when={c().output.archive_originals}>
+            <div class="input-row">
               <input
-                type="checkbox"
-                checked={c().capture.show_cursor}
-                onChange={(e) =>
-                  props.patch("capture", {
-                    ...c().capture,
-                    show_cursor: e.currentTarget.checked,
+                type="text"
+                value={c().output.archive_directory ?? ""}
+                placeholder={t("settings.placeholder.archive")}
+                onInput={(e) =>
+                  props.patch("output", {
+                    ...c().output,
+                    archive_directory: e.currentTarget.value.trim() || null,
                   })
                 }
               />
-              <span class="check-label">
-                {c().capture.show_cursor ? "captured" : "hidden"}
-              </span>
-            </label>
-            <span class="field-hint">applies to screenshots and recordings</span>
+              <button
+                type="button"
+                class="btn"
+                data-variant="ghost"
+                data-size="xs"
+                onClick={pickArchive}
+                title={t("settings.browse.title")}
+              >
+                <FolderOpen size={11} stroke-width={1.5} />
+                {t("common.browse")}
+              </button>
+            </div>
+          </Show>
+          <span class="field-hint">
+            {t("settings.hint.archive")}
+          </span>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.scratch")}</label>
+        <div class="field-control">
+          <div class="input-row">
+            <input
+              type="text"
+              value={c().output.scratch_directory ?? ""}
+              placeholder={t("settings.placeholder.scratch")}
+              onInput={(e) =>
+                props.patch("output", {
+                  ...c().output,
+                  scratch_directory: e.currentTarget.value.trim() || null,
+                })
+              }
+            />
+            <button
+              type="button"
+              class="btn"
+              data-variant="ghost"
+              data-size="xs"
+              onClick={pickScratch}
+              title={t("settings.browse.title")}
+            >
+              <FolderOpen size={11} stroke-width={1.5} />
+              {t("common.browse")}
+            </button>
           </div>
+          <span class="field-hint">
+            {t("settings.hint.scratch")}
+          </span>
         </div>
-      </Section>
-
-      <Section title="timing">
-        <div class="field">
-          <label class="field-label">pre-capture delay</label>
-          <div class="field-control">
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.scratch-cap")}</label>
+        <div class="field-control">
+          <input
+            type="number"
+            min={0}
+            max={65536}
+            value={c().output.scratch_cap_mb}
+            onChange={(e) =>
+              props.patch("output", {
+                ...c().output,
+                scratch_cap_mb: commitNumber(e.currentTarget, { min: 0, max: 65536, fallback: c().output.scratch_cap_mb, int: true }),
+              })
+            }
+          />
+          <span class="field-hint">{t("settings.hint.scratch-cap")}</span>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.retention")}</label>
+        <div class="field-control">
+          <div class="input-row">
             <input
               type="number"
               min={0}
-              max={5000}
-              step={100}
-              value={c().capture.delay_ms}
-              onInput={(e) => {
-                const v = parseInt(e.currentTarget.value || "0");
-                if (!isNaN(v) && v >= 0 && v <= 5000)
-                  props.patch("capture", { ...c().capture, delay_ms: v });
-              }}
+              aria-label={t("settings.retention.files-kept")}
+              value={retention().max_files}
               onChange={(e) =>
-                props.patch("capture", {
-                  ...c().capture,
-                  delay_ms: commitNumber(e.currentTarget, { min: 0, max: 5000, fallback: c().capture.delay_ms, int: true }),
+                patchRetention({
+                  max_files: commitNumber(e.currentTarget, { min: 0, max: 1000000, fallback: retention().max_files, int: true }),
                 })
               }
             />
-            <span class="field-hint">ms before grabbing pixels — useful for tooltips / menus (0 = instant)</span>
-          </div>
-        </div>
-      </Section>
-
-      <Section title="recording (gif + mp4)">
-        <div class="field">
-          <label class="field-label">gif frame rate</label>
-          <div class="field-control">
+            <span class="field-hint">{t("settings.retention.files")}</span>
             <input
               type="number"
-              min={1}
-              max={60}
-              value={c().capture.gif_fps}
-              onInput={(e) => {
-                const v = parseInt(e.currentTarget.value || "15");
-                if (!isNaN(v) && v >= 1 && v <= 60)
-                  props.patch("capture", { ...c().capture, gif_fps: v });
-              }}
+              min={0}
+              aria-label={t("settings.retention.mib-kept")}
+              value={retention().max_size_mb}
               onChange={(e) =>
-                props.patch("capture", {
-                  ...c().capture,
-                  gif_fps: commitNumber(e.currentTarget, { min: 1, max: 60, fallback: c().capture.gif_fps, int: true }),
+                patchRetention({
+                  max_size_mb: commitNumber(e.currentTarget, { min: 0, max: 4194304, fallback: retention().max_size_mb, int: true }),
                 })
               }
             />
-            <span class="field-hint">fps, 1-60</span>
+            <span class="field-hint">MiB</span>
+            <input
+              type="text"
+              placeholder={t("common.delete")}
+              aria-label={t("settings.retention.move-to")}
+              value={retention().move_to ?? ""}
+              onInput={(e) => patchRetention({ move_to: e.currentTarget.value.trim() || null })}
+            />
           </div>
+          <span class="field-hint">
+            {t("settings.hint.retention")}
+          </span>
         </div>
-        <div class="field">
-          <label class="field-label">video frame rate</label>
-          <div class="field-control">
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.metadata")}</label>
+        <div class="field-control">
+          <select
+            value={c().output.metadata}
+            onChange={(e) =>
+              props.patch("output", {
+                ...c().output,
+                metadata: e.currentTarget.value as AppConfig["output"]["metadata"],
+              })
+            }
+          >
+            <option value="keep">{t("settings.metadata.keep")}</option>
+            <option value="strip">{t("settings.metadata.strip")}</option>
+            <option value="embed">{t("settings.metadata.embed")}</option>
+          </select>
+          <span class="field-hint">
+            {t("settings.hint.metadata")}
+          </span>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.tags")}</label>
+        <div class="field-control">
+          <label class="check">
             <input
-              type="number"
-              min={1}
-              max={60}
-              value={c().capture.video_fps}
+              type="checkbox"
+              checked={c().output.tags_in_metadata ?? false}
+              onChange={(e) =>
+                props.patch("output", { ...c().output, tags_in_metadata: e.currentTarget.checked })
+              }
+            />
+            <span class="check-label">{t("settings.check.tags")}</span>
+          </label>
+          <span class="field-hint">
+            {t("settings.hint.tags")}
+          </span>
+        </div>
+      </div>
+      <ResizeField
+        value={c().output.resize}
+        onChange={(resize) => props.patch("output", { ...c().output, resize })}
+        hint={t("settings.hint.resize")}
+      />
+    </Section>
+    <Section title={t("settings.section.per-app")}>
+      <div class="field">
+        <label class="field-label">{t("settings.field.profiles")}</label>
+        <div class="field-control">
+          <For each={profiles()}>
+            {(profile, i) => (
+              <div class="row">
+                <input
+                  type="text"
+                  placeholder="figma.exe"
+                  value={profile.app}
+                  onChange={(e) => patchProfile(i(), { app: e.currentTarget.value.trim() })}
+                />
+                <select
+                  value={profile.format ?? ""}
+                  onChange={(e) =>
+                    patchProfile(i(), {
+                      format: (e.currentTarget.value || null) as AppProfile["format"],
+                    })
+                  }
+                >
+                  <option value="">{t("settings.profiles.format")}</option>
+                  <option value="Png">png</option>
+                  <option value="Jpeg">jpeg</option>
+                  <option value="Webp">webp</option>
+                  <option value="Bmp">bmp</option>
+                  <option value="Avif">avif</option>
+                  <option value="Jxl">jpeg xl</option>
+                </select>
+                <select
+                  value={profile.post_action ?? ""}
+                  onChange={(e) =>
+                    patchProfile(i(), {
+                      post_action: (e.currentTarget.value || null) as AppProfile["post_action"],
+                    })
+                  }
+                >
+                  <option value="">{t("settings.profiles.action")}</option>
+                  <For each={POST_ACTIONS}>{(a) => <option value={a.id}>{t(a.label)}</option>}</For>
+                </select>
+                <input
+                  type="text"
+                  placeholder={t("settings.profiles.subfolder")}
+                  value={profile.subfolder}
+                  onChange={(e) => patchProfile(i(), { subfolder: e.currentTarget.value.trim() })}
+                />
+                <Show when={profile.subfolder}>
+                  <input
+                    type="number"
+                    min={0}
+                    title={t("settings.profiles.files")}
+                    aria-label={t("settings.retention.files-kept")}
+                    value={profile.retention?.max_files ?? 0}
+                    onChange={(e) =>
+                      patchProfileRetention(i(), {
+                        max_files: commitNumber(e.currentTarget, { min: 0, max: 1000000, fallback: 0, int: true }),
+                      })
+                    }
+                  />
+                  <input
+                    type="number"
+                    min={0}
+                    title={t("settings.profiles.mib")}
+                    aria-label={t("settings.retention.mib-kept")}
+                    value={profile.retention?.max_size_mb ?? 0}
+                    onChange={(e) =>
+                      patchProfileRetention(i(), {
+                        max_size_mb: commitNumber(e.currentTarget, { min: 0, max: 4194304, fallback: 0, int: true }),
+                      })
+                    }
+                  />
+                </Show>
+                <button
+                  class="icon-btn"
+                  title={t("common.remove")}
+                  aria-label={t("common.remove")}
+                  onClick={() => patchProfiles(profiles().filter((_, j) => j !== i()))}
+                >
+                  <X size={12} stroke-width={1.5} />
+                </button>
+              </div>
+            )}
+          </For>
+          <button
+            class="btn"
+            data-variant="ghost"
+            onClick={() =>
+              patchProfiles([
+                ...profiles(),
+                { app: "", format: null, post_action: null, subfolder: "" },
+              ])
+            }
+          >
+            {t("settings.profiles.add")}
+          </button>
+          <span class="field-hint">
+            {t("settings.hint.profiles")}
+          </span>
+        </div>
+      </div>
+    </Section>
+    <Section title={t("settings.section.decorations")}>
+      <div class="field">
+        <label class="field-label">{t("settings.field.border")}</label>
+        <div class="field-control">
+          <div class="input-row">
+            <input
+              type="number"
+              min={0}
+              max={256}
+              value={deco().border_width}
+              onChange={(e) =>
+                patchDeco({
+                  border_width: commitNumber(e.currentTarget, { min: 0, max: 256, fallback: deco().border_width, int: true }),
+                })
+              }
+            />
+            <input
+              type="color"
+              aria-label={t("settings.deco.border-color")}
+              value={deco().border_color}
+              onInput={(e) => patchDeco({ border_color: e.currentTarget.value })}
+            />
+          </div>
+          <span class="field-hint">{t("settings.hint.border")}</span>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.corners")}</label>
+        <div class="field-control">
+          <div class="input-row">
+            <input
+              type="number"
+              min={0}
+              max={512}
+              value={deco().corner_radius}
+              onChange={(e) =>
+                patchDeco({
+                  corner_radius: commitNumber(e.currentTarget, { min: 0, max: 512, fallback: deco().corner_radius, int: true }),
+                })
+              }
+            />
+            <label class="check">
+              <input
+                type="checkbox"
+                checked={deco().corner_background === ""}
+                onChange={(e) =>
+                  patchDeco({ corner_background: e.currentTarget.checked ? "" : "#ffffff" })
+                }
+              />
+              <span class="check-label">{t("settings.deco.transparent")}</span>
+            </label>
+            <Show when={deco().corner_background !== ""}>
+              <input
+                type="color"
+                aria-label={t("settings.deco.corner-background")}
+                value={deco().corner_background}
+                onInput={(e) => patchDeco({ corner_background: e.currentTarget.value })}
+              />
+            </Show>
+          </div>
+          <span class="field-hint">{t("settings.hint.corners")}</span>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.caption")}</label>
+        <div class="field-control">
+          <input
+            type="text"
+            maxLength={200}
+            placeholder={t("common.none")}
+            value={deco().caption}
+            onChange={(e) => patchDeco({ caption: e.currentTarget.value })}
+          />
+          <span class="field-hint">
+            {t("settings.hint.caption")}
+          </span>
+        </div>
+      </div>
+    </Section>
+    <Section title={t("settings.section.appearance")}>
+      <div class="field">
+        <label class="field-label">{t("settings.field.theme")}</label>
+        <div class="field-control">
+          <select
+            value={c().ui.theme}
+            onChange={(e) =>
+              props.patch("ui", {
+                ...c().ui,
+                theme: e.currentTarget.value as AppConfig["ui"]["theme"],
+              })
+            }
+          >
+            <For each={THEMES}>{(th) => <option value={th.id}>{t(th.label)}</option>}</For>
+          </select>
+          <span class="field-hint">{t("settings.hint.theme")}</span>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.accent")}</label>
+        <div class="field-control">
+          <div class="accent-swatches" role="radiogroup" aria-label={t("settings.field.accent")}>
+            <For each={ACCENTS}>
+              {(a) => (
+                <button
+                  type="button"
+                  class="accent-swatch"
+                  classList={{ "is-active": c().ui.accent === a.id }}
+                  style={{ background: a.color ?? "var(--paper)" }}
+                  role="radio"
+                  aria-checked={c().ui.accent === a.id}
+                  aria-label={t(a.label)}
+                  title={t(a.label)}
+                  onClick={() => props.patch("ui", { ...c().ui, accent: a.id })}
+                />
+              )}
+            </For>
+            <input
+              type="color"
+              title={t("settings.accent.custom")}
+              aria-label={t("settings.accent.custom-label")}
+              classList={{ "is-active": c().ui.accent === "custom" }}
+              value={c().ui.accent_color}
+              onInput={(e) =>
+                props.patch("ui", {
+                  ...c().ui,
+                  accent: "custom",
+                  accent_color: e.currentTarget.value,
+                })
+              }
+            />
+          </div>
+          <span class="field-hint">{t("settings.hint.accent")}</span>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.scale")}</label>
+        <div class="field-control">
+          <select
+            value={c().ui.scale_percent}
+            onChange={(e) =>
+              props.patch("ui", { ...c().ui, scale_percent: parseInt(e.currentTarget.value) })
+            }
+          >
+            <For each={UI_SCALES}>{(pct) => <option value={pct}>{pct}%</option>}</For>
+          </select>
+          <span class="field-hint">{t("settings.hint.scale")}</span>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.compact")}</label>
+        <div class="field-control">
+          <label class="check">
+            <input
+              type="checkbox"
+              checked={c().ui.compact}
+              onChange={(e) => props.patch("ui", { ...c().ui, compact: e.currentTarget.checked })}
+            />
+            <span class="check-label">{t("settings.check.compact")}</span>
+          </label>
+          <span class="field-hint">{t("settings.hint.compact")}</span>
+        </div>
+      </div>
+    </Section>
+    <Show when={IS_LINUX}>
+      <GnomeCompanionSection />
+    </Show>
+    </>
+  );
+}
+
+// gnome withholds the window list, keep-above, and the tray from ordinary
+// apps; the bundled shell extension restores all three. only rendered on a
+// gnome session.
+function GnomeCompanionSection() {
+  const [status, { refetch }] = createResource(api.gnomeCompanionStatus);
+  const [installing, setInstalling] = createSignal(false);
+  const [err, setErr] = createSignal<string | null>(null);
+
+  const install = async () => {
+    setInstalling(true);
+    setErr(null);
+    try {
+      await api.installGnomeCompanion();
+      await refetch();
+    } catch (e) {
+      setErr(String(e));
+    } finally {
+      setInstalling(false);
+    }
+  };
+
+  return (
+    <Show when={status()?.on_gnome}>
+      <Section title={t("settings.section.gnome")}>
+        <div class="field">
+          <label class="field-label">{t("settings.field.companion")}</label>
+          <div class="field-control">
+            <Switch>
+              <Match when={status()?.active}>
+                <span class="field-hint">
+                  {t("settings.gnome.active")}
+                </span>
+              </Match>
+              <Match when={status()?.installed}>
+                <span class="field-hint">
+                  {t("settings.gnome.installed")}
+                </span>
+              </Match>
+              <Match when={true}>
+                <div class="input-row">
+                  <button
+                    class="btn"
+                    data-variant="ghost"
+                    disabled={installing()}
+                    onClick={install}
+                  >
+                    {installing() ? t("settings.gnome.installing") : t("settings.gnome.install")}
+                  </button>
+                </div>
+                <span class="field-hint">
+                  {t("settings.gnome.missing")}
+                </span>
+              </Match>
+            </Switch>
+            <Show when={err()}>
+              <p class="flash" data-tone="warn">{err()}</p>
+            </Show>
+          </div>
+        </div>
+      </Section>
+    </Show>
+  );
+}
+
+const CAPTURE_KINDS: { id: CaptureKind; label: MessageKey }[] = [
+  { id: "region", label: "settings.kind.region" },
+  { id: "window", label: "settings.kind.window" },
+  { id: "full-screen", label: "settings.kind.full-screen" },
+  { id: "gif", label: "settings.kind.gif" },
+];
+
+function CapturePane(props: { c: AppConfig; patch: Patch }) {
+  const c = () => props.c;
+  type GameMode = NonNullable<AppConfig["capture"]["game_mode"]>;
+  const gameMode = (): GameMode =>
+    c().capture.game_mode ?? { enabled: false, action: "none", apps: [] };
+  const patchGameMode = (next: Partial<GameMode>) =>
+    props.patch("capture", { ...c().capture, game_mode: { ...gameMode(), ...next } });
+  const patchGameRule = (index: number, next: Partial<GameMode["apps"][number]>) =>
+    patchGameMode({
+      apps: gameMode().apps.map((rule, j) => (j === index ? { ...rule, ...next } : rule)),
+    });
+  return (
+    <>
+      <Section title={t("settings.section.cursor")}>
+        <div class="field">
+          <label class="field-label">{t("settings.field.cursor")}</label>
+          <div class="field-control">
+            <label class="check">
+              <input
+                type="checkbox"
+                checked={c().capture.show_cursor}
+                onChange={(e) =>
+                  props.patch("capture", {
+                    ...c().capture,
+                    show_cursor: e.currentTarget.checked,
+                  })
+                }
+              />
+              <span class="check-label">
+                {c().capture.show_cursor ? t("settings.cursor.captured") : t("settings.cursor.hidden")}
+              </span>
+            </label>
+            <span class="field-hint">{t("settings.hint.cursor")}</span>
+          </div>
+        </div>
+      </Section>
+
+      <Section title={t("settings.section.overlay")}>
+        <div class="field">
+          <label class="field-label">{t("settings.field.monitors")}</label>
+          <div class="field-control">
+            <label class="check">
+              <input
+                type="checkbox"
+                checked={c().capture.selector_cursor_monitor ?? false}
+                onChange={(e) =>
+                  props.patch("capture", {
+                    ...c().capture,
+                    selector_cursor_monitor: e.currentTarget.checked,
+                  })
+                }
+              />
+              <span class="check-label">
+                {c().capture.selector_cursor_monitor ? t("settings.monitors.pointer") : t("settings.monitors.all")}
+              </span>
+            </label>
+            <span class="field-hint">
+              {t("settings.hint.monitors")}
+            </span>
+          </div>
+        </div>
+      </Section>
+
+      <Section title={t("settings.section.after")}>
+        <div class="field">
+          <label class="field-label">{t("settings.field.actions")}</label>
+          <div class="field-control">
+            <table class="diag-table action-matrix">
+              <thead>
+                <tr>
+                  <th />
+                  <For each={POST_ACTIONS}>{(a) => <th>{t(a.label)}</th>}</For>
+                </tr>
+              </thead>
+              <tbody>
+                <For each={CAPTURE_KINDS}>
+                  {(kind) => (
+                    <tr>
+                      <th scope="row">{t(kind.label)}</th>
+                      <For each={POST_ACTIONS}>
+                        {(a) => (
+                          <td>
+                            <Show when={kind.id !== "gif" || !STILL_ONLY_ACTIONS.includes(a.id)}>
+                              <input
+                                type="radio"
+                                name={`post-${kind.id}`}
+                                aria-label={`${t(kind.label)}: ${t(a.label)}`}
+                                checked={c().post_capture.actions[kind.id] === a.id}
+                                onChange={() =>
+                                  props.patch("post_capture", {
+                                    ...c().post_capture,
+                                    actions: { ...c().post_capture.actions, [kind.id]: a.id },
+                                  })
+                                }
+                              />
+                            </Show>
+                          </td>
+                        )}
+                      </For>
+                    </tr>
+                  )}
+                </For>
+              </tbody>
+            </table>
+            <span class="field-hint">
+              {t("settings.hint.actions")}
+            </span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.duplicates")}</label>
+          <div class="field-control">
+            <select
+              value={c().capture.duplicates ?? "warn"}
+              onChange={(e) =>
+                props.patch("capture", {
+                  ...c().capture,
+                  duplicates: e.currentTarget.value as never,
+                })
+              }
+            >
+              <option value="off">{t("settings.duplicates.off")}</option>
+              <option value="warn">{t("settings.duplicates.warn")}</option>
+              <option value="skip">{t("settings.duplicates.skip")}</option>
+            </select>
+            <span class="field-hint">
+              {t("settings.hint.duplicates")}
+            </span>
+          </div>
+        </div>
+      </Section>
+
+      <Show when={!IS_LINUX}>
+        <Section title={t("settings.section.window")}>
+          <div class="field">
+            <label class="field-label">{t("settings.field.frame")}</label>
+            <div class="field-control">
+              <select
+                value={c().capture.window_frame}
+                onChange={(e) =>
+                  props.patch("capture", {
+                    ...c().capture,
+                    window_frame: e.currentTarget.value as never,
+                  })
+                }
+              >
+                <option value="visible">{t("settings.frame.visible")}</option>
+                <option value="with-shadow">{t("settings.frame.shadow")}</option>
+                <option value="client-only">{t("settings.frame.client")}</option>
+              </select>
+              <span class="field-hint">{t("settings.hint.frame")}</span>
+            </div>
+          </div>
+          <div class="field">
+            <label class="field-label">{t("settings.field.corners")}</label>
+            <div class="field-control">
+              <label class="check">
+                <input
+                  type="checkbox"
+                  checked={c().capture.clean_window_corners}
+                  onChange={(e) =>
+                    props.patch("capture", {
+                      ...c().capture,
+                      clean_window_corners: e.currentTarget.checked,
+                    })
+                  }
+                />
+                <span class="check-label">
+                  {c().capture.clean_window_corners ? t("settings.deco.transparent") : t("settings.corners.left")}
+                </span>
+              </label>
+              <span class="field-hint">{t("settings.hint.window-corners")}</span>
+            </div>
+          </div>
+          <div class="field">
+            <label class="field-label">{t("settings.field.covered")}</label>
+            <div class="field-control">
+              <label class="check">
+                <input
+                  type="checkbox"
+                  checked={c().capture.capture_covered_windows}
+                  onChange={(e) =>
+                    props.patch("capture", {
+                      ...c().capture,
+                      capture_covered_windows: e.currentTarget.checked,
+                    })
+                  }
+                />
+                <span class="check-label">
+                  {c().capture.capture_covered_windows ? t("settings.covered.own") : t("settings.covered.screen")}
+                </span>
+              </label>
+              <span class="field-hint">{t("settings.hint.covered")}</span>
+            </div>
+          </div>
+          <div class="field">
+            <label class="field-label">{t("settings.field.backend")}</label>
+            <div class="field-control">
+              <select
+                value={c().capture.backend}
+                onChange={(e) =>
+                  props.patch("capture", {
+                    ...c().capture,
+                    backend: e.currentTarget.value as never,
+                  })
+                }
+              >
+                <option value="auto">{t("settings.backend.auto")}</option>
+                <option value="wgc">windows.graphics.capture</option>
+              </select>
+              <span class="field-hint">{t("settings.hint.backend")}</span>
+            </div>
+          </div>
+          <Show when={c().capture.backend === "wgc"}>
+            <div class="field">
+              <label class="field-label">{t("settings.field.wgc-border")}</label>
+              <div class="field-control">
+                <label class="check">
+                  <input
+                    type="checkbox"
+                    checked={c().capture.wgc_border}
+                    onChange={(e) =>
+                      props.patch("capture", {
+                        ...c().capture,
+                        wgc_border: e.currentTarget.checked,
+                      })
+                    }
+                  />
+                  <span class="check-label">
+                    {c().capture.wgc_border ? t("settings.wgc-border.shown") : t("settings.wgc-border.suppressed")}
+                  </span>
+                </label>
+                <span class="field-hint">{t("settings.hint.wgc-border")}</span>
+              </div>
+            </div>
+          </Show>
+          <div class="field">
+            <label class="field-label">{t("settings.field.ui-elements")}</label>
+            <div class="field-control">
+              <label class="check">
+                <input
+                  type="checkbox"
+                  checked={c().capture.detect_ui_elements}
+                  onChange={(e) =>
+                    props.patch("capture", {
+                      ...c().capture,
+                      detect_ui_elements: e.currentTarget.checked,
+                    })
+                  }
+                />
+                <span class="check-label">
+                  {c().capture.detect_ui_elements ? t("settings.ui-elements.on") : t("settings.ui-elements.off")}
+                </span>
+              </label>
+              <span class="field-hint">{t("settings.hint.ui-elements")}</span>
+            </div>
+          </div>
+        </Section>
+      </Show>
+
+      <Section title={t("settings.section.timing")}>
+        <div class="field">
+          <label class="field-label">{t("settings.field.delay")}</label>
+          <div class="field-control">
+            <input
+              type="number"
+              min={0}
+              max={5000}
+              step={100}
+              value={c().capture.delay_ms}
+              onInput={(e) => {
+                const v = parseInt(e.currentTarget.value || "0");
+                if (!isNaN(v) && v >= 0 && v <= 5000)
+                  props.patch("capture", { ...c().capture, delay_ms: v });
+              }}
+              onChange={(e) =>
+                props.patch("capture", {
+                  ...c().capture,
+                  delay_ms: commitNumber(e.currentTarget, { min: 0, max: 5000, fallback: c().capture.delay_ms, int: true }),
+                })
+              }
+            />
+            <span class="field-hint">{t("settings.hint.delay")}</span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.hide-hub")}</label>
+          <div class="field-control">
+            <label class="check">
+              <input
+                type="checkbox"
+                checked={c().capture.hide_hub_during_capture}
+                onChange={(e) =>
+                  props.patch("capture", {
+                    ...c().capture,
+                    hide_hub_during_capture: e.currentTarget.checked,
+                  })
+                }
+              />
+              <span class="check-label">
+                {c().capture.hide_hub_during_capture ? t("settings.hide-hub.on") : t("settings.hide-hub.off")}
+              </span>
+            </label>
+            <span class="field-hint">{t("settings.hint.hide-hub")}</span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.excluded")}</label>
+          <div class="field-control">
+            <input
+              type="text"
+              placeholder="keepassxc, 1password.exe"
+              value={c().capture.exclusion.apps.join(", ")}
+              onChange={(e) =>
+                props.patch("capture", {
+                  ...c().capture,
+                  exclusion: {
+                    ...c().capture.exclusion,
+                    apps: e.currentTarget.value
+                      .split(",")
+                      .map((app) => app.trim())
+                      .filter((app) => app.length > 0),
+                  },
+                })
+              }
+            />
+            <span class="field-hint">{t("settings.hint.excluded")}</span>
+          </div>
+        </div>
+        <Show when={c().capture.exclusion.apps.length > 0}>
+          <div class="field">
+            <label class="field-label">{t("settings.field.exclusion-mode")}</label>
+            <div class="field-control">
+              <select
+                value={c().capture.exclusion.mode}
+                onChange={(e) =>
+                  props.patch("capture", {
+                    ...c().capture,
+                    exclusion: {
+                      ...c().capture.exclusion,
+                      mode: e.currentTarget.value as never,
+                    },
+                  })
+                }
+              >
+                <option value="blackout">{t("settings.exclusion.blackout")}</option>
+                <option value="skip">{t("settings.exclusion.skip")}</option>
+              </select>
+              <span class="field-hint">{t("settings.hint.exclusion")}</span>
+            </div>
+          </div>
+        </Show>
+      </Section>
+
+      <Section title={t("settings.section.game")}>
+        <div class="field">
+          <label class="field-label">{t("settings.field.fullscreen")}</label>
+          <div class="field-control">
+            <select
+              value={gameMode().enabled ? gameMode().action : "off"}
+              onChange={(e) => {
+                const v = e.currentTarget.value;
+                patchGameMode(
+                  v === "off" ? { enabled: false } : { enabled: true, action: v as GameModeAction },
+                );
+              }}
+            >
+              <option value="off">{t("settings.game.off")}</option>
+              <option value="none">{t("settings.game.none")}</option>
+              <option value="suspend-hotkeys">{t("settings.game.suspend")}</option>
+              <Show when={!IS_LINUX}>
+                <option value="low-overhead">{t("settings.game.wgc")}</option>
+              </Show>
+            </select>
+            <span class="field-hint">
+              {t("settings.hint.game")}
+            </span>
+          </div>
+        </div>
+        <Show when={gameMode().enabled}>
+          <div class="field">
+            <label class="field-label">{t("settings.section.per-app")}</label>
+            <div class="field-control">
+              <For each={gameMode().apps}>
+                {(rule, i) => (
+                  <div class="row">
+                    <input
+                      type="text"
+                      placeholder="cs2.exe"
+                      value={rule.app}
+                      onChange={(e) => patchGameRule(i(), { app: e.currentTarget.value.trim() })}
+                    />
+                    <select
+                      value={rule.action}
+                      onChange={(e) =>
+                        patchGameRule(i(), { action: e.currentTarget.value as GameModeAction })
+                      }
+                    >
+                      <option value="none">{t("settings.game.rule.none")}</option>
+                      <option value="suspend-hotkeys">{t("settings.game.rule.suspend")}</option>
+                      <Show when={!IS_LINUX}>
+                        <option value="low-overhead">{t("settings.game.wgc")}</option>
+                      </Show>
+                    </select>
+                    <button
+                      class="icon-btn"
+                      title={t("common.remove")}
+                      aria-label={t("common.remove")}
+                      onClick={() =>
+                        patchGameMode({ apps: gameMode().apps.filter((_, j) => j !== i()) })
+                      }
+                    >
+                      <X size={12} stroke-width={1.5} />
+                    </button>
+                  </div>
+                )}
+              </For>
+              <button
+                class="btn"
+                data-variant="ghost"
+                onClick={() =>
+                  patchGameMode({
+                    apps: [...gameMode().apps, { app: "", action: "suspend-hotkeys" }],
+                  })
+                }
+              >
+                {t("settings.profiles.add")}
+              </button>
+              <span class="field-hint">
+                {t("settings.hint.game-apps")}
+              </span>
+            </div>
+          </div>
+        </Show>
+      </Section>
+
+      <Section title={t("settings.section.recording")}>
+        <div class="field">
+          <label class="field-label">{t("settings.field.gif-fps")}</label>
+          <div class="field-control">
+            <input
+              type="number"
+              min={1}
+              max={60}
+              value={c().capture.gif_fps}
+              onInput={(e) => {
+                const v = parseInt(e.currentTarget.value || "15");
+                if (!isNaN(v) && v >= 1 && v <= 60)
+                  props.patch("capture", { ...c().capture, gif_fps: v });
+              }}
+              onChange={(e) =>
+                props.patch("capture", {
+                  ...c().capture,
+                  gif_fps: commitNumber(e.currentTarget, { min: 1, max: 60, fallback: c().capture.gif_fps, int: true }),
+                })
+              }
+            />
+            <span class="field-hint">{t("settings.hint.gif-fps")}</span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.video-fps")}</label>
+          <div class="field-control">
+            <input
+              type="number"
+              min={1}
+              max={60}
+              value={c().capture.video_fps}
+              onInput={(e) => {
+                const v = parseInt(e.currentTarget.value || "30");
+                if (!isNaN(v) && v >= 1 && v <= 60)
+                  props.patch("capture", { ...c().capture, video_fps: v });
+              }}
+              onChange={(e) =>
+                props.patch("capture", {
+                  ...c().capture,
+                  video_fps: commitNumber(e.currentTarget, { min: 1, max: 60, fallback: c().capture.video_fps, int: true }),
+                })
+              }
+            />
+            <span class="field-hint">{t("settings.hint.video-fps")}</span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.video-quality")}</label>
+          <div class="field-control">
+            <select
+              value={c().capture.video_quality}
+              onChange={(e) =>
+                props.patch("capture", {
+                  ...c().capture,
+                  video_quality: e.currentTarget.value as never,
+                })
+              }
+            >
+              <option value="High">{t("settings.video.high")}</option>
+              <option value="Balanced">{t("settings.video.balanced")}</option>
+              <option value="Compact">{t("settings.video.compact")}</option>
+            </select>
+            <span class="field-hint">{t("settings.hint.video-quality")}</span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.max-duration")}</label>
+          <div class="field-control">
+            <input
+              type="number"
+              min={1}
+              max={300}
+              value={c().capture.gif_max_duration_secs}
               onInput={(e) => {
                 const v = parseInt(e.currentTarget.value || "30");
-                if (!isNaN(v) && v >= 1 && v <= 60)
-                  props.patch("capture", { ...c().capture, video_fps: v });
+                if (!isNaN(v) && v >= 1 && v <= 300)
+                  props.patch("capture", { ...c().capture, gif_max_duration_secs: v });
               }}
               onChange={(e) =>
-                props.patch("capture", {
-                  ...c().capture,
-                  video_fps: commitNumber(e.currentTarget, { min: 1, max: 60, fallback: c().capture.video_fps, int: true }),
-                })
+                props.patch("capture", {
+                  ...c().capture,
+                  gif_max_duration_secs: commitNumber(e.currentTarget, { min: 1, max: 300, fallback: c().capture.gif_max_duration_secs, int: true }),
+                })
+              }
+            />
+            <span class="field-hint">{t("settings.hint.max-duration")}</span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.audio")}</label>
+          <div class="field-control">
+            <label class="check">
+              <input
+                type="checkbox"
+                checked={c().capture.record_audio}
+                onChange={(e) =>
+                  props.patch("capture", {
+                    ...c().capture,
+                    record_audio: e.currentTarget.checked,
+                  })
+                }
+              />
+              <span class="check-label">
+                {c().capture.record_audio ? t("settings.toggle.enabled") : t("settings.toggle.disabled")}
+              </span>
+            </label>
+            <span class="field-hint">{t("settings.hint.audio")}</span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.review")}</label>
+          <div class="field-control">
+            <label class="check">
+              <input
+                type="checkbox"
+                checked={c().capture.review_recordings}
+                onChange={(e) =>
+                  props.patch("capture", {
+                    ...c().capture,
+                    review_recordings: e.currentTarget.checked,
+                  })
+                }
+              />
+              <span class="check-label">{t("settings.check.review")}</span>
+            </label>
+            <span class="field-hint">
+              {t("settings.hint.review")}
+            </span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.zoom-cursor")}</label>
+          <div class="field-control">
+            <label class="check">
+              <input
+                type="checkbox"
+                checked={c().capture.zoom.enabled}
+                onChange={(e) =>
+                  props.patch("capture", {
+                    ...c().capture,
+                    zoom: { ...c().capture.zoom, enabled: e.currentTarget.checked },
+                  })
+                }
+              />
+              <span class="check-label">{t("settings.check.zoom")}</span>
+            </label>
+            <span class="field-hint">
+              {t("settings.hint.zoom-cursor")}
+            </span>
+          </div>
+        </div>
+        <Show when={c().capture.zoom.enabled}>
+          <div class="field">
+            <label class="field-label">{t("settings.field.zoom")}</label>
+            <div class="field-control">
+              <input
+                type="number"
+                min={125}
+                max={400}
+                step={25}
+                value={c().capture.zoom.zoom_percent}
+                onChange={(e) =>
+                  props.patch("capture", {
+                    ...c().capture,
+                    zoom: {
+                      ...c().capture.zoom,
+                      zoom_percent: commitNumber(e.currentTarget, { min: 125, max: 400, fallback: c().capture.zoom.zoom_percent, int: true }),
+                    },
+                  })
+                }
+              />
+              <span class="field-hint">{t("settings.hint.zoom")}</span>
+            </div>
+          </div>
+          <div class="field">
+            <label class="field-label">{t("settings.field.smoothing")}</label>
+            <div class="field-control">
+              <input
+                type="number"
+                min={0}
+                max={2000}
+                step={50}
+                value={c().capture.zoom.smoothing_ms}
+                onChange={(e) =>
+                  props.patch("capture", {
+                    ...c().capture,
+                    zoom: {
+                      ...c().capture.zoom,
+                      smoothing_ms: commitNumber(e.currentTarget, { min: 0, max: 2000, fallback: c().capture.zoom.smoothing_ms, int: true }),
+                    },
+                  })
+                }
+              />
+              <span class="field-hint">{t("settings.hint.smoothing")}</span>
+            </div>
+          </div>
+        </Show>
+        <div class="field">
+          <label class="field-label">{t("settings.field.stream")}</label>
+          <div class="field-control">
+            <input
+              type="password"
+              placeholder={
+                c().capture.stream.url_encrypted ? t("settings.stream.saved") : "rtmp://…"
+              }
+              value={c().capture.stream.url}
+              onInput={(e) =>
+                // a new url replaces the stored one rather than being ignored
+                // in favour of it on save
+                props.patch("capture", {
+                  ...c().capture,
+                  stream: { ...c().capture.stream, url: e.currentTarget.value, url_encrypted: "" },
+                })
+              }
+            />
+            <span class="field-hint">
+              {t("settings.hint.stream")}
+            </span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.bitrate")}</label>
+          <div class="field-control">
+            <input
+              type="number"
+              min={500}
+              max={50000}
+              step={500}
+              value={c().capture.stream.bitrate_kbps}
+              onChange={(e) =>
+                props.patch("capture", {
+                  ...c().capture,
+                  stream: {
+                    ...c().capture.stream,
+                    bitrate_kbps: commitNumber(e.currentTarget, { min: 500, max: 50000, fallback: c().capture.stream.bitrate_kbps, int: true }),
+                  },
+                })
+              }
+            />
+            <span class="field-hint">{t("settings.hint.bitrate")}</span>
+          </div>
+        </div>
+      </Section>
+
+      <AutoCaptureSection c={c()} patch={props.patch} />
+
+      <WatchFolderSection c={c()} patch={props.patch} />
+    </>
+  );
+}
+
+function AutoCaptureSection(props: { c: AppConfig; patch: Patch }) {
+  const c = () => props.c;
+  const auto = () => c().auto_capture;
+  const [running, { mutate: setRunning }] = createResource(() => api.autoCaptureRunning());
+  const [err, setErr] = createSignal<string | null>(null);
+  const [lapseFormat, setLapseFormat] = createSignal<"gif" | "mp4">("mp4");
+  const [lapseFps, setLapseFps] = createSignal(24);
+  const [lapseWidth, setLapseWidth] = createSignal(1920);
+  const [lapseBusy, setLapseBusy] = createSignal(false);
+  const [lapseResult, setLapseResult] = createSignal<{ tone: string; msg: string } | null>(null);
+  const assemble = async () => {
+    setLapseBusy(true);
+    setLapseResult(null);
+    try {
+      const path = await api.assembleTimelapse(lapseFormat(), lapseFps(), lapseWidth());
+      setLapseResult({ tone: "ok", msg: t("settings.auto.saved", { path }) });
+    } catch (e) {
+      setLapseResult({ tone: "warn", msg: String(e) });
+    } finally {
+      setLapseBusy(false);
+    }
+  };
+  const unlistenPromise = listen<boolean>("capscr://auto-capture", (e) => setRunning(e.payload));
+  onCleanup(() => {
+    unlistenPromise.then((un) => un());
+  });
+  const toggle = async () => {
+    setErr(null);
+    try {
+      await api.setAutoCaptureRunning(!running());
+    } catch (e) {
+      setErr(String(e));
+    }
+  };
+  const pickDirectory = async () => {
+    const picked = await openDialog({
+      directory: true,
+      multiple: false,
+      defaultPath: auto().directory || c().output.directory,
+      title: t("settings.pick.auto"),
+    });
+    if (typeof picked === "string" && picked.length > 0) {
+      props.patch("auto_capture", { ...auto(), directory: picked });
+    }
+  };
+  return (
+    <Section title={t("settings.section.auto")}>
+      <div class="field">
+        <label class="field-label">{t("settings.field.status")}</label>
+        <div class="field-control">
+          <div class="input-row">
+            <button type="button" class="btn" data-size="xs" onClick={toggle}>
+              {running() ? t("settings.auto.stop") : t("settings.auto.start")}
+            </button>
+            <span class="check-label">{running() ? t("settings.auto.capturing") : t("settings.auto.stopped")}</span>
+          </div>
+          <span class="field-hint">{t("settings.hint.auto-status")}</span>
+          <Show when={err()}>
+            <p class="flash" data-tone="warn">{err()}</p>
+          </Show>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.interval")}</label>
+        <div class="field-control">
+          <input
+            type="number"
+            min={5}
+            max={86400}
+            value={auto().interval_secs}
+            onChange={(e) =>
+              props.patch("auto_capture", {
+                ...auto(),
+                interval_secs: commitNumber(e.currentTarget, { min: 5, max: 86400, fallback: auto().interval_secs, int: true }),
+              })
+            }
+          />
+          <span class="field-hint">{t("settings.hint.interval")}</span>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.capture")}</label>
+        <div class="field-control">
+          <select
+            value={auto().target}
+            onChange={(e) =>
+              props.patch("auto_capture", {
+                ...auto(),
+                target: e.currentTarget.value as AppConfig["auto_capture"]["target"],
+              })
+            }
+          >
+            <option value="active-monitor">{t("settings.auto.active")}</option>
+            <option value="all-monitors">{t("settings.auto.all")}</option>
+          </select>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.folder")}</label>
+        <div class="field-control">
+          <div class="input-row">
+            <input
+              type="text"
+              value={auto().directory}
+              placeholder={t("settings.placeholder.auto")}
+              onInput={(e) =>
+                props.patch("auto_capture", { ...auto(), directory: e.currentTarget.value })
+              }
+            />
+            <button
+              type="button"
+              class="btn"
+              data-variant="ghost"
+              data-size="xs"
+              onClick={pickDirectory}
+              title={t("settings.browse.title")}
+            >
+              <FolderOpen size={11} stroke-width={1.5} />
+              {t("common.browse")}
+            </button>
+          </div>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.keep")}</label>
+        <div class="field-control">
+          <input
+            type="number"
+            min={0}
+            value={auto().max_count}
+            onChange={(e) =>
+              props.patch("auto_capture", {
+                ...auto(),
+                max_count: commitNumber(e.currentTarget, { min: 0, max: 1_000_000, fallback: auto().max_count, int: true }),
+              })
+            }
+          />
+          <span class="field-hint">{t("settings.hint.keep")}</span>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.quota")}</label>
+        <div class="field-control">
+          <input
+            type="number"
+            min={0}
+            value={auto().max_disk_mb}
+            onChange={(e) =>
+              props.patch("auto_capture", {
+                ...auto(),
+                max_disk_mb: commitNumber(e.currentTarget, { min: 0, max: 1_000_000, fallback: auto().max_disk_mb, int: true }),
+              })
+            }
+          />
+          <span class="field-hint">{t("settings.hint.quota")}</span>
+        </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.lapse")}</label>
+        <div class="field-control">
+          <div class="input-row">
+            <select
+              value={lapseFormat()}
+              onChange={(e) => setLapseFormat(e.currentTarget.value as "gif" | "mp4")}
+            >
+              <option value="mp4">mp4</option>
+              <option value="gif">gif</option>
+            </select>
+            <input
+              type="number"
+              min={1}
+              max={60}
+              value={lapseFps()}
+              title={t("settings.lapse.fps")}
+              onChange={(e) =>
+                setLapseFps(commitNumber(e.currentTarget, { min: 1, max: 60, fallback: lapseFps(), int: true }))
+              }
+            />
+            <input
+              type="number"
+              min={0}
+              max={7680}
+              value={lapseWidth()}
+              title={t("settings.lapse.width")}
+              onChange={(e) =>
+                setLapseWidth(commitNumber(e.currentTarget, { min: 0, max: 7680, fallback: lapseWidth(), int: true }))
               }
             />
-            <span class="field-hint">fps for mp4 recordings, 1-60</span>
+            <button type="button" class="btn" data-size="xs" onClick={assemble} disabled={lapseBusy()}>
+              {lapseBusy() ? t("settings.lapse.assembling") : t("settings.lapse.assemble")}
+            </button>
           </div>
+          <span class="field-hint">{t("settings.hint.lapse")}</span>
+          <Show when={lapseResult()}>
+            <p class="flash" data-tone={lapseResult()!.tone}>{lapseResult()!.msg}</p>
+          </Show>
         </div>
-        <div class="field">
-          <label class="field-label">video quality</label>
-          <div class="field-control">
-            <select
-              value={c().capture.video_quality}
+      </div>
+    </Section>
+  );
+}
+
+function WatchFolderSection(props: { c: AppConfig; patch: Patch }) {
+  const watch = () => props.c.watch_folder;
+  const pickDirectory = async () => {
+    const picked = await openDialog({
+      directory: true,
+      multiple: false,
+      defaultPath: watch().directory || undefined,
+      title: t("settings.pick.watch"),
+    });
+    if (typeof picked === "string" && picked.length > 0) {
+      props.patch("watch_folder", { ...watch(), directory: picked });
+    }
+  };
+  return (
+    <Section title={t("settings.section.watch")}>
+      <div class="field">
+        <label class="field-label">{t("settings.field.watch")}</label>
+        <div class="field-control">
+          <label class="check">
+            <input
+              type="checkbox"
+              checked={watch().enabled}
               onChange={(e) =>
-                props.patch("capture", {
-                  ...c().capture,
-                  video_quality: e.currentTarget.value as never,
-                })
+                props.patch("watch_folder", { ...watch(), enabled: e.currentTarget.checked })
               }
-            >
-              <option value="High">high — near-lossless, biggest files</option>
-              <option value="Balanced">balanced</option>
-              <option value="Compact">compact — smallest files</option>
-            </select>
-            <span class="field-hint">mp4 encode quality; gifs are unaffected</span>
-          </div>
+            />
+            <span class="check-label">{t("settings.check.watch")}</span>
+          </label>
+          <span class="field-hint">
+            {t("settings.hint.watch")}
+          </span>
         </div>
-        <div class="field">
-          <label class="field-label">max duration</label>
-          <div class="field-control">
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.folder")}</label>
+        <div class="field-control">
+          <div class="input-row">
             <input
-              type="number"
-              min={1}
-              max={300}
-              value={c().capture.gif_max_duration_secs}
-              onInput={(e) => {
-                const v = parseInt(e.currentTarget.value || "30");
-                if (!isNaN(v) && v >= 1 && v <= 300)
-                  props.patch("capture", { ...c().capture, gif_max_duration_secs: v });
-              }}
-              onChange={(e) =>
-                props.patch("capture", {
-                  ...c().capture,
-                  gif_max_duration_secs: commitNumber(e.currentTarget, { min: 1, max: 300, fallback: c().capture.gif_max_duration_secs, int: true }),
-                })
+              type="text"
+              value={watch().directory}
+              placeholder={t("settings.placeholder.watch")}
+              onInput={(e) =>
+                props.patch("watch_folder", { ...watch(), directory: e.currentTarget.value })
               }
             />
-            <span class="field-hint">seconds, 1-300 — the recording auto-stops and saves when reached</span>
+            <button
+              type="button"
+              class="btn"
+              data-variant="ghost"
+              data-size="xs"
+              onClick={pickDirectory}
+              title={t("settings.browse.title")}
+            >
+              <FolderOpen size={11} stroke-width={1.5} />
+              {t("common.browse")}
+            </button>
           </div>
         </div>
-        <div class="field">
-          <label class="field-label">record audio</label>
-          <div class="field-control">
-            <label class="check">
-              <input
-                type="checkbox"
-                checked={c().capture.record_audio}
-                onChange={(e) =>
-                  props.patch("capture", {
-                    ...c().capture,
-                    record_audio: e.currentTarget.checked,
-                  })
-                }
-              />
-              <span class="check-label">
-                {c().capture.record_audio ? "enabled" : "disabled"}
-              </span>
-            </label>
-            <span class="field-hint">captures system loopback audio for MP4 recordings</span>
-          </div>
+      </div>
+      <div class="field">
+        <label class="field-label">{t("settings.field.then")}</label>
+        <div class="field-control">
+          <select
+            value={watch().action}
+            onChange={(e) =>
+              props.patch("watch_folder", {
+                ...watch(),
+                action: e.currentTarget.value as AppConfig["watch_folder"]["action"],
+              })
+            }
+          >
+            <option value="upload">{t("settings.watch.upload")}</option>
+            <option value="clipboard">{t("settings.watch.clipboard")}</option>
+            <option value="save-file">{t("settings.watch.save")}</option>
+            <option value="save-and-clipboard">{t("settings.watch.save-copy")}</option>
+          </select>
+          <span class="field-hint">
+            {t("settings.hint.watch-action")}
+          </span>
         </div>
-      </Section>
-    </>
+      </div>
+    </Section>
   );
 }
 
@@ -517,9 +1930,30 @@ function HdrPane(props: { c: AppConfig; patch: Patch }) {
   const c = () => props.c;
   return (
     <>
-    <Section title="hdr png sidecar">
+    <Section title={t("settings.section.hdr-sidecar")}>
+      <div class="field">
+        <label class="field-label">{t("settings.field.container")}</label>
+        <div class="field-control">
+          <select
+            value={c().capture.hdr.container}
+            onChange={(e) =>
+              props.patch("capture", {
+                ...c().capture,
+                hdr: { ...c().capture.hdr, container: e.currentTarget.value as never },
+              })
+            }
+          >
+            <option value="png">{t("settings.hdr.png")}</option>
+            <option value="avif">{t("settings.hdr.avif")}</option>
+            <option value="jxr">{t("settings.hdr.jxr")}</option>
+          </select>
+          <span class="field-hint">
+            {t("settings.hint.container")}
+          </span>
+        </div>
+      </div>
       <div class="field">
-        <label class="field-label">output format</label>
+        <label class="field-label">{t("settings.field.output-format")}</label>
         <div class="field-control">
           <select
             value={c().capture.hdr.output_format}
@@ -530,19 +1964,41 @@ function HdrPane(props: { c: AppConfig; patch: Patch }) {
               })
             }
           >
-            <option value="pq">pq (bt.2020, hdr10-style — default)</option>
-            <option value="hlg">hlg (bt.2020, broadcast-style)</option>
+            <option value="pq">{t("settings.hdr.pq")}</option>
+            <option value="hlg">{t("settings.hdr.hlg")}</option>
           </select>
           <span class="field-hint">
-            pq is the safest for windows photos / edge. hlg is transcoded from
-            the pq source by decoding to nits then applying hlg oetf.
+            {t("settings.hint.output-format")}
           </span>
         </div>
       </div>
     </Section>
-    <Section title="hdr tonemap">
+    <Section title={t("settings.section.hdr-tonemap")}>
+      <div class="field">
+        <label class="field-label">{t("settings.field.operator")}</label>
+        <div class="field-control">
+          <select
+            value={c().capture.hdr.tonemap_operator}
+            onChange={(e) =>
+              props.patch("capture", {
+                ...c().capture,
+                hdr: { ...c().capture.hdr, tonemap_operator: e.currentTarget.value as never },
+              })
+            }
+          >
+            <option value="bt2390">{t("settings.tonemap.bt2390")}</option>
+            <option value="reinhard-extended">{t("settings.tonemap.reinhard")}</option>
+            <option value="aces-filmic">{t("settings.tonemap.aces")}</option>
+            <option value="hable">{t("settings.tonemap.hable")}</option>
+            <option value="exposure">{t("settings.tonemap.exposure")}</option>
+          </select>
+          <span class="field-hint">
+            {t("settings.hint.operator")}
+          </span>
+        </div>
+      </div>
       <div class="field">
-        <label class="field-label">sdr white override</label>
+        <label class="field-label">{t("settings.field.sdr-white")}</label>
         <div class="field-control">
           <input
             type="number"
@@ -568,13 +2024,12 @@ function HdrPane(props: { c: AppConfig; patch: Patch }) {
             }
           />
           <span class="field-hint">
-            manual sdr-white target in nits. 0 = auto-detect from the os
-            sdr-content brightness slider (recommended)
+            {t("settings.hint.sdr-white")}
           </span>
         </div>
       </div>
       <div class="field">
-        <label class="field-label">pre-tonemap scale</label>
+        <label class="field-label">{t("settings.field.pre-scale")}</label>
         <div class="field-control">
           <input
             type="number"
@@ -600,11 +2055,11 @@ function HdrPane(props: { c: AppConfig; patch: Patch }) {
               })
             }
           />
-          <span class="field-hint">multiply luminance before mapping (1.0 = identity)</span>
+          <span class="field-hint">{t("settings.hint.pre-scale")}</span>
         </div>
       </div>
       <div class="field">
-        <label class="field-label">p99 maxcll</label>
+        <label class="field-label">{t("settings.field.p99")}</label>
         <div class="field-control">
           <label class="check">
             <input
@@ -622,14 +2077,14 @@ function HdrPane(props: { c: AppConfig; patch: Patch }) {
             />
             <span class="check-label">
               {c().capture.hdr.use_p99_max_cll
-                ? "p99 (sky / lights ignored)"
-                : "pure max, every spike counted"}
+                ? t("settings.p99.on")
+                : t("settings.p99.off")}
             </span>
           </label>
         </div>
       </div>
       <div class="field">
-        <label class="field-label">preserve hdr</label>
+        <label class="field-label">{t("settings.field.preserve-hdr")}</label>
         <div class="field-control">
           <label class="check">
             <input
@@ -644,13 +2099,12 @@ function HdrPane(props: { c: AppConfig; patch: Patch }) {
             />
             <span class="check-label">
               {c().output.preserve_hdr
-                ? `writes a 16-bit bt.2020+${c().capture.hdr.output_format} .hdr.png sidecar next to each hdr capture`
-                : "tonemaps to sdr only — no hdr sidecar saved"}
+                ? t("settings.preserve.on", { container: c().capture.hdr.container })
+                : t("settings.preserve.off")}
             </span>
           </label>
           <span class="field-hint">
-            source format is HDR10 (the common case). transfer = whichever
-            output format you pick above. fullscreen / active-monitor captures only.
+            {t("settings.hint.preserve")}
           </span>
         </div>
       </div>
@@ -704,9 +2158,9 @@ function HotkeysPane(props: { c: AppConfig; patch: Patch }) {
 
   return (
     <>
-      <Section title="global">
+      <Section title={t("settings.section.global")}>
         <div class="field">
-          <label class="field-label">all hotkeys</label>
+          <label class="field-label">{t("settings.field.all-hotkeys")}</label>
           <div class="field-control">
             <button
               class="btn"
@@ -714,15 +2168,56 @@ function HotkeysPane(props: { c: AppConfig; patch: Patch }) {
               disabled={busy()}
               onClick={toggle}
             >
-              {diag()?.disabled_globally ? "re-enable" : "disable all"}
+              {diag()?.disabled_globally ? t("settings.keys.enable") : t("settings.keys.disable")}
             </button>
             <span class="field-hint">
-              kill switch toggled from the tray menu too. survives restart.
+              {t("settings.hint.all-hotkeys")}
+            </span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.stop-recording")}</label>
+          <div class="field-control">
+            <HotkeyInput
+              value={props.c.hotkeys.stop_recording ?? ""}
+              onChange={(next) =>
+                props.patch("hotkeys", { ...props.c.hotkeys, stop_recording: next })
+              }
+            />
+            <span class="field-hint">
+              {t("settings.hint.stop-recording")}
+            </span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.dnd")}</label>
+          <div class="field-control">
+            <HotkeyInput
+              value={props.c.hotkeys.do_not_disturb ?? ""}
+              onChange={(next) =>
+                props.patch("hotkeys", { ...props.c.hotkeys, do_not_disturb: next })
+              }
+            />
+            <input
+              type="number"
+              min={0}
+              max={1440}
+              value={props.c.hotkeys.do_not_disturb_minutes ?? 0}
+              aria-label={t("settings.dnd.minutes")}
+              onChange={(e) =>
+                props.patch("hotkeys", {
+                  ...props.c.hotkeys,
+                  do_not_disturb_minutes: commitNumber(e.currentTarget, { min: 0, max: 1440, fallback: props.c.hotkeys.do_not_disturb_minutes ?? 0, int: true }),
+                })
+              }
+            />
+            <span class="field-hint">
+              {t("settings.hint.dnd")}
             </span>
           </div>
         </div>
         <div class="field">
-          <label class="field-label">backend</label>
+          <label class="field-label">{t("settings.field.hotkey-backend")}</label>
           <div class="field-control">
             <code>{diag()?.backend ?? "…"}</code>
             <Show when={diag()?.backend === "portal"}>
@@ -731,13 +2226,12 @@ function HotkeysPane(props: { c: AppConfig; patch: Patch }) {
                 data-variant="ghost"
                 disabled={rebinding()}
                 onClick={rebind}
-                title="re-open the desktop's shortcut approval if it was dismissed"
+                title={t("settings.rebind.title")}
               >
-                re-bind with desktop
+                {t("settings.rebind")}
               </button>
               <span class="field-hint">
-                the desktop registers and can remap these bindings; the
-                effective column below shows what actually triggers each task.
+                {t("settings.hint.rebind")}
               </span>
             </Show>
           </div>
@@ -748,9 +2242,9 @@ function HotkeysPane(props: { c: AppConfig; patch: Patch }) {
       </Section>
 
       <Show when={IS_LINUX}>
-        <Section title="advanced input">
+        <Section title={t("settings.section.advanced-input")}>
           <div class="field">
-            <label class="field-label">raw input (evdev)</label>
+            <label class="field-label">{t("settings.field.evdev")}</label>
             <div class="field-control">
               <label class="check">
                 <input
@@ -764,13 +2258,13 @@ function HotkeysPane(props: { c: AppConfig; patch: Patch }) {
                   }}
                 />
                 <span class="check-label">
-                  {(props.c.hotkeys.advanced_input ?? evdev()?.enabled) ? "enabled" : "off"}
+                  {(props.c.hotkeys.advanced_input ?? evdev()?.enabled)
+                    ? t("settings.toggle.enabled")
+                    : t("common.off")}
                 </span>
               </label>
               <span class="field-hint">
-                reads /dev/input directly: needed for mouse-button hotkeys and
-                for keyboards on desktops without the GlobalShortcuts portal.
-                sees all input devices while capscr runs.
+                {t("settings.hint.evdev")}
               </span>
             </div>
           </div>
@@ -780,27 +2274,26 @@ function HotkeysPane(props: { c: AppConfig; patch: Patch }) {
               && evdev()!.readable_device_count === 0}
           >
             <p class="flash" data-tone="warn">
-              no readable input devices — add yourself to the input group
-              (<code>sudo usermod -aG input $USER</code>), re-login, then{" "}
+              {tm("settings.evdev.none", { command: <code>sudo usermod -aG input $USER</code> })}{" "}
               <button class="btn" data-variant="ghost" onClick={() => refetchEvdev()}>
-                re-check
+                {t("settings.evdev.recheck")}
               </button>
             </p>
           </Show>
         </Section>
       </Show>
 
-      <Section title="per-binding status">
+      <Section title={t("settings.section.bindings")}>
         <div class="field-control" style="flex-direction: column; align-items: stretch;">
-          <Show when={tasks().length > 0} fallback={<p class="lede">no tasks defined.</p>}>
+          <Show when={tasks().length > 0} fallback={<p class="lede">{t("settings.bindings.none")}</p>}>
             <table class="diag-table">
               <thead>
                 <tr>
-                  <th>task</th>
-                  <th>hotkey</th>
-                  <th>status</th>
-                  <th>effective</th>
-                  <th>reason</th>
+                  <th>{t("settings.bindings.task")}</th>
+                  <th>{t("settings.bindings.hotkey")}</th>
+                  <th>{t("settings.field.status")}</th>
+                  <th>{t("settings.bindings.effective")}</th>
+                  <th>{t("settings.bindings.reason")}</th>
                 </tr>
               </thead>
               <tbody>
@@ -812,7 +2305,7 @@ function HotkeysPane(props: { c: AppConfig; patch: Patch }) {
                     return (
                       <tr>
                         <td>{task.name || task.id}</td>
-                        <td>{task.hotkey || <span class="warn">unbound</span>}</td>
+                        <td>{task.hotkey || <span class="warn">{t("settings.bindings.unbound")}</span>}</td>
                         <td>
                           <Show
                             when={task.hotkey}
@@ -820,7 +2313,7 @@ function HotkeysPane(props: { c: AppConfig; patch: Patch }) {
                           >
                             <Show
                               when={e}
-                              fallback={<span class="lede">unknown</span>}
+                              fallback={<span class="lede">{t("settings.bindings.unknown")}</span>}
                             >
                               <span
                                 class={
@@ -852,55 +2345,53 @@ function HotkeysPane(props: { c: AppConfig; patch: Patch }) {
 
       {/* the LL keyboard hook is windows machinery — no section to debug elsewhere */}
       <Show when={!IS_LINUX}>
-      <Section title="ll-hook telemetry (debug)">
+      <Section title={t("settings.section.hook")}>
         <p class="lede">
-          live counters from the low-level keyboard hook. press any key and
-          click refresh. if <code>calls_total</code> doesn't climb, the hook
-          isn't installed (or another tool is blocking it).
+          {tm("settings.hook.lede", { counter: <code>calls_total</code> })}
         </p>
         <div style="margin-bottom: 10px;">
           <button class="btn" data-variant="ghost" onClick={() => refetch()}>
-            refresh
+            {t("common.refresh")}
           </button>
         </div>
         <Show
           when={diag()?.hook}
           fallback={
             <p class="flash" data-tone="warn">
-              hook telemetry not in response (backend missing the field — old
-              build still running, or non-windows). diag payload:{" "}
-              <code>{JSON.stringify(diag() ?? null)}</code>
+              {tm("settings.hook.missing", {
+                payload: <code>{JSON.stringify(diag() ?? null)}</code>,
+              })}
             </p>
           }
         >
           <table class="diag-table">
             <tbody>
-              <tr><td>hook installed</td><td><code>{String(diag()!.hook!.installed)}</code></td></tr>
-              <tr><td>enabled</td><td><code>{String(diag()!.hook!.enabled)}</code></td></tr>
-              <tr><td>bindings in hook table</td><td><code>{diag()!.hook!.registered_count}</code></td></tr>
-              <tr><td>callback calls total</td><td><code>{diag()!.hook!.calls_total}</code></td></tr>
-              <tr><td>keydown calls</td><td><code>{diag()!.hook!.keydown_calls}</code></td></tr>
-              <tr><td>matched a binding</td><td><code>{diag()!.hook!.matched_calls}</code></td></tr>
-              <tr><td>dispatched to worker</td><td><code>{diag()!.hook!.dispatch_sent}</code></td></tr>
-              <tr><td>dispatch dropped (queue full)</td><td><code>{diag()!.hook!.dispatch_dropped}</code></td></tr>
+              <tr><td>{t("settings.hook.installed")}</td><td><code>{String(diag()!.hook!.installed)}</code></td></tr>
+              <tr><td>{t("settings.toggle.enabled")}</td><td><code>{String(diag()!.hook!.enabled)}</code></td></tr>
+              <tr><td>{t("settings.hook.bindings")}</td><td><code>{diag()!.hook!.registered_count}</code></td></tr>
+              <tr><td>{t("settings.hook.calls")}</td><td><code>{diag()!.hook!.calls_total}</code></td></tr>
+              <tr><td>{t("settings.hook.keydown")}</td><td><code>{diag()!.hook!.keydown_calls}</code></td></tr>
+              <tr><td>{t("settings.hook.matched")}</td><td><code>{diag()!.hook!.matched_calls}</code></td></tr>
+              <tr><td>{t("settings.hook.dispatched")}</td><td><code>{diag()!.hook!.dispatch_sent}</code></td></tr>
+              <tr><td>{t("settings.hook.dropped")}</td><td><code>{diag()!.hook!.dispatch_dropped}</code></td></tr>
               <tr>
-                <td>last seen (vk + mods)</td>
+                <td>{t("settings.hook.last")}</td>
                 <td>
                   <code>vk=0x{diag()!.hook!.last_vk.toString(16).padStart(2, "0").toUpperCase()}</code>
                   {" "}
                   <code>mods=0b{diag()!.hook!.last_mods.toString(2).padStart(4, "0")}</code>
                   <span class="lede" style="margin-left: 8px;">
-                    (mods: bit0=Ctrl bit1=Alt bit2=Shift bit3=Win)
+                    {t("settings.hook.mods")}
                   </span>
                 </td>
               </tr>
             </tbody>
           </table>
           <Show when={diag()!.hook!.registered.length > 0}>
-            <p class="lede" style="margin-top: 10px;">registered chords pushed to the hook table:</p>
+            <p class="lede" style="margin-top: 10px;">{t("settings.hook.registered")}</p>
             <table class="diag-table">
               <thead>
-                <tr><th>task id</th><th>vk</th><th>mods</th></tr>
+                <tr><th>{t("settings.hook.task")}</th><th>vk</th><th>mods</th></tr>
               </thead>
               <tbody>
                 <For each={diag()!.hook!.registered}>
@@ -946,25 +2437,23 @@ function SshPane() {
   };
 
   return (
-    <Section title="known sftp hosts">
+    <Section title={t("settings.section.sftp")}>
       <p class="lede">
-        capscr remembers each sftp server's public-key fingerprint on first
-        connect and refuses to upload on a later mismatch. forget a host to
-        re-trust a rotated key.
+        {t("settings.sftp.lede")}
       </p>
       <Show when={err()}>
         <p class="flash" data-tone="err">{err()}</p>
       </Show>
       <div class="field-control" style="flex-direction: column; align-items: stretch;">
         <Show when={hosts() && hosts()!.length > 0} fallback={
-          <p class="lede">no hosts trusted yet. upload via sftp to populate this list.</p>
+          <p class="lede">{t("settings.sftp.empty")}</p>
         }>
           <table class="diag-table">
             <thead>
               <tr>
-                <th>host:port</th>
-                <th>fingerprint</th>
-                <th>first seen</th>
+                <th>{t("settings.sftp.host")}</th>
+                <th>{t("settings.sftp.fingerprint")}</th>
+                <th>{t("settings.sftp.first-seen")}</th>
                 <th></th>
               </tr>
             </thead>
@@ -983,7 +2472,7 @@ function SshPane() {
                         disabled={busy() === h.host_port}
                         onClick={() => forget(h.host_port)}
                       >
-                        forget
+                        {t("settings.sftp.forget")}
                       </button>
                     </td>
                   </tr>
@@ -1001,9 +2490,9 @@ function NotifyPane(props: { c: AppConfig; patch: Patch }) {
   const c = () => props.c;
   return (
     <>
-      <Section title="feedback">
+      <Section title={t("settings.section.feedback")}>
         <div class="field">
-          <label class="field-label">os notifications</label>
+          <label class="field-label">{t("settings.field.notifications")}</label>
           <div class="field-control">
             <label class="check">
               <input
@@ -1017,13 +2506,13 @@ function NotifyPane(props: { c: AppConfig; patch: Patch }) {
                 }
               />
               <span class="check-label">
-                {c().ui.show_notifications ? "on" : "silent"}
+                {c().ui.show_notifications ? t("common.on") : t("settings.notify.silent")}
               </span>
             </label>
           </div>
         </div>
         <div class="field">
-          <label class="field-label">sound cue</label>
+          <label class="field-label">{t("settings.field.sound")}</label>
           <div class="field-control">
             <label class="check">
               <input
@@ -1038,17 +2527,62 @@ function NotifyPane(props: { c: AppConfig; patch: Patch }) {
               />
               <span class="check-label">
                 {c().post_capture.play_sound
-                  ? "sound cue on capture / upload"
-                  : "silent"}
+                  ? t("settings.sound.on")
+                  : t("settings.notify.silent")}
+              </span>
+            </label>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.open-after")}</label>
+          <div class="field-control">
+            <label class="check">
+              <input
+                type="checkbox"
+                checked={c().post_capture.open_file_after_save}
+                onChange={(e) =>
+                  props.patch("post_capture", {
+                    ...c().post_capture,
+                    open_file_after_save: e.currentTarget.checked,
+                  })
+                }
+              />
+              <span class="check-label">
+                {c().post_capture.open_file_after_save
+                  ? t("settings.open-after.on")
+                  : t("settings.open-after.off")}
               </span>
             </label>
           </div>
         </div>
       </Section>
 
-      <Section title="system">
+      <Section title={t("settings.section.system")}>
+        <div class="field">
+          <label class="field-label">{t("settings.language")}</label>
+          <div class="field-control">
+            <select
+              value={c().ui.language ?? "system"}
+              onChange={(e) =>
+                props.patch("ui", {
+                  ...c().ui,
+                  language: e.currentTarget.value as AppConfig["ui"]["language"],
+                })
+              }
+            >
+              <For each={LANGUAGES}>
+                {(l) => (
+                  <option value={l.id}>
+                    {l.id === "system" ? t("settings.language.system") : l.label}
+                  </option>
+                )}
+              </For>
+            </select>
+            <span class="field-hint">{t("settings.language.hint")}</span>
+          </div>
+        </div>
         <div class="field">
-          <label class="field-label">start at login</label>
+          <label class="field-label">{t("settings.field.autostart")}</label>
           <div class="field-control">
             <label class="check">
               <input
@@ -1063,15 +2597,57 @@ function NotifyPane(props: { c: AppConfig; patch: Patch }) {
               />
               <span class="check-label">
                 {c().ui.auto_start
-                  ? "launches automatically at login"
-                  : "manual launch only"}
+                  ? t("settings.autostart.on")
+                  : t("settings.autostart.off")}
+              </span>
+            </label>
+            <span class="field-hint">{t("settings.hint.autostart")}</span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.context-menu")}</label>
+          <div class="field-control">
+            <label class="check">
+              <input
+                type="checkbox"
+                checked={c().ui.context_menu}
+                onChange={(e) =>
+                  props.patch("ui", {
+                    ...c().ui,
+                    context_menu: e.currentTarget.checked,
+                  })
+                }
+              />
+              <span class="check-label">{t("settings.check.context-menu")}</span>
+            </label>
+            <span class="field-hint">
+              {t("settings.hint.context-menu")}
+            </span>
+          </div>
+        </div>
+        <div class="field">
+          <label class="field-label">{t("settings.field.minimized")}</label>
+          <div class="field-control">
+            <label class="check">
+              <input
+                type="checkbox"
+                checked={c().ui.start_minimized}
+                onChange={(e) =>
+                  props.patch("ui", {
+                    ...c().ui,
+                    start_minimized: e.currentTarget.checked,
+                  })
+                }
+              />
+              <span class="check-label">
+                {c().ui.start_minimized ? t("settings.minimized.on") : t("settings.minimized.off")}
               </span>
             </label>
-            <span class="field-hint">launch capscr automatically at login (applied on next save)</span>
+            <span class="field-hint">{t("settings.hint.minimized")}</span>
           </div>
         </div>
         <div class="field">
-          <label class="field-label">close button behavior</label>
+          <label class="field-label">{t("settings.field.close")}</label>
           <div class="field-control">
             <select
               value={c().ui.close_behavior ?? "minimize-to-tray"}
@@ -1083,14 +2659,14 @@ function NotifyPane(props: { c: AppConfig; patch: Patch }) {
                 });
               }}
             >
-              <option value="minimize-to-tray">minimize to system tray</option>
-              <option value="minimize-to-taskbar">minimize to taskbar</option>
-              <option value="exit">exit application</option>
+              <option value="minimize-to-tray">{t("settings.close.tray")}</option>
+              <option value="minimize-to-taskbar">{t("settings.close.taskbar")}</option>
+              <option value="exit">{t("settings.close.exit")}</option>
             </select>
           </div>
         </div>
         <div class="field">
-          <label class="field-label">clipboard history</label>
+          <label class="field-label">{t("settings.field.clipboard-history")}</label>
           <div class="field-control">
             <label class="check">
               <input
@@ -1105,14 +2681,14 @@ function NotifyPane(props: { c: AppConfig; patch: Patch }) {
               />
               <span class="check-label">
                 {c().ui.save_clipboard_to_history
-                  ? "saves clipboard-only captures to the local history cache"
-                  : "clipboard-only captures are not saved to history"}
+                  ? t("settings.clipboard-history.on")
+                  : t("settings.clipboard-history.off")}
               </span>
             </label>
           </div>
         </div>
         <div class="field">
-          <label class="field-label">check for updates</label>
+          <label class="field-label">{t("settings.field.updates")}</label>
           <div class="field-control">
             <label class="check">
               <input
@@ -1127,13 +2703,78 @@ function NotifyPane(props: { c: AppConfig; patch: Patch }) {
               />
               <span class="check-label">
                 {c().ui.check_updates_on_launch
-                  ? "queries GitHub releases 4s after hub opens"
-                  : "no network call, you'll need to grab updates manually"}
+                  ? t("settings.updates.on")
+                  : t("settings.updates.off")}
               </span>
             </label>
           </div>
         </div>
       </Section>
+      <LogsSection />
     </>
   );
 }
+
+function LogsSection() {
+  const [shown, setShown] = createSignal(false);
+  const [text, setText] = createSignal("");
+  const [err, setErr] = createSignal<string | null>(null);
+  let view: HTMLPreElement | undefined;
+
+  const load = async () => {
+    setErr(null);
+    try {
+      setText(await api.readLogTail());
+      // newest lines are what a report needs
+      if (view) view.scrollTop = view.scrollHeight;
+    } catch (e) {
+      setErr(String(e));
+    }
+  };
+  const toggle = () => {
+    setShown(!shown());
+    if (shown()) void load();
+  };
+
+  return (
+    <Section title={t("settings.section.logs")}>
+      <div class="field">
+        <label class="field-label">{t("settings.field.log")}</label>
+        <div class="field-control">
+          <div class="input-row">
+            <button type="button" class="btn" data-size="xs" onClick={toggle}>
+              {shown() ? t("settings.logs.hide") : t("settings.logs.view")}
+            </button>
+            <Show when={shown()}>
+              <button type="button" class="btn" data-variant="ghost" data-size="xs" onClick={load}>
+                {t("common.refresh")}
+              </button>
+            </Show>
+            <button
+              type="button"
+              class="btn"
+              data-variant="ghost"
+              data-size="xs"
+              onClick={() => api.revealLogFile().catch((e) => setErr(String(e)))}
+              title={t("settings.logs.reveal")}
+            >
+              <FolderOpen size={11} stroke-width={1.5} />
+              {t("settings.logs.open")}
+            </button>
+          </div>
+          <span class="field-hint">
+            {t("settings.hint.log")}
+          </span>
+          <Show when={err()}>
+            <p class="flash" data-tone="err">{err()}</p>
+          </Show>
+          <Show when={shown()}>
+            <pre class="log-view" ref={view} tabindex={0} aria-label={t("settings.logs.recent")}>
+              {text() || t("settings.logs.empty")}
+            </pre>
+          </Show>
+        </div>
+      </div>
+    </Section>
+  );
+}
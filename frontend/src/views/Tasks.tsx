@@ -4,38 +4,74 @@ import { Plus, Trash2, Zap } from "lucide-solid";
 import { api, AppConfig, CaptureTask, HotkeyDiagnostics } from "../api";
 import { setConfigDirty } from "../dirty";
 import { HotkeyInput } from "../components/HotkeyInput";
+import { targetFor, WindowPicker } from "../components/WindowPicker";
+import { MessageKey, t, tm } from "../i18n";
 import { config, mutateConfig, refetchConfig } from "../store";
 
-const CAPTURE_MODES: { id: CaptureTask["capture_mode"]; label: string }[] = [
-  { id: "region", label: "region (drag a rect)" },
-  { id: "region-last", label: "region (last — no drag)" },
-  { id: "window", label: "window (pick one)" },
-  { id: "fullscreen", label: "fullscreen (primary)" },
-  { id: "active-monitor", label: "active monitor" },
-  { id: "region-gif", label: "region gif" },
-  { id: "region-mp4", label: "region mp4 (video)" },
+const CAPTURE_MODES: { id: CaptureTask["capture_mode"]; label: MessageKey }[] = [
+  { id: "region", label: "tasks.mode.region" },
+  { id: "region-last", label: "tasks.mode.region-last" },
+  { id: "active-window", label: "tasks.mode.active-window" },
+  { id: "window", label: "tasks.mode.window" },
+  { id: "target-window", label: "tasks.mode.target-window" },
+  { id: "fullscreen", label: "tasks.mode.fullscreen" },
+  { id: "active-monitor", label: "tasks.mode.active-monitor" },
+  { id: "region-gif", label: "tasks.mode.region-gif" },
+  { id: "region-mp4", label: "tasks.mode.region-mp4" },
+  { id: "region-stream", label: "tasks.mode.region-stream" },
 ];
 
-const POST_ACTIONS: { id: CaptureTask["post_action"]; label: string }[] = [
-  { id: "clipboard", label: "clipboard only" },
-  { id: "save-file", label: "save to output dir" },
-  { id: "save-and-clipboard", label: "save + clipboard" },
-  { id: "upload", label: "upload" },
-  { id: "open-editor", label: "open in editor" },
-  { id: "copy-text", label: "copy detected text (ocr)" },
-  { id: "prompt", label: "prompt" },
-  { id: "do-nothing", label: "do nothing" },
+export const POST_ACTIONS: { id: CaptureTask["post_action"]; label: MessageKey }[] = [
+  { id: "clipboard", label: "tasks.action.clipboard" },
+  { id: "save-file", label: "tasks.action.save-file" },
+  { id: "save-and-clipboard", label: "tasks.action.save-and-clipboard" },
+  { id: "upload", label: "tasks.action.upload" },
+  { id: "open-editor", label: "tasks.action.open-editor" },
+  { id: "copy-text", label: "tasks.action.copy-text" },
+  { id: "prompt", label: "tasks.action.prompt" },
+  { id: "do-nothing", label: "tasks.action.do-nothing" },
 ];
 
+// several tasks can share a hotkey when each wants a different gesture
+const GESTURES: { id: NonNullable<CaptureTask["gesture"]>; label: MessageKey }[] = [
+  { id: "press", label: "tasks.gesture.press" },
+  { id: "double-press", label: "tasks.gesture.double-press" },
+  { id: "long-press", label: "tasks.gesture.long-press" },
+];
+
+// fixed recording frames; "free" drags a region as usual
+const RECORDING_SIZES: {
+  id: string;
+  label: MessageKey;
+  size: CaptureTask["recording_size"];
+}[] = [
+  { id: "free", label: "tasks.size.free", size: null },
+  { id: "720p", label: "tasks.size.720p", size: { width: 1280, height: 720 } },
+  { id: "1080p", label: "tasks.size.1080p", size: { width: 1920, height: 1080 } },
+  { id: "custom", label: "tasks.size.custom", size: { width: 1280, height: 720 } },
+];
+
+const recordingSizeId = (size: CaptureTask["recording_size"]) =>
+  !size
+    ? "free"
+    : (RECORDING_SIZES.find(
+        (preset) =>
+          preset.id !== "custom" &&
+          preset.size?.width === size.width &&
+          preset.size?.height === size.height,
+      )?.id ?? "custom");
+
 const isRecordingMode = (mode: CaptureTask["capture_mode"]) =>
-  mode === "region-gif" || mode === "region-mp4";
+  mode === "region-gif" || mode === "region-mp4" || mode === "region-stream";
 
 // recordings can't be edited or OCR'd (the editor would flatten the animation,
 // and there's no still frame to read text from), so those post-actions are only
 // offered for still-image modes
+export const STILL_ONLY_ACTIONS: CaptureTask["post_action"][] = ["open-editor", "copy-text"];
+
 const postActionsFor = (mode: CaptureTask["capture_mode"]) =>
   isRecordingMode(mode)
-    ? POST_ACTIONS.filter((p) => p.id !== "open-editor" && p.id !== "copy-text")
+    ? POST_ACTIONS.filter((p) => !STILL_ONLY_ACTIONS.includes(p.id))
     : POST_ACTIONS;
 
 const UPLOAD_TARGETS: NonNullable<CaptureTask["target_destination"]>[] = [
@@ -44,6 +80,10 @@ const UPLOAD_TARGETS: NonNullable<CaptureTask["target_destination"]>[] = [
   "ftp",
   "sftp",
   "s3",
+  "webdav",
+  "dropbox",
+  "email",
+  "webhook",
 ];
 
 export function Tasks() {
@@ -79,27 +119,33 @@ export function Tasks() {
     }, 4000);
   };
 
+  const [pickingFor, setPickingFor] = createSignal<string | null>(null);
+
   const saveConfig = async (c: AppConfig) => {
-    const bound = c.capture_tasks.map((t) => t.hotkey).filter(Boolean);
+    const bound = c.capture_tasks
+      .filter((task) => task.hotkey)
+      .map((task) => `${task.hotkey} (${task.gesture ?? "press"})`);
     const dupes = bound.filter((h, i) => bound.indexOf(h) !== i);
     if (dupes.length > 0) {
-      setStatus({ tone: "err", msg: `duplicate hotkey: ${[...new Set(dupes)].join(", ")} — each task needs a unique key combo` });
+      setStatus({ tone: "err", msg: t("tasks.status.duplicate", { hotkeys: [...new Set(dupes)].join(", ") }) });
       // the rejected edit already mutated the in-memory store; pull the saved
       // config back from disk so the UI stops showing the unsaved duplicate
       refetchConfig();
       return;
     }
 
-    setStatus({ tone: "", msg: "saving..." });
+    setStatus({ tone: "", msg: t("tasks.status.saving") });
     try {
       await api.setConfig(c);
       setStatus({
         tone: "ok",
-        msg: `${c.capture_tasks.length} task${c.capture_tasks.length === 1 ? "" : "s"} live.`,
+        msg: t(c.capture_tasks.length === 1 ? "tasks.status.live.one" : "tasks.status.live.other", {
+          count: c.capture_tasks.length,
+        }),
       });
       setConfigDirty(false);
     } catch (e) {
-      setStatus({ tone: "err", msg: `err: ${e}` });
+      setStatus({ tone: "err", msg: t("common.error", { error: String(e) }) });
     }
   };
 
@@ -132,7 +178,7 @@ export function Tasks() {
     const id = `task-${Date.now().toString(36)}`;
     const newTask: CaptureTask = {
       id,
-      name: "new task",
+      name: t("tasks.new-name"),
       hotkey: "",
       capture_mode: "region",
       post_action: "save-and-clipboard",
@@ -146,9 +192,9 @@ export function Tasks() {
   return (
     <>
       <div class="view-head">
-        <h1>tasks</h1>
+        <h1>{t("tab.tasks")}</h1>
         <span class="lede">
-          one hotkey, one capture, one post-action.
+          {t("tasks.lede")}
         </span>
       </div>
 
@@ -169,7 +215,7 @@ export function Tasks() {
               <div class="btn-row">
                 <button class="btn" onClick={addTask}>
                   <Plus size={12} stroke-width={1.5} />
-                  new
+                  {t("tasks.new")}
                 </button>
               </div>
               <Show when={status()}>
@@ -184,10 +230,8 @@ export function Tasks() {
               fallback={
                 <div class="empty">
                   <span class="stick" />
-                  no tasks
-                  <p>
-                    press <kbd>new</kbd>, give it a hotkey, save.
-                  </p>
+                  {t("tasks.empty")}
+                  <p>{tm("tasks.empty.hint", { new: <kbd>{t("tasks.new")}</kbd> })}</p>
                 </div>
               }
             >
@@ -211,7 +255,7 @@ export function Tasks() {
                             fallback={
                               <span class="warn">
                                 <span class="k">key </span>
-                                <span class="v">unbound</span>
+                                <span class="v">{t("tasks.unbound")}</span>
                               </span>
                             }
                           >
@@ -227,11 +271,11 @@ export function Tasks() {
                                       ? "chip-live"
                                       : "chip-fail"
                                   }
-                                  title={s().reason ?? "registered with the keyboard hook"}
+                                  title={s().reason ?? t("tasks.registered")}
                                 >
                                   ● {s().status === "live"
-                                    ? "live"
-                                    : "rejected"}
+                                    ? t("tasks.live")
+                                    : t("tasks.rejected")}
                                 </span>
                               )}
                             </Show>
@@ -239,7 +283,7 @@ export function Tasks() {
                           <Show when={!task.name.trim()}>
                             <span class="warn">
                               <span class="k">name </span>
-                              <span class="v">required</span>
+                              <span class="v">{t("tasks.required")}</span>
                             </span>
                           </Show>
                           <span>
@@ -250,7 +294,7 @@ export function Tasks() {
 
                         <div class="list-item-fields">
                           <div class="field">
-                            <label class="field-label">name</label>
+                            <label class="field-label">{t("tasks.field.name")}</label>
                             <div class="field-control">
                               <input
                                 type="text"
@@ -264,7 +308,7 @@ export function Tasks() {
                             </div>
                           </div>
                           <div class="field">
-                            <label class="field-label">hotkey</label>
+                            <label class="field-label">{t("tasks.field.hotkey")}</label>
                             <div class="field-control">
                               <HotkeyInput
                                 value={task.hotkey}
@@ -273,12 +317,34 @@ export function Tasks() {
                                 }
                               />
                               <span class="field-hint">
-                                click, press combo
+                                {t("tasks.hint.hotkey")}
                               </span>
                             </div>
                           </div>
+                          <Show when={task.hotkey}>
+                            <div class="field">
+                              <label class="field-label">{t("tasks.field.gesture")}</label>
+                              <div class="field-control">
+                                <select
+                                  value={task.gesture ?? "press"}
+                                  onChange={(e) =>
+                                    updateTask(i(), {
+                                      gesture: e.currentTarget.value as CaptureTask["gesture"],
+                                    })
+                                  }
+                                >
+                                  <For each={GESTURES}>
+                                    {(g) => <option value={g.id}>{t(g.label)}</option>}
+                                  </For>
+                                </select>
+                                <span class="field-hint">
+                                  {t("tasks.hint.gesture")}
+                                </span>
+                              </div>
+                            </div>
+                          </Show>
                           <div class="field">
-                            <label class="field-label">capture mode</label>
+                            <label class="field-label">{t("tasks.field.mode")}</label>
                             <div class="field-control">
                               <select
                                 value={task.capture_mode}
@@ -288,6 +354,9 @@ export function Tasks() {
                                   const update: Partial<CaptureTask> = {
                                     capture_mode: mode,
                                   };
+                                  if (mode === "target-window" && !task.target_window) {
+                                    update.target_window = { title: "", process: "" };
+                                  }
                                   // editor and ocr post-actions don't apply to
                                   // recordings — fall back to save so the task
                                   // isn't left on a filtered-out action that
@@ -304,14 +373,83 @@ export function Tasks() {
                               >
                                 <For each={CAPTURE_MODES}>
                                   {(m) => (
-                                    <option value={m.id}>{m.label}</option>
+                                    <option value={m.id}>{t(m.label)}</option>
                                   )}
                                 </For>
                               </select>
                             </div>
                           </div>
+                          <Show when={task.capture_mode === "target-window"}>
+                            <Show
+                              when={pickingFor() === task.id}
+                              fallback={
+                                <div class="field">
+                                  <label class="field-label" />
+                                  <div class="field-control">
+                                    <button
+                                      class="btn"
+                                      data-variant="ghost"
+                                      onClick={() => setPickingFor(task.id)}
+                                    >
+                                      {t("tasks.pick-window")}
+                                    </button>
+                                  </div>
+                                </div>
+                              }
+                            >
+                              <WindowPicker
+                                onPick={(w) => {
+                                  updateTask(i(), { target_window: targetFor(w) });
+                                  setPickingFor(null);
+                                }}
+                                onClose={() => setPickingFor(null)}
+                              />
+                            </Show>
+                            <div class="field">
+                              <label class="field-label">{t("tasks.field.window-title")}</label>
+                              <div class="field-control">
+                                <input
+                                  type="text"
+                                  placeholder="Visual Studio Code"
+                                  value={task.target_window?.title ?? ""}
+                                  onChange={(e) =>
+                                    updateTask(i(), {
+                                      target_window: {
+                                        title: e.currentTarget.value,
+                                        process: task.target_window?.process ?? "",
+                                      },
+                                    })
+                                  }
+                                />
+                                <span class="field-hint">
+                                  {t("tasks.hint.window-title")}
+                                </span>
+                              </div>
+                            </div>
+                            <div class="field">
+                              <label class="field-label">{t("tasks.field.process")}</label>
+                              <div class="field-control">
+                                <input
+                                  type="text"
+                                  placeholder="code"
+                                  value={task.target_window?.process ?? ""}
+                                  onChange={(e) =>
+                                    updateTask(i(), {
+                                      target_window: {
+                                        title: task.target_window?.title ?? "",
+                                        process: e.currentTarget.value,
+                                      },
+                                    })
+                                  }
+                                />
+                                <span class="field-hint">
+                                  {t("tasks.hint.process")}
+                                </span>
+                              </div>
+                            </div>
+                          </Show>
                           <div class="field">
-                            <label class="field-label">post-action</label>
+                            <label class="field-label">{t("tasks.field.post-action")}</label>
                             <div class="field-control">
                               <select
                                 value={task.post_action}
@@ -327,7 +465,7 @@ export function Tasks() {
                               >
                                 <For each={postActionsFor(task.capture_mode)}>
                                   {(p) => (
-                                    <option value={p.id}>{p.label}</option>
+                                    <option value={p.id}>{t(p.label)}</option>
                                   )}
                                 </For>
                               </select>
@@ -335,7 +473,7 @@ export function Tasks() {
                           </div>
                           <Show when={task.post_action === "upload"}>
                             <div class="field">
-                              <label class="field-label">target</label>
+                              <label class="field-label">{t("tasks.field.target")}</label>
                               <div class="field-control">
                                 <select
                                   value={task.target_destination ?? "imgur"}
@@ -347,22 +485,148 @@ export function Tasks() {
                                   }
                                 >
                                   <For each={UPLOAD_TARGETS}>
-                                    {(t) => <option value={t}>{t}</option>}
+                                    {(target) => <option value={target}>{target}</option>}
                                   </For>
                                 </select>
                               </div>
                             </div>
+                            <Show
+                              when={
+                                task.target_destination === "custom" &&
+                                c().upload.custom_presets.length > 0
+                              }
+                            >
+                              <div class="field">
+                                <label class="field-label">{t("tasks.field.preset")}</label>
+                                <div class="field-control">
+                                  <select
+                                    value={task.custom_preset ?? ""}
+                                    onChange={(e) =>
+                                      updateTask(i(), {
+                                        custom_preset: e.currentTarget.value || null,
+                                      })
+                                    }
+                                  >
+                                    <option value="">{t("tasks.preset.default")}</option>
+                                    <For each={c().upload.custom_presets}>
+                                      {(p) => <option value={p.id}>{p.name || p.id}</option>}
+                                    </For>
+                                  </select>
+                                </div>
+                              </div>
+                            </Show>
+                          </Show>
+                          <Show when={isRecordingMode(task.capture_mode)}>
+                            <div class="field">
+                              <label class="field-label">{t("tasks.field.follow")}</label>
+                              <div class="field-control">
+                                <label class="check">
+                                  <input
+                                    type="checkbox"
+                                    checked={task.follow_active_window ?? false}
+                                    onChange={(e) =>
+                                      updateTask(i(), {
+                                        follow_active_window: e.currentTarget.checked,
+                                      })
+                                    }
+                                  />
+                                  <span class="check-label">{t("tasks.check.follow")}</span>
+                                </label>
+                                <span class="field-hint">
+                                  {t("tasks.hint.follow")}
+                                </span>
+                              </div>
+                            </div>
+                            <Show when={task.capture_mode !== "region-stream"}>
+                              <div class="field">
+                                <label class="field-label">{t("tasks.field.hold")}</label>
+                                <div class="field-control">
+                                  <label class="check">
+                                    <input
+                                      type="checkbox"
+                                      checked={task.hold_to_record ?? false}
+                                      onChange={(e) =>
+                                        updateTask(i(), {
+                                          hold_to_record: e.currentTarget.checked,
+                                        })
+                                      }
+                                    />
+                                    <span class="check-label">{t("tasks.check.hold")}</span>
+                                  </label>
+                                  <span class="field-hint">
+                                    {t("tasks.hint.hold")}
+                                  </span>
+                                </div>
+                              </div>
+                            </Show>
+                            <Show when={!task.follow_active_window}>
+                              <div class="field">
+                                <label class="field-label">{t("tasks.field.size")}</label>
+                                <div class="field-control">
+                                  <select
+                                    value={recordingSizeId(task.recording_size)}
+                                    onChange={(e) => {
+                                      const id = e.currentTarget.value;
+                                      const preset = RECORDING_SIZES.find((p) => p.id === id);
+                                      updateTask(i(), {
+                                        recording_size:
+                                          id === "custom"
+                                            ? (task.recording_size ?? preset?.size ?? null)
+                                            : (preset?.size ?? null),
+                                      });
+                                    }}
+                                  >
+                                    <For each={RECORDING_SIZES}>
+                                      {(p) => <option value={p.id}>{t(p.label)}</option>}
+                                    </For>
+                                  </select>
+                                  <Show when={recordingSizeId(task.recording_size) === "custom"}>
+                                    <input
+                                      type="number"
+                                      min={16}
+                                      max={8192}
+                                      value={task.recording_size?.width ?? 1280}
+                                      onChange={(e) =>
+                                        updateTask(i(), {
+                                          recording_size: {
+                                            width: Number(e.currentTarget.value) || 1280,
+                                            height: task.recording_size?.height ?? 720,
+                                          },
+                                        })
+                                      }
+                                    />
+                                    <input
+                                      type="number"
+                                      min={16}
+                                      max={8192}
+                                      value={task.recording_size?.height ?? 720}
+                                      onChange={(e) =>
+                                        updateTask(i(), {
+                                          recording_size: {
+                                            width: task.recording_size?.width ?? 1280,
+                                            height: Number(e.currentTarget.value) || 720,
+                                          },
+                                        })
+                                      }
+                                    />
+                                  </Show>
+                                  <span class="field-hint">
+                                    {t("tasks.hint.size")}
+                                  </span>
+                                </div>
+                              </div>
+                            </Show>
                           </Show>
                           <Show when={!isRecordingMode(task.capture_mode)}>
                             <div class="field">
-                              <label class="field-label">delay</label>
+                              <label class="field-label">{t("tasks.field.delay")}</label>
                               <div class="field-control">
                                 <input
                                   type="number"
                                   min={0}
                                   max={30000}
                                   step={100}
-                                  placeholder="global"
+                                  placeholder={t("tasks.delay.global")}
                                   value={task.delay_ms ?? ""}
                                   onChange={(e) => {
                                     const raw = e.currentTarget.value.trim();
@@ -375,7 +639,7 @@ export function Tasks() {
                                   }}
                                 />
                                 <span class="field-hint">
-                                  ms before capture — blank uses the global delay
+                                  {t("tasks.hint.delay")}
                                 </span>
                               </div>
                             </div>
@@ -388,17 +652,17 @@ export function Tasks() {
                           class="btn"
                           data-variant="ghost"
                           data-size="xs"
-                          title="run this task now (same path as pressing its hotkey)"
+                          title={t("tasks.fire.title")}
                           onClick={() => {
                             api
                               .fireTask(task.id)
                               .catch((e) =>
-                                setStatus({ tone: "err", msg: `fire: ${e}` }),
+                                setStatus({ tone: "err", msg: t("tasks.status.fire-error", { error: String(e) }) }),
                               );
                           }}
                         >
                           <Zap size={11} stroke-width={1.5} />
-                          fire
+                          {t("tasks.fire")}
                         </button>
                         <button
                           class="btn"
@@ -407,8 +671,8 @@ export function Tasks() {
                           classList={{ "is-arm": confirmDelete() === task.id }}
                           title={
                             confirmDelete() === task.id
-                              ? "click again to confirm"
-                              : "delete this task"
+                              ? t("common.confirm.title")
+                              : t("tasks.delete.title")
                           }
                           onClick={() =>
                             confirmDelete() === task.id
@@ -417,7 +681,7 @@ export function Tasks() {
                           }
                         >
                           <Trash2 size={11} stroke-width={1.5} />
-                          {confirmDelete() === task.id ? "confirm?" : "delete"}
+                          {confirmDelete() === task.id ? t("common.confirm") : t("common.delete")}
                         </button>
                       </div>
                     </div>
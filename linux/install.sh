@@ -40,6 +40,8 @@ sed -e "s|{{exec}}|$bin_dir/capscr|g" \
     linux/capscr.desktop > "$app_dir/capscr.desktop"
 
 command -v update-desktop-database >/dev/null && update-desktop-database "$app_dir" || true
+# capscr:// links open through the desktop file
+command -v xdg-mime >/dev/null && xdg-mime default capscr.desktop x-scheme-handler/capscr || true
 
 systemd-run --user --collect --unit=capscr "$bin_dir/capscr"
 sleep 1
@@ -0,0 +1,249 @@
+// interval ("auto") capture: a silent screenshot every few seconds or minutes
+// into its own folder, pruned to a file count / disk quota. started and stopped
+// from the tray or settings; it never resumes by itself on launch.
+
+use crate::config::{AutoCaptureTarget, Config};
+use crate::state::AppState;
+use anyhow::{anyhow, Result};
+use crossbeam_channel::{Receiver, RecvTimeoutError};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Emitter, Manager};
+
+// every auto capture carries this prefix so pruning only ever touches files
+// this feature wrote, even when the folder is shared with normal captures
+pub(crate) const FILE_PREFIX: &str = "auto_";
+// consecutive failed ticks before the loop gives up (a revoked screencast
+// permission or a vanished folder won't fix itself on the next tick)
+const MAX_CONSECUTIVE_FAILURES: u32 = 3;
+
+pub fn is_running(app: &AppHandle) -> bool {
+    app.state::<AppState>()
+        .auto_capture_stop
+        .lock()
+        .unwrap()
+        .is_some()
+}
+
+pub fn start(app: &AppHandle) -> Result<()> {
+    let state = app.state::<AppState>();
+    let mut slot = state.auto_capture_stop.lock().unwrap();
+    if slot.is_some() {
+        return Ok(());
+    }
+    let (tx, rx) = crossbeam_channel::bounded::<()>(1);
+    let thread_app = app.clone();
+    std::thread::Builder::new()
+        .name("capscr-auto-capture".into())
+        .spawn(move || run(thread_app, rx))?;
+    *slot = Some(tx);
+    drop(slot);
+    changed(app);
+    Ok(())
+}
+
+pub fn stop(app: &AppHandle) {
+    // dropping the sender disconnects the loop's receiver, which wakes it
+    // immediately instead of after the rest of the interval
+    let was_running = app
+        .state::<AppState>()
+        .auto_capture_stop
+        .lock()
+        .unwrap()
+        .take()
+        .is_some();
+    if was_running {
+        changed(app);
+    }
+}
+
+fn changed(app: &AppHandle) {
+    crate::tray::SystemTray::rebuild(app);
+    let _ = app.emit("capscr://auto-capture", is_running(app));
+}
+
+fn run(app: AppHandle, stop: Receiver<()>) {
+    let mut failures = 0;
+    loop {
+        let state = app.state::<AppState>();
+        let config = state.config.lock().unwrap().clone();
+        // a selector or recording on screen would land in the shot; skip this
+        // tick rather than capture the overlay
+        if state.capture_in_progress.load(Ordering::SeqCst) {
+            tracing::debug!("auto capture: capture in progress, skipping tick");
+        } else {
+            match tick(&config) {
+                Ok(path) => {
+                    failures = 0;
+                    crate::commands::notify_capture_saved(&app, &path);
+                }
+                Err(e) => {
+                    failures += 1;
+                    tracing::warn!("auto capture tick failed ({failures}): {e:#}");
+                    if failures >= MAX_CONSECUTIVE_FAILURES {
+                        crate::commands::emit_error(
+                            &app,
+                            "auto-capture",
+                            &crate::i18n::tf("error.auto-capture", &[("error", &format!("{e:#}"))]),
+                        );
+                        let _ = crate::clipboard::show_notification(
+                            crate::i18n::t("notify.auto-capture-stopped"),
+                            &format!("{e:#}"),
+                        );
+                        break;
+                    }
+                }
+            }
+        }
+        let interval = Duration::from_secs(config.auto_capture.interval_secs as u64);
+        match stop.recv_timeout(interval) {
+            Err(RecvTimeoutError::Timeout) => continue,
+            _ => return,
+        }
+    }
+    stop_from_loop(&app, &stop);
+}
+
+// the loop ended by itself: clear the slot, unless a stop already took this
+// loop's sender (a still-connected receiver means the slot holds it, since the
+// slot is its only owner) and a newer loop may now own the slot
+fn stop_from_loop(app: &AppHandle, stop: &Receiver<()>) {
+    let state = app.state::<AppState>();
+    let mut slot = state.auto_capture_stop.lock().unwrap();
+    if !matches!(
+        stop.try_recv(),
+        Err(crossbeam_channel::TryRecvError::Disconnected)
+    ) {
+        *slot = None;
+        drop(slot);
+        changed(app);
+    }
+}
+
+fn tick(config: &Config) -> Result<PathBuf> {
+    let dir = config.auto_capture.resolved_directory(&config.output);
+    std::fs::create_dir_all(&dir)?;
+    let (mut image, origin) = match config.auto_capture.target {
+        AutoCaptureTarget::ActiveMonitor => (
+            crate::commands::capture_active_monitor_with_hdr()?.0,
+            crate::commands::active_monitor_origin(),
+        ),
+        AutoCaptureTarget::AllMonitors => (
+            crate::capture::ScreenCapture::all_monitors()?,
+            crate::capture::ScreenCapture::desktop_origin().ok(),
+        ),
+    };
+    if !config.capture.exclusion.apps.is_empty() {
+        // unattended, so a frame we can't place on screen is dropped rather
+        // than saved unmasked
+        let origin = origin.ok_or_else(|| anyhow!("couldn't place the capture on screen"))?;
+        crate::capture::exclusion::apply(&mut image, origin, &config.capture.exclusion, None)?;
+    }
+    let name = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let ext = config.output.format.extension();
+    let path =
+        crate::clipboard::get_unique_filepath(&dir.join(format!("{FILE_PREFIX}{name}.{ext}")));
+    crate::clipboard::save_image(&image, &path, config.output.format, config.output.quality)?;
+    let meta = crate::metadata::CaptureMeta::now();
+    if let Err(e) = crate::metadata::apply_to_file(&path, config.output.metadata, &meta) {
+        tracing::warn!("auto capture: couldn't rewrite metadata: {e:#}");
+    }
+    prune(
+        &dir,
+        config.auto_capture.max_count,
+        config.auto_capture.max_disk_mb,
+    );
+    Ok(path)
+}
+
+fn prune(dir: &Path, max_count: u32, max_disk_mb: u32) {
+    if max_count == 0 && max_disk_mb == 0 {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let files: Vec<(PathBuf, u64, SystemTime)> = entries
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(FILE_PREFIX))
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            meta.is_file().then(|| {
+                (
+                    entry.path(),
+                    meta.len(),
+                    meta.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                )
+            })
+        })
+        .collect();
+    for path in files_to_prune(files, max_count, max_disk_mb as u64 * 1024 * 1024) {
+        if let Err(e) = std::fs::remove_file(&path) {
+            tracing::warn!("auto capture: couldn't prune {}: {e}", path.display());
+        }
+    }
+}
+
+/// oldest-first files to delete so what remains fits both limits (0 = no
+/// limit). the newest file always stays. retention prunes with it too
+pub(crate) fn files_to_prune(
+    mut files: Vec<(PathBuf, u64, SystemTime)>,
+    max_count: u32,
+    max_bytes: u64,
+) -> Vec<PathBuf> {
+    files.sort_by(|a, b| a.2.cmp(&b.2).then_with(|| a.0.cmp(&b.0)));
+    let mut total: u64 = files.iter().map(|f| f.1).sum();
+    let mut remaining = files.len();
+    let mut doomed = Vec::new();
+    for (path, size, _) in files {
+        let over_count = max_count > 0 && remaining > max_count as usize;
+        let over_bytes = max_bytes > 0 && total > max_bytes;
+        // never delete the newest capture, even if it alone busts the quota
+        if !(over_count || over_bytes) || remaining == 1 {
+            break;
+        }
+        total -= size;
+        remaining -= 1;
+        doomed.push(path);
+    }
+    doomed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(name: &str, size: u64, age_secs: u64) -> (PathBuf, u64, SystemTime) {
+        (
+            PathBuf::from(name),
+            size,
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000 - age_secs),
+        )
+    }
+
+    #[test]
+    fn prunes_oldest_past_count_and_quota() {
+        let files = vec![
+            file("c", 10, 10),
+            file("a", 10, 30),
+            file("b", 10, 20),
+            file("d", 10, 0),
+        ];
+        assert_eq!(
+            files_to_prune(files.clone(), 2, 0),
+            vec![PathBuf::from("a"), PathBuf::from("b")]
+        );
+        assert_eq!(
+            files_to_prune(files.clone(), 0, 25),
+            files_to_prune(files.clone(), 2, 0)
+        );
+        assert!(files_to_prune(files, 0, 0).is_empty());
+    }
+
+    #[test]
+    fn keeps_the_newest_capture_even_over_quota() {
+        let files = vec![file("old", 100, 10), file("new", 100, 0)];
+        assert_eq!(files_to_prune(files, 0, 50), vec![PathBuf::from("old")]);
+    }
+}
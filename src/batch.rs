@@ -0,0 +1,184 @@
+// batch convert: every image in a folder through the export pipeline the
+// captures get (decorations, the output downscale, the configured format and
+// quality) into the output directory. the sources are only read. driven from
+// the history view and from `capscr --batch=<folder>`.
+
+use crate::config::Config;
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// decode + lanczos + encode is cpu-bound; more workers than this just fight
+// the capture path for cores
+const MAX_WORKERS: usize = 4;
+// stills only; a gif would come out as its first frame
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "bmp"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchConvertProgress {
+    /// files finished so far, this one included
+    pub done: usize,
+    pub total: usize,
+    pub source: String,
+    pub output: Option<String>,
+    pub error: Option<String>,
+}
+
+/// the folder's images, not recursing, in name order
+pub fn list_images(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut images: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && is_image(path))
+        .collect();
+    images.sort();
+    Ok(images)
+}
+
+fn is_image(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| IMAGE_EXTENSIONS.iter().any(|x| e.eq_ignore_ascii_case(x)))
+}
+
+/// convert every image in `dir`, calling `progress` as each one finishes.
+/// returns how many were written
+pub fn run(
+    dir: &Path,
+    config: &Config,
+    progress: impl Fn(&BatchConvertProgress) + Sync,
+) -> Result<usize> {
+    let same_folder = match (
+        std::fs::canonicalize(dir),
+        std::fs::canonicalize(&config.output.directory),
+    ) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    };
+    // the results would land among the sources and be picked up next run
+    if same_folder {
+        return Err(anyhow!(
+            "pick a folder other than the output folder; results are written there"
+        ));
+    }
+    let images = list_images(dir)?;
+    if images.is_empty() {
+        return Err(anyhow!(
+            "no png, jpeg, webp or bmp images in {}",
+            dir.display()
+        ));
+    }
+    std::fs::create_dir_all(&config.output.directory)?;
+    let total = images.len();
+    let next = AtomicUsize::new(0);
+    let done = AtomicUsize::new(0);
+    let written = AtomicUsize::new(0);
+    let workers = std::thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .clamp(1, MAX_WORKERS)
+        .min(total);
+    std::thread::scope(|s| {
+        for _ in 0..workers {
+            s.spawn(|| loop {
+                let Some(source) = images.get(next.fetch_add(1, Ordering::Relaxed)) else {
+                    break;
+                };
+                let outcome = convert_one(source, config);
+                if outcome.is_ok() {
+                    written.fetch_add(1, Ordering::Relaxed);
+                }
+                let (output, error) = match outcome {
+                    Ok(path) => (Some(path.to_string_lossy().into_owned()), None),
+                    Err(e) => (None, Some(format!("{e:#}"))),
+                };
+                progress(&BatchConvertProgress {
+                    done: done.fetch_add(1, Ordering::Relaxed) + 1,
+                    total,
+                    source: source.to_string_lossy().into_owned(),
+                    output,
+                    error,
+                });
+            });
+        }
+    });
+    Ok(written.into_inner())
+}
+
+/// one image through decorations and the downscale, saved in the output
+/// folder under its own name with the configured format's extension
+pub fn convert_one(source: &Path, config: &Config) -> Result<PathBuf> {
+    let mut image = image::open(source)?.to_rgba8();
+    // same order as a capture: decorations first, the downscale at save time
+    if config.output.decoration.is_active() {
+        image = crate::decorate::apply(&image, &config.output.decoration);
+    }
+    if let Some(small) = crate::decorate::downscale(&image, &config.output.resize) {
+        image = small;
+    }
+    let path = crate::clipboard::get_unique_filepath(&output_path(source, config));
+    if let Err(e) =
+        crate::clipboard::save_image(&image, &path, config.output.format, config.output.quality)
+    {
+        // get_unique_filepath claimed the name with an empty file
+        let _ = std::fs::remove_file(&path);
+        return Err(e);
+    }
+    Ok(path)
+}
+
+fn output_path(source: &Path, config: &Config) -> PathBuf {
+    let stem = source
+        .file_stem()
+        .map_or_else(|| "image".into(), |s| s.to_string_lossy());
+    config
+        .output
+        .directory
+        .join(format!("{stem}.{}", config.output.format.extension()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ImageFormat;
+
+    #[test]
+    fn outputs_keep_the_name_and_take_the_configured_format() {
+        let mut config = Config::default();
+        config.output.directory = PathBuf::from("out");
+        config.output.format = ImageFormat::Webp;
+        assert_eq!(
+            output_path(Path::new("shots/Screenshot 1.PNG"), &config),
+            PathBuf::from("out").join("Screenshot 1.webp")
+        );
+    }
+
+    #[test]
+    fn converts_a_folder_into_the_output_directory() {
+        let root =
+            std::env::temp_dir().join(format!("capscr_batch_{}", uuid::Uuid::new_v4().as_simple()));
+        let src = root.join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        for name in ["a.png", "b.bmp"] {
+            image::RgbaImage::from_pixel(8, 4, image::Rgba([10, 20, 30, 255]))
+                .save(src.join(name))
+                .unwrap();
+        }
+        std::fs::write(src.join("notes.txt"), "not an image").unwrap();
+        let mut config = Config::default();
+        config.output.directory = root.join("out");
+        config.output.format = ImageFormat::Png;
+
+        let seen = std::sync::Mutex::new(Vec::new());
+        let written = run(&src, &config, |p| seen.lock().unwrap().push(p.done)).unwrap();
+        assert_eq!(written, 2);
+        let mut seen = seen.into_inner().unwrap();
+        seen.sort();
+        assert_eq!(seen, vec![1, 2]);
+        assert!(root.join("out").join("a.png").exists());
+        assert!(root.join("out").join("b.png").exists());
+
+        assert!(run(&config.output.directory, &config, |_| {}).is_err());
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
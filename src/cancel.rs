@@ -0,0 +1,211 @@
+// cancelling what's in flight: a capture counting down its delay, an upload
+// still sending, a recording. the slow steps run under a Task and check it as
+// they go; cancel() ends every Task running at the time and has an active
+// recording thrown away instead of saved. the tray's Cancel item, there while
+// anything can be cancelled, does that. narrower ones end only their own: Esc,
+// bound while a countdown runs and only then (held any longer it would steal
+// Esc from whatever has focus), ends the countdowns, and on linux the Cancel
+// button on a countdown or upload toast ends that one task.
+
+use crate::recording::RecordingState;
+use crate::state::AppState;
+use crate::tray::SystemTray;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+const POLL: Duration = Duration::from_millis(25);
+
+// bumped by cancel; a Task begun under an older one has been cancelled
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+// bumped by Esc; only escapable Tasks watch it
+static ESCAPE_GENERATION: AtomicU64 = AtomicU64::new(0);
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+// the running tasks Esc cancels
+static ESCAPABLE: AtomicUsize = AtomicUsize::new(0);
+// set when a cancel lands on a recording, so finalizing drops it unsaved
+static DISCARD_RECORDING: AtomicBool = AtomicBool::new(false);
+
+/// the error a step returns when it stopped because it was cancelled. callers
+/// end quietly on it instead of reporting a failure
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl std::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// one cancellable step. it stops counting as in flight when dropped
+pub struct Task {
+    app: AppHandle,
+    generation: u64,
+    // the Esc generation it began under, for an escapable task
+    escape_generation: Option<u64>,
+    // set by this task's own toast button
+    own_cancel: Arc<AtomicBool>,
+}
+
+impl Task {
+    /// `escapable` binds Esc for as long as the task runs
+    pub fn begin(app: &AppHandle, escapable: bool) -> Task {
+        RUNNING.fetch_add(1, Ordering::SeqCst);
+        let rebind = escapable && ESCAPABLE.fetch_add(1, Ordering::SeqCst) == 0;
+        changed(app, rebind);
+        Task {
+            app: app.clone(),
+            generation: GENERATION.load(Ordering::SeqCst),
+            escape_generation: escapable.then(|| ESCAPE_GENERATION.load(Ordering::SeqCst)),
+            own_cancel: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn cancelled(&self) -> bool {
+        GENERATION.load(Ordering::SeqCst) != self.generation
+            || self
+                .escape_generation
+                .is_some_and(|g| ESCAPE_GENERATION.load(Ordering::SeqCst) != g)
+            || self.own_cancel.load(Ordering::SeqCst)
+    }
+
+    /// sleep for `duration`, waking early on a cancel. false when cancelled
+    pub fn sleep(&self, duration: Duration) -> bool {
+        wait(duration, || self.cancelled())
+    }
+
+    /// wait for what a worker thread sends back, or for a cancel
+    pub fn recv<T>(&self, rx: &crossbeam_channel::Receiver<T>) -> anyhow::Result<T> {
+        loop {
+            match rx.recv_timeout(POLL) {
+                Ok(value) => return Ok(value),
+                Err(crossbeam_channel::RecvTimeoutError::Timeout) => {
+                    if self.cancelled() {
+                        return Err(Cancelled.into());
+                    }
+                }
+                Err(crossbeam_channel::RecvTimeoutError::Disconnected) => {
+                    anyhow::bail!("worker exited without a result")
+                }
+            }
+        }
+    }
+
+    /// a toast with a Cancel button for this task alone. only the freedesktop
+    /// backend reports the click back; elsewhere it would be a toast with no
+    /// button, so nothing is shown and the tray item is the way to cancel
+    pub fn offer_cancel(&self, title: &str, body: &str) {
+        if !cfg!(target_os = "linux") {
+            return;
+        }
+        let app = self.app.clone();
+        // a toast outliving its task flags a task no one checks any more
+        let own_cancel = self.own_cancel.clone();
+        let _ = crate::clipboard::show_notification_with_actions(
+            title,
+            body,
+            None,
+            &[("cancel", crate::i18n::t("common.cancel"))],
+            move |action| {
+                if action == "cancel" && !own_cancel.swap(true, Ordering::SeqCst) {
+                    tracing::info!("cancelled one task from its toast");
+                    let _ = app.emit("capscr://cancelled", ());
+                }
+            },
+        );
+    }
+}
+
+impl Drop for Task {
+    fn drop(&mut self) {
+        RUNNING.fetch_sub(1, Ordering::SeqCst);
+        let rebind =
+            self.escape_generation.is_some() && ESCAPABLE.fetch_sub(1, Ordering::SeqCst) == 1;
+        changed(&self.app, rebind);
+    }
+}
+
+/// whether anything cancellable is in flight
+pub fn running() -> bool {
+    RUNNING.load(Ordering::SeqCst) > 0
+}
+
+/// whether Esc should be bound to cancel right now
+pub fn escapable() -> bool {
+    ESCAPABLE.load(Ordering::SeqCst) > 0
+}
+
+/// cancel every running task, and discard a recording in progress
+pub fn cancel(app: &AppHandle) {
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+    let recording = matches!(
+        *app.state::<AppState>().recording_state.lock().unwrap(),
+        RecordingState::Recording
+    );
+    if recording {
+        DISCARD_RECORDING.store(true, Ordering::SeqCst);
+        crate::commands::trigger_task(app, crate::commands::STOP_RECORDING_HOTKEY_ID);
+    }
+    tracing::info!("cancelled what was in flight");
+    let _ = app.emit("capscr://cancelled", ());
+}
+
+/// cancel the countdowns that bound Esc, leaving uploads and a recording be
+pub fn cancel_escapable(app: &AppHandle) {
+    ESCAPE_GENERATION.fetch_add(1, Ordering::SeqCst);
+    tracing::info!("cancelled the countdown");
+    let _ = app.emit("capscr://cancelled", ());
+}
+
+/// whether the recording being finalized was cancelled. clears the flag
+pub fn take_discarded() -> bool {
+    DISCARD_RECORDING.swap(false, Ordering::SeqCst)
+}
+
+// false when `cancelled` turned true before `duration` ran out
+fn wait(duration: Duration, cancelled: impl Fn() -> bool) -> bool {
+    let deadline = Instant::now() + duration;
+    loop {
+        if cancelled() {
+            return false;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        std::thread::sleep(POLL.min(deadline - now));
+    }
+}
+
+// `rebind` is true when Esc starts or stops being wanted
+fn changed(app: &AppHandle, rebind: bool) {
+    if rebind {
+        app.state::<AppState>().reload_hotkeys();
+    }
+    SystemTray::rebuild(app);
+    let _ = app.emit("capscr://cancellable", running());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+
+    #[test]
+    fn a_wait_ends_early_once_cancelled() {
+        assert!(wait(Duration::from_millis(30), || false));
+        let checks = AtomicU32::new(0);
+        let started = Instant::now();
+        let finished = wait(Duration::from_secs(30), || {
+            checks.fetch_add(1, Ordering::SeqCst) >= 2
+        });
+        assert!(!finished);
+        assert!(started.elapsed() < Duration::from_secs(5));
+        // and a cancelled error can be told apart from a failure
+        let err: anyhow::Error = Cancelled.into();
+        assert!(err.is::<Cancelled>());
+    }
+}
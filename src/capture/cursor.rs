@@ -381,11 +381,19 @@ mod x11_impl {
 // global pointer position where the platform can answer it. wayland can't
 // (by design), which callers treat as "fall back to the primary monitor"
 pub fn pointer_position() -> Option<(i32, i32)> {
+    #[cfg(windows)]
+    {
+        use windows::Win32::Foundation::POINT;
+        use windows::Win32::UI::WindowsAndMessaging::GetCursorPos;
+        let mut p = POINT::default();
+        unsafe { GetCursorPos(&mut p).ok()? };
+        Some((p.x, p.y))
+    }
     #[cfg(target_os = "linux")]
     {
         x11_impl::pointer_position()
     }
-    #[cfg(not(target_os = "linux"))]
+    #[cfg(not(any(windows, target_os = "linux")))]
     {
         None
     }
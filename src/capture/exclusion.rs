@@ -0,0 +1,240 @@
+// capture exclusion list. windows belonging to the listed apps (password
+// managers, banking clients) are blacked out of captures and recordings, or
+// keep the capture from happening at all. it works off window geometry after
+// the grab, so an excluded window's whole rect is masked even where another
+// window overlaps it — over-masking is the failure we want here, the pixels
+// we'd need to tell what's on top are exactly the ones we can't trust
+
+use anyhow::{anyhow, Context, Result};
+use image::{Rgba, RgbaImage};
+use xcap::Window;
+
+use super::Rectangle;
+use crate::config::{ExclusionConfig, ExclusionMode};
+
+/// an on-screen window of an excluded app
+#[derive(Debug, Clone)]
+pub struct ExcludedWindow {
+    pub id: u32,
+    pub app: String,
+    pub rect: Rectangle,
+}
+
+/// visible windows of the listed apps, in screen coordinates. an error means
+/// the windows couldn't be enumerated; callers fail closed on it
+pub fn excluded_windows(apps: &[String]) -> Result<Vec<ExcludedWindow>> {
+    if apps.is_empty() {
+        return Ok(Vec::new());
+    }
+    let own_pid = std::process::id();
+    let mut found = Vec::new();
+    for window in Window::all().context("list windows for the exclusion list")? {
+        // an unknown minimized state counts as visible: better to mask a
+        // window that wasn't there than miss one that was
+        if window.is_minimized().unwrap_or(false) {
+            continue;
+        }
+        let pid = window.pid().unwrap_or(0);
+        if pid == own_pid {
+            continue;
+        }
+        let names = [
+            window.app_name().unwrap_or_default(),
+            process_name(pid).unwrap_or_default(),
+        ];
+        let Some(app) = names.into_iter().find(|name| app_matches(apps, name)) else {
+            continue;
+        };
+        let Ok(id) = window.id() else {
+            continue;
+        };
+        if let Some(rect) = window_geometry(&window, id) {
+            found.push(ExcludedWindow { id, app, rect });
+        }
+    }
+    Ok(found)
+}
+
+/// apply the exclusion list to a finished capture whose top-left pixel sits
+/// at `origin` on screen, returning how many windows were blacked out.
+/// `picked_window` is the window a window capture targeted, which is refused
+/// outright when it's excluded itself
+pub fn apply(
+    img: &mut RgbaImage,
+    origin: (i32, i32),
+    config: &ExclusionConfig,
+    picked_window: Option<u32>,
+) -> Result<usize> {
+    let windows = excluded_windows(&config.apps)?;
+    if let Some(hit) = picked_window.and_then(|id| windows.iter().find(|w| w.id == id)) {
+        return Err(anyhow!("{} is on the capture exclusion list", hit.app));
+    }
+    match config.mode {
+        ExclusionMode::Blackout => {
+            let painted = mask(img, origin, &windows);
+            if painted > 0 {
+                tracing::info!("capture exclusion blacked out {painted} window(s)");
+            }
+            Ok(painted)
+        }
+        ExclusionMode::Skip => match overlapping(&windows, origin, img.dimensions()) {
+            Some(hit) => Err(anyhow!(
+                "capture skipped: {} is on screen and on the exclusion list",
+                hit.app
+            )),
+            None => Ok(0),
+        },
+    }
+}
+
+/// paint every window that overlaps the frame black, returning how many did
+pub fn mask(img: &mut RgbaImage, origin: (i32, i32), windows: &[ExcludedWindow]) -> usize {
+    let mut painted = 0;
+    for window in windows {
+        let Some((x0, y0, x1, y1)) = frame_overlap(&window.rect, origin, img.dimensions()) else {
+            continue;
+        };
+        for y in y0..y1 {
+            for x in x0..x1 {
+                img.put_pixel(x, y, Rgba([0, 0, 0, 255]));
+            }
+        }
+        painted += 1;
+    }
+    painted
+}
+
+/// the first window that shows up anywhere in a frame of `size` at `origin`
+pub fn overlapping(
+    windows: &[ExcludedWindow],
+    origin: (i32, i32),
+    size: (u32, u32),
+) -> Option<&ExcludedWindow> {
+    windows
+        .iter()
+        .find(|w| frame_overlap(&w.rect, origin, size).is_some())
+}
+
+// a window rect in frame pixel coordinates, clipped to the frame
+fn frame_overlap(
+    rect: &Rectangle,
+    origin: (i32, i32),
+    (width, height): (u32, u32),
+) -> Option<(u32, u32, u32, u32)> {
+    let left = (rect.x as i64 - origin.0 as i64).max(0);
+    let top = (rect.y as i64 - origin.1 as i64).max(0);
+    let right = (rect.x as i64 + rect.width as i64 - origin.0 as i64).min(width as i64);
+    let bottom = (rect.y as i64 + rect.height as i64 - origin.1 as i64).min(height as i64);
+    (left < right && top < bottom).then_some((left as u32, top as u32, right as u32, bottom as u32))
+}
+
+// "KeePassXC", "keepassxc.exe" and "KEEPASSXC" all name the same app
+fn normalize(name: &str) -> String {
+    let lower = name.trim().to_lowercase();
+    match lower.strip_suffix(".exe") {
+        Some(stem) => stem.to_string(),
+        None => lower,
+    }
+}
+
+pub fn app_matches(apps: &[String], name: &str) -> bool {
+    let name = normalize(name);
+    !name.is_empty() && apps.iter().any(|app| normalize(app) == name)
+}
+
+// the dwm frame on windows, so the invisible resize border isn't masked along
+// with the window
+fn window_geometry(window: &Window, id: u32) -> Option<Rectangle> {
+    #[cfg(windows)]
+    if let Some(rect) = super::window_rect(id, crate::config::WindowFrame::Visible) {
+        return Some(rect);
+    }
+    #[cfg(not(windows))]
+    let _ = id;
+    Some(Rectangle::new(
+        window.x().ok()?,
+        window.y().ok()?,
+        window.width().ok()?,
+        window.height().ok()?,
+    ))
+}
+
+// the executable name, since xcap's app name is the file description on
+// windows and the wm class on x11, neither of which is what users type
+#[cfg(windows)]
+pub(crate) fn process_name(pid: u32) -> Option<String> {
+    use windows::core::PWSTR;
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+        PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+
+    unsafe {
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let mut buf = [0u16; 1024];
+        let mut len = buf.len() as u32;
+        let queried = QueryFullProcessImageNameW(
+            process,
+            PROCESS_NAME_WIN32,
+            PWSTR(buf.as_mut_ptr()),
+            &mut len,
+        );
+        let _ = CloseHandle(process);
+        queried.ok()?;
+        let path = String::from_utf16_lossy(&buf[..len as usize]);
+        std::path::Path::new(&path)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn process_name(pid: u32) -> Option<String> {
+    std::fs::read_to_string(format!("/proc/{pid}/comm"))
+        .ok()
+        .map(|name| name.trim().to_string())
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+pub(crate) fn process_name(_pid: u32) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn excluded(x: i32, y: i32, width: u32, height: u32) -> ExcludedWindow {
+        ExcludedWindow {
+            id: 1,
+            app: "keepassxc".into(),
+            rect: Rectangle::new(x, y, width, height),
+        }
+    }
+
+    #[test]
+    fn app_names_match_with_or_without_exe() {
+        let apps = vec!["KeePassXC".to_string(), "1password.exe".to_string()];
+        assert!(app_matches(&apps, "keepassxc.exe"));
+        assert!(app_matches(&apps, "1Password"));
+        assert!(!app_matches(&apps, "keepass"));
+        assert!(!app_matches(&apps, ""));
+    }
+
+    #[test]
+    fn mask_paints_only_the_part_inside_the_frame() {
+        // a 10x10 frame at screen (100, 100); the window hangs off its top-left
+        let mut img = RgbaImage::from_pixel(10, 10, Rgba([255, 255, 255, 255]));
+        let windows = [excluded(95, 95, 10, 10)];
+        assert_eq!(mask(&mut img, (100, 100), &windows), 1);
+        assert_eq!(img.get_pixel(4, 4), &Rgba([0, 0, 0, 255]));
+        assert_eq!(img.get_pixel(5, 5), &Rgba([255, 255, 255, 255]));
+        assert_eq!(img.get_pixel(0, 9), &Rgba([255, 255, 255, 255]));
+
+        let elsewhere = [excluded(0, 0, 50, 50)];
+        assert_eq!(mask(&mut img, (100, 100), &elsewhere), 0);
+        assert!(overlapping(&elsewhere, (100, 100), (10, 10)).is_none());
+        assert!(overlapping(&windows, (100, 100), (10, 10)).is_some());
+    }
+}
@@ -106,6 +106,77 @@ pub fn fast_gdi_capture(x: i32, y: i32, width: u32, height: u32) -> Result<RgbaI
     }
 }
 
+// PW_RENDERFULLCONTENT isn't in the metadata windows-rs is generated from
+const PW_RENDERFULLCONTENT: u32 = 0x2;
+
+/// have a window paint itself into a bitmap with PrintWindow, so windows on
+/// top of it, the screen edge or another virtual desktop don't matter.
+/// PW_RENDERFULLCONTENT gets dwm to include DirectComposition content
+/// (chromium, WebView2, UWP) that a plain WM_PRINT leaves black. the result
+/// covers the full GetWindowRect, or the client area with `client_only`
+pub fn print_window(hwnd: HWND, client_only: bool) -> Result<RgbaImage> {
+    use windows::Win32::Storage::Xps::{PrintWindow, PRINT_WINDOW_FLAGS, PW_CLIENTONLY};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetClientRect, GetWindowRect, IsIconic, IsWindow,
+    };
+
+    unsafe {
+        if !IsWindow(hwnd).as_bool() {
+            return Err(anyhow!("window is gone"));
+        }
+        // a minimized window has nothing left to paint
+        if IsIconic(hwnd).as_bool() {
+            return Err(anyhow!("window is minimized"));
+        }
+        let mut rect = RECT::default();
+        let mut flags = PW_RENDERFULLCONTENT;
+        if client_only {
+            GetClientRect(hwnd, &mut rect)?;
+            flags |= PW_CLIENTONLY.0;
+        } else {
+            GetWindowRect(hwnd, &mut rect)?;
+        }
+        let width = rect.right - rect.left;
+        let height = rect.bottom - rect.top;
+        if width <= 0 || height <= 0 {
+            return Err(anyhow!("window has no area"));
+        }
+
+        let screen_dc = GetDC(HWND::default());
+        if screen_dc.is_invalid() {
+            return Err(anyhow!("GetDC failed"));
+        }
+        let mem_dc = CreateCompatibleDC(screen_dc);
+        ReleaseDC(HWND::default(), screen_dc);
+        if mem_dc.is_invalid() {
+            return Err(anyhow!("CreateCompatibleDC failed"));
+        }
+        let Some((bitmap, bits_ptr)) = create_32bpp_dib(width, height) else {
+            let _ = DeleteDC(mem_dc);
+            return Err(anyhow!("create_32bpp_dib failed"));
+        };
+        let old_bitmap = SelectObject(mem_dc, bitmap);
+        let ok = PrintWindow(hwnd, mem_dc, PRINT_WINDOW_FLAGS(flags)).as_bool();
+        let _ = GdiFlush();
+
+        let pixel_count = width as usize * height as usize;
+        let mut rgba_data = vec![0u8; pixel_count * 4];
+        if ok {
+            let src = std::slice::from_raw_parts(bits_ptr as *const u8, pixel_count * 4);
+            // the alpha PrintWindow leaves behind is as undefined as BitBlt's
+            super::par_convert(src, &mut rgba_data, |s| [s[2], s[1], s[0], 255]);
+        }
+        SelectObject(mem_dc, old_bitmap);
+        let _ = DeleteObject(bitmap);
+        let _ = DeleteDC(mem_dc);
+        if !ok {
+            return Err(anyhow!("PrintWindow failed"));
+        }
+        RgbaImage::from_raw(width as u32, height as u32, rgba_data)
+            .ok_or_else(|| anyhow!("RgbaImage::from_raw failed"))
+    }
+}
+
 use super::MonitorInfo;
 use windows::Win32::Foundation::{BOOL, LPARAM, RECT};
 use windows::Win32::Graphics::Gdi::{
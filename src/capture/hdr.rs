@@ -3,7 +3,7 @@ use image::RgbaImage;
 
 use super::current_tonemap_params;
 use super::tonemapping::{
-    hdr10_to_sdr_bt2390, hlg_to_sdr_bt2390, scrgb_to_sdr_bt2390, TonemapParams,
+    hdr10_packed_to_sdr_bt2390, hlg_to_sdr_bt2390, scrgb_half_to_sdr_bt2390, TonemapParams,
 };
 
 const MAX_HDR_DIMENSION: u32 = 16384;
@@ -12,7 +12,7 @@ const MAX_HDR_PIXELS: usize = 256 * 1024 * 1024;
 // IEEE 754 binary16 -> binary32 conversion. used to decode scRGB pixels from
 // the DXGI desktop duplication texture (R16G16B16A16_FLOAT). manual unpack
 // to avoid pulling in the `half` crate for one function.
-fn f16_to_f32(bits: u16) -> f32 {
+pub(super) fn f16_to_f32(bits: u16) -> f32 {
     let sign = ((bits >> 15) & 0x1) as u32;
     let exp = ((bits >> 10) & 0x1F) as u32;
     let mant = (bits & 0x3FF) as u32;
@@ -113,7 +113,10 @@ impl Default for HdrDisplayInfo {
 // colorimetry — `capscr --wayland-diag`'s hdr-readiness section
 // (color_probe.rs + ExtCopySession::offered_formats) watches both in the
 // field.
-pub struct HdrCapture;
+pub struct HdrCapture {
+    // overrides the configured operator for this capture only
+    operator: Option<crate::config::TonemapOperator>,
+}
 
 #[cfg(target_os = "linux")]
 mod linux_hdr {
@@ -169,7 +172,12 @@ mod linux_hdr {
 
 impl HdrCapture {
     pub fn new() -> Self {
-        Self
+        Self { operator: None }
+    }
+
+    pub fn with_operator(mut self, operator: crate::config::TonemapOperator) -> Self {
+        self.operator = Some(operator);
+        self
     }
 
     pub fn get_display_hdr_info() -> Result<HdrDisplayInfo> {
@@ -195,10 +203,19 @@ impl HdrCapture {
         }
     }
 
+    // true when any monitor is in HDR mode; which one a capture hits is
+    // decided per target by get_display_hdr_info_at / is_hdr_at_point
     pub fn is_hdr_available() -> bool {
-        Self::get_display_hdr_info()
-            .map(|info| info.is_hdr_enabled)
-            .unwrap_or(false)
+        #[cfg(target_os = "windows")]
+        {
+            windows_hdr::any_hdr_output()
+        }
+        #[cfg(not(target_os = "windows"))]
+        {
+            Self::get_display_hdr_info()
+                .map(|info| info.is_hdr_enabled)
+                .unwrap_or(false)
+        }
     }
 
     pub fn is_hdr_at_point(x: i32, y: i32) -> bool {
@@ -277,7 +294,7 @@ impl HdrCapture {
                 width,
                 height,
                 format,
-                data: raw_data,
+                data: raw_data.into(),
                 max_luminance_nits: hdr_info.max_luminance,
             })
         };
@@ -334,7 +351,10 @@ impl HdrCapture {
             _ => return RgbaImage::new(1, 1),
         };
 
-        let params: TonemapParams = current_tonemap_params();
+        let mut params: TonemapParams = current_tonemap_params();
+        if let Some(operator) = self.operator {
+            params.operator = operator;
+        }
 
         match format {
             HdrFormat::ScRgb => {
@@ -347,24 +367,12 @@ impl HdrCapture {
                     );
                     return RgbaImage::new(width, height);
                 }
-                let float_data: Vec<f32> = raw_data[..expected_bytes]
-                    .chunks_exact(2)
-                    .map(|chunk| {
-                        let bits = u16::from_le_bytes([chunk[0], chunk[1]]);
-                        let v = f16_to_f32(bits);
-                        if v.is_finite() {
-                            v
-                        } else {
-                            0.0
-                        }
-                    })
-                    .collect();
-                scrgb_to_sdr_bt2390(&float_data, width, height, sdr_white, params)
+                scrgb_half_to_sdr_bt2390(raw_data, width, height, sdr_white, params)
             }
             HdrFormat::Hdr10 => {
                 // DXGI desktop duplication delivers HDR10 as R10G10B10A2_UNORM,
                 // 4 bytes per pixel, packed: r=bits 0-9, g=10-19, b=20-29, a=30-31.
-                // unpack into PQ-normalized u16 quads for hdr10_to_sdr_bt2390.
+                // the tonemap workers unpack it in place
                 let expected_bytes = pixel_count.saturating_mul(4);
                 if raw_data.len() < expected_bytes {
                     tracing::warn!(
@@ -374,21 +382,7 @@ impl HdrCapture {
                     );
                     return RgbaImage::new(width, height);
                 }
-                let mut u16_data: Vec<u16> = Vec::with_capacity(pixel_count * 4);
-                for chunk in raw_data[..expected_bytes].chunks_exact(4) {
-                    let packed = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
-                    let r = (packed & 0x3FF) as u16; // 10 bits
-                    let g = ((packed >> 10) & 0x3FF) as u16;
-                    let b = ((packed >> 20) & 0x3FF) as u16;
-                    let a = ((packed >> 30) & 0x3) as u16; // 2 bits
-                                                           // hdr10_to_sdr_bt2390 normalises by 65535.0, so map our
-                                                           // 10-bit and 2-bit channels into the 16-bit space.
-                    u16_data.push(r << 6 | r >> 4); // 10-bit -> 16-bit via bit replication
-                    u16_data.push(g << 6 | g >> 4);
-                    u16_data.push(b << 6 | b >> 4);
-                    u16_data.push(a * 0x5555); // 2-bit -> 16-bit via repeat
-                }
-                hdr10_to_sdr_bt2390(&u16_data, width, height, sdr_white, params)
+                hdr10_packed_to_sdr_bt2390(raw_data, width, height, sdr_white, params)
             }
             HdrFormat::Hlg => {
                 let expected_bytes = pixel_count.saturating_mul(4);
@@ -402,17 +396,13 @@ impl HdrCapture {
                 if raw_data.len() < expected_bytes {
                     return RgbaImage::new(width, height);
                 }
-                let mut result = RgbaImage::new(width, height);
-                for (i, pixel) in raw_data.chunks_exact(4).enumerate() {
-                    if i >= pixel_count {
-                        break;
-                    }
-                    let x = (i as u32) % width;
-                    let y = (i as u32) / width;
-                    // DXGI_FORMAT_B8G8R8A8_UNORM is BGRA, swap R and B to match RGBA output
-                    result.put_pixel(x, y, image::Rgba([pixel[2], pixel[1], pixel[0], pixel[3]]));
-                }
-                result
+                let mut rgba = vec![0u8; expected_bytes];
+                // DXGI_FORMAT_B8G8R8A8_UNORM is BGRA, swap R and B to match RGBA output
+                super::par_convert(&raw_data[..expected_bytes], &mut rgba, |s| {
+                    [s[2], s[1], s[0], s[3]]
+                });
+                RgbaImage::from_raw(width, height, rgba)
+                    .unwrap_or_else(|| RgbaImage::new(width, height))
             }
         }
     }
@@ -424,6 +414,23 @@ impl Default for HdrCapture {
     }
 }
 
+// index of the output a capture at `target` should use, given each output's
+// desktop rect as (left, top, right, bottom): the one containing the point,
+// else the primary (the one holding the desktop origin), else the first. HDR
+// detection, white level and duplication all resolve through this, so a
+// mixed HDR/SDR desktop never reads one monitor's state for another's pixels
+fn output_index_for(rects: &[(i32, i32, i32, i32)], target: Option<(i32, i32)>) -> Option<usize> {
+    let containing = |x: i32, y: i32| {
+        rects
+            .iter()
+            .position(|&(l, t, r, b)| x >= l && x < r && y >= t && y < b)
+    };
+    target
+        .and_then(|(x, y)| containing(x, y))
+        .or_else(|| containing(0, 0))
+        .or((!rects.is_empty()).then_some(0))
+}
+
 #[cfg(target_os = "windows")]
 mod windows_hdr {
     use super::*;
@@ -496,50 +503,29 @@ mod windows_hdr {
         get_hdr_display_info_at(None)
     }
 
-    // resolve HDR info for the output that contains `target`, not just the first
-    // HDR output. on a multi-HDR-monitor desktop each panel can have a different
-    // SDR-content brightness, so tonemapping the secondary monitor against the
-    // primary's SDR white level came out too bright or too dim. falls back to the
-    // first HDR output when the target is None or lands on no enumerated output.
+    // resolve HDR info for the output that contains `target` (the primary when
+    // None). on a multi-monitor desktop each panel has its own HDR state and
+    // SDR-content brightness: tonemapping the secondary against the primary's
+    // white level came out too bright or too dim, and an SDR monitor next to an
+    // HDR one used to borrow the HDR one's info. an SDR output reports the SDR
+    // default so the caller falls back to the plain capture for it
     pub fn get_hdr_display_info_at(target: Option<(i32, i32)>) -> Result<HdrDisplayInfo> {
         unsafe {
             let factory: IDXGIFactory1 = CreateDXGIFactory1()?;
+            let (_, output) = pick_adapter_output(&factory, target)?;
+            Ok(hdr_info_from_output(&output).unwrap_or_default())
+        }
+    }
 
-            if let Some((tx, ty)) = target {
-                let mut adapter_idx = 0u32;
-                while let Ok(adapter) = factory.EnumAdapters1(adapter_idx) {
-                    let mut output_idx = 0u32;
-                    while let Ok(output) = adapter.EnumOutputs(output_idx) {
-                        let output: IDXGIOutput = output;
-                        if let Ok(desc) = output.GetDesc() {
-                            let r = desc.DesktopCoordinates;
-                            if tx >= r.left && tx < r.right && ty >= r.top && ty < r.bottom {
-                                if let Some(info) = hdr_info_from_output(&output) {
-                                    return Ok(info);
-                                }
-                            }
-                        }
-                        output_idx += 1;
-                    }
-                    adapter_idx += 1;
-                }
-            }
-
-            // no target, or target not on an HDR output: first HDR output wins
-            let mut adapter_idx = 0u32;
-            while let Ok(adapter) = factory.EnumAdapters1(adapter_idx) {
-                let mut output_idx = 0u32;
-                while let Ok(output) = adapter.EnumOutputs(output_idx) {
-                    let output: IDXGIOutput = output;
-                    if let Some(info) = hdr_info_from_output(&output) {
-                        return Ok(info);
-                    }
-                    output_idx += 1;
-                }
-                adapter_idx += 1;
-            }
-
-            Ok(HdrDisplayInfo::default())
+    // whether any output is in an HDR mode, for gating the HDR paths at all
+    pub fn any_hdr_output() -> bool {
+        unsafe {
+            let Ok(factory) = CreateDXGIFactory1::<IDXGIFactory1>() else {
+                return false;
+            };
+            enumerate_outputs(&factory)
+                .iter()
+                .any(|(_, output, _)| output_is_hdr(output))
         }
     }
 
@@ -675,24 +661,16 @@ mod windows_hdr {
         }
 
         let is_hdr = unsafe {
-            let factory = match CreateDXGIFactory1() {
+            let factory: IDXGIFactory1 = match CreateDXGIFactory1() {
                 Ok(f) => f,
                 Err(_) => return false,
             };
-            let (_, output) = match pick_adapter_output(&factory, Some((x, y))) {
-                Ok(res) => res,
-                Err(_) => return false,
-            };
-            if let Ok(output6) = output.cast::<IDXGIOutput6>() {
-                if let Ok(desc1) = output6.GetDesc1() {
-                    let color_space = desc1.ColorSpace.0;
-                    color_space == 12 || color_space == 13 || color_space == 14
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
+            // a point on no output is not HDR; the primary fallback of
+            // pick_adapter_output would answer for a different monitor
+            enumerate_outputs(&factory)
+                .iter()
+                .find(|(_, _, (l, t, r, b))| x >= *l && x < *r && y >= *t && y < *b)
+                .is_some_and(|(_, output, _)| output_is_hdr(output))
         };
 
         if let Ok(mut cache) = cache_mutex.lock() {
@@ -1077,31 +1055,46 @@ mod windows_hdr {
         }
     }
 
-    fn pick_adapter_output(
+    // every output on every adapter with its desktop rect
+    unsafe fn enumerate_outputs(
         factory: &IDXGIFactory1,
-        target: Option<(i32, i32)>,
-    ) -> Result<(IDXGIAdapter1, IDXGIOutput)> {
-        unsafe {
-            if let Some((tx, ty)) = target {
-                let mut adapter_idx = 0u32;
-                while let Ok(adapter) = factory.EnumAdapters1(adapter_idx) {
-                    let mut output_idx = 0u32;
-                    while let Ok(output) = adapter.EnumOutputs(output_idx) {
-                        if let Ok(desc) = output.GetDesc() {
-                            let r = desc.DesktopCoordinates;
-                            if tx >= r.left && tx < r.right && ty >= r.top && ty < r.bottom {
-                                return Ok((adapter, output));
-                            }
-                        }
-                        output_idx += 1;
-                    }
-                    adapter_idx += 1;
+    ) -> Vec<(IDXGIAdapter1, IDXGIOutput, (i32, i32, i32, i32))> {
+        let mut outputs = Vec::new();
+        let mut adapter_idx = 0u32;
+        while let Ok(adapter) = factory.EnumAdapters1(adapter_idx) {
+            let mut output_idx = 0u32;
+            while let Ok(output) = adapter.EnumOutputs(output_idx) {
+                if let Ok(desc) = output.GetDesc() {
+                    let r = desc.DesktopCoordinates;
+                    outputs.push((adapter.clone(), output, (r.left, r.top, r.right, r.bottom)));
                 }
+                output_idx += 1;
             }
-            let adapter = factory.EnumAdapters1(0)?;
-            let output = adapter.EnumOutputs(0)?;
-            Ok((adapter, output))
+            adapter_idx += 1;
         }
+        outputs
+    }
+
+    unsafe fn output_is_hdr(output: &IDXGIOutput) -> bool {
+        output
+            .cast::<IDXGIOutput6>()
+            .and_then(|output6| output6.GetDesc1())
+            .is_ok_and(|desc1| matches!(desc1.ColorSpace.0, 12..=14))
+    }
+
+    // the output containing `target`, else the primary (see output_index_for).
+    // used to be adapter 0 / output 0 for None, which on some multi-GPU and
+    // re-ordered setups isn't the primary and didn't match the HDR info
+    fn pick_adapter_output(
+        factory: &IDXGIFactory1,
+        target: Option<(i32, i32)>,
+    ) -> Result<(IDXGIAdapter1, IDXGIOutput)> {
+        let mut outputs = unsafe { enumerate_outputs(factory) };
+        let rects: Vec<_> = outputs.iter().map(|(_, _, rect)| *rect).collect();
+        let index = output_index_for(&rects, target)
+            .ok_or_else(|| anyhow!("no DXGI outputs to capture"))?;
+        let (adapter, output, _) = outputs.swap_remove(index);
+        Ok((adapter, output))
     }
 
     pub fn prewarm_d3d_devices() {
@@ -1187,6 +1180,19 @@ mod tests {
         assert_eq!(info.format, HdrFormat::Sdr);
     }
 
+    #[test]
+    fn output_for_target_prefers_containing_then_primary() {
+        // secondary on the left, primary at the origin
+        let rects = [(-1920, 0, 0, 1080), (0, 0, 2560, 1440)];
+        assert_eq!(output_index_for(&rects, Some((-10, 500))), Some(0));
+        assert_eq!(output_index_for(&rects, Some((100, 100))), Some(1));
+        assert_eq!(output_index_for(&rects, None), Some(1));
+        // a stale point off every output lands on the primary
+        assert_eq!(output_index_for(&rects, Some((9000, 9000))), Some(1));
+        assert_eq!(output_index_for(&[(100, 100, 200, 200)], None), Some(0));
+        assert_eq!(output_index_for(&[], None), None);
+    }
+
     #[test]
     fn test_hdr_capture_creation() {
         let _capture = HdrCapture::new();
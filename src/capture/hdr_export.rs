@@ -0,0 +1,315 @@
+// HDR sidecar export in containers other than PNG. AVIF goes through the
+// recording ffmpeg (no pure-rust encoder here writes 10-bit with HDR colour
+// tags); JPEG XR goes through the Windows WIC encoder as linear scRGB halves,
+// the layout Game Bar and Snipping Tool use for their own HDR shots.
+//
+// same source scope as hdr_png: HDR10 only, other formats return errors so the
+// caller keeps just the SDR file
+
+use anyhow::{anyhow, Result};
+use std::path::{Path, PathBuf};
+
+use super::hdr::HdrFormat;
+use super::hdr_png::{
+    encode_hdr_png, packed_hdr10_words, pq_eotf_to_nits, pq_to_hlg_lut, unpack_rgb10a2, HdrBitmap,
+    HdrTransfer,
+};
+use crate::config::HdrContainer;
+
+/// every sidecar suffix capscr has ever written, for listing and cleanup
+pub const HDR_SIDECAR_SUFFIXES: &[&str] = &[".hdr.png", ".hdr.avif", ".hdr.jxr"];
+
+pub fn is_hdr_sidecar(file_name: &str) -> bool {
+    HDR_SIDECAR_SUFFIXES.iter().any(|s| file_name.ends_with(s))
+}
+
+/// the sidecars that exist next to `path`, whichever container they're in
+pub fn existing_hdr_sidecars(path: &Path) -> Vec<PathBuf> {
+    let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+        return Vec::new();
+    };
+    HDR_SIDECAR_SUFFIXES
+        .iter()
+        .map(|suffix| path.with_file_name(format!("{stem}{suffix}")))
+        .filter(|p| p.as_path() != path && p.exists())
+        .collect()
+}
+
+/// write `bitmap` to `path` in `container`. `quality` (1-100) only affects
+/// AVIF; PNG and JPEG XR are written losslessly
+pub fn encode_hdr_file(
+    path: &Path,
+    bitmap: &HdrBitmap,
+    transfer: HdrTransfer,
+    container: HdrContainer,
+    quality: u8,
+) -> Result<()> {
+    if container != HdrContainer::Png && bitmap.format != HdrFormat::Hdr10 {
+        return Err(anyhow!(
+            "{:?} HDR sources can't be exported yet; falling back to SDR",
+            bitmap.format
+        ));
+    }
+    match container {
+        HdrContainer::Png => encode_hdr_png(path, bitmap, transfer),
+        HdrContainer::Avif => encode_hdr10_avif(path, bitmap, transfer, quality),
+        HdrContainer::Jxr => encode_hdr10_jxr(path, bitmap),
+    }
+}
+
+// AV1 crf runs 0 (lossless) to 63
+fn avif_crf(quality: u8) -> u8 {
+    ((100 - quality.clamp(1, 100) as u32) * 63 / 100) as u8
+}
+
+fn encode_hdr10_avif(
+    path: &Path,
+    bitmap: &HdrBitmap,
+    transfer: HdrTransfer,
+    quality: u8,
+) -> Result<()> {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    if !crate::recording::is_ffmpeg_available() {
+        return Err(anyhow!(
+            "HDR AVIF export needs ffmpeg; record one MP4 first to fetch it"
+        ));
+    }
+    let words = packed_hdr10_words(bitmap)?;
+    let lut = (transfer == HdrTransfer::Hlg).then(pq_to_hlg_lut);
+    let mut rgb48 = Vec::with_capacity(bitmap.pixel_count() as usize * 6);
+    for word in words {
+        let [r, g, b, _] = unpack_rgb10a2(word);
+        for channel in [r, g, b] {
+            let value = lut.as_ref().map_or(channel, |lut| lut[channel as usize]);
+            rgb48.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    let trc = match transfer {
+        HdrTransfer::Pq => "smpte2084",
+        HdrTransfer::Hlg => "arib-std-b67",
+    };
+    let size = format!("{}x{}", bitmap.width, bitmap.height);
+    let crf = avif_crf(quality).to_string();
+    // libaom keeps full 4:4:4 chroma (sharp text); svt-av1 only does 4:2:0 and
+    // even dimensions, so it's the fallback for builds without libaom
+    let mut last_err = anyhow!("no AV1 encoder in this ffmpeg build");
+    let encoders = [
+        (
+            "libaom-av1",
+            "yuv444p10le",
+            "scale=out_color_matrix=bt2020:out_range=full",
+        ),
+        (
+            "libsvtav1",
+            "yuv420p10le",
+            "scale=out_color_matrix=bt2020:out_range=full,crop=trunc(iw/2)*2:trunc(ih/2)*2",
+        ),
+    ];
+    for (codec, pix_fmt, filter) in encoders {
+        let mut child = match crate::recording::ffmpeg_command()
+            .args([
+                "-f", "rawvideo", "-pix_fmt", "rgb48le", "-s", &size, "-i", "-",
+            ])
+            .args([
+                "-frames:v",
+                "1",
+                "-c:v",
+                codec,
+                "-crf",
+                &crf,
+                "-pix_fmt",
+                pix_fmt,
+            ])
+            .args(["-vf", filter])
+            .args(["-color_primaries", "bt2020", "-color_trc", trc])
+            .args(["-colorspace", "bt2020nc", "-color_range", "pc"])
+            .args(["-still-picture", "1", "-f", "avif", "-y"])
+            .arg(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(e) => return Err(anyhow!("Failed to spawn ffmpeg: {}", e)),
+        };
+        let wrote = child
+            .stdin
+            .take()
+            .ok_or_else(|| anyhow!("Failed to open ffmpeg stdin"))
+            .and_then(|mut stdin| stdin.write_all(&rgb48).map_err(Into::into));
+        let status = child.wait()?;
+        match wrote {
+            Ok(()) if status.success() => return Ok(()),
+            Ok(()) => last_err = anyhow!("ffmpeg {codec} exited with {status}"),
+            Err(e) => last_err = anyhow!("ffmpeg {codec}: {e}"),
+        }
+        let _ = std::fs::remove_file(path);
+    }
+    Err(last_err)
+}
+
+// scRGB: linear BT.709 primaries where 1.0 is 80 nits
+const SCRGB_REFERENCE_NITS: f32 = 80.0;
+// BT.2020 -> BT.709 linear RGB (BT.2087). wide-gamut colours come out negative,
+// which scRGB keeps instead of clipping
+const BT2020_TO_BT709: [[f32; 3]; 3] = [
+    [1.660_491, -0.587_641_1, -0.072_849_86],
+    [-0.124_550_5, 1.132_899_9, -0.008_349_4],
+    [-0.018_150_76, -0.100_578_9, 1.118_729_6],
+];
+
+// scRGB half-float RGBA for an HDR10 bitmap, 8 bytes per pixel little-endian
+fn hdr10_to_scrgb_half(bitmap: &HdrBitmap) -> Result<Vec<u8>> {
+    let words = packed_hdr10_words(bitmap)?;
+    // the source is 10-bit, so the PQ decode only ever sees 1024 codes
+    let linear: Vec<f32> = (0..1024)
+        .map(|code| pq_eotf_to_nits(code as f32 / 1023.0) / SCRGB_REFERENCE_NITS)
+        .collect();
+    let mut out = Vec::with_capacity(bitmap.pixel_count() as usize * 8);
+    for word in words {
+        let rgb = [
+            linear[(word & 0x3FF) as usize],
+            linear[((word >> 10) & 0x3FF) as usize],
+            linear[((word >> 20) & 0x3FF) as usize],
+        ];
+        for row in BT2020_TO_BT709 {
+            let value = row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2];
+            out.extend_from_slice(&f16_bits(value).to_le_bytes());
+        }
+        let alpha = ((word >> 30) & 0x3) as f32 / 3.0;
+        out.extend_from_slice(&f16_bits(alpha).to_le_bytes());
+    }
+    Ok(out)
+}
+
+// f32 -> IEEE half, round-half-up. anything under the smallest normal half
+// (~6e-5, i.e. 0.005 nits in scRGB) flushes to zero
+fn f16_bits(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exp = ((bits >> 23) & 0xFF) as i32 - 127 + 15;
+    let mantissa = bits & 0x7F_FFFF;
+    if value.is_nan() {
+        return sign | 0x7E00;
+    }
+    if exp >= 0x1F {
+        return sign | 0x7C00;
+    }
+    if exp <= 0 {
+        return sign;
+    }
+    // a rounding carry out of the mantissa bumps the exponent, which is the
+    // correctly rounded result (up to infinity at the very top)
+    let rounded = (((exp as u32) << 10) | (mantissa >> 13)) + ((mantissa >> 12) & 1);
+    sign | rounded.min(0x7C00) as u16
+}
+
+#[cfg(windows)]
+fn encode_hdr10_jxr(path: &Path, bitmap: &HdrBitmap) -> Result<()> {
+    use windows::core::HSTRING;
+    use windows::Win32::Foundation::GENERIC_WRITE;
+    use windows::Win32::Graphics::Imaging::{
+        CLSID_WICImagingFactory, GUID_ContainerFormatWmp, GUID_WICPixelFormat64bppRGBAHalf,
+        IWICImagingFactory, WICBitmapEncoderNoCache,
+    };
+    use windows::Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED,
+    };
+
+    let pixels = hdr10_to_scrgb_half(bitmap)?;
+    let stride = bitmap.width * 8;
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        let factory: IWICImagingFactory =
+            CoCreateInstance(&CLSID_WICImagingFactory, None, CLSCTX_INPROC_SERVER)?;
+        let stream = factory.CreateStream()?;
+        stream.InitializeFromFilename(&HSTRING::from(path.as_os_str()), GENERIC_WRITE.0)?;
+        let encoder = factory.CreateEncoder(&GUID_ContainerFormatWmp, std::ptr::null())?;
+        encoder.Initialize(&stream, WICBitmapEncoderNoCache)?;
+        let mut frame = None;
+        let mut options = None;
+        encoder.CreateNewFrame(&mut frame, &mut options)?;
+        let frame = frame.ok_or_else(|| anyhow!("WIC returned no JPEG XR frame"))?;
+        frame.Initialize(options.as_ref())?;
+        frame.SetSize(bitmap.width, bitmap.height)?;
+        let mut format = GUID_WICPixelFormat64bppRGBAHalf;
+        frame.SetPixelFormat(&mut format)?;
+        // the encoder may counter-offer another layout; converting to it would
+        // need a WIC format converter, and the Wmp encoder always takes halves
+        if format != GUID_WICPixelFormat64bppRGBAHalf {
+            return Err(anyhow!("JPEG XR encoder refused half-float RGBA"));
+        }
+        frame.WritePixels(bitmap.height, stride, &pixels)?;
+        frame.Commit()?;
+        encoder.Commit()?;
+    }
+    Ok(())
+}
+
+#[cfg(not(windows))]
+fn encode_hdr10_jxr(_path: &Path, bitmap: &HdrBitmap) -> Result<()> {
+    // still validate and convert so a bad buffer reports the same error here
+    hdr10_to_scrgb_half(bitmap)?;
+    Err(anyhow!("JPEG XR export needs the Windows imaging codecs"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn half_floats_match_ieee() {
+        assert_eq!(f16_bits(0.0), 0x0000);
+        assert_eq!(f16_bits(1.0), 0x3C00);
+        assert_eq!(f16_bits(0.5), 0x3800);
+        assert_eq!(f16_bits(-2.0), 0xC000);
+        assert_eq!(f16_bits(65504.0), 0x7BFF);
+        assert_eq!(f16_bits(1e6), 0x7C00);
+        assert_eq!(f16_bits(1e-6), 0x0000);
+    }
+
+    #[test]
+    fn scrgb_white_is_reference_relative() {
+        // PQ code for ~80 nits in every channel, opaque
+        let code = (0..1024u32)
+            .min_by(|a, b| {
+                let da = (pq_eotf_to_nits(*a as f32 / 1023.0) - 80.0).abs();
+                let db = (pq_eotf_to_nits(*b as f32 / 1023.0) - 80.0).abs();
+                da.total_cmp(&db)
+            })
+            .unwrap();
+        let word = code | (code << 10) | (code << 20) | (3 << 30);
+        let bitmap = HdrBitmap {
+            width: 1,
+            height: 1,
+            format: HdrFormat::Hdr10,
+            data: word.to_le_bytes().to_vec().into(),
+            max_luminance_nits: 1000.0,
+        };
+        let px = hdr10_to_scrgb_half(&bitmap).unwrap();
+        let channel = |i: usize| u16::from_le_bytes([px[i * 2], px[i * 2 + 1]]);
+        // neutral grey survives the gamut matrix (rows sum to 1) and lands
+        // near 1.0, within the 10-bit PQ step around 80 nits
+        for i in 0..3 {
+            let half = channel(i);
+            assert!(
+                (0x3B80..=0x3C40).contains(&half),
+                "channel {i}: {half:#06x}"
+            );
+        }
+        assert_eq!(channel(3), 0x3C00);
+    }
+
+    #[test]
+    fn sidecar_names_are_recognised() {
+        assert!(is_hdr_sidecar("capture_1.hdr.png"));
+        assert!(is_hdr_sidecar("capture_1.hdr.jxr"));
+        assert!(!is_hdr_sidecar("capture_1.png"));
+        assert_eq!(avif_crf(100), 0);
+        assert_eq!(avif_crf(1), 62);
+    }
+}
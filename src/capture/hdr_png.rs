@@ -18,6 +18,7 @@ use png::chunk::ChunkType;
 use std::fs::File;
 use std::io::BufWriter;
 use std::path::Path;
+use std::sync::Arc;
 
 use super::hdr::HdrFormat;
 
@@ -33,7 +34,10 @@ pub struct HdrBitmap {
     /// - `ScRgb`: R16G16B16A16 half-float, 8 bytes per pixel, little-endian.
     /// - `Hlg`:   4 bytes per pixel (HLG-encoded BT.2020 8-bit per channel).
     /// - `Sdr`:   should never reach this struct — caller drops it.
-    pub data: Vec<u8>,
+    ///
+    /// shared, so handing the bitmap to the save, history and sidecar workers
+    /// doesn't copy a 4k frame per consumer
+    pub data: Arc<[u8]>,
     pub max_luminance_nits: f32,
 }
 
@@ -90,7 +94,7 @@ pub(crate) fn unpack_rgb10a2(word: u32) -> [u16; 4] {
     ]
 }
 
-fn packed_hdr10_words(bitmap: &HdrBitmap) -> Result<impl Iterator<Item = u32> + '_> {
+pub(super) fn packed_hdr10_words(bitmap: &HdrBitmap) -> Result<impl Iterator<Item = u32> + '_> {
     let pixel_count = bitmap.pixel_count();
     let expected_bytes = pixel_count
         .checked_mul(4)
@@ -146,20 +150,7 @@ fn encode_hdr10_png(path: &Path, bitmap: &HdrBitmap) -> Result<()> {
 //   4. quantise back to u16, write 16-bit RGBA PNG, attach cICP 9/18/0/1
 fn encode_hdr10_as_hlg_png(path: &Path, bitmap: &HdrBitmap) -> Result<()> {
     let words = packed_hdr10_words(bitmap)?;
-
-    // build a 65536-entry LUT mapping PQ-encoded u16 -> HLG-encoded u16.
-    // the alpha channel passes through unchanged.
-    let mut pq_to_hlg = vec![0u16; 65536];
-    for i in 0..65536u32 {
-        let pq_norm = i as f32 / 65535.0;
-        let nits = pq_eotf_to_nits(pq_norm);
-        // normalise to HLG nominal peak. BT.2100 uses 1000 nits as the
-        // reference white for HLG; values above are theoretically allowed
-        // but capped here at 1.0 since HLG OETF is only defined on [0, 1]
-        let linear = (nits / 1000.0).clamp(0.0, 1.0);
-        let hlg_norm = hlg_oetf(linear);
-        pq_to_hlg[i as usize] = (hlg_norm * 65535.0).round().clamp(0.0, 65535.0) as u16;
-    }
+    let pq_to_hlg = pq_to_hlg_lut();
 
     let file = File::create(path)?;
     let mut w = BufWriter::new(file);
@@ -185,9 +176,26 @@ fn encode_hdr10_as_hlg_png(path: &Path, bitmap: &HdrBitmap) -> Result<()> {
     Ok(())
 }
 
+// 65536-entry LUT mapping PQ-encoded u16 -> HLG-encoded u16. alpha never goes
+// through it
+pub(super) fn pq_to_hlg_lut() -> Vec<u16> {
+    let mut pq_to_hlg = vec![0u16; 65536];
+    for i in 0..65536u32 {
+        let pq_norm = i as f32 / 65535.0;
+        let nits = pq_eotf_to_nits(pq_norm);
+        // normalise to HLG nominal peak. BT.2100 uses 1000 nits as the
+        // reference white for HLG; values above are theoretically allowed
+        // but capped here at 1.0 since HLG OETF is only defined on [0, 1]
+        let linear = (nits / 1000.0).clamp(0.0, 1.0);
+        let hlg_norm = hlg_oetf(linear);
+        pq_to_hlg[i as usize] = (hlg_norm * 65535.0).round().clamp(0.0, 65535.0) as u16;
+    }
+    pq_to_hlg
+}
+
 // SMPTE ST 2084 / BT.2100 PQ EOTF (decode). takes a normalised 0..1 input
 // and returns absolute luminance in nits, range [0, 10000].
-fn pq_eotf_to_nits(pq_norm: f32) -> f32 {
+pub(super) fn pq_eotf_to_nits(pq_norm: f32) -> f32 {
     let m1 = 2610.0 / 16384.0;
     let m2 = (2523.0 / 4096.0) * 128.0;
     let c1 = 3424.0 / 4096.0;
@@ -300,7 +308,7 @@ mod tests {
             width: 2,
             height: 2,
             format: HdrFormat::Hdr10,
-            data: vec![0u8; 16], // 4 pixels × 4 packed bytes
+            data: vec![0u8; 16].into(), // 4 pixels × 4 packed bytes
             max_luminance_nits: 1000.0,
         };
         encode_hdr_png(&tmp, &bitmap, HdrTransfer::Pq).unwrap();
@@ -320,7 +328,7 @@ mod tests {
             width: 2,
             height: 2,
             format: HdrFormat::Hdr10,
-            data: vec![0u8; 16],
+            data: vec![0u8; 16].into(),
             max_luminance_nits: 1000.0,
         };
         encode_hdr_png(&tmp, &bitmap, HdrTransfer::Hlg).unwrap();
@@ -353,7 +361,7 @@ mod tests {
             width: 4,
             height: 1,
             format: HdrFormat::Hdr10,
-            data: data.clone(),
+            data: data.clone().into(),
             max_luminance_nits: 1000.0,
         };
 
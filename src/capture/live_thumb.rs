@@ -0,0 +1,152 @@
+// live window-picker previews on windows: DWM composites each window's
+// current contents straight into the hub at the slot its picker row reserves,
+// so a video or a terminal keeps moving without capscr grabbing a frame. DWM
+// can only draw into a top-level window, so the frontend reports where each
+// row's thumbnail box is (in client pixels) and every scroll or resize sends
+// the set again. elsewhere nothing is registered and the picker falls back to
+// a one-shot WindowCapture downscale per row.
+
+use super::Rectangle;
+use serde::Deserialize;
+
+/// where one picker row wants a window's preview, in the hub's client pixels
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+pub struct ThumbSlot {
+    pub id: u32,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+pub fn supported() -> bool {
+    cfg!(windows)
+}
+
+// the largest rect with the source's aspect that fits the slot, centred in it.
+// DWM stretches to whatever it's given, and the css box is a fixed 16:10
+fn fit(source: (u32, u32), slot: &ThumbSlot) -> Rectangle {
+    let (sw, sh) = (source.0.max(1) as f64, source.1.max(1) as f64);
+    let scale = (slot.width as f64 / sw).min(slot.height as f64 / sh);
+    let width = ((sw * scale).round() as u32).clamp(1, slot.width.max(1));
+    let height = ((sh * scale).round() as u32).clamp(1, slot.height.max(1));
+    Rectangle::new(
+        slot.x + (slot.width.saturating_sub(width) / 2) as i32,
+        slot.y + (slot.height.saturating_sub(height) / 2) as i32,
+        width,
+        height,
+    )
+}
+
+#[cfg(windows)]
+mod dwm {
+    use super::{fit, ThumbSlot};
+    use anyhow::Result;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use windows::Win32::Foundation::{HWND, RECT};
+    use windows::Win32::Graphics::Dwm::{
+        DwmQueryThumbnailSourceSize, DwmRegisterThumbnail, DwmUnregisterThumbnail,
+        DwmUpdateThumbnailProperties, DWM_THUMBNAIL_PROPERTIES, DWM_TNP_OPACITY,
+        DWM_TNP_RECTDESTINATION, DWM_TNP_SOURCECLIENTAREAONLY, DWM_TNP_VISIBLE,
+    };
+
+    // source window id -> (destination hwnd, thumbnail handle)
+    static REGISTERED: Mutex<Option<HashMap<u32, (isize, isize)>>> = Mutex::new(None);
+
+    pub fn show(dest: isize, slots: &[ThumbSlot]) -> Result<()> {
+        let mut guard = REGISTERED.lock().unwrap();
+        let registered = guard.get_or_insert_with(HashMap::new);
+        // rows that scrolled out or went away, and any left on another window
+        registered.retain(|id, (d, thumb)| {
+            let keep = *d == dest && slots.iter().any(|s| s.id == *id);
+            if !keep {
+                unsafe {
+                    let _ = DwmUnregisterThumbnail(*thumb);
+                }
+            }
+            keep
+        });
+        for slot in slots {
+            let thumb = match registered.get(&slot.id) {
+                Some(&(_, thumb)) => thumb,
+                None => {
+                    let source = HWND(slot.id as usize as *mut _);
+                    let thumb = match unsafe { DwmRegisterThumbnail(HWND(dest as _), source) } {
+                        Ok(thumb) => thumb,
+                        // closed since it was listed; its row keeps a blank box
+                        Err(e) => {
+                            tracing::debug!("no live thumbnail for window {}: {e}", slot.id);
+                            continue;
+                        }
+                    };
+                    registered.insert(slot.id, (dest, thumb));
+                    thumb
+                }
+            };
+            let size = unsafe { DwmQueryThumbnailSourceSize(thumb) }.unwrap_or_default();
+            let r = fit((size.cx.max(0) as u32, size.cy.max(0) as u32), slot);
+            let props = DWM_THUMBNAIL_PROPERTIES {
+                dwFlags: DWM_TNP_RECTDESTINATION
+                    | DWM_TNP_VISIBLE
+                    | DWM_TNP_OPACITY
+                    | DWM_TNP_SOURCECLIENTAREAONLY,
+                rcDestination: RECT {
+                    left: r.x,
+                    top: r.y,
+                    right: r.x + r.width as i32,
+                    bottom: r.y + r.height as i32,
+                },
+                opacity: 255,
+                fVisible: true.into(),
+                fSourceClientAreaOnly: false.into(),
+                ..Default::default()
+            };
+            unsafe { DwmUpdateThumbnailProperties(thumb, &props)? };
+        }
+        Ok(())
+    }
+}
+
+/// show live previews into `dest` (an HWND) at `slots`, dropping any shown
+/// before that aren't in the set. an empty set clears them all
+#[cfg(windows)]
+pub fn show(dest: isize, slots: &[ThumbSlot]) -> anyhow::Result<()> {
+    dwm::show(dest, slots)
+}
+
+#[cfg(not(windows))]
+pub fn show(_dest: isize, _slots: &[ThumbSlot]) -> anyhow::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot(x: i32, y: i32, width: u32, height: u32) -> ThumbSlot {
+        ThumbSlot {
+            id: 1,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn previews_keep_the_window_aspect_inside_their_slot() {
+        // a wide window letterboxes top and bottom
+        assert_eq!(
+            fit((1920, 600), &slot(10, 20, 96, 60)),
+            Rectangle::new(10, 35, 96, 30)
+        );
+        // a tall one pillarboxes
+        assert_eq!(
+            fit((600, 1200), &slot(0, 0, 96, 60)),
+            Rectangle::new(33, 0, 30, 60)
+        );
+        // an unknown source size still yields something drawable
+        assert_eq!(fit((0, 0), &slot(0, 0, 96, 60)).width, 60);
+    }
+}
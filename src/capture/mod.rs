@@ -5,6 +5,7 @@ mod cursor;
 mod d2d_tonemap;
 #[cfg(target_os = "linux")]
 mod color_probe;
+pub mod exclusion;
 #[cfg(target_os = "linux")]
 mod ext_copy;
 #[cfg(target_os = "linux")]
@@ -12,9 +13,11 @@ pub mod gnome_shell;
 #[cfg(windows)]
 mod gdi;
 mod hdr;
+mod hdr_export;
 mod hdr_png;
 #[cfg(target_os = "linux")]
 mod kwin;
+pub mod live_thumb;
 #[cfg(target_os = "linux")]
 mod pipewire_stream;
 #[cfg(target_os = "linux")]
@@ -32,14 +35,17 @@ mod window;
 #[cfg(target_os = "linux")]
 mod x11_grab;
 
-#[cfg(not(windows))]
-pub use cursor::pointer_position;
-pub use cursor::{capture_cursor_shot, composite_cursor_shot, composite_system_cursor};
+pub use cursor::{
+    capture_cursor_shot, composite_cursor_shot, composite_system_cursor, pointer_position,
+};
 #[cfg(windows)]
 pub use d2d_tonemap::capture_hdr_to_sdr_sweep;
 #[cfg(windows)]
 pub use gdi::{fast_gdi_capture, fast_list_monitors};
 pub use hdr::HdrCapture;
+pub use hdr_export::{
+    encode_hdr_file, existing_hdr_sidecars, is_hdr_sidecar, HDR_SIDECAR_SUFFIXES,
+};
 pub use hdr_png::{encode_hdr_png, read_cicp, HdrBitmap, HdrTransfer};
 // still captures run one at a time behind the capture gate, so a process-wide
 // cursor hint is safe. it exists because capture_one_monitor's signature is
@@ -171,13 +177,35 @@ pub use screen::ScreenCapture;
 pub use tonemapping::TonemapParams;
 #[cfg(windows)]
 pub use wgc::capture_at_point as wgc_capture_at_point;
-pub use window::WindowCapture;
+pub use window::{
+    active_window_rect, clean_window_corners, compile_window_pattern, window_rect, WindowCapture,
+};
 #[cfg(target_os = "linux")]
 pub use x11_grab::X11RegionGrabber;
 
 use std::sync::OnceLock;
 
-static TONEMAP_OVERRIDE: OnceLock<TonemapParams> = OnceLock::new();
+// replaced on every config save so tonemap changes apply without a restart
+static TONEMAP_OVERRIDE: std::sync::RwLock<Option<TonemapParams>> = std::sync::RwLock::new(None);
+
+/// how Windows.Graphics.Capture sessions are used, from `capture.backend`
+/// and `capture.wgc_border`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WgcOptions {
+    /// go through WGC for every monitor and window, not just HDR ones
+    pub preferred: bool,
+    /// keep the yellow border windows 11 draws around a captured target
+    pub border: bool,
+}
+
+static WGC_OPTIONS: std::sync::RwLock<WgcOptions> = std::sync::RwLock::new(WgcOptions {
+    preferred: false,
+    border: false,
+});
+
+// set by game mode while a full-screen app that asked for low-overhead
+// capture has focus; routes through WGC whatever capture.backend says
+static WGC_FORCED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
 
 thread_local! {
     // set while a parallel monitor-capture worker runs so par_convert falls back
@@ -204,11 +232,28 @@ pub(crate) fn capture_serial<R>(f: impl FnOnce() -> R) -> R {
 }
 
 pub fn install_tonemap_params(params: TonemapParams) {
-    let _ = TONEMAP_OVERRIDE.set(params);
+    *TONEMAP_OVERRIDE.write().unwrap_or_else(|e| e.into_inner()) = Some(params);
 }
 
 pub fn current_tonemap_params() -> TonemapParams {
-    TONEMAP_OVERRIDE.get().copied().unwrap_or_default()
+    TONEMAP_OVERRIDE
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .unwrap_or_default()
+}
+
+pub fn install_wgc_options(options: WgcOptions) {
+    *WGC_OPTIONS.write().unwrap_or_else(|e| e.into_inner()) = options;
+}
+
+pub fn current_wgc_options() -> WgcOptions {
+    let mut options = *WGC_OPTIONS.read().unwrap_or_else(|e| e.into_inner());
+    options.preferred |= WGC_FORCED.load(std::sync::atomic::Ordering::SeqCst);
+    options
+}
+
+pub fn set_wgc_forced(forced: bool) {
+    WGC_FORCED.store(forced, std::sync::atomic::Ordering::SeqCst);
 }
 
 // Capture path gates. defaults match ShareX behaviour: plain GDI BitBlt
@@ -221,6 +266,8 @@ pub fn current_tonemap_params() -> TonemapParams {
 //                        (instant, OS-quality, but composes SDR content
 //                        via the HDR compositor on HDR displays which
 //                        can subtly shift SDR brightness vs GDI BitBlt)
+// the WGC gate also opens when capture.backend = "wgc", which additionally
+// routes SDR monitors and windows through WGC (see current_wgc_options)
 pub fn hdr_aware_enabled() -> bool {
     static GATE: OnceLock<bool> = OnceLock::new();
     *GATE.get_or_init(|| {
@@ -246,7 +293,7 @@ pub fn wgc_enabled() -> bool {
             forced_on,
         );
         forced_on
-    })
+    }) || current_wgc_options().preferred
 }
 
 // opt-in: skip the fixed ~10ms settle sleep before the first DXGI
@@ -446,8 +493,7 @@ pub struct MonitorInfo {
     pub is_primary: bool,
 }
 
-#[cfg(test)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct WindowInfo {
     pub id: u32,
     pub title: String,
@@ -504,6 +550,19 @@ pub fn list_monitors() -> Result<Vec<MonitorInfo>> {
     }
 }
 
+// identifies the current monitor arrangement (every output's virtual-desktop
+// rect, order-independent) so per-layout state can tell setups apart. an
+// enumeration failure yields the empty key, which still works as a layout
+pub fn monitor_layout_key() -> String {
+    let mut rects: Vec<String> = list_monitors()
+        .unwrap_or_default()
+        .iter()
+        .map(|m| format!("{}x{}+{}+{}", m.width, m.height, m.x, m.y))
+        .collect();
+    rects.sort();
+    rects.join(",")
+}
+
 // wayland output enumeration via wlroots protocols. ids live in their own
 // namespace (counting down from u32::MAX) so they can never collide with
 // xcap's ids; capture_one_monitor routes them to the wayland grab path.
@@ -969,7 +1028,7 @@ pub fn is_black_frame(img: &RgbaImage) -> bool {
 // GDI-on-HDR (transparent black), which was the selector black-screen bug.
 // the WGC path (OS-side tonemap) and the Direct2D HdrToneMap path stay
 // reachable via their env opt-ins / the --d2d-sweep diagnostic. SDR monitors
-// use GDI BitBlt. any slice that still comes back fully black is retried
+// use GDI BitBlt, or WGC when capture.backend picks it. any slice that still comes back fully black is retried
 // through GDI before being accepted, and a fully-transparent slice is forced
 // opaque
 #[cfg(windows)]
@@ -1009,6 +1068,11 @@ pub fn capture_one_monitor(monitor: &MonitorInfo) -> Result<RgbaImage> {
                     gdi_capture()
                 })?
         }
+    } else if current_wgc_options().preferred {
+        wgc_capture_at_point(center.0, center.1).or_else(|e| {
+            tracing::warn!("WGC capture failed at {center:?} — GDI fallback: {e:#}");
+            gdi_capture()
+        })?
     } else {
         gdi_capture()?
     };
@@ -59,6 +59,17 @@ impl ScreenCapture {
         })
     }
 
+    /// the screen position of all_monitors()'s top-left pixel
+    pub fn desktop_origin() -> Result<(i32, i32)> {
+        #[cfg(windows)]
+        let monitors = super::fast_list_monitors()?;
+        #[cfg(not(windows))]
+        let monitors = super::list_monitors()?;
+        let min_x = monitors.iter().map(|m| m.x).min().unwrap_or(0);
+        let min_y = monitors.iter().map(|m| m.y).min().unwrap_or(0);
+        Ok((min_x, min_y))
+    }
+
     pub fn all_monitors() -> Result<RgbaImage> {
         const MAX_TOTAL_DIMENSION: i32 = 32768;
 
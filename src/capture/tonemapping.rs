@@ -17,7 +17,13 @@
 // in working space — that way SDR-on-HDR pixels sit at exactly 1.0 and the
 // p99 of maxRGB is 1.0 whenever there is no HDR content, which makes the
 // tonemap the identity and preserves SDR pixel-for-pixel.
+//
+// that default curve is one of several operators picked in settings
+// (TonemapOperator); the others trade SDR fidelity for a filmic look and all
+// run on the same working space, with the frame peak as their white point.
 
+use super::hdr::f16_to_f32;
+use crate::config::TonemapOperator;
 use image::RgbaImage;
 
 const MAX_TONEMAP_DIMENSION: u32 = 16384;
@@ -43,6 +49,7 @@ pub struct TonemapParams {
     /// outliers (specular glints, sun pixels) so the rest of the image
     /// isn't crushed by their presence.
     pub use_p99_max_cll: bool,
+    pub operator: TonemapOperator,
 }
 
 impl Default for TonemapParams {
@@ -51,6 +58,7 @@ impl Default for TonemapParams {
             sdr_white_nits_override: 0.0,
             user_brightness_scale: 1.0,
             use_p99_max_cll: true,
+            operator: TonemapOperator::Bt2390,
         }
     }
 }
@@ -109,11 +117,12 @@ fn srgb_lut() -> &'static [u8; SRGB_LUT_SIZE] {
     })
 }
 
+// takes the table so hot loops resolve the OnceLock once, not per channel
 #[inline]
-fn linear_to_srgb_u8(linear: f32) -> u8 {
+fn linear_to_srgb_u8(lut: &[u8; SRGB_LUT_SIZE], linear: f32) -> u8 {
     let clamped = linear.clamp(0.0, 1.0);
     let idx = (clamped * ((SRGB_LUT_SIZE - 1) as f32)) as usize;
-    srgb_lut()[idx.min(SRGB_LUT_SIZE - 1)]
+    lut[idx.min(SRGB_LUT_SIZE - 1)]
 }
 
 // normalized PQ encode: v in [0, 1] where 1.0 = 10000 nits absolute, output
@@ -155,17 +164,14 @@ fn pq_to_linear_norm(pq: f32) -> f32 {
 // asymptoting at 1.0, preserving SDR up to `knee = 0.85`, mapping SDR white (1.0)
 // to 0.925 (very bright and close to white), and rolling off all HDR highlights smoothly
 // into [0.925, 1.0) so they never clip or blow out.
-#[inline]
-fn tonemap_pixel(r: f32, g: f32, b: f32, l_src: f32) -> (f32, f32, f32) {
-    let max_val = r.max(g).max(b);
-    if max_val <= 0.85 {
-        return (r, g, b);
-    }
-    let knee = 0.85f32;
-    let w = l_src - knee;
-    let c = 1.0 - knee;
-
-    // solve ln(1.0 + B * w) = c * B for B using robust bisection method
+const ROLLOFF_KNEE: f32 = 0.85;
+
+// the rolloff's log steepness B for a frame peak: solves
+// ln(1 + B·(l_src - knee)) = (1 - knee)·B by bisection so the peak lands at
+// exactly 1.0. depends only on the peak, so it runs once per frame
+fn solve_rolloff(l_src: f32) -> f32 {
+    let w = l_src - ROLLOFF_KNEE;
+    let c = 1.0 - ROLLOFF_KNEE;
     let mut low = 0.0f32;
     let mut high = 1000.0f32;
     let mut b_param = 1.0f32;
@@ -179,15 +185,24 @@ fn tonemap_pixel(r: f32, g: f32, b: f32, l_src: f32) -> (f32, f32, f32) {
         }
         b_param = mid;
     }
+    b_param
+}
 
-    let excess = max_val - knee;
-    let compressed = knee + 1.0 / b_param * (1.0 + b_param * excess).ln();
+#[inline]
+fn rolloff_pixel(r: f32, g: f32, b: f32, b_param: f32) -> (f32, f32, f32) {
+    let max_val = r.max(g).max(b);
+    if max_val <= ROLLOFF_KNEE {
+        return (r, g, b);
+    }
+    let excess = max_val - ROLLOFF_KNEE;
+    let compressed = ROLLOFF_KNEE + 1.0 / b_param * (1.0 + b_param * excess).ln();
 
     // desaturate highlights toward white as they get brighter to prevent neon/overblown look
     // and preserve details in highly saturated channels (only apply to pixels above SDR white)
     let hdr_excess = (max_val - 1.0).max(0.0);
     let ratio = hdr_excess / max_val;
-    let desat_factor = 0.06 * ratio.powf(4.0);
+    let ratio_sq = ratio * ratio;
+    let desat_factor = 0.06 * ratio_sq * ratio_sq;
     let r_desat = r * (1.0 - desat_factor) + max_val * desat_factor;
     let g_desat = g * (1.0 - desat_factor) + max_val * desat_factor;
     let b_desat = b * (1.0 - desat_factor) + max_val * desat_factor;
@@ -196,27 +211,120 @@ fn tonemap_pixel(r: f32, g: f32, b: f32, l_src: f32) -> (f32, f32, f32) {
     (r_desat * scale, g_desat * scale, b_desat * scale)
 }
 
-#[allow(clippy::uninit_vec)]
-pub fn scrgb_to_sdr_bt2390(
-    scrgb_rgba: &[f32],
-    width: u32,
-    height: u32,
-    sdr_white_nits: f32,
-    params: TonemapParams,
-) -> RgbaImage {
+#[cfg(test)]
+fn tonemap_pixel(r: f32, g: f32, b: f32, l_src: f32) -> (f32, f32, f32) {
+    rolloff_pixel(r, g, b, solve_rolloff(l_src))
+}
+
+// maxRGB Reinhard-extended with white point w: y = x(1 + x/w²)/(1 + x),
+// scaled onto all three channels so hue holds
+#[inline]
+fn reinhard_extended_pixel(r: f32, g: f32, b: f32, inv_white_sq: f32) -> (f32, f32, f32) {
+    let max_val = r.max(g).max(b);
+    if max_val <= 0.0 {
+        return (0.0, 0.0, 0.0);
+    }
+    let scale = (1.0 + max_val * inv_white_sq) / (1.0 + max_val);
+    (r * scale, g * scale, b * scale)
+}
+
+// Krzysztof Narkowicz's fit of the ACES reference rendering transform
+#[inline]
+fn aces_curve(x: f32) -> f32 {
+    (x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14)
+}
+
+// John Hable's Uncharted 2 curve (shoulder, linear section, toe strengths)
+#[inline]
+fn hable_curve(x: f32) -> f32 {
+    const A: f32 = 0.15;
+    const B: f32 = 0.50;
+    const C: f32 = 0.10;
+    const D: f32 = 0.20;
+    const E: f32 = 0.02;
+    const F: f32 = 0.30;
+    ((x * (A * x + C * B) + D * E) / (x * (A * x + B) + D * F)) - E / F
+}
+
+// Hable's customary exposure bias; the curve is tuned for inputs around 2x
+const HABLE_EXPOSURE_BIAS: f32 = 2.0;
+
+// an operator with everything that depends only on the frame peak (`l_src`)
+// solved up front, so the per-pixel work is a handful of mul/adds plus at
+// most one ln. the filmic curves normalise against the peak so it lands at
+// output white
+#[derive(Debug, Clone, Copy)]
+struct PreparedOperator {
+    operator: TonemapOperator,
+    // rolloff steepness for Bt2390, 1/w² for Reinhard, 1/curve(peak) for
+    // the filmic curves
+    k: f32,
+}
+
+impl PreparedOperator {
+    fn new(operator: TonemapOperator, l_src: f32) -> Self {
+        let k = match operator {
+            TonemapOperator::Bt2390 => solve_rolloff(l_src),
+            TonemapOperator::ReinhardExtended => 1.0 / (l_src * l_src),
+            TonemapOperator::AcesFilmic => 1.0 / aces_curve(l_src),
+            TonemapOperator::Hable => 1.0 / hable_curve(l_src * HABLE_EXPOSURE_BIAS),
+            TonemapOperator::Exposure => 1.0,
+        };
+        Self { operator, k }
+    }
+
+    #[inline]
+    fn apply(&self, r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+        let k = self.k;
+        match self.operator {
+            TonemapOperator::Bt2390 => rolloff_pixel(r, g, b, k),
+            TonemapOperator::ReinhardExtended => reinhard_extended_pixel(r, g, b, k),
+            TonemapOperator::AcesFilmic => {
+                (aces_curve(r) * k, aces_curve(g) * k, aces_curve(b) * k)
+            }
+            TonemapOperator::Hable => {
+                let map = |c: f32| hable_curve(c * HABLE_EXPOSURE_BIAS) * k;
+                (map(r), map(g), map(b))
+            }
+            TonemapOperator::Exposure => (r.min(1.0), g.min(1.0), b.min(1.0)),
+        }
+    }
+}
+
+#[cfg(test)]
+fn tonemap_with(operator: TonemapOperator, r: f32, g: f32, b: f32, l_src: f32) -> (f32, f32, f32) {
+    PreparedOperator::new(operator, l_src).apply(r, g, b)
+}
+
+// frame-size guard shared by every entry point: the pixel count, or the
+// image to return when the frame is unusable
+fn checked_pixel_count(width: u32, height: u32) -> Result<usize, RgbaImage> {
     if width == 0 || height == 0 || width > MAX_TONEMAP_DIMENSION || height > MAX_TONEMAP_DIMENSION
     {
-        return RgbaImage::new(1, 1);
+        return Err(RgbaImage::new(1, 1));
     }
-    let pixel_count = match (width as usize).checked_mul(height as usize) {
-        Some(c) if c <= MAX_TONEMAP_PIXELS => c,
-        _ => return RgbaImage::new(1, 1),
-    };
-    if scrgb_rgba.len() < pixel_count * 4 {
-        return RgbaImage::new(width, height);
+    match (width as usize).checked_mul(height as usize) {
+        Some(c) if c <= MAX_TONEMAP_PIXELS => Ok(c),
+        _ => Err(RgbaImage::new(1, 1)),
     }
-    let scrgb = &scrgb_rgba[..pixel_count * 4];
+}
 
+// decode + tonemap + sRGB-encode in one parallel pass over the source. the
+// source stays in its capture layout and `decode` turns pixel `i` into scRGB
+// (1.0 = 80 nits) RGBA inside each worker, so no intermediate full-frame
+// float buffer is built and no pass runs single-threaded. callers have
+// already checked the source holds `pixel_count` pixels
+fn tonemap_frame<D>(
+    pixel_count: usize,
+    width: u32,
+    height: u32,
+    sdr_white_nits: f32,
+    params: TonemapParams,
+    decode: D,
+) -> RgbaImage
+where
+    D: Fn(usize) -> [f32; 4] + Sync,
+{
     // working space: scRGB units (1.0 = 80 nits) rescaled so the OS-reported
     // SDR-white pixel sits exactly at 1.0. SDR pixels on an HDR display land
     // at-or-below 1.0 in working space and the luminance-based tonemap
@@ -234,9 +342,7 @@ pub fn scrgb_to_sdr_bt2390(
     let mut raw_peak = 1.0f32;
     let stride = (pixel_count / 100_000).max(1);
     for i in (0..pixel_count).step_by(stride) {
-        let r = scrgb[i * 4];
-        let g = scrgb[i * 4 + 1];
-        let b = scrgb[i * 4 + 2];
+        let [r, g, b, _] = decode(i);
         if r.is_finite() && g.is_finite() && b.is_finite() {
             let m = r.max(g).max(b);
             if m > raw_peak {
@@ -250,69 +356,50 @@ pub fn scrgb_to_sdr_bt2390(
     #[allow(clippy::manual_clamp)]
     let l_src = (raw_peak * coeff).min(40.0).max(1.05);
 
+    let operator = PreparedOperator::new(params.operator, l_src);
+
     tracing::info!(
-        "tonemap: {}x{} sdr_white={:.0}nits coeff={:.4} raw_peak={:.3} l_src={:.3}",
+        "tonemap: {}x{} sdr_white={:.0}nits coeff={:.4} raw_peak={:.3} l_src={:.3} operator={:?}",
         width,
         height,
         sdr_white,
         coeff,
         raw_peak,
         l_src,
+        params.operator,
     );
 
-    // fused decode + tonemap + sRGB-encode in a single parallel pass.
-    // skipping the intermediate working-space allocation + the p99 scan
-    // saves ~30-50% of the per-frame CPU vs the previous two-pass pipeline.
     let thread_count = std::thread::available_parallelism()
         .map(|n| n.get().min(16))
         .unwrap_or(4)
         .max(1);
     let chunk_pixels = pixel_count.div_ceil(thread_count);
-    let mut out_bytes = Vec::with_capacity(pixel_count * 4);
-    unsafe {
-        out_bytes.set_len(pixel_count * 4);
-    }
+    let mut out_bytes = vec![0u8; pixel_count * 4];
+    let lut = srgb_lut();
+    let decode = &decode;
 
     std::thread::scope(|s| {
         for (chunk_idx, out_chunk) in out_bytes.chunks_mut(chunk_pixels * 4).enumerate() {
-            let src_start = chunk_idx * chunk_pixels * 4;
-            let src_end = (src_start + out_chunk.len()).min(scrgb.len());
-            let src_chunk = &scrgb[src_start..src_end];
+            let first = chunk_idx * chunk_pixels;
             s.spawn(move || {
-                let pixels = out_chunk.len() / 4;
-                for i in 0..pixels {
-                    let r_raw = src_chunk[i * 4];
-                    let g_raw = src_chunk[i * 4 + 1];
-                    let b_raw = src_chunk[i * 4 + 2];
-                    let a_raw = src_chunk[i * 4 + 3];
-
-                    let r = if r_raw.is_finite() {
-                        (r_raw * coeff).max(0.0)
-                    } else {
-                        0.0
-                    };
-                    let g = if g_raw.is_finite() {
-                        (g_raw * coeff).max(0.0)
-                    } else {
-                        0.0
-                    };
-                    let b = if b_raw.is_finite() {
-                        (b_raw * coeff).max(0.0)
-                    } else {
-                        0.0
-                    };
-                    let a = if a_raw.is_finite() {
-                        a_raw.clamp(0.0, 1.0)
-                    } else {
-                        1.0
-                    };
-
-                    let (r_tm, g_tm, b_tm) = tonemap_pixel(r, g, b, l_src);
-
-                    out_chunk[i * 4] = linear_to_srgb_u8(r_tm);
-                    out_chunk[i * 4 + 1] = linear_to_srgb_u8(g_tm);
-                    out_chunk[i * 4 + 2] = linear_to_srgb_u8(b_tm);
-                    out_chunk[i * 4 + 3] = (a * 255.0).clamp(0.0, 255.0) as u8;
+                // branch-light body over fixed 4-byte output lanes so the
+                // compiler can keep the per-channel math in vector registers
+                let encode = |v: f32| linear_to_srgb_u8(lut, v);
+                for (offset, out) in out_chunk.chunks_exact_mut(4).enumerate() {
+                    let [r_raw, g_raw, b_raw, a_raw] = decode(first + offset);
+                    let finite_or =
+                        |v: f32, fallback: f32| if v.is_finite() { v } else { fallback };
+                    let r = (finite_or(r_raw, 0.0) * coeff).max(0.0);
+                    let g = (finite_or(g_raw, 0.0) * coeff).max(0.0);
+                    let b = (finite_or(b_raw, 0.0) * coeff).max(0.0);
+                    let a = finite_or(a_raw, 1.0).clamp(0.0, 1.0);
+
+                    let (r_tm, g_tm, b_tm) = operator.apply(r, g, b);
+
+                    out[0] = encode(r_tm);
+                    out[1] = encode(g_tm);
+                    out[2] = encode(b_tm);
+                    out[3] = (a * 255.0).clamp(0.0, 255.0) as u8;
                 }
             });
         }
@@ -321,7 +408,68 @@ pub fn scrgb_to_sdr_bt2390(
     RgbaImage::from_raw(width, height, out_bytes).unwrap_or_else(|| RgbaImage::new(width, height))
 }
 
-#[allow(clippy::uninit_vec)]
+pub fn scrgb_to_sdr_bt2390(
+    scrgb_rgba: &[f32],
+    width: u32,
+    height: u32,
+    sdr_white_nits: f32,
+    params: TonemapParams,
+) -> RgbaImage {
+    let pixel_count = match checked_pixel_count(width, height) {
+        Ok(c) => c,
+        Err(img) => return img,
+    };
+    if scrgb_rgba.len() < pixel_count * 4 {
+        return RgbaImage::new(width, height);
+    }
+    tonemap_frame(pixel_count, width, height, sdr_white_nits, params, |i| {
+        let p = &scrgb_rgba[i * 4..i * 4 + 4];
+        [p[0], p[1], p[2], p[3]]
+    })
+}
+
+/// scRGB straight from an R16G16B16A16_FLOAT texture (little-endian halves),
+/// decoded inside the tonemap workers
+pub fn scrgb_half_to_sdr_bt2390(
+    half_rgba: &[u8],
+    width: u32,
+    height: u32,
+    sdr_white_nits: f32,
+    params: TonemapParams,
+) -> RgbaImage {
+    let pixel_count = match checked_pixel_count(width, height) {
+        Ok(c) => c,
+        Err(img) => return img,
+    };
+    if half_rgba.len() < pixel_count * 8 {
+        return RgbaImage::new(width, height);
+    }
+    tonemap_frame(pixel_count, width, height, sdr_white_nits, params, |i| {
+        let p = &half_rgba[i * 8..i * 8 + 8];
+        let half = |k: usize| {
+            let v = f16_to_f32(u16::from_le_bytes([p[k], p[k + 1]]));
+            if v.is_finite() {
+                v
+            } else {
+                0.0
+            }
+        };
+        [half(0), half(2), half(4), half(6)]
+    })
+}
+
+// PQ code (16-bit normalised) -> scRGB, one entry per code. 256 KiB built once,
+// replacing two powf per channel per pixel
+fn pq_scrgb_lut() -> &'static [f32] {
+    use std::sync::OnceLock;
+    static LUT: OnceLock<Vec<f32>> = OnceLock::new();
+    LUT.get_or_init(|| {
+        (0..=u16::MAX)
+            .map(|code| pq_to_linear(code as f32 / 65535.0) / 80.0)
+            .collect()
+    })
+}
+
 pub fn hdr10_to_sdr_bt2390(
     pq_data: &[u16],
     width: u32,
@@ -329,38 +477,63 @@ pub fn hdr10_to_sdr_bt2390(
     sdr_white_nits: f32,
     params: TonemapParams,
 ) -> RgbaImage {
-    if width == 0 || height == 0 || width > MAX_TONEMAP_DIMENSION || height > MAX_TONEMAP_DIMENSION
-    {
-        return RgbaImage::new(1, 1);
-    }
-    let pixel_count = match (width as usize).checked_mul(height as usize) {
-        Some(c) if c <= MAX_TONEMAP_PIXELS => c,
-        _ => return RgbaImage::new(1, 1),
+    let pixel_count = match checked_pixel_count(width, height) {
+        Ok(c) => c,
+        Err(img) => return img,
     };
     if pq_data.len() < pixel_count * 4 {
         return RgbaImage::new(width, height);
     }
 
     // decode PQ -> linear nits, then rescale into scRGB (1.0 = 80 nits)
-    // so we can hand off to the scRGB path.
-    let mut scrgb = Vec::with_capacity(pixel_count * 4);
-    unsafe {
-        scrgb.set_len(pixel_count * 4);
-    }
-    for (src, dest) in pq_data.chunks_exact(4).zip(scrgb.chunks_exact_mut(4)) {
-        let r_pq = src[0] as f32 / 65535.0;
-        let g_pq = src[1] as f32 / 65535.0;
-        let b_pq = src[2] as f32 / 65535.0;
-        let a_pq = src[3] as f32 / 65535.0;
-        dest[0] = pq_to_linear(r_pq) / 80.0;
-        dest[1] = pq_to_linear(g_pq) / 80.0;
-        dest[2] = pq_to_linear(b_pq) / 80.0;
-        dest[3] = a_pq;
-    }
-    scrgb_to_sdr_bt2390(&scrgb, width, height, sdr_white_nits, params)
+    let lut = pq_scrgb_lut();
+    tonemap_frame(pixel_count, width, height, sdr_white_nits, params, |i| {
+        let p = &pq_data[i * 4..i * 4 + 4];
+        [
+            lut[p[0] as usize],
+            lut[p[1] as usize],
+            lut[p[2] as usize],
+            p[3] as f32 / 65535.0,
+        ]
+    })
+}
+
+/// HDR10 straight from the packed R10G10B10A2 capture layout, without first
+/// widening the frame to 16-bit quads
+pub fn hdr10_packed_to_sdr_bt2390(
+    packed: &[u8],
+    width: u32,
+    height: u32,
+    sdr_white_nits: f32,
+    params: TonemapParams,
+) -> RgbaImage {
+    let pixel_count = match checked_pixel_count(width, height) {
+        Ok(c) => c,
+        Err(img) => return img,
+    };
+    if packed.len() < pixel_count * 4 {
+        return RgbaImage::new(width, height);
+    }
+    let lut = pq_scrgb_lut();
+    // 10-bit -> 16-bit by bit replication, the same widening the u16 path's
+    // callers apply, so both entry points index the same LUT entries
+    let widen = |v: u32| ((v << 6) | (v >> 4)) as usize;
+    tonemap_frame(pixel_count, width, height, sdr_white_nits, params, |i| {
+        let word = u32::from_le_bytes([
+            packed[i * 4],
+            packed[i * 4 + 1],
+            packed[i * 4 + 2],
+            packed[i * 4 + 3],
+        ]);
+        [
+            lut[widen(word & 0x3FF)],
+            lut[widen((word >> 10) & 0x3FF)],
+            lut[widen((word >> 20) & 0x3FF)],
+            ((word >> 30) & 0x3) as f32 / 3.0,
+        ]
+    })
 }
 
-#[allow(clippy::uninit_vec)]
 pub fn hlg_to_sdr_bt2390(
     hlg_data: &[u8],
     width: u32,
@@ -368,13 +541,9 @@ pub fn hlg_to_sdr_bt2390(
     sdr_white_nits: f32,
     params: TonemapParams,
 ) -> RgbaImage {
-    if width == 0 || height == 0 || width > MAX_TONEMAP_DIMENSION || height > MAX_TONEMAP_DIMENSION
-    {
-        return RgbaImage::new(1, 1);
-    }
-    let pixel_count = match (width as usize).checked_mul(height as usize) {
-        Some(c) if c <= MAX_TONEMAP_PIXELS => c,
-        _ => return RgbaImage::new(1, 1),
+    let pixel_count = match checked_pixel_count(width, height) {
+        Ok(c) => c,
+        Err(img) => return img,
     };
     if hlg_data.len() < pixel_count * 4 {
         return RgbaImage::new(width, height);
@@ -382,21 +551,20 @@ pub fn hlg_to_sdr_bt2390(
 
     // HLG reference white is roughly 0.75 signal -> 1.0 linear; bring the
     // peak up to scRGB ~12 (1000 nits) before handing to the scRGB path.
-    let mut scrgb = Vec::with_capacity(pixel_count * 4);
-    unsafe {
-        scrgb.set_len(pixel_count * 4);
-    }
-    for (src, dest) in hlg_data.chunks_exact(4).zip(scrgb.chunks_exact_mut(4)) {
-        let r_hlg = src[0] as f32 / 255.0;
-        let g_hlg = src[1] as f32 / 255.0;
-        let b_hlg = src[2] as f32 / 255.0;
-        let a_hlg = src[3] as f32 / 255.0;
-        dest[0] = hlg_to_linear(r_hlg) * 12.0;
-        dest[1] = hlg_to_linear(g_hlg) * 12.0;
-        dest[2] = hlg_to_linear(b_hlg) * 12.0;
-        dest[3] = a_hlg;
-    }
-    scrgb_to_sdr_bt2390(&scrgb, width, height, sdr_white_nits, params)
+    // 8-bit input, so the whole decode is a 256-entry table
+    let mut lut = [0f32; 256];
+    for (code, entry) in lut.iter_mut().enumerate() {
+        *entry = hlg_to_linear(code as f32 / 255.0) * 12.0;
+    }
+    tonemap_frame(pixel_count, width, height, sdr_white_nits, params, |i| {
+        let p = &hlg_data[i * 4..i * 4 + 4];
+        [
+            lut[p[0] as usize],
+            lut[p[1] as usize],
+            lut[p[2] as usize],
+            p[3] as f32 / 255.0,
+        ]
+    })
 }
 
 fn effective_sdr_white(detected_nits: f32, override_nits: f32) -> f32 {
@@ -609,6 +777,92 @@ mod tests {
         assert_eq!(img.width(), 1);
     }
 
+    #[test]
+    fn every_operator_maps_peak_to_white_and_black_to_black() {
+        for &operator in TonemapOperator::all() {
+            let (r, g, b) = tonemap_with(operator, 4.0, 4.0, 4.0, 4.0);
+            for c in [r, g, b] {
+                assert!((c - 1.0).abs() < 0.02, "{operator:?} peak: {c}");
+            }
+            let (r, g, b) = tonemap_with(operator, 0.0, 0.0, 0.0, 4.0);
+            for c in [r, g, b] {
+                assert!(c.abs() < 0.01, "{operator:?} black: {c}");
+            }
+        }
+    }
+
+    #[test]
+    fn every_operator_is_monotonic() {
+        for &operator in TonemapOperator::all() {
+            let mut previous = -1.0f32;
+            for step in 0..=80 {
+                let x = step as f32 * 0.05;
+                let (y, _, _) = tonemap_with(operator, x, x, x, 4.0);
+                assert!(
+                    y >= previous - 1e-5,
+                    "{operator:?} dips at {x}: {y} < {previous}"
+                );
+                previous = y;
+            }
+        }
+    }
+
+    #[test]
+    fn operator_choice_changes_the_output() {
+        let scrgb = solid(2, 2, 6.0, 3.0, 1.0);
+        let pixel = |operator| {
+            let params = TonemapParams {
+                operator,
+                ..TonemapParams::default()
+            };
+            *scrgb_to_sdr_bt2390(&scrgb, 2, 2, 80.0, params).get_pixel(0, 0)
+        };
+        assert_ne!(
+            pixel(TonemapOperator::Bt2390),
+            pixel(TonemapOperator::AcesFilmic)
+        );
+        // exposure clips: every channel here sits at or above SDR white
+        let clipped = pixel(TonemapOperator::Exposure);
+        assert_eq!([clipped[0], clipped[1], clipped[2]], [255, 255, 255]);
+    }
+
+    #[test]
+    fn packed_and_half_sources_match_their_widened_forms() {
+        // a gradient across several thread chunks, so chunk offsets into the
+        // packed source are exercised too
+        let (width, height) = (97u32, 61u32);
+        let mut packed = Vec::new();
+        let mut widened = Vec::new();
+        for i in 0..width * height {
+            let (r, g, b) = ((i * 7) % 1024, (i * 13) % 1024, (i * 29) % 1024);
+            let word = r | (g << 10) | (b << 20) | (3 << 30);
+            packed.extend_from_slice(&word.to_le_bytes());
+            for v in [r, g, b] {
+                widened.push(((v << 6) | (v >> 4)) as u16);
+            }
+            widened.push(u16::MAX);
+        }
+        let params = TonemapParams::default();
+        assert_eq!(
+            hdr10_packed_to_sdr_bt2390(&packed, width, height, 203.0, params),
+            hdr10_to_sdr_bt2390(&widened, width, height, 203.0, params)
+        );
+
+        // f16: 2.0 = 0x4000, 0.5 = 0x3800, 6.0 = 0x4600, 1.0 = 0x3C00
+        let half_px = [0x00u8, 0x40, 0x00, 0x38, 0x00, 0x46, 0x00, 0x3C];
+        let halves: Vec<u8> = half_px.iter().copied().cycle().take(8 * 6).collect();
+        let floats: Vec<f32> = [2.0f32, 0.5, 6.0, 1.0]
+            .iter()
+            .copied()
+            .cycle()
+            .take(4 * 6)
+            .collect();
+        assert_eq!(
+            scrgb_half_to_sdr_bt2390(&halves, 3, 2, 203.0, params),
+            scrgb_to_sdr_bt2390(&floats, 3, 2, 203.0, params)
+        );
+    }
+
     #[test]
     fn override_takes_precedence_over_detected_white() {
         // detected 80, override 250: a pixel at scRGB 250/80 should land at
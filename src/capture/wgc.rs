@@ -119,9 +119,16 @@ fn capture_item(item: GraphicsCaptureItem) -> Result<RgbaImage> {
             .CreateCaptureSession(&item)
             .map_err(|e| anyhow!("CreateCaptureSession: {e}"))?;
 
-        // suppress capscr's own cursor in the capture — the user is
-        // pressing a hotkey, the cursor in the result is rarely wanted.
+        // WGC's own cursor stays off: capture.show_cursor is honoured by
+        // compositing the cursor afterwards, the same way for every backend,
+        // so letting WGC draw it too would double it up
         let _ = session.SetIsCursorCaptureEnabled(false);
+        // IsBorderRequired only exists on windows 11; older builds error here
+        // and always draw the border, which is all we can do there
+        let border = super::current_wgc_options().border;
+        if let Err(e) = session.SetIsBorderRequired(border) {
+            tracing::debug!("SetIsBorderRequired({border}) unsupported: {e}");
+        }
 
         session
             .StartCapture()
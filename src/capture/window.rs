@@ -1,45 +1,109 @@
 use anyhow::{anyhow, Result};
 use image::RgbaImage;
+use regex::{Regex, RegexBuilder};
 use xcap::Window;
 
-use super::Capture;
-#[cfg(test)]
-use super::WindowInfo;
+use super::{Capture, WindowInfo};
+use crate::config::WindowFrame;
 
 pub struct WindowCapture {
     window_id: u32,
+    frame: WindowFrame,
+    clean_corners: bool,
+    covered: bool,
 }
 
 impl WindowCapture {
     pub fn new(window_id: u32) -> Self {
-        Self { window_id }
+        Self {
+            window_id,
+            frame: WindowFrame::Visible,
+            clean_corners: false,
+            covered: true,
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.window_id
+    }
+
+    pub fn with_frame(mut self, frame: WindowFrame, clean_corners: bool) -> Self {
+        self.frame = frame;
+        self.clean_corners = clean_corners;
+        self
+    }
+
+    /// let the window render itself (windows PrintWindow) so overlapping
+    /// windows don't bleed into the capture. off grabs its rect off the screen
+    pub fn with_covered(mut self, covered: bool) -> Self {
+        self.covered = covered;
+        self
     }
 
-    #[cfg(test)]
     pub fn from_title(title: &str) -> Result<Self> {
-        let windows = Window::all()?;
-        let window = windows
-            .into_iter()
-            .find(|w| w.title().map(|t| t.contains(title)).unwrap_or(false))
-            .ok_or_else(|| anyhow!("Window with title '{}' not found", title))?;
-        Ok(Self {
-            window_id: window.id()?,
-        })
+        Self::from_target(title, "")?
+            .ok_or_else(|| anyhow!("Window with title '{}' not found", title))
     }
 
-    #[cfg(test)]
-    pub fn focused() -> Result<Self> {
-        let windows = Window::all()?;
-        let window = windows
-            .into_iter()
-            .find(|w| {
-                !w.is_minimized().unwrap_or(true)
-                    && !w.title().map(|t| t.is_empty()).unwrap_or(true)
-            })
-            .ok_or_else(|| anyhow!("No focused window found"))?;
-        Ok(Self {
-            window_id: window.id()?,
-        })
+    /// the topmost visible window whose title and process/app name match the
+    /// given case-insensitive regexes. an empty pattern matches anything.
+    /// Ok(None) means nothing matched, as opposed to a bad pattern or a
+    /// failed enumeration
+    pub fn from_target(title: &str, process: &str) -> Result<Option<Self>> {
+        let title_re = compile_window_pattern(title)?;
+        let process_re = compile_window_pattern(process)?;
+        // xcap enumerates in z-order, topmost first, so the first match is
+        // the one the user most likely sees. capscr's own windows never
+        // count, or a broad pattern would capture the hub or the editor
+        let own_pid = std::process::id();
+        let found = Window::all()?.into_iter().find(|w| {
+            if w.is_minimized().unwrap_or(true) || w.pid().is_ok_and(|p| p == own_pid) {
+                return false;
+            }
+            let title = w.title().unwrap_or_default();
+            let app_name = w.app_name().unwrap_or_default();
+            window_matches(title_re.as_ref(), process_re.as_ref(), &title, &app_name)
+        });
+        match found {
+            Some(window) => Ok(Some(Self::new(window.id()?))),
+            None => Ok(None),
+        }
+    }
+
+    /// the window that has focus. capscr's own windows and minimized ones
+    /// don't count; Ok(None) when nothing else has focus or the platform
+    /// can't say (wayland)
+    #[cfg(windows)]
+    pub fn focused() -> Result<Option<Self>> {
+        use windows::Win32::UI::WindowsAndMessaging::{
+            GetForegroundWindow, GetWindowThreadProcessId, IsIconic,
+        };
+        unsafe {
+            let hwnd = GetForegroundWindow();
+            if hwnd.0.is_null() || IsIconic(hwnd).as_bool() {
+                return Ok(None);
+            }
+            let mut pid = 0u32;
+            GetWindowThreadProcessId(hwnd, Some(&mut pid));
+            if pid == std::process::id() {
+                return Ok(None);
+            }
+            Ok(Some(Self::new(hwnd.0 as usize as u32)))
+        }
+    }
+
+    #[cfg(not(windows))]
+    pub fn focused() -> Result<Option<Self>> {
+        let own_pid = std::process::id();
+        let found = Window::all()?.into_iter().find(|w| {
+            w.is_focused().unwrap_or(false)
+                && !w.is_minimized().unwrap_or(true)
+                && !w.pid().is_ok_and(|p| p == own_pid)
+        });
+        match found {
+            Some(window) => Ok(Some(Self::new(window.id()?))),
+            None => Ok(None),
+        }
     }
 
     fn find_window(&self) -> Result<Window> {
@@ -50,12 +114,15 @@ impl WindowCapture {
             .ok_or_else(|| anyhow!("Window {} not found", self.window_id))
     }
 
-    #[cfg(test)]
     pub fn list_application_windows() -> Result<Vec<WindowInfo>> {
         let windows = Window::all()?;
+        let own_pid = std::process::id();
         let mut app_windows: Vec<WindowInfo> = windows
             .into_iter()
             .filter_map(|w| {
+                if w.pid().ok()? == own_pid {
+                    return None;
+                }
                 let title = w.title().ok()?;
                 let (width, height) = (w.width().ok()?, w.height().ok()?);
                 if title.is_empty() || width <= 50 || height <= 50 || w.is_minimized().ok()? {
@@ -73,13 +140,39 @@ impl WindowCapture {
             })
             .collect();
 
-        app_windows.sort_by(|a, b| a.title.cmp(&b.title));
+        // grouped by app in the picker, so keep each app's windows together
+        app_windows.sort_by(|a, b| {
+            a.app_name
+                .to_lowercase()
+                .cmp(&b.app_name.to_lowercase())
+                .then_with(|| a.title.cmp(&b.title))
+        });
         Ok(app_windows)
     }
+
+    /// a quick, untonemapped grab of the window scaled down to `max_width`,
+    /// for picker previews where the full capture path would be too slow
+    pub fn preview(&self, max_width: u32) -> Result<RgbaImage> {
+        let img = self.find_window()?.capture_image()?;
+        let scale = (max_width as f32 / img.width().max(1) as f32).min(1.0);
+        let width = ((img.width() as f32 * scale) as u32).max(1);
+        let height = ((img.height() as f32 * scale) as u32).max(1);
+        Ok(image::imageops::thumbnail(&img, width, height))
+    }
 }
 
 impl Capture for WindowCapture {
     fn capture(&self) -> Result<RgbaImage> {
+        let mut img = self.capture_raw()?;
+        if self.clean_corners && self.frame == WindowFrame::Visible {
+            clean_window_corners(self.window_id, &mut img);
+        }
+        Ok(img)
+    }
+}
+
+impl WindowCapture {
+    fn capture_raw(&self) -> Result<RgbaImage> {
         tracing::info!("WindowCapture::capture entry: window_id={}", self.window_id);
 
         // capture path priority for window captures:
@@ -90,53 +183,62 @@ impl Capture for WindowCapture {
         //   3. default -> xcap's GDI BitBlt (instant, overblown on HDR)
         #[cfg(windows)]
         {
-            use windows::Win32::Foundation::{HWND, RECT};
-            use windows::Win32::Graphics::Dwm::{
-                DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS,
-            };
-            use windows::Win32::UI::WindowsAndMessaging::GetWindowRect;
+            use windows::Win32::Foundation::HWND;
 
             let wgc_on = super::wgc_enabled();
             let hwnd = HWND(self.window_id as usize as *mut _);
 
-            let center_res = unsafe {
-                let mut r = RECT::default();
-                let ok = DwmGetWindowAttribute(
-                    hwnd,
-                    DWMWA_EXTENDED_FRAME_BOUNDS,
-                    &mut r as *mut RECT as *mut _,
-                    std::mem::size_of::<RECT>() as u32,
-                )
-                .is_ok();
-                if ok || GetWindowRect(hwnd, &mut r).is_ok() {
-                    Ok(((r.left + r.right) / 2, (r.top + r.bottom) / 2))
-                } else {
-                    Err(anyhow!("Failed to get window rect"))
+            let center_res = window_rect(self.window_id, WindowFrame::Visible)
+                .map(|r| (r.x + r.width as i32 / 2, r.y + r.height as i32 / 2))
+                .ok_or_else(|| anyhow!("Failed to get window rect"));
+            let is_hdr = center_res
+                .as_ref()
+                .is_ok_and(|c| super::HdrCapture::is_hdr_at_point(c.0, c.1));
+
+            // the WGC backend renders the window itself too, covered or not,
+            // and also sees the gpu surfaces PrintWindow comes back black for.
+            // it only knows the visible frame, other frames take the paths below
+            let wgc_preferred = super::current_wgc_options().preferred;
+            if wgc_preferred && self.frame == WindowFrame::Visible {
+                match self.wgc_window(hwnd) {
+                    Ok(img) => return Ok(img),
+                    Err(e) => tracing::warn!("WGC window capture failed — fallthrough: {e:#}"),
+                }
+            }
+
+            // PrintWindow draws through GDI and would clip HDR content, so HDR
+            // windows keep the paths below. the drop shadow is dwm's, not the
+            // window's, so WithShadow still has to come off the screen
+            if self.covered && !is_hdr && self.frame != WindowFrame::WithShadow {
+                match self.print_window(hwnd) {
+                    Ok(img) => return Ok(img),
+                    Err(e) => tracing::warn!(
+                        "PrintWindow capture (window {}) failed — fallthrough: {e:#}",
+                        self.window_id
+                    ),
                 }
-            };
+            }
+
+            // xcap and WGC both hand back the visible frame; any other frame
+            // choice is a plain screen-region grab of the matching rect
+            if self.frame != WindowFrame::Visible {
+                return self_capture_screen_region(self.window_id, self.frame);
+            }
 
             if let Ok(center) = center_res {
-                let is_hdr = super::HdrCapture::is_hdr_at_point(center.0, center.1);
                 if is_hdr {
+                    // a preferred WGC already had its go above
                     if wgc_on {
-                        let t0 = std::time::Instant::now();
-                        match super::wgc::capture_window(hwnd) {
-                            Ok(img) => {
-                                tracing::info!(
-                                    "WGC capture (window {}) {}x{} in {}ms",
-                                    self.window_id,
-                                    img.width(),
-                                    img.height(),
-                                    t0.elapsed().as_millis()
-                                );
-                                return Ok(img);
-                            }
-                            Err(e) => {
-                                tracing::warn!("WGC window capture failed — fallthrough: {e:#}")
+                        if !wgc_preferred {
+                            match self.wgc_window(hwnd) {
+                                Ok(img) => return Ok(img),
+                                Err(e) => {
+                                    tracing::warn!("WGC window capture failed — fallthrough: {e:#}")
+                                }
                             }
                         }
                     } else {
-                        match self_capture_screen_region(self.window_id) {
+                        match self_capture_screen_region(self.window_id, self.frame) {
                             Ok(img) => return Ok(img),
                             Err(e) => tracing::warn!(
                                 "WindowCapture CPU HDR path failed — fallthrough: {e:#}"
@@ -161,7 +263,7 @@ impl Capture for WindowCapture {
                 // are invisible to PrintWindow.
                 #[cfg(windows)]
                 {
-                    self_capture_screen_region(self.window_id)
+                    self_capture_screen_region(self.window_id, self.frame)
                 }
                 #[cfg(target_os = "linux")]
                 {
@@ -176,6 +278,83 @@ impl Capture for WindowCapture {
     }
 }
 
+#[cfg(windows)]
+impl WindowCapture {
+    /// only the PrintWindow render, with no screen-grab fallback, for callers
+    /// that have a better fallback of their own
+    pub fn capture_rendered(&self) -> Result<RgbaImage> {
+        if self.frame == WindowFrame::WithShadow {
+            return Err(anyhow!("the drop shadow only exists on screen"));
+        }
+        let hwnd = windows::Win32::Foundation::HWND(self.window_id as usize as *mut _);
+        let mut img = self.print_window(hwnd)?;
+        if self.clean_corners && self.frame == WindowFrame::Visible {
+            clean_window_corners(self.window_id, &mut img);
+        }
+        Ok(img)
+    }
+
+    fn print_window(&self, hwnd: windows::Win32::Foundation::HWND) -> Result<RgbaImage> {
+        let t0 = std::time::Instant::now();
+        let client_only = self.frame == WindowFrame::ClientOnly;
+        let mut img = super::gdi::print_window(hwnd, client_only)?;
+        if !client_only {
+            // the bitmap spans GetWindowRect, invisible resize border included
+            let full = window_rect(self.window_id, WindowFrame::WithShadow);
+            let visible = window_rect(self.window_id, WindowFrame::Visible);
+            if let (Some(full), Some(visible)) = (full, visible) {
+                img = crop_to_visible(&img, &full, &visible);
+            }
+        }
+        // apps that ignore WM_PRINT leave the bitmap untouched
+        if is_blank(&img) {
+            return Err(anyhow!("window painted nothing"));
+        }
+        tracing::info!(
+            "PrintWindow capture (window {}) {}x{} in {}ms",
+            self.window_id,
+            img.width(),
+            img.height(),
+            t0.elapsed().as_millis()
+        );
+        Ok(img)
+    }
+
+    fn wgc_window(&self, hwnd: windows::Win32::Foundation::HWND) -> Result<RgbaImage> {
+        let t0 = std::time::Instant::now();
+        let img = super::wgc::capture_window(hwnd)?;
+        tracing::info!(
+            "WGC capture (window {}) {}x{} in {}ms",
+            self.window_id,
+            img.width(),
+            img.height(),
+            t0.elapsed().as_millis()
+        );
+        Ok(img)
+    }
+}
+
+// cut the visible frame out of a bitmap of the full window rect. both rects
+// are in screen coordinates
+fn crop_to_visible(
+    img: &RgbaImage,
+    full: &super::Rectangle,
+    visible: &super::Rectangle,
+) -> RgbaImage {
+    let x = (visible.x - full.x).clamp(0, img.width() as i32) as u32;
+    let y = (visible.y - full.y).clamp(0, img.height() as i32) as u32;
+    let width = visible.width.min(img.width() - x);
+    let height = visible.height.min(img.height() - y);
+    if width == 0 || height == 0 {
+        return img.clone();
+    }
+    image::imageops::crop_imm(img, x, y, width, height).to_image()
+}
+
+fn is_blank(img: &RgbaImage) -> bool {
+    img.pixels().all(|p| p[0] == 0 && p[1] == 0 && p[2] == 0)
+}
+
 // xcap can drop a window from its enumeration (stale ids, override-redirect
 // surfaces) while the X server still knows its geometry; ask the server
 // directly and grab that screen region, mirroring the DWM frame-bounds
@@ -205,39 +384,309 @@ fn self_capture_screen_region(window_id: u32) -> Result<RgbaImage> {
 }
 
 #[cfg(windows)]
-fn self_capture_screen_region(hwnd_u32: u32) -> Result<RgbaImage> {
-    use windows::Win32::Foundation::{HWND, RECT};
-    use windows::Win32::Graphics::Dwm::{DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS};
-    use windows::Win32::UI::WindowsAndMessaging::GetWindowRect;
+fn self_capture_screen_region(hwnd_u32: u32, frame: WindowFrame) -> Result<RgbaImage> {
+    let region = window_rect(hwnd_u32, frame).ok_or_else(|| anyhow!("GetWindowRect failed"))?;
+    // hand off to RegionCapture which uses xcap::Monitor (DXGI Desktop
+    // Duplication on Windows). DXGI captures the actual composed desktop,
+    // so it sees every surface including DComp-backed WebView2 content.
+    super::region::RegionCapture::new(region).capture()
+}
 
-    let hwnd = HWND(hwnd_u32 as usize as *mut _);
+/// screen rect of a window for the given frame choice. Visible is the DWM
+/// extended frame bounds (falling back to GetWindowRect on pre-DWM paths),
+/// WithShadow is GetWindowRect — which on windows 10+ includes the invisible
+/// resize border the shadow is drawn into — and ClientOnly is the client
+/// area mapped to screen coordinates
+#[cfg(windows)]
+pub fn window_rect(window_id: u32, frame: WindowFrame) -> Option<super::Rectangle> {
+    use windows::Win32::Foundation::{HWND, POINT, RECT};
+    use windows::Win32::Graphics::Dwm::{DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS};
+    use windows::Win32::Graphics::Gdi::ClientToScreen;
+    use windows::Win32::UI::WindowsAndMessaging::{GetClientRect, GetWindowRect};
 
+    let hwnd = HWND(window_id as usize as *mut _);
     let rect = unsafe {
         let mut r = RECT::default();
-        let ok = DwmGetWindowAttribute(
-            hwnd,
-            DWMWA_EXTENDED_FRAME_BOUNDS,
-            &mut r as *mut RECT as *mut _,
-            std::mem::size_of::<RECT>() as u32,
-        )
-        .is_ok();
-        if !ok {
-            GetWindowRect(hwnd, &mut r).map_err(|e| anyhow!("GetWindowRect failed: {e}"))?;
+        match frame {
+            WindowFrame::Visible => {
+                let ok = DwmGetWindowAttribute(
+                    hwnd,
+                    DWMWA_EXTENDED_FRAME_BOUNDS,
+                    &mut r as *mut RECT as *mut _,
+                    std::mem::size_of::<RECT>() as u32,
+                )
+                .is_ok();
+                if !ok {
+                    GetWindowRect(hwnd, &mut r).ok()?;
+                }
+            }
+            WindowFrame::WithShadow => GetWindowRect(hwnd, &mut r).ok()?,
+            WindowFrame::ClientOnly => {
+                GetClientRect(hwnd, &mut r).ok()?;
+                let mut origin = POINT { x: 0, y: 0 };
+                if !ClientToScreen(hwnd, &mut origin).as_bool() {
+                    return None;
+                }
+                r.left += origin.x;
+                r.right += origin.x;
+                r.top += origin.y;
+                r.bottom += origin.y;
+            }
         }
         r
     };
-
-    let width = (rect.right - rect.left).max(1);
-    let height = (rect.bottom - rect.top).max(1);
-
-    // hand off to RegionCapture which uses xcap::Monitor (DXGI Desktop
-    // Duplication on Windows). DXGI captures the actual composed desktop,
-    // so it sees every surface including DComp-backed WebView2 content.
-    let region = super::Rectangle {
+    Some(super::Rectangle {
         x: rect.left,
         y: rect.top,
-        width: width as u32,
-        height: height as u32,
+        width: (rect.right - rect.left).max(1) as u32,
+        height: (rect.bottom - rect.top).max(1) as u32,
+    })
+}
+
+#[cfg(not(windows))]
+pub fn window_rect(_window_id: u32, _frame: WindowFrame) -> Option<super::Rectangle> {
+    None
+}
+
+/// screen rect of the focused window. capscr's own windows don't count — the
+/// recording bar takes focus when it's clicked — and neither does a minimized
+/// one. None when nothing else has focus or the platform can't say (wayland)
+#[cfg(windows)]
+pub fn active_window_rect() -> Option<super::Rectangle> {
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetForegroundWindow, GetWindowThreadProcessId, IsIconic,
     };
-    super::region::RegionCapture::new(region).capture()
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.0.is_null() || IsIconic(hwnd).as_bool() {
+            return None;
+        }
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if pid == std::process::id() {
+            return None;
+        }
+        window_rect(hwnd.0 as usize as u32, WindowFrame::Visible)
+    }
+}
+
+#[cfg(not(windows))]
+pub fn active_window_rect() -> Option<super::Rectangle> {
+    let window = Window::all()
+        .ok()?
+        .into_iter()
+        .find(|w| w.is_focused().unwrap_or(false))?;
+    if window.pid().ok()? == std::process::id() || window.is_minimized().unwrap_or(false) {
+        return None;
+    }
+    Some(super::Rectangle::new(
+        window.x().ok()?,
+        window.y().ok()?,
+        window.width().ok()?,
+        window.height().ok()?,
+    ))
+}
+
+// radius in physical pixels of the rounded corners dwm draws on this window,
+// or 0 where it draws square ones: windows 10, maximized/snapped-full windows,
+// and windows that opted out via DWMWA_WINDOW_CORNER_PREFERENCE
+#[cfg(windows)]
+fn window_corner_radius(window_id: u32) -> u32 {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::Graphics::Dwm::{
+        DwmGetWindowAttribute, DWMWA_VISIBLE_FRAME_BORDER_THICKNESS,
+        DWMWA_WINDOW_CORNER_PREFERENCE, DWMWCP_DONOTROUND, DWMWCP_ROUNDSMALL,
+        DWM_WINDOW_CORNER_PREFERENCE,
+    };
+    use windows::Win32::UI::HiDpi::GetDpiForWindow;
+    use windows::Win32::UI::WindowsAndMessaging::IsZoomed;
+
+    let hwnd = HWND(window_id as usize as *mut _);
+    unsafe {
+        if IsZoomed(hwnd).as_bool() {
+            return 0;
+        }
+        // the visible-border attribute only exists on windows 11, which is
+        // also the first release that rounds corners — a cheap os probe
+        let mut border = 0u32;
+        let is_win11 = DwmGetWindowAttribute(
+            hwnd,
+            DWMWA_VISIBLE_FRAME_BORDER_THICKNESS,
+            &mut border as *mut u32 as *mut _,
+            std::mem::size_of::<u32>() as u32,
+        )
+        .is_ok();
+        if !is_win11 {
+            return 0;
+        }
+        let mut pref = DWM_WINDOW_CORNER_PREFERENCE::default();
+        let base = match DwmGetWindowAttribute(
+            hwnd,
+            DWMWA_WINDOW_CORNER_PREFERENCE,
+            &mut pref as *mut DWM_WINDOW_CORNER_PREFERENCE as *mut _,
+            std::mem::size_of::<DWM_WINDOW_CORNER_PREFERENCE>() as u32,
+        ) {
+            Ok(()) if pref == DWMWCP_DONOTROUND => 0,
+            Ok(()) if pref == DWMWCP_ROUNDSMALL => 4,
+            _ => 8,
+        };
+        let dpi = GetDpiForWindow(hwnd).max(96);
+        (base * dpi).div_ceil(96)
+    }
+}
+
+/// make the pixels outside a window's rounded corners transparent. xcap's
+/// PrintWindow path fills them black and a frozen-frame crop fills them with
+/// whatever sat behind the window; neither belongs to the window
+pub fn clean_window_corners(window_id: u32, img: &mut RgbaImage) {
+    #[cfg(windows)]
+    clear_rounded_corners(img, window_corner_radius(window_id));
+    #[cfg(not(windows))]
+    let _ = (window_id, img);
+}
+
+// anti-aliased quarter-circle mask on each corner: alpha is scaled by how much
+// of the pixel falls inside the arc, so the cut-out edge isn't jagged
+#[cfg(any(windows, test))]
+fn clear_rounded_corners(img: &mut RgbaImage, radius: u32) {
+    let (w, h) = img.dimensions();
+    let radius = radius.min(w / 2).min(h / 2);
+    if radius == 0 {
+        return;
+    }
+    let r = radius as f32;
+    for dy in 0..radius {
+        for dx in 0..radius {
+            // distance from the arc centre, measured at the pixel centre
+            let fx = r - (dx as f32 + 0.5);
+            let fy = r - (dy as f32 + 0.5);
+            let coverage = (r - (fx * fx + fy * fy).sqrt() + 0.5).clamp(0.0, 1.0);
+            if coverage >= 1.0 {
+                continue;
+            }
+            for (x, y) in [
+                (dx, dy),
+                (w - 1 - dx, dy),
+                (dx, h - 1 - dy),
+                (w - 1 - dx, h - 1 - dy),
+            ] {
+                let px = img.get_pixel_mut(x, y);
+                px[3] = (px[3] as f32 * coverage).round() as u8;
+            }
+        }
+    }
+}
+
+/// compile a user-supplied window pattern case-insensitively. empty means
+/// "match anything" and yields None
+pub fn compile_window_pattern(pattern: &str) -> Result<Option<Regex>> {
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return Ok(None);
+    }
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        // user patterns come from config.toml; keep a hostile one from
+        // building a huge automaton
+        .size_limit(1 << 20)
+        .build()
+        .map(Some)
+        .map_err(|e| anyhow!("invalid window pattern '{pattern}': {e}"))
+}
+
+fn window_matches(
+    title_re: Option<&Regex>,
+    process_re: Option<&Regex>,
+    title: &str,
+    app_name: &str,
+) -> bool {
+    if title.is_empty() || (title_re.is_none() && process_re.is_none()) {
+        return false;
+    }
+    title_re.is_none_or(|re| re.is_match(title))
+        && process_re.is_none_or(|re| re.is_match(app_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_patterns_match_title_and_process() {
+        let title = compile_window_pattern("visual studio code").unwrap();
+        let process = compile_window_pattern("^code(\\.exe)?$").unwrap();
+        assert!(window_matches(
+            title.as_ref(),
+            None,
+            "main.rs - capscr - Visual Studio Code",
+            "Code.exe"
+        ));
+        assert!(window_matches(
+            title.as_ref(),
+            process.as_ref(),
+            "main.rs - Visual Studio Code",
+            "Code.exe"
+        ));
+        assert!(!window_matches(
+            title.as_ref(),
+            process.as_ref(),
+            "Visual Studio Code docs - Firefox",
+            "firefox.exe"
+        ));
+        // no pattern at all must never grab an arbitrary window
+        assert!(!window_matches(None, None, "anything", "anything"));
+        assert!(compile_window_pattern("   ").unwrap().is_none());
+        assert!(compile_window_pattern("(unclosed").is_err());
+    }
+
+    #[test]
+    fn rounded_corners_clear_outside_the_arc_only() {
+        let mut img = RgbaImage::from_pixel(40, 30, image::Rgba([10, 20, 30, 255]));
+        clear_rounded_corners(&mut img, 8);
+        for (x, y) in [(0, 0), (39, 0), (0, 29), (39, 29)] {
+            assert_eq!(
+                img.get_pixel(x, y)[3],
+                0,
+                "corner ({x},{y}) must be cleared"
+            );
+        }
+        // edge midpoints and the interior are untouched
+        for (x, y) in [(20, 0), (0, 15), (20, 15), (8, 8)] {
+            assert_eq!(img.get_pixel(x, y)[3], 255);
+        }
+        // colour is kept so viewers that ignore alpha still see the window
+        assert_eq!(img.get_pixel(0, 0)[0], 10);
+    }
+
+    #[test]
+    fn print_window_bitmap_is_cropped_to_the_visible_frame() {
+        let rect = |x, y, width, height| super::super::Rectangle {
+            x,
+            y,
+            width,
+            height,
+        };
+        let mut img = RgbaImage::new(120, 90);
+        img.put_pixel(7, 0, image::Rgba([9, 9, 9, 255]));
+        // windows 10+: a 7px invisible border left, right and bottom
+        let cropped = crop_to_visible(&img, &rect(100, 50, 120, 90), &rect(107, 50, 106, 83));
+        assert_eq!(cropped.dimensions(), (106, 83));
+        assert_eq!(cropped.get_pixel(0, 0)[0], 9);
+        // bounds that disagree with the bitmap never index past it
+        let clamped = crop_to_visible(&img, &rect(0, 0, 120, 90), &rect(100, 80, 500, 500));
+        assert_eq!(clamped.dimensions(), (20, 10));
+        assert!(!is_blank(&img));
+        assert!(is_blank(&RgbaImage::new(4, 4)));
+    }
+
+    #[test]
+    fn zero_radius_and_tiny_images_are_left_alone() {
+        let mut img = RgbaImage::from_pixel(4, 4, image::Rgba([0, 0, 0, 255]));
+        clear_rounded_corners(&mut img, 0);
+        assert!(img.pixels().all(|p| p[3] == 255));
+        // a radius larger than the image is clamped instead of indexing out
+        let mut tiny = RgbaImage::from_pixel(3, 3, image::Rgba([0, 0, 0, 255]));
+        clear_rounded_corners(&mut tiny, 50);
+        assert_eq!(tiny.get_pixel(1, 1)[3], 255);
+    }
 }
@@ -384,6 +384,11 @@ pub fn save_image<P: AsRef<Path>>(
     if let Some(parent) = path.parent() {
         if !parent.as_os_str().is_empty() {
             std::fs::create_dir_all(parent)?;
+            // raw rgba is the ceiling for every format (bmp comes close); a
+            // half-written capture would otherwise sit in the folder as a
+            // corrupt file
+            let raw = image.width() as u64 * image.height() as u64 * 4;
+            crate::disk::ensure_room(parent, raw, "capture")?;
         }
     }
 
@@ -424,26 +429,124 @@ pub fn save_image<P: AsRef<Path>>(
             image.save(path)?;
         }
         crate::config::ImageFormat::Jxl => {
-            let width = image.width();
-            let height = image.height();
-            let raw_pixels = image.as_raw();
-            let bytes = if quality >= 100 {
-                jxl_encoder::LosslessConfig::new()
-                    .encode(raw_pixels, width, height, jxl_encoder::PixelLayout::Rgba8)
-                    .map_err(|e| anyhow!("JXL encoding failed: {}", e))?
-            } else {
-                let distance = ((100 - quality) as f64 / 10.0).clamp(0.0, 15.0);
-                jxl_encoder::LossyConfig::new(distance as f32)
-                    .encode(raw_pixels, width, height, jxl_encoder::PixelLayout::Rgba8)
-                    .map_err(|e| anyhow!("JXL encoding failed: {}", e))?
-            };
-            std::fs::write(path, bytes)?;
+            std::fs::write(path, encode_jxl(image, quality)?)?;
         }
     }
 
     Ok(())
 }
 
+fn encode_jxl(image: &RgbaImage, quality: u8) -> Result<Vec<u8>> {
+    let width = image.width();
+    let height = image.height();
+    let raw_pixels = image.as_raw();
+    if quality >= 100 {
+        jxl_encoder::LosslessConfig::new()
+            .encode(raw_pixels, width, height, jxl_encoder::PixelLayout::Rgba8)
+            .map_err(|e| anyhow!("JXL encoding failed: {}", e))
+    } else {
+        let distance = ((100 - quality) as f64 / 10.0).clamp(0.0, 15.0);
+        jxl_encoder::LossyConfig::new(distance as f32)
+            .encode(raw_pixels, width, height, jxl_encoder::PixelLayout::Rgba8)
+            .map_err(|e| anyhow!("JXL encoding failed: {}", e))
+    }
+}
+
+// in-memory twin of save_image, for size estimates and upload recompression
+pub(crate) fn encode_to_vec(
+    image: &RgbaImage,
+    format: crate::config::ImageFormat,
+    quality: u8,
+) -> Result<Vec<u8>> {
+    use crate::config::ImageFormat;
+    use image::buffer::ConvertBuffer;
+    use image::ImageEncoder;
+
+    let mut out = std::io::Cursor::new(Vec::new());
+    let codec = match format {
+        ImageFormat::Jpeg => {
+            let rgb_image: image::RgbImage = image.convert();
+            image::codecs::jpeg::JpegEncoder::new_with_quality(&mut out, quality.min(100))
+                .write_image(
+                    &rgb_image,
+                    rgb_image.width(),
+                    rgb_image.height(),
+                    image::ExtendedColorType::Rgb8,
+                )?;
+            return Ok(out.into_inner());
+        }
+        ImageFormat::Jxl => return encode_jxl(image, quality),
+        ImageFormat::Png => image::ImageFormat::Png,
+        ImageFormat::Gif => image::ImageFormat::Gif,
+        ImageFormat::Webp => image::ImageFormat::WebP,
+        ImageFormat::Bmp => image::ImageFormat::Bmp,
+        ImageFormat::Avif => image::ImageFormat::Avif,
+    };
+    image.write_to(&mut out, codec)?;
+    Ok(out.into_inner())
+}
+
+// the estimate encodes a 4×4 grid of tiles taken evenly across the image
+// rather than a downscaled copy: downscaling smooths away the text edges and
+// noise that dominate a screenshot's compressed size, tiles keep them
+const ESTIMATE_GRID: u32 = 4;
+const ESTIMATE_TILE: u32 = 128;
+
+fn size_sample(image: &RgbaImage) -> Option<RgbaImage> {
+    let (w, h) = image.dimensions();
+    let sample_side = ESTIMATE_GRID * ESTIMATE_TILE;
+    if w as u64 * h as u64 <= sample_side as u64 * sample_side as u64 {
+        return None;
+    }
+    let tile_w = (w / ESTIMATE_GRID).clamp(1, ESTIMATE_TILE);
+    let tile_h = (h / ESTIMATE_GRID).clamp(1, ESTIMATE_TILE);
+    let mut sample = RgbaImage::new(tile_w * ESTIMATE_GRID, tile_h * ESTIMATE_GRID);
+    for gy in 0..ESTIMATE_GRID {
+        for gx in 0..ESTIMATE_GRID {
+            let x = gx * (w - tile_w) / (ESTIMATE_GRID - 1);
+            let y = gy * (h - tile_h) / (ESTIMATE_GRID - 1);
+            let tile = image::imageops::crop_imm(image, x, y, tile_w, tile_h).to_image();
+            image::imageops::replace(
+                &mut sample,
+                &tile,
+                (gx * tile_w) as i64,
+                (gy * tile_h) as i64,
+            );
+        }
+    }
+    Some(sample)
+}
+
+/// ballpark encoded size of `image` in every output format at `quality`.
+/// formats whose encoder fails are left out. each format encodes on its own
+/// thread since avif and gif are much slower than the rest
+pub fn estimate_encoded_sizes(
+    image: &RgbaImage,
+    quality: u8,
+) -> Vec<(crate::config::ImageFormat, u64)> {
+    let sample = size_sample(image);
+    let encoded = sample.as_ref().unwrap_or(image);
+    let scale = (image.width() as f64 * image.height() as f64)
+        / (encoded.width() as f64 * encoded.height() as f64);
+    thread::scope(|s| {
+        let jobs: Vec<_> = crate::config::ImageFormat::all()
+            .iter()
+            .map(|&format| {
+                (
+                    format,
+                    s.spawn(move || encode_to_vec(encoded, format, quality)),
+                )
+            })
+            .collect();
+        jobs.into_iter()
+            .filter_map(|(format, job)| {
+                let bytes = job.join().ok()?.ok()?;
+                Some((format, (bytes.len() as f64 * scale).round() as u64))
+            })
+            .collect()
+    })
+}
+
 fn sanitize_notification_text(text: &str) -> String {
     text.chars()
         .filter(|c| !c.is_control() || *c == '\n')
@@ -457,6 +560,60 @@ fn sanitize_notification_text(text: &str) -> String {
 const NOTIFICATION_DEDUPE_MS: u128 = 1500;
 
 pub fn show_notification(title: &str, body: &str) -> Result<()> {
+    if let Some(n) = build_notification(title, body) {
+        n.show()?;
+    }
+    Ok(())
+}
+
+/// like show_notification, with buttons and an optional preview image.
+/// `on_action` gets the id of the clicked action; "default" is a click on the
+/// toast body. only the freedesktop backend reports clicks back and shows the
+/// image, so elsewhere the toast shows without either
+pub fn show_notification_with_actions<F>(
+    title: &str,
+    body: &str,
+    image: Option<&Path>,
+    actions: &[(&str, &str)],
+    on_action: F,
+) -> Result<()>
+where
+    F: FnOnce(&str) + Send + 'static,
+{
+    let Some(mut n) = build_notification(title, body) else {
+        return Ok(());
+    };
+    #[cfg(target_os = "linux")]
+    {
+        if let Some(image) = image {
+            n.image_path(&image.to_string_lossy());
+        }
+        for (id, label) in actions {
+            n.action(id, label);
+        }
+        // wait_for_action blocks until the toast is dismissed or times out
+        std::thread::spawn(move || match n.show() {
+            Ok(handle) => handle.wait_for_action(|action| {
+                if action != "__closed" {
+                    on_action(action);
+                }
+            }),
+            Err(e) => tracing::warn!("notification failed: {e}"),
+        });
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = (image, actions, on_action);
+        n.show()?;
+    }
+    Ok(())
+}
+
+// None when an identical toast just fired
+fn build_notification(title: &str, body: &str) -> Option<notify_rust::Notification> {
+    if crate::dnd::active() {
+        return None;
+    }
     let safe_title = sanitize_notification_text(title);
     let safe_body = sanitize_notification_text(body);
 
@@ -464,7 +621,7 @@ pub fn show_notification(title: &str, body: &str) -> Result<()> {
     // last NOTIFICATION_DEDUPE_MS. Cheap mutex on a 2-tuple is fine here
     // because this path is called at human speed.
     if !should_emit(&safe_title, &safe_body) {
-        return Ok(());
+        return None;
     }
 
     let mut n = notify_rust::Notification::new();
@@ -485,9 +642,7 @@ pub fn show_notification(title: &str, body: &str) -> Result<()> {
         .icon("capscr")
         .hint(notify_rust::Hint::DesktopEntry("capscr".into()));
 
-    n.show()?;
-
-    Ok(())
+    Some(n)
 }
 
 fn should_emit(title: &str, body: &str) -> bool {
@@ -509,3 +664,26 @@ fn should_emit(title: &str, body: &str) -> bool {
     *guard = Some((title.to_string(), body.to_string(), now));
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_sample_tiles_only_large_images() {
+        assert!(size_sample(&RgbaImage::new(300, 200)).is_none());
+        let sample = size_sample(&RgbaImage::new(2560, 1440)).unwrap();
+        assert_eq!(sample.dimensions(), (512, 512));
+        // a very wide strip still yields a sample
+        let strip = size_sample(&RgbaImage::new(16000, 20)).unwrap();
+        assert_eq!(strip.dimensions(), (512, 20));
+    }
+
+    #[test]
+    fn estimates_cover_every_format() {
+        let image = RgbaImage::from_fn(96, 64, |x, y| image::Rgba([x as u8, y as u8, 128, 255]));
+        let sizes = estimate_encoded_sizes(&image, 90);
+        assert_eq!(sizes.len(), crate::config::ImageFormat::all().len());
+        assert!(sizes.iter().all(|(_, bytes)| *bytes > 0));
+    }
+}
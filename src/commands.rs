@@ -3,17 +3,20 @@
 use crate::capture::{Capture, Rectangle, RegionCapture, ScreenCapture, WindowCapture};
 use crate::clipboard::{get_unique_filepath, save_image, show_notification, ClipboardManager};
 use crate::config::{
-    CaptureTask, Config, PostCaptureAction, TaskCaptureMode, TaskPostAction, UploadDestination,
+    CaptureTask, Config, DedupeMode, DuplicateCaptures, ImageFormat, LinkFormat, PostCaptureAction,
+    ProxyMode, TaskCaptureMode, TaskPostAction, UploadDestination,
 };
+use crate::i18n::{t, tf};
 use crate::overlay::{RecordingOverlay, SelectionResult, UnifiedSelector};
 use crate::plugin::{CaptureType, PluginEvent, PluginResponse};
-use crate::recording::{GifRecorder, RecordingSettings, RecordingState, StopReason};
+use crate::recording::{FrameStats, GifRecorder, RecordingSettings, RecordingState, StopReason};
 use crate::sound::Sound;
 use crate::state::{AppState, HotkeyStatus, UploadRecord};
+use crate::tray::SystemTray;
 use crate::upload::{CustomUploader, FtpTarget, UploadService};
 use image::RgbaImage;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 use tauri::{AppHandle, Emitter, Manager, State};
@@ -56,6 +59,8 @@ pub struct HistoryEntry {
     pub is_gif: bool,
     pub is_mp4: bool,
     pub has_hdr: bool,
+    pub tags: Vec<String>,
+    pub note: String,
 }
 
 #[tauri::command]
@@ -125,6 +130,83 @@ pub fn set_config(
             config.upload.s3.secret_access_key_encrypted =
                 stored.upload.s3.secret_access_key_encrypted.clone();
         }
+        if config.upload.imgur.client_secret.is_empty()
+            && config.upload.imgur.client_secret_encrypted.is_empty()
+            && !stored.upload.imgur.client_secret_encrypted.is_empty()
+        {
+            config.upload.imgur.client_secret_encrypted =
+                stored.upload.imgur.client_secret_encrypted.clone();
+        }
+        if config.upload.webdav.password.is_empty()
+            && config.upload.webdav.password_encrypted.is_empty()
+            && !stored.upload.webdav.password_encrypted.is_empty()
+        {
+            config.upload.webdav.password_encrypted =
+                stored.upload.webdav.password_encrypted.clone();
+        }
+        if config.upload.webhook.url.is_empty()
+            && config.upload.webhook.url_encrypted.is_empty()
+            && !stored.upload.webhook.url_encrypted.is_empty()
+        {
+            config.upload.webhook.url_encrypted = stored.upload.webhook.url_encrypted.clone();
+        }
+        if config.capture.stream.url.is_empty()
+            && config.capture.stream.url_encrypted.is_empty()
+            && !stored.capture.stream.url_encrypted.is_empty()
+        {
+            config.capture.stream.url_encrypted = stored.capture.stream.url_encrypted.clone();
+        }
+        if config.upload.proxy.password.is_empty()
+            && config.upload.proxy.password_encrypted.is_empty()
+            && !stored.upload.proxy.password_encrypted.is_empty()
+        {
+            config.upload.proxy.password_encrypted = stored.upload.proxy.password_encrypted.clone();
+        }
+        for preset in &mut config.upload.custom_presets {
+            let Some(stored_preset) = stored
+                .upload
+                .custom_presets
+                .iter()
+                .find(|p| p.id == preset.id)
+            else {
+                continue;
+            };
+            for header in &mut preset.headers {
+                if header.secret && header.value.is_empty() && header.value_encrypted.is_empty() {
+                    if let Some(prev) = stored_preset
+                        .headers
+                        .iter()
+                        .find(|h| h.name.eq_ignore_ascii_case(&header.name))
+                    {
+                        header.value_encrypted = prev.value_encrypted.clone();
+                    }
+                }
+            }
+            for field in &mut preset.form_fields {
+                if field.secret && field.value.is_empty() && field.value_encrypted.is_empty() {
+                    if let Some(prev) = stored_preset
+                        .form_fields
+                        .iter()
+                        .find(|f| f.name == field.name)
+                    {
+                        field.value_encrypted = prev.value_encrypted.clone();
+                    }
+                }
+            }
+        }
+        // the sign-in is owned by the imgur_sign_in/out commands; a Settings
+        // save from a window that loaded config earlier must not undo it
+        config.upload.imgur.refresh_token_encrypted =
+            stored.upload.imgur.refresh_token_encrypted.clone();
+        config.upload.imgur.username = stored.upload.imgur.username.clone();
+        config.upload.dropbox.refresh_token_encrypted =
+            stored.upload.dropbox.refresh_token_encrypted.clone();
+        config.upload.dropbox.account_name = stored.upload.dropbox.account_name.clone();
+        // likewise the hub's position, which the window records as it's dragged,
+        // and the editor's last save-as folder and recent colors
+        config.ui.window_position = stored.ui.window_position;
+        config.output.save_as_directory = stored.output.save_as_directory.clone();
+        config.ui.editor_recent_colors = stored.ui.editor_recent_colors.clone();
     }
     // the global hotkey kill switch lives in the atomic (the tray and Settings
     // toggle it there); make the persisted config agree with it so this save
@@ -134,7 +216,18 @@ pub fn set_config(
         .load(std::sync::atomic::Ordering::SeqCst);
     config.validate().map_err(|e| e.to_string())?;
     config.save().map_err(|e| e.to_string())?;
+    apply_config(&app, &state, config);
+    Ok(())
+}
+
+/// make a saved config the running one: hotkeys, proxy, scratch folder, hub
+/// scale, language, autostart, asset scope and tray all follow it. settings
+/// saves and hand edits picked up by the config watcher both land here
+pub(crate) fn apply_config(app: &AppHandle, state: &AppState, config: Config) {
+    crate::i18n::set_language(config.ui.language);
     crate::install_hdr_runtime_from_config(&config);
+    crate::upload::set_proxy(build_proxy_setup(&config));
+    crate::scratch::configure(&config);
     // respect the tray's Disable-hotkeys toggle: when off, reload with an
     // empty task list so the new config doesn't silently re-register hotkeys
     use std::sync::atomic::Ordering;
@@ -147,14 +240,23 @@ pub fn set_config(
             crate::hotkeys::evdev_linux::start(app.clone());
         }
     }
-    let tasks_to_register = if state.hotkeys_disabled.load(Ordering::SeqCst) {
-        Vec::new()
-    } else {
-        config.capture_tasks.clone()
-    };
-    state.send_hotkey_reload(tasks_to_register);
+    let (tasks_to_register, stop_to_register, dnd_to_register) =
+        if state.hotkeys_disabled.load(Ordering::SeqCst) {
+            (Vec::new(), String::new(), String::new())
+        } else {
+            (
+                config.capture_tasks.clone(),
+                config.hotkeys.stop_recording.clone(),
+                config.hotkeys.do_not_disturb.clone(),
+            )
+        };
+    state.send_hotkey_reload(tasks_to_register, stop_to_register, dnd_to_register);
     let want_autostart = config.ui.auto_start;
+    let want_context_menu = config.ui.context_menu;
     let output_dir = config.output.directory.clone();
+    if let Some(hub) = app.get_webview_window(HUB_LABEL) {
+        apply_hub_scale(&hub, &config.ui);
+    }
     *state.config.lock().unwrap() = config;
     if let Err(e) = app
         .asset_protocol_scope()
@@ -185,9 +287,9 @@ pub fn set_config(
             tracing::warn!("autostart toggle failed: {e}");
         }
     }
-    crate::rebuild_tray_menu(&app);
+    std::thread::spawn(move || crate::context_menu::sync(want_context_menu));
+    SystemTray::rebuild(app);
     let _ = app.emit("capscr://config-updated", ());
-    Ok(())
 }
 
 #[tauri::command]
@@ -224,7 +326,7 @@ pub fn take_screenshot(
             let state = app_handle.state::<AppState>();
             let show = state.config.lock().unwrap().ui.show_notifications;
             if show {
-                let _ = show_notification("Capture failed", &friendly);
+                let _ = show_notification(t("notify.capture-failed"), &friendly);
             }
         }
     });
@@ -238,32 +340,32 @@ fn humanize_capture_error(e: &anyhow::Error) -> String {
     let raw = format!("{:#}", e);
     let s = raw.to_lowercase();
     if s.contains("d3d11") || s.contains("device") || s.contains("dxgi") {
-        "GPU couldn't initialise the capture pipeline. Try updating your graphics driver or rebooting.".into()
+        t("error.gpu").into()
     } else if s.contains("no monitor")
         || s.contains("no display")
         || s.contains("monitor not found")
     {
-        "capscr couldn't find a display. If you just unplugged a monitor, try again or restart capscr.".into()
+        t("error.no-display").into()
     } else if s.contains("access is denied") || s.contains("permission") || s.contains("denied") {
         if cfg!(windows) {
-            "Windows blocked the capture. Run capscr as administrator or check Settings > Privacy > Screen recording.".into()
+            t("error.denied.windows").into()
         } else {
-            "The desktop blocked the capture. If a screen-sharing prompt appeared, allow it and try again; `capscr --wayland-diag` shows which capture sources this session grants.".into()
+            t("error.denied.desktop").into()
         }
     } else if s.contains("hdr") {
         if cfg!(windows) {
-            "HDR capture failed. Turn HDR off in Windows display settings, or disable HDR in capscr Settings > capture > hdr.".into()
+            t("error.hdr.windows").into()
         } else {
-            "HDR capture failed. Disable HDR in capscr Settings > capture > hdr.".into()
+            t("error.hdr").into()
         }
     } else if s.contains("shader") || s.contains("compile") {
-        "Shader compilation failed. Update your graphics driver. (If this keeps happening, file an issue with your GPU model.)".into()
+        t("error.shader").into()
     } else if s.contains("clipboard") {
-        "Couldn't write to the clipboard. Another app may be holding it open — try the capture again.".into()
+        t("error.clipboard").into()
     } else if s.contains("region") && (s.contains("invalid") || s.contains("zero")) {
-        "Selected region is too small or off-screen. Drag a larger area.".into()
+        t("error.region").into()
     } else if s.contains("window") && s.contains("not found") {
-        "The selected window vanished before we could capture it (minimised, closed, or moved off-screen). Try the selector again.".into()
+        t("error.window-gone").into()
     } else {
         raw
     }
@@ -274,25 +376,28 @@ pub fn run_capture_pipeline(
     post: PostActionArg,
     app: &AppHandle,
 ) -> anyhow::Result<()> {
-    run_capture_pipeline_inner(mode, post, app, None, None)
+    run_capture_pipeline_inner(mode, post, app, UploadOverride::default(), None, None)
 }
 
-pub fn run_capture_pipeline_with_target(
+pub(crate) fn run_capture_pipeline_with_target(
     mode: CaptureModeArg,
     post: PostActionArg,
     app: &AppHandle,
-    upload_target: Option<crate::config::TaskUploadTarget>,
+    upload: UploadOverride,
     delay_override: Option<u32>,
 ) -> anyhow::Result<()> {
-    run_capture_pipeline_inner(mode, post, app, upload_target, delay_override)
+    run_capture_pipeline_inner(mode, post, app, upload, delay_override, None)
 }
 
+// `target_window` skips the selector and captures that window directly; set by
+// targeted-window tasks once the title/process match has been resolved
 fn run_capture_pipeline_inner(
     mode: CaptureModeArg,
-    post: PostActionArg,
+    mut post: PostActionArg,
     app: &AppHandle,
-    upload_target: Option<crate::config::TaskUploadTarget>,
+    upload: UploadOverride,
     delay_override: Option<u32>,
+    target_window: Option<u32>,
 ) -> anyhow::Result<()> {
     // cancel selection if already active
     if UnifiedSelector::active_selector_active() {
@@ -330,31 +435,37 @@ fn run_capture_pipeline_inner(
 
     // keep capscr itself out of the frozen desktop when capture is triggered
     // from an open hub or a second-instance jump command
-    let hub_was_visible = app
-        .get_webview_window(HUB_LABEL)
-        .and_then(|hub| hub.is_visible().ok().map(|visible| (hub, visible)))
-        .filter(|(_, visible)| *visible);
-    if let Some((hub, _)) = hub_was_visible {
-        let _ = hub.hide();
-        // let the compositor present one frame without the hub before capture
-        std::thread::sleep(Duration::from_millis(34));
-    }
+    let hub_hidden = HubHidden::for_capture(&app);
+    // an open editor stays where it is for the user but not for the capture;
+    // released once the pixels are in hand
+    let own_windows = OwnWindowsExcluded::arm(&app);
 
     // honour the configured pre-capture delay before capturing the freeze-frame
     // (used to set up menus / hover states before the snapshot is taken).
     {
         // a per-task delay overrides the global one; clamp so a hand-edited
         // config can't stall the capture thread for minutes
-        let global_delay = app
-            .state::<AppState>()
-            .config
-            .lock()
-            .unwrap()
-            .capture
-            .delay_ms;
+        let (global_delay, show) = {
+            let config = app.state::<AppState>().config.lock().unwrap().clone();
+            (config.capture.delay_ms, config.ui.show_notifications)
+        };
         let delay_ms = delay_override.unwrap_or(global_delay).min(30_000);
         if delay_ms > 0 {
-            std::thread::sleep(Duration::from_millis(delay_ms as u64));
+            // Esc, the tray or the toast cancels; dropped before the selector
+            // opens so its own Esc is free again
+            let countdown = crate::cancel::Task::begin(app, true);
+            // the toast only when it times out before the frame is taken
+            if show && delay_ms >= 5_000 {
+                let secs = delay_ms / 1000;
+                countdown.offer_cancel(
+                    &tf("notify.countdown", &[("secs", &secs)]),
+                    t("notify.countdown.body"),
+                );
+            }
+            if !countdown.sleep(Duration::from_millis(delay_ms as u64)) {
+                tracing::info!("capture cancelled during its delay");
+                return Ok(());
+            }
         }
     }
 
@@ -362,52 +473,55 @@ fn run_capture_pipeline_inner(
     // the selector. only the first use (no stored rect yet) falls back to a
     // normal region drag.
     let replay_rect = if matches!(mode, CaptureModeArg::RegionLast) {
-        *gate_state.last_region.lock().unwrap()
+        gate_state.last_region()
     } else {
         None
     };
     #[cfg(target_os = "linux")]
-    let compositor_window =
-        if matches!(mode, CaptureModeArg::Window) && crate::capture::is_wayland_session() {
-            let include_cursor = gate_state.config.lock().unwrap().capture.show_cursor;
-            match crate::capture::capture_wayland_window(include_cursor) {
-                Ok(image) => Some(image),
-                Err(error) if format!("{error:#}").contains("Cancelled") => return Ok(()),
-                // with the companion extension the capscr selector picks
-                // windows on gnome too; fall through to it like on kde/x11
-                Err(_)
-                    if crate::shell::desktop() == crate::shell::DesktopEnv::Gnome
-                        && crate::capture::gnome_shell::available() =>
-                {
-                    tracing::debug!("using the capscr selector via the companion extension");
-                    None
-                }
-                // gnome without the companion offers no window list to
-                // ordinary apps; its own picker through the portal's
-                // interactive mode is the only sanctioned window-pick there
-                Err(_) if crate::shell::desktop() == crate::shell::DesktopEnv::Gnome => {
-                    match crate::capture::portal_screenshot_interactive() {
-                        Ok(image) => Some(image),
-                        Err(error) => {
-                            tracing::info!("portal interactive pick declined ({error:#})");
-                            return Ok(());
-                        }
+    let compositor_window = if matches!(mode, CaptureModeArg::Window)
+        && target_window.is_none()
+        && crate::capture::is_wayland_session()
+    {
+        let include_cursor = gate_state.config.lock().unwrap().capture.show_cursor;
+        match crate::capture::capture_wayland_window(include_cursor) {
+            Ok(image) => Some(image),
+            Err(error) if format!("{error:#}").contains("Cancelled") => return Ok(()),
+            // with the companion extension the capscr selector picks
+            // windows on gnome too; fall through to it like on kde/x11
+            Err(_)
+                if crate::shell::desktop() == crate::shell::DesktopEnv::Gnome
+                    && crate::capture::gnome_shell::available() =>
+            {
+                tracing::debug!("using the capscr selector via the companion extension");
+                None
+            }
+            // gnome without the companion offers no window list to
+            // ordinary apps; its own picker through the portal's
+            // interactive mode is the only sanctioned window-pick there
+            Err(_) if crate::shell::desktop() == crate::shell::DesktopEnv::Gnome => {
+                match crate::capture::portal_screenshot_interactive() {
+                    Ok(image) => Some(image),
+                    Err(error) => {
+                        tracing::info!("portal interactive pick declined ({error:#})");
+                        return Ok(());
                     }
                 }
-                Err(error) => {
-                    tracing::debug!(
+            }
+            Err(error) => {
+                tracing::debug!(
                     "compositor window selection unavailable ({error:#}); using capscr selector"
                 );
-                    None
-                }
+                None
             }
-        } else {
-            None
-        };
+        }
+    } else {
+        None
+    };
     #[cfg(not(target_os = "linux"))]
     let compositor_window: Option<image::RgbaImage> = None;
 
     let needs_selector = compositor_window.is_none()
+        && target_window.is_none()
         && (matches!(
             mode,
             CaptureModeArg::Region | CaptureModeArg::Window | CaptureModeArg::Fullscreen
@@ -418,6 +532,9 @@ fn run_capture_pipeline_inner(
     // critical path. only the selector-backed modes consume the result.
     if needs_selector {
         UnifiedSelector::prewarm_window_list();
+        let capture = gate_state.config.lock().unwrap().capture.clone();
+        UnifiedSelector::set_element_detection(capture.detect_ui_elements);
+        UnifiedSelector::set_cursor_monitor_only(capture.selector_cursor_monitor);
     }
 
     let frozen_frame = if needs_selector {
@@ -459,6 +576,7 @@ fn run_capture_pipeline_inner(
         SelectionResult::Cancelled
     } else {
         match mode {
+            _ if target_window.is_some() => SelectionResult::Window(target_window.unwrap()),
             _ if replay_rect.is_some() => SelectionResult::Region(replay_rect.unwrap()),
             CaptureModeArg::Region
             | CaptureModeArg::RegionLast
@@ -485,6 +603,44 @@ fn run_capture_pipeline_inner(
         std::thread::sleep(Duration::from_millis(20));
     }
 
+    // the title has to be read while the window is still the selection; only
+    // worth a window enumeration when it will be written into the file
+    let embed_metadata =
+        gate_state.config.lock().unwrap().output.metadata == crate::config::MetadataMode::Embed;
+    let window_title = match &selection {
+        SelectionResult::Window(id) if embed_metadata => window_title(*id),
+        _ => None,
+    };
+    let picked_window = match &selection {
+        SelectionResult::Window(id) => Some(*id),
+        _ => None,
+    };
+    // app profiles follow the picked window's process; only worth finding
+    // when there's a profile to match it against
+    let has_profiles = !gate_state
+        .config
+        .lock()
+        .unwrap()
+        .output
+        .app_profiles
+        .is_empty();
+    let picked_app = match &selection {
+        SelectionResult::Window(id) if has_profiles => window_app(*id),
+        _ => None,
+    };
+    if let Some(profile) = picked_app.as_deref().and_then(|name| {
+        let config = gate_state.config.lock().unwrap();
+        config.output.profile_for(name).cloned()
+    }) {
+        tracing::info!("capture of {} follows its app profile", profile.app);
+        if let Some(action) = profile.post_action {
+            post = PostActionArg::from_task_action(action);
+        }
+    }
+    if upload.local_only && matches!(post, PostActionArg::Upload) {
+        post = PostActionArg::SaveAndClipboard;
+    }
+
     let (mut image, mut hdr_bitmap, screen_origin): (
         image::RgbaImage,
         Option<crate::capture::HdrBitmap>,
@@ -496,7 +652,7 @@ fn run_capture_pipeline_inner(
         },
         SelectionResult::Region(rect) => {
             // remember the rectangle so a "region (last)" task can replay it
-            *gate_state.last_region.lock().unwrap() = Some(rect);
+            gate_state.remember_region(rect);
             #[cfg(target_os = "linux")]
             let native_wayland = if crate::capture::is_wayland_session() {
                 Some(crate::capture::capture_wayland_region(rect)?)
@@ -548,49 +704,63 @@ fn run_capture_pipeline_inner(
         }
         #[cfg(target_os = "linux")]
         SelectionResult::FrozenRegion { rect, image } => {
-            *gate_state.last_region.lock().unwrap() = Some(rect);
+            gate_state.remember_region(rect);
             (Arc::unwrap_or_clone(image), None, Some((rect.x, rect.y)))
         }
         SelectionResult::Window(hwnd) => {
+            let (window_frame, clean_corners, covered) = {
+                let cfg = gate_state.config.lock().unwrap();
+                (
+                    cfg.capture.window_frame,
+                    cfg.capture.clean_window_corners,
+                    cfg.capture.capture_covered_windows,
+                )
+            };
+            let window_capture = || {
+                WindowCapture::new(hwnd)
+                    .with_frame(window_frame, clean_corners)
+                    .with_covered(covered)
+            };
             if let Some(frozen) = &frozen_frame {
                 #[cfg(windows)]
-                unsafe {
-                    use windows::Win32::Foundation::{HWND, RECT};
-                    use windows::Win32::Graphics::Dwm::{
-                        DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS,
+                {
+                    // the frozen frame has whatever overlapped the window baked
+                    // in; a PrintWindow render doesn't, so it goes first
+                    let rendered = if covered {
+                        window_capture()
+                            .capture_rendered()
+                            .inspect_err(|e| {
+                                tracing::warn!("covered window capture failed, cropping: {e:#}")
+                            })
+                            .ok()
+                            .map(|img| {
+                                let origin = crate::capture::window_rect(hwnd, window_frame)
+                                    .map(|r| (r.x, r.y));
+                                (img, None, origin)
+                            })
+                    } else {
+                        None
                     };
-                    use windows::Win32::UI::WindowsAndMessaging::GetWindowRect;
-                    let mut rect = RECT::default();
-                    let hwnd_struct = HWND(hwnd as *mut _);
-                    let dwm_ok = DwmGetWindowAttribute(
-                        hwnd_struct,
-                        DWMWA_EXTENDED_FRAME_BOUNDS,
-                        &mut rect as *mut RECT as *mut _,
-                        std::mem::size_of::<RECT>() as u32,
-                    )
-                    .is_ok();
-                    if dwm_ok || GetWindowRect(hwnd_struct, &mut rect).is_ok() {
-                        let (min_x, min_y) =
-                            if let Ok(monitors) = crate::capture::fast_list_monitors() {
-                                let mx = monitors.iter().map(|m| m.x).min().unwrap_or(0);
-                                let my = monitors.iter().map(|m| m.y).min().unwrap_or(0);
-                                (mx, my)
-                            } else {
-                                (0, 0)
-                            };
-
-                        let w_x = rect.left;
-                        let w_y = rect.top;
-                        let w_w = (rect.right - rect.left).max(0) as u32;
-                        let w_h = (rect.bottom - rect.top).max(0) as u32;
-
-                        let img_x = (w_x - min_x).max(0) as u32;
-                        let img_y = (w_y - min_y).max(0) as u32;
-                        let crop_width = w_w.min(frozen.width().saturating_sub(img_x));
-                        let crop_height = w_h.min(frozen.height().saturating_sub(img_y));
-
-                        if crop_width > 0 && crop_height > 0 {
-                            let cropped = image::imageops::crop_imm(
+                    let cropped = rendered.or_else(|| {
+                        crate::capture::window_rect(hwnd, window_frame).and_then(|rect| {
+                            let (min_x, min_y) =
+                                if let Ok(monitors) = crate::capture::fast_list_monitors() {
+                                    let mx = monitors.iter().map(|m| m.x).min().unwrap_or(0);
+                                    let my = monitors.iter().map(|m| m.y).min().unwrap_or(0);
+                                    (mx, my)
+                                } else {
+                                    (0, 0)
+                                };
+
+                            let img_x = (rect.x - min_x).max(0) as u32;
+                            let img_y = (rect.y - min_y).max(0) as u32;
+                            let crop_width = rect.width.min(frozen.width().saturating_sub(img_x));
+                            let crop_height =
+                                rect.height.min(frozen.height().saturating_sub(img_y));
+                            if crop_width == 0 || crop_height == 0 {
+                                return None;
+                            }
+                            let mut img = image::imageops::crop_imm(
                                 &**frozen,
                                 img_x,
                                 img_y,
@@ -598,18 +768,20 @@ fn run_capture_pipeline_inner(
                                 crop_height,
                             )
                             .to_image();
-                            (cropped, None, Some((w_x, w_y)))
-                        } else {
-                            let cap = WindowCapture::new(hwnd);
-                            let img = cap.capture()?;
+                            if clean_corners && window_frame == crate::config::WindowFrame::Visible
+                            {
+                                crate::capture::clean_window_corners(hwnd, &mut img);
+                            }
+                            Some((img, None, Some((rect.x, rect.y))))
+                        })
+                    });
+                    match cropped {
+                        Some(result) => result,
+                        None => {
+                            let img = window_capture().capture()?;
                             let origin = window_screen_origin(hwnd);
                             (img, None, origin)
                         }
-                    } else {
-                        let cap = WindowCapture::new(hwnd);
-                        let img = cap.capture()?;
-                        let origin = window_screen_origin(hwnd);
-                        (img, None, origin)
                     }
                 }
                 #[cfg(not(windows))]
@@ -641,15 +813,14 @@ fn run_capture_pipeline_inner(
                     match cropped {
                         Some(result) => result,
                         None => {
-                            let cap = WindowCapture::new(hwnd);
-                            let img = cap.capture()?;
+                            let img = window_capture().capture()?;
                             let origin = window_screen_origin(hwnd);
                             (img, None, origin)
                         }
                     }
                 }
             } else {
-                let cap = WindowCapture::new(hwnd);
+                let cap = window_capture();
                 let img = match cap.capture() {
                     Ok(img) => img,
                     Err(_) => {
@@ -706,14 +877,42 @@ fn run_capture_pipeline_inner(
             };
             Sound::Screenshot.play_if_enabled(play_sound);
             if show {
-                let _ = show_notification("Color picked", &hex);
+                let _ = show_notification(t("notify.color-picked"), &hex);
             }
             return Ok(());
         }
     };
+    drop(own_windows);
+    drop(hub_hidden);
 
     let state = app.state::<AppState>();
 
+    // excluded apps come out before anything else sees the pixels. without a
+    // screen origin there's no window geometry to match against, so the
+    // capture fails closed like an unattended one does
+    let exclusion = state.config.lock().unwrap().capture.exclusion.clone();
+    if !exclusion.apps.is_empty() {
+        let Some(origin) = screen_origin else {
+            let windows = crate::capture::exclusion::excluded_windows(&exclusion.apps)?;
+            if let Some(hit) = picked_window.and_then(|id| windows.iter().find(|w| w.id == id)) {
+                return Err(anyhow::anyhow!(
+                    "{} is on the capture exclusion list",
+                    hit.app
+                ));
+            }
+            return Err(anyhow::anyhow!(
+                "couldn't place the capture on screen to apply the exclusion list"
+            ));
+        };
+        let masked =
+            crate::capture::exclusion::apply(&mut image, origin, &exclusion, picked_window)?;
+        // the hdr sidecar is a second copy of the same pixels; drop it
+        // rather than mask it too
+        if masked > 0 {
+            hdr_bitmap = None;
+        }
+    }
+
     // honour the show_cursor toggle by painting the live cursor into the
     // captured pixels at its screen-relative position. Skipped if the
     // capture didn't expose a screen origin (e.g. an unknown selection
@@ -737,13 +936,53 @@ fn run_capture_pipeline_inner(
         }
     }
 
-    let capture_type = match mode {
-        CaptureModeArg::Region | CaptureModeArg::RegionLast => CaptureType::Region,
-        CaptureModeArg::Window => CaptureType::Window,
-        CaptureModeArg::Fullscreen | CaptureModeArg::ActiveMonitor => CaptureType::FullScreen,
+    let meta = crate::metadata::CaptureMeta {
+        monitor: screen_origin
+            .filter(|_| embed_metadata)
+            .and_then(monitor_name_at),
+        window_title,
+        app: picked_app,
+        ..crate::metadata::CaptureMeta::now()
+    };
+
+    let capture_type = mode.capture_type();
+
+    let (duplicates, show) = {
+        let config = state.config.lock().unwrap();
+        (config.capture.duplicates, config.ui.show_notifications)
     };
+    // hashing the whole frame is only worth it when a repeat changes anything
+    let repeat = match duplicates {
+        DuplicateCaptures::Off => None,
+        _ => crate::duplicate::check(&image),
+    };
+    if let Some(ago) = repeat {
+        let secs = ago.as_secs();
+        match duplicates {
+            DuplicateCaptures::Off => {}
+            DuplicateCaptures::Warn => {
+                if show {
+                    let _ = show_notification(
+                        t("notify.duplicate"),
+                        &tf("notify.duplicate.body", &[("secs", &secs)]),
+                    );
+                }
+            }
+            DuplicateCaptures::Skip => {
+                tracing::info!("capture identical to the one {secs}s ago; skipped");
+                if show {
+                    let _ = show_notification(
+                        t("notify.duplicate-skipped"),
+                        &tf("notify.duplicate-skipped.body", &[("secs", &secs)]),
+                    );
+                }
+                return Ok(());
+            }
+        }
+    }
 
     let mut image = Arc::new(image);
+    let original = image.clone();
     {
         // make sure the background plugin load has finished before dispatching
         // on_capture, so a capture fired right after launch still runs plugin
@@ -769,22 +1008,27 @@ fn run_capture_pipeline_inner(
             PluginResponse::Continue => {}
         }
     }
+    archive_original(app, &capture_config(&state, &meta), original);
 
     if matches!(post, PostActionArg::OpenEditor | PostActionArg::Prompt) {
-        let config = state.config.lock().unwrap().clone();
+        let config = capture_config(&state, &meta);
         let base = config.output_path();
         let path = get_unique_filepath(&base);
         if let Err(e) = std::fs::create_dir_all(&config.output.directory) {
             tracing::warn!("failed to create output dir: {e}");
         }
         crate::clipboard::save_image(&image, &path, config.output.format, config.output.quality)?;
+        if let Err(e) = crate::metadata::apply_to_file(&path, config.output.metadata, &meta) {
+            tracing::warn!("couldn't rewrite capture metadata: {e:#}");
+        }
         maybe_write_hdr_sidecar(&path, &hdr_bitmap, &config);
-        *state.last_save.lock().unwrap() = Some(path.clone());
+        state.record_save(&path);
+        SystemTray::rebuild(app);
         notify_capture_saved(app, &path);
         open_editor_window(app, &path.to_string_lossy()).map_err(|e| anyhow::anyhow!(e))?;
         Sound::Screenshot.play_if_enabled(config.post_capture.play_sound);
         if config.ui.show_notifications {
-            let _ = show_notification("Capture opened", &path.to_string_lossy());
+            let _ = show_notification(t("notify.capture-opened"), &path.to_string_lossy());
         }
         return Ok(());
     }
@@ -797,7 +1041,7 @@ fn run_capture_pipeline_inner(
         let trimmed = text.trim();
         if trimmed.is_empty() {
             if config.ui.show_notifications {
-                let _ = show_notification("No text found", "the capture had no detectable text");
+                let _ = show_notification(t("notify.no-text"), t("notify.no-text.body"));
             }
         } else {
             let mut cb = ClipboardManager::new().map_err(|e| anyhow::anyhow!(e))?;
@@ -805,12 +1049,21 @@ fn run_capture_pipeline_inner(
             Sound::Screenshot.play_if_enabled(config.post_capture.play_sound);
             if config.ui.show_notifications {
                 let preview: String = trimmed.chars().take(80).collect();
-                let _ = show_notification("Text copied", &preview);
+                let _ = show_notification(t("notify.text-copied"), &preview);
             }
         }
         return Ok(());
     }
 
+    // decorations go on after ocr and the editor hand-off have returned: the
+    // editor gets the raw capture to work on, and ocr shouldn't read the caption
+    let decoration = state.config.lock().unwrap().output.decoration.clone();
+    if decoration.is_active() {
+        image = Arc::new(crate::decorate::apply(&image, &decoration));
+        // the sidecar's pixels no longer line up with the framed image
+        hdr_bitmap = None;
+    }
+
     let post_action = match post {
         PostActionArg::Clipboard => PostCaptureAction::CopyToClipboard,
         PostActionArg::SaveFile => PostCaptureAction::SaveToFile,
@@ -827,11 +1080,72 @@ fn run_capture_pipeline_inner(
         image.clone(),
         hdr_bitmap,
         post_action,
-        upload_target,
+        &upload,
+        &meta,
     );
     result.map(|_| ())
 }
 
+// the capture as taken, before plugins, decoration or a lossy format, kept as
+// a lossless png for re-editing later. queued on the encode pool like any
+// save; a failed write is logged and never holds up the processed output
+fn archive_original(app: &AppHandle, config: &Config, image: Arc<RgbaImage>) {
+    let Some(dir) = config.output.archive_dir() else {
+        return;
+    };
+    if let Err(e) = std::fs::create_dir_all(&dir) {
+        tracing::warn!("failed to create archive dir: {e}");
+        return;
+    }
+    let mut named = config.clone();
+    named.output.format = ImageFormat::Png;
+    let path = get_unique_filepath(&dir.join(named.generate_filename()));
+    let placeholder = path.clone();
+    let estimate = crate::encode::estimate_bytes(image.width(), image.height(), ImageFormat::Png);
+    let run = move || {
+        if let Err(e) = save_image(&image, &path, ImageFormat::Png, 100) {
+            tracing::warn!("couldn't archive the original capture: {e:#}");
+            let _ = std::fs::remove_file(&path);
+        }
+    };
+    crate::encode::submit(app, estimate, run, move || {
+        let _ = std::fs::remove_file(&placeholder);
+    });
+}
+
+// an image another program wrote into the watch folder: decorated and handed
+// to the post-capture action as if capscr had just captured it
+pub(crate) fn run_pipeline_on_file(
+    app: &AppHandle,
+    path: &Path,
+    action: TaskPostAction,
+) -> anyhow::Result<()> {
+    let post_action = match action {
+        TaskPostAction::Clipboard => PostCaptureAction::CopyToClipboard,
+        TaskPostAction::SaveFile => PostCaptureAction::SaveToFile,
+        TaskPostAction::SaveAndClipboard => PostCaptureAction::SaveAndCopy,
+        TaskPostAction::Upload => PostCaptureAction::Upload,
+        // sanitize keeps the rest out; never publish on a fallback
+        _ => PostCaptureAction::CopyToClipboard,
+    };
+    let mut image = Arc::new(image::open(path)?.to_rgba8());
+    let state = app.state::<AppState>();
+    let decoration = state.config.lock().unwrap().output.decoration.clone();
+    if decoration.is_active() {
+        image = Arc::new(crate::decorate::apply(&image, &decoration));
+    }
+    run_post_action(
+        app,
+        &state,
+        image,
+        None,
+        post_action,
+        &UploadOverride::default(),
+        &crate::metadata::CaptureMeta::now(),
+    )
+    .map(|_| ())
+}
+
 fn build_s3_service(config: &Config) -> UploadService {
     UploadService::S3(crate::upload::S3Target {
         bucket: config.upload.s3.bucket.clone(),
@@ -843,6 +1157,29 @@ fn build_s3_service(config: &Config) -> UploadService {
     })
 }
 
+fn build_webdav_target(config: &Config) -> crate::upload::webdav::WebdavTarget {
+    let webdav = &config.upload.webdav;
+    crate::upload::webdav::WebdavTarget {
+        server_url: webdav.server_url.clone(),
+        username: webdav.username.clone(),
+        password: webdav.password_plaintext(),
+        path_template: webdav.path_template.clone(),
+        share_link: webdav.share_link,
+        public_url_template: webdav.public_url_template.clone(),
+    }
+}
+
+// signed out still builds a target; the upload then fails with a hint to
+// sign in rather than falling back to another host
+fn build_dropbox_service(config: &Config) -> UploadService {
+    let dropbox = &config.upload.dropbox;
+    UploadService::Dropbox(crate::upload::dropbox::DropboxAccount {
+        app_key: dropbox.app_key.clone(),
+        refresh_token: dropbox.refresh_token_plaintext().unwrap_or_default(),
+        folder: dropbox.folder.clone(),
+    })
+}
+
 fn build_ftp_service(config: &Config) -> UploadService {
     UploadService::Ftp(FtpTarget {
         host: config.upload.ftp.host.clone(),
@@ -869,6 +1206,9 @@ fn build_sftp_service(config: &Config) -> UploadService {
 }
 
 fn build_imgur_service(config: &Config) -> UploadService {
+    if let Some(account) = imgur_account(config) {
+        return UploadService::ImgurAccount(account);
+    }
     let cid = config.upload.imgur_client_id.trim();
     if cid.is_empty() || cid == "546c25a59c58ad7" {
         UploadService::Imgur
@@ -877,49 +1217,334 @@ fn build_imgur_service(config: &Config) -> UploadService {
     }
 }
 
-// build an upload service, optionally overriding the global destination with a
-// per-task target. `target_override = None` uses the global config destination.
+// `preset = None` is the active preset. with no presets configured the empty
+// uploader fails with "URL not configured", same as an unset custom url did
+fn build_custom_uploader(config: &Config, preset: Option<&str>) -> CustomUploader {
+    let Some(preset) = config.upload.custom_preset(preset) else {
+        return CustomUploader::default();
+    };
+    CustomUploader {
+        name: preset.name.clone(),
+        request_url: preset.url.clone(),
+        method: match preset.method {
+            crate::config::CustomHttpMethod::Post => crate::upload::CustomMethod::Post,
+            crate::config::CustomHttpMethod::Put => crate::upload::CustomMethod::Put,
+        },
+        file_form_name: preset.form_name.clone(),
+        response_url_path: preset.response_path.clone(),
+        response_regex: preset.response_regex.clone(),
+        headers: preset
+            .headers
+            .iter()
+            .map(|h| (h.name.trim().to_string(), h.value_plaintext()))
+            .collect(),
+        form_fields: preset
+            .form_fields
+            .iter()
+            .map(|f| (f.name.trim().to_string(), f.value_plaintext()))
+            .collect(),
+        max_file_size: preset.max_file_mb as usize * 1024 * 1024,
+    }
+}
+
+fn imgur_account(config: &Config) -> Option<crate::upload::imgur::ImgurAccount> {
+    let imgur = &config.upload.imgur;
+    Some(crate::upload::imgur::ImgurAccount {
+        client_id: config.upload.imgur_client_id.trim().to_string(),
+        client_secret: imgur.client_secret_plaintext(),
+        refresh_token: imgur.refresh_token_plaintext()?,
+        album_id: imgur.album_id.clone(),
+        title: imgur.title.clone(),
+        description: imgur.description.clone(),
+    })
+}
+
+/// where one upload goes when it shouldn't follow the global destination: a
+/// task's target, or a custom preset picked in the editor
+#[derive(Debug, Clone, Default)]
+pub(crate) struct UploadOverride {
+    /// `None` uses the global config destination
+    pub target: Option<crate::config::TaskUploadTarget>,
+    /// custom preset id; `None` (or an id that no longer exists) uses the
+    /// active preset
+    pub custom_preset: Option<String>,
+    /// the capture came from a capscr:// link: an upload action, configured
+    /// or from an app profile, saves and copies instead
+    pub local_only: bool,
+}
+
+impl UploadOverride {
+    pub(crate) fn for_task(task: &CaptureTask) -> Self {
+        Self {
+            target: task.target_destination,
+            custom_preset: task.custom_preset.clone(),
+            local_only: false,
+        }
+    }
+
+    // a named preset implies the custom destination
+    fn for_preset(preset: Option<String>) -> Self {
+        Self {
+            target: preset
+                .as_ref()
+                .map(|_| crate::config::TaskUploadTarget::Custom),
+            custom_preset: preset,
+        }
+    }
+}
+
 fn build_upload_service(config: &Config) -> UploadService {
-    build_upload_service_for_target(config, None)
+    build_upload_service_for_target(config, &UploadOverride::default())
+}
+
+fn build_upload_service_for_target(config: &Config, upload: &UploadOverride) -> UploadService {
+    let destination = upload
+        .target
+        .map(UploadDestination::from)
+        .unwrap_or(config.upload.destination);
+    build_destination_service(config, destination, upload.custom_preset.as_deref())
 }
 
-fn build_upload_service_for_target(
+fn build_destination_service(
     config: &Config,
-    target_override: Option<crate::config::TaskUploadTarget>,
+    destination: UploadDestination,
+    preset: Option<&str>,
 ) -> UploadService {
-    use crate::config::TaskUploadTarget;
-    match target_override {
-        None => match config.upload.destination {
-            UploadDestination::Imgur => build_imgur_service(config),
-            UploadDestination::Custom => UploadService::Custom(CustomUploader {
-                name: "Custom".to_string(),
-                request_url: config.upload.custom_url.clone(),
-                file_form_name: config.upload.custom_form_name.clone(),
-                response_url_path: config.upload.custom_response_path.clone(),
-            }),
-            UploadDestination::Ftp => build_ftp_service(config),
-            UploadDestination::Sftp => build_sftp_service(config),
-            UploadDestination::S3 => build_s3_service(config),
-        },
-        Some(TaskUploadTarget::Imgur) => build_imgur_service(config),
-        Some(TaskUploadTarget::Custom) => UploadService::Custom(CustomUploader {
-            name: "Custom".to_string(),
-            request_url: config.upload.custom_url.clone(),
-            file_form_name: config.upload.custom_form_name.clone(),
-            response_url_path: config.upload.custom_response_path.clone(),
+    let service = match destination {
+        UploadDestination::Imgur => build_imgur_service(config),
+        UploadDestination::Custom => UploadService::Custom(build_custom_uploader(config, preset)),
+        UploadDestination::Ftp => build_ftp_service(config),
+        UploadDestination::Sftp => build_sftp_service(config),
+        UploadDestination::S3 => build_s3_service(config),
+        UploadDestination::Webdav => UploadService::Webdav(build_webdav_target(config)),
+        UploadDestination::Dropbox => build_dropbox_service(config),
+        UploadDestination::Email => UploadService::Email(crate::upload::send::EmailTarget {
+            to: config.upload.email.to.trim().to_string(),
+            subject: config.upload.email.subject.clone(),
         }),
-        Some(TaskUploadTarget::Ftp) => build_ftp_service(config),
-        Some(TaskUploadTarget::Sftp) => build_sftp_service(config),
-        Some(TaskUploadTarget::S3) => build_s3_service(config),
+        UploadDestination::Webhook => build_webhook_service(config),
+    };
+    // email and direct webhook posts hand over the image itself, there's no
+    // link to carry a key. a webhook's link host is wrapped on its own build
+    if config.upload.encrypt && destination.hosts_links() {
+        UploadService::Encrypted(Box::new(service))
+    } else {
+        service
+    }
+}
+
+fn build_webhook_service(config: &Config) -> UploadService {
+    let webhook = &config.upload.webhook;
+    // sanitize keeps link_host on a real host; the check here just makes the
+    // recursion impossible whatever the config says
+    let host = (webhook.kind.needs_link_host() && webhook.link_host.hosts_links())
+        .then(|| Box::new(build_destination_service(config, webhook.link_host, None)));
+    UploadService::Webhook(crate::upload::send::WebhookTarget {
+        kind: webhook.kind,
+        url: webhook.url_plaintext(),
+        message: webhook.message.clone(),
+        host,
+    })
+}
+
+pub(crate) fn build_proxy_setup(config: &Config) -> crate::upload::proxy::ProxySetup {
+    use crate::upload::proxy::ProxySetup;
+    let proxy = &config.upload.proxy;
+    match proxy.mode {
+        ProxyMode::System => ProxySetup::System,
+        ProxyMode::Direct => ProxySetup::Direct,
+        ProxyMode::Manual => ProxySetup::Manual {
+            url: proxy.url.clone(),
+            username: proxy.username.clone(),
+            password: proxy.password_plaintext(),
+        },
+    }
+}
+
+// upload_deduped under a cancel task, so the tray or the toast can abandon a
+// slow upload. a request can't be pulled back mid-flight: a cancelled one runs
+// out on its own thread and its link is dropped
+fn upload_cancellable(
+    app: &AppHandle,
+    config: &Config,
+    data: Vec<u8>,
+    mime: &'static str,
+    file_name: &'static str,
+    service: UploadService,
+) -> anyhow::Result<crate::upload::UploadResult> {
+    let task = crate::cancel::Task::begin(app, false);
+    if config.ui.show_notifications {
+        task.offer_cancel(t("notify.uploading"), file_name);
+    }
+    let (tx, rx) = crossbeam_channel::bounded(1);
+    let (worker_app, config) = (app.clone(), config.clone());
+    std::thread::Builder::new()
+        .name("capscr-upload".into())
+        .spawn(move || {
+            let result = upload_deduped(&worker_app, &config, &data, mime, file_name, &service);
+            let _ = tx.send(result);
+        })?;
+    task.recv(&rx)?
+}
+
+// uploads through the dedupe index: bytes that already went to the same
+// destination within upload.dedupe_days come back as the earlier link, after
+// asking in Ask mode. anything over the destination's size limit is
+// recompressed first. the dialog blocks, so this must run off the main thread
+fn upload_deduped(
+    app: &AppHandle,
+    config: &Config,
+    data: &[u8],
+    mime: &str,
+    file_name: &str,
+    service: &UploadService,
+) -> anyhow::Result<crate::upload::UploadResult> {
+    use crate::upload::dedupe;
+    let uploader = crate::upload::shared_uploader()?;
+    let key = match config.upload.dedupe {
+        DedupeMode::Off => None,
+        _ => dedupe::destination_key(service),
+    };
+    // the index matches the capture as taken, before any recompression
+    let hash = key.as_ref().map(|_| dedupe::digest(data));
+    if let (Some(key), Some(hash)) = (&key, &hash) {
+        if let Some(earlier) = dedupe::find(hash, key, config.upload.dedupe_days) {
+            if config.upload.dedupe == DedupeMode::Reuse || ask_reuse_upload(app, &earlier) {
+                tracing::info!(
+                    "reusing the earlier upload of identical bytes: {}",
+                    earlier.url
+                );
+                return Ok(crate::upload::UploadResult {
+                    url: earlier.url,
+                    delete_url: earlier.delete_url,
+                });
+            }
+        }
+    }
+    // scanned as taken, before recompression blurs the text
+    if config.upload.secret_scan.enabled && mime.starts_with("image/") {
+        confirm_secret_scan(app, data, file_name, &config.upload.secret_scan)?;
+    }
+    let fitted = crate::upload::recompress::fit(
+        data,
+        mime,
+        file_name,
+        service.size_limit(),
+        &config.upload.recompress,
+    )?;
+    let result = match &fitted {
+        Some(f) => uploader.upload_raw(&f.data, f.mime, &f.file_name, service)?,
+        None => uploader.upload_raw(data, mime, file_name, service)?,
+    };
+    if let (Some(key), Some(hash)) = (&key, &hash) {
+        dedupe::remember(hash, key, &result);
+    }
+    Ok(result)
+}
+
+// a batch upload can hit several prompts at once; they're asked one at a time
+static UPLOAD_PROMPT: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+fn ask_reuse_upload(app: &AppHandle, earlier: &crate::upload::dedupe::IndexedUpload) -> bool {
+    use tauri_plugin_dialog::DialogExt;
+    let _asking = UPLOAD_PROMPT.lock().unwrap_or_else(|e| e.into_inner());
+    let when = chrono::DateTime::from_timestamp(earlier.uploaded_unix as i64, 0)
+        .map(|at| {
+            let date = at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M");
+            tf("dialog.reuse.when", &[("date", &date)])
+        })
+        .unwrap_or_default();
+    app.dialog()
+        .message(tf(
+            "dialog.reuse.body",
+            &[("when", &when), ("url", &earlier.url)],
+        ))
+        .title(t("dialog.reuse"))
+        .kind(tauri_plugin_dialog::MessageDialogKind::Info)
+        .buttons(tauri_plugin_dialog::MessageDialogButtons::OkCancelCustom(
+            t("dialog.reuse.yes").into(),
+            t("dialog.reuse.no").into(),
+        ))
+        .blocking_show()
+}
+
+// OCR the upload and ask before publishing anything that reads like a secret.
+// an OCR failure (no tesseract, a format the engine can't decode) lets the
+// upload through: the scan is a warning on top, not something uploads need
+fn confirm_secret_scan(
+    app: &AppHandle,
+    data: &[u8],
+    file_name: &str,
+    settings: &crate::config::SecretScanConfig,
+) -> anyhow::Result<()> {
+    use tauri_plugin_dialog::DialogExt;
+    let text = match ocr_image_bytes(data) {
+        Ok(text) => text,
+        Err(e) => {
+            tracing::warn!("secret scan skipped for {file_name}, OCR failed: {e:#}");
+            return Ok(());
+        }
+    };
+    let findings = crate::upload::sensitive::scan(&text, settings);
+    let Some(first) = findings.first() else {
+        return Ok(());
+    };
+    let listed: String = findings
+        .iter()
+        .map(|f| format!("\n• {}: {}", f.kind.display_name(), f.masked))
+        .collect();
+    let _asking = UPLOAD_PROMPT.lock().unwrap_or_else(|e| e.into_inner());
+    let proceed = app
+        .dialog()
+        .message(tf(
+            "dialog.secret.body",
+            &[("file", &file_name), ("listed", &listed)],
+        ))
+        .title(t("dialog.secret"))
+        .kind(tauri_plugin_dialog::MessageDialogKind::Warning)
+        .buttons(tauri_plugin_dialog::MessageDialogButtons::OkCancelCustom(
+            t("dialog.secret.yes").into(),
+            t("common.cancel").into(),
+        ))
+        .blocking_show();
+    if proceed {
+        tracing::info!(
+            "uploading {file_name} despite {} secret-scan finding(s)",
+            findings.len()
+        );
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "upload cancelled: the secret scan flagged {file_name} ({})",
+            first.kind.display_name()
+        ))
     }
 }
 
+// the upload commands are async so the upload, and the dedupe prompt with it,
+// stay off the main thread
+async fn upload_deduped_blocking(
+    app: AppHandle,
+    config: Config,
+    data: Vec<u8>,
+    mime: &'static str,
+    file_name: String,
+    service: UploadService,
+) -> Result<crate::upload::UploadResult, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        upload_deduped(&app, &config, &data, mime, &file_name, &service)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())
+}
+
 // returns the tonemapped SDR image alongside the raw HDR bitmap when the
 // source display is HDR. Region / Window captures go through GDI BitBlt and
 // can't produce HDR data, so only ActiveMonitor / Fullscreen call this.
 // targets the monitor under the cursor; the primary monitor was previously
 // hardcoded and surprised multi-display users.
-fn capture_active_monitor_with_hdr(
+pub(crate) fn capture_active_monitor_with_hdr(
 ) -> anyhow::Result<(RgbaImage, Option<crate::capture::HdrBitmap>)> {
     tracing::info!("capture_active_monitor_with_hdr entry");
     let target = cursor_position();
@@ -985,18 +1610,39 @@ fn capture_active_monitor_with_hdr(
     Ok((capture.capture()?, None))
 }
 
-#[cfg(windows)]
 fn cursor_position() -> Option<(i32, i32)> {
-    use windows::Win32::Foundation::POINT;
-    use windows::Win32::UI::WindowsAndMessaging::GetCursorPos;
-    let mut p = POINT::default();
-    unsafe { GetCursorPos(&mut p).ok()? };
-    Some((p.x, p.y))
+    crate::capture::pointer_position()
 }
 
-#[cfg(not(windows))]
-fn cursor_position() -> Option<(i32, i32)> {
-    crate::capture::pointer_position()
+fn window_title(window_id: u32) -> Option<String> {
+    let windows = xcap::Window::all().ok()?;
+    let w = windows
+        .into_iter()
+        .find(|w| w.id().map(|i| i == window_id).unwrap_or(false))?;
+    w.title().ok().filter(|t| !t.is_empty())
+}
+
+// process name of a window, which is what app profiles match against
+fn window_app(window_id: u32) -> Option<String> {
+    let windows = xcap::Window::all().ok()?;
+    let w = windows
+        .into_iter()
+        .find(|w| w.id().map(|i| i == window_id).unwrap_or(false))?;
+    w.pid()
+        .ok()
+        .and_then(crate::capture::exclusion::process_name)
+        .or_else(|| w.app_name().ok())
+        .filter(|n| !n.is_empty())
+}
+
+// name of the monitor holding a capture's top-left corner
+fn monitor_name_at((x, y): (i32, i32)) -> Option<String> {
+    crate::capture::list_monitors()
+        .ok()?
+        .into_iter()
+        .find(|m| x >= m.x && y >= m.y && x < m.x + m.width as i32 && y < m.y + m.height as i32)
+        .map(|m| m.name)
+        .filter(|n| !n.is_empty())
 }
 
 fn window_screen_origin(window_id: u32) -> Option<(i32, i32)> {
@@ -1018,7 +1664,7 @@ fn window_bounds(window_id: u32) -> Option<(i32, i32, u32, u32)> {
     Some((w.x().ok()?, w.y().ok()?, w.width().ok()?, w.height().ok()?))
 }
 
-fn active_monitor_origin() -> Option<(i32, i32)> {
+pub(crate) fn active_monitor_origin() -> Option<(i32, i32)> {
     let origin_of = |m: xcap::Monitor| Some((m.x().ok()?, m.y().ok()?));
     let (cx, cy) = cursor_position()?;
     xcap::Monitor::from_point(cx, cy)
@@ -1035,7 +1681,7 @@ fn active_monitor_origin() -> Option<(i32, i32)> {
 }
 
 // if the user opted into HDR preservation and the source produced an HDR
-// bitmap, write a `<basename>.hdr.png` sidecar next to the SDR file. Failures
+// bitmap, write a `<basename>.hdr.<ext>` sidecar next to the SDR file. Failures
 // are reported via tracing but never fail the overall capture — the SDR file
 // is the source of truth.
 fn maybe_write_hdr_sidecar(
@@ -1051,14 +1697,21 @@ fn maybe_write_hdr_sidecar(
         Some(s) => s.to_os_string(),
         None => return,
     };
+    let container = config.capture.hdr.container;
     let mut sidecar_name = stem;
-    sidecar_name.push(".hdr.png");
+    sidecar_name.push(container.sidecar_suffix());
     let sidecar_path = sdr_path.with_file_name(sidecar_name);
     let transfer = match config.capture.hdr.output_format {
         crate::config::HdrOutputFormat::Pq => crate::capture::HdrTransfer::Pq,
         crate::config::HdrOutputFormat::Hlg => crate::capture::HdrTransfer::Hlg,
     };
-    if let Err(e) = crate::capture::encode_hdr_png(&sidecar_path, bitmap, transfer) {
+    if let Err(e) = crate::capture::encode_hdr_file(
+        &sidecar_path,
+        bitmap,
+        transfer,
+        container,
+        config.output.quality,
+    ) {
         tracing::warn!("hdr sidecar write failed for {sidecar_path:?}: {e}");
     }
 }
@@ -1067,15 +1720,31 @@ fn maybe_write_hdr_sidecar(
 // rely on this to tie the HDR sidecar to the right basename — reading
 // `state.last_save` would surface a previous capture's path when this
 // action was clipboard-only.
+// the config a capture is written with: the global one, with the format and
+// folder of the captured app's profile folded in
+fn capture_config(state: &AppState, meta: &crate::metadata::CaptureMeta) -> Config {
+    let mut config = state.config.lock().unwrap().clone();
+    let profile = meta
+        .app
+        .as_deref()
+        .and_then(|app| config.output.profile_for(app))
+        .cloned();
+    if let Some(profile) = profile {
+        config.output.apply_profile(&profile);
+    }
+    config
+}
+
 fn run_post_action(
     app: &AppHandle,
     state: &AppState,
     image: Arc<RgbaImage>,
     hdr_bitmap: Option<crate::capture::HdrBitmap>,
     action: PostCaptureAction,
-    upload_target: Option<crate::config::TaskUploadTarget>,
+    upload: &UploadOverride,
+    meta: &crate::metadata::CaptureMeta,
 ) -> anyhow::Result<Option<PathBuf>> {
-    let config = state.config.lock().unwrap().clone();
+    let config = capture_config(state, meta);
 
     // the encode runs on a worker thread; on_saved fires there once the write
     // actually succeeds, so callers announce "saved" only when it's true. a
@@ -1084,7 +1753,7 @@ fn run_post_action(
     let do_save_async = |img: Arc<RgbaImage>,
                          hdr: Option<crate::capture::HdrBitmap>,
                          app_handle: AppHandle,
-                         on_saved: Box<dyn FnOnce(&std::path::Path) + Send>|
+                         on_saved: Box<dyn FnOnce(&AppHandle, &std::path::Path) + Send>|
      -> anyhow::Result<PathBuf> {
         let base = config.output_path();
         let path = get_unique_filepath(&base);
@@ -1093,35 +1762,54 @@ fn run_post_action(
         }
 
         let path_clone = path.clone();
+        let placeholder = path.clone();
         let format = config.output.format;
         let quality = config.output.quality;
         let config_clone = config.clone();
-        std::thread::spawn(move || {
+        let meta = meta.clone();
+        let estimate = crate::encode::estimate_bytes(img.width(), img.height(), format);
+        let pool_app = app_handle.clone();
+        let run = move || {
             let t0 = std::time::Instant::now();
+            // lanczos on a 4k frame is slow enough to keep off the capture thread
+            let (img, hdr) = match crate::decorate::downscale(&img, &config_clone.output.resize) {
+                // a sidecar at the old size wouldn't line up with the file
+                Some(small) => (Arc::new(small), None),
+                None => (img, hdr),
+            };
             match save_image(&img, &path_clone, format, quality) {
                 Ok(()) => {
+                    let metadata = config_clone.output.metadata;
+                    if let Err(e) = crate::metadata::apply_to_file(&path_clone, metadata, &meta) {
+                        tracing::warn!("couldn't rewrite capture metadata: {e:#}");
+                    }
                     maybe_write_hdr_sidecar(&path_clone, &hdr, &config_clone);
-                    *app_handle.state::<AppState>().last_save.lock().unwrap() =
-                        Some(path_clone.clone());
+                    app_handle.state::<AppState>().record_save(&path_clone);
+                    SystemTray::rebuild(&app_handle);
                     tracing::info!(
                         "Background save completed in {}ms",
                         t0.elapsed().as_millis()
                     );
                     notify_capture_saved(&app_handle, &path_clone);
-                    on_saved(&path_clone);
+                    on_saved(&app_handle, &path_clone);
+                    if config_clone.post_capture.open_file_after_save {
+                        open_saved_file(&path_clone);
+                    }
                 }
                 Err(e) => {
                     tracing::error!("Background save_image failed: {e:#}");
                     let _ = std::fs::remove_file(&path_clone);
-                    emit_error(&app_handle, "save", "couldn't write the capture to disk");
+                    emit_error(&app_handle, "save", t("notify.write-failed"));
                     if config_clone.ui.show_notifications {
-                        let _ = show_notification(
-                            "Capture failed",
-                            "couldn't write the capture to disk",
-                        );
+                        let _ =
+                            show_notification(t("notify.capture-failed"), t("notify.write-failed"));
                     }
                 }
             }
+        };
+        // a cancelled save leaves nothing behind, not even the placeholder
+        crate::encode::submit(&pool_app, estimate, run, move || {
+            let _ = std::fs::remove_file(&placeholder);
         });
         Ok(path)
     };
@@ -1147,14 +1835,22 @@ fn run_post_action(
         let path = get_unique_filepath(&base);
 
         let path_clone = path.clone();
+        let placeholder = path.clone();
         let format = config.output.format;
         let quality = config.output.quality;
         let config_clone = config.clone();
-        std::thread::spawn(move || {
+        let meta = meta.clone();
+        let estimate = crate::encode::estimate_bytes(img.width(), img.height(), format);
+        let pool_app = app_handle.clone();
+        let run = move || {
             let t0 = std::time::Instant::now();
             if let Err(e) = save_image(&img, &path_clone, format, quality) {
                 tracing::error!("Background save_image to history failed: {e:#}");
             } else {
+                let metadata = config_clone.output.metadata;
+                if let Err(e) = crate::metadata::apply_to_file(&path_clone, metadata, &meta) {
+                    tracing::warn!("couldn't rewrite capture metadata: {e:#}");
+                }
                 maybe_write_hdr_sidecar(&path_clone, &hdr, &config_clone);
                 tracing::info!(
                     "Background save to history completed in {}ms",
@@ -1162,6 +1858,9 @@ fn run_post_action(
                 );
                 notify_capture_saved(&app_handle, &path_clone);
             }
+        };
+        crate::encode::submit(&pool_app, estimate, run, move || {
+            let _ = std::fs::remove_file(&placeholder);
         });
         Some(path)
     };
@@ -1174,15 +1873,19 @@ fn run_post_action(
 
     let do_upload = || -> anyhow::Result<crate::upload::UploadResult> {
         let uploader = crate::upload::shared_uploader()?;
-        let service = build_upload_service_for_target(&config, upload_target);
-        let result = uploader.upload(&image, &service)?;
+        let service = build_upload_service_for_target(&config, upload);
+        let png = match crate::decorate::downscale(&image, &config.upload.resize) {
+            Some(small) => uploader.encode_png(&small)?,
+            None => uploader.encode_png(&image)?,
+        };
+        let result = upload_cancellable(app, &config, png, "image/png", "screenshot.png", service)?;
         state.record_upload(UploadRecord {
             url: result.url.clone(),
             delete_url: result.delete_url.clone(),
         });
-        crate::rebuild_tray_menu(app);
+        SystemTray::rebuild(app);
         if config.upload.copy_url_to_clipboard {
-            let _ = crate::upload::copy_url_to_clipboard(&result.url);
+            let _ = crate::upload::copy_url_to_clipboard(&result.url, config.upload.link_format);
         }
         Ok(result)
     };
@@ -1193,34 +1896,28 @@ fn run_post_action(
             let show = config.ui.show_notifications;
             let path = do_save_async(
                 image.clone(),
-                hdr_bitmap.clone(),
+                hdr_bitmap,
                 app.clone(),
-                Box::new(move |path| {
+                Box::new(move |app, path| {
                     Sound::Screenshot.play_if_enabled(play);
                     if show {
-                        let _ = show_notification("Capture saved", &path.to_string_lossy());
+                        notify_saved_file(app, t("notify.saved"), path);
                     }
                 }),
             )?;
             Ok(Some(path))
         }
         PostCaptureAction::CopyToClipboard => {
-            let history_path =
-                do_save_to_history_async(image.clone(), hdr_bitmap.clone(), app.clone());
+            let history_path = do_save_to_history_async(image.clone(), hdr_bitmap, app.clone());
             let clipboard_ok = do_clipboard().is_ok();
             Sound::Screenshot.play_if_enabled(config.post_capture.play_sound);
             if config.ui.show_notifications {
-                let title = if clipboard_ok {
-                    "Copied"
+                let (title, body) = if clipboard_ok {
+                    ("notify.copied", "notify.copied.body")
                 } else {
-                    "Clipboard busy"
+                    ("notify.clipboard-busy", "notify.clipboard-busy.body")
                 };
-                let body = if clipboard_ok {
-                    "Capture on clipboard"
-                } else {
-                    "Capture ready but clipboard was occupied"
-                };
-                let _ = show_notification(title, body);
+                let _ = show_notification(t(title), t(body));
             }
             Ok(history_path)
         }
@@ -1230,29 +1927,35 @@ fn run_post_action(
             let show = config.ui.show_notifications;
             let path = do_save_async(
                 image.clone(),
-                hdr_bitmap.clone(),
+                hdr_bitmap,
                 app.clone(),
-                Box::new(move |path| {
+                Box::new(move |app, path| {
                     Sound::Screenshot.play_if_enabled(play);
                     if show {
                         let title = if clipboard_ok {
-                            "Capture saved + copied"
+                            "notify.saved-copied"
                         } else {
-                            "Capture saved (clipboard busy)"
+                            "notify.saved-busy"
                         };
-                        let _ = show_notification(title, &path.to_string_lossy());
+                        notify_saved_file(app, t(title), path);
                     }
                 }),
             )?;
             Ok(Some(path))
         }
         PostCaptureAction::Upload => {
-            let history_path =
-                do_save_to_history_async(image.clone(), hdr_bitmap.clone(), app.clone());
-            let result = do_upload()?;
+            let history_path = do_save_to_history_async(image.clone(), hdr_bitmap, app.clone());
+            let result = match do_upload() {
+                // the capture is in history already; only the link is gone
+                Err(e) if e.is::<crate::cancel::Cancelled>() => {
+                    tracing::info!("upload cancelled");
+                    return Ok(history_path);
+                }
+                result => result?,
+            };
             Sound::Upload.play_if_enabled(config.post_capture.play_sound);
             if config.ui.show_notifications {
-                let _ = show_notification("Uploaded", &result.url);
+                let _ = show_notification(t("notify.uploaded"), &result.url);
             }
             emit_upload_success(app, &result);
             Ok(history_path)
@@ -1263,28 +1966,27 @@ fn run_post_action(
             let show = config.ui.show_notifications;
             let path = do_save_async(
                 image.clone(),
-                hdr_bitmap.clone(),
+                hdr_bitmap,
                 app.clone(),
-                Box::new(move |path| {
+                Box::new(move |app, path| {
                     Sound::Screenshot.play_if_enabled(play);
                     if show {
                         let title = if clipboard_ok {
-                            "Capture saved + copied"
+                            "notify.saved-copied"
                         } else {
-                            "Capture saved (clipboard busy)"
+                            "notify.saved-busy"
                         };
-                        let _ = show_notification(title, &path.to_string_lossy());
+                        notify_saved_file(app, t(title), path);
                     }
                 }),
             )?;
             Ok(Some(path))
         }
         PostCaptureAction::DoNothing => {
-            let history_path =
-                do_save_to_history_async(image.clone(), hdr_bitmap.clone(), app.clone());
+            let history_path = do_save_to_history_async(image.clone(), hdr_bitmap, app.clone());
             Sound::Screenshot.play_if_enabled(config.post_capture.play_sound);
             if config.ui.show_notifications {
-                let _ = show_notification("Capture complete", "Screenshot taken successfully.");
+                let _ = show_notification(t("notify.complete"), t("notify.complete.body"));
             }
             Ok(history_path)
         }
@@ -1314,6 +2016,7 @@ pub fn open_in_default_image_editor(path: &std::path::Path) -> anyhow::Result<()
 pub fn list_captures(state: State<AppState>) -> Result<Vec<HistoryEntry>, String> {
     let config = state.config.lock().unwrap().clone();
     let dir = config.output.directory.clone();
+    let mut tagged = crate::tags::all();
 
     let mut filenames: std::collections::HashSet<String> = std::collections::HashSet::new();
     let mut dir_entries: Vec<std::fs::DirEntry> = Vec::new();
@@ -1367,7 +2070,7 @@ pub fn list_captures(state: State<AppState>) -> Result<Vec<HistoryEntry>, String
             .and_then(|f| f.to_str())
             .unwrap_or("")
             .to_string();
-        if filename.ends_with(".hdr.png") {
+        if crate::capture::is_hdr_sidecar(&filename) {
             continue;
         }
         let metadata = match entry.metadata() {
@@ -1384,7 +2087,11 @@ pub fn list_captures(state: State<AppState>) -> Result<Vec<HistoryEntry>, String
         let has_hdr = path
             .file_stem()
             .and_then(|s| s.to_str())
-            .map(|stem| filenames.contains(&format!("{stem}.hdr.png")))
+            .map(|stem| {
+                crate::capture::HDR_SIDECAR_SUFFIXES
+                    .iter()
+                    .any(|suffix| filenames.contains(&format!("{stem}{suffix}")))
+            })
             .unwrap_or(false);
 
         let path_str = path.to_string_lossy().to_string();
@@ -1395,6 +2102,7 @@ pub fn list_captures(state: State<AppState>) -> Result<Vec<HistoryEntry>, String
         };
 
         let is_mp4 = ext == "mp4";
+        let notes = tagged.remove(&path_clean).unwrap_or_default();
         entries.push(HistoryEntry {
             path: path_clean,
             filename,
@@ -1403,6 +2111,8 @@ pub fn list_captures(state: State<AppState>) -> Result<Vec<HistoryEntry>, String
             is_gif: ext == "gif",
             is_mp4,
             has_hdr,
+            tags: notes.tags,
+            note: notes.note,
         });
     }
 
@@ -1440,15 +2150,53 @@ pub fn delete_capture(path: String, state: State<AppState>) -> Result<(), String
     if !is_path_allowed(&canonical, &config) {
         return Err("Path is outside the allowed directories".into());
     }
-    // also remove the `<stem>.hdr.png` sidecar if present, so deleting a
+    // also remove the `<stem>.hdr.*` sidecar if present, so deleting a
     // capture from History doesn't leave orphan HDR data on disk.
-    if let Some(stem) = canonical.file_stem().and_then(|s| s.to_str()) {
-        let sidecar = canonical.with_file_name(format!("{stem}.hdr.png"));
-        if sidecar.exists() && sidecar != canonical {
-            let _ = std::fs::remove_file(&sidecar);
+    for sidecar in crate::capture::existing_hdr_sidecars(&canonical) {
+        let _ = std::fs::remove_file(&sidecar);
+    }
+    if let Some(project) = editor_project_path(&canonical) {
+        let _ = std::fs::remove_file(&project);
+    }
+    std::fs::remove_file(&canonical).map_err(|e| e.to_string())?;
+    crate::tags::remove(&path);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_capture_tags(path: String) -> crate::tags::CaptureNotes {
+    crate::tags::get(&path)
+}
+
+/// tag and annotate a capture from the editor or history. returns what was
+/// stored, after tags are normalized
+#[tauri::command]
+pub fn set_capture_tags(
+    app: AppHandle,
+    path: String,
+    tags: Vec<String>,
+    note: String,
+    state: State<AppState>,
+) -> Result<crate::tags::CaptureNotes, String> {
+    let config = state.config.lock().unwrap().clone();
+    let canonical = std::fs::canonicalize(&path).map_err(|e| e.to_string())?;
+    if !is_path_allowed(&canonical, &config) {
+        return Err("Path is outside the allowed directories".into());
+    }
+    let notes = crate::tags::set(&path, &tags, &note).map_err(|e| e.to_string())?;
+    if config.output.tags_in_metadata {
+        // a png gets them as text chunks; other formats keep them in the index only
+        let rewritten = std::fs::read(&canonical)
+            .ok()
+            .and_then(|bytes| crate::metadata::png_with_notes(&bytes, &notes.tags, &notes.note));
+        if let Some(bytes) = rewritten {
+            if let Err(e) = crate::disk::replace_file(&canonical, &bytes) {
+                tracing::warn!("couldn't write tags into {}: {e}", canonical.display());
+            }
         }
     }
-    std::fs::remove_file(&canonical).map_err(|e| e.to_string())
+    let _ = app.emit("capscr://tags-changed", &path);
+    Ok(notes)
 }
 
 #[tauri::command]
@@ -1483,15 +2231,40 @@ pub fn copy_capture_to_clipboard(path: String, state: State<AppState>) -> Result
 }
 
 #[tauri::command]
-pub fn reupload_capture(
+pub async fn reupload_capture(
     path: String,
+    preset: Option<String>,
     app: AppHandle,
-    state: State<AppState>,
+    state: State<'_, AppState>,
 ) -> Result<UploadResponse, String> {
-    let buf = PathBuf::from(&path);
     let config = state.config.lock().unwrap().clone();
-    let canonical = std::fs::canonicalize(&buf).map_err(|e| e.to_string())?;
-    if !is_path_allowed(&canonical, &config) {
+    let (bytes, mime, file_name) = read_capture_for_upload(&path, &config)?;
+    let service = build_upload_service_for_target(&config, &UploadOverride::for_preset(preset));
+    let result =
+        upload_deduped_blocking(app.clone(), config.clone(), bytes, mime, file_name, service)
+            .await?;
+    state.record_upload(UploadRecord {
+        url: result.url.clone(),
+        delete_url: result.delete_url.clone(),
+    });
+    SystemTray::rebuild(&app);
+    if config.upload.copy_url_to_clipboard {
+        let _ = crate::upload::copy_url_to_clipboard(&result.url, config.upload.link_format);
+    }
+    emit_upload_success(&app, &result);
+    Ok(UploadResponse {
+        url: result.url,
+        delete_url: result.delete_url,
+    })
+}
+
+// the bytes, mime and name of a history capture, for reupload and batch upload
+fn read_capture_for_upload(
+    path: &str,
+    config: &Config,
+) -> Result<(Vec<u8>, &'static str, String), String> {
+    let canonical = std::fs::canonicalize(PathBuf::from(path)).map_err(|e| e.to_string())?;
+    if !is_path_allowed(&canonical, config) {
         return Err("Path is outside the allowed directories".into());
     }
     // GIF files contain animation data that image::open drops to the first frame.
@@ -1525,24 +2298,127 @@ pub fn reupload_capture(
         .and_then(|n| n.to_str())
         .unwrap_or("capture")
         .to_string();
-    let uploader = crate::upload::shared_uploader().map_err(|e| e.to_string())?;
-    let service = build_upload_service(&config);
-    let result = uploader
-        .upload_raw(&bytes, mime, &file_name, &service)
-        .map_err(|e| e.to_string())?;
-    state.record_upload(UploadRecord {
-        url: result.url.clone(),
-        delete_url: result.delete_url.clone(),
-    });
-    crate::rebuild_tray_menu(&app);
-    if config.upload.copy_url_to_clipboard {
-        let _ = crate::upload::copy_url_to_clipboard(&result.url);
+    Ok((bytes, mime, file_name))
+}
+
+fn join_links(urls: &[String], format: LinkFormat) -> String {
+    urls.iter()
+        .filter(|u| !u.is_empty())
+        .map(|u| crate::upload::format_link(u, format))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// a few uploads at a time: hosts rate limit bursts, and each worker may hold a
+// decoded copy of its capture while recompressing
+const BATCH_UPLOAD_WORKERS: usize = 3;
+const MAX_BATCH_UPLOAD: usize = 100;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum BatchItemState {
+    Uploading,
+    Done,
+    Failed,
+}
+
+/// one row of the batch progress list, sent as `capscr://batch-upload-progress`
+/// whenever an item starts or finishes
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchUploadProgress {
+    pub index: usize,
+    pub path: String,
+    pub state: BatchItemState,
+    pub url: Option<String>,
+    pub error: Option<String>,
+}
+
+/// upload several history captures in parallel. one capture failing doesn't
+/// stop the rest; the result has a row per path, in the order given
+#[tauri::command]
+pub async fn batch_upload(
+    paths: Vec<String>,
+    preset: Option<String>,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Vec<BatchUploadProgress>, String> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    if paths.len() > MAX_BATCH_UPLOAD {
+        return Err(format!("pick at most {MAX_BATCH_UPLOAD} captures"));
     }
-    emit_upload_success(&app, &result);
-    Ok(UploadResponse {
-        url: result.url,
-        delete_url: result.delete_url,
+    let config = state.config.lock().unwrap().clone();
+    let service = build_upload_service_for_target(&config, &UploadOverride::for_preset(preset));
+    // one compose window per capture is never what a batch wants
+    if matches!(service, UploadService::Email(_)) {
+        return Err("batch upload needs a destination that hosts links".into());
+    }
+    let worker_app = app.clone();
+    let rows = tauri::async_runtime::spawn_blocking(move || {
+        let app = worker_app;
+        let next = AtomicUsize::new(0);
+        let rows = std::sync::Mutex::new(vec![None; paths.len()]);
+        std::thread::scope(|s| {
+            for _ in 0..BATCH_UPLOAD_WORKERS.min(paths.len()) {
+                s.spawn(|| loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(path) = paths.get(index) else {
+                        break;
+                    };
+                    let report = |state, url, error| {
+                        let row = BatchUploadProgress {
+                            index,
+                            path: path.clone(),
+                            state,
+                            url,
+                            error,
+                        };
+                        let _ = app.emit("capscr://batch-upload-progress", &row);
+                        row
+                    };
+                    report(BatchItemState::Uploading, None, None);
+                    let outcome = read_capture_for_upload(path, &config).and_then(
+                        |(bytes, mime, file_name)| {
+                            upload_deduped(&app, &config, &bytes, mime, &file_name, &service)
+                                .map_err(|e| e.to_string())
+                        },
+                    );
+                    let row = match outcome {
+                        Ok(result) => {
+                            app.state::<AppState>().record_upload(UploadRecord {
+                                url: result.url.clone(),
+                                delete_url: result.delete_url.clone(),
+                            });
+                            emit_upload_success(&app, &result);
+                            report(BatchItemState::Done, Some(result.url), None)
+                        }
+                        Err(e) => report(BatchItemState::Failed, None, Some(e)),
+                    };
+                    rows.lock().unwrap()[index] = Some(row);
+                });
+            }
+        });
+        rows.into_inner()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
     })
+    .await
+    .map_err(|e| e.to_string())?;
+    SystemTray::rebuild(&app);
+    Ok(rows)
+}
+
+/// the batch's links, one per line, wrapped as `format`
+#[tauri::command]
+pub fn copy_batch_links(urls: Vec<String>, format: LinkFormat) -> Result<(), String> {
+    let text = join_links(&urls, format);
+    if text.is_empty() {
+        return Err("no links to copy".into());
+    }
+    ClipboardManager::new()
+        .and_then(|mut cb| cb.copy_text(&text))
+        .map_err(|e| e.to_string())
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1570,7 +2446,7 @@ pub fn open_in_explorer(
 }
 
 #[cfg(windows)]
-fn reveal_in_file_manager(_app: &AppHandle, path: &std::path::Path) {
+pub(crate) fn reveal_in_file_manager(_app: &AppHandle, path: &std::path::Path) {
     let _ = std::process::Command::new("explorer")
         .arg("/select,")
         .arg(path)
@@ -1580,7 +2456,7 @@ fn reveal_in_file_manager(_app: &AppHandle, path: &std::path::Path) {
 // the opener plugin talks to the freedesktop FileManager1 dbus interface and
 // falls back to plain-opening the parent directory itself
 #[cfg(not(windows))]
-fn reveal_in_file_manager(app: &AppHandle, path: &std::path::Path) {
+pub(crate) fn reveal_in_file_manager(app: &AppHandle, path: &std::path::Path) {
     use tauri_plugin_opener::OpenerExt;
     if let Err(e) = app.opener().reveal_item_in_dir(path) {
         tracing::warn!("reveal in file manager failed: {e}");
@@ -1589,10 +2465,20 @@ fn reveal_in_file_manager(app: &AppHandle, path: &std::path::Path) {
 
 #[tauri::command]
 pub fn exit_app(app: AppHandle) {
-    // save any active recording before exiting so the user doesn't lose frames
     let state = app.state::<AppState>();
+    // nothing new should start while the app winds down, and on linux the
+    // portal and x11 grabs are released rather than left to process teardown
+    if !state.shutdown_hotkeys(Duration::from_secs(1)) {
+        tracing::debug!("hotkey thread didn't confirm shutdown; exiting anyway");
+    }
+    // save any active recording before exiting so the user doesn't lose frames
     let cfg = state.config.lock().unwrap().clone();
     let mut recorder = state.gif_recorder.lock().unwrap().take();
+    // a stream has nothing to save; just hang up so the ingest sees a clean end
+    if let Some(mut live) = recorder.take_if(|rec| rec.is_live()) {
+        live.stop();
+        let _ = live.end_stream();
+    }
     if let Some(ref mut rec) = recorder {
         rec.stop();
         // let the capture thread drain its last frames before we save, the same
@@ -1621,20 +2507,117 @@ pub fn exit_app(app: AppHandle) {
             Ok(()) => {
                 notify_capture_saved(&app, &path);
                 if cfg.ui.show_notifications {
-                    let title = if is_mp4 { "Video saved" } else { "GIF saved" };
-                    let _ = show_notification(title, &path.to_string_lossy());
+                    let title = if is_mp4 {
+                        "notify.video-saved"
+                    } else {
+                        "notify.gif-saved"
+                    };
+                    let _ = show_notification(t(title), &path.to_string_lossy());
                 }
             }
             Err(e) => {
                 tracing::warn!("recording save on exit failed: {e}");
                 let err_type = if is_mp4 { "mp4-save" } else { "gif-save" };
                 emit_error(&app, err_type, &e.to_string());
+                // the next launch offers it back instead
+                preserve_recording(rec);
             }
         }
     }
+    // a recording still waiting in the trim preview is kept whole
+    let pending_review = state.recording_review.lock().unwrap().take();
+    if let Some(review) = pending_review {
+        match save_review(&review, &cfg, None) {
+            Ok(path) => notify_capture_saved(&app, &path),
+            Err(e) => {
+                tracing::warn!("saving the recording under review on exit failed: {e:#}");
+                preserve_recording(&review.recorder);
+            }
+        }
+    }
+    // settle what's written lazily, such as the hub position
+    if let Err(e) = state.save_config_now() {
+        tracing::warn!("couldn't write config on exit: {e:#}");
+    }
     app.exit(0);
 }
 
+fn preserve_recording(rec: &GifRecorder) {
+    let Some(dir) = crate::recording::recovery::dir() else {
+        return;
+    };
+    match rec.preserve_frames(&dir) {
+        Ok(Some(path)) => tracing::info!("kept unsaved recording frames at {}", path.display()),
+        Ok(None) => {}
+        Err(e) => tracing::warn!("couldn't keep the unsaved recording: {e:#}"),
+    }
+}
+
+// the app is going down without exit_app (a panic): move whatever gif
+// frames are spooled to the recovery folder rather than lose them with the
+// temp dir. try_lock throughout, the panicking thread may hold any of these
+pub fn preserve_unsaved_recordings(app: &AppHandle) {
+    let state = app.state::<AppState>();
+    if let Ok(mut guard) = state.gif_recorder.try_lock() {
+        if let Some(rec) = guard.as_mut() {
+            rec.stop();
+            preserve_recording(rec);
+        }
+    }
+    if let Ok(guard) = state.recording_review.try_lock() {
+        if let Some(review) = guard.as_ref() {
+            preserve_recording(&review.recorder);
+        }
+    }
+}
+
+#[tauri::command]
+pub fn list_recovered_recordings() -> Vec<crate::recording::recovery::RecoveredRecording> {
+    crate::recording::recovery::list()
+}
+
+// encode a recording kept from a crash into the output folder as a normal gif
+#[tauri::command]
+pub async fn restore_recovered_recording(id: String, app: AppHandle) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let cfg = app.state::<AppState>().config.lock().unwrap().clone();
+        let path = recording_output_path(&cfg, false);
+        crate::recording::recovery::restore(&id, &path).map_err(|e| e.to_string())?;
+        notify_capture_saved(&app, &path);
+        Ok(path.to_string_lossy().into_owned())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+pub fn discard_recovered_recording(id: String) -> Result<(), String> {
+    crate::recording::recovery::discard(&id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn list_crash_reports() -> Vec<crate::crash::CrashReport> {
+    crate::crash::list()
+}
+
+// the whole report, for the hub to put on the clipboard
+#[tauri::command]
+pub fn read_crash_report(id: String) -> Result<String, String> {
+    crate::crash::read(&id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn reveal_crash_report(id: String, app: AppHandle) -> Result<(), String> {
+    let path = crate::crash::report_path(&id).map_err(|e| e.to_string())?;
+    reveal_in_file_manager(&app, &path);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn dismiss_crash_report(id: String) -> Result<(), String> {
+    crate::crash::dismiss(&id).map_err(|e| e.to_string())
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct UpdateInfo {
     pub version: String,
@@ -1818,11 +2801,13 @@ pub fn prewarm_hub_window(app: &tauri::App) -> tauri::Result<()> {
     if app.get_webview_window(HUB_LABEL).is_some() {
         return Ok(());
     }
+    let ui = app.state::<AppState>().config.lock().unwrap().ui.clone();
+    let (size, min) = hub_sizes(&ui);
     let url = tauri::WebviewUrl::App("index.html".into());
     let mut builder = tauri::WebviewWindowBuilder::new(app, HUB_LABEL, url)
         .title("capscr")
-        .inner_size(900.0, 640.0)
-        .min_inner_size(720.0, 480.0)
+        .inner_size(size.0, size.1)
+        .min_inner_size(min.0, min.1)
         .resizable(true)
         .decorations(false)
         .visible(false);
@@ -1832,6 +2817,8 @@ pub fn prewarm_hub_window(app: &tauri::App) -> tauri::Result<()> {
     }
 
     let window = builder.build()?;
+    apply_hub_scale(&window, &ui);
+    restore_hub_position(&window);
     // intercept the close button so the WebView2 process stays alive for the
     // next tray-click. Without this we pay multi-second cold-boot every time
     // the user closes and re-opens the hub, even after the startup prewarm.
@@ -1877,6 +2864,16 @@ fn intercept_hub_close(window: tauri::WebviewWindow) {
                     }
                 }
             }
+            // minimizing on windows reports a move to (-32000, -32000)
+            tauri::WindowEvent::Moved(position) => {
+                let minimized = app
+                    .get_webview_window(HUB_LABEL)
+                    .and_then(|hub| hub.is_minimized().ok())
+                    .unwrap_or(false);
+                if !minimized {
+                    remember_hub_position(&app, *position);
+                }
+            }
             // record what the OS actually dropped so upload_file can trust the
             // path the webview later hands it (drag-drop is the only legitimate
             // caller with an arbitrary path)
@@ -1896,11 +2893,13 @@ pub fn open_hub_window(app: &AppHandle) -> tauri::Result<()> {
         let _ = window.set_focus();
         return Ok(());
     }
+    let ui = app.state::<AppState>().config.lock().unwrap().ui.clone();
+    let (size, min) = hub_sizes(&ui);
     let url = tauri::WebviewUrl::App("index.html".into());
     let mut builder = tauri::WebviewWindowBuilder::new(app, HUB_LABEL, url)
         .title("capscr")
-        .inner_size(900.0, 640.0)
-        .min_inner_size(720.0, 480.0)
+        .inner_size(size.0, size.1)
+        .min_inner_size(min.0, min.1)
         .resizable(true)
         .decorations(false)
         .visible(true);
@@ -1910,13 +2909,217 @@ pub fn open_hub_window(app: &AppHandle) -> tauri::Result<()> {
     }
 
     let window = builder.build()?;
+    apply_hub_scale(&window, &ui);
+    restore_hub_position(&window);
     intercept_hub_close(window.clone());
     heal_stuck_boot(window);
     Ok(())
 }
 
+// the hub's logical size at 100%. the ui scale multiplies both so a zoomed hub
+// still fits its layout, and compact mode lowers the floor for small screens
+const HUB_SIZE: (f64, f64) = (900.0, 640.0);
+const HUB_MIN_SIZE: (f64, f64) = (720.0, 480.0);
+const HUB_COMPACT_MIN_SIZE: (f64, f64) = (520.0, 400.0);
+
+fn hub_sizes(ui: &crate::config::UiConfig) -> ((f64, f64), (f64, f64)) {
+    let scale = ui.scale();
+    let min = if ui.compact {
+        HUB_COMPACT_MIN_SIZE
+    } else {
+        HUB_MIN_SIZE
+    };
+    (
+        (HUB_SIZE.0 * scale, HUB_SIZE.1 * scale),
+        (min.0 * scale, min.1 * scale),
+    )
+}
+
+// webview zoom rather than css zoom, so text, hit-testing and the custom
+// titlebar's drag region all scale together
+fn apply_hub_scale(window: &tauri::WebviewWindow, ui: &crate::config::UiConfig) {
+    if let Err(e) = window.set_zoom(ui.scale()) {
+        tracing::warn!("hub set_zoom({}) failed: {e}", ui.scale());
+    }
+    let (_, min) = hub_sizes(ui);
+    let _ = window.set_min_size(Some(tauri::LogicalSize::new(min.0, min.1)));
+}
+
+// put the hub back where it was last dragged, as long as that spot is still on
+// a connected monitor; a position from an unplugged display is clamped onto
+// the nearest one rather than leaving the hub off-screen
+fn restore_hub_position(window: &tauri::WebviewWindow) {
+    let Some(saved) = window
+        .app_handle()
+        .state::<AppState>()
+        .config
+        .lock()
+        .unwrap()
+        .ui
+        .window_position
+    else {
+        return;
+    };
+    let Ok(monitors) = window.available_monitors() else {
+        return;
+    };
+    let bounds: Vec<(i32, i32, u32, u32)> = monitors
+        .iter()
+        .map(|m| {
+            let (pos, size) = (m.position(), m.size());
+            (pos.x, pos.y, size.width, size.height)
+        })
+        .collect();
+    let size = window
+        .outer_size()
+        .map(|s| (s.width, s.height))
+        .unwrap_or((900, 640));
+    if let Some((x, y)) = clamp_to_monitors((saved.x, saved.y), size, &bounds) {
+        let _ = window.set_position(tauri::PhysicalPosition::new(x, y));
+    }
+}
+
+// keep the whole window on the monitor nearest its saved top-left corner.
+// None when there are no monitors to place it on
+fn clamp_to_monitors(
+    (x, y): (i32, i32),
+    (width, height): (u32, u32),
+    monitors: &[(i32, i32, u32, u32)],
+) -> Option<(i32, i32)> {
+    let distance = |&(mx, my, mw, mh): &(i32, i32, u32, u32)| {
+        let dx = (mx - x).max(x - (mx + mw as i32)).max(0) as i64;
+        let dy = (my - y).max(y - (my + mh as i32)).max(0) as i64;
+        dx * dx + dy * dy
+    };
+    let &(mx, my, mw, mh) = monitors.iter().min_by_key(|m| distance(m))?;
+    let max_x = mx + (mw as i32 - width as i32).max(0);
+    let max_y = my + (mh as i32 - height as i32).max(0);
+    Some((x.clamp(mx, max_x), y.clamp(my, max_y)))
+}
+
+// drags fire a Moved per frame; only the position the hub comes to rest at is
+// written to disk
+fn remember_hub_position(app: &AppHandle, position: tauri::PhysicalPosition<i32>) {
+    let state = app.state::<AppState>();
+    state.config.lock().unwrap().ui.window_position = Some(crate::config::WindowPosition {
+        x: position.x,
+        y: position.y,
+    });
+    state.save_config_soon(app);
+}
+
 const EDITOR_LABEL: &str = "editor";
 
+// an open hub is hidden for the length of a capture or recording and shown
+// again afterwards. capture.hide_hub_during_capture turns this off
+struct HubHidden(Option<tauri::WebviewWindow>);
+
+impl HubHidden {
+    fn for_capture(app: &AppHandle) -> Self {
+        let enabled = app
+            .state::<AppState>()
+            .config
+            .lock()
+            .unwrap()
+            .capture
+            .hide_hub_during_capture;
+        let hub = app
+            .get_webview_window(HUB_LABEL)
+            .filter(|hub| enabled && hub.is_visible().unwrap_or(false));
+        if let Some(hub) = &hub {
+            let _ = hub.hide();
+            // let the compositor present one frame without the hub before capture
+            std::thread::sleep(Duration::from_millis(34));
+        }
+        Self(hub)
+    }
+
+    // a recording outlives the call that started it; finalize_gif_recording
+    // restores the hub instead of this guard
+    fn hold_until_recording_ends(mut self, app: &AppHandle) {
+        if self.0.take().is_some() {
+            app.state::<AppState>()
+                .hub_hidden_for_recording
+                .store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+}
+
+impl Drop for HubHidden {
+    fn drop(&mut self) {
+        if let Some(hub) = self.0.take() {
+            let _ = hub.show();
+        }
+    }
+}
+
+// keeps capscr's visible windows out of a capture in progress. the hub is
+// handled by HubHidden; anything else (the editor) is excluded with
+// WDA_EXCLUDEFROMCAPTURE on windows, which leaves it on screen, and hidden
+// then re-shown elsewhere. pinned images are left alone: the user put those
+// on screen to be part of the shot
+struct OwnWindowsExcluded {
+    windows: Vec<tauri::WebviewWindow>,
+}
+
+impl OwnWindowsExcluded {
+    fn arm(app: &AppHandle) -> Self {
+        let windows: Vec<_> = app
+            .webview_windows()
+            .into_iter()
+            .filter(|(label, _)| label != HUB_LABEL && !label.starts_with("pin_"))
+            .map(|(_, window)| window)
+            .filter(|window| window.is_visible().unwrap_or(false))
+            .collect();
+        if windows.is_empty() {
+            return Self { windows };
+        }
+        for window in &windows {
+            set_capture_excluded(window, true);
+        }
+        #[cfg(not(windows))]
+        std::thread::sleep(Duration::from_millis(34));
+        Self { windows }
+    }
+}
+
+impl Drop for OwnWindowsExcluded {
+    fn drop(&mut self) {
+        for window in &self.windows {
+            set_capture_excluded(window, false);
+        }
+    }
+}
+
+#[cfg(windows)]
+fn set_capture_excluded(window: &tauri::WebviewWindow, excluded: bool) {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::UI::WindowsAndMessaging::{
+        SetWindowDisplayAffinity, WDA_EXCLUDEFROMCAPTURE, WDA_NONE,
+    };
+    let Ok(hwnd) = window.hwnd() else {
+        return;
+    };
+    let affinity = if excluded {
+        WDA_EXCLUDEFROMCAPTURE
+    } else {
+        WDA_NONE
+    };
+    // tauri's HWND may come from a different windows-rs release than ours
+    unsafe {
+        let _ = SetWindowDisplayAffinity(HWND(hwnd.0 as _), affinity);
+    }
+}
+
+#[cfg(not(windows))]
+fn set_capture_excluded(window: &tauri::WebviewWindow, excluded: bool) {
+    let _ = if excluded {
+        window.hide()
+    } else {
+        window.show()
+    };
+}
+
 pub fn open_editor_window(app: &AppHandle, image_path: &str) -> tauri::Result<()> {
     let state = app.state::<AppState>();
     *state.editor_image_path.lock().unwrap() = Some(image_path.to_string());
@@ -2014,6 +3217,130 @@ pub async fn open_editor(
     open_editor_window(&app, &canonical.to_string_lossy()).map_err(|e| e.to_string())
 }
 
+// an image from outside the capture folders, handed over by the file
+// manager's context menu or picked in the hub: copied into the output folder
+// first, so the editor saves beside the user's captures and never overwrites
+// the original
+pub(crate) fn open_external_in_editor(app: &AppHandle, source: &Path) -> anyhow::Result<PathBuf> {
+    let source = std::fs::canonicalize(source)?;
+    let format = image::ImageFormat::from_path(&source)?;
+    if !matches!(
+        format,
+        image::ImageFormat::Png
+            | image::ImageFormat::Jpeg
+            | image::ImageFormat::WebP
+            | image::ImageFormat::Bmp
+    ) {
+        anyhow::bail!("the editor opens png, jpeg, webp and bmp images");
+    }
+    // a corrupt or mislabelled file fails here rather than as a blank editor
+    image::image_dimensions(&source)?;
+    let config = app.state::<AppState>().config.lock().unwrap().clone();
+    let path = if is_path_allowed(&source, &config) {
+        source
+    } else {
+        std::fs::create_dir_all(&config.output.directory)?;
+        let name = source
+            .file_name()
+            .ok_or_else(|| anyhow::anyhow!("not a file: {}", source.display()))?;
+        let path = get_unique_filepath(&config.output.directory.join(name));
+        std::fs::copy(&source, &path)?;
+        notify_capture_saved(app, &path);
+        path
+    };
+    open_editor_window(app, &path.to_string_lossy()).map_err(|e| anyhow::anyhow!(e))?;
+    Ok(path)
+}
+
+// "open image" in the hub: any image on disk goes through the editor, and from
+// there save and upload, the same way a fresh capture does
+#[tauri::command]
+pub async fn open_image_in_editor(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Option<String>, String> {
+    use tauri_plugin_dialog::DialogExt;
+    let start_dir = state.config.lock().unwrap().output.directory.clone();
+    let Some(picked) = app
+        .dialog()
+        .file()
+        .set_title(t("dialog.open-image"))
+        .set_directory(&start_dir)
+        .add_filter(t("dialog.images"), &["png", "jpg", "jpeg", "webp", "bmp"])
+        .blocking_pick_file()
+    else {
+        return Ok(None);
+    };
+    let source = picked.into_path().map_err(|e| e.to_string())?;
+    let path = tauri::async_runtime::spawn_blocking(move || open_external_in_editor(&app, &source))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("{e:#}"))?;
+    Ok(Some(path.to_string_lossy().into_owned()))
+}
+
+/// pick a folder and run its images through the output settings into the
+/// output directory, emitting `capscr://batch-convert-progress` per file.
+/// None when the picker was cancelled, else how many were written
+#[tauri::command]
+pub async fn batch_convert_folder(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Option<usize>, String> {
+    use tauri_plugin_dialog::DialogExt;
+    let config = state.config.lock().unwrap().clone();
+    let Some(picked) = app
+        .dialog()
+        .file()
+        .set_title(t("dialog.convert-folder"))
+        .blocking_pick_folder()
+    else {
+        return Ok(None);
+    };
+    let dir = picked.into_path().map_err(|e| e.to_string())?;
+    let written = tauri::async_runtime::spawn_blocking(move || {
+        crate::batch::run(&dir, &config, |progress| {
+            let _ = app.emit("capscr://batch-convert-progress", progress);
+        })
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| format!("{e:#}"))?;
+    Ok(Some(written))
+}
+
+// the receiving end of an encrypted upload: download the sealed file, decrypt
+// it with the key from the link's fragment, and keep the result in the
+// history folder. images open in the editor; a recording opens in the
+// system player since the editor can't take it
+#[tauri::command]
+pub async fn open_encrypted_link(link: String, app: AppHandle) -> Result<String, String> {
+    let data =
+        tauri::async_runtime::spawn_blocking(move || crate::upload::encrypt::fetch_and_open(&link))
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| format!("{e:#}"))?;
+    let ext = match image::guess_format(&data) {
+        Ok(format) => format.extensions_str().first().copied().unwrap_or("png"),
+        Err(_) if data.get(4..8) == Some(b"ftyp") => "mp4",
+        Err(_) => return Err("the decrypted file isn't an image or a recording".into()),
+    };
+    let dir = history_dir().ok_or("no history folder")?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let name = chrono::Local::now().format("decrypted_%Y%m%d_%H%M%S");
+    let path = get_unique_filepath(&dir.join(format!("{name}.{ext}")));
+    std::fs::write(&path, &data).map_err(|e| e.to_string())?;
+    let shown = path.to_string_lossy().into_owned();
+    if ext == "gif" || ext == "mp4" {
+        app.opener()
+            .open_path(shown.clone(), None::<&str>)
+            .map_err(|e| e.to_string())?;
+    } else {
+        open_editor_window(&app, &shown).map_err(|e| e.to_string())?;
+    }
+    Ok(shown)
+}
+
 #[tauri::command]
 pub fn save_edited_image(
     bytes: Vec<u8>,
@@ -2038,6 +3365,19 @@ pub fn save_edited_image(
     if bytes.len() > 100 * 1024 * 1024 {
         return Err("Image too large to save".into());
     }
+    // the bytes come from the webview's encoder, so strip mode has to scrub
+    // them here; embedding is left to the original capture's save
+    let bytes = match config.output.metadata {
+        crate::config::MetadataMode::Strip => crate::metadata::strip(&bytes).unwrap_or(bytes),
+        _ => bytes,
+    };
+    // an editor save would otherwise drop the tags written into the png
+    let notes = crate::tags::get(&target_path);
+    let bytes = if config.output.tags_in_metadata && !notes.is_empty() {
+        crate::metadata::png_with_notes(&bytes, &notes.tags, &notes.note).unwrap_or(bytes)
+    } else {
+        bytes
+    };
     // atomic write: stage to a sibling temp file, then rename. A disk-full
     // or permission-denied mid-write would otherwise truncate the original
     // — the user would lose the un-edited capture too.
@@ -2052,20 +3392,171 @@ pub fn save_edited_image(
         let _ = std::fs::remove_file(&tmp);
         return Err(format!("rename failed: {e}"));
     }
-    // the hdr sidecar (<stem>.hdr.png) was captured from the original unedited
+    // the hdr sidecar (<stem>.hdr.*) was captured from the original unedited
     // pixels — once we overwrite the sdr file the sidecar no longer represents
     // the image content, so remove it rather than leaving a misleading orphan
-    if let Some(stem) = buf.file_stem().and_then(|s| s.to_str()) {
-        let sidecar = buf.with_file_name(format!("{stem}.hdr.png"));
-        if sidecar.exists() {
-            let _ = std::fs::remove_file(&sidecar);
-        }
+    for sidecar in crate::capture::existing_hdr_sidecars(&buf) {
+        let _ = std::fs::remove_file(&sidecar);
     }
     // surface the edit to the History tab so its tile picks up the new mtime
     notify_capture_saved(&app, &buf);
     Ok(())
 }
 
+/// "save as" from the editor: the user picks any folder, name and format in
+/// a native dialog. the dialog runs here rather than in the webview so the
+/// destination always comes from the user, never from page script. the
+/// editor sends png and this re-encodes to whatever the extension names.
+/// Ok(None) when the dialog was cancelled
+#[tauri::command]
+pub async fn save_edited_image_as(
+    bytes: Vec<u8>,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Option<String>, String> {
+    use tauri_plugin_dialog::DialogExt;
+    if bytes.len() > 100 * 1024 * 1024 {
+        return Err("Image too large to save".into());
+    }
+    let config = state.config.lock().unwrap().clone();
+    let configured = config.output.format;
+    let start_dir = config
+        .output
+        .save_as_directory
+        .clone()
+        .filter(|d| d.is_dir())
+        .unwrap_or_else(|| config.output.directory.clone());
+    let file_name = format!("{}.{}", config.generate_filename(), configured.extension());
+
+    // the configured format's filter goes first so it's the dialog's default
+    let mut formats = vec![configured];
+    formats.extend(ImageFormat::all().iter().filter(|&&f| f != configured));
+    let mut dialog = app
+        .dialog()
+        .file()
+        .set_title(t("dialog.save-as"))
+        .set_directory(&start_dir)
+        .set_file_name(&file_name);
+    for format in formats {
+        let exts: &[&str] = match format {
+            ImageFormat::Jpeg => &["jpg", "jpeg"],
+            _ => &[format.extension()],
+        };
+        dialog = dialog.add_filter(format.display_name(), exts);
+    }
+    let Some(picked) = dialog.blocking_save_file() else {
+        return Ok(None);
+    };
+    let mut path = picked.into_path().map_err(|e| e.to_string())?;
+    let format = match path
+        .extension()
+        .and_then(|e| e.to_str())
+        .and_then(ImageFormat::from_extension)
+    {
+        Some(format) => format,
+        // no or unknown extension: keep the name and add the configured one
+        None => {
+            let mut name = path.file_name().unwrap_or_default().to_os_string();
+            name.push(format!(".{}", configured.extension()));
+            path.set_file_name(name);
+            configured
+        }
+    };
+
+    let image = image::load_from_memory(&bytes)
+        .map_err(|e| e.to_string())?
+        .into_rgba8();
+    save_image(&image, &path, format, config.output.quality).map_err(|e| e.to_string())?;
+
+    if let Some(dir) = path.parent() {
+        state.config.lock().unwrap().output.save_as_directory = Some(dir.to_path_buf());
+        state.save_config_soon(&app);
+    }
+    state.record_save(&path);
+    SystemTray::rebuild(&app);
+    Ok(Some(path.to_string_lossy().into_owned()))
+}
+
+const EDITOR_PROJECT_SUFFIX: &str = ".capscr.json";
+// the project embeds the unedited capture as a png data url, so it runs a bit
+// over the size of the image itself
+const MAX_EDITOR_PROJECT_BYTES: usize = 150 * 1024 * 1024;
+
+/// `<file name>.capscr.json` beside a capture: the editor's re-editable
+/// project. the whole name, so shot.png and shot.jpg each get their own
+pub(crate) fn editor_project_path(image: &Path) -> Option<PathBuf> {
+    let name = image.file_name()?.to_str()?;
+    Some(image.with_file_name(format!("{name}{EDITOR_PROJECT_SUFFIX}")))
+}
+
+fn allowed_capture(path: &str, config: &Config) -> Result<PathBuf, String> {
+    let canonical = std::fs::canonicalize(path).map_err(|e| e.to_string())?;
+    if !is_path_allowed(&canonical, config) {
+        return Err("Path is outside the allowed capture directories".into());
+    }
+    Ok(canonical)
+}
+
+/// the project saved beside `path`, as the json text the editor wrote. the
+/// editor owns the format; this side only checks it's a bounded json object
+#[tauri::command]
+pub fn load_editor_project(path: String, state: State<AppState>) -> Result<Option<String>, String> {
+    let config = state.config.lock().unwrap().clone();
+    let canonical = allowed_capture(&path, &config)?;
+    let Some(project) = editor_project_path(&canonical).filter(|p| p.is_file()) else {
+        return Ok(None);
+    };
+    let len = std::fs::metadata(&project)
+        .map_err(|e| e.to_string())?
+        .len();
+    if len > MAX_EDITOR_PROJECT_BYTES as u64 {
+        return Err("editor project too large".into());
+    }
+    std::fs::read_to_string(&project)
+        .map(Some)
+        .map_err(|e| e.to_string())
+}
+
+/// write (or with `None`, remove) the project beside a capture the editor just
+/// saved
+#[tauri::command]
+pub fn save_editor_project(
+    target_path: String,
+    project: Option<String>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let config = state.config.lock().unwrap().clone();
+    let canonical = allowed_capture(&target_path, &config)?;
+    let path = editor_project_path(&canonical).ok_or("target has no file name")?;
+    let Some(project) = project else {
+        if path.exists() {
+            std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+        }
+        return Ok(());
+    };
+    if project.len() > MAX_EDITOR_PROJECT_BYTES {
+        return Err("editor project too large".into());
+    }
+    match serde_json::from_str::<serde_json::Value>(&project) {
+        Ok(serde_json::Value::Object(_)) => {}
+        _ => return Err("editor project isn't a json object".into()),
+    }
+    let mut tmp = path.clone();
+    let name = path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .unwrap_or("project");
+    tmp.set_file_name(format!(".{name}.tmp"));
+    if let Err(e) = std::fs::write(&tmp, project) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(format!("write failed: {e}"));
+    }
+    std::fs::rename(&tmp, &path).map_err(|e| {
+        let _ = std::fs::remove_file(&tmp);
+        format!("rename failed: {e}")
+    })
+}
+
 #[tauri::command]
 pub fn copy_edited_image_to_clipboard(bytes: Vec<u8>) -> Result<(), String> {
     let img = image::load_from_memory(&bytes).map_err(|e| e.to_string())?;
@@ -2075,10 +3566,10 @@ pub fn copy_edited_image_to_clipboard(bytes: Vec<u8>) -> Result<(), String> {
 }
 
 #[tauri::command]
-pub fn upload_file(
+pub async fn upload_file(
     path: String,
     app: AppHandle,
-    state: State<AppState>,
+    state: State<'_, AppState>,
 ) -> Result<UploadResponse, String> {
     let buf = PathBuf::from(&path);
     let canonical = std::fs::canonicalize(&buf).map_err(|e| e.to_string())?;
@@ -2120,19 +3611,18 @@ pub fn upload_file(
         .unwrap_or("upload")
         .to_string();
 
-    let uploader = crate::upload::shared_uploader().map_err(|e| e.to_string())?;
     let service = build_upload_service(&config);
-    let result = uploader
-        .upload_raw(&bytes, mime, &file_name, &service)
-        .map_err(|e| e.to_string())?;
+    let result =
+        upload_deduped_blocking(app.clone(), config.clone(), bytes, mime, file_name, service)
+            .await?;
 
     state.record_upload(UploadRecord {
         url: result.url.clone(),
         delete_url: result.delete_url.clone(),
     });
-    crate::rebuild_tray_menu(&app);
+    SystemTray::rebuild(&app);
     if config.upload.copy_url_to_clipboard {
-        let _ = crate::upload::copy_url_to_clipboard(&result.url);
+        let _ = crate::upload::copy_url_to_clipboard(&result.url, config.upload.link_format);
     }
     emit_upload_success(&app, &result);
 
@@ -2143,26 +3633,38 @@ pub fn upload_file(
 }
 
 #[tauri::command]
-pub fn upload_edited_image(
+pub async fn upload_edited_image(
     bytes: Vec<u8>,
+    preset: Option<String>,
     app: AppHandle,
-    state: State<AppState>,
+    state: State<'_, AppState>,
 ) -> Result<UploadResponse, String> {
     let img = image::load_from_memory(&bytes).map_err(|e| e.to_string())?;
-    let rgba = img.into_rgba8();
+    let mut rgba = img.into_rgba8();
     let config = state.config.lock().unwrap().clone();
+    if let Some(small) = crate::decorate::downscale(&rgba, &config.upload.resize) {
+        rgba = small;
+    }
     let uploader = crate::upload::shared_uploader().map_err(|e| e.to_string())?;
-    let service = build_upload_service(&config);
-    let result = uploader
-        .upload(&rgba, &service)
-        .map_err(|e| e.to_string())?;
+    let png = uploader.encode_png(&rgba).map_err(|e| e.to_string())?;
+    let service = build_upload_service_for_target(&config, &UploadOverride::for_preset(preset));
+    let file_name = String::from("screenshot.png");
+    let result = upload_deduped_blocking(
+        app.clone(),
+        config.clone(),
+        png,
+        "image/png",
+        file_name,
+        service,
+    )
+    .await?;
     state.record_upload(UploadRecord {
         url: result.url.clone(),
         delete_url: result.delete_url.clone(),
     });
-    crate::rebuild_tray_menu(&app);
+    SystemTray::rebuild(&app);
     if config.upload.copy_url_to_clipboard {
-        let _ = crate::upload::copy_url_to_clipboard(&result.url);
+        let _ = crate::upload::copy_url_to_clipboard(&result.url, config.upload.link_format);
     }
     emit_upload_success(&app, &result);
     Ok(UploadResponse {
@@ -2171,7 +3673,58 @@ pub fn upload_edited_image(
     })
 }
 
+// forgets every remembered upload, e.g. after links were deleted on the host
+#[tauri::command]
+pub fn clear_upload_index() -> Result<(), String> {
+    crate::upload::dedupe::clear().map_err(|e| e.to_string())
+}
+
+// the stop-recording binding rides the task registry under this id so every
+// hotkey backend dispatches it; task ids can't contain underscores
+pub const STOP_RECORDING_HOTKEY_ID: &str = "__stop_recording";
+// same for the do-not-disturb toggle
+pub const DND_HOTKEY_ID: &str = "__do_not_disturb";
+// and Esc while a capture counts down
+pub const CANCEL_HOTKEY_ID: &str = "__cancel";
+
+// the hold-to-record task whose hotkey is down right now. cleared by the
+// release, which can land before the recording has finished starting
+static HELD_TASK: std::sync::Mutex<Option<String>> = std::sync::Mutex::new(None);
+
 pub fn trigger_task(app: &AppHandle, task_id: &str) {
+    if task_id == DND_HOTKEY_ID {
+        crate::dnd::toggle(app);
+        return;
+    }
+    if task_id == CANCEL_HOTKEY_ID {
+        // Esc ends the countdown that bound it, never an upload or a
+        // recording running alongside
+        if crate::cancel::escapable() {
+            crate::cancel::cancel_escapable(app);
+        }
+        return;
+    }
+    if task_id == STOP_RECORDING_HOTKEY_ID {
+        let recording = matches!(
+            *app.state::<AppState>().recording_state.lock().unwrap(),
+            RecordingState::Recording
+        );
+        if recording {
+            stop_gif_recording(app);
+        }
+        return;
+    }
+    // the bindings are dropped while do not disturb is on; this catches a
+    // gesture that was already settling, or a desktop that still delivers them
+    if crate::dnd::active() {
+        return;
+    }
+    fire_task_by_id(app, task_id);
+}
+
+/// run a task the user asked for outside its hotkey (the hub, a jump list
+/// entry), which do not disturb doesn't hold back
+pub fn fire_task_by_id(app: &AppHandle, task_id: &str) {
     let task = {
         let state = app.state::<AppState>();
         let config = state.config.lock().unwrap();
@@ -2185,6 +3738,9 @@ pub fn trigger_task(app: &AppHandle, task_id: &str) {
         tracing::warn!("hotkey fired for unknown task id: {task_id}");
         return;
     };
+    if task.hold_to_record {
+        *HELD_TASK.lock().unwrap() = Some(task.id.clone());
+    }
     let app_handle = app.clone();
     let task_label = task.name.clone();
     std::thread::spawn(move || {
@@ -2194,29 +3750,158 @@ pub fn trigger_task(app: &AppHandle, task_id: &str) {
             let state = app_handle.state::<AppState>();
             let show = state.config.lock().unwrap().ui.show_notifications;
             if show {
-                let _ = show_notification(&format!("Task '{}' failed", task_label), &e.to_string());
+                let _ = show_notification(
+                    &tf("notify.task-failed", &[("name", &task_label)]),
+                    &e.to_string(),
+                );
             }
         }
     });
 }
 
+/// a task's hotkey came back up. only a hold-to-record task acts on it: its
+/// recording stops and saves as if the stop button was pressed
+pub fn release_task(app: &AppHandle, task_id: &str) {
+    {
+        let mut held = HELD_TASK.lock().unwrap();
+        if held.as_deref() != Some(task_id) {
+            return;
+        }
+        *held = None;
+    }
+    let state = app.state::<AppState>();
+    let recording = matches!(
+        *state.recording_state.lock().unwrap(),
+        RecordingState::Recording
+    );
+    let ours = state.recording_task_id.lock().unwrap().as_deref() == Some(task_id);
+    if recording && ours {
+        stop_gif_recording(app);
+    }
+}
+
+// a hold task whose key was let go while its recording was still starting
+fn stop_if_released(task: &CaptureTask, app: &AppHandle) {
+    if task.hold_to_record && HELD_TASK.lock().unwrap().as_deref() != Some(task.id.as_str()) {
+        stop_gif_recording(app);
+    }
+}
+
 pub fn run_task(task: &CaptureTask, app: &AppHandle) -> anyhow::Result<()> {
     if matches!(
         task.capture_mode,
-        TaskCaptureMode::RegionGif | TaskCaptureMode::RegionMp4
+        TaskCaptureMode::RegionGif | TaskCaptureMode::RegionMp4 | TaskCaptureMode::RegionStream
     ) {
         return run_gif_task(task, app);
     }
+    if task.capture_mode == TaskCaptureMode::TargetWindow {
+        return run_target_window_task(task, app);
+    }
+    if task.capture_mode == TaskCaptureMode::ActiveWindow {
+        return run_active_window_capture(
+            PostActionArg::from_task_action(task.post_action),
+            app,
+            UploadOverride::for_task(task),
+            task.delay_ms,
+        );
+    }
     let mode = match task.capture_mode {
         TaskCaptureMode::Region
         | TaskCaptureMode::RegionLast
         | TaskCaptureMode::Window
         | TaskCaptureMode::Fullscreen => CaptureModeArg::from_task_mode(task.capture_mode),
         TaskCaptureMode::ActiveMonitor => CaptureModeArg::ActiveMonitor,
-        TaskCaptureMode::RegionGif | TaskCaptureMode::RegionMp4 => unreachable!("handled above"),
+        TaskCaptureMode::TargetWindow
+        | TaskCaptureMode::ActiveWindow
+        | TaskCaptureMode::RegionGif
+        | TaskCaptureMode::RegionMp4
+        | TaskCaptureMode::RegionStream => unreachable!("handled above"),
     };
     let post = PostActionArg::from_task_action(task.post_action);
-    run_capture_pipeline_with_target(mode, post, app, task.target_destination, task.delay_ms)
+    run_capture_pipeline_with_target(
+        mode,
+        post,
+        app,
+        UploadOverride::for_task(task),
+        task.delay_ms,
+    )
+}
+
+// resolve the task's title/process patterns to a live window and capture it
+// without the selector. no match is an expected outcome (the app isn't open),
+// so it gets a notification rather than an error toast
+fn run_target_window_task(task: &CaptureTask, app: &AppHandle) -> anyhow::Result<()> {
+    let target = task
+        .target_window
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("task has no window to target"))?;
+    #[cfg(target_os = "linux")]
+    if crate::capture::is_wayland_session() {
+        anyhow::bail!("capturing a window by title needs an X11 session on linux");
+    }
+    let Some(window) = WindowCapture::from_target(&target.title, &target.process)? else {
+        tracing::info!(
+            "task '{}': no window matches title={:?} process={:?}",
+            task.id,
+            target.title,
+            target.process
+        );
+        let show = app
+            .state::<AppState>()
+            .config
+            .lock()
+            .unwrap()
+            .ui
+            .show_notifications;
+        if show {
+            let what = if target.title.trim().is_empty() {
+                target.process.trim()
+            } else {
+                target.title.trim()
+            };
+            let _ = show_notification(
+                t("notify.no-window"),
+                &tf("notify.no-window.body", &[("what", &what)]),
+            );
+        }
+        return Ok(());
+    };
+    let post = PostActionArg::from_task_action(task.post_action);
+    run_capture_pipeline_inner(
+        CaptureModeArg::Window,
+        post,
+        app,
+        UploadOverride::for_task(task),
+        task.delay_ms,
+        Some(window.id()),
+    )
+}
+
+/// capture the focused window without the selector, which only opens when no
+/// window can be resolved (capscr itself has focus, or wayland won't say).
+/// the window is read before any delay runs, so a delay for opening a menu
+/// still captures the window the hotkey was pressed in
+pub(crate) fn run_active_window_capture(
+    post: PostActionArg,
+    app: &AppHandle,
+    upload: UploadOverride,
+    delay_ms: Option<u32>,
+) -> anyhow::Result<()> {
+    let focused = WindowCapture::focused().unwrap_or_else(|e| {
+        tracing::debug!("couldn't read the focused window: {e:#}");
+        None
+    });
+    if focused.is_none() {
+        tracing::info!("no focused window to capture; opening the selector");
+    }
+    run_capture_pipeline_inner(
+        CaptureModeArg::Window,
+        post,
+        app,
+        upload,
+        delay_ms,
+        focused.map(|w| w.id()),
+    )
 }
 
 #[cfg(any(windows, target_os = "linux"))]
@@ -2325,62 +4010,52 @@ fn perform_ffmpeg_download() -> anyhow::Result<()> {
 #[cfg(target_os = "linux")]
 fn handle_missing_ffmpeg(app: &AppHandle) -> anyhow::Result<()> {
     use tauri_plugin_dialog::DialogExt;
-    let instructions = "Recording MP4 requires FFmpeg, which was not found on your system.\n\n\
-         Install it with your package manager, e.g.\n\
-         sudo apt install ffmpeg   (Debian/Ubuntu)\n\
-         sudo dnf install ffmpeg   (Fedora)";
+    let instructions = t("ffmpeg.instructions");
 
     // the hosted build is x86_64 only; other arches get the instructions
     if LINUX_FFMPEG_SHA256.is_empty() || !cfg!(target_arch = "x86_64") {
         app.dialog()
             .message(instructions)
-            .title("FFmpeg Required")
+            .title(t("ffmpeg.required"))
             .kind(tauri_plugin_dialog::MessageDialogKind::Info)
             .blocking_show();
         return Ok(());
     }
     if FFMPEG_DOWNLOADING.load(Ordering::SeqCst) {
-        let _ = show_notification(
-            "FFmpeg Download",
-            "FFmpeg is already downloading in the background. Please wait.",
-        );
+        let _ = show_notification(t("ffmpeg.download"), t("ffmpeg.busy"));
         return Ok(());
     }
     let download = app
         .dialog()
-        .message(
-            "Recording MP4 requires FFmpeg, which was not found on your system.\n\n\
-             Download a verified static FFmpeg (~30 MB, used only by capscr), or \
-             install it with your package manager instead?",
-        )
-        .title("FFmpeg Required")
+        .message(t("ffmpeg.offer"))
+        .title(t("ffmpeg.required"))
         .kind(tauri_plugin_dialog::MessageDialogKind::Info)
         .buttons(tauri_plugin_dialog::MessageDialogButtons::OkCancelCustom(
-            "Download".into(),
-            "Show instructions".into(),
+            t("ffmpeg.offer.download").into(),
+            t("ffmpeg.offer.instructions").into(),
         ))
         .blocking_show();
     if !download {
         app.dialog()
             .message(instructions)
-            .title("Install FFmpeg")
+            .title(t("ffmpeg.install"))
             .kind(tauri_plugin_dialog::MessageDialogKind::Info)
             .blocking_show();
         return Ok(());
     }
     FFMPEG_DOWNLOADING.store(true, Ordering::SeqCst);
     std::thread::spawn(move || {
-        let _ = show_notification("FFmpeg Download", "Downloading a verified FFmpeg build...");
+        let _ = show_notification(t("ffmpeg.download"), t("ffmpeg.downloading"));
         match download_verified_ffmpeg(LINUX_FFMPEG_URL, LINUX_FFMPEG_SHA256, "ffmpeg") {
             Ok(()) => {
-                let _ = show_notification(
-                    "FFmpeg Configured",
-                    "FFmpeg is ready. You can now record MP4 videos.",
-                );
+                let _ = show_notification(t("ffmpeg.configured"), t("ffmpeg.ready"));
             }
             Err(e) => {
                 tracing::error!("failed to download ffmpeg: {e}");
-                let _ = show_notification("FFmpeg Download Failed", &format!("{e}"));
+                let _ = show_notification(
+                    t("ffmpeg.failed"),
+                    &tf("ffmpeg.failed.body", &[("error", &e)]),
+                );
             }
         }
         FFMPEG_DOWNLOADING.store(false, Ordering::SeqCst);
@@ -2396,17 +4071,14 @@ fn handle_missing_ffmpeg(_app: &AppHandle) -> anyhow::Result<()> {
 #[cfg(windows)]
 fn handle_missing_ffmpeg(app: &AppHandle) -> anyhow::Result<()> {
     if FFMPEG_DOWNLOADING.load(Ordering::SeqCst) {
-        let _ = show_notification(
-            "FFmpeg Download",
-            "FFmpeg is already downloading in the background. Please wait.",
-        );
+        let _ = show_notification(t("ffmpeg.download"), t("ffmpeg.busy"));
         return Ok(());
     }
 
     use tauri_plugin_dialog::DialogExt;
     let is_confirmed = app.dialog()
-        .message("Recording MP4 requires FFmpeg, which was not found on your system.\n\nWould you like to automatically download and configure it?")
-        .title("FFmpeg Required")
+        .message(t("ffmpeg.offer.windows"))
+        .title(t("ffmpeg.required"))
         .kind(tauri_plugin_dialog::MessageDialogKind::Info)
         .buttons(tauri_plugin_dialog::MessageDialogButtons::YesNo)
         .blocking_show();
@@ -2414,22 +4086,16 @@ fn handle_missing_ffmpeg(app: &AppHandle) -> anyhow::Result<()> {
     if is_confirmed {
         FFMPEG_DOWNLOADING.store(true, Ordering::SeqCst);
         std::thread::spawn(move || {
-            let _ = show_notification(
-                "FFmpeg Download",
-                "Starting FFmpeg download (approx. 90MB)...",
-            );
+            let _ = show_notification(t("ffmpeg.download"), t("ffmpeg.starting"));
             match perform_ffmpeg_download() {
                 Ok(()) => {
-                    let _ = show_notification(
-                        "FFmpeg Configured",
-                        "FFmpeg has been downloaded and configured. You can now record MP4 videos.",
-                    );
+                    let _ = show_notification(t("ffmpeg.configured"), t("ffmpeg.ready.downloaded"));
                 }
                 Err(e) => {
                     tracing::error!("failed to download ffmpeg: {}", e);
                     let _ = show_notification(
-                        "FFmpeg Download Failed",
-                        &format!("Could not configure FFmpeg: {}", e),
+                        t("ffmpeg.failed"),
+                        &tf("ffmpeg.failed.body", &[("error", &e)]),
                     );
                 }
             }
@@ -2463,15 +4129,12 @@ fn run_gif_task(task: &CaptureTask, app: &AppHandle) -> anyhow::Result<()> {
                     .as_deref()
                     .and_then(|id| cfg.capture_tasks.iter().find(|t| t.id == id))
                     .map(|t| t.name.clone())
-                    .unwrap_or_else(|| "another task".to_string())
+                    .unwrap_or_else(|| t("error.another-task").to_string())
             };
             emit_error(
                 app,
                 "recording",
-                &format!(
-                    "already recording '{}' — press its hotkey again to stop first",
-                    active_name
-                ),
+                &tf("error.already-recording", &[("name", &active_name)]),
             );
         }
         return Ok(());
@@ -2482,13 +4145,49 @@ fn run_gif_task(task: &CaptureTask, app: &AppHandle) -> anyhow::Result<()> {
         return Ok(());
     }
 
-    if matches!(task.capture_mode, TaskCaptureMode::RegionMp4)
-        && !crate::recording::is_ffmpeg_available()
+    if matches!(
+        task.capture_mode,
+        TaskCaptureMode::RegionMp4 | TaskCaptureMode::RegionStream
+    ) && !crate::recording::is_ffmpeg_available()
     {
         handle_missing_ffmpeg(app)?;
         return Ok(());
     }
 
+    // a stream with nowhere to go should fail before the selector opens
+    if task.capture_mode == TaskCaptureMode::RegionStream {
+        let cfg = state.config.lock().unwrap().clone();
+        if let Err(e) = stream_target(&cfg) {
+            emit_error(app, "stream", &e.to_string());
+            return Ok(());
+        }
+    }
+
+    // nothing to select: the recording starts on whatever window has focus
+    if task.follow_active_window {
+        let Some(region) = crate::capture::active_window_rect() else {
+            emit_error(app, "recording", t("error.no-focused-window"));
+            return Ok(());
+        };
+        let hub_hidden = HubHidden::for_capture(app);
+        start_gif_recording(task, app, region)?;
+        stop_if_released(task, app);
+        hub_hidden.hold_until_recording_ends(app);
+        return Ok(());
+    }
+
+    // a held key can't wait on a drag: hold tasks record the last region
+    // selected and only open the selector while there's none yet
+    if task.hold_to_record && task.recording_size.is_none() {
+        if let Some(region) = state.last_region() {
+            let hub_hidden = HubHidden::for_capture(app);
+            start_gif_recording(task, app, region)?;
+            stop_if_released(task, app);
+            hub_hidden.hold_until_recording_ends(app);
+            return Ok(());
+        }
+    }
+
     // gate is held only during selection so a screenshot hotkey pressed while
     // the region selector is visible doesn't open a second overlay
     use std::sync::atomic::Ordering as OrdGif;
@@ -2500,7 +4199,14 @@ fn run_gif_task(task: &CaptureTask, app: &AppHandle) -> anyhow::Result<()> {
         tracing::info!("capture already in progress; dropping gif trigger");
         return Ok(());
     }
-    let selection = UnifiedSelector::select(None);
+    let hub_hidden = HubHidden::for_capture(app);
+    UnifiedSelector::set_cursor_monitor_only(
+        state.config.lock().unwrap().capture.selector_cursor_monitor,
+    );
+    let selection = match task.recording_size {
+        Some(size) => UnifiedSelector::select_fixed((size.width, size.height)),
+        None => UnifiedSelector::select(None),
+    };
     state.capture_in_progress.store(false, OrdGif::SeqCst);
 
     let region = match selection {
@@ -2515,8 +4221,13 @@ fn run_gif_task(task: &CaptureTask, app: &AppHandle) -> anyhow::Result<()> {
             return Ok(());
         }
     };
+    if task.hold_to_record {
+        state.remember_region(region);
+    }
 
-    start_gif_recording(task, app, region)
+    start_gif_recording(task, app, region)?;
+    hub_hidden.hold_until_recording_ends(app);
+    Ok(())
 }
 
 fn start_gif_recording(
@@ -2527,7 +4238,8 @@ fn start_gif_recording(
     let state = app.state::<AppState>();
     let cfg = state.config.lock().unwrap().clone();
 
-    let is_mp4 = matches!(task.capture_mode, TaskCaptureMode::RegionMp4);
+    let is_live = task.capture_mode == TaskCaptureMode::RegionStream;
+    let is_mp4 = matches!(task.capture_mode, TaskCaptureMode::RegionMp4) || is_live;
     let settings = RecordingSettings {
         fps: if is_mp4 {
             cfg.capture.video_fps
@@ -2544,6 +4256,13 @@ fn start_gif_recording(
         } else {
             crate::recording::RecordingFormat::Gif
         },
+        exclusion: cfg.capture.exclusion.clone(),
+        zoom: cfg.capture.zoom.clone(),
+        stream: if is_live {
+            Some(stream_target(&cfg)?)
+        } else {
+            None
+        },
     };
 
     // steer the wayland frame grabs; windows composites the cursor per frame
@@ -2566,6 +4285,9 @@ fn start_gif_recording(
     }
 
     let mut recorder = GifRecorder::new(settings).with_region(region);
+    if task.follow_active_window {
+        recorder = recorder.following_active_window();
+    }
     recorder.start()?;
 
     *state.gif_recorder.lock().unwrap() = Some(recorder);
@@ -2576,29 +4298,81 @@ fn start_gif_recording(
     // hotkey does; the callback fires on the overlay thread, which is safe
     // because stop_gif_recording only touches mutex-guarded state
     let app_for_stop = app.clone();
+    // a stream has no max duration, so its bar just counts up
     RecordingOverlay::start(
         region,
-        cfg.capture.gif_max_duration_secs as u64,
+        if is_live {
+            0
+        } else {
+            cfg.capture.gif_max_duration_secs as u64
+        },
         Box::new(move || stop_gif_recording(&app_for_stop)),
     );
     let _ = app.emit("capscr://recording-started", task.id.clone());
-    set_tray_tooltip(app, &format!("capscr · recording '{}'", task.name));
+    let status = if is_live {
+        "tray.tooltip.streaming"
+    } else {
+        "tray.tooltip.recording"
+    };
+    SystemTray::set_tooltip(app, &tf(status, &[("name", &task.name)]));
+    SystemTray::rebuild(app);
 
     let app2 = app.clone();
     let task_owned = task.clone();
+    let max_duration = if is_live {
+        Duration::ZERO
+    } else {
+        Duration::from_secs(cfg.capture.gif_max_duration_secs as u64)
+    };
     std::thread::spawn(move || {
+        let mut overlay_at = region;
+        let mut tooltip = String::new();
+        let mut warned = false;
         loop {
             std::thread::sleep(Duration::from_millis(300));
             let st = app2.state::<AppState>();
 
             let user_stopped = st.recording_task_id.lock().unwrap().is_none();
-            let recorder_done = {
+            let (recorder_done, current, stats) = {
                 let rec = st.gif_recorder.lock().unwrap();
                 match rec.as_ref() {
-                    Some(r) => !matches!(r.state(), RecordingState::Recording),
-                    None => true,
+                    Some(r) => (
+                        !matches!(r.state(), RecordingState::Recording),
+                        r.current_region(),
+                        Some(r.stats()),
+                    ),
+                    None => (true, None, None),
                 }
             };
+            // the first second is mostly startup; a rate measured over it
+            // would read as choppy for every recording
+            let ending = stats
+                .filter(|s| crate::recording::nears_max_duration(s.elapsed, max_duration))
+                .map(|s| max_duration.saturating_sub(s.elapsed));
+            if let Some(remaining) = ending.filter(|_| !warned) {
+                warn_recording_ending(&app2, &task_owned, remaining);
+                warned = true;
+            }
+            if let Some(stats) = stats.filter(|s| s.elapsed >= Duration::from_secs(1)) {
+                let mut next = format!(
+                    "{} · {}",
+                    tf(status, &[("name", &task_owned.name)]),
+                    stats.summary()
+                );
+                if let Some(remaining) = ending {
+                    let secs = remaining.as_secs();
+                    next.push_str(&tf("tray.tooltip.stops-in", &[("secs", &secs)]));
+                }
+                if next != tooltip {
+                    SystemTray::set_tooltip(&app2, &next);
+                    tooltip = next;
+                }
+            }
+            // a followed window drags the border along with it
+            if let Some(current) = current.filter(|c| *c != overlay_at) {
+                RecordingOverlay::move_to(current);
+                overlay_at = current;
+            }
 
             if user_stopped || recorder_done {
                 break;
@@ -2611,6 +4385,36 @@ fn start_gif_recording(
     Ok(())
 }
 
+// tell the user a recording is about to hit gif_max_duration_secs rather than
+// let a long demo end silently: the hub and the linux recording bar pick up
+// the event, and a notification covers a hub that's hidden
+fn warn_recording_ending(app: &AppHandle, task: &CaptureTask, remaining: Duration) {
+    let secs = remaining.as_secs_f32().ceil() as u64;
+    let _ = app.emit("capscr://recording-ending", secs);
+    let (show, stop_hotkey) = {
+        let state = app.state::<AppState>();
+        let cfg = state.config.lock().unwrap();
+        (
+            cfg.ui.show_notifications,
+            cfg.hotkeys.stop_recording.clone(),
+        )
+    };
+    if show {
+        let stop_with = [stop_hotkey.as_str(), task.hotkey.as_str()]
+            .into_iter()
+            .find(|hotkey| !hotkey.is_empty())
+            .map(|hotkey| tf("notify.recording-ending.stop", &[("hotkey", &hotkey)]))
+            .unwrap_or_default();
+        let _ = show_notification(
+            t("notify.recording-ending"),
+            &tf(
+                "notify.recording-ending.body",
+                &[("name", &task.name), ("secs", &secs), ("stop", &stop_with)],
+            ),
+        );
+    }
+}
+
 fn stop_gif_recording(app: &AppHandle) {
     let state = app.state::<AppState>();
     // stop the capture thread first, then clear task_id so the monitor thread
@@ -2629,11 +4433,14 @@ fn finalize_gif_recording(task: &CaptureTask, app: &AppHandle) {
 
     let state = app.state::<AppState>();
     *state.recording_state.lock().unwrap() = RecordingState::Processing;
+    SystemTray::rebuild(app);
 
     let cfg = state.config.lock().unwrap().clone();
-    let mut recorder = state.gif_recorder.lock().unwrap().take();
+    let recorder = state.gif_recorder.lock().unwrap().take();
+    // read even without a recorder, so a stale flag can't eat the next one
+    let discarded = crate::cancel::take_discarded();
 
-    if let Some(ref mut rec) = recorder {
+    if let Some(mut rec) = recorder {
         rec.stop();
         // wait for the capture thread to finish rather than sleeping a fixed
         // duration -- the thread sets state to processing after its last frame
@@ -2649,84 +4456,405 @@ fn finalize_gif_recording(task: &CaptureTask, app: &AppHandle) {
         }
 
         let is_mp4 = matches!(task.capture_mode, TaskCaptureMode::RegionMp4);
-
-        let mut path = cfg.output_path();
-        if is_mp4 {
-            path.set_extension("mp4");
+        report_recording_stats(app, rec.stats());
+
+        if rec.is_live() {
+            end_live_stream(app, &rec);
+        } else if discarded {
+            tracing::info!("recording cancelled; discarding it");
+        } else if cfg.capture.review_recordings {
+            hold_recording_for_review(task, app, rec);
         } else {
-            path.set_extension("gif");
+            let path = recording_output_path(&cfg, is_mp4);
+            let save_result = if is_mp4 {
+                rec.save_mp4(&path)
+            } else {
+                rec.save(&path).map(|_| false)
+            };
+
+            match save_result {
+                Ok(audio_dropped) => {
+                    deliver_recording(task, app, &cfg, &path, audio_dropped, rec.stop_reason())
+                }
+                Err(e) => {
+                    let err_type = if is_mp4 { "mp4-save" } else { "gif-save" };
+                    tracing::warn!("{} failed: {e}", err_type);
+                    emit_error(app, err_type, &e.to_string());
+                }
+            }
         }
-        let path = get_unique_filepath(&path);
-        if let Err(e) = std::fs::create_dir_all(&cfg.output.directory) {
-            tracing::warn!("failed to create output dir: {e}");
+    }
+
+    *state.recording_state.lock().unwrap() = RecordingState::Idle;
+    *state.recording_task_id.lock().unwrap() = None;
+    if state
+        .hub_hidden_for_recording
+        .swap(false, std::sync::atomic::Ordering::SeqCst)
+    {
+        if let Some(hub) = app.get_webview_window(HUB_LABEL) {
+            let _ = hub.show();
         }
+    }
+    let _ = app.emit("capscr://recording-stopped", task.id.clone());
+    SystemTray::set_tooltip(app, "capscr");
+    SystemTray::rebuild(app);
+}
 
-        let save_result = if is_mp4 {
-            rec.save_mp4(&path)
+#[derive(Debug, Clone, Serialize)]
+struct RecordingStatsPayload {
+    target_fps: u32,
+    achieved_fps: f32,
+    frames: u32,
+    dropped: u32,
+    failed: u32,
+    duration_ms: u64,
+}
+
+// so a choppy recording can be traced back to a capture loop that couldn't
+// keep up rather than to the encoder
+fn report_recording_stats(app: &AppHandle, stats: FrameStats) {
+    tracing::info!(
+        "recording stats: {} ({} kept, {} failed grabs over {:.1}s)",
+        stats.summary(),
+        stats.kept,
+        stats.failed,
+        stats.elapsed.as_secs_f32()
+    );
+    let _ = app.emit(
+        "capscr://recording-stats",
+        RecordingStatsPayload {
+            target_fps: stats.target_fps,
+            achieved_fps: stats.achieved_fps(),
+            frames: stats.kept,
+            dropped: stats.dropped(),
+            failed: stats.failed,
+            duration_ms: stats.elapsed.as_millis() as u64,
+        },
+    );
+}
+
+// the ingest a region-stream task pushes to, from capture.stream
+fn stream_target(cfg: &Config) -> anyhow::Result<crate::recording::StreamTarget> {
+    let target = crate::recording::StreamTarget {
+        url: cfg.capture.stream.url_plaintext(),
+        bitrate_kbps: cfg.capture.stream.bitrate_kbps,
+    };
+    if target.url.is_empty() {
+        anyhow::bail!("set a stream url under settings → capture → live stream first");
+    }
+    target.validate()?;
+    Ok(target)
+}
+
+// nothing to save after a stream; just hang up and say so if the ingest
+// went away underneath it
+fn end_live_stream(app: &AppHandle, rec: &GifRecorder) {
+    if let Err(e) = rec.end_stream() {
+        tracing::warn!("stream ended uncleanly: {e}");
+        emit_error(app, "stream", &tf("error.stream-unclean", &[("error", &e)]));
+    } else if rec.stop_reason() == Some(StopReason::EncoderFailed) {
+        emit_error(app, "stream", t("error.ingest-dropped"));
+    }
+}
+
+// a fresh, unique file in the output folder for a finished recording
+fn recording_output_path(cfg: &Config, is_mp4: bool) -> PathBuf {
+    let mut path = cfg.output_path();
+    path.set_extension(if is_mp4 { "mp4" } else { "gif" });
+    let path = get_unique_filepath(&path);
+    if let Err(e) = std::fs::create_dir_all(&cfg.output.directory) {
+        tracing::warn!("failed to create output dir: {e}");
+    }
+    path
+}
+
+// everything that follows a recording landing on disk, whether it was saved
+// straight away or came out of the trim preview
+fn deliver_recording(
+    task: &CaptureTask,
+    app: &AppHandle,
+    cfg: &Config,
+    path: &Path,
+    audio_dropped: bool,
+    stop_reason: Option<StopReason>,
+) {
+    let is_mp4 = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("mp4"));
+    app.state::<AppState>().record_save(path);
+    SystemTray::rebuild(app);
+    Sound::Screenshot.play_if_enabled(cfg.post_capture.play_sound);
+    if cfg.ui.show_notifications {
+        let title = if is_mp4 {
+            "notify.video-saved"
         } else {
-            rec.save(&path).map(|_| false)
+            "notify.gif-saved"
         };
-
-        match save_result {
-            Ok(audio_dropped) => {
-                *state.last_save.lock().unwrap() = Some(path.clone());
-                Sound::Screenshot.play_if_enabled(cfg.post_capture.play_sound);
-                if cfg.ui.show_notifications {
-                    let title = if is_mp4 { "Video saved" } else { "GIF saved" };
-                    let _ = show_notification(title, &path.to_string_lossy());
-                }
-                // the user asked for system audio but the track was lost
-                if audio_dropped {
-                    emit_error(
-                        app,
-                        "recording",
-                        "saved without audio — the system-audio track couldn't be captured or muxed",
-                    );
-                }
-                // a stop the user didn't ask for deserves an explanation
-                let early_stop_note = match rec.stop_reason() {
-                    Some(StopReason::MaxDuration) => Some(format!(
-                        "hit the {}s max duration — raise it under settings → capture",
-                        cfg.capture.gif_max_duration_secs
-                    )),
-                    Some(StopReason::FrameCap) => {
-                        Some("hit the frame-count safety limit — recording saved".to_string())
-                    }
-                    Some(StopReason::DiskFull) => {
-                        Some("stopped early: the disk is nearly full — recording saved".to_string())
-                    }
-                    Some(StopReason::EncoderFailed) => Some(
-                        "stopped early: couldn't write frames to disk — saved what was captured"
-                            .to_string(),
-                    ),
-                    _ => None,
-                };
-                if let Some(note) = early_stop_note {
-                    emit_error(app, "recording", &note);
-                }
-                notify_capture_saved(app, &path);
-                apply_gif_post_action(task, app, &path, &cfg);
+        notify_saved_file(app, t(title), path);
+    }
+    if cfg.post_capture.open_file_after_save {
+        open_saved_file(path);
+    }
+    // the user asked for system audio but the track was lost
+    if audio_dropped {
+        emit_error(app, "recording", t("error.no-audio"));
+    }
+    // a stop the user didn't ask for deserves an explanation
+    let early_stop_note = match stop_reason {
+        Some(StopReason::MaxDuration) => Some(tf(
+            "error.max-duration",
+            &[("secs", &cfg.capture.gif_max_duration_secs)],
+        )),
+        Some(StopReason::FrameCap) => Some(t("error.frame-cap").to_string()),
+        Some(StopReason::DiskFull) => Some(t("error.disk-full").to_string()),
+        Some(StopReason::EncoderFailed) => Some(t("error.encoder-failed").to_string()),
+        _ => None,
+    };
+    if let Some(note) = early_stop_note {
+        emit_error(app, "recording", &note);
+    }
+    notify_capture_saved(app, path);
+    apply_gif_post_action(task, app, path, cfg);
+}
+
+// park a stopped recording for the hub's trim preview instead of saving it.
+// an mp4 is finished into the app cache first so the webview can play it
+fn hold_recording_for_review(task: &CaptureTask, app: &AppHandle, rec: GifRecorder) {
+    let is_mp4 = rec.format() == crate::recording::RecordingFormat::Mp4;
+    let err_type = if is_mp4 { "mp4-save" } else { "gif-save" };
+    let mut video = None;
+    let mut audio_dropped = false;
+    if is_mp4 {
+        let staged = app
+            .path()
+            .app_cache_dir()
+            .map_err(anyhow::Error::from)
+            .and_then(|dir| {
+                let dir = dir.join("review");
+                std::fs::create_dir_all(&dir)?;
+                Ok(dir.join(format!(
+                    "capscr_review_{}.mp4",
+                    uuid::Uuid::new_v4().as_simple()
+                )))
+            })
+            .and_then(|path| rec.save_mp4(&path).map(|dropped| (path, dropped)));
+        match staged {
+            Ok((path, dropped)) => {
+                video = Some(path);
+                audio_dropped = dropped;
             }
             Err(e) => {
-                let err_type = if is_mp4 { "mp4-save" } else { "gif-save" };
-                tracing::warn!("{} failed: {e}", err_type);
+                tracing::warn!("{err_type} for review failed: {e}");
                 emit_error(app, err_type, &e.to_string());
+                return;
             }
         }
+    } else if rec.frame_times().is_empty() {
+        emit_error(app, err_type, t("error.no-frames"));
+        return;
     }
 
-    *state.recording_state.lock().unwrap() = RecordingState::Idle;
-    *state.recording_task_id.lock().unwrap() = None;
-    let _ = app.emit("capscr://recording-stopped", task.id.clone());
-    set_tray_tooltip(app, "capscr");
+    let review = crate::state::RecordingReview {
+        task: task.clone(),
+        stop_reason: rec.stop_reason(),
+        recorder: rec,
+        video,
+        audio_dropped,
+    };
+    let state = app.state::<AppState>();
+    let unanswered = state.recording_review.lock().unwrap().replace(review);
+    // one review at a time: a recording the user never got back to is saved
+    // whole rather than thrown away
+    if let Some(previous) = unanswered {
+        if let Err(e) = finish_review(app, previous, None) {
+            tracing::warn!("saving an unreviewed recording failed: {e:#}");
+        }
+    }
+    if let Err(e) = open_hub_window(app) {
+        tracing::warn!("failed to open the hub for recording review: {e}");
+    }
+    let _ = app.emit("capscr://recording-review", ());
+}
+
+// write a reviewed recording to the output folder, cut to `trim` when the
+// user moved the in/out points
+fn save_review(
+    review: &crate::state::RecordingReview,
+    cfg: &Config,
+    trim: Option<(Duration, Duration)>,
+) -> anyhow::Result<PathBuf> {
+    let path = recording_output_path(cfg, review.video.is_some());
+    match (&review.video, trim) {
+        (Some(video), Some((start, end))) => {
+            crate::recording::cut_video(video, &path, start, end, cfg.capture.video_quality.crf())?
+        }
+        (Some(video), None) => {
+            if std::fs::rename(video, &path).is_err() {
+                // the cache and output dir may sit on different volumes
+                if let Some(dir) = path.parent() {
+                    let len = std::fs::metadata(video).map_or(0, |m| m.len());
+                    crate::disk::ensure_room(dir, len, "recording")?;
+                }
+                std::fs::copy(video, &path)?;
+            }
+        }
+        (None, Some((start, end))) => review.recorder.save_trimmed(&path, start, end)?,
+        (None, None) => review.recorder.save(&path)?,
+    }
+    Ok(path)
 }
 
-fn set_tray_tooltip(app: &AppHandle, tooltip: &str) {
-    if let Some(tray) = app.tray_by_id("capscr-tray") {
-        let _ = tray.set_tooltip(Some(tooltip));
+fn finish_review(
+    app: &AppHandle,
+    review: crate::state::RecordingReview,
+    trim: Option<(Duration, Duration)>,
+) -> anyhow::Result<PathBuf> {
+    let cfg = app.state::<AppState>().config.lock().unwrap().clone();
+    match save_review(&review, &cfg, trim) {
+        Ok(path) => {
+            deliver_recording(
+                &review.task,
+                app,
+                &cfg,
+                &path,
+                review.audio_dropped,
+                review.stop_reason,
+            );
+            Ok(path)
+        }
+        Err(e) => {
+            let err_type = if review.video.is_some() {
+                "mp4-save"
+            } else {
+                "gif-save"
+            };
+            emit_error(app, err_type, &e.to_string());
+            Err(e)
+        }
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingReviewInfo {
+    // "gif" or "mp4"
+    pub format: &'static str,
+    // mp4: the staged file for the preview's <video>
+    pub video_path: Option<String>,
+    // gif: when each spooled frame was captured, in ms from the start
+    pub frame_times_ms: Vec<u64>,
+}
+
+// preview frames only need to fill the hub's modal, and a full-size 4k png per
+// scrub step would make the slider crawl
+const REVIEW_PREVIEW_MAX: u32 = 960;
+
+#[tauri::command]
+pub fn recording_review(state: State<AppState>) -> Option<RecordingReviewInfo> {
+    let guard = state.recording_review.lock().unwrap();
+    let review = guard.as_ref()?;
+    Some(RecordingReviewInfo {
+        format: if review.video.is_some() { "mp4" } else { "gif" },
+        video_path: review
+            .video
+            .as_ref()
+            .map(|p| p.to_string_lossy().into_owned()),
+        frame_times_ms: review
+            .recorder
+            .frame_times()
+            .iter()
+            .map(|t| t.as_millis() as u64)
+            .collect(),
+    })
+}
+
+// one spooled gif frame as a jpeg data url for the review scrubber
+#[tauri::command]
+pub async fn recording_review_frame(index: usize, app: AppHandle) -> Result<String, String> {
+    use base64::Engine;
+    tauri::async_runtime::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        let frame = {
+            let guard = state.recording_review.lock().unwrap();
+            let review = guard.as_ref().ok_or("no recording is waiting for review")?;
+            review
+                .recorder
+                .read_frame(index)
+                .map_err(|e| e.to_string())?
+        };
+        let mut preview = image::DynamicImage::ImageRgba8(frame);
+        if preview.width() > REVIEW_PREVIEW_MAX || preview.height() > REVIEW_PREVIEW_MAX {
+            preview = preview.resize(
+                REVIEW_PREVIEW_MAX,
+                REVIEW_PREVIEW_MAX,
+                image::imageops::FilterType::Triangle,
+            );
+        }
+        let mut jpeg = Vec::new();
+        image::codecs::jpeg::JpegEncoder::new_with_quality(&mut jpeg, 80)
+            .encode_image(&preview.to_rgb8())
+            .map_err(|e| e.to_string())?;
+        Ok(format!(
+            "data:image/jpeg;base64,{}",
+            base64::engine::general_purpose::STANDARD.encode(jpeg)
+        ))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+// save the recording under review. no in/out points keeps the whole thing,
+// which skips the re-encode an mp4 cut needs
+#[tauri::command]
+pub async fn finish_recording_review(
+    start_ms: Option<u64>,
+    end_ms: Option<u64>,
+    app: AppHandle,
+) -> Result<String, String> {
+    let review = app
+        .state::<AppState>()
+        .recording_review
+        .lock()
+        .unwrap()
+        .take()
+        .ok_or("no recording is waiting for review")?;
+    let trim = start_ms
+        .zip(end_ms)
+        .map(|(start, end)| (Duration::from_millis(start), Duration::from_millis(end)));
+    tauri::async_runtime::spawn_blocking(move || {
+        let cfg = app.state::<AppState>().config.lock().unwrap().clone();
+        match save_review(&review, &cfg, trim) {
+            Ok(path) => {
+                deliver_recording(
+                    &review.task,
+                    &app,
+                    &cfg,
+                    &path,
+                    review.audio_dropped,
+                    review.stop_reason,
+                );
+                Ok(path.to_string_lossy().into_owned())
+            }
+            Err(e) => {
+                // keep it reviewable so a failed cut can be retried or saved whole
+                let state = app.state::<AppState>();
+                let mut slot = state.recording_review.lock().unwrap();
+                if slot.is_none() {
+                    *slot = Some(review);
+                }
+                Err(e.to_string())
+            }
+        }
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+pub fn discard_recording_review(state: State<AppState>) {
+    // dropping the review removes the spool and any staged video
+    state.recording_review.lock().unwrap().take();
+}
+
 // trim an mp4 recording to [start_secs, start_secs + duration). `fast` stream-
 // copies (instant, but the start snaps to the nearest keyframe); otherwise the
 // clip is re-encoded for a frame-accurate cut. writes a new file next to the
@@ -2774,7 +4902,6 @@ fn trim_mp4_blocking(
     end_secs: f64,
     fast: bool,
 ) -> anyhow::Result<String> {
-    use std::path::Path;
     if path.contains("..") {
         anyhow::bail!("path contains directory traversal");
     }
@@ -2876,7 +5003,7 @@ fn apply_gif_post_action(
             let app2 = app.clone();
             let path = path.to_path_buf();
             let cfg = cfg.clone();
-            let target_override = task.target_destination;
+            let upload = UploadOverride::for_task(task);
             std::thread::spawn(move || {
                 let bytes = match std::fs::read(&path) {
                     Ok(b) => b,
@@ -2885,14 +5012,7 @@ fn apply_gif_post_action(
                         return;
                     }
                 };
-                let uploader = match crate::upload::shared_uploader() {
-                    Ok(u) => u,
-                    Err(e) => {
-                        emit_error(&app2, "upload", &e.to_string());
-                        return;
-                    }
-                };
-                let service = build_upload_service_for_target(&cfg, target_override);
+                let service = build_upload_service_for_target(&cfg, &upload);
                 let is_mp4 = path.extension().is_some_and(|ext| ext == "mp4");
                 let (mime, default_name) = if is_mp4 {
                     ("video/mp4", "capture.mp4")
@@ -2903,16 +5023,19 @@ fn apply_gif_post_action(
                     .file_name()
                     .and_then(|n| n.to_str())
                     .unwrap_or(default_name);
-                match uploader.upload_raw(&bytes, mime, file_name, &service) {
+                match upload_deduped(&app2, &cfg, &bytes, mime, file_name, &service) {
                     Ok(result) => {
                         let st = app2.state::<AppState>();
                         st.record_upload(UploadRecord {
                             url: result.url.clone(),
                             delete_url: result.delete_url.clone(),
                         });
-                        crate::rebuild_tray_menu(&app2);
+                        SystemTray::rebuild(&app2);
                         if cfg.upload.copy_url_to_clipboard {
-                            let _ = crate::upload::copy_url_to_clipboard(&result.url);
+                            let _ = crate::upload::copy_url_to_clipboard(
+                                &result.url,
+                                cfg.upload.link_format,
+                            );
                         }
                         Sound::Upload.play_if_enabled(cfg.post_capture.play_sound);
                         emit_upload_success(&app2, &result);
@@ -2938,15 +5061,39 @@ impl CaptureModeArg {
         match mode {
             TaskCaptureMode::Region => CaptureModeArg::Region,
             TaskCaptureMode::RegionLast => CaptureModeArg::RegionLast,
-            TaskCaptureMode::Window => CaptureModeArg::Window,
+            TaskCaptureMode::Window
+            | TaskCaptureMode::ActiveWindow
+            | TaskCaptureMode::TargetWindow => CaptureModeArg::Window,
             TaskCaptureMode::Fullscreen => CaptureModeArg::Fullscreen,
             TaskCaptureMode::ActiveMonitor => CaptureModeArg::ActiveMonitor,
-            TaskCaptureMode::RegionGif | TaskCaptureMode::RegionMp4 => CaptureModeArg::Region,
+            TaskCaptureMode::RegionGif
+            | TaskCaptureMode::RegionMp4
+            | TaskCaptureMode::RegionStream => CaptureModeArg::Region,
+        }
+    }
+
+    pub fn capture_type(self) -> CaptureType {
+        match self {
+            CaptureModeArg::Region | CaptureModeArg::RegionLast => CaptureType::Region,
+            CaptureModeArg::Window => CaptureType::Window,
+            CaptureModeArg::Fullscreen | CaptureModeArg::ActiveMonitor => CaptureType::FullScreen,
         }
     }
 }
 
 impl PostActionArg {
+    /// the configured action for a capture started without a task
+    pub fn configured_for(app: &AppHandle, mode: CaptureModeArg) -> Self {
+        let state = app.state::<AppState>();
+        let action = state
+            .config
+            .lock()
+            .unwrap()
+            .post_capture
+            .action_for(mode.capture_type());
+        Self::from_task_action(action)
+    }
+
     pub fn from_task_action(action: TaskPostAction) -> Self {
         match action {
             TaskPostAction::Clipboard => PostActionArg::Clipboard,
@@ -2977,6 +5124,18 @@ pub fn emit_error(app: &AppHandle, kind: &str, msg: &str) {
     );
 }
 
+// saves waiting on or running in the encode pool, for the hub's status bar on
+// first paint; capscr://encoding-progress carries every change after that
+#[tauri::command]
+pub fn encoding_progress() -> crate::encode::EncodingProgress {
+    crate::encode::progress()
+}
+
+#[tauri::command]
+pub fn cancel_pending_encodes() -> usize {
+    crate::encode::cancel_pending()
+}
+
 // single funnel for "a capture file was written": notifies the History tab and
 // fires the plugin on_capture_saved hook. every save path routes through here so
 // the hook can't silently miss a save site
@@ -2989,6 +5148,47 @@ pub fn notify_capture_saved(app: &AppHandle, path: &std::path::Path) {
     });
 }
 
+// the "saved" toast for a file the user asked for, with buttons to open it
+// or reveal it. history copies and auto-capture frames don't go through here.
+// the preview comes from the thumbnail cache, so the history grid reuses it
+fn notify_saved_file(app: &AppHandle, title: &str, path: &std::path::Path) {
+    // only the freedesktop backend shows a notification image
+    let preview = if cfg!(target_os = "linux") {
+        crate::thumbs::dir(app)
+            .and_then(|dir| crate::thumbs::for_file(&dir, path, crate::thumbs::GRID_WIDTH))
+            .ok()
+    } else {
+        None
+    };
+    let app = app.clone();
+    let target = path.to_path_buf();
+    let _ = crate::clipboard::show_notification_with_actions(
+        title,
+        &path.to_string_lossy(),
+        preview.as_deref(),
+        &[
+            ("default", t("notify.action.open")),
+            ("reveal", t("notify.action.reveal")),
+        ],
+        move |action| match action {
+            "reveal" => reveal_in_file_manager(&app, &target),
+            _ => open_saved_file(&target),
+        },
+    );
+}
+
+pub(crate) fn open_saved_file(path: &std::path::Path) {
+    if let Err(e) = open_in_default_image_editor(path) {
+        tracing::warn!("opening {path:?} failed: {e}");
+    }
+}
+
+/// the most recent capture written to disk, if it's still there
+pub(crate) fn last_saved_capture(app: &AppHandle) -> Option<PathBuf> {
+    let path = app.state::<AppState>().last_save.lock().unwrap().clone()?;
+    path.is_file().then_some(path)
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct UploadSuccessPayload {
     pub url: String,
@@ -3005,9 +5205,12 @@ pub fn emit_upload_success(app: &AppHandle, result: &crate::upload::UploadResult
     );
     let state = app.state::<AppState>();
     let pm = state.plugin_manager.read().unwrap();
-    let _ = pm.dispatch(&PluginEvent::PostUpload {
-        url: result.url.clone(),
-    });
+    // the email hand-off has no url for plugins to act on
+    if !result.url.is_empty() {
+        let _ = pm.dispatch(&PluginEvent::PostUpload {
+            url: result.url.clone(),
+        });
+    }
 }
 
 #[tauri::command]
@@ -3153,9 +5356,42 @@ mod ocr_locale_tests {
             Vec::<&str>::new()
         );
         assert_eq!(
-            desired_tess_langs(None, None, Some("uk_UA.KOI8-U")),
-            vec!["ukr"]
+            desired_tess_langs(None, None, Some("uk_UA.KOI8-U")),
+            vec!["ukr"]
+        );
+    }
+}
+
+#[cfg(test)]
+mod hub_position_tests {
+    use super::clamp_to_monitors;
+
+    const SIDE_BY_SIDE: [(i32, i32, u32, u32); 2] = [(0, 0, 1920, 1080), (1920, 0, 2560, 1440)];
+
+    #[test]
+    fn a_position_on_screen_is_kept() {
+        assert_eq!(
+            clamp_to_monitors((2200, 300), (900, 640), &SIDE_BY_SIDE),
+            Some((2200, 300))
+        );
+    }
+
+    #[test]
+    fn a_window_hanging_off_an_edge_is_pulled_back() {
+        assert_eq!(
+            clamp_to_monitors((1500, 900), (900, 640), &SIDE_BY_SIDE[..1]),
+            Some((1020, 440))
+        );
+    }
+
+    #[test]
+    fn an_unplugged_monitor_falls_back_to_the_nearest() {
+        // saved on a monitor left of the primary that's since gone
+        assert_eq!(
+            clamp_to_monitors((-1800, 200), (900, 640), &SIDE_BY_SIDE),
+            Some((0, 200))
         );
+        assert_eq!(clamp_to_monitors((0, 0), (900, 640), &[]), None);
     }
 }
 
@@ -3419,6 +5655,26 @@ pub fn dismiss_tray_hint(app: AppHandle, state: State<AppState>) -> Result<(), S
     Ok(())
 }
 
+// a color picked in the editor goes to the front of its recent row; returns
+// the row as it now is
+#[tauri::command]
+pub fn remember_editor_color(
+    app: AppHandle,
+    state: State<AppState>,
+    color: String,
+) -> Result<Vec<String>, String> {
+    let recent = {
+        let mut cfg = state.config.lock().unwrap();
+        if !cfg.ui.remember_editor_color(&color) {
+            return Err(format!("not a #rrggbb color: {color}"));
+        }
+        cfg.ui.editor_recent_colors.clone()
+    };
+    state.save_config_soon(&app);
+    let _ = app.emit("capscr://config-updated", ());
+    Ok(recent)
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct EvdevStatus {
     pub enabled: bool,
@@ -3555,8 +5811,15 @@ pub fn portal_rebind_shortcuts(app: AppHandle, state: State<AppState>) -> Result
     {
         crate::hotkeys::portal_linux::rebind().map_err(|e| format!("{e:#}"))?;
         // re-flush so statuses reflect the fresh outcome
-        let tasks = state.config.lock().unwrap().capture_tasks.clone();
-        state.send_hotkey_reload(tasks);
+        let (tasks, stop_recording, do_not_disturb) = {
+            let cfg = state.config.lock().unwrap();
+            (
+                cfg.capture_tasks.clone(),
+                cfg.hotkeys.stop_recording.clone(),
+                cfg.hotkeys.do_not_disturb.clone(),
+            )
+        };
+        state.send_hotkey_reload(tasks, stop_recording, do_not_disturb);
         let _ = app.emit("capscr://hotkey-status", ());
         Ok(())
     }
@@ -3655,6 +5918,27 @@ pub struct SftpKnownHost {
     pub first_seen_unix: u64,
 }
 
+// enough of the log for the settings viewer to show the recent failures
+// without shipping megabytes over ipc
+const LOG_TAIL_BYTES: u64 = 64 * 1024;
+
+#[tauri::command]
+pub fn read_log_tail() -> Result<String, String> {
+    let dir = crate::logfile::dir().ok_or("config dir unresolvable")?;
+    match crate::logfile::tail(&crate::logfile::current_path(&dir), LOG_TAIL_BYTES) {
+        Ok(text) => Ok(text),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+pub fn reveal_log_file(app: AppHandle) -> Result<(), String> {
+    let dir = crate::logfile::dir().ok_or("config dir unresolvable")?;
+    reveal_in_file_manager(&app, &crate::logfile::current_path(&dir));
+    Ok(())
+}
+
 #[tauri::command]
 pub fn sftp_known_hosts() -> Result<Vec<SftpKnownHost>, String> {
     let path = crate::upload::known_hosts::KnownHosts::default_path()
@@ -3681,22 +5965,19 @@ pub struct ConnectionTestReport {
 }
 
 // invoke wrapper around trigger_task so the hub UI can dry-run a task
-// without the user needing to press its hotkey. hides the hub window first
-// because a region/window capture overlay launched from a focused hub paints
-// over its own selector and looks broken. tray-driven captures already get
-// this for free via the existing hub.hide path; this matches the behaviour.
+// without the user needing to press its hotkey. the task's capture hides the
+// hub (HubHidden) and brings it back when done, so a dry run lands the user
+// back where they started
 #[tauri::command]
 pub fn fire_task(task_id: String, app: AppHandle) -> Result<(), String> {
-    if let Some(hub) = app.get_webview_window(HUB_LABEL) {
-        let _ = hub.hide();
-    }
-    trigger_task(&app, &task_id);
+    fire_task_by_id(&app, &task_id);
     Ok(())
 }
 
 #[tauri::command]
 pub fn test_upload_connection(
     destination: String,
+    preset: Option<String>,
     state: State<AppState>,
 ) -> Result<ConnectionTestReport, String> {
     let cfg = state.config.lock().unwrap().clone();
@@ -3729,12 +6010,7 @@ pub fn test_upload_connection(
         "Imgur" | "imgur" => crate::upload::test_connection_imgur(&cfg.upload.imgur_client_id)
             .map_err(|e| e.to_string())?,
         "Custom" | "custom" => {
-            let uploader = crate::upload::CustomUploader {
-                name: "Custom".to_string(),
-                request_url: cfg.upload.custom_url.clone(),
-                file_form_name: cfg.upload.custom_form_name.clone(),
-                response_url_path: cfg.upload.custom_response_path.clone(),
-            };
+            let uploader = build_custom_uploader(&cfg, preset.as_deref());
             crate::upload::test_connection_custom(&uploader).map_err(|e| e.to_string())?
         }
         "S3" | "s3" => {
@@ -3748,6 +6024,10 @@ pub fn test_upload_connection(
             };
             crate::upload::test_connection_s3(&target).map_err(|e| e.to_string())?
         }
+        "Webdav" | "webdav" => {
+            crate::upload::webdav::test_connection_webdav(&build_webdav_target(&cfg))
+                .map_err(|e| e.to_string())?
+        }
         other => return Err(format!("'{other}' has no test-connection probe")),
     };
     let overall_ok = !steps.is_empty() && steps.iter().all(|s| s.ok);
@@ -3770,6 +6050,139 @@ pub fn sftp_forget_host(host_port: String) -> Result<bool, String> {
     Ok(removed)
 }
 
+// read a ShareX .sxcu the user picked. the result only fills the Destinations
+// form; nothing is persisted until they save
+#[tauri::command]
+pub fn import_sxcu(path: String) -> Result<crate::upload::sxcu::SxcuImport, String> {
+    let path = std::path::PathBuf::from(path);
+    let is_sxcu = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("sxcu") || e.eq_ignore_ascii_case("json"));
+    if !is_sxcu {
+        return Err("pick a .sxcu file".to_string());
+    }
+    let meta = std::fs::metadata(&path).map_err(|e| e.to_string())?;
+    if !meta.is_file() || meta.len() > crate::upload::sxcu::MAX_SXCU_SIZE {
+        return Err("not an uploader definition (too large or not a file)".to_string());
+    }
+    let text = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    crate::upload::sxcu::parse_sxcu(&text).map_err(|e| e.to_string())
+}
+
+// imgur account sign-in (PIN grant). the UI opens this url, the user approves
+// capscr and pastes back the PIN imgur shows
+#[tauri::command]
+pub fn imgur_authorize_url(state: State<AppState>) -> Result<String, String> {
+    let cfg = state.config.lock().unwrap().clone();
+    crate::upload::imgur::authorize_url(&cfg.upload.imgur_client_id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn imgur_sign_in(
+    pin: String,
+    app: AppHandle,
+    state: State<AppState>,
+) -> Result<String, String> {
+    let cfg = state.config.lock().unwrap().clone();
+    let tokens = crate::upload::imgur::exchange_pin(
+        &cfg.upload.imgur_client_id,
+        &cfg.upload.imgur.client_secret_plaintext(),
+        &pin,
+    )
+    .map_err(|e| e.to_string())?;
+    let blob = crate::secret::encrypt(&tokens.refresh_token).map_err(|e| e.to_string())?;
+    {
+        let mut cfg = state.config.lock().unwrap();
+        cfg.upload.imgur.refresh_token_encrypted = blob;
+        cfg.upload.imgur.username = tokens.account_username.clone();
+        cfg.save().map_err(|e| e.to_string())?;
+    }
+    let _ = app.emit("capscr://config-updated", ());
+    Ok(tokens.account_username)
+}
+
+#[tauri::command]
+pub fn imgur_sign_out(app: AppHandle, state: State<AppState>) -> Result<(), String> {
+    {
+        let mut cfg = state.config.lock().unwrap();
+        cfg.upload.imgur.refresh_token_encrypted.clear();
+        cfg.upload.imgur.username.clear();
+        cfg.upload.imgur.album_id.clear();
+        cfg.save().map_err(|e| e.to_string())?;
+    }
+    crate::upload::imgur::forget_cached_token();
+    let _ = app.emit("capscr://config-updated", ());
+    Ok(())
+}
+
+#[tauri::command]
+pub fn imgur_albums(
+    state: State<AppState>,
+) -> Result<Vec<crate::upload::imgur::ImgurAlbum>, String> {
+    let cfg = state.config.lock().unwrap().clone();
+    let account = imgur_account(&cfg).ok_or_else(|| "not signed in to Imgur".to_string())?;
+    crate::upload::imgur::list_albums(&account).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn imgur_create_album(
+    title: String,
+    state: State<AppState>,
+) -> Result<crate::upload::imgur::ImgurAlbum, String> {
+    let cfg = state.config.lock().unwrap().clone();
+    let account = imgur_account(&cfg).ok_or_else(|| "not signed in to Imgur".to_string())?;
+    let title = title.trim();
+    if title.is_empty() || title.len() > 255 {
+        return Err("album title must be 1-255 bytes".to_string());
+    }
+    crate::upload::imgur::create_album(&account, title, cfg.upload.imgur.album_privacy.api_value())
+        .map_err(|e| e.to_string())
+}
+
+// dropbox sign-in (code grant with PKCE). same shape as imgur's: the UI opens
+// this url, the user approves capscr and pastes back the code dropbox shows
+#[tauri::command]
+pub fn dropbox_authorize_url(state: State<AppState>) -> Result<String, String> {
+    let cfg = state.config.lock().unwrap().clone();
+    crate::upload::dropbox::authorize_url(&cfg.upload.dropbox.app_key).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn dropbox_sign_in(
+    code: String,
+    app: AppHandle,
+    state: State<AppState>,
+) -> Result<String, String> {
+    let cfg = state.config.lock().unwrap().clone();
+    let tokens = crate::upload::dropbox::exchange_code(&cfg.upload.dropbox.app_key, &code)
+        .map_err(|e| e.to_string())?;
+    // the name is only cosmetic; a failed lookup still leaves a working sign-in
+    let name = crate::upload::dropbox::account_name(&tokens.access_token).unwrap_or_default();
+    let blob = crate::secret::encrypt(&tokens.refresh_token).map_err(|e| e.to_string())?;
+    {
+        let mut cfg = state.config.lock().unwrap();
+        cfg.upload.dropbox.refresh_token_encrypted = blob;
+        cfg.upload.dropbox.account_name = name.clone();
+        cfg.save().map_err(|e| e.to_string())?;
+    }
+    let _ = app.emit("capscr://config-updated", ());
+    Ok(name)
+}
+
+#[tauri::command]
+pub fn dropbox_sign_out(app: AppHandle, state: State<AppState>) -> Result<(), String> {
+    {
+        let mut cfg = state.config.lock().unwrap();
+        cfg.upload.dropbox.refresh_token_encrypted.clear();
+        cfg.upload.dropbox.account_name.clear();
+        cfg.save().map_err(|e| e.to_string())?;
+    }
+    crate::upload::dropbox::forget_cached_token();
+    let _ = app.emit("capscr://config-updated", ());
+    Ok(())
+}
+
 /// Arm the LL hook to capture the next non-modifier keydown as a hotkey.
 /// On press, the backend emits `capscr://hotkey-captured` with the vk +
 /// mods + canonical hotkey string and clears the arm. UI cancels via
@@ -3814,24 +6227,24 @@ pub fn set_hotkeys_disabled(
         return Ok(());
     }
     // persist into config so the toggle survives restart
-    {
-        let mut cfg = state.config.lock().unwrap();
-        cfg.hotkeys.disabled_globally = disabled;
-        if let Err(e) = cfg.save() {
-            tracing::warn!("hotkeys_disabled persist failed: {e}");
-        }
-    }
+    state.config.lock().unwrap().hotkeys.disabled_globally = disabled;
+    state.save_config_soon(&app);
     #[cfg(windows)]
     crate::hotkeys::ll_hook::set_enabled(!disabled);
     // re-emit reload so the manager status reflects the new state. when
     // disabled we send an empty Vec; when re-enabled we send the live tasks.
-    let tasks = if disabled {
-        Vec::new()
+    let (tasks, stop_recording, do_not_disturb) = if disabled {
+        (Vec::new(), String::new(), String::new())
     } else {
-        state.config.lock().unwrap().capture_tasks.clone()
+        let cfg = state.config.lock().unwrap();
+        (
+            cfg.capture_tasks.clone(),
+            cfg.hotkeys.stop_recording.clone(),
+            cfg.hotkeys.do_not_disturb.clone(),
+        )
     };
-    state.send_hotkey_reload(tasks);
-    crate::rebuild_tray_menu(&app);
+    state.send_hotkey_reload(tasks, stop_recording, do_not_disturb);
+    SystemTray::rebuild(&app);
     let _ = app.emit("capscr://hotkey-status", ());
     // the store mirrors hotkeys.disabled_globally too; nudge it to refetch so a
     // later Settings save doesn't persist a stale (enabled) value over the switch
@@ -3839,6 +6252,176 @@ pub fn set_hotkeys_disabled(
     Ok(())
 }
 
+#[tauri::command]
+pub fn do_not_disturb_status() -> crate::dnd::DndStatus {
+    crate::dnd::status()
+}
+
+// `minutes` None or 0 keeps it on until it's turned off
+#[tauri::command]
+pub fn set_do_not_disturb(enabled: bool, minutes: Option<u32>, app: AppHandle) {
+    if enabled {
+        crate::dnd::enable(&app, minutes);
+    } else {
+        crate::dnd::disable(&app);
+    }
+}
+
+#[tauri::command]
+pub fn game_mode_status() -> crate::game_mode::GameModeStatus {
+    crate::game_mode::status()
+}
+
+#[tauri::command]
+pub fn auto_capture_running(app: AppHandle) -> bool {
+    crate::auto_capture::is_running(&app)
+}
+
+#[tauri::command]
+pub fn set_auto_capture_running(running: bool, app: AppHandle) -> Result<(), String> {
+    if running {
+        crate::auto_capture::start(&app).map_err(|e| e.to_string())
+    } else {
+        crate::auto_capture::stop(&app);
+        Ok(())
+    }
+}
+
+// assemble a folder of captures into a time-lapse in the output directory, one
+// capture per frame at `fps`. no folder means the auto capture folder. returns
+// the written file's path
+#[tauri::command]
+pub async fn assemble_timelapse(
+    dir: Option<String>,
+    format: String,
+    fps: u32,
+    max_width: u32,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    use crate::recording::RecordingFormat;
+    let config = state.config.lock().unwrap().clone();
+    let auto_dir = config.auto_capture.resolved_directory(&config.output);
+    let dir = dir.map(PathBuf::from).unwrap_or_else(|| auto_dir.clone());
+    let canonical = std::fs::canonicalize(&dir).map_err(|e| e.to_string())?;
+    let in_auto_dir =
+        std::fs::canonicalize(&auto_dir).is_ok_and(|auto_dir| canonical.starts_with(auto_dir));
+    if !in_auto_dir && !is_path_allowed(&canonical, &config) {
+        return Err("Folder is outside the allowed directories".into());
+    }
+    let (format, ext) = match format.as_str() {
+        "gif" => (RecordingFormat::Gif, "gif"),
+        "mp4" => (RecordingFormat::Mp4, "mp4"),
+        other => return Err(format!("unsupported time-lapse format: {other}")),
+    };
+    if format == RecordingFormat::Mp4 && !crate::recording::is_ffmpeg_available() {
+        return Err("MP4 time-lapses need ffmpeg; record one MP4 first to fetch it".into());
+    }
+    let options = crate::recording::TimelapseOptions {
+        format,
+        fps,
+        max_width,
+        quality: config.output.quality,
+        video_crf: config.capture.video_quality.crf(),
+    };
+    let name = chrono::Local::now().format("timelapse_%Y%m%d_%H%M%S");
+    let dest = crate::clipboard::get_unique_filepath(
+        &config.output.directory.join(format!("{name}.{ext}")),
+    );
+    let written = tauri::async_runtime::spawn_blocking(move || -> anyhow::Result<PathBuf> {
+        let frames = crate::recording::timelapse_frames(&canonical)?;
+        if let Err(e) = crate::recording::assemble_timelapse(&frames, &dest, options) {
+            // get_unique_filepath claimed the name with an empty file
+            let _ = std::fs::remove_file(&dest);
+            return Err(e);
+        }
+        Ok(dest)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| format!("{e:#}"))?;
+    notify_capture_saved(&app, &written);
+    Ok(written.to_string_lossy().into_owned())
+}
+
+/// transcode a gif into a video or a recording into a gif, written to the
+/// output directory under the source's name. shared by the tray's Tools menu
+/// and `capscr --convert`
+pub(crate) fn convert_recording_file(
+    source: &Path,
+    config: &Config,
+    options: crate::recording::ConvertOptions,
+) -> anyhow::Result<PathBuf> {
+    if !crate::recording::is_ffmpeg_available() {
+        anyhow::bail!("converting needs ffmpeg; record one MP4 first to fetch it");
+    }
+    let stem = source
+        .file_stem()
+        .map_or_else(|| "recording".into(), |s| s.to_string_lossy());
+    std::fs::create_dir_all(&config.output.directory)?;
+    let dest = crate::clipboard::get_unique_filepath(
+        &config
+            .output
+            .directory
+            .join(format!("{stem}.{}", options.target.extension())),
+    );
+    if let Err(e) = crate::recording::convert_media(source, &dest, options) {
+        // get_unique_filepath claimed the name with an empty file
+        let _ = std::fs::remove_file(&dest);
+        return Err(e);
+    }
+    Ok(dest)
+}
+
+/// the tray's Tools entries: pick a file, convert it with the recording
+/// settings' frame rate and quality, and announce it like a saved capture.
+/// blocks on the file dialog, so call it off the main thread
+pub(crate) fn convert_from_tray(app: &AppHandle, target: crate::recording::ConvertTarget) {
+    use crate::recording::{ConvertOptions, ConvertTarget};
+    use tauri_plugin_dialog::DialogExt;
+    let config = app.state::<AppState>().config.lock().unwrap().clone();
+    let (title, filter, extensions): (&str, &str, &[&str]) = match target {
+        ConvertTarget::Gif => (
+            t("dialog.convert.to-gif"),
+            t("dialog.videos"),
+            &["mp4", "webm", "mov", "mkv"],
+        ),
+        ConvertTarget::Mp4 | ConvertTarget::Webm => (t("dialog.convert.to-video"), "GIF", &["gif"]),
+    };
+    let Some(picked) = app
+        .dialog()
+        .file()
+        .set_title(title)
+        .set_directory(&config.output.directory)
+        .add_filter(filter, extensions)
+        .blocking_pick_file()
+    else {
+        return;
+    };
+    let options = ConvertOptions {
+        target,
+        // a video keeps its own rate; a gif is thinned to the recording rate
+        fps: if target == ConvertTarget::Gif {
+            config.capture.gif_fps
+        } else {
+            0
+        },
+        max_width: 0,
+        video_crf: config.capture.video_quality.crf(),
+    };
+    let result = picked
+        .into_path()
+        .map_err(|e| anyhow::anyhow!("{e}"))
+        .and_then(|source| convert_recording_file(&source, &config, options));
+    match result {
+        Ok(path) => notify_capture_saved(app, &path),
+        Err(e) => {
+            tracing::warn!("convert failed: {e:#}");
+            emit_error(app, "convert", &format!("{e:#}"));
+        }
+    }
+}
+
 #[tauri::command]
 pub fn run_ocr(path: String, state: State<AppState>) -> Result<String, String> {
     let config = state.config.lock().unwrap().clone();
@@ -4044,7 +6627,7 @@ fn ocr_image_bytes(_image_bytes: &[u8]) -> anyhow::Result<String> {
 /// static grid thumbnail for the history view. full-size animated gifs
 /// dropped straight into <img> tags decode to gigabytes across a grid, and
 /// files outside the asset-protocol scope render blank; a cached first-frame
-/// jpeg in the app cache dir solves both. keyed by path + size + mtime.
+/// jpeg from the thumbnail cache solves both
 #[tauri::command]
 pub async fn history_thumbnail(
     path: String,
@@ -4056,65 +6639,212 @@ pub async fn history_thumbnail(
     if !is_path_allowed(&canonical, &config) {
         return Err("Path is outside the allowed directories".into());
     }
+    let cache_dir = crate::thumbs::dir(&app).map_err(|e| e.to_string())?;
+    tokio::task::spawn_blocking(move || {
+        crate::thumbs::for_file(&cache_dir, &canonical, crate::thumbs::GRID_WIDTH)
+            .map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map(|thumb| thumb.to_string_lossy().to_string())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptureComparison {
+    /// the diff heatmap png in the app cache dir, overwritten by the next
+    /// comparison
+    pub heatmap: String,
+    pub width: u32,
+    pub height: u32,
+    pub changed: u64,
+    pub total: u64,
+}
+
+/// pixel diff of two history captures for the compare tool. pixels whose
+/// channels all moved by at most `tolerance` count as unchanged
+#[tauri::command]
+pub async fn compare_captures(
+    a: String,
+    b: String,
+    tolerance: u8,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<CaptureComparison, String> {
+    let config = state.config.lock().unwrap().clone();
+    let mut paths = Vec::with_capacity(2);
+    for path in [&a, &b] {
+        let canonical = std::fs::canonicalize(path).map_err(|e| e.to_string())?;
+        if !is_path_allowed(&canonical, &config) {
+            return Err("Path is outside the allowed directories".into());
+        }
+        paths.push(canonical);
+    }
     let cache_dir = app
         .path()
         .app_cache_dir()
         .map_err(|e| e.to_string())?
-        .join("thumbs");
-    tokio::task::spawn_blocking(move || {
-        thumbnail_for(&cache_dir, &canonical).map_err(|e| e.to_string())
+        .join("compare");
+    tokio::task::spawn_blocking(move || -> anyhow::Result<CaptureComparison> {
+        let a = image::open(&paths[0])?.into_rgba8();
+        let b = image::open(&paths[1])?.into_rgba8();
+        let diff = crate::diff::compare(&a, &b, tolerance);
+        std::fs::create_dir_all(&cache_dir)?;
+        let heatmap = cache_dir.join("diff.png");
+        diff.heatmap.save(&heatmap)?;
+        Ok(CaptureComparison {
+            heatmap: heatmap.to_string_lossy().into_owned(),
+            width: diff.heatmap.width(),
+            height: diff.heatmap.height(),
+            changed: diff.changed,
+            total: diff.total,
+        })
     })
     .await
     .map_err(|e| e.to_string())?
-    .map(|thumb| thumb.to_string_lossy().to_string())
+    .map_err(|e| format!("{e:#}"))
 }
 
-fn thumbnail_for(cache_dir: &std::path::Path, path: &std::path::Path) -> anyhow::Result<PathBuf> {
-    use std::hash::{Hash, Hasher};
-    const THUMB_WIDTH: u32 = 480;
-    let meta = std::fs::metadata(path)?;
-    let mut hasher = std::collections::hash_map::DefaultHasher::new();
-    path.hash(&mut hasher);
-    meta.len().hash(&mut hasher);
-    if let Ok(modified) = meta.modified() {
-        if let Ok(age) = modified.duration_since(std::time::UNIX_EPOCH) {
-            age.as_secs().hash(&mut hasher);
+/// join the selected history captures, in selection order, into one image
+/// saved as a new capture. returns its path
+#[tauri::command]
+pub async fn stitch_captures(
+    paths: Vec<String>,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    if paths.len() > crate::stitch::MAX_IMAGES {
+        return Err(format!(
+            "pick at most {} captures to stitch",
+            crate::stitch::MAX_IMAGES
+        ));
+    }
+    let config = state.config.lock().unwrap().clone();
+    let mut sources = Vec::with_capacity(paths.len());
+    for path in &paths {
+        let canonical = std::fs::canonicalize(path).map_err(|e| e.to_string())?;
+        if !is_path_allowed(&canonical, &config) {
+            return Err("Path is outside the allowed directories".into());
+        }
+        sources.push(canonical);
+    }
+    let written = tokio::task::spawn_blocking(move || -> anyhow::Result<PathBuf> {
+        let images = sources
+            .iter()
+            .map(|path| Ok(image::open(path)?.into_rgba8()))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let (stitched, _) = crate::stitch::stitch(&images)?;
+        let name = chrono::Local::now().format("stitch_%Y%m%d_%H%M%S");
+        let dest = get_unique_filepath(
+            &config
+                .output
+                .directory
+                .join(format!("{name}.{}", config.output.format.extension())),
+        );
+        if let Err(e) = save_image(
+            &stitched,
+            &dest,
+            config.output.format,
+            config.output.quality,
+        ) {
+            // get_unique_filepath claimed the name with an empty file
+            let _ = std::fs::remove_file(&dest);
+            return Err(e);
         }
+        Ok(dest)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| format!("{e:#}"))?;
+    notify_capture_saved(&app, &written);
+    Ok(written.to_string_lossy().into_owned())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FormatSizeEstimate {
+    pub format: crate::config::ImageFormat,
+    pub extension: &'static str,
+    pub bytes: u64,
+}
+
+// per-format file size for the editor footer, at the configured quality, so
+// the user can see what a save would cost before picking a format
+#[tauri::command]
+pub async fn estimate_capture_sizes(
+    path: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<FormatSizeEstimate>, String> {
+    let config = state.config.lock().unwrap().clone();
+    let canonical = std::fs::canonicalize(&path).map_err(|e| e.to_string())?;
+    if !is_path_allowed(&canonical, &config) {
+        return Err("Path is outside the allowed directories".into());
     }
-    std::fs::create_dir_all(cache_dir)?;
-    let thumb = cache_dir.join(format!("{:016x}.jpg", hasher.finish()));
-    if thumb.exists() {
-        return Ok(thumb);
+    let quality = config.output.quality;
+    tokio::task::spawn_blocking(move || -> anyhow::Result<Vec<FormatSizeEstimate>> {
+        let image = image::open(&canonical)?.into_rgba8();
+        Ok(crate::clipboard::estimate_encoded_sizes(&image, quality)
+            .into_iter()
+            .map(|(format, bytes)| FormatSizeEstimate {
+                format,
+                extension: format.extension(),
+                bytes,
+            })
+            .collect())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())
+}
+
+/// open windows for the target-window task picker, grouped by app. empty on
+/// wayland, where windows can't be enumerated
+#[tauri::command]
+pub async fn list_open_windows() -> Result<Vec<crate::capture::WindowInfo>, String> {
+    #[cfg(target_os = "linux")]
+    if crate::capture::is_wayland_session() {
+        return Ok(Vec::new());
     }
-    let ext = path
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_ascii_lowercase();
-    let frame: RgbaImage = if ext == "gif" {
-        // first frame only; the grid shows a still, the viewer animates
-        use image::AnimationDecoder;
-        let file = std::io::BufReader::new(std::fs::File::open(path)?);
-        let decoder = image::codecs::gif::GifDecoder::new(file)?;
-        decoder
-            .into_frames()
-            .next()
-            .ok_or_else(|| anyhow::anyhow!("gif has no frames"))??
-            .into_buffer()
-    } else {
-        image::open(path)?.to_rgba8()
-    };
-    let scale = (THUMB_WIDTH as f32 / frame.width() as f32).min(1.0);
-    let width = ((frame.width() as f32 * scale) as u32).max(1);
-    let height = ((frame.height() as f32 * scale) as u32).max(1);
-    let small = image::imageops::thumbnail(&frame, width, height);
-    let rgb = image::DynamicImage::ImageRgba8(small).to_rgb8();
-    // atomic-ish: encode to a temp name then rename, so a torn write never
-    // caches as a valid thumb
-    let staging = thumb.with_extension("jpg.tmp");
-    rgb.save_with_format(&staging, image::ImageFormat::Jpeg)?;
-    std::fs::rename(&staging, &thumb)?;
-    Ok(thumb)
+    tokio::task::spawn_blocking(WindowCapture::list_application_windows)
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+// live preview for one picker row. one cache entry per window id, replaced
+// on every call; the frontend cache-busts the url so a refresh shows new pixels
+#[tauri::command]
+pub async fn window_thumbnail(id: u32, app: AppHandle) -> Result<String, String> {
+    use crate::thumbs::PREVIEW_WIDTH;
+    let cache_dir = crate::thumbs::dir(&app).map_err(|e| e.to_string())?;
+    tokio::task::spawn_blocking(move || -> anyhow::Result<PathBuf> {
+        let small = WindowCapture::new(id).preview(PREVIEW_WIDTH)?;
+        crate::thumbs::store(&cache_dir, &format!("window-{id}"), &small, PREVIEW_WIDTH)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map(|thumb| thumb.to_string_lossy().to_string())
+    .map_err(|e| e.to_string())
+}
+
+/// composite live previews of the picker's rows into `window` where its
+/// thumbnail boxes are; an empty `slots` clears them. false when the platform
+/// can't (anything but windows), and the picker grabs one-shot previews instead
+#[tauri::command]
+pub fn set_live_window_thumbnails(
+    window: tauri::WebviewWindow,
+    slots: Vec<crate::capture::live_thumb::ThumbSlot>,
+) -> Result<bool, String> {
+    use crate::capture::live_thumb;
+    if !live_thumb::supported() {
+        return Ok(false);
+    }
+    #[cfg(windows)]
+    {
+        let hwnd = window.hwnd().map_err(|e| e.to_string())?;
+        live_thumb::show(hwnd.0 as isize, &slots).map_err(|e| e.to_string())?;
+    }
+    #[cfg(not(windows))]
+    let _ = (window, slots);
+    Ok(true)
 }
 
 /// OCR a freshly captured image by encoding it to PNG in memory first
@@ -4346,3 +7076,48 @@ fn watch_pin_navigation(app: &AppHandle, window: tauri::WebviewWindow) {
 pub fn get_pinned_image_path(label: String, state: State<'_, AppState>) -> Option<String> {
     state.pinned_images.lock().unwrap().get(&label).cloned()
 }
+
+#[cfg(test)]
+mod editor_project_tests {
+    use super::editor_project_path;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn project_sits_beside_the_capture_under_its_file_name() {
+        assert_eq!(
+            editor_project_path(Path::new("/caps/capture_20260101_120000.png")),
+            Some(PathBuf::from(
+                "/caps/capture_20260101_120000.png.capscr.json"
+            ))
+        );
+        // the same stem in another format is another capture
+        assert_ne!(
+            editor_project_path(Path::new("/caps/shot.png")),
+            editor_project_path(Path::new("/caps/shot.jpg"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod batch_upload_tests {
+    use super::join_links;
+    use crate::config::LinkFormat;
+
+    #[test]
+    fn batch_links_join_one_per_line() {
+        let urls = vec![
+            "https://h.example/a.png".to_string(),
+            String::new(),
+            "https://h.example/b c.png".into(),
+        ];
+        assert_eq!(
+            join_links(&urls, LinkFormat::Url),
+            "https://h.example/a.png\nhttps://h.example/b c.png"
+        );
+        assert_eq!(
+            join_links(&urls, LinkFormat::Markdown),
+            "![](https://h.example/a.png)\n![](https://h.example/b%20c.png)"
+        );
+        assert_eq!(join_links(&[], LinkFormat::Url), "");
+    }
+}
@@ -0,0 +1,98 @@
+// config.toml records the schema version it was written under. an older file
+// is brought up to date one step at a time on the raw toml, before the typed
+// parse, so a renamed or restructured setting is carried over instead of
+// being ignored as unknown and quietly replaced by its default.
+
+use toml::{Table, Value};
+
+/// the schema this build writes. bump it together with a new step in STEPS
+pub const CURRENT: u32 = 1;
+
+// STEPS[n] lifts a version-n table to version n + 1
+const STEPS: [fn(&mut Table); CURRENT as usize] = [v0_to_v1];
+
+// the flat custom uploader fields from before presets, and where each one
+// lives in a preset
+const LEGACY_CUSTOM_UPLOADER: [(&str, &str); 7] = [
+    ("custom_url", "url"),
+    ("custom_form_name", "form_name"),
+    ("custom_response_path", "response_path"),
+    ("custom_method", "method"),
+    ("custom_response_regex", "response_regex"),
+    ("custom_headers", "headers"),
+    ("custom_form_fields", "form_fields"),
+];
+
+/// upgrade `table` in place and return the version it was written under. a
+/// file from a newer capscr is left alone; whatever this build doesn't know
+/// is ignored by the parse
+pub fn run(table: &mut Table) -> u32 {
+    let from = table
+        .get("version")
+        .and_then(Value::as_integer)
+        .map_or(0, |v| v.clamp(0, u32::MAX as i64) as u32);
+    for step in STEPS.iter().skip(from as usize) {
+        step(table);
+    }
+    if from < CURRENT {
+        table.insert("version".into(), Value::Integer(CURRENT as i64));
+    }
+    from
+}
+
+// 0 -> 1, everything written before configs were versioned:
+// - the single custom uploader becomes the first entry of
+//   `upload.custom_presets`, unless there are presets already
+// - `post_capture.action`, superseded by the per-capture-type `actions` map
+//   and never read, is dropped
+fn v0_to_v1(table: &mut Table) {
+    if let Some(Value::Table(upload)) = table.get_mut("upload") {
+        let mut preset = Table::new();
+        for (old, new) in LEGACY_CUSTOM_UPLOADER {
+            // an empty string stood for the default
+            if let Some(value) = upload.remove(old).filter(|v| v.as_str() != Some("")) {
+                preset.insert(new.into(), value);
+            }
+        }
+        let has_presets = upload
+            .get("custom_presets")
+            .and_then(Value::as_array)
+            .is_some_and(|presets| !presets.is_empty());
+        if preset.contains_key("url") && !has_presets {
+            preset.insert("id".into(), "custom".into());
+            upload.insert(
+                "custom_presets".into(),
+                Value::Array(vec![Value::Table(preset)]),
+            );
+            upload.insert("active_custom_preset".into(), "custom".into());
+        }
+    }
+    if let Some(Value::Table(post)) = table.get_mut("post_capture") {
+        post.remove("action");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_unversioned_file_is_stamped_and_a_current_one_left_alone() {
+        let mut table: Table = toml::from_str("[output]\nquality = 80\n").unwrap();
+        assert_eq!(run(&mut table), 0);
+        assert_eq!(table["version"].as_integer(), Some(CURRENT as i64));
+        let before = table.clone();
+        assert_eq!(run(&mut table), CURRENT);
+        assert_eq!(table, before);
+    }
+
+    #[test]
+    fn v0_drops_the_old_single_post_capture_action() {
+        let mut table: Table =
+            toml::from_str("[post_capture]\naction = \"Upload\"\nplay_sound = false\n").unwrap();
+        run(&mut table);
+        let post = table["post_capture"].as_table().unwrap();
+        assert!(!post.contains_key("action"));
+        assert_eq!(post["play_sound"].as_bool(), Some(false));
+    }
+}
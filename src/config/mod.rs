@@ -4,9 +4,15 @@
 use anyhow::Context;
 use anyhow::{anyhow, Result};
 use directories::ProjectDirs;
+use crate::plugin::CaptureType;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+mod migrate;
+pub use migrate::CURRENT as CONFIG_VERSION;
 
 const MAX_QUALITY: u8 = 100;
 const MIN_GIF_FPS: u32 = 1;
@@ -15,11 +21,47 @@ const MAX_GIF_DURATION_SECS: u32 = 300;
 const MAX_DELAY_MS: u32 = 30000;
 const MAX_FILENAME_TEMPLATE_LEN: usize = 128;
 const MAX_HOTKEY_LEN: usize = 64;
+// a day; longer than that is "until turned off", which 0 already means
+const MAX_DND_MINUTES: u32 = 24 * 60;
 const MAX_CUSTOM_URL_LEN: usize = 512;
+const MAX_IMGUR_ALBUM_ID_LEN: usize = 32;
+const MAX_IMGUR_TEXT_LEN: usize = 1024;
+const MAX_CUSTOM_EXTRAS: usize = 16;
+const MAX_CUSTOM_PRESETS: usize = 32;
+const MAX_PRESET_ID_LEN: usize = 64;
+const MAX_PRESET_NAME_LEN: usize = 64;
+// the uploader refuses anything over 32 MB whatever the preset says
+const MAX_CUSTOM_FILE_MB: u32 = 32;
 const MAX_FORM_NAME_LEN: usize = 64;
 const MAX_RESPONSE_PATH_LEN: usize = 128;
 const MIN_TICK_INTERVAL_MS: u32 = 16;
 const MAX_TICK_INTERVAL_MS: u32 = 500;
+const MIN_RESIZE_DIMENSION: u32 = 16;
+const MAX_RESIZE_DIMENSION: u32 = 16384;
+const MIN_RESIZE_PERCENT: u8 = 5;
+const MAX_BORDER_WIDTH: u32 = 256;
+const MAX_CORNER_RADIUS: u32 = 512;
+const MAX_CAPTION_LEN: usize = 200;
+const MIN_UI_SCALE_PERCENT: u16 = 80;
+const MAX_UI_SCALE_PERCENT: u16 = 150;
+
+// hash of what save() last wrote, so the config watcher can tell capscr's own
+// writes from a hand edit
+static LAST_WRITTEN: Mutex<Option<u64>> = Mutex::new(None);
+
+fn content_hash(content: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// true when `content` is exactly what this process last saved
+pub fn written_by_us(content: &str) -> bool {
+    *LAST_WRITTEN.lock().unwrap() == Some(content_hash(content))
+}
+
+pub const MAX_SCRATCH_CAP_MB: u32 = 65536;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 // container-level default: any field absent from config.toml is filled from
@@ -29,6 +71,9 @@ const MAX_TICK_INTERVAL_MS: u32 = 500;
 // pass only runs *after* a successful parse, so it never saw this).
 #[serde(default)]
 pub struct Config {
+    /// schema version the file was written under; older files are migrated
+    /// on load (see migrate.rs)
+    pub version: u32,
     pub output: OutputConfig,
     pub capture: CaptureConfig,
     #[serde(default)]
@@ -42,6 +87,10 @@ pub struct Config {
     pub performance: PerformanceConfig,
     #[serde(default)]
     pub marketplace: MarketplaceConfig,
+    #[serde(default)]
+    pub auto_capture: AutoCaptureConfig,
+    #[serde(default)]
+    pub watch_folder: WatchFolderConfig,
     #[serde(default = "default_capture_tasks")]
     pub capture_tasks: Vec<CaptureTask>,
 }
@@ -56,10 +105,92 @@ pub struct CaptureTask {
     pub post_action: TaskPostAction,
     #[serde(default)]
     pub target_destination: Option<TaskUploadTarget>,
+    /// custom uploader preset id for `target_destination = "custom"`; None
+    /// uses the active preset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_preset: Option<String>,
     /// per-task pre-capture delay in ms; None falls back to the global
     /// capture.delay_ms, so a timed task can coexist with instant ones
     #[serde(default)]
     pub delay_ms: Option<u32>,
+    /// which window a `target-window` task captures without showing the
+    /// selector. ignored by every other capture mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_window: Option<WindowTarget>,
+    /// gif/mp4 tasks: skip the selector and keep the recording aimed at
+    /// whichever window has focus, at the size it had when recording started
+    #[serde(default)]
+    pub follow_active_window: bool,
+    /// gif/mp4 tasks: record a fixed-size frame the user positions before
+    /// recording starts instead of dragging a free region
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recording_size: Option<RecordingSize>,
+    /// gif/mp4 tasks: record only while the hotkey is held. the release
+    /// stops and saves
+    #[serde(default)]
+    pub hold_to_record: bool,
+    /// how the hotkey has to be pressed. tasks can share a hotkey when each
+    /// wants a different gesture
+    #[serde(default)]
+    pub gesture: HotkeyGesture,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum HotkeyGesture {
+    #[default]
+    Press,
+    DoublePress,
+    LongPress,
+}
+
+/// fixed recording frame in physical px (1280x720, 1920x1080 or custom)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RecordingSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// case-insensitive regex patterns a targeted capture matches against the
+/// window title and the owning process/app name. an empty pattern matches
+/// anything; at least one must be set
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct WindowTarget {
+    pub title: String,
+    pub process: String,
+}
+
+const MAX_WINDOW_PATTERN_LEN: usize = 256;
+const MAX_EXCLUDED_APPS: usize = 64;
+const MAX_EXCLUDED_APP_LEN: usize = 128;
+const MIN_ZOOM_PERCENT: u32 = 125;
+const MAX_ZOOM_PERCENT: u32 = 400;
+const MAX_ZOOM_SMOOTHING_MS: u32 = 2000;
+const MIN_RECORDING_SIZE: u32 = 16;
+const MAX_RECORDING_SIZE: u32 = 8192;
+const MIN_STREAM_BITRATE_KBPS: u32 = 500;
+const MAX_STREAM_BITRATE_KBPS: u32 = 50_000;
+const MIN_AUTO_CAPTURE_INTERVAL_SECS: u32 = 5;
+const MAX_AUTO_CAPTURE_INTERVAL_SECS: u32 = 24 * 60 * 60;
+
+impl WindowTarget {
+    pub fn is_empty(&self) -> bool {
+        self.title.trim().is_empty() && self.process.trim().is_empty()
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.is_empty() {
+            return Err(anyhow!("needs a title or process pattern"));
+        }
+        for pattern in [&self.title, &self.process] {
+            if pattern.len() > MAX_WINDOW_PATTERN_LEN {
+                return Err(anyhow!("window pattern too long"));
+            }
+            crate::capture::compile_window_pattern(pattern)?;
+        }
+        Ok(())
+    }
 }
 
 impl Default for CaptureTask {
@@ -74,7 +205,13 @@ impl Default for CaptureTask {
             capture_mode: TaskCaptureMode::Region,
             post_action: TaskPostAction::SaveAndClipboard,
             target_destination: None,
+            custom_preset: None,
             delay_ms: None,
+            target_window: None,
+            follow_active_window: false,
+            recording_size: None,
+            hold_to_record: false,
+            gesture: HotkeyGesture::Press,
         }
     }
 }
@@ -84,11 +221,18 @@ impl Default for CaptureTask {
 pub enum TaskCaptureMode {
     Region,
     RegionLast,
+    /// pick a window in the selector
     Window,
+    /// the focused window straight away; the selector only when there's none
+    ActiveWindow,
+    /// the first window matching the task's target_window patterns, no selector
+    TargetWindow,
     Fullscreen,
     ActiveMonitor,
     RegionGif,
     RegionMp4,
+    /// push the region live to an rtmp ingest instead of saving a file
+    RegionStream,
 }
 
 impl TaskCaptureMode {
@@ -97,10 +241,13 @@ impl TaskCaptureMode {
             TaskCaptureMode::Region => "Region",
             TaskCaptureMode::RegionLast => "Region (last)",
             TaskCaptureMode::Window => "Window",
+            TaskCaptureMode::ActiveWindow => "Active window",
+            TaskCaptureMode::TargetWindow => "Window (by title/process)",
             TaskCaptureMode::Fullscreen => "Fullscreen (selector)",
             TaskCaptureMode::ActiveMonitor => "Active monitor",
             TaskCaptureMode::RegionGif => "Region GIF",
             TaskCaptureMode::RegionMp4 => "Region MP4",
+            TaskCaptureMode::RegionStream => "Region stream (RTMP)",
         }
     }
 }
@@ -142,6 +289,26 @@ pub enum TaskUploadTarget {
     Ftp,
     Sftp,
     S3,
+    Webdav,
+    Dropbox,
+    Email,
+    Webhook,
+}
+
+impl From<TaskUploadTarget> for UploadDestination {
+    fn from(target: TaskUploadTarget) -> Self {
+        match target {
+            TaskUploadTarget::Imgur => UploadDestination::Imgur,
+            TaskUploadTarget::Custom => UploadDestination::Custom,
+            TaskUploadTarget::Ftp => UploadDestination::Ftp,
+            TaskUploadTarget::Sftp => UploadDestination::Sftp,
+            TaskUploadTarget::S3 => UploadDestination::S3,
+            TaskUploadTarget::Webdav => UploadDestination::Webdav,
+            TaskUploadTarget::Dropbox => UploadDestination::Dropbox,
+            TaskUploadTarget::Email => UploadDestination::Email,
+            TaskUploadTarget::Webhook => UploadDestination::Webhook,
+        }
+    }
 }
 
 fn default_capture_tasks() -> Vec<CaptureTask> {
@@ -153,7 +320,13 @@ fn default_capture_tasks() -> Vec<CaptureTask> {
             capture_mode: TaskCaptureMode::Region,
             post_action: TaskPostAction::SaveAndClipboard,
             target_destination: None,
+            custom_preset: None,
             delay_ms: None,
+            target_window: None,
+            follow_active_window: false,
+            recording_size: None,
+            hold_to_record: false,
+            gesture: HotkeyGesture::Press,
         },
         CaptureTask {
             id: "gif-save".to_string(),
@@ -162,7 +335,13 @@ fn default_capture_tasks() -> Vec<CaptureTask> {
             capture_mode: TaskCaptureMode::RegionGif,
             post_action: TaskPostAction::SaveFile,
             target_destination: None,
+            custom_preset: None,
             delay_ms: None,
+            target_window: None,
+            follow_active_window: false,
+            recording_size: None,
+            hold_to_record: false,
+            gesture: HotkeyGesture::Press,
         },
         CaptureTask {
             id: "mp4-save".to_string(),
@@ -171,7 +350,13 @@ fn default_capture_tasks() -> Vec<CaptureTask> {
             capture_mode: TaskCaptureMode::RegionMp4,
             post_action: TaskPostAction::SaveFile,
             target_destination: None,
+            custom_preset: None,
             delay_ms: None,
+            target_window: None,
+            follow_active_window: false,
+            recording_size: None,
+            hold_to_record: false,
+            gesture: HotkeyGesture::Press,
         },
     ]
 }
@@ -184,11 +369,401 @@ pub struct OutputConfig {
     pub quality: u8,
     pub filename_template: String,
     /// when true and the source is HDR (HDR10 currently — scRGB / HLG arrive
-    /// in Phase 2), capscr writes a `<basename>.hdr.png` sidecar alongside
-    /// the normal SDR file. The sidecar is a 16-bit BT.2020 + PQ PNG with a
-    /// `cICP` chunk so HDR-aware viewers display it as real HDR.
+    /// in Phase 2), capscr writes a `<basename>.hdr.<ext>` sidecar alongside
+    /// the normal SDR file, in the container picked by `capture.hdr.container`.
+    /// The default is a 16-bit BT.2020 + PQ PNG with a `cICP` chunk so
+    /// HDR-aware viewers display it as real HDR.
     #[serde(default)]
     pub preserve_hdr: bool,
+    /// keep a `<file name>.capscr.json` beside each capture saved from the
+    /// editor, holding the unedited pixels and the annotations so a reopen can
+    /// still move or undo them. off by default: each one carries a full copy
+    /// of the capture
+    #[serde(default)]
+    pub editor_projects: bool,
+    #[serde(default)]
+    pub decoration: DecorationConfig,
+    /// downscale applied to captures written to disk
+    #[serde(default)]
+    pub resize: ResizeConfig,
+    #[serde(default)]
+    pub metadata: MetadataMode,
+    /// also write a capture's tags and note into its png, as the Keywords
+    /// and Comment text chunks, when they're set from the editor or history
+    #[serde(default)]
+    pub tags_in_metadata: bool,
+    /// the folder the editor's "save as" last wrote to. None starts the
+    /// dialog in `directory`; owned by the editor, not the Settings view
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub save_as_directory: Option<PathBuf>,
+    /// where intermediate files go while in use: gif frame spools, mp4s
+    /// being encoded, the audio tap, mail attachments. None is `capscr` in
+    /// the system temp dir
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scratch_directory: Option<PathBuf>,
+    /// leftovers from earlier runs are cleared at startup down to this many
+    /// MiB, oldest first
+    #[serde(default = "default_scratch_cap_mb")]
+    pub scratch_cap_mb: u32,
+    /// per-app overrides for window captures, by the captured window's process
+    #[serde(default)]
+    pub app_profiles: Vec<AppProfile>,
+    /// also keep every capture as it was taken, as a lossless png, before
+    /// plugins, decoration, resizing or a lossy format touch it
+    #[serde(default)]
+    pub archive_originals: bool,
+    /// where those go. None is `originals` inside `directory`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_directory: Option<PathBuf>,
+    /// how much of `directory` is kept; app profiles with a subfolder can set
+    /// their own
+    #[serde(default)]
+    pub retention: RetentionPolicy,
+}
+
+fn default_scratch_cap_mb() -> u32 {
+    1024
+}
+
+/// how captures of one app's windows are kept, whichever task took them:
+/// figma always as png into a design folder, outlook to the clipboard only
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct AppProfile {
+    /// process name, matched like the exclusion list
+    pub app: String,
+    /// None keeps output.format
+    pub format: Option<ImageFormat>,
+    /// None keeps the task's action
+    pub post_action: Option<TaskPostAction>,
+    /// folder under output.directory; empty saves straight into it
+    pub subfolder: String,
+    /// None applies output.retention. only a profile with a subfolder has a
+    /// folder of its own to keep in check
+    pub retention: Option<RetentionPolicy>,
+}
+
+/// how much of a capture folder is kept. files over the limit go oldest
+/// first; a zero limit is no limit
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct RetentionPolicy {
+    pub max_files: u32,
+    pub max_size_mb: u32,
+    /// move what's over the limit here instead of deleting it. a relative
+    /// path is a folder inside the one being kept in check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub move_to: Option<PathBuf>,
+}
+
+impl RetentionPolicy {
+    pub fn is_active(&self) -> bool {
+        self.max_files > 0 || self.max_size_mb > 0
+    }
+}
+
+impl OutputConfig {
+    /// the folder untouched originals go to, while archiving is on
+    pub fn archive_dir(&self) -> Option<PathBuf> {
+        self.archive_originals.then(|| {
+            self.archive_directory
+                .clone()
+                .unwrap_or_else(|| self.directory.join("originals"))
+        })
+    }
+
+    /// the profile for captures of `app`'s windows, if it has one
+    pub fn profile_for(&self, app: &str) -> Option<&AppProfile> {
+        self.app_profiles
+            .iter()
+            .find(|p| crate::capture::exclusion::app_matches(std::slice::from_ref(&p.app), app))
+    }
+
+    /// every capture folder with a retention policy, and the policy
+    pub fn retention_targets(&self) -> Vec<(PathBuf, RetentionPolicy)> {
+        let profiles = self.app_profiles.iter().filter_map(|p| {
+            let policy = p.retention.clone()?;
+            (!p.subfolder.is_empty()).then(|| (self.directory.join(&p.subfolder), policy))
+        });
+        std::iter::once((self.directory.clone(), self.retention.clone()))
+            .chain(profiles)
+            .filter(|(_, policy)| policy.is_active())
+            .collect()
+    }
+
+    /// `profile`'s format and folder in place of the global ones
+    pub fn apply_profile(&mut self, profile: &AppProfile) {
+        if let Some(format) = profile.format {
+            self.format = format;
+        }
+        if !profile.subfolder.is_empty() {
+            self.directory = self.directory.join(&profile.subfolder);
+        }
+    }
+
+    fn sanitize_app_profiles(&mut self) {
+        let mut seen = std::collections::HashSet::new();
+        self.app_profiles = std::mem::take(&mut self.app_profiles)
+            .into_iter()
+            .map(|profile| {
+                // relative and downward only, so a profile can't write outside
+                // the output directory
+                let subfolder: Vec<&str> = profile
+                    .subfolder
+                    .split(['/', '\\'])
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty() && *s != "." && *s != ".." && !s.contains(':'))
+                    .collect();
+                AppProfile {
+                    app: profile.app.trim().to_string(),
+                    subfolder: subfolder.join("/"),
+                    ..profile
+                }
+            })
+            .filter(|profile| {
+                !profile.app.is_empty()
+                    && profile.app.len() <= MAX_EXCLUDED_APP_LEN
+                    && seen.insert(profile.app.to_lowercase())
+            })
+            .take(MAX_EXCLUDED_APPS)
+            .collect();
+    }
+}
+
+/// what saved png and jpeg files carry besides pixels
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum MetadataMode {
+    /// whatever the encoder or the editor wrote, which for capscr's own
+    /// encoders is nothing
+    #[default]
+    Keep,
+    /// scrub text, exif, xmp and timestamps from every save, editor saves too
+    Strip,
+    /// write the capture time, monitor, window title and capscr version
+    Embed,
+}
+
+impl MetadataMode {
+    pub fn all() -> &'static [MetadataMode] {
+        &[MetadataMode::Keep, MetadataMode::Strip, MetadataMode::Embed]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MetadataMode::Keep => "Keep",
+            MetadataMode::Strip => "Strip all",
+            MetadataMode::Embed => "Embed capture info",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ResizeMode {
+    #[default]
+    Off,
+    /// shrink so neither side exceeds `max_dimension`
+    MaxDimension,
+    /// scale both sides by `percent`
+    Percent,
+}
+
+impl ResizeMode {
+    pub fn all() -> &'static [ResizeMode] {
+        &[
+            ResizeMode::Off,
+            ResizeMode::MaxDimension,
+            ResizeMode::Percent,
+        ]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ResizeMode::Off => "Off",
+            ResizeMode::MaxDimension => "Max dimension",
+            ResizeMode::Percent => "Percentage",
+        }
+    }
+}
+
+/// a downscale step; never enlarges
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ResizeConfig {
+    pub mode: ResizeMode,
+    pub max_dimension: u32,
+    pub percent: u8,
+}
+
+impl Default for ResizeConfig {
+    fn default() -> Self {
+        Self {
+            mode: ResizeMode::Off,
+            max_dimension: 1920,
+            percent: 50,
+        }
+    }
+}
+
+impl ResizeConfig {
+    /// the size an image of `width`×`height` should be scaled to, or None
+    /// when it is already small enough
+    pub fn target_size(&self, width: u32, height: u32) -> Option<(u32, u32)> {
+        let factor = match self.mode {
+            ResizeMode::Off => return None,
+            ResizeMode::MaxDimension => {
+                let longest = width.max(height);
+                if longest <= self.max_dimension {
+                    return None;
+                }
+                f64::from(self.max_dimension) / f64::from(longest)
+            }
+            ResizeMode::Percent => {
+                if self.percent >= 100 {
+                    return None;
+                }
+                f64::from(self.percent) / 100.0
+            }
+        };
+        let scale = |n: u32| ((f64::from(n) * factor).round() as u32).max(1);
+        Some((scale(width), scale(height)))
+    }
+
+    fn sanitize(&mut self) {
+        self.max_dimension = self
+            .max_dimension
+            .clamp(MIN_RESIZE_DIMENSION, MAX_RESIZE_DIMENSION);
+        self.percent = self.percent.clamp(MIN_RESIZE_PERCENT, 100);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum RecompressFormat {
+    #[default]
+    Jpeg,
+    /// lossless webp first, which keeps text crisp; the jpeg steps after it
+    /// since the webp encoder can't do lossy
+    Webp,
+}
+
+impl RecompressFormat {
+    pub fn all() -> &'static [RecompressFormat] {
+        &[RecompressFormat::Jpeg, RecompressFormat::Webp]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            RecompressFormat::Jpeg => "JPEG",
+            RecompressFormat::Webp => "WebP, then JPEG",
+        }
+    }
+}
+
+pub const MIN_RECOMPRESS_QUALITY: u8 = 10;
+pub const MAX_RECOMPRESS_QUALITY: u8 = 90;
+
+/// what happens to an upload that is over its destination's size limit
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct RecompressConfig {
+    /// re-encode instead of failing the upload
+    pub enabled: bool,
+    pub format: RecompressFormat,
+    /// the lowest jpeg quality tried before giving up
+    pub min_quality: u8,
+}
+
+impl Default for RecompressConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            format: RecompressFormat::Jpeg,
+            min_quality: 50,
+        }
+    }
+}
+
+impl RecompressConfig {
+    fn sanitize(&mut self) {
+        self.min_quality = self
+            .min_quality
+            .clamp(MIN_RECOMPRESS_QUALITY, MAX_RECOMPRESS_QUALITY);
+    }
+}
+
+/// OCR pass over images about to be uploaded that asks for confirmation when
+/// the text looks like it holds something private
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct SecretScanConfig {
+    pub enabled: bool,
+    pub emails: bool,
+    /// well-known token formats (aws, github, slack, stripe, ...), jwts and
+    /// private key headers
+    pub api_keys: bool,
+    /// 13-19 digit runs that pass the luhn check
+    pub card_numbers: bool,
+}
+
+impl Default for SecretScanConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            emails: true,
+            api_keys: true,
+            card_numbers: true,
+        }
+    }
+}
+
+/// frame drawn around every capture at export time; all off by default
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct DecorationConfig {
+    /// solid border in pixels, 0 for none
+    pub border_width: u32,
+    /// `#rrggbb`
+    pub border_color: String,
+    /// outer corner radius in pixels, 0 for square
+    pub corner_radius: u32,
+    /// `#rrggbb` behind the rounded corners; empty leaves them transparent,
+    /// which only png, webp and avif keep
+    pub corner_background: String,
+    /// text for a caption bar above the capture, with the filename template's
+    /// strftime fields; empty for no bar
+    pub caption: String,
+}
+
+impl Default for DecorationConfig {
+    fn default() -> Self {
+        Self {
+            border_width: 0,
+            border_color: "#1c1c1c".to_string(),
+            corner_radius: 0,
+            corner_background: String::new(),
+            caption: String::new(),
+        }
+    }
+}
+
+impl DecorationConfig {
+    pub fn is_active(&self) -> bool {
+        self.border_width > 0 || self.corner_radius > 0 || !self.caption.trim().is_empty()
+    }
+
+    fn sanitize(&mut self) {
+        self.border_width = self.border_width.min(MAX_BORDER_WIDTH);
+        self.corner_radius = self.corner_radius.min(MAX_CORNER_RADIUS);
+        if !is_valid_hex_color(&self.border_color) {
+            self.border_color = Self::default().border_color;
+        }
+        if !self.corner_background.is_empty() && !is_valid_hex_color(&self.corner_background) {
+            self.corner_background = String::new();
+        }
+        truncate_utf8(&mut self.caption, MAX_CAPTION_LEN);
+    }
 }
 
 /// the default captures directory: <Pictures>/capscr, falling back to the home
@@ -212,6 +787,18 @@ impl Default for OutputConfig {
             quality: 90,
             filename_template: "capture_%Y%m%d_%H%M%S".to_string(),
             preserve_hdr: false,
+            editor_projects: false,
+            decoration: DecorationConfig::default(),
+            resize: ResizeConfig::default(),
+            metadata: MetadataMode::Keep,
+            tags_in_metadata: false,
+            save_as_directory: None,
+            scratch_directory: None,
+            scratch_cap_mb: default_scratch_cap_mb(),
+            app_profiles: Vec::new(),
+            archive_originals: false,
+            archive_directory: None,
+            retention: RetentionPolicy::default(),
         }
     }
 }
@@ -246,6 +833,20 @@ impl ImageFormat {
         }
     }
 
+    /// the format a file name's extension asks for, case-insensitively
+    pub fn from_extension(ext: &str) -> Option<ImageFormat> {
+        match ext.to_ascii_lowercase().as_str() {
+            "png" => Some(ImageFormat::Png),
+            "jpg" | "jpeg" | "jpe" => Some(ImageFormat::Jpeg),
+            "gif" => Some(ImageFormat::Gif),
+            "webp" => Some(ImageFormat::Webp),
+            "bmp" => Some(ImageFormat::Bmp),
+            "avif" => Some(ImageFormat::Avif),
+            "jxl" => Some(ImageFormat::Jxl),
+            _ => None,
+        }
+    }
+
     pub fn all() -> &'static [ImageFormat] {
         &[
             ImageFormat::Png,
@@ -286,71 +887,383 @@ pub struct CaptureConfig {
     pub hdr: HdrConfig,
     #[serde(default = "default_record_audio")]
     pub record_audio: bool,
+    /// which part of a window a window capture keeps. windows only — other
+    /// platforms hand back whatever the compositor calls the window
+    #[serde(default)]
+    pub window_frame: WindowFrame,
+    /// clear the wedges outside a windows 11 window's rounded corners so they
+    /// come out transparent instead of black (or desktop) pixels
+    #[serde(default = "default_true")]
+    pub clean_window_corners: bool,
+    /// windows: have the window render itself (PrintWindow) so whatever
+    /// overlaps it, or the screen edge, doesn't end up in a window capture.
+    /// off grabs the window's rect from the screen as it looks
+    #[serde(default = "default_true")]
+    pub capture_covered_windows: bool,
+    /// windows: which api grabs monitors and windows
+    #[serde(default)]
+    pub backend: CaptureBackend,
+    /// windows 11: let windows draw its yellow capture border while a WGC
+    /// capture runs. off suppresses it; older builds always show it
+    #[serde(default)]
+    pub wgc_border: bool,
+    /// apps whose windows are kept out of captures and recordings
+    #[serde(default)]
+    pub exclusion: ExclusionConfig,
+    /// what changes while a full-screen app (usually a game) has focus
+    #[serde(default)]
+    pub game_mode: GameModeConfig,
+    /// recordings: ease in on the cursor while it moves
+    #[serde(default)]
+    pub zoom: ZoomConfig,
+    /// open a preview with in/out trim points when a recording stops instead
+    /// of saving the whole thing straight away
+    #[serde(default)]
+    pub review_recordings: bool,
+    /// where region-stream tasks push to. experimental
+    #[serde(default)]
+    pub stream: StreamConfig,
+    /// windows: ctrl-hover in the selector walks ui automation elements so
+    /// individual controls (buttons, panes, toolbars) highlight and capture as
+    /// regions, not just top-level and child hwnds. off by default since some
+    /// apps answer automation queries slowly
+    #[serde(default)]
+    pub detect_ui_elements: bool,
+    /// the selection overlay covers only the monitor under the pointer
+    /// rather than the whole virtual desktop
+    #[serde(default)]
+    pub selector_cursor_monitor: bool,
+    /// hide an open hub while a capture or recording runs and bring it back
+    /// afterwards, so it never ends up in the shot
+    #[serde(default = "default_true")]
+    pub hide_hub_during_capture: bool,
+    /// what happens to a capture pixel-identical to the one before it, taken
+    /// within the last minute: usually a hotkey pressed twice
+    #[serde(default)]
+    pub duplicates: DuplicateCaptures,
 }
 
-fn default_record_audio() -> bool {
-    false
-}
-
-fn default_video_fps() -> u32 {
-    30
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum DuplicateCaptures {
+    /// keep every capture
+    Off,
+    /// keep it, with a notification saying it's a repeat
+    #[default]
+    Warn,
+    /// drop it before it's saved, copied or uploaded
+    Skip,
 }
 
-/// x264 crf tier for mp4 recordings; gifs are palette-bound and unaffected
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
-pub enum VideoQuality {
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum WindowFrame {
+    /// the visible frame (DWM extended frame bounds), no drop shadow
     #[default]
-    High,
-    Balanced,
-    Compact,
+    Visible,
+    /// the full window rect, including the invisible resize border the drop
+    /// shadow is drawn into
+    WithShadow,
+    /// the client area only — no title bar or border
+    ClientOnly,
 }
 
-impl VideoQuality {
-    pub fn crf(self) -> u8 {
-        match self {
-            VideoQuality::High => 18,
-            VideoQuality::Balanced => 23,
-            VideoQuality::Compact => 28,
-        }
+impl WindowFrame {
+    pub fn all() -> &'static [WindowFrame] {
+        &[
+            WindowFrame::Visible,
+            WindowFrame::WithShadow,
+            WindowFrame::ClientOnly,
+        ]
     }
-}
 
-impl Default for CaptureConfig {
-    fn default() -> Self {
-        Self {
-            show_cursor: true,
-            delay_ms: 0,
-            gif_fps: 15,
-            gif_max_duration_secs: 30,
-            video_fps: 30,
-            video_quality: VideoQuality::default(),
-            hdr: HdrConfig::default(),
-            record_audio: false,
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WindowFrame::Visible => "Window frame",
+            WindowFrame::WithShadow => "Frame and shadow",
+            WindowFrame::ClientOnly => "Client area only",
         }
     }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "kebab-case")]
-pub enum HdrOutputFormat {
-    /// 16-bit BT.2020 PNG with PQ transfer (cICP 9/16/0/1). default — the
-    /// best-supported HDR PNG format for Windows Photos, Edge, IrfanView w/
-    /// libpng>=1.6.39.
+pub enum CaptureBackend {
+    /// GDI BitBlt for SDR content, the CPU tonemap for HDR monitors
     #[default]
-    Pq,
-    /// 16-bit BT.2020 PNG with HLG transfer (cICP 9/18/0/1). useful when
-    /// targeting TV/broadcast displays or HLG-aware viewers. transcoded from
-    /// the HDR10 PQ source by decoding PQ to linear nits then applying HLG OETF.
-    Hlg,
+    Auto,
+    /// Windows.Graphics.Capture for every monitor and window, falling back to
+    /// GDI when it fails. sees hardware-accelerated and some protected
+    /// content GDI returns black for, at the cost of a short session start
+    Wgc,
 }
 
-impl HdrOutputFormat {
-    pub fn all() -> &'static [HdrOutputFormat] {
-        &[HdrOutputFormat::Pq, HdrOutputFormat::Hlg]
+impl CaptureBackend {
+    pub fn all() -> &'static [CaptureBackend] {
+        &[CaptureBackend::Auto, CaptureBackend::Wgc]
     }
+
     pub fn display_name(&self) -> &'static str {
         match self {
-            HdrOutputFormat::Pq => "PQ (BT.2020, HDR10-style — default)",
+            CaptureBackend::Auto => "Automatic",
+            CaptureBackend::Wgc => "Windows.Graphics.Capture",
+        }
+    }
+}
+
+/// what happens to a capture an excluded app's window shows up in
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExclusionMode {
+    /// paint the app's windows black; recordings black them out per frame
+    #[default]
+    Blackout,
+    /// refuse the capture outright; recordings drop the frames instead
+    Skip,
+}
+
+impl ExclusionMode {
+    pub fn all() -> &'static [ExclusionMode] {
+        &[ExclusionMode::Blackout, ExclusionMode::Skip]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ExclusionMode::Blackout => "Black out",
+            ExclusionMode::Skip => "Skip the capture",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ExclusionConfig {
+    /// process names, matched case-insensitively with or without ".exe"
+    pub apps: Vec<String>,
+    pub mode: ExclusionMode,
+}
+
+impl ExclusionConfig {
+    fn sanitize(&mut self) {
+        let mut seen = std::collections::HashSet::new();
+        self.apps = std::mem::take(&mut self.apps)
+            .into_iter()
+            .map(|app| app.trim().to_string())
+            .filter(|app| {
+                !app.is_empty()
+                    && app.len() <= MAX_EXCLUDED_APP_LEN
+                    && seen.insert(app.to_lowercase())
+            })
+            .take(MAX_EXCLUDED_APPS)
+            .collect();
+    }
+}
+
+/// what capscr does while a full-screen app has focus
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum GameModeAction {
+    /// nothing changes
+    #[default]
+    None,
+    /// hotkeys without Ctrl, Alt or Win (bare keys, Shift+key, mouse
+    /// buttons) are released so the app gets them; chords stay bound
+    SuspendHotkeys,
+    /// windows: captures go through Windows.Graphics.Capture, which reads a
+    /// full-screen game's frames without stalling it the way GDI does
+    LowOverhead,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct GameModeRule {
+    /// process name, matched like the exclusion list
+    pub app: String,
+    pub action: GameModeAction,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct GameModeConfig {
+    /// watch for full-screen apps at all
+    pub enabled: bool,
+    /// what a full-screen app with no rule of its own gets
+    pub action: GameModeAction,
+    /// per-process overrides of `action`
+    pub apps: Vec<GameModeRule>,
+}
+
+impl GameModeConfig {
+    fn sanitize(&mut self) {
+        let mut seen = std::collections::HashSet::new();
+        self.apps = std::mem::take(&mut self.apps)
+            .into_iter()
+            .map(|rule| GameModeRule {
+                app: rule.app.trim().to_string(),
+                ..rule
+            })
+            .filter(|rule| {
+                !rule.app.is_empty()
+                    && rule.app.len() <= MAX_EXCLUDED_APP_LEN
+                    && seen.insert(rule.app.to_lowercase())
+            })
+            .take(MAX_EXCLUDED_APPS)
+            .collect();
+    }
+}
+
+/// automatic zoom-to-cursor for recordings: the frame eases in on the
+/// pointer while it moves and back out once it settles
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ZoomConfig {
+    pub enabled: bool,
+    /// magnification at full zoom, 200 = 2x
+    pub zoom_percent: u32,
+    /// roughly how long the camera takes to catch up with the cursor; 0
+    /// snaps straight to it
+    pub smoothing_ms: u32,
+}
+
+impl Default for ZoomConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            zoom_percent: 200,
+            smoothing_ms: 400,
+        }
+    }
+}
+
+/// live rtmp output for region-stream tasks, e.g. a local obs or twitch
+/// ingest. video only — no audio track is sent
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct StreamConfig {
+    /// plaintext ingest url as typed in the UI. most services put the stream
+    /// key in the url, so it gets vault-wrapped on save like a webhook url
+    pub url: String,
+    pub url_encrypted: String,
+    /// target video bitrate; rtmp ingests expect a steady rate rather than
+    /// the constant quality file recordings use
+    pub bitrate_kbps: u32,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            url_encrypted: String::new(),
+            bitrate_kbps: 4500,
+        }
+    }
+}
+
+impl StreamConfig {
+    pub fn url_plaintext(&self) -> String {
+        if !self.url_encrypted.is_empty() {
+            match crate::secret::decrypt(&self.url_encrypted) {
+                Ok(p) => return p,
+                Err(e) => {
+                    tracing::warn!("stream url decrypt failed: {e}");
+                }
+            }
+        }
+        self.url.clone()
+    }
+
+    fn sanitize(&mut self) {
+        self.url = self.url.trim().to_string();
+        self.bitrate_kbps = self
+            .bitrate_kbps
+            .clamp(MIN_STREAM_BITRATE_KBPS, MAX_STREAM_BITRATE_KBPS);
+    }
+}
+
+impl ZoomConfig {
+    fn sanitize(&mut self) {
+        self.zoom_percent = self.zoom_percent.clamp(MIN_ZOOM_PERCENT, MAX_ZOOM_PERCENT);
+        self.smoothing_ms = self.smoothing_ms.min(MAX_ZOOM_SMOOTHING_MS);
+    }
+}
+
+fn default_record_audio() -> bool {
+    false
+}
+
+fn default_video_fps() -> u32 {
+    30
+}
+
+/// x264 crf tier for mp4 recordings; gifs are palette-bound and unaffected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum VideoQuality {
+    #[default]
+    High,
+    Balanced,
+    Compact,
+}
+
+impl VideoQuality {
+    pub fn crf(self) -> u8 {
+        match self {
+            VideoQuality::High => 18,
+            VideoQuality::Balanced => 23,
+            VideoQuality::Compact => 28,
+        }
+    }
+}
+
+impl Default for CaptureConfig {
+    fn default() -> Self {
+        Self {
+            show_cursor: true,
+            delay_ms: 0,
+            gif_fps: 15,
+            gif_max_duration_secs: 30,
+            video_fps: 30,
+            video_quality: VideoQuality::default(),
+            hdr: HdrConfig::default(),
+            record_audio: false,
+            window_frame: WindowFrame::Visible,
+            clean_window_corners: true,
+            capture_covered_windows: true,
+            backend: CaptureBackend::Auto,
+            wgc_border: false,
+            exclusion: ExclusionConfig::default(),
+            game_mode: GameModeConfig::default(),
+            zoom: ZoomConfig::default(),
+            review_recordings: false,
+            stream: StreamConfig::default(),
+            detect_ui_elements: false,
+            selector_cursor_monitor: false,
+            hide_hub_during_capture: true,
+            duplicates: DuplicateCaptures::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum HdrOutputFormat {
+    /// 16-bit BT.2020 PNG with PQ transfer (cICP 9/16/0/1). default — the
+    /// best-supported HDR PNG format for Windows Photos, Edge, IrfanView w/
+    /// libpng>=1.6.39.
+    #[default]
+    Pq,
+    /// 16-bit BT.2020 PNG with HLG transfer (cICP 9/18/0/1). useful when
+    /// targeting TV/broadcast displays or HLG-aware viewers. transcoded from
+    /// the HDR10 PQ source by decoding PQ to linear nits then applying HLG OETF.
+    Hlg,
+}
+
+impl HdrOutputFormat {
+    pub fn all() -> &'static [HdrOutputFormat] {
+        &[HdrOutputFormat::Pq, HdrOutputFormat::Hlg]
+    }
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            HdrOutputFormat::Pq => "PQ (BT.2020, HDR10-style — default)",
             HdrOutputFormat::Hlg => "HLG (BT.2020, broadcast-style)",
         }
     }
@@ -362,19 +1275,109 @@ impl std::fmt::Display for HdrOutputFormat {
     }
 }
 
+/// file container for the HDR sidecar
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum HdrContainer {
+    /// 16-bit PNG tagged with `cICP`. default — lossless and readable everywhere
+    #[default]
+    Png,
+    /// 10-bit AV1 still tagged with BT.2020 + the chosen transfer. encoded
+    /// through the recording ffmpeg, so it needs ffmpeg with libaom or svt-av1
+    Avif,
+    /// JPEG XR in linear scRGB half floats, the format Xbox Game Bar and the
+    /// Windows Snipping Tool save HDR shots in. Windows only (WIC encoder);
+    /// linear, so the PQ/HLG transfer choice doesn't apply
+    Jxr,
+}
+
+impl HdrContainer {
+    pub fn all() -> &'static [HdrContainer] {
+        &[HdrContainer::Png, HdrContainer::Avif, HdrContainer::Jxr]
+    }
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            HdrContainer::Png => "PNG (16-bit, lossless — default)",
+            HdrContainer::Avif => "AVIF (10-bit AV1)",
+            HdrContainer::Jxr => "JPEG XR (scRGB, Windows)",
+        }
+    }
+    /// appended to the capture's stem to name its sidecar
+    pub fn sidecar_suffix(&self) -> &'static str {
+        match self {
+            HdrContainer::Png => ".hdr.png",
+            HdrContainer::Avif => ".hdr.avif",
+            HdrContainer::Jxr => ".hdr.jxr",
+        }
+    }
+}
+
+/// curve that maps HDR working space (SDR white = 1.0) down to SDR
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum TonemapOperator {
+    /// luminance knee + log rolloff. default — SDR content passes through
+    /// untouched and only HDR highlights are compressed
+    #[default]
+    Bt2390,
+    /// maxRGB Reinhard with the frame peak as white point: smooth global
+    /// compression, slightly dimmer SDR midtones
+    ReinhardExtended,
+    /// Narkowicz's ACES filmic fit, per channel. punchier contrast and the
+    /// film-like desaturation of bright colours
+    AcesFilmic,
+    /// Hable's Uncharted 2 filmic curve, per channel. softer toe and shoulder
+    /// than ACES
+    Hable,
+    /// exposure only: scale, then clip at SDR white
+    Exposure,
+}
+
+impl TonemapOperator {
+    pub fn all() -> &'static [TonemapOperator] {
+        &[
+            TonemapOperator::Bt2390,
+            TonemapOperator::ReinhardExtended,
+            TonemapOperator::AcesFilmic,
+            TonemapOperator::Hable,
+            TonemapOperator::Exposure,
+        ]
+    }
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            TonemapOperator::Bt2390 => "Highlight rolloff (default)",
+            TonemapOperator::ReinhardExtended => "Reinhard extended",
+            TonemapOperator::AcesFilmic => "ACES filmic",
+            TonemapOperator::Hable => "Hable filmic",
+            TonemapOperator::Exposure => "Exposure (clip)",
+        }
+    }
+}
+
+impl std::fmt::Display for TonemapOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(default)]
 pub struct HdrConfig {
     /// manual override for the display's SDR white level in nits. 0.0 means
     /// auto-detect via DISPLAYCONFIG_SDR_WHITE_LEVEL (with a DXGI fallback).
     pub brightness_nits: f32,
+    /// exposure multiplier applied before the tonemap operator
     pub user_brightness_scale: f32,
     pub use_p99_max_cll: bool,
+    #[serde(default)]
+    pub tonemap_operator: TonemapOperator,
     /// HDR-preserving PNG transfer characteristic when preserve_hdr is on.
     /// applies only to HDR sources; SDR captures bypass the HDR encoder
     /// entirely
     #[serde(default)]
     pub output_format: HdrOutputFormat,
+    #[serde(default)]
+    pub container: HdrContainer,
 }
 
 impl Default for HdrConfig {
@@ -383,7 +1386,9 @@ impl Default for HdrConfig {
             brightness_nits: 0.0,
             user_brightness_scale: 1.0,
             use_p99_max_cll: true,
+            tonemap_operator: TonemapOperator::Bt2390,
             output_format: HdrOutputFormat::Pq,
+            container: HdrContainer::Png,
         }
     }
 }
@@ -395,6 +1400,18 @@ pub struct HotkeyConfig {
     pub screenshot: String,
     #[serde(default)]
     pub record_gif: String,
+    /// ends whichever recording is running; a recording task's own hotkey
+    /// still toggles it too
+    #[serde(default)]
+    pub stop_recording: String,
+    /// turns do not disturb on and off: no task hotkeys, notifications or
+    /// sounds while it's on
+    #[serde(default)]
+    pub do_not_disturb: String,
+    /// how long the do-not-disturb hotkey turns it on for; 0 keeps it on
+    /// until it's toggled off again
+    #[serde(default)]
+    pub do_not_disturb_minutes: u32,
     // user-controlled global kill switch toggled from the tray or Settings.
     // when true, the LL keyboard hook is installed but its match table is
     // empty, so no chord can fire a task. preserved across restarts.
@@ -434,16 +1451,71 @@ impl CloseBehavior {
     }
 }
 
+/// hub language. `system` follows the OS locale and falls back to english
+/// when it isn't one capscr ships
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Language {
+    #[default]
+    System,
+    En,
+    De,
+    Fr,
+    Ja,
+}
+
+impl Language {
+    pub fn all() -> &'static [Language] {
+        &[
+            Language::System,
+            Language::En,
+            Language::De,
+            Language::Fr,
+            Language::Ja,
+        ]
+    }
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Language::System => "System default",
+            Language::En => "English",
+            Language::De => "Deutsch",
+            Language::Fr => "Français",
+            Language::Ja => "日本語",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct UiConfig {
     pub theme: Theme,
+    /// highlight color for active tabs, checks, focus rings and primary
+    /// buttons; mono keeps the all-greyscale look
+    #[serde(default)]
+    pub accent: Accent,
+    /// `#rrggbb`, only read when `accent` is custom
+    #[serde(default = "default_accent_color")]
+    pub accent_color: String,
+    /// hub zoom on top of the OS scale factor, 80-150
+    #[serde(default = "default_ui_scale_percent")]
+    pub scale_percent: u16,
+    /// icon-width sidebar and tighter rows, for a small hub window
+    #[serde(default)]
+    pub compact: bool,
+    #[serde(default)]
+    pub language: Language,
     pub show_notifications: bool,
     pub copy_to_clipboard: bool,
     #[serde(default)]
     pub close_behavior: CloseBehavior,
     #[serde(default)]
     pub auto_start: bool,
+    /// "Annotate with capscr" on image files in the file manager
+    #[serde(default)]
+    pub context_menu: bool,
+    /// launch straight to the tray; off opens the hub on every launch
+    #[serde(default = "default_true")]
+    pub start_minimized: bool,
     #[serde(default = "default_true")]
     pub check_updates_on_launch: bool,
     #[serde(default = "default_true")]
@@ -452,16 +1524,68 @@ pub struct UiConfig {
     // it never nags again on tray-less desktops (vanilla gnome)
     #[serde(default)]
     pub tray_hint_dismissed: bool,
+    /// where the hub was last dragged to, in physical screen pixels. None
+    /// lets the OS place it; owned by the hub window, not the Settings view
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window_position: Option<WindowPosition>,
+    /// `#rrggbb` colors last picked in the editor, newest first. owned by
+    /// the editor like the hub position
+    #[serde(default)]
+    pub editor_recent_colors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WindowPosition {
+    pub x: i32,
+    pub y: i32,
 }
 
 fn default_true() -> bool {
     true
 }
 
+fn default_ui_scale_percent() -> u16 {
+    100
+}
+
+pub const MAX_RECENT_COLORS: usize = 8;
+
+impl UiConfig {
+    pub fn scale(&self) -> f64 {
+        f64::from(self.scale_percent) / 100.0
+    }
+
+    /// move `color` to the front of the recent colors, keeping the newest
+    /// few. false when it isn't a `#rrggbb` color
+    pub fn remember_editor_color(&mut self, color: &str) -> bool {
+        if !is_valid_hex_color(color) {
+            return false;
+        }
+        let color = color.to_ascii_lowercase();
+        self.editor_recent_colors.retain(|c| *c != color);
+        self.editor_recent_colors.insert(0, color);
+        self.editor_recent_colors.truncate(MAX_RECENT_COLORS);
+        true
+    }
+}
+
+fn default_accent_color() -> String {
+    "#e0a84a".to_string()
+}
+
+fn is_valid_hex_color(s: &str) -> bool {
+    s.len() == 7 && s.starts_with('#') && s[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
 impl Default for UiConfig {
     fn default() -> Self {
         Self {
             theme: Theme::Dark,
+            accent: Accent::Mono,
+            accent_color: default_accent_color(),
+            scale_percent: default_ui_scale_percent(),
+            compact: false,
+            language: Language::System,
             show_notifications: true,
             copy_to_clipboard: true,
             close_behavior: CloseBehavior::MinimizeToTray,
@@ -469,9 +1593,13 @@ impl Default for UiConfig {
             // before the user has chosen to, and with no persisted config the
             // launch-time reconcile would otherwise re-add it every boot
             auto_start: false,
+            context_menu: false,
+            start_minimized: true,
             check_updates_on_launch: true,
             save_clipboard_to_history: true,
             tray_hint_dismissed: false,
+            window_position: None,
+            editor_recent_colors: Vec::new(),
         }
     }
 }
@@ -520,7 +1648,12 @@ impl std::fmt::Display for PostCaptureAction {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct PostCaptureConfig {
-    pub action: PostCaptureAction,
+    /// what a capture started without a task (tray menu, jump list) does
+    /// with the result, per kind of capture. replaces the single `action`,
+    /// which nothing ever read, so it isn't migrated
+    pub actions: BTreeMap<CaptureType, TaskPostAction>,
+    /// open screenshots and recordings saved by a capture in the system's
+    /// default viewer
     pub open_file_after_save: bool,
     pub play_sound: bool,
 }
@@ -528,13 +1661,48 @@ pub struct PostCaptureConfig {
 impl Default for PostCaptureConfig {
     fn default() -> Self {
         Self {
-            action: PostCaptureAction::SaveAndCopy,
+            actions: CaptureType::all()
+                .iter()
+                .map(|&kind| (kind, default_post_action(kind)))
+                .collect(),
             open_file_after_save: false,
             play_sound: true,
         }
     }
 }
 
+// what the tray did before the actions were configurable
+fn default_post_action(kind: CaptureType) -> TaskPostAction {
+    match kind {
+        CaptureType::Gif => TaskPostAction::SaveFile,
+        _ => TaskPostAction::Clipboard,
+    }
+}
+
+impl PostCaptureConfig {
+    pub fn action_for(&self, kind: CaptureType) -> TaskPostAction {
+        self.actions
+            .get(&kind)
+            .copied()
+            .unwrap_or_else(|| default_post_action(kind))
+    }
+
+    fn sanitize(&mut self) {
+        for &kind in CaptureType::all() {
+            self.actions
+                .entry(kind)
+                .or_insert_with(|| default_post_action(kind));
+        }
+        // the editor would flatten a recording and there's no still frame to
+        // read text from; the tasks view hides the same two for recordings
+        if let Some(gif) = self.actions.get_mut(&CaptureType::Gif) {
+            if matches!(gif, TaskPostAction::CopyText | TaskPostAction::OpenEditor) {
+                *gif = TaskPostAction::SaveFile;
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub enum UploadDestination {
     #[default]
@@ -543,6 +1711,14 @@ pub enum UploadDestination {
     Ftp,
     Sftp,
     S3,
+    /// WebDAV, with public share links on Nextcloud/ownCloud
+    Webdav,
+    /// a signed-in dropbox account; uploads come back as shared links
+    Dropbox,
+    /// hands the capture to the default mail client as an attachment
+    Email,
+    /// posts the capture to a chat channel's incoming webhook
+    Webhook,
 }
 
 impl UploadDestination {
@@ -553,9 +1729,19 @@ impl UploadDestination {
             UploadDestination::Ftp,
             UploadDestination::Sftp,
             UploadDestination::S3,
+            UploadDestination::Webdav,
+            UploadDestination::Dropbox,
+            UploadDestination::Email,
+            UploadDestination::Webhook,
         ]
     }
 
+    /// destinations that end at a public link, which the link-only webhooks
+    /// need to host the image first
+    pub fn hosts_links(&self) -> bool {
+        !matches!(self, UploadDestination::Email | UploadDestination::Webhook)
+    }
+
     pub fn display_name(&self) -> &'static str {
         match self {
             UploadDestination::Imgur => "Imgur",
@@ -563,6 +1749,10 @@ impl UploadDestination {
             UploadDestination::Ftp => "FTP",
             UploadDestination::Sftp => "SFTP",
             UploadDestination::S3 => "S3 Compatible",
+            UploadDestination::Webdav => "WebDAV / Nextcloud",
+            UploadDestination::Dropbox => "Dropbox",
+            UploadDestination::Email => "Email",
+            UploadDestination::Webhook => "Chat webhook",
         }
     }
 }
@@ -581,9 +1771,12 @@ impl std::fmt::Display for UploadDestination {
 pub struct UploadConfig {
     pub destination: UploadDestination,
     pub copy_url_to_clipboard: bool,
-    pub custom_url: String,
-    pub custom_form_name: String,
-    pub custom_response_path: String,
+    /// how the uploaded url is wrapped when it's copied
+    pub link_format: LinkFormat,
+    /// named custom HTTP uploaders. `destination = "Custom"` uploads through
+    /// `active_custom_preset`; tasks and the editor can pick any of them
+    pub custom_presets: Vec<CustomUploaderPreset>,
+    pub active_custom_preset: String,
     #[serde(default = "default_imgur_client_id")]
     pub imgur_client_id: String,
     #[serde(default)]
@@ -592,6 +1785,42 @@ pub struct UploadConfig {
     pub sftp: SftpUploadConfig,
     #[serde(default)]
     pub s3: S3UploadConfig,
+    #[serde(default)]
+    pub imgur: ImgurAccountConfig,
+    #[serde(default)]
+    pub webdav: WebdavUploadConfig,
+    #[serde(default)]
+    pub dropbox: DropboxConfig,
+    #[serde(default)]
+    pub email: EmailConfig,
+    #[serde(default)]
+    pub webhook: WebhookConfig,
+    /// downscale applied to captures before they are uploaded, independent
+    /// of the one for saved files
+    #[serde(default)]
+    pub resize: ResizeConfig,
+    #[serde(default)]
+    pub recompress: RecompressConfig,
+    #[serde(default)]
+    pub secret_scan: SecretScanConfig,
+    /// upload an AES-GCM sealed copy and put the key in the link's fragment.
+    /// only for destinations that host links and take any file type
+    pub encrypt: bool,
+    /// what happens when identical bytes already went to the same
+    /// destination within `dedupe_days`
+    pub dedupe: DedupeMode,
+    #[serde(default = "default_dedupe_days")]
+    pub dedupe_days: u32,
+    /// how the http destinations reach the internet. ftp and sftp connect
+    /// directly whatever this says
+    #[serde(default)]
+    pub proxy: ProxyConfig,
+}
+
+pub const MAX_DEDUPE_DAYS: u32 = 365;
+
+fn default_dedupe_days() -> u32 {
+    7
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -627,13 +1856,25 @@ impl Default for UploadConfig {
         Self {
             destination: UploadDestination::Imgur,
             copy_url_to_clipboard: true,
-            custom_url: String::new(),
-            custom_form_name: String::from("file"),
-            custom_response_path: String::from("url"),
+            link_format: LinkFormat::Url,
+            custom_presets: Vec::new(),
+            active_custom_preset: String::new(),
             imgur_client_id: default_imgur_client_id(),
             ftp: FtpUploadConfig::default(),
             sftp: SftpUploadConfig::default(),
             s3: S3UploadConfig::default(),
+            imgur: ImgurAccountConfig::default(),
+            webdav: WebdavUploadConfig::default(),
+            dropbox: DropboxConfig::default(),
+            email: EmailConfig::default(),
+            webhook: WebhookConfig::default(),
+            resize: ResizeConfig::default(),
+            recompress: RecompressConfig::default(),
+            secret_scan: SecretScanConfig::default(),
+            encrypt: false,
+            dedupe: DedupeMode::Ask,
+            dedupe_days: default_dedupe_days(),
+            proxy: ProxyConfig::default(),
         }
     }
 }
@@ -786,25 +2027,725 @@ impl S3UploadConfig {
     }
 }
 
-fn default_sftp_port() -> u16 {
-    22
-}
+pub const MAX_WEBDAV_PATH_TEMPLATE_LEN: usize = 256;
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-pub enum Theme {
-    Light,
-    Dark,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebdavUploadConfig {
+    /// the account's WebDAV root, e.g.
+    /// https://cloud.example.com/remote.php/dav/files/me
+    pub server_url: String,
+    pub username: String,
+    /// plaintext password (legacy field); an app password on nextcloud
+    pub password: String,
+    /// DPAPI-wrapped password
+    pub password_encrypted: String,
+    /// folder below the root that captures go into; strftime fields allowed
+    pub path_template: String,
+    /// make a public share link through the nextcloud/ownCloud sharing api
+    pub share_link: bool,
+    /// used when share_link is off. `{path}` is the expanded folder and
+    /// `{filename}` the uploaded name; empty returns the WebDAV url itself
+    pub public_url_template: String,
+}
+
+impl Default for WebdavUploadConfig {
+    fn default() -> Self {
+        Self {
+            server_url: String::new(),
+            username: String::new(),
+            password: String::new(),
+            password_encrypted: String::new(),
+            path_template: "capscr/%Y-%m".to_string(),
+            share_link: true,
+            public_url_template: String::new(),
+        }
+    }
+}
+
+impl WebdavUploadConfig {
+    pub fn password_plaintext(&self) -> String {
+        if !self.password_encrypted.is_empty() {
+            match crate::secret::decrypt(&self.password_encrypted) {
+                Ok(p) => return p,
+                Err(e) => {
+                    tracing::warn!("WebDAV password decrypt failed: {e}");
+                }
+            }
+        }
+        self.password.clone()
+    }
+
+    fn sanitize(&mut self) {
+        self.server_url = self.server_url.trim().to_string();
+        truncate_utf8(&mut self.path_template, MAX_WEBDAV_PATH_TEMPLATE_LEN);
+    }
+}
+
+pub const MAX_DROPBOX_FOLDER_LEN: usize = 256;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DropboxConfig {
+    /// app key of the user's own dropbox app. sign-in uses PKCE, so there
+    /// is no app secret to keep
+    pub app_key: String,
+    /// vault-wrapped refresh token. only the sign-in/out commands write it;
+    /// set_config carries the stored value forward
+    pub refresh_token_encrypted: String,
+    /// account name reported after sign-in, shown in Destinations
+    pub account_name: String,
+    /// folder uploads go into, from the dropbox root (or the app folder for
+    /// an app-folder app)
+    pub folder: String,
+}
+
+impl Default for DropboxConfig {
+    fn default() -> Self {
+        Self {
+            app_key: String::new(),
+            refresh_token_encrypted: String::new(),
+            account_name: String::new(),
+            folder: "/capscr".to_string(),
+        }
+    }
+}
+
+impl DropboxConfig {
+    pub fn refresh_token_plaintext(&self) -> Option<String> {
+        if self.refresh_token_encrypted.is_empty() {
+            return None;
+        }
+        match crate::secret::decrypt(&self.refresh_token_encrypted) {
+            Ok(t) if !t.is_empty() => Some(t),
+            Ok(_) => None,
+            Err(e) => {
+                tracing::warn!("Dropbox refresh token decrypt failed: {e}");
+                None
+            }
+        }
+    }
+
+    fn sanitize(&mut self) {
+        self.app_key = self.app_key.trim().to_string();
+        let folder: Vec<&str> = self
+            .folder
+            .split(['/', '\\'])
+            .map(str::trim)
+            .filter(|s| !s.is_empty() && *s != "." && *s != "..")
+            .collect();
+        self.folder = format!("/{}", folder.join("/"));
+        truncate_utf8(&mut self.folder, MAX_DROPBOX_FOLDER_LEN);
+    }
+}
+
+pub const MAX_EMAIL_SUBJECT_LEN: usize = 200;
+pub const MAX_WEBHOOK_MESSAGE_LEN: usize = 2000;
+
+/// the "email" destination: the mail client opens a new message with the
+/// capture attached, and the user sends it from there
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmailConfig {
+    /// prefilled recipient; empty leaves the To field for the user
+    pub to: String,
+    pub subject: String,
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            to: String::new(),
+            subject: "Screenshot".to_string(),
+        }
+    }
+}
+
+impl EmailConfig {
+    /// a single plain address or nothing; anything else would end up as an
+    /// extra argument to the mail client
+    pub fn recipient_is_valid(&self) -> bool {
+        // a save from settings validates before anything trims
+        let to = self.to.trim();
+        to.is_empty()
+            || (!to.starts_with('-')
+                && to.contains('@')
+                && !to.chars().any(|c| c.is_whitespace() || c.is_control()))
+    }
+
+    fn sanitize(&mut self) {
+        self.to = self.to.trim().to_string();
+        if !self.recipient_is_valid() {
+            self.to.clear();
+        }
+        self.subject = self.subject.replace(|c: char| c.is_control(), " ");
+        truncate_utf8(&mut self.subject, MAX_EMAIL_SUBJECT_LEN);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum WebhookKind {
+    #[default]
+    Discord,
+    Slack,
+    Teams,
+}
+
+impl WebhookKind {
+    pub fn all() -> &'static [WebhookKind] {
+        &[WebhookKind::Discord, WebhookKind::Slack, WebhookKind::Teams]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WebhookKind::Discord => "Discord",
+            WebhookKind::Slack => "Slack",
+            WebhookKind::Teams => "Microsoft Teams",
+        }
+    }
+
+    /// discord takes the file itself; slack and teams incoming webhooks only
+    /// take text, so those post a link to a copy on `link_host`
+    pub fn needs_link_host(&self) -> bool {
+        !matches!(self, WebhookKind::Discord)
+    }
+}
+
+/// the "chat webhook" destination
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhookConfig {
+    pub kind: WebhookKind,
+    /// plaintext webhook url as typed in the UI. the url alone is enough to
+    /// post to the channel, so it's a secret and gets vault-wrapped on save
+    pub url: String,
+    pub url_encrypted: String,
+    /// optional text posted alongside the capture
+    pub message: String,
+    /// where slack and teams captures are uploaded for the link
+    pub link_host: UploadDestination,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            kind: WebhookKind::Discord,
+            url: String::new(),
+            url_encrypted: String::new(),
+            message: String::new(),
+            link_host: UploadDestination::Imgur,
+        }
+    }
+}
+
+impl WebhookConfig {
+    pub fn url_plaintext(&self) -> String {
+        if !self.url_encrypted.is_empty() {
+            match crate::secret::decrypt(&self.url_encrypted) {
+                Ok(p) => return p,
+                Err(e) => {
+                    tracing::warn!("webhook url decrypt failed: {e}");
+                }
+            }
+        }
+        self.url.clone()
+    }
+
+    fn sanitize(&mut self) {
+        self.url = self.url.trim().to_string();
+        truncate_utf8(&mut self.message, MAX_WEBHOOK_MESSAGE_LEN);
+        if !self.link_host.hosts_links() {
+            self.link_host = UploadDestination::Imgur;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProxyMode {
+    /// whatever the OS or the HTTP(S)_PROXY variables say
+    #[default]
+    System,
+    /// never use a proxy
+    Direct,
+    /// the proxy in `ProxyConfig::url`
+    Manual,
+}
+
+impl ProxyMode {
+    pub fn all() -> &'static [ProxyMode] {
+        &[ProxyMode::System, ProxyMode::Direct, ProxyMode::Manual]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ProxyMode::System => "System proxy",
+            ProxyMode::Direct => "No proxy",
+            ProxyMode::Manual => "Manual",
+        }
+    }
+}
+
+pub const MAX_PROXY_URL_LEN: usize = 512;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ProxyConfig {
+    pub mode: ProxyMode,
+    /// http://, https://, socks5:// or socks5h:// with host and port
+    pub url: String,
+    pub username: String,
+    /// plaintext only until the next save moves it to the credential vault
+    pub password: String,
+    pub password_encrypted: String,
+}
+
+impl ProxyConfig {
+    pub fn password_plaintext(&self) -> String {
+        if !self.password_encrypted.is_empty() {
+            match crate::secret::decrypt(&self.password_encrypted) {
+                Ok(p) => return p,
+                Err(e) => {
+                    tracing::warn!("proxy password decrypt failed: {e}");
+                }
+            }
+        }
+        self.password.clone()
+    }
+
+    fn sanitize(&mut self) {
+        self.url = self.url.trim().to_string();
+        self.username = self.username.trim().to_string();
+        // a broken manual proxy would fail every upload; validate() refuses
+        // one at save time, so this only repairs a hand-edited file
+        if self.mode == ProxyMode::Manual
+            && (self.url.len() > MAX_PROXY_URL_LEN
+                || crate::upload::proxy::parse_proxy_url(&self.url).is_err())
+        {
+            tracing::warn!("ignoring invalid manual upload proxy '{}'", self.url);
+            self.mode = ProxyMode::System;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum CustomHttpMethod {
+    /// multipart form upload
+    #[default]
+    Post,
+    /// raw file body; extra form fields are sent as query parameters
+    Put,
+}
+
+impl CustomHttpMethod {
+    pub fn all() -> &'static [CustomHttpMethod] {
+        &[CustomHttpMethod::Post, CustomHttpMethod::Put]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CustomHttpMethod::Post => "POST (multipart)",
+            CustomHttpMethod::Put => "PUT (raw body)",
+        }
+    }
+}
+
+/// extra request header for the custom uploader. `secret` values (api keys,
+/// bearer tokens) are vault-wrapped on save like the FTP password
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct CustomHeader {
+    pub name: String,
+    pub value: String,
+    pub secret: bool,
+    pub value_encrypted: String,
+}
+
+impl CustomHeader {
+    pub fn value_plaintext(&self) -> String {
+        if !self.value_encrypted.is_empty() {
+            match crate::secret::decrypt(&self.value_encrypted) {
+                Ok(v) => return v,
+                Err(e) => {
+                    tracing::warn!("custom header '{}' decrypt failed: {e}", self.name);
+                }
+            }
+        }
+        self.value.clone()
+    }
+}
+
+/// extra form field (or query parameter, for PUT) for the custom uploader.
+/// ShareX-style hosts take their api key here, so `secret` values get the
+/// same vault treatment as secret headers
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct CustomFormField {
+    pub name: String,
+    pub value: String,
+    pub secret: bool,
+    pub value_encrypted: String,
+}
+
+impl CustomFormField {
+    pub fn value_plaintext(&self) -> String {
+        if !self.value_encrypted.is_empty() {
+            match crate::secret::decrypt(&self.value_encrypted) {
+                Ok(v) => return v,
+                Err(e) => {
+                    tracing::warn!("custom form field '{}' decrypt failed: {e}", self.name);
+                }
+            }
+        }
+        self.value.clone()
+    }
+}
+
+/// one named custom HTTP uploader
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct CustomUploaderPreset {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub method: CustomHttpMethod,
+    /// multipart key the file goes under (POST only)
+    pub form_name: String,
+    /// dotted json path to the url in the response
+    pub response_path: String,
+    /// regex alternative to `response_path`: the first capture group (or the
+    /// whole match) over the raw response body is the url
+    pub response_regex: String,
+    pub headers: Vec<CustomHeader>,
+    pub form_fields: Vec<CustomFormField>,
+    /// the host's upload limit in MB; 0 leaves only capscr's own cap
+    pub max_file_mb: u32,
+}
+
+impl Default for CustomUploaderPreset {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            name: String::from("Custom"),
+            url: String::new(),
+            method: CustomHttpMethod::Post,
+            form_name: String::from("file"),
+            response_path: String::from("url"),
+            response_regex: String::new(),
+            headers: Vec::new(),
+            form_fields: Vec::new(),
+            max_file_mb: 0,
+        }
+    }
+}
+
+impl CustomUploaderPreset {
+    fn validate(&self) -> Result<()> {
+        if self.id.trim().is_empty() || self.id.len() > MAX_PRESET_ID_LEN {
+            return Err(anyhow!("custom uploader preset needs an id"));
+        }
+        if self.name.len() > MAX_PRESET_NAME_LEN {
+            return Err(anyhow!("custom uploader preset name too long"));
+        }
+        if self.url.len() > MAX_CUSTOM_URL_LEN {
+            return Err(anyhow!("custom upload URL too long"));
+        }
+        if !self.url.is_empty() && !self.url.starts_with("https://") {
+            return Err(anyhow!("custom upload URL must use HTTPS"));
+        }
+        if self.form_name.len() > MAX_FORM_NAME_LEN {
+            return Err(anyhow!("custom form name too long"));
+        }
+        if !self.form_name.is_empty() && !is_valid_form_name(&self.form_name) {
+            return Err(anyhow!("custom form name contains invalid characters"));
+        }
+        if self.response_path.len() > MAX_RESPONSE_PATH_LEN {
+            return Err(anyhow!("custom response path too long"));
+        }
+        if !self.response_path.is_empty() && !is_valid_response_path(&self.response_path) {
+            return Err(anyhow!("custom response path has invalid format"));
+        }
+        if !self.response_regex.is_empty() {
+            crate::upload::compile_response_regex(&self.response_regex)?;
+        }
+        if self.headers.len() > MAX_CUSTOM_EXTRAS || self.form_fields.len() > MAX_CUSTOM_EXTRAS {
+            return Err(anyhow!(
+                "custom uploader allows at most {} headers and {} form fields",
+                MAX_CUSTOM_EXTRAS,
+                MAX_CUSTOM_EXTRAS
+            ));
+        }
+        if self.headers.iter().any(|h| h.name.trim().is_empty())
+            || self.form_fields.iter().any(|f| f.name.trim().is_empty())
+        {
+            return Err(anyhow!("custom header and form field names can't be empty"));
+        }
+        Ok(())
+    }
+
+    fn sanitize(&mut self) {
+        truncate_utf8(&mut self.name, MAX_PRESET_NAME_LEN);
+        self.max_file_mb = self.max_file_mb.min(MAX_CUSTOM_FILE_MB);
+        if self.form_name.len() > MAX_FORM_NAME_LEN || !is_valid_form_name(&self.form_name) {
+            self.form_name = "file".to_string();
+        }
+        if self.response_path.len() > MAX_RESPONSE_PATH_LEN
+            || !is_valid_response_path(&self.response_path)
+        {
+            self.response_path = "url".to_string();
+        }
+        if self.url.len() > MAX_CUSTOM_URL_LEN
+            || (!self.url.is_empty() && !self.url.starts_with("https://"))
+        {
+            self.url = String::new();
+        }
+        if !self.response_regex.is_empty()
+            && crate::upload::compile_response_regex(&self.response_regex).is_err()
+        {
+            self.response_regex = String::new();
+        }
+        self.headers.retain(|h| !h.name.trim().is_empty());
+        self.headers.truncate(MAX_CUSTOM_EXTRAS);
+        self.form_fields.retain(|f| !f.name.trim().is_empty());
+        self.form_fields.truncate(MAX_CUSTOM_EXTRAS);
+    }
+}
+
+impl UploadConfig {
+    /// the preset `id` names, falling back to the active one and then the
+    /// first, so a task pointing at a deleted preset still uploads somewhere
+    pub fn custom_preset(&self, id: Option<&str>) -> Option<&CustomUploaderPreset> {
+        id.and_then(|id| self.custom_presets.iter().find(|p| p.id == id))
+            .or_else(|| {
+                self.custom_presets
+                    .iter()
+                    .find(|p| p.id == self.active_custom_preset)
+            })
+            .or_else(|| self.custom_presets.first())
+    }
+
+    fn custom_headers_mut(&mut self) -> impl Iterator<Item = &mut CustomHeader> {
+        self.custom_presets
+            .iter_mut()
+            .flat_map(|p| p.headers.iter_mut())
+    }
+
+    fn custom_form_fields_mut(&mut self) -> impl Iterator<Item = &mut CustomFormField> {
+        self.custom_presets
+            .iter_mut()
+            .flat_map(|p| p.form_fields.iter_mut())
+    }
+}
+
+fn is_valid_form_name(name: &str) -> bool {
+    name.chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn is_valid_response_path(path: &str) -> bool {
+    path.chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-')
+        && !path.starts_with('.')
+        && !path.ends_with('.')
+        && !path.contains("..")
+}
+
+/// text copied to the clipboard after an upload
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum LinkFormat {
+    #[default]
+    Url,
+    /// `![](url)`
+    Markdown,
+    /// `<img src="url">`
+    Html,
+    /// `[img]url[/img]`
+    Bbcode,
+}
+
+impl LinkFormat {
+    pub fn all() -> &'static [LinkFormat] {
+        &[
+            LinkFormat::Url,
+            LinkFormat::Markdown,
+            LinkFormat::Html,
+            LinkFormat::Bbcode,
+        ]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LinkFormat::Url => "Raw URL",
+            LinkFormat::Markdown => "Markdown",
+            LinkFormat::Html => "HTML <img>",
+            LinkFormat::Bbcode => "BBCode",
+        }
+    }
+}
+
+/// reuse of an earlier upload's link when the same bytes are uploaded again
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum DedupeMode {
+    /// always upload a fresh copy
+    Off,
+    /// ask whether to reuse the earlier link
+    #[default]
+    Ask,
+    /// reuse the earlier link without asking
+    Reuse,
+}
+
+impl DedupeMode {
+    pub fn all() -> &'static [DedupeMode] {
+        &[DedupeMode::Off, DedupeMode::Ask, DedupeMode::Reuse]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            DedupeMode::Off => "Always upload",
+            DedupeMode::Ask => "Ask",
+            DedupeMode::Reuse => "Reuse the earlier link",
+        }
+    }
+}
+
+/// who can see an album created from capscr. mirrors imgur's album
+/// `privacy` values
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ImgurPrivacy {
+    /// reachable by link only, never listed on the profile
+    #[default]
+    Hidden,
+    /// listed on the account's public profile
+    Public,
+    /// like hidden, but imgur also strips it from search and embeds
+    Secret,
+}
+
+impl ImgurPrivacy {
+    pub fn all() -> &'static [ImgurPrivacy] {
+        &[
+            ImgurPrivacy::Hidden,
+            ImgurPrivacy::Public,
+            ImgurPrivacy::Secret,
+        ]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ImgurPrivacy::Hidden => "Hidden (link only)",
+            ImgurPrivacy::Public => "Public",
+            ImgurPrivacy::Secret => "Secret",
+        }
+    }
+
+    pub fn api_value(&self) -> &'static str {
+        match self {
+            ImgurPrivacy::Hidden => "hidden",
+            ImgurPrivacy::Public => "public",
+            ImgurPrivacy::Secret => "secret",
+        }
+    }
+}
+
+/// signed-in imgur account. when `refresh_token_encrypted` is set, uploads go
+/// to the account with a bearer token instead of the anonymous Client-ID
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ImgurAccountConfig {
+    /// OAuth client secret paired with `imgur_client_id`. imgur only issues
+    /// account tokens to a registered app, so the shared anonymous key can't
+    /// sign in. plaintext slot — migrated into the vault on save
+    pub client_secret: String,
+    pub client_secret_encrypted: String,
+    /// vault-wrapped refresh token. only the login/logout commands write it;
+    /// set_config carries the stored value forward
+    pub refresh_token_encrypted: String,
+    /// account name reported by the token exchange, shown in Destinations
+    pub username: String,
+    /// album id uploads are added to. empty = the account's loose images
+    pub album_id: String,
+    pub title: String,
+    pub description: String,
+    /// privacy for albums created from Destinations
+    pub album_privacy: ImgurPrivacy,
+}
+
+impl ImgurAccountConfig {
+    pub fn client_secret_plaintext(&self) -> String {
+        if !self.client_secret_encrypted.is_empty() {
+            match crate::secret::decrypt(&self.client_secret_encrypted) {
+                Ok(p) => return p,
+                Err(e) => {
+                    tracing::warn!("Imgur client secret decrypt failed: {e}");
+                }
+            }
+        }
+        self.client_secret.clone()
+    }
+
+    pub fn refresh_token_plaintext(&self) -> Option<String> {
+        if self.refresh_token_encrypted.is_empty() {
+            return None;
+        }
+        match crate::secret::decrypt(&self.refresh_token_encrypted) {
+            Ok(t) if !t.is_empty() => Some(t),
+            Ok(_) => None,
+            Err(e) => {
+                tracing::warn!("Imgur refresh token decrypt failed: {e}");
+                None
+            }
+        }
+    }
+
+    pub fn is_signed_in(&self) -> bool {
+        !self.refresh_token_encrypted.is_empty()
+    }
+}
+
+// imgur album ids are short base62 slugs; this also keeps the value safe to
+// splice into an api path
+fn is_valid_imgur_album_id(id: &str) -> bool {
+    id.len() <= MAX_IMGUR_ALBUM_ID_LEN && id.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn truncate_utf8(s: &mut String, max: usize) {
+    if s.len() > max {
+        let mut end = max;
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        s.truncate(end);
+    }
+}
+
+fn default_sftp_port() -> u16 {
+    22
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Theme {
+    Light,
+    Dark,
+    /// follow the OS dark/light preference, switching live when it changes
+    System,
 }
 
 impl Theme {
     pub fn all() -> &'static [Theme] {
-        &[Theme::Dark, Theme::Light]
+        &[Theme::Dark, Theme::Light, Theme::System]
     }
 
     pub fn display_name(&self) -> &'static str {
         match self {
             Theme::Light => "Light",
             Theme::Dark => "Dark",
+            Theme::System => "Follow system",
         }
     }
 }
@@ -815,6 +2756,43 @@ impl std::fmt::Display for Theme {
     }
 }
 
+// preset palettes; the hex values live with the css in frontend/src/theme.ts
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Accent {
+    #[default]
+    Mono,
+    Amber,
+    Phosphor,
+    Ice,
+    Rose,
+    Custom,
+}
+
+impl Accent {
+    pub fn all() -> &'static [Accent] {
+        &[
+            Accent::Mono,
+            Accent::Amber,
+            Accent::Phosphor,
+            Accent::Ice,
+            Accent::Rose,
+            Accent::Custom,
+        ]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Accent::Mono => "Mono",
+            Accent::Amber => "Amber",
+            Accent::Phosphor => "Phosphor",
+            Accent::Ice => "Ice",
+            Accent::Rose => "Rose",
+            Accent::Custom => "Custom",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "kebab-case")]
 pub enum RendererBackend {
@@ -869,6 +2847,142 @@ impl Default for PerformanceConfig {
     }
 }
 
+/// interval ("auto") capture: a silent screenshot every `interval_secs` into
+/// its own folder until stopped from the tray or settings. it never starts by
+/// itself on launch, so only the parameters live here
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoCaptureConfig {
+    pub interval_secs: u32,
+    pub target: AutoCaptureTarget,
+    /// empty means an `auto` folder inside the output directory
+    pub directory: PathBuf,
+    /// oldest auto captures are deleted past this many files; 0 = unlimited
+    pub max_count: u32,
+    /// oldest auto captures are deleted past this many MiB; 0 = unlimited
+    pub max_disk_mb: u32,
+}
+
+impl Default for AutoCaptureConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 60,
+            target: AutoCaptureTarget::ActiveMonitor,
+            directory: PathBuf::new(),
+            max_count: 1000,
+            max_disk_mb: 0,
+        }
+    }
+}
+
+impl AutoCaptureConfig {
+    pub fn resolved_directory(&self, output: &OutputConfig) -> PathBuf {
+        if self.directory.as_os_str().is_empty() {
+            output.directory.join("auto")
+        } else {
+            self.directory.clone()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum AutoCaptureTarget {
+    #[default]
+    ActiveMonitor,
+    AllMonitors,
+}
+
+impl AutoCaptureTarget {
+    pub fn all() -> &'static [AutoCaptureTarget] {
+        &[
+            AutoCaptureTarget::ActiveMonitor,
+            AutoCaptureTarget::AllMonitors,
+        ]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AutoCaptureTarget::ActiveMonitor => "Active monitor",
+            AutoCaptureTarget::AllMonitors => "All monitors",
+        }
+    }
+}
+
+/// a folder another program writes screenshots into (a game's screenshot
+/// folder, say); images that appear there while capscr runs go through
+/// `action` as if they'd been captured
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatchFolderConfig {
+    pub enabled: bool,
+    pub directory: PathBuf,
+    /// clipboard, save-file, save-and-clipboard or upload; the rest need a
+    /// person at the keyboard and fall back to clipboard
+    pub action: TaskPostAction,
+}
+
+impl Default for WatchFolderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: PathBuf::new(),
+            action: TaskPostAction::Upload,
+        }
+    }
+}
+
+impl WatchFolderConfig {
+    pub fn is_supported_action(action: TaskPostAction) -> bool {
+        matches!(
+            action,
+            TaskPostAction::Clipboard
+                | TaskPostAction::SaveFile
+                | TaskPostAction::SaveAndClipboard
+                | TaskPostAction::Upload
+        )
+    }
+
+    fn saves(&self) -> bool {
+        matches!(
+            self.action,
+            TaskPostAction::SaveFile | TaskPostAction::SaveAndClipboard
+        )
+    }
+
+    /// whether the folder is one capscr writes this action's results into:
+    /// the output folder or anything under it (profile subfolders, the
+    /// originals archive) when saving, the history folder when uploading.
+    /// every result would be picked up again
+    fn watches_own_output(&self, output: &OutputConfig) -> bool {
+        if !self.enabled || self.directory.as_os_str().is_empty() {
+            return false;
+        }
+        let history = Config::config_dir().map(|d| d.join("history"));
+        let written = match self.action {
+            _ if self.saves() => Some(output.directory.clone()),
+            TaskPostAction::Upload => history,
+            _ => None,
+        };
+        written.is_some_and(|root| same_or_inside(&self.directory, &root))
+    }
+}
+
+// `dir` is `root` or below it, however either is spelled: canonical where the
+// folders exist, otherwise with trailing separators and `.` dropped, and
+// without regard to case on windows
+fn same_or_inside(dir: &Path, root: &Path) -> bool {
+    let normalize = |p: &Path| -> PathBuf {
+        let p = std::fs::canonicalize(p).unwrap_or_else(|_| p.components().collect());
+        if cfg!(windows) {
+            PathBuf::from(p.to_string_lossy().to_lowercase())
+        } else {
+            p
+        }
+    };
+    normalize(dir).starts_with(normalize(root))
+}
+
 impl Config {
     pub fn validate(&self) -> Result<()> {
         if self.output.quality > MAX_QUALITY {
@@ -921,7 +3035,20 @@ impl Config {
         if self.output.directory.to_string_lossy().contains("..") {
             return Err(anyhow!("output directory contains path traversal"));
         }
-        for hotkey in [&self.hotkeys.screenshot, &self.hotkeys.record_gif] {
+        if self
+            .output
+            .scratch_directory
+            .as_ref()
+            .is_some_and(|dir| !dir.is_absolute())
+        {
+            return Err(anyhow!("scratch folder must be an absolute path"));
+        }
+        for hotkey in [
+            &self.hotkeys.screenshot,
+            &self.hotkeys.record_gif,
+            &self.hotkeys.stop_recording,
+            &self.hotkeys.do_not_disturb,
+        ] {
             if hotkey.len() > MAX_HOTKEY_LEN {
                 return Err(anyhow!("hotkey string too long"));
             }
@@ -932,42 +3059,54 @@ impl Config {
                 return Err(anyhow!("hotkey contains invalid characters"));
             }
         }
-        if self.upload.custom_url.len() > MAX_CUSTOM_URL_LEN {
-            return Err(anyhow!("custom upload URL too long"));
+        if self.upload.custom_presets.len() > MAX_CUSTOM_PRESETS {
+            return Err(anyhow!(
+                "at most {} custom uploader presets",
+                MAX_CUSTOM_PRESETS
+            ));
         }
-        if !self.upload.custom_url.is_empty() && !self.upload.custom_url.starts_with("https://") {
-            return Err(anyhow!("custom upload URL must use HTTPS"));
+        let mut preset_ids = std::collections::HashSet::new();
+        for preset in &self.upload.custom_presets {
+            preset
+                .validate()
+                .map_err(|e| anyhow!("custom uploader '{}': {e}", preset.name))?;
+            if !preset_ids.insert(preset.id.as_str()) {
+                return Err(anyhow!(
+                    "duplicate custom uploader preset id '{}'",
+                    preset.id
+                ));
+            }
         }
-        if self.upload.custom_form_name.len() > MAX_FORM_NAME_LEN {
-            return Err(anyhow!("custom form name too long"));
+        if self.upload.proxy.mode == ProxyMode::Manual {
+            if self.upload.proxy.url.len() > MAX_PROXY_URL_LEN {
+                return Err(anyhow!("proxy url too long"));
+            }
+            crate::upload::proxy::parse_proxy_url(&self.upload.proxy.url)?;
         }
-        if !self.upload.custom_form_name.is_empty()
-            && !self
-                .upload
-                .custom_form_name
-                .chars()
-                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
-        {
-            return Err(anyhow!("custom form name contains invalid characters"));
+        if !(MIN_UI_SCALE_PERCENT..=MAX_UI_SCALE_PERCENT).contains(&self.ui.scale_percent) {
+            return Err(anyhow!(
+                "ui scale must be between {}% and {}%",
+                MIN_UI_SCALE_PERCENT,
+                MAX_UI_SCALE_PERCENT
+            ));
         }
-        if self.upload.custom_response_path.len() > MAX_RESPONSE_PATH_LEN {
-            return Err(anyhow!("custom response path too long"));
+        // it goes straight into a css custom property
+        if !is_valid_hex_color(&self.ui.accent_color) {
+            return Err(anyhow!("accent color must be a #rrggbb hex color"));
         }
-        if !self.upload.custom_response_path.is_empty() {
-            if !self
-                .upload
-                .custom_response_path
-                .chars()
-                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-')
-            {
-                return Err(anyhow!("custom response path contains invalid characters"));
-            }
-            if self.upload.custom_response_path.starts_with('.')
-                || self.upload.custom_response_path.ends_with('.')
-                || self.upload.custom_response_path.contains("..")
-            {
-                return Err(anyhow!("custom response path has invalid format"));
-            }
+        if !self.upload.email.recipient_is_valid() {
+            return Err(anyhow!("email recipient must be a single address"));
+        }
+        if !is_valid_imgur_album_id(&self.upload.imgur.album_id) {
+            return Err(anyhow!("imgur album id has invalid format"));
+        }
+        if self.upload.imgur.title.len() > MAX_IMGUR_TEXT_LEN
+            || self.upload.imgur.description.len() > MAX_IMGUR_TEXT_LEN
+        {
+            return Err(anyhow!(
+                "imgur title and description must be <= {} bytes",
+                MAX_IMGUR_TEXT_LEN
+            ));
         }
         if self.performance.tick_interval_ms < MIN_TICK_INTERVAL_MS
             || self.performance.tick_interval_ms > MAX_TICK_INTERVAL_MS
@@ -995,8 +3134,28 @@ impl Config {
             ));
         }
 
+        if !(MIN_AUTO_CAPTURE_INTERVAL_SECS..=MAX_AUTO_CAPTURE_INTERVAL_SECS)
+            .contains(&self.auto_capture.interval_secs)
+        {
+            return Err(anyhow!(
+                "auto_capture.interval_secs must be between {} and {}",
+                MIN_AUTO_CAPTURE_INTERVAL_SECS,
+                MAX_AUTO_CAPTURE_INTERVAL_SECS
+            ));
+        }
+        if self.auto_capture.directory.to_string_lossy().contains("..") {
+            return Err(anyhow!("auto capture directory contains path traversal"));
+        }
+        // saving into the folder being watched would pick every save up again
+        if self.watch_folder.watches_own_output(&self.output) {
+            return Err(anyhow!(
+                "the watch folder can't be a folder its action saves into"
+            ));
+        }
+
         let mut seen_ids = std::collections::HashSet::new();
         let mut seen_hotkeys = std::collections::HashSet::new();
+        let mut seen_gestures = std::collections::HashSet::new();
         for task in &self.capture_tasks {
             if task.id.is_empty()
                 || !task
@@ -1015,13 +3174,17 @@ impl Config {
             if !seen_ids.insert(task.id.clone()) {
                 return Err(anyhow!("duplicate capture_task id: {}", task.id));
             }
-            if !task.hotkey.is_empty() && !seen_hotkeys.insert(task.hotkey.clone()) {
+            if !task.hotkey.is_empty() && !seen_gestures.insert((task.hotkey.clone(), task.gesture))
+            {
                 return Err(anyhow!(
                     "duplicate hotkey '{}' on task '{}'",
                     task.hotkey,
                     task.id
                 ));
             }
+            if !task.hotkey.is_empty() {
+                seen_hotkeys.insert(task.hotkey.clone());
+            }
             if task.name.is_empty() || task.name.len() > 128 {
                 return Err(anyhow!("capture_task name length invalid for {}", task.id));
             }
@@ -1038,6 +3201,30 @@ impl Config {
                     task.id
                 ));
             }
+            if task.capture_mode == TaskCaptureMode::TargetWindow {
+                let target = task
+                    .target_window
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("capture_task '{}' has no window to target", task.id))?;
+                target
+                    .validate()
+                    .map_err(|e| anyhow!("capture_task '{}' window target: {e}", task.id))?;
+            }
+        }
+        if seen_hotkeys.contains(&self.hotkeys.stop_recording) {
+            return Err(anyhow!(
+                "stop-recording hotkey '{}' is already bound to a task",
+                self.hotkeys.stop_recording
+            ));
+        }
+        if self.hotkeys.do_not_disturb_minutes > MAX_DND_MINUTES {
+            return Err(anyhow!(
+                "do not disturb runs for at most {MAX_DND_MINUTES} minutes"
+            ));
+        }
+        let dnd = &self.hotkeys.do_not_disturb;
+        if seen_hotkeys.contains(dnd) || (!dnd.is_empty() && *dnd == self.hotkeys.stop_recording) {
+            return Err(anyhow!("do-not-disturb hotkey '{dnd}' is already bound"));
         }
         Ok(())
     }
@@ -1051,6 +3238,53 @@ impl Config {
             .gif_max_duration_secs
             .min(MAX_GIF_DURATION_SECS);
         self.capture.delay_ms = self.capture.delay_ms.min(MAX_DELAY_MS);
+        self.capture.exclusion.sanitize();
+        self.capture.game_mode.sanitize();
+        self.capture.zoom.sanitize();
+        self.capture.stream.sanitize();
+        self.ui.scale_percent = self
+            .ui
+            .scale_percent
+            .clamp(MIN_UI_SCALE_PERCENT, MAX_UI_SCALE_PERCENT);
+        self.output.decoration.sanitize();
+        self.post_capture.sanitize();
+        self.output.resize.sanitize();
+        self.output.sanitize_app_profiles();
+        self.upload.resize.sanitize();
+        self.upload.recompress.sanitize();
+        self.upload.webdav.sanitize();
+        self.upload.dropbox.sanitize();
+        self.upload.email.sanitize();
+        self.upload.webhook.sanitize();
+        self.upload.dedupe_days = self.upload.dedupe_days.clamp(1, MAX_DEDUPE_DAYS);
+        self.upload.proxy.sanitize();
+        // the string goes straight into a css custom property
+        if !is_valid_hex_color(&self.ui.accent_color) {
+            self.ui.accent_color = default_accent_color();
+        }
+        self.ui
+            .editor_recent_colors
+            .retain(|c| is_valid_hex_color(c));
+        // a relative scratch folder would land wherever capscr was started from
+        if self
+            .output
+            .scratch_directory
+            .as_ref()
+            .is_some_and(|dir| !dir.is_absolute())
+        {
+            self.output.scratch_directory = None;
+        }
+        self.output.scratch_cap_mb = self.output.scratch_cap_mb.min(MAX_SCRATCH_CAP_MB);
+        // same for the archive, which would otherwise scatter originals
+        if self
+            .output
+            .archive_directory
+            .as_ref()
+            .is_some_and(|dir| !dir.is_absolute())
+        {
+            self.output.archive_directory = None;
+        }
+        self.ui.editor_recent_colors.truncate(MAX_RECENT_COLORS);
 
         if self.output.filename_template.len() > MAX_FILENAME_TEMPLATE_LEN
             || self.output.filename_template.contains('/')
@@ -1060,35 +3294,35 @@ impl Config {
             self.output.filename_template = "capture_%Y%m%d_%H%M%S".to_string();
         }
 
-        if self.upload.custom_form_name.len() > MAX_FORM_NAME_LEN
-            || !self
-                .upload
-                .custom_form_name
-                .chars()
-                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
-        {
-            self.upload.custom_form_name = "file".to_string();
+        let mut preset_ids = std::collections::HashSet::new();
+        self.upload.custom_presets.retain(|p| {
+            !p.id.trim().is_empty()
+                && p.id.len() <= MAX_PRESET_ID_LEN
+                && preset_ids.insert(p.id.clone())
+        });
+        self.upload.custom_presets.truncate(MAX_CUSTOM_PRESETS);
+        for preset in &mut self.upload.custom_presets {
+            preset.sanitize();
         }
-
-        if self.upload.custom_response_path.len() > MAX_RESPONSE_PATH_LEN
-            || !self
-                .upload
-                .custom_response_path
-                .chars()
-                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-')
-            || self.upload.custom_response_path.starts_with('.')
-            || self.upload.custom_response_path.ends_with('.')
-            || self.upload.custom_response_path.contains("..")
+        if !self
+            .upload
+            .custom_presets
+            .iter()
+            .any(|p| p.id == self.upload.active_custom_preset)
         {
-            self.upload.custom_response_path = "url".to_string();
+            self.upload.active_custom_preset = self
+                .upload
+                .custom_presets
+                .first()
+                .map(|p| p.id.clone())
+                .unwrap_or_default();
         }
 
-        if self.upload.custom_url.len() > MAX_CUSTOM_URL_LEN
-            || (!self.upload.custom_url.is_empty()
-                && !self.upload.custom_url.starts_with("https://"))
-        {
-            self.upload.custom_url = String::new();
+        if !is_valid_imgur_album_id(&self.upload.imgur.album_id) {
+            self.upload.imgur.album_id = String::new();
         }
+        truncate_utf8(&mut self.upload.imgur.title, MAX_IMGUR_TEXT_LEN);
+        truncate_utf8(&mut self.upload.imgur.description, MAX_IMGUR_TEXT_LEN);
 
         // repair a bad output directory in place so validate() doesn't fail the
         // whole config: reset an empty or traversal path to the default captures
@@ -1125,18 +3359,45 @@ impl Config {
             self.capture.hdr.user_brightness_scale = 100.0;
         }
 
+        self.auto_capture.interval_secs = self.auto_capture.interval_secs.clamp(
+            MIN_AUTO_CAPTURE_INTERVAL_SECS,
+            MAX_AUTO_CAPTURE_INTERVAL_SECS,
+        );
+        if self.auto_capture.directory.to_string_lossy().contains("..") {
+            self.auto_capture.directory = PathBuf::new();
+        }
+        // never upload what the user didn't choose to publish
+        if !WatchFolderConfig::is_supported_action(self.watch_folder.action) {
+            self.watch_folder.action = TaskPostAction::Clipboard;
+        }
+        if self.watch_folder.watches_own_output(&self.output) {
+            self.watch_folder.enabled = false;
+        }
+
         let hotkey_chars_ok = |s: &str| {
             s.chars()
                 .all(|c| c.is_alphanumeric() || c == '+' || c == ' ')
         };
-        for hk in [&mut self.hotkeys.screenshot, &mut self.hotkeys.record_gif] {
+        for hk in [
+            &mut self.hotkeys.screenshot,
+            &mut self.hotkeys.record_gif,
+            &mut self.hotkeys.stop_recording,
+            &mut self.hotkeys.do_not_disturb,
+        ] {
             if hk.len() > MAX_HOTKEY_LEN || !hotkey_chars_ok(hk) {
                 hk.clear();
             }
         }
 
+        let known_presets: std::collections::HashSet<&str> = self
+            .upload
+            .custom_presets
+            .iter()
+            .map(|p| p.id.as_str())
+            .collect();
         let mut seen_ids = std::collections::HashSet::new();
         let mut seen_hotkeys = std::collections::HashSet::new();
+        let mut seen_gestures = std::collections::HashSet::new();
         self.capture_tasks.retain_mut(|task| {
             // an empty/malformed id or name can't be repaired meaningfully; drop
             // the task rather than nuke the whole config
@@ -1155,22 +3416,83 @@ impl Config {
             if task.hotkey.len() > MAX_HOTKEY_LEN || !hotkey_chars_ok(&task.hotkey) {
                 task.hotkey.clear();
             }
-            if !task.hotkey.is_empty() && !seen_hotkeys.insert(task.hotkey.clone()) {
+            if !task.hotkey.is_empty() && !seen_gestures.insert((task.hotkey.clone(), task.gesture))
+            {
                 task.hotkey.clear();
             }
+            if !task.hotkey.is_empty() {
+                seen_hotkeys.insert(task.hotkey.clone());
+            }
+            // an unusable window target degrades the task to a normal window
+            // pick instead of dropping it
+            if task
+                .target_window
+                .as_ref()
+                .is_some_and(|target| target.validate().is_err())
+            {
+                task.target_window = None;
+            }
+            if task.capture_mode == TaskCaptureMode::TargetWindow && task.target_window.is_none() {
+                task.capture_mode = TaskCaptureMode::Window;
+            }
+            if let Some(size) = task.recording_size.as_mut() {
+                size.width = size.width.clamp(MIN_RECORDING_SIZE, MAX_RECORDING_SIZE);
+                size.height = size.height.clamp(MIN_RECORDING_SIZE, MAX_RECORDING_SIZE);
+            }
+            if !matches!(
+                task.capture_mode,
+                TaskCaptureMode::RegionGif | TaskCaptureMode::RegionMp4
+            ) {
+                task.hold_to_record = false;
+            }
+            // a deleted preset falls back to the active one
+            if task
+                .custom_preset
+                .as_deref()
+                .is_some_and(|id| !known_presets.contains(id))
+            {
+                task.custom_preset = None;
+            }
             true
         });
+        // a gestured hotkey only knows which task it meant once the gesture
+        // completes, by which point a hold has already been let go
+        let gestured: std::collections::HashSet<String> = self
+            .capture_tasks
+            .iter()
+            .filter(|t| !t.hotkey.is_empty() && t.gesture != HotkeyGesture::Press)
+            .map(|t| t.hotkey.clone())
+            .collect();
+        for task in &mut self.capture_tasks {
+            if gestured.contains(&task.hotkey) {
+                task.hold_to_record = false;
+            }
+        }
+        // a task keeps its hotkey; the stop binding gives way
+        if seen_hotkeys.contains(&self.hotkeys.stop_recording) {
+            self.hotkeys.stop_recording.clear();
+        }
+        let dnd = &self.hotkeys.do_not_disturb;
+        if seen_hotkeys.contains(dnd) || (!dnd.is_empty() && *dnd == self.hotkeys.stop_recording) {
+            self.hotkeys.do_not_disturb.clear();
+        }
+        self.hotkeys.do_not_disturb_minutes =
+            self.hotkeys.do_not_disturb_minutes.min(MAX_DND_MINUTES);
     }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            version: CONFIG_VERSION,
             output: OutputConfig::default(),
             capture: CaptureConfig::default(),
             hotkeys: HotkeyConfig {
                 screenshot: "Ctrl+Shift+S".to_string(),
                 record_gif: "Ctrl+Shift+G".to_string(),
+                stop_recording: String::new(),
+                do_not_disturb: String::new(),
+                do_not_disturb_minutes: 0,
                 disabled_globally: false,
                 advanced_input: None,
             },
@@ -1179,6 +3501,8 @@ impl Default for Config {
             upload: UploadConfig::default(),
             performance: PerformanceConfig::default(),
             marketplace: MarketplaceConfig::default(),
+            auto_capture: AutoCaptureConfig::default(),
+            watch_folder: WatchFolderConfig::default(),
             capture_tasks: default_capture_tasks(),
         }
     }
@@ -1197,8 +3521,8 @@ impl Config {
         if let Some(path) = Self::config_path() {
             if path.exists() {
                 let content = fs::read_to_string(&path)?;
-                match toml::from_str::<Config>(&content) {
-                    Ok(mut config) => {
+                match Self::from_toml(&content) {
+                    Ok((mut config, written_under)) => {
                         config.sanitize();
                         if let Err(e) = config.validate() {
                             // keep the validated-default fallback but preserve the
@@ -1211,6 +3535,22 @@ impl Config {
                             );
                             return Ok(Config::default());
                         }
+                        if written_under < CONFIG_VERSION {
+                            // the pre-upgrade file stays next to it, for going
+                            // back to an older capscr
+                            let backup =
+                                path.with_file_name(format!("config.v{written_under}.toml"));
+                            if let Err(e) = fs::write(&backup, &content) {
+                                tracing::warn!("couldn't keep the pre-upgrade config: {e}");
+                            } else if let Err(e) = config.save() {
+                                tracing::warn!("couldn't write the upgraded config: {e}");
+                            }
+                        } else if written_under > CONFIG_VERSION {
+                            tracing::warn!(
+                                "config.toml is from a newer capscr (schema {written_under}); \
+                                 settings this version doesn't know are ignored"
+                            );
+                        }
                         // first-launch-after-upgrade migration: if the legacy
                         // plaintext FTP password is set but the encrypted slot
                         // is empty, wrap it now and persist. The user never
@@ -1224,7 +3564,33 @@ impl Config {
                                     .upload
                                     .sftp
                                     .private_key_passphrase_encrypted
-                                    .is_empty());
+                                    .is_empty())
+                            || (!config.upload.imgur.client_secret.is_empty()
+                                && config.upload.imgur.client_secret_encrypted.is_empty())
+                            || (!config.upload.webdav.password.is_empty()
+                                && config.upload.webdav.password_encrypted.is_empty())
+                            || (!config.upload.webhook.url.is_empty()
+                                && config.upload.webhook.url_encrypted.is_empty())
+                            || (!config.capture.stream.url.is_empty()
+                                && config.capture.stream.url_encrypted.is_empty())
+                            || (!config.upload.proxy.password.is_empty()
+                                && config.upload.proxy.password_encrypted.is_empty())
+                            || config
+                                .upload
+                                .custom_presets
+                                .iter()
+                                .flat_map(|p| &p.headers)
+                                .any(|h| {
+                                    h.secret && !h.value.is_empty() && h.value_encrypted.is_empty()
+                                })
+                            || config
+                                .upload
+                                .custom_presets
+                                .iter()
+                                .flat_map(|p| &p.form_fields)
+                                .any(|f| {
+                                    f.secret && !f.value.is_empty() && f.value_encrypted.is_empty()
+                                });
                         #[cfg(target_os = "linux")]
                         let needs_secret_migration = needs_secret_migration
                             || [
@@ -1232,8 +3598,31 @@ impl Config {
                                 &config.upload.sftp.password_encrypted,
                                 &config.upload.sftp.private_key_passphrase_encrypted,
                                 &config.upload.s3.secret_access_key_encrypted,
+                                &config.upload.imgur.client_secret_encrypted,
+                                &config.upload.imgur.refresh_token_encrypted,
+                                &config.upload.webdav.password_encrypted,
+                                &config.upload.dropbox.refresh_token_encrypted,
+                                &config.upload.webhook.url_encrypted,
+                                &config.upload.proxy.password_encrypted,
+                                &config.capture.stream.url_encrypted,
                             ]
-                            .iter()
+                            .into_iter()
+                            .chain(
+                                config
+                                    .upload
+                                    .custom_presets
+                                    .iter()
+                                    .flat_map(|p| &p.headers)
+                                    .map(|h| &h.value_encrypted),
+                            )
+                            .chain(
+                                config
+                                    .upload
+                                    .custom_presets
+                                    .iter()
+                                    .flat_map(|p| &p.form_fields)
+                                    .map(|f| &f.value_encrypted),
+                            )
                             .any(|blob| !blob.is_empty() && !blob.starts_with("keyring:"));
                         if needs_secret_migration {
                             if let Err(e) = config.migrate_secrets() {
@@ -1261,6 +3650,29 @@ impl Config {
         Ok(Config::default())
     }
 
+    /// parse, repair and validate a config file's contents without any of
+    /// load()'s fallbacks: a bad file is an error, not a reset to defaults
+    pub fn parse(content: &str) -> Result<Self> {
+        let (mut config, _) = Self::from_toml(content)?;
+        config.sanitize();
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// deserialize config.toml contents, bringing an older schema up to date
+    /// first. also returns the version the file was written under
+    fn from_toml(content: &str) -> Result<(Self, u32)> {
+        let mut table: toml::Table = toml::from_str(content)?;
+        let written_under = migrate::run(&mut table);
+        // an up-to-date file parses from the text, so errors keep their line
+        let config = if written_under == CONFIG_VERSION {
+            toml::from_str(content)?
+        } else {
+            toml::Value::Table(table).try_into()?
+        };
+        Ok((config, written_under))
+    }
+
     fn backup_corrupt_config(path: &Path, content: &str) {
         let mut backup = path.to_path_buf();
         let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
@@ -1285,9 +3697,18 @@ impl Config {
             if let Some(path) = Self::config_path() {
                 let content = toml::to_string_pretty(&to_persist)?;
                 // atomic write: write to a temp file, then rename so a crash
-                // mid-write can't leave config.toml truncated/corrupted
+                // mid-write can't leave config.toml truncated/corrupted. the
+                // sync makes sure the rename never lands ahead of the data
                 let tmp = path.with_extension("toml.tmp");
-                fs::write(&tmp, &content)?;
+                {
+                    use std::io::Write;
+                    let mut file = fs::File::create(&tmp)?;
+                    file.write_all(content.as_bytes())?;
+                    file.sync_all()?;
+                }
+                // recorded ahead of the rename so the watcher can't catch the
+                // new file before it knows the write was ours
+                *LAST_WRITTEN.lock().unwrap() = Some(content_hash(&content));
                 fs::rename(&tmp, &path)?;
             }
         }
@@ -1304,7 +3725,29 @@ impl Config {
             &mut self.upload.sftp.password_encrypted,
             &mut self.upload.sftp.private_key_passphrase_encrypted,
             &mut self.upload.s3.secret_access_key_encrypted,
-        ] {
+            &mut self.upload.imgur.client_secret_encrypted,
+            &mut self.upload.imgur.refresh_token_encrypted,
+            &mut self.upload.webdav.password_encrypted,
+            &mut self.upload.dropbox.refresh_token_encrypted,
+            &mut self.upload.webhook.url_encrypted,
+            &mut self.upload.proxy.password_encrypted,
+            &mut self.capture.stream.url_encrypted,
+        ]
+        .into_iter()
+        .chain(
+            self.upload
+                .custom_presets
+                .iter_mut()
+                .flat_map(|p| p.headers.iter_mut())
+                .map(|h| &mut h.value_encrypted),
+        )
+        .chain(
+            self.upload
+                .custom_presets
+                .iter_mut()
+                .flat_map(|p| p.form_fields.iter_mut())
+                .map(|f| &mut f.value_encrypted),
+        ) {
             if !blob.is_empty() && !blob.starts_with("keyring:") {
                 let plaintext = crate::secret::decrypt(blob)
                     .context("couldn't read a legacy on-disk credential")?;
@@ -1370,93 +3813,649 @@ impl Config {
                 }
             }
         }
+        let imgur = &mut self.upload.imgur;
+        if !imgur.client_secret.is_empty() && imgur.client_secret_encrypted.is_empty() {
+            match crate::secret::encrypt(&imgur.client_secret) {
+                Ok(blob) => {
+                    imgur.client_secret_encrypted = blob;
+                    imgur.client_secret.clear();
+                    tracing::info!("migrated Imgur client secret into encrypted vault");
+                }
+                Err(e) => {
+                    return Err(
+                        e.context("couldn't store Imgur client secret in the credential vault")
+                    )
+                }
+            }
+        }
+        let webdav = &mut self.upload.webdav;
+        if !webdav.password.is_empty() && webdav.password_encrypted.is_empty() {
+            match crate::secret::encrypt(&webdav.password) {
+                Ok(blob) => {
+                    webdav.password_encrypted = blob;
+                    webdav.password.clear();
+                    tracing::info!("migrated WebDAV password into encrypted vault");
+                }
+                Err(e) => {
+                    return Err(e.context("couldn't store WebDAV password in the credential vault"))
+                }
+            }
+        }
+        let webhook = &mut self.upload.webhook;
+        if !webhook.url.is_empty() && webhook.url_encrypted.is_empty() {
+            match crate::secret::encrypt(&webhook.url) {
+                Ok(blob) => {
+                    webhook.url_encrypted = blob;
+                    webhook.url.clear();
+                    tracing::info!("migrated webhook url into encrypted vault");
+                }
+                Err(e) => {
+                    return Err(e.context("couldn't store webhook url in the credential vault"))
+                }
+            }
+        }
+        let stream = &mut self.capture.stream;
+        if !stream.url.is_empty() && stream.url_encrypted.is_empty() {
+            match crate::secret::encrypt(&stream.url) {
+                Ok(blob) => {
+                    stream.url_encrypted = blob;
+                    stream.url.clear();
+                    tracing::info!("migrated stream url into encrypted vault");
+                }
+                Err(e) => {
+                    return Err(e.context("couldn't store stream url in the credential vault"))
+                }
+            }
+        }
+        let proxy = &mut self.upload.proxy;
+        if !proxy.password.is_empty() && proxy.password_encrypted.is_empty() {
+            match crate::secret::encrypt(&proxy.password) {
+                Ok(blob) => {
+                    proxy.password_encrypted = blob;
+                    proxy.password.clear();
+                    tracing::info!("migrated proxy password into encrypted vault");
+                }
+                Err(e) => {
+                    return Err(e.context("couldn't store proxy password in the credential vault"))
+                }
+            }
+        }
+        for header in self.upload.custom_headers_mut() {
+            if header.secret && !header.value.is_empty() && header.value_encrypted.is_empty() {
+                header.value_encrypted = crate::secret::encrypt(&header.value).map_err(|e| {
+                    e.context(format!(
+                        "couldn't store custom header '{}' in the credential vault",
+                        header.name
+                    ))
+                })?;
+                header.value.clear();
+                tracing::info!(
+                    "migrated custom header '{}' into encrypted vault",
+                    header.name
+                );
+            }
+        }
+        for field in self.upload.custom_form_fields_mut() {
+            if field.secret && !field.value.is_empty() && field.value_encrypted.is_empty() {
+                field.value_encrypted = crate::secret::encrypt(&field.value).map_err(|e| {
+                    e.context(format!(
+                        "couldn't store custom form field '{}' in the credential vault",
+                        field.name
+                    ))
+                })?;
+                field.value.clear();
+                tracing::info!(
+                    "migrated custom form field '{}' into encrypted vault",
+                    field.name
+                );
+            }
+        }
+        Ok(())
+    }
+
+    pub fn ensure_output_dir(&self) -> Result<()> {
+        let dir = &self.output.directory;
+        if dir.as_os_str().is_empty() {
+            return Err(anyhow!("Output directory path is empty"));
+        }
+
+        let dir_str = dir.to_string_lossy();
+        if dir_str.contains("..") {
+            return Err(anyhow!("Output directory contains path traversal"));
+        }
+
+        #[cfg(windows)]
+        {
+            if dir_str.starts_with("\\\\") {
+                return Err(anyhow!("Network paths are not allowed"));
+            }
+        }
+
+        fs::create_dir_all(dir)?;
+
+        // any local directory the user can actually write to is a valid
+        // output dir — people keep captures on secondary drives. Probe
+        // writability directly instead of whitelisting home/pictures/temp.
+        let probe = dir.join(".capscr-write-probe");
+        fs::write(&probe, b"").map_err(|e| anyhow!("Output directory is not writable: {e}"))?;
+        let _ = fs::remove_file(&probe);
+
         Ok(())
     }
 
-    pub fn ensure_output_dir(&self) -> Result<()> {
-        let dir = &self.output.directory;
-        if dir.as_os_str().is_empty() {
-            return Err(anyhow!("Output directory path is empty"));
-        }
+    pub fn generate_filename(&self) -> String {
+        let now = chrono::Local::now();
+        let formatted = now.format(&self.output.filename_template).to_string();
+        let sanitized: String = formatted
+            .chars()
+            .filter(|c| c.is_alphanumeric() || *c == '_' || *c == '-')
+            .take(200)
+            .collect();
+        let safe_name = if sanitized.is_empty() {
+            format!("capture_{}", now.timestamp())
+        } else {
+            sanitized
+        };
+        format!("{}.{}", safe_name, self.output.format.extension())
+    }
+
+    pub fn output_path(&self) -> PathBuf {
+        self.output.directory.join(self.generate_filename())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_performance_config() {
+        let config = Config::default();
+        assert_eq!(config.performance.tick_interval_ms, 100);
+        assert_eq!(config.performance.renderer, RendererBackend::TinySkia);
+        assert!(config.performance.lazy_init_upload);
+        assert!(config.performance.lazy_init_plugins);
+    }
+
+    #[test]
+    fn video_quality_settings_default_and_repair() {
+        let config = Config::default();
+        assert_eq!(config.capture.video_fps, 30);
+        assert_eq!(config.capture.video_quality.crf(), 18);
+        assert_eq!(VideoQuality::Balanced.crf(), 23);
+        assert_eq!(VideoQuality::Compact.crf(), 28);
+
+        // configs written before the fields existed must deserialize to defaults
+        let old: CaptureConfig = toml::from_str(
+            "show_cursor = true\ndelay_ms = 0\ngif_fps = 15\ngif_max_duration_secs = 30\n",
+        )
+        .unwrap();
+        assert_eq!(old.video_fps, 30);
+        assert_eq!(old.video_quality, VideoQuality::High);
+        assert_eq!(old.window_frame, WindowFrame::Visible);
+        assert!(old.clean_window_corners);
+        assert!(old.capture_covered_windows);
+        assert_eq!(old.backend, CaptureBackend::Auto);
+        assert!(!old.wgc_border);
+        assert!(!old.detect_ui_elements);
+        assert!(!old.selector_cursor_monitor);
+        assert!(old.hide_hub_during_capture);
+        assert_eq!(old.duplicates, DuplicateCaptures::Warn);
+        assert_eq!(old.hdr.container, HdrContainer::Png);
+        assert_eq!(old.hdr.tonemap_operator, TonemapOperator::Bt2390);
+
+        let mut config = Config::default();
+        config.capture.video_fps = 500;
+        config.sanitize();
+        assert_eq!(config.capture.video_fps, 60);
+    }
+
+    #[test]
+    fn ui_theme_and_accent_default_and_repair() {
+        let config = Config::default();
+        assert_eq!(config.ui.theme, Theme::Dark);
+        assert_eq!(config.ui.accent, Accent::Mono);
+
+        let old: UiConfig =
+            toml::from_str("theme = \"System\"\nshow_notifications = true\n").unwrap();
+        assert_eq!(old.theme, Theme::System);
+        assert_eq!(old.accent, Accent::Mono);
+        assert_eq!(old.accent_color, "#e0a84a");
+
+        let mut config = Config::default();
+        config.ui.accent = Accent::Custom;
+        config.ui.accent_color = "#12abEF".into();
+        config.sanitize();
+        assert_eq!(config.ui.accent_color, "#12abEF");
+        config.ui.accent_color = "red; background: url(x)".into();
+        // a save from settings is refused rather than repaired
+        assert!(config.validate().is_err());
+        config.sanitize();
+        assert_eq!(config.ui.accent_color, "#e0a84a");
+    }
+
+    #[test]
+    fn scratch_directory_must_be_absolute() {
+        let old: OutputConfig = toml::from_str("quality = 80\n").unwrap();
+        assert_eq!(old.scratch_directory, None);
+        assert_eq!(old.scratch_cap_mb, 1024);
+
+        let mut config = Config::default();
+        config.output.scratch_directory = Some(PathBuf::from("scratch"));
+        config.output.scratch_cap_mb = u32::MAX;
+        config.sanitize();
+        assert_eq!(config.output.scratch_directory, None);
+        assert_eq!(config.output.scratch_cap_mb, MAX_SCRATCH_CAP_MB);
+
+        let absolute = std::env::temp_dir().join("capscr-scratch");
+        config.output.scratch_directory = Some(absolute.clone());
+        config.sanitize();
+        assert_eq!(config.output.scratch_directory, Some(absolute));
+
+        config.output.scratch_directory = Some(PathBuf::from("scratch"));
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn originals_archive_beside_the_output_unless_pointed_elsewhere() {
+        let mut config = Config::default();
+        assert_eq!(config.output.archive_dir(), None);
+        config.output.archive_originals = true;
+        assert_eq!(
+            config.output.archive_dir(),
+            Some(config.output.directory.join("originals"))
+        );
+        config.output.archive_directory = Some(PathBuf::from("originals"));
+        config.sanitize();
+        assert_eq!(config.output.archive_directory, None);
+        let absolute = std::env::temp_dir().join("capscr-originals");
+        config.output.archive_directory = Some(absolute.clone());
+        config.sanitize();
+        assert_eq!(config.output.archive_dir(), Some(absolute));
+    }
+
+    #[test]
+    fn editor_recent_colors_are_newest_first_and_capped() {
+        let mut ui = UiConfig::default();
+        assert!(ui.editor_recent_colors.is_empty());
+        for i in 0..10 {
+            assert!(ui.remember_editor_color(&format!("#00000{i}")));
+        }
+        assert_eq!(ui.editor_recent_colors.len(), MAX_RECENT_COLORS);
+        assert_eq!(ui.editor_recent_colors[0], "#000009");
+        // picking one again moves it up instead of listing it twice
+        assert!(ui.remember_editor_color("#000005"));
+        assert_eq!(ui.editor_recent_colors[0], "#000005");
+        assert_eq!(ui.editor_recent_colors.len(), MAX_RECENT_COLORS);
+        assert!(ui.remember_editor_color("#ABCDEF"));
+        assert_eq!(ui.editor_recent_colors[0], "#abcdef");
+        assert!(!ui.remember_editor_color("red"));
+
+        let mut config = Config::default();
+        config.ui.editor_recent_colors = vec!["#123456".into(), "url(x)".into()];
+        config.sanitize();
+        assert_eq!(config.ui.editor_recent_colors, vec!["#123456".to_string()]);
+    }
+
+    #[test]
+    fn decoration_defaults_off_and_sanitizes() {
+        let config = Config::default();
+        assert!(!config.output.decoration.is_active());
+
+        let mut config = Config::default();
+        config.output.decoration.border_width = 10_000;
+        config.output.decoration.border_color = "blue".into();
+        config.output.decoration.corner_background = "#12345".into();
+        config.output.decoration.caption = "x".repeat(500);
+        config.sanitize();
+        let deco = &config.output.decoration;
+        assert_eq!(deco.border_width, MAX_BORDER_WIDTH);
+        assert_eq!(deco.border_color, "#1c1c1c");
+        assert!(deco.corner_background.is_empty());
+        assert!(deco.caption.len() <= MAX_CAPTION_LEN);
+        assert!(deco.is_active());
+    }
+
+    #[test]
+    fn send_to_destinations_sanitize() {
+        let mut config = Config::default();
+        config.upload.email.to = " -oProxyCommand=x ".into();
+        config.upload.email.subject = "line\nbreak".into();
+        config.upload.webhook.message = "x".repeat(5000);
+        config.upload.webhook.link_host = UploadDestination::Webhook;
+        config.sanitize();
+        assert!(config.upload.email.to.is_empty());
+        assert_eq!(config.upload.email.subject, "line break");
+        assert_eq!(config.upload.webhook.message.len(), MAX_WEBHOOK_MESSAGE_LEN);
+        assert_eq!(config.upload.webhook.link_host, UploadDestination::Imgur);
+
+        config.upload.email.to = " someone@example.com ".into();
+        config.sanitize();
+        assert_eq!(config.upload.email.to, "someone@example.com");
+        assert!(config.validate().is_ok());
+        // a save from settings skips sanitize
+        config.upload.email.to = "-oProxyCommand=x".into();
+        assert!(config.validate().is_err());
+        config.upload.email.to = " a@b.co ".into();
+        assert!(config.validate().is_ok());
+
+        let target: TaskUploadTarget = serde_json::from_str("\"webhook\"").unwrap();
+        assert_eq!(UploadDestination::from(target), UploadDestination::Webhook);
+        assert!(!UploadDestination::Email.hosts_links());
+        assert!(WebhookKind::Slack.needs_link_host());
+    }
+
+    #[test]
+    fn dedupe_window_is_clamped() {
+        let mut config = Config::default();
+        assert_eq!(config.upload.dedupe, DedupeMode::Ask);
+        config.upload.dedupe_days = 0;
+        config.sanitize();
+        assert_eq!(config.upload.dedupe_days, 1);
+        config.upload.dedupe_days = 10_000;
+        config.sanitize();
+        assert_eq!(config.upload.dedupe_days, MAX_DEDUPE_DAYS);
+        let mode: DedupeMode = serde_json::from_str("\"reuse\"").unwrap();
+        assert_eq!(mode, DedupeMode::Reuse);
+    }
+
+    #[test]
+    fn recompress_floor_and_preset_limit_are_clamped() {
+        let mut config = Config::default();
+        assert!(config.upload.recompress.enabled);
+        config.upload.recompress.min_quality = 0;
+        config.upload.custom_presets = vec![CustomUploaderPreset {
+            id: "a".into(),
+            max_file_mb: 500,
+            ..Default::default()
+        }];
+        config.sanitize();
+        assert_eq!(config.upload.recompress.min_quality, MIN_RECOMPRESS_QUALITY);
+        assert_eq!(
+            config.upload.custom_presets[0].max_file_mb,
+            MAX_CUSTOM_FILE_MB
+        );
+        let format: RecompressFormat = serde_json::from_str("\"webp\"").unwrap();
+        assert_eq!(format, RecompressFormat::Webp);
+        // the secret scan is opt-in, with every category armed once it's on
+        assert!(!config.upload.secret_scan.enabled);
+        let scan: SecretScanConfig = toml::from_str("enabled = true").unwrap();
+        assert!(scan.emails && scan.api_keys && scan.card_numbers);
+    }
 
-        let dir_str = dir.to_string_lossy();
-        if dir_str.contains("..") {
-            return Err(anyhow!("Output directory contains path traversal"));
-        }
+    #[test]
+    fn zoom_settings_are_clamped() {
+        let mut config = Config::default();
+        assert!(!config.capture.zoom.enabled);
+        config.capture.zoom.zoom_percent = 50;
+        config.capture.zoom.smoothing_ms = 60_000;
+        config.sanitize();
+        assert_eq!(config.capture.zoom.zoom_percent, MIN_ZOOM_PERCENT);
+        assert_eq!(config.capture.zoom.smoothing_ms, MAX_ZOOM_SMOOTHING_MS);
+        config.capture.zoom.zoom_percent = 1000;
+        config.sanitize();
+        assert_eq!(config.capture.zoom.zoom_percent, MAX_ZOOM_PERCENT);
+    }
 
-        #[cfg(windows)]
-        {
-            if dir_str.starts_with("\\\\") {
-                return Err(anyhow!("Network paths are not allowed"));
-            }
-        }
+    #[test]
+    fn stream_settings_are_sanitized() {
+        let mut config = Config::default();
+        assert_eq!(config.capture.stream.bitrate_kbps, 4500);
+        config.capture.stream.url = "  rtmp://127.0.0.1/live/key \n".into();
+        config.capture.stream.bitrate_kbps = 10;
+        config.sanitize();
+        assert_eq!(config.capture.stream.url, "rtmp://127.0.0.1/live/key");
+        assert_eq!(config.capture.stream.bitrate_kbps, MIN_STREAM_BITRATE_KBPS);
+        let mode: TaskCaptureMode = serde_json::from_str("\"region-stream\"").unwrap();
+        assert_eq!(mode, TaskCaptureMode::RegionStream);
+        let mode: TaskCaptureMode = serde_json::from_str("\"active-window\"").unwrap();
+        assert_eq!(mode, TaskCaptureMode::ActiveWindow);
+    }
 
-        fs::create_dir_all(dir)?;
+    #[test]
+    fn exclusion_list_is_trimmed_and_deduped() {
+        let mut config = Config::default();
+        assert!(config.capture.exclusion.apps.is_empty());
+        config.capture.exclusion.apps = vec![
+            " KeePassXC ".into(),
+            "".into(),
+            "keepassxc".into(),
+            "1Password.exe".into(),
+        ];
+        config.sanitize();
+        assert_eq!(
+            config.capture.exclusion.apps,
+            vec!["KeePassXC", "1Password.exe"]
+        );
+        let mode: ExclusionMode = serde_json::from_str("\"skip\"").unwrap();
+        assert_eq!(mode, ExclusionMode::Skip);
+    }
 
-        // any local directory the user can actually write to is a valid
-        // output dir — people keep captures on secondary drives. Probe
-        // writability directly instead of whitelisting home/pictures/temp.
-        let probe = dir.join(".capscr-write-probe");
-        fs::write(&probe, b"").map_err(|e| anyhow!("Output directory is not writable: {e}"))?;
-        let _ = fs::remove_file(&probe);
+    #[test]
+    fn game_mode_rules_are_trimmed_and_deduped() {
+        let mut config = Config::default();
+        assert!(!config.capture.game_mode.enabled);
+        let rule = |app: &str, action| GameModeRule {
+            app: app.into(),
+            action,
+        };
+        config.capture.game_mode.apps = vec![
+            rule(" cs2.exe ", GameModeAction::SuspendHotkeys),
+            rule("", GameModeAction::LowOverhead),
+            rule("CS2.EXE", GameModeAction::None),
+        ];
+        config.sanitize();
+        assert_eq!(
+            config.capture.game_mode.apps,
+            vec![rule("cs2.exe", GameModeAction::SuspendHotkeys)]
+        );
+        let action: GameModeAction = serde_json::from_str("\"low-overhead\"").unwrap();
+        assert_eq!(action, GameModeAction::LowOverhead);
+    }
 
-        Ok(())
+    #[test]
+    fn app_profiles_redirect_format_and_folder_for_their_app_only() {
+        let mut config = Config::default();
+        let profile = |app: &str, subfolder: &str| AppProfile {
+            app: app.into(),
+            format: Some(ImageFormat::Png),
+            post_action: None,
+            subfolder: subfolder.into(),
+            retention: None,
+        };
+        config.output.format = ImageFormat::Jpeg;
+        config.output.app_profiles = vec![
+            profile(" Figma.exe ", "/design/../mocks\\"),
+            profile("FIGMA.EXE", "other"),
+            profile("outlook", "C:\\mail"),
+        ];
+        config.sanitize();
+        assert_eq!(
+            config.output.app_profiles,
+            vec![
+                profile("Figma.exe", "design/mocks"),
+                profile("outlook", "mail")
+            ]
+        );
+
+        let base = config.output.directory.clone();
+        let mut output = config.output.clone();
+        let figma = output.profile_for("figma").cloned().unwrap();
+        output.apply_profile(&figma);
+        assert_eq!(output.format, ImageFormat::Png);
+        assert_eq!(output.directory, base.join("design/mocks"));
+        assert!(config.output.profile_for("slack").is_none());
     }
 
-    pub fn generate_filename(&self) -> String {
-        let now = chrono::Local::now();
-        let formatted = now.format(&self.output.filename_template).to_string();
-        let sanitized: String = formatted
-            .chars()
-            .filter(|c| c.is_alphanumeric() || *c == '_' || *c == '-')
-            .take(200)
-            .collect();
-        let safe_name = if sanitized.is_empty() {
-            format!("capture_{}", now.timestamp())
-        } else {
-            sanitized
+    #[test]
+    fn retention_covers_the_output_folder_and_profile_subfolders() {
+        let mut config = Config::default();
+        assert!(config.output.retention_targets().is_empty());
+        let policy = RetentionPolicy {
+            max_files: 500,
+            max_size_mb: 0,
+            move_to: None,
         };
-        format!("{}.{}", safe_name, self.output.format.extension())
+        config.output.retention = RetentionPolicy {
+            max_size_mb: 2048,
+            ..RetentionPolicy::default()
+        };
+        config.output.app_profiles = vec![
+            AppProfile {
+                app: "figma".into(),
+                subfolder: "design".into(),
+                retention: Some(policy.clone()),
+                ..AppProfile::default()
+            },
+            // no folder of its own; the output folder's policy covers it
+            AppProfile {
+                app: "slack".into(),
+                retention: Some(policy),
+                ..AppProfile::default()
+            },
+        ];
+        config.sanitize();
+        let base = config.output.directory.clone();
+        let targets = config.output.retention_targets();
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0], (base.clone(), config.output.retention.clone()));
+        assert_eq!(targets[1].0, base.join("design"));
+        assert_eq!(targets[1].1.max_files, 500);
     }
 
-    pub fn output_path(&self) -> PathBuf {
-        self.output.directory.join(self.generate_filename())
+    #[test]
+    fn manual_proxy_needs_a_usable_url() {
+        let mut config = Config::default();
+        assert_eq!(config.upload.proxy.mode, ProxyMode::System);
+        config.upload.proxy.mode = ProxyMode::Manual;
+        config.upload.proxy.url = "proxy.corp:3128".into();
+        assert!(config.validate().is_err());
+        config.upload.proxy.url = " socks5h://proxy.corp:1080 ".into();
+        config.sanitize();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.upload.proxy.url, "socks5h://proxy.corp:1080");
+
+        // a hand-edited file falls back instead of breaking every upload
+        config.upload.proxy.url = "ftp://proxy.corp".into();
+        config.sanitize();
+        assert_eq!(config.upload.proxy.mode, ProxyMode::System);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn dropbox_folder_is_an_absolute_path_without_dot_segments() {
+        let mut config = Config::default();
+        assert_eq!(config.upload.dropbox.folder, "/capscr");
+        config.upload.dropbox.folder = " shots\\2026/../x/ ".into();
+        config.sanitize();
+        assert_eq!(config.upload.dropbox.folder, "/shots/2026/x");
+        config.upload.dropbox.folder = String::new();
+        config.sanitize();
+        assert_eq!(config.upload.dropbox.folder, "/");
+    }
 
     #[test]
-    fn test_default_performance_config() {
-        let config = Config::default();
-        assert_eq!(config.performance.tick_interval_ms, 100);
-        assert_eq!(config.performance.renderer, RendererBackend::TinySkia);
-        assert!(config.performance.lazy_init_upload);
-        assert!(config.performance.lazy_init_plugins);
+    fn resize_target_size() {
+        let mut r = ResizeConfig::default();
+        assert_eq!(r.target_size(3840, 2160), None);
+
+        r.mode = ResizeMode::MaxDimension;
+        r.max_dimension = 1920;
+        assert_eq!(r.target_size(3840, 2160), Some((1920, 1080)));
+        assert_eq!(r.target_size(1000, 2400), Some((800, 1920)));
+        // never upscales
+        assert_eq!(r.target_size(800, 600), None);
+
+        r.mode = ResizeMode::Percent;
+        r.percent = 50;
+        assert_eq!(r.target_size(3, 1), Some((2, 1)));
+        r.percent = 100;
+        assert_eq!(r.target_size(3840, 2160), None);
     }
 
     #[test]
-    fn video_quality_settings_default_and_repair() {
+    fn resize_sanitize_clamps_and_is_separate_per_destination() {
+        let mut config = Config::default();
+        config.output.resize.mode = ResizeMode::Percent;
+        config.output.resize.percent = 0;
+        config.upload.resize.max_dimension = 0;
+        config.sanitize();
+        assert_eq!(config.output.resize.percent, MIN_RESIZE_PERCENT);
+        assert_eq!(config.upload.resize.max_dimension, MIN_RESIZE_DIMENSION);
+        assert_eq!(config.upload.resize.mode, ResizeMode::Off);
+    }
+
+    #[test]
+    fn metadata_mode_defaults_to_keep_and_parses_kebab() {
+        assert_eq!(Config::default().output.metadata, MetadataMode::Keep);
+        let output: OutputConfig = toml::from_str("metadata = \"embed\"").unwrap();
+        assert_eq!(output.metadata, MetadataMode::Embed);
+    }
+
+    #[test]
+    fn post_capture_actions_per_capture_type() {
         let config = Config::default();
-        assert_eq!(config.capture.video_fps, 30);
-        assert_eq!(config.capture.video_quality.crf(), 18);
-        assert_eq!(VideoQuality::Balanced.crf(), 23);
-        assert_eq!(VideoQuality::Compact.crf(), 28);
+        assert_eq!(
+            config.post_capture.action_for(CaptureType::Region),
+            TaskPostAction::Clipboard
+        );
+        assert_eq!(
+            config.post_capture.action_for(CaptureType::Gif),
+            TaskPostAction::SaveFile
+        );
 
-        // configs written before the fields existed must deserialize to defaults
-        let old: CaptureConfig = toml::from_str(
-            "show_cursor = true\ndelay_ms = 0\ngif_fps = 15\ngif_max_duration_secs = 30\n",
+        // an old config's single action is ignored; a partial map is filled in
+        let mut config: Config = toml::from_str(
+            "[post_capture]\naction = \"Upload\"\n[post_capture.actions]\nwindow = \"upload\"\ngif = \"copy-text\"\n",
         )
         .unwrap();
-        assert_eq!(old.video_fps, 30);
-        assert_eq!(old.video_quality, VideoQuality::High);
+        config.sanitize();
+        let post = &config.post_capture;
+        assert_eq!(post.action_for(CaptureType::Window), TaskPostAction::Upload);
+        assert_eq!(
+            post.action_for(CaptureType::FullScreen),
+            TaskPostAction::Clipboard
+        );
+        assert_eq!(post.actions.len(), CaptureType::all().len());
+        assert_eq!(post.action_for(CaptureType::Gif), TaskPostAction::SaveFile);
+    }
+
+    #[test]
+    fn image_format_from_extension_round_trips() {
+        for &format in ImageFormat::all() {
+            assert_eq!(
+                ImageFormat::from_extension(format.extension()),
+                Some(format)
+            );
+        }
+        assert_eq!(ImageFormat::from_extension("JPEG"), Some(ImageFormat::Jpeg));
+        assert_eq!(ImageFormat::from_extension("tiff"), None);
+    }
+
+    #[test]
+    fn editor_projects_default_off() {
+        assert!(!Config::default().output.editor_projects);
+        let old: OutputConfig = toml::from_str("quality = 80\n").unwrap();
+        assert!(!old.editor_projects);
+    }
+
+    #[test]
+    fn ui_scale_defaults_and_clamps() {
+        let config = Config::default();
+        assert_eq!(config.ui.scale_percent, 100);
+        assert!(!config.ui.compact);
+
+        let old: UiConfig = toml::from_str("show_notifications = true\n").unwrap();
+        assert_eq!(old.scale_percent, 100);
 
         let mut config = Config::default();
-        config.capture.video_fps = 500;
+        config.ui.scale_percent = 400;
+        assert!(config.validate().is_err());
         config.sanitize();
-        assert_eq!(config.capture.video_fps, 60);
+        assert_eq!(config.ui.scale_percent, 150);
+        config.ui.scale_percent = 10;
+        config.sanitize();
+        assert_eq!(config.ui.scale_percent, 80);
+        assert!((config.ui.scale() - 0.8).abs() < f64::EPSILON);
     }
 
     #[test]
@@ -1468,6 +4467,113 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn custom_uploader_extras_default_and_repair() {
+        let mut config = Config::default();
+        config.upload.custom_presets = vec![CustomUploaderPreset {
+            id: "a".into(),
+            url: "https://up.example/api".into(),
+            response_regex: "(unclosed".into(),
+            headers: vec![
+                CustomHeader {
+                    name: " ".into(),
+                    ..Default::default()
+                },
+                CustomHeader {
+                    name: "X-Api-Key".into(),
+                    value: "k".into(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }];
+        config.capture_tasks[0].custom_preset = Some("deleted".into());
+        assert!(config.validate().is_err());
+        config.sanitize();
+        assert!(config.capture_tasks[0].custom_preset.is_none());
+        let preset = &config.upload.custom_presets[0];
+        assert!(preset.response_regex.is_empty());
+        assert_eq!(preset.headers.len(), 1);
+        assert_eq!(preset.method, CustomHttpMethod::Post);
+        assert_eq!(config.upload.active_custom_preset, "a");
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn form_fields_from_older_configs_stay_plain() {
+        let preset: CustomUploaderPreset = toml::from_str(
+            "id = \"a\"\nurl = \"https://up.example/api\"\n\
+             [[form_fields]]\nname = \"album\"\nvalue = \"shots\"\n",
+        )
+        .unwrap();
+        let field = &preset.form_fields[0];
+        assert!(!field.secret);
+        assert!(field.value_encrypted.is_empty());
+        assert_eq!(field.value_plaintext(), "shots");
+    }
+
+    #[test]
+    fn legacy_custom_uploader_migrates_into_a_preset() {
+        let (config, written_under) = Config::from_toml(
+            "[upload]\n\
+             custom_url = \"https://up.example/api\"\n\
+             custom_response_path = \"data.link\"\n\
+             custom_form_name = \"\"\n\
+             [[upload.custom_headers]]\nname = \"Authorization\"\nvalue_encrypted = \"blob\"\nsecret = true\n",
+        )
+        .unwrap();
+        assert_eq!(written_under, 0);
+        assert_eq!(config.version, CONFIG_VERSION);
+        let upload = &config.upload;
+        assert_eq!(upload.active_custom_preset, "custom");
+        let preset = upload.custom_preset(None).unwrap();
+        assert_eq!(preset.url, "https://up.example/api");
+        assert_eq!(preset.response_path, "data.link");
+        assert_eq!(preset.form_name, "file");
+        assert_eq!(preset.headers[0].value_encrypted, "blob");
+        // the legacy block never round-trips
+        let written = toml::to_string(&config).unwrap();
+        assert!(!written.contains("custom_url"), "{written}");
+        assert!(written.contains("[[upload.custom_presets]]"), "{written}");
+    }
+
+    #[test]
+    fn preset_lookup_falls_back_to_active_then_first() {
+        let mut upload = UploadConfig::default();
+        assert!(upload.custom_preset(None).is_none());
+        upload.custom_presets = ["a", "b"]
+            .iter()
+            .map(|id| CustomUploaderPreset {
+                id: id.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        assert_eq!(upload.custom_preset(None).unwrap().id, "a");
+        upload.active_custom_preset = "b".into();
+        assert_eq!(upload.custom_preset(None).unwrap().id, "b");
+        assert_eq!(upload.custom_preset(Some("a")).unwrap().id, "a");
+        assert_eq!(upload.custom_preset(Some("gone")).unwrap().id, "b");
+    }
+
+    #[test]
+    fn imgur_account_settings_default_and_repair() {
+        let config = Config::default();
+        assert!(!config.upload.imgur.is_signed_in());
+        assert_eq!(config.upload.imgur.album_privacy, ImgurPrivacy::Hidden);
+
+        let mut config = Config::default();
+        config.upload.imgur.album_id = "../account/me".into();
+        config.upload.imgur.title = "é".repeat(MAX_IMGUR_TEXT_LEN);
+        assert!(config.validate().is_err());
+        config.sanitize();
+        assert!(config.upload.imgur.album_id.is_empty());
+        assert!(config.upload.imgur.title.len() <= MAX_IMGUR_TEXT_LEN);
+        assert!(config.validate().is_ok());
+
+        config.upload.imgur.album_id = "aB3dE9x".into();
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn sanitize_repairs_instead_of_discarding_config() {
         let mut config = Config::default();
@@ -1486,7 +4592,13 @@ mod tests {
             capture_mode: TaskCaptureMode::Region,
             post_action: TaskPostAction::SaveFile,
             target_destination: None,
+            custom_preset: None,
             delay_ms: None,
+            target_window: None,
+            follow_active_window: false,
+            recording_size: None,
+            hold_to_record: false,
+            gesture: HotkeyGesture::Press,
         });
 
         config.sanitize();
@@ -1523,7 +4635,7 @@ mod tests {
         assert!(full.contains("use_p99_max_cll"));
         let trimmed = full
             .lines()
-            .filter(|l| !l.contains("use_p99_max_cll"))
+            .filter(|l| !l.contains("use_p99_max_cll") && !l.contains("follow_active_window"))
             .collect::<Vec<_>>()
             .join("\n");
         let doctored = format!("future_only_key = \"ignored\"\n{trimmed}");
@@ -1538,6 +4650,132 @@ mod tests {
             Config::default().capture_tasks.len(),
             "the user's tasks must survive a missing/unknown field"
         );
+        assert!(
+            parsed.capture_tasks.iter().all(|t| !t.follow_active_window),
+            "tasks saved before follow-window existed keep recording a fixed region"
+        );
+    }
+
+    #[test]
+    fn stop_recording_hotkey_cannot_shadow_a_task() {
+        let mut config = Config::default();
+        config.hotkeys.stop_recording = "Ctrl+Shift+X".to_string();
+        assert!(config.validate().is_ok());
+        config.hotkeys.stop_recording = config.capture_tasks[1].hotkey.clone();
+        assert!(config.validate().is_err());
+        config.sanitize();
+        assert!(config.hotkeys.stop_recording.is_empty());
+        assert_eq!(config.capture_tasks[1].hotkey, "Ctrl+Shift+G");
+    }
+
+    #[test]
+    fn do_not_disturb_hotkey_gives_way_to_tasks_and_the_stop_binding() {
+        let mut config = Config::default();
+        config.hotkeys.do_not_disturb = "Ctrl+Alt+D".to_string();
+        config.hotkeys.do_not_disturb_minutes = 60;
+        assert!(config.validate().is_ok());
+        config.hotkeys.stop_recording = "Ctrl+Alt+D".to_string();
+        assert!(config.validate().is_err());
+        config.sanitize();
+        assert!(config.hotkeys.do_not_disturb.is_empty());
+        assert_eq!(config.hotkeys.stop_recording, "Ctrl+Alt+D");
+        config.hotkeys.do_not_disturb_minutes = u32::MAX;
+        config.sanitize();
+        assert_eq!(config.hotkeys.do_not_disturb_minutes, MAX_DND_MINUTES);
+    }
+
+    #[test]
+    fn recording_sizes_are_clamped_and_survive_a_round_trip() {
+        let mut config = Config::default();
+        assert!(config
+            .capture_tasks
+            .iter()
+            .all(|t| t.recording_size.is_none()));
+        config.capture_tasks[1].recording_size = Some(RecordingSize {
+            width: 1920,
+            height: 1080,
+        });
+        config.capture_tasks[2].recording_size = Some(RecordingSize {
+            width: 0,
+            height: 100_000,
+        });
+        config.sanitize();
+        assert_eq!(
+            config.capture_tasks[2].recording_size,
+            Some(RecordingSize {
+                width: MIN_RECORDING_SIZE,
+                height: MAX_RECORDING_SIZE,
+            })
+        );
+        let parsed: Config = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+        assert_eq!(
+            parsed.capture_tasks[1].recording_size,
+            Some(RecordingSize {
+                width: 1920,
+                height: 1080,
+            })
+        );
+        assert!(parsed.capture_tasks[0].recording_size.is_none());
+    }
+
+    #[test]
+    fn tasks_share_a_hotkey_only_under_different_gestures() {
+        let mut config = Config::default();
+        config.capture_tasks[0].hotkey = config.capture_tasks[1].hotkey.clone();
+        assert!(config.validate().is_err());
+        config.capture_tasks[1].gesture = HotkeyGesture::LongPress;
+        config.capture_tasks[1].hold_to_record = true;
+        assert!(config.validate().is_ok());
+        config.sanitize();
+        assert_eq!(
+            config.capture_tasks[0].hotkey,
+            config.capture_tasks[1].hotkey
+        );
+        assert!(
+            !config.capture_tasks[1].hold_to_record,
+            "a gestured key is already up by the time it picks its task"
+        );
+    }
+
+    #[test]
+    fn hold_to_record_only_sticks_to_recording_tasks() {
+        let mut config = Config::default();
+        for task in &mut config.capture_tasks {
+            task.hold_to_record = true;
+        }
+        config.sanitize();
+        assert!(!config.capture_tasks[0].hold_to_record);
+        assert!(config.capture_tasks[1].hold_to_record);
+        assert!(config.capture_tasks[2].hold_to_record);
+    }
+
+    #[test]
+    fn target_window_tasks_validate_their_patterns() {
+        let mut config = Config::default();
+        config.capture_tasks[0].capture_mode = TaskCaptureMode::TargetWindow;
+        assert!(
+            config.validate().is_err(),
+            "a target-window task needs a target"
+        );
+
+        config.capture_tasks[0].target_window = Some(WindowTarget {
+            title: "Visual Studio Code".to_string(),
+            process: String::new(),
+        });
+        assert!(config.validate().is_ok());
+
+        config.capture_tasks[0].target_window = Some(WindowTarget {
+            title: "(unclosed".to_string(),
+            process: String::new(),
+        });
+        assert!(config.validate().is_err());
+        config.sanitize();
+        assert_eq!(
+            config.capture_tasks[0].capture_mode,
+            TaskCaptureMode::Window
+        );
+        assert!(config.capture_tasks[0].target_window.is_none());
+        assert!(config.validate().is_ok());
     }
 
     #[test]
@@ -1556,4 +4794,52 @@ mod tests {
         empty.sanitize();
         assert!(!empty.output.directory.as_os_str().is_empty());
     }
+
+    #[test]
+    fn watch_folder_falls_back_to_clipboard_and_never_watches_its_own_saves() {
+        let mut config = Config::default();
+        config.watch_folder.action = TaskPostAction::OpenEditor;
+        config.sanitize();
+        assert_eq!(config.watch_folder.action, TaskPostAction::Clipboard);
+
+        config.watch_folder.enabled = true;
+        config.watch_folder.directory = config.output.directory.clone();
+        assert!(
+            config.validate().is_ok(),
+            "uploading from the output folder is fine"
+        );
+        config.watch_folder.action = TaskPostAction::SaveFile;
+        assert!(config.validate().is_err());
+        config.sanitize();
+        assert!(!config.watch_folder.enabled);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn watch_folder_guard_sees_through_spelling_and_subfolders() {
+        let mut config = Config::default();
+        config.output.directory = std::env::temp_dir().join("capscr-watch-out");
+        config.watch_folder.enabled = true;
+        config.watch_folder.action = TaskPostAction::SaveFile;
+
+        let mut trailing = config.output.directory.clone().into_os_string();
+        trailing.push(std::path::MAIN_SEPARATOR_STR);
+        config.watch_folder.directory = PathBuf::from(trailing);
+        assert!(config.validate().is_err());
+        config.watch_folder.directory = config.output.directory.join("originals");
+        assert!(config.validate().is_err());
+        config.watch_folder.directory = config.output.directory.join("design");
+        assert!(config.validate().is_err());
+        config.watch_folder.directory = std::env::temp_dir().join("capscr-watch-in");
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn parse_rejects_a_broken_file_instead_of_resetting() {
+        let written = toml::to_string_pretty(&Config::default()).unwrap();
+        let parsed = Config::parse(&written).unwrap();
+        assert_eq!(parsed.output.quality, Config::default().output.quality);
+        assert!(Config::parse("[output\nquality = ").is_err());
+        assert!(Config::parse("[capture]\ngif_fps = \"fast\"").is_err());
+    }
 }
@@ -0,0 +1,77 @@
+// hand edits to config.toml take effect without a restart. the file is polled,
+// and a change capscr didn't write itself is parsed, validated and applied the
+// same way a save from settings is: hotkeys re-registered, tray rebuilt, and
+// the hub told to refetch so its theme and scale follow. a file that doesn't
+// parse or validate is left alone and the running config stays as it was.
+
+use crate::config::Config;
+use crate::state::AppState;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Manager};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+pub fn spawn(app: &AppHandle) {
+    let app = app.clone();
+    if let Err(e) = std::thread::Builder::new()
+        .name("capscr-config-watch".into())
+        .spawn(move || run(app))
+    {
+        tracing::warn!("couldn't start the config watcher: {e}");
+    }
+}
+
+fn run(app: AppHandle) {
+    let Some(path) = Config::config_path() else {
+        return;
+    };
+    let mut seen = stamp(&path);
+    let mut settling = None;
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let now = stamp(&path);
+        if now == seen {
+            settling = None;
+            continue;
+        }
+        // editors often write in more than one pass; wait for a poll where
+        // the file held still before reading it
+        if settling != Some(now) {
+            settling = Some(now);
+            continue;
+        }
+        seen = now;
+        settling = None;
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        if crate::config::written_by_us(&content) {
+            continue;
+        }
+        match Config::parse(&content) {
+            Ok(mut config) => {
+                tracing::info!("config.toml changed on disk, applying it");
+                let state = app.state::<AppState>();
+                // the tray and settings own the hotkey kill switch, as on save
+                config.hotkeys.disabled_globally = state
+                    .hotkeys_disabled
+                    .load(std::sync::atomic::Ordering::SeqCst);
+                crate::commands::apply_config(&app, &state, config);
+            }
+            Err(e) => {
+                tracing::warn!("config.toml changed but wasn't reloaded: {e:#}");
+                crate::commands::emit_error(
+                    &app,
+                    "config",
+                    &crate::i18n::tf("error.config-reload", &[("error", &format!("{e:#}"))]),
+                );
+            }
+        }
+    }
+}
+
+fn stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let meta = std::fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
@@ -0,0 +1,151 @@
+// "Annotate with capscr" on image files in Explorer and the Linux file
+// managers. the entry launches `capscr --edit <file>`, which the running
+// instance picks up through the single-instance forward (see main.rs) and
+// opens in the editor. optional and per-user: Settings turns it on and off.
+
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "bmp"];
+
+/// bring the registration in line with the setting. rewritten while enabled
+/// so a moved install keeps working, removed when disabled
+pub fn sync(enabled: bool) {
+    let result = if enabled { register() } else { unregister() };
+    if let Err(e) = result {
+        tracing::warn!(
+            "context menu {} failed: {e:#}",
+            if enabled { "register" } else { "unregister" }
+        );
+    }
+}
+
+#[cfg(windows)]
+const VERB_KEY: &str = "capscr.annotate";
+
+#[cfg(windows)]
+fn verb_key(ext: &str) -> String {
+    format!("Software\\Classes\\SystemFileAssociations\\.{ext}\\shell\\{VERB_KEY}")
+}
+
+#[cfg(windows)]
+fn register() -> anyhow::Result<()> {
+    use crate::win_registry::set_string;
+
+    let exe = std::env::current_exe()?;
+    let exe = exe.to_string_lossy();
+    for ext in IMAGE_EXTENSIONS {
+        let key = verb_key(ext);
+        set_string(&key, None, "Annotate with capscr")?;
+        set_string(&key, Some("Icon"), &format!("\"{exe}\",0"))?;
+        set_string(
+            &format!("{key}\\command"),
+            None,
+            &format!("\"{exe}\" --edit \"%1\""),
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn unregister() -> anyhow::Result<()> {
+    for ext in IMAGE_EXTENSIONS {
+        crate::win_registry::delete_tree(&verb_key(ext))?;
+    }
+    Ok(())
+}
+
+// a hidden desktop entry for the image types lists capscr under "Open With"
+// in Files, Dolphin and the rest; no per-file-manager extension needed
+#[cfg(target_os = "linux")]
+fn desktop_file() -> Option<std::path::PathBuf> {
+    directories::BaseDirs::new().map(|d| {
+        d.data_dir()
+            .join("applications")
+            .join("capscr-annotate.desktop")
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn desktop_entry(exe: &std::path::Path) -> String {
+    let mime: String = IMAGE_EXTENSIONS
+        .iter()
+        .map(|ext| match *ext {
+            "jpg" | "jpeg" => "image/jpeg;".to_string(),
+            other => format!("image/{other};"),
+        })
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name=Annotate with capscr\n\
+         Exec=\"{}\" --edit %f\n\
+         Icon=capscr\n\
+         MimeType={mime}\n\
+         NoDisplay=true\n\
+         Terminal=false\n",
+        exe.display()
+    )
+}
+
+#[cfg(target_os = "linux")]
+fn register() -> anyhow::Result<()> {
+    let path = desktop_file().ok_or_else(|| anyhow::anyhow!("no data directory"))?;
+    let entry = desktop_entry(&std::env::current_exe()?);
+    if std::fs::read_to_string(&path).is_ok_and(|current| current == entry) {
+        return Ok(());
+    }
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&path, entry)?;
+    refresh_desktop_database(&path);
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn unregister() -> anyhow::Result<()> {
+    let Some(path) = desktop_file() else {
+        return Ok(());
+    };
+    match std::fs::remove_file(&path) {
+        Ok(()) => {
+            refresh_desktop_database(&path);
+            Ok(())
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+// file managers read the mime cache, not the entries themselves
+#[cfg(target_os = "linux")]
+fn refresh_desktop_database(entry: &std::path::Path) {
+    if let Some(dir) = entry.parent() {
+        let _ = std::process::Command::new("update-desktop-database")
+            .arg(dir)
+            .status();
+    }
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn register() -> anyhow::Result<()> {
+    Ok(())
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn unregister() -> anyhow::Result<()> {
+    Ok(())
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn desktop_entry_lists_each_image_type_once() {
+        let entry = desktop_entry(std::path::Path::new("/opt/capscr/capscr"));
+        assert!(entry.contains("Exec=\"/opt/capscr/capscr\" --edit %f\n"));
+        assert!(entry.contains("MimeType=image/bmp;image/jpeg;image/png;image/webp;\n"));
+        assert!(entry.contains("NoDisplay=true\n"));
+    }
+}
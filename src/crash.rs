@@ -0,0 +1,212 @@
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::backtrace::Backtrace;
+use std::fmt::Write as _;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+
+// reports past this many are dropped oldest first when a new one is written;
+// a crash loop shouldn't fill the config folder
+const KEPT_REPORTS: usize = 5;
+// the end of the log that led up to the panic, appended to the report
+const LOG_EXCERPT_BYTES: u64 = 16 * 1024;
+
+pub fn dir() -> Option<PathBuf> {
+    Config::config_dir().map(|d| d.join("crashes"))
+}
+
+/// a crash report left by an earlier session, offered in the hub until it's
+/// dismissed
+#[derive(Debug, Clone, Serialize)]
+pub struct CrashReport {
+    pub id: String,
+    /// the panic message and where it was raised
+    pub summary: String,
+    /// unix seconds
+    pub crashed_at: u64,
+}
+
+/// chain a hook that writes a report with a backtrace before the existing one
+/// runs. release builds abort on panic, so this is the only trace a field
+/// failure in the gdi/dxgi paths leaves beyond the last log line
+pub fn install() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        // an unwinding build catches panics off the main thread per capture;
+        // those didn't crash anything
+        let fatal = cfg!(panic = "abort") || std::thread::current().name() == Some("main");
+        if fatal {
+            if let Err(e) = write_report(info) {
+                eprintln!("capscr: can't write the crash report: {e}");
+            }
+        }
+        previous(info);
+    }));
+}
+
+fn write_report(info: &PanicHookInfo<'_>) -> Result<PathBuf> {
+    let dir = dir().ok_or_else(|| anyhow!("no config directory"))?;
+    std::fs::create_dir_all(&dir)?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis());
+    let path = dir.join(format!("crash-{now}.txt"));
+    // captured regardless of RUST_BACKTRACE: nobody sets it on a tray app
+    let backtrace = Backtrace::force_capture();
+    let log = crate::logfile::dir().and_then(|d| {
+        crate::logfile::tail(&crate::logfile::current_path(&d), LOG_EXCERPT_BYTES).ok()
+    });
+    std::fs::write(&path, render(info, &backtrace.to_string(), log.as_deref()))?;
+    prune(&dir, KEPT_REPORTS);
+    Ok(path)
+}
+
+fn render(info: &PanicHookInfo<'_>, backtrace: &str, log: Option<&str>) -> String {
+    let thread = std::thread::current();
+    let mut out = String::new();
+    let _ = writeln!(out, "{}", summary_line(info));
+    let _ = writeln!(out);
+    let _ = writeln!(out, "version: capscr {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(
+        out,
+        "os: {} {}",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+    let _ = writeln!(out, "thread: {}", thread.name().unwrap_or("<unnamed>"));
+    let _ = writeln!(out);
+    let _ = writeln!(out, "backtrace:");
+    let _ = writeln!(out, "{backtrace}");
+    if let Some(log) = log.filter(|l| !l.is_empty()) {
+        let _ = writeln!(out, "recent log:");
+        out.push_str(log);
+    }
+    out
+}
+
+fn summary_line(info: &PanicHookInfo<'_>) -> String {
+    let payload = info.payload();
+    let msg = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("<non-string panic payload>");
+    match info.location() {
+        Some(loc) => format!("panicked at {}:{}: {msg}", loc.file(), loc.line()),
+        None => format!("panicked: {msg}"),
+    }
+}
+
+fn report_paths(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_stem()
+                .and_then(|s| s.to_str())
+                .is_some_and(is_report_id)
+                && p.extension().is_some_and(|e| e == "txt")
+        })
+        .collect();
+    // the millisecond stamp in the name sorts newest last
+    paths.sort_by_key(|p| report_stamp(p));
+    paths
+}
+
+fn report_stamp(path: &Path) -> u128 {
+    path.file_stem()
+        .and_then(|s| s.to_str())
+        .and_then(|s| s.strip_prefix("crash-"))
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0)
+}
+
+fn prune(dir: &Path, keep: usize) {
+    let paths = report_paths(dir);
+    let excess = paths.len().saturating_sub(keep);
+    for path in &paths[..excess] {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// reports from earlier sessions, newest first
+pub fn list() -> Vec<CrashReport> {
+    let Some(dir) = dir() else {
+        return Vec::new();
+    };
+    let mut found: Vec<CrashReport> = report_paths(&dir)
+        .into_iter()
+        .filter_map(|path| {
+            let id = path.file_stem()?.to_str()?.to_string();
+            let text = std::fs::read_to_string(&path).ok()?;
+            let summary = text.lines().next().unwrap_or_default().to_string();
+            Some(CrashReport {
+                crashed_at: (report_stamp(&path) / 1000) as u64,
+                id,
+                summary,
+            })
+        })
+        .collect();
+    found.reverse();
+    found
+}
+
+pub fn read(id: &str) -> Result<String> {
+    Ok(std::fs::read_to_string(report_path(id)?)?)
+}
+
+pub fn dismiss(id: &str) -> Result<()> {
+    std::fs::remove_file(report_path(id)?)?;
+    Ok(())
+}
+
+// ids come back from the webview; only a bare report name may reach the folder
+pub fn report_path(id: &str) -> Result<PathBuf> {
+    if !is_report_id(id) {
+        return Err(anyhow!("not a crash report: {id}"));
+    }
+    let dir = dir().ok_or_else(|| anyhow!("no config directory"))?;
+    Ok(dir.join(format!("{id}.txt")))
+}
+
+fn is_report_id(id: &str) -> bool {
+    id.strip_prefix("crash-")
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_bare_report_names_are_ids() {
+        assert!(is_report_id("crash-1760590000123"));
+        assert!(!is_report_id("crash-"));
+        assert!(!is_report_id("crash-12/../config"));
+        assert!(!is_report_id("../crash-1"));
+        assert!(!is_report_id("capscr.log"));
+    }
+
+    #[test]
+    fn prune_drops_the_oldest_reports() {
+        let dir = std::env::temp_dir().join(format!(
+            "capscr_crashes_{}",
+            uuid::Uuid::new_v4().as_simple()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        for stamp in [30u64, 1000, 200, 4] {
+            std::fs::write(dir.join(format!("crash-{stamp}.txt")), "x").unwrap();
+        }
+        std::fs::write(dir.join("notes.txt"), "x").unwrap();
+        prune(&dir, 2);
+        let left: Vec<u128> = report_paths(&dir).iter().map(|p| report_stamp(p)).collect();
+        assert_eq!(left, vec![200, 1000]);
+        assert!(dir.join("notes.txt").exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
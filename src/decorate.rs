@@ -0,0 +1,294 @@
+// export-time decorations: a caption bar above the capture, a solid border
+// around both, and rounded outer corners. applied once per capture before it
+// is saved, copied or uploaded, so every destination gets the same pixels.
+// the per-destination downscale lives here too since it runs at the same point
+
+use crate::config::{DecorationConfig, ResizeConfig};
+use ab_glyph::{Font, FontVec, PxScale, ScaleFont};
+use image::{Rgba, RgbaImage};
+use std::sync::OnceLock;
+
+pub fn apply(image: &RgbaImage, deco: &DecorationConfig) -> RgbaImage {
+    use std::fmt::Write;
+    // the caption takes the same strftime fields as the filename template. a
+    // bad specifier makes chrono's Display fail, so fall back to the raw text
+    let mut caption = String::new();
+    if write!(caption, "{}", chrono::Local::now().format(&deco.caption)).is_err() {
+        caption = deco.caption.clone();
+    }
+    apply_with_font(image, deco, caption.trim(), ui_font())
+}
+
+/// the capture scaled down per `resize`, or None when it already fits
+pub fn downscale(image: &RgbaImage, resize: &ResizeConfig) -> Option<RgbaImage> {
+    let (w, h) = resize.target_size(image.width(), image.height())?;
+    Some(image::imageops::resize(
+        image,
+        w,
+        h,
+        image::imageops::FilterType::Lanczos3,
+    ))
+}
+
+fn apply_with_font(
+    image: &RgbaImage,
+    deco: &DecorationConfig,
+    caption: &str,
+    font: Option<&FontVec>,
+) -> RgbaImage {
+    let border = parse_hex(&deco.border_color).unwrap_or(Rgba([0, 0, 0, 255]));
+    let mut out = match font.filter(|_| !caption.is_empty()) {
+        Some(font) => with_caption(image, caption, font, border, deco.border_width > 0),
+        None => image.clone(),
+    };
+    if deco.border_width > 0 {
+        out = with_border(&out, deco.border_width, border);
+    }
+    if deco.corner_radius > 0 {
+        let fill = parse_hex(&deco.corner_background).unwrap_or(Rgba([0, 0, 0, 0]));
+        round_corners(&mut out, deco.corner_radius, fill);
+    }
+    out
+}
+
+fn with_caption(
+    image: &RgbaImage,
+    caption: &str,
+    font: &FontVec,
+    border: Rgba<u8>,
+    bordered: bool,
+) -> RgbaImage {
+    // scale with the capture so a 4k shot doesn't get a sliver of a bar
+    let px = (image.width() as f32 / 60.0).clamp(13.0, 40.0);
+    let bar = (px * 1.9).round() as u32;
+    // the bar joins the border when there is one, otherwise a neutral dark
+    let bg = if bordered {
+        border
+    } else {
+        Rgba([28, 28, 28, 255])
+    };
+    let ink = if luminance(bg) > 140.0 {
+        [5, 5, 5]
+    } else {
+        [245, 245, 245]
+    };
+
+    let mut out = RgbaImage::from_pixel(image.width(), image.height() + bar, bg);
+    image::imageops::replace(&mut out, image, 0, i64::from(bar));
+
+    let scaled = font.as_scaled(PxScale::from(px));
+    let pad = px;
+    let baseline = (bar as f32 + scaled.ascent() + scaled.descent()) / 2.0;
+    let mut x = pad;
+    let mut prev = None;
+    for c in caption.chars() {
+        let id = font.glyph_id(c);
+        if let Some(p) = prev {
+            x += scaled.kern(p, id);
+        }
+        let advance = scaled.h_advance(id);
+        // cut long captions off at the right padding rather than wrapping
+        if x + advance > image.width() as f32 - pad {
+            break;
+        }
+        let glyph = id.with_scale_and_position(px, ab_glyph::point(x, baseline));
+        if let Some(outlined) = font.outline_glyph(glyph) {
+            let bounds = outlined.px_bounds();
+            outlined.draw(|gx, gy, coverage| {
+                let px_x = bounds.min.x as i32 + gx as i32;
+                let px_y = bounds.min.y as i32 + gy as i32;
+                if px_x < 0 || px_y < 0 || px_y as u32 >= bar || px_x as u32 >= out.width() {
+                    return;
+                }
+                let dst = out.get_pixel_mut(px_x as u32, px_y as u32);
+                // zip stops at rgb; the bar is opaque
+                for (d, &k) in dst.0.iter_mut().zip(ink.iter()) {
+                    *d = (f32::from(k) * coverage + f32::from(*d) * (1.0 - coverage)).round() as u8;
+                }
+            });
+        }
+        x += advance;
+        prev = Some(id);
+    }
+    out
+}
+
+fn with_border(image: &RgbaImage, width: u32, color: Rgba<u8>) -> RgbaImage {
+    let mut out =
+        RgbaImage::from_pixel(image.width() + width * 2, image.height() + width * 2, color);
+    image::imageops::replace(&mut out, image, i64::from(width), i64::from(width));
+    out
+}
+
+// everything outside a rounded rectangle the size of the image becomes
+// `fill`, with one pixel of coverage-based falloff so the curve isn't stepped
+fn round_corners(image: &mut RgbaImage, radius: u32, fill: Rgba<u8>) {
+    let (w, h) = image.dimensions();
+    let r = radius.min(w / 2).min(h / 2);
+    if r == 0 {
+        return;
+    }
+    let rf = r as f32;
+    for cy in 0..r {
+        for cx in 0..r {
+            // distance from the pixel center to the corner arc's center
+            let dx = rf - (cx as f32 + 0.5);
+            let dy = rf - (cy as f32 + 0.5);
+            let outside = ((dx * dx + dy * dy).sqrt() - rf + 0.5).clamp(0.0, 1.0);
+            if outside == 0.0 {
+                continue;
+            }
+            for (x, y) in [
+                (cx, cy),
+                (w - 1 - cx, cy),
+                (cx, h - 1 - cy),
+                (w - 1 - cx, h - 1 - cy),
+            ] {
+                let px = image.get_pixel_mut(x, y);
+                for (p, &f) in px.0.iter_mut().zip(fill.0.iter()) {
+                    *p = (f32::from(f) * outside + f32::from(*p) * (1.0 - outside)).round() as u8;
+                }
+            }
+        }
+    }
+}
+
+fn parse_hex(s: &str) -> Option<Rgba<u8>> {
+    let hex = s.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let n = u32::from_str_radix(hex, 16).ok()?;
+    Some(Rgba([(n >> 16) as u8, (n >> 8) as u8, n as u8, 255]))
+}
+
+fn luminance(c: Rgba<u8>) -> f32 {
+    0.2126 * f32::from(c[0]) + 0.7152 * f32::from(c[1]) + 0.0722 * f32::from(c[2])
+}
+
+// there's no bundled face; the caption uses the desktop's own ui font, found
+// once per process. None leaves captions off rather than failing the capture
+fn ui_font() -> Option<&'static FontVec> {
+    static FONT: OnceLock<Option<FontVec>> = OnceLock::new();
+    FONT.get_or_init(|| {
+        let font = font_candidates()
+            .into_iter()
+            .find_map(|path| FontVec::try_from_vec(std::fs::read(&path).ok()?).ok());
+        if font.is_none() {
+            tracing::warn!("no ui font found; capture captions are disabled");
+        }
+        font
+    })
+    .as_ref()
+}
+
+#[cfg(windows)]
+fn font_candidates() -> Vec<std::path::PathBuf> {
+    let fonts = std::env::var_os("WINDIR")
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| "C:\\Windows".into())
+        .join("Fonts");
+    ["segoeui.ttf", "arial.ttf", "tahoma.ttf"]
+        .iter()
+        .map(|f| fonts.join(f))
+        .collect()
+}
+
+#[cfg(not(windows))]
+fn font_candidates() -> Vec<std::path::PathBuf> {
+    let mut paths = Vec::new();
+    // fontconfig knows the desktop's configured sans face
+    if let Ok(out) = std::process::Command::new("fc-match")
+        .args(["--format=%{file}", "sans-serif:style=Regular"])
+        .output()
+    {
+        let file = String::from_utf8_lossy(&out.stdout).trim().to_string();
+        if !file.is_empty() {
+            paths.push(file.into());
+        }
+    }
+    paths.extend(
+        [
+            "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+            "/usr/share/fonts/dejavu-sans-fonts/DejaVuSans.ttf",
+            "/usr/share/fonts/TTF/DejaVuSans.ttf",
+            "/usr/share/fonts/noto/NotoSans-Regular.ttf",
+            "/System/Library/Fonts/Supplemental/Arial.ttf",
+        ]
+        .iter()
+        .map(std::path::PathBuf::from),
+    );
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deco() -> DecorationConfig {
+        DecorationConfig::default()
+    }
+
+    #[test]
+    fn default_decoration_is_inactive_and_a_no_op() {
+        let d = deco();
+        assert!(!d.is_active());
+        let img = RgbaImage::from_pixel(10, 8, Rgba([1, 2, 3, 255]));
+        assert_eq!(apply_with_font(&img, &d, "", None), img);
+    }
+
+    #[test]
+    fn border_grows_the_image_and_keeps_the_pixels() {
+        let mut d = deco();
+        d.border_width = 4;
+        d.border_color = "#ff0000".into();
+        let img = RgbaImage::from_pixel(10, 8, Rgba([1, 2, 3, 255]));
+        let out = apply_with_font(&img, &d, "", None);
+        assert_eq!(out.dimensions(), (18, 16));
+        assert_eq!(*out.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+        assert_eq!(*out.get_pixel(4, 4), Rgba([1, 2, 3, 255]));
+    }
+
+    #[test]
+    fn rounded_corners_clear_the_corner_and_keep_the_middle() {
+        let mut d = deco();
+        d.corner_radius = 6;
+        let img = RgbaImage::from_pixel(20, 20, Rgba([200, 200, 200, 255]));
+        let out = apply_with_font(&img, &d, "", None);
+        assert_eq!(out.get_pixel(0, 0)[3], 0);
+        assert_eq!(out.get_pixel(19, 19)[3], 0);
+        assert_eq!(*out.get_pixel(10, 10), Rgba([200, 200, 200, 255]));
+        // the straight edge between the corners is untouched
+        assert_eq!(out.get_pixel(10, 0)[3], 255);
+    }
+
+    #[test]
+    fn caption_without_a_font_is_skipped() {
+        let mut d = deco();
+        d.caption = "%Y".into();
+        let img = RgbaImage::from_pixel(10, 8, Rgba([1, 2, 3, 255]));
+        assert_eq!(
+            apply_with_font(&img, &d, "2026", None).dimensions(),
+            (10, 8)
+        );
+    }
+
+    #[test]
+    fn downscale_only_when_over_the_limit() {
+        let resize = ResizeConfig {
+            mode: crate::config::ResizeMode::MaxDimension,
+            max_dimension: 20,
+            percent: 50,
+        };
+        let img = RgbaImage::from_pixel(40, 10, Rgba([9, 9, 9, 255]));
+        assert_eq!(downscale(&img, &resize).unwrap().dimensions(), (20, 5));
+        assert!(downscale(&RgbaImage::new(20, 20), &resize).is_none());
+    }
+
+    #[test]
+    fn parse_hex_reads_rrggbb_only() {
+        assert_eq!(parse_hex("#0a0B0c"), Some(Rgba([10, 11, 12, 255])));
+        assert_eq!(parse_hex(""), None);
+        assert_eq!(parse_hex("#fff"), None);
+    }
+}
@@ -0,0 +1,104 @@
+// pixel diff of two captures for the history view's compare tool: a heatmap
+// over a faded copy of the first image, and how many pixels changed. meant
+// for before/after UI checks, so captures of different sizes still compare:
+// anything only one of them covers counts as changed.
+
+use image::{Rgba, RgbaImage};
+
+// unchanged pixels are kept at this fraction of their luminance so the
+// heatmap still shows where on the screen a change is
+const BACKDROP_LEVEL: f32 = 0.25;
+// what the area covered by only one of the images is painted
+const UNCOVERED: Rgba<u8> = Rgba([200, 0, 200, 255]);
+
+#[derive(Debug)]
+pub struct Diff {
+    pub heatmap: RgbaImage,
+    pub changed: u64,
+    pub total: u64,
+}
+
+/// compare `a` against `b`. a pixel counts as changed when any channel moved
+/// by more than `tolerance`, which lets jpeg noise through as unchanged
+pub fn compare(a: &RgbaImage, b: &RgbaImage, tolerance: u8) -> Diff {
+    let width = a.width().max(b.width());
+    let height = a.height().max(b.height());
+    let mut heatmap = RgbaImage::new(width, height);
+    let mut changed = 0u64;
+    for (x, y, out) in heatmap.enumerate_pixels_mut() {
+        let (pa, pb) = match (a.get_pixel_checked(x, y), b.get_pixel_checked(x, y)) {
+            (Some(pa), Some(pb)) => (pa, pb),
+            _ => {
+                changed += 1;
+                *out = UNCOVERED;
+                continue;
+            }
+        };
+        let delta =
+            pa.0.iter()
+                .zip(pb.0.iter())
+                .map(|(&ca, &cb)| ca.abs_diff(cb))
+                .max()
+                .unwrap_or(0);
+        *out = if delta > tolerance {
+            changed += 1;
+            heat(delta)
+        } else {
+            backdrop(pa)
+        };
+    }
+    Diff {
+        heatmap,
+        changed,
+        total: width as u64 * height as u64,
+    }
+}
+
+// yellow for a slight change through to red for a full swing
+fn heat(delta: u8) -> Rgba<u8> {
+    Rgba([255, 255 - delta, 0, 255])
+}
+
+fn backdrop(p: &Rgba<u8>) -> Rgba<u8> {
+    let [r, g, b, _] = p.0;
+    let luma = 0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32;
+    let level = (luma * BACKDROP_LEVEL).round() as u8;
+    Rgba([level, level, level, 255])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_images_have_no_changes() {
+        let img = RgbaImage::from_pixel(6, 4, Rgba([120, 40, 200, 255]));
+        let diff = compare(&img, &img, 0);
+        assert_eq!(diff.changed, 0);
+        assert_eq!(diff.total, 24);
+        assert_eq!(diff.heatmap.dimensions(), (6, 4));
+    }
+
+    #[test]
+    fn changes_past_the_tolerance_are_counted_and_painted() {
+        let a = RgbaImage::from_pixel(4, 4, Rgba([100, 100, 100, 255]));
+        let mut b = a.clone();
+        b.put_pixel(0, 0, Rgba([104, 100, 100, 255]));
+        b.put_pixel(3, 3, Rgba([255, 100, 100, 255]));
+        let diff = compare(&a, &b, 8);
+        assert_eq!(diff.changed, 1);
+        assert_eq!(*diff.heatmap.get_pixel(3, 3), heat(155));
+        assert_eq!(*diff.heatmap.get_pixel(0, 0), backdrop(a.get_pixel(0, 0)));
+    }
+
+    #[test]
+    fn size_mismatch_counts_the_uncovered_area() {
+        let a = RgbaImage::from_pixel(4, 2, Rgba([0, 0, 0, 255]));
+        let b = RgbaImage::from_pixel(2, 3, Rgba([0, 0, 0, 255]));
+        let diff = compare(&a, &b, 0);
+        assert_eq!(diff.heatmap.dimensions(), (4, 3));
+        // 4x3 canvas, only the 2x2 both cover matches
+        assert_eq!(diff.changed, 8);
+        assert_eq!(*diff.heatmap.get_pixel(3, 0), UNCOVERED);
+    }
+}
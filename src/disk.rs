@@ -0,0 +1,125 @@
+use anyhow::{anyhow, Result};
+use std::path::Path;
+
+// left free on top of the estimate: encoders and muxers overshoot it, and a
+// volume filled to the last byte takes the rest of the system down with us
+const HEADROOM_BYTES: u64 = 64 * 1024 * 1024;
+
+/// fail before a write of about `needed` bytes into `dir` would run the
+/// volume dry, instead of leaving a truncated file behind. a volume that
+/// can't be probed (missing folder, unsupported platform) lets it through
+pub fn ensure_room(dir: &Path, needed: u64, what: &str) -> Result<()> {
+    let Some(free) = free_space(dir) else {
+        return Ok(());
+    };
+    if has_room(free, needed) {
+        return Ok(());
+    }
+    Err(anyhow!(
+        "not enough disk space for the {what}: it needs about {}, and {} has {} free. \
+         free some space or point the output folder somewhere else, then try again",
+        mib(needed),
+        dir.display(),
+        mib(free)
+    ))
+}
+
+fn has_room(free: u64, needed: u64) -> bool {
+    free >= needed.saturating_add(HEADROOM_BYTES)
+}
+
+fn mib(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+/// bytes available to this user on the volume holding `dir`
+#[cfg(windows)]
+pub fn free_space(dir: &Path) -> Option<u64> {
+    use windows::core::PCWSTR;
+    use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let wide: Vec<u16> = dir
+        .as_os_str()
+        .to_string_lossy()
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .collect();
+    let mut available: u64 = 0;
+    unsafe {
+        GetDiskFreeSpaceExW(PCWSTR(wide.as_ptr()), Some(&mut available), None, None).ok()?;
+    }
+    Some(available)
+}
+
+/// bytes available to this user on the volume holding `dir`
+#[cfg(target_os = "linux")]
+pub fn free_space(dir: &Path) -> Option<u64> {
+    use std::os::unix::ffi::OsStrExt;
+    let dir = std::ffi::CString::new(dir.as_os_str().as_bytes()).ok()?;
+    let mut stats: libc::statvfs = unsafe { std::mem::zeroed() };
+    // f_bavail = blocks available to unprivileged users, matching the windows
+    // call's lpFreeBytesAvailable semantics
+    (unsafe { libc::statvfs(dir.as_ptr(), &mut stats) } == 0)
+        .then(|| stats.f_bavail as u64 * stats.f_frsize as u64)
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+pub fn free_space(_dir: &Path) -> Option<u64> {
+    None
+}
+
+/// replace `path` with `bytes` through a hidden sibling temp file and a
+/// rename, so a crash or a full disk mid-write leaves the old file whole
+pub fn replace_file(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("capscr");
+    let tmp = path.with_file_name(format!(".{name}.tmp"));
+    let written = std::fs::File::create(&tmp).and_then(|mut file| {
+        file.write_all(bytes)?;
+        // the rename must never land ahead of the data
+        file.sync_all()
+    });
+    let result = written.and_then(|()| std::fs::rename(&tmp, path));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(windows, target_os = "linux"))]
+    #[test]
+    fn free_space_reports_a_nonzero_figure() {
+        assert!(free_space(&std::env::temp_dir()).is_some_and(|bytes| bytes > 0));
+    }
+
+    #[test]
+    fn room_needs_headroom_past_the_estimate() {
+        let needed = 100 * 1024 * 1024;
+        assert!(has_room(needed + HEADROOM_BYTES, needed));
+        assert!(!has_room(needed + HEADROOM_BYTES - 1, needed));
+        assert!(!has_room(u64::MAX - 1, u64::MAX));
+    }
+
+    #[test]
+    fn replace_file_swaps_the_whole_file_and_leaves_no_temp() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("shot.png");
+        std::fs::write(&path, b"old and longer").unwrap();
+        replace_file(&path, b"new").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"new");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn a_missing_folder_is_not_a_failure() {
+        let missing = std::env::temp_dir().join("capscr-no-such-folder/deeper");
+        assert!(ensure_room(&missing, u64::MAX, "recording").is_ok());
+    }
+}
@@ -0,0 +1,96 @@
+// do not disturb: for presenting or gaming. while it's on, task hotkeys are
+// released (so the keys reach whatever has focus), and notifications and
+// capture sounds are dropped; the tray and the hub keep working. only the
+// do-not-disturb hotkey itself stays bound, so it can turn the mode back off.
+// it lives in memory only: a restart always comes back with it off.
+
+use crate::state::AppState;
+use crate::tray::SystemTray;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Manager};
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+// unix ms the auto-resume timer ends at; None while it's on until turned off
+static UNTIL: Mutex<Option<u64>> = Mutex::new(None);
+// bumped on every change, so a superseded auto-resume timer does nothing
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq)]
+pub struct DndStatus {
+    pub active: bool,
+    /// unix ms it turns itself off at, when it was turned on for a while
+    pub until_ms: Option<u64>,
+}
+
+pub fn active() -> bool {
+    ACTIVE.load(Ordering::SeqCst)
+}
+
+pub fn status() -> DndStatus {
+    DndStatus {
+        active: active(),
+        until_ms: *UNTIL.lock().unwrap(),
+    }
+}
+
+/// turn do not disturb on, for `minutes` or (None / 0) until turned off
+pub fn enable(app: &AppHandle, minutes: Option<u32>) {
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let duration = minutes
+        .filter(|&m| m > 0)
+        .map(|m| Duration::from_secs(m as u64 * 60));
+    *UNTIL.lock().unwrap() = duration.map(|d| {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        (now + d).as_millis() as u64
+    });
+    let was = ACTIVE.swap(true, Ordering::SeqCst);
+    if let Some(duration) = duration {
+        let app = app.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(duration);
+            if GENERATION.load(Ordering::SeqCst) == generation {
+                disable(&app);
+            }
+        });
+    }
+    changed(app, !was);
+}
+
+pub fn disable(app: &AppHandle) {
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+    *UNTIL.lock().unwrap() = None;
+    let was = ACTIVE.swap(false, Ordering::SeqCst);
+    changed(app, was);
+}
+
+/// the do-not-disturb hotkey: on for the configured time, or off again
+pub fn toggle(app: &AppHandle) {
+    if active() {
+        disable(app);
+        return;
+    }
+    let minutes = app
+        .state::<AppState>()
+        .config
+        .lock()
+        .unwrap()
+        .hotkeys
+        .do_not_disturb_minutes;
+    enable(app, Some(minutes));
+}
+
+// `flipped` is false when only the timer changed, which leaves the bindings be
+fn changed(app: &AppHandle, flipped: bool) {
+    if flipped {
+        // the hotkey thread drops every task binding while it's on
+        app.state::<AppState>().reload_hotkeys();
+        tracing::info!("do not disturb {}", if active() { "on" } else { "off" });
+    }
+    SystemTray::rebuild(app);
+    let _ = app.emit("capscr://dnd-changed", status());
+}
@@ -0,0 +1,70 @@
+// a capture identical to the one just before it is nearly always a hotkey
+// pressed twice, or a press that was thought not to have taken. each pipeline
+// capture is hashed and compared with the last one; inside a minute a match
+// is warned about or dropped, per capture.duplicates. only the last capture
+// is remembered, in memory, so an unchanged screen taken again a while later
+// is kept like any other.
+
+use image::RgbaImage;
+use sha2::{Digest, Sha256};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Seen {
+    size: (u32, u32),
+    digest: [u8; 32],
+    at: Instant,
+}
+
+static LAST: Mutex<Option<Seen>> = Mutex::new(None);
+
+/// remember `image` as the latest capture. returns how long ago an identical
+/// one was taken, when that was within the window
+pub fn check(image: &RgbaImage) -> Option<Duration> {
+    let seen = Seen {
+        size: image.dimensions(),
+        digest: Sha256::digest(image.as_raw()).into(),
+        at: Instant::now(),
+    };
+    let mut last = LAST.lock().unwrap();
+    let repeat = repeat_of(last.as_ref(), &seen);
+    *last = Some(seen);
+    repeat
+}
+
+fn repeat_of(last: Option<&Seen>, next: &Seen) -> Option<Duration> {
+    let last = last?;
+    let ago = next.at.saturating_duration_since(last.at);
+    (last.size == next.size && last.digest == next.digest && ago <= WINDOW).then_some(ago)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seen(fill: u8, at: Instant) -> Seen {
+        let image = RgbaImage::from_pixel(4, 3, image::Rgba([fill, 0, 0, 255]));
+        Seen {
+            size: image.dimensions(),
+            digest: Sha256::digest(image.as_raw()).into(),
+            at,
+        }
+    }
+
+    #[test]
+    fn only_the_same_pixels_inside_a_minute_count_as_a_repeat() {
+        let t0 = Instant::now();
+        let first = seen(10, t0);
+        assert_eq!(repeat_of(None, &first), None);
+        let again = seen(10, t0 + Duration::from_secs(2));
+        assert_eq!(
+            repeat_of(Some(&first), &again),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(repeat_of(Some(&first), &seen(11, t0)), None);
+        assert_eq!(repeat_of(Some(&first), &seen(10, t0 + WINDOW * 2)), None);
+    }
+}
@@ -0,0 +1,167 @@
+// capture saves are encoded on a small pool of their own instead of a fresh
+// thread each: a burst of 4k pngs otherwise fights the next capture for every
+// core. the hub shows how many saves are waiting and roughly how much they'll
+// write, and can drop the ones that haven't started yet.
+
+use crate::config::ImageFormat;
+use crossbeam_channel::Sender;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use tauri::{AppHandle, Emitter};
+
+// png encoding is single threaded per image; two saves in flight keep a burst
+// moving without starving the capture path
+const WORKERS: usize = 2;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq)]
+pub struct EncodingProgress {
+    pub queued: usize,
+    pub running: usize,
+    /// rough size of what the queued and running saves will write
+    pub pending_bytes: u64,
+}
+
+struct Job {
+    epoch: u64,
+    estimate: u64,
+    app: AppHandle,
+    run: Box<dyn FnOnce() + Send>,
+    cancel: Box<dyn FnOnce() + Send>,
+}
+
+static QUEUE: OnceLock<Sender<Job>> = OnceLock::new();
+static PROGRESS: Mutex<EncodingProgress> = Mutex::new(EncodingProgress {
+    queued: 0,
+    running: 0,
+    pending_bytes: 0,
+});
+// bumped by cancel_pending; a job queued under an older epoch is dropped when
+// a worker picks it up
+static CANCEL_EPOCH: AtomicU64 = AtomicU64::new(0);
+
+/// queue `run` on the encode pool. `cancel` runs instead when the job is
+/// cancelled before it starts, to clean up whatever the caller set aside for it
+pub fn submit(
+    app: &AppHandle,
+    estimate: u64,
+    run: impl FnOnce() + Send + 'static,
+    cancel: impl FnOnce() + Send + 'static,
+) {
+    update(app, |p| {
+        p.queued += 1;
+        p.pending_bytes += estimate;
+    });
+    let job = Job {
+        epoch: CANCEL_EPOCH.load(Ordering::SeqCst),
+        estimate,
+        app: app.clone(),
+        run: Box::new(run),
+        cancel: Box::new(cancel),
+    };
+    // the workers never exit, so the send only fails if none could be spawned
+    if let Err(err) = queue().send(job) {
+        tracing::warn!("encode pool unavailable, saving inline");
+        work(err.into_inner());
+    }
+}
+
+/// drop every save that hasn't started. returns how many that was
+pub fn cancel_pending() -> usize {
+    let queued = PROGRESS.lock().unwrap().queued;
+    CANCEL_EPOCH.fetch_add(1, Ordering::SeqCst);
+    queued
+}
+
+pub fn progress() -> EncodingProgress {
+    *PROGRESS.lock().unwrap()
+}
+
+/// what a `width`x`height` capture is likely to take on disk. ratios are
+/// typical of ui screenshots, which compress far better than photos; good for
+/// a ballpark in the status bar and nothing else
+pub fn estimate_bytes(width: u32, height: u32, format: ImageFormat) -> u64 {
+    let raw = width as u64 * height as u64 * 4;
+    match format {
+        ImageFormat::Bmp => raw,
+        ImageFormat::Png | ImageFormat::Gif => raw / 5,
+        ImageFormat::Jpeg | ImageFormat::Webp => raw / 12,
+        ImageFormat::Avif | ImageFormat::Jxl => raw / 20,
+    }
+}
+
+fn queue() -> &'static Sender<Job> {
+    QUEUE.get_or_init(|| {
+        let (tx, rx) = crossbeam_channel::unbounded::<Job>();
+        for i in 0..WORKERS {
+            let rx = rx.clone();
+            let spawned = std::thread::Builder::new()
+                .name(format!("capscr-encode-{i}"))
+                .spawn(move || {
+                    for job in rx {
+                        work(job);
+                    }
+                });
+            if let Err(e) = spawned {
+                tracing::warn!("couldn't start encode worker: {e}");
+            }
+        }
+        tx
+    })
+}
+
+fn work(job: Job) {
+    let Job {
+        epoch,
+        estimate,
+        app,
+        run,
+        cancel,
+    } = job;
+    if epoch < CANCEL_EPOCH.load(Ordering::SeqCst) {
+        cancel();
+        update(&app, |p| {
+            p.queued -= 1;
+            p.pending_bytes = p.pending_bytes.saturating_sub(estimate);
+        });
+        return;
+    }
+    update(&app, |p| {
+        p.queued -= 1;
+        p.running += 1;
+    });
+    run();
+    update(&app, |p| {
+        p.running -= 1;
+        p.pending_bytes = p.pending_bytes.saturating_sub(estimate);
+    });
+}
+
+fn update(app: &AppHandle, change: impl FnOnce(&mut EncodingProgress)) {
+    let snapshot = {
+        let mut progress = PROGRESS.lock().unwrap();
+        change(&mut progress);
+        *progress
+    };
+    let _ = app.emit("capscr://encoding-progress", snapshot);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimates_follow_how_well_the_format_compresses() {
+        let png = estimate_bytes(3840, 2160, ImageFormat::Png);
+        assert_eq!(
+            estimate_bytes(3840, 2160, ImageFormat::Bmp),
+            3840 * 2160 * 4
+        );
+        assert!(png > estimate_bytes(3840, 2160, ImageFormat::Jpeg));
+        assert!(
+            estimate_bytes(3840, 2160, ImageFormat::Jpeg)
+                > estimate_bytes(3840, 2160, ImageFormat::Avif)
+        );
+        assert_eq!(estimate_bytes(0, 2160, ImageFormat::Png), 0);
+    }
+}
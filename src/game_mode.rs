@@ -0,0 +1,255 @@
+// game mode: while a full-screen app (a game, usually) has focus, capscr can
+// hand its bare hotkeys back to it, or capture through WGC, which reads an
+// exclusive full-screen swapchain without the stall and black frames GDI
+// gives. what happens is set per process in capture.game_mode, with a
+// default for unlisted apps. the focused window is polled rather than hooked:
+// a couple of seconds' lag entering or leaving a game doesn't matter, and it
+// costs nothing while the option is off.
+
+use crate::capture::{self, exclusion, Rectangle};
+use crate::config::{GameModeAction, GameModeConfig};
+use crate::state::AppState;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+const POLL: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct GameModeStatus {
+    /// process name of the full-screen app, while one has focus
+    pub app: Option<String>,
+    pub action: GameModeAction,
+}
+
+static CURRENT: Mutex<GameModeStatus> = Mutex::new(GameModeStatus {
+    app: None,
+    action: GameModeAction::None,
+});
+static STARTED: AtomicBool = AtomicBool::new(false);
+
+pub fn status() -> GameModeStatus {
+    CURRENT.lock().unwrap().clone()
+}
+
+/// whether game mode holds `hotkey` back right now
+pub fn suspends(hotkey: &str) -> bool {
+    CURRENT.lock().unwrap().action == GameModeAction::SuspendHotkeys && is_bare(hotkey)
+}
+
+/// start watching the focused window. called once from setup
+pub fn spawn(app: &AppHandle) {
+    if STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let app = app.clone();
+    let spawned = std::thread::Builder::new()
+        .name("capscr-game-mode".into())
+        .spawn(move || loop {
+            let config = app
+                .state::<AppState>()
+                .config
+                .lock()
+                .unwrap()
+                .capture
+                .game_mode
+                .clone();
+            let next = if config.enabled {
+                detect(&config)
+            } else {
+                GameModeStatus::default()
+            };
+            if next != *CURRENT.lock().unwrap() {
+                apply(&app, next);
+            }
+            std::thread::sleep(POLL);
+        });
+    if let Err(e) = spawned {
+        tracing::warn!("couldn't start game mode watcher: {e}");
+    }
+}
+
+fn detect(config: &GameModeConfig) -> GameModeStatus {
+    let Some(app) = fullscreen_app() else {
+        return GameModeStatus::default();
+    };
+    let action = action_for(config, &app);
+    if action == GameModeAction::None {
+        return GameModeStatus::default();
+    }
+    GameModeStatus {
+        app: Some(app),
+        action,
+    }
+}
+
+fn apply(app: &AppHandle, next: GameModeStatus) {
+    let hotkeys_changed = {
+        let mut current = CURRENT.lock().unwrap();
+        let was_suspending = current.action == GameModeAction::SuspendHotkeys;
+        *current = next.clone();
+        was_suspending != (next.action == GameModeAction::SuspendHotkeys)
+    };
+    capture::set_wgc_forced(next.action == GameModeAction::LowOverhead);
+    if hotkeys_changed {
+        app.state::<AppState>().reload_hotkeys();
+    }
+    match &next.app {
+        Some(name) => tracing::info!("game mode: {name} is full screen ({:?})", next.action),
+        None => tracing::info!("game mode: no full-screen app"),
+    }
+    let _ = app.emit("capscr://game-mode", next);
+}
+
+fn action_for(config: &GameModeConfig, app: &str) -> GameModeAction {
+    config
+        .apps
+        .iter()
+        .find(|rule| exclusion::app_matches(std::slice::from_ref(&rule.app), app))
+        .map_or(config.action, |rule| rule.action)
+}
+
+// no ctrl, alt or win: the keys a game binds too. mouse buttons count
+fn is_bare(hotkey: &str) -> bool {
+    !hotkey.is_empty()
+        && !hotkey.split('+').any(|part| {
+            matches!(
+                part.trim().to_lowercase().as_str(),
+                "ctrl" | "control" | "alt" | "super" | "win" | "meta" | "cmd"
+            )
+        })
+}
+
+// a window fills its monitor when it covers all of it; borderless games
+// often overhang by a pixel or two
+fn covers(window: &Rectangle, monitor: &Rectangle) -> bool {
+    let right = |r: &Rectangle| r.x as i64 + r.width as i64;
+    let bottom = |r: &Rectangle| r.y as i64 + r.height as i64;
+    window.x <= monitor.x
+        && window.y <= monitor.y
+        && right(window) >= right(monitor)
+        && bottom(window) >= bottom(monitor)
+}
+
+/// process name of the focused window when it fills its monitor
+#[cfg(windows)]
+fn fullscreen_app() -> Option<String> {
+    use windows::Win32::Foundation::RECT;
+    use windows::Win32::Graphics::Gdi::{
+        GetMonitorInfoW, MonitorFromWindow, MONITORINFO, MONITOR_DEFAULTTONEAREST,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetClassNameW, GetForegroundWindow, GetWindowRect, GetWindowThreadProcessId, IsIconic,
+    };
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.0.is_null() || IsIconic(hwnd).as_bool() {
+            return None;
+        }
+        // the desktop covers the monitor too
+        let mut class = [0u16; 64];
+        let len = GetClassNameW(hwnd, &mut class) as usize;
+        if matches!(
+            String::from_utf16_lossy(&class[..len]).as_str(),
+            "Progman" | "WorkerW" | "Shell_TrayWnd"
+        ) {
+            return None;
+        }
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if pid == std::process::id() {
+            return None;
+        }
+        let mut rect = RECT::default();
+        GetWindowRect(hwnd, &mut rect).ok()?;
+        let mut info = MONITORINFO {
+            cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+            ..Default::default()
+        };
+        let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+        if !GetMonitorInfoW(monitor, &mut info).as_bool() {
+            return None;
+        }
+        let window = Rectangle::normalize(rect.left, rect.top, rect.right, rect.bottom);
+        let m = info.rcMonitor;
+        let monitor = Rectangle::normalize(m.left, m.top, m.right, m.bottom);
+        if !covers(&window, &monitor) {
+            return None;
+        }
+        exclusion::process_name(pid)
+    }
+}
+
+// x11 through xcap. wayland doesn't say which window has focus, so nothing is
+// ever detected there
+#[cfg(not(windows))]
+fn fullscreen_app() -> Option<String> {
+    let window = xcap::Window::all()
+        .ok()?
+        .into_iter()
+        .find(|w| w.is_focused().unwrap_or(false))?;
+    let pid = window.pid().ok()?;
+    if pid == std::process::id() || window.is_minimized().unwrap_or(false) {
+        return None;
+    }
+    let rect = Rectangle::new(
+        window.x().ok()?,
+        window.y().ok()?,
+        window.width().ok()?,
+        window.height().ok()?,
+    );
+    let filled = capture::list_monitors().ok()?.iter().any(|m| {
+        let monitor = Rectangle::new(m.x, m.y, m.width, m.height);
+        covers(&rect, &monitor)
+    });
+    if !filled {
+        return None;
+    }
+    exclusion::process_name(pid).or_else(|| window.app_name().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GameModeRule;
+
+    #[test]
+    fn only_hotkeys_without_a_chord_modifier_are_bare() {
+        assert!(is_bare("F9"));
+        assert!(is_bare("PrintScreen"));
+        assert!(is_bare("Shift+F"));
+        assert!(is_bare("Mouse4"));
+        assert!(!is_bare("Ctrl+Shift+S"));
+        assert!(!is_bare("Win+Alt+R"));
+        assert!(!is_bare(""));
+    }
+
+    #[test]
+    fn a_window_has_to_cover_its_whole_monitor() {
+        let monitor = Rectangle::new(1920, 0, 2560, 1440);
+        assert!(covers(&monitor, &monitor));
+        assert!(covers(&Rectangle::new(1918, -2, 2564, 1444), &monitor));
+        assert!(!covers(&Rectangle::new(1920, 0, 2560, 1400), &monitor));
+        assert!(!covers(&Rectangle::new(0, 0, 1920, 1080), &monitor));
+    }
+
+    #[test]
+    fn per_app_rules_override_the_default() {
+        let config = GameModeConfig {
+            enabled: true,
+            action: GameModeAction::SuspendHotkeys,
+            apps: vec![GameModeRule {
+                app: "vlc".into(),
+                action: GameModeAction::None,
+            }],
+        };
+        assert_eq!(action_for(&config, "vlc.exe"), GameModeAction::None);
+        assert_eq!(
+            action_for(&config, "eldenring.exe"),
+            GameModeAction::SuspendHotkeys
+        );
+    }
+}
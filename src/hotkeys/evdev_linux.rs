@@ -346,6 +346,9 @@ fn read_device(path: PathBuf, app: AppHandle) {
     let mut buf = [0u8; EVENT_SIZE];
     // per-thread dedupe so a fast double-report of one press fires once
     let mut last_fire: HashMap<String, Instant> = HashMap::new();
+    // key that fired a task and is still down, so its release can end a
+    // hold-to-record recording. side buttons fire on release already
+    let mut held: Option<(u16, String)> = None;
     loop {
         // evdev delivers whole 24-byte records; read_exact stays aligned
         if file.read_exact(&mut buf).is_err() {
@@ -370,39 +373,57 @@ fn read_device(path: PathBuf, app: AppHandle) {
             continue;
         }
 
+        if value == 0 && held.as_ref().is_some_and(|(held, _)| *held == code) {
+            if let Some((_, task_id)) = held.take() {
+                super::gesture::release(&app, &task_id);
+            }
+            continue;
+        }
         if binding_fires_on_edge(code, value) {
-            dispatch(&app, normalize_button(code).unwrap_or(code), &mut last_fire);
+            let fired = dispatch(&app, normalize_button(code).unwrap_or(code), &mut last_fire);
+            if normalize_button(code).is_none() {
+                held = fired.map(|task_id| (code, task_id));
+            } else if let Some(task_id) = fired.filter(|id| super::gesture::is_gestured(id)) {
+                // the button is already up; a double click still counts
+                super::gesture::release(&app, &task_id);
+            }
         }
     }
 }
 
-fn dispatch(app: &AppHandle, code: u16, last_fire: &mut HashMap<String, Instant>) {
+/// fire the task bound to `code` under the held modifiers. returns its id
+fn dispatch(
+    app: &AppHandle,
+    code: u16,
+    last_fire: &mut HashMap<String, Instant>,
+) -> Option<String> {
     if app
         .state::<crate::state::AppState>()
         .hotkeys_disabled
         .load(Ordering::SeqCst)
     {
-        return;
+        return None;
     }
     let mods = MODS.load(Ordering::SeqCst);
     let task_id = {
         let guard = BINDINGS.lock().unwrap();
         guard.as_ref().and_then(|m| m.get(&(mods, code)).cloned())
-    };
-    let Some(task_id) = task_id else {
-        return;
-    };
+    }?;
+    if super::gesture::press(app, &task_id) {
+        return Some(task_id);
+    }
     let now = Instant::now();
     let recent = last_fire
         .get(&task_id)
         .map(|t| now.duration_since(*t).as_millis() <= 250)
         .unwrap_or(false);
     if recent {
-        return;
+        return None;
     }
     last_fire.insert(task_id.clone(), now);
     tracing::debug!("evdev: triggering task '{task_id}'");
     crate::commands::trigger_task(app, &task_id);
+    Some(task_id)
 }
 
 #[cfg(test)]
@@ -0,0 +1,376 @@
+// double- and long-press gestures on a single hotkey (PrintScreen once for a
+// region, twice for the full screen, held for a recording). the os only ever
+// binds the key once: tasks sharing it ride the binding of the first one, and
+// every backend's press and release for that binding is fed through a small
+// state machine here that settles which task the gesture meant. a plain press
+// on a gestured key fires on release (or once the double-press window runs
+// out) instead of on key-down, since until then it could still become one of
+// the others.
+
+use crate::config::{CaptureTask, HotkeyGesture};
+use crossbeam_channel::Sender;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+/// how long after a release a second press still counts as a double press
+pub const DOUBLE_PRESS_WINDOW: Duration = Duration::from_millis(300);
+/// how long a key has to stay down to count as a long press
+pub const LONG_PRESS: Duration = Duration::from_millis(500);
+
+/// the tasks bound to one hotkey, by gesture
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Group {
+    pub press: Option<String>,
+    pub double_press: Option<String>,
+    pub long_press: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Phase {
+    #[default]
+    Idle,
+    /// down, not yet long enough to be a long press
+    Down { since: Instant },
+    /// released once; a press before the window closes is a double press
+    Up { since: Instant },
+    /// a gesture already fired; waiting for the key to come back up
+    Spent,
+}
+
+/// where one gestured hotkey is between its press and the task it fires
+#[derive(Debug, Default)]
+pub struct Machine {
+    phase: Phase,
+}
+
+impl Machine {
+    pub fn press(&mut self, group: &Group, now: Instant) -> Option<String> {
+        match self.phase {
+            Phase::Idle => {
+                self.phase = Phase::Down { since: now };
+                None
+            }
+            Phase::Up { .. } => {
+                self.phase = Phase::Spent;
+                group.double_press.clone()
+            }
+            // auto-repeat of a key that's still down
+            Phase::Down { .. } | Phase::Spent => None,
+        }
+    }
+
+    pub fn release(&mut self, group: &Group, now: Instant) -> Option<String> {
+        let since = match self.phase {
+            Phase::Down { since } => since,
+            Phase::Spent => {
+                self.phase = Phase::Idle;
+                return None;
+            }
+            Phase::Idle | Phase::Up { .. } => return None,
+        };
+        self.phase = Phase::Idle;
+        if group.long_press.is_some() && now.duration_since(since) >= LONG_PRESS {
+            return group.long_press.clone();
+        }
+        if group.double_press.is_some() {
+            self.phase = Phase::Up { since: now };
+            return None;
+        }
+        group.press.clone()
+    }
+
+    /// fire whatever a timeout settles: a key held past LONG_PRESS, or a
+    /// single press whose double-press window ran out
+    pub fn poll(&mut self, group: &Group, now: Instant) -> Option<String> {
+        match self.deadline(group) {
+            Some(deadline) if now >= deadline => {}
+            _ => return None,
+        }
+        match self.phase {
+            Phase::Down { .. } => {
+                self.phase = Phase::Spent;
+                group.long_press.clone()
+            }
+            Phase::Up { .. } => {
+                self.phase = Phase::Idle;
+                group.press.clone()
+            }
+            Phase::Idle | Phase::Spent => None,
+        }
+    }
+
+    pub fn deadline(&self, group: &Group) -> Option<Instant> {
+        match self.phase {
+            Phase::Down { since } if group.long_press.is_some() => Some(since + LONG_PRESS),
+            Phase::Up { since } => Some(since + DOUBLE_PRESS_WINDOW),
+            _ => None,
+        }
+    }
+}
+
+// binding task id -> the group riding it
+static GROUPS: Mutex<Option<HashMap<String, Group>>> = Mutex::new(None);
+static INPUT: OnceLock<Sender<Input>> = OnceLock::new();
+
+struct Input {
+    lead: String,
+    down: bool,
+    at: Instant,
+    app: AppHandle,
+}
+
+/// group `tasks` by hotkey. a hotkey any task wants a double or long press
+/// on is bound once, under its first task's id; returns the other tasks on
+/// it, which don't get a binding of their own
+pub fn set_tasks(tasks: &[CaptureTask]) -> HashSet<String> {
+    let (groups, riders) = build_groups(tasks);
+    *GROUPS.lock().unwrap() = Some(groups);
+    riders
+}
+
+/// `registered` plus the tasks riding one of those bindings
+pub fn with_riders(registered: Vec<String>) -> Vec<String> {
+    let guard = GROUPS.lock().unwrap();
+    let Some(groups) = guard.as_ref() else {
+        return registered;
+    };
+    let mut ids = registered.clone();
+    for lead in &registered {
+        if let Some(group) = groups.get(lead) {
+            for id in [&group.press, &group.double_press, &group.long_press]
+                .into_iter()
+                .flatten()
+            {
+                if id != lead {
+                    ids.push(id.clone());
+                }
+            }
+        }
+    }
+    ids
+}
+
+/// a binding went down. false when it isn't gestured and the caller should
+/// fire it as usual
+pub fn press(app: &AppHandle, binding: &str) -> bool {
+    feed(app, binding, true)
+}
+
+/// a binding came back up. gestured ones feed their machine; anything else
+/// goes on to a hold-to-record release
+pub fn release(app: &AppHandle, binding: &str) {
+    if !feed(app, binding, false) {
+        crate::commands::release_task(app, binding);
+    }
+}
+
+/// whether `binding` carries more than a plain press
+pub fn is_gestured(binding: &str) -> bool {
+    GROUPS
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|g| g.contains_key(binding))
+}
+
+fn feed(app: &AppHandle, binding: &str, down: bool) -> bool {
+    if !is_gestured(binding) {
+        return false;
+    }
+    let input = Input {
+        lead: binding.to_string(),
+        down,
+        at: Instant::now(),
+        app: app.clone(),
+    };
+    if queue().send(input).is_err() {
+        tracing::warn!("gesture thread unavailable; dropping '{binding}'");
+    }
+    true
+}
+
+fn build_groups(tasks: &[CaptureTask]) -> (HashMap<String, Group>, HashSet<String>) {
+    let mut by_hotkey: HashMap<String, Vec<&CaptureTask>> = HashMap::new();
+    for task in tasks.iter().filter(|t| !t.hotkey.is_empty()) {
+        by_hotkey
+            .entry(super::format_hotkey_string(&task.hotkey))
+            .or_default()
+            .push(task);
+    }
+    let mut groups = HashMap::new();
+    let mut riders = HashSet::new();
+    for members in by_hotkey.values() {
+        if members.iter().all(|t| t.gesture == HotkeyGesture::Press) {
+            continue;
+        }
+        // members are in config order, so the binding stays on the same task
+        // across reloads
+        let lead = members[0].id.clone();
+        let mut group = Group::default();
+        for task in members {
+            let slot = match task.gesture {
+                HotkeyGesture::Press => &mut group.press,
+                HotkeyGesture::DoublePress => &mut group.double_press,
+                HotkeyGesture::LongPress => &mut group.long_press,
+            };
+            slot.get_or_insert_with(|| task.id.clone());
+            if task.id != lead {
+                riders.insert(task.id.clone());
+            }
+        }
+        groups.insert(lead, group);
+    }
+    (groups, riders)
+}
+
+fn queue() -> &'static Sender<Input> {
+    INPUT.get_or_init(|| {
+        let (tx, rx) = crossbeam_channel::unbounded::<Input>();
+        let spawned = std::thread::Builder::new()
+            .name("capscr-hotkey-gesture".into())
+            .spawn(move || run(rx));
+        if let Err(e) = spawned {
+            tracing::warn!("couldn't start gesture thread: {e}");
+        }
+        tx
+    })
+}
+
+// one thread owns every machine, waking for input or the nearest deadline
+fn run(rx: crossbeam_channel::Receiver<Input>) {
+    let mut machines: HashMap<String, Machine> = HashMap::new();
+    let mut app: Option<AppHandle> = None;
+    loop {
+        let groups = GROUPS.lock().unwrap().clone().unwrap_or_default();
+        let deadline = machines
+            .iter()
+            .filter_map(|(lead, m)| m.deadline(groups.get(lead)?))
+            .min();
+        let received = match deadline {
+            Some(deadline) => rx.recv_deadline(deadline),
+            None => rx
+                .recv()
+                .map_err(|_| crossbeam_channel::RecvTimeoutError::Disconnected),
+        };
+        let mut fired = Vec::new();
+        match received {
+            Ok(input) => {
+                if let Some(group) = groups.get(&input.lead) {
+                    let machine = machines.entry(input.lead.clone()).or_default();
+                    let task = if input.down {
+                        machine.press(group, input.at)
+                    } else {
+                        machine.release(group, input.at)
+                    };
+                    fired.extend(task);
+                }
+                app = Some(input.app);
+            }
+            Err(crossbeam_channel::RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                for (lead, machine) in &mut machines {
+                    if let Some(group) = groups.get(lead) {
+                        fired.extend(machine.poll(group, now));
+                    }
+                }
+            }
+            Err(crossbeam_channel::RecvTimeoutError::Disconnected) => return,
+        }
+        // a reload can drop a group mid-gesture
+        machines.retain(|lead, _| groups.contains_key(lead));
+        if let Some(app) = &app {
+            for task_id in fired {
+                crate::commands::trigger_task(app, &task_id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group() -> Group {
+        Group {
+            press: Some("region".into()),
+            double_press: Some("full".into()),
+            long_press: Some("record".into()),
+        }
+    }
+
+    #[test]
+    fn a_single_press_fires_once_the_double_press_window_closes() {
+        let g = group();
+        let mut m = Machine::default();
+        let t0 = Instant::now();
+        assert_eq!(m.press(&g, t0), None);
+        assert_eq!(m.release(&g, t0 + Duration::from_millis(80)), None);
+        let early = t0 + Duration::from_millis(200);
+        assert_eq!(m.poll(&g, early), None);
+        let late = t0 + Duration::from_millis(80) + DOUBLE_PRESS_WINDOW;
+        assert_eq!(m.poll(&g, late), Some("region".into()));
+        assert_eq!(m.deadline(&g), None);
+    }
+
+    #[test]
+    fn a_second_press_inside_the_window_is_a_double_press() {
+        let g = group();
+        let mut m = Machine::default();
+        let t0 = Instant::now();
+        m.press(&g, t0);
+        m.release(&g, t0 + Duration::from_millis(80));
+        assert_eq!(
+            m.press(&g, t0 + Duration::from_millis(200)),
+            Some("full".into())
+        );
+        // its release and any auto-repeat fire nothing more
+        assert_eq!(m.press(&g, t0 + Duration::from_millis(230)), None);
+        assert_eq!(m.release(&g, t0 + Duration::from_millis(260)), None);
+        assert_eq!(m.poll(&g, t0 + Duration::from_secs(5)), None);
+    }
+
+    #[test]
+    fn holding_past_the_threshold_is_a_long_press() {
+        let g = group();
+        let mut m = Machine::default();
+        let t0 = Instant::now();
+        m.press(&g, t0);
+        assert_eq!(m.poll(&g, t0 + Duration::from_millis(100)), None);
+        assert_eq!(m.poll(&g, t0 + LONG_PRESS), Some("record".into()));
+        assert_eq!(m.release(&g, t0 + Duration::from_secs(2)), None);
+    }
+
+    #[test]
+    fn without_a_double_press_task_a_tap_fires_on_release() {
+        let g = Group {
+            double_press: None,
+            ..group()
+        };
+        let mut m = Machine::default();
+        let t0 = Instant::now();
+        m.press(&g, t0);
+        assert_eq!(
+            m.release(&g, t0 + Duration::from_millis(80)),
+            Some("region".into())
+        );
+    }
+
+    #[test]
+    fn tasks_sharing_a_gestured_hotkey_ride_the_first_ones_binding() {
+        let mut tasks = crate::config::Config::default().capture_tasks;
+        tasks.truncate(3);
+        tasks[0].hotkey = "PrintScreen".into();
+        tasks[1].hotkey = "PrintScreen".into();
+        tasks[1].gesture = HotkeyGesture::DoublePress;
+        let (groups, riders) = build_groups(&tasks);
+        assert_eq!(groups.len(), 1);
+        let group = &groups[&tasks[0].id];
+        assert_eq!(group.press.as_deref(), Some(tasks[0].id.as_str()));
+        assert_eq!(group.double_press.as_deref(), Some(tasks[1].id.as_str()));
+        assert_eq!(riders, HashSet::from([tasks[1].id.clone()]));
+        // plain hotkeys stay out of the machine entirely
+        assert!(!groups.contains_key(&tasks[2].id));
+    }
+}
@@ -29,9 +29,11 @@ pub struct HookBinding {
     pub mods: u8,
 }
 
+// Release: the key (or side button) that last fired `task_id` came back up
 #[derive(Clone, Debug)]
 pub enum HookEvent {
     Fire { task_id: String },
+    Release { task_id: String },
     Captured { vk: u32, mods: u8 },
 }
 
@@ -63,6 +65,10 @@ struct HookRegistry {
     bindings: HashMap<HookBinding, String>,
     enabled: bool,
     tx: Option<Sender<HookEvent>>,
+    // vk that fired a binding and hasn't been released yet, with its task.
+    // further downs of it are auto-repeat and swallowed without firing; its
+    // up is reported so a hold-to-record task can stop
+    held: Option<(u32, String)>,
 }
 
 static REGISTRY: OnceLock<Mutex<HookRegistry>> = OnceLock::new();
@@ -73,6 +79,7 @@ fn registry() -> &'static Mutex<HookRegistry> {
             bindings: HashMap::new(),
             enabled: true,
             tx: None,
+            held: None,
         })
     })
 }
@@ -152,12 +159,18 @@ pub fn snapshot_telemetry() -> HookTelemetry {
 }
 
 pub fn set_bindings(bindings: HashMap<HookBinding, String>) {
-    registry().lock().unwrap().bindings = bindings;
+    let mut reg = registry().lock().unwrap();
+    reg.bindings = bindings;
+    reg.held = None;
+    drop(reg);
     notify_hook_thread();
 }
 
 pub fn set_enabled(enabled: bool) {
-    registry().lock().unwrap().enabled = enabled;
+    let mut reg = registry().lock().unwrap();
+    reg.enabled = enabled;
+    reg.held = None;
+    drop(reg);
     notify_hook_thread();
 }
 
@@ -211,6 +224,10 @@ unsafe extern "system" fn hook_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -
     }
 
     if !is_down {
+        if release_held(vk) {
+            // the down was swallowed, so its up is too
+            return LRESULT(1);
+        }
         return unsafe { CallNextHookEx(None, code, wparam, lparam) };
     }
 
@@ -241,14 +258,22 @@ unsafe extern "system" fn hook_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -
     // do the send afterwards. avoids holding the mutex while crossing thread
     // boundaries inside the LL timeout window.
     let (task_id, tx) = {
-        let reg = match registry().lock() {
+        let mut reg = match registry().lock() {
             Ok(g) => g,
             Err(_) => return unsafe { CallNextHookEx(None, code, wparam, lparam) },
         };
         if !reg.enabled {
             return unsafe { CallNextHookEx(None, code, wparam, lparam) };
         }
-        (reg.bindings.get(&binding).cloned(), reg.tx.clone())
+        if reg.held.as_ref().is_some_and(|(held, _)| *held == vk) {
+            // auto-repeat of a key that already fired
+            return LRESULT(1);
+        }
+        let task_id = reg.bindings.get(&binding).cloned();
+        if let Some(id) = &task_id {
+            reg.held = Some((vk, id.clone()));
+        }
+        (task_id, reg.tx.clone())
     };
 
     if let (Some(task_id), Some(tx)) = (task_id, tx) {
@@ -263,6 +288,27 @@ unsafe extern "system" fn hook_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -
     unsafe { CallNextHookEx(None, code, wparam, lparam) }
 }
 
+// report the up of the key that last fired a binding. true when `vk` was it
+fn release_held(vk: u32) -> bool {
+    let (task_id, tx) = {
+        let mut reg = match registry().lock() {
+            Ok(g) => g,
+            Err(_) => return false,
+        };
+        if !reg.held.as_ref().is_some_and(|(held, _)| *held == vk) {
+            return false;
+        }
+        let Some((_, task_id)) = reg.held.take() else {
+            return false;
+        };
+        (task_id, reg.tx.clone())
+    };
+    if let Some(tx) = tx {
+        let _ = tx.try_send(HookEvent::Release { task_id });
+    }
+    true
+}
+
 unsafe extern "system" fn mouse_hook_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
     HOOK_CALLS_TOTAL.fetch_add(1, Ordering::SeqCst);
     if code < 0 {
@@ -301,14 +347,18 @@ unsafe extern "system" fn mouse_hook_proc(code: i32, wparam: WPARAM, lparam: LPA
 
             let binding = HookBinding { vk, mods };
             let (task_id, tx) = {
-                let reg = match registry().lock() {
+                let mut reg = match registry().lock() {
                     Ok(g) => g,
                     Err(_) => return unsafe { CallNextHookEx(None, code, wparam, lparam) },
                 };
                 if !reg.enabled {
                     return unsafe { CallNextHookEx(None, code, wparam, lparam) };
                 }
-                (reg.bindings.get(&binding).cloned(), reg.tx.clone())
+                let task_id = reg.bindings.get(&binding).cloned();
+                if let Some(id) = &task_id {
+                    reg.held = Some((vk, id.clone()));
+                }
+                (task_id, reg.tx.clone())
             };
 
             if let (Some(task_id), Some(tx)) = (task_id, tx) {
@@ -320,6 +370,9 @@ unsafe extern "system" fn mouse_hook_proc(code: i32, wparam: WPARAM, lparam: LPA
                 return LRESULT(1);
             }
         } else if is_up {
+            if release_held(vk) {
+                return LRESULT(1);
+            }
             // consume release event if it was just captured or matches an active binding
             let was_captured = LAST_CAPTURED_XBUTTON.swap(0, Ordering::SeqCst) == vk as u8;
             let is_bound = {
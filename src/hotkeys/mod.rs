@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+pub mod gesture;
 #[cfg(windows)]
 pub mod ll_hook;
 
@@ -409,6 +410,17 @@ impl HotkeyManager {
                 hotkey_str
             ));
         }
+        self.register_transient(task_id, hotkey_str, label)
+    }
+
+    /// register without the bare-key guard, for a binding capscr holds only
+    /// for a moment (Esc while a capture counts down), never a user's own
+    pub fn register_transient(
+        &mut self,
+        task_id: impl Into<String>,
+        hotkey_str: &str,
+        label: &str,
+    ) -> Result<()> {
         let hotkey = parse_hotkey(hotkey_str)?;
         #[cfg(windows)]
         if hotkey_to_hook_binding(&hotkey).is_none() {
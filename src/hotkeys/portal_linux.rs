@@ -235,8 +235,9 @@ fn parse_shortcut_list(value: Option<&OwnedValue>) -> HashMap<String, String> {
 }
 
 // one thread per signal: Activated fires tasks with the same kill-switch and
-// auto-repeat dedupe the other dispatchers apply; Closed drops the session so
-// the next reload recreates and rebinds it
+// auto-repeat dedupe the other dispatchers apply; Deactivated is the key
+// coming back up, which ends a hold-to-record recording; Closed drops the
+// session so the next reload recreates and rebinds it
 fn spawn_listeners(conn: &zbus::blocking::Connection, session: &OwnedObjectPath) {
     let activated_conn = conn.clone();
     let our_session = session.clone();
@@ -273,6 +274,9 @@ fn spawn_listeners(conn: &zbus::blocking::Connection, session: &OwnedObjectPath)
                 {
                     continue;
                 }
+                if super::gesture::press(app, &shortcut_id) {
+                    continue;
+                }
                 let now = std::time::Instant::now();
                 let allow = last_fire
                     .get(&shortcut_id)
@@ -287,6 +291,40 @@ fn spawn_listeners(conn: &zbus::blocking::Connection, session: &OwnedObjectPath)
         })
         .ok();
 
+    let deactivated_conn = conn.clone();
+    let deactivated_session = session.clone();
+    std::thread::Builder::new()
+        .name("capscr-portal-deactivated".into())
+        .spawn(move || {
+            let Ok(proxy) = zbus::blocking::Proxy::new(
+                &deactivated_conn,
+                "org.freedesktop.portal.Desktop",
+                "/org/freedesktop/portal/desktop",
+                "org.freedesktop.portal.GlobalShortcuts",
+            ) else {
+                return;
+            };
+            let Ok(signals) = proxy.receive_signal("Deactivated") else {
+                return;
+            };
+            for msg in signals {
+                let Ok((session, shortcut_id, _timestamp, _options)) =
+                    msg.body()
+                        .deserialize::<(OwnedObjectPath, String, u64, HashMap<String, OwnedValue>)>(
+                        )
+                else {
+                    continue;
+                };
+                if session != deactivated_session {
+                    continue;
+                }
+                if let Some(app) = APP.get() {
+                    super::gesture::release(app, &shortcut_id);
+                }
+            }
+        })
+        .ok();
+
     let closed_conn = conn.clone();
     let closed_session = session.clone();
     std::thread::Builder::new()
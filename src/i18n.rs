@@ -0,0 +1,1329 @@
+// what the backend shows on its own — the tray menu, desktop notifications
+// and native dialogs — in the same `ui.language` the hub follows. the hub's
+// bundles live in frontend/src/locales; these are kept here so a toast can be
+// worded without a webview. each row carries every language, english first
+
+use crate::config::Language;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// column of MESSAGES in use: 0 en, 1 de, 2 fr, 3 ja
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+
+/// follow a language setting. called at startup and from apply_config, ahead
+/// of the tray rebuild that picks the new labels up
+pub fn set_language(language: Language) {
+    let column = match language {
+        Language::System => system_locale().map_or(0, |tag| column_for(&tag)),
+        Language::En => 0,
+        Language::De => 1,
+        Language::Fr => 2,
+        Language::Ja => 3,
+    };
+    CURRENT.store(column, Ordering::Relaxed);
+}
+
+/// the message for `key` in the current language; an unknown key shows as
+/// itself rather than as nothing
+pub fn t(key: &'static str) -> &'static str {
+    MESSAGES
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map_or(key, |(_, row)| row[CURRENT.load(Ordering::Relaxed)])
+}
+
+/// `t` with each `{name}` filled from `vars`
+pub fn tf(key: &'static str, vars: &[(&str, &dyn Display)]) -> String {
+    let mut message = t(key).to_string();
+    for (name, value) in vars {
+        message = message.replace(&format!("{{{name}}}"), &value.to_string());
+    }
+    message
+}
+
+// de_DE.UTF-8, de-AT and plain de all read as german; anything capscr
+// doesn't ship, C and POSIX included, is english
+fn column_for(tag: &str) -> usize {
+    let base = tag.split(['_', '-', '.', '@']).next().unwrap_or_default();
+    match base.to_ascii_lowercase().as_str() {
+        "de" => 1,
+        "fr" => 2,
+        "ja" => 3,
+        _ => 0,
+    }
+}
+
+#[cfg(windows)]
+fn system_locale() -> Option<String> {
+    use windows::Win32::Globalization::GetUserDefaultLocaleName;
+    // LOCALE_NAME_MAX_LENGTH
+    let mut name = [0u16; 85];
+    let len = unsafe { GetUserDefaultLocaleName(&mut name) };
+    // the length counts the terminating nul
+    (len > 1).then(|| String::from_utf16_lossy(&name[..len as usize - 1]))
+}
+
+// LANGUAGE is gettext's priority list, so its first shipped entry wins; then
+// the single locale in LC_ALL, LC_MESSAGES or LANG
+#[cfg(not(windows))]
+fn system_locale() -> Option<String> {
+    let listed = std::env::var("LANGUAGE").ok().and_then(|list| {
+        list.split(':')
+            .find(|tag| column_for(tag) != 0 || tag.starts_with("en"))
+            .map(str::to_string)
+    });
+    listed.or_else(|| {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|v| !v.is_empty())
+    })
+}
+
+const MESSAGES: &[(&str, [&str; 4])] = &[
+    // tray menu
+    ("tray.capture", ["Capture", "Aufnahme", "Capture", "キャプチャ"]),
+    ("tray.capture.region", ["Region", "Bereich", "Zone", "範囲"]),
+    (
+        "tray.capture.region-last",
+        [
+            "Repeat last region",
+            "Letzten Bereich wiederholen",
+            "Répéter la dernière zone",
+            "前回の範囲をもう一度",
+        ],
+    ),
+    ("tray.capture.window", ["Window", "Fenster", "Fenêtre", "ウィンドウ"]),
+    (
+        "tray.capture.fullscreen",
+        [
+            "Fullscreen (selector)",
+            "Vollbild (Auswahl)",
+            "Plein écran (sélecteur)",
+            "全画面（選択）",
+        ],
+    ),
+    (
+        "tray.capture.active-monitor",
+        ["Active monitor", "Aktiver Bildschirm", "Écran actif", "アクティブなモニター"],
+    ),
+    (
+        "tray.auto-capture.start",
+        [
+            "Start auto capture (every {secs}s)",
+            "Automatische Aufnahme starten (alle {secs} s)",
+            "Lancer la capture automatique (toutes les {secs} s)",
+            "自動キャプチャを開始（{secs} 秒ごと）",
+        ],
+    ),
+    (
+        "tray.auto-capture.stop",
+        [
+            "Stop auto capture",
+            "Automatische Aufnahme beenden",
+            "Arrêter la capture automatique",
+            "自動キャプチャを停止",
+        ],
+    ),
+    ("tray.record", ["Record", "Aufzeichnen", "Enregistrer", "録画"]),
+    (
+        "tray.record.active",
+        [
+            "Record ● recording",
+            "Aufzeichnen ● läuft",
+            "Enregistrer ● en cours",
+            "録画 ● 録画中",
+        ],
+    ),
+    (
+        "tray.record.saving",
+        [
+            "Saving recording…",
+            "Aufzeichnung wird gespeichert…",
+            "Enregistrement de la vidéo…",
+            "録画を保存中…",
+        ],
+    ),
+    (
+        "tray.record.stop",
+        [
+            "Stop recording",
+            "Aufzeichnung beenden",
+            "Arrêter l'enregistrement",
+            "録画を停止",
+        ],
+    ),
+    (
+        "tray.record.gif",
+        [
+            "Region GIF (toggle)",
+            "Bereich als GIF (umschalten)",
+            "Zone en GIF (bascule)",
+            "範囲を GIF で（切り替え）",
+        ],
+    ),
+    (
+        "tray.record.mp4",
+        [
+            "Region MP4 (toggle)",
+            "Bereich als MP4 (umschalten)",
+            "Zone en MP4 (bascule)",
+            "範囲を MP4 で（切り替え）",
+        ],
+    ),
+    ("tray.tools", ["Tools", "Werkzeuge", "Outils", "ツール"]),
+    ("tray.tools.gif-to-mp4", ["GIF → MP4…", "GIF → MP4…", "GIF → MP4…", "GIF → MP4…"]),
+    ("tray.tools.gif-to-webm", ["GIF → WebM…", "GIF → WebM…", "GIF → WebM…", "GIF → WebM…"]),
+    (
+        "tray.tools.video-to-gif",
+        ["Video → GIF…", "Video → GIF…", "Vidéo → GIF…", "動画 → GIF…"],
+    ),
+    (
+        "tray.recent-uploads",
+        [
+            "Recent uploads (click → copy)",
+            "Letzte Uploads (Klick → kopieren)",
+            "Envois récents (clic → copier)",
+            "最近のアップロード（クリック → コピー）",
+        ],
+    ),
+    (
+        "tray.recent-uploads.none",
+        [
+            "Recent uploads (none yet)",
+            "Letzte Uploads (noch keine)",
+            "Envois récents (aucun pour l'instant)",
+            "最近のアップロード（まだありません）",
+        ],
+    ),
+    (
+        "tray.recent-captures",
+        [
+            "Recent captures (click → open)",
+            "Letzte Aufnahmen (Klick → öffnen)",
+            "Captures récentes (clic → ouvrir)",
+            "最近のキャプチャ（クリック → 開く）",
+        ],
+    ),
+    (
+        "tray.recent-captures.none",
+        [
+            "Recent captures (none yet)",
+            "Letzte Aufnahmen (noch keine)",
+            "Captures récentes (aucune pour l'instant)",
+            "最近のキャプチャ（まだありません）",
+        ],
+    ),
+    (
+        "tray.copy-last-url",
+        [
+            "Copy last upload URL",
+            "URL des letzten Uploads kopieren",
+            "Copier l'URL du dernier envoi",
+            "最後のアップロード URL をコピー",
+        ],
+    ),
+    (
+        "tray.open-captures",
+        [
+            "Open captures folder",
+            "Aufnahmeordner öffnen",
+            "Ouvrir le dossier des captures",
+            "キャプチャのフォルダーを開く",
+        ],
+    ),
+    (
+        "tray.open-last-capture",
+        [
+            "Open last capture",
+            "Letzte Aufnahme öffnen",
+            "Ouvrir la dernière capture",
+            "最後のキャプチャを開く",
+        ],
+    ),
+    (
+        "tray.reveal-last-capture",
+        [
+            "Open containing folder",
+            "Enthaltenden Ordner öffnen",
+            "Ouvrir le dossier parent",
+            "保存先のフォルダーを開く",
+        ],
+    ),
+    (
+        "tray.destination",
+        ["Upload destination", "Upload-Ziel", "Destination d'envoi", "アップロード先"],
+    ),
+    (
+        "tray.destination.custom",
+        ["Custom HTTPS", "Eigenes HTTPS", "HTTPS personnalisé", "カスタム HTTPS"],
+    ),
+    ("tray.open-hub", ["Open hub", "Hub öffnen", "Ouvrir le hub", "ハブを開く"]),
+    (
+        "tray.hotkeys.enable",
+        [
+            "Enable all hotkeys",
+            "Alle Tastenkürzel aktivieren",
+            "Activer tous les raccourcis",
+            "すべてのホットキーを有効にする",
+        ],
+    ),
+    (
+        "tray.hotkeys.disable",
+        [
+            "Disable all hotkeys",
+            "Alle Tastenkürzel deaktivieren",
+            "Désactiver tous les raccourcis",
+            "すべてのホットキーを無効にする",
+        ],
+    ),
+    ("tray.dnd", ["Do not disturb", "Nicht stören", "Ne pas déranger", "おやすみモード"]),
+    (
+        "tray.dnd.on",
+        [
+            "Do not disturb ● on",
+            "Nicht stören ● an",
+            "Ne pas déranger ● activé",
+            "おやすみモード ● オン",
+        ],
+    ),
+    (
+        "tray.dnd.until",
+        [
+            "Do not disturb ● until {time}",
+            "Nicht stören ● bis {time}",
+            "Ne pas déranger ● jusqu'à {time}",
+            "おやすみモード ● {time} まで",
+        ],
+    ),
+    ("tray.dnd.off", ["Turn off", "Ausschalten", "Désactiver", "オフにする"]),
+    (
+        "tray.dnd.forever",
+        [
+            "Until turned off",
+            "Bis zum Ausschalten",
+            "Jusqu'à désactivation",
+            "オフにするまで",
+        ],
+    ),
+    ("tray.dnd.30", ["For 30 minutes", "30 Minuten lang", "Pendant 30 minutes", "30 分間"]),
+    ("tray.dnd.60", ["For 1 hour", "1 Stunde lang", "Pendant 1 heure", "1 時間"]),
+    ("tray.dnd.120", ["For 2 hours", "2 Stunden lang", "Pendant 2 heures", "2 時間"]),
+    ("tray.exit", ["Exit", "Beenden", "Quitter", "終了"]),
+    (
+        "tray.cancel.recording",
+        [
+            "Cancel recording (discard)",
+            "Aufzeichnung abbrechen (verwerfen)",
+            "Annuler l'enregistrement (abandonner)",
+            "録画をキャンセル（破棄）",
+        ],
+    ),
+    (
+        "tray.cancel.capture",
+        [
+            "Cancel capture",
+            "Aufnahme abbrechen",
+            "Annuler la capture",
+            "キャプチャをキャンセル",
+        ],
+    ),
+    (
+        "tray.tooltip.recording",
+        [
+            "capscr · recording '{name}'",
+            "capscr · zeichnet '{name}' auf",
+            "capscr · enregistre '{name}'",
+            "capscr · '{name}' を録画中",
+        ],
+    ),
+    (
+        "tray.tooltip.streaming",
+        [
+            "capscr · streaming '{name}'",
+            "capscr · streamt '{name}'",
+            "capscr · diffuse '{name}'",
+            "capscr · '{name}' を配信中",
+        ],
+    ),
+    (
+        "tray.tooltip.stops-in",
+        [
+            " · stops in {secs}s",
+            " · endet in {secs} s",
+            " · s'arrête dans {secs} s",
+            " · あと {secs} 秒で停止",
+        ],
+    ),
+    // notifications
+    ("notify.copied", ["Copied", "Kopiert", "Copié", "コピーしました"]),
+    (
+        "notify.copied.body",
+        [
+            "Capture on clipboard",
+            "Aufnahme in der Zwischenablage",
+            "Capture dans le presse-papiers",
+            "キャプチャをクリップボードに入れました",
+        ],
+    ),
+    (
+        "notify.clipboard-busy",
+        [
+            "Clipboard busy",
+            "Zwischenablage belegt",
+            "Presse-papiers occupé",
+            "クリップボードが使用中",
+        ],
+    ),
+    (
+        "notify.clipboard-busy.body",
+        [
+            "Capture ready but clipboard was occupied",
+            "Aufnahme fertig, aber die Zwischenablage war belegt",
+            "Capture prête, mais le presse-papiers était occupé",
+            "キャプチャはできましたが、クリップボードが使用中でした",
+        ],
+    ),
+    (
+        "notify.no-uploads",
+        ["No uploads yet", "Noch keine Uploads", "Aucun envoi", "まだアップロードがありません"],
+    ),
+    (
+        "notify.no-uploads.body",
+        [
+            "Upload something first and the URL will land here.",
+            "Lade zuerst etwas hoch, dann landet die URL hier.",
+            "Envoyez d'abord quelque chose et l'URL arrivera ici.",
+            "先に何かアップロードすると、ここに URL が入ります。",
+        ],
+    ),
+    (
+        "notify.no-captures",
+        [
+            "No captures yet",
+            "Noch keine Aufnahmen",
+            "Aucune capture",
+            "まだキャプチャがありません",
+        ],
+    ),
+    (
+        "notify.no-captures.body",
+        [
+            "Save a capture first and it will open from here.",
+            "Speichere zuerst eine Aufnahme, dann öffnet sie sich von hier.",
+            "Enregistrez d'abord une capture et elle s'ouvrira d'ici.",
+            "先にキャプチャを保存すると、ここから開けます。",
+        ],
+    ),
+    (
+        "notify.destination.body",
+        [
+            "Switched to {destination}",
+            "Gewechselt zu {destination}",
+            "Passé à {destination}",
+            "{destination} に切り替えました",
+        ],
+    ),
+    (
+        "notify.hotkeys.off",
+        [
+            "Hotkeys disabled",
+            "Tastenkürzel deaktiviert",
+            "Raccourcis désactivés",
+            "ホットキーを無効にしました",
+        ],
+    ),
+    (
+        "notify.hotkeys.off.body",
+        [
+            "All capscr hotkeys are off. Re-enable from the tray or Settings → Hotkeys.",
+            "Alle capscr-Tastenkürzel sind aus. Wieder einschalten im Tray oder unter Einstellungen → Tastenkürzel.",
+            "Tous les raccourcis de capscr sont désactivés. Réactivez-les depuis la zone de notification ou Paramètres → Raccourcis.",
+            "capscr のホットキーはすべてオフです。トレイか 設定 → ホットキー から再び有効にできます。",
+        ],
+    ),
+    (
+        "notify.hotkeys.on",
+        [
+            "Hotkeys enabled",
+            "Tastenkürzel aktiviert",
+            "Raccourcis activés",
+            "ホットキーを有効にしました",
+        ],
+    ),
+    (
+        "notify.hotkeys.on.body",
+        [
+            "All task hotkeys are live again.",
+            "Alle Aufgaben-Tastenkürzel sind wieder aktiv.",
+            "Tous les raccourcis des tâches sont de nouveau actifs.",
+            "タスクのホットキーがすべて再び有効になりました。",
+        ],
+    ),
+    (
+        "notify.capture-missing",
+        [
+            "Capture not found",
+            "Aufnahme nicht gefunden",
+            "Capture introuvable",
+            "キャプチャが見つかりません",
+        ],
+    ),
+    (
+        "notify.capture-missing.body",
+        [
+            "It was moved or deleted since it was saved.",
+            "Sie wurde seit dem Speichern verschoben oder gelöscht.",
+            "Elle a été déplacée ou supprimée depuis son enregistrement.",
+            "保存後に移動または削除されました。",
+        ],
+    ),
+    (
+        "notify.capture-failed",
+        [
+            "Capture failed",
+            "Aufnahme fehlgeschlagen",
+            "Échec de la capture",
+            "キャプチャに失敗しました",
+        ],
+    ),
+    (
+        "notify.write-failed",
+        [
+            "couldn't write the capture to disk",
+            "die Aufnahme konnte nicht auf die Festplatte geschrieben werden",
+            "impossible d'écrire la capture sur le disque",
+            "キャプチャをディスクに書き込めませんでした",
+        ],
+    ),
+    (
+        "notify.color-picked",
+        ["Color picked", "Farbe aufgenommen", "Couleur prélevée", "色を取得しました"],
+    ),
+    (
+        "notify.duplicate",
+        [
+            "Duplicate capture",
+            "Doppelte Aufnahme",
+            "Capture en double",
+            "重複したキャプチャ",
+        ],
+    ),
+    (
+        "notify.duplicate.body",
+        [
+            "identical to the one taken {secs}s ago; kept anyway",
+            "identisch mit der vor {secs} s; trotzdem behalten",
+            "identique à celle prise il y a {secs} s ; conservée quand même",
+            "{secs} 秒前のものと同一です。そのまま残しました",
+        ],
+    ),
+    (
+        "notify.duplicate-skipped",
+        [
+            "Duplicate skipped",
+            "Duplikat übersprungen",
+            "Doublon ignoré",
+            "重複をスキップしました",
+        ],
+    ),
+    (
+        "notify.duplicate-skipped.body",
+        [
+            "identical to the one taken {secs}s ago",
+            "identisch mit der vor {secs} s",
+            "identique à celle prise il y a {secs} s",
+            "{secs} 秒前のものと同一です",
+        ],
+    ),
+    (
+        "notify.capture-opened",
+        [
+            "Capture opened",
+            "Aufnahme geöffnet",
+            "Capture ouverte",
+            "キャプチャを開きました",
+        ],
+    ),
+    (
+        "notify.no-text",
+        [
+            "No text found",
+            "Kein Text gefunden",
+            "Aucun texte trouvé",
+            "テキストが見つかりません",
+        ],
+    ),
+    (
+        "notify.no-text.body",
+        [
+            "the capture had no detectable text",
+            "die Aufnahme enthielt keinen erkennbaren Text",
+            "la capture ne contenait aucun texte détectable",
+            "キャプチャから文字を検出できませんでした",
+        ],
+    ),
+    (
+        "notify.text-copied",
+        ["Text copied", "Text kopiert", "Texte copié", "テキストをコピーしました"],
+    ),
+    ("notify.uploading", ["Uploading…", "Lädt hoch…", "Envoi…", "アップロード中…"]),
+    (
+        "notify.uploaded",
+        ["Uploaded", "Hochgeladen", "Envoyé", "アップロードしました"],
+    ),
+    (
+        "notify.saved",
+        [
+            "Capture saved",
+            "Aufnahme gespeichert",
+            "Capture enregistrée",
+            "キャプチャを保存しました",
+        ],
+    ),
+    (
+        "notify.saved-copied",
+        [
+            "Capture saved + copied",
+            "Aufnahme gespeichert + kopiert",
+            "Capture enregistrée + copiée",
+            "キャプチャを保存 + コピーしました",
+        ],
+    ),
+    (
+        "notify.saved-busy",
+        [
+            "Capture saved (clipboard busy)",
+            "Aufnahme gespeichert (Zwischenablage belegt)",
+            "Capture enregistrée (presse-papiers occupé)",
+            "キャプチャを保存しました（クリップボードが使用中）",
+        ],
+    ),
+    (
+        "notify.complete",
+        [
+            "Capture complete",
+            "Aufnahme fertig",
+            "Capture terminée",
+            "キャプチャが完了しました",
+        ],
+    ),
+    (
+        "notify.complete.body",
+        [
+            "Screenshot taken successfully.",
+            "Screenshot erfolgreich aufgenommen.",
+            "Capture d'écran réussie.",
+            "スクリーンショットを撮影しました。",
+        ],
+    ),
+    (
+        "notify.video-saved",
+        ["Video saved", "Video gespeichert", "Vidéo enregistrée", "動画を保存しました"],
+    ),
+    (
+        "notify.gif-saved",
+        ["GIF saved", "GIF gespeichert", "GIF enregistré", "GIF を保存しました"],
+    ),
+    (
+        "notify.task-failed",
+        [
+            "Task '{name}' failed",
+            "Aufgabe '{name}' fehlgeschlagen",
+            "Échec de la tâche '{name}'",
+            "タスク「{name}」に失敗しました",
+        ],
+    ),
+    (
+        "notify.no-window",
+        [
+            "No matching window",
+            "Kein passendes Fenster",
+            "Aucune fenêtre correspondante",
+            "一致するウィンドウがありません",
+        ],
+    ),
+    (
+        "notify.no-window.body",
+        [
+            "nothing open matches \"{what}\" — is the app running?",
+            "nichts Geöffnetes passt zu „{what}“ — läuft die App?",
+            "rien d'ouvert ne correspond à « {what} » — l'application est-elle lancée ?",
+            "「{what}」に一致するものが開いていません — アプリは起動していますか？",
+        ],
+    ),
+    (
+        "notify.recording-ending",
+        [
+            "Recording ending soon",
+            "Aufzeichnung endet bald",
+            "L'enregistrement se termine bientôt",
+            "まもなく録画が終了します",
+        ],
+    ),
+    (
+        "notify.recording-ending.body",
+        [
+            "'{name}' reaches its time limit in {secs}s{stop}",
+            "'{name}' erreicht sein Zeitlimit in {secs} s{stop}",
+            "'{name}' atteint sa durée maximale dans {secs} s{stop}",
+            "「{name}」はあと {secs} 秒で制限時間に達します{stop}",
+        ],
+    ),
+    (
+        "notify.recording-ending.stop",
+        [
+            " — press {hotkey} to stop it sooner",
+            " — {hotkey} beendet sie früher",
+            " — appuyez sur {hotkey} pour l'arrêter plus tôt",
+            " — 早めに止めるには {hotkey} を押してください",
+        ],
+    ),
+    (
+        "notify.countdown",
+        [
+            "Capturing in {secs}s",
+            "Aufnahme in {secs} s",
+            "Capture dans {secs} s",
+            "{secs} 秒後にキャプチャ",
+        ],
+    ),
+    (
+        "notify.countdown.body",
+        [
+            "Esc cancels",
+            "Esc bricht ab",
+            "Échap annule",
+            "Esc でキャンセル",
+        ],
+    ),
+    (
+        "notify.auto-capture-stopped",
+        [
+            "Auto capture stopped",
+            "Automatische Aufnahme beendet",
+            "Capture automatique arrêtée",
+            "自動キャプチャを停止しました",
+        ],
+    ),
+    (
+        "notify.output-unreachable",
+        [
+            "capscr: captures folder unreachable",
+            "capscr: Aufnahmeordner nicht erreichbar",
+            "capscr : dossier des captures inaccessible",
+            "capscr: キャプチャのフォルダーにアクセスできません",
+        ],
+    ),
+    (
+        "notify.output-unreachable.body",
+        [
+            "{error}. Open Settings → Output to point at a writable path.",
+            "{error}. Unter Einstellungen → Ausgabe einen beschreibbaren Pfad wählen.",
+            "{error}. Ouvrez Paramètres → Sortie pour choisir un chemin accessible en écriture.",
+            "{error}。設定 → 出力 で書き込み可能な場所を指定してください。",
+        ],
+    ),
+    (
+        "notify.recovered",
+        [
+            "capscr kept an unsaved recording",
+            "capscr hat eine ungespeicherte Aufzeichnung behalten",
+            "capscr a conservé un enregistrement non sauvegardé",
+            "capscr は未保存の録画を残しています",
+        ],
+    ),
+    (
+        "notify.recovered.one",
+        [
+            "{count} recording from the last session can be restored from the hub.",
+            "{count} Aufzeichnung aus der letzten Sitzung kann im Hub wiederhergestellt werden.",
+            "{count} enregistrement de la dernière session peut être restauré depuis le hub.",
+            "前回のセッションの録画 {count} 件をハブから復元できます。",
+        ],
+    ),
+    (
+        "notify.recovered.other",
+        [
+            "{count} recordings from the last session can be restored from the hub.",
+            "{count} Aufzeichnungen aus der letzten Sitzung können im Hub wiederhergestellt werden.",
+            "{count} enregistrements de la dernière session peuvent être restaurés depuis le hub.",
+            "前回のセッションの録画 {count} 件をハブから復元できます。",
+        ],
+    ),
+    (
+        "notify.crashed",
+        [
+            "capscr crashed last time",
+            "capscr ist beim letzten Mal abgestürzt",
+            "capscr a planté la dernière fois",
+            "capscr は前回クラッシュしました",
+        ],
+    ),
+    (
+        "notify.crashed.body",
+        [
+            "a crash report was saved; the hub can open or copy it for a bug report.",
+            "ein Absturzbericht wurde gespeichert; der Hub kann ihn für einen Fehlerbericht öffnen oder kopieren.",
+            "un rapport de plantage a été enregistré ; le hub peut l'ouvrir ou le copier pour un rapport de bug.",
+            "クラッシュレポートを保存しました。ハブで開くか、バグ報告用にコピーできます。",
+        ],
+    ),
+    (
+        "notify.hotkey-conflicts",
+        [
+            "capscr: hotkey conflicts",
+            "capscr: Tastenkürzel-Konflikte",
+            "capscr : conflits de raccourcis",
+            "capscr: ホットキーの競合",
+        ],
+    ),
+    (
+        "notify.hotkey-conflict",
+        [
+            "Hotkey conflict",
+            "Tastenkürzel-Konflikt",
+            "Conflit de raccourci",
+            "ホットキーの競合",
+        ],
+    ),
+    ("notify.action.open", ["Open", "Öffnen", "Ouvrir", "開く"]),
+    (
+        "notify.action.reveal",
+        [
+            "Show in folder",
+            "Im Ordner zeigen",
+            "Afficher dans le dossier",
+            "フォルダーで表示",
+        ],
+    ),
+    ("common.cancel", ["Cancel", "Abbrechen", "Annuler", "キャンセル"]),
+    // ffmpeg setup
+    (
+        "ffmpeg.download",
+        [
+            "FFmpeg Download",
+            "FFmpeg-Download",
+            "Téléchargement de FFmpeg",
+            "FFmpeg のダウンロード",
+        ],
+    ),
+    (
+        "ffmpeg.busy",
+        [
+            "FFmpeg is already downloading in the background. Please wait.",
+            "FFmpeg wird bereits im Hintergrund heruntergeladen. Bitte warten.",
+            "FFmpeg est déjà en cours de téléchargement en arrière-plan. Patientez.",
+            "FFmpeg はバックグラウンドでダウンロード中です。しばらくお待ちください。",
+        ],
+    ),
+    (
+        "ffmpeg.downloading",
+        [
+            "Downloading a verified FFmpeg build...",
+            "Lädt einen geprüften FFmpeg-Build herunter...",
+            "Téléchargement d'une version vérifiée de FFmpeg...",
+            "検証済みの FFmpeg をダウンロード中...",
+        ],
+    ),
+    (
+        "ffmpeg.starting",
+        [
+            "Starting FFmpeg download (approx. 90MB)...",
+            "FFmpeg-Download startet (ca. 90 MB)...",
+            "Début du téléchargement de FFmpeg (environ 90 Mo)...",
+            "FFmpeg のダウンロードを開始します（約 90MB）...",
+        ],
+    ),
+    (
+        "ffmpeg.configured",
+        [
+            "FFmpeg Configured",
+            "FFmpeg eingerichtet",
+            "FFmpeg configuré",
+            "FFmpeg を設定しました",
+        ],
+    ),
+    (
+        "ffmpeg.ready",
+        [
+            "FFmpeg is ready. You can now record MP4 videos.",
+            "FFmpeg ist bereit. Du kannst jetzt MP4-Videos aufzeichnen.",
+            "FFmpeg est prêt. Vous pouvez maintenant enregistrer des vidéos MP4.",
+            "FFmpeg の準備ができました。MP4 動画を録画できます。",
+        ],
+    ),
+    (
+        "ffmpeg.ready.downloaded",
+        [
+            "FFmpeg has been downloaded and configured. You can now record MP4 videos.",
+            "FFmpeg wurde heruntergeladen und eingerichtet. Du kannst jetzt MP4-Videos aufzeichnen.",
+            "FFmpeg a été téléchargé et configuré. Vous pouvez maintenant enregistrer des vidéos MP4.",
+            "FFmpeg をダウンロードして設定しました。MP4 動画を録画できます。",
+        ],
+    ),
+    (
+        "ffmpeg.failed",
+        [
+            "FFmpeg Download Failed",
+            "FFmpeg-Download fehlgeschlagen",
+            "Échec du téléchargement de FFmpeg",
+            "FFmpeg のダウンロードに失敗しました",
+        ],
+    ),
+    (
+        "ffmpeg.failed.body",
+        [
+            "Could not configure FFmpeg: {error}",
+            "FFmpeg konnte nicht eingerichtet werden: {error}",
+            "Impossible de configurer FFmpeg : {error}",
+            "FFmpeg を設定できませんでした: {error}",
+        ],
+    ),
+    (
+        "ffmpeg.required",
+        [
+            "FFmpeg Required",
+            "FFmpeg benötigt",
+            "FFmpeg requis",
+            "FFmpeg が必要です",
+        ],
+    ),
+    (
+        "ffmpeg.install",
+        [
+            "Install FFmpeg",
+            "FFmpeg installieren",
+            "Installer FFmpeg",
+            "FFmpeg をインストール",
+        ],
+    ),
+    (
+        "ffmpeg.instructions",
+        [
+            "Recording MP4 requires FFmpeg, which was not found on your system.\n\n\
+             Install it with your package manager, e.g.\n\
+             sudo apt install ffmpeg   (Debian/Ubuntu)\n\
+             sudo dnf install ffmpeg   (Fedora)",
+            "Für MP4-Aufzeichnungen wird FFmpeg benötigt, das auf deinem System nicht gefunden wurde.\n\n\
+             Installiere es mit deiner Paketverwaltung, z. B.\n\
+             sudo apt install ffmpeg   (Debian/Ubuntu)\n\
+             sudo dnf install ffmpeg   (Fedora)",
+            "L'enregistrement en MP4 nécessite FFmpeg, introuvable sur votre système.\n\n\
+             Installez-le avec votre gestionnaire de paquets, par ex.\n\
+             sudo apt install ffmpeg   (Debian/Ubuntu)\n\
+             sudo dnf install ffmpeg   (Fedora)",
+            "MP4 の録画には FFmpeg が必要ですが、システムに見つかりませんでした。\n\n\
+             パッケージマネージャーでインストールしてください。例:\n\
+             sudo apt install ffmpeg   (Debian/Ubuntu)\n\
+             sudo dnf install ffmpeg   (Fedora)",
+        ],
+    ),
+    (
+        "ffmpeg.offer",
+        [
+            "Recording MP4 requires FFmpeg, which was not found on your system.\n\n\
+             Download a verified static FFmpeg (~30 MB, used only by capscr), or \
+             install it with your package manager instead?",
+            "Für MP4-Aufzeichnungen wird FFmpeg benötigt, das auf deinem System nicht gefunden wurde.\n\n\
+             Ein geprüftes statisches FFmpeg herunterladen (~30 MB, nur von capscr genutzt) \
+             oder es stattdessen mit der Paketverwaltung installieren?",
+            "L'enregistrement en MP4 nécessite FFmpeg, introuvable sur votre système.\n\n\
+             Télécharger un FFmpeg statique vérifié (~30 Mo, utilisé uniquement par capscr), \
+             ou l'installer plutôt avec votre gestionnaire de paquets ?",
+            "MP4 の録画には FFmpeg が必要ですが、システムに見つかりませんでした。\n\n\
+             検証済みの静的 FFmpeg（約 30 MB、capscr 専用）をダウンロードしますか？\
+             それともパッケージマネージャーでインストールしますか？",
+        ],
+    ),
+    (
+        "ffmpeg.offer.windows",
+        [
+            "Recording MP4 requires FFmpeg, which was not found on your system.\n\n\
+             Would you like to automatically download and configure it?",
+            "Für MP4-Aufzeichnungen wird FFmpeg benötigt, das auf deinem System nicht gefunden wurde.\n\n\
+             Soll es automatisch heruntergeladen und eingerichtet werden?",
+            "L'enregistrement en MP4 nécessite FFmpeg, introuvable sur votre système.\n\n\
+             Voulez-vous le télécharger et le configurer automatiquement ?",
+            "MP4 の録画には FFmpeg が必要ですが、システムに見つかりませんでした。\n\n\
+             自動でダウンロードして設定しますか？",
+        ],
+    ),
+    (
+        "ffmpeg.offer.download",
+        ["Download", "Herunterladen", "Télécharger", "ダウンロード"],
+    ),
+    (
+        "ffmpeg.offer.instructions",
+        [
+            "Show instructions",
+            "Anleitung zeigen",
+            "Afficher les instructions",
+            "手順を表示",
+        ],
+    ),
+    // dialogs
+    (
+        "dialog.reuse",
+        [
+            "Already uploaded",
+            "Bereits hochgeladen",
+            "Déjà envoyé",
+            "アップロード済み",
+        ],
+    ),
+    (
+        "dialog.reuse.body",
+        [
+            "This exact file was already uploaded{when}:\n{url}\n\n\
+             Reuse that link instead of uploading it again?",
+            "Genau diese Datei wurde bereits hochgeladen{when}:\n{url}\n\n\
+             Diesen Link wiederverwenden, statt sie erneut hochzuladen?",
+            "Ce fichier exact a déjà été envoyé{when} :\n{url}\n\n\
+             Réutiliser ce lien au lieu de l'envoyer à nouveau ?",
+            "このファイルはすでにアップロードされています{when}:\n{url}\n\n\
+             もう一度アップロードせずに、このリンクを使いますか？",
+        ],
+    ),
+    (
+        "dialog.reuse.when",
+        [" on {date}", " am {date}", " le {date}", "（{date}）"],
+    ),
+    (
+        "dialog.reuse.yes",
+        [
+            "Reuse link",
+            "Link wiederverwenden",
+            "Réutiliser le lien",
+            "リンクを使う",
+        ],
+    ),
+    (
+        "dialog.reuse.no",
+        [
+            "Upload again",
+            "Erneut hochladen",
+            "Envoyer à nouveau",
+            "もう一度アップロード",
+        ],
+    ),
+    (
+        "dialog.secret",
+        [
+            "Possible secret in upload",
+            "Mögliches Geheimnis im Upload",
+            "Secret possible dans l'envoi",
+            "アップロードに機密情報の可能性",
+        ],
+    ),
+    (
+        "dialog.secret.body",
+        [
+            "{file} looks like it shows private information:{listed}\n\nUpload it anyway?",
+            "{file} scheint private Informationen zu zeigen:{listed}\n\nTrotzdem hochladen?",
+            "{file} semble montrer des informations privées :{listed}\n\nL'envoyer quand même ?",
+            "{file} に個人的な情報が写っているようです:{listed}\n\nそれでもアップロードしますか？",
+        ],
+    ),
+    (
+        "dialog.secret.yes",
+        [
+            "Upload anyway",
+            "Trotzdem hochladen",
+            "Envoyer quand même",
+            "それでもアップロード",
+        ],
+    ),
+    ("dialog.open-image", ["Open image", "Bild öffnen", "Ouvrir une image", "画像を開く"]),
+    ("dialog.images", ["Images", "Bilder", "Images", "画像"]),
+    (
+        "dialog.convert-folder",
+        [
+            "Convert folder",
+            "Ordner umwandeln",
+            "Convertir un dossier",
+            "フォルダーを変換",
+        ],
+    ),
+    (
+        "dialog.save-as",
+        [
+            "Save capture as",
+            "Aufnahme speichern unter",
+            "Enregistrer la capture sous",
+            "名前を付けてキャプチャを保存",
+        ],
+    ),
+    (
+        "dialog.convert.to-gif",
+        [
+            "Convert video to GIF",
+            "Video in GIF umwandeln",
+            "Convertir une vidéo en GIF",
+            "動画を GIF に変換",
+        ],
+    ),
+    (
+        "dialog.convert.to-video",
+        [
+            "Convert GIF to video",
+            "GIF in Video umwandeln",
+            "Convertir un GIF en vidéo",
+            "GIF を動画に変換",
+        ],
+    ),
+    ("dialog.videos", ["Videos", "Videos", "Vidéos", "動画"]),
+    // capture errors, worded for someone who isn't reading the log
+    (
+        "error.gpu",
+        [
+            "GPU couldn't initialise the capture pipeline. Try updating your graphics driver or rebooting.",
+            "Die GPU konnte die Aufnahme nicht starten. Grafiktreiber aktualisieren oder neu starten.",
+            "Le GPU n'a pas pu initialiser la capture. Mettez à jour le pilote graphique ou redémarrez.",
+            "GPU でキャプチャを初期化できませんでした。グラフィックドライバーを更新するか、再起動してください。",
+        ],
+    ),
+    (
+        "error.no-display",
+        [
+            "capscr couldn't find a display. If you just unplugged a monitor, try again or restart capscr.",
+            "capscr hat keinen Bildschirm gefunden. Wurde gerade ein Monitor abgezogen, erneut versuchen oder capscr neu starten.",
+            "capscr n'a trouvé aucun écran. Si vous venez de débrancher un moniteur, réessayez ou redémarrez capscr.",
+            "capscr がディスプレイを見つけられませんでした。モニターを外した直後なら、もう一度試すか capscr を再起動してください。",
+        ],
+    ),
+    (
+        "error.denied.windows",
+        [
+            "Windows blocked the capture. Run capscr as administrator or check Settings > Privacy > Screen recording.",
+            "Windows hat die Aufnahme blockiert. capscr als Administrator starten oder Einstellungen > Datenschutz > Bildschirmaufnahme prüfen.",
+            "Windows a bloqué la capture. Lancez capscr en administrateur ou vérifiez Paramètres > Confidentialité > Enregistrement de l'écran.",
+            "Windows がキャプチャをブロックしました。capscr を管理者として実行するか、設定 > プライバシー > 画面録画 を確認してください。",
+        ],
+    ),
+    (
+        "error.denied.desktop",
+        [
+            "The desktop blocked the capture. If a screen-sharing prompt appeared, allow it and try again; `capscr --wayland-diag` shows which capture sources this session grants.",
+            "Der Desktop hat die Aufnahme blockiert. Erschien eine Bildschirmfreigabe-Abfrage, sie erlauben und erneut versuchen; `capscr --wayland-diag` zeigt, welche Quellen diese Sitzung erlaubt.",
+            "Le bureau a bloqué la capture. Si une demande de partage d'écran est apparue, acceptez-la et réessayez ; `capscr --wayland-diag` indique les sources autorisées par cette session.",
+            "デスクトップがキャプチャをブロックしました。画面共有の確認が出たら許可して再試行してください。`capscr --wayland-diag` でこのセッションが許可するキャプチャ元を確認できます。",
+        ],
+    ),
+    (
+        "error.hdr.windows",
+        [
+            "HDR capture failed. Turn HDR off in Windows display settings, or disable HDR in capscr Settings > capture > hdr.",
+            "HDR-Aufnahme fehlgeschlagen. HDR in den Windows-Anzeigeeinstellungen ausschalten oder in capscr unter Einstellungen > Aufnahme > HDR deaktivieren.",
+            "Échec de la capture HDR. Désactivez le HDR dans les paramètres d'affichage de Windows, ou dans capscr Paramètres > capture > hdr.",
+            "HDR キャプチャに失敗しました。Windows のディスプレイ設定で HDR をオフにするか、capscr の 設定 > キャプチャ > HDR で無効にしてください。",
+        ],
+    ),
+    (
+        "error.hdr",
+        [
+            "HDR capture failed. Disable HDR in capscr Settings > capture > hdr.",
+            "HDR-Aufnahme fehlgeschlagen. HDR in capscr unter Einstellungen > Aufnahme > HDR deaktivieren.",
+            "Échec de la capture HDR. Désactivez le HDR dans capscr Paramètres > capture > hdr.",
+            "HDR キャプチャに失敗しました。capscr の 設定 > キャプチャ > HDR で無効にしてください。",
+        ],
+    ),
+    (
+        "error.shader",
+        [
+            "Shader compilation failed. Update your graphics driver. (If this keeps happening, file an issue with your GPU model.)",
+            "Shader-Kompilierung fehlgeschlagen. Grafiktreiber aktualisieren. (Passiert das öfter, bitte ein Issue mit dem GPU-Modell anlegen.)",
+            "Échec de la compilation des shaders. Mettez à jour le pilote graphique. (Si cela se répète, ouvrez un ticket avec votre modèle de GPU.)",
+            "シェーダーのコンパイルに失敗しました。グラフィックドライバーを更新してください。（繰り返す場合は GPU の型番を添えて報告してください）",
+        ],
+    ),
+    (
+        "error.clipboard",
+        [
+            "Couldn't write to the clipboard. Another app may be holding it open — try the capture again.",
+            "Die Zwischenablage ließ sich nicht beschreiben. Eine andere App hält sie womöglich offen — Aufnahme wiederholen.",
+            "Impossible d'écrire dans le presse-papiers. Une autre application le garde peut-être ouvert — refaites la capture.",
+            "クリップボードに書き込めませんでした。別のアプリが使用中かもしれません — もう一度キャプチャしてください。",
+        ],
+    ),
+    (
+        "error.region",
+        [
+            "Selected region is too small or off-screen. Drag a larger area.",
+            "Der gewählte Bereich ist zu klein oder außerhalb des Bildschirms. Einen größeren Bereich ziehen.",
+            "La zone choisie est trop petite ou hors écran. Tracez une zone plus grande.",
+            "選択した範囲が小さすぎるか画面外です。もっと広い範囲をドラッグしてください。",
+        ],
+    ),
+    (
+        "error.window-gone",
+        [
+            "The selected window vanished before we could capture it (minimised, closed, or moved off-screen). Try the selector again.",
+            "Das gewählte Fenster verschwand vor der Aufnahme (minimiert, geschlossen oder vom Bildschirm geschoben). Auswahl erneut versuchen.",
+            "La fenêtre choisie a disparu avant la capture (réduite, fermée ou sortie de l'écran). Relancez le sélecteur.",
+            "選択したウィンドウがキャプチャ前に消えました（最小化・終了・画面外への移動）。もう一度選択してください。",
+        ],
+    ),
+    (
+        "error.already-recording",
+        [
+            "already recording '{name}' — press its hotkey again to stop first",
+            "es wird bereits '{name}' aufgezeichnet — zuerst sein Tastenkürzel erneut drücken, um zu stoppen",
+            "'{name}' est déjà en cours d'enregistrement — appuyez d'abord de nouveau sur son raccourci pour l'arrêter",
+            "すでに「{name}」を録画中です — 先にそのホットキーをもう一度押して停止してください",
+        ],
+    ),
+    (
+        "error.another-task",
+        [
+            "another task",
+            "eine andere Aufgabe",
+            "une autre tâche",
+            "別のタスク",
+        ],
+    ),
+    (
+        "error.no-focused-window",
+        [
+            "no focused window to follow — click one first",
+            "kein fokussiertes Fenster zum Folgen — zuerst eines anklicken",
+            "aucune fenêtre active à suivre — cliquez d'abord sur une fenêtre",
+            "追従するアクティブなウィンドウがありません — 先にどれかをクリックしてください",
+        ],
+    ),
+    (
+        "error.stream-unclean",
+        [
+            "the stream didn't end cleanly: {error}",
+            "der Stream endete nicht sauber: {error}",
+            "le flux ne s'est pas terminé proprement : {error}",
+            "配信が正常に終了しませんでした: {error}",
+        ],
+    ),
+    (
+        "error.ingest-dropped",
+        [
+            "the ingest dropped the connection — check the stream url and that the server is up",
+            "der Ingest-Server hat die Verbindung getrennt — Stream-URL prüfen und ob der Server läuft",
+            "le serveur d'ingestion a coupé la connexion — vérifiez l'URL du flux et que le serveur fonctionne",
+            "配信先が接続を切断しました — 配信 URL とサーバーが稼働しているかを確認してください",
+        ],
+    ),
+    (
+        "error.no-audio",
+        [
+            "saved without audio — the system-audio track couldn't be captured or muxed",
+            "ohne Ton gespeichert — die Systemton-Spur konnte nicht aufgenommen oder eingebunden werden",
+            "enregistré sans son — la piste audio système n'a pas pu être capturée ou multiplexée",
+            "音声なしで保存しました — システム音声のトラックを録音または多重化できませんでした",
+        ],
+    ),
+    (
+        "error.no-frames",
+        [
+            "No frames captured",
+            "Keine Bilder aufgenommen",
+            "Aucune image capturée",
+            "フレームを取得できませんでした",
+        ],
+    ),
+    (
+        "error.max-duration",
+        [
+            "hit the {secs}s max duration — raise it under settings → capture",
+            "die Höchstdauer von {secs} s wurde erreicht — unter Einstellungen → Aufnahme erhöhen",
+            "durée maximale de {secs} s atteinte — augmentez-la dans paramètres → capture",
+            "最大録画時間 {secs} 秒に達しました — 設定 → キャプチャ で延ばせます",
+        ],
+    ),
+    (
+        "error.frame-cap",
+        [
+            "hit the frame-count safety limit — recording saved",
+            "das Sicherheitslimit für Bilder wurde erreicht — Aufzeichnung gespeichert",
+            "limite de sécurité du nombre d'images atteinte — enregistrement sauvegardé",
+            "フレーム数の安全上限に達しました — 録画は保存済みです",
+        ],
+    ),
+    (
+        "error.disk-full",
+        [
+            "stopped early: the disk is nearly full — recording saved",
+            "vorzeitig beendet: die Festplatte ist fast voll — Aufzeichnung gespeichert",
+            "arrêt anticipé : le disque est presque plein — enregistrement sauvegardé",
+            "途中で停止しました: ディスクの空きがほとんどありません — 録画は保存済みです",
+        ],
+    ),
+    (
+        "error.encoder-failed",
+        [
+            "stopped early: couldn't write frames to disk — saved what was captured",
+            "vorzeitig beendet: Bilder ließen sich nicht schreiben — das Aufgenommene wurde gespeichert",
+            "arrêt anticipé : impossible d'écrire les images sur le disque — ce qui a été capturé est sauvegardé",
+            "途中で停止しました: フレームをディスクに書き込めませんでした — 取得済みの分は保存しました",
+        ],
+    ),
+    (
+        "error.config-reload",
+        [
+            "config.toml wasn't reloaded: {error}",
+            "config.toml wurde nicht neu geladen: {error}",
+            "config.toml n'a pas été rechargé : {error}",
+            "config.toml を再読み込みできませんでした: {error}",
+        ],
+    ),
+    (
+        "error.auto-capture",
+        [
+            "auto capture stopped: {error}",
+            "automatische Aufnahme beendet: {error}",
+            "capture automatique arrêtée : {error}",
+            "自動キャプチャを停止しました: {error}",
+        ],
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholders(message: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = message
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn every_language_has_every_message_and_its_placeholders() {
+        for (key, row) in MESSAGES {
+            let english = placeholders(row[0]);
+            for message in row {
+                assert!(!message.is_empty(), "{key} is empty in one language");
+                assert_eq!(placeholders(message), english, "{key}: {message}");
+            }
+        }
+    }
+
+    #[test]
+    fn keys_are_unique() {
+        let mut keys: Vec<&str> = MESSAGES.iter().map(|(k, _)| *k).collect();
+        keys.sort_unstable();
+        let len = keys.len();
+        keys.dedup();
+        assert_eq!(keys.len(), len);
+    }
+
+    #[test]
+    fn locale_tags_pick_a_bundle() {
+        assert_eq!(column_for("de_DE.UTF-8"), 1);
+        assert_eq!(column_for("fr-CA"), 2);
+        assert_eq!(column_for("ja_JP"), 3);
+        assert_eq!(column_for("en_US.UTF-8"), 0);
+        assert_eq!(column_for("C"), 0);
+        assert_eq!(column_for("pt_BR"), 0);
+    }
+}
@@ -0,0 +1,103 @@
+// a small json list in the config folder, rewritten whole on every change:
+// the upload dedupe index and the capture tags. each file has one lock that
+// every read and load-modify-save takes, so two threads saving at once can't
+// drop each other's entries
+
+use anyhow::{anyhow, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub struct JsonIndex {
+    file: &'static str,
+    lock: Mutex<()>,
+}
+
+impl JsonIndex {
+    pub const fn new(file: &'static str) -> Self {
+        Self {
+            file,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// every entry; a missing or unreadable index is just an empty one
+    pub fn read<T: DeserializeOwned>(&self) -> Vec<T> {
+        let Some(path) = self.path() else {
+            return Vec::new();
+        };
+        let _guard = self.lock.lock().unwrap();
+        load(&path)
+    }
+
+    /// load, change and write back. `change` returns whether it changed
+    /// anything; nothing is written when it didn't
+    pub fn update<T>(&self, change: impl FnOnce(&mut Vec<T>) -> bool) -> Result<()>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let path = self
+            .path()
+            .ok_or_else(|| anyhow!("no config directory to keep {} in", self.file))?;
+        let _guard = self.lock.lock().unwrap();
+        let mut entries = load(&path);
+        if change(&mut entries) {
+            save(&path, &entries)?;
+        }
+        Ok(())
+    }
+
+    /// drop the whole index
+    pub fn clear(&self) -> Result<()> {
+        let Some(path) = self.path() else {
+            return Ok(());
+        };
+        let _guard = self.lock.lock().unwrap();
+        match std::fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn path(&self) -> Option<PathBuf> {
+        crate::config::Config::config_dir().map(|d| d.join(self.file))
+    }
+}
+
+/// put `entry` first, replacing whatever `same` matches, and let the oldest
+/// fall off past `max`
+pub fn push_newest<T>(entries: &mut Vec<T>, entry: T, max: usize, same: impl Fn(&T) -> bool) {
+    entries.retain(|e| !same(e));
+    entries.insert(0, entry);
+    entries.truncate(max);
+}
+
+fn load<T: DeserializeOwned>(path: &Path) -> Vec<T> {
+    std::fs::read(path)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+fn save<T: Serialize>(path: &Path, entries: &[T]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    crate::disk::replace_file(path, &serde_json::to_vec(entries)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_newest_replaces_and_caps() {
+        let mut entries = vec![3, 2, 1];
+        push_newest(&mut entries, 2, 3, |e| *e == 2);
+        assert_eq!(entries, [2, 3, 1]);
+        push_newest(&mut entries, 4, 3, |e| *e == 4);
+        assert_eq!(entries, [4, 2, 3]);
+    }
+}
@@ -42,6 +42,11 @@ const TASKS: &[Task] = &[
         title: "Capture region",
         desc: "Drag a rectangle to capture",
     },
+    Task {
+        arg: "--jump=region-last",
+        title: "Repeat last region",
+        desc: "Capture the previous region again",
+    },
     Task {
         arg: "--jump=window",
         title: "Capture window",
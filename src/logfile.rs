@@ -0,0 +1,175 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use tracing_subscriber::fmt::MakeWriter;
+
+use crate::config::Config;
+
+// rotate once the current file passes this; with the older files kept that
+// caps the folder at a few tens of megabytes
+const MAX_LOG_BYTES: u64 = 4 * 1024 * 1024;
+// capscr.log plus capscr.1.log (the newest older one) up to this suffix
+const KEPT_LOGS: usize = 3;
+
+pub fn dir() -> Option<PathBuf> {
+    Config::config_dir().map(|d| d.join("logs"))
+}
+
+pub fn current_path(dir: &Path) -> PathBuf {
+    dir.join("capscr.log")
+}
+
+fn rotated_path(dir: &Path, n: usize) -> PathBuf {
+    dir.join(format!("capscr.{n}.log"))
+}
+
+/// the log file the tracing subscriber writes to besides stderr, which a
+/// release build on windows has nowhere to show
+pub struct RotatingLog {
+    dir: PathBuf,
+    current: Mutex<Current>,
+}
+
+struct Current {
+    // None only between closing the full file and opening its successor
+    file: Option<File>,
+    len: u64,
+}
+
+impl RotatingLog {
+    pub fn open(dir: PathBuf) -> io::Result<Self> {
+        std::fs::create_dir_all(&dir)?;
+        let file = open_append(&current_path(&dir))?;
+        let len = file.metadata()?.len();
+        Ok(Self {
+            dir,
+            current: Mutex::new(Current {
+                file: Some(file),
+                len,
+            }),
+        })
+    }
+
+    fn write_record(&self, buf: &[u8]) -> io::Result<()> {
+        let mut current = self.current.lock().unwrap_or_else(|e| e.into_inner());
+        if current.len > 0 && current.len + buf.len() as u64 > MAX_LOG_BYTES {
+            self.rotate(&mut current)?;
+        }
+        let file = match current.file.as_mut() {
+            Some(file) => file,
+            None => return Err(io::Error::other("log file closed")),
+        };
+        file.write_all(buf)?;
+        current.len += buf.len() as u64;
+        Ok(())
+    }
+
+    fn rotate(&self, current: &mut Current) -> io::Result<()> {
+        // windows won't rename a file that's still open
+        drop(current.file.take());
+        for n in (1..KEPT_LOGS).rev() {
+            let _ = std::fs::rename(rotated_path(&self.dir, n), rotated_path(&self.dir, n + 1));
+        }
+        let live = current_path(&self.dir);
+        let _ = std::fs::rename(&live, rotated_path(&self.dir, 1));
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&live)?;
+        current.file = Some(file);
+        current.len = 0;
+        Ok(())
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+pub struct LogWriter<'a>(&'a RotatingLog);
+
+impl Write for LogWriter<'_> {
+    // the fmt layer formats a whole event before writing it, so each call is
+    // one record and a rotation never splits a line across files
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write_record(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for RotatingLog {
+    type Writer = LogWriter<'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        LogWriter(self)
+    }
+}
+
+/// the last `max_bytes` of the current log, starting at a line boundary
+pub fn tail(path: &Path, max_bytes: u64) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    let start = len.saturating_sub(max_bytes);
+    file.seek(SeekFrom::Start(start))?;
+    let mut buf = Vec::with_capacity((len - start) as usize);
+    file.read_to_end(&mut buf)?;
+    let text = String::from_utf8_lossy(&buf);
+    if start == 0 {
+        return Ok(text.into_owned());
+    }
+    // the cut landed mid-line; drop the partial one
+    Ok(match text.find('\n') {
+        Some(i) => text[i + 1..].to_string(),
+        None => String::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("capscr_logs_{}", uuid::Uuid::new_v4().as_simple()))
+    }
+
+    #[test]
+    fn a_full_log_rotates_and_the_oldest_falls_off() {
+        let dir = scratch_dir();
+        let log = RotatingLog::open(dir.clone()).unwrap();
+        let line = vec![b'x'; (MAX_LOG_BYTES / 2) as usize + 1];
+        // every write past the first overflows the file and rotates it
+        for _ in 0..(KEPT_LOGS + 3) {
+            log.make_writer().write_all(&line).unwrap();
+        }
+        for n in 1..=KEPT_LOGS {
+            assert!(rotated_path(&dir, n).exists(), "capscr.{n}.log missing");
+        }
+        assert!(!rotated_path(&dir, KEPT_LOGS + 1).exists());
+        assert_eq!(
+            std::fs::metadata(current_path(&dir)).unwrap().len(),
+            line.len() as u64
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn tail_starts_on_a_whole_line() {
+        let dir = scratch_dir();
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = current_path(&dir);
+        std::fs::write(&path, "first line\nsecond line\nthird\n").unwrap();
+        assert_eq!(tail(&path, 15).unwrap(), "third\n");
+        assert_eq!(
+            tail(&path, 1000).unwrap(),
+            "first line\nsecond line\nthird\n"
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
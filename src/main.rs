@@ -1,32 +1,58 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod auto_capture;
+mod batch;
+mod cancel;
 mod capture;
 mod clipboard;
 mod commands;
 mod config;
+mod config_watch;
+mod context_menu;
+mod crash;
+mod decorate;
+mod diff;
+mod disk;
 #[cfg(target_os = "linux")]
 mod distro;
+mod dnd;
+mod duplicate;
+mod encode;
+mod game_mode;
 mod hotkeys;
+mod i18n;
+mod json_index;
 #[cfg(windows)]
 mod jumplist;
+mod logfile;
 mod marketplace;
+mod metadata;
 mod overlay;
 mod plugin;
+mod protocol;
 mod recording;
+mod retention;
+mod scratch;
 mod secret;
 #[cfg(target_os = "linux")]
 mod shell;
 mod sound;
 mod state;
+mod stitch;
+mod tags;
+mod thumbs;
+mod tray;
 mod upload;
+mod watch_folder;
 #[cfg(windows)]
 mod win_darkmode;
+#[cfg(windows)]
+mod win_registry;
 
 use crossbeam_channel as cb;
 use std::time::Duration;
-use tauri::menu::{Menu, MenuItem, PredefinedMenuItem, Submenu};
-use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
 use tauri::{AppHandle, Emitter, Manager};
+use tracing_subscriber::prelude::*;
 use tracing_subscriber::EnvFilter;
 
 use state::HotkeyCommand;
@@ -38,16 +64,27 @@ pub fn install_hdr_runtime_from_config(config: &config::Config) {
         sdr_white_nits_override: config.capture.hdr.brightness_nits,
         user_brightness_scale: config.capture.hdr.user_brightness_scale,
         use_p99_max_cll: config.capture.hdr.use_p99_max_cll,
+        operator: config.capture.hdr.tonemap_operator,
+    });
+    capture::install_wgc_options(capture::WgcOptions {
+        preferred: config.capture.backend == config::CaptureBackend::Wgc,
+        border: config.capture.wgc_border,
     });
 }
 
 #[cfg(windows)]
 fn set_dpi_awareness() {
     use windows::Win32::UI::HiDpi::{
-        SetProcessDpiAwarenessContext, DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2,
+        SetProcessDpiAwareness, SetProcessDpiAwarenessContext,
+        DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2, PROCESS_PER_MONITOR_DPI_AWARE,
     };
     unsafe {
-        let _ = SetProcessDpiAwarenessContext(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2);
+        // v2 needs 1703+. without any per-monitor awareness windows scales
+        // our coordinates on every non-primary-dpi monitor and selections
+        // land on the wrong pixels, so fall back to v1 rather than nothing
+        if SetProcessDpiAwarenessContext(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2).is_err() {
+            let _ = SetProcessDpiAwareness(PROCESS_PER_MONITOR_DPI_AWARE);
+        }
     }
 }
 
@@ -68,13 +105,31 @@ fn main() {
     jumplist::set_app_user_model_id();
     #[cfg(windows)]
     win_darkmode::enable_dark_menus();
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+    // a release build on windows has no console, so the file is the only
+    // place a failed capture or upload leaves a trace to report
+    let log_file = logfile::dir().and_then(|dir| match logfile::RotatingLog::open(dir) {
+        Ok(log) => Some(log),
+        Err(e) => {
+            eprintln!("capscr: can't open the log file: {e}");
+            None
+        }
+    });
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| {
             EnvFilter::new("info,wry=warn,tao=warn,tauri=warn,hyper=warn,reqwest=warn")
         }))
+        .with(tracing_subscriber::fmt::layer())
+        .with(log_file.map(|log| {
+            tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_writer(log)
+        }))
         .init();
+    tracing::info!("capscr {} starting", env!("CARGO_PKG_VERSION"));
+    crash::install();
 
     let config = config::Config::load().unwrap_or_default();
+    i18n::set_language(config.ui.language);
     // ensure the output dir exists off the startup critical path: the capture
     // and save paths create it on demand anyway, so this is only an early
     // warning, and canonicalize on a cold or networked Pictures folder can
@@ -87,14 +142,27 @@ fn main() {
                 // notification channel so the user knows captures will fail
                 // until they fix it.
                 let _ = clipboard::show_notification(
-                    "capscr: captures folder unreachable",
-                    &format!("{e}. Open Settings → Output to point at a writable path."),
+                    i18n::t("notify.output-unreachable"),
+                    &i18n::tf("notify.output-unreachable.body", &[("error", &e)]),
                 );
                 tracing::error!("ensure_output_dir failed at startup: {e:#}");
             }
         });
     }
     install_hdr_runtime_from_config(&config);
+    upload::set_proxy(commands::build_proxy_setup(&config));
+    scratch::configure(&config);
+    {
+        // anything older than this launch is a leftover of an earlier run
+        let started = std::time::SystemTime::now();
+        let cap = u64::from(config.output.scratch_cap_mb) * 1024 * 1024;
+        std::thread::spawn(move || {
+            let removed = scratch::clean_up(cap, started);
+            if removed > 0 {
+                tracing::info!("removed {removed} leftover scratch files");
+            }
+        });
+    }
 
     // pre-warm the Win32 audio subsystem in the background so the first
     // capture cue isn't delayed by waveOut initialisation. Fire-and-forget;
@@ -112,14 +180,23 @@ fn main() {
     let app_state = state::AppState::new(config);
 
     let autostart_desired = app_state.config.lock().unwrap().ui.auto_start;
+    let context_menu_desired = app_state.config.lock().unwrap().ui.context_menu;
     let initial_jump = parse_jump_arg(std::env::args());
 
     tauri::Builder::default()
         // single-instance plugin must be the first one — when a second
         // capscr.exe launches (e.g. via a jump list shortcut), it forwards
         // argv to the running instance and exits.
-        .plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
-            let kind = parse_jump_arg(argv.iter().cloned());
+        .plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+            let kind = parse_jump_arg(argv.iter().cloned()).map(|kind| {
+                match kind.strip_prefix("edit:") {
+                    // relative to where the second launch ran, not to us
+                    Some(path) => {
+                        format!("edit:{}", std::path::Path::new(&cwd).join(path).display())
+                    }
+                    None => kind,
+                }
+            });
             dispatch_jump(app, kind.as_deref());
         }))
         .plugin(tauri_plugin_clipboard_manager::init())
@@ -132,12 +209,15 @@ fn main() {
             tauri_plugin_autostart::MacosLauncher::LaunchAgent,
             None,
         ))
-        // exclude VISIBLE — we manage hub visibility manually via prewarm + tray-click
+        // exclude VISIBLE — we manage hub visibility manually via prewarm + tray-click.
+        // and POSITION: the hub's position lives in ui.window_position, which
+        // is clamped to the connected monitors on restore
         .plugin(
             tauri_plugin_window_state::Builder::default()
                 .with_state_flags(
                     tauri_plugin_window_state::StateFlags::all()
-                        & !tauri_plugin_window_state::StateFlags::VISIBLE,
+                        & !tauri_plugin_window_state::StateFlags::VISIBLE
+                        & !tauri_plugin_window_state::StateFlags::POSITION,
                 )
                 .build(),
         )
@@ -173,6 +253,38 @@ fn main() {
                     );
                 }
             }
+            // release builds abort on panic, so any panic ends the process
+            // and drop never cleans up; an unwinding (debug) build only dies
+            // from one on the main thread, the rest are caught per capture
+            {
+                let handle = app.handle().clone();
+                let default_hook = std::panic::take_hook();
+                std::panic::set_hook(Box::new(move |info| {
+                    if cfg!(panic = "abort") || std::thread::current().name() == Some("main") {
+                        commands::preserve_unsaved_recordings(&handle);
+                    }
+                    default_hook(info);
+                }));
+            }
+            let recovered = recording::recovery::list().len();
+            if recovered > 0 {
+                let body = if recovered == 1 {
+                    "notify.recovered.one"
+                } else {
+                    "notify.recovered.other"
+                };
+                let _ = clipboard::show_notification(
+                    i18n::t("notify.recovered"),
+                    &i18n::tf(body, &[("count", &recovered)]),
+                );
+            }
+            let crashes = crash::list().len();
+            if crashes > 0 {
+                let _ = clipboard::show_notification(
+                    i18n::t("notify.crashed"),
+                    i18n::t("notify.crashed.body"),
+                );
+            }
             // the linux selector overlay builds its webview through a stored
             // AppHandle; register it before any capture can fire
             #[cfg(target_os = "linux")]
@@ -186,7 +298,11 @@ fn main() {
                     handle.state::<state::AppState>().load_plugins();
                 });
             }
-            build_tray(app)?;
+            watch_folder::spawn(app.handle());
+            config_watch::spawn(app.handle());
+            game_mode::spawn(app.handle());
+            retention::spawn(app.handle());
+            tray::SystemTray::build(app)?;
             // on a tray-less desktop (vanilla gnome has no StatusNotifier
             // host) the tray icon silently never appears, stranding a
             // tray-first app. detect that and surface the hub with a one-time
@@ -224,6 +340,7 @@ fn main() {
                 }
             }
             sync_autostart(app, autostart_desired);
+            std::thread::spawn(move || context_menu::sync(context_menu_desired));
             // jump-list registration does synchronous COM (CoCreateInstance per
             // task) — push it off the setup/UI thread so the hub WebView2
             // prewarm can start sooner. it only needs the AUMID (set early in
@@ -233,6 +350,9 @@ fn main() {
                 if let Err(e) = jumplist::register() {
                     tracing::warn!("jumplist register failed: {e}");
                 }
+                if let Err(e) = protocol::register() {
+                    tracing::warn!("capscr:// handler register failed: {e}");
+                }
             });
             // make sure the asset:// protocol can reach the user's configured
             // output dir even if they moved it off the default $PICTURE/capscr.
@@ -270,6 +390,17 @@ fn main() {
             if let Err(e) = commands::prewarm_hub_window(app) {
                 tracing::warn!("hub pre-warm failed: {e}");
             }
+            // a jump-list launch runs its own action instead
+            let start_minimized = app
+                .state::<state::AppState>()
+                .config
+                .lock()
+                .unwrap()
+                .ui
+                .start_minimized;
+            if !start_minimized && initial_jump.is_none() {
+                let _ = commands::open_hub_window(app.handle());
+            }
             // first-launch jump-list dispatch: if capscr.exe was launched with
             // --jump=<kind>, run that action now. We delay slightly so the tray
             // and webview are fully ready before any capture pipeline fires.
@@ -290,11 +421,25 @@ fn main() {
             commands::take_screenshot,
             commands::list_captures,
             commands::history_thumbnail,
+            commands::compare_captures,
+            commands::stitch_captures,
+            commands::estimate_capture_sizes,
+            commands::list_open_windows,
+            commands::window_thumbnail,
+            commands::set_live_window_thumbnails,
             commands::delete_capture,
+            commands::get_capture_tags,
+            commands::set_capture_tags,
             commands::copy_capture_to_clipboard,
             commands::reupload_capture,
+            commands::batch_upload,
+            commands::copy_batch_links,
             commands::open_in_explorer,
             commands::trim_mp4,
+            commands::recording_review,
+            commands::recording_review_frame,
+            commands::finish_recording_review,
+            commands::discard_recording_review,
             commands::exit_app,
             commands::set_autostart,
             commands::get_autostart,
@@ -309,9 +454,18 @@ fn main() {
             commands::install_update,
             commands::get_editor_image_path,
             commands::open_editor,
+            commands::open_image_in_editor,
+            commands::batch_convert_folder,
+            commands::encoding_progress,
+            commands::cancel_pending_encodes,
+            commands::open_encrypted_link,
             commands::save_edited_image,
+            commands::save_edited_image_as,
+            commands::load_editor_project,
+            commands::save_editor_project,
             commands::copy_edited_image_to_clipboard,
             commands::upload_edited_image,
+            commands::clear_upload_index,
             commands::upload_file,
             commands::hotkey_diagnostics,
             commands::evdev_status,
@@ -322,12 +476,37 @@ fn main() {
             commands::pin_manual_drag,
             commands::pin_move_by,
             commands::dismiss_tray_hint,
+            commands::remember_editor_color,
+            commands::list_recovered_recordings,
+            commands::restore_recovered_recording,
+            commands::discard_recovered_recording,
+            commands::list_crash_reports,
+            commands::read_crash_report,
+            commands::reveal_crash_report,
+            commands::dismiss_crash_report,
             commands::set_hotkeys_disabled,
+            commands::do_not_disturb_status,
+            commands::set_do_not_disturb,
+            commands::game_mode_status,
+            commands::auto_capture_running,
+            commands::set_auto_capture_running,
+            commands::assemble_timelapse,
             commands::start_hotkey_capture,
             commands::cancel_hotkey_capture,
             commands::sftp_known_hosts,
+            commands::read_log_tail,
+            commands::reveal_log_file,
             commands::sftp_forget_host,
             commands::test_upload_connection,
+            commands::import_sxcu,
+            commands::imgur_authorize_url,
+            commands::imgur_sign_in,
+            commands::imgur_sign_out,
+            commands::imgur_albums,
+            commands::imgur_create_album,
+            commands::dropbox_authorize_url,
+            commands::dropbox_sign_in,
+            commands::dropbox_sign_out,
             commands::fire_task,
             commands::run_ocr,
             commands::pin_image,
@@ -345,12 +524,16 @@ fn main() {
         ])
         .build(tauri::generate_context!())
         .expect("error while building capscr")
-        .run(|_app, event| {
-            if let tauri::RunEvent::ExitRequested { code, api, .. } = &event {
+        .run(|app, event| match &event {
+            tauri::RunEvent::ExitRequested { code, api, .. } => {
                 if code.is_none() {
                     api.prevent_exit();
                 }
             }
+            // exit_app has already saved anything it could; this catches the
+            // session ending underneath a recording
+            tauri::RunEvent::Exit => commands::preserve_unsaved_recordings(app),
+            _ => {}
         });
 }
 
@@ -373,428 +556,6 @@ fn sync_autostart(app: &tauri::App, desired: bool) {
     }
 }
 
-// build the full tray menu fresh from current AppState. Called once at
-// startup and again any time the state surfaces in the menu changes —
-// new upload, destination switched, hotkeys toggled.
-fn build_tray_menu<R: tauri::Runtime, M: tauri::Manager<R>>(app: &M) -> tauri::Result<Menu<R>> {
-    use std::sync::atomic::Ordering;
-
-    // --- Capture submenu ---
-    let cap_region = MenuItem::with_id(app, "cap_region", "Region", true, None::<&str>)?;
-    let cap_window = MenuItem::with_id(app, "cap_window", "Window", true, None::<&str>)?;
-    let cap_fullscreen = MenuItem::with_id(
-        app,
-        "cap_fullscreen",
-        "Fullscreen (selector)",
-        true,
-        None::<&str>,
-    )?;
-    let cap_active = MenuItem::with_id(
-        app,
-        "cap_active_monitor",
-        "Active monitor",
-        true,
-        None::<&str>,
-    )?;
-    let capture_submenu = Submenu::with_items(
-        app,
-        "Capture",
-        true,
-        &[&cap_region, &cap_window, &cap_fullscreen, &cap_active],
-    )?;
-
-    // record submenu
-    let rec_region_gif = MenuItem::with_id(
-        app,
-        "rec_region_gif",
-        "Region GIF (toggle)",
-        true,
-        None::<&str>,
-    )?;
-    let rec_region_mp4 = MenuItem::with_id(
-        app,
-        "rec_region_mp4",
-        "Region MP4 (toggle)",
-        true,
-        None::<&str>,
-    )?;
-    let record_submenu =
-        Submenu::with_items(app, "Record", true, &[&rec_region_gif, &rec_region_mp4])?;
-
-    // --- Recent uploads submenu (dynamic) ---
-    let state = app.state::<state::AppState>();
-    let recent: Vec<state::UploadRecord> = state
-        .recent_uploads
-        .lock()
-        .unwrap()
-        .iter()
-        .cloned()
-        .collect();
-    let recent_items: Vec<MenuItem<R>> = recent
-        .iter()
-        .enumerate()
-        .map(|(i, rec)| {
-            // truncate long URLs so the menu doesn't sprawl. count/slice by
-            // char, not byte: a custom uploader's url comes from arbitrary
-            // server json and can hold multibyte utf-8, where a byte slice
-            // would panic on a non-boundary cut
-            let label = if rec.url.chars().count() > 56 {
-                format!("{}…", rec.url.chars().take(55).collect::<String>())
-            } else {
-                rec.url.clone()
-            };
-            MenuItem::with_id(
-                app,
-                format!("recent_upload_{i}"),
-                &label,
-                true,
-                None::<&str>,
-            )
-            .expect("recent upload item")
-        })
-        .collect();
-    let recent_refs: Vec<&dyn tauri::menu::IsMenuItem<R>> = if recent_items.is_empty() {
-        Vec::new()
-    } else {
-        recent_items
-            .iter()
-            .map(|m| m as &dyn tauri::menu::IsMenuItem<R>)
-            .collect()
-    };
-    let recent_submenu_enabled = !recent_items.is_empty();
-    let recent_submenu = if recent_submenu_enabled {
-        Submenu::with_items(app, "Recent uploads (click → copy)", true, &recent_refs)?
-    } else {
-        Submenu::with_items(
-            app,
-            "Recent uploads (none yet)",
-            false,
-            &[] as &[&dyn tauri::menu::IsMenuItem<R>],
-        )?
-    };
-
-    // --- Upload / utility items ---
-    let copy_last_url = MenuItem::with_id(
-        app,
-        "copy_last_url",
-        "Copy last upload URL",
-        true,
-        None::<&str>,
-    )?;
-    let open_captures = MenuItem::with_id(
-        app,
-        "open_captures",
-        "Open captures folder",
-        true,
-        None::<&str>,
-    )?;
-
-    // --- Destination switcher ---
-    let current_dest = state.config.lock().unwrap().upload.destination;
-    let mark = |is_current: bool, label: &str| -> String {
-        if is_current {
-            format!("● {label}")
-        } else {
-            format!("○ {label}")
-        }
-    };
-    let dest_imgur = MenuItem::with_id(
-        app,
-        "dest_imgur",
-        mark(current_dest == config::UploadDestination::Imgur, "Imgur"),
-        true,
-        None::<&str>,
-    )?;
-    let dest_custom = MenuItem::with_id(
-        app,
-        "dest_custom",
-        mark(
-            current_dest == config::UploadDestination::Custom,
-            "Custom HTTPS",
-        ),
-        true,
-        None::<&str>,
-    )?;
-    let dest_ftp = MenuItem::with_id(
-        app,
-        "dest_ftp",
-        mark(current_dest == config::UploadDestination::Ftp, "FTP"),
-        true,
-        None::<&str>,
-    )?;
-    let dest_sftp = MenuItem::with_id(
-        app,
-        "dest_sftp",
-        mark(current_dest == config::UploadDestination::Sftp, "SFTP"),
-        true,
-        None::<&str>,
-    )?;
-    let dest_submenu = Submenu::with_items(
-        app,
-        "Upload destination",
-        true,
-        &[&dest_imgur, &dest_custom, &dest_ftp, &dest_sftp],
-    )?;
-
-    // --- Open hub (single top-level item, no submenu) ---
-    let open_hub = MenuItem::with_id(app, "tab_default", "Open hub", true, None::<&str>)?;
-
-    // --- Hotkey toggle (stateful) ---
-    let disabled = state.hotkeys_disabled.load(Ordering::SeqCst);
-    let hotkeys_toggle = MenuItem::with_id(
-        app,
-        "hotkeys_toggle",
-        if disabled {
-            "Enable all hotkeys"
-        } else {
-            "Disable all hotkeys"
-        },
-        true,
-        None::<&str>,
-    )?;
-
-    let separator1 = PredefinedMenuItem::separator(app)?;
-    let separator2 = PredefinedMenuItem::separator(app)?;
-    let separator3 = PredefinedMenuItem::separator(app)?;
-    let separator4 = PredefinedMenuItem::separator(app)?;
-    let separator5 = PredefinedMenuItem::separator(app)?;
-    let exit_item = MenuItem::with_id(app, "exit", "Exit", true, None::<&str>)?;
-
-    Menu::with_items(
-        app,
-        &[
-            &capture_submenu,
-            &record_submenu,
-            &separator1,
-            &recent_submenu,
-            &copy_last_url,
-            &open_captures,
-            &dest_submenu,
-            &separator2,
-            &open_hub,
-            &separator3,
-            &hotkeys_toggle,
-            &separator4,
-            &exit_item,
-            &separator5,
-        ],
-    )
-}
-
-/// rebuild the tray menu from current state and apply it to the running tray.
-/// safe to call from any thread. silently no-ops if the tray hasn't been built
-/// yet (startup race).
-pub fn rebuild_tray_menu(app: &AppHandle) {
-    if let Some(tray) = app.tray_by_id("capscr-tray") {
-        match build_tray_menu(app) {
-            Ok(menu) => {
-                if let Err(e) = tray.set_menu(Some(menu)) {
-                    tracing::warn!("tray set_menu failed: {e}");
-                }
-            }
-            Err(e) => tracing::warn!("rebuild_tray_menu construct failed: {e}"),
-        }
-    }
-}
-
-fn build_tray(app: &tauri::App) -> tauri::Result<()> {
-    let menu = build_tray_menu(app)?;
-
-    let icon = app
-        .default_window_icon()
-        .cloned()
-        .ok_or_else(|| tauri::Error::AssetNotFound("default tray icon".into()))?;
-
-    TrayIconBuilder::with_id("capscr-tray")
-        .icon(icon)
-        .menu(&menu)
-        .show_menu_on_left_click(false)
-        .tooltip("capscr")
-        .on_menu_event(|app, event| {
-            use commands::{CaptureModeArg, PostActionArg};
-            let id = event.id.as_ref();
-            let spawn_capture = |mode: CaptureModeArg, post: PostActionArg| {
-                let app = app.clone();
-                std::thread::spawn(move || {
-                    if let Err(e) = commands::run_capture_pipeline(mode, post, &app) {
-                        tracing::warn!("tray capture failed: {e}");
-                        commands::emit_error(&app, "capture", &e.to_string());
-                    }
-                });
-            };
-            // route by id. dynamic-id items (recent_upload_*) are matched by
-            // prefix below so the static-arm part stays compact.
-            match id {
-                "cap_region" => spawn_capture(CaptureModeArg::Region, PostActionArg::Clipboard),
-                "cap_window" => spawn_capture(CaptureModeArg::Window, PostActionArg::Clipboard),
-                "cap_fullscreen" => {
-                    spawn_capture(CaptureModeArg::Fullscreen, PostActionArg::Clipboard)
-                }
-                "cap_active_monitor" => {
-                    spawn_capture(CaptureModeArg::ActiveMonitor, PostActionArg::Clipboard)
-                }
-                "rec_region_gif" => {
-                    // synthesize a tray-driven gif task so run_gif_task's start/stop
-                    // toggle (keyed off the task id in AppState) works the same as
-                    // a real hotkey-bound task
-                    let app = app.clone();
-                    std::thread::spawn(move || {
-                        let task = config::CaptureTask {
-                            id: "__tray_gif".into(),
-                            name: "Tray GIF".into(),
-                            hotkey: String::new(),
-                            capture_mode: config::TaskCaptureMode::RegionGif,
-                            post_action: config::TaskPostAction::SaveFile,
-                            target_destination: None,
-                            delay_ms: None,
-                        };
-                        if let Err(e) = commands::run_task(&task, &app) {
-                            tracing::warn!("tray gif failed: {e}");
-                            commands::emit_error(&app, "gif", &e.to_string());
-                        }
-                    });
-                }
-                "rec_region_mp4" => {
-                    // synthesize a tray-driven mp4 task so run_gif_task's start/stop
-                    // toggle (keyed off the task id in AppState) works the same as
-                    // a real hotkey-bound task
-                    let app = app.clone();
-                    std::thread::spawn(move || {
-                        let task = config::CaptureTask {
-                            id: "__tray_mp4".into(),
-                            name: "Tray MP4".into(),
-                            hotkey: String::new(),
-                            capture_mode: config::TaskCaptureMode::RegionMp4,
-                            post_action: config::TaskPostAction::SaveFile,
-                            target_destination: None,
-                            delay_ms: None,
-                        };
-                        if let Err(e) = commands::run_task(&task, &app) {
-                            tracing::warn!("tray mp4 failed: {e}");
-                            commands::emit_error(&app, "mp4", &e.to_string());
-                        }
-                    });
-                }
-                "copy_last_url" => {
-                    let st = app.state::<state::AppState>();
-                    let last = st.last_upload.lock().unwrap().clone();
-                    match last {
-                        Some(rec) => {
-                            if let Err(e) = crate::upload::copy_url_to_clipboard(&rec.url) {
-                                tracing::warn!("copy last url failed: {e}");
-                            } else if st.config.lock().unwrap().ui.show_notifications {
-                                let _ = crate::clipboard::show_notification(
-                                    "Copied",
-                                    &rec.url,
-                                );
-                            }
-                        }
-                        None => {
-                            let _ = crate::clipboard::show_notification(
-                                "No uploads yet",
-                                "Upload something first and the URL will land here.",
-                            );
-                        }
-                    }
-                }
-                "open_captures" => {
-                    let st = app.state::<state::AppState>();
-                    let dir = st.config.lock().unwrap().output.directory.clone();
-                    let _ = std::fs::create_dir_all(&dir);
-                    use tauri_plugin_opener::OpenerExt;
-                    let _ = app
-                        .opener()
-                        .open_path(dir.to_string_lossy().to_string(), None::<&str>);
-                }
-                "tab_default" => {
-                    let _ = commands::open_hub_window(app);
-                }
-                "dest_imgur" | "dest_custom" | "dest_ftp" | "dest_sftp" => {
-                    let st = app.state::<state::AppState>();
-                    let new_dest = match id {
-                        "dest_imgur" => config::UploadDestination::Imgur,
-                        "dest_custom" => config::UploadDestination::Custom,
-                        "dest_ftp" => config::UploadDestination::Ftp,
-                        _ => config::UploadDestination::Sftp,
-                    };
-                    {
-                        let mut cfg = st.config.lock().unwrap();
-                        if cfg.upload.destination != new_dest {
-                            cfg.upload.destination = new_dest;
-                            if let Err(e) = cfg.save() {
-                                tracing::warn!(
-                                    "save after destination switch failed: {e}"
-                                );
-                            }
-                        }
-                    }
-                    rebuild_tray_menu(app);
-                    let _ = app.emit("capscr://config-updated", ());
-                    let _ = crate::clipboard::show_notification(
-                        "Upload destination",
-                        &format!("Switched to {:?}", new_dest),
-                    );
-                }
-                "hotkeys_toggle" => {
-                    use std::sync::atomic::Ordering;
-                    let st = app.state::<state::AppState>();
-                    let was_disabled = st.hotkeys_disabled.load(Ordering::SeqCst);
-                    let next = !was_disabled;
-                    if let Err(e) = commands::set_hotkeys_disabled(
-                        next,
-                        app.clone(),
-                        st,
-                    ) {
-                        tracing::warn!("tray hotkey toggle failed: {e}");
-                    }
-                    let _ = crate::clipboard::show_notification(
-                        if next { "Hotkeys disabled" } else { "Hotkeys enabled" },
-                        if next {
-                            "All capscr hotkeys are off. Re-enable from the tray or Settings → Hotkeys."
-                        } else {
-                            "All task hotkeys are live again."
-                        },
-                    );
-                }
-                "exit" => commands::exit_app(app.clone()),
-                other if other.starts_with("recent_upload_") => {
-                    let idx: usize = other
-                        .trim_start_matches("recent_upload_")
-                        .parse()
-                        .unwrap_or(usize::MAX);
-                    let st = app.state::<state::AppState>();
-                    let url = st
-                        .recent_uploads
-                        .lock()
-                        .unwrap()
-                        .get(idx)
-                        .map(|r| r.url.clone());
-                    if let Some(url) = url {
-                        if let Err(e) = crate::upload::copy_url_to_clipboard(&url) {
-                            tracing::warn!("copy recent url failed: {e}");
-                        } else if st.config.lock().unwrap().ui.show_notifications {
-                            let _ = crate::clipboard::show_notification("Copied", &url);
-                        }
-                    }
-                }
-                _ => {}
-            }
-        })
-        .on_tray_icon_event(|tray, event| {
-            if let TrayIconEvent::Click {
-                button: MouseButton::Left,
-                button_state: MouseButtonState::Up,
-                ..
-            } = event
-            {
-                let _ = commands::open_hub_window(tray.app_handle());
-            }
-        })
-        .build(app)?;
-
-    Ok(())
-}
-
 fn spawn_hotkey_thread(
     app: tauri::AppHandle,
     rx: cb::Receiver<HotkeyCommand>,
@@ -822,6 +583,9 @@ fn spawn_hotkey_thread(
                 while let Ok(ev) = hook_rx.recv() {
                     match ev {
                         ll_hook::HookEvent::Fire { task_id } => {
+                            if hotkeys::gesture::press(&app_dispatch, &task_id) {
+                                continue;
+                            }
                             // dedupe auto-repeat: ignore repeats within 250ms of
                             // the previous fire for the same task. WH_KEYBOARD_LL
                             // forwards held-key auto-repeat presses; users only
@@ -838,6 +602,9 @@ fn spawn_hotkey_thread(
                             last_fire.insert(task_id.clone(), now);
                             commands::trigger_task(&app_dispatch, &task_id);
                         }
+                        ll_hook::HookEvent::Release { task_id } => {
+                            hotkeys::gesture::release(&app_dispatch, &task_id);
+                        }
                         ll_hook::HookEvent::Captured { vk, mods } => {
                             let hotkey = hotkeys::format_vk_mods(vk, mods);
                             let payload = serde_json::json!({
@@ -868,16 +635,20 @@ fn spawn_hotkey_thread(
                 let mut last_fire: std::collections::HashMap<String, std::time::Instant> =
                     std::collections::HashMap::new();
                 while let Ok(ev) = rx.recv() {
-                    if ev.state() != HotKeyState::Pressed {
-                        continue;
-                    }
                     let Some(task_id) = hotkeys::task_for_hotkey_id(ev.id()) else {
                         continue;
                     };
+                    if ev.state() == HotKeyState::Released {
+                        hotkeys::gesture::release(&app_dispatch, &task_id);
+                        continue;
+                    }
                     let st = app_dispatch.state::<state::AppState>();
                     if st.hotkeys_disabled.load(Ordering::SeqCst) {
                         continue;
                     }
+                    if hotkeys::gesture::press(&app_dispatch, &task_id) {
+                        continue;
+                    }
                     let now = std::time::Instant::now();
                     let allow = match last_fire.get(&task_id) {
                         None => true,
@@ -928,9 +699,24 @@ fn spawn_hotkey_thread(
             hotkeys::set_advanced_input(advanced);
             hotkeys::portal_linux::start(app.clone());
         }
-        for task in &initial_tasks {
+        let riders = hotkeys::gesture::set_tasks(&initial_tasks);
+        for task in initial_tasks.iter().filter(|t| !riders.contains(&t.id)) {
             hm.try_register_labeled(task.id.clone(), &task.hotkey, &task.name);
         }
+        let (stop_recording, do_not_disturb) = {
+            let st = app.state::<state::AppState>();
+            let cfg = st.config.lock().unwrap();
+            (
+                cfg.hotkeys.stop_recording.clone(),
+                cfg.hotkeys.do_not_disturb.clone(),
+            )
+        };
+        hm.try_register_labeled(
+            commands::STOP_RECORDING_HOTKEY_ID,
+            &stop_recording,
+            "Stop recording",
+        );
+        hm.try_register_labeled(commands::DND_HOTKEY_ID, &do_not_disturb, "Do not disturb");
         hm.flush_to_hook();
         #[cfg(target_os = "linux")]
         if hotkeys::advanced_input_enabled() {
@@ -947,27 +733,75 @@ fn spawn_hotkey_thread(
         }
         // record startup registration outcomes so the hub Tasks view can show
         // a per-task status chip even before the user has opened it.
-        commands::record_hotkey_status(&app, &hm.registered_task_ids(), &startup_errors);
+        let registered = hotkeys::gesture::with_riders(hm.registered_task_ids());
+        commands::record_hotkey_status(&app, &registered, &startup_errors);
         if !startup_errors.is_empty() {
             let summary = startup_errors
                 .iter()
                 .map(|e| format!("'{}' ({}): {}", e.hotkey, e.task_id, e.reason))
                 .collect::<Vec<_>>()
                 .join("\n");
-            let _ = clipboard::show_notification("capscr: hotkey conflicts", &summary);
+            let _ = clipboard::show_notification(i18n::t("notify.hotkey-conflicts"), &summary);
         }
 
         // reload loop: hotkey re-registration is driven by the Reload command
         // channel, which is sent on config save, tray toggle, and any other
         // path that mutates the binding set.
-        while let Ok(HotkeyCommand::Reload { tasks }) = rx.recv() {
+        while let Ok(command) = rx.recv() {
+            let (tasks, stop_recording, do_not_disturb) = match command {
+                HotkeyCommand::Reload {
+                    tasks,
+                    stop_recording,
+                    do_not_disturb,
+                } => (tasks, stop_recording, do_not_disturb),
+                HotkeyCommand::Shutdown(done) => {
+                    hm.unregister_all();
+                    hm.flush_to_hook();
+                    let _ = done.send(());
+                    return;
+                }
+            };
             hm.unregister_all();
-            for task in &tasks {
+            // do not disturb keeps only its own binding, so every other key
+            // goes through to the game or presentation untouched
+            let (tasks, stop_recording) = if dnd::active() {
+                (Vec::new(), String::new())
+            } else {
+                (tasks, stop_recording)
+            };
+            // and a full-screen game that wants its keys gets the bare ones
+            let tasks: Vec<_> = tasks
+                .into_iter()
+                .filter(|t| !game_mode::suspends(&t.hotkey))
+                .collect();
+            let stop_recording = if game_mode::suspends(&stop_recording) {
+                String::new()
+            } else {
+                stop_recording
+            };
+            let riders = hotkeys::gesture::set_tasks(&tasks);
+            for task in tasks.iter().filter(|t| !riders.contains(&t.id)) {
                 hm.try_register_labeled(task.id.clone(), &task.hotkey, &task.name);
             }
+            hm.try_register_labeled(
+                commands::STOP_RECORDING_HOTKEY_ID,
+                &stop_recording,
+                "Stop recording",
+            );
+            hm.try_register_labeled(commands::DND_HOTKEY_ID, &do_not_disturb, "Do not disturb");
+            // Esc cancels a countdown, bound only while one runs. not on
+            // wayland: the portal would ask the user to approve it each time
+            if cancel::escapable() && !capture::is_wayland_session() {
+                if let Err(e) =
+                    hm.register_transient(commands::CANCEL_HOTKEY_ID, "Escape", "Cancel capture")
+                {
+                    tracing::debug!("couldn't bind Esc to cancel: {e}");
+                }
+            }
             hm.flush_to_hook();
             let errs = hm.take_errors();
-            commands::record_hotkey_status(&app, &hm.registered_task_ids(), &errs);
+            let registered = hotkeys::gesture::with_riders(hm.registered_task_ids());
+            commands::record_hotkey_status(&app, &registered, &errs);
             for err in &errs {
                 tracing::warn!(
                     "hotkey '{}' for task '{}' failed: {}",
@@ -977,15 +811,31 @@ fn spawn_hotkey_thread(
                 );
                 let msg = format!("'{}' ({}) — {}", err.hotkey, err.task_id, err.reason);
                 commands::emit_error(&app, "hotkey", &msg);
-                let _ = crate::clipboard::show_notification("Hotkey conflict", &msg);
+                let _ = crate::clipboard::show_notification(
+                    crate::i18n::t("notify.hotkey-conflict"),
+                    &msg,
+                );
             }
         }
     });
 }
 
+// a `capscr://` link arrives as a bare argument and means the same thing;
+// `--edit <file>` comes from the file manager's context menu
 fn parse_jump_arg<I: IntoIterator<Item = String>>(args: I) -> Option<String> {
-    args.into_iter()
-        .find_map(|a| a.strip_prefix("--jump=").map(String::from))
+    let mut args = args.into_iter();
+    while let Some(a) = args.next() {
+        if let Some(kind) = a.strip_prefix("--jump=") {
+            return Some(kind.to_string());
+        }
+        if a == "--edit" {
+            return args.next().map(|path| format!("edit:{path}"));
+        }
+        if let Some(kind) = protocol::jump_kind(&a) {
+            return Some(kind);
+        }
+    }
+    None
 }
 
 /// returns true when the process should exit immediately after writing to the
@@ -997,6 +847,8 @@ fn handle_cli_short_circuit<I: IntoIterator<Item = String>>(args: I) -> bool {
     let mut want_help = false;
     let mut want_wayland_diag = false;
     let mut sweep_dir: Option<String> = None;
+    let mut batch_dir: Option<String> = None;
+    let mut convert: Option<ConvertArgs> = None;
     for a in args.into_iter().skip(1) {
         match a.as_str() {
             "--version" | "-V" => want_version = true,
@@ -1005,6 +857,25 @@ fn handle_cli_short_circuit<I: IntoIterator<Item = String>>(args: I) -> bool {
             s if s.starts_with("--d2d-sweep=") => {
                 sweep_dir = Some(s.trim_start_matches("--d2d-sweep=").to_string());
             }
+            s if s.starts_with("--batch=") => {
+                batch_dir = Some(s.trim_start_matches("--batch=").to_string());
+            }
+            s if s.starts_with("--convert=") => {
+                convert.get_or_insert_with(ConvertArgs::default).file =
+                    s.trim_start_matches("--convert=").to_string();
+            }
+            s if s.starts_with("--to=") => {
+                convert.get_or_insert_with(ConvertArgs::default).to =
+                    Some(s.trim_start_matches("--to=").to_string());
+            }
+            s if s.starts_with("--fps=") => {
+                convert.get_or_insert_with(ConvertArgs::default).fps =
+                    Some(s.trim_start_matches("--fps=").to_string());
+            }
+            s if s.starts_with("--width=") => {
+                convert.get_or_insert_with(ConvertArgs::default).width =
+                    Some(s.trim_start_matches("--width=").to_string());
+            }
             _ => {}
         }
     }
@@ -1044,6 +915,16 @@ fn handle_cli_short_circuit<I: IntoIterator<Item = String>>(args: I) -> bool {
         }
         return true;
     }
+    if let Some(dir) = batch_dir {
+        attach_parent_console();
+        run_batch_cli(std::path::Path::new(&dir));
+        return true;
+    }
+    if let Some(args) = convert {
+        attach_parent_console();
+        run_convert_cli(&args);
+        return true;
+    }
     if !want_version && !want_help {
         return false;
     }
@@ -1062,11 +943,20 @@ fn print_help() {
         \n\
         Usage:\n  \
           capscr [--jump=<kind>]\n  \
+          capscr --edit <file>\n  \
+          capscr --batch=<folder>\n  \
+          capscr --convert=<file> [--to=gif|mp4|webm] [--fps=<n>] [--width=<px>]\n  \
           capscr --version | -V\n  \
           capscr --help | -h\n\
         \n\
         Options:\n  \
-          --jump=<kind>   Trigger a one-shot action and exit. kinds: region, window, fullscreen, captures, hub\n  \
+          --jump=<kind>   Trigger a one-shot action and exit. kinds: region, region-last, window, fullscreen, captures, hub,\n                  \
+          task:<id>, window-title:<regex>\n  \
+          --edit <file>   Open an image in the editor (a copy, if it's outside the captures folder)\n  \
+          --batch=<folder> Run every image in a folder through the output settings (decorations, resize,\n                  \
+          format) into the captures folder, then exit\n  \
+          --convert=<file> Turn a GIF into a video or a video into a GIF in the captures folder, then exit.\n                  \
+          --to picks the format (default: the other kind), --fps the frame rate, --width a max width\n  \
           --version       Print version and exit\n  \
           --help          Print this help and exit\n\
         \n\
@@ -1077,6 +967,86 @@ fn print_help() {
     );
 }
 
+// same settings the running app would use; progress goes to stdout since
+// there's no window to report into
+fn run_batch_cli(dir: &std::path::Path) {
+    let config = match config::Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("batch failed: couldn't load config: {e:#}");
+            return;
+        }
+    };
+    let result = batch::run(dir, &config, |p| match (&p.output, &p.error) {
+        (Some(output), _) => println!("[{}/{}] {} -> {output}", p.done, p.total, p.source),
+        (None, Some(error)) => eprintln!("[{}/{}] {}: {error}", p.done, p.total, p.source),
+        (None, None) => {}
+    });
+    match result {
+        Ok(written) => println!(
+            "batch done: {written} image(s) -> {}",
+            config.output.directory.display()
+        ),
+        Err(e) => eprintln!("batch failed: {e:#}"),
+    }
+}
+
+#[derive(Default)]
+struct ConvertArgs {
+    file: String,
+    to: Option<String>,
+    fps: Option<String>,
+    width: Option<String>,
+}
+
+fn run_convert_cli(args: &ConvertArgs) {
+    use recording::{ConvertOptions, ConvertTarget};
+    let source = std::path::Path::new(&args.file);
+    if args.file.is_empty() {
+        eprintln!("convert failed: --to, --fps and --width go with --convert=<file>");
+        return;
+    }
+    let target = match &args.to {
+        Some(to) => ConvertTarget::parse(to),
+        None => ConvertTarget::opposite_of(source),
+    };
+    let Some(target) = target else {
+        eprintln!("convert failed: give --to=gif, --to=mp4 or --to=webm");
+        return;
+    };
+    let number = |value: &Option<String>, flag: &str| -> Option<u32> {
+        match value.as_deref().map(str::parse::<u32>) {
+            None => Some(0),
+            Some(Ok(n)) => Some(n),
+            Some(Err(_)) => {
+                eprintln!("convert failed: {flag} takes a whole number");
+                None
+            }
+        }
+    };
+    let (Some(fps), Some(max_width)) = (number(&args.fps, "--fps"), number(&args.width, "--width"))
+    else {
+        return;
+    };
+    let config = match config::Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("convert failed: couldn't load config: {e:#}");
+            return;
+        }
+    };
+    let options = ConvertOptions {
+        target,
+        fps,
+        max_width,
+        video_crf: config.capture.video_quality.crf(),
+    };
+    match commands::convert_recording_file(source, &config, options) {
+        Ok(path) => println!("{} -> {}", source.display(), path.display()),
+        Err(e) => eprintln!("convert failed: {e:#}"),
+    }
+}
+
 #[cfg(windows)]
 fn attach_parent_console() {
     use windows::Win32::System::Console::{AttachConsole, ATTACH_PARENT_PROCESS};
@@ -1095,12 +1065,26 @@ fn dispatch_jump(app: &tauri::AppHandle, kind: Option<&str>) {
         let _ = commands::open_hub_window(app);
         return;
     };
+    // a link can start a capture, but never publish one, and nothing else
+    let (kind, from_link) = match kind.strip_prefix(protocol::LINK_PREFIX) {
+        Some(mode) => (mode, true),
+        None => (kind, false),
+    };
+    if from_link && !protocol::is_capture_mode(kind) {
+        tracing::warn!("ignoring link jump {kind}");
+        return;
+    }
     let app_clone = app.clone();
     let spawn_capture = move |mode: CaptureModeArg| {
         std::thread::spawn(move || {
-            if let Err(e) =
-                commands::run_capture_pipeline(mode, PostActionArg::Clipboard, &app_clone)
-            {
+            let post = PostActionArg::configured_for(&app_clone, mode);
+            let upload = commands::UploadOverride {
+                local_only: from_link,
+                ..Default::default()
+            };
+            let run =
+                commands::run_capture_pipeline_with_target(mode, post, &app_clone, upload, None);
+            if let Err(e) = run {
                 tracing::warn!("jump-list capture failed: {e}");
                 commands::emit_error(&app_clone, "capture", &e.to_string());
             }
@@ -1108,6 +1092,7 @@ fn dispatch_jump(app: &tauri::AppHandle, kind: Option<&str>) {
     };
     match kind {
         "region" => spawn_capture(CaptureModeArg::Region),
+        "region-last" => spawn_capture(CaptureModeArg::RegionLast),
         "window" => spawn_capture(CaptureModeArg::Window),
         "fullscreen" => spawn_capture(CaptureModeArg::Fullscreen),
         "captures" => {
@@ -1126,7 +1111,46 @@ fn dispatch_jump(app: &tauri::AppHandle, kind: Option<&str>) {
         other if other.starts_with("task:") => {
             let task_id = other.trim_start_matches("task:").to_string();
             let app = app.clone();
-            std::thread::spawn(move || commands::trigger_task(&app, &task_id));
+            std::thread::spawn(move || commands::fire_task_by_id(&app, &task_id));
+        }
+        // built off this thread: the editor window deadlocks on the main one
+        other if other.starts_with("edit:") => {
+            let path = std::path::PathBuf::from(other.trim_start_matches("edit:"));
+            let app = app.clone();
+            std::thread::spawn(move || {
+                if let Err(e) = commands::open_external_in_editor(&app, &path) {
+                    tracing::warn!("couldn't open {} in the editor: {e:#}", path.display());
+                    commands::emit_error(&app, "editor", &format!("{e:#}"));
+                }
+            });
+        }
+        // one-off targeted window capture to the clipboard, no task needed
+        other if other.starts_with("window-title:") => {
+            let task = config::CaptureTask {
+                id: "__jump_window_title".into(),
+                name: "Window by title".into(),
+                hotkey: String::new(),
+                capture_mode: config::TaskCaptureMode::TargetWindow,
+                post_action: config::TaskPostAction::Clipboard,
+                target_destination: None,
+                custom_preset: None,
+                delay_ms: None,
+                target_window: Some(config::WindowTarget {
+                    title: other.trim_start_matches("window-title:").to_string(),
+                    process: String::new(),
+                }),
+                follow_active_window: false,
+                recording_size: None,
+                hold_to_record: false,
+                gesture: config::HotkeyGesture::Press,
+            };
+            let app = app.clone();
+            std::thread::spawn(move || {
+                if let Err(e) = commands::run_task(&task, &app) {
+                    tracing::warn!("jump-list window-title capture failed: {e}");
+                    commands::emit_error(&app, "capture", &e.to_string());
+                }
+            });
         }
         other => {
             tracing::warn!("unknown --jump= kind: {other}");
@@ -0,0 +1,375 @@
+// metadata in saved images. the encoders capscr uses write none of their own,
+// but editor saves arrive as whatever bytes the webview produced, and an
+// archival setup wants the opposite: the capture's provenance in the file.
+// both work on the encoded bytes after the fact, png and jpeg only; the other
+// formats are left as encoded
+
+use crate::config::MetadataMode;
+use anyhow::{anyhow, Result};
+use std::path::Path;
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+// textual, exif and modification-time chunks. iCCP/sRGB/gAMA stay: they
+// describe the pixels rather than where they came from
+const PNG_METADATA_CHUNKS: &[&[u8; 4]] = &[b"tEXt", b"zTXt", b"iTXt", b"eXIf", b"tIME"];
+// a window title can be anything; keep the exif segment well under jpeg's
+// 64k segment limit
+const MAX_FIELD_LEN: usize = 1024;
+
+/// what is known about a capture when it is written
+#[derive(Debug, Clone)]
+pub struct CaptureMeta {
+    pub taken: chrono::DateTime<chrono::Local>,
+    pub monitor: Option<String>,
+    pub window_title: Option<String>,
+    /// process of the captured window, when one was picked
+    pub app: Option<String>,
+}
+
+impl CaptureMeta {
+    pub fn now() -> Self {
+        Self {
+            taken: chrono::Local::now(),
+            monitor: None,
+            window_title: None,
+            app: None,
+        }
+    }
+
+    fn software() -> String {
+        format!("capscr {}", env!("CARGO_PKG_VERSION"))
+    }
+}
+
+/// rewrite a freshly saved capture per `mode`. the new bytes replace the
+/// file through a temp file, so a failed write never truncates the capture
+pub fn apply_to_file(path: &Path, mode: MetadataMode, meta: &CaptureMeta) -> Result<()> {
+    // nothing to do for keep, or for a format neither rewrite handles
+    let handled = path.extension().and_then(|e| e.to_str()).is_some_and(|e| {
+        ["png", "jpg", "jpeg"]
+            .iter()
+            .any(|x| e.eq_ignore_ascii_case(x))
+    });
+    if mode == MetadataMode::Keep || !handled {
+        return Ok(());
+    }
+    let bytes = std::fs::read(path)?;
+    let rewritten = match mode {
+        MetadataMode::Keep => None,
+        MetadataMode::Strip => strip(&bytes),
+        MetadataMode::Embed => embed(&bytes, meta),
+    };
+    if let Some(out) = rewritten {
+        crate::disk::replace_file(path, &out)?;
+    }
+    Ok(())
+}
+
+/// `bytes` with metadata removed, or None for a format this doesn't handle
+pub fn strip(bytes: &[u8]) -> Option<Vec<u8>> {
+    if bytes.starts_with(PNG_SIGNATURE) {
+        png_rebuild(bytes, &[]).ok()
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        jpeg_rebuild(bytes, None).ok()
+    } else {
+        None
+    }
+}
+
+/// `bytes` with any existing metadata replaced by the capture's, or None for
+/// a format this doesn't handle
+pub fn embed(bytes: &[u8], meta: &CaptureMeta) -> Option<Vec<u8>> {
+    if bytes.starts_with(PNG_SIGNATURE) {
+        png_rebuild(bytes, &png_text_chunks(meta)).ok()
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        jpeg_rebuild(bytes, Some(&exif_segment(meta))).ok()
+    } else {
+        None
+    }
+}
+
+fn clipped(s: &str) -> &str {
+    if s.len() <= MAX_FIELD_LEN {
+        return s;
+    }
+    let mut end = MAX_FIELD_LEN;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+/// a png's tags and note as its Keywords and Comment text, leaving the rest
+/// of its metadata alone. empty ones are removed. None when not a png
+pub fn png_with_notes(bytes: &[u8], tags: &[String], note: &str) -> Option<Vec<u8>> {
+    if !bytes.starts_with(PNG_SIGNATURE) {
+        return None;
+    }
+    let mut extra = Vec::new();
+    if !tags.is_empty() {
+        extra.push(png_itxt("Keywords", &tags.join(", ")));
+    }
+    if !note.is_empty() {
+        extra.push(png_itxt("Comment", note));
+    }
+    png_rebuild_with(bytes, &extra, |kind, data| {
+        kind == b"iTXt" && (data.starts_with(b"Keywords\0") || data.starts_with(b"Comment\0"))
+    })
+    .ok()
+}
+
+// copies every chunk except the metadata ones, inserting `extra` (already
+// framed chunks) right after IHDR
+fn png_rebuild(bytes: &[u8], extra: &[Vec<u8>]) -> Result<Vec<u8>> {
+    png_rebuild_with(bytes, extra, |kind, _| {
+        PNG_METADATA_CHUNKS.iter().any(|k| &k[..] == kind)
+    })
+}
+
+// the same, dropping whichever chunks `drop` picks by kind and data
+fn png_rebuild_with(
+    bytes: &[u8],
+    extra: &[Vec<u8>],
+    drop: impl Fn(&[u8], &[u8]) -> bool,
+) -> Result<Vec<u8>> {
+    let mut out = Vec::with_capacity(bytes.len() + extra.iter().map(Vec::len).sum::<usize>());
+    out.extend_from_slice(PNG_SIGNATURE);
+    let mut at = PNG_SIGNATURE.len();
+    while at < bytes.len() {
+        let header = bytes
+            .get(at..at + 8)
+            .ok_or_else(|| anyhow!("truncated png chunk header"))?;
+        let len = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        let end = at + 12 + len;
+        let chunk = bytes
+            .get(at..end)
+            .ok_or_else(|| anyhow!("truncated png chunk"))?;
+        let kind = &header[4..8];
+        if !drop(kind, &chunk[8..chunk.len() - 4]) {
+            out.extend_from_slice(chunk);
+        }
+        if kind == b"IHDR" {
+            for c in extra {
+                out.extend_from_slice(c);
+            }
+        }
+        at = end;
+        if kind == b"IEND" {
+            break;
+        }
+    }
+    Ok(out)
+}
+
+fn png_chunk(kind: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut chunk = Vec::with_capacity(data.len() + 12);
+    chunk.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    chunk.extend_from_slice(kind);
+    chunk.extend_from_slice(data);
+    let mut crc = crc32fast::Hasher::new();
+    crc.update(kind);
+    crc.update(data);
+    chunk.extend_from_slice(&crc.finalize().to_be_bytes());
+    chunk
+}
+
+// iTXt rather than tEXt: tEXt is latin-1 only and window titles aren't
+fn png_itxt(keyword: &str, text: &str) -> Vec<u8> {
+    let mut data = Vec::with_capacity(keyword.len() + text.len() + 5);
+    data.extend_from_slice(keyword.as_bytes());
+    // separator, no compression, method 0, empty language tag and
+    // translated keyword
+    data.extend_from_slice(&[0, 0, 0, 0, 0]);
+    data.extend_from_slice(clipped(text).as_bytes());
+    png_chunk(b"iTXt", &data)
+}
+
+// the png spec's registered keywords, so viewers show them without guessing
+fn png_text_chunks(meta: &CaptureMeta) -> Vec<Vec<u8>> {
+    let mut chunks = vec![
+        png_itxt("Creation Time", &meta.taken.to_rfc2822()),
+        png_itxt("Software", &CaptureMeta::software()),
+    ];
+    if let Some(title) = &meta.window_title {
+        chunks.push(png_itxt("Title", title));
+    }
+    if let Some(monitor) = &meta.monitor {
+        chunks.push(png_itxt("Source", monitor));
+    }
+    chunks
+}
+
+// copies the marker segments up to the scan, dropping app1 (exif / xmp),
+// app3-app13, app15 and comments. app0 (jfif), app2 (icc) and app14 (adobe
+// colour transform) change how the pixels decode, so they stay. `exif`, a
+// complete app1 segment, goes in after app0 or straight after SOI
+fn jpeg_rebuild(bytes: &[u8], exif: Option<&[u8]>) -> Result<Vec<u8>> {
+    let mut out = Vec::with_capacity(bytes.len() + exif.map_or(0, <[u8]>::len));
+    out.extend_from_slice(&[0xFF, 0xD8]);
+    let mut exif = exif;
+    let mut at = 2;
+    loop {
+        let marker = bytes
+            .get(at..at + 2)
+            .ok_or_else(|| anyhow!("jpeg ended before the scan"))?;
+        if marker[0] != 0xFF {
+            return Err(anyhow!("bad jpeg marker at {at}"));
+        }
+        let code = marker[1];
+        if code != 0xE0 {
+            if let Some(segment) = exif.take() {
+                out.extend_from_slice(segment);
+            }
+        }
+        // start of scan: everything from here is entropy-coded data
+        if code == 0xDA {
+            out.extend_from_slice(&bytes[at..]);
+            return Ok(out);
+        }
+        let len = bytes
+            .get(at + 2..at + 4)
+            .map(|l| u16::from_be_bytes([l[0], l[1]]) as usize)
+            .ok_or_else(|| anyhow!("truncated jpeg segment"))?;
+        let end = at + 2 + len;
+        let segment = bytes
+            .get(at..end)
+            .ok_or_else(|| anyhow!("truncated jpeg segment"))?;
+        let metadata = matches!(code, 0xE1 | 0xE3..=0xED | 0xEF | 0xFE);
+        if !metadata {
+            out.extend_from_slice(segment);
+        }
+        at = end;
+    }
+}
+
+// a big-endian tiff structure with a single IFD of ascii tags
+fn exif_segment(meta: &CaptureMeta) -> Vec<u8> {
+    let description = match (&meta.window_title, &meta.monitor) {
+        (Some(t), Some(m)) => Some(format!("{} ({m})", clipped(t))),
+        (Some(t), None) => Some(clipped(t).to_string()),
+        (None, Some(m)) => Some(clipped(m).to_string()),
+        (None, None) => None,
+    };
+    // tags must be in ascending order
+    let mut fields: Vec<(u16, String)> = Vec::new();
+    if let Some(d) = description {
+        fields.push((0x010E, d)); // ImageDescription
+    }
+    fields.push((0x0131, CaptureMeta::software())); // Software
+    fields.push((0x0132, meta.taken.format("%Y:%m:%d %H:%M:%S").to_string())); // DateTime
+
+    let ifd_len = 2 + fields.len() * 12 + 4;
+    let mut tiff = Vec::new();
+    tiff.extend_from_slice(b"MM\x00\x2A");
+    tiff.extend_from_slice(&8u32.to_be_bytes());
+    tiff.extend_from_slice(&(fields.len() as u16).to_be_bytes());
+    let mut data = Vec::new();
+    for (tag, value) in &fields {
+        let mut bytes = value.as_bytes().to_vec();
+        bytes.push(0);
+        tiff.extend_from_slice(&tag.to_be_bytes());
+        tiff.extend_from_slice(&2u16.to_be_bytes()); // ascii
+        tiff.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+        if bytes.len() <= 4 {
+            bytes.resize(4, 0);
+            tiff.extend_from_slice(&bytes);
+        } else {
+            let offset = 8 + ifd_len + data.len();
+            tiff.extend_from_slice(&(offset as u32).to_be_bytes());
+            data.extend_from_slice(&bytes);
+            // values start on a word boundary
+            if data.len() % 2 == 1 {
+                data.push(0);
+            }
+        }
+    }
+    tiff.extend_from_slice(&0u32.to_be_bytes()); // no next IFD
+    tiff.extend_from_slice(&data);
+
+    let mut segment = vec![0xFF, 0xE1];
+    segment.extend_from_slice(&((tiff.len() + 8) as u16).to_be_bytes());
+    segment.extend_from_slice(b"Exif\x00\x00");
+    segment.extend_from_slice(&tiff);
+    segment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, Rgba, RgbaImage};
+
+    fn encoded(format: ImageFormat) -> Vec<u8> {
+        let img = RgbaImage::from_pixel(4, 3, Rgba([10, 20, 30, 255]));
+        let mut out = std::io::Cursor::new(Vec::new());
+        if format == ImageFormat::Jpeg {
+            image::DynamicImage::ImageRgba8(img)
+                .to_rgb8()
+                .write_to(&mut out, format)
+                .unwrap();
+        } else {
+            img.write_to(&mut out, format).unwrap();
+        }
+        out.into_inner()
+    }
+
+    fn meta() -> CaptureMeta {
+        CaptureMeta {
+            window_title: Some("Notes — draft".into()),
+            monitor: Some("DP-1".into()),
+            ..CaptureMeta::now()
+        }
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack.windows(needle.len()).any(|w| w == needle)
+    }
+
+    #[test]
+    fn png_embed_round_trips_and_strip_removes_it() {
+        let png = encoded(ImageFormat::Png);
+        let tagged = embed(&png, &meta()).unwrap();
+        assert!(contains(&tagged, b"iTXtTitle"));
+        assert!(contains(&tagged, "Notes — draft".as_bytes()));
+        assert_eq!(image::load_from_memory(&tagged).unwrap().width(), 4);
+
+        let stripped = strip(&tagged).unwrap();
+        assert!(!contains(&stripped, b"iTXt"));
+        assert_eq!(stripped, png);
+    }
+
+    #[test]
+    fn png_notes_replace_only_keywords_and_comment() {
+        let png = embed(&encoded(ImageFormat::Png), &meta()).unwrap();
+        let tags = vec!["bug".to_string(), "login".to_string()];
+        let noted = png_with_notes(&png, &tags, "first draft").unwrap();
+        let noted = png_with_notes(&noted, &tags, "second draft").unwrap();
+        assert!(contains(&noted, b"iTXtKeywords\0\0\0\0\0bug, login"));
+        assert!(contains(&noted, b"second draft"));
+        assert!(!contains(&noted, b"first draft"));
+        // the capture's own metadata stays
+        assert!(contains(&noted, b"iTXtTitle"));
+        assert_eq!(image::load_from_memory(&noted).unwrap().width(), 4);
+
+        assert_eq!(png_with_notes(&noted, &[], "").unwrap(), png);
+        assert!(png_with_notes(b"GIF89a", &tags, "").is_none());
+    }
+
+    #[test]
+    fn jpeg_embed_adds_exif_and_still_decodes() {
+        let jpeg = encoded(ImageFormat::Jpeg);
+        let tagged = embed(&jpeg, &meta()).unwrap();
+        assert!(contains(&tagged, b"Exif\x00\x00MM"));
+        assert!(contains(&tagged, b"DP-1"));
+        assert_eq!(image::load_from_memory(&tagged).unwrap().height(), 3);
+
+        let stripped = strip(&tagged).unwrap();
+        assert!(!contains(&stripped, b"Exif"));
+        assert_eq!(image::load_from_memory(&stripped).unwrap().height(), 3);
+    }
+
+    #[test]
+    fn other_formats_are_left_alone() {
+        assert!(strip(&encoded(ImageFormat::Bmp)).is_none());
+        assert!(embed(b"GIF89a", &meta()).is_none());
+    }
+}
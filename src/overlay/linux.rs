@@ -33,6 +33,7 @@ struct ActiveSelection {
     surfaces: Vec<SelectorSurface>,
     ready: HashSet<String>,
     focus_label: String,
+    fixed_size: Option<(u32, u32)>,
     tx: Sender<SelectionResult>,
 }
 
@@ -290,7 +291,10 @@ fn compose_frozen_region(rect: Rectangle, surfaces: &[SelectorSurface]) -> Optio
     Some(captured)
 }
 
-pub fn select(frozen_frame: Option<Arc<RgbaImage>>) -> SelectionResult {
+pub fn select(
+    frozen_frame: Option<Arc<RgbaImage>>,
+    fixed_size: Option<(u32, u32)>,
+) -> SelectionResult {
     let Some(app) = APP.get() else {
         tracing::warn!("selector invoked before app handle registration");
         return SelectionResult::Cancelled;
@@ -309,6 +313,15 @@ pub fn select(frozen_frame: Option<Arc<RgbaImage>>) -> SelectionResult {
         .then(crate::capture::active_wayland_monitor)
         .and_then(Result::ok)
         .map(|monitor| monitor.name);
+    // confined to one monitor, wayland only freezes and maps the active
+    // output; x11 crops its single desktop-wide surface down to it below
+    let confine = super::unified::cursor_monitor_only();
+    let monitors = match &active_output {
+        Some(name) if confine && monitors.iter().any(|m| &m.name == name) => {
+            monitors.into_iter().filter(|m| &m.name == name).collect()
+        }
+        _ => monitors,
+    };
     let all_windows = PREWARMED
         .lock()
         .unwrap()
@@ -393,11 +406,28 @@ pub fn select(frozen_frame: Option<Arc<RgbaImage>>) -> SelectionResult {
         let Some(frame) = frame else {
             return SelectionResult::Cancelled;
         };
+        let pointer_monitor = confine
+            .then(crate::capture::pointer_position)
+            .flatten()
+            .and_then(|(x, y)| {
+                monitors.iter().find(|m| {
+                    x >= m.x && y >= m.y && x < m.x + m.width as i32 && y < m.y + m.height as i32
+                })
+            })
+            .and_then(|m| {
+                let bounds = Rectangle::new(m.x, m.y, m.width, m.height);
+                let cropped = super::unified::crop_to_monitor(&frame, desktop_origin, bounds)?;
+                Some((Arc::new(cropped), (m.x, m.y, m.width, m.height)))
+            });
+        let (frame, origin, rect) = match pointer_monitor {
+            Some((cropped, bounds)) => (cropped, (bounds.0, bounds.1), bounds),
+            None => (frame, desktop_origin, rect),
+        };
         vec![SelectorSurface {
             label: format!("{SELECTOR_LABEL_PREFIX}0"),
             output_name: None,
             frame,
-            origin: desktop_origin,
+            origin,
             windows: all_windows,
             rect,
         }]
@@ -415,7 +445,8 @@ pub fn select(frozen_frame: Option<Arc<RgbaImage>>) -> SelectionResult {
         tracing::error!("frozen frame orientation mismatches its output; using webview selector");
     }
     let force_webview = std::env::var_os("CAPSCR_FORCE_WEBVIEW_SELECTOR").is_some();
-    if pure_wayland && frames_oriented && !force_webview {
+    // the native selector has no fixed-frame mode, so presets use the webview
+    if pure_wayland && frames_oriented && !force_webview && fixed_size.is_none() {
         // debugging aid: map only the named output's selector surface
         let only_output = std::env::var("CAPSCR_SELECTOR_OUTPUT").ok();
         let outputs = surfaces
@@ -514,6 +545,7 @@ pub fn select(frozen_frame: Option<Arc<RgbaImage>>) -> SelectionResult {
         surfaces,
         ready: HashSet::new(),
         focus_label,
+        fixed_size,
         tx,
     });
 
@@ -693,6 +725,9 @@ pub struct SelectorContext {
     pub frame_width: u32,
     pub frame_height: u32,
     pub windows: Vec<WindowRect>,
+    // recording size preset in physical px; the frame follows the pointer
+    pub fixed_width: Option<u32>,
+    pub fixed_height: Option<u32>,
 }
 
 #[tauri::command]
@@ -710,6 +745,8 @@ pub fn selector_context(window: tauri::WebviewWindow) -> Result<SelectorContext,
         frame_width: surface.frame.width(),
         frame_height: surface.frame.height(),
         windows: surface.windows.clone(),
+        fixed_width: active.fixed_size.map(|size| size.0),
+        fixed_height: active.fixed_size.map(|size| size.1),
     })
 }
 
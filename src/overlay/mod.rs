@@ -3,6 +3,8 @@
 #[cfg(target_os = "linux")]
 pub mod linux;
 pub mod recording;
+#[cfg(windows)]
+mod uia;
 mod unified;
 #[cfg(target_os = "linux")]
 mod wayland_native_selector;
@@ -8,7 +8,7 @@ mod windows_impl {
     use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU64, Ordering};
     use std::sync::Mutex;
     use std::thread;
-    use std::time::Instant;
+    use std::time::{Duration, Instant};
     use windows::{
         core::PCWSTR,
         Win32::{
@@ -28,10 +28,11 @@ mod windows_impl {
                 CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, GetClientRect,
                 GetCursorPos, GetMessageW, KillTimer, LoadCursorW, PostMessageW, RegisterClassW,
                 SetCursor, SetLayeredWindowAttributes, SetTimer, SetWindowDisplayAffinity,
-                ShowWindow, TranslateMessage, CS_HREDRAW, CS_VREDRAW, IDC_ARROW, IDC_HAND,
-                LWA_COLORKEY, MSG, SW_HIDE, SW_SHOWNA, WDA_EXCLUDEFROMCAPTURE, WM_DESTROY,
-                WM_LBUTTONUP, WM_PAINT, WM_SETCURSOR, WM_TIMER, WM_USER, WNDCLASSW, WS_EX_LAYERED,
-                WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_TRANSPARENT, WS_POPUP,
+                SetWindowPos, ShowWindow, TranslateMessage, CS_HREDRAW, CS_VREDRAW, IDC_ARROW,
+                IDC_HAND, LWA_COLORKEY, MSG, SWP_NOACTIVATE, SWP_NOSIZE, SWP_NOZORDER, SW_HIDE,
+                SW_SHOWNA, WDA_EXCLUDEFROMCAPTURE, WM_DESTROY, WM_LBUTTONUP, WM_PAINT,
+                WM_SETCURSOR, WM_TIMER, WM_USER, WNDCLASSW, WS_EX_LAYERED, WS_EX_TOOLWINDOW,
+                WS_EX_TOPMOST, WS_EX_TRANSPARENT, WS_POPUP,
             },
         },
     };
@@ -93,6 +94,50 @@ mod windows_impl {
         }
     }
 
+    // follow-window recordings drag the border and the bar along with the window
+    pub fn move_to(region: Rectangle) {
+        if !RUNNING.load(Ordering::SeqCst) {
+            return;
+        }
+        REGION_X.store(region.x, Ordering::SeqCst);
+        REGION_Y.store(region.y, Ordering::SeqCst);
+        REGION_W.store(region.width as i32, Ordering::SeqCst);
+        REGION_H.store(region.height as i32, Ordering::SeqCst);
+        unsafe {
+            if let Some(hwnd) = *OVERLAY_HWND.lock().unwrap() {
+                let hwnd = HWND(hwnd as *mut _);
+                let _ = SetWindowPos(
+                    hwnd,
+                    None,
+                    region.x - BORDER_WIDTH,
+                    region.y - BORDER_WIDTH,
+                    region.width as i32 + BORDER_WIDTH * 2,
+                    region.height as i32 + BORDER_WIDTH * 2,
+                    SWP_NOACTIVATE | SWP_NOZORDER,
+                );
+                let _ = InvalidateRect(hwnd, None, true);
+            }
+            if let Some(bar) = *CONTROL_HWND.lock().unwrap() {
+                let region_rect = RECT {
+                    left: region.x,
+                    top: region.y,
+                    right: region.x + region.width as i32,
+                    bottom: region.y + region.height as i32,
+                };
+                let (bar_x, bar_y) = bar_placement(&region_rect, scaled(BAR_W), scaled(BAR_H));
+                let _ = SetWindowPos(
+                    HWND(bar as *mut _),
+                    None,
+                    bar_x,
+                    bar_y,
+                    0,
+                    0,
+                    SWP_NOACTIVATE | SWP_NOZORDER | SWP_NOSIZE,
+                );
+            }
+        }
+    }
+
     fn fire_stop_callback() {
         let cb = ON_STOP.lock().unwrap().take();
         if let Some(cb) = cb {
@@ -114,6 +159,17 @@ mod windows_impl {
         }
     }
 
+    // the clock turns red over the last seconds before the max duration
+    fn nears_limit() -> bool {
+        let elapsed = START_TIME
+            .lock()
+            .unwrap()
+            .map(|t| t.elapsed())
+            .unwrap_or_default();
+        let max = Duration::from_secs(MAX_SECS.load(Ordering::SeqCst));
+        crate::recording::nears_max_duration(elapsed, max)
+    }
+
     fn elapsed_label() -> Vec<u16> {
         let elapsed = START_TIME
             .lock()
@@ -446,7 +502,14 @@ mod windows_impl {
                 SetBkMode(hdc, TRANSPARENT);
 
                 // elapsed / max time
-                SetTextColor(hdc, COLORREF(0x00E6E6E6));
+                SetTextColor(
+                    hdc,
+                    if nears_limit() {
+                        COLORREF(0x003C3CE6)
+                    } else {
+                        COLORREF(0x00E6E6E6)
+                    },
+                );
                 let mut label = elapsed_label();
                 let stop = stop_button_rect(&client);
                 let mut time_rect = RECT {
@@ -965,6 +1028,7 @@ mod fallback_impl {
     use super::*;
 
     pub fn start(_region: Rectangle, _max_secs: u64, _on_stop: Box<dyn Fn() + Send>) {}
+    pub fn move_to(_region: Rectangle) {}
     pub fn stop() {}
 }
 
@@ -986,6 +1050,21 @@ impl RecordingOverlay {
         fallback_impl::start(region, max_secs, on_stop);
     }
 
+    #[cfg(windows)]
+    pub fn move_to(region: Rectangle) {
+        windows_impl::move_to(region);
+    }
+
+    // the gtk frame stays where the recording started; wayland gives no way
+    // to follow a window anyway
+    #[cfg(target_os = "linux")]
+    pub fn move_to(_region: Rectangle) {}
+
+    #[cfg(not(any(windows, target_os = "linux")))]
+    pub fn move_to(region: Rectangle) {
+        fallback_impl::move_to(region);
+    }
+
     #[cfg(windows)]
     pub fn stop() {
         windows_impl::stop();
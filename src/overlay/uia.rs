@@ -0,0 +1,91 @@
+// ui automation element lookup for the windows selector. hit-testing goes
+// through the hovered top-level window's element subtree rather than
+// ElementFromPoint, which would only ever find the selector overlay itself
+// (it's the topmost window covering the whole desktop)
+
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    System::Com::{
+        CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED,
+    },
+    UI::Accessibility::{CUIAutomation, IUIAutomation, IUIAutomationElement},
+};
+
+// deep enough for real control trees (browsers nest a couple dozen levels);
+// the bound keeps a pathological or cyclic provider from stalling a hover
+const MAX_DEPTH: usize = 32;
+// per-level sibling scan cap, same reasoning for flat lists with many items
+const MAX_SIBLINGS: usize = 512;
+// below this the highlight is smaller than the outline drawn around it
+const MIN_ELEMENT_SIZE: i32 = 6;
+
+thread_local! {
+    static AUTOMATION: Option<IUIAutomation> = unsafe {
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+        CoCreateInstance(&CUIAutomation, None, CLSCTX_INPROC_SERVER)
+            .map_err(|e| tracing::debug!("ui automation unavailable: {e}"))
+            .ok()
+    };
+}
+
+fn contains(rect: &RECT, x: i32, y: i32) -> bool {
+    x >= rect.left && x < rect.right && y >= rect.top && y < rect.bottom
+}
+
+fn usable(rect: &RECT) -> bool {
+    rect.right - rect.left >= MIN_ELEMENT_SIZE && rect.bottom - rect.top >= MIN_ELEMENT_SIZE
+}
+
+// the child of `parent` (in the control view) under the point, if any
+unsafe fn child_at(
+    automation: &IUIAutomation,
+    parent: &IUIAutomationElement,
+    x: i32,
+    y: i32,
+) -> Option<(IUIAutomationElement, RECT)> {
+    let walker = automation.ControlViewWalker().ok()?;
+    let mut child = walker.GetFirstChildElement(parent).ok();
+    let mut scanned = 0;
+    while let Some(element) = child {
+        if scanned >= MAX_SIBLINGS {
+            break;
+        }
+        scanned += 1;
+        let offscreen = element
+            .CurrentIsOffscreen()
+            .map(|b| b.as_bool())
+            .unwrap_or(true);
+        if !offscreen {
+            if let Ok(rect) = element.CurrentBoundingRectangle() {
+                if usable(&rect) && contains(&rect, x, y) {
+                    return Some((element, rect));
+                }
+            }
+        }
+        child = walker.GetNextSiblingElement(&element).ok();
+    }
+    None
+}
+
+/// bounds (left, top, right, bottom) of the innermost control under the point
+/// within the given top-level window, or None when automation finds nothing
+/// finer than the window itself
+pub fn element_rect_at(root: isize, x: i32, y: i32) -> Option<(i32, i32, i32, i32)> {
+    AUTOMATION.with(|automation| unsafe {
+        let automation = automation.as_ref()?;
+        let root_element = automation.ElementFromHandle(HWND(root as *mut _)).ok()?;
+        let root_rect = root_element.CurrentBoundingRectangle().ok()?;
+        let mut current = root_element;
+        let mut rect = root_rect;
+        for _ in 0..MAX_DEPTH {
+            match child_at(automation, &current, x, y) {
+                Some((element, child_rect)) => {
+                    current = element;
+                    rect = child_rect;
+                }
+                None => break,
+            }
+        }
+        (rect != root_rect).then_some((rect.left, rect.top, rect.right, rect.bottom))
+    })
+}
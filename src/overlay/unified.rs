@@ -1,8 +1,8 @@
 #![allow(dead_code)]
 
 use crate::capture::Rectangle;
-#[cfg(target_os = "linux")]
 use image::RgbaImage;
+use std::sync::atomic::{AtomicBool, Ordering};
 #[cfg(target_os = "linux")]
 use std::sync::Arc;
 
@@ -29,6 +29,81 @@ pub enum SelectionResult {
     PickedColor(u8, u8, u8),
 }
 
+// capture.selector_cursor_monitor, set before each selection: the overlay
+// covers only the monitor under the pointer instead of the whole desktop
+static CURSOR_MONITOR_ONLY: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn cursor_monitor_only() -> bool {
+    CURSOR_MONITOR_ONLY.load(Ordering::SeqCst)
+}
+
+// the part of a whole-desktop frame (top-left at `desktop_origin`) that
+// `monitor` shows. None when the two don't overlap
+pub(crate) fn crop_to_monitor(
+    frame: &RgbaImage,
+    desktop_origin: (i32, i32),
+    monitor: Rectangle,
+) -> Option<RgbaImage> {
+    let x = monitor.x.checked_sub(desktop_origin.0)?.max(0) as u32;
+    let y = monitor.y.checked_sub(desktop_origin.1)?.max(0) as u32;
+    let width = monitor.width.min(frame.width().saturating_sub(x));
+    let height = monitor.height.min(frame.height().saturating_sub(y));
+    (width > 0 && height > 0)
+        .then(|| image::imageops::crop_imm(frame, x, y, width, height).to_image())
+}
+
+// how close (in desktop px) a ctrl-dragged corner must come to a window edge
+// before it's pulled onto it
+pub(crate) const EDGE_SNAP_DISTANCE: i32 = 8;
+
+// snap a selection corner to the nearest window edge on each axis. an edge only
+// counts while the point is alongside it (within the window's span on the other
+// axis) so corners don't jump to edges of windows elsewhere on the desktop
+pub(crate) fn snap_to_window_edges(
+    x: i32,
+    y: i32,
+    windows: impl IntoIterator<Item = Rectangle>,
+) -> (i32, i32) {
+    let mut best_x = (EDGE_SNAP_DISTANCE + 1, x);
+    let mut best_y = (EDGE_SNAP_DISTANCE + 1, y);
+    for rect in windows {
+        let right = rect.x.saturating_add_unsigned(rect.width);
+        let bottom = rect.y.saturating_add_unsigned(rect.height);
+        let alongside_y = y >= rect.y - EDGE_SNAP_DISTANCE && y <= bottom + EDGE_SNAP_DISTANCE;
+        let alongside_x = x >= rect.x - EDGE_SNAP_DISTANCE && x <= right + EDGE_SNAP_DISTANCE;
+        if alongside_y {
+            for edge in [rect.x, right] {
+                let distance = (x - edge).abs();
+                if distance < best_x.0 {
+                    best_x = (distance, edge);
+                }
+            }
+        }
+        if alongside_x {
+            for edge in [rect.y, bottom] {
+                let distance = (y - edge).abs();
+                if distance < best_y.0 {
+                    best_y = (distance, edge);
+                }
+            }
+        }
+    }
+    (best_x.1, best_y.1)
+}
+
+// a fixed-size recording frame centred on the pointer and pushed back inside
+// `bounds` (the monitor under the pointer) so it never straddles a bezel. a
+// frame larger than the monitor is shrunk to fit
+pub(crate) fn fixed_frame_at(x: i32, y: i32, size: (u32, u32), bounds: Rectangle) -> Rectangle {
+    let width = size.0.min(bounds.width).max(1);
+    let height = size.1.min(bounds.height).max(1);
+    let max_x = bounds.x.saturating_add_unsigned(bounds.width - width);
+    let max_y = bounds.y.saturating_add_unsigned(bounds.height - height);
+    let left = x.saturating_sub_unsigned(width / 2).clamp(bounds.x, max_x);
+    let top = y.saturating_sub_unsigned(height / 2).clamp(bounds.y, max_y);
+    Rectangle::new(left, top, width, height)
+}
+
 #[cfg(windows)]
 mod windows_impl {
     use super::*;
@@ -54,6 +129,7 @@ mod windows_impl {
                 Threading::{AttachThreadInput, GetCurrentThreadId},
             },
             UI::{
+                HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI},
                 Input::KeyboardAndMouse::{
                     SetFocus, VK_CONTROL, VK_DOWN, VK_ESCAPE, VK_LEFT, VK_RETURN, VK_RIGHT,
                     VK_SHIFT, VK_SPACE, VK_UP,
@@ -113,6 +189,10 @@ mod windows_impl {
     static PICKED_R: AtomicU32 = AtomicU32::new(0);
     static PICKED_G: AtomicU32 = AtomicU32::new(0);
     static PICKED_B: AtomicU32 = AtomicU32::new(0);
+    // fixed recording frame size in physical px; 0 means a free drag. while
+    // set, the frame follows the pointer and a click or Enter commits it
+    static FIXED_W: AtomicU32 = AtomicU32::new(0);
+    static FIXED_H: AtomicU32 = AtomicU32::new(0);
 
     static SELECTOR_HWND: Mutex<Option<isize>> = Mutex::new(None);
 
@@ -237,6 +317,11 @@ mod windows_impl {
     static PREWARMED_WINDOWS: Mutex<Option<std::thread::JoinHandle<Vec<CachedWindow>>>> =
         Mutex::new(None);
     static HOVERED_WINDOW: AtomicU32 = AtomicU32::new(0);
+    // ui automation element under a ctrl-hover (left, top, right, bottom), when
+    // element detection is on. takes precedence over HOVERED_WINDOW for the
+    // highlight, and a click on it commits its bounds as a region
+    static HOVERED_ELEMENT: Mutex<Option<(i32, i32, i32, i32)>> = Mutex::new(None);
+    static DETECT_ELEMENTS: AtomicBool = AtomicBool::new(false);
     static CURSOR_X: AtomicI32 = AtomicI32::new(0);
     static CURSOR_Y: AtomicI32 = AtomicI32::new(0);
 
@@ -249,6 +334,18 @@ mod windows_impl {
         bottom: i32,
     }
 
+    // the process is per-monitor dpi aware, so the overlay draws in raw
+    // pixels and its fixed-size chrome (the loupe) has to be scaled by the dpi
+    // of the monitor it's on, or it shrinks to nothing on a 150% display
+    unsafe fn monitor_scale_at(pt: POINT) -> f32 {
+        let hmon = MonitorFromPoint(pt, MONITOR_DEFAULTTONEAREST);
+        let (mut dpi_x, mut dpi_y) = (96u32, 96u32);
+        if GetDpiForMonitor(hmon, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y).is_err() {
+            return 1.0;
+        }
+        dpi_x.max(96) as f32 / 96.0
+    }
+
     fn enumerate_windows() -> Vec<CachedWindow> {
         let mut windows = Vec::new();
         unsafe {
@@ -279,6 +376,14 @@ mod windows_impl {
             return BOOL(1);
         }
 
+        // capscr's own windows (an open editor, the selector itself) are
+        // never something to snap to
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if pid == std::process::id() {
+            return BOOL(1);
+        }
+
         // GetWindowRect includes the ~7px transparent DWM shadow extent;
         // DwmGetWindowAttribute(DWMWA_EXTENDED_FRAME_BOUNDS) gives the
         // tight visible rectangle the user actually perceives as "the
@@ -355,6 +460,38 @@ mod windows_impl {
         None
     }
 
+    fn snap_point_to_windows(pt: POINT) -> (i32, i32) {
+        let windows = WINDOW_LIST.lock().unwrap();
+        snap_to_window_edges(
+            pt.x,
+            pt.y,
+            windows.iter().map(|win| {
+                Rectangle::new(
+                    win.left,
+                    win.top,
+                    (win.right - win.left).max(0) as u32,
+                    (win.bottom - win.top).max(0) as u32,
+                )
+            }),
+        )
+    }
+
+    pub fn set_element_detection(enabled: bool) {
+        DETECT_ELEMENTS.store(enabled, Ordering::SeqCst);
+    }
+
+    // ctrl-hover target when element detection is on: the innermost automation
+    // element under the cursor, else the child-window fallback
+    fn hover_element_or_child(pt: POINT) -> (Option<CachedWindow>, Option<(i32, i32, i32, i32)>) {
+        let Some(top) = find_window_at_point(pt) else {
+            return (None, None);
+        };
+        match super::super::uia::element_rect_at(top.hwnd, pt.x, pt.y) {
+            Some(element) => (Some(top), Some(element)),
+            None => (find_child_window_at_point(pt), None),
+        }
+    }
+
     fn create_gdi_bitmap_from_image(img: &image::RgbaImage) -> Option<HBITMAP> {
         use windows::Win32::Graphics::Gdi::{
             CreateDIBSection, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
@@ -462,7 +599,61 @@ mod windows_impl {
             .ok()
     }
 
-    pub fn select(frozen_frame: Option<std::sync::Arc<image::RgbaImage>>) -> SelectionResult {
+    fn fixed_size() -> Option<(u32, u32)> {
+        let size = (
+            FIXED_W.load(Ordering::SeqCst),
+            FIXED_H.load(Ordering::SeqCst),
+        );
+        (size.0 > 0 && size.1 > 0).then_some(size)
+    }
+
+    // desktop rect of the monitor holding `pt`, or the nearest one
+    unsafe fn monitor_at(pt: POINT) -> Option<Rectangle> {
+        let hmon = MonitorFromPoint(pt, MONITOR_DEFAULTTONEAREST);
+        let mut mi = MONITORINFO {
+            cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+            ..Default::default()
+        };
+        GetMonitorInfoW(hmon, &mut mi).as_bool().then(|| {
+            Rectangle::normalize(
+                mi.rcMonitor.left,
+                mi.rcMonitor.top,
+                mi.rcMonitor.right,
+                mi.rcMonitor.bottom,
+            )
+        })
+    }
+
+    // park the fixed frame under the cursor, clamped to the cursor's monitor
+    unsafe fn place_fixed_frame(pt: POINT, size: (u32, u32)) {
+        let bounds = if let Some(monitor) = monitor_at(pt) {
+            monitor
+        } else {
+            Rectangle::new(
+                VIRTUAL_X.load(Ordering::SeqCst),
+                VIRTUAL_Y.load(Ordering::SeqCst),
+                SCREEN_WIDTH.load(Ordering::SeqCst).max(1) as u32,
+                SCREEN_HEIGHT.load(Ordering::SeqCst).max(1) as u32,
+            )
+        };
+        let frame = fixed_frame_at(pt.x, pt.y, size, bounds);
+        START_X.store(frame.x, Ordering::SeqCst);
+        START_Y.store(frame.y, Ordering::SeqCst);
+        END_X.store(
+            frame.x.saturating_add_unsigned(frame.width),
+            Ordering::SeqCst,
+        );
+        END_Y.store(
+            frame.y.saturating_add_unsigned(frame.height),
+            Ordering::SeqCst,
+        );
+        DRAG_STARTED.store(true, Ordering::SeqCst);
+    }
+
+    pub fn select(
+        frozen_frame: Option<std::sync::Arc<image::RgbaImage>>,
+        fixed_size: Option<(u32, u32)>,
+    ) -> SelectionResult {
         // single-flight: the windows_impl module backs the entire selector with
         // process-wide statics (START_X / SCREEN_BITMAP / etc.). A second
         // simultaneous select() call from e.g. tray-click while a hotkey-bound
@@ -485,16 +676,46 @@ mod windows_impl {
         FULLSCREEN.store(false, Ordering::SeqCst);
         WINDOW_SELECTED.store(0, Ordering::SeqCst);
         HOVERED_WINDOW.store(0, Ordering::SeqCst);
+        *HOVERED_ELEMENT.lock().unwrap() = None;
         PICKED_COLOR_SET.store(false, Ordering::SeqCst);
+        let (fixed_w, fixed_h) = fixed_size.unwrap_or_default();
+        FIXED_W.store(fixed_w, Ordering::SeqCst);
+        FIXED_H.store(fixed_h, Ordering::SeqCst);
 
         let windows = take_window_list();
         *WINDOW_LIST.lock().unwrap() = windows;
 
         unsafe {
-            let virt_x = GetSystemMetrics(SM_XVIRTUALSCREEN);
-            let virt_y = GetSystemMetrics(SM_YVIRTUALSCREEN);
-            let virt_width = GetSystemMetrics(SM_CXVIRTUALSCREEN);
-            let virt_height = GetSystemMetrics(SM_CYVIRTUALSCREEN);
+            let desktop_x = GetSystemMetrics(SM_XVIRTUALSCREEN);
+            let desktop_y = GetSystemMetrics(SM_YVIRTUALSCREEN);
+            // confined to the pointer's monitor, the overlay window, its
+            // bitmaps and the frozen frame they're built from all shrink to
+            // that one screen; everything after works off these bounds
+            let mut frozen_frame = frozen_frame;
+            let mut pt = POINT::default();
+            let monitor = (super::cursor_monitor_only() && GetCursorPos(&mut pt).is_ok())
+                .then(|| monitor_at(pt))
+                .flatten();
+            let (virt_x, virt_y, virt_width, virt_height) = match monitor {
+                Some(monitor) => {
+                    frozen_frame = frozen_frame.and_then(|frame| {
+                        super::crop_to_monitor(&frame, (desktop_x, desktop_y), monitor)
+                            .map(std::sync::Arc::new)
+                    });
+                    (
+                        monitor.x,
+                        monitor.y,
+                        monitor.width as i32,
+                        monitor.height as i32,
+                    )
+                }
+                None => (
+                    desktop_x,
+                    desktop_y,
+                    GetSystemMetrics(SM_CXVIRTUALSCREEN),
+                    GetSystemMetrics(SM_CYVIRTUALSCREEN),
+                ),
+            };
 
             VIRTUAL_X.store(virt_x, Ordering::SeqCst);
             VIRTUAL_Y.store(virt_y, Ordering::SeqCst);
@@ -745,6 +966,12 @@ mod windows_impl {
             );
 
             let _ = ShowWindow(hwnd, SW_SHOWNORMAL);
+            if let Some(size) = fixed_size {
+                let mut pt = POINT::default();
+                if GetCursorPos(&mut pt).is_ok() {
+                    place_fixed_frame(pt, size);
+                }
+            }
             // grab foreground + keyboard focus so Escape, the arrow nudges, and
             // Enter reach WM_KEYDOWN. the hotkey that spawned us was swallowed by
             // the low-level hook, so Windows can refuse a bare SetForegroundWindow
@@ -792,6 +1019,8 @@ mod windows_impl {
             }
 
             WINDOW_LIST.lock().unwrap().clear();
+            FIXED_W.store(0, Ordering::SeqCst);
+            FIXED_H.store(0, Ordering::SeqCst);
 
             if CANCELLED.load(Ordering::SeqCst) {
                 return SelectionResult::Cancelled;
@@ -818,7 +1047,7 @@ mod windows_impl {
             let ex = END_X.load(Ordering::SeqCst);
             let mut ey = END_Y.load(Ordering::SeqCst);
 
-            if SHIFT_AT_COMMIT.load(Ordering::SeqCst) {
+            if SHIFT_AT_COMMIT.load(Ordering::SeqCst) && fixed_size.is_none() {
                 let dx = ex - sx;
                 let dy = ey - sy;
                 let w = dx.abs() as f64;
@@ -924,7 +1153,7 @@ mod windows_impl {
                 let ex = END_X.load(Ordering::SeqCst);
                 let mut ey = END_Y.load(Ordering::SeqCst);
 
-                if shift_held() {
+                if shift_held() && fixed_size().is_none() {
                     let dx = ex - sx;
                     let dy = ey - sy;
                     let w = dx.abs() as f64;
@@ -1021,17 +1250,28 @@ mod windows_impl {
                     let _ = TextOutW(back_dc, text_x, text_y, &text_wide);
                 } else if !mouse_down && !has_selection {
                     let hovered = HOVERED_WINDOW.load(Ordering::SeqCst);
-                    if hovered != 0 {
-                        let hwnd = HWND(hovered as usize as *mut _);
+                    let element = *HOVERED_ELEMENT.lock().unwrap();
+                    if hovered != 0 || element.is_some() {
                         let mut rect = RECT::default();
-                        let dwm_ok = DwmGetWindowAttribute(
-                            hwnd,
-                            DWMWA_EXTENDED_FRAME_BOUNDS,
-                            &mut rect as *mut RECT as *mut _,
-                            std::mem::size_of::<RECT>() as u32,
-                        )
-                        .is_ok();
-                        let rect_ok = dwm_ok || GetWindowRect(hwnd, &mut rect).is_ok();
+                        let rect_ok = if let Some((left, top, right, bottom)) = element {
+                            rect = RECT {
+                                left,
+                                top,
+                                right,
+                                bottom,
+                            };
+                            true
+                        } else {
+                            let hwnd = HWND(hovered as usize as *mut _);
+                            let dwm_ok = DwmGetWindowAttribute(
+                                hwnd,
+                                DWMWA_EXTENDED_FRAME_BOUNDS,
+                                &mut rect as *mut RECT as *mut _,
+                                std::mem::size_of::<RECT>() as u32,
+                            )
+                            .is_ok();
+                            dwm_ok || GetWindowRect(hwnd, &mut rect).is_ok()
+                        };
                         if rect_ok {
                             let left = rect.left - virt_x;
                             let top = rect.top - virt_y;
@@ -1119,17 +1359,20 @@ mod windows_impl {
                             let mem_dc = HDC(dc as *mut _);
                             let old_bmp = SelectObject(mem_dc, HBITMAP(bmp as *mut _));
 
-                            let mag_x = cursor_x + 30;
-                            let mag_y = cursor_y + 30;
+                            let cursor_pt = POINT {
+                                x: CURSOR_X.load(Ordering::SeqCst),
+                                y: CURSOR_Y.load(Ordering::SeqCst),
+                            };
+                            let scale = monitor_scale_at(cursor_pt);
+                            let mag_size = (MAGNIFIER_SIZE as f32 * scale).round() as i32;
+                            let mag_gap = (30.0 * scale).round() as i32;
+                            let mag_x = cursor_x + mag_gap;
+                            let mag_y = cursor_y + mag_gap;
                             // flip against the bounds of the monitor the cursor is
                             // on, not the whole virtual desktop, so the loupe never
                             // straddles a bezel onto a neighbouring monitor when
                             // the cursor nears a non-outermost monitor edge
                             let (mon_right, mon_bottom, mon_left, mon_top) = {
-                                let cursor_pt = POINT {
-                                    x: CURSOR_X.load(Ordering::SeqCst),
-                                    y: CURSOR_Y.load(Ordering::SeqCst),
-                                };
                                 let hmon = MonitorFromPoint(cursor_pt, MONITOR_DEFAULTTONEAREST);
                                 let mut mi = MONITORINFO {
                                     cbSize: std::mem::size_of::<MONITORINFO>() as u32,
@@ -1146,34 +1389,25 @@ mod windows_impl {
                                     (width, height, 0, 0)
                                 }
                             };
-                            let mag_x = if mag_x + MAGNIFIER_SIZE > mon_right {
-                                (cursor_x - MAGNIFIER_SIZE - 30).max(mon_left)
+                            let mag_x = if mag_x + mag_size > mon_right {
+                                (cursor_x - mag_size - mag_gap).max(mon_left)
                             } else {
                                 mag_x
                             };
-                            let mag_y = if mag_y + MAGNIFIER_SIZE > mon_bottom {
-                                (cursor_y - MAGNIFIER_SIZE - 30).max(mon_top)
+                            let mag_y = if mag_y + mag_size > mon_bottom {
+                                (cursor_y - mag_size - mag_gap).max(mon_top)
                             } else {
                                 mag_y
                             };
 
                             let zoom = MAGNIFIER_ZOOM.load(Ordering::Relaxed).max(1);
-                            let src_size = MAGNIFIER_SIZE / zoom;
+                            let src_size = mag_size / zoom;
                             let src_x = (cursor_x - src_size / 2).max(0).min(width - src_size);
                             let src_y = (cursor_y - src_size / 2).max(0).min(height - src_size);
 
                             let _ = StretchBlt(
-                                back_dc,
-                                mag_x,
-                                mag_y,
-                                MAGNIFIER_SIZE,
-                                MAGNIFIER_SIZE,
-                                mem_dc,
-                                src_x,
-                                src_y,
-                                src_size,
-                                src_size,
-                                SRCCOPY,
+                                back_dc, mag_x, mag_y, mag_size, mag_size, mem_dc, src_x, src_y,
+                                src_size, src_size, SRCCOPY,
                             );
 
                             SelectObject(mem_dc, old_bmp);
@@ -1190,8 +1424,8 @@ mod windows_impl {
                                 back_dc,
                                 mag_x,
                                 mag_y,
-                                mag_x + MAGNIFIER_SIZE,
-                                mag_y + MAGNIFIER_SIZE,
+                                mag_x + mag_size,
+                                mag_y + mag_size,
                             );
 
                             let center_pen = CreatePen(
@@ -1200,8 +1434,8 @@ mod windows_impl {
                                 windows::Win32::Foundation::COLORREF(0x00808080),
                             );
                             SelectObject(back_dc, center_pen);
-                            let cx = mag_x + MAGNIFIER_SIZE / 2;
-                            let cy = mag_y + MAGNIFIER_SIZE / 2;
+                            let cx = mag_x + mag_size / 2;
+                            let cy = mag_y + mag_size / 2;
                             let _ =
                                 windows::Win32::Graphics::Gdi::MoveToEx(back_dc, cx - 10, cy, None);
                             let _ = windows::Win32::Graphics::Gdi::LineTo(back_dc, cx + 10, cy);
@@ -1234,15 +1468,32 @@ mod windows_impl {
                 CURSOR_X.store(pt.x, Ordering::SeqCst);
                 CURSOR_Y.store(pt.y, Ordering::SeqCst);
 
-                if mouse_down {
-                    END_X.store(pt.x, Ordering::SeqCst);
-                    END_Y.store(pt.y, Ordering::SeqCst);
+                if let Some(size) = fixed_size() {
+                    place_fixed_frame(pt, size);
+                } else if mouse_down {
+                    // ctrl-drag pulls the corner onto nearby window edges. a
+                    // cursor already sitting on END is the echo of an arrow
+                    // nudge's SetCursorPos, left alone so 1px nudges stick
+                    let echo = pt.x == END_X.load(Ordering::SeqCst)
+                        && pt.y == END_Y.load(Ordering::SeqCst);
+                    let (x, y) = if ctrl_held() && !echo {
+                        snap_point_to_windows(pt)
+                    } else {
+                        (pt.x, pt.y)
+                    };
+                    END_X.store(x, Ordering::SeqCst);
+                    END_Y.store(y, Ordering::SeqCst);
                 } else {
-                    let cached_opt = if ctrl_held() {
-                        find_child_window_at_point(pt)
+                    let (cached_opt, element) = if ctrl_held() {
+                        if DETECT_ELEMENTS.load(Ordering::SeqCst) {
+                            hover_element_or_child(pt)
+                        } else {
+                            (find_child_window_at_point(pt), None)
+                        }
                     } else {
-                        find_window_at_point(pt)
+                        (find_window_at_point(pt), None)
                     };
+                    *HOVERED_ELEMENT.lock().unwrap() = element;
                     if let Some(cached) = cached_opt {
                         HOVERED_WINDOW.store(cached.hwnd as u32, Ordering::SeqCst);
                     } else {
@@ -1281,20 +1532,41 @@ mod windows_impl {
                     PostQuitMessage(0);
                     return LRESULT(0);
                 }
-                START_X.store(pt.x, Ordering::SeqCst);
-                START_Y.store(pt.y, Ordering::SeqCst);
-                END_X.store(pt.x, Ordering::SeqCst);
-                END_Y.store(pt.y, Ordering::SeqCst);
+                if fixed_size().is_some() {
+                    // the frame already sits under the cursor; commit on release
+                    MOUSE_DOWN.store(true, Ordering::SeqCst);
+                    return LRESULT(0);
+                }
+                let (x, y) = if ctrl_held() {
+                    snap_point_to_windows(pt)
+                } else {
+                    (pt.x, pt.y)
+                };
+                START_X.store(x, Ordering::SeqCst);
+                START_Y.store(y, Ordering::SeqCst);
+                END_X.store(x, Ordering::SeqCst);
+                END_Y.store(y, Ordering::SeqCst);
                 MOUSE_DOWN.store(true, Ordering::SeqCst);
                 DRAG_STARTED.store(true, Ordering::SeqCst);
                 LRESULT(0)
             }
             WM_LBUTTONUP => {
-                if MOUSE_DOWN.load(Ordering::SeqCst) {
+                if fixed_size().is_some() {
+                    if MOUSE_DOWN.swap(false, Ordering::SeqCst) {
+                        SHIFT_AT_COMMIT.store(false, Ordering::SeqCst);
+                        SELECTING.store(false, Ordering::SeqCst);
+                        PostQuitMessage(0);
+                    }
+                } else if MOUSE_DOWN.load(Ordering::SeqCst) {
                     let mut pt = POINT::default();
                     GetCursorPos(&mut pt).ok();
-                    END_X.store(pt.x, Ordering::SeqCst);
-                    END_Y.store(pt.y, Ordering::SeqCst);
+                    let (x, y) = if ctrl_held() {
+                        snap_point_to_windows(pt)
+                    } else {
+                        (pt.x, pt.y)
+                    };
+                    END_X.store(x, Ordering::SeqCst);
+                    END_Y.store(y, Ordering::SeqCst);
                     MOUSE_DOWN.store(false, Ordering::SeqCst);
                     // remember the aspect-snap modifier at release time so the
                     // committed rect matches the last painted (snapped) one
@@ -1308,7 +1580,23 @@ mod windows_impl {
                     let dx = (ex - sx).abs();
                     let dy = (ey - sy).abs();
 
+                    let element = if ctrl_held() {
+                        *HOVERED_ELEMENT.lock().unwrap()
+                    } else {
+                        None
+                    };
                     if dx <= CLICK_THRESHOLD && dy <= CLICK_THRESHOLD {
+                        if let Some((left, top, right, bottom)) = element {
+                            // a detected control commits as a region of its bounds
+                            START_X.store(left, Ordering::SeqCst);
+                            START_Y.store(top, Ordering::SeqCst);
+                            END_X.store(right, Ordering::SeqCst);
+                            END_Y.store(bottom, Ordering::SeqCst);
+                            SHIFT_AT_COMMIT.store(false, Ordering::SeqCst);
+                            SELECTING.store(false, Ordering::SeqCst);
+                            PostQuitMessage(0);
+                            return LRESULT(0);
+                        }
                         let cached_opt = if ctrl_held() {
                             find_child_window_at_point(pt)
                         } else {
@@ -1352,6 +1640,19 @@ mod windows_impl {
                     SHIFT_AT_COMMIT.store(shift_held(), Ordering::SeqCst);
                     SELECTING.store(false, Ordering::SeqCst);
                     PostQuitMessage(0);
+                } else if let Some(size) = fixed_size().filter(|_| {
+                    [VK_LEFT, VK_RIGHT, VK_UP, VK_DOWN]
+                        .iter()
+                        .any(|vk| key == vk.0 as i32)
+                }) {
+                    // arrows nudge the fixed frame a pixel at a time
+                    let mut pt = POINT::default();
+                    let _ = GetCursorPos(&mut pt);
+                    pt.x += (key == VK_RIGHT.0 as i32) as i32 - (key == VK_LEFT.0 as i32) as i32;
+                    pt.y += (key == VK_DOWN.0 as i32) as i32 - (key == VK_UP.0 as i32) as i32;
+                    let _ = SetCursorPos(pt.x, pt.y);
+                    place_fixed_frame(pt, size);
+                    let _ = InvalidateRect(hwnd, None, false);
                 } else if key == VK_LEFT.0 as i32
                     || key == VK_RIGHT.0 as i32
                     || key == VK_UP.0 as i32
@@ -1619,7 +1920,10 @@ mod windows_impl {
 mod fallback_impl {
     use super::*;
 
-    pub fn select(_frozen_frame: Option<std::sync::Arc<image::RgbaImage>>) -> SelectionResult {
+    pub fn select(
+        _frozen_frame: Option<std::sync::Arc<image::RgbaImage>>,
+        _fixed_size: Option<(u32, u32)>,
+    ) -> SelectionResult {
         SelectionResult::FullScreen
     }
 }
@@ -1627,19 +1931,38 @@ mod fallback_impl {
 pub struct UnifiedSelector;
 
 impl UnifiedSelector {
-    #[cfg(windows)]
     pub fn select(frozen_frame: Option<std::sync::Arc<image::RgbaImage>>) -> SelectionResult {
-        windows_impl::select(frozen_frame)
+        Self::select_sized(frozen_frame, None)
+    }
+
+    /// region selection with a fixed-size frame (physical px) that follows the
+    /// pointer instead of a free drag; used by recording size presets
+    pub fn select_fixed(size: (u32, u32)) -> SelectionResult {
+        Self::select_sized(None, Some(size))
+    }
+
+    #[cfg(windows)]
+    fn select_sized(
+        frozen_frame: Option<std::sync::Arc<image::RgbaImage>>,
+        fixed_size: Option<(u32, u32)>,
+    ) -> SelectionResult {
+        windows_impl::select(frozen_frame, fixed_size)
     }
 
     #[cfg(target_os = "linux")]
-    pub fn select(frozen_frame: Option<std::sync::Arc<image::RgbaImage>>) -> SelectionResult {
-        super::linux::select(frozen_frame)
+    fn select_sized(
+        frozen_frame: Option<std::sync::Arc<image::RgbaImage>>,
+        fixed_size: Option<(u32, u32)>,
+    ) -> SelectionResult {
+        super::linux::select(frozen_frame, fixed_size)
     }
 
     #[cfg(not(any(windows, target_os = "linux")))]
-    pub fn select(frozen_frame: Option<std::sync::Arc<image::RgbaImage>>) -> SelectionResult {
-        fallback_impl::select(frozen_frame)
+    fn select_sized(
+        frozen_frame: Option<std::sync::Arc<image::RgbaImage>>,
+        fixed_size: Option<(u32, u32)>,
+    ) -> SelectionResult {
+        fallback_impl::select(frozen_frame, fixed_size)
     }
 
     #[cfg(windows)]
@@ -1685,4 +2008,87 @@ impl UnifiedSelector {
 
     #[cfg(not(any(windows, target_os = "linux")))]
     pub fn prewarm_window_list() {}
+
+    /// opt the next selections into ui automation element hover (ctrl-hover
+    /// highlights individual controls). windows only; a no-op elsewhere
+    #[cfg(windows)]
+    pub fn set_element_detection(enabled: bool) {
+        windows_impl::set_element_detection(enabled);
+    }
+
+    #[cfg(not(windows))]
+    pub fn set_element_detection(_enabled: bool) {}
+
+    /// keep the next selection on the monitor under the pointer
+    pub fn set_cursor_monitor_only(enabled: bool) {
+        CURSOR_MONITOR_ONLY.store(enabled, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_desktop_frame_crops_to_one_monitor() {
+        // two 100x50 monitors side by side, the left one at x -100
+        let mut frame = RgbaImage::new(200, 50);
+        frame.put_pixel(100, 0, image::Rgba([255, 0, 0, 255]));
+        let right = Rectangle::new(0, 0, 100, 50);
+        let cropped = crop_to_monitor(&frame, (-100, 0), right).unwrap();
+        assert_eq!(cropped.dimensions(), (100, 50));
+        assert_eq!(cropped.get_pixel(0, 0), &image::Rgba([255, 0, 0, 255]));
+        // a monitor the frame doesn't reach has nothing to show
+        assert!(crop_to_monitor(&frame, (-100, 0), Rectangle::new(200, 0, 100, 50)).is_none());
+    }
+
+    #[test]
+    fn corners_snap_to_nearby_window_edges() {
+        let windows = [Rectangle::new(100, 100, 400, 300)];
+        assert_eq!(snap_to_window_edges(495, 203, windows), (500, 203));
+        assert_eq!(snap_to_window_edges(104, 396, windows), (100, 400));
+        // out of range on both axes stays put
+        assert_eq!(snap_to_window_edges(300, 250, windows), (300, 250));
+    }
+
+    #[test]
+    fn edges_only_snap_when_alongside_the_window() {
+        let windows = [Rectangle::new(100, 100, 400, 300)];
+        // near the left edge's x but far below the window
+        assert_eq!(snap_to_window_edges(103, 900, windows), (103, 900));
+    }
+
+    #[test]
+    fn nearest_edge_wins_across_windows() {
+        let windows = [
+            Rectangle::new(0, 0, 500, 500),
+            Rectangle::new(503, 0, 500, 500),
+        ];
+        assert_eq!(snap_to_window_edges(502, 250, windows), (503, 250));
+    }
+
+    #[test]
+    fn fixed_frames_centre_on_the_pointer_and_stay_on_the_monitor() {
+        let monitor = Rectangle::new(0, 0, 1920, 1080);
+        assert_eq!(
+            fixed_frame_at(960, 540, (1280, 720), monitor),
+            Rectangle::new(320, 180, 1280, 720)
+        );
+        assert_eq!(
+            fixed_frame_at(10, 1075, (1280, 720), monitor),
+            Rectangle::new(0, 360, 1280, 720)
+        );
+        // a secondary monitor to the left of the primary
+        let left = Rectangle::new(-1920, 0, 1920, 1080);
+        assert_eq!(
+            fixed_frame_at(-5, 540, (1280, 720), left),
+            Rectangle::new(-1280, 180, 1280, 720)
+        );
+        // larger than the monitor shrinks to it
+        let small = Rectangle::new(0, 0, 1366, 768);
+        assert_eq!(
+            fixed_frame_at(700, 400, (1920, 1080), small),
+            Rectangle::new(0, 0, 1366, 768)
+        );
+    }
 }
@@ -567,7 +567,7 @@ impl State {
             DragPhase::Dragging { start, .. } => {
                 self.phase = DragPhase::Dragging {
                     start,
-                    end: (self.pointer_x, self.pointer_y),
+                    end: self.snapped_pointer(),
                 };
             }
             DragPhase::Standing { .. } => {}
@@ -589,6 +589,21 @@ impl State {
         self.repaint();
     }
 
+    // the pointer, pulled onto nearby window edges while ctrl is held
+    fn snapped_pointer(&self) -> (f64, f64) {
+        if !self.ctrl {
+            return (self.pointer_x, self.pointer_y);
+        }
+        let (x, y) = super::unified::snap_to_window_edges(
+            self.pointer_x.round() as i32,
+            self.pointer_y.round() as i32,
+            self.outputs
+                .iter()
+                .flat_map(|output| output.windows.iter().map(|window| window.rect)),
+        );
+        (x as f64, y as f64)
+    }
+
     // the current outline, derived from the drag phase or the hovered window
     fn repaint(&mut self) {
         let outline = match self.phase {
@@ -696,9 +711,10 @@ impl State {
                     return;
                 }
                 // a fresh press replaces any standing selection
+                let point = self.snapped_pointer();
                 self.phase = DragPhase::Dragging {
-                    start: (self.pointer_x, self.pointer_y),
-                    end: (self.pointer_x, self.pointer_y),
+                    start: point,
+                    end: point,
                 };
                 self.repaint();
             }
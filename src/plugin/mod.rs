@@ -46,7 +46,11 @@ impl PluginEvent {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+// also keys the per-type default post-capture actions in the config
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
 pub enum CaptureType {
     FullScreen,
     Window,
@@ -54,6 +58,26 @@ pub enum CaptureType {
     Gif,
 }
 
+impl CaptureType {
+    pub fn all() -> &'static [CaptureType] {
+        &[
+            CaptureType::Region,
+            CaptureType::Window,
+            CaptureType::FullScreen,
+            CaptureType::Gif,
+        ]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CaptureType::FullScreen => "Full screen",
+            CaptureType::Window => "Window",
+            CaptureType::Region => "Region",
+            CaptureType::Gif => "Recording",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum PluginResponse {
     Continue,
@@ -196,8 +220,8 @@ impl PluginManager {
             if !plugin.wants_capture() {
                 continue;
             }
-            let blob = build_capture_blob(&current, mode);
-            match plugin.call_capture_hook(&blob) {
+            let header = capture_blob_header(&current, mode);
+            match plugin.call_capture_hook(&header, current.as_raw()) {
                 Ok(wasm::CaptureOutcome::Continue) => {}
                 Ok(wasm::CaptureOutcome::Cancel) => return PluginResponse::Cancel,
                 Ok(wasm::CaptureOutcome::Modified(img)) => {
@@ -220,17 +244,16 @@ impl PluginManager {
     }
 }
 
-/// pack a capture for the on_capture hook: `[w:u32 LE][h:u32 LE][mode:u32 LE][rgba…]`.
+/// header of the on_capture blob `[w:u32 LE][h:u32 LE][mode:u32 LE][rgba…]`;
+/// the rgba follows straight from the image's own buffer.
 /// mode mirrors CaptureType's discriminants (FullScreen=0, Window=1, Region=2, Gif=3)
 #[cfg(feature = "plugin-runtime")]
-fn build_capture_blob(image: &RgbaImage, mode: CaptureType) -> Vec<u8> {
-    let raw = image.as_raw();
-    let mut blob = Vec::with_capacity(12 + raw.len());
-    blob.extend_from_slice(&image.width().to_le_bytes());
-    blob.extend_from_slice(&image.height().to_le_bytes());
-    blob.extend_from_slice(&(mode as u32).to_le_bytes());
-    blob.extend_from_slice(raw);
-    blob
+fn capture_blob_header(image: &RgbaImage, mode: CaptureType) -> [u8; 12] {
+    let mut header = [0u8; 12];
+    header[0..4].copy_from_slice(&image.width().to_le_bytes());
+    header[4..8].copy_from_slice(&image.height().to_le_bytes());
+    header[8..12].copy_from_slice(&(mode as u32).to_le_bytes());
+    header
 }
 
 impl Default for PluginManager {
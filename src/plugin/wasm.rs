@@ -591,8 +591,10 @@ impl WasmPlugin {
     /// deliver the capture blob ([w:u32][h:u32][mode:u32][rgba]) to on_capture
     /// and decode the i64 response. cancel/replace are honoured only with the
     /// image:modify capability; anything malformed degrades to Continue so a
-    /// buggy or hostile plugin can never corrupt or silently drop a capture
-    pub fn call_capture_hook(&self, blob: &[u8]) -> Result<CaptureOutcome> {
+    /// buggy or hostile plugin can never corrupt or silently drop a capture.
+    /// header and pixels are written into guest memory back to back, so the
+    /// host never assembles a second copy of the frame
+    pub fn call_capture_hook(&self, header: &[u8; 12], pixels: &[u8]) -> Result<CaptureOutcome> {
         let hook = match self.capture_hook.as_ref() {
             Some(h) => h,
             None => return Ok(CaptureOutcome::Continue),
@@ -604,7 +606,8 @@ impl WasmPlugin {
             .alloc
             .as_ref()
             .ok_or_else(|| anyhow!("plugin '{}' has no capscr_alloc export", self.id))?;
-        if blob.len() > i32::MAX as usize {
+        let blob_len = header.len() + pixels.len();
+        if blob_len > i32::MAX as usize {
             return Err(anyhow!("capture blob too large for the guest"));
         }
 
@@ -618,7 +621,7 @@ impl WasmPlugin {
         store.set_epoch_deadline(deadline);
         store.data_mut().fetch_deadline = Some(std::time::Instant::now() + FETCH_HOOK_BUDGET);
 
-        let len = blob.len() as i32;
+        let len = blob_len as i32;
         let ptr = alloc
             .call(&mut *store, len)
             .map_err(|e| anyhow!("capscr_alloc({len}): {e}"))?;
@@ -626,7 +629,10 @@ impl WasmPlugin {
             return Err(anyhow!("capscr_alloc returned {ptr} (out of memory?)"));
         }
         self.memory
-            .write(&mut *store, ptr as usize, blob)
+            .write(&mut *store, ptr as usize, header)
+            .map_err(|e| anyhow!("memory write: {e}"))?;
+        self.memory
+            .write(&mut *store, ptr as usize + header.len(), pixels)
             .map_err(|e| anyhow!("memory write: {e}"))?;
 
         let ret = hook
@@ -1196,14 +1202,13 @@ mod tests {
         (dir, plugin)
     }
 
-    // a 2x2 region capture blob: [w=2][h=2][mode=2][16 rgba bytes]
-    fn sample_blob() -> Vec<u8> {
-        let mut b = Vec::new();
-        b.extend_from_slice(&2u32.to_le_bytes());
-        b.extend_from_slice(&2u32.to_le_bytes());
-        b.extend_from_slice(&2u32.to_le_bytes());
-        b.extend_from_slice(&[0u8; 16]);
-        b
+    // a 2x2 region capture: header [w=2][h=2][mode=2], then 16 rgba bytes
+    fn sample_header() -> [u8; 12] {
+        let mut h = [0u8; 12];
+        h[0..4].copy_from_slice(&2u32.to_le_bytes());
+        h[4..8].copy_from_slice(&2u32.to_le_bytes());
+        h[8..12].copy_from_slice(&2u32.to_le_bytes());
+        h
     }
 
     const CONTINUE_WAT: &str = r#"
@@ -1234,7 +1239,7 @@ mod tests {
     fn capture_continue_is_continue() {
         let (_d, p) = load_capture_plugin(CONTINUE_WAT, caps_map(&[("image", &["read"])]));
         assert!(matches!(
-            p.call_capture_hook(&sample_blob()).unwrap(),
+            p.call_capture_hook(&sample_header(), &[0u8; 16]).unwrap(),
             CaptureOutcome::Continue
         ));
     }
@@ -1243,7 +1248,7 @@ mod tests {
     fn capture_cancel_honoured_with_modify_cap() {
         let (_d, p) = load_capture_plugin(CANCEL_WAT, caps_map(&[("image", &["read", "modify"])]));
         assert!(matches!(
-            p.call_capture_hook(&sample_blob()).unwrap(),
+            p.call_capture_hook(&sample_header(), &[0u8; 16]).unwrap(),
             CaptureOutcome::Cancel
         ));
     }
@@ -1252,7 +1257,7 @@ mod tests {
     fn capture_cancel_ignored_without_modify_cap() {
         let (_d, p) = load_capture_plugin(CANCEL_WAT, caps_map(&[("image", &["read"])]));
         assert!(matches!(
-            p.call_capture_hook(&sample_blob()).unwrap(),
+            p.call_capture_hook(&sample_header(), &[0u8; 16]).unwrap(),
             CaptureOutcome::Continue
         ));
     }
@@ -1260,7 +1265,7 @@ mod tests {
     #[test]
     fn capture_modify_returns_replacement_image() {
         let (_d, p) = load_capture_plugin(MODIFY_WAT, caps_map(&[("image", &["read", "modify"])]));
-        let out = p.call_capture_hook(&sample_blob()).unwrap();
+        let out = p.call_capture_hook(&sample_header(), &[0u8; 16]).unwrap();
         let CaptureOutcome::Modified(img) = out else {
             panic!("expected Modified");
         };
@@ -1273,7 +1278,7 @@ mod tests {
     fn capture_modify_ignored_without_modify_cap() {
         let (_d, p) = load_capture_plugin(MODIFY_WAT, caps_map(&[("image", &["read"])]));
         assert!(matches!(
-            p.call_capture_hook(&sample_blob()).unwrap(),
+            p.call_capture_hook(&sample_header(), &[0u8; 16]).unwrap(),
             CaptureOutcome::Continue
         ));
     }
@@ -1284,7 +1289,7 @@ mod tests {
         let (_d, p) = load_capture_plugin(MODIFY_WAT, HashMap::new());
         assert!(!p.wants_capture());
         assert!(matches!(
-            p.call_capture_hook(&sample_blob()).unwrap(),
+            p.call_capture_hook(&sample_header(), &[0u8; 16]).unwrap(),
             CaptureOutcome::Continue
         ));
     }
@@ -0,0 +1,108 @@
+// capscr:// links. a page or a doc can link `capscr://capture?mode=region` to
+// start a capture; the OS launches capscr with the url as its argument, which
+// tauri-plugin-single-instance forwards to the running process like a jump
+// list `--jump=` arg (see main.rs). a link can start a capture mode or open
+// the hub, nothing more: tasks and window-title matches stay out of reach of
+// any page that can put a link in front of the user. a capture a link starts
+// never leaves the machine either: some modes need no click at all, so the
+// configured post action could otherwise upload the screen on a page's say-so.
+
+pub const SCHEME: &str = "capscr";
+
+/// marks a jump kind as coming from a link rather than the jump list or cli
+pub const LINK_PREFIX: &str = "link:";
+
+const CAPTURE_MODES: &[&str] = &["region", "region-last", "window", "fullscreen"];
+
+pub fn is_capture_mode(kind: &str) -> bool {
+    CAPTURE_MODES.contains(&kind)
+}
+
+/// the jump kind a `capscr://` url stands for, or None for any other argument.
+/// captures come back as `link:<mode>`
+pub fn jump_kind(arg: &str) -> Option<String> {
+    let url = url::Url::parse(arg).ok()?;
+    if url.scheme() != SCHEME {
+        return None;
+    }
+    // browsers differ on whether `capscr://capture` arrives with a trailing /
+    if !matches!(url.path(), "" | "/") {
+        tracing::warn!("unsupported capscr:// link: {arg}");
+        return None;
+    }
+    match url.host_str() {
+        Some("capture") => {
+            let mode = url
+                .query_pairs()
+                .find(|(key, _)| key == "mode")
+                .map_or_else(|| "region".to_string(), |(_, value)| value.into_owned());
+            if CAPTURE_MODES.contains(&mode.as_str()) {
+                Some(format!("{LINK_PREFIX}{mode}"))
+            } else {
+                tracing::warn!("unsupported capscr:// capture mode: {mode}");
+                None
+            }
+        }
+        Some("hub") => Some("hub".to_string()),
+        _ => {
+            tracing::warn!("unsupported capscr:// link: {arg}");
+            None
+        }
+    }
+}
+
+/// point the per-user `capscr:` url class at this exe. rewritten every launch
+/// so a moved or updated install keeps answering links
+#[cfg(windows)]
+pub fn register() -> anyhow::Result<()> {
+    use crate::win_registry::set_string;
+
+    let exe = std::env::current_exe()?;
+    let exe = exe.to_string_lossy();
+    let class = format!("Software\\Classes\\{SCHEME}");
+    set_string(&class, None, "URL:capscr capture link")?;
+    set_string(&class, Some("URL Protocol"), "")?;
+    set_string(
+        &format!("{class}\\DefaultIcon"),
+        None,
+        &format!("\"{exe}\",0"),
+    )?;
+    set_string(
+        &format!("{class}\\shell\\open\\command"),
+        None,
+        &format!("\"{exe}\" \"%1\""),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capture_links_map_to_jump_kinds() {
+        assert_eq!(
+            jump_kind("capscr://capture?mode=window").as_deref(),
+            Some("link:window")
+        );
+        assert_eq!(
+            jump_kind("capscr://capture/?mode=fullscreen").as_deref(),
+            Some("link:fullscreen")
+        );
+        assert_eq!(
+            jump_kind("capscr://capture").as_deref(),
+            Some("link:region")
+        );
+        assert_eq!(jump_kind("capscr://hub").as_deref(), Some("hub"));
+    }
+
+    #[test]
+    fn anything_else_is_ignored() {
+        assert_eq!(jump_kind("capscr://capture?mode=task:upload"), None);
+        assert_eq!(jump_kind("capscr://capture/../hub"), None);
+        assert_eq!(jump_kind("capscr://settings"), None);
+        assert_eq!(jump_kind("https://capture?mode=region"), None);
+        assert_eq!(jump_kind("C:\\Program Files\\capscr\\capscr.exe"), None);
+        assert_eq!(jump_kind("--jump=region"), None);
+    }
+}
@@ -0,0 +1,219 @@
+use anyhow::{anyhow, Result};
+use std::path::Path;
+use std::process::Stdio;
+
+use super::mp4_stream::ffmpeg_command;
+
+// gif can't time frames shorter than 2cs, so it tops out at 50fps
+const MAX_GIF_FPS: u32 = 50;
+const MAX_VIDEO_FPS: u32 = 60;
+const VIDEO_EXTENSIONS: &[&str] = &["mp4", "webm", "mov", "mkv"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvertTarget {
+    Gif,
+    Mp4,
+    Webm,
+}
+
+impl ConvertTarget {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "gif" => Some(Self::Gif),
+            "mp4" => Some(Self::Mp4),
+            "webm" => Some(Self::Webm),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Mp4 => "mp4",
+            Self::Webm => "webm",
+        }
+    }
+
+    /// the usual direction for `src`: a gif becomes an mp4, a video a gif.
+    /// None for anything that's neither
+    pub fn opposite_of(src: &Path) -> Option<Self> {
+        let ext = src.extension()?.to_str()?.to_ascii_lowercase();
+        if ext == "gif" {
+            Some(Self::Mp4)
+        } else if VIDEO_EXTENSIONS.contains(&ext.as_str()) {
+            Some(Self::Gif)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ConvertOptions {
+    pub target: ConvertTarget,
+    // 0 keeps the source's frame rate
+    pub fps: u32,
+    // frames wider than this are downscaled (aspect kept); 0 keeps the size
+    pub max_width: u32,
+    pub video_crf: u8,
+}
+
+/// transcode a gif into a video or a video into a gif at `dest` with ffmpeg.
+/// the audio track is dropped: a gif has none to give or keep
+pub fn convert_media(src: &Path, dest: &Path, options: ConvertOptions) -> Result<()> {
+    if src.extension() == dest.extension() {
+        return Err(anyhow!(
+            "{} is already a {}",
+            src.display(),
+            options.target.extension()
+        ));
+    }
+    // a palette gif of a long video can dwarf the source; twice its size is a
+    // rough floor, not a promise
+    if let Some(dir) = dest.parent() {
+        let src_len = std::fs::metadata(src).map_or(0, |m| m.len());
+        crate::disk::ensure_room(dir, src_len.saturating_mul(2), "converted recording")?;
+    }
+    let mut cmd = ffmpeg_command();
+    cmd.args([
+        "-i",
+        &src.to_string_lossy(),
+        "-an",
+        "-vf",
+        &video_filter(options),
+    ]);
+    let crf = options.video_crf.min(51).to_string();
+    match options.target {
+        ConvertTarget::Gif => {
+            cmd.args(["-loop", "0"]);
+        }
+        ConvertTarget::Mp4 => {
+            cmd.args([
+                "-c:v",
+                "libx264",
+                "-pix_fmt",
+                "yuv420p",
+                "-crf",
+                &crf,
+                "-movflags",
+                "+faststart",
+            ]);
+        }
+        ConvertTarget::Webm => {
+            // constant quality: vp9 reads -crf only with the bitrate zeroed
+            cmd.args([
+                "-c:v",
+                "libvpx-vp9",
+                "-pix_fmt",
+                "yuv420p",
+                "-crf",
+                &crf,
+                "-b:v",
+                "0",
+            ]);
+        }
+    }
+    let output = cmd
+        .args(["-y", &dest.to_string_lossy()])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| anyhow!("Failed to launch ffmpeg: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let tail = stderr.trim().lines().last().unwrap_or("ffmpeg error");
+        let _ = std::fs::remove_file(dest);
+        return Err(anyhow!("ffmpeg failed: {tail}"));
+    }
+    Ok(())
+}
+
+fn video_filter(options: ConvertOptions) -> String {
+    let mut filters = Vec::new();
+    if options.fps > 0 {
+        let cap = match options.target {
+            ConvertTarget::Gif => MAX_GIF_FPS,
+            ConvertTarget::Mp4 | ConvertTarget::Webm => MAX_VIDEO_FPS,
+        };
+        filters.push(format!("fps={}", options.fps.min(cap)));
+    }
+    match (options.target, options.max_width) {
+        (ConvertTarget::Gif, 0) => {}
+        (ConvertTarget::Gif, width) => {
+            filters.push(format!("scale='min({width},iw)':-1:flags=lanczos"));
+        }
+        // yuv420p needs even dimensions, which a gif often doesn't have
+        (_, 0) => filters.push("scale=trunc(iw/2)*2:trunc(ih/2)*2".to_string()),
+        (_, width) => {
+            filters.push(format!("scale='min({width},iw)':-2:flags=lanczos"));
+            filters.push("scale=trunc(iw/2)*2:trunc(ih/2)*2".to_string());
+        }
+    }
+    let chain = filters.join(",");
+    match options.target {
+        // one palette for the whole clip, built from the frames that change,
+        // beats ffmpeg's default web-safe palette by a mile
+        ConvertTarget::Gif => {
+            let head = if chain.is_empty() {
+                String::new()
+            } else {
+                format!("{chain},")
+            };
+            format!(
+                "{head}split[a][b];[a]palettegen=stats_mode=diff[p];[b][p]paletteuse=dither=sierra2_4a"
+            )
+        }
+        ConvertTarget::Mp4 | ConvertTarget::Webm => chain,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(target: ConvertTarget, fps: u32, max_width: u32) -> ConvertOptions {
+        ConvertOptions {
+            target,
+            fps,
+            max_width,
+            video_crf: 23,
+        }
+    }
+
+    #[test]
+    fn direction_follows_the_source() {
+        assert_eq!(
+            ConvertTarget::opposite_of(Path::new("a/rec.GIF")),
+            Some(ConvertTarget::Mp4)
+        );
+        assert_eq!(
+            ConvertTarget::opposite_of(Path::new("rec.webm")),
+            Some(ConvertTarget::Gif)
+        );
+        assert_eq!(ConvertTarget::opposite_of(Path::new("shot.png")), None);
+        assert_eq!(ConvertTarget::parse("WebM"), Some(ConvertTarget::Webm));
+        assert_eq!(ConvertTarget::parse("avi"), None);
+    }
+
+    #[test]
+    fn video_output_is_always_even_sized() {
+        assert_eq!(
+            video_filter(options(ConvertTarget::Mp4, 0, 0)),
+            "scale=trunc(iw/2)*2:trunc(ih/2)*2"
+        );
+        assert_eq!(
+            video_filter(options(ConvertTarget::Webm, 120, 640)),
+            "fps=60,scale='min(640,iw)':-2:flags=lanczos,scale=trunc(iw/2)*2:trunc(ih/2)*2"
+        );
+    }
+
+    #[test]
+    fn gif_output_gets_a_palette_and_a_capped_rate() {
+        assert_eq!(
+            video_filter(options(ConvertTarget::Gif, 60, 480)),
+            "fps=50,scale='min(480,iw)':-1:flags=lanczos,split[a][b];[a]palettegen=stats_mode=diff[p];[b][p]paletteuse=dither=sierra2_4a"
+        );
+        assert!(video_filter(options(ConvertTarget::Gif, 0, 0)).starts_with("split[a][b];"));
+    }
+}
@@ -2,13 +2,16 @@ use anyhow::{anyhow, Result};
 use gif::{Encoder, Frame, Repeat};
 use image::RgbaImage;
 use std::fs::OpenOptions;
+use std::ops::Range;
 use std::path::Path;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
+use crate::capture::exclusion::{self, ExcludedWindow};
 use crate::capture::{MonitorInfo, Rectangle, ScreenCapture};
+use crate::config::{ExclusionConfig, ExclusionMode};
 
 fn find_best_monitor(rect: Rectangle) -> Option<MonitorInfo> {
     let monitors = crate::capture::list_monitors().ok()?;
@@ -45,20 +48,29 @@ fn find_best_monitor(rect: Rectangle) -> Option<MonitorInfo> {
 
 use super::mp4_stream::{ffmpeg_command, Mp4Streamer};
 use super::spool::FrameSpool;
-use super::{RecordingFormat, RecordingSettings, RecordingState, StopReason};
+use super::zoom::ZoomCamera;
+use super::{FrameStats, RecordingFormat, RecordingSettings, RecordingState, StopReason};
 
 // insanity backstop above the theoretical max of 300s * 60fps
 const MAX_FRAMES: usize = 21600;
 const MAX_GIF_DIMENSION: u32 = 4096;
 const MAX_GIF_FILE_SIZE: u64 = 500 * 1024 * 1024;
+// what lzw makes of palettized screen frames: at best, and usually
+const GIF_BEST_RATIO: u64 = 32;
+const GIF_TYPICAL_RATIO: u64 = 8;
 const MIN_FRAME_INTERVAL_MS: u64 = 16;
+// enumerating every window costs more than a frame grab, so the exclusion
+// list's window geometry is refreshed on this interval rather than per frame
+const EXCLUSION_REFRESH: Duration = Duration::from_millis(500);
 
 // where kept frames go during capture. RAM stays flat either way: gif frames
 // spool to a temp file for the post-stop encode, mp4 frames stream into a
-// live ffmpeg child as they arrive
+// live ffmpeg child as they arrive, and a live stream is that same child
+// pushing to an rtmp ingest
 enum FrameSink {
     Gif(FrameSpool),
     Mp4(Mp4Streamer),
+    Live(Mp4Streamer),
 }
 
 pub struct GifRecorder {
@@ -68,6 +80,11 @@ pub struct GifRecorder {
     stop_reason: Arc<Mutex<Option<StopReason>>>,
     stop_signal: Option<Sender<()>>,
     region: Option<Rectangle>,
+    // re-aim the region at the focused window every frame
+    follow_active_window: bool,
+    // where the region is right now; only moves while following a window
+    live_region: Arc<Mutex<Option<Rectangle>>>,
+    stats: Arc<Mutex<FrameStats>>,
     audio_temp_path: Option<std::path::PathBuf>,
     audio_stop_tx: Option<Sender<()>>,
 }
@@ -118,6 +135,194 @@ fn schedule_frames(
     schedule
 }
 
+// quantize and write a spooled frame sequence as a looping gif. frames are
+// timed by their spool timestamps against the nominal `fps`, and resized to
+// the first frame's dimensions if a later one differs
+pub(super) fn encode_gif(spool: &mut FrameSpool, fps: u32, quality: u8, path: &Path) -> Result<()> {
+    let all = 0..spool.len();
+    encode_gif_frames(spool, all, fps, quality, path)
+}
+
+// encode_gif over a slice of the spool, with the first kept frame at t=0
+fn encode_gif_frames(
+    spool: &mut FrameSpool,
+    frames: Range<usize>,
+    fps: u32,
+    quality: u8,
+    path: &Path,
+) -> Result<()> {
+    let frames = frames.start.min(spool.len())..frames.end.min(spool.len());
+    if frames.is_empty() {
+        return Err(anyhow!("No frames captured"));
+    }
+
+    let orig_width = spool.metas()[frames.start].width;
+    let orig_height = spool.metas()[frames.start].height;
+
+    if orig_width > MAX_GIF_DIMENSION || orig_height > MAX_GIF_DIMENSION {
+        return Err(anyhow!("Image dimensions exceed GIF safety limit"));
+    }
+    // a byte per palettized pixel before lzw, which packs screen content
+    // several times over. only refuse when even a well-compressed gif can't
+    // fit; a tight fit is worth a warning, not a lost recording
+    if let Some(dir) = path.parent() {
+        let palettized = orig_width as u64 * orig_height as u64 * frames.len() as u64;
+        let estimate = |ratio: u64| (palettized / ratio).min(MAX_GIF_FILE_SIZE);
+        crate::disk::ensure_room(dir, estimate(GIF_BEST_RATIO), "gif")?;
+        if let Err(e) = crate::disk::ensure_room(dir, estimate(GIF_TYPICAL_RATIO), "gif") {
+            tracing::warn!("gif may not fit: {e:#}");
+        }
+    }
+    if orig_width > u16::MAX as u32 || orig_height > u16::MAX as u32 {
+        return Err(anyhow!("Image dimensions too large for GIF format"));
+    }
+    if orig_width == 0 || orig_height == 0 {
+        return Err(anyhow!("Image has zero dimension"));
+    }
+
+    let width = orig_width as u16;
+    let height = orig_height as u16;
+
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+
+    let fps = fps.clamp(1, 60);
+    let nominal = Duration::from_secs_f64(1.0 / fps as f64);
+    let origin = spool.metas()[frames.start].at;
+    let times: Vec<Duration> = spool.metas()[frames.clone()]
+        .iter()
+        .map(|m| m.at.saturating_sub(origin))
+        .collect();
+    let delays = gif_delay_schedule(&times, nominal);
+
+    let filter = if quality >= 80 {
+        image::imageops::FilterType::Lanczos3
+    } else if quality >= 50 {
+        image::imageops::FilterType::Triangle
+    } else {
+        image::imageops::FilterType::Nearest
+    };
+
+    let sample_step = (frames.len() / 15).max(1);
+    let mut sample_pixels = Vec::new();
+
+    for i in frames.clone().step_by(sample_step) {
+        let frame = spool.read_frame(i)?;
+        let resized = if frame.width() != orig_width || frame.height() != orig_height {
+            image::imageops::resize(&frame, orig_width, orig_height, filter)
+        } else {
+            frame
+        };
+
+        let rgba = resized.as_raw();
+        let total_pixels = resized.width() * resized.height();
+        let pixel_step = (total_pixels / 10000).max(1) as usize;
+
+        for chunk in rgba.chunks_exact(4).step_by(pixel_step) {
+            sample_pixels.extend_from_slice(chunk);
+        }
+    }
+
+    if sample_pixels.is_empty() {
+        sample_pixels.extend_from_slice(&[0, 0, 0, 255]);
+    }
+
+    let nq = color_quant::NeuQuant::new(10, 256, &sample_pixels);
+    let colormap_rgba = nq.color_map_rgba();
+    let mut global_palette = Vec::with_capacity(256 * 3);
+    for chunk in colormap_rgba.chunks_exact(4) {
+        global_palette.push(chunk[0]);
+        global_palette.push(chunk[1]);
+        global_palette.push(chunk[2]);
+    }
+
+    {
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        let mut encoder = Encoder::new(file, width, height, &global_palette)?;
+        encoder.set_repeat(Repeat::Infinite)?;
+
+        for (frame_idx, &delay) in delays.iter().enumerate() {
+            // 0-delay frames were folded into a neighbour by the schedule;
+            // skipping them here also skips their disk read + quantize cost
+            if delay == 0 {
+                continue;
+            }
+            let frame = spool.read_frame(frames.start + frame_idx)?;
+            let resized = if frame.width() != orig_width || frame.height() != orig_height {
+                image::imageops::resize(&frame, orig_width, orig_height, filter)
+            } else {
+                frame
+            };
+
+            let rgba_data = resized.as_raw();
+            let pixel_count = (width as usize).saturating_mul(height as usize);
+
+            if pixel_count.saturating_mul(4) > 64 * 1024 * 1024 {
+                return Err(anyhow!("Frame too large to encode"));
+            }
+
+            let mut indexed_pixels = vec![0u8; pixel_count];
+            let mut last_pixel = [0u8; 4];
+            let mut last_index = 0u8;
+            let mut cache_valid = false;
+
+            for (idx, chunk) in rgba_data.chunks_exact(4).enumerate() {
+                if cache_valid
+                    && chunk[0] == last_pixel[0]
+                    && chunk[1] == last_pixel[1]
+                    && chunk[2] == last_pixel[2]
+                    && chunk[3] == last_pixel[3]
+                {
+                    indexed_pixels[idx] = last_index;
+                } else {
+                    let color_idx = nq.index_of(chunk) as u8;
+                    indexed_pixels[idx] = color_idx;
+                    last_pixel.copy_from_slice(chunk);
+                    last_index = color_idx;
+                    cache_valid = true;
+                }
+            }
+
+            let frame = Frame {
+                width,
+                height,
+                delay: delays[frame_idx],
+                buffer: std::borrow::Cow::Owned(indexed_pixels),
+                ..Default::default()
+            };
+
+            encoder.write_frame(&frame)?;
+        }
+    } // encoder and file handle dropped here — all bytes flushed before size check
+
+    if let Ok(metadata) = std::fs::metadata(path) {
+        if metadata.len() > MAX_GIF_FILE_SIZE {
+            let _ = std::fs::remove_file(path);
+            return Err(anyhow!("Generated GIF exceeds maximum file size"));
+        }
+    }
+
+    Ok(())
+}
+
+// the spool frames an in/out trim keeps: the one on screen at `start` through
+// the last to appear before `end`. never empty unless the timeline is
+fn trim_range(times: &[Duration], start: Duration, end: Duration) -> Range<usize> {
+    if times.is_empty() {
+        return 0..0;
+    }
+    let first = times.partition_point(|t| *t <= start).saturating_sub(1);
+    let last = times.partition_point(|t| *t < end).max(first + 1);
+    first..last
+}
+
 // gif delays count in hundredths of a second; players treat <2cs as a slow
 // 10cs default, so that's the drop threshold. 6000cs caps a single hold at 60s
 fn gif_delay_schedule(times: &[Duration], nominal: Duration) -> Vec<u16> {
@@ -172,6 +377,64 @@ fn compute_frame_fingerprint(image: &RgbaImage) -> u64 {
     hash
 }
 
+// scale a followed window's frame down to fit the recording's fixed size and
+// centre it on black. never scales up: a small window stays sharp and gets a
+// wider border instead
+fn fit_to_canvas(img: &RgbaImage, width: u32, height: u32) -> RgbaImage {
+    if img.dimensions() == (width, height) {
+        return img.clone();
+    }
+    let scale = (width as f32 / img.width().max(1) as f32)
+        .min(height as f32 / img.height().max(1) as f32)
+        .min(1.0);
+    let fitted_w = ((img.width() as f32 * scale) as u32).clamp(1, width);
+    let fitted_h = ((img.height() as f32 * scale) as u32).clamp(1, height);
+    let mut canvas = RgbaImage::from_pixel(width, height, image::Rgba([0, 0, 0, 255]));
+    let left = (width - fitted_w) / 2;
+    let top = (height - fitted_h) / 2;
+    if scale < 1.0 {
+        let scaled = image::imageops::resize(
+            img,
+            fitted_w,
+            fitted_h,
+            image::imageops::FilterType::Triangle,
+        );
+        image::imageops::replace(&mut canvas, &scaled, left as i64, top as i64);
+    } else {
+        image::imageops::replace(&mut canvas, img, left as i64, top as i64);
+    }
+    canvas
+}
+
+// run a frame through the capture exclusion list. Err drops the frame: in
+// skip mode while an excluded window is in it, and in either mode when the
+// window list or the frame's place on screen is unknown
+fn screen_frame(
+    mut img: RgbaImage,
+    origin: Option<(i32, i32)>,
+    config: &ExclusionConfig,
+    windows: Option<&[ExcludedWindow]>,
+) -> Result<RgbaImage> {
+    if config.apps.is_empty() {
+        return Ok(img);
+    }
+    let (Some(windows), Some(origin)) = (windows, origin) else {
+        return Err(anyhow!(
+            "frame held back: exclusion list couldn't be checked"
+        ));
+    };
+    match config.mode {
+        ExclusionMode::Blackout => {
+            exclusion::mask(&mut img, origin, windows);
+            Ok(img)
+        }
+        ExclusionMode::Skip => match exclusion::overlapping(windows, origin, img.dimensions()) {
+            Some(hit) => Err(anyhow!("frame skipped: {} is on screen", hit.app)),
+            None => Ok(img),
+        },
+    }
+}
+
 impl GifRecorder {
     pub fn new(settings: RecordingSettings) -> Self {
         Self {
@@ -181,6 +444,9 @@ impl GifRecorder {
             stop_reason: Arc::new(Mutex::new(None)),
             stop_signal: None,
             region: None,
+            follow_active_window: false,
+            live_region: Arc::new(Mutex::new(None)),
+            stats: Arc::new(Mutex::new(FrameStats::default())),
             audio_temp_path: None,
             audio_stop_tx: None,
         }
@@ -191,6 +457,25 @@ impl GifRecorder {
         self
     }
 
+    /// track the focused window instead of holding the region still. frames
+    /// keep the starting region's size and the window is scaled down to fit,
+    /// since neither sink can change dimensions mid-recording
+    pub fn following_active_window(mut self) -> Self {
+        self.follow_active_window = true;
+        self
+    }
+
+    /// the screen rect being recorded right now
+    pub fn current_region(&self) -> Option<Rectangle> {
+        (*self.live_region.lock().unwrap_or_else(|e| e.into_inner())).or(self.region)
+    }
+
+    /// how the capture loop is keeping up, live while it runs and final once
+    /// it has stopped
+    pub fn stats(&self) -> FrameStats {
+        *self.stats.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
     #[allow(dead_code)]
     pub fn state(&self) -> RecordingState {
         *self.state.lock().unwrap_or_else(|e| e.into_inner())
@@ -205,6 +490,11 @@ impl GifRecorder {
         self.settings.format
     }
 
+    /// pushing to an ingest rather than recording anything to save
+    pub fn is_live(&self) -> bool {
+        self.settings.stream.is_some()
+    }
+
     pub fn start(&mut self) -> Result<()> {
         {
             let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
@@ -214,15 +504,18 @@ impl GifRecorder {
             *state = RecordingState::Recording;
         }
 
-        let new_sink = match self.settings.format {
-            RecordingFormat::Gif => match FrameSpool::create() {
+        let new_sink = match (self.settings.format, &self.settings.stream) {
+            (_, Some(target)) => {
+                FrameSink::Live(Mp4Streamer::live(self.settings.fps, target.clone()))
+            }
+            (RecordingFormat::Gif, None) => match FrameSpool::create() {
                 Ok(spool) => FrameSink::Gif(spool),
                 Err(e) => {
                     *self.state.lock().unwrap_or_else(|p| p.into_inner()) = RecordingState::Idle;
                     return Err(e);
                 }
             },
-            RecordingFormat::Mp4 => {
+            (RecordingFormat::Mp4, None) => {
                 FrameSink::Mp4(Mp4Streamer::new(self.settings.fps, self.settings.video_crf))
             }
         };
@@ -232,9 +525,13 @@ impl GifRecorder {
         let (tx, rx): (Sender<()>, Receiver<()>) = channel();
         self.stop_signal = Some(tx);
 
-        // gifs have no audio track; only mp4 recordings pay for the wasapi tap
-        if self.settings.record_audio && self.settings.format == RecordingFormat::Mp4 {
-            let temp_dir = std::env::temp_dir();
+        // gifs have no audio track; only mp4 recordings pay for the wasapi tap.
+        // the live pipe has nowhere to mux it in either
+        if self.settings.record_audio
+            && self.settings.format == RecordingFormat::Mp4
+            && !self.is_live()
+        {
+            let temp_dir = crate::scratch::dir();
             let audio_filename = format!("capscr_audio_{}.wav", uuid::Uuid::new_v4().as_simple());
             let audio_path = temp_dir.join(audio_filename);
             self.audio_temp_path = Some(audio_path.clone());
@@ -253,10 +550,37 @@ impl GifRecorder {
         let sink = Arc::clone(&self.sink);
         let stop_reason = Arc::clone(&self.stop_reason);
         let fps = self.settings.fps.max(1);
+        // a stream runs until it's stopped: no max duration or frame cap, and
+        // every tick goes out so the ingest sees a steady frame rate instead
+        // of a still screen held back as duplicates
+        let live = self.is_live();
+        let stats = Arc::clone(&self.stats);
+        *stats.lock().unwrap_or_else(|e| e.into_inner()) = FrameStats {
+            target_fps: fps,
+            ..FrameStats::default()
+        };
         let max_duration = self.settings.max_duration;
-        let region = self.region;
-        let best_monitor = region.and_then(find_best_monitor);
+        let mut region = self.region;
+        let mut best_monitor = region.and_then(find_best_monitor);
+        let follow = self.follow_active_window && region.is_some();
+        let canvas = region.filter(|_| follow).map(|r| {
+            (
+                r.width.min(MAX_GIF_DIMENSION),
+                r.height.min(MAX_GIF_DIMENSION),
+            )
+        });
+        let live_region = Arc::clone(&self.live_region);
+        *live_region.lock().unwrap_or_else(|e| e.into_inner()) = region;
         let show_cursor = self.settings.show_cursor;
+        let exclusion_config = self.settings.exclusion.clone();
+        // whole-desktop recordings are downscaled, so there's no fixed frame
+        // for the camera to map the pointer into
+        let mut zoom = (self.settings.zoom.enabled && region.is_some())
+            .then(|| ZoomCamera::new(&self.settings.zoom));
+        let desktop_origin = match region {
+            Some(_) => None,
+            None => ScreenCapture::desktop_origin().ok(),
+        };
 
         thread::spawn(move || {
             #[cfg(windows)]
@@ -298,17 +622,45 @@ impl GifRecorder {
             let mut frames_kept: usize = 0;
             let mut last_fingerprint: u64 = 0;
             let mut consecutive_dupes: u32 = 0;
+            let mut excluded: Option<Vec<ExcludedWindow>> = Some(Vec::new());
+            let mut excluded_at: Option<Instant> = None;
 
             let reason = loop {
                 if rx.try_recv().is_ok() {
                     break StopReason::Requested;
                 }
 
-                if start_time.elapsed() >= max_duration {
+                if !live && start_time.elapsed() >= max_duration {
                     break StopReason::MaxDuration;
                 }
 
                 let frame_start = Instant::now();
+                stats.lock().unwrap_or_else(|e| e.into_inner()).elapsed =
+                    frame_start.duration_since(start_time);
+
+                // keep the last known rect while nothing trackable has focus,
+                // e.g. the desktop or capscr's own recording bar
+                if follow {
+                    if let Some(rect) = crate::capture::active_window_rect() {
+                        if region != Some(rect) {
+                            region = Some(rect);
+                            best_monitor = find_best_monitor(rect);
+                            *live_region.lock().unwrap_or_else(|e| e.into_inner()) = region;
+                        }
+                    }
+                }
+
+                if !exclusion_config.apps.is_empty()
+                    && excluded_at.is_none_or(|at| at.elapsed() >= EXCLUSION_REFRESH)
+                {
+                    excluded = exclusion::excluded_windows(&exclusion_config.apps)
+                        .inspect_err(|e| tracing::warn!("recording exclusion check failed: {e:#}"))
+                        .ok();
+                    excluded_at = Some(Instant::now());
+                }
+                let screen = |img: RgbaImage, origin: Option<(i32, i32)>| {
+                    screen_frame(img, origin, &exclusion_config, excluded.as_deref())
+                };
 
                 let capture_result = if let Some(rect) = region {
                     // fast path: direct region grab from the persistent
@@ -372,24 +724,32 @@ impl GifRecorder {
                         }
                     }
                 } else {
-                    ScreenCapture::all_monitors().map(|img| {
-                        if img.width() > MAX_GIF_DIMENSION || img.height() > MAX_GIF_DIMENSION {
-                            let scale_w = MAX_GIF_DIMENSION as f32 / img.width() as f32;
-                            let scale_h = MAX_GIF_DIMENSION as f32 / img.height() as f32;
-                            let scale = scale_w.min(scale_h);
-                            let new_w = ((img.width() as f32) * scale) as u32;
-                            let new_h = ((img.height() as f32) * scale) as u32;
-                            image::imageops::resize(
-                                &img,
-                                new_w.max(1),
-                                new_h.max(1),
-                                image::imageops::FilterType::Triangle,
-                            )
-                        } else {
-                            img
+                    // masked before the downscale, while pixels still line up
+                    // with screen coordinates
+                    ScreenCapture::all_monitors().and_then(|img| {
+                        let img = screen(img, desktop_origin)?;
+                        if img.width() <= MAX_GIF_DIMENSION && img.height() <= MAX_GIF_DIMENSION {
+                            return Ok(img);
                         }
+                        let scale_w = MAX_GIF_DIMENSION as f32 / img.width() as f32;
+                        let scale_h = MAX_GIF_DIMENSION as f32 / img.height() as f32;
+                        let scale = scale_w.min(scale_h);
+                        let new_w = ((img.width() as f32) * scale) as u32;
+                        let new_h = ((img.height() as f32) * scale) as u32;
+                        Ok(image::imageops::resize(
+                            &img,
+                            new_w.max(1),
+                            new_h.max(1),
+                            image::imageops::FilterType::Triangle,
+                        ))
                     })
                 };
+                let capture_result = match region {
+                    Some(rect) => {
+                        capture_result.and_then(|img| screen(img, Some((rect.x, rect.y))))
+                    }
+                    None => capture_result,
+                };
 
                 tracing::debug!(
                     "recording frame capture took {}ms (ok={})",
@@ -397,6 +757,18 @@ impl GifRecorder {
                     capture_result.is_ok(),
                 );
 
+                let usable = capture_result.as_ref().is_ok_and(|img| {
+                    img.width() <= MAX_GIF_DIMENSION && img.height() <= MAX_GIF_DIMENSION
+                });
+                {
+                    let mut stats = stats.lock().unwrap_or_else(|e| e.into_inner());
+                    if usable {
+                        stats.sampled += 1;
+                    } else {
+                        stats.failed += 1;
+                    }
+                }
+
                 if let Ok(mut image) = capture_result {
                     if image.width() <= MAX_GIF_DIMENSION && image.height() <= MAX_GIF_DIMENSION {
                         // grab the cursor once so the same snapshot drives both the
@@ -407,9 +779,19 @@ impl GifRecorder {
                             None
                         };
 
+                        // the zoom camera follows the pointer even when it isn't
+                        // drawn, so its movement has to keep frames coming too
+                        let pointer = zoom
+                            .is_some()
+                            .then(crate::capture::pointer_position)
+                            .flatten();
+
                         let mut fingerprint = compute_frame_fingerprint(&image);
-                        if let (Some(shot), Some(rect)) = (&cursor_shot, region) {
-                            let (cx, cy) = shot.screen_pos();
+                        let tracked = cursor_shot
+                            .as_ref()
+                            .map(|shot| shot.screen_pos())
+                            .or(pointer);
+                        if let (Some((cx, cy)), Some(rect)) = (tracked, region) {
                             // only perturb the fingerprint while the cursor is inside the
                             // region: movement within it yields new frames, while a cursor
                             // moving outside the capture must not defeat dedup
@@ -422,7 +804,11 @@ impl GifRecorder {
                             }
                         }
 
-                        if fingerprint == last_fingerprint {
+                        // a still screen under an easing camera still changes
+                        if !live
+                            && fingerprint == last_fingerprint
+                            && !zoom.as_ref().is_some_and(ZoomCamera::in_motion)
+                        {
                             consecutive_dupes += 1;
                             if consecutive_dupes < 30 {
                                 let elapsed = frame_start.elapsed();
@@ -443,28 +829,42 @@ impl GifRecorder {
                                 (rect.x, rect.y),
                             );
                         }
+                        let at = frame_start.duration_since(start_time);
+                        if let (Some(camera), Some(rect)) = (zoom.as_mut(), region) {
+                            // a followed window is scaled on the canvas below, so
+                            // the camera works in the window's own pixels first
+                            let cursor =
+                                pointer.map(|(x, y)| ((x - rect.x) as f32, (y - rect.y) as f32));
+                            image = camera.apply(image, cursor, at);
+                        }
+                        if let Some((width, height)) = canvas {
+                            image = fit_to_canvas(&image, width, height);
+                        }
 
-                        if frames_kept >= MAX_FRAMES {
+                        if !live && frames_kept >= MAX_FRAMES {
                             break StopReason::FrameCap;
                         }
 
-                        let at = frame_start.duration_since(start_time);
                         let mut sink_guard = sink.lock().unwrap_or_else(|e| e.into_inner());
                         match sink_guard.as_mut() {
                             Some(FrameSink::Gif(spool)) => match spool.push(&image, at) {
-                                Ok(true) => frames_kept += 1,
+                                Ok(true) => {
+                                    frames_kept += 1;
+                                    stats.lock().unwrap_or_else(|e| e.into_inner()).kept += 1;
+                                }
                                 Ok(false) => break StopReason::DiskFull,
                                 Err(e) => {
                                     tracing::error!("frame spool write failed: {e}");
                                     break StopReason::EncoderFailed;
                                 }
                             },
-                            Some(FrameSink::Mp4(streamer)) => {
+                            Some(FrameSink::Mp4(streamer) | FrameSink::Live(streamer)) => {
                                 if let Err(e) = streamer.push(image, at) {
                                     tracing::error!("mp4 stream write failed: {e}");
                                     break StopReason::EncoderFailed;
                                 }
                                 frames_kept += 1;
+                                stats.lock().unwrap_or_else(|e| e.into_inner()).kept += 1;
                             }
                             // reset() cleared the sink under us — just end
                             None => break StopReason::Requested,
@@ -478,6 +878,7 @@ impl GifRecorder {
                 }
             };
 
+            stats.lock().unwrap_or_else(|e| e.into_inner()).elapsed = start_time.elapsed();
             *stop_reason.lock().unwrap_or_else(|e| e.into_inner()) = Some(reason);
             if let Ok(mut state_lock) = state.lock() {
                 *state_lock = RecordingState::Processing;
@@ -500,14 +901,62 @@ impl GifRecorder {
     pub fn frame_count(&self) -> usize {
         match self.sink.lock().unwrap_or_else(|e| e.into_inner()).as_ref() {
             Some(FrameSink::Gif(spool)) => spool.len(),
-            Some(FrameSink::Mp4(streamer)) => streamer.frames_pushed() as usize,
+            Some(FrameSink::Mp4(streamer) | FrameSink::Live(streamer)) => {
+                streamer.frames_pushed() as usize
+            }
             None => 0,
         }
     }
 
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-        let path = path.as_ref();
+        self.save_frames(path.as_ref(), None)
+    }
 
+    /// save only what was captured between `start` and `end`
+    pub fn save_trimmed<P: AsRef<Path>>(
+        &self,
+        path: P,
+        start: Duration,
+        end: Duration,
+    ) -> Result<()> {
+        self.save_frames(path.as_ref(), Some((start, end)))
+    }
+
+    /// hand the spooled gif frames to `dir` for the next launch to encode,
+    /// when there's no saving them now. try_lock since this also runs from
+    /// the panic hook, where the panicking thread may hold the sink. None
+    /// for mp4 and streams, whose frames went to ffmpeg
+    pub fn preserve_frames(&self, dir: &Path) -> Result<Option<std::path::PathBuf>> {
+        let mut guard = match self.sink.try_lock() {
+            Ok(guard) => guard,
+            Err(std::sync::TryLockError::Poisoned(e)) => e.into_inner(),
+            Err(std::sync::TryLockError::WouldBlock) => return Err(anyhow!("frame spool is busy")),
+        };
+        match guard.as_mut() {
+            Some(FrameSink::Gif(spool)) if !spool.is_empty() => spool
+                .preserve(dir, self.settings.fps, self.settings.quality)
+                .map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// capture time of every spooled frame, for the review timeline. empty
+    /// for mp4, whose frames went to the encoder as they arrived
+    pub fn frame_times(&self) -> Vec<Duration> {
+        match self.sink.lock().unwrap_or_else(|e| e.into_inner()).as_ref() {
+            Some(FrameSink::Gif(spool)) => spool.metas().iter().map(|m| m.at).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn read_frame(&self, idx: usize) -> Result<RgbaImage> {
+        match self.sink.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+            Some(FrameSink::Gif(spool)) => spool.read_frame(idx),
+            _ => Err(anyhow!("No frames captured")),
+        }
+    }
+
+    fn save_frames(&self, path: &Path, trim: Option<(Duration, Duration)>) -> Result<()> {
         let path_str = path.to_string_lossy();
         if path_str.contains("..") {
             return Err(anyhow!("Path contains directory traversal"));
@@ -523,148 +972,18 @@ impl GifRecorder {
             _ => return Err(anyhow!("No frames captured")),
         };
 
-        if spool.is_empty() {
-            return Err(anyhow!("No frames captured"));
-        }
-
-        let orig_width = spool.metas()[0].width;
-        let orig_height = spool.metas()[0].height;
-
-        if orig_width > MAX_GIF_DIMENSION || orig_height > MAX_GIF_DIMENSION {
-            return Err(anyhow!("Image dimensions exceed GIF safety limit"));
-        }
-        if orig_width > u16::MAX as u32 || orig_height > u16::MAX as u32 {
-            return Err(anyhow!("Image dimensions too large for GIF format"));
-        }
-        if orig_width == 0 || orig_height == 0 {
-            return Err(anyhow!("Image has zero dimension"));
-        }
-
-        let width = orig_width as u16;
-        let height = orig_height as u16;
-
-        if let Some(parent) = path.parent() {
-            if !parent.as_os_str().is_empty() {
-                std::fs::create_dir_all(parent)?;
-            }
-        }
-
-        let fps = self.settings.fps.clamp(1, 60);
-        let nominal = Duration::from_secs_f64(1.0 / fps as f64);
         let times: Vec<Duration> = spool.metas().iter().map(|m| m.at).collect();
-        let delays = gif_delay_schedule(&times, nominal);
-
-        let filter = if self.settings.quality >= 80 {
-            image::imageops::FilterType::Lanczos3
-        } else if self.settings.quality >= 50 {
-            image::imageops::FilterType::Triangle
-        } else {
-            image::imageops::FilterType::Nearest
+        let frames = match trim {
+            Some((start, end)) => trim_range(&times, start, end),
+            None => 0..times.len(),
         };
-
-        let num_frames = spool.len();
-        let sample_step = (num_frames / 15).max(1);
-        let mut sample_pixels = Vec::new();
-
-        for i in (0..num_frames).step_by(sample_step) {
-            let frame = spool.read_frame(i)?;
-            let resized = if frame.width() != orig_width || frame.height() != orig_height {
-                image::imageops::resize(&frame, orig_width, orig_height, filter)
-            } else {
-                frame
-            };
-
-            let rgba = resized.as_raw();
-            let total_pixels = resized.width() * resized.height();
-            let pixel_step = (total_pixels / 10000).max(1) as usize;
-
-            for chunk in rgba.chunks_exact(4).step_by(pixel_step) {
-                sample_pixels.extend_from_slice(chunk);
-            }
-        }
-
-        if sample_pixels.is_empty() {
-            sample_pixels.extend_from_slice(&[0, 0, 0, 255]);
-        }
-
-        let nq = color_quant::NeuQuant::new(10, 256, &sample_pixels);
-        let colormap_rgba = nq.color_map_rgba();
-        let mut global_palette = Vec::with_capacity(256 * 3);
-        for chunk in colormap_rgba.chunks_exact(4) {
-            global_palette.push(chunk[0]);
-            global_palette.push(chunk[1]);
-            global_palette.push(chunk[2]);
-        }
-
-        {
-            let file = OpenOptions::new()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .open(path)?;
-            let mut encoder = Encoder::new(file, width, height, &global_palette)?;
-            encoder.set_repeat(Repeat::Infinite)?;
-
-            for (frame_idx, &delay) in delays.iter().enumerate() {
-                // 0-delay frames were folded into a neighbour by the schedule;
-                // skipping them here also skips their disk read + quantize cost
-                if delay == 0 {
-                    continue;
-                }
-                let frame = spool.read_frame(frame_idx)?;
-                let resized = if frame.width() != orig_width || frame.height() != orig_height {
-                    image::imageops::resize(&frame, orig_width, orig_height, filter)
-                } else {
-                    frame
-                };
-
-                let rgba_data = resized.as_raw();
-                let pixel_count = (width as usize).saturating_mul(height as usize);
-
-                if pixel_count.saturating_mul(4) > 64 * 1024 * 1024 {
-                    return Err(anyhow!("Frame too large to encode"));
-                }
-
-                let mut indexed_pixels = vec![0u8; pixel_count];
-                let mut last_pixel = [0u8; 4];
-                let mut last_index = 0u8;
-                let mut cache_valid = false;
-
-                for (idx, chunk) in rgba_data.chunks_exact(4).enumerate() {
-                    if cache_valid
-                        && chunk[0] == last_pixel[0]
-                        && chunk[1] == last_pixel[1]
-                        && chunk[2] == last_pixel[2]
-                        && chunk[3] == last_pixel[3]
-                    {
-                        indexed_pixels[idx] = last_index;
-                    } else {
-                        let color_idx = nq.index_of(chunk) as u8;
-                        indexed_pixels[idx] = color_idx;
-                        last_pixel.copy_from_slice(chunk);
-                        last_index = color_idx;
-                        cache_valid = true;
-                    }
-                }
-
-                let frame = Frame {
-                    width,
-                    height,
-                    delay: delays[frame_idx],
-                    buffer: std::borrow::Cow::Owned(indexed_pixels),
-                    ..Default::default()
-                };
-
-                encoder.write_frame(&frame)?;
-            }
-        } // encoder and file handle dropped here — all bytes flushed before size check
-
-        if let Ok(metadata) = std::fs::metadata(path) {
-            if metadata.len() > MAX_GIF_FILE_SIZE {
-                let _ = std::fs::remove_file(path);
-                return Err(anyhow!("Generated GIF exceeds maximum file size"));
-            }
-        }
+        encode_gif_frames(
+            spool,
+            frames,
+            self.settings.fps,
+            self.settings.quality,
+            path,
+        )?;
 
         if let Some(ref wav_path) = self.audio_temp_path {
             let _ = std::fs::remove_file(wav_path);
@@ -673,6 +992,16 @@ impl GifRecorder {
         Ok(())
     }
 
+    /// flush the last frames to the ingest and hang up. the stream is over
+    /// either way; an error means ffmpeg didn't exit cleanly, which is usually
+    /// the ingest dropping the connection
+    pub fn end_stream(&self) -> Result<()> {
+        match self.sink.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+            Some(FrameSink::Live(streamer)) => streamer.end_stream(),
+            _ => Err(anyhow!("not streaming")),
+        }
+    }
+
     /// Ok(true) means the recording saved but the system-audio track the user
     /// asked for is missing (capture produced nothing usable, or the mux
     /// failed) — the caller should tell them it saved without audio.
@@ -715,7 +1044,15 @@ impl GifRecorder {
             .map(|p| p.exists() && std::fs::metadata(p).map(|m| m.len() > 44).unwrap_or(false))
             .unwrap_or(false);
 
-        if audio_exists {
+        // muxing writes a second copy of the video; when the output folder
+        // can't hold it, a same-volume rename still keeps the silent recording
+        let video_len = std::fs::metadata(&temp_video).map_or(0, |m| m.len());
+        let room = match path.parent() {
+            Some(dir) => crate::disk::ensure_room(dir, video_len, "recording"),
+            None => Ok(()),
+        };
+
+        if audio_exists && room.is_ok() {
             let wav_path = self.audio_temp_path.as_ref().unwrap();
             let mux_ok = ffmpeg_command()
                 .args([
@@ -745,10 +1082,13 @@ impl GifRecorder {
             // a broken wav must not cost the user their video — fall through
             // and keep the silent recording
             tracing::warn!("audio mux failed; saving recording without audio");
+        } else if let Some(wav_path) = &self.audio_temp_path {
+            let _ = std::fs::remove_file(wav_path);
         }
 
         if std::fs::rename(&temp_video, path).is_err() {
-            // temp dir and output dir may sit on different volumes
+            // scratch and output folders may sit on different volumes
+            room?;
             std::fs::copy(&temp_video, path)
                 .map_err(|e| anyhow!("Failed to move recording into place: {}", e))?;
             let _ = std::fs::remove_file(&temp_video);
@@ -1102,12 +1442,65 @@ mod tests {
         assert_eq!(mp4.format(), RecordingFormat::Mp4);
     }
 
+    #[test]
+    fn followed_windows_fit_the_starting_size() {
+        // wider than the canvas: scaled to its width, letterboxed top and bottom
+        let wide = RgbaImage::from_pixel(200, 50, image::Rgba([255, 0, 0, 255]));
+        let fitted = fit_to_canvas(&wide, 100, 100);
+        assert_eq!(fitted.dimensions(), (100, 100));
+        assert_eq!(fitted.get_pixel(50, 50), &image::Rgba([255, 0, 0, 255]));
+        assert_eq!(fitted.get_pixel(50, 5), &image::Rgba([0, 0, 0, 255]));
+
+        // smaller than the canvas: centred at its own size
+        let small = RgbaImage::from_pixel(10, 10, image::Rgba([0, 255, 0, 255]));
+        let fitted = fit_to_canvas(&small, 100, 100);
+        assert_eq!(fitted.get_pixel(45, 45), &image::Rgba([0, 255, 0, 255]));
+        assert_eq!(fitted.get_pixel(44, 44), &image::Rgba([0, 0, 0, 255]));
+    }
+
+    #[test]
+    fn excluded_apps_black_out_or_drop_frames() {
+        let frame = || RgbaImage::from_pixel(8, 8, image::Rgba([255, 255, 255, 255]));
+        let windows = [ExcludedWindow {
+            id: 7,
+            app: "keepassxc".into(),
+            rect: Rectangle::new(4, 4, 8, 8),
+        }];
+        let mut config = ExclusionConfig {
+            apps: vec!["keepassxc".into()],
+            mode: ExclusionMode::Blackout,
+        };
+        let masked = screen_frame(frame(), Some((0, 0)), &config, Some(&windows)).unwrap();
+        assert_eq!(masked.get_pixel(5, 5), &image::Rgba([0, 0, 0, 255]));
+        assert_eq!(masked.get_pixel(3, 3), &image::Rgba([255, 255, 255, 255]));
+        // fail closed when the window list or the frame's position is missing
+        assert!(screen_frame(frame(), Some((0, 0)), &config, None).is_err());
+        assert!(screen_frame(frame(), None, &config, Some(&windows)).is_err());
+
+        config.mode = ExclusionMode::Skip;
+        assert!(screen_frame(frame(), Some((0, 0)), &config, Some(&windows)).is_err());
+        assert!(screen_frame(frame(), Some((100, 100)), &config, Some(&windows)).is_ok());
+        config.apps.clear();
+        assert!(screen_frame(frame(), None, &config, None).is_ok());
+    }
+
     fn times_at_interval(count: usize, interval_ms: f64) -> Vec<Duration> {
         (0..count)
             .map(|i| Duration::from_secs_f64(i as f64 * interval_ms / 1000.0))
             .collect()
     }
 
+    #[test]
+    fn trim_keeps_the_frame_on_screen_at_the_in_point() {
+        let times = times_at_interval(10, 100.0);
+        let ms = Duration::from_millis;
+        assert_eq!(trim_range(&times, ms(250), ms(600)), 2..6);
+        assert_eq!(trim_range(&times, ms(0), ms(10_000)), 0..10);
+        // an out point before the next frame still keeps the in-point frame
+        assert_eq!(trim_range(&times, ms(420), ms(430)), 4..5);
+        assert_eq!(trim_range(&[], ms(0), ms(100)), 0..0);
+    }
+
     #[test]
     fn gif_schedule_uses_real_gaps() {
         let times = vec![
@@ -1237,6 +1630,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn trimmed_save_starts_at_the_in_point() {
+        let recorder = GifRecorder::new(RecordingSettings::default());
+        {
+            let mut spool = FrameSpool::create().unwrap();
+            for i in 0..20u32 {
+                let img = RgbaImage::from_pixel(16, 12, image::Rgba([(i * 12) as u8, 0, 0, 255]));
+                spool
+                    .push(&img, Duration::from_millis(i as u64 * 100))
+                    .unwrap();
+            }
+            *recorder.sink.lock().unwrap() = Some(FrameSink::Gif(spool));
+        }
+        assert_eq!(recorder.frame_times().len(), 20);
+
+        let path = std::env::temp_dir().join(format!(
+            "capscr_test_{}.gif",
+            uuid::Uuid::new_v4().as_simple()
+        ));
+        recorder
+            .save_trimmed(
+                &path,
+                Duration::from_millis(500),
+                Duration::from_millis(1000),
+            )
+            .unwrap();
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(std::fs::File::open(&path).unwrap())
+            .unwrap();
+        let mut total_cs: u64 = 0;
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            total_cs += frame.delay as u64;
+            frames += 1;
+        }
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(frames, 5);
+        // four 100ms gaps plus the nominal hold, timed from the in point
+        assert_eq!(total_cs, 47);
+    }
+
     #[test]
     fn test_find_best_monitor_overlap() {
         if let Ok(monitors) = crate::capture::list_monitors() {
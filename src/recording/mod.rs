@@ -1,14 +1,30 @@
 #![allow(dead_code)]
 
+mod convert;
 mod gif_encoder;
 mod mp4_stream;
+pub mod recovery;
 mod spool;
+mod timelapse;
+mod zoom;
 
+pub use convert::{convert_media, ConvertOptions, ConvertTarget};
 pub use gif_encoder::{is_ffmpeg_available, GifRecorder};
-pub use mp4_stream::ffmpeg_command;
+pub use mp4_stream::{cut_video, ffmpeg_command, StreamTarget};
+pub use timelapse::{assemble_timelapse, timelapse_frames, TimelapseOptions};
 
 use std::time::Duration;
 
+// how long before max_duration cuts a recording off the user is warned
+pub const MAX_DURATION_WARNING: Duration = Duration::from_secs(10);
+
+/// whether a recording `elapsed` into its `max_duration` cap is inside the
+/// warning window. a cap no longer than the window never warns (there'd be no
+/// time to react) and a zero cap means there is none
+pub fn nears_max_duration(elapsed: Duration, max_duration: Duration) -> bool {
+    max_duration > MAX_DURATION_WARNING && elapsed + MAX_DURATION_WARNING >= max_duration
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RecordingState {
     Idle,
@@ -34,6 +50,44 @@ pub enum StopReason {
     EncoderFailed,
 }
 
+// how the capture loop kept up with the target rate. a sample is a tick that
+// produced a usable screen grab, whether it was kept or folded into the
+// previous frame as a duplicate; a slot the loop never sampled (a slow grab
+// overran it, or the grab failed) is what makes a recording look choppy
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameStats {
+    pub target_fps: u32,
+    pub elapsed: Duration,
+    pub sampled: u32,
+    pub kept: u32,
+    pub failed: u32,
+}
+
+impl FrameStats {
+    pub fn achieved_fps(&self) -> f32 {
+        let secs = self.elapsed.as_secs_f32();
+        if secs <= 0.0 {
+            return 0.0;
+        }
+        self.sampled as f32 / secs
+    }
+
+    /// slots at the target rate that went by without a sample
+    pub fn dropped(&self) -> u32 {
+        let expected = (self.elapsed.as_secs_f64() * self.target_fps as f64).floor() as u32;
+        expected.saturating_sub(self.sampled)
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "{:.1} of {} fps, {} dropped",
+            self.achieved_fps(),
+            self.target_fps,
+            self.dropped()
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RecordingSettings {
     pub fps: u32,
@@ -48,6 +102,12 @@ pub struct RecordingSettings {
     // decides the frame sink: gif spools raw frames to disk for a post-stop
     // encode, mp4 streams into a live ffmpeg child
     pub format: RecordingFormat,
+    // apps kept out of every frame, from capture.exclusion
+    pub exclusion: crate::config::ExclusionConfig,
+    // zoom-to-cursor for region recordings, from capture.zoom
+    pub zoom: crate::config::ZoomConfig,
+    // push the mp4 pipe to this ingest instead of a file; nothing is saved
+    pub stream: Option<StreamTarget>,
 }
 
 impl Default for RecordingSettings {
@@ -60,6 +120,9 @@ impl Default for RecordingSettings {
             show_cursor: false,
             record_audio: false,
             format: RecordingFormat::Gif,
+            exclusion: Default::default(),
+            zoom: Default::default(),
+            stream: None,
         }
     }
 }
@@ -78,11 +141,52 @@ mod tests {
             show_cursor: false,
             record_audio: false,
             format: RecordingFormat::Mp4,
+            exclusion: Default::default(),
+            zoom: Default::default(),
+            stream: None,
         };
         assert_eq!(settings.quality, 90);
         assert_eq!(settings.fps, 30);
     }
 
+    #[test]
+    fn frame_stats_count_unsampled_slots_as_dropped() {
+        let stats = FrameStats {
+            target_fps: 10,
+            elapsed: Duration::from_millis(2050),
+            sampled: 15,
+            kept: 9,
+            failed: 1,
+        };
+        assert_eq!(stats.dropped(), 5);
+        assert!((stats.achieved_fps() - 7.317).abs() < 0.01);
+
+        // a loop that ran ahead of the clock never reports negative drops
+        let ahead = FrameStats {
+            sampled: 30,
+            ..stats
+        };
+        assert_eq!(ahead.dropped(), 0);
+        assert_eq!(FrameStats::default().achieved_fps(), 0.0);
+    }
+
+    #[test]
+    fn warning_covers_the_last_ten_seconds_of_a_capped_recording() {
+        let cap = Duration::from_secs(30);
+        assert!(!nears_max_duration(Duration::from_secs(19), cap));
+        assert!(nears_max_duration(Duration::from_secs(20), cap));
+        assert!(nears_max_duration(Duration::from_secs(29), cap));
+        // too short to warn about, or uncapped
+        assert!(!nears_max_duration(
+            Duration::from_secs(5),
+            Duration::from_secs(10)
+        ));
+        assert!(!nears_max_duration(
+            Duration::from_secs(600),
+            Duration::ZERO
+        ));
+    }
+
     #[test]
     fn test_recording_state() {
         assert_eq!(RecordingState::Idle, RecordingState::Idle);
@@ -1,20 +1,46 @@
 use anyhow::{anyhow, Result};
 use image::RgbaImage;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Child, ChildStdin, Stdio};
 use std::time::Duration;
 
 use super::gif_encoder::find_ffmpeg;
 
+/// an rtmp ingest a live recording is pushed to
+#[derive(Debug, Clone)]
+pub struct StreamTarget {
+    pub url: String,
+    pub bitrate_kbps: u32,
+}
+
+impl StreamTarget {
+    pub fn validate(&self) -> Result<()> {
+        let scheme = self.url.split("://").next().unwrap_or_default();
+        if !self.url.contains("://")
+            || !["rtmp", "rtmps"].contains(&scheme.to_ascii_lowercase().as_str())
+        {
+            return Err(anyhow!("stream url must start with rtmp:// or rtmps://"));
+        }
+        Ok(())
+    }
+}
+
+enum VideoOutput {
+    File(PathBuf),
+    // flv over rtmp; nothing lands on disk
+    Live(StreamTarget),
+}
+
 // encodes the recording as it happens: frames go straight into a live ffmpeg
 // child, so an hour-long capture costs one frame of RAM and saving is a remux
 // instead of a full re-encode. ffmpeg spawns lazily on the first frame because
-// the pipe needs pixel dimensions up front
+// the pipe needs pixel dimensions up front. the same pipe can feed an rtmp
+// ingest instead of a file, which is all a live stream is
 pub struct Mp4Streamer {
     fps: u32,
     crf: u8,
-    temp_path: PathBuf,
+    output: VideoOutput,
     child: Option<Child>,
     stdin: Option<ChildStdin>,
     width: u32,
@@ -29,14 +55,23 @@ pub struct Mp4Streamer {
 
 impl Mp4Streamer {
     pub fn new(fps: u32, crf: u8) -> Self {
-        let temp_path = std::env::temp_dir().join(format!(
+        let temp_path = crate::scratch::dir().join(format!(
             "capscr_video_{}.mp4",
             uuid::Uuid::new_v4().as_simple()
         ));
+        Self::with_output(fps, crf, VideoOutput::File(temp_path))
+    }
+
+    /// push to `target` live instead of writing a file
+    pub fn live(fps: u32, target: StreamTarget) -> Self {
+        Self::with_output(fps, 23, VideoOutput::Live(target))
+    }
+
+    fn with_output(fps: u32, crf: u8, output: VideoOutput) -> Self {
         Self {
             fps: fps.clamp(1, 60),
             crf: crf.min(51),
-            temp_path,
+            output,
             child: None,
             stdin: None,
             width: 0,
@@ -67,6 +102,20 @@ impl Mp4Streamer {
     /// flushes the pending frame with one nominal hold, closes the pipe, and
     /// waits for ffmpeg. returns the finished video-only temp file
     pub fn finish(&mut self) -> Result<PathBuf> {
+        let path = match &self.output {
+            VideoOutput::File(path) => path.clone(),
+            VideoOutput::Live(_) => return Err(anyhow!("a live stream has no file to save")),
+        };
+        self.close()?;
+        Ok(path)
+    }
+
+    /// flush what's left and hang up on the ingest
+    pub fn end_stream(&mut self) -> Result<()> {
+        self.close()
+    }
+
+    fn close(&mut self) -> Result<()> {
         if let Some((img, at)) = self.pending.take() {
             let nominal = Duration::from_secs_f64(1.0 / self.fps as f64);
             self.write_span(&img, at, at.saturating_add(nominal))?;
@@ -86,37 +135,13 @@ impl Mp4Streamer {
         if !status.success() {
             return Err(anyhow!("ffmpeg exited with error status: {}", status));
         }
-        Ok(self.temp_path.clone())
+        Ok(())
     }
 
     fn spawn_encoder(&mut self, width: u32, height: u32) -> Result<()> {
         (self.width, self.height) = even_dims(width, height);
 
-        let crf = self.crf.to_string();
-        let args = [
-            "-f",
-            "rawvideo",
-            "-pix_fmt",
-            "rgba",
-            "-s",
-            &format!("{}x{}", self.width, self.height),
-            "-r",
-            &self.fps.to_string(),
-            "-i",
-            "-",
-            "-c:v",
-            "libx264",
-            // realtime encode must keep up with capture or the pipe stalls
-            "-preset",
-            "veryfast",
-            "-pix_fmt",
-            "yuv420p",
-            "-crf",
-            &crf,
-            "-y",
-            &self.temp_path.to_string_lossy(),
-        ];
-
+        let args = encoder_args(&self.output, self.fps, self.crf, self.width, self.height);
         let mut child = ffmpeg_command()
             .args(args)
             .stdin(Stdio::piped())
@@ -193,8 +218,65 @@ impl Drop for Mp4Streamer {
             let _ = child.kill();
             let _ = child.wait();
         }
-        let _ = std::fs::remove_file(&self.temp_path);
+        if let VideoOutput::File(path) = &self.output {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+fn encoder_args(output: &VideoOutput, fps: u32, crf: u8, width: u32, height: u32) -> Vec<String> {
+    let mut args: Vec<String> = [
+        "-f",
+        "rawvideo",
+        "-pix_fmt",
+        "rgba",
+        "-s",
+        &format!("{width}x{height}"),
+        "-r",
+        &fps.to_string(),
+        "-i",
+        "-",
+        "-c:v",
+        "libx264",
+        // realtime encode must keep up with capture or the pipe stalls
+        "-preset",
+        "veryfast",
+        "-pix_fmt",
+        "yuv420p",
+    ]
+    .map(String::from)
+    .into();
+    match output {
+        VideoOutput::File(path) => {
+            args.extend(["-crf".into(), crf.to_string(), "-y".into()]);
+            args.push(path.to_string_lossy().into_owned());
+        }
+        VideoOutput::Live(target) => {
+            // ingests want a capped bitrate and a keyframe every two seconds
+            // so viewers can join mid-stream; zerolatency drops the lookahead
+            // that would otherwise hold frames back
+            let rate = format!("{}k", target.bitrate_kbps);
+            args.extend(
+                [
+                    "-tune",
+                    "zerolatency",
+                    "-b:v",
+                    &rate,
+                    "-maxrate",
+                    &rate,
+                    "-bufsize",
+                    &format!("{}k", target.bitrate_kbps * 2),
+                    "-g",
+                    &(fps * 2).to_string(),
+                    "-f",
+                    "flv",
+                    &target.url,
+                ]
+                .map(String::from),
+            );
+        }
     }
+    args
 }
 
 // libx264 needs even dimensions for yuv420p
@@ -202,6 +284,53 @@ fn even_dims(width: u32, height: u32) -> (u32, u32) {
     (width.max(2) & !1, height.max(2) & !1)
 }
 
+/// cut a finished recording down to [start, end) at `dest`. re-encodes so the
+/// in point lands on the exact frame the user picked rather than the keyframe
+/// before it, and keeps the audio track when there is one
+pub fn cut_video(src: &Path, dest: &Path, start: Duration, end: Duration, crf: u8) -> Result<()> {
+    let length = end.saturating_sub(start);
+    if length < Duration::from_millis(50) {
+        return Err(anyhow!("trim must be at least 0.05s and end after start"));
+    }
+    // the cut is re-encoded at the recording's own crf, so the whole source
+    // is a safe ceiling on its size
+    if let Some(dir) = dest.parent() {
+        let src_len = std::fs::metadata(src).map_or(0, |m| m.len());
+        crate::disk::ensure_room(dir, src_len, "trimmed recording")?;
+    }
+    let output = ffmpeg_command()
+        .args([
+            "-i",
+            &src.to_string_lossy(),
+            "-ss",
+            &format!("{:.3}", start.as_secs_f64()),
+            "-t",
+            &format!("{:.3}", length.as_secs_f64()),
+            "-c:v",
+            "libx264",
+            "-pix_fmt",
+            "yuv420p",
+            "-crf",
+            &crf.min(51).to_string(),
+            "-c:a",
+            "aac",
+            "-y",
+            &dest.to_string_lossy(),
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| anyhow!("Failed to launch ffmpeg: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let tail = stderr.trim().lines().last().unwrap_or("ffmpeg error");
+        let _ = std::fs::remove_file(dest);
+        return Err(anyhow!("ffmpeg failed: {tail}"));
+    }
+    Ok(())
+}
+
 pub fn ffmpeg_command() -> std::process::Command {
     let cmd = std::process::Command::new(find_ffmpeg());
     #[cfg(windows)]
@@ -232,11 +361,48 @@ mod tests {
         let path;
         {
             let s = Mp4Streamer::new(15, 23);
-            path = s.temp_path.clone();
+            let VideoOutput::File(p) = &s.output else {
+                unreachable!()
+            };
+            path = p.clone();
         }
         assert!(!path.exists());
     }
 
+    #[test]
+    fn live_output_pushes_flv_at_a_fixed_bitrate() {
+        let target = StreamTarget {
+            url: "rtmp://127.0.0.1/live/key".into(),
+            bitrate_kbps: 3000,
+        };
+        let args = encoder_args(&VideoOutput::Live(target.clone()), 30, 23, 1280, 720);
+        let after = |flag: &str| {
+            let i = args.iter().position(|a| a == flag).unwrap();
+            args[i + 1].as_str()
+        };
+        assert_eq!(after("-b:v"), "3000k");
+        assert_eq!(after("-g"), "60");
+        assert_eq!(after("-f"), "rawvideo");
+        assert_eq!(args.last().unwrap(), "rtmp://127.0.0.1/live/key");
+        assert!(!args.iter().any(|a| a == "-crf"));
+
+        let mut live = Mp4Streamer::live(30, target);
+        assert!(live.finish().is_err(), "a stream has no file to hand back");
+    }
+
+    #[test]
+    fn stream_urls_must_be_rtmp() {
+        let target = |url: &str| StreamTarget {
+            url: url.into(),
+            bitrate_kbps: 4500,
+        };
+        assert!(target("rtmp://live.twitch.tv/app/key").validate().is_ok());
+        assert!(target("RTMPS://a.example/live").validate().is_ok());
+        assert!(target("https://example.com").validate().is_err());
+        assert!(target("").validate().is_err());
+        assert!(target("/tmp/out.flv").validate().is_err());
+    }
+
     // duration from the mvhd box: version byte decides 32- vs 64-bit fields
     fn mp4_duration_secs(bytes: &[u8]) -> Option<f64> {
         let pos = bytes.windows(4).position(|w| w == b"mvhd")?;
@@ -0,0 +1,110 @@
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use super::gif_encoder::encode_gif;
+use super::spool::{FrameSpool, SpoolIndex};
+use crate::config::Config;
+
+// gif recordings the app went down with (a panic, or a quit whose save
+// failed) keep their frame spool here until the next launch encodes or
+// discards them. mp4 frames went to ffmpeg as they arrived and aren't kept
+pub fn dir() -> Option<PathBuf> {
+    Config::config_dir().map(|d| d.join("recovery"))
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecoveredRecording {
+    pub id: String,
+    pub frames: usize,
+    pub duration_ms: u64,
+    /// when the app kept it, unix seconds
+    pub kept_at: u64,
+}
+
+pub fn list() -> Vec<RecoveredRecording> {
+    let Some(dir) = dir() else {
+        return Vec::new();
+    };
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+    let mut found: Vec<RecoveredRecording> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension()? != "rgba" {
+                return None;
+            }
+            let id = path.file_stem()?.to_str()?.to_string();
+            // the index alone: opening the spool would delete it on close
+            let index = SpoolIndex::read(&path).ok()?;
+            let frames = index.frames().len();
+            let duration_ms = index.frames().last().map_or(0, |m| m.at.as_millis() as u64);
+            let kept_at = entry
+                .metadata()
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map_or(0, |d| d.as_secs());
+            Some(RecoveredRecording {
+                id,
+                frames,
+                duration_ms,
+                kept_at,
+            })
+        })
+        .collect();
+    found.sort_by_key(|r| std::cmp::Reverse(r.kept_at));
+    found
+}
+
+/// encode a kept recording as a gif at `out`, then drop it from the folder
+pub fn restore(id: &str, out: &Path) -> Result<()> {
+    let path = spool_path(id)?;
+    let (mut spool, index) = FrameSpool::open_preserved(&path)?;
+    if spool.is_empty() {
+        return Err(anyhow!("the kept recording has no readable frames"));
+    }
+    encode_gif(&mut spool, index.fps, index.quality, out)?;
+    drop(spool);
+    let _ = std::fs::remove_file(path.with_extension("json"));
+    Ok(())
+}
+
+pub fn discard(id: &str) -> Result<()> {
+    let path = spool_path(id)?;
+    let _ = std::fs::remove_file(path.with_extension("json"));
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+// ids come back from the webview; only a bare spool name may reach the folder
+fn spool_path(id: &str) -> Result<PathBuf> {
+    if !is_spool_id(id) {
+        return Err(anyhow!("not a recovered recording: {id}"));
+    }
+    let dir = dir().ok_or_else(|| anyhow!("no config directory"))?;
+    Ok(dir.join(format!("{id}.rgba")))
+}
+
+fn is_spool_id(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_bare_spool_names_are_ids() {
+        assert!(is_spool_id("capscr_frames_0123abcd"));
+        assert!(!is_spool_id(""));
+        assert!(!is_spool_id("../config"));
+        assert!(!is_spool_id("a/b"));
+        assert!(!is_spool_id("frames.json"));
+    }
+}
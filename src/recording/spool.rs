@@ -1,8 +1,9 @@
 use anyhow::{anyhow, Result};
 use image::RgbaImage;
+use serde::{Deserialize, Serialize};
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Seek, SeekFrom, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 // keep at least this much of the volume free for the rest of the system
@@ -13,7 +14,7 @@ const MIN_SPOOL_BYTES: u64 = 64 * 1024 * 1024;
 // mid-recording still stops us before the disk runs dry
 const REPROBE_INTERVAL: usize = 64;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct FrameMeta {
     pub at: Duration,
     pub width: u32,
@@ -23,19 +24,44 @@ pub struct FrameMeta {
 
 // disk-backed frame store: recording RAM stays flat no matter how long the
 // capture runs. frames are raw rgba so the capture thread pays one sequential
-// write per frame and no encode cost; the file lives in the temp dir and is
-// removed on drop
+// write per frame and no encode cost; the file lives in the scratch folder
+// and is removed on drop
 pub struct FrameSpool {
     file: File,
     path: PathBuf,
     metas: Vec<FrameMeta>,
     bytes_written: u64,
     byte_budget: u64,
+    // set once the file has been handed to the recovery folder; drop leaves
+    // it there
+    preserved: bool,
+}
+
+// the `.json` beside a preserved spool: everything needed to encode it again
+// in a later run
+#[derive(Serialize, Deserialize)]
+pub struct SpoolIndex {
+    pub fps: u32,
+    pub quality: u8,
+    frames: Vec<FrameMeta>,
+}
+
+impl SpoolIndex {
+    /// the index kept beside the preserved spool at `path`
+    pub fn read(path: &Path) -> Result<Self> {
+        Ok(serde_json::from_slice(&std::fs::read(
+            path.with_extension("json"),
+        )?)?)
+    }
+
+    pub fn frames(&self) -> &[FrameMeta] {
+        &self.frames
+    }
 }
 
 impl FrameSpool {
     pub fn create() -> Result<Self> {
-        let path = std::env::temp_dir().join(format!(
+        let path = crate::scratch::dir().join(format!(
             "capscr_frames_{}.rgba",
             uuid::Uuid::new_v4().as_simple()
         ));
@@ -44,16 +70,74 @@ impl FrameSpool {
             .write(true)
             .create_new(true)
             .open(&path)?;
-        let byte_budget = spool_budget(free_disk_space(&path));
+        let byte_budget = spool_budget(path.parent().and_then(crate::disk::free_space));
         Ok(Self {
             file,
             path,
             metas: Vec::new(),
             bytes_written: 0,
             byte_budget,
+            preserved: false,
         })
     }
 
+    /// move the frames into `dir` with their index beside them, so a
+    /// recording the app couldn't save can be encoded on the next launch.
+    /// returns where the frames now are
+    pub fn preserve(&mut self, dir: &Path, fps: u32, quality: u8) -> Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+        self.file.flush()?;
+        let name = self
+            .path
+            .file_name()
+            .ok_or_else(|| anyhow!("spool has no file name"))?;
+        let kept = dir.join(name);
+        // the temp dir is often another volume (or a tmpfs gone at reboot),
+        // where a rename can't reach
+        let renamed = std::fs::rename(&self.path, &kept).is_ok();
+        if !renamed {
+            std::fs::copy(&self.path, &kept)?;
+        }
+        let index = SpoolIndex {
+            fps,
+            quality,
+            frames: self.metas.clone(),
+        };
+        std::fs::write(kept.with_extension("json"), serde_json::to_vec(&index)?)?;
+        // swap the handle before the old file goes; windows can't remove a
+        // file that is still open
+        self.file = OpenOptions::new().read(true).write(true).open(&kept)?;
+        if !renamed {
+            let _ = std::fs::remove_file(&self.path);
+        }
+        self.path = kept.clone();
+        self.preserved = true;
+        Ok(kept)
+    }
+
+    /// reopen frames kept by `preserve`. dropping the spool removes them again
+    pub fn open_preserved(path: &Path) -> Result<(Self, SpoolIndex)> {
+        let index = SpoolIndex::read(path)?;
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        let bytes_written = file.metadata()?.len();
+        // a crash can cut the last write short; drop any frame past the end
+        let metas: Vec<FrameMeta> = index
+            .frames
+            .iter()
+            .copied()
+            .filter(|m| m.offset + u64::from(m.width) * u64::from(m.height) * 4 <= bytes_written)
+            .collect();
+        let spool = Self {
+            file,
+            path: path.to_path_buf(),
+            metas,
+            bytes_written,
+            byte_budget: MAX_SPOOL_BYTES,
+            preserved: false,
+        };
+        Ok((spool, index))
+    }
+
     /// appends a frame. Ok(false) means the disk budget is exhausted and the
     /// frame was not written — the recording should stop gracefully
     pub fn push(&mut self, image: &RgbaImage, at: Duration) -> Result<bool> {
@@ -62,7 +146,12 @@ impl FrameSpool {
             return Ok(false);
         }
         if self.metas.len().is_multiple_of(REPROBE_INTERVAL) {
-            let live = spool_budget(free_disk_space(&self.path).map(|f| f + self.bytes_written));
+            let live = spool_budget(
+                self.path
+                    .parent()
+                    .and_then(crate::disk::free_space)
+                    .map(|f| f + self.bytes_written),
+            );
             self.byte_budget = self.byte_budget.min(live);
             if self.bytes_written.saturating_add(len) > self.byte_budget {
                 return Ok(false);
@@ -113,7 +202,9 @@ impl FrameSpool {
 
 impl Drop for FrameSpool {
     fn drop(&mut self) {
-        let _ = std::fs::remove_file(&self.path);
+        if !self.preserved {
+            let _ = std::fs::remove_file(&self.path);
+        }
     }
 }
 
@@ -126,41 +217,6 @@ fn spool_budget(free: Option<u64>) -> u64 {
     }
 }
 
-#[cfg(windows)]
-fn free_disk_space(path: &std::path::Path) -> Option<u64> {
-    use windows::core::PCWSTR;
-    use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
-
-    let dir = path.parent()?;
-    let wide: Vec<u16> = dir
-        .as_os_str()
-        .to_string_lossy()
-        .encode_utf16()
-        .chain(std::iter::once(0))
-        .collect();
-    let mut available: u64 = 0;
-    unsafe {
-        GetDiskFreeSpaceExW(PCWSTR(wide.as_ptr()), Some(&mut available), None, None).ok()?;
-    }
-    Some(available)
-}
-
-#[cfg(target_os = "linux")]
-fn free_disk_space(path: &std::path::Path) -> Option<u64> {
-    use std::os::unix::ffi::OsStrExt;
-    let dir = std::ffi::CString::new(path.parent()?.as_os_str().as_bytes()).ok()?;
-    let mut stats: libc::statvfs = unsafe { std::mem::zeroed() };
-    // f_bavail = blocks available to unprivileged users, matching the windows
-    // call's lpFreeBytesAvailable semantics
-    (unsafe { libc::statvfs(dir.as_ptr(), &mut stats) } == 0)
-        .then(|| stats.f_bavail as u64 * stats.f_frsize as u64)
-}
-
-#[cfg(not(any(windows, target_os = "linux")))]
-fn free_disk_space(_path: &std::path::Path) -> Option<u64> {
-    None
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,13 +246,6 @@ mod tests {
         assert_eq!(spool.metas()[3].at, Duration::from_millis(300));
     }
 
-    #[cfg(any(windows, target_os = "linux"))]
-    #[test]
-    fn free_disk_space_reports_a_nonzero_figure() {
-        let probe = std::env::temp_dir().join("capscr-spool-probe");
-        assert!(free_disk_space(&probe).is_some_and(|bytes| bytes > 0));
-    }
-
     #[test]
     fn push_still_appends_after_a_read() {
         let mut spool = FrameSpool::create().unwrap();
@@ -230,6 +279,39 @@ mod tests {
         assert!(!path.exists());
     }
 
+    #[test]
+    fn preserved_frames_reopen_in_a_later_run() {
+        let dir = std::env::temp_dir().join(format!(
+            "capscr-recovery-test-{}",
+            uuid::Uuid::new_v4().as_simple()
+        ));
+        let kept;
+        {
+            let mut spool = FrameSpool::create().unwrap();
+            for i in 0..3u8 {
+                spool
+                    .push(
+                        &solid_frame(8, 4, i * 50),
+                        Duration::from_millis(i as u64 * 40),
+                    )
+                    .unwrap();
+            }
+            let temp = spool.path.clone();
+            kept = spool.preserve(&dir, 12, 80).unwrap();
+            assert!(!temp.exists());
+        }
+        // the preserved spool outlives its drop
+        assert!(kept.exists());
+        let (mut spool, index) = FrameSpool::open_preserved(&kept).unwrap();
+        assert_eq!((index.fps, index.quality), (12, 80));
+        assert_eq!(spool.len(), 3);
+        assert_eq!(spool.read_frame(2).unwrap().get_pixel(1, 1)[0], 100);
+        assert_eq!(spool.metas()[1].at, Duration::from_millis(40));
+        drop(spool);
+        assert!(!kept.exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn budget_respects_reserve_and_caps() {
         assert_eq!(spool_budget(None), MAX_SPOOL_BYTES);
@@ -0,0 +1,194 @@
+use anyhow::{anyhow, Result};
+use image::RgbaImage;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::gif_encoder::encode_gif;
+use super::mp4_stream::Mp4Streamer;
+use super::spool::FrameSpool;
+use super::RecordingFormat;
+
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "bmp"];
+// a day of 5s interval captures; beyond this a single assembly is unwieldy
+const MAX_TIMELAPSE_FRAMES: usize = 20_000;
+// gif can't time frames shorter than 2cs, so it tops out at 50fps
+const MAX_GIF_FPS: u32 = 50;
+const MAX_MP4_FPS: u32 = 60;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TimelapseOptions {
+    pub format: RecordingFormat,
+    pub fps: u32,
+    // frames wider than this are downscaled (aspect kept); 0 keeps the size
+    pub max_width: u32,
+    pub quality: u8,
+    pub video_crf: u8,
+}
+
+/// the still images in `dir`, in filename order. interval captures are named by
+/// timestamp, so that's capture order
+pub fn timelapse_frames(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut frames: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && !crate::capture::is_hdr_sidecar(&path.to_string_lossy())
+                && path
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| IMAGE_EXTENSIONS.contains(&e.to_ascii_lowercase().as_str()))
+        })
+        .collect();
+    frames.sort();
+    if frames.is_empty() {
+        return Err(anyhow!("no images found in {}", dir.display()));
+    }
+    if frames.len() > MAX_TIMELAPSE_FRAMES {
+        return Err(anyhow!(
+            "{} images is more than the {MAX_TIMELAPSE_FRAMES}-frame time-lapse limit",
+            frames.len()
+        ));
+    }
+    Ok(frames)
+}
+
+/// play `frames` back one per tick at `options.fps` and write the result to
+/// `dest`. unreadable images are skipped rather than failing the whole run
+pub fn assemble_timelapse(
+    frames: &[PathBuf],
+    dest: &Path,
+    options: TimelapseOptions,
+) -> Result<()> {
+    let fps = match options.format {
+        RecordingFormat::Gif => options.fps.clamp(1, MAX_GIF_FPS),
+        RecordingFormat::Mp4 => options.fps.clamp(1, MAX_MP4_FPS),
+    };
+    let tick = Duration::from_secs_f64(1.0 / fps as f64);
+    let load = |path: &PathBuf| -> Option<RgbaImage> {
+        match image::open(path) {
+            Ok(img) => Some(fit_width(img.to_rgba8(), options.max_width)),
+            Err(e) => {
+                tracing::warn!("time-lapse: skipping {}: {e}", path.display());
+                None
+            }
+        }
+    };
+
+    if let Some(parent) = dest.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+
+    match options.format {
+        RecordingFormat::Gif => {
+            let mut spool = FrameSpool::create()?;
+            for image in frames.iter().filter_map(load) {
+                let at = tick * spool.len() as u32;
+                if !spool.push(&image, at)? {
+                    return Err(anyhow!(
+                        "not enough free disk space to assemble the time-lapse"
+                    ));
+                }
+            }
+            encode_gif(&mut spool, fps, options.quality, dest)
+        }
+        RecordingFormat::Mp4 => {
+            let mut streamer = Mp4Streamer::new(fps, options.video_crf);
+            for image in frames.iter().filter_map(load) {
+                let at = tick * streamer.frames_pushed() as u32;
+                streamer.push(image, at)?;
+            }
+            if streamer.frames_pushed() == 0 {
+                return Err(anyhow!("none of the images could be read"));
+            }
+            let temp = streamer.finish()?;
+            if std::fs::rename(&temp, dest).is_err() {
+                // temp dir and output dir may sit on different volumes
+                std::fs::copy(&temp, dest)
+                    .map_err(|e| anyhow!("Failed to move time-lapse into place: {}", e))?;
+                let _ = std::fs::remove_file(&temp);
+            }
+            Ok(())
+        }
+    }
+}
+
+fn fit_width(image: RgbaImage, max_width: u32) -> RgbaImage {
+    if max_width == 0 || image.width() <= max_width {
+        return image;
+    }
+    let height = ((image.height() as u64 * max_width as u64) / image.width() as u64).max(1) as u32;
+    image::imageops::resize(
+        &image,
+        max_width,
+        height,
+        image::imageops::FilterType::Triangle,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_are_images_in_name_order() {
+        let dir = std::env::temp_dir().join(format!(
+            "capscr_timelapse_{}",
+            uuid::Uuid::new_v4().as_simple()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let img = RgbaImage::from_pixel(4, 4, image::Rgba([1, 2, 3, 255]));
+        for name in ["auto_2.png", "auto_1.png", "auto_1.hdr.png"] {
+            img.save(dir.join(name)).unwrap();
+        }
+        std::fs::write(dir.join("notes.txt"), "x").unwrap();
+        let frames = timelapse_frames(&dir).unwrap();
+        let names: Vec<_> = frames
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(names, ["auto_1.png", "auto_2.png"]);
+    }
+
+    #[test]
+    fn wide_frames_are_scaled_to_max_width() {
+        let img = RgbaImage::new(400, 200);
+        let fitted = fit_width(img.clone(), 100);
+        assert_eq!(fitted.dimensions(), (100, 50));
+        assert_eq!(fit_width(img, 0).dimensions(), (400, 200));
+    }
+
+    #[test]
+    fn gif_timelapse_assembles() {
+        let dir = std::env::temp_dir().join(format!(
+            "capscr_timelapse_{}",
+            uuid::Uuid::new_v4().as_simple()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        for i in 0..3u8 {
+            RgbaImage::from_pixel(8, 8, image::Rgba([i * 80, 0, 0, 255]))
+                .save(dir.join(format!("auto_{i}.png")))
+                .unwrap();
+        }
+        let frames = timelapse_frames(&dir).unwrap();
+        let dest = dir.join("out.gif");
+        assemble_timelapse(
+            &frames,
+            &dest,
+            TimelapseOptions {
+                format: RecordingFormat::Gif,
+                fps: 10,
+                max_width: 0,
+                quality: 80,
+                video_crf: 23,
+            },
+        )
+        .unwrap();
+        let written = std::fs::metadata(&dest).map(|m| m.len()).unwrap_or(0);
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(written > 0);
+    }
+}
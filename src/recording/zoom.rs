@@ -0,0 +1,212 @@
+// automatic zoom-to-cursor. a virtual camera eases in on the pointer while it
+// moves and drifts back out to the whole region once it has been still for a
+// moment, so a tutorial reads as edited without anyone editing it. it runs on
+// kept frames just before they reach the sink, after the cursor composite, so
+// the pointer scales along with everything under it. output frames keep the
+// region's size — neither sink can change dimensions mid-recording
+
+use std::time::Duration;
+
+use image::imageops::{self, FilterType};
+use image::RgbaImage;
+
+use crate::config::ZoomConfig;
+
+// how long the pointer has to sit still before the camera pulls back out
+const IDLE_ZOOM_OUT: Duration = Duration::from_millis(1500);
+// hand jitter on a resting mouse shouldn't count as activity
+const MOVE_THRESHOLD_PX: f32 = 4.0;
+// below this the camera is treated as fully zoomed out and frames pass through
+const SCALE_EPSILON: f32 = 0.005;
+// a stall longer than this (a dropped run of frames) shouldn't let the camera
+// leap in one step
+const MAX_STEP: Duration = Duration::from_millis(250);
+
+pub struct ZoomCamera {
+    factor: f32,
+    smoothing: Duration,
+    scale: f32,
+    // camera centre in frame pixels; None until the first frame sizes it
+    center: Option<(f32, f32)>,
+    last_cursor: Option<(f32, f32)>,
+    last_moved: Option<Duration>,
+    last_at: Option<Duration>,
+}
+
+impl ZoomCamera {
+    pub fn new(config: &ZoomConfig) -> Self {
+        Self {
+            factor: (config.zoom_percent as f32 / 100.0).max(1.0),
+            smoothing: Duration::from_millis(config.smoothing_ms as u64),
+            scale: 1.0,
+            center: None,
+            last_cursor: None,
+            last_moved: None,
+            last_at: None,
+        }
+    }
+
+    /// still easing somewhere, so a frame that looks like the last one
+    /// isn't a duplicate once the camera is applied
+    pub fn in_motion(&self) -> bool {
+        let target = if self.active(self.last_at.unwrap_or_default()) {
+            self.factor
+        } else {
+            1.0
+        };
+        (self.scale - target).abs() > SCALE_EPSILON
+    }
+
+    fn active(&self, at: Duration) -> bool {
+        self.last_moved
+            .is_some_and(|moved| at.saturating_sub(moved) < IDLE_ZOOM_OUT)
+    }
+
+    /// advance the camera to `at` and frame `img` through it. `cursor` is the
+    /// pointer in frame pixels, None when it's outside the frame or unknown
+    pub fn apply(&mut self, img: RgbaImage, cursor: Option<(f32, f32)>, at: Duration) -> RgbaImage {
+        let (width, height) = (img.width() as f32, img.height() as f32);
+        if width < 2.0 || height < 2.0 {
+            return img;
+        }
+        let middle = (width / 2.0, height / 2.0);
+        let cursor = cursor.filter(|&(x, y)| x >= 0.0 && y >= 0.0 && x < width && y < height);
+
+        if let Some(now) = cursor {
+            let moved = self.last_cursor.is_none_or(|then| {
+                (now.0 - then.0).abs() > MOVE_THRESHOLD_PX
+                    || (now.1 - then.1).abs() > MOVE_THRESHOLD_PX
+            });
+            // the first sighting only seeds the position; a pointer that was
+            // already resting there when recording began isn't activity
+            if moved && self.last_cursor.is_some() {
+                self.last_moved = Some(at);
+            }
+            self.last_cursor = Some(now);
+        } else {
+            // leaving the frame ends the zoom instead of parking on the edge
+            self.last_cursor = None;
+            self.last_moved = None;
+        }
+
+        let step = self
+            .last_at
+            .map_or(Duration::ZERO, |then| at.saturating_sub(then))
+            .min(MAX_STEP);
+        self.last_at = Some(at);
+        let blend = if self.smoothing.is_zero() {
+            1.0
+        } else {
+            1.0 - (-step.as_secs_f32() / self.smoothing.as_secs_f32()).exp()
+        };
+
+        let (target_scale, target_center) = match (self.active(at), self.last_cursor) {
+            (true, Some(cursor)) => (self.factor, cursor),
+            _ => (1.0, middle),
+        };
+        let center = self.center.unwrap_or(middle);
+        self.scale += (target_scale - self.scale) * blend;
+        let center = (
+            center.0 + (target_center.0 - center.0) * blend,
+            center.1 + (target_center.1 - center.1) * blend,
+        );
+        self.center = Some(center);
+
+        if self.scale - 1.0 < SCALE_EPSILON {
+            return img;
+        }
+        let (x, y, view_w, view_h) = viewport((width, height), center, self.scale);
+        let view = imageops::crop_imm(&img, x, y, view_w, view_h).to_image();
+        imageops::resize(&view, img.width(), img.height(), FilterType::Triangle)
+    }
+}
+
+// the crop a camera at `center` and `scale` sees, slid back inside the frame
+// near the edges rather than showing anything past them
+fn viewport((width, height): (f32, f32), center: (f32, f32), scale: f32) -> (u32, u32, u32, u32) {
+    let view_w = (width / scale).round().clamp(1.0, width);
+    let view_h = (height / scale).round().clamp(1.0, height);
+    let x = (center.0 - view_w / 2.0).clamp(0.0, width - view_w);
+    let y = (center.1 - view_h / 2.0).clamp(0.0, height - view_h);
+    (x as u32, y as u32, view_w as u32, view_h as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    fn camera(smoothing_ms: u32) -> ZoomCamera {
+        ZoomCamera::new(&ZoomConfig {
+            enabled: true,
+            zoom_percent: 200,
+            smoothing_ms,
+        })
+    }
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn viewport_stays_inside_the_frame() {
+        assert_eq!(viewport((100.0, 80.0), (50.0, 40.0), 2.0), (25, 20, 50, 40));
+        // a cursor in the corner pins the view to that corner
+        assert_eq!(viewport((100.0, 80.0), (0.0, 79.0), 2.0), (0, 40, 50, 40));
+        assert_eq!(viewport((100.0, 80.0), (50.0, 40.0), 1.0), (0, 0, 100, 80));
+    }
+
+    #[test]
+    fn zooms_in_on_movement_and_back_out_when_idle() {
+        // left half white, right half black
+        let mut img = RgbaImage::from_pixel(100, 100, Rgba([0, 0, 0, 255]));
+        for y in 0..100 {
+            for x in 0..50 {
+                img.put_pixel(x, y, Rgba([255, 255, 255, 255]));
+            }
+        }
+        let mut cam = camera(0);
+        let first = cam.apply(img.clone(), Some((10.0, 50.0)), ms(0));
+        assert_eq!(first, img, "a resting pointer doesn't trigger the zoom");
+
+        let zoomed = cam.apply(img.clone(), Some((12.0, 50.0)), ms(100));
+        assert_eq!(zoomed, img, "jitter under the threshold is ignored");
+        let zoomed = cam.apply(img.clone(), Some((20.0, 50.0)), ms(200));
+        assert_eq!(zoomed.dimensions(), (100, 100));
+        // zoomed 2x on the left edge: the whole frame is now the white half
+        assert_eq!(zoomed.get_pixel(95, 50), &Rgba([255, 255, 255, 255]));
+
+        let idle = cam.apply(img.clone(), Some((20.0, 50.0)), ms(200) + IDLE_ZOOM_OUT);
+        assert_eq!(idle, img);
+        assert!(!cam.in_motion());
+    }
+
+    #[test]
+    fn smoothing_eases_instead_of_snapping() {
+        let img = RgbaImage::new(100, 100);
+        let mut cam = camera(400);
+        cam.apply(img.clone(), Some((10.0, 10.0)), ms(0));
+        cam.apply(img.clone(), Some((30.0, 30.0)), ms(100));
+        assert!(cam.scale > 1.0 && cam.scale < 2.0);
+        assert!(cam.in_motion());
+        for i in 2..40 {
+            cam.apply(
+                img.clone(),
+                Some((30.0 + i as f32 * 5.0 % 60.0, 30.0)),
+                ms(i * 100),
+            );
+        }
+        assert!((cam.scale - 2.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn leaving_the_frame_ends_the_zoom() {
+        let img = RgbaImage::new(100, 100);
+        let mut cam = camera(0);
+        cam.apply(img.clone(), Some((10.0, 10.0)), ms(0));
+        cam.apply(img.clone(), Some((40.0, 40.0)), ms(50));
+        assert!((cam.scale - 2.0).abs() < SCALE_EPSILON);
+        cam.apply(img.clone(), None, ms(100));
+        assert!((cam.scale - 1.0).abs() < SCALE_EPSILON);
+    }
+}
@@ -0,0 +1,280 @@
+// keeps capture folders inside their retention policy: once a folder holds
+// more files or more bytes than allowed, the oldest go, deleted or moved to
+// the policy's folder. runs shortly after launch and then every few minutes
+// on a thread of its own, reading the config fresh each time; a capture never
+// waits on it. only files directly in a folder that capscr named count — the
+// filename template's names and its own timelapse, stitch and auto capture
+// ones — so the user's own pictures, profile subfolders, archived originals
+// and anything else kept there are left alone. hdr sidecars and editor
+// projects aren't counted but follow their capture, and the newest capture
+// always stays.
+
+use crate::auto_capture::{files_to_prune, FILE_PREFIX};
+use crate::config::RetentionPolicy;
+use crate::state::AppState;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Manager};
+
+const FIRST_RUN: Duration = Duration::from_secs(30);
+const INTERVAL: Duration = Duration::from_secs(10 * 60);
+// what capscr writes: screenshots in every output format, and recordings
+const CAPTURE_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "webp", "bmp", "avif", "jxl", "jxr", "gif", "mp4", "webm",
+];
+// names capscr writes into a capture folder that don't come from the template
+const OWN_PREFIXES: &[&str] = &["timelapse_", "stitch_", FILE_PREFIX];
+
+static STARTED: AtomicBool = AtomicBool::new(false);
+
+/// start the schedule. called once from setup
+pub fn spawn(app: &AppHandle) {
+    if STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let app = app.clone();
+    let spawned = std::thread::Builder::new()
+        .name("capscr-retention".into())
+        .spawn(move || {
+            std::thread::sleep(FIRST_RUN);
+            loop {
+                let (targets, names) = {
+                    let state = app.state::<AppState>();
+                    let cfg = state.config.lock().unwrap();
+                    (
+                        cfg.output.retention_targets(),
+                        OwnNames::new(&cfg.output.filename_template),
+                    )
+                };
+                for (dir, policy) in targets {
+                    let handled = enforce(&dir, &policy, &names);
+                    if handled > 0 {
+                        let verb = if policy.move_to.is_some() {
+                            "moved"
+                        } else {
+                            "removed"
+                        };
+                        tracing::info!(
+                            "retention: {verb} {handled} old captures from {}",
+                            dir.display()
+                        );
+                    }
+                }
+                std::thread::sleep(INTERVAL);
+            }
+        });
+    if let Err(e) = spawned {
+        tracing::warn!("couldn't start retention thread: {e}");
+    }
+}
+
+/// bring `dir` within `policy`, counting only the files `names` says capscr
+/// wrote. returns how many were deleted or moved
+pub fn enforce(dir: &Path, policy: &RetentionPolicy, names: &OwnNames) -> usize {
+    let doomed = files_to_prune(
+        collect(dir, names),
+        policy.max_files,
+        u64::from(policy.max_size_mb) * 1024 * 1024,
+    );
+    let move_to = policy.move_to.as_ref().map(|to| dir.join(to));
+    if let Some(to) = &move_to {
+        if let Err(e) = std::fs::create_dir_all(to) {
+            // deleting instead would lose what the user asked to keep
+            tracing::warn!("retention folder {} unusable: {e}", to.display());
+            return 0;
+        }
+    }
+    let apply = |path: &Path| match &move_to {
+        Some(to) => move_file(path, to).map(Some),
+        None => std::fs::remove_file(path).map(|()| None),
+    };
+    doomed
+        .iter()
+        .filter(|path| {
+            let moved = match apply(path) {
+                Ok(moved) => moved,
+                Err(e) => {
+                    tracing::debug!("retention skipped {}: {e}", path.display());
+                    return false;
+                }
+            };
+            // the hdr sidecars and editor project follow their capture
+            let companions = crate::capture::existing_hdr_sidecars(path)
+                .into_iter()
+                .chain(crate::commands::editor_project_path(path).filter(|p| p.is_file()));
+            for companion in companions {
+                let _ = apply(&companion);
+            }
+            let old_key = path.to_string_lossy();
+            match moved {
+                Some(dest) => {
+                    let notes = crate::tags::get(&old_key);
+                    if !notes.is_empty() {
+                        let new_key = dest.to_string_lossy();
+                        let _ = crate::tags::set(&new_key, &notes.tags, &notes.note);
+                    }
+                    crate::tags::remove(&old_key);
+                }
+                None => crate::tags::remove(&old_key),
+            }
+            true
+        })
+        .count()
+}
+
+fn collect(dir: &Path, names: &OwnNames) -> Vec<(PathBuf, u64, SystemTime)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter(|entry| is_capture(&entry.path(), names))
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok().filter(|m| m.is_file())?;
+            Some((entry.path(), meta.len(), meta.modified().ok()?))
+        })
+        .collect()
+}
+
+fn is_capture(path: &Path, names: &OwnNames) -> bool {
+    let is_sidecar = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(crate::capture::is_hdr_sidecar);
+    !is_sidecar
+        && path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| CAPTURE_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+        && path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .is_some_and(|stem| names.matches(stem))
+}
+
+/// recognises the names capscr gives captures: the filename template expanded
+/// and cut down to letters, digits, `_` and `-` as generate_filename does, the
+/// `_N` a name clash appends, and capscr's fixed prefixes
+pub struct OwnNames(Option<Regex>);
+
+impl OwnNames {
+    pub fn new(template: &str) -> Self {
+        let mut pattern = String::from("^(?:");
+        let mut chars = template.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                if c.is_alphanumeric() || c == '_' || c == '-' {
+                    pattern.push_str(&regex::escape(c.encode_utf8(&mut [0; 4])));
+                }
+                continue;
+            }
+            // padding flags sit between the % and the specifier
+            let mut spec = chars.next();
+            while matches!(spec, Some('-' | '_' | '0' | '^' | '#')) {
+                spec = chars.next();
+            }
+            pattern.push_str(match spec {
+                Some('Y' | 'C' | 'y' | 'm' | 'd' | 'e' | 'H' | 'I' | 'k' | 'l' | 'M' | 'S')
+                | Some('j' | 'u' | 'w' | 'U' | 'W' | 'V' | 'G' | 'g' | 's' | 'f') => r"\d+",
+                // names, dates and times with separators, zones: whatever
+                // survives the cut
+                _ => r"[\w-]*?",
+            });
+        }
+        // an empty expansion falls back to capture_<unix time>
+        pattern.push_str(r"|capture_\d+)(?:_\d+)?$");
+        Self(Regex::new(&pattern).ok())
+    }
+
+    fn matches(&self, stem: &str) -> bool {
+        OWN_PREFIXES.iter().any(|prefix| stem.starts_with(prefix))
+            || self.0.as_ref().is_some_and(|re| re.is_match(stem))
+    }
+}
+
+// a rename where it can, a copy where the folders are on different volumes.
+// returns where the file ended up
+fn move_file(path: &Path, to: &Path) -> std::io::Result<PathBuf> {
+    let name = path.file_name().unwrap_or_default();
+    let dest = crate::clipboard::get_unique_filepath(&to.join(name));
+    if std::fs::rename(path, &dest).is_err() {
+        std::fs::copy(path, &dest)?;
+        std::fs::remove_file(path)?;
+    }
+    Ok(dest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_names() -> OwnNames {
+        OwnNames::new("capture_%Y%m%d_%H%M%S")
+    }
+
+    #[test]
+    fn only_capture_files_are_counted() {
+        let names = default_names();
+        let counted = |name: &str| is_capture(Path::new(name), &names);
+        assert!(counted("capture_20260101_120000.PNG"));
+        assert!(counted("capture_20260101_120000_2.mp4"));
+        assert!(counted("timelapse_20260101_120000.mp4"));
+        assert!(counted("auto_20260101_120000.png"));
+        assert!(!counted("capture_20260101_120000.capscr.json"));
+        assert!(!counted("capture_20260101_120000.hdr.png"));
+        assert!(!counted("capture_20260101_120000.hdr.avif"));
+        assert!(!counted("notes.txt"));
+        assert!(!counted("originals"));
+        assert!(!counted("holiday.jpg"));
+        assert!(!counted("capture_final.png"));
+    }
+
+    #[test]
+    fn names_follow_the_template() {
+        let names = OwnNames::new("%F shot %T");
+        assert!(names.matches("2026-01-01shot120000"));
+        assert!(!names.matches("holiday"));
+        // an expansion with nothing left falls back to capture_<unix time>
+        assert!(OwnNames::new("%%").matches("capture_1767225600"));
+    }
+
+    #[test]
+    fn a_foreign_file_in_the_folder_survives() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let hour_ago = SystemTime::now() - Duration::from_secs(3600);
+        let files = [
+            ("holiday.jpg", hour_ago),
+            ("capture_20260101_120000.png", hour_ago),
+            ("capture_20260101_130000.png", SystemTime::now()),
+        ];
+        for (name, modified) in files {
+            let file = std::fs::File::create(dir.path().join(name)).unwrap();
+            file.set_modified(modified).unwrap();
+        }
+        let policy = RetentionPolicy {
+            max_files: 1,
+            max_size_mb: 0,
+            move_to: None,
+        };
+        assert_eq!(enforce(dir.path(), &policy, &default_names()), 1);
+        assert!(dir.path().join("holiday.jpg").is_file());
+        assert!(!dir.path().join("capture_20260101_120000.png").exists());
+        assert!(dir.path().join("capture_20260101_130000.png").is_file());
+    }
+
+    #[test]
+    fn a_newest_capture_over_the_size_limit_stays() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let big = dir.path().join("capture_20260101_120000.png");
+        std::fs::write(&big, vec![0u8; 2 * 1024 * 1024]).unwrap();
+        let policy = RetentionPolicy {
+            max_files: 0,
+            max_size_mb: 1,
+            move_to: None,
+        };
+        assert_eq!(enforce(dir.path(), &policy, &default_names()), 0);
+        assert!(big.is_file());
+    }
+}
@@ -0,0 +1,159 @@
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+use crate::config::Config;
+
+// the folder intermediate files are written to. set from config at startup
+// and on every save, read by whatever needs a temp file
+static CONFIGURED: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+// a configured folder is only the parent: clean_up deletes whatever it finds,
+// so it must never be pointed at a folder the user keeps their own files in
+const SUBFOLDER: &str = "capscr-scratch";
+
+pub fn configure(config: &Config) {
+    *CONFIGURED.write().unwrap_or_else(|e| e.into_inner()) =
+        config.output.scratch_directory.clone();
+}
+
+/// the scratch folder, created if needed. a configured folder gets its own
+/// `capscr-scratch` subfolder; one that can't be created falls back to the
+/// default under the system temp dir rather than failing a recording
+pub fn dir() -> PathBuf {
+    let configured = CONFIGURED.read().unwrap_or_else(|e| e.into_inner()).clone();
+    let dir = resolve(configured);
+    match std::fs::create_dir_all(&dir) {
+        Ok(()) => dir,
+        Err(e) => {
+            tracing::warn!(
+                "scratch folder {} unusable ({e}); using temp",
+                dir.display()
+            );
+            let fallback = default_dir();
+            // never the bare temp dir: clean_up would sweep other programs' files
+            let _ = std::fs::create_dir_all(&fallback);
+            fallback
+        }
+    }
+}
+
+fn resolve(configured: Option<PathBuf>) -> PathBuf {
+    configured
+        .map(|parent| parent.join(SUBFOLDER))
+        .unwrap_or_else(default_dir)
+}
+
+fn default_dir() -> PathBuf {
+    std::env::temp_dir().join("capscr")
+}
+
+struct Leftover {
+    path: PathBuf,
+    len: u64,
+    modified: SystemTime,
+}
+
+/// remove what earlier runs left behind (a crash skips every drop that would
+/// have) until the rest fits in `cap_bytes`, oldest first. files touched
+/// since `started` belong to this run and are never counted or removed.
+/// returns how many files went
+pub fn clean_up(cap_bytes: u64, started: SystemTime) -> usize {
+    let dir = dir();
+    let mut found = Vec::new();
+    collect(&dir, 0, &mut found);
+    let doomed = leftovers_over_cap(found, cap_bytes, started);
+    doomed
+        .iter()
+        .filter(|path| std::fs::remove_file(path).is_ok())
+        .count()
+}
+
+// the scratch folder holds files and one level of per-use subfolders
+// (mail attachments); nothing deeper is ours
+fn collect(dir: &std::path::Path, depth: u8, out: &mut Vec<Leftover>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        if meta.is_dir() {
+            if depth == 0 {
+                collect(&entry.path(), depth + 1, out);
+            }
+        } else if let Ok(modified) = meta.modified() {
+            out.push(Leftover {
+                path: entry.path(),
+                len: meta.len(),
+                modified,
+            });
+        }
+    }
+}
+
+fn leftovers_over_cap(
+    mut found: Vec<Leftover>,
+    cap_bytes: u64,
+    started: SystemTime,
+) -> Vec<PathBuf> {
+    found.retain(|f| f.modified < started);
+    // newest first, so the ones kept are the most recent
+    found.sort_by_key(|f| std::cmp::Reverse(f.modified));
+    let mut kept: u64 = 0;
+    found
+        .into_iter()
+        .filter(|f| {
+            kept = kept.saturating_add(f.len);
+            kept > cap_bytes
+        })
+        .map(|f| f.path)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn leftover(name: &str, len: u64, age_secs: u64, now: SystemTime) -> Leftover {
+        Leftover {
+            path: PathBuf::from(name),
+            len,
+            modified: now - Duration::from_secs(age_secs),
+        }
+    }
+
+    #[test]
+    fn oldest_leftovers_go_first_and_this_run_is_untouched() {
+        let started = SystemTime::now();
+        let found = vec![
+            leftover("newest", 40, 10, started),
+            leftover("oldest", 40, 300, started),
+            leftover("middle", 40, 100, started),
+            // written after launch: a recording of this run
+            Leftover {
+                path: PathBuf::from("live"),
+                len: 1000,
+                modified: started + Duration::from_secs(5),
+            },
+        ];
+        let doomed = leftovers_over_cap(found, 90, started);
+        assert_eq!(doomed, vec![PathBuf::from("oldest")]);
+    }
+
+    #[test]
+    fn a_configured_folder_is_only_the_parent() {
+        let videos = std::env::temp_dir().join("Videos");
+        assert_eq!(resolve(Some(videos.clone())), videos.join("capscr-scratch"));
+        assert_eq!(resolve(None), std::env::temp_dir().join("capscr"));
+    }
+
+    #[test]
+    fn a_zero_cap_clears_every_leftover() {
+        let started = SystemTime::now();
+        let found = vec![leftover("a", 1, 10, started), leftover("b", 1, 20, started)];
+        assert_eq!(leftovers_over_cap(found, 0, started).len(), 2);
+    }
+}
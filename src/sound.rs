@@ -16,7 +16,11 @@ impl Sound {
         }
     }
 
+    /// silent while do not disturb is on
     pub fn play(self) {
+        if crate::dnd::active() {
+            return;
+        }
         #[cfg(windows)]
         {
             engine::play(self);
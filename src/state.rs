@@ -1,18 +1,55 @@
 #![allow(dead_code)]
 
+use crate::capture::Rectangle;
 use crate::config::{CaptureTask, Config};
+use crate::json_index::push_newest;
 use crate::plugin::PluginManager;
-use crate::recording::{GifRecorder, RecordingState};
+use crate::recording::{GifRecorder, RecordingState, StopReason};
 use crossbeam_channel::Sender;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Mutex, RwLock};
+use std::time::Duration;
+use tauri::Manager;
 
 const RECENT_UPLOADS_CAP: usize = 5;
+const RECENT_SAVES_CAP: usize = 5;
+// distinct monitor layouts whose last region is remembered. a laptop that docks
+// into a couple of desks needs a handful; the rest are stale hotplug states
+const LAST_REGION_LAYOUTS_CAP: usize = 8;
+// how long the config has to sit unchanged before a save_config_soon writes it
+const CONFIG_SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
 
 pub enum HotkeyCommand {
-    Reload { tasks: Vec<CaptureTask> },
+    Reload {
+        tasks: Vec<CaptureTask>,
+        stop_recording: String,
+        do_not_disturb: String,
+    },
+    // release every binding and end the hotkey thread; the sender is told
+    // once the bindings are gone
+    Shutdown(Sender<()>),
+}
+
+// a stopped recording held back for trimming. gifs keep their frame spool and
+// encode only the kept range; mp4s were encoded live, so they wait as a
+// finished file in the app cache where the hub's <video> can play it
+pub struct RecordingReview {
+    pub task: CaptureTask,
+    pub recorder: GifRecorder,
+    pub video: Option<PathBuf>,
+    pub audio_dropped: bool,
+    pub stop_reason: Option<StopReason>,
+}
+
+impl Drop for RecordingReview {
+    fn drop(&mut self) {
+        // saving moves the file out; a discarded review leaves it behind
+        if let Some(video) = &self.video {
+            let _ = std::fs::remove_file(video);
+        }
+    }
 }
 
 // per-task hotkey registration status, surfaced in the hub Tasks view + the
@@ -43,11 +80,19 @@ pub struct AppState {
     pub gif_recorder: Mutex<Option<GifRecorder>>,
     pub recording_state: Mutex<RecordingState>,
     pub recording_task_id: Mutex<Option<String>>,
+    // a stopped recording waiting on the hub's trim preview; None unless
+    // capture.review_recordings is on
+    pub recording_review: Mutex<Option<RecordingReview>>,
     pub last_save: Mutex<Option<PathBuf>>,
+    // most-recent-first saved captures for the tray's recent captures menu
+    pub recent_saves: Mutex<VecDeque<PathBuf>>,
     pub last_upload: Mutex<Option<UploadRecord>>,
     pub recent_uploads: Mutex<VecDeque<UploadRecord>>,
     pub editor_image_path: Mutex<Option<String>>,
     pub capture_in_progress: AtomicBool,
+    // set while a recording runs with the hub hidden for it; the recording's
+    // finalize brings the hub back
+    pub hub_hidden_for_recording: AtomicBool,
     // user-toggled global kill switch. mirrors config.hotkeys.disabled_globally
     // for in-memory speed; AppState::new restores it from disk so the toggle
     // survives restart.
@@ -56,9 +101,11 @@ pub struct AppState {
     // register/reload pass.
     pub hotkey_status: Mutex<HashMap<String, HotkeyStatus>>,
     pub pinned_images: Mutex<HashMap<String, String>>,
-    // last committed region selection, so a "region (last)" task can re-fire the
-    // same rectangle without showing the selector. None until the first drag.
-    pub last_region: Mutex<Option<crate::capture::Rectangle>>,
+    // last committed region selection per monitor layout, most-recent-first, so
+    // a "region (last)" task can re-fire the same rectangle without showing the
+    // selector. persisted to last-region.json so it survives a restart; keyed by
+    // layout so a rect drawn on a docked desk never replays onto a bare laptop
+    last_regions: Mutex<Vec<StoredRegion>>,
     // canonical paths the OS delivered via window drag-drop. upload_file trusts a
     // path only if the user actually dropped it (or it's one of capscr's own
     // files), so a webview-supplied arbitrary path can't be uploaded off disk.
@@ -66,12 +113,76 @@ pub struct AppState {
     // last url a healthy webview was observed on; the about:blank watchdogs
     // navigate stuck webviews here when no live hub exists to copy from
     pub canonical_webview_url: Mutex<Option<url::Url>>,
+    // stop handle for a running interval capture; dropping the sender ends the
+    // loop. None while auto capture is off
+    pub auto_capture_stop: Mutex<Option<Sender<()>>>,
+    // bumped by every save_config_soon and save_config_now; a debounced write
+    // only goes ahead if nothing has superseded it in the meantime
+    config_save_generation: AtomicU64,
 }
 
 // a session's dropped-path set won't grow past a real user's drags, but cap it
 // so a pathological stream of distinct drops can't accumulate unboundedly
 const DROPPED_PATHS_CAP: usize = 4096;
 
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct StoredRegion {
+    layout: String,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+}
+
+fn last_region_path() -> Option<PathBuf> {
+    Config::config_dir().map(|dir| dir.join("last-region.json"))
+}
+
+fn load_last_regions() -> Vec<StoredRegion> {
+    let Some(path) = last_region_path() else {
+        return Vec::new();
+    };
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|body| serde_json::from_str(&body).ok())
+        .unwrap_or_default()
+}
+
+fn store_last_regions(regions: &[StoredRegion]) {
+    let Some(path) = last_region_path() else {
+        return;
+    };
+    match serde_json::to_string_pretty(regions) {
+        Ok(body) => {
+            // through a temp file, like the other index files, so a crash
+            // mid-write can't leave a half-written one behind
+            let written = path
+                .parent()
+                .map_or(Ok(()), std::fs::create_dir_all)
+                .and_then(|()| crate::disk::replace_file(&path, body.as_bytes()));
+            if let Err(e) = written {
+                tracing::warn!("couldn't persist the last region: {e:#}");
+            }
+        }
+        Err(e) => tracing::warn!("couldn't serialize the last region: {e:#}"),
+    }
+}
+
+// move the layout's entry to the front with the new rect, dropping the oldest
+// layouts past the cap
+fn upsert_region(regions: &mut Vec<StoredRegion>, layout: String, rect: Rectangle) {
+    let entry = StoredRegion {
+        layout: layout.clone(),
+        x: rect.x,
+        y: rect.y,
+        width: rect.width,
+        height: rect.height,
+    };
+    push_newest(regions, entry, LAST_REGION_LAYOUTS_CAP, |r| {
+        r.layout == layout
+    });
+}
+
 #[derive(Clone, Debug)]
 pub struct UploadRecord {
     pub url: String,
@@ -97,20 +208,50 @@ impl AppState {
             gif_recorder: Mutex::new(None),
             recording_state: Mutex::new(RecordingState::Idle),
             recording_task_id: Mutex::new(None),
+            recording_review: Mutex::new(None),
             last_save: Mutex::new(None),
+            recent_saves: Mutex::new(VecDeque::with_capacity(RECENT_SAVES_CAP)),
             last_upload: Mutex::new(None),
             recent_uploads: Mutex::new(VecDeque::with_capacity(RECENT_UPLOADS_CAP)),
             editor_image_path: Mutex::new(None),
             capture_in_progress: AtomicBool::new(false),
+            hub_hidden_for_recording: AtomicBool::new(false),
             hotkeys_disabled: AtomicBool::new(disabled),
             hotkey_status: Mutex::new(HashMap::new()),
             pinned_images: Mutex::new(HashMap::new()),
-            last_region: Mutex::new(None),
+            last_regions: Mutex::new(load_last_regions()),
             dropped_paths: Mutex::new(HashSet::new()),
             canonical_webview_url: Mutex::new(None),
+            auto_capture_stop: Mutex::new(None),
+            config_save_generation: AtomicU64::new(0),
         }
     }
 
+    /// persist the config once it has stayed unchanged for a moment rather than
+    /// on every change, so a burst of small ones (a dragged hub, picked editor
+    /// colors, tray toggles) is a single write. exit flushes whatever's pending
+    pub fn save_config_soon(&self, app: &tauri::AppHandle) {
+        let generation = self.config_save_generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let app = app.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(CONFIG_SAVE_DEBOUNCE);
+            let state = app.state::<AppState>();
+            if state.config_save_generation.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            if let Err(e) = state.config.lock().unwrap().save() {
+                tracing::warn!("couldn't write config: {e:#}");
+            }
+        });
+    }
+
+    /// write the config now, which also covers any save_config_soon still
+    /// waiting. the caller must not hold the config lock
+    pub fn save_config_now(&self) -> anyhow::Result<()> {
+        self.config_save_generation.fetch_add(1, Ordering::SeqCst);
+        self.config.lock().unwrap().save()
+    }
+
     /// record canonicalized paths the OS delivered to a window drag-drop, so
     /// upload_file can later confirm the user really dropped a given file
     pub fn remember_dropped_paths<I: IntoIterator<Item = PathBuf>>(&self, paths: I) {
@@ -125,6 +266,25 @@ impl AppState {
         }
     }
 
+    /// remember a committed region selection for the current monitor layout
+    pub fn remember_region(&self, rect: Rectangle) {
+        let layout = crate::capture::monitor_layout_key();
+        let mut regions = self.last_regions.lock().unwrap();
+        upsert_region(&mut regions, layout, rect);
+        store_last_regions(&regions);
+    }
+
+    /// the last region selected under the current monitor layout, if any
+    pub fn last_region(&self) -> Option<Rectangle> {
+        let layout = crate::capture::monitor_layout_key();
+        self.last_regions
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|r| r.layout == layout)
+            .map(|r| Rectangle::new(r.x, r.y, r.width, r.height))
+    }
+
     pub fn was_dropped(&self, canonical: &Path) -> bool {
         self.dropped_paths.lock().unwrap().contains(canonical)
     }
@@ -166,16 +326,70 @@ impl AppState {
         }
     }
 
-    pub fn send_hotkey_reload(&self, tasks: Vec<CaptureTask>) {
+    /// re-register the configured hotkeys, for a change the hotkey thread
+    /// filters them by (do not disturb, game mode) rather than a config save.
+    /// the kill switch already holds them all back, so that's left alone
+    pub fn reload_hotkeys(&self) {
+        if self.hotkeys_disabled.load(Ordering::SeqCst) {
+            return;
+        }
+        let (tasks, stop_recording, do_not_disturb) = {
+            let cfg = self.config.lock().unwrap();
+            (
+                cfg.capture_tasks.clone(),
+                cfg.hotkeys.stop_recording.clone(),
+                cfg.hotkeys.do_not_disturb.clone(),
+            )
+        };
+        self.send_hotkey_reload(tasks, stop_recording, do_not_disturb);
+    }
+
+    pub fn send_hotkey_reload(
+        &self,
+        tasks: Vec<CaptureTask>,
+        stop_recording: String,
+        do_not_disturb: String,
+    ) {
         if let Some(tx) = self.hotkey_tx.lock().unwrap().as_ref() {
-            let _ = tx.send(HotkeyCommand::Reload { tasks });
+            let _ = tx.send(HotkeyCommand::Reload {
+                tasks,
+                stop_recording,
+                do_not_disturb,
+            });
         }
     }
 
+    /// release the global hotkeys ahead of exit, waiting up to `max` for the
+    /// hotkey thread to confirm. false if it didn't in time (or isn't running)
+    pub fn shutdown_hotkeys(&self, max: std::time::Duration) -> bool {
+        let Some(tx) = self.hotkey_tx.lock().unwrap().take() else {
+            return false;
+        };
+        let (done_tx, done_rx) = crossbeam_channel::bounded(1);
+        if tx.send(HotkeyCommand::Shutdown(done_tx)).is_err() {
+            return false;
+        }
+        done_rx.recv_timeout(max).is_ok()
+    }
+
+    /// note a saved capture: it becomes last_save and tops the recent-saves
+    /// ring (most-recent-first, cap 5)
+    pub fn record_save(&self, path: &Path) {
+        *self.last_save.lock().unwrap() = Some(path.to_path_buf());
+        let mut recent = self.recent_saves.lock().unwrap();
+        recent.retain(|p| p != path);
+        recent.push_front(path.to_path_buf());
+        recent.truncate(RECENT_SAVES_CAP);
+    }
+
     /// push a new upload onto the recent-uploads ring (most-recent-first, cap 5)
     /// and also set last_upload for back-compat with the existing copy-last-url
     /// tray path
     pub fn record_upload(&self, record: UploadRecord) {
+        // an email hand-off leaves nothing to copy or delete later
+        if record.url.is_empty() {
+            return;
+        }
         *self.last_upload.lock().unwrap() = Some(record.clone());
         let mut recent = self.recent_uploads.lock().unwrap();
         // drop any existing entry with the same url so the new one bubbles to
@@ -187,3 +401,25 @@ impl AppState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last_region_is_kept_per_layout_most_recent_first() {
+        let mut regions = Vec::new();
+        upsert_region(&mut regions, "a".into(), Rectangle::new(0, 0, 10, 10));
+        upsert_region(&mut regions, "b".into(), Rectangle::new(5, 5, 20, 20));
+        upsert_region(&mut regions, "a".into(), Rectangle::new(1, 2, 30, 40));
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0].layout, "a");
+        assert_eq!((regions[0].x, regions[0].width), (1, 30));
+
+        for i in 0..LAST_REGION_LAYOUTS_CAP + 3 {
+            upsert_region(&mut regions, format!("l{i}"), Rectangle::new(0, 0, 1, 1));
+        }
+        assert_eq!(regions.len(), LAST_REGION_LAYOUTS_CAP);
+        assert!(regions.iter().all(|r| r.layout != "a"));
+    }
+}
@@ -0,0 +1,202 @@
+// join several overlapping captures (a long timeline shot in parts, a page
+// scrolled by hand) into one image. alignment is a plain offset search along
+// one axis: each capture is slid over the previous one's far edge and placed
+// where the overlapping pixels agree best. no feature matching, so captures
+// have to share a scale and be shifted along a single axis.
+
+use anyhow::{anyhow, Result};
+use image::{Rgba, RgbaImage};
+
+// narrower overlaps match too easily on flat ui backgrounds
+const MIN_OVERLAP: u32 = 16;
+// mean per-channel difference under which an overlap counts as a match;
+// leaves room for jpeg noise and a blinking caret
+const MATCH_THRESHOLD: f64 = 6.0;
+// lines compared per candidate overlap, spread across its whole width, and
+// pixels sampled along each line; keeps a 4k-wide search well under a second
+const SAMPLE_LINES: u32 = 64;
+const SAMPLE_PIXELS: u32 = 128;
+pub const MAX_IMAGES: usize = 32;
+// same ceiling as a single screen capture
+const MAX_PIXELS: u64 = 256 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// left to right
+    Horizontal,
+    /// top to bottom
+    Vertical,
+}
+
+impl Direction {
+    fn along(self, img: &RgbaImage) -> u32 {
+        match self {
+            Direction::Horizontal => img.width(),
+            Direction::Vertical => img.height(),
+        }
+    }
+
+    fn across(self, img: &RgbaImage) -> u32 {
+        match self {
+            Direction::Horizontal => img.height(),
+            Direction::Vertical => img.width(),
+        }
+    }
+
+    fn pixel(self, img: &RgbaImage, along: u32, across: u32) -> Rgba<u8> {
+        match self {
+            Direction::Horizontal => *img.get_pixel(along, across),
+            Direction::Vertical => *img.get_pixel(across, along),
+        }
+    }
+}
+
+/// stitch `images` in the order given. the direction is taken from whichever
+/// axis the first two overlap on; with no overlap found they're butted
+/// together side by side
+pub fn stitch(images: &[RgbaImage]) -> Result<(RgbaImage, Direction)> {
+    if images.len() < 2 {
+        return Err(anyhow!("pick at least two captures to stitch"));
+    }
+    if images.len() > MAX_IMAGES {
+        return Err(anyhow!("pick at most {MAX_IMAGES} captures to stitch"));
+    }
+    let direction = detect_direction(&images[0], &images[1]);
+    let mut offsets = vec![0u64];
+    for pair in images.windows(2) {
+        let overlap = best_overlap(&pair[0], &pair[1], direction).map_or(0, |(o, _)| o);
+        let prev = *offsets.last().unwrap();
+        offsets.push(prev + (direction.along(&pair[0]) - overlap) as u64);
+    }
+    let along = offsets.last().unwrap() + direction.along(images.last().unwrap()) as u64;
+    let across = images
+        .iter()
+        .map(|i| direction.across(i))
+        .max()
+        .unwrap_or(0);
+    let along =
+        u32::try_from(along).map_err(|_| anyhow!("the stitched image would be too large"))?;
+    let (width, height) = match direction {
+        Direction::Horizontal => (along, across),
+        Direction::Vertical => (across, along),
+    };
+    if width as u64 * height as u64 > MAX_PIXELS {
+        return Err(anyhow!("the stitched image would be too large"));
+    }
+    let mut canvas = RgbaImage::new(width, height);
+    // later captures go on top: they hold the newer pixels of the overlap
+    for (img, offset) in images.iter().zip(offsets) {
+        let (x, y) = match direction {
+            Direction::Horizontal => (offset as i64, 0),
+            Direction::Vertical => (0, offset as i64),
+        };
+        image::imageops::replace(&mut canvas, img, x, y);
+    }
+    Ok((canvas, direction))
+}
+
+fn detect_direction(a: &RgbaImage, b: &RgbaImage) -> Direction {
+    let horizontal = best_overlap(a, b, Direction::Horizontal);
+    let vertical = best_overlap(a, b, Direction::Vertical);
+    match (horizontal, vertical) {
+        (Some((_, h)), Some((_, v))) if v < h => Direction::Vertical,
+        (None, Some(_)) => Direction::Vertical,
+        _ => Direction::Horizontal,
+    }
+}
+
+/// how far `b` slides back over the end of `a` along `direction`, with the
+/// match score. None when no overlap is close enough to count
+fn best_overlap(a: &RgbaImage, b: &RgbaImage, direction: Direction) -> Option<(u32, f64)> {
+    // b entirely inside a isn't an overlap, it's a duplicate
+    let max = direction.along(a).min(direction.along(b)).saturating_sub(1);
+    let mut best: Option<(u32, f64)> = None;
+    for overlap in MIN_OVERLAP..=max {
+        let score = overlap_score(a, b, overlap, direction);
+        // ties go to the wider overlap: flat backgrounds match everywhere,
+        // and captures of a panned view usually share a lot
+        if score <= MATCH_THRESHOLD && !best.is_some_and(|(_, s)| score > s) {
+            best = Some((overlap, score));
+        }
+    }
+    best
+}
+
+fn overlap_score(a: &RgbaImage, b: &RgbaImage, overlap: u32, direction: Direction) -> f64 {
+    let start = direction.along(a) - overlap;
+    let across = direction.across(a).min(direction.across(b));
+    let lines = overlap.min(SAMPLE_LINES);
+    let step = (across / SAMPLE_PIXELS).max(1);
+    let mut total = 0u64;
+    let mut count = 0u64;
+    for k in 0..lines {
+        let i = (k as u64 * overlap as u64 / lines as u64) as u32;
+        for c in (0..across).step_by(step as usize) {
+            let pa = direction.pixel(a, start + i, c);
+            let pb = direction.pixel(b, i, c);
+            total += pa.0[..3]
+                .iter()
+                .zip(&pb.0[..3])
+                .map(|(&x, &y)| x.abs_diff(y) as u64)
+                .sum::<u64>();
+            count += 3;
+        }
+    }
+    if count == 0 {
+        return f64::MAX;
+    }
+    total as f64 / count as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a strip with a distinct value per column and row, so only the true
+    // offset lines up
+    fn pattern(width: u32, height: u32) -> RgbaImage {
+        RgbaImage::from_fn(width, height, |x, y| {
+            Rgba([
+                (x * 7 % 251) as u8,
+                (y * 13 % 241) as u8,
+                ((x ^ y) % 256) as u8,
+                255,
+            ])
+        })
+    }
+
+    fn crop(img: &RgbaImage, x: u32, y: u32, w: u32, h: u32) -> RgbaImage {
+        image::imageops::crop_imm(img, x, y, w, h).to_image()
+    }
+
+    #[test]
+    fn horizontal_parts_rejoin_at_their_overlap() {
+        let whole = pattern(300, 40);
+        let parts = [
+            crop(&whole, 0, 0, 140, 40),
+            crop(&whole, 100, 0, 140, 40),
+            crop(&whole, 200, 0, 100, 40),
+        ];
+        let (stitched, direction) = stitch(&parts).unwrap();
+        assert_eq!(direction, Direction::Horizontal);
+        assert_eq!(stitched, whole);
+    }
+
+    #[test]
+    fn vertical_parts_rejoin_at_their_overlap() {
+        let whole = pattern(40, 200);
+        let parts = [crop(&whole, 0, 0, 40, 120), crop(&whole, 0, 90, 40, 110)];
+        let (stitched, direction) = stitch(&parts).unwrap();
+        assert_eq!(direction, Direction::Vertical);
+        assert_eq!(stitched, whole);
+    }
+
+    #[test]
+    fn unrelated_captures_are_butted_together() {
+        let a = RgbaImage::from_pixel(50, 30, Rgba([255, 0, 0, 255]));
+        let b = RgbaImage::from_pixel(60, 20, Rgba([0, 0, 255, 255]));
+        let (stitched, _) = stitch(&[a, b]).unwrap();
+        assert_eq!(stitched.dimensions(), (110, 30));
+        assert!(stitch(&[pattern(10, 10)]).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+// tags and a note per capture, set from the editor or the history view and
+// searched from history. they live in a local index keyed by the capture's
+// path rather than in the file, so every format can carry them and a rename
+// outside capscr just loses them; with output.tags_in_metadata a png also
+// gets them as text chunks, for other tools to read
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::json_index::{push_newest, JsonIndex};
+
+const MAX_ENTRIES: usize = 5000;
+const MAX_TAGS: usize = 20;
+const MAX_TAG_LEN: usize = 40;
+const MAX_NOTE_LEN: usize = 2000;
+
+// the editor and history can both save at once
+static INDEX: JsonIndex = JsonIndex::new("capture-tags.json");
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CaptureNotes {
+    pub tags: Vec<String>,
+    pub note: String,
+}
+
+impl CaptureNotes {
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.note.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct TaggedCapture {
+    path: String,
+    #[serde(flatten)]
+    notes: CaptureNotes,
+}
+
+/// the tags and note of the capture at `path`, empty when it has none
+pub fn get(path: &str) -> CaptureNotes {
+    INDEX
+        .read::<TaggedCapture>()
+        .into_iter()
+        .find(|e| e.path == key(path))
+        .map(|e| e.notes)
+        .unwrap_or_default()
+}
+
+/// every tagged capture, by path
+pub fn all() -> HashMap<String, CaptureNotes> {
+    INDEX
+        .read::<TaggedCapture>()
+        .into_iter()
+        .map(|e| (e.path, e.notes))
+        .collect()
+}
+
+/// replace the tags and note of the capture at `path`, returning them as
+/// stored. clearing both drops the capture from the index
+pub fn set(path: &str, tags: &[String], note: &str) -> Result<CaptureNotes> {
+    let notes = CaptureNotes {
+        tags: normalize_tags(tags),
+        note: clipped(note.trim(), MAX_NOTE_LEN).to_string(),
+    };
+    INDEX.update(|entries: &mut Vec<TaggedCapture>| {
+        upsert(entries, key(path), notes.clone());
+        true
+    })?;
+    Ok(notes)
+}
+
+/// forget the capture at `path`, e.g. once it's deleted
+pub fn remove(path: &str) {
+    let removed = INDEX.update(|entries: &mut Vec<TaggedCapture>| {
+        let before = entries.len();
+        entries.retain(|e| e.path != key(path));
+        entries.len() != before
+    });
+    if let Err(e) = removed {
+        tracing::warn!("couldn't write the tag index: {e:#}");
+    }
+}
+
+// history lists paths without windows' verbatim prefix; the editor may not
+fn key(path: &str) -> &str {
+    path.strip_prefix(r"\\?\").unwrap_or(path)
+}
+
+// lowercase, no leading '#', spaces as dashes, each once, in the order given
+fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim().trim_start_matches('#').to_lowercase();
+        let tag = tag.split_whitespace().collect::<Vec<_>>().join("-");
+        let tag = clipped(&tag, MAX_TAG_LEN).to_string();
+        if !tag.is_empty() && !out.contains(&tag) {
+            out.push(tag);
+        }
+    }
+    out.truncate(MAX_TAGS);
+    out
+}
+
+fn clipped(s: &str, max: usize) -> &str {
+    match s.char_indices().nth(max) {
+        Some((end, _)) => &s[..end],
+        None => s,
+    }
+}
+
+// most recently tagged first; the oldest fall off past the cap
+fn upsert(entries: &mut Vec<TaggedCapture>, path: &str, notes: CaptureNotes) {
+    if notes.is_empty() {
+        entries.retain(|e| e.path != path);
+        return;
+    }
+    let entry = TaggedCapture {
+        path: path.to_string(),
+        notes,
+    };
+    push_newest(entries, entry, MAX_ENTRIES, |e| e.path == path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(tags: &[&str]) -> Vec<String> {
+        tags.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn tags_are_normalized_and_deduplicated() {
+        let tags = strings(&["  #Bug ", "bug", "Login  Page", "", "#", "ünï"]);
+        assert_eq!(normalize_tags(&tags), ["bug", "login-page", "ünï"]);
+        let long = "x".repeat(MAX_TAG_LEN + 5);
+        assert_eq!(normalize_tags(&[long])[0].len(), MAX_TAG_LEN);
+        let many: Vec<String> = (0..MAX_TAGS + 5).map(|i| format!("t{i}")).collect();
+        assert_eq!(normalize_tags(&many).len(), MAX_TAGS);
+    }
+
+    #[test]
+    fn upsert_replaces_and_clearing_removes() {
+        let notes = |tags: &[&str], note: &str| CaptureNotes {
+            tags: strings(tags),
+            note: note.into(),
+        };
+        let mut entries = Vec::new();
+        upsert(&mut entries, "a.png", notes(&["bug"], ""));
+        upsert(&mut entries, "b.png", notes(&[], "draft"));
+        upsert(&mut entries, "a.png", notes(&["bug", "ui"], "x"));
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].path, "a.png");
+        assert_eq!(entries[0].notes.tags, ["bug", "ui"]);
+
+        upsert(&mut entries, "b.png", CaptureNotes::default());
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].path, "a.png");
+    }
+}
@@ -0,0 +1,204 @@
+// one disk cache for every downscaled preview capscr shows: the history grid,
+// the window picker's rows and the image in the saved-capture notification.
+// a thumbnail made for one is reused by the others, so a 4k capture is
+// decoded and shrunk once rather than per view. jpegs in the app cache dir
+// (inside the asset-protocol scope), capped at MAX_CACHE_BYTES with the
+// least recently used thumbnails evicted first.
+
+use anyhow::Result;
+use image::RgbaImage;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+use tauri::Manager;
+
+/// history grid tiles and the notification preview
+pub const GRID_WIDTH: u32 = 480;
+/// window picker rows
+pub const PREVIEW_WIDTH: u32 = 320;
+const MAX_CACHE_BYTES: u64 = 128 * 1024 * 1024;
+// prune below the cap rather than to it, so the next few writes don't each
+// trigger another scan
+const PRUNE_TARGET_BYTES: u64 = MAX_CACHE_BYTES / 4 * 3;
+
+// running size of the cache dir; None until the first write scans it
+static CACHE_BYTES: Mutex<Option<u64>> = Mutex::new(None);
+
+pub fn dir(app: &tauri::AppHandle) -> Result<PathBuf> {
+    Ok(app.path().app_cache_dir()?.join("thumbs"))
+}
+
+/// a thumbnail of the image file at `path`, at most `width` wide. keyed by
+/// path + size + mtime, so an edited capture gets a fresh one. gifs use their
+/// first frame
+pub fn for_file(dir: &Path, path: &Path, width: u32) -> Result<PathBuf> {
+    use std::hash::{Hash, Hasher};
+    let meta = std::fs::metadata(path)?;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    path.hash(&mut hasher);
+    meta.len().hash(&mut hasher);
+    if let Ok(modified) = meta.modified() {
+        if let Ok(age) = modified.duration_since(std::time::UNIX_EPOCH) {
+            age.as_secs().hash(&mut hasher);
+        }
+    }
+    width.hash(&mut hasher);
+    let thumb = dir.join(format!("{:016x}.jpg", hasher.finish()));
+    if thumb.exists() {
+        touch(&thumb);
+        return Ok(thumb);
+    }
+    let frame = decode_first_frame(path)?;
+    write(dir, &thumb, &frame, width)?;
+    Ok(thumb)
+}
+
+/// store an already rendered preview under `key`, replacing the last one. for
+/// live pixels (a window's current contents) that no file stands behind
+pub fn store(dir: &Path, key: &str, image: &RgbaImage, width: u32) -> Result<PathBuf> {
+    let thumb = dir.join(format!("{key}.jpg"));
+    let replaced = std::fs::metadata(&thumb).map_or(0, |m| m.len());
+    write(dir, &thumb, image, width)?;
+    forget(replaced);
+    Ok(thumb)
+}
+
+fn decode_first_frame(path: &Path) -> Result<RgbaImage> {
+    let is_gif = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("gif"));
+    if !is_gif {
+        return Ok(image::open(path)?.to_rgba8());
+    }
+    // first frame only; the grid shows a still, the viewer animates
+    use image::AnimationDecoder;
+    let file = std::io::BufReader::new(std::fs::File::open(path)?);
+    let decoder = image::codecs::gif::GifDecoder::new(file)?;
+    Ok(decoder
+        .into_frames()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("gif has no frames"))??
+        .into_buffer())
+}
+
+fn write(dir: &Path, thumb: &Path, image: &RgbaImage, width: u32) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let scale = (width as f32 / image.width() as f32).min(1.0);
+    let rgb = if scale < 1.0 {
+        let w = ((image.width() as f32 * scale) as u32).max(1);
+        let h = ((image.height() as f32 * scale) as u32).max(1);
+        image::DynamicImage::ImageRgba8(image::imageops::thumbnail(image, w, h)).to_rgb8()
+    } else {
+        image::DynamicImage::ImageRgba8(image.clone()).to_rgb8()
+    };
+    // encode to a temp name then rename, so a torn write never caches as a
+    // valid thumb
+    let staging = thumb.with_extension("jpg.tmp");
+    rgb.save_with_format(&staging, image::ImageFormat::Jpeg)?;
+    std::fs::rename(&staging, thumb)?;
+    let written = std::fs::metadata(thumb).map_or(0, |m| m.len());
+    account(dir, written);
+    Ok(())
+}
+
+// a hit counts as a use, so eviction goes by last use rather than creation
+fn touch(thumb: &Path) {
+    if let Ok(file) = std::fs::File::options().write(true).open(thumb) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+}
+
+fn account(dir: &Path, added: u64) {
+    let mut total = CACHE_BYTES.lock().unwrap();
+    let size = total.get_or_insert_with(|| entries(dir).iter().map(|e| e.1).sum());
+    *size += added;
+    if *size > MAX_CACHE_BYTES {
+        *size = prune(dir, PRUNE_TARGET_BYTES);
+    }
+}
+
+fn forget(removed: u64) {
+    if let Some(size) = CACHE_BYTES.lock().unwrap().as_mut() {
+        *size = size.saturating_sub(removed);
+    }
+}
+
+fn entries(dir: &Path) -> Vec<(PathBuf, u64, SystemTime)> {
+    let Ok(read) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    read.flatten()
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            meta.is_file().then(|| {
+                let used = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                (entry.path(), meta.len(), used)
+            })
+        })
+        .collect()
+}
+
+/// delete the least recently used thumbnails until the dir holds at most
+/// `target` bytes. returns what's left
+fn prune(dir: &Path, target: u64) -> u64 {
+    let mut entries = entries(dir);
+    let mut size: u64 = entries.iter().map(|e| e.1).sum();
+    entries.sort_by_key(|e| e.2);
+    for (path, len, _) in entries {
+        if size <= target {
+            break;
+        }
+        if std::fs::remove_file(&path).is_ok() {
+            size -= len;
+        }
+    }
+    size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "capscr_thumbs_{}",
+            uuid::Uuid::new_v4().as_simple()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn a_file_is_thumbnailed_once_per_width() {
+        let dir = temp_dir();
+        let source = dir.join("capture.png");
+        RgbaImage::from_pixel(1000, 500, image::Rgba([30, 60, 90, 255]))
+            .save(&source)
+            .unwrap();
+        let cache = dir.join("thumbs");
+        let grid = for_file(&cache, &source, GRID_WIDTH).unwrap();
+        assert_eq!(image::image_dimensions(&grid).unwrap(), (480, 240));
+        assert_eq!(for_file(&cache, &source, GRID_WIDTH).unwrap(), grid);
+        assert_ne!(for_file(&cache, &source, PREVIEW_WIDTH).unwrap(), grid);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn pruning_drops_the_least_recently_used_first() {
+        let dir = temp_dir();
+        let now = SystemTime::now();
+        for (i, name) in ["old.jpg", "mid.jpg", "new.jpg"].iter().enumerate() {
+            let path = dir.join(name);
+            std::fs::write(&path, vec![0u8; 100]).unwrap();
+            let file = std::fs::File::options().write(true).open(&path).unwrap();
+            file.set_modified(now - std::time::Duration::from_secs(100 - i as u64 * 10))
+                .unwrap();
+        }
+        assert_eq!(prune(&dir, 200), 200);
+        assert!(!dir.join("old.jpg").exists());
+        assert!(dir.join("mid.jpg").exists());
+        assert!(dir.join("new.jpg").exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,749 @@
+// the system tray: one icon whose menu is rebuilt from AppState whenever
+// something it shows changes (a new capture or upload, the destination, the
+// hotkey kill switch, do not disturb, a recording starting or stopping).
+// tauri can't patch a menu item in place across platforms, so every change
+// builds the whole menu again and swaps it in; it's a few dozen items and
+// happens on user-visible events only.
+
+use crate::i18n::{t, tf};
+use crate::recording::RecordingState;
+use crate::{auto_capture, cancel, commands, config, plugin, recording, state};
+use tauri::menu::{IsMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+use tauri::{AppHandle, Emitter, Manager};
+
+pub struct SystemTray;
+
+impl SystemTray {
+    pub const ID: &'static str = "capscr-tray";
+
+    /// create the tray icon. called once from setup
+    pub fn build(app: &tauri::App) -> tauri::Result<()> {
+        let menu = menu(app)?;
+
+        let icon = app
+            .default_window_icon()
+            .cloned()
+            .ok_or_else(|| tauri::Error::AssetNotFound("default tray icon".into()))?;
+
+        TrayIconBuilder::with_id(Self::ID)
+            .icon(icon)
+            .menu(&menu)
+            .show_menu_on_left_click(false)
+            .tooltip("capscr")
+            .on_menu_event(|app, event| on_menu_event(app, event.id.as_ref()))
+            .on_tray_icon_event(|tray, event| {
+                if let TrayIconEvent::Click {
+                    button: MouseButton::Left,
+                    button_state: MouseButtonState::Up,
+                    ..
+                } = event
+                {
+                    let _ = commands::open_hub_window(tray.app_handle());
+                }
+            })
+            .build(app)?;
+
+        Ok(())
+    }
+
+    /// rebuild the menu from current state and apply it to the running tray.
+    /// safe to call from any thread. silently no-ops if the tray hasn't been
+    /// built yet (startup race).
+    pub fn rebuild(app: &AppHandle) {
+        if let Some(tray) = app.tray_by_id(Self::ID) {
+            match menu(app) {
+                Ok(menu) => {
+                    if let Err(e) = tray.set_menu(Some(menu)) {
+                        tracing::warn!("tray set_menu failed: {e}");
+                    }
+                }
+                Err(e) => tracing::warn!("tray menu construct failed: {e}"),
+            }
+        }
+    }
+
+    pub fn set_tooltip(app: &AppHandle, tooltip: &str) {
+        if let Some(tray) = app.tray_by_id(Self::ID) {
+            let _ = tray.set_tooltip(Some(tooltip));
+        }
+    }
+}
+
+// build the full tray menu fresh from current AppState
+fn menu<R: tauri::Runtime, M: tauri::Manager<R>>(app: &M) -> tauri::Result<Menu<R>> {
+    use std::sync::atomic::Ordering;
+
+    // --- Capture submenu ---
+    let cap_region = MenuItem::with_id(
+        app,
+        "cap_region",
+        t("tray.capture.region"),
+        true,
+        None::<&str>,
+    )?;
+    let cap_region_last = MenuItem::with_id(
+        app,
+        "cap_region_last",
+        t("tray.capture.region-last"),
+        true,
+        None::<&str>,
+    )?;
+    let cap_window = MenuItem::with_id(
+        app,
+        "cap_window",
+        t("tray.capture.window"),
+        true,
+        None::<&str>,
+    )?;
+    let cap_fullscreen = MenuItem::with_id(
+        app,
+        "cap_fullscreen",
+        t("tray.capture.fullscreen"),
+        true,
+        None::<&str>,
+    )?;
+    let cap_active = MenuItem::with_id(
+        app,
+        "cap_active_monitor",
+        t("tray.capture.active-monitor"),
+        true,
+        None::<&str>,
+    )?;
+    let (auto_capture_running, auto_capture_interval) = {
+        let st = app.state::<state::AppState>();
+        let running = st.auto_capture_stop.lock().unwrap().is_some();
+        let interval = st.config.lock().unwrap().auto_capture.interval_secs;
+        (running, interval)
+    };
+    let auto_capture_toggle = MenuItem::with_id(
+        app,
+        "auto_capture_toggle",
+        if auto_capture_running {
+            t("tray.auto-capture.stop").to_string()
+        } else {
+            tf(
+                "tray.auto-capture.start",
+                &[("secs", &auto_capture_interval)],
+            )
+        },
+        true,
+        None::<&str>,
+    )?;
+    let capture_separator = PredefinedMenuItem::separator(app)?;
+    let capture_submenu = Submenu::with_items(
+        app,
+        t("tray.capture"),
+        true,
+        &[
+            &cap_region,
+            &cap_region_last,
+            &cap_window,
+            &cap_fullscreen,
+            &cap_active,
+            &capture_separator,
+            &auto_capture_toggle,
+        ],
+    )?;
+
+    // record submenu. while a recording runs it leads with a stop item and
+    // the start items are greyed out; while one saves nothing can start
+    let recording = *app
+        .state::<state::AppState>()
+        .recording_state
+        .lock()
+        .unwrap();
+    let idle = recording == RecordingState::Idle;
+    let rec_stop = MenuItem::with_id(
+        app,
+        "rec_stop",
+        if recording == RecordingState::Processing {
+            t("tray.record.saving")
+        } else {
+            t("tray.record.stop")
+        },
+        recording == RecordingState::Recording,
+        None::<&str>,
+    )?;
+    let rec_region_gif = MenuItem::with_id(
+        app,
+        "rec_region_gif",
+        t("tray.record.gif"),
+        idle,
+        None::<&str>,
+    )?;
+    let rec_region_mp4 = MenuItem::with_id(
+        app,
+        "rec_region_mp4",
+        t("tray.record.mp4"),
+        idle,
+        None::<&str>,
+    )?;
+    let record_submenu = if idle {
+        Submenu::with_items(
+            app,
+            t("tray.record"),
+            true,
+            &[&rec_region_gif, &rec_region_mp4],
+        )?
+    } else {
+        Submenu::with_items(
+            app,
+            t("tray.record.active"),
+            true,
+            &[&rec_stop, &rec_region_gif, &rec_region_mp4],
+        )?
+    };
+
+    // tools submenu: converters that work on files rather than the screen
+    let tool_gif_to_mp4 = MenuItem::with_id(
+        app,
+        "tool_gif_to_mp4",
+        t("tray.tools.gif-to-mp4"),
+        true,
+        None::<&str>,
+    )?;
+    let tool_gif_to_webm = MenuItem::with_id(
+        app,
+        "tool_gif_to_webm",
+        t("tray.tools.gif-to-webm"),
+        true,
+        None::<&str>,
+    )?;
+    let tool_video_to_gif = MenuItem::with_id(
+        app,
+        "tool_video_to_gif",
+        t("tray.tools.video-to-gif"),
+        true,
+        None::<&str>,
+    )?;
+    let tools_submenu = Submenu::with_items(
+        app,
+        t("tray.tools"),
+        true,
+        &[&tool_gif_to_mp4, &tool_gif_to_webm, &tool_video_to_gif],
+    )?;
+
+    // --- Recent uploads submenu (dynamic) ---
+    let state = app.state::<state::AppState>();
+    let recent: Vec<state::UploadRecord> = state
+        .recent_uploads
+        .lock()
+        .unwrap()
+        .iter()
+        .cloned()
+        .collect();
+    let recent_items: Vec<MenuItem<R>> = recent
+        .iter()
+        .enumerate()
+        .map(|(i, rec)| {
+            // truncate long URLs so the menu doesn't sprawl. count/slice by
+            // char, not byte: a custom uploader's url comes from arbitrary
+            // server json and can hold multibyte utf-8, where a byte slice
+            // would panic on a non-boundary cut
+            let label = if rec.url.chars().count() > 56 {
+                format!("{}…", rec.url.chars().take(55).collect::<String>())
+            } else {
+                rec.url.clone()
+            };
+            MenuItem::with_id(
+                app,
+                format!("recent_upload_{i}"),
+                &label,
+                true,
+                None::<&str>,
+            )
+            .expect("recent upload item")
+        })
+        .collect();
+    let recent_refs: Vec<&dyn tauri::menu::IsMenuItem<R>> = if recent_items.is_empty() {
+        Vec::new()
+    } else {
+        recent_items
+            .iter()
+            .map(|m| m as &dyn tauri::menu::IsMenuItem<R>)
+            .collect()
+    };
+    let recent_submenu_enabled = !recent_items.is_empty();
+    let recent_submenu = if recent_submenu_enabled {
+        Submenu::with_items(app, t("tray.recent-uploads"), true, &recent_refs)?
+    } else {
+        Submenu::with_items(
+            app,
+            t("tray.recent-uploads.none"),
+            false,
+            &[] as &[&dyn tauri::menu::IsMenuItem<R>],
+        )?
+    };
+
+    // --- Recent captures submenu (dynamic) ---
+    let recent_saves: Vec<std::path::PathBuf> = state
+        .recent_saves
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|p| p.is_file())
+        .cloned()
+        .collect();
+    let recent_save_items: Vec<MenuItem<R>> = recent_saves
+        .iter()
+        .enumerate()
+        .map(|(i, path)| {
+            let label = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            MenuItem::with_id(
+                app,
+                format!("recent_capture_{i}"),
+                &label,
+                true,
+                None::<&str>,
+            )
+            .expect("recent capture item")
+        })
+        .collect();
+    let recent_save_refs: Vec<&dyn tauri::menu::IsMenuItem<R>> = recent_save_items
+        .iter()
+        .map(|m| m as &dyn tauri::menu::IsMenuItem<R>)
+        .collect();
+    let recent_captures_submenu = if recent_save_items.is_empty() {
+        Submenu::with_items(
+            app,
+            t("tray.recent-captures.none"),
+            false,
+            &[] as &[&dyn tauri::menu::IsMenuItem<R>],
+        )?
+    } else {
+        Submenu::with_items(app, t("tray.recent-captures"), true, &recent_save_refs)?
+    };
+
+    // --- Upload / utility items ---
+    let copy_last_url = MenuItem::with_id(
+        app,
+        "copy_last_url",
+        t("tray.copy-last-url"),
+        true,
+        None::<&str>,
+    )?;
+    let open_captures = MenuItem::with_id(
+        app,
+        "open_captures",
+        t("tray.open-captures"),
+        true,
+        None::<&str>,
+    )?;
+    let open_last_capture = MenuItem::with_id(
+        app,
+        "open_last_capture",
+        t("tray.open-last-capture"),
+        true,
+        None::<&str>,
+    )?;
+    let reveal_last_capture = MenuItem::with_id(
+        app,
+        "reveal_last_capture",
+        t("tray.reveal-last-capture"),
+        true,
+        None::<&str>,
+    )?;
+
+    // --- Destination switcher ---
+    let current_dest = state.config.lock().unwrap().upload.destination;
+    let mark = |is_current: bool, label: &str| -> String {
+        if is_current {
+            format!("● {label}")
+        } else {
+            format!("○ {label}")
+        }
+    };
+    let dest_imgur = MenuItem::with_id(
+        app,
+        "dest_imgur",
+        mark(current_dest == config::UploadDestination::Imgur, "Imgur"),
+        true,
+        None::<&str>,
+    )?;
+    let dest_custom = MenuItem::with_id(
+        app,
+        "dest_custom",
+        mark(
+            current_dest == config::UploadDestination::Custom,
+            t("tray.destination.custom"),
+        ),
+        true,
+        None::<&str>,
+    )?;
+    let dest_ftp = MenuItem::with_id(
+        app,
+        "dest_ftp",
+        mark(current_dest == config::UploadDestination::Ftp, "FTP"),
+        true,
+        None::<&str>,
+    )?;
+    let dest_sftp = MenuItem::with_id(
+        app,
+        "dest_sftp",
+        mark(current_dest == config::UploadDestination::Sftp, "SFTP"),
+        true,
+        None::<&str>,
+    )?;
+    let dest_submenu = Submenu::with_items(
+        app,
+        t("tray.destination"),
+        true,
+        &[&dest_imgur, &dest_custom, &dest_ftp, &dest_sftp],
+    )?;
+
+    // --- Open hub (single top-level item, no submenu) ---
+    let open_hub = MenuItem::with_id(app, "tab_default", t("tray.open-hub"), true, None::<&str>)?;
+
+    // --- Hotkey toggle (stateful) ---
+    let disabled = state.hotkeys_disabled.load(Ordering::SeqCst);
+    let hotkeys_toggle = MenuItem::with_id(
+        app,
+        "hotkeys_toggle",
+        if disabled {
+            t("tray.hotkeys.enable")
+        } else {
+            t("tray.hotkeys.disable")
+        },
+        true,
+        None::<&str>,
+    )?;
+
+    // --- Do not disturb submenu ---
+    let dnd = crate::dnd::status();
+    let dnd_off = MenuItem::with_id(app, "dnd_off", t("tray.dnd.off"), dnd.active, None::<&str>)?;
+    let dnd_on = MenuItem::with_id(app, "dnd_on", t("tray.dnd.forever"), true, None::<&str>)?;
+    let dnd_30 = MenuItem::with_id(app, "dnd_30", t("tray.dnd.30"), true, None::<&str>)?;
+    let dnd_60 = MenuItem::with_id(app, "dnd_60", t("tray.dnd.60"), true, None::<&str>)?;
+    let dnd_120 = MenuItem::with_id(app, "dnd_120", t("tray.dnd.120"), true, None::<&str>)?;
+    let dnd_title = match (dnd.active, dnd.until_ms) {
+        (false, _) => t("tray.dnd").to_string(),
+        (true, None) => t("tray.dnd.on").to_string(),
+        (true, Some(ms)) => {
+            let until = chrono::DateTime::from_timestamp_millis(ms as i64)
+                .map(|at| at.with_timezone(&chrono::Local).format("%H:%M").to_string())
+                .unwrap_or_default();
+            tf("tray.dnd.until", &[("time", &until)])
+        }
+    };
+    let dnd_separator = PredefinedMenuItem::separator(app)?;
+    let dnd_submenu = Submenu::with_items(
+        app,
+        dnd_title,
+        true,
+        &[
+            &dnd_off,
+            &dnd_separator,
+            &dnd_on,
+            &dnd_30,
+            &dnd_60,
+            &dnd_120,
+        ],
+    )?;
+
+    let separator1 = PredefinedMenuItem::separator(app)?;
+    let separator2 = PredefinedMenuItem::separator(app)?;
+    let separator3 = PredefinedMenuItem::separator(app)?;
+    let separator4 = PredefinedMenuItem::separator(app)?;
+    let separator5 = PredefinedMenuItem::separator(app)?;
+    let exit_item = MenuItem::with_id(app, "exit", t("tray.exit"), true, None::<&str>)?;
+
+    // --- Cancel, leading the menu while something can be cancelled ---
+    let recording_now = recording == RecordingState::Recording;
+    let cancel_item = MenuItem::with_id(
+        app,
+        "cancel",
+        if recording_now {
+            t("tray.cancel.recording")
+        } else {
+            t("tray.cancel.capture")
+        },
+        true,
+        None::<&str>,
+    )?;
+    let cancel_separator = PredefinedMenuItem::separator(app)?;
+    let mut items: Vec<&dyn IsMenuItem<R>> = Vec::new();
+    if cancel::running() || recording_now {
+        items.extend([&cancel_item as &dyn IsMenuItem<R>, &cancel_separator]);
+    }
+    items.extend([
+        &capture_submenu as &dyn IsMenuItem<R>,
+        &record_submenu,
+        &tools_submenu,
+        &separator1,
+        &recent_captures_submenu,
+        &recent_submenu,
+        &copy_last_url,
+        &open_last_capture,
+        &reveal_last_capture,
+        &open_captures,
+        &dest_submenu,
+        &separator2,
+        &open_hub,
+        &separator3,
+        &hotkeys_toggle,
+        &dnd_submenu,
+        &separator4,
+        &exit_item,
+        &separator5,
+    ]);
+    Menu::with_items(app, &items)
+}
+
+fn on_menu_event(app: &AppHandle, id: &str) {
+    use commands::{CaptureModeArg, PostActionArg};
+    let spawn_capture = |mode: CaptureModeArg| {
+        let app = app.clone();
+        std::thread::spawn(move || {
+            let post = PostActionArg::configured_for(&app, mode);
+            if let Err(e) = commands::run_capture_pipeline(mode, post, &app) {
+                tracing::warn!("tray capture failed: {e}");
+                commands::emit_error(&app, "capture", &e.to_string());
+            }
+        });
+    };
+    // route by id. dynamic-id items (recent_upload_*, recent_capture_*) are
+    // matched by prefix below so the static-arm part stays compact.
+    match id {
+        "cap_region" => spawn_capture(CaptureModeArg::Region),
+        "cap_region_last" => spawn_capture(CaptureModeArg::RegionLast),
+        "cap_window" => spawn_capture(CaptureModeArg::Window),
+        "auto_capture_toggle" => {
+            if auto_capture::is_running(app) {
+                auto_capture::stop(app);
+            } else if let Err(e) = auto_capture::start(app) {
+                tracing::warn!("auto capture start failed: {e:#}");
+                commands::emit_error(app, "auto-capture", &e.to_string());
+            }
+        }
+        "cap_fullscreen" => spawn_capture(CaptureModeArg::Fullscreen),
+        "cap_active_monitor" => spawn_capture(CaptureModeArg::ActiveMonitor),
+        "cancel" => cancel::cancel(app),
+        "rec_stop" => commands::trigger_task(app, commands::STOP_RECORDING_HOTKEY_ID),
+        "rec_region_gif" => {
+            // synthesize a tray-driven gif task so run_gif_task's start/stop
+            // toggle (keyed off the task id in AppState) works the same as
+            // a real hotkey-bound task
+            let app = app.clone();
+            std::thread::spawn(move || {
+                let post_action = app
+                    .state::<state::AppState>()
+                    .config
+                    .lock()
+                    .unwrap()
+                    .post_capture
+                    .action_for(plugin::CaptureType::Gif);
+                let task = config::CaptureTask {
+                    id: "__tray_gif".into(),
+                    name: "Tray GIF".into(),
+                    hotkey: String::new(),
+                    capture_mode: config::TaskCaptureMode::RegionGif,
+                    post_action,
+                    target_destination: None,
+                    custom_preset: None,
+                    delay_ms: None,
+                    target_window: None,
+                    follow_active_window: false,
+                    recording_size: None,
+                    hold_to_record: false,
+                    gesture: config::HotkeyGesture::Press,
+                };
+                if let Err(e) = commands::run_task(&task, &app) {
+                    tracing::warn!("tray gif failed: {e}");
+                    commands::emit_error(&app, "gif", &e.to_string());
+                }
+            });
+        }
+        "rec_region_mp4" => {
+            // synthesize a tray-driven mp4 task so run_gif_task's start/stop
+            // toggle (keyed off the task id in AppState) works the same as
+            // a real hotkey-bound task
+            let app = app.clone();
+            std::thread::spawn(move || {
+                let post_action = app
+                    .state::<state::AppState>()
+                    .config
+                    .lock()
+                    .unwrap()
+                    .post_capture
+                    .action_for(plugin::CaptureType::Gif);
+                let task = config::CaptureTask {
+                    id: "__tray_mp4".into(),
+                    name: "Tray MP4".into(),
+                    hotkey: String::new(),
+                    capture_mode: config::TaskCaptureMode::RegionMp4,
+                    post_action,
+                    target_destination: None,
+                    custom_preset: None,
+                    delay_ms: None,
+                    target_window: None,
+                    follow_active_window: false,
+                    recording_size: None,
+                    hold_to_record: false,
+                    gesture: config::HotkeyGesture::Press,
+                };
+                if let Err(e) = commands::run_task(&task, &app) {
+                    tracing::warn!("tray mp4 failed: {e}");
+                    commands::emit_error(&app, "mp4", &e.to_string());
+                }
+            });
+        }
+        "tool_gif_to_mp4" | "tool_gif_to_webm" | "tool_video_to_gif" => {
+            use recording::ConvertTarget;
+            let target = match id {
+                "tool_gif_to_mp4" => ConvertTarget::Mp4,
+                "tool_gif_to_webm" => ConvertTarget::Webm,
+                _ => ConvertTarget::Gif,
+            };
+            let app = app.clone();
+            std::thread::spawn(move || commands::convert_from_tray(&app, target));
+        }
+        "copy_last_url" => {
+            let st = app.state::<state::AppState>();
+            let last = st.last_upload.lock().unwrap().clone();
+            match last {
+                Some(rec) => {
+                    let format = st.config.lock().unwrap().upload.link_format;
+                    if let Err(e) = crate::upload::copy_url_to_clipboard(&rec.url, format) {
+                        tracing::warn!("copy last url failed: {e}");
+                    } else if st.config.lock().unwrap().ui.show_notifications {
+                        let _ = crate::clipboard::show_notification(t("notify.copied"), &rec.url);
+                    }
+                }
+                None => {
+                    let _ = crate::clipboard::show_notification(
+                        t("notify.no-uploads"),
+                        t("notify.no-uploads.body"),
+                    );
+                }
+            }
+        }
+        "open_last_capture" | "reveal_last_capture" => match commands::last_saved_capture(app) {
+            Some(path) if id == "open_last_capture" => commands::open_saved_file(&path),
+            Some(path) => commands::reveal_in_file_manager(app, &path),
+            None => {
+                let _ = crate::clipboard::show_notification(
+                    t("notify.no-captures"),
+                    t("notify.no-captures.body"),
+                );
+            }
+        },
+        "open_captures" => {
+            let st = app.state::<state::AppState>();
+            let dir = st.config.lock().unwrap().output.directory.clone();
+            let _ = std::fs::create_dir_all(&dir);
+            use tauri_plugin_opener::OpenerExt;
+            let _ = app
+                .opener()
+                .open_path(dir.to_string_lossy().to_string(), None::<&str>);
+        }
+        "tab_default" => {
+            let _ = commands::open_hub_window(app);
+        }
+        "dest_imgur" | "dest_custom" | "dest_ftp" | "dest_sftp" => {
+            let st = app.state::<state::AppState>();
+            // the toast names it as the menu does
+            let (new_dest, destination) = match id {
+                "dest_imgur" => (config::UploadDestination::Imgur, "Imgur"),
+                "dest_custom" => (
+                    config::UploadDestination::Custom,
+                    t("tray.destination.custom"),
+                ),
+                "dest_ftp" => (config::UploadDestination::Ftp, "FTP"),
+                _ => (config::UploadDestination::Sftp, "SFTP"),
+            };
+            let changed = {
+                let mut cfg = st.config.lock().unwrap();
+                let changed = cfg.upload.destination != new_dest;
+                cfg.upload.destination = new_dest;
+                changed
+            };
+            if changed {
+                st.save_config_soon(app);
+            }
+            SystemTray::rebuild(app);
+            let _ = app.emit("capscr://config-updated", ());
+            let _ = crate::clipboard::show_notification(
+                t("tray.destination"),
+                &tf("notify.destination.body", &[("destination", &destination)]),
+            );
+        }
+        "hotkeys_toggle" => {
+            use std::sync::atomic::Ordering;
+            let st = app.state::<state::AppState>();
+            let was_disabled = st.hotkeys_disabled.load(Ordering::SeqCst);
+            let next = !was_disabled;
+            if let Err(e) = commands::set_hotkeys_disabled(next, app.clone(), st) {
+                tracing::warn!("tray hotkey toggle failed: {e}");
+            }
+            let _ = crate::clipboard::show_notification(
+                if next {
+                    t("notify.hotkeys.off")
+                } else {
+                    t("notify.hotkeys.on")
+                },
+                if next {
+                    t("notify.hotkeys.off.body")
+                } else {
+                    t("notify.hotkeys.on.body")
+                },
+            );
+        }
+        "dnd_off" => crate::dnd::disable(app),
+        "dnd_on" => crate::dnd::enable(app, None),
+        "dnd_30" => crate::dnd::enable(app, Some(30)),
+        "dnd_60" => crate::dnd::enable(app, Some(60)),
+        "dnd_120" => crate::dnd::enable(app, Some(120)),
+        "exit" => commands::exit_app(app.clone()),
+        other if other.starts_with("recent_capture_") => {
+            let idx: usize = other
+                .trim_start_matches("recent_capture_")
+                .parse()
+                .unwrap_or(usize::MAX);
+            // same filter the menu was built with, so the index lines up
+            let path = app
+                .state::<state::AppState>()
+                .recent_saves
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|p| p.is_file())
+                .nth(idx)
+                .cloned();
+            match path {
+                Some(path) => commands::open_saved_file(&path),
+                None => {
+                    let _ = crate::clipboard::show_notification(
+                        t("notify.capture-missing"),
+                        t("notify.capture-missing.body"),
+                    );
+                }
+            }
+        }
+        other if other.starts_with("recent_upload_") => {
+            let idx: usize = other
+                .trim_start_matches("recent_upload_")
+                .parse()
+                .unwrap_or(usize::MAX);
+            let st = app.state::<state::AppState>();
+            let url = st
+                .recent_uploads
+                .lock()
+                .unwrap()
+                .get(idx)
+                .map(|r| r.url.clone());
+            if let Some(url) = url {
+                let format = st.config.lock().unwrap().upload.link_format;
+                if let Err(e) = crate::upload::copy_url_to_clipboard(&url, format) {
+                    tracing::warn!("copy recent url failed: {e}");
+                } else if st.config.lock().unwrap().ui.show_notifications {
+                    let _ = crate::clipboard::show_notification(t("notify.copied"), &url);
+                }
+            }
+        }
+        _ => {}
+    }
+}
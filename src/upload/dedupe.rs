@@ -0,0 +1,181 @@
+// local index of recent uploads by content hash. sending the same bytes to
+// the same destination again can hand back the earlier link instead of
+// spending time and hosting quota on a second copy. the index only ever holds
+// hashes and links, never image data
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{UploadResult, UploadService};
+use crate::json_index::{push_newest, JsonIndex};
+
+const MAX_ENTRIES: usize = 500;
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+// several upload threads may remember at once
+static INDEX: JsonIndex = JsonIndex::new("upload-index.json");
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedUpload {
+    pub sha256: String,
+    pub destination: String,
+    pub url: String,
+    pub delete_url: Option<String>,
+    pub uploaded_unix: u64,
+}
+
+pub fn digest(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+/// what makes two uploads land in the same place. None for the send-to
+/// destinations, where sending again is the point, and for encrypted uploads,
+/// whose links can't be stored
+pub fn destination_key(service: &UploadService) -> Option<String> {
+    Some(match service {
+        UploadService::Imgur => "imgur".into(),
+        UploadService::ImgurWithClientId(cid) => format!("imgur:{cid}"),
+        UploadService::ImgurAccount(account) => {
+            format!("imgur-account:{}", account.album_id)
+        }
+        UploadService::Custom(c) => format!("custom:{}", c.request_url),
+        UploadService::Ftp(t) => format!("ftp:{}:{}{}", t.host, t.port, t.remote_dir),
+        UploadService::Sftp(t) => format!("sftp:{}:{}{}", t.host, t.port, t.remote_dir),
+        UploadService::S3(t) => format!("s3:{}/{}", t.endpoint, t.bucket),
+        UploadService::Webdav(t) => format!("webdav:{}/{}", t.server_url, t.path_template),
+        UploadService::Dropbox(account) => format!("dropbox:{}", account.folder),
+        // an encrypted link carries its key in the fragment, and the index is
+        // plain json on disk; keeping it there would undo the encryption
+        UploadService::Encrypted(_) | UploadService::Email(_) | UploadService::Webhook(_) => {
+            return None
+        }
+    })
+}
+
+/// the newest upload of `sha256` to `destination` from the last `days` days
+pub fn find(sha256: &str, destination: &str, days: u32) -> Option<IndexedUpload> {
+    let entries: Vec<IndexedUpload> = INDEX.read();
+    find_in(
+        &entries,
+        sha256,
+        destination,
+        u64::from(days) * DAY_SECS,
+        now_unix(),
+    )
+    .cloned()
+}
+
+pub fn remember(sha256: &str, destination: &str, result: &UploadResult) {
+    let entry = IndexedUpload {
+        sha256: sha256.to_string(),
+        destination: destination.to_string(),
+        url: result.url.clone(),
+        delete_url: result.delete_url.clone(),
+        uploaded_unix: now_unix(),
+    };
+    let written = INDEX.update(|entries: &mut Vec<IndexedUpload>| {
+        insert(entries, entry);
+        true
+    });
+    if let Err(e) = written {
+        tracing::warn!("couldn't write the upload index: {e:#}");
+    }
+}
+
+/// drop every entry, e.g. after links were deleted on the host
+pub fn clear() -> Result<()> {
+    INDEX.clear()
+}
+
+fn find_in<'a>(
+    entries: &'a [IndexedUpload],
+    sha256: &str,
+    destination: &str,
+    max_age_secs: u64,
+    now: u64,
+) -> Option<&'a IndexedUpload> {
+    entries.iter().find(|e| {
+        e.sha256 == sha256
+            && e.destination == destination
+            && now.saturating_sub(e.uploaded_unix) <= max_age_secs
+    })
+}
+
+// newest first, one entry per hash and destination
+fn insert(entries: &mut Vec<IndexedUpload>, entry: IndexedUpload) {
+    let (sha256, destination) = (entry.sha256.clone(), entry.destination.clone());
+    push_newest(entries, entry, MAX_ENTRIES, |e| {
+        e.sha256 == sha256 && e.destination == destination
+    });
+}
+
+fn now_unix() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(sha256: &str, destination: &str, at: u64) -> IndexedUpload {
+        IndexedUpload {
+            sha256: sha256.into(),
+            destination: destination.into(),
+            url: format!("https://h.example/{sha256}/{at}"),
+            delete_url: None,
+            uploaded_unix: at,
+        }
+    }
+
+    #[test]
+    fn lookup_matches_hash_destination_and_age() {
+        let mut entries = Vec::new();
+        insert(&mut entries, entry("aa", "imgur", 1_000));
+        insert(&mut entries, entry("aa", "s3:x/b", 5_000));
+
+        let hit = find_in(&entries, "aa", "imgur", DAY_SECS, 2_000).unwrap();
+        assert_eq!(hit.uploaded_unix, 1_000);
+        assert!(find_in(&entries, "aa", "custom:u", DAY_SECS, 2_000).is_none());
+        assert!(find_in(&entries, "bb", "imgur", DAY_SECS, 2_000).is_none());
+        assert!(find_in(&entries, "aa", "imgur", DAY_SECS, 1_000 + DAY_SECS + 1).is_none());
+    }
+
+    #[test]
+    fn insert_replaces_the_older_upload_and_caps_the_index() {
+        let mut entries = Vec::new();
+        insert(&mut entries, entry("aa", "imgur", 1));
+        insert(&mut entries, entry("aa", "imgur", 2));
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].uploaded_unix, 2);
+
+        for i in 0..MAX_ENTRIES + 10 {
+            insert(&mut entries, entry(&format!("{i}"), "imgur", 3));
+        }
+        assert_eq!(entries.len(), MAX_ENTRIES);
+        assert_eq!(entries[0].sha256, format!("{}", MAX_ENTRIES + 9));
+    }
+
+    #[test]
+    fn send_to_and_encrypted_destinations_are_never_deduped() {
+        let email = UploadService::Email(super::super::send::EmailTarget::default());
+        assert!(destination_key(&email).is_none());
+        assert_eq!(
+            destination_key(&UploadService::Imgur).as_deref(),
+            Some("imgur")
+        );
+        let sealed = UploadService::Encrypted(Box::new(UploadService::Imgur));
+        assert!(destination_key(&sealed).is_none());
+    }
+
+    #[test]
+    fn digest_is_hex_sha256() {
+        assert_eq!(
+            digest(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
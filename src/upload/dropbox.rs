@@ -0,0 +1,244 @@
+// dropbox sign-in and uploads. like imgur's PIN grant, dropbox's code flow
+// without a redirect uri shows the user a code to paste back, so a desktop
+// app needs no local listener. PKCE replaces the client secret: users
+// register their own app and only its app key is stored. the refresh token is
+// long-lived and goes into the secret vault; access tokens last four hours
+// and only ever live in memory here.
+
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use sha2::{Digest, Sha256};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::{
+    read_capped, sanitize_remote_filename, shared_uploader, uniquify_remote_filename,
+    OAuthProvider, OAuthTokens, UploadResult, MAX_URL_LEN,
+};
+
+const AUTHORIZE_URL: &str = "https://www.dropbox.com/oauth2/authorize";
+const ACCOUNT_URL: &str = "https://api.dropboxapi.com/2/users/get_current_account";
+const UPLOAD_URL: &str = "https://content.dropboxapi.com/2/files/upload";
+const SHARE_URL: &str = "https://api.dropboxapi.com/2/sharing/create_shared_link_with_settings";
+const MAX_CODE_LEN: usize = 128;
+const OAUTH: OAuthProvider = OAuthProvider {
+    name: "Dropbox",
+    token_url: "https://api.dropboxapi.com/oauth2/token",
+    default_expiry: Duration::from_secs(14400),
+};
+
+/// an upload target bound to a signed-in dropbox account
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DropboxAccount {
+    pub app_key: String,
+    /// empty when nobody has signed in yet
+    pub refresh_token: String,
+    /// absolute folder in the dropbox, e.g. /capscr
+    pub folder: String,
+}
+
+// the PKCE verifier between opening the browser and the user pasting the
+// code back. a second authorize_url replaces it, so only the newest code works
+static PENDING_VERIFIER: Mutex<Option<String>> = Mutex::new(None);
+
+/// browser url the user approves capscr at. dropbox answers with a code page
+pub fn authorize_url(app_key: &str) -> Result<String> {
+    let app_key = app_key.trim();
+    if app_key.is_empty() {
+        return Err(anyhow!("set a Dropbox app key before signing in"));
+    }
+    let verifier = format!(
+        "{}{}",
+        uuid::Uuid::new_v4().as_simple(),
+        uuid::Uuid::new_v4().as_simple()
+    );
+    let mut url = url::Url::parse(AUTHORIZE_URL)?;
+    url.query_pairs_mut()
+        .append_pair("client_id", app_key)
+        .append_pair("response_type", "code")
+        .append_pair("token_access_type", "offline")
+        .append_pair("code_challenge", &pkce_challenge(&verifier))
+        .append_pair("code_challenge_method", "S256");
+    *PENDING_VERIFIER.lock().unwrap() = Some(verifier);
+    Ok(url.to_string())
+}
+
+/// trade the code dropbox showed the user for a token pair
+pub fn exchange_code(app_key: &str, code: &str) -> Result<OAuthTokens> {
+    let code = code.trim();
+    if code.is_empty()
+        || code.len() > MAX_CODE_LEN
+        || !code
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+    {
+        return Err(anyhow!("that doesn't look like a Dropbox access code"));
+    }
+    let verifier = PENDING_VERIFIER
+        .lock()
+        .unwrap()
+        .take()
+        .ok_or_else(|| anyhow!("start the sign-in again; the Dropbox code has expired"))?;
+    let json = OAUTH.request_tokens(&[
+        ("client_id", app_key.trim()),
+        ("grant_type", "authorization_code"),
+        ("code", code),
+        ("code_verifier", verifier.as_str()),
+    ])?;
+    let tokens = OAUTH.parse_tokens(&json, None)?;
+    OAUTH.remember(&tokens);
+    Ok(tokens)
+}
+
+/// the signed-in account's display name, shown in Destinations
+pub fn account_name(access_token: &str) -> Result<String> {
+    let response = shared_uploader()?
+        .client
+        .post(ACCOUNT_URL)
+        .bearer_auth(access_token)
+        .send()?;
+    let json = read_json(response)?;
+    Ok(json
+        .pointer("/name/display_name")
+        .or_else(|| json.get("email"))
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string())
+}
+
+fn access_token(account: &DropboxAccount) -> Result<String> {
+    if account.refresh_token.is_empty() {
+        return Err(anyhow!("sign in to Dropbox from Destinations first"));
+    }
+    OAUTH.access_token(
+        &[("client_id", account.app_key.trim())],
+        &account.refresh_token,
+    )
+}
+
+pub fn forget_cached_token() {
+    OAUTH.forget_cached_token();
+}
+
+/// uploads into the account's folder and returns a shared link to the file
+pub fn upload(data: &[u8], file_name: &str, account: &DropboxAccount) -> Result<UploadResult> {
+    let token = access_token(account)?;
+    let uploader = shared_uploader()?;
+    let client = &uploader.client;
+    let name = uniquify_remote_filename(&sanitize_remote_filename(file_name));
+    let path = format!("{}/{}", account.folder.trim_end_matches('/'), name);
+    let arg = serde_json::json!({ "path": path, "mode": "add", "autorename": true });
+    let response = client
+        .post(UPLOAD_URL)
+        .bearer_auth(&token)
+        .header("Dropbox-API-Arg", header_safe_json(&arg))
+        .header("Content-Type", "application/octet-stream")
+        .body(data.to_vec())
+        .send()?;
+    let uploaded = read_json(response)?;
+    // autorename may have picked another name
+    let stored = uploaded
+        .get("path_lower")
+        .and_then(|p| p.as_str())
+        .ok_or_else(|| anyhow!("Dropbox returned no file path"))?;
+
+    let response = client
+        .post(SHARE_URL)
+        .bearer_auth(&token)
+        .json(&serde_json::json!({ "path": stored }))
+        .send()?;
+    let link = if response.status().as_u16() == 409 {
+        // a link made earlier for the same path comes back inside the error
+        let text = read_capped(response)?;
+        let json: serde_json::Value = serde_json::from_str(&text)?;
+        json.pointer("/error/shared_link_already_exists/metadata/url")
+            .and_then(|u| u.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("Dropbox couldn't share the upload: {text}"))?
+    } else {
+        read_json(response)?
+            .get("url")
+            .and_then(|u| u.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("Dropbox returned no shared link"))?
+    };
+    if link.len() > MAX_URL_LEN || !link.starts_with("https://") {
+        return Err(anyhow!("Dropbox returned an unusable link"));
+    }
+    Ok(UploadResult {
+        url: link,
+        delete_url: None,
+    })
+}
+
+fn pkce_challenge(verifier: &str) -> String {
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+// Dropbox-API-Arg rides in an http header, which has to be ascii; dropbox
+// reads \u escapes in it like any json
+fn header_safe_json(value: &serde_json::Value) -> String {
+    let mut out = String::new();
+    for c in value.to_string().chars() {
+        if c.is_ascii() {
+            out.push(c);
+        } else {
+            let mut units = [0u16; 2];
+            for unit in c.encode_utf16(&mut units) {
+                out.push_str(&format!("\\u{:04x}", unit));
+            }
+        }
+    }
+    out
+}
+
+fn read_json(response: reqwest::blocking::Response) -> Result<serde_json::Value> {
+    let status = response.status();
+    if status.as_u16() == 401 {
+        forget_cached_token();
+        return Err(anyhow!("Dropbox rejected the sign-in ({status})"));
+    }
+    if !status.is_success() {
+        return Err(anyhow!("Dropbox request failed with status: {status}"));
+    }
+    Ok(serde_json::from_str(&read_capped(response)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authorize_url_uses_pkce_and_offline_access() {
+        let url = authorize_url(" key1 ").unwrap();
+        assert!(url.starts_with(AUTHORIZE_URL));
+        assert!(url.contains("client_id=key1"));
+        assert!(url.contains("token_access_type=offline"));
+        assert!(url.contains("code_challenge_method=S256"));
+        assert!(PENDING_VERIFIER.lock().unwrap().is_some());
+        assert!(authorize_url("  ").is_err());
+    }
+
+    #[test]
+    fn pkce_challenge_matches_rfc7636_example() {
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn api_arg_header_is_ascii() {
+        let arg = serde_json::json!({ "path": "/Bilder/schön 😀.png" });
+        let header = header_safe_json(&arg);
+        assert!(header.is_ascii());
+        let back: serde_json::Value = serde_json::from_str(&header).unwrap();
+        assert_eq!(back, arg);
+    }
+
+    #[test]
+    fn bad_codes_are_refused_before_any_request() {
+        assert!(exchange_code("key", "has space").is_err());
+        assert!(exchange_code("key", "").is_err());
+    }
+}
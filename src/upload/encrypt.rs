@@ -0,0 +1,215 @@
+// encrypt-before-upload, PrivateBin style. the capture is sealed with
+// AES-256-GCM under a fresh random key, the destination only ever stores the
+// ciphertext, and the key travels in the link's #fragment — browsers and
+// http clients never send the fragment, so the host can't read the image.
+// opening a link is the reverse: fetch the ciphertext, take the key from the
+// fragment, decrypt
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use anyhow::{anyhow, Result};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+use super::{shared_uploader, ImageUploader, UploadResult, UploadService, MAX_UPLOAD_SIZE};
+
+// file layout: magic, 12-byte nonce, then the ciphertext with its 16-byte tag
+const MAGIC: &[u8; 8] = b"CAPSCRE1";
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+/// how much bigger sealing makes a file
+pub(super) const SEALED_OVERHEAD: usize = MAGIC.len() + NONCE_LEN + TAG_LEN;
+
+pub(crate) const ENCRYPTED_MIME: &str = "application/octet-stream";
+
+/// the sealed bytes and the base64url key that opens them
+pub struct Sealed {
+    pub data: Vec<u8>,
+    pub key: String,
+}
+
+pub fn seal(plain: &[u8]) -> Result<Sealed> {
+    let key = Aes256Gcm::generate_key(OsRng);
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = Aes256Gcm::new(&key)
+        .encrypt(&nonce, plain)
+        .map_err(|_| anyhow!("encryption failed"))?;
+    let mut data = Vec::with_capacity(MAGIC.len() + NONCE_LEN + ciphertext.len());
+    data.extend_from_slice(MAGIC);
+    data.extend_from_slice(&nonce);
+    data.extend_from_slice(&ciphertext);
+    Ok(Sealed {
+        data,
+        key: URL_SAFE_NO_PAD.encode(key),
+    })
+}
+
+pub fn open(data: &[u8], key: &str) -> Result<Vec<u8>> {
+    let key = URL_SAFE_NO_PAD
+        .decode(key.trim())
+        .ok()
+        .filter(|k| k.len() == KEY_LEN)
+        .ok_or_else(|| anyhow!("the link's key is missing or malformed"))?;
+    let body = data
+        .strip_prefix(MAGIC)
+        .filter(|b| b.len() >= NONCE_LEN + TAG_LEN)
+        .ok_or_else(|| anyhow!("not a capscr encrypted upload"))?;
+    let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+    Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key))
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        // a wrong key and a tampered file look the same to GCM
+        .map_err(|_| anyhow!("couldn't decrypt: wrong key or damaged file"))
+}
+
+/// the hosted url with the key as its fragment
+pub fn link(url: &str, key: &str) -> Result<String> {
+    let mut parsed = url::Url::parse(url)?;
+    parsed.set_fragment(Some(key));
+    Ok(parsed.to_string())
+}
+
+/// the url to fetch and the key, from a link made by `link`
+fn split_link(link: &str) -> Result<(url::Url, String)> {
+    let mut url = url::Url::parse(link.trim()).map_err(|_| anyhow!("not a valid link"))?;
+    let key = url
+        .fragment()
+        .filter(|f| !f.is_empty())
+        .map(String::from)
+        .ok_or_else(|| anyhow!("the link has no #key; it wasn't made by an encrypted upload"))?;
+    url.set_fragment(None);
+    Ok((direct_download_url(url), key))
+}
+
+// share pages from the link-hosting destinations are html viewers; point
+// at the raw file instead so the fetch gets the ciphertext
+fn direct_download_url(mut url: url::Url) -> url::Url {
+    let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+    if host == "www.dropbox.com" || host == "dropbox.com" {
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(k, _)| k != "dl" && k != "raw")
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect();
+        url.query_pairs_mut()
+            .clear()
+            .extend_pairs(pairs)
+            .append_pair("dl", "1");
+        return url;
+    }
+    // nextcloud/ownCloud public shares: <base>/s/<token> -> .../download
+    let segments: Vec<String> = url
+        .path_segments()
+        .map(|s| s.filter(|s| !s.is_empty()).map(String::from).collect())
+        .unwrap_or_default();
+    if matches!(segments.as_slice(), [.., s, _token] if s == "s") {
+        if let Ok(mut path) = url.path_segments_mut() {
+            path.pop_if_empty().push("download");
+        }
+    }
+    url
+}
+
+impl ImageUploader {
+    pub(super) fn upload_encrypted(
+        &self,
+        data: &[u8],
+        file_name: &str,
+        inner: &UploadService,
+    ) -> Result<UploadResult> {
+        match inner {
+            UploadService::Imgur
+            | UploadService::ImgurWithClientId(_)
+            | UploadService::ImgurAccount(_) => {
+                return Err(anyhow!(
+                    "Imgur only takes images; pick another destination for encrypted uploads"
+                ))
+            }
+            UploadService::Email(_) | UploadService::Webhook(_) | UploadService::Encrypted(_) => {
+                return Err(anyhow!(
+                    "encrypted uploads need a destination that hosts links"
+                ))
+            }
+            _ => {}
+        }
+        let sealed = seal(data)?;
+        // the real name and extension would tell the host what's inside
+        let stem = std::path::Path::new(file_name)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("upload");
+        let file_name = format!("{stem}.bin");
+        let hosted = self.upload_raw(&sealed.data, ENCRYPTED_MIME, &file_name, inner)?;
+        Ok(UploadResult {
+            url: link(&hosted.url, &sealed.key)?,
+            delete_url: hosted.delete_url,
+        })
+    }
+}
+
+/// download an encrypted link and return the decrypted file
+pub fn fetch_and_open(link: &str) -> Result<Vec<u8>> {
+    let (url, key) = split_link(link)?;
+    ImageUploader::validate_url_security(url.as_str())?;
+    let response = shared_uploader()?.client.get(url).send()?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(anyhow!("download failed with status: {}", status));
+    }
+    if response.content_length().unwrap_or(0) > MAX_UPLOAD_SIZE as u64 {
+        return Err(anyhow!("download too large"));
+    }
+    let data = response.bytes()?;
+    if data.len() > MAX_UPLOAD_SIZE {
+        return Err(anyhow!("download too large"));
+    }
+    open(&data, &key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sealed_data_opens_with_its_key_only() {
+        let plain = b"\x89PNG fake image bytes";
+        let sealed = seal(plain).unwrap();
+        assert!(sealed.data.starts_with(MAGIC));
+        assert_eq!(sealed.data.len(), plain.len() + SEALED_OVERHEAD);
+        assert_eq!(open(&sealed.data, &sealed.key).unwrap(), plain);
+
+        let other = seal(plain).unwrap();
+        assert_ne!(sealed.key, other.key);
+        assert!(open(&sealed.data, &other.key).is_err());
+
+        let mut tampered = sealed.data.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(open(&tampered, &sealed.key).is_err());
+        assert!(open(plain, &sealed.key).is_err());
+        assert!(open(&sealed.data, "short").is_err());
+    }
+
+    #[test]
+    fn link_carries_the_key_in_the_fragment() {
+        let made = link("https://files.example/a/shot.bin?x=1", "k3y_-").unwrap();
+        assert_eq!(made, "https://files.example/a/shot.bin?x=1#k3y_-");
+        let (url, key) = split_link(&made).unwrap();
+        assert_eq!(url.as_str(), "https://files.example/a/shot.bin?x=1");
+        assert_eq!(key, "k3y_-");
+        assert!(split_link("https://files.example/a/shot.bin").is_err());
+    }
+
+    #[test]
+    fn share_pages_map_to_their_raw_file() {
+        let (url, _) =
+            split_link("https://www.dropbox.com/scl/fi/abc/s.bin?rlkey=z&dl=0#k").unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://www.dropbox.com/scl/fi/abc/s.bin?rlkey=z&dl=1"
+        );
+        let (url, _) = split_link("https://cloud.example.com/s/AbC123#k").unwrap();
+        assert_eq!(url.as_str(), "https://cloud.example.com/s/AbC123/download");
+        let (url, _) = split_link("https://cdn.example/s.bin#k").unwrap();
+        assert_eq!(url.as_str(), "https://cdn.example/s.bin");
+    }
+}
@@ -0,0 +1,245 @@
+// imgur account sign-in. imgur has no OAuth device flow; the closest thing
+// for a desktop app is the PIN grant — the user approves capscr in the
+// browser, imgur shows a short PIN, and the PIN is traded for a refresh
+// token. the refresh token is long-lived and goes into the secret vault;
+// access tokens expire after a month and only ever live in memory here.
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::time::Duration;
+
+use super::{read_capped, shared_uploader, OAuthProvider};
+
+const AUTHORIZE_URL: &str = "https://api.imgur.com/oauth2/authorize";
+const API_BASE: &str = "https://api.imgur.com/3";
+const MAX_PIN_LEN: usize = 64;
+const OAUTH: OAuthProvider = OAuthProvider {
+    name: "Imgur",
+    token_url: "https://api.imgur.com/oauth2/token",
+    default_expiry: Duration::from_secs(3600),
+};
+
+/// an upload target bound to a signed-in imgur account
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ImgurAccount {
+    pub client_id: String,
+    pub client_secret: String,
+    pub refresh_token: String,
+    pub album_id: String,
+    pub title: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImgurTokens {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub account_username: String,
+    pub expires_in: Duration,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImgurAlbum {
+    pub id: String,
+    pub title: String,
+    pub privacy: String,
+    pub images_count: u64,
+}
+
+/// browser url the user approves capscr at. imgur answers with a PIN page
+pub fn authorize_url(client_id: &str) -> Result<String> {
+    let client_id = client_id.trim();
+    if client_id.is_empty() {
+        return Err(anyhow!("set an Imgur Client-ID before signing in"));
+    }
+    let mut url = url::Url::parse(AUTHORIZE_URL)?;
+    url.query_pairs_mut()
+        .append_pair("client_id", client_id)
+        .append_pair("response_type", "pin");
+    Ok(url.to_string())
+}
+
+/// trade the PIN imgur showed the user for a token pair
+pub fn exchange_pin(client_id: &str, client_secret: &str, pin: &str) -> Result<ImgurTokens> {
+    let pin = pin.trim();
+    if pin.is_empty() || pin.len() > MAX_PIN_LEN || !pin.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return Err(anyhow!("that doesn't look like an Imgur PIN"));
+    }
+    if client_secret.trim().is_empty() {
+        return Err(anyhow!("an Imgur client secret is required to sign in"));
+    }
+    let json = OAUTH.request_tokens(&[
+        ("client_id", client_id.trim()),
+        ("client_secret", client_secret.trim()),
+        ("grant_type", "pin"),
+        ("pin", pin),
+    ])?;
+    parse_token_response(&json)
+}
+
+/// a valid access token for `account`, refreshing through imgur when the
+/// cached one is missing or about to expire
+pub fn access_token(account: &ImgurAccount) -> Result<String> {
+    OAUTH.access_token(
+        &[
+            ("client_id", account.client_id.trim()),
+            ("client_secret", account.client_secret.trim()),
+        ],
+        &account.refresh_token,
+    )
+}
+
+pub fn forget_cached_token() {
+    OAUTH.forget_cached_token();
+}
+
+pub fn list_albums(account: &ImgurAccount) -> Result<Vec<ImgurAlbum>> {
+    let token = access_token(account)?;
+    let json = send_json(
+        shared_uploader()?
+            .client
+            .get(format!("{API_BASE}/account/me/albums/0"))
+            .bearer_auth(token),
+    )?;
+    let albums = json
+        .get("data")
+        .and_then(|d| d.as_array())
+        .ok_or_else(|| anyhow!("Imgur returned no album list"))?;
+    Ok(albums.iter().filter_map(parse_album).collect())
+}
+
+pub fn create_album(account: &ImgurAccount, title: &str, privacy: &str) -> Result<ImgurAlbum> {
+    let token = access_token(account)?;
+    let json = send_json(
+        shared_uploader()?
+            .client
+            .post(format!("{API_BASE}/album"))
+            .bearer_auth(token)
+            .form(&[("title", title), ("privacy", privacy)]),
+    )?;
+    let id = json
+        .get("data")
+        .and_then(|d| d.get("id"))
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow!("Imgur didn't return an album id"))?;
+    Ok(ImgurAlbum {
+        id: id.to_string(),
+        title: title.to_string(),
+        privacy: privacy.to_string(),
+        images_count: 0,
+    })
+}
+
+// extra multipart fields for an account upload. empty values are left out so
+// imgur applies its own defaults
+pub(super) fn upload_fields(account: &ImgurAccount) -> Vec<(&'static str, String)> {
+    [
+        ("album", &account.album_id),
+        ("title", &account.title),
+        ("description", &account.description),
+    ]
+    .into_iter()
+    .filter(|(_, v)| !v.trim().is_empty())
+    .map(|(k, v)| (k, v.trim().to_string()))
+    .collect()
+}
+
+// a fresh sign-in's tokens, cached, plus the account name imgur adds
+fn parse_token_response(json: &serde_json::Value) -> Result<ImgurTokens> {
+    let tokens = OAUTH.parse_tokens(json, None)?;
+    OAUTH.remember(&tokens);
+    Ok(ImgurTokens {
+        access_token: tokens.access_token,
+        refresh_token: tokens.refresh_token,
+        account_username: json
+            .get("account_username")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        expires_in: tokens.expires_in,
+    })
+}
+
+fn parse_album(v: &serde_json::Value) -> Option<ImgurAlbum> {
+    let id = v.get("id")?.as_str()?;
+    Some(ImgurAlbum {
+        id: id.to_string(),
+        title: v
+            .get("title")
+            .and_then(|t| t.as_str())
+            .unwrap_or("(untitled)")
+            .to_string(),
+        privacy: v
+            .get("privacy")
+            .and_then(|p| p.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        images_count: v.get("images_count").and_then(|n| n.as_u64()).unwrap_or(0),
+    })
+}
+
+fn send_json(request: reqwest::blocking::RequestBuilder) -> Result<serde_json::Value> {
+    let response = request.send()?;
+    let status = response.status();
+    if status.as_u16() == 401 || status.as_u16() == 403 {
+        forget_cached_token();
+        return Err(anyhow!("Imgur rejected the sign-in ({status})"));
+    }
+    if !status.is_success() {
+        return Err(anyhow!("Imgur request failed with status: {status}"));
+    }
+    Ok(serde_json::from_str(&read_capped(response)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authorize_url_requests_a_pin() {
+        let url = authorize_url(" abc123 ").unwrap();
+        assert!(url.starts_with(AUTHORIZE_URL));
+        assert!(url.contains("client_id=abc123"));
+        assert!(url.contains("response_type=pin"));
+        assert!(authorize_url("  ").is_err());
+    }
+
+    #[test]
+    fn token_response_parses_and_requires_both_tokens() {
+        let tokens = parse_token_response(&serde_json::json!({
+            "access_token": "a1", "refresh_token": "r1", "expires_in": 2419200,
+            "token_type": "bearer", "account_username": "someone",
+        }))
+        .unwrap();
+        assert_eq!(tokens.access_token, "a1");
+        assert_eq!(tokens.refresh_token, "r1");
+        assert_eq!(tokens.account_username, "someone");
+        assert_eq!(tokens.expires_in, Duration::from_secs(2419200));
+        assert!(parse_token_response(&serde_json::json!({ "access_token": "a1" })).is_err());
+    }
+
+    #[test]
+    fn upload_fields_skip_blank_values() {
+        let account = ImgurAccount {
+            album_id: "aB3".into(),
+            title: "  ".into(),
+            description: " notes ".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            upload_fields(&account),
+            vec![
+                ("album", "aB3".to_string()),
+                ("description", "notes".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn bad_pins_are_refused_before_any_request() {
+        assert!(exchange_pin("cid", "secret", "12 34").is_err());
+        assert!(exchange_pin("cid", "secret", "").is_err());
+        assert!(exchange_pin("cid", "", "1234").is_err());
+    }
+}
@@ -1,31 +1,69 @@
 #![allow(dead_code)]
 
+pub mod dedupe;
+pub mod dropbox;
+pub mod encrypt;
+pub mod imgur;
 pub mod known_hosts;
+pub mod proxy;
+pub mod recompress;
+pub mod send;
+pub mod sensitive;
+pub mod sxcu;
+pub mod webdav;
 
 use anyhow::{anyhow, Result};
 use image::RgbaImage;
+use std::collections::BTreeMap;
 use std::io::Cursor;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr, ToSocketAddrs};
-use std::sync::{Arc, OnceLock};
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::config::LinkFormat;
 
 const MAX_UPLOAD_SIZE: usize = 32 * 1024 * 1024;
 const UPLOAD_TIMEOUT_SECS: u64 = 60;
 const MAX_URL_LEN: usize = 2048;
 const MAX_RESPONSE_SIZE: usize = 1024 * 1024;
 const MAX_REDIRECTS: usize = 5;
+const RATE_LIMIT_BACKOFF_MS: u64 = 2000;
 const MAX_FORM_NAME_LEN: usize = 64;
 const MAX_RESPONSE_PATH_LEN: usize = 128;
+const MAX_CUSTOM_HEADERS: usize = 16;
+const MAX_CUSTOM_FORM_FIELDS: usize = 16;
+const MAX_HEADER_VALUE_LEN: usize = 4096;
+const MAX_RESPONSE_REGEX_LEN: usize = 256;
+// refresh an OAuth access token a little before its stated expiry so a slow
+// upload can't straddle it
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(300);
+// headers reqwest computes from the body or the url; a user value would
+// either be ignored or corrupt the request framing
+const RESERVED_HEADERS: &[&str] = &[
+    "host",
+    "content-length",
+    "content-type",
+    "transfer-encoding",
+    "connection",
+];
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum UploadService {
     #[default]
     Imgur,
     ImgurWithClientId(String),
+    ImgurAccount(imgur::ImgurAccount),
     Custom(CustomUploader),
     Ftp(FtpTarget),
     Sftp(SftpTarget),
     S3(S3Target),
+    Webdav(webdav::WebdavTarget),
+    Dropbox(dropbox::DropboxAccount),
+    Email(send::EmailTarget),
+    Webhook(send::WebhookTarget),
+    /// seal the bytes before they reach the wrapped destination; the link
+    /// carries the key
+    Encrypted(Box<UploadService>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -61,12 +99,29 @@ pub struct SftpTarget {
     pub private_key_passphrase: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CustomMethod {
+    /// multipart form: the file under `file_form_name` plus the extra fields
+    #[default]
+    Post,
+    /// the raw file as the request body; extra fields go on the query string
+    Put,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CustomUploader {
     pub name: String,
     pub request_url: String,
+    pub method: CustomMethod,
     pub file_form_name: String,
     pub response_url_path: String,
+    /// when set, the url is the first capture group (or the whole match) of
+    /// this regex over the raw response, instead of `response_url_path`
+    pub response_regex: String,
+    pub headers: Vec<(String, String)>,
+    pub form_fields: Vec<(String, String)>,
+    /// the host's limit in bytes, 0 for none beyond MAX_UPLOAD_SIZE
+    pub max_file_size: usize,
 }
 
 impl Default for CustomUploader {
@@ -74,8 +129,13 @@ impl Default for CustomUploader {
         Self {
             name: String::from("Custom"),
             request_url: String::new(),
+            method: CustomMethod::Post,
             file_form_name: String::from("file"),
             response_url_path: String::from("url"),
+            response_regex: String::new(),
+            headers: Vec::new(),
+            form_fields: Vec::new(),
+            max_file_size: 0,
         }
     }
 }
@@ -90,20 +150,29 @@ pub struct ImageUploader {
     client: reqwest::blocking::Client,
 }
 
-static SHARED_UPLOADER: OnceLock<std::result::Result<ImageUploader, String>> = OnceLock::new();
+// rebuilt when the proxy settings change; callers holding the previous Arc
+// finish their upload on the old client
+static SHARED_UPLOADER: Mutex<Option<Arc<ImageUploader>>> = Mutex::new(None);
+// access tokens of the signed-in accounts, by provider name
+static TOKEN_CACHE: Mutex<BTreeMap<&'static str, CachedToken>> = Mutex::new(BTreeMap::new());
 
 // the actual SSRF enforcement: reqwest resolves every connection — the initial
 // request and each redirect hop — through this resolver, so a hostname that
 // resolves to a private/internal address is refused at connect time. this
 // closes the gap where the redirect policy only string-matched the host and
 // where the real connect re-resolved DNS after validate_url_security's checks.
-pub(crate) struct ValidatingResolver;
+// the one exception is the configured proxy: it's reached by name and may
+// well be on the LAN, and the targets behind it are resolved by the proxy
+#[derive(Default)]
+pub(crate) struct ValidatingResolver {
+    proxy_hosts: Vec<String>,
+}
 
 /// a reqwest DNS resolver that refuses any host resolving to a private/internal
 /// address. share it with other outbound clients (the marketplace) so they get
 /// the same SSRF guard the uploader has.
 pub(crate) fn ssrf_validating_resolver() -> Arc<ValidatingResolver> {
-    Arc::new(ValidatingResolver)
+    Arc::new(ValidatingResolver::default())
 }
 
 // resolve a hostname and keep only public addresses, rejecting the whole lookup
@@ -127,8 +196,20 @@ fn resolve_public_addrs(host: &str) -> std::result::Result<Vec<SocketAddr>, Stri
 impl reqwest::dns::Resolve for ValidatingResolver {
     fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
         let host = name.as_str().to_string();
+        let is_proxy = self
+            .proxy_hosts
+            .iter()
+            .any(|p| p.eq_ignore_ascii_case(&host));
         Box::pin(async move {
-            match resolve_public_addrs(&host) {
+            let resolved = if is_proxy {
+                (host.as_str(), 0u16)
+                    .to_socket_addrs()
+                    .map(|addrs| addrs.collect::<Vec<_>>())
+                    .map_err(|e| e.to_string())
+            } else {
+                resolve_public_addrs(&host)
+            };
+            match resolved {
                 Ok(addrs) => {
                     let iter: reqwest::dns::Addrs = Box::new(addrs.into_iter());
                     Ok(iter)
@@ -141,10 +222,17 @@ impl reqwest::dns::Resolve for ValidatingResolver {
 
 impl ImageUploader {
     pub fn new() -> Result<Self> {
-        let client = reqwest::blocking::Client::builder()
+        Self::with_proxy(&proxy::current())
+    }
+
+    pub fn with_proxy(setup: &proxy::ProxySetup) -> Result<Self> {
+        let builder = proxy::apply(reqwest::blocking::Client::builder(), setup)?;
+        let client = builder
             .timeout(Duration::from_secs(UPLOAD_TIMEOUT_SECS))
             .user_agent("capscr/1.0")
-            .dns_resolver(Arc::new(ValidatingResolver))
+            .dns_resolver(Arc::new(ValidatingResolver {
+                proxy_hosts: proxy::proxy_hosts(setup),
+            }))
             // a cheap first pass on each redirect target; the dns resolver above
             // is what actually stops a redirect to a private/internal IP (SSRF)
             .redirect(reqwest::redirect::Policy::custom(|attempt| {
@@ -277,6 +365,11 @@ impl ImageUploader {
             .unwrap_or_default();
 
         if resolved_ips.is_empty() {
+            // behind a proxy the local resolver may not know public names at
+            // all; the proxy resolves them, and the checks above still hold
+            if !proxy::proxy_hosts(&proxy::current()).is_empty() {
+                return Ok(());
+            }
             return Err(anyhow!("Could not resolve hostname"));
         }
 
@@ -375,31 +468,73 @@ impl ImageUploader {
         if data.len() > MAX_UPLOAD_SIZE {
             return Err(anyhow!("Upload too large ({} bytes)", data.len()));
         }
+        // the wrapped upload does its own retrying
+        if let UploadService::Encrypted(inner) = service {
+            return self.upload_encrypted(data, file_name, inner);
+        }
         // retry transient network failures up to 3 times with exponential
         // backoff (300ms, 600ms). HTTP-status errors and parser errors are
         // NOT retried — those indicate a real problem at the destination,
         // not a flaky link.
+        // a link-only webhook needs the image hosted first. that upload
+        // retries on its own, so a flaky webhook post below doesn't upload
+        // the capture twice
+        let hosted = match service {
+            UploadService::Webhook(send::WebhookTarget {
+                host: Some(host), ..
+            }) => Some(self.upload_raw(data, mime, file_name, host)?),
+            _ => None,
+        };
         let attempts = 3u32;
         let mut delay_ms = 300u64;
         let mut last_err: Option<anyhow::Error> = None;
         for attempt in 0..attempts {
             let result = match service {
-                UploadService::Imgur => self.upload_imgur(data, mime, file_name, "546c25a59c58ad7"),
+                UploadService::Imgur => self.upload_imgur(
+                    data,
+                    mime,
+                    file_name,
+                    "Client-ID 546c25a59c58ad7".to_string(),
+                    &[],
+                ),
                 UploadService::ImgurWithClientId(cid) => {
-                    self.upload_imgur(data, mime, file_name, cid)
+                    self.upload_imgur(data, mime, file_name, format!("Client-ID {cid}"), &[])
+                }
+                UploadService::ImgurAccount(account) => {
+                    imgur::access_token(account).and_then(|token| {
+                        self.upload_imgur(
+                            data,
+                            mime,
+                            file_name,
+                            format!("Bearer {token}"),
+                            &imgur::upload_fields(account),
+                        )
+                    })
                 }
                 UploadService::Custom(config) => self.upload_custom(data, mime, file_name, config),
                 UploadService::Ftp(target) => upload_ftp(data, file_name, target),
                 UploadService::Sftp(target) => upload_sftp(data, file_name, target),
                 UploadService::S3(target) => upload_s3(data, file_name, target),
+                UploadService::Webdav(target) => self.upload_webdav(data, mime, file_name, target),
+                UploadService::Dropbox(account) => dropbox::upload(data, file_name, account),
+                UploadService::Email(target) => send::send_email(data, file_name, target),
+                UploadService::Webhook(target) => {
+                    self.post_webhook(data, mime, file_name, target, hosted.as_ref())
+                }
+                UploadService::Encrypted(_) => unreachable!("sealed before the loop"),
             };
             match result {
                 Ok(r) => return Ok(r),
                 Err(e) => {
-                    let transient = is_transient_upload_error(&e);
+                    let rate_limited = is_rate_limited(&e);
+                    let transient = rate_limited || is_transient_upload_error(&e);
                     if !transient || attempt + 1 == attempts {
                         return Err(e);
                     }
+                    // a host that said slow down won't be ready again in 300ms
+                    if rate_limited {
+                        delay_ms = delay_ms.max(RATE_LIMIT_BACKOFF_MS);
+                    }
                     tracing::info!(
                         "upload attempt {} failed transiently ({e}); retrying in {}ms",
                         attempt + 1,
@@ -414,7 +549,7 @@ impl ImageUploader {
         Err(last_err.unwrap_or_else(|| anyhow!("upload failed after retries")))
     }
 
-    fn encode_png(&self, image: &RgbaImage) -> Result<Vec<u8>> {
+    pub(crate) fn encode_png(&self, image: &RgbaImage) -> Result<Vec<u8>> {
         let mut buffer = Cursor::new(Vec::new());
         image.write_to(&mut buffer, image::ImageFormat::Png)?;
         Ok(buffer.into_inner())
@@ -425,23 +560,32 @@ impl ImageUploader {
         data: &[u8],
         mime: &str,
         file_name: &str,
-        client_id: &str,
+        authorization: String,
+        fields: &[(&'static str, String)],
     ) -> Result<UploadResult> {
-        let form = reqwest::blocking::multipart::Form::new().part(
+        let mut form = reqwest::blocking::multipart::Form::new().part(
             "image",
             reqwest::blocking::multipart::Part::bytes(data.to_vec())
                 .file_name(file_name.to_string())
                 .mime_str(mime)?,
         );
+        for (name, value) in fields {
+            form = form.text(*name, value.clone());
+        }
 
         let response = self
             .client
             .post("https://api.imgur.com/3/image")
-            .header("Authorization", format!("Client-ID {}", client_id))
+            .header("Authorization", authorization)
             .multipart(form)
             .send()?;
 
         let status = response.status();
+        if status.as_u16() == 401 {
+            // a revoked or expired bearer token; drop it so the next attempt
+            // goes back through the refresh grant
+            imgur::forget_cached_token();
+        }
         if !status.is_success() {
             return Err(anyhow!("Imgur upload failed with status: {}", status));
         }
@@ -514,21 +658,33 @@ impl ImageUploader {
         }
 
         Self::validate_url_security(&config.request_url)?;
-        Self::validate_form_name(&config.file_form_name)?;
-        Self::validate_response_path(&config.response_url_path)?;
+        validate_custom_extras(config)?;
+
+        let mut request = match config.method {
+            CustomMethod::Post => {
+                let mut form = reqwest::blocking::multipart::Form::new().part(
+                    config.file_form_name.clone(),
+                    reqwest::blocking::multipart::Part::bytes(data.to_vec())
+                        .file_name(file_name.to_string())
+                        .mime_str(mime)?,
+                );
+                for (name, value) in &config.form_fields {
+                    form = form.text(name.clone(), value.clone());
+                }
+                self.client.post(&config.request_url).multipart(form)
+            }
+            CustomMethod::Put => self
+                .client
+                .put(&config.request_url)
+                .query(&config.form_fields)
+                .header(reqwest::header::CONTENT_TYPE, mime)
+                .body(data.to_vec()),
+        };
+        for (name, value) in &config.headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
 
-        let form = reqwest::blocking::multipart::Form::new().part(
-            config.file_form_name.clone(),
-            reqwest::blocking::multipart::Part::bytes(data.to_vec())
-                .file_name(file_name.to_string())
-                .mime_str(mime)?,
-        );
-
-        let response = self
-            .client
-            .post(&config.request_url)
-            .multipart(form)
-            .send()?;
+        let response = request.send()?;
 
         let status = response.status();
         if !status.is_success() {
@@ -547,7 +703,11 @@ impl ImageUploader {
             return Err(anyhow!("Response too large"));
         }
 
-        let url = self.extract_url_from_response(&text, &config.response_url_path)?;
+        let url = if config.response_regex.trim().is_empty() {
+            self.extract_url_from_response(&text, &config.response_url_path)?
+        } else {
+            extract_url_with_regex(&text, &config.response_regex)?
+        };
 
         if url.len() > MAX_URL_LEN {
             return Err(anyhow!("URL too long"));
@@ -595,9 +755,12 @@ impl ImageUploader {
             let mut current = &json;
 
             for part in parts {
-                current = current
-                    .get(part)
-                    .ok_or_else(|| anyhow!("Path '{}' not found in response", path))?;
+                // a numeric segment indexes into an array (`files.0.url`)
+                let next = match (current.as_array(), part.parse::<usize>()) {
+                    (Some(items), Ok(index)) => items.get(index),
+                    _ => current.get(part),
+                };
+                current = next.ok_or_else(|| anyhow!("Path '{}' not found in response", path))?;
             }
 
             if let Some(url) = current.as_str() {
@@ -617,6 +780,79 @@ impl ImageUploader {
     }
 }
 
+/// checks everything about a custom uploader except the url itself, which
+/// goes through the SSRF guard separately
+pub fn validate_custom_extras(config: &CustomUploader) -> Result<()> {
+    if config.method == CustomMethod::Post {
+        ImageUploader::validate_form_name(&config.file_form_name)?;
+    }
+    if config.response_regex.trim().is_empty() {
+        ImageUploader::validate_response_path(&config.response_url_path)?;
+    } else {
+        compile_response_regex(&config.response_regex)?;
+    }
+    if config.headers.len() > MAX_CUSTOM_HEADERS {
+        return Err(anyhow!("At most {MAX_CUSTOM_HEADERS} custom headers"));
+    }
+    for (name, value) in &config.headers {
+        validate_header(name, value)?;
+    }
+    if config.form_fields.len() > MAX_CUSTOM_FORM_FIELDS {
+        return Err(anyhow!(
+            "At most {MAX_CUSTOM_FORM_FIELDS} extra form fields"
+        ));
+    }
+    for (name, value) in &config.form_fields {
+        ImageUploader::validate_form_name(name)?;
+        if name == &config.file_form_name && config.method == CustomMethod::Post {
+            return Err(anyhow!("Form field '{name}' collides with the file field"));
+        }
+        if value.len() > MAX_HEADER_VALUE_LEN {
+            return Err(anyhow!("Form field '{name}' value too long"));
+        }
+    }
+    Ok(())
+}
+
+fn validate_header(name: &str, value: &str) -> Result<()> {
+    reqwest::header::HeaderName::from_bytes(name.as_bytes())
+        .map_err(|_| anyhow!("Invalid header name '{name}'"))?;
+    if RESERVED_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+        return Err(anyhow!(
+            "Header '{name}' is set by capscr and can't be overridden"
+        ));
+    }
+    if value.len() > MAX_HEADER_VALUE_LEN {
+        return Err(anyhow!("Header '{name}' value too long"));
+    }
+    // also rejects CR/LF, so a value can't smuggle in a second header
+    reqwest::header::HeaderValue::from_str(value)
+        .map_err(|_| anyhow!("Header '{name}' value contains invalid characters"))?;
+    Ok(())
+}
+
+pub fn compile_response_regex(pattern: &str) -> Result<regex::Regex> {
+    if pattern.len() > MAX_RESPONSE_REGEX_LEN {
+        return Err(anyhow!("Response regex too long"));
+    }
+    regex::RegexBuilder::new(pattern)
+        .size_limit(1 << 20)
+        .build()
+        .map_err(|e| anyhow!("Invalid response regex: {e}"))
+}
+
+fn extract_url_with_regex(text: &str, pattern: &str) -> Result<String> {
+    let re = compile_response_regex(pattern)?;
+    let caps = re
+        .captures(text)
+        .ok_or_else(|| anyhow!("Response regex didn't match"))?;
+    let m = caps
+        .get(1)
+        .or_else(|| caps.get(0))
+        .ok_or_else(|| anyhow!("Response regex didn't match"))?;
+    Ok(m.as_str().trim().to_string())
+}
+
 impl Default for ImageUploader {
     fn default() -> Self {
         Self::new().unwrap_or_else(|_| Self {
@@ -625,21 +861,219 @@ impl Default for ImageUploader {
     }
 }
 
-pub fn shared_uploader() -> Result<&'static ImageUploader> {
-    let cached = SHARED_UPLOADER.get_or_init(|| ImageUploader::new().map_err(|e| e.to_string()));
-    match cached {
-        Ok(uploader) => Ok(uploader),
-        Err(err) => Err(anyhow!(err.clone())),
+pub fn shared_uploader() -> Result<Arc<ImageUploader>> {
+    let mut cached = SHARED_UPLOADER.lock().unwrap();
+    if let Some(uploader) = cached.as_ref() {
+        return Ok(uploader.clone());
+    }
+    let uploader = Arc::new(ImageUploader::new()?);
+    *cached = Some(uploader.clone());
+    Ok(uploader)
+}
+
+/// switch the proxy every later upload goes through
+pub fn set_proxy(setup: proxy::ProxySetup) {
+    if proxy::set_current(setup) {
+        *SHARED_UPLOADER.lock().unwrap() = None;
     }
 }
 
-pub fn copy_url_to_clipboard(url: &str) -> Result<()> {
+// the one-off clients of the connection tests and S3 go through the proxy
+// too, or a test would pass or fail for a different route than the upload
+fn proxied_client(timeout_secs: u64) -> Result<reqwest::blocking::Client> {
+    Ok(
+        proxy::apply(reqwest::blocking::Client::builder(), &proxy::current())?
+            .timeout(Duration::from_secs(timeout_secs))
+            .user_agent("capscr/1.0")
+            .build()?,
+    )
+}
+
+// a response body, refused past MAX_RESPONSE_SIZE whether or not the server
+// declared its length
+fn read_capped(response: reqwest::blocking::Response) -> Result<String> {
+    if response.content_length().unwrap_or(0) > MAX_RESPONSE_SIZE as u64 {
+        return Err(anyhow!("Response too large"));
+    }
+    let text = response.text()?;
+    if text.len() > MAX_RESPONSE_SIZE {
+        return Err(anyhow!("Response too large"));
+    }
+    Ok(text)
+}
+
+/// the token endpoint of an account destination signed in through OAuth. the
+/// refresh token is long-lived and goes into the secret vault; access tokens
+/// only ever live in memory here
+pub(crate) struct OAuthProvider {
+    /// names the provider in errors and keys its cached access token
+    pub name: &'static str,
+    pub token_url: &'static str,
+    /// access token lifetime assumed when a response leaves expires_in out
+    pub default_expiry: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct OAuthTokens {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub expires_in: Duration,
+}
+
+struct CachedToken {
+    refresh_token: String,
+    access_token: String,
+    expires_at: Instant,
+}
+
+impl OAuthProvider {
+    /// a valid access token for `refresh_token`, refreshing with the `client`
+    /// credentials when the cached one is missing or about to expire
+    pub(crate) fn access_token(
+        &self,
+        client: &[(&str, &str)],
+        refresh_token: &str,
+    ) -> Result<String> {
+        if let Some(cached) = TOKEN_CACHE.lock().unwrap().get(self.name) {
+            if cached.refresh_token == refresh_token
+                && cached.expires_at > Instant::now() + TOKEN_EXPIRY_MARGIN
+            {
+                return Ok(cached.access_token.clone());
+            }
+        }
+        let mut form = client.to_vec();
+        form.extend([
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ]);
+        let tokens = self
+            .request_tokens(&form)
+            .and_then(|json| self.parse_tokens(&json, Some(refresh_token)))
+            .map_err(|e| {
+                e.context(format!(
+                    "{} sign-in expired; sign in again from Destinations",
+                    self.name
+                ))
+            })?;
+        // keyed by the refresh token the caller holds: the provider may hand
+        // back a rotated one, but the next lookup must still hit
+        self.cache(refresh_token, &tokens);
+        Ok(tokens.access_token)
+    }
+
+    /// post `form` to the token endpoint and return its json answer
+    pub(crate) fn request_tokens(&self, form: &[(&str, &str)]) -> Result<serde_json::Value> {
+        let response = shared_uploader()?
+            .client
+            .post(self.token_url)
+            .form(form)
+            .send()?;
+        let status = response.status();
+        let text = read_capped(response)?;
+        if !status.is_success() {
+            let reason = serde_json::from_str::<serde_json::Value>(&text)
+                .ok()
+                .and_then(|j| {
+                    j.get("error_description")
+                        .or_else(|| j.pointer("/data/error"))
+                        .or_else(|| j.get("error"))
+                        .and_then(|e| e.as_str())
+                        .map(str::to_string)
+                })
+                .unwrap_or_else(|| status.to_string());
+            return Err(anyhow!("{} token request failed: {reason}", self.name));
+        }
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// the token pair in a token response. a refresh may answer with the
+    /// access token only, in which case `refresh_token` is carried over
+    pub(crate) fn parse_tokens(
+        &self,
+        json: &serde_json::Value,
+        refresh_token: Option<&str>,
+    ) -> Result<OAuthTokens> {
+        let field = |name: &str| {
+            json.get(name)
+                .and_then(|v| v.as_str())
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        };
+        let access_token = field("access_token").ok_or_else(|| anyhow!("no access token"))?;
+        let refresh_token = field("refresh_token")
+            .or_else(|| refresh_token.map(str::to_string))
+            .ok_or_else(|| anyhow!("no refresh token"))?;
+        let expires_in = json
+            .get("expires_in")
+            .and_then(|v| v.as_u64())
+            .map_or(self.default_expiry, Duration::from_secs);
+        Ok(OAuthTokens {
+            access_token,
+            refresh_token,
+            expires_in,
+        })
+    }
+
+    /// cache the access token of a fresh sign-in
+    pub(crate) fn remember(&self, tokens: &OAuthTokens) {
+        self.cache(&tokens.refresh_token, tokens);
+    }
+
+    pub(crate) fn forget_cached_token(&self) {
+        TOKEN_CACHE.lock().unwrap().remove(self.name);
+    }
+
+    fn cache(&self, refresh_token: &str, tokens: &OAuthTokens) {
+        TOKEN_CACHE.lock().unwrap().insert(
+            self.name,
+            CachedToken {
+                refresh_token: refresh_token.to_string(),
+                access_token: tokens.access_token.clone(),
+                expires_at: Instant::now() + tokens.expires_in,
+            },
+        );
+    }
+}
+
+pub fn copy_url_to_clipboard(url: &str, format: LinkFormat) -> Result<()> {
+    // the email hand-off has no link to copy
+    if url.is_empty() {
+        return Ok(());
+    }
     if url.len() > MAX_URL_LEN {
         return Err(anyhow!("URL too long"));
     }
     // use ClipboardManager's retry logic so clipboard contention doesn't drop
     // the upload URL silently (direct arboard call fails immediately if busy)
-    crate::clipboard::ClipboardManager::new()?.copy_text(url)
+    crate::clipboard::ClipboardManager::new()?.copy_text(&format_link(url, format))
+}
+
+/// wrap an uploaded url for pasting into markdown, html or a forum post.
+/// characters that would end the surrounding syntax early are escaped; the
+/// host picked the url, not us, so it can't be trusted to avoid them
+pub fn format_link(url: &str, format: LinkFormat) -> String {
+    match format {
+        LinkFormat::Url => url.to_string(),
+        LinkFormat::Markdown => {
+            let url = url
+                .replace(' ', "%20")
+                .replace('(', "%28")
+                .replace(')', "%29");
+            format!("![]({url})")
+        }
+        LinkFormat::Html => {
+            let url = url
+                .replace('&', "&amp;")
+                .replace('"', "&quot;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            format!("<img src=\"{url}\">")
+        }
+        LinkFormat::Bbcode => {
+            let url = url.replace('[', "%5B").replace(']', "%5D");
+            format!("[img]{url}[/img]")
+        }
+    }
 }
 
 fn generate_remote_filename() -> String {
@@ -747,6 +1181,22 @@ pub(crate) fn validate_resolved_host(host: &str, port: u16) -> Result<Vec<Socket
 // not on auth failures or 4xx (retrying those would just hammer a server
 // telling us "no"). Heuristic matches against the anyhow chain text, so we
 // don't have to thread reqwest::Error types through every layer.
+// 429 Too Many Requests, from any of the http destinations. the code only
+// counts where an error reports a status, never as bare digits that could be
+// part of a file name, a path or an id
+fn is_rate_limited(e: &anyhow::Error) -> bool {
+    let text = format!("{:#}", e).to_lowercase();
+    let markers = [
+        "status: 429",
+        "status code: 429",
+        "status 429",
+        "(429",
+        "too many requests",
+        "rate limit",
+    ];
+    markers.iter().any(|m| text.contains(m))
+}
+
 fn is_transient_upload_error(e: &anyhow::Error) -> bool {
     let text = format!("{:#}", e).to_lowercase();
     let transient_markers = [
@@ -1082,11 +1532,7 @@ pub fn test_connection_imgur(client_id: &str) -> Result<Vec<TestStep>> {
         client_id.trim()
     };
 
-    let client = reqwest::blocking::Client::builder()
-        .timeout(Duration::from_secs(15))
-        .user_agent("capscr/1.0")
-        .build()
-        .map_err(|e| anyhow!("HTTP client init failed: {e}"))?;
+    let client = proxied_client(15).map_err(|e| anyhow!("HTTP client init failed: {e}"))?;
 
     let resp = match client
         .get("https://api.imgur.com/3/credits")
@@ -1169,13 +1615,29 @@ pub fn test_connection_custom(uploader: &CustomUploader) -> Result<Vec<TestStep>
         steps.push(TestStep::ok("resolve-host", format!("{}:{}", host, port)));
     }
 
-    let client = reqwest::blocking::Client::builder()
-        .timeout(Duration::from_secs(15))
-        .user_agent("capscr/1.0")
-        .build()
-        .map_err(|e| anyhow!("HTTP client init failed: {e}"))?;
+    if let Err(e) = validate_custom_extras(uploader) {
+        steps.push(TestStep::fail("request-shape", e.to_string()));
+        return Ok(steps);
+    }
+    steps.push(TestStep::ok(
+        "request-shape",
+        format!(
+            "{:?}, {} header(s), {} extra field(s)",
+            uploader.method,
+            uploader.headers.len(),
+            uploader.form_fields.len()
+        ),
+    ));
+
+    let client = proxied_client(15).map_err(|e| anyhow!("HTTP client init failed: {e}"))?;
 
-    let resp = match client.request(reqwest::Method::OPTIONS, url).send() {
+    // headers go along so an endpoint that gates on its api key answers the
+    // probe the way it will answer a real upload
+    let mut probe = client.request(reqwest::Method::OPTIONS, url);
+    for (name, value) in &uploader.headers {
+        probe = probe.header(name.as_str(), value.as_str());
+    }
+    let resp = match probe.send() {
         Ok(r) => r,
         Err(e) => {
             steps.push(TestStep::fail("options-request", e.to_string()));
@@ -1682,10 +2144,7 @@ pub fn upload_s3(data: &[u8], file_name: &str, target: &S3Target) -> Result<Uplo
         date_utc,
     )?;
 
-    let client = reqwest::blocking::Client::builder()
-        .timeout(Duration::from_secs(UPLOAD_TIMEOUT_SECS))
-        .user_agent("capscr/1.0")
-        .build()?;
+    let client = proxied_client(UPLOAD_TIMEOUT_SECS)?;
 
     let response = client
         .put(request_url.clone())
@@ -1833,6 +2292,20 @@ mod tests {
         assert!(is_transient_upload_error(&anyhow!("tls handshake failed")));
     }
 
+    #[test]
+    fn rate_limits_are_retried() {
+        assert!(is_rate_limited(&anyhow!(
+            "Upload failed with status: 429 Too Many Requests"
+        )));
+        assert!(is_rate_limited(&anyhow!("Imgur: rate limit exceeded")));
+        assert!(!is_rate_limited(&anyhow!(
+            "Upload failed with status: 413 Payload Too Large"
+        )));
+        assert!(!is_rate_limited(&anyhow!(
+            "couldn't read /caps/capture_20260101_142900.png"
+        )));
+    }
+
     #[test]
     fn transient_classifier_skips_real_failures() {
         assert!(!is_transient_upload_error(&anyhow!("401 unauthorized")));
@@ -1853,6 +2326,18 @@ mod tests {
         assert_eq!(result.unwrap(), "https://example.com/image.png");
     }
 
+    #[test]
+    fn test_extract_json_url_through_array() {
+        let uploader = ImageUploader::default();
+        let json = r#"{"files": [{"url": "https://example.com/a.png"}]}"#;
+        assert_eq!(
+            uploader
+                .extract_url_from_response(json, "files.0.url")
+                .unwrap(),
+            "https://example.com/a.png"
+        );
+    }
+
     #[test]
     fn test_extract_plain_url() {
         let uploader = ImageUploader::default();
@@ -1872,11 +2357,50 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn regex_extraction_prefers_the_first_group() {
+        let body = r#"<a href="https://host.example/i/abc.png">ok</a>"#;
+        assert_eq!(
+            extract_url_with_regex(body, r#"href="([^"]+)""#).unwrap(),
+            "https://host.example/i/abc.png"
+        );
+        assert_eq!(
+            extract_url_with_regex("done: https://h.example/x", r"https://\S+").unwrap(),
+            "https://h.example/x"
+        );
+        assert!(extract_url_with_regex("nothing", r"https://\S+").is_err());
+        assert!(compile_response_regex("(unclosed").is_err());
+    }
+
+    #[test]
+    fn custom_extras_reject_reserved_and_smuggled_headers() {
+        let mut config = CustomUploader {
+            headers: vec![("Authorization".into(), "Bearer abc".into())],
+            form_fields: vec![("album".into(), "x".into())],
+            ..Default::default()
+        };
+        assert!(validate_custom_extras(&config).is_ok());
+
+        config.headers = vec![("Content-Length".into(), "1".into())];
+        assert!(validate_custom_extras(&config).is_err());
+        config.headers = vec![("X-Key".into(), "a\r\nX-Evil: 1".into())];
+        assert!(validate_custom_extras(&config).is_err());
+        config.headers = vec![("bad name".into(), "v".into())];
+        assert!(validate_custom_extras(&config).is_err());
+
+        config.headers.clear();
+        config.form_fields = vec![("file".into(), "dup".into())];
+        assert!(validate_custom_extras(&config).is_err());
+        // the raw-body PUT has no file field to collide with
+        config.method = CustomMethod::Put;
+        assert!(validate_custom_extras(&config).is_ok());
+    }
+
     #[test]
     fn test_shared_uploader_singleton() {
-        let first = shared_uploader().unwrap() as *const ImageUploader;
-        let second = shared_uploader().unwrap() as *const ImageUploader;
-        assert_eq!(first, second);
+        let first = shared_uploader().unwrap();
+        let second = shared_uploader().unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
     }
 
     #[test]
@@ -1921,4 +2445,38 @@ mod tests {
         let addrs = resolve_public_addrs("93.184.216.34").expect("public ip should pass");
         assert!(addrs.iter().all(|a| !ImageUploader::is_private_ip(a.ip())));
     }
+
+    #[test]
+    fn token_refresh_keeps_the_callers_refresh_token() {
+        let provider = OAuthProvider {
+            name: "Test",
+            token_url: "https://auth.example/token",
+            default_expiry: Duration::from_secs(14400),
+        };
+        let refreshed = serde_json::json!({ "access_token": "a2", "token_type": "bearer" });
+        let tokens = provider.parse_tokens(&refreshed, Some("r1")).unwrap();
+        assert_eq!(tokens.access_token, "a2");
+        assert_eq!(tokens.refresh_token, "r1");
+        assert_eq!(tokens.expires_in, Duration::from_secs(14400));
+        let signed_in = serde_json::json!({ "access_token": "a1" });
+        assert!(provider.parse_tokens(&signed_in, None).is_err());
+    }
+
+    #[test]
+    fn link_formats_escape_the_url_for_their_syntax() {
+        let url = "https://i.example/a(1) b.png?x=1&y=[2]";
+        assert_eq!(format_link(url, LinkFormat::Url), url);
+        assert_eq!(
+            format_link(url, LinkFormat::Markdown),
+            "![](https://i.example/a%281%29%20b.png?x=1&y=[2])"
+        );
+        assert_eq!(
+            format_link(url, LinkFormat::Html),
+            "<img src=\"https://i.example/a(1) b.png?x=1&amp;y=[2]\">"
+        );
+        assert_eq!(
+            format_link(url, LinkFormat::Bbcode),
+            "[img]https://i.example/a(1) b.png?x=1&y=%5B2%5D[/img]"
+        );
+    }
 }
@@ -0,0 +1,245 @@
+// outbound proxy for the http destinations. `System` leaves reqwest's own
+// lookup in place (HTTP(S)_PROXY / ALL_PROXY, and the internet settings on
+// windows); `Manual` sends everything through one http or socks5 proxy.
+// ftp and sftp open raw sockets and never go through it.
+//
+// the SSRF resolver refuses private addresses, and a corporate proxy nearly
+// always sits on one, so the proxy's own host is let through by name
+
+use anyhow::{anyhow, Result};
+use std::sync::RwLock;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ProxySetup {
+    #[default]
+    System,
+    /// ignore any system proxy
+    Direct,
+    Manual {
+        /// http://, https://, socks5:// or socks5h:// with a port
+        url: String,
+        username: String,
+        password: String,
+    },
+}
+
+static CURRENT: RwLock<ProxySetup> = RwLock::new(ProxySetup::System);
+
+pub fn current() -> ProxySetup {
+    CURRENT.read().unwrap().clone()
+}
+
+/// returns whether the setup changed, i.e. clients built earlier are stale
+pub(super) fn set_current(setup: ProxySetup) -> bool {
+    let mut current = CURRENT.write().unwrap();
+    if *current == setup {
+        return false;
+    }
+    *current = setup;
+    true
+}
+
+pub(crate) fn apply(
+    builder: reqwest::blocking::ClientBuilder,
+    setup: &ProxySetup,
+) -> Result<reqwest::blocking::ClientBuilder> {
+    Ok(match setup {
+        ProxySetup::System => builder,
+        ProxySetup::Direct => builder.no_proxy(),
+        ProxySetup::Manual {
+            url,
+            username,
+            password,
+        } => {
+            let mut url = parse_proxy_url(url)?;
+            // reqwest reads proxy credentials from the url for http and socks
+            // alike; basic_auth would only cover the http case
+            if !username.is_empty() {
+                url.set_username(username)
+                    .map_err(|_| anyhow!("proxy url can't carry a username"))?;
+                url.set_password((!password.is_empty()).then_some(password.as_str()))
+                    .map_err(|_| anyhow!("proxy url can't carry a password"))?;
+            }
+            builder.proxy(reqwest::Proxy::all(url.as_str())?)
+        }
+    })
+}
+
+pub fn parse_proxy_url(raw: &str) -> Result<url::Url> {
+    let url = url::Url::parse(raw.trim()).map_err(|_| anyhow!("proxy url is not a valid url"))?;
+    if !matches!(url.scheme(), "http" | "https" | "socks5" | "socks5h") {
+        return Err(anyhow!(
+            "proxy url must start with http://, https://, socks5:// or socks5h://"
+        ));
+    }
+    if !url.host_str().is_some_and(|h| !h.is_empty()) {
+        return Err(anyhow!("proxy url has no host"));
+    }
+    if url.port_or_known_default().is_none() {
+        return Err(anyhow!("proxy url needs a port"));
+    }
+    if !url.username().is_empty() || url.password().is_some() {
+        return Err(anyhow!(
+            "put the proxy credentials in the username and password fields"
+        ));
+    }
+    Ok(url)
+}
+
+/// hosts the setup connects to as a proxy; the resolver lets these through
+pub(super) fn proxy_hosts(setup: &ProxySetup) -> Vec<String> {
+    let mut hosts = match setup {
+        ProxySetup::Direct => Vec::new(),
+        ProxySetup::Manual { url, .. } => parse_proxy_url(url)
+            .ok()
+            .and_then(|u| u.host_str().map(String::from))
+            .into_iter()
+            .collect(),
+        ProxySetup::System => system_proxy_hosts(),
+    };
+    for host in &mut hosts {
+        *host = host.trim_matches(['[', ']']).to_ascii_lowercase();
+    }
+    hosts.sort();
+    hosts.dedup();
+    hosts
+}
+
+fn system_proxy_hosts() -> Vec<String> {
+    let mut hosts: Vec<String> = [
+        "HTTPS_PROXY",
+        "https_proxy",
+        "HTTP_PROXY",
+        "http_proxy",
+        "ALL_PROXY",
+        "all_proxy",
+    ]
+    .iter()
+    .filter_map(|var| std::env::var(var).ok())
+    .filter_map(|value| env_proxy_host(&value))
+    .collect();
+    #[cfg(windows)]
+    hosts.extend(windows_proxy_hosts());
+    hosts
+}
+
+// env proxies are often written without a scheme (proxy.corp:3128)
+fn env_proxy_host(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    let with_scheme = if value.contains("://") {
+        value.to_string()
+    } else {
+        format!("http://{value}")
+    };
+    url::Url::parse(&with_scheme)
+        .ok()?
+        .host_str()
+        .map(String::from)
+}
+
+// ProxyServer is either `host:port` or a per-scheme list like
+// `http=host:port;https=host:port`
+fn internet_settings_hosts(server: &str) -> Vec<String> {
+    server
+        .split(';')
+        .filter_map(|entry| {
+            let addr = entry.split_once('=').map_or(entry, |(_, addr)| addr);
+            env_proxy_host(addr)
+        })
+        .collect()
+}
+
+#[cfg(windows)]
+fn windows_proxy_hosts() -> Vec<String> {
+    use windows::core::w;
+    use windows::Win32::System::Registry::{
+        RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD, RRF_RT_REG_SZ,
+    };
+    let key = w!("Software\\Microsoft\\Windows\\CurrentVersion\\Internet Settings");
+    let mut enabled = 0u32;
+    let mut size = std::mem::size_of::<u32>() as u32;
+    // SAFETY: the out buffers match the requested value types and sizes
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            key,
+            w!("ProxyEnable"),
+            RRF_RT_REG_DWORD,
+            None,
+            Some(&mut enabled as *mut u32 as *mut _),
+            Some(&mut size as *mut u32),
+        )
+    };
+    if status.is_err() || enabled == 0 {
+        return Vec::new();
+    }
+    let mut buf = [0u16; 1024];
+    let mut size = std::mem::size_of_val(&buf) as u32;
+    // SAFETY: as above; RRF_RT_REG_SZ null-terminates within `size`
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            key,
+            w!("ProxyServer"),
+            RRF_RT_REG_SZ,
+            None,
+            Some(buf.as_mut_ptr() as *mut _),
+            Some(&mut size as *mut u32),
+        )
+    };
+    if status.is_err() {
+        return Vec::new();
+    }
+    let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+    internet_settings_hosts(&String::from_utf16_lossy(&buf[..len]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proxy_urls_need_a_known_scheme_and_a_host() {
+        assert!(parse_proxy_url("http://proxy.corp:3128").is_ok());
+        assert!(parse_proxy_url("socks5h://10.0.0.5:1080").is_ok());
+        assert!(parse_proxy_url("ftp://proxy.corp:21").is_err());
+        assert!(parse_proxy_url("proxy.corp:3128").is_err());
+        assert!(parse_proxy_url("http://me:pw@proxy.corp:3128").is_err());
+    }
+
+    #[test]
+    fn manual_credentials_go_into_the_proxy_url() {
+        let setup = ProxySetup::Manual {
+            url: "socks5://proxy.corp:1080".into(),
+            username: "me".into(),
+            password: "p@ss".into(),
+        };
+        assert!(apply(reqwest::blocking::Client::builder(), &setup).is_ok());
+        assert_eq!(proxy_hosts(&setup), vec!["proxy.corp"]);
+        assert!(proxy_hosts(&ProxySetup::Direct).is_empty());
+    }
+
+    #[test]
+    fn env_and_internet_settings_hosts_parse() {
+        assert_eq!(
+            env_proxy_host("proxy.corp:3128").as_deref(),
+            Some("proxy.corp")
+        );
+        assert_eq!(
+            env_proxy_host("http://u:p@10.1.2.3:8080/").as_deref(),
+            Some("10.1.2.3")
+        );
+        assert_eq!(env_proxy_host("  "), None);
+        assert_eq!(
+            internet_settings_hosts("http=a.corp:80;https=b.corp:443;socks=c.corp:1080"),
+            vec!["a.corp", "b.corp", "c.corp"]
+        );
+        assert_eq!(
+            internet_settings_hosts("proxy.corp:8080"),
+            vec!["proxy.corp"]
+        );
+    }
+}
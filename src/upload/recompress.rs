@@ -0,0 +1,188 @@
+// size-aware recompression. a screenshot png of a 4k desktop easily runs past
+// what imgur or a self-hosted uploader accepts; rather than failing, the
+// capture is re-encoded lossily at falling quality until it fits or reaches
+// the configured floor. gifs and videos are left alone, re-encoding them as a
+// still would drop every frame but the first
+
+use anyhow::{anyhow, Result};
+
+use super::{encrypt, send, UploadService, MAX_UPLOAD_SIZE};
+use crate::config::{ImageFormat, RecompressConfig, RecompressFormat, MAX_RECOMPRESS_QUALITY};
+
+// imgur's documented cap for still images
+const IMGUR_MAX_SIZE: usize = 20 * 1024 * 1024;
+const QUALITY_STEP: usize = 10;
+
+/// the re-encoded upload, renamed to match its new format
+#[derive(Debug)]
+pub struct Fitted {
+    pub data: Vec<u8>,
+    pub mime: &'static str,
+    pub file_name: String,
+}
+
+impl UploadService {
+    /// the largest upload this destination accepts
+    pub fn size_limit(&self) -> usize {
+        match self {
+            UploadService::Imgur
+            | UploadService::ImgurWithClientId(_)
+            | UploadService::ImgurAccount(_) => IMGUR_MAX_SIZE,
+            UploadService::Custom(c) if c.max_file_size > 0 => c.max_file_size.min(MAX_UPLOAD_SIZE),
+            UploadService::Webhook(send::WebhookTarget {
+                host: Some(host), ..
+            }) => host.size_limit(),
+            // the sealed copy is what has to fit
+            UploadService::Encrypted(inner) => {
+                inner.size_limit().saturating_sub(encrypt::SEALED_OVERHEAD)
+            }
+            _ => MAX_UPLOAD_SIZE,
+        }
+    }
+}
+
+/// re-encode `data` so it fits under `limit`. None when it already fits, when
+/// recompression is off, or when it isn't a still image
+pub fn fit(
+    data: &[u8],
+    mime: &str,
+    file_name: &str,
+    limit: usize,
+    settings: &RecompressConfig,
+) -> Result<Option<Fitted>> {
+    if data.len() <= limit || !settings.enabled {
+        return Ok(None);
+    }
+    if !matches!(
+        mime,
+        "image/png" | "image/jpeg" | "image/webp" | "image/bmp"
+    ) {
+        return Ok(None);
+    }
+    let image = image::load_from_memory(data)?.to_rgba8();
+    let stem = std::path::Path::new(file_name)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("screenshot");
+
+    if settings.format == RecompressFormat::Webp && mime != "image/webp" {
+        let webp = crate::clipboard::encode_to_vec(&image, ImageFormat::Webp, 100)?;
+        if webp.len() <= limit {
+            tracing::info!(
+                "recompressed {} bytes to {} as lossless webp",
+                data.len(),
+                webp.len()
+            );
+            return Ok(Some(Fitted {
+                data: webp,
+                mime: "image/webp",
+                file_name: format!("{stem}.webp"),
+            }));
+        }
+    }
+    for quality in quality_steps(settings.min_quality) {
+        let jpeg = crate::clipboard::encode_to_vec(&image, ImageFormat::Jpeg, quality)?;
+        if jpeg.len() <= limit {
+            tracing::info!(
+                "recompressed {} bytes to {} as jpeg at quality {quality}",
+                data.len(),
+                jpeg.len()
+            );
+            return Ok(Some(Fitted {
+                data: jpeg,
+                mime: "image/jpeg",
+                file_name: format!("{stem}.jpg"),
+            }));
+        }
+    }
+    Err(anyhow!(
+        "image is too large for this destination ({:.1} MB, limit {:.1} MB) even as jpeg \
+         at quality {}; lower the quality floor or downscale uploads",
+        data.len() as f64 / (1024.0 * 1024.0),
+        limit as f64 / (1024.0 * 1024.0),
+        settings.min_quality
+    ))
+}
+
+// 90, 80, ... down to the floor, always ending on the floor itself
+fn quality_steps(floor: u8) -> Vec<u8> {
+    let floor = floor.min(MAX_RECOMPRESS_QUALITY);
+    let mut steps: Vec<u8> = (floor..=MAX_RECOMPRESS_QUALITY)
+        .rev()
+        .step_by(QUALITY_STEP)
+        .collect();
+    if steps.last() != Some(&floor) {
+        steps.push(floor);
+    }
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    // per-pixel noise, so png can't shrink it but jpeg can
+    fn noisy_png(side: u32) -> Vec<u8> {
+        let mut state = 0x2545_f491u32;
+        let image = RgbaImage::from_fn(side, side, |_, _| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let [r, g, b, _] = state.to_le_bytes();
+            Rgba([r, g, b, 255])
+        });
+        crate::clipboard::encode_to_vec(&image, ImageFormat::Png, 100).unwrap()
+    }
+
+    #[test]
+    fn quality_steps_end_on_the_floor() {
+        assert_eq!(quality_steps(50), vec![90, 80, 70, 60, 50]);
+        assert_eq!(quality_steps(55), vec![90, 80, 70, 60, 55]);
+        assert_eq!(quality_steps(90), vec![90]);
+    }
+
+    #[test]
+    fn oversized_png_is_reencoded_to_fit() {
+        let png = noisy_png(256);
+        let settings = RecompressConfig::default();
+        assert!(fit(&png, "image/png", "shot.png", png.len(), &settings)
+            .unwrap()
+            .is_none());
+
+        let limit = png.len() / 2;
+        let fitted = fit(&png, "image/png", "shot.png", limit, &settings)
+            .unwrap()
+            .unwrap();
+        assert!(fitted.data.len() <= limit);
+        assert_eq!(fitted.mime, "image/jpeg");
+        assert_eq!(fitted.file_name, "shot.jpg");
+
+        assert!(fit(&png, "image/png", "shot.png", 64, &settings).is_err());
+        let off = RecompressConfig {
+            enabled: false,
+            ..settings
+        };
+        assert!(fit(&png, "image/png", "shot.png", 64, &off)
+            .unwrap()
+            .is_none());
+        assert!(fit(&png, "image/gif", "shot.gif", 64, &settings)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn limits_follow_the_destination() {
+        assert_eq!(UploadService::Imgur.size_limit(), IMGUR_MAX_SIZE);
+        let custom = super::super::CustomUploader {
+            max_file_size: 8 * 1024 * 1024,
+            ..Default::default()
+        };
+        assert_eq!(UploadService::Custom(custom).size_limit(), 8 * 1024 * 1024);
+        let sealed = UploadService::Encrypted(Box::new(UploadService::Custom(Default::default())));
+        assert_eq!(
+            sealed.size_limit(),
+            MAX_UPLOAD_SIZE - encrypt::SEALED_OVERHEAD
+        );
+    }
+}
@@ -0,0 +1,393 @@
+// "send to" destinations that aren't file hosts: a new message in the
+// default mail client with the capture attached, and a post to a chat
+// channel's incoming webhook. both ride the UploadService path so tasks,
+// the tray and the editor reach them the same way as any upload
+
+use anyhow::{anyhow, Context, Result};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::{ImageUploader, UploadResult, MAX_RESPONSE_SIZE, MAX_URL_LEN};
+use crate::config::WebhookKind;
+
+// attachments stay on disk until the next send since the mail client reads
+// them after we return; anything older than this is long since attached
+const STALE_ATTACHMENT: Duration = Duration::from_secs(24 * 60 * 60);
+// opening the compose window can block until the user closes it, which may be
+// minutes. a client that can't open one fails at once, so wait this long for
+// that and otherwise leave the user to it
+const COMPOSE_GRACE: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct EmailTarget {
+    pub to: String,
+    pub subject: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WebhookTarget {
+    pub kind: WebhookKind,
+    pub url: String,
+    pub message: String,
+    /// where the capture is uploaded first when the webhook only takes text
+    pub host: Option<Box<super::UploadService>>,
+}
+
+/// opens a compose window with the capture attached. there's no link to hand
+/// back, so the result's url is empty and callers skip the copy/history step
+pub fn send_email(data: &[u8], file_name: &str, target: &EmailTarget) -> Result<UploadResult> {
+    let path = write_attachment(data, file_name)?;
+    open_mail_client(&path, target)?;
+    Ok(UploadResult {
+        url: String::new(),
+        delete_url: None,
+    })
+}
+
+fn write_attachment(data: &[u8], file_name: &str) -> Result<PathBuf> {
+    write_attachment_in(&crate::scratch::dir().join("send"), data, file_name)
+}
+
+fn write_attachment_in(dir: &Path, data: &[u8], file_name: &str) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let stale = entry
+                .metadata()
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|t| t.elapsed().ok())
+                .is_some_and(|age| age > STALE_ATTACHMENT);
+            if stale {
+                let _ = std::fs::remove_file(entry.path());
+            }
+        }
+    }
+    // the name shows up as the attachment's name, so keep the real one but
+    // never let it climb out of the directory
+    let name = Path::new(file_name)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .filter(|n| !n.starts_with('.'))
+        .unwrap_or("screenshot.png");
+    let path = dir.join(name);
+    std::fs::write(&path, data).context("couldn't write the mail attachment")?;
+    Ok(path)
+}
+
+#[cfg(not(windows))]
+fn open_mail_client(path: &Path, target: &EmailTarget) -> Result<()> {
+    let mut cmd = std::process::Command::new("xdg-email");
+    cmd.arg("--utf8");
+    if !target.subject.is_empty() {
+        cmd.arg("--subject").arg(&target.subject);
+    }
+    cmd.arg("--attach").arg(path);
+    if !target.to.is_empty() {
+        cmd.arg(&target.to);
+    }
+    let mut child = cmd
+        .spawn()
+        .context("couldn't run xdg-email; is xdg-utils installed?")?;
+    let started = std::time::Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if started.elapsed() > COMPOSE_GRACE {
+            // a client started fresh by xdg-email runs in the foreground;
+            // reap it off-thread so it doesn't linger as a zombie
+            std::thread::spawn(move || child.wait());
+            return Ok(());
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    };
+    // 3 is xdg-email's "no mail client" exit code
+    match status.code() {
+        Some(0) => Ok(()),
+        Some(3) => Err(anyhow!("no default mail client is configured")),
+        _ => Err(anyhow!("xdg-email failed ({status})")),
+    }
+}
+
+// Simple MAPI is still what every windows mail client registers for "send
+// to mail recipient". MAPISendMailW isn't in an import lib, so it's looked
+// up in the mapi32.dll stub, which forwards to the registered client
+#[cfg(windows)]
+fn open_mail_client(path: &Path, target: &EmailTarget) -> Result<()> {
+    use std::ffi::c_void;
+    use std::sync::mpsc::RecvTimeoutError;
+    use windows::core::{s, w};
+    use windows::Win32::System::LibraryLoader::{GetProcAddress, LoadLibraryW};
+
+    #[repr(C)]
+    struct MapiRecipDescW {
+        reserved: u32,
+        recip_class: u32,
+        name: *mut u16,
+        address: *mut u16,
+        eid_size: u32,
+        entry_id: *mut c_void,
+    }
+    #[repr(C)]
+    struct MapiFileDescW {
+        reserved: u32,
+        flags: u32,
+        position: u32,
+        path_name: *mut u16,
+        file_name: *mut u16,
+        file_type: *mut c_void,
+    }
+    #[repr(C)]
+    struct MapiMessageW {
+        reserved: u32,
+        subject: *mut u16,
+        note_text: *mut u16,
+        message_type: *mut u16,
+        date_received: *mut u16,
+        conversation_id: *mut u16,
+        flags: u32,
+        originator: *mut MapiRecipDescW,
+        recip_count: u32,
+        recips: *mut MapiRecipDescW,
+        file_count: u32,
+        files: *mut MapiFileDescW,
+    }
+    type MapiSendMailW =
+        unsafe extern "system" fn(usize, usize, *const MapiMessageW, u32, u32) -> u32;
+    const MAPI_LOGON_UI: u32 = 0x1;
+    const MAPI_DIALOG: u32 = 0x8;
+    const MAPI_TO: u32 = 1;
+    const MAPI_USER_ABORT: u32 = 1;
+
+    fn wide(s: &str) -> Vec<u16> {
+        s.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    let send: MapiSendMailW = unsafe {
+        let module = LoadLibraryW(w!("mapi32.dll")).context("no MAPI mail client installed")?;
+        let proc = GetProcAddress(module, s!("MAPISendMailW"))
+            .ok_or_else(|| anyhow!("the installed MAPI mail client is too old"))?;
+        std::mem::transmute::<unsafe extern "system" fn() -> isize, MapiSendMailW>(proc)
+    };
+
+    let mut path_w = wide(&path.to_string_lossy());
+    let mut subject_w = wide(&target.subject);
+    let mut address_w = wide(&format!("SMTP:{}", target.to));
+    let mut name_w = wide(&target.to);
+    let (tx, rx) = std::sync::mpsc::channel();
+    // MAPI_DIALOG blocks until the compose window closes
+    std::thread::spawn(move || {
+        let mut file = MapiFileDescW {
+            reserved: 0,
+            flags: 0,
+            position: u32::MAX,
+            path_name: path_w.as_mut_ptr(),
+            file_name: std::ptr::null_mut(),
+            file_type: std::ptr::null_mut(),
+        };
+        let mut recip = MapiRecipDescW {
+            reserved: 0,
+            recip_class: MAPI_TO,
+            name: name_w.as_mut_ptr(),
+            address: address_w.as_mut_ptr(),
+            eid_size: 0,
+            entry_id: std::ptr::null_mut(),
+        };
+        let has_recip = name_w.len() > 1;
+        let message = MapiMessageW {
+            reserved: 0,
+            subject: subject_w.as_mut_ptr(),
+            note_text: std::ptr::null_mut(),
+            message_type: std::ptr::null_mut(),
+            date_received: std::ptr::null_mut(),
+            conversation_id: std::ptr::null_mut(),
+            flags: 0,
+            originator: std::ptr::null_mut(),
+            recip_count: u32::from(has_recip),
+            recips: if has_recip {
+                &mut recip
+            } else {
+                std::ptr::null_mut()
+            },
+            file_count: 1,
+            files: &mut file,
+        };
+        let code = unsafe { send(0, 0, &message, MAPI_LOGON_UI | MAPI_DIALOG, 0) };
+        let _ = tx.send(code);
+    });
+    match rx.recv_timeout(COMPOSE_GRACE) {
+        Ok(0) | Ok(MAPI_USER_ABORT) | Err(RecvTimeoutError::Timeout) => Ok(()),
+        Ok(code) => Err(anyhow!(
+            "the mail client refused the message (MAPI error {code})"
+        )),
+        Err(RecvTimeoutError::Disconnected) => Err(anyhow!("the mail client crashed")),
+    }
+}
+
+impl ImageUploader {
+    /// `hosted` is the link-only webhooks' copy of the capture, uploaded by
+    /// the caller so a retried post doesn't upload it again
+    pub(super) fn post_webhook(
+        &self,
+        data: &[u8],
+        mime: &str,
+        file_name: &str,
+        target: &WebhookTarget,
+        hosted: Option<&UploadResult>,
+    ) -> Result<UploadResult> {
+        if target.url.is_empty() {
+            return Err(anyhow!("Webhook URL not configured"));
+        }
+        Self::validate_url_security(&target.url)?;
+        match (target.kind, hosted) {
+            (WebhookKind::Discord, _) => self.post_discord(data, mime, file_name, target),
+            (kind, Some(hosted)) => {
+                let body = link_payload(kind, &target.message, &hosted.url);
+                let response = self.client.post(&target.url).json(&body).send()?;
+                let status = response.status();
+                if !status.is_success() {
+                    return Err(anyhow!(
+                        "{} webhook failed with status: {}",
+                        kind.display_name(),
+                        status
+                    ));
+                }
+                Ok(hosted.clone())
+            }
+            (kind, None) => Err(anyhow!(
+                "{} webhooks need a link host for the image",
+                kind.display_name()
+            )),
+        }
+    }
+
+    fn post_discord(
+        &self,
+        data: &[u8],
+        mime: &str,
+        file_name: &str,
+        target: &WebhookTarget,
+    ) -> Result<UploadResult> {
+        // wait=true makes discord answer with the created message, which
+        // carries the attachment's cdn url
+        let mut url = url::Url::parse(&target.url)?;
+        url.query_pairs_mut().append_pair("wait", "true");
+        let payload = if target.message.is_empty() {
+            serde_json::json!({})
+        } else {
+            serde_json::json!({ "content": target.message })
+        };
+        let form = reqwest::blocking::multipart::Form::new()
+            .text("payload_json", payload.to_string())
+            .part(
+                "files[0]",
+                reqwest::blocking::multipart::Part::bytes(data.to_vec())
+                    .file_name(file_name.to_string())
+                    .mime_str(mime)?,
+            );
+        let response = self.client.post(url).multipart(form).send()?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!("Discord webhook failed with status: {}", status));
+        }
+        let text = response.text()?;
+        if text.len() > MAX_RESPONSE_SIZE {
+            return Err(anyhow!("Response too large"));
+        }
+        let json: serde_json::Value = serde_json::from_str(&text)?;
+        let link = discord_attachment_url(&json)
+            .ok_or_else(|| anyhow!("No attachment in Discord response"))?;
+        if link.len() > MAX_URL_LEN {
+            return Err(anyhow!("URL too long"));
+        }
+        Self::validate_returned_url(link)?;
+        Ok(UploadResult {
+            url: link.to_string(),
+            delete_url: None,
+        })
+    }
+}
+
+fn discord_attachment_url(json: &serde_json::Value) -> Option<&str> {
+    json.get("attachments")?.get(0)?.get("url")?.as_str()
+}
+
+fn link_payload(kind: WebhookKind, message: &str, link: &str) -> serde_json::Value {
+    match kind {
+        WebhookKind::Teams => {
+            // teams workflows webhooks take an adaptive card; the image
+            // element renders the capture inline in the channel
+            let mut body = Vec::new();
+            if !message.is_empty() {
+                body.push(serde_json::json!({
+                    "type": "TextBlock",
+                    "text": message,
+                    "wrap": true,
+                }));
+            }
+            body.push(serde_json::json!({ "type": "Image", "url": link }));
+            serde_json::json!({
+                "type": "message",
+                "attachments": [{
+                    "contentType": "application/vnd.microsoft.card.adaptive",
+                    "content": {
+                        "type": "AdaptiveCard",
+                        "version": "1.4",
+                        "body": body,
+                        "actions": [{ "type": "Action.OpenUrl", "title": "Open", "url": link }],
+                    },
+                }],
+            })
+        }
+        // slack unfurls the bare link into a preview
+        _ if message.is_empty() => serde_json::json!({ "text": link }),
+        _ => serde_json::json!({ "text": format!("{message}\n{link}") }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slack_text_carries_message_and_link() {
+        let body = link_payload(WebhookKind::Slack, "build 42", "https://i.example/a.png");
+        assert_eq!(body["text"], "build 42\nhttps://i.example/a.png");
+        let body = link_payload(WebhookKind::Slack, "", "https://i.example/a.png");
+        assert_eq!(body["text"], "https://i.example/a.png");
+    }
+
+    #[test]
+    fn teams_card_shows_the_image() {
+        let body = link_payload(WebhookKind::Teams, "", "https://i.example/a.png");
+        let card = &body["attachments"][0]["content"];
+        assert_eq!(card["type"], "AdaptiveCard");
+        assert_eq!(card["body"][0]["type"], "Image");
+        assert_eq!(card["body"][0]["url"], "https://i.example/a.png");
+    }
+
+    #[test]
+    fn discord_response_attachment() {
+        let json = serde_json::json!({
+            "id": "1",
+            "attachments": [{ "url": "https://cdn.discordapp.com/attachments/1/2/a.png" }],
+        });
+        assert_eq!(
+            discord_attachment_url(&json),
+            Some("https://cdn.discordapp.com/attachments/1/2/a.png")
+        );
+        assert_eq!(discord_attachment_url(&serde_json::json!({})), None);
+    }
+
+    #[test]
+    fn attachment_name_stays_in_the_send_dir() {
+        let scratch = tempfile::tempdir().expect("tempdir");
+        let send = scratch.path().join("send");
+        let path = write_attachment_in(&send, b"x", "../../evil.png").unwrap();
+        assert_eq!(path.file_name().unwrap(), "evil.png");
+        assert_eq!(path.parent().unwrap(), send);
+        let path = write_attachment_in(&send, b"x", "..").unwrap();
+        assert_eq!(path.file_name().unwrap(), "screenshot.png");
+    }
+}
@@ -0,0 +1,350 @@
+// ShareX custom uploader (.sxcu) import. an sxcu file is a JSON description of
+// one HTTP upload endpoint; most image hosts publish one. only the parts that
+// map onto capscr's custom uploader are taken — multipart POST or raw-body PUT,
+// static headers and fields, and a json path or regex for the url. anything
+// that can't be represented either fails the import or comes back as a
+// warning, never silently dropped.
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::config::{CustomFormField, CustomHeader, CustomHttpMethod, CustomUploaderPreset};
+
+pub const MAX_SXCU_SIZE: u64 = 64 * 1024;
+
+/// the preset comes back without an id; the UI assigns one when it adds it
+#[derive(Debug, Clone, Serialize)]
+pub struct SxcuImport {
+    pub preset: CustomUploaderPreset,
+    pub warnings: Vec<String>,
+}
+
+// field names vary in case between ShareX versions, so look them up loosely
+fn field<'a>(
+    obj: &'a serde_json::Map<String, serde_json::Value>,
+    name: &str,
+) -> Option<&'a serde_json::Value> {
+    obj.iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v)
+}
+
+fn string_field(obj: &serde_json::Map<String, serde_json::Value>, name: &str) -> String {
+    field(obj, name)
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+fn string_map(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    name: &str,
+) -> Result<BTreeMap<String, String>> {
+    let Some(value) = field(obj, name) else {
+        return Ok(BTreeMap::new());
+    };
+    let map = value
+        .as_object()
+        .ok_or_else(|| anyhow!("'{name}' must be an object"))?;
+    map.iter()
+        .map(|(k, v)| {
+            let v = match v {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Number(n) => n.to_string(),
+                serde_json::Value::Bool(b) => b.to_string(),
+                _ => return Err(anyhow!("'{name}.{k}' must be a string")),
+            };
+            Ok((k.clone(), v))
+        })
+        .collect()
+}
+
+// ShareX expands {filename}, {random}, $header:..$ and friends at upload time;
+// capscr sends values verbatim
+fn has_sharex_syntax(value: &str) -> bool {
+    (value.contains('{') && value.contains('}')) || value.matches('$').count() >= 2
+}
+
+// headers and fields that carry credentials get the secret flag so they're
+// vault-wrapped
+fn looks_secret(name: &str) -> bool {
+    let lower = name.to_ascii_lowercase();
+    [
+        "authorization",
+        "key",
+        "token",
+        "secret",
+        "password",
+        "auth",
+    ]
+    .iter()
+    .any(|needle| lower.contains(needle))
+}
+
+pub fn parse_sxcu(text: &str) -> Result<SxcuImport> {
+    let json: serde_json::Value = serde_json::from_str(text.trim_start_matches('\u{feff}'))
+        .map_err(|e| anyhow!("not a valid .sxcu file: {e}"))?;
+    let obj = json
+        .as_object()
+        .ok_or_else(|| anyhow!("not a valid .sxcu file: expected a JSON object"))?;
+    let mut warnings = Vec::new();
+
+    let destination = string_field(obj, "DestinationType");
+    if !destination.is_empty()
+        && !destination.contains("ImageUploader")
+        && !destination.contains("FileUploader")
+    {
+        return Err(anyhow!(
+            "this uploader is for {destination}, not images or files"
+        ));
+    }
+
+    let mut url = url::Url::parse(&string_field(obj, "RequestURL"))
+        .map_err(|e| anyhow!("RequestURL is missing or invalid: {e}"))?;
+    if url.scheme() != "https" {
+        return Err(anyhow!(
+            "RequestURL must be https; capscr refuses plain http uploads"
+        ));
+    }
+    let parameters = string_map(obj, "Parameters")?;
+    if !parameters.is_empty() {
+        let mut query = url.query_pairs_mut();
+        for (k, v) in &parameters {
+            query.append_pair(k, v);
+        }
+    }
+
+    // older files use RequestType
+    let request_method = match string_field(obj, "RequestMethod") {
+        m if m.is_empty() => string_field(obj, "RequestType"),
+        m => m,
+    };
+    let body = string_field(obj, "Body");
+    let method = match (request_method.to_ascii_uppercase().as_str(), body.as_str()) {
+        ("" | "POST", "" | "MultipartFormData") => CustomHttpMethod::Post,
+        ("PUT", "Binary") => CustomHttpMethod::Put,
+        (m, "" | "MultipartFormData") if m != "POST" => {
+            return Err(anyhow!(
+                "{m} with a multipart body isn't supported; only POST"
+            ))
+        }
+        (m, b) => {
+            let m = if m.is_empty() { "POST" } else { m };
+            return Err(anyhow!("{m} with a {b} body isn't supported"));
+        }
+    };
+
+    let mut headers = Vec::new();
+    for (name, value) in string_map(obj, "Headers")? {
+        if has_sharex_syntax(&value) {
+            warnings.push(format!(
+                "header '{name}' uses ShareX placeholders; sent verbatim"
+            ));
+        }
+        headers.push(CustomHeader {
+            secret: looks_secret(&name),
+            name,
+            value,
+            value_encrypted: String::new(),
+        });
+    }
+    let mut form_fields = Vec::new();
+    for (name, value) in string_map(obj, "Arguments")? {
+        if has_sharex_syntax(&value) {
+            warnings.push(format!(
+                "field '{name}' uses ShareX placeholders; sent verbatim"
+            ));
+        }
+        form_fields.push(CustomFormField {
+            secret: looks_secret(&name),
+            name,
+            value,
+            value_encrypted: String::new(),
+        });
+    }
+
+    let mut form_name = string_field(obj, "FileFormName");
+    if form_name.is_empty() {
+        form_name = "file".to_string();
+    }
+
+    let regex_list: Vec<String> = field(obj, "RegexList")
+        .and_then(|v| v.as_array())
+        .map(|a| {
+            a.iter()
+                .filter_map(|r| r.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    let (response_path, response_regex) =
+        parse_url_template(&string_field(obj, "URL"), &regex_list, &mut warnings);
+
+    let mut name = string_field(obj, "Name");
+    if name.is_empty() {
+        name = url.host_str().unwrap_or("Custom").to_string();
+    }
+
+    Ok(SxcuImport {
+        preset: CustomUploaderPreset {
+            id: String::new(),
+            name,
+            url: url.to_string(),
+            method,
+            form_name,
+            response_path,
+            response_regex,
+            headers,
+            form_fields,
+            max_file_mb: 0,
+        },
+        warnings,
+    })
+}
+
+// the sxcu `URL` field is a template over the response. capscr can follow a
+// template that is exactly one json path or one regex; anything composed
+// (`https://host/{json:id}.png`) falls back to reading the raw body
+fn parse_url_template(
+    template: &str,
+    regex_list: &[String],
+    warnings: &mut Vec<String>,
+) -> (String, String) {
+    let fallback = ("url".to_string(), String::new());
+    let inner = template
+        .strip_prefix('{')
+        .and_then(|t| t.strip_suffix('}'))
+        .or_else(|| template.strip_prefix('$').and_then(|t| t.strip_suffix('$')));
+    let (kind, arg) = match inner.and_then(|i| i.split_once(':')) {
+        Some(parts) if !parts.1.contains(['{', '}', '$']) => parts,
+        _ => {
+            if !(template.is_empty() || template == "{response}" || template == "$response$") {
+                warnings.push(format!(
+                    "url template '{template}' can't be mapped; the raw response is used instead"
+                ));
+            }
+            return fallback;
+        }
+    };
+    match kind {
+        "json" => {
+            // `files[0].url` → `files.0.url`; a leading `$.` is jsonpath noise
+            let path = arg
+                .trim_start_matches("$.")
+                .replace('[', ".")
+                .replace(']', "");
+            (path, String::new())
+        }
+        "regex" => {
+            // `{regex:1|2}` / `$regex:1,2$` index RegexList (1-based); newer
+            // files inline the pattern as `{regex:pattern|group}`
+            let (head, group) = arg
+                .rsplit_once(['|', ','])
+                .filter(|(_, g)| g.chars().all(|c| c.is_ascii_digit()))
+                .map(|(h, g)| (h, g.parse::<usize>().unwrap_or(1)))
+                .unwrap_or((arg, 1));
+            let pattern = match head.parse::<usize>() {
+                Ok(index) => match index.checked_sub(1).and_then(|i| regex_list.get(i)) {
+                    Some(p) => p.clone(),
+                    None => {
+                        warnings.push(format!("url regex #{index} is missing from RegexList"));
+                        return fallback;
+                    }
+                },
+                Err(_) => head.to_string(),
+            };
+            if group > 1 {
+                warnings.push(format!(
+                    "url regex uses capture group {group}; capscr takes the first group"
+                ));
+            }
+            (fallback.0, pattern)
+        }
+        _ => {
+            warnings.push(format!(
+                "url template '{template}' isn't supported; the raw response is used instead"
+            ));
+            fallback
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn imports_a_typical_multipart_uploader() {
+        let import = parse_sxcu(
+            r#"{
+                "Version": "14.1.0",
+                "Name": "example host",
+                "DestinationType": "ImageUploader, FileUploader",
+                "RequestMethod": "POST",
+                "RequestURL": "https://up.example.com/api/upload",
+                "Parameters": { "expiry": "7d" },
+                "Headers": { "Authorization": "Bearer abc", "X-Client": "{random}" },
+                "Body": "MultipartFormData",
+                "Arguments": { "album": "shots", "key": "k-123" },
+                "FileFormName": "image",
+                "URL": "{json:files[0].url}"
+            }"#,
+        )
+        .unwrap();
+        let preset = &import.preset;
+        assert_eq!(preset.name, "example host");
+        assert_eq!(preset.url, "https://up.example.com/api/upload?expiry=7d");
+        assert_eq!(preset.method, CustomHttpMethod::Post);
+        assert_eq!(preset.form_name, "image");
+        assert_eq!(preset.response_path, "files.0.url");
+        let auth = &preset.headers[0];
+        assert_eq!(auth.name, "Authorization");
+        assert!(auth.secret);
+        assert!(!preset.headers[1].secret);
+        assert_eq!(preset.form_fields[0].value, "shots");
+        assert!(!preset.form_fields[0].secret);
+        assert!(preset.form_fields[1].secret);
+        assert_eq!(import.warnings.len(), 1, "{:?}", import.warnings);
+    }
+
+    #[test]
+    fn legacy_regex_urls_resolve_through_regex_list() {
+        let import = parse_sxcu(
+            r#"{
+                "RequestType": "PUT",
+                "RequestURL": "https://put.example.com/",
+                "Body": "Binary",
+                "RegexList": ["href=\"([^\"]+)\""],
+                "URL": "$regex:1,1$"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(import.preset.method, CustomHttpMethod::Put);
+        assert_eq!(import.preset.response_regex, r#"href="([^"]+)""#);
+        assert_eq!(import.preset.name, "put.example.com");
+        assert!(import.warnings.is_empty());
+    }
+
+    #[test]
+    fn composed_url_templates_fall_back_with_a_warning() {
+        let import = parse_sxcu(
+            r#"{"RequestURL": "https://a.example/", "URL": "https://a.example/{json:id}.png"}"#,
+        )
+        .unwrap();
+        assert_eq!(import.preset.response_path, "url");
+        assert!(import.preset.response_regex.is_empty());
+        assert_eq!(import.warnings.len(), 1);
+    }
+
+    #[test]
+    fn unsupported_uploaders_are_refused() {
+        assert!(parse_sxcu(r#"{"RequestURL": "http://plain.example/"}"#).is_err());
+        assert!(parse_sxcu(
+            r#"{"RequestURL": "https://a.example/", "DestinationType": "URLShortener"}"#
+        )
+        .is_err());
+        assert!(parse_sxcu(r#"{"RequestURL": "https://a.example/", "Body": "JSON"}"#).is_err());
+        assert!(parse_sxcu("not json").is_err());
+    }
+}
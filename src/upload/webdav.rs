@@ -0,0 +1,311 @@
+// WebDAV uploads, with optional public share links on Nextcloud/ownCloud.
+// the capture is PUT under a strftime-expanded folder below the server url,
+// creating the folders on the way. a plain WebDAV url needs the account's
+// credentials to open, so for nextcloud the OCS sharing api turns the file
+// into a public link; other servers need public_url_template instead
+
+use anyhow::{anyhow, Result};
+use reqwest::{Method, StatusCode};
+
+use super::{
+    build_url, sanitize_remote_filename, shared_uploader, uniquify_remote_filename, ImageUploader,
+    TestStep, UploadResult, MAX_RESPONSE_SIZE, MAX_URL_LEN,
+};
+
+const MAX_PATH_SEGMENTS: usize = 16;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WebdavTarget {
+    /// the WebDAV root, e.g. https://cloud.example.com/remote.php/dav/files/me
+    pub server_url: String,
+    pub username: String,
+    pub password: String,
+    /// folder below the root; strftime fields allowed
+    pub path_template: String,
+    /// create a nextcloud/ownCloud public share and return its link
+    pub share_link: bool,
+    pub public_url_template: String,
+}
+
+impl ImageUploader {
+    pub(super) fn upload_webdav(
+        &self,
+        data: &[u8],
+        mime: &str,
+        file_name: &str,
+        target: &WebdavTarget,
+    ) -> Result<UploadResult> {
+        if target.server_url.is_empty() {
+            return Err(anyhow!("WebDAV server URL not configured"));
+        }
+        Self::validate_url_security(&target.server_url)?;
+        let folders = expand_path(&target.path_template, chrono::Local::now())?;
+        let file_name = uniquify_remote_filename(&sanitize_remote_filename(file_name));
+
+        let mut url = url::Url::parse(&target.server_url)?;
+        for depth in 1..=folders.len() {
+            self.make_collection(&join(&url, &folders[..depth])?, target)?;
+        }
+        let mut path = folders.clone();
+        path.push(file_name.clone());
+        url = join(&url, &path)?;
+
+        let response = self
+            .client
+            .put(url.clone())
+            .basic_auth(&target.username, Some(&target.password))
+            .header("Content-Type", mime)
+            .body(data.to_vec())
+            .send()?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!("WebDAV upload failed with status: {}", status));
+        }
+
+        let link = if target.share_link {
+            self.nextcloud_share(&target.server_url, &path, target)?
+        } else if !target.public_url_template.is_empty() {
+            let folder = folders.join("/");
+            build_url(
+                &target.public_url_template.replace("{path}", &folder),
+                &file_name,
+            )?
+        } else {
+            url.to_string()
+        };
+        Ok(UploadResult {
+            url: link,
+            delete_url: None,
+        })
+    }
+
+    // MKCOL answers 405 when the folder is already there
+    fn make_collection(&self, url: &url::Url, target: &WebdavTarget) -> Result<()> {
+        let response = self
+            .client
+            .request(Method::from_bytes(b"MKCOL")?, url.clone())
+            .basic_auth(&target.username, Some(&target.password))
+            .send()?;
+        match response.status() {
+            s if s.is_success() || s == StatusCode::METHOD_NOT_ALLOWED => Ok(()),
+            s => Err(anyhow!(
+                "WebDAV couldn't create folder {}: {}",
+                url.path(),
+                s
+            )),
+        }
+    }
+
+    fn nextcloud_share(
+        &self,
+        server_url: &str,
+        path: &[String],
+        target: &WebdavTarget,
+    ) -> Result<String> {
+        let (base, prefix) = nextcloud_split(server_url).ok_or_else(|| {
+            anyhow!("share links need a Nextcloud/ownCloud url ending in /remote.php/…")
+        })?;
+        let mut ocs = base;
+        ocs.path_segments_mut()
+            .map_err(|_| anyhow!("invalid server URL"))?
+            .pop_if_empty()
+            .extend([
+                "ocs",
+                "v2.php",
+                "apps",
+                "files_sharing",
+                "api",
+                "v1",
+                "shares",
+            ]);
+        ocs.set_query(Some("format=json"));
+        let share_path = format!("/{}", [prefix, path.to_vec()].concat().join("/"));
+        let response = self
+            .client
+            .post(ocs)
+            .basic_auth(&target.username, Some(&target.password))
+            .header("OCS-APIRequest", "true")
+            // share type 3 is a public link; permission 1 is read-only
+            .form(&[
+                ("path", share_path.as_str()),
+                ("shareType", "3"),
+                ("permissions", "1"),
+            ])
+            .send()?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!("share link request failed with status: {}", status));
+        }
+        let text = response.text()?;
+        if text.len() > MAX_RESPONSE_SIZE {
+            return Err(anyhow!("Response too large"));
+        }
+        let json: serde_json::Value = serde_json::from_str(&text)?;
+        let link = json
+            .pointer("/ocs/data/url")
+            .and_then(|u| u.as_str())
+            .ok_or_else(|| anyhow!("No share link in response"))?;
+        if link.len() > MAX_URL_LEN {
+            return Err(anyhow!("URL too long"));
+        }
+        Self::validate_returned_url(link)?;
+        Ok(link.to_string())
+    }
+}
+
+// PROPFIND on the root checks the url and the credentials without writing
+pub fn test_connection_webdav(target: &WebdavTarget) -> Result<Vec<TestStep>> {
+    let mut steps = Vec::new();
+    if target.server_url.is_empty() {
+        steps.push(TestStep::fail("config", "Server URL is empty".into()));
+        return Ok(steps);
+    }
+    if let Err(e) = ImageUploader::validate_url_security(&target.server_url) {
+        steps.push(TestStep::fail("validate-url", e.to_string()));
+        return Ok(steps);
+    }
+    steps.push(TestStep::ok("validate-url", target.server_url.clone()));
+    if let Err(e) = expand_path(&target.path_template, chrono::Local::now()) {
+        steps.push(TestStep::fail("path-template", e.to_string()));
+        return Ok(steps);
+    }
+    if target.share_link && nextcloud_split(&target.server_url).is_none() {
+        steps.push(TestStep::fail(
+            "share-link",
+            "share links need a Nextcloud/ownCloud url ending in /remote.php/…".into(),
+        ));
+        return Ok(steps);
+    }
+
+    let uploader = shared_uploader()?;
+    let response = uploader
+        .client
+        .request(Method::from_bytes(b"PROPFIND")?, &target.server_url)
+        .basic_auth(&target.username, Some(&target.password))
+        .header("Depth", "0")
+        .send();
+    match response {
+        Ok(r) if r.status().is_success() => {
+            steps.push(TestStep::ok("propfind", format!("status {}", r.status())))
+        }
+        Ok(r) if r.status() == StatusCode::UNAUTHORIZED => steps.push(TestStep::fail(
+            "propfind",
+            "username or password rejected".into(),
+        )),
+        Ok(r) => steps.push(TestStep::fail("propfind", format!("status {}", r.status()))),
+        Err(e) => steps.push(TestStep::fail("propfind", e.to_string())),
+    }
+    Ok(steps)
+}
+
+/// folder segments from the path template, expanded at `now`
+fn expand_path<Tz: chrono::TimeZone>(
+    template: &str,
+    now: chrono::DateTime<Tz>,
+) -> Result<Vec<String>>
+where
+    Tz::Offset: std::fmt::Display,
+{
+    use std::fmt::Write;
+    let mut expanded = String::new();
+    write!(expanded, "{}", now.format(template))
+        .map_err(|_| anyhow!("invalid date field in path template"))?;
+    let segments: Vec<String> = expanded
+        .split(['/', '\\'])
+        .map(str::trim)
+        .filter(|s| !s.is_empty() && *s != ".")
+        .map(String::from)
+        .collect();
+    if segments
+        .iter()
+        .any(|s| s == ".." || s.chars().any(char::is_control))
+    {
+        return Err(anyhow!("path template has an invalid folder name"));
+    }
+    if segments.len() > MAX_PATH_SEGMENTS {
+        return Err(anyhow!("path template is too deep"));
+    }
+    Ok(segments)
+}
+
+fn join(base: &url::Url, segments: &[String]) -> Result<url::Url> {
+    let mut url = base.clone();
+    url.path_segments_mut()
+        .map_err(|_| anyhow!("invalid server URL"))?
+        .pop_if_empty()
+        .extend(segments);
+    Ok(url)
+}
+
+// nextcloud serves WebDAV at <base>/remote.php/webdav/… or
+// <base>/remote.php/dav/files/<user>/…; the sharing api lives under <base>
+// and wants paths relative to the user's root. returns the base and the
+// server url's own folder below that root
+fn nextcloud_split(server_url: &str) -> Option<(url::Url, Vec<String>)> {
+    let url = url::Url::parse(server_url).ok()?;
+    let segments: Vec<String> = url
+        .path_segments()?
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            percent_encoding::percent_decode_str(s)
+                .decode_utf8_lossy()
+                .into_owned()
+        })
+        .collect();
+    let at = segments.iter().position(|s| s == "remote.php")?;
+    let prefix = match &segments[at + 1..] {
+        [webdav, rest @ ..] if webdav == "webdav" => rest,
+        [dav, files, _user, rest @ ..] if dav == "dav" && files == "files" => rest,
+        _ => return None,
+    };
+    let mut base = url.clone();
+    base.set_query(None);
+    base.path_segments_mut()
+        .ok()?
+        .clear()
+        .extend(&segments[..at]);
+    Some((base, prefix.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn path_template_expands_and_splits() {
+        let now = chrono::Utc.with_ymd_and_hms(2026, 3, 9, 12, 0, 0).unwrap();
+        assert_eq!(
+            expand_path("/screenshots/%Y/%m/", now).unwrap(),
+            vec!["screenshots", "2026", "03"]
+        );
+        assert!(expand_path("", now).unwrap().is_empty());
+        assert!(expand_path("a/../b", now).is_err());
+        assert!(expand_path("a\\..\\b", now).is_err());
+    }
+
+    #[test]
+    fn nextcloud_urls_split_into_base_and_user_path() {
+        let (base, prefix) =
+            nextcloud_split("https://cloud.example.com/remote.php/dav/files/me/Photos").unwrap();
+        assert_eq!(base.as_str(), "https://cloud.example.com/");
+        assert_eq!(prefix, vec!["Photos"]);
+
+        let (base, prefix) =
+            nextcloud_split("https://example.com/nextcloud/remote.php/webdav/").unwrap();
+        assert_eq!(base.as_str(), "https://example.com/nextcloud");
+        assert!(prefix.is_empty());
+
+        assert!(nextcloud_split("https://dav.example.com/files/").is_none());
+    }
+
+    #[test]
+    fn join_appends_encoded_segments() {
+        let base = url::Url::parse("https://h.example/remote.php/webdav/").unwrap();
+        let url = join(&base, &["my shots".into(), "a.png".into()]).unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://h.example/remote.php/webdav/my%20shots/a.png"
+        );
+    }
+}
@@ -0,0 +1,181 @@
+// watch folder: images another program writes into a chosen folder (a game's
+// screenshot folder, say) run through the post-capture action as they appear,
+// so capscr doubles as an uploader for screenshots it didn't take. files
+// already there when watching starts are left alone.
+
+use crate::config::TaskPostAction;
+use crate::state::AppState;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+// a folder poll rather than a change notification: it's one directory
+// listing, and a game may write a file in several passes anyway
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+// what image::open decodes; gif is left out since a still upload would drop
+// the animation
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "bmp"];
+
+/// start the watcher thread. it follows the config, so enabling, disabling or
+/// moving the folder in settings takes effect on the next poll
+pub fn spawn(app: &AppHandle) {
+    let app = app.clone();
+    if let Err(e) = std::thread::Builder::new()
+        .name("capscr-watch-folder".into())
+        .spawn(move || run(app))
+    {
+        tracing::warn!("couldn't start the watch folder thread: {e}");
+    }
+}
+
+fn run(app: AppHandle) {
+    let mut watch: Option<Watch> = None;
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let cfg = app
+            .state::<AppState>()
+            .config
+            .lock()
+            .unwrap()
+            .watch_folder
+            .clone();
+        if !cfg.enabled || cfg.directory.as_os_str().is_empty() {
+            watch = None;
+            continue;
+        }
+        // a removable drive or network share may come back; keep polling
+        let Some(listing) = list_images(&cfg.directory) else {
+            continue;
+        };
+        match watch.as_mut() {
+            Some(w) if w.dir == cfg.directory => {
+                for path in w.poll(listing) {
+                    tracing::info!("watch folder: new image {}", path.display());
+                    if let Err(e) = crate::commands::run_pipeline_on_file(&app, &path, cfg.action) {
+                        tracing::warn!("watch folder: {} failed: {e:#}", path.display());
+                        crate::commands::emit_error(
+                            &app,
+                            "watch-folder",
+                            &format!("{}: {e:#}", path.display()),
+                        );
+                    }
+                }
+            }
+            _ => {
+                tracing::info!("watching {} for new images", cfg.directory.display());
+                watch = Some(Watch::new(cfg.directory.clone(), listing));
+            }
+        }
+    }
+}
+
+fn list_images(dir: &Path) -> Option<Vec<(PathBuf, u64)>> {
+    let entries = std::fs::read_dir(dir).ok()?;
+    Some(
+        entries
+            .flatten()
+            .filter(|entry| is_image(&entry.path()))
+            .filter_map(|entry| {
+                let meta = entry.metadata().ok()?;
+                meta.is_file().then(|| (entry.path(), meta.len()))
+            })
+            .collect(),
+    )
+}
+
+fn is_image(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| IMAGE_EXTENSIONS.iter().any(|x| e.eq_ignore_ascii_case(x)))
+}
+
+struct Watch {
+    dir: PathBuf,
+    // handed off already, or present when watching started
+    seen: HashSet<PathBuf>,
+    // new files and their size at the last poll
+    growing: HashMap<PathBuf, u64>,
+}
+
+impl Watch {
+    fn new(dir: PathBuf, listing: Vec<(PathBuf, u64)>) -> Self {
+        Self {
+            dir,
+            seen: listing.into_iter().map(|(path, _)| path).collect(),
+            growing: HashMap::new(),
+        }
+    }
+
+    /// new files whose size held still since the last poll, so a game still
+    /// writing one isn't read half-done
+    fn poll(&mut self, listing: Vec<(PathBuf, u64)>) -> Vec<PathBuf> {
+        let present: HashSet<PathBuf> = listing.iter().map(|(path, _)| path.clone()).collect();
+        let mut ready = Vec::new();
+        for (path, len) in listing {
+            if self.seen.contains(&path) {
+                continue;
+            }
+            if self.growing.insert(path.clone(), len) == Some(len) && len > 0 {
+                self.growing.remove(&path);
+                self.seen.insert(path.clone());
+                ready.push(path);
+            }
+        }
+        // a deleted name that comes back later is a new screenshot
+        self.seen.retain(|path| present.contains(path));
+        self.growing.retain(|path, _| present.contains(path));
+        ready.sort();
+        ready
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, len: u64) -> (PathBuf, u64) {
+        (PathBuf::from(name), len)
+    }
+
+    #[test]
+    fn only_new_files_that_stopped_growing_are_picked_up() {
+        let mut watch = Watch::new(PathBuf::from("shots"), vec![entry("old.png", 10)]);
+        assert!(watch
+            .poll(vec![entry("old.png", 10), entry("new.png", 0)])
+            .is_empty());
+        assert!(watch
+            .poll(vec![entry("old.png", 10), entry("new.png", 0)])
+            .is_empty());
+        assert!(watch
+            .poll(vec![entry("old.png", 10), entry("new.png", 500)])
+            .is_empty());
+        assert_eq!(
+            watch.poll(vec![entry("old.png", 10), entry("new.png", 500)]),
+            vec![PathBuf::from("new.png")]
+        );
+        assert!(watch
+            .poll(vec![entry("old.png", 10), entry("new.png", 500)])
+            .is_empty());
+    }
+
+    #[test]
+    fn a_deleted_and_rewritten_name_counts_again() {
+        let mut watch = Watch::new(PathBuf::from("shots"), vec![entry("a.png", 10)]);
+        assert!(watch.poll(Vec::new()).is_empty());
+        assert!(watch.poll(vec![entry("a.png", 20)]).is_empty());
+        assert_eq!(
+            watch.poll(vec![entry("a.png", 20)]),
+            vec![PathBuf::from("a.png")]
+        );
+    }
+
+    #[test]
+    fn image_extensions_match_case_insensitively() {
+        assert!(is_image(Path::new("Screenshot 1.PNG")));
+        assert!(is_image(Path::new("shot.jpeg")));
+        assert!(!is_image(Path::new("clip.gif")));
+        assert!(!is_image(Path::new("notes.txt")));
+        assert!(!is_image(Path::new("png")));
+    }
+}
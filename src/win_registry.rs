@@ -0,0 +1,48 @@
+// per-user shell registration (the capscr: url class, the image context menu)
+// lives under HKCU\Software\Classes, so none of it needs elevation
+
+use std::ffi::OsStr;
+use std::os::windows::ffi::OsStrExt;
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::ERROR_FILE_NOT_FOUND;
+use windows::Win32::System::Registry::{
+    RegDeleteTreeW, RegSetKeyValueW, HKEY_CURRENT_USER, REG_SZ,
+};
+
+fn wide(s: &str) -> Vec<u16> {
+    OsStr::new(s).encode_wide().chain(Some(0)).collect()
+}
+
+/// write a string value under HKCU, creating the key as needed. `name` None
+/// is the key's default value
+pub fn set_string(subkey: &str, name: Option<&str>, value: &str) -> anyhow::Result<()> {
+    let subkey = wide(subkey);
+    let name = name.map(wide);
+    let data = wide(value);
+    // SAFETY: every buffer is nul-terminated utf-16 and outlives the call;
+    // cbdata is the byte length including the terminator
+    unsafe {
+        RegSetKeyValueW(
+            HKEY_CURRENT_USER,
+            PCWSTR(subkey.as_ptr()),
+            name.as_ref().map_or(PCWSTR::null(), |n| PCWSTR(n.as_ptr())),
+            REG_SZ.0,
+            Some(data.as_ptr() as *const _),
+            (data.len() * 2) as u32,
+        )
+    }
+    .ok()?;
+    Ok(())
+}
+
+/// remove a key under HKCU with everything below it; a missing key is fine
+pub fn delete_tree(subkey: &str) -> anyhow::Result<()> {
+    let subkey = wide(subkey);
+    // SAFETY: subkey is nul-terminated utf-16 and outlives the call
+    let status = unsafe { RegDeleteTreeW(HKEY_CURRENT_USER, PCWSTR(subkey.as_ptr())) };
+    if status == ERROR_FILE_NOT_FOUND {
+        return Ok(());
+    }
+    status.ok()?;
+    Ok(())
+}